@@ -1,6 +1,14 @@
 /// Authentication commands for configuring GitHub access
 pub mod auth;
+/// Aggregation of the bypasses used across pull requests
+pub mod bypass_report;
 /// Pull request checking and validation commands
 pub mod check_pr;
 /// Configuration management commands
 pub mod config_cmd;
+/// Local title and body checks against the configured rules
+pub mod explain;
+/// Polling repositories for pull requests without webhooks
+pub mod poll;
+/// Selection of the repositories a command acts on
+pub mod repositories;
@@ -0,0 +1,140 @@
+use clap::ValueEnum;
+use merge_warden_core::validation_result::BypassRuleType;
+use merge_warden_core::CheckResult;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::errors::CliError;
+
+#[cfg(test)]
+#[path = "bypass_report_tests.rs"]
+mod tests;
+
+/// The output format of a bypass report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BypassReportFormat {
+    /// A JSON array with one object per user and rule type
+    Json,
+
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+/// How often one user bypassed one rule type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BypassUsage {
+    /// The user who had the bypass permission
+    pub user: String,
+
+    /// The rule that was bypassed
+    pub rule_type: BypassRuleType,
+
+    /// The number of pull requests on which the rule was bypassed
+    pub count: u32,
+
+    /// The pull requests on which the rule was bypassed, as `owner/repo#number`
+    pub pull_requests: Vec<String>,
+}
+
+/// Aggregates the bypasses used across processed pull requests, grouped by user and
+/// rule type.
+///
+/// Users are compared ignoring case, as on GitHub. A pull request is counted once per
+/// user and rule type, even if it is recorded again by a later poll.
+#[derive(Debug, Default)]
+pub struct BypassStatistics {
+    /// The usages, keyed by the user in lower case and the rule type
+    usages: BTreeMap<(String, String), BypassUsage>,
+}
+
+impl BypassStatistics {
+    /// Records the bypasses used on one pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - The repository of the pull request, as `owner/repo`
+    /// * `pr_number` - The pull request number
+    /// * `result` - The check result of the pull request
+    pub fn record(&mut self, repository: &str, pr_number: u64, result: &CheckResult) {
+        let pull_request = format!("{}#{}", repository, pr_number);
+        for bypass in &result.bypasses_used {
+            let key = (
+                bypass.user.to_lowercase(),
+                rule_type_name(&bypass.rule_type),
+            );
+            let usage = self.usages.entry(key).or_insert_with(|| BypassUsage {
+                user: bypass.user.clone(),
+                rule_type: bypass.rule_type.clone(),
+                count: 0,
+                pull_requests: Vec::new(),
+            });
+            if !usage
+                .pull_requests
+                .iter()
+                .any(|pr| pr.eq_ignore_ascii_case(&pull_request))
+            {
+                usage.count += 1;
+                usage.pull_requests.push(pull_request.clone());
+            }
+        }
+    }
+
+    /// The usages, ordered by user and then by rule type.
+    pub fn usages(&self) -> impl Iterator<Item = &BypassUsage> {
+        self.usages.values()
+    }
+}
+
+/// Returns the name under which a rule type is reported, matching its JSON form.
+fn rule_type_name(rule_type: &BypassRuleType) -> String {
+    format!("{:?}", rule_type)
+}
+
+/// Quotes a CSV field if it holds a comma, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Formats the bypass statistics as a report.
+///
+/// The CSV report has the columns `user`, `rule_type`, `count` and `pull_requests`,
+/// with the pull requests separated by spaces.
+///
+/// # Arguments
+///
+/// * `statistics` - The aggregated bypasses
+/// * `format` - The output format
+///
+/// # Errors
+///
+/// Returns `CliError::Other` if the JSON report cannot be serialized.
+pub fn format_bypass_report(
+    statistics: &BypassStatistics,
+    format: BypassReportFormat,
+) -> Result<String, CliError> {
+    match format {
+        BypassReportFormat::Json => {
+            let usages: Vec<&BypassUsage> = statistics.usages().collect();
+            serde_json::to_string_pretty(&usages).map_err(|e| {
+                CliError::Other(format!("Failed to serialize the bypass report: {}", e))
+            })
+        }
+        BypassReportFormat::Csv => {
+            let mut lines = vec!["user,rule_type,count,pull_requests".to_string()];
+            for usage in statistics.usages() {
+                lines.push(format!(
+                    "{},{},{},{}",
+                    csv_field(&usage.user),
+                    rule_type_name(&usage.rule_type),
+                    usage.count,
+                    csv_field(&usage.pull_requests.join(" "))
+                ));
+            }
+            Ok(lines.join("\n"))
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use merge_warden_core::validation_result::BypassInfo;
+
+use super::*;
+
+fn result_with_bypasses(bypasses: &[(BypassRuleType, &str)]) -> CheckResult {
+    CheckResult {
+        bypasses_used: bypasses
+            .iter()
+            .map(|(rule_type, user)| BypassInfo {
+                rule_type: rule_type.clone(),
+                user: user.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_record_groups_bypasses_by_user_and_rule_type() {
+    let mut statistics = BypassStatistics::default();
+    statistics.record(
+        "owner/a",
+        1,
+        &result_with_bypasses(&[
+            (BypassRuleType::TitleConvention, "alice"),
+            (BypassRuleType::WorkItemReference, "alice"),
+        ]),
+    );
+    statistics.record(
+        "owner/b",
+        2,
+        &result_with_bypasses(&[(BypassRuleType::TitleConvention, "Alice")]),
+    );
+    statistics.record(
+        "owner/a",
+        3,
+        &result_with_bypasses(&[(BypassRuleType::PrSize, "bob")]),
+    );
+    statistics.record("owner/a", 4, &result_with_bypasses(&[]));
+
+    let usages: Vec<_> = statistics
+        .usages()
+        .map(|u| (u.user.as_str(), u.rule_type.clone(), u.count))
+        .collect();
+
+    assert_eq!(
+        usages,
+        vec![
+            ("alice", BypassRuleType::TitleConvention, 2),
+            ("alice", BypassRuleType::WorkItemReference, 1),
+            ("bob", BypassRuleType::PrSize, 1),
+        ]
+    );
+}
+
+#[test]
+fn test_record_counts_a_pull_request_once() {
+    let mut statistics = BypassStatistics::default();
+    let result = result_with_bypasses(&[(BypassRuleType::AllRules, "alice")]);
+    statistics.record("owner/a", 1, &result);
+    statistics.record("Owner/A", 1, &result);
+
+    let usage = statistics.usages().next().unwrap();
+
+    assert_eq!(usage.count, 1);
+    assert_eq!(usage.pull_requests, vec!["owner/a#1"]);
+}
+
+#[test]
+fn test_format_bypass_report_as_json() {
+    let mut statistics = BypassStatistics::default();
+    statistics.record(
+        "owner/a",
+        1,
+        &result_with_bypasses(&[(BypassRuleType::SignedCommits, "alice")]),
+    );
+
+    let report = format_bypass_report(&statistics, BypassReportFormat::Json).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "user": "alice",
+            "rule_type": "SignedCommits",
+            "count": 1,
+            "pull_requests": ["owner/a#1"]
+        }])
+    );
+}
+
+#[test]
+fn test_format_bypass_report_as_csv() {
+    let mut statistics = BypassStatistics::default();
+    statistics.record(
+        "owner/a",
+        1,
+        &result_with_bypasses(&[(BypassRuleType::TitleConvention, "release,bot")]),
+    );
+    statistics.record(
+        "owner/a",
+        2,
+        &result_with_bypasses(&[(BypassRuleType::TitleConvention, "release,bot")]),
+    );
+
+    let report = format_bypass_report(&statistics, BypassReportFormat::Csv).unwrap();
+
+    assert_eq!(
+        report,
+        "user,rule_type,count,pull_requests\n\"release,bot\",TitleConvention,2,owner/a#1 owner/a#2"
+    );
+}
+
+#[test]
+fn test_format_bypass_report_without_bypasses() {
+    let statistics = BypassStatistics::default();
+
+    assert_eq!(
+        format_bypass_report(&statistics, BypassReportFormat::Json).unwrap(),
+        "[]"
+    );
+    assert_eq!(
+        format_bypass_report(&statistics, BypassReportFormat::Csv).unwrap(),
+        "user,rule_type,count,pull_requests"
+    );
+}
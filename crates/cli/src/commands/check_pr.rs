@@ -17,19 +17,25 @@ use keyring::Entry;
 use merge_warden_core::config::{
     resolve_pull_request_config, CurrentPullRequestValidationConfiguration,
 };
+use merge_warden_core::dry_run::PlannedOperation;
 use merge_warden_core::errors::ConfigLoadError;
+use merge_warden_core::state::{InMemoryStateStore, StateStore};
 use merge_warden_core::MergeWarden;
 use merge_warden_developer_platforms::app_auth::AppAuthProvider;
 use merge_warden_developer_platforms::github::GitHubProvider;
-use std::collections::HashMap;
+use merge_warden_developer_platforms::webhook::PullRequestAction;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::fs;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::commands::auth::{
     KEY_RING_APP_ID, KEY_RING_APP_PRIVATE_KEY_PATH, KEY_RING_SERVICE_NAME,
 };
+use crate::commands::repositories::{
+    format_repository_summaries, is_repository_selected, resolve_repositories, RepositorySummary,
+};
 use crate::config::{get_config_path, AppConfig};
 use crate::errors::CliError;
 
@@ -89,6 +95,14 @@ struct MergeWardenWebhookHandler {
     github_client: GitHubClient,
     /// Application configuration loaded from the config file
     config: AppConfig,
+    /// Repositories to process, as `owner/repo`; empty to process every repository
+    repositories: Vec<String>,
+    /// Outcomes of the processed pull requests, keyed by repository full name
+    summaries: Arc<Mutex<BTreeMap<String, RepositorySummary>>>,
+    /// When true, pull requests are not changed; the planned changes are printed instead
+    dry_run: bool,
+    /// State kept between the events of a pull request, shared by every `MergeWarden`
+    state_store: Arc<dyn StateStore>,
 }
 
 #[async_trait]
@@ -101,13 +115,23 @@ impl WebhookHandler for MergeWardenWebhookHandler {
             return Ok(());
         }
 
-        let action = envelope.payload.raw()["action"].as_str().unwrap_or("");
-        match action {
-            "opened" | "edited" | "ready_for_review" | "reopened" | "unlocked" | "synchronize" => {}
-            _ => {
-                info!(action, "Pull request action does not require processing");
-                return Ok(());
-            }
+        let action =
+            PullRequestAction::from(envelope.payload.raw()["action"].as_str().unwrap_or(""));
+        if !action.requires_processing() {
+            info!(
+                action = action.as_str(),
+                "Pull request action does not require processing"
+            );
+            return Ok(());
+        }
+
+        let full_name = envelope.repository.full_name.as_str();
+        if !is_repository_selected(&self.repositories, full_name) {
+            info!(
+                repository = full_name,
+                "Repository is not in the selected repositories; ignoring event"
+            );
+            return Ok(());
         }
 
         let pr_number = envelope
@@ -150,7 +174,7 @@ impl WebhookHandler for MergeWardenWebhookHandler {
             repository_owner = repo_owner.as_str(),
             repository = repo_name.as_str(),
             pull_request = pr_number,
-            action,
+            action = action.as_str(),
             "Processing pull request"
         );
 
@@ -170,46 +194,15 @@ impl WebhookHandler for MergeWardenWebhookHandler {
             })?;
 
         let provider = GitHubProvider::new(installation_client);
-        // Clone before moving — cheap Arc-backed clone of InstallationClient.
-        let issue_provider = provider.clone();
-
-        let merge_warden_config_path = ".github/merge-warden.toml";
-        let validation_config = match resolve_pull_request_config(
+        let warden = create_merge_warden(
+            provider,
             repo_owner,
             repo_name,
-            merge_warden_config_path,
-            &provider,
-            &self.config.policies,
-            Some(&provider),
+            &self.config,
+            self.state_store.clone(),
         )
         .await
-        {
-            Ok(merge_warden_config) => {
-                info!(
-                    "Loaded merge-warden config from {}",
-                    merge_warden_config_path
-                );
-                merge_warden_config
-            }
-            Err(ConfigLoadError::OrgPolicyUnavailable(ref msg)) => {
-                error!(
-                    merge_warden_config_path,
-                    org_policy_error = msg.as_str(),
-                    "Org policy unreachable and fail_if_unreachable = true; aborting PR check"
-                );
-                return Err(Box::from(format!("Org policy unavailable: {}", msg)));
-            }
-            Err(e) => {
-                warn!(
-                    "Failed to resolve PR config from {}: {}. Falling back to compiled-in defaults.",
-                    merge_warden_config_path, e
-                );
-                CurrentPullRequestValidationConfiguration::from_app_defaults(&self.config.policies)
-            }
-        };
-
-        let warden = MergeWarden::with_config(provider, validation_config)
-            .with_issue_provider(Box::new(issue_provider));
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
 
         info!(
             message = "Processing pull request",
@@ -217,24 +210,169 @@ impl WebhookHandler for MergeWardenWebhookHandler {
             repository = repo_name.as_str()
         );
 
-        warden
-            .process_pull_request(repo_owner, repo_name, pr_number.into())
-            .await
-            .map_err(|e| {
-                error!(
-                    repository_owner = repo_owner.as_str(),
-                    repository = repo_name.as_str(),
-                    pull_request = pr_number,
-                    error = e.to_string(),
-                    "Failed to process pull request"
+        let result = if self.dry_run {
+            let result = if action == PullRequestAction::Reopened {
+                warden
+                    .process_reopened_pull_request_dry_run(repo_owner, repo_name, pr_number.into())
+                    .await
+            } else {
+                warden
+                    .process_pull_request_dry_run(repo_owner, repo_name, pr_number.into())
+                    .await
+            };
+            if let Ok(result) = &result {
+                println!(
+                    "{}",
+                    format_planned_operations(
+                        full_name,
+                        pr_number.into(),
+                        &result.planned_operations
+                    )
                 );
-                Box::new(e) as Box<dyn std::error::Error + Send + Sync>
-            })?;
+            }
+            result
+        } else if action == PullRequestAction::Reopened {
+            warden
+                .process_reopened_pull_request(repo_owner, repo_name, pr_number.into())
+                .await
+        } else {
+            warden
+                .process_pull_request(repo_owner, repo_name, pr_number.into())
+                .await
+        };
+        if let Ok(mut summaries) = self.summaries.lock() {
+            summaries
+                .entry(full_name.to_string())
+                .or_default()
+                .record(result.as_ref().ok());
+        }
+
+        result.map_err(|e| {
+            error!(
+                repository_owner = repo_owner.as_str(),
+                repository = repo_name.as_str(),
+                pull_request = pr_number,
+                error = e.to_string(),
+                "Failed to process pull request"
+            );
+            Box::new(e) as Box<dyn std::error::Error + Send + Sync>
+        })?;
 
         Ok(())
     }
 }
 
+/// Formats the changes a dry run planned for a pull request, one operation per line.
+///
+/// # Arguments
+///
+/// * `full_name` - The repository, as `owner/repo`
+/// * `pr_number` - The pull request number
+/// * `operations` - The planned operations, in the order they would have been applied
+fn format_planned_operations(
+    full_name: &str,
+    pr_number: u64,
+    operations: &[PlannedOperation],
+) -> String {
+    if operations.is_empty() {
+        return format!("Dry run for {full_name}#{pr_number}: no changes planned");
+    }
+
+    let mut lines = vec![format!(
+        "Dry run for {full_name}#{pr_number}: {} changes planned",
+        operations.len()
+    )];
+    for operation in operations {
+        for (index, line) in operation.to_string().lines().enumerate() {
+            if index == 0 {
+                lines.push(format!("  - {line}"));
+            } else {
+                lines.push(format!("    {line}"));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+/// Creates a `MergeWarden` for a repository, using the repository's configuration.
+///
+/// The repository's `.github/merge-warden.toml` is merged with the policies in the
+/// CLI configuration file. When the repository configuration cannot be loaded, the
+/// CLI policies are used on their own.
+///
+/// A `MergeWarden` is created for every event or poll, so the state it keeps between
+/// runs, such as the previous check conclusion and whether the pull request was
+/// already converted to a draft, lives in `state_store`. Pass the same store for
+/// every run of a command.
+///
+/// # Arguments
+///
+/// * `provider` - A GitHub provider scoped to the installation that covers the repository
+/// * `repo_owner` - The owner of the repository
+/// * `repo_name` - The name of the repository
+/// * `config` - The CLI configuration
+/// * `state_store` - The state store shared by the runs of the command
+///
+/// # Errors
+///
+/// Returns `CliError::ConfigError` if the organization policy cannot be reached and
+/// `fail_if_unreachable` is set.
+pub(crate) async fn create_merge_warden(
+    provider: GitHubProvider,
+    repo_owner: &str,
+    repo_name: &str,
+    config: &AppConfig,
+    state_store: Arc<dyn StateStore>,
+) -> Result<MergeWarden<GitHubProvider>, CliError> {
+    // Clone before moving — cheap Arc-backed clone of InstallationClient.
+    let issue_provider = provider.clone();
+
+    let merge_warden_config_path = ".github/merge-warden.toml";
+    let validation_config = match resolve_pull_request_config(
+        repo_owner,
+        repo_name,
+        merge_warden_config_path,
+        &provider,
+        &config.policies,
+        Some(&provider),
+    )
+    .await
+    {
+        Ok(merge_warden_config) => {
+            info!(
+                "Loaded merge-warden config from {}",
+                merge_warden_config_path
+            );
+            merge_warden_config
+        }
+        Err(ConfigLoadError::OrgPolicyUnavailable(ref msg)) => {
+            error!(
+                merge_warden_config_path,
+                org_policy_error = msg.as_str(),
+                "Org policy unreachable and fail_if_unreachable = true; aborting PR check"
+            );
+            return Err(CliError::ConfigError(format!(
+                "Org policy unavailable: {}",
+                msg
+            )));
+        }
+        Err(e) => {
+            warn!(
+                "Failed to resolve PR config from {}: {}. Falling back to compiled-in defaults.",
+                merge_warden_config_path, e
+            );
+            let mut fallback =
+                CurrentPullRequestValidationConfiguration::from_app_defaults(&config.policies);
+            fallback.apply_feature_flags(config.policies.feature_flags.flags_for(repo_owner));
+            fallback
+        }
+    };
+
+    Ok(MergeWarden::with_config(provider, validation_config)
+        .with_issue_provider(Box::new(issue_provider))
+        .with_state_store(state_store))
+}
+
 /// Arguments for the check-pr command
 #[derive(Args, Debug)]
 pub struct CheckPrArgs {
@@ -245,6 +383,19 @@ pub struct CheckPrArgs {
     /// Alternate config file
     #[arg(short, long)]
     pub config: Option<String>,
+
+    /// Only process these repositories, as a comma-separated list of owner/repo
+    #[arg(short, long, value_delimiter = ',')]
+    pub repos: Vec<String>,
+
+    /// Only process the repositories listed in this file, one owner/repo per line
+    #[arg(long)]
+    pub repos_file: Option<String>,
+
+    /// Print the labels, comments and check runs each pull request would get, without
+    /// changing the pull requests
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 /// Creates a GitHub application client based on the provided configuration.
@@ -294,7 +445,7 @@ pub struct CheckPrArgs {
 ///     Ok(())
 /// }
 /// ```
-async fn create_github_app(config: &AppConfig) -> Result<GitHubClient, CliError> {
+pub(crate) async fn create_github_app(config: &AppConfig) -> Result<GitHubClient, CliError> {
     debug!("Creating GitHub app client");
     match config.authentication.auth_method.as_str() {
         "token" => {
@@ -386,7 +537,11 @@ async fn create_github_app(config: &AppConfig) -> Result<GitHubClient, CliError>
 /// - Setting up an HTTP server to listen for webhook events from GitHub.
 ///
 /// The function listens for incoming webhook events and processes them to validate pull requests
-/// based on the specified configuration.
+/// based on the specified configuration. When repositories are selected with `--repos` or
+/// `--repos-file`, events for other repositories are ignored. With `--dry-run`, pull
+/// requests are validated without being changed and the planned changes are printed.
+/// When the server is stopped with Ctrl+C, it prints how many pull requests passed or
+/// failed in each repository.
 ///
 /// # Arguments
 ///
@@ -402,6 +557,8 @@ async fn create_github_app(config: &AppConfig) -> Result<GitHubClient, CliError>
 ///
 /// This function will return a `CliError` in the following cases:
 /// - If the configuration file cannot be loaded.
+/// - If a selected repository is not of the form `owner/repo`, or the repository file
+///   cannot be read.
 /// - If the GitHub client cannot be initialized due to authentication issues.
 /// - If the webhook secret cannot be retrieved.
 /// - If the HTTP server fails to start.
@@ -416,6 +573,13 @@ pub async fn execute(args: CheckPrArgs) -> Result<(), CliError> {
     let config_path = get_config_path(args.config.as_deref());
     let config = AppConfig::load(&config_path)
         .map_err(|e| CliError::ConfigError(format!("Failed to load configuration: {}", e)))?;
+    let repositories = resolve_repositories(&args.repos, args.repos_file.as_deref())?;
+    if !repositories.is_empty() {
+        info!(repositories = ?repositories, "Processing only the selected repositories");
+    }
+    if args.dry_run {
+        info!("Dry run: pull requests are validated but not changed");
+    }
 
     let github_client = create_github_app(&config).await?;
     let webhook_secret = retrieve_webhook_secret()?;
@@ -434,9 +598,14 @@ pub async fn execute(args: CheckPrArgs) -> Result<(), CliError> {
     let processor_config = ProcessorConfig::default();
     let bypass_processor = EventProcessor::new(processor_config.clone());
     let receiver_processor = EventProcessor::new(processor_config);
+    let summaries = Arc::new(Mutex::new(BTreeMap::new()));
     let handler: Arc<dyn WebhookHandler> = Arc::new(MergeWardenWebhookHandler {
         github_client,
         config,
+        repositories: repositories.clone(),
+        summaries: summaries.clone(),
+        dry_run: args.dry_run,
+        state_store: Arc::new(InMemoryStateStore::new()),
     });
     let mut receiver = WebhookReceiver::new(secret_provider, receiver_processor);
     receiver.add_handler(handler.clone()).await;
@@ -454,7 +623,16 @@ pub async fn execute(args: CheckPrArgs) -> Result<(), CliError> {
     let listener = tokio::net::TcpListener::bind(addr.clone()).await.unwrap();
 
     info!("Listening on {}", addr);
-    axum::serve(listener, app).await.unwrap();
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await
+        .unwrap();
+
+    if let Ok(summaries) = summaries.lock() {
+        println!("{}", format_repository_summaries(&repositories, &summaries));
+    }
 
     Ok(())
 }
@@ -1,5 +1,8 @@
+use std::path::Path;
+
 use anyhow::Result;
 use clap::Subcommand;
+use merge_warden_core::config::{lint_config_content, CONFIG_FILE_PATH};
 use tracing::{debug, error, info, instrument};
 
 use crate::config::{get_config_path, AppConfig};
@@ -21,14 +24,26 @@ pub enum ConfigCommands {
         #[arg(short, long)]
         path: Option<String>,
     },
+
+    /// Check a repository's merge-warden.toml without contacting GitHub
+    Lint {
+        /// Path to the repository configuration file [default: .github/merge-warden.toml]
+        #[arg(short, long)]
+        file: Option<String>,
+    },
 }
 
+#[cfg(test)]
+#[path = "config_cmd_tests.rs"]
+mod tests;
+
 /// Execute the config command
 #[instrument]
 pub async fn execute(cmd: ConfigCommands) -> Result<(), CliError> {
     match cmd {
         ConfigCommands::Init { path } => init_config(path.as_deref()),
         ConfigCommands::Validate { path } => validate_config(path.as_deref()),
+        ConfigCommands::Lint { file } => lint_config(file.as_deref().unwrap_or(CONFIG_FILE_PATH)),
     }
 }
 
@@ -88,3 +103,37 @@ fn validate_config(path: Option<&str>) -> Result<(), CliError> {
         }
     }
 }
+
+/// Lint a repository configuration file, reporting every problem found
+#[instrument]
+fn lint_config(file: &str) -> Result<(), CliError> {
+    let path = Path::new(file);
+    debug!(message = "Linting repository configuration", path = ?path);
+
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        error!(message = "Failed to read configuration", path = ?path, error = ?e);
+        CliError::ConfigError(format!("Failed to read {}: {}", path.display(), e))
+    })?;
+
+    let problems = lint_config_content(&content).errors;
+    if problems.is_empty() {
+        info!(message = "Repository configuration is valid", path = ?path);
+        println!("{} is valid", path.display());
+        return Ok(());
+    }
+
+    error!(
+        message = "Repository configuration is invalid",
+        path = ?path,
+        problems = problems.len()
+    );
+    println!("{} has {} problem(s):", path.display(), problems.len());
+    for problem in &problems {
+        println!("  - {}", problem.replace('\n', "\n    "));
+    }
+    Err(CliError::ConfigError(format!(
+        "{} has {} problem(s)",
+        path.display(),
+        problems.len()
+    )))
+}
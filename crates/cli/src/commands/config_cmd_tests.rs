@@ -0,0 +1,45 @@
+use super::*;
+use std::io::Write;
+
+fn write_config(content: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn test_lint_config_accepts_a_valid_file() {
+    let file = write_config("schemaVersion = 1\n");
+
+    assert!(lint_config(file.path().to_str().unwrap()).is_ok());
+}
+
+#[test]
+fn test_lint_config_reports_the_number_of_problems() {
+    let file = write_config(
+        r#"
+schemaVersion = 1
+
+[policies.pullRequests.prTitle]
+pattern = "^(feat|fix"
+
+[policies.pullRequests.prSize]
+excluded_file_patterns = ["src/{a,b.rs"]
+"#,
+    );
+
+    let error = lint_config(file.path().to_str().unwrap()).unwrap_err();
+
+    assert!(matches!(error, CliError::ConfigError(_)));
+    assert!(error.to_string().contains("2 problem(s)"));
+}
+
+#[test]
+fn test_lint_config_fails_for_a_missing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("merge-warden.toml");
+
+    let error = lint_config(path.to_str().unwrap()).unwrap_err();
+
+    assert!(error.to_string().contains("Failed to read"));
+}
@@ -0,0 +1,122 @@
+use clap::Args;
+use merge_warden_core::config::CurrentPullRequestValidationConfiguration;
+use merge_warden_core::explain::{explain, Explanation};
+use merge_warden_core::templates::TemplateEngineRenderer;
+use tracing::{debug, instrument};
+
+use crate::config::{get_config_path, AppConfig};
+use crate::errors::CliError;
+
+#[cfg(test)]
+#[path = "explain_tests.rs"]
+mod tests;
+
+/// Arguments for the explain command
+#[derive(Args, Debug)]
+pub struct ExplainArgs {
+    /// The pull request title to check
+    #[arg(short, long)]
+    pub title: String,
+
+    /// The pull request description to check
+    #[arg(short, long)]
+    pub body: Option<String>,
+
+    /// Alternate config file
+    #[arg(short, long)]
+    pub config: Option<String>,
+}
+
+/// Executes the `explain` command.
+///
+/// Runs the title, work item and WIP checks against the given title and body using
+/// the policies in the configuration file, and prints the outcome of each check
+/// together with the guidance Merge Warden would post on the pull request. No
+/// network calls are made; the work item check only matches the configured patterns.
+///
+/// When no config file is given and none exists at the default location, the
+/// built-in policy defaults are used.
+///
+/// # Arguments
+///
+/// * `args` - The title, body and optional config file path
+///
+/// # Errors
+///
+/// Returns `CliError::ConfigError` if the configuration file cannot be loaded, and
+/// `CliError::ValidationFailed` if any enabled check fails.
+#[instrument]
+pub async fn execute(args: ExplainArgs) -> Result<(), CliError> {
+    let config_path = get_config_path(args.config.as_deref());
+    let config = if args.config.is_none() && !config_path.exists() {
+        debug!(path = ?config_path, "No configuration file found; using the default policies");
+        AppConfig::default()
+    } else {
+        AppConfig::load(&config_path)
+            .map_err(|e| CliError::ConfigError(format!("Failed to load configuration: {}", e)))?
+    };
+
+    let validation_config =
+        CurrentPullRequestValidationConfiguration::from_app_defaults(&config.policies);
+    let explanation = explain(
+        &args.title,
+        args.body.as_deref(),
+        &validation_config,
+        &TemplateEngineRenderer::new(),
+    );
+
+    println!("{}", format_explanation(&explanation));
+
+    if explanation.is_passing() {
+        Ok(())
+    } else {
+        Err(CliError::ValidationFailed(
+            "The title or body does not pass the configured checks".to_string(),
+        ))
+    }
+}
+
+/// Formats an explanation as one line per check, each followed by its guidance
+/// when the check fails.
+///
+/// # Arguments
+///
+/// * `explanation` - The outcome of the checks
+fn format_explanation(explanation: &Explanation) -> String {
+    let mut lines = Vec::new();
+
+    let title_status = match &explanation.title {
+        None => "skipped (title validation is not enabled)",
+        Some(result) if result.is_valid() => "passed",
+        Some(_) => "failed",
+    };
+    lines.push(format!("PR title: {title_status}"));
+    if let Some(guidance) = &explanation.title_guidance {
+        lines.push(guidance.trim().to_string());
+    }
+
+    let work_item_status = match &explanation.work_item {
+        None => "skipped (work item validation is not enabled)".to_string(),
+        Some(result) if result.is_valid() => match &result.matched_pattern {
+            Some(pattern) => format!("passed (matched `{pattern}`)"),
+            None => "passed".to_string(),
+        },
+        Some(_) => "failed".to_string(),
+    };
+    lines.push(format!("Work item: {work_item_status}"));
+    if let Some(guidance) = &explanation.work_item_guidance {
+        lines.push(guidance.trim().to_string());
+    }
+
+    let wip_status = match explanation.wip_detected {
+        None => "skipped (WIP blocking is not enabled)",
+        Some(true) => "failed (marked as work in progress)",
+        Some(false) => "passed",
+    };
+    lines.push(format!("WIP: {wip_status}"));
+    if let Some(guidance) = &explanation.wip_guidance {
+        lines.push(guidance.trim().to_string());
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn test_format_explanation_lists_each_check() {
+    let config = CurrentPullRequestValidationConfiguration::default();
+    let explanation = explain(
+        "feat: add login",
+        Some("Fixes #42"),
+        &config,
+        &TemplateEngineRenderer::new(),
+    );
+
+    let output = format_explanation(&explanation);
+
+    assert!(output.starts_with("PR title: passed\n"));
+    assert!(output.contains("Work item: passed (matched `"));
+    assert!(output.ends_with("WIP: skipped (WIP blocking is not enabled)"));
+}
+
+#[test]
+fn test_format_explanation_includes_guidance_for_failures() {
+    let config = CurrentPullRequestValidationConfiguration::default();
+    let explanation = explain("add login", None, &config, &TemplateEngineRenderer::new());
+
+    let output = format_explanation(&explanation);
+
+    assert!(output.contains("PR title: failed\nThe pull request title needs correction"));
+    assert!(output.contains("Work item: failed\nThe pull request body needs improvement"));
+}
@@ -0,0 +1,430 @@
+use chrono::{DateTime, Utc};
+use clap::Args;
+use futures::stream::{self, StreamExt};
+use github_bot_sdk::{
+    auth::InstallationId,
+    client::{GitHubClient, InstallationClient},
+};
+use merge_warden_core::state::{InMemoryStateStore, StateStore};
+use merge_warden_developer_platforms::github::GitHubProvider;
+use merge_warden_developer_platforms::models::PullRequestSummary;
+use merge_warden_developer_platforms::PullRequestProvider;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, instrument, warn};
+
+use crate::commands::bypass_report::{format_bypass_report, BypassReportFormat, BypassStatistics};
+use crate::commands::check_pr::{create_github_app, create_merge_warden};
+use crate::commands::repositories::{
+    format_repository_summaries, resolve_repositories, RepositorySummary,
+};
+use crate::config::{get_config_path, AppConfig, PollingConfig};
+use crate::errors::CliError;
+
+#[cfg(test)]
+#[path = "poll_tests.rs"]
+mod tests;
+
+/// Number of pull requests of one repository processed at the same time.
+const MAX_CONCURRENT_PULL_REQUESTS: usize = 4;
+
+/// Arguments for the poll command
+#[derive(Args, Debug)]
+pub struct PollArgs {
+    /// Alternate config file
+    #[arg(short, long)]
+    pub config: Option<String>,
+
+    /// Poll these repositories, as a comma-separated list of owner/repo
+    #[arg(short, long, value_delimiter = ',')]
+    pub repos: Vec<String>,
+
+    /// Poll the repositories listed in this file, one owner/repo per line
+    #[arg(long)]
+    pub repos_file: Option<String>,
+
+    /// Seconds between two polls; overrides `polling.interval_seconds`
+    #[arg(short, long)]
+    pub interval: Option<u64>,
+
+    /// Poll once and exit
+    #[arg(long)]
+    pub once: bool,
+
+    /// Print the bypasses used, grouped by user and rule type, when polling stops
+    #[arg(long, value_enum)]
+    pub bypass_report: Option<BypassReportFormat>,
+}
+
+/// Tracks which pull requests of each repository have already been processed.
+///
+/// For each repository it keeps the latest update time of the pull requests it has
+/// seen, so that the next poll only lists pull requests updated at or after it. Update
+/// times have a precision of one second, so a pull request can be updated in the same
+/// second as the latest one seen but after it was listed. The tracker therefore also
+/// remembers which pull requests it returned at that time, and returns any other pull
+/// request updated then. Pull requests that failed to process are kept and returned
+/// again by the next poll.
+#[derive(Debug, Default)]
+pub struct PollTracker {
+    /// The latest update time seen per repository, keyed by `owner/repo` in lower case
+    last_seen: HashMap<String, SeenBoundary>,
+
+    /// Pull requests to retry per repository, keyed by `owner/repo` in lower case
+    retries: HashMap<String, BTreeSet<u64>>,
+}
+
+/// The latest update time seen in a repository and the pull requests returned with it.
+#[derive(Debug)]
+struct SeenBoundary {
+    /// The latest update time of the pull requests seen
+    updated_at: DateTime<Utc>,
+
+    /// The pull requests returned that were updated at `updated_at`
+    pull_requests: BTreeSet<u64>,
+}
+
+impl PollTracker {
+    /// Returns the latest update time seen in `repository`, or `None` before its
+    /// first poll.
+    pub fn since(&self, repository: &str) -> Option<DateTime<Utc>> {
+        self.last_seen
+            .get(&repository.to_lowercase())
+            .map(|seen| seen.updated_at)
+    }
+
+    /// Returns the pull requests of `repository` to process and marks them as seen.
+    ///
+    /// These are the listed pull requests updated after the latest update time seen
+    /// so far, or at that time but not yet returned, followed by the pull requests
+    /// that failed in an earlier poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - The repository, as `owner/repo`
+    /// * `listed` - The open pull requests listed by the provider
+    pub fn pull_requests_to_process(
+        &mut self,
+        repository: &str,
+        listed: &[PullRequestSummary],
+    ) -> Vec<u64> {
+        let key = repository.to_lowercase();
+        let seen = self.last_seen.remove(&key);
+
+        let is_new = |pr: &PullRequestSummary| match &seen {
+            None => true,
+            Some(seen) => {
+                pr.updated_at > seen.updated_at
+                    || (pr.updated_at == seen.updated_at
+                        && !seen.pull_requests.contains(&pr.number))
+            }
+        };
+        let new: Vec<&PullRequestSummary> = listed.iter().filter(|pr| is_new(pr)).collect();
+
+        let mut numbers: Vec<u64> = new.iter().map(|pr| pr.number).collect();
+        for number in self.retries.remove(&key).unwrap_or_default() {
+            if !numbers.contains(&number) {
+                numbers.push(number);
+            }
+        }
+
+        let latest = new
+            .iter()
+            .map(|pr| pr.updated_at)
+            .chain(seen.as_ref().map(|seen| seen.updated_at))
+            .max();
+        if let Some(latest) = latest {
+            let mut pull_requests = match seen {
+                Some(seen) if seen.updated_at == latest => seen.pull_requests,
+                _ => BTreeSet::new(),
+            };
+            pull_requests.extend(
+                new.iter()
+                    .filter(|pr| pr.updated_at == latest)
+                    .map(|pr| pr.number),
+            );
+            self.last_seen.insert(
+                key,
+                SeenBoundary {
+                    updated_at: latest,
+                    pull_requests,
+                },
+            );
+        }
+
+        numbers
+    }
+
+    /// Records that a pull request failed to process, so that the next poll retries it.
+    pub fn record_failure(&mut self, repository: &str, pr_number: u64) {
+        self.retries
+            .entry(repository.to_lowercase())
+            .or_default()
+            .insert(pr_number);
+    }
+}
+
+/// Returns the time between two polls.
+///
+/// The `--interval` argument takes precedence over `polling.interval_seconds`.
+///
+/// # Errors
+///
+/// Returns `CliError::InvalidArguments` if the interval is zero.
+pub fn resolve_interval(
+    interval: Option<u64>,
+    config: &PollingConfig,
+) -> Result<Duration, CliError> {
+    match interval.unwrap_or(config.interval_seconds) {
+        0 => Err(CliError::InvalidArguments(
+            "The poll interval must be at least one second".to_string(),
+        )),
+        seconds => Ok(Duration::from_secs(seconds)),
+    }
+}
+
+/// Creates a client for the installation of the GitHub App that covers a repository.
+///
+/// # Errors
+///
+/// Returns `CliError::AuthError` if the app is not installed on the repository or
+/// the installation cannot be looked up.
+async fn installation_client(
+    github_client: &GitHubClient,
+    repo_owner: &str,
+    repo_name: &str,
+) -> Result<InstallationClient, CliError> {
+    let response = github_client
+        .get_as_app(&format!("/repos/{}/{}/installation", repo_owner, repo_name))
+        .await
+        .map_err(|e| {
+            CliError::AuthError(format!(
+                "Failed to look up the installation for {}/{}: {}",
+                repo_owner, repo_name, e
+            ))
+        })?;
+    if !response.status().is_success() {
+        return Err(CliError::AuthError(format!(
+            "The GitHub App is not installed on {}/{} (status {})",
+            repo_owner,
+            repo_name,
+            response.status()
+        )));
+    }
+
+    let installation: serde_json::Value = response.json().await.map_err(|e| {
+        CliError::AuthError(format!(
+            "Failed to read the installation for {}/{}: {}",
+            repo_owner, repo_name, e
+        ))
+    })?;
+    let installation_id = installation["id"].as_u64().ok_or_else(|| {
+        CliError::AuthError(format!(
+            "The installation for {}/{} has no ID",
+            repo_owner, repo_name
+        ))
+    })?;
+
+    github_client
+        .installation_by_id(InstallationId::new(installation_id))
+        .await
+        .map_err(|e| {
+            CliError::AuthError(format!(
+                "Failed to create the installation client for {}/{}: {}",
+                repo_owner, repo_name, e
+            ))
+        })
+}
+
+/// Lists the pull requests of a repository updated since the last poll and processes
+/// them.
+///
+/// Failures are logged and, for pull requests, retried on the next poll; they never
+/// stop the polling. `state_store` keeps the state of each pull request from one poll
+/// to the next.
+async fn poll_repository(
+    github_client: &GitHubClient,
+    config: &AppConfig,
+    state_store: &Arc<dyn StateStore>,
+    repository: &str,
+    tracker: &mut PollTracker,
+    summaries: &mut BTreeMap<String, RepositorySummary>,
+    bypasses: &mut BypassStatistics,
+) {
+    let Some((repo_owner, repo_name)) = repository.split_once('/') else {
+        return;
+    };
+
+    let provider = match installation_client(github_client, repo_owner, repo_name).await {
+        Ok(client) => GitHubProvider::new(client),
+        Err(e) => {
+            warn!(
+                repository,
+                error = e.to_string(),
+                "Failed to poll repository"
+            );
+            return;
+        }
+    };
+
+    let listed = match provider
+        .list_pull_requests(repo_owner, repo_name, tracker.since(repository))
+        .await
+    {
+        Ok(listed) => listed,
+        Err(e) => {
+            warn!(
+                repository,
+                error = e.to_string(),
+                "Failed to list pull requests"
+            );
+            return;
+        }
+    };
+
+    let pr_numbers = tracker.pull_requests_to_process(repository, &listed);
+    if pr_numbers.is_empty() {
+        debug!(repository, "No pull requests updated since the last poll");
+        return;
+    }
+
+    let warden =
+        match create_merge_warden(provider, repo_owner, repo_name, config, state_store.clone())
+            .await
+        {
+            Ok(warden) => warden,
+            Err(e) => {
+                warn!(
+                    repository,
+                    error = e.to_string(),
+                    "Failed to load the repository configuration"
+                );
+                for pr_number in pr_numbers {
+                    tracker.record_failure(repository, pr_number);
+                }
+                return;
+            }
+        };
+
+    info!(
+        repository,
+        count = pr_numbers.len(),
+        "Processing pull requests updated since the last poll"
+    );
+    let targets = stream::iter(
+        pr_numbers
+            .into_iter()
+            .map(|pr_number| (repo_owner, repo_name, pr_number)),
+    );
+    let mut results =
+        std::pin::pin!(warden.process_pull_requests(targets, MAX_CONCURRENT_PULL_REQUESTS));
+    while let Some(item) = results.next().await {
+        match &item.result {
+            Ok(result) => bypasses.record(repository, item.target.pr_number, result),
+            Err(_) => tracker.record_failure(repository, item.target.pr_number),
+        }
+        summaries
+            .entry(repository.to_string())
+            .or_default()
+            .record(item.result.as_ref().ok());
+    }
+}
+
+/// Executes the `poll` command.
+///
+/// Polls the selected repositories for open pull requests and processes each one that
+/// was updated since the previous poll, for environments that cannot receive
+/// webhooks. The first poll processes every open pull request. A pull request that
+/// Merge Warden itself updates, for example by adding a label, is processed once more
+/// in the next poll, which then finds nothing to change.
+///
+/// The repositories are taken from `--repos` and `--repos-file`, or from
+/// `polling.repositories` in the configuration file when neither is given. Polling
+/// continues until Ctrl+C is pressed, which also interrupts a poll in progress, or
+/// stops after the first poll with `--once`, and then prints how many pull requests passed or failed in each repository. With
+/// `--bypass-report` it also prints the bypasses used on the processed pull requests,
+/// grouped by user and rule type.
+///
+/// # Arguments
+///
+/// * `args` - The repositories, the poll interval and the optional config file path
+///
+/// # Errors
+///
+/// Returns `CliError::ConfigError` if the configuration file cannot be loaded,
+/// `CliError::InvalidArguments` if no repositories are selected, a repository is not
+/// of the form `owner/repo` or the interval is zero, and `CliError::AuthError` if the
+/// GitHub client cannot be created, and `CliError::Other` if the bypass report cannot
+/// be serialized.
+#[instrument]
+pub async fn execute(args: PollArgs) -> Result<(), CliError> {
+    let config_path = get_config_path(args.config.as_deref());
+    let config = AppConfig::load(&config_path)
+        .map_err(|e| CliError::ConfigError(format!("Failed to load configuration: {}", e)))?;
+
+    let mut repositories = resolve_repositories(&args.repos, args.repos_file.as_deref())?;
+    if repositories.is_empty() {
+        repositories = resolve_repositories(&config.polling.repositories, None)?;
+    }
+    if repositories.is_empty() {
+        return Err(CliError::InvalidArguments(
+            "No repositories to poll. Pass --repos or --repos-file, or set polling.repositories"
+                .to_string(),
+        ));
+    }
+    let interval = resolve_interval(args.interval, &config.polling)?;
+
+    let github_client = create_github_app(&config).await?;
+
+    info!(
+        repositories = ?repositories,
+        interval_seconds = interval.as_secs(),
+        "Polling repositories for pull requests"
+    );
+    let state_store: Arc<dyn StateStore> = Arc::new(InMemoryStateStore::new());
+    let mut tracker = PollTracker::default();
+    let mut summaries = BTreeMap::new();
+    let mut bypasses = BypassStatistics::default();
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // One future for the whole run, so a Ctrl+C pressed while a repository is polled
+    // is not lost before the next wait.
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    'poll: loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = &mut ctrl_c => break,
+        }
+
+        for repository in &repositories {
+            tokio::select! {
+                _ = poll_repository(
+                    &github_client,
+                    &config,
+                    &state_store,
+                    repository,
+                    &mut tracker,
+                    &mut summaries,
+                    &mut bypasses,
+                ) => {}
+                _ = &mut ctrl_c => {
+                    info!(repository, "Interrupted while polling; stopping");
+                    break 'poll;
+                }
+            }
+        }
+
+        if args.once {
+            break;
+        }
+    }
+
+    println!("{}", format_repository_summaries(&repositories, &summaries));
+    if let Some(format) = args.bypass_report {
+        println!("{}", format_bypass_report(&bypasses, format)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use chrono::TimeZone;
+
+use super::*;
+
+fn at(hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, hour, 0, 0).unwrap()
+}
+
+fn summary(number: u64, hour: u32) -> PullRequestSummary {
+    PullRequestSummary {
+        number,
+        updated_at: at(hour),
+    }
+}
+
+#[test]
+fn test_tracker_processes_every_pull_request_on_the_first_poll() {
+    let mut tracker = PollTracker::default();
+    assert_eq!(tracker.since("owner/repo"), None);
+
+    let numbers = tracker.pull_requests_to_process("owner/repo", &[summary(2, 9), summary(1, 8)]);
+
+    assert_eq!(numbers, vec![2, 1]);
+    assert_eq!(tracker.since("owner/repo"), Some(at(9)));
+}
+
+#[test]
+fn test_tracker_skips_pull_requests_not_updated_since_the_last_poll() {
+    let mut tracker = PollTracker::default();
+    tracker.pull_requests_to_process("owner/repo", &[summary(2, 9), summary(1, 8)]);
+
+    // The provider may return the pull request updated at exactly the last-seen time
+    let numbers = tracker.pull_requests_to_process("Owner/Repo", &[summary(1, 10), summary(2, 9)]);
+
+    assert_eq!(numbers, vec![1]);
+    assert_eq!(tracker.since("owner/repo"), Some(at(10)));
+    assert!(tracker
+        .pull_requests_to_process("owner/repo", &[])
+        .is_empty());
+    assert_eq!(tracker.since("owner/repo"), Some(at(10)));
+}
+
+#[test]
+fn test_tracker_processes_pull_requests_updated_in_the_same_second_as_the_last_poll() {
+    let mut tracker = PollTracker::default();
+    tracker.pull_requests_to_process("owner/repo", &[summary(2, 9), summary(1, 8)]);
+
+    // Pull request 3 was updated at the same time as 2, but after 2 was listed
+    let numbers = tracker.pull_requests_to_process("owner/repo", &[summary(3, 9), summary(2, 9)]);
+
+    assert_eq!(numbers, vec![3]);
+    assert_eq!(tracker.since("owner/repo"), Some(at(9)));
+    assert!(tracker
+        .pull_requests_to_process("owner/repo", &[summary(3, 9), summary(2, 9)])
+        .is_empty());
+}
+
+#[test]
+fn test_tracker_retries_failed_pull_requests_once() {
+    let mut tracker = PollTracker::default();
+    tracker.pull_requests_to_process("owner/repo", &[summary(1, 8)]);
+    tracker.record_failure("owner/repo", 1);
+    tracker.record_failure("owner/repo", 3);
+
+    let numbers = tracker.pull_requests_to_process("owner/repo", &[summary(3, 9)]);
+
+    assert_eq!(numbers, vec![3, 1]);
+    assert!(tracker
+        .pull_requests_to_process("owner/repo", &[])
+        .is_empty());
+}
+
+#[test]
+fn test_tracker_keeps_repositories_apart() {
+    let mut tracker = PollTracker::default();
+    tracker.pull_requests_to_process("owner/repo", &[summary(1, 8)]);
+    tracker.record_failure("owner/repo", 1);
+
+    let numbers = tracker.pull_requests_to_process("owner/other", &[summary(1, 7)]);
+
+    assert_eq!(numbers, vec![1]);
+    assert_eq!(tracker.since("owner/other"), Some(at(7)));
+    assert_eq!(tracker.since("owner/repo"), Some(at(8)));
+}
+
+#[test]
+fn test_resolve_interval_prefers_the_argument() {
+    let config = PollingConfig {
+        interval_seconds: 60,
+        repositories: Vec::new(),
+    };
+
+    assert_eq!(
+        resolve_interval(None, &config).unwrap(),
+        Duration::from_secs(60)
+    );
+    assert_eq!(
+        resolve_interval(Some(15), &config).unwrap(),
+        Duration::from_secs(15)
+    );
+    assert!(matches!(
+        resolve_interval(Some(0), &config),
+        Err(CliError::InvalidArguments(_))
+    ));
+}
@@ -0,0 +1,195 @@
+use merge_warden_core::CheckResult;
+use std::collections::BTreeMap;
+use std::fs;
+
+use crate::errors::CliError;
+
+#[cfg(test)]
+#[path = "repositories_tests.rs"]
+mod tests;
+
+/// Parses a list of repositories from text.
+///
+/// Entries are separated by commas or new lines. Blank entries and lines starting
+/// with `#` are ignored, so a repository file can hold comments.
+///
+/// # Arguments
+///
+/// * `content` - The text holding the repositories
+pub fn parse_repository_list(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .flat_map(|line| line.split(','))
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Collects the repositories given on the command line and in a repository file.
+///
+/// Each repository must have the form `owner/repo`. Duplicates are removed, ignoring
+/// case, and the first occurrence keeps its position. An empty result means that no
+/// repositories were selected.
+///
+/// # Arguments
+///
+/// * `repos` - The repositories given on the command line
+/// * `repos_file` - The path of a file listing repositories, if any
+///
+/// # Errors
+///
+/// Returns `CliError::ConfigError` if the repository file cannot be read, and
+/// `CliError::InvalidArguments` if a repository is not of the form `owner/repo`.
+pub fn resolve_repositories(
+    repos: &[String],
+    repos_file: Option<&str>,
+) -> Result<Vec<String>, CliError> {
+    let mut entries = repos
+        .iter()
+        .flat_map(|r| parse_repository_list(r))
+        .collect::<Vec<_>>();
+    if let Some(path) = repos_file {
+        let content = fs::read_to_string(path).map_err(|e| {
+            CliError::ConfigError(format!(
+                "Failed to read the repository file {}: {}",
+                path, e
+            ))
+        })?;
+        entries.extend(parse_repository_list(&content));
+    }
+
+    let mut repositories: Vec<String> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if !is_repository_name(&entry) {
+            return Err(CliError::InvalidArguments(format!(
+                "Invalid repository '{}'. Expected the form owner/repo",
+                entry
+            )));
+        }
+        if !repositories.iter().any(|r| r.eq_ignore_ascii_case(&entry)) {
+            repositories.push(entry);
+        }
+    }
+
+    Ok(repositories)
+}
+
+/// Returns `true` if `name` has the form `owner/repo`.
+fn is_repository_name(name: &str) -> bool {
+    match name.split_once('/') {
+        Some((owner, repo)) => !owner.is_empty() && !repo.is_empty() && !repo.contains('/'),
+        None => false,
+    }
+}
+
+/// Returns `true` if `full_name` is one of the selected repositories.
+///
+/// Every repository is selected when `repositories` is empty. Repository names are
+/// compared ignoring case, as GitHub does.
+///
+/// # Arguments
+///
+/// * `repositories` - The selected repositories, as `owner/repo`
+/// * `full_name` - The repository to look up, as `owner/repo`
+pub fn is_repository_selected(repositories: &[String], full_name: &str) -> bool {
+    repositories.is_empty()
+        || repositories
+            .iter()
+            .any(|r| r.eq_ignore_ascii_case(full_name))
+}
+
+/// The pull request outcomes of one repository.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepositorySummary {
+    /// Pull requests that passed every check.
+    pub passed: u32,
+
+    /// Pull requests with at least one failing check, or marked as work in progress.
+    pub with_issues: u32,
+
+    /// Pull requests that could not be processed.
+    pub errors: u32,
+}
+
+impl RepositorySummary {
+    /// Records the outcome of processing one pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The check result, or `None` when processing failed
+    pub fn record(&mut self, result: Option<&CheckResult>) {
+        match result {
+            None => self.errors += 1,
+            Some(result) if is_passing(result) => self.passed += 1,
+            Some(_) => self.with_issues += 1,
+        }
+    }
+
+    /// The number of pull requests recorded.
+    pub fn total(&self) -> u32 {
+        self.passed + self.with_issues + self.errors
+    }
+}
+
+/// Returns `true` if every check passed and the pull request is not a work in progress.
+fn is_passing(result: &CheckResult) -> bool {
+    result.title_valid
+        && result.work_item_referenced
+        && result.size_valid
+        && !result.wip_detected
+        && result.checklist_complete
+        && result.review_threads_resolved
+        && result.deleted_files_paired
+        && result.version_bump_valid
+        && result.suspicious_files_absent
+        && result.base_branch_valid
+        && result.labels_exclusive
+        && result.commits_signed
+        && result.revert_referenced
+        && result.up_to_date
+        && result.footers_present
+        && result.title_descriptive
+        && result.change_types_within_limit
+        && result.label_policy_valid
+}
+
+/// Formats the per-repository summaries as one line per repository.
+///
+/// Repositories that were selected but saw no pull requests are listed with zero
+/// counts, so that a missing webhook delivery stands out.
+///
+/// # Arguments
+///
+/// * `repositories` - The selected repositories, as `owner/repo`
+/// * `summaries` - The outcomes, keyed by repository full name
+pub fn format_repository_summaries(
+    repositories: &[String],
+    summaries: &BTreeMap<String, RepositorySummary>,
+) -> String {
+    let mut all = summaries.clone();
+    for repository in repositories {
+        if !all.keys().any(|k| k.eq_ignore_ascii_case(repository)) {
+            all.insert(repository.clone(), RepositorySummary::default());
+        }
+    }
+
+    if all.is_empty() {
+        return "No pull requests were processed".to_string();
+    }
+
+    let mut lines = vec!["Pull requests processed per repository:".to_string()];
+    for (repository, summary) in &all {
+        lines.push(format!(
+            "  {}: {} processed, {} passed, {} with issues, {} errors",
+            repository,
+            summary.total(),
+            summary.passed,
+            summary.with_issues,
+            summary.errors
+        ));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,103 @@
+use super::*;
+use std::io::Write;
+
+fn passing_result() -> CheckResult {
+    CheckResult::default()
+}
+
+#[test]
+fn test_parse_repository_list_splits_commas_and_lines() {
+    let repositories = parse_repository_list("owner/a, owner/b\n# team repos\n\nowner/c\n");
+
+    assert_eq!(repositories, vec!["owner/a", "owner/b", "owner/c"]);
+}
+
+#[test]
+fn test_resolve_repositories_combines_arguments_and_file() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "owner/b\nOwner/A\nowner/c").unwrap();
+
+    let repositories = resolve_repositories(
+        &["owner/a,owner/b".to_string()],
+        Some(file.path().to_str().unwrap()),
+    )
+    .unwrap();
+
+    assert_eq!(repositories, vec!["owner/a", "owner/b", "owner/c"]);
+}
+
+#[test]
+fn test_resolve_repositories_rejects_invalid_names() {
+    for invalid in ["owner", "owner/", "/repo", "owner/repo/extra"] {
+        let result = resolve_repositories(&[invalid.to_string()], None);
+
+        assert!(
+            matches!(result, Err(CliError::InvalidArguments(_))),
+            "{invalid} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_resolve_repositories_reports_missing_file() {
+    let result = resolve_repositories(&[], Some("/nonexistent/repositories.txt"));
+
+    assert!(matches!(result, Err(CliError::ConfigError(_))));
+}
+
+#[test]
+fn test_is_repository_selected() {
+    let repositories = vec!["owner/a".to_string()];
+
+    assert!(is_repository_selected(&repositories, "Owner/A"));
+    assert!(!is_repository_selected(&repositories, "owner/b"));
+    assert!(is_repository_selected(&[], "owner/b"));
+}
+
+#[test]
+fn test_repository_summary_records_outcomes() {
+    let mut summary = RepositorySummary::default();
+    let failing = CheckResult {
+        title_valid: false,
+        ..passing_result()
+    };
+
+    summary.record(Some(&passing_result()));
+    summary.record(Some(&failing));
+    summary.record(None);
+
+    assert_eq!(
+        summary,
+        RepositorySummary {
+            passed: 1,
+            with_issues: 1,
+            errors: 1,
+        }
+    );
+    assert_eq!(summary.total(), 3);
+}
+
+#[test]
+fn test_format_repository_summaries_lists_selected_repositories() {
+    let mut summaries = BTreeMap::new();
+    summaries.insert(
+        "owner/a".to_string(),
+        RepositorySummary {
+            passed: 2,
+            with_issues: 1,
+            errors: 0,
+        },
+    );
+
+    let output =
+        format_repository_summaries(&["owner/a".to_string(), "owner/b".to_string()], &summaries);
+
+    assert_eq!(
+        output,
+        "Pull requests processed per repository:\n  owner/a: 3 processed, 2 passed, 1 with issues, 0 errors\n  owner/b: 0 processed, 0 passed, 0 with issues, 0 errors"
+    );
+    assert_eq!(
+        format_repository_summaries(&[], &BTreeMap::new()),
+        "No pull requests were processed"
+    );
+}
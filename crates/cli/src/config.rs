@@ -32,6 +32,10 @@ pub struct AppConfig {
     #[serde(default)]
     /// Webhook server configuration settings
     pub webhooks: WebHookConfig,
+
+    #[serde(default)]
+    /// Polling configuration settings
+    pub polling: PollingConfig,
 }
 
 impl AppConfig {
@@ -88,6 +92,7 @@ impl Default for AppConfig {
             policies: ApplicationDefaults::default(),
             authentication: AuthenticationConfig::new(),
             webhooks: WebHookConfig::new(),
+            polling: PollingConfig::new(),
         }
     }
 }
@@ -177,6 +182,34 @@ impl Default for WebHookConfig {
     }
 }
 
+/// Configuration for polling repositories for pull requests instead of receiving webhooks
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollingConfig {
+    /// The number of seconds between two polls
+    #[serde(default = "default_poll_interval_seconds")]
+    pub interval_seconds: u64,
+
+    /// The repositories to poll, as `owner/repo`
+    #[serde(default)]
+    pub repositories: Vec<String>,
+}
+
+impl PollingConfig {
+    /// Creates a new PollingConfig with default values
+    pub fn new() -> Self {
+        PollingConfig {
+            interval_seconds: default_poll_interval_seconds(),
+            repositories: Vec::new(),
+        }
+    }
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Get the path to the configuration file
 pub fn get_config_path(config_path: Option<&str>) -> PathBuf {
     if let Some(path) = config_path {
@@ -192,3 +225,8 @@ pub fn get_config_path(config_path: Option<&str>) -> PathBuf {
 fn default_port() -> u32 {
     3100
 }
+
+/// Returns the default number of seconds between two polls
+fn default_poll_interval_seconds() -> u64 {
+    300
+}
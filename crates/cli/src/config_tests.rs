@@ -87,3 +87,36 @@ fn load_ignores_top_level_org_policy_source_table() {
         "the [policies] table alongside the misplaced top-level table should still load normally"
     );
 }
+
+#[test]
+fn load_defaults_polling_when_absent_from_file() {
+    let path = temp_path("merge_warden_cli_no_polling_test.toml");
+    std::fs::write(&path, "[policies]\nenable_title_validation = true\n").unwrap();
+
+    let r = AppConfig::load(&path);
+    let _ = std::fs::remove_file(&path);
+
+    let config = r.expect("AppConfig::load should succeed");
+    assert_eq!(config.polling.interval_seconds, 300);
+    assert!(config.polling.repositories.is_empty());
+}
+
+#[test]
+fn load_reads_polling_from_toml_file() {
+    let path = temp_path("merge_warden_cli_polling_test.toml");
+    std::fs::write(
+        &path,
+        "[polling]\ninterval_seconds = 60\nrepositories = [\"owner/repo\", \"owner/other\"]\n",
+    )
+    .unwrap();
+
+    let r = AppConfig::load(&path);
+    let _ = std::fs::remove_file(&path);
+
+    let config = r.expect("AppConfig::load should succeed");
+    assert_eq!(config.polling.interval_seconds, 60);
+    assert_eq!(
+        config.polling.repositories,
+        vec!["owner/repo".to_string(), "owner/other".to_string()]
+    );
+}
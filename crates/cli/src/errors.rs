@@ -19,6 +19,10 @@ pub enum CliError {
     #[error("Invalid arguments: {0}")]
     InvalidArguments(String),
 
+    /// The checked content does not pass the configured rules
+    #[error("Validation failed: {0}")]
+    ValidationFailed(String),
+
     /// Other errors
     #[error("Error: {0}")]
     Other(String),
@@ -36,6 +40,7 @@ impl std::process::Termination for CliError {
             CliError::ConfigError(_) => std::process::ExitCode::from(2),
             CliError::AuthError(_) => std::process::ExitCode::from(3),
             CliError::InvalidArguments(_) => std::process::ExitCode::from(5),
+            CliError::ValidationFailed(_) => std::process::ExitCode::from(1),
             CliError::Other(_) => std::process::ExitCode::FAILURE,
         }
     }
@@ -19,6 +19,12 @@ fn test_invalid_arguments_display() {
     assert_eq!(format!("{}", err), "Invalid arguments: bad arg");
 }
 
+#[test]
+fn test_validation_failed_display() {
+    let err = CliError::ValidationFailed("bad title".to_string());
+    assert_eq!(format!("{}", err), "Validation failed: bad title");
+}
+
 #[test]
 fn test_other_error_display() {
     let err = CliError::Other("other".to_string());
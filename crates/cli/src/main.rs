@@ -10,6 +10,8 @@
 //!
 //! - `checkpr` - Validate a pull request against configured rules
 //! - `config` - Manage configuration files and settings
+//! - `explain` - Check a PR title and body locally against the configured rules
+//! - `poll` - Poll repositories for pull requests instead of receiving webhooks
 //! - `auth` - Authenticate with Git providers (GitHub, GitLab, etc.)
 //!
 //! # Examples
@@ -18,6 +20,12 @@
 //! # Check a pull request
 //! merge-warden checkpr --repo owner/repo --pr-number 123
 //!
+//! # Poll repositories every five minutes
+//! merge-warden poll --repos owner/repo,owner/other --interval 300
+//!
+//! # Check a title and body before opening a pull request
+//! merge-warden explain --title "feat: add login" --body "Fixes #42"
+//!
 //! # Initialize configuration
 //! merge-warden config init
 //!
@@ -41,7 +49,10 @@ mod config;
 /// Error types specific to the CLI.
 mod errors;
 
-use commands::{auth::AuthCommands, check_pr::CheckPrArgs, config_cmd::ConfigCommands};
+use commands::{
+    auth::AuthCommands, check_pr::CheckPrArgs, config_cmd::ConfigCommands, explain::ExplainArgs,
+    poll::PollArgs,
+};
 use errors::CliError;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
@@ -76,6 +87,12 @@ enum Commands {
     #[command(subcommand)]
     Config(ConfigCommands),
 
+    /// Check a PR title and body against the configured rules without network calls
+    Explain(ExplainArgs),
+
+    /// Poll repositories for pull requests instead of receiving webhooks
+    Poll(PollArgs),
+
     /// Authenticate with Git providers
     #[command(subcommand)]
     Auth(AuthCommands),
@@ -142,6 +159,18 @@ async fn main() -> Result<(), CliError> {
                 return Err(e);
             }
         }
+        Commands::Explain(args) => {
+            if let Err(e) = commands::explain::execute(args).await {
+                error!("Error explaining pull request checks: {}", e);
+                return Err(e);
+            }
+        }
+        Commands::Poll(args) => {
+            if let Err(e) = commands::poll::execute(args).await {
+                error!("Error polling repositories: {}", e);
+                return Err(e);
+            }
+        }
         Commands::Auth(cmd) => {
             if let Err(e) = commands::auth::execute(cmd).await {
                 error!("Error executing auth command: {}", e);
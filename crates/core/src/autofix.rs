@@ -0,0 +1,204 @@
+//! # Autofix
+//!
+//! This module works out the edits Merge Warden makes to a pull request to fix a
+//! failing check, when [`AutofixPolicyConfig`] enables a fix for that check.
+//!
+//! The functions only compute the new title or description; the caller applies them
+//! through [`PullRequestProvider::update_pull_request`] and explains the change in a
+//! comment. Every fix is conservative: it is only returned when the check currently
+//! fails, was not bypassed, and passes once the fix is applied.
+//!
+//! [`AutofixPolicyConfig`]: crate::config::AutofixPolicyConfig
+//! [`PullRequestProvider::update_pull_request`]: merge_warden_developer_platforms::PullRequestProvider::update_pull_request
+
+use merge_warden_developer_platforms::models::PullRequest;
+use regex::Regex;
+use std::sync::OnceLock;
+
+use crate::checks::{check_pr_title, check_work_item_reference, TitleIssue};
+use crate::config::CurrentPullRequestValidationConfiguration;
+
+#[cfg(test)]
+#[path = "autofix_tests.rs"]
+mod tests;
+
+/// Compiled once at first use. Matches a segment of a branch name that either starts
+/// with `issue` or `gh` followed by an issue number, or starts with an issue number
+/// followed by `-` or `_` and a word, such as `123-add-login`.
+static BRANCH_ISSUE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Returns the compiled branch issue regex, initialising it on first call.
+fn branch_issue_regex() -> &'static Regex {
+    BRANCH_ISSUE_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)(?:^|/)(?:(?:issues?|gh)[-_]?(\d+)(?:[-_/]|$)|(\d+)[-_][a-z])")
+            .expect("BRANCH_ISSUE_REGEX is a valid regex")
+    })
+}
+
+/// Returns the issue number a branch is named after, if any.
+///
+/// The number must start a segment of the branch name and either be prefixed with
+/// `issue` or `gh`, or be followed by `-` or `_` and a word. Version numbers and
+/// dates such as `release/1.2` or `renovate/2024-10-deps` are therefore not mistaken
+/// for issues, and neither is a bare number such as `users/jane/15`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::autofix::branch_issue_number;
+///
+/// assert_eq!(branch_issue_number("feature/123-add-login"), Some(123));
+/// assert_eq!(branch_issue_number("gh-42"), Some(42));
+/// assert_eq!(branch_issue_number("release/1.2"), None);
+/// assert_eq!(branch_issue_number("release/2024-01"), None);
+/// assert_eq!(branch_issue_number("feature/add-login"), None);
+/// ```
+pub fn branch_issue_number(branch: &str) -> Option<u64> {
+    branch_issue_regex().captures(branch).and_then(|captures| {
+        captures
+            .get(1)
+            .or_else(|| captures.get(2))
+            .and_then(|number| number.as_str().parse().ok())
+    })
+}
+
+/// Returns the fixed title of a pull request, or `None` when the title is not fixed.
+///
+/// The title is fixed when [`AutofixPolicyConfig::title`] and the title check are
+/// enabled, the title fails the check and the check suggests a fix that makes it
+/// pass. Fixes that change the type, such as `feature` to `feat`, are never applied
+/// because the author may have meant a different type.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::autofix::fix_title;
+/// use merge_warden_core::config::CurrentPullRequestValidationConfiguration;
+/// use merge_warden_developer_platforms::models::PullRequest;
+///
+/// let mut config = CurrentPullRequestValidationConfiguration::default();
+/// config.autofix.title = true;
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "FEAT: add login".to_string(),
+///     draft: false,
+///     body: None,
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
+/// };
+///
+/// assert_eq!(fix_title(&pr, &config).as_deref(), Some("feat: add login"));
+/// ```
+///
+/// [`AutofixPolicyConfig::title`]: crate::config::AutofixPolicyConfig::title
+pub fn fix_title(
+    pr: &PullRequest,
+    config: &CurrentPullRequestValidationConfiguration,
+) -> Option<String> {
+    if !config.autofix.title || !config.enforce_title_convention {
+        return None;
+    }
+
+    let bypass_rule = config.bypass_rules.title_convention();
+    let result = check_pr_title(pr, pr.author.as_ref(), bypass_rule, config);
+    if result.is_valid() {
+        return None;
+    }
+
+    let diagnosis = result.diagnosis?;
+    let changes_type = diagnosis.issues.iter().any(|issue| {
+        matches!(
+            issue,
+            TitleIssue::UnrecognizedType { .. } | TitleIssue::TypeNotAllowed { .. }
+        )
+    });
+    if changes_type {
+        return None;
+    }
+
+    let fixed = diagnosis.suggested_fix.filter(|fixed| *fixed != pr.title)?;
+    let fixed_pr = PullRequest {
+        title: fixed.clone(),
+        ..pr.clone()
+    };
+    check_pr_title(&fixed_pr, fixed_pr.author.as_ref(), bypass_rule, config)
+        .is_valid()
+        .then_some(fixed)
+}
+
+/// Returns the reference line added to a description for an issue number.
+///
+/// `Relates to` is used rather than a closing keyword so that merging the pull
+/// request does not close an issue the author did not mean to close.
+pub fn work_item_reference_line(issue_number: u64) -> String {
+    format!("Relates to #{}", issue_number)
+}
+
+/// Returns the description of a pull request with a work item reference added, or
+/// `None` when the description is not fixed.
+///
+/// The description is fixed when [`AutofixPolicyConfig::work_item`] and the work
+/// item check are enabled, the description fails the check, the head branch is named
+/// after an issue (see [`branch_issue_number`]) and the configured work item patterns
+/// accept the added [`work_item_reference_line`]. The caller must still check that
+/// the issue exists before applying the fix.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::autofix::fix_work_item_reference;
+/// use merge_warden_core::config::CurrentPullRequestValidationConfiguration;
+/// use merge_warden_developer_platforms::models::PullRequest;
+///
+/// let mut config = CurrentPullRequestValidationConfiguration::default();
+/// config.autofix.work_item = true;
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "feat: add login".to_string(),
+///     draft: false,
+///     body: Some("Adds a login page.".to_string()),
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: "feature/123-add-login".to_string(),
+/// };
+///
+/// assert_eq!(
+///     fix_work_item_reference(&pr, &config).as_deref(),
+///     Some("Adds a login page.\n\nRelates to #123")
+/// );
+/// ```
+///
+/// [`AutofixPolicyConfig::work_item`]: crate::config::AutofixPolicyConfig::work_item
+pub fn fix_work_item_reference(
+    pr: &PullRequest,
+    config: &CurrentPullRequestValidationConfiguration,
+) -> Option<String> {
+    if !config.autofix.work_item || !config.enforce_work_item_references {
+        return None;
+    }
+
+    let bypass_rule = config.bypass_rules.work_item_convention();
+    if check_work_item_reference(pr, pr.author.as_ref(), bypass_rule, config).is_valid() {
+        return None;
+    }
+
+    let issue_number = branch_issue_number(&pr.head_ref)?;
+    let line = work_item_reference_line(issue_number);
+    let body = match pr.body.as_deref().map(str::trim_end) {
+        Some(body) if !body.trim().is_empty() => format!("{}\n\n{}", body, line),
+        _ => line,
+    };
+
+    let fixed_pr = PullRequest {
+        body: Some(body.clone()),
+        ..pr.clone()
+    };
+    check_work_item_reference(&fixed_pr, fixed_pr.author.as_ref(), bypass_rule, config)
+        .is_valid()
+        .then_some(body)
+}
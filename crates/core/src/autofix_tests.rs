@@ -0,0 +1,181 @@
+use super::*;
+use crate::config::{AutofixPolicyConfig, WorkItemPatterns};
+
+fn make_pr(title: &str, body: Option<&str>, head_ref: &str) -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: title.to_string(),
+        draft: false,
+        body: body.map(str::to_string),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: head_ref.to_string(),
+    }
+}
+
+fn autofix_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        autofix: AutofixPolicyConfig {
+            title: true,
+            work_item: true,
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_branch_issue_number() {
+    assert_eq!(branch_issue_number("feature/123-add-login"), Some(123));
+    assert_eq!(branch_issue_number("123-add-login"), Some(123));
+    assert_eq!(branch_issue_number("fix/issue-7"), Some(7));
+    assert_eq!(branch_issue_number("fix/GH_42_crash"), Some(42));
+    assert_eq!(branch_issue_number("users/jane/issue15"), Some(15));
+
+    assert_eq!(branch_issue_number("users/jane/15"), None);
+    assert_eq!(branch_issue_number("release/1.2.0"), None);
+    assert_eq!(branch_issue_number("feature/2fa-login"), None);
+    assert_eq!(branch_issue_number("feature/login-v2"), None);
+    assert_eq!(branch_issue_number(""), None);
+}
+
+#[test]
+fn test_branch_issue_number_ignores_dates() {
+    for branch in [
+        "release/2024-01",
+        "release/2024-01-15",
+        "renovate/2024-10-deps",
+        "backup_2024_10_01",
+        "hotfix/20241015",
+    ] {
+        assert_eq!(branch_issue_number(branch), None, "{branch}");
+    }
+}
+
+#[test]
+fn test_fix_title_applies_suggested_fix() {
+    let config = autofix_config();
+
+    assert_eq!(
+        fix_title(&make_pr("Feat: add login", None, ""), &config).as_deref(),
+        Some("feat: add login")
+    );
+    assert_eq!(
+        fix_title(&make_pr("feat:add login", None, ""), &config).as_deref(),
+        Some("feat: add login")
+    );
+}
+
+#[test]
+fn test_fix_title_is_off_by_default() {
+    let config = CurrentPullRequestValidationConfiguration::default();
+
+    assert_eq!(
+        fix_title(&make_pr("FEAT: add login", None, ""), &config),
+        None
+    );
+}
+
+#[test]
+fn test_fix_title_ignores_valid_titles() {
+    assert_eq!(
+        fix_title(&make_pr("feat: add login", None, ""), &autofix_config()),
+        None
+    );
+}
+
+#[test]
+fn test_fix_title_does_not_change_the_type() {
+    assert_eq!(
+        fix_title(&make_pr("feature: add login", None, ""), &autofix_config()),
+        None
+    );
+}
+
+#[test]
+fn test_fix_title_skips_titles_without_a_fix() {
+    assert_eq!(
+        fix_title(&make_pr("add login", None, ""), &autofix_config()),
+        None
+    );
+}
+
+#[test]
+fn test_fix_title_skips_disabled_title_check() {
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: false,
+        ..autofix_config()
+    };
+
+    assert_eq!(
+        fix_title(&make_pr("FEAT: add login", None, ""), &config),
+        None
+    );
+}
+
+#[test]
+fn test_fix_work_item_reference_appends_reference_from_branch() {
+    let pr = make_pr(
+        "feat: add login",
+        Some("Adds a login page.\n\n"),
+        "feature/123-add-login",
+    );
+
+    assert_eq!(
+        fix_work_item_reference(&pr, &autofix_config()).as_deref(),
+        Some("Adds a login page.\n\nRelates to #123")
+    );
+}
+
+#[test]
+fn test_fix_work_item_reference_fills_empty_description() {
+    let config = autofix_config();
+
+    for body in [None, Some(""), Some("  \n")] {
+        let pr = make_pr("feat: add login", body, "123-add-login");
+        assert_eq!(
+            fix_work_item_reference(&pr, &config).as_deref(),
+            Some("Relates to #123")
+        );
+    }
+}
+
+#[test]
+fn test_fix_work_item_reference_needs_an_issue_branch() {
+    let pr = make_pr("feat: add login", Some("Adds a login page."), "add-login");
+
+    assert_eq!(fix_work_item_reference(&pr, &autofix_config()), None);
+}
+
+#[test]
+fn test_fix_work_item_reference_ignores_referenced_descriptions() {
+    let pr = make_pr(
+        "feat: add login",
+        Some("Fixes #99"),
+        "feature/123-add-login",
+    );
+
+    assert_eq!(fix_work_item_reference(&pr, &autofix_config()), None);
+}
+
+#[test]
+fn test_fix_work_item_reference_is_off_by_default() {
+    let pr = make_pr("feat: add login", None, "feature/123-add-login");
+
+    assert_eq!(
+        fix_work_item_reference(&pr, &CurrentPullRequestValidationConfiguration::default()),
+        None
+    );
+}
+
+#[test]
+fn test_fix_work_item_reference_requires_the_configured_patterns_to_match() {
+    let config = CurrentPullRequestValidationConfiguration {
+        work_item_reference_pattern: WorkItemPatterns::from(r"[A-Z]+-\d+"),
+        ..autofix_config()
+    };
+    let pr = make_pr("feat: add login", None, "feature/123-add-login");
+
+    assert_eq!(fix_work_item_reference(&pr, &config), None);
+}
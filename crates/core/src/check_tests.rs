@@ -3,19 +3,35 @@
 //! These tests verify the behavior of PR title and work item reference validation functions,
 //! including bypass functionality and edge cases.
 
-use merge_warden_developer_platforms::models::{PullRequest, User};
+use merge_warden_developer_platforms::models::{
+    CommitVerification, PullRequest, PullRequestCommit, PullRequestFile, ReviewThread, User,
+};
 
 use crate::{
     checks::{
-        check_pr_title, check_work_item_reference, diagnose_pr_title, extract_any_issue_reference,
-        extract_closing_issue_reference, IssueReference, TitleDiagnosis, TitleIssue,
-        TitleValidationResult,
+        check_base_branch, check_change_types, check_deleted_file_pairings,
+        check_descriptive_title, check_exclusive_labels, check_label_policy, check_pr_title,
+        check_required_checklist, check_required_footers, check_revert_reference,
+        check_signed_commits, check_suspicious_files, check_unresolved_review_threads,
+        check_up_to_date, check_version_bump, check_work_item_reference, count_emoji,
+        diagnose_pr_title, diagnose_title_characters, diagnose_title_lint,
+        extract_any_issue_reference, extract_closing_issue_reference,
+        find_pr_template_work_item_lines, is_revert_title, normalize_work_item_reference,
+        parse_conventional_commit, parse_footers, parse_task_list, same_repository_issue_numbers,
+        ConventionalCommit, IssueReference, SuspiciousFileReason, TaskListItem, TitleDiagnosis,
+        TitleIssue, TitleValidationResult,
     },
     config::{
-        BypassRule, CurrentPullRequestValidationConfiguration, CONVENTIONAL_COMMIT_REGEX,
+        BaseBranchPolicyConfig, BaseBranchRule, BypassRule, ChangeTypesPolicyConfig,
+        ChecklistPolicyConfig, CurrentPullRequestValidationConfiguration, DeletedFilePairingRule,
+        DeletedFilePolicyConfig, ExclusiveLabelGroup, ExclusiveLabelsPolicyConfig,
+        FooterPolicyConfig, LabelPolicyConfig, RequiredFooter, ReviewThreadPolicyConfig,
+        SubjectCase, SuspiciousFilePolicyConfig, TitleCharacterPolicyConfig, TitleLintPolicyConfig,
+        UpToDatePolicyConfig, WorkItemPatterns, CONVENTIONAL_COMMIT_REGEX, VALID_PR_TYPES,
         WORK_ITEM_REGEX,
     },
     validation_result::{BypassInfo, BypassRuleType, ValidationResult},
+    version::{BumpLevel, Version},
 };
 
 // Helper functions for creating test data
@@ -41,6 +57,8 @@ fn create_pull_request(
         author,
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     }
 }
 
@@ -58,7 +76,7 @@ fn create_default_config() -> CurrentPullRequestValidationConfiguration {
         title_pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
         invalid_title_label: Some("invalid-title".to_string()),
         enforce_work_item_references: true,
-        work_item_reference_pattern: WORK_ITEM_REGEX.to_string(),
+        work_item_reference_pattern: WORK_ITEM_REGEX.into(),
         missing_work_item_label: Some("missing-work-item".to_string()),
         pr_size_check: crate::config::PrSizeCheckConfig::default(),
         change_type_labels: None, // Use default behavior for tests
@@ -75,7 +93,7 @@ fn create_config_with_invalid_title_regex() -> CurrentPullRequestValidationConfi
 
 fn create_config_with_invalid_work_item_regex() -> CurrentPullRequestValidationConfiguration {
     let mut config = create_default_config();
-    config.work_item_reference_pattern = r"[invalid regex(".to_string(); // Invalid regex
+    config.work_item_reference_pattern = r"[invalid regex(".into(); // Invalid regex
     config
 }
 
@@ -88,7 +106,7 @@ fn should_return_bypassed_when_author_can_bypass_title_validation_with_invalid_t
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["bypass-user"]);
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(result.was_bypassed());
@@ -104,7 +122,7 @@ fn should_return_bypassed_when_author_can_bypass_title_validation_with_valid_tit
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["bypass-user"]);
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(result.was_bypassed());
@@ -120,7 +138,7 @@ fn should_return_invalid_when_author_cannot_bypass_title_validation_with_invalid
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["different-user"]);
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -133,7 +151,7 @@ fn should_return_invalid_when_author_is_none_with_bypass_enabled() {
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["any-user"]);
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -147,7 +165,7 @@ fn should_return_invalid_when_bypass_is_disabled_with_invalid_title() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -161,7 +179,7 @@ fn should_return_invalid_when_regex_compilation_fails() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_config_with_invalid_title_regex();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -175,7 +193,7 @@ fn should_return_valid_when_title_matches_conventional_format_build_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -189,7 +207,7 @@ fn should_return_valid_when_title_matches_conventional_format_chore_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -203,7 +221,7 @@ fn should_return_valid_when_title_matches_conventional_format_ci_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -217,7 +235,7 @@ fn should_return_valid_when_title_matches_conventional_format_docs_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -231,7 +249,7 @@ fn should_return_valid_when_title_matches_conventional_format_feat_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -250,7 +268,7 @@ fn should_return_valid_when_title_matches_conventional_format_feat_type_with_sco
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -269,7 +287,7 @@ fn should_return_valid_when_title_matches_conventional_format_feat_type_with_bre
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -284,7 +302,7 @@ fn should_return_valid_when_title_matches_conventional_format_feat_type_with_sco
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -298,7 +316,7 @@ fn should_return_valid_when_title_matches_conventional_format_fix_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -317,7 +335,7 @@ fn should_return_valid_when_title_matches_conventional_format_fix_type_with_scop
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -331,7 +349,7 @@ fn should_return_valid_when_title_matches_conventional_format_perf_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -345,7 +363,7 @@ fn should_return_valid_when_title_matches_conventional_format_refactor_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -359,7 +377,7 @@ fn should_return_valid_when_title_matches_conventional_format_revert_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -373,7 +391,7 @@ fn should_return_valid_when_title_matches_conventional_format_style_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -387,7 +405,7 @@ fn should_return_valid_when_title_matches_conventional_format_test_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -406,7 +424,7 @@ fn should_return_valid_when_title_matches_conventional_format_with_complex_scope
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -425,7 +443,7 @@ fn should_return_valid_when_title_matches_conventional_format_with_underscores_i
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -439,7 +457,7 @@ fn should_return_valid_when_title_matches_conventional_format_with_numbers_in_sc
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -453,7 +471,7 @@ fn should_return_invalid_when_title_has_incorrect_case() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -467,7 +485,7 @@ fn should_return_invalid_when_title_has_invalid_type() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -481,7 +499,7 @@ fn should_return_invalid_when_title_missing_colon() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -495,7 +513,7 @@ fn should_return_invalid_when_title_missing_space_after_colon() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -509,7 +527,7 @@ fn should_return_invalid_when_title_has_empty_description() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -523,7 +541,7 @@ fn should_return_invalid_when_title_has_uppercase_scope() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -537,7 +555,7 @@ fn should_return_invalid_when_title_has_spaces_in_scope() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -551,13 +569,222 @@ fn should_return_invalid_when_title_is_completely_invalid() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
     assert!(result.bypass_info().is_none());
 }
 
+// Tests for the title lint policy's allowed types with the default title pattern
+
+fn create_config_with_allowed_title_types(
+    types: &[&str],
+) -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        title_lint: TitleLintPolicyConfig {
+            enabled: true,
+            allowed_types: types.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        },
+        ..create_default_config()
+    }
+}
+
+#[test]
+fn should_return_valid_when_title_uses_allowed_custom_type() {
+    let pr = create_pull_request(1, "deps(cargo): bump serde", None, None);
+    let config = create_config_with_allowed_title_types(&["feat", "fix", "deps"]);
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    assert!(result.is_valid());
+    assert!(result.diagnosis.is_none());
+}
+
+#[test]
+fn should_report_allowed_types_when_title_type_is_not_allowed() {
+    let pr = create_pull_request(1, "docs: add guide", None, None);
+    let config = create_config_with_allowed_title_types(&["feat", "deps"]);
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    assert!(!result.is_valid());
+    let diagnosis = result.diagnosis.unwrap();
+    assert_eq!(
+        diagnosis.issues,
+        vec![TitleIssue::TypeNotAllowed {
+            found: "docs".to_string(),
+            allowed: vec!["feat".to_string(), "deps".to_string()],
+        }]
+    );
+    assert_eq!(diagnosis.suggested_fix, None);
+    assert_eq!(
+        diagnosis.issues[0].to_string(),
+        "The type `docs` is not allowed in this repository \u{2014} use one of `feat`, `deps`."
+    );
+}
+
+#[test]
+fn should_diagnose_allowed_custom_type_like_a_standard_type() {
+    let pr = create_pull_request(1, "DEPS:bump serde", None, None);
+    let config = create_config_with_allowed_title_types(&["deps"]);
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    assert!(!result.is_valid());
+    let diagnosis = result.diagnosis.unwrap();
+    assert!(diagnosis.issues.contains(&TitleIssue::UppercaseType {
+        found: "DEPS".to_string()
+    }));
+    assert_eq!(diagnosis.suggested_fix.as_deref(), Some("deps: bump serde"));
+}
+
+#[test]
+fn should_only_correct_typos_to_allowed_types() {
+    let config = create_config_with_allowed_title_types(&["fix", "deps"]);
+
+    let result = check_pr_title(
+        &create_pull_request(1, "feature: add login", None, None),
+        None,
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    assert!(!result.is_valid());
+    assert_eq!(
+        result.diagnosis.unwrap().issues,
+        vec![TitleIssue::TypeNotAllowed {
+            found: "feature".to_string(),
+            allowed: vec!["fix".to_string(), "deps".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn should_ignore_allowed_title_types_when_unset_empty_disabled_or_pattern_is_custom() {
+    let pr = create_pull_request(1, "deps: bump serde", None, None);
+    let bypass_rule = create_bypass_rule_disabled();
+
+    assert!(!check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &bypass_rule,
+        &create_default_config()
+    )
+    .is_valid());
+    assert!(!check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &bypass_rule,
+        &create_config_with_allowed_title_types(&[])
+    )
+    .is_valid());
+
+    let mut config = create_config_with_allowed_title_types(&["deps"]);
+    config.title_lint.enabled = false;
+    assert!(!check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config).is_valid());
+
+    let mut config = create_config_with_allowed_title_types(&["deps"]);
+    config.title_pattern = "^chore: .+".to_string();
+    assert!(!check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config).is_valid());
+}
+
+// Tests for parse_conventional_commit function
+
+#[test]
+fn should_parse_conventional_commit_parts() {
+    assert_eq!(
+        parse_conventional_commit("feat(auth)!: drop session cookies"),
+        Some(ConventionalCommit {
+            type_: "feat".to_string(),
+            scope: Some("auth".to_string()),
+            breaking: true,
+            description: "drop session cookies".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_conventional_commit("fix: handle empty body"),
+        Some(ConventionalCommit {
+            type_: "fix".to_string(),
+            scope: None,
+            breaking: false,
+            description: "handle empty body".to_string(),
+        })
+    );
+}
+
+#[test]
+fn should_parse_the_same_titles_as_the_default_title_pattern() {
+    let regex = regex::Regex::new(CONVENTIONAL_COMMIT_REGEX).unwrap();
+    let titles = [
+        "feat: add login",
+        "feat(api-v2)!: remove endpoint",
+        "chore(deps_dev): bump serde",
+        "feat!: drop support",
+        "Feat: add login",
+        "feat:add login",
+        "feat: ",
+        "feat(AUTH): add login",
+        "feat(spa ces): add login",
+        "feature: add login",
+        " feat: add login",
+        "feat()!: empty scope",
+        "This is just a random title",
+    ];
+
+    for title in titles {
+        assert_eq!(
+            parse_conventional_commit(title).is_some(),
+            regex.is_match(title),
+            "title: {title}"
+        );
+    }
+}
+
+#[test]
+fn should_parse_every_valid_pr_type() {
+    for pr_type in VALID_PR_TYPES {
+        let commit = parse_conventional_commit(&format!("{pr_type}: change something"));
+
+        assert_eq!(
+            commit.map(|c| c.type_).as_deref(),
+            Some(pr_type),
+            "type: {pr_type}"
+        );
+    }
+}
+
+#[test]
+fn should_serialize_conventional_commit_type_as_type() {
+    let commit = parse_conventional_commit("docs(readme): fix typo").unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&commit).unwrap(),
+        serde_json::json!({
+            "type": "docs",
+            "scope": "readme",
+            "breaking": false,
+            "description": "fix typo",
+        })
+    );
+}
+
 // Tests for check_work_item_reference function
 
 #[test]
@@ -572,7 +799,7 @@ fn should_return_bypassed_when_author_can_bypass_work_item_validation_with_inval
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["bypass-user"]);
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(result.was_bypassed());
@@ -588,7 +815,7 @@ fn should_return_bypassed_when_author_can_bypass_work_item_validation_with_no_bo
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["bypass-user"]);
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(result.was_bypassed());
@@ -609,7 +836,7 @@ fn should_return_bypassed_when_author_can_bypass_work_item_validation_with_valid
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["bypass-user"]);
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(result.was_bypassed());
@@ -630,7 +857,7 @@ fn should_return_invalid_when_author_cannot_bypass_work_item_validation_with_inv
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["different-user"]);
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -643,7 +870,7 @@ fn should_return_invalid_when_author_is_none_with_work_item_bypass_enabled() {
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["any-user"]);
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -657,7 +884,7 @@ fn should_return_invalid_when_body_is_none_with_bypass_disabled() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -676,7 +903,7 @@ fn should_return_invalid_when_work_item_bypass_is_disabled_with_invalid_body() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -690,130 +917,271 @@ fn should_return_invalid_when_work_item_regex_compilation_fails() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_config_with_invalid_work_item_regex();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
     assert!(result.bypass_info().is_none());
 }
 
+fn create_config_with_work_item_patterns(
+    patterns: &[&str],
+) -> CurrentPullRequestValidationConfiguration {
+    let mut config = create_default_config();
+    config.work_item_reference_pattern =
+        WorkItemPatterns::new(patterns.iter().map(|p| p.to_string()).collect());
+    config
+}
+
 #[test]
-fn should_return_valid_when_body_contains_fixes_with_hash_reference() {
+fn should_return_valid_when_any_of_multiple_work_item_patterns_matches() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This change fixes #123"),
+        Some("Implements PROJ-42"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
-    let config = create_default_config();
+    let config = create_config_with_work_item_patterns(&[r"#\d+", r"PROJ-\d+"]);
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
-    assert!(result.bypass_info().is_none());
+    assert_eq!(result.matched_pattern.as_deref(), Some(r"PROJ-\d+"));
 }
 
 #[test]
-fn should_return_valid_when_body_contains_fixes_with_hash_reference_case_insensitive() {
+fn should_report_first_matching_work_item_pattern() {
+    let user = create_user(123, "developer");
+    let pr = create_pull_request(1, "feat: add feature", Some("PROJ-42, see #7"), Some(user));
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_config_with_work_item_patterns(&[r"#\d+", r"PROJ-\d+"]);
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert_eq!(result.matched_pattern.as_deref(), Some(r"#\d+"));
+}
+
+#[test]
+fn should_return_invalid_when_no_work_item_pattern_matches() {
+    let user = create_user(123, "developer");
+    let pr = create_pull_request(1, "feat: add feature", Some("No reference"), Some(user));
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_config_with_work_item_patterns(&[r"#\d+", r"PROJ-\d+"]);
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(!result.is_valid());
+    assert!(result.matched_pattern.is_none());
+}
+
+#[test]
+fn should_skip_invalid_work_item_pattern_and_try_the_next() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This change FIXES #123"),
+        Some("Implements PROJ-42"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
-    let config = create_default_config();
+    let config = create_config_with_work_item_patterns(&[r"[invalid regex(", r"PROJ-\d+"]);
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
-    assert!(!result.was_bypassed());
-    assert!(result.bypass_info().is_none());
+    assert_eq!(result.matched_pattern.as_deref(), Some(r"PROJ-\d+"));
 }
 
 #[test]
-fn should_return_valid_when_body_contains_closes_with_hash_reference() {
+fn should_normalize_and_deduplicate_work_item_references() {
     let user = create_user(123, "developer");
-    let pr = create_pull_request(1, "feat: add feature", Some("This closes #456"), Some(user));
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("Fixes GH-123. Also fixes gh-123, closes #123 and relates to #7."),
+        Some(user),
+    );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
-    assert!(!result.was_bypassed());
-    assert!(result.bypass_info().is_none());
+    assert_eq!(result.references, vec!["#123", "#7"]);
 }
 
 #[test]
-fn should_return_valid_when_body_contains_closes_with_hash_reference_case_insensitive() {
+fn should_normalize_cross_repository_work_item_references() {
     let user = create_user(123, "developer");
-    let pr = create_pull_request(1, "feat: add feature", Some("This Closes #456"), Some(user));
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some(
+            "Fixes Owner/Repo#5\nResolves https://github.com/owner/repo/issues/5\nCloses other/repo#5",
+        ),
+        Some(user),
+    );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
-    assert!(result.is_valid());
-    assert!(!result.was_bypassed());
-    assert!(result.bypass_info().is_none());
+    assert_eq!(result.references, vec!["owner/repo#5", "other/repo#5"]);
 }
 
 #[test]
-fn should_return_valid_when_body_contains_resolves_with_hash_reference() {
+fn should_uppercase_custom_work_item_prefixes() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This resolves #789"),
+        Some("Implements proj-42 and PROJ-42, then Proj-43"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
-    let config = create_default_config();
+    let config = create_config_with_work_item_patterns(&[r"(?i)PROJ-\d+"]);
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
-    assert!(result.is_valid());
-    assert!(!result.was_bypassed());
-    assert!(result.bypass_info().is_none());
+    assert_eq!(result.references, vec!["PROJ-42", "PROJ-43"]);
 }
 
 #[test]
-fn should_return_valid_when_body_contains_references_with_hash_reference() {
+fn should_collect_work_item_references_from_every_pattern() {
     let user = create_user(123, "developer");
+    let pr = create_pull_request(1, "feat: add feature", Some("PROJ-42, see #7"), Some(user));
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_config_with_work_item_patterns(&[r"#\d+", r"PROJ-\d+"]);
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert_eq!(result.references, vec!["#7", "PROJ-42"]);
+}
+
+#[test]
+fn should_normalize_work_item_reference_forms() {
+    assert_eq!(normalize_work_item_reference("#12"), "#12");
+    assert_eq!(normalize_work_item_reference("GH-12"), "#12");
+    assert_eq!(normalize_work_item_reference("relates to gh-12"), "#12");
+    assert_eq!(
+        normalize_work_item_reference("Owner/Repo#12"),
+        "owner/repo#12"
+    );
+    assert_eq!(
+        normalize_work_item_reference("https://GitHub.com/Owner/Repo/issues/12"),
+        "owner/repo#12"
+    );
+    assert_eq!(normalize_work_item_reference("abc-12"), "ABC-12");
+    // Not a recognised form: returned trimmed but unchanged
+    assert_eq!(normalize_work_item_reference(" ticket "), "ticket");
+    assert_eq!(normalize_work_item_reference("release-v2"), "release-v2");
+}
+
+#[test]
+fn should_find_reference_keyword_lines_in_pr_template() {
+    let template = "\
+## Description
+
+Describe the fix in a few sentences.
+
+## Related issues
+
+<!-- Fixes #(issue number) -->
+- relates to: <issue link>
+> Closes #
+";
+
+    let lines = find_pr_template_work_item_lines(template, &WorkItemPatterns::default());
+
+    assert_eq!(
+        lines,
+        vec![
+            "Fixes #(issue number)".to_string(),
+            "relates to: <issue link>".to_string(),
+            "Closes #".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn should_find_pr_template_lines_matching_work_item_patterns() {
+    let template = "## Ticket\n\nJira: PROJ-123\n\n## Notes\n";
+    let patterns = WorkItemPatterns::new(vec![r"[A-Z]+-\d+".to_string()]);
+
+    let lines = find_pr_template_work_item_lines(template, &patterns);
+
+    assert_eq!(lines, vec!["Jira: PROJ-123".to_string()]);
+}
+
+#[test]
+fn should_find_no_lines_in_pr_template_without_work_item_references() {
+    let template = "## Description\n\n- [ ] Tests added\n- [ ] Docs updated\n";
+
+    let lines = find_pr_template_work_item_lines(template, &WorkItemPatterns::default());
+
+    assert!(lines.is_empty());
+}
+
+#[test]
+fn should_limit_the_lines_found_in_pr_template() {
+    let template = "Fixes #\nCloses #\nResolves #\nReferences #\n";
+
+    let lines = find_pr_template_work_item_lines(template, &WorkItemPatterns::default());
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[2], "Resolves #");
+}
+
+#[test]
+fn should_find_same_repository_issue_numbers_in_work_item_references() {
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This references #101"),
-        Some(user),
+        Some("Fixes GH-7, relates to https://github.com/Owner/Repo/issues/8 and other/repo#9"),
+        None,
     );
-    let bypass_rule = create_bypass_rule_disabled();
+    let result = check_work_item_reference(
+        &pr,
+        pr.author.as_ref(),
+        &BypassRule::default(),
+        &create_default_config(),
+    );
+
+    assert_eq!(
+        same_repository_issue_numbers(&result.references, "owner", "repo"),
+        vec![7, 8]
+    );
+}
+
+#[test]
+fn should_not_report_matched_pattern_when_bypassed() {
+    let user = create_user(123, "bypass-user");
+    let pr = create_pull_request(1, "feat: add feature", Some("fixes #1"), Some(user));
+    let bypass_rule = create_bypass_rule_enabled_for_users(vec!["bypass-user"]);
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
-    assert!(result.is_valid());
-    assert!(!result.was_bypassed());
-    assert!(result.bypass_info().is_none());
+    assert!(result.was_bypassed());
+    assert!(result.matched_pattern.is_none());
 }
 
 #[test]
-fn should_return_valid_when_body_contains_relates_to_with_hash_reference() {
+fn should_return_valid_when_body_contains_fixes_with_hash_reference() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This relates to #202"),
+        Some("This change fixes #123"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -821,18 +1189,18 @@ fn should_return_valid_when_body_contains_relates_to_with_hash_reference() {
 }
 
 #[test]
-fn should_return_valid_when_body_contains_fixes_with_gh_reference() {
+fn should_return_valid_when_body_contains_fixes_with_hash_reference_case_insensitive() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This fixes GH-303"),
+        Some("This change FIXES #123"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -840,18 +1208,13 @@ fn should_return_valid_when_body_contains_fixes_with_gh_reference() {
 }
 
 #[test]
-fn should_return_valid_when_body_contains_fixes_with_full_github_url() {
+fn should_return_valid_when_body_contains_closes_with_hash_reference() {
     let user = create_user(123, "developer");
-    let pr = create_pull_request(
-        1,
-        "feat: add feature",
-        Some("This fixes https://github.com/owner/repo/issues/404"),
-        Some(user),
-    );
+    let pr = create_pull_request(1, "feat: add feature", Some("This closes #456"), Some(user));
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -859,18 +1222,13 @@ fn should_return_valid_when_body_contains_fixes_with_full_github_url() {
 }
 
 #[test]
-fn should_return_valid_when_body_contains_fixes_with_owner_repo_format() {
+fn should_return_valid_when_body_contains_closes_with_hash_reference_case_insensitive() {
     let user = create_user(123, "developer");
-    let pr = create_pull_request(
-        1,
-        "feat: add feature",
-        Some("This fixes owner/repo#505"),
-        Some(user),
-    );
+    let pr = create_pull_request(1, "feat: add feature", Some("This Closes #456"), Some(user));
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -878,18 +1236,18 @@ fn should_return_valid_when_body_contains_fixes_with_owner_repo_format() {
 }
 
 #[test]
-fn should_return_valid_when_body_contains_multiple_work_item_references() {
+fn should_return_valid_when_body_contains_resolves_with_hash_reference() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This fixes #123 and also relates to #456"),
+        Some("This resolves #789"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -897,18 +1255,18 @@ fn should_return_valid_when_body_contains_multiple_work_item_references() {
 }
 
 #[test]
-fn should_return_valid_when_body_contains_work_item_reference_with_extra_whitespace() {
+fn should_return_valid_when_body_contains_references_with_hash_reference() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This   fixes    #606"),
+        Some("This references #101"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -916,18 +1274,18 @@ fn should_return_valid_when_body_contains_work_item_reference_with_extra_whitesp
 }
 
 #[test]
-fn should_return_valid_when_body_contains_work_item_reference_with_newlines() {
+fn should_return_valid_when_body_contains_relates_to_with_hash_reference() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This is a great feature.\n\nFixes #707"),
+        Some("This relates to #202"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid());
     assert!(!result.was_bypassed());
@@ -935,70 +1293,268 @@ fn should_return_valid_when_body_contains_work_item_reference_with_newlines() {
 }
 
 #[test]
-fn should_return_invalid_when_body_has_no_work_item_reference() {
+fn should_return_valid_when_body_contains_fixes_with_gh_reference() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This is just a description"),
+        Some("This fixes GH-303"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
-    assert!(!result.is_valid());
+    assert!(result.is_valid());
     assert!(!result.was_bypassed());
     assert!(result.bypass_info().is_none());
 }
 
 #[test]
-fn should_return_invalid_when_body_has_invalid_keyword() {
+fn should_return_valid_when_body_contains_fixes_with_full_github_url() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This handles #808"),
+        Some("This fixes https://github.com/owner/repo/issues/404"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
-    assert!(!result.is_valid());
+    assert!(result.is_valid());
     assert!(!result.was_bypassed());
     assert!(result.bypass_info().is_none());
 }
 
 #[test]
-fn should_return_invalid_when_body_has_keyword_without_reference() {
+fn should_return_valid_when_body_contains_fixes_with_owner_repo_format() {
     let user = create_user(123, "developer");
     let pr = create_pull_request(
         1,
         "feat: add feature",
-        Some("This fixes something"),
+        Some("This fixes owner/repo#505"),
         Some(user),
     );
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
-    assert!(!result.is_valid());
+    assert!(result.is_valid());
     assert!(!result.was_bypassed());
     assert!(result.bypass_info().is_none());
 }
 
 #[test]
-fn should_return_invalid_when_body_has_malformed_reference() {
+fn should_return_valid_when_body_contains_multiple_work_item_references() {
     let user = create_user(123, "developer");
-    let pr = create_pull_request(1, "feat: add feature", Some("This fixes #abc"), Some(user));
-    let bypass_rule = create_bypass_rule_disabled();
-    let config = create_default_config();
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("This fixes #123 and also relates to #456"),
+        Some(user),
+    );
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_default_config();
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(result.is_valid());
+    assert!(!result.was_bypassed());
+    assert!(result.bypass_info().is_none());
+}
+
+#[test]
+fn should_return_invalid_when_body_contains_fewer_work_item_references_than_required() {
+    let pr = create_pull_request(1, "feat: add feature", Some("Fixes #123"), None);
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = CurrentPullRequestValidationConfiguration {
+        min_work_item_references: 2,
+        ..create_default_config()
+    };
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(!result.is_valid());
+    assert!(!result.was_bypassed());
+    assert_eq!(result.references, vec!["#123".to_string()]);
+}
+
+#[test]
+fn should_return_valid_when_body_contains_required_number_of_work_item_references() {
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("Fixes #123\nRelates to #456"),
+        None,
+    );
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = CurrentPullRequestValidationConfiguration {
+        min_work_item_references: 2,
+        ..create_default_config()
+    };
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(result.is_valid());
+    assert_eq!(
+        result.references,
+        vec!["#123".to_string(), "#456".to_string()]
+    );
+}
+
+#[test]
+fn should_count_duplicate_work_item_references_once_towards_the_minimum() {
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("Fixes #123\nRelates to GH-123"),
+        None,
+    );
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = CurrentPullRequestValidationConfiguration {
+        min_work_item_references: 2,
+        ..create_default_config()
+    };
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(!result.is_valid());
+    assert_eq!(result.references, vec!["#123".to_string()]);
+}
+
+#[test]
+fn should_return_valid_without_work_item_reference_when_none_are_required() {
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = CurrentPullRequestValidationConfiguration {
+        min_work_item_references: 0,
+        ..create_default_config()
+    };
+
+    for body in [None, Some("No work item here")] {
+        let pr = create_pull_request(1, "feat: add feature", body, None);
+
+        let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+        assert!(result.is_valid());
+        assert!(!result.was_bypassed());
+        assert_eq!(result.matched_pattern, None);
+        assert!(result.references.is_empty());
+    }
+
+    let pr = create_pull_request(1, "feat: add feature", Some("Fixes #123"), None);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+    assert!(result.is_valid());
+    assert_eq!(result.references, vec!["#123".to_string()]);
+}
+
+#[test]
+fn should_return_valid_when_body_contains_work_item_reference_with_extra_whitespace() {
+    let user = create_user(123, "developer");
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("This   fixes    #606"),
+        Some(user),
+    );
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_default_config();
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(result.is_valid());
+    assert!(!result.was_bypassed());
+    assert!(result.bypass_info().is_none());
+}
+
+#[test]
+fn should_return_valid_when_body_contains_work_item_reference_with_newlines() {
+    let user = create_user(123, "developer");
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("This is a great feature.\n\nFixes #707"),
+        Some(user),
+    );
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_default_config();
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(result.is_valid());
+    assert!(!result.was_bypassed());
+    assert!(result.bypass_info().is_none());
+}
+
+#[test]
+fn should_return_invalid_when_body_has_no_work_item_reference() {
+    let user = create_user(123, "developer");
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("This is just a description"),
+        Some(user),
+    );
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_default_config();
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(!result.is_valid());
+    assert!(!result.was_bypassed());
+    assert!(result.bypass_info().is_none());
+}
+
+#[test]
+fn should_return_invalid_when_body_has_invalid_keyword() {
+    let user = create_user(123, "developer");
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("This handles #808"),
+        Some(user),
+    );
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(!result.is_valid());
+    assert!(!result.was_bypassed());
+    assert!(result.bypass_info().is_none());
+}
+
+#[test]
+fn should_return_invalid_when_body_has_keyword_without_reference() {
+    let user = create_user(123, "developer");
+    let pr = create_pull_request(
+        1,
+        "feat: add feature",
+        Some("This fixes something"),
+        Some(user),
+    );
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_default_config();
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
+
+    assert!(!result.is_valid());
+    assert!(!result.was_bypassed());
+    assert!(result.bypass_info().is_none());
+}
+
+#[test]
+fn should_return_invalid_when_body_has_malformed_reference() {
+    let user = create_user(123, "developer");
+    let pr = create_pull_request(1, "feat: add feature", Some("This fixes #abc"), Some(user));
+    let bypass_rule = create_bypass_rule_disabled();
+    let config = create_default_config();
+
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -1012,7 +1568,7 @@ fn should_return_invalid_when_body_has_reference_without_keyword() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -1026,7 +1582,7 @@ fn should_return_invalid_when_body_is_empty_string() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_work_item_reference(&pr, &bypass_rule, &config);
+    let result = check_work_item_reference(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -1802,7 +2358,7 @@ fn should_return_diagnosis_some_when_check_pr_title_with_invalid_title() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid(), "Expected invalid result for bad title");
     assert!(
@@ -1817,7 +2373,7 @@ fn should_return_diagnosis_none_when_check_pr_title_with_valid_title() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid(), "Expected valid result for good title");
     assert!(
@@ -1833,7 +2389,7 @@ fn should_return_diagnosis_none_when_check_pr_title_with_bypassed_user() {
     let bypass_rule = create_bypass_rule_enabled_for_users(vec!["release-bot"]);
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(result.is_valid(), "Expected bypassed result to be valid");
     assert!(result.was_bypassed(), "Expected bypass to have been used");
@@ -1903,7 +2459,7 @@ fn should_return_invalid_when_title_has_empty_scope() {
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(!result.is_valid());
     assert!(!result.was_bypassed());
@@ -1917,7 +2473,7 @@ fn should_return_invalid_with_empty_scope_in_diagnosis_when_title_has_empty_scop
     let bypass_rule = create_bypass_rule_disabled();
     let config = create_default_config();
 
-    let result = check_pr_title(&pr, &bypass_rule, &config);
+    let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 
     assert!(
         !result.is_valid(),
@@ -1934,3 +2490,1549 @@ fn should_return_invalid_with_empty_scope_in_diagnosis_when_title_has_empty_scop
         diagnosis.issues
     );
 }
+
+// ============================================================
+// Checklist parsing and validation
+// ============================================================
+
+fn checklist_config(required_items: &[&str]) -> ChecklistPolicyConfig {
+    ChecklistPolicyConfig {
+        enabled: true,
+        required_items: required_items.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn should_parse_checked_and_unchecked_task_list_items() {
+    let items = parse_task_list("- [ ] Tests added\n* [x] Docs updated\n+ [X] Changelog entry");
+
+    assert_eq!(
+        items,
+        vec![
+            TaskListItem {
+                text: "Tests added".to_string(),
+                checked: false,
+                depth: 0,
+            },
+            TaskListItem {
+                text: "Docs updated".to_string(),
+                checked: true,
+                depth: 0,
+            },
+            TaskListItem {
+                text: "Changelog entry".to_string(),
+                checked: true,
+                depth: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_parse_ordered_task_list_items() {
+    let items = parse_task_list("1. [x] First\n2) [ ] Second");
+
+    assert_eq!(items.len(), 2);
+    assert!(items[0].checked);
+    assert_eq!(items[1].text, "Second");
+    assert!(!items[1].checked);
+}
+
+#[test]
+fn should_assign_depth_to_nested_task_list_items() {
+    let body = "- [x] Parent\n  - [ ] Child\n      - [ ] Grandchild\n  - [x] Second child\n- [ ] Sibling\n\t- [x] Tab child";
+
+    let depths: Vec<usize> = parse_task_list(body).iter().map(|i| i.depth).collect();
+
+    assert_eq!(depths, vec![0, 1, 2, 1, 0, 1]);
+}
+
+#[test]
+fn should_ignore_non_task_lines_and_empty_checkboxes() {
+    let body = "Plain text [ ] not a task\n- regular bullet\n- [ ]\n-[ ] no space\n- [y] bad box\n- [ ]no space after box";
+
+    assert!(parse_task_list(body).is_empty());
+}
+
+#[test]
+fn should_ignore_task_list_items_inside_code_fences() {
+    let body =
+        "```markdown\n- [ ] Example item\n```\n~~~\n- [ ] Another example\n~~~\n- [x] Real item";
+
+    let items = parse_task_list(body);
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].text, "Real item");
+}
+
+#[test]
+fn should_require_all_items_when_no_required_items_configured() {
+    let pr = create_pull_request(
+        1,
+        "feat: test",
+        Some("- [x] Tests added\n  - [ ] Unit tests\n- [ ] Docs updated"),
+        None,
+    );
+
+    let result = check_required_checklist(&pr, &checklist_config(&[]));
+
+    assert!(!result.is_valid());
+    assert_eq!(
+        result.unchecked_items,
+        vec!["Unit tests".to_string(), "Docs updated".to_string()]
+    );
+    assert!(result.missing_items.is_empty());
+}
+
+#[test]
+fn should_return_valid_when_all_items_checked() {
+    let pr = create_pull_request(
+        1,
+        "feat: test",
+        Some("- [x] Tests added\n  - [x] Unit tests"),
+        None,
+    );
+
+    let result = check_required_checklist(&pr, &checklist_config(&[]));
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_only_require_configured_items() {
+    let pr = create_pull_request(
+        1,
+        "feat: test",
+        Some("- [x] Tests added\n- [ ] Screenshots attached (optional)"),
+        None,
+    );
+
+    let result = check_required_checklist(&pr, &checklist_config(&["TESTS ADDED"]));
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_report_unchecked_and_missing_required_items() {
+    let pr = create_pull_request(
+        1,
+        "feat: test",
+        Some("## Checklist\n\n- [ ] Tests added\n- [x] Docs updated"),
+        None,
+    );
+
+    let result =
+        check_required_checklist(&pr, &checklist_config(&["Tests added", "Changelog entry"]));
+
+    assert!(!result.is_valid());
+    assert_eq!(result.unchecked_items, vec!["Tests added".to_string()]);
+    assert_eq!(result.missing_items, vec!["Changelog entry".to_string()]);
+}
+
+#[test]
+fn should_report_missing_required_items_when_body_is_empty() {
+    let pr = create_pull_request(1, "feat: test", None, None);
+
+    let result = check_required_checklist(&pr, &checklist_config(&["Tests added"]));
+
+    assert!(!result.is_valid());
+    assert_eq!(result.missing_items, vec!["Tests added".to_string()]);
+}
+
+// ============================================================
+// check_unresolved_review_threads
+// ============================================================
+
+fn review_threads(resolved: &[bool]) -> Vec<ReviewThread> {
+    resolved
+        .iter()
+        .enumerate()
+        .map(|(i, is_resolved)| ReviewThread {
+            id: format!("T_{}", i),
+            is_resolved: *is_resolved,
+            is_outdated: false,
+        })
+        .collect()
+}
+
+#[test]
+fn should_pass_review_thread_check_when_all_threads_are_resolved() {
+    let config = ReviewThreadPolicyConfig {
+        enabled: true,
+        max_unresolved: 0,
+    };
+
+    let result = check_unresolved_review_threads(&review_threads(&[true, true]), &config);
+
+    assert!(result.is_valid());
+    assert_eq!(result.unresolved_count, 0);
+    assert_eq!(result.total_count, 2);
+}
+
+#[test]
+fn should_pass_review_thread_check_when_unresolved_count_equals_limit() {
+    let config = ReviewThreadPolicyConfig {
+        enabled: true,
+        max_unresolved: 2,
+    };
+
+    let result = check_unresolved_review_threads(&review_threads(&[false, true, false]), &config);
+
+    assert!(result.is_valid());
+    assert_eq!(result.unresolved_count, 2);
+}
+
+#[test]
+fn should_fail_review_thread_check_when_unresolved_count_exceeds_limit() {
+    let config = ReviewThreadPolicyConfig {
+        enabled: true,
+        max_unresolved: 1,
+    };
+
+    let result = check_unresolved_review_threads(&review_threads(&[false, false, true]), &config);
+
+    assert!(!result.is_valid());
+    assert_eq!(result.unresolved_count, 2);
+    assert_eq!(result.total_count, 3);
+}
+
+#[test]
+fn should_count_outdated_unresolved_threads_as_unresolved() {
+    let threads = vec![ReviewThread {
+        id: "T_1".to_string(),
+        is_resolved: false,
+        is_outdated: true,
+    }];
+
+    let result = check_unresolved_review_threads(&threads, &ReviewThreadPolicyConfig::default());
+
+    assert!(!result.is_valid());
+    assert_eq!(result.unresolved_count, 1);
+}
+
+// ============================================================
+// check_deleted_file_pairings
+// ============================================================
+
+fn changed_file(filename: &str, status: &str) -> PullRequestFile {
+    PullRequestFile {
+        filename: filename.to_string(),
+        additions: 1,
+        deletions: 1,
+        changes: 2,
+        status: status.to_string(),
+    }
+}
+
+fn deleted_files_config(rules: &[(&str, &[&str])]) -> DeletedFilePolicyConfig {
+    DeletedFilePolicyConfig {
+        enabled: true,
+        rules: rules
+            .iter()
+            .map(|(deleted, requires)| DeletedFilePairingRule {
+                deleted: deleted.to_string(),
+                requires: requires.iter().map(|r| r.to_string()).collect(),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_pass_deleted_file_check_when_no_files_are_deleted() {
+    let files = vec![changed_file("src/lib.rs", "modified")];
+    let config = deleted_files_config(&[("src/*", &["docs/*"])]);
+
+    let result = check_deleted_file_pairings(&files, &config);
+
+    assert!(result.is_valid());
+    assert!(result.violations.is_empty());
+}
+
+#[test]
+fn should_pass_deleted_file_check_when_paired_file_is_changed() {
+    let files = vec![
+        changed_file("src/parser.rs", "removed"),
+        changed_file("docs/parser.md", "modified"),
+    ];
+    let config = deleted_files_config(&[("src/*", &["docs/*"])]);
+
+    let result = check_deleted_file_pairings(&files, &config);
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_fail_deleted_file_check_when_paired_file_is_missing() {
+    let files = vec![
+        changed_file("src/parser.rs", "removed"),
+        changed_file("src/lexer.rs", "removed"),
+        changed_file("README.md", "modified"),
+    ];
+    let config = deleted_files_config(&[("src/*", &["docs/*", "tests/*"])]);
+
+    let result = check_deleted_file_pairings(&files, &config);
+
+    assert!(!result.is_valid());
+    assert_eq!(result.violations.len(), 1);
+    assert_eq!(result.violations[0].deleted_pattern, "src/*");
+    assert_eq!(
+        result.violations[0].deleted_files,
+        vec!["src/parser.rs".to_string(), "src/lexer.rs".to_string()]
+    );
+    assert_eq!(
+        result.violations[0].required_patterns,
+        vec!["docs/*".to_string(), "tests/*".to_string()]
+    );
+}
+
+#[test]
+fn should_not_count_triggering_deletions_as_paired_changes() {
+    let files = vec![changed_file("docs/old.md", "removed")];
+    let config = deleted_files_config(&[("docs/*", &["docs/*"])]);
+
+    let result = check_deleted_file_pairings(&files, &config);
+
+    assert!(!result.is_valid());
+}
+
+#[test]
+fn should_accept_deleted_status_for_deleted_files() {
+    let files = vec![changed_file("src/parser.rs", "deleted")];
+    let config = deleted_files_config(&[("src/*", &["docs/*"])]);
+
+    let result = check_deleted_file_pairings(&files, &config);
+
+    assert!(!result.is_valid());
+}
+
+#[test]
+fn should_ignore_deleted_file_rules_without_required_patterns() {
+    let files = vec![changed_file("src/parser.rs", "removed")];
+    let config = deleted_files_config(&[("src/*", &[])]);
+
+    let result = check_deleted_file_pairings(&files, &config);
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_report_each_violated_deleted_file_rule() {
+    let files = vec![
+        changed_file("src/parser.rs", "removed"),
+        changed_file("api/v1.yaml", "removed"),
+        changed_file("tests/parser_tests.rs", "modified"),
+    ];
+    let config = deleted_files_config(&[("src/*", &["tests/*"]), ("api/*", &["CHANGELOG.md"])]);
+
+    let result = check_deleted_file_pairings(&files, &config);
+
+    assert!(!result.is_valid());
+    assert_eq!(result.violations.len(), 1);
+    assert_eq!(result.violations[0].deleted_pattern, "api/*");
+}
+
+// ============================================================
+// check_version_bump
+// ============================================================
+
+#[test]
+fn should_accept_version_bump_matching_required_level() {
+    let result = check_version_bump(
+        &Version::new(1, 4, 2),
+        &Version::new(1, 5, 0),
+        BumpLevel::Minor,
+    );
+
+    assert!(result.is_valid());
+    assert!(result.is_bumped());
+    assert_eq!(result.expected_version, Some(Version::new(1, 5, 0)));
+}
+
+#[test]
+fn should_accept_version_bump_above_required_level() {
+    let result = check_version_bump(
+        &Version::new(1, 4, 2),
+        &Version::new(2, 0, 0),
+        BumpLevel::Patch,
+    );
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_reject_version_bump_below_required_level() {
+    let result = check_version_bump(
+        &Version::new(1, 4, 2),
+        &Version::new(1, 4, 3),
+        BumpLevel::Major,
+    );
+
+    assert!(!result.is_valid());
+    assert_eq!(result.expected_version, Some(Version::new(2, 0, 0)));
+}
+
+#[test]
+fn should_reject_version_bump_that_skips_versions() {
+    let result = check_version_bump(
+        &Version::new(1, 4, 2),
+        &Version::new(1, 7, 0),
+        BumpLevel::Minor,
+    );
+
+    assert!(!result.is_valid());
+    assert_eq!(result.expected_version, Some(Version::new(1, 5, 0)));
+}
+
+#[test]
+fn should_reject_version_that_goes_backwards() {
+    let result = check_version_bump(
+        &Version::new(1, 4, 2),
+        &Version::new(1, 3, 0),
+        BumpLevel::Patch,
+    );
+
+    assert!(!result.is_valid());
+    assert!(result.is_bumped());
+    assert_eq!(result.expected_version, Some(Version::new(1, 4, 3)));
+}
+
+#[test]
+fn should_treat_unchanged_version_as_valid() {
+    let mut new = Version::new(1, 4, 2);
+    new.suffix = Some("-rc.1".to_string());
+
+    let result = check_version_bump(&Version::new(1, 4, 2), &new, BumpLevel::Major);
+
+    assert!(result.is_valid());
+    assert!(!result.is_bumped());
+    assert_eq!(result.expected_version, None);
+}
+
+// Tests for the title character policy
+
+fn create_title_character_policy(
+    max_emoji: Option<usize>,
+    disallowed_characters: &str,
+) -> TitleCharacterPolicyConfig {
+    TitleCharacterPolicyConfig {
+        enabled: true,
+        max_emoji,
+        disallowed_characters: disallowed_characters.to_string(),
+    }
+}
+
+#[test]
+fn should_count_emoji_sequences_once() {
+    assert_eq!(count_emoji(""), 0);
+    assert_eq!(count_emoji("plain ascii text"), 0);
+    // Accented letters, CJK and symbols such as arrows and trademarks are not emoji
+    assert_eq!(count_emoji("café 日本語 → ™ © ±"), 0);
+    assert_eq!(count_emoji("🚀"), 1);
+    assert_eq!(count_emoji("🚀🎉"), 2);
+    // Variation selector, skin tone, ZWJ family, flag and keycap sequences
+    assert_eq!(count_emoji("❤️"), 1);
+    assert_eq!(count_emoji("👍🏽"), 1);
+    assert_eq!(count_emoji("👩‍💻"), 1);
+    assert_eq!(count_emoji("👨‍👩‍👧‍👦"), 1);
+    assert_eq!(count_emoji("🇳🇿🇯🇵"), 2);
+    assert_eq!(count_emoji("1️⃣"), 1);
+    // Digits without a keycap mark are not emoji
+    assert_eq!(count_emoji("v1.2 #42 *"), 0);
+}
+
+#[test]
+fn should_accept_title_within_character_limits() {
+    let config = create_title_character_policy(Some(1), "<>");
+
+    assert!(diagnose_title_characters("feat: add login", &config).is_none());
+    assert!(diagnose_title_characters("feat: add login ✨", &config).is_none());
+}
+
+#[test]
+fn should_only_check_description_after_prefix() {
+    // `!` and the scope belong to the prefix and are never flagged
+    let config = create_title_character_policy(Some(0), "!()");
+
+    assert!(diagnose_title_characters("feat(auth)!: drop v1 tokens", &config).is_none());
+}
+
+#[test]
+fn should_flag_too_many_emoji_and_suggest_keeping_the_first() {
+    let config = create_title_character_policy(Some(1), "");
+
+    let diagnosis = diagnose_title_characters("fix: 🐛 crash on 🚀 launch 🎉", &config).unwrap();
+
+    assert_eq!(
+        diagnosis.issues,
+        vec![TitleIssue::TooManyEmoji { found: 3, max: 1 }]
+    );
+    assert_eq!(
+        diagnosis.suggested_fix.as_deref(),
+        Some("fix: 🐛 crash on launch")
+    );
+}
+
+#[test]
+fn should_flag_any_emoji_when_limit_is_zero() {
+    let config = create_title_character_policy(Some(0), "");
+
+    let diagnosis = diagnose_title_characters("docs: update readme 👨‍👩‍👧", &config).unwrap();
+
+    assert_eq!(
+        diagnosis.issues,
+        vec![TitleIssue::TooManyEmoji { found: 1, max: 0 }]
+    );
+    assert_eq!(
+        diagnosis.suggested_fix.as_deref(),
+        Some("docs: update readme")
+    );
+    assert!(diagnosis.issues[0]
+        .to_string()
+        .starts_with("Emoji are not allowed in the description (found 1)"));
+}
+
+#[test]
+fn should_flag_disallowed_characters_once_each() {
+    let config = create_title_character_policy(None, "<>`");
+
+    let diagnosis = diagnose_title_characters("feat: render `<b>` and <i>", &config).unwrap();
+
+    assert_eq!(
+        diagnosis.issues,
+        vec![TitleIssue::DisallowedCharacters {
+            found: "`<>".to_string()
+        }]
+    );
+    assert_eq!(
+        diagnosis.suggested_fix.as_deref(),
+        Some("feat: render b and i")
+    );
+}
+
+#[test]
+fn should_not_suggest_fix_when_description_would_be_empty() {
+    let config = create_title_character_policy(Some(0), "");
+
+    let diagnosis = diagnose_title_characters("chore: 🎉", &config).unwrap();
+
+    assert_eq!(diagnosis.suggested_fix, None);
+}
+
+#[test]
+fn should_fail_title_check_when_character_policy_is_broken() {
+    let pr = create_pull_request(1, "feat: add login 🚀🎉", None, None);
+    let config = CurrentPullRequestValidationConfiguration {
+        title_characters: create_title_character_policy(Some(1), ""),
+        ..create_default_config()
+    };
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    assert!(!result.is_valid());
+    let diagnosis = result.diagnosis.unwrap();
+    assert_eq!(
+        diagnosis.issues,
+        vec![TitleIssue::TooManyEmoji { found: 2, max: 1 }]
+    );
+    assert_eq!(
+        diagnosis.suggested_fix.as_deref(),
+        Some("feat: add login 🚀")
+    );
+}
+
+#[test]
+fn should_ignore_character_policy_when_disabled() {
+    let pr = create_pull_request(1, "feat: add login 🚀🎉", None, None);
+    let config = CurrentPullRequestValidationConfiguration {
+        title_characters: TitleCharacterPolicyConfig {
+            enabled: false,
+            ..create_title_character_policy(Some(0), "")
+        },
+        ..create_default_config()
+    };
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_combine_prefix_and_character_issues() {
+    let pr = create_pull_request(1, "FEAT: add login 🚀🎉", None, None);
+    let config = CurrentPullRequestValidationConfiguration {
+        title_characters: create_title_character_policy(Some(0), ""),
+        ..create_default_config()
+    };
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    let diagnosis = result.diagnosis.unwrap();
+    assert!(diagnosis.issues.contains(&TitleIssue::UppercaseType {
+        found: "FEAT".to_string()
+    }));
+    assert!(diagnosis
+        .issues
+        .contains(&TitleIssue::TooManyEmoji { found: 2, max: 0 }));
+    assert_eq!(diagnosis.suggested_fix.as_deref(), Some("feat: add login"));
+}
+
+// Tests for the title lint policy
+
+fn create_title_lint_policy() -> TitleLintPolicyConfig {
+    TitleLintPolicyConfig {
+        enabled: true,
+        subject_case: Some(SubjectCase::Lower),
+        forbid_trailing_period: true,
+        header_max_length: Some(50),
+        allowed_types: vec!["feat".to_string(), "fix".to_string(), "chore".to_string()],
+    }
+}
+
+#[test]
+fn should_accept_title_following_lint_rules() {
+    let config = create_title_lint_policy();
+
+    assert!(diagnose_title_lint("feat: add login", &config).is_none());
+    assert!(diagnose_title_lint("fix(auth)!: drop v1 tokens", &config).is_none());
+    // Only the first letter is checked, and digits and symbols satisfy either case
+    assert!(diagnose_title_lint("chore: bump Rust to 1.80", &config).is_none());
+    assert!(diagnose_title_lint("chore: `cargo` cache", &config).is_none());
+}
+
+#[test]
+fn should_report_each_broken_lint_rule() {
+    let config = create_title_lint_policy();
+
+    let diagnosis = diagnose_title_lint(
+        "docs(readme): Describe the installation steps in more detail.",
+        &config,
+    )
+    .unwrap();
+
+    assert_eq!(
+        diagnosis.issues,
+        vec![
+            TitleIssue::HeaderTooLong {
+                length: 61,
+                max: 50
+            },
+            TitleIssue::TypeNotAllowed {
+                found: "docs".to_string(),
+                allowed: vec!["feat".to_string(), "fix".to_string(), "chore".to_string()],
+            },
+            TitleIssue::TrailingPeriod,
+            TitleIssue::WrongSubjectCase {
+                expected: SubjectCase::Lower
+            },
+        ]
+    );
+    // Neither the length nor the type can be fixed automatically
+    assert_eq!(diagnosis.suggested_fix, None);
+    assert_eq!(
+        diagnosis.issues[1].to_string(),
+        "The type `docs` is not allowed in this repository \u{2014} use one of `feat`, `fix`, `chore`."
+    );
+}
+
+#[test]
+fn should_suggest_sentence_case_without_trailing_period() {
+    let config = TitleLintPolicyConfig {
+        subject_case: Some(SubjectCase::Sentence),
+        ..create_title_lint_policy()
+    };
+
+    let diagnosis = diagnose_title_lint("fix: handle empty config...", &config).unwrap();
+
+    assert_eq!(
+        diagnosis.issues,
+        vec![
+            TitleIssue::TrailingPeriod,
+            TitleIssue::WrongSubjectCase {
+                expected: SubjectCase::Sentence
+            },
+        ]
+    );
+    assert_eq!(
+        diagnosis.suggested_fix.as_deref(),
+        Some("fix: Handle empty config")
+    );
+}
+
+#[test]
+fn should_only_check_enabled_lint_rules() {
+    let config = TitleLintPolicyConfig {
+        enabled: true,
+        ..TitleLintPolicyConfig::default()
+    };
+
+    assert!(diagnose_title_lint(
+        "docs: Describe the installation steps in more detail.",
+        &config
+    )
+    .is_none());
+}
+
+#[test]
+fn should_skip_type_and_subject_rules_without_colon() {
+    let config = create_title_lint_policy();
+
+    // The title pattern reports the missing prefix instead
+    assert!(diagnose_title_lint("Add login.", &config).is_none());
+}
+
+#[test]
+fn should_fail_title_check_when_lint_policy_is_broken() {
+    let pr = create_pull_request(1, "feat: Add login.", None, None);
+    let config = CurrentPullRequestValidationConfiguration {
+        title_lint: create_title_lint_policy(),
+        ..create_default_config()
+    };
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    assert!(!result.is_valid());
+    let diagnosis = result.diagnosis.unwrap();
+    assert_eq!(
+        diagnosis.issues,
+        vec![
+            TitleIssue::TrailingPeriod,
+            TitleIssue::WrongSubjectCase {
+                expected: SubjectCase::Lower
+            },
+        ]
+    );
+    assert_eq!(diagnosis.suggested_fix.as_deref(), Some("feat: add login"));
+}
+
+#[test]
+fn should_ignore_lint_policy_when_disabled() {
+    let pr = create_pull_request(1, "feat: Add login.", None, None);
+    let config = CurrentPullRequestValidationConfiguration {
+        title_lint: TitleLintPolicyConfig {
+            enabled: false,
+            ..create_title_lint_policy()
+        },
+        ..create_default_config()
+    };
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_combine_character_and_lint_issues() {
+    let pr = create_pull_request(1, "feat: Add login 🚀.", None, None);
+    let config = CurrentPullRequestValidationConfiguration {
+        title_characters: create_title_character_policy(Some(0), ""),
+        title_lint: create_title_lint_policy(),
+        ..create_default_config()
+    };
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    let diagnosis = result.diagnosis.unwrap();
+    assert_eq!(
+        diagnosis.issues,
+        vec![
+            TitleIssue::TooManyEmoji { found: 1, max: 0 },
+            TitleIssue::TrailingPeriod,
+            TitleIssue::WrongSubjectCase {
+                expected: SubjectCase::Lower
+            },
+        ]
+    );
+    assert_eq!(diagnosis.suggested_fix.as_deref(), Some("feat: add login"));
+}
+
+#[test]
+fn should_apply_lint_fixes_to_prefix_fix() {
+    let pr = create_pull_request(1, "FEAT: Add login.", None, None);
+    let config = CurrentPullRequestValidationConfiguration {
+        title_lint: create_title_lint_policy(),
+        ..create_default_config()
+    };
+
+    let result = check_pr_title(
+        &pr,
+        pr.author.as_ref(),
+        &create_bypass_rule_disabled(),
+        &config,
+    );
+
+    let diagnosis = result.diagnosis.unwrap();
+    assert!(diagnosis.issues.contains(&TitleIssue::UppercaseType {
+        found: "FEAT".to_string()
+    }));
+    // The type is compared ignoring case, so it is not reported twice
+    assert!(!diagnosis
+        .issues
+        .iter()
+        .any(|issue| matches!(issue, TitleIssue::TypeNotAllowed { .. })));
+    assert!(diagnosis.issues.contains(&TitleIssue::TrailingPeriod));
+    assert_eq!(diagnosis.suggested_fix.as_deref(), Some("feat: add login"));
+}
+
+// ============================================================
+// check_suspicious_files
+// ============================================================
+
+fn added_file(filename: &str, additions: u32) -> PullRequestFile {
+    PullRequestFile {
+        filename: filename.to_string(),
+        additions,
+        deletions: 0,
+        changes: additions,
+        status: "added".to_string(),
+    }
+}
+
+fn suspicious_files_config() -> SuspiciousFilePolicyConfig {
+    SuspiciousFilePolicyConfig {
+        enabled: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_pass_suspicious_file_check_for_ordinary_files() {
+    let files = vec![
+        added_file("src/lib.rs", 120),
+        changed_file("README.md", "modified"),
+    ];
+
+    let result = check_suspicious_files(&files, &suspicious_files_config());
+
+    assert!(result.is_valid());
+    assert!(result.files.is_empty());
+}
+
+#[test]
+fn should_flag_secret_and_binary_files_in_any_directory() {
+    let files = vec![
+        added_file("deploy/keys/id_rsa", 27),
+        added_file("services/api/.env", 4),
+        added_file("dist/release.zip", 0),
+    ];
+
+    let result = check_suspicious_files(&files, &suspicious_files_config());
+
+    assert!(!result.is_valid());
+    let reasons: Vec<_> = result.files.iter().map(|f| f.reason.clone()).collect();
+    assert_eq!(
+        reasons,
+        vec![
+            SuspiciousFileReason::Secret("id_rsa".to_string()),
+            SuspiciousFileReason::Secret(".env".to_string()),
+            SuspiciousFileReason::Binary("*.zip".to_string()),
+        ]
+    );
+    assert_eq!(result.files[0].filename, "deploy/keys/id_rsa");
+}
+
+#[test]
+fn should_flag_files_adding_more_lines_than_the_limit() {
+    let config = SuspiciousFilePolicyConfig {
+        max_file_lines: 100,
+        ..suspicious_files_config()
+    };
+    let files = vec![
+        added_file("data/fixtures.json", 101),
+        added_file("src/lib.rs", 100),
+    ];
+
+    let result = check_suspicious_files(&files, &config);
+
+    assert_eq!(result.files.len(), 1);
+    assert_eq!(result.files[0].filename, "data/fixtures.json");
+    assert_eq!(result.files[0].reason, SuspiciousFileReason::TooLarge(101));
+    assert_eq!(result.files[0].reason.to_string(), "adds 101 lines");
+}
+
+#[test]
+fn should_not_limit_file_lines_when_max_file_lines_is_zero() {
+    let config = SuspiciousFilePolicyConfig {
+        max_file_lines: 0,
+        ..suspicious_files_config()
+    };
+    let files = vec![added_file("data/fixtures.json", 100_000)];
+
+    let result = check_suspicious_files(&files, &config);
+
+    assert!(result.is_valid());
+}
+
+#[test]
+fn should_ignore_deleted_suspicious_files() {
+    let files = vec![changed_file("certs/server.pem", "removed")];
+
+    let result = check_suspicious_files(&files, &suspicious_files_config());
+
+    assert!(result.is_valid());
+}
+
+// ============================================================
+// check_base_branch
+// ============================================================
+
+fn base_branch_pr(title: &str, head_ref: &str, base_ref: &str) -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: title.to_string(),
+        draft: false,
+        body: None,
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: base_ref.to_string(),
+        head_ref: head_ref.to_string(),
+    }
+}
+
+fn base_branch_config() -> BaseBranchPolicyConfig {
+    BaseBranchPolicyConfig {
+        enabled: true,
+        rules: vec![
+            BaseBranchRule {
+                title_types: vec!["hotfix".to_string()],
+                branch_prefixes: vec!["hotfix/".to_string()],
+                allowed_bases: vec!["release/*".to_string()],
+            },
+            BaseBranchRule {
+                title_types: vec!["feat".to_string()],
+                allowed_bases: vec!["develop".to_string()],
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_pass_base_branch_check_when_base_is_allowed() {
+    let pr = base_branch_pr("hotfix(auth)!: stop the crash", "crash", "release/1.4");
+
+    let result = check_base_branch(&pr, &base_branch_config());
+
+    assert!(result.is_valid());
+    assert_eq!(result.base_branch, "release/1.4");
+    assert_eq!(result.allowed_bases, vec!["release/*"]);
+}
+
+#[test]
+fn should_fail_base_branch_check_when_base_is_not_allowed() {
+    let pr = base_branch_pr("feat: add login", "login", "main");
+
+    let result = check_base_branch(&pr, &base_branch_config());
+
+    assert!(!result.is_valid());
+    assert_eq!(result.base_branch, "main");
+    assert_eq!(result.allowed_bases, vec!["develop"]);
+}
+
+#[test]
+fn should_match_base_branch_rules_by_head_branch_prefix() {
+    let pr = base_branch_pr("fix: stop the crash", "hotfix/crash", "develop");
+
+    let result = check_base_branch(&pr, &base_branch_config());
+
+    assert!(!result.is_valid());
+    assert_eq!(result.allowed_bases, vec!["release/*"]);
+}
+
+#[test]
+fn should_pass_base_branch_check_when_no_rule_applies() {
+    let pr = base_branch_pr("docs: explain login", "docs", "main");
+
+    let result = check_base_branch(&pr, &base_branch_config());
+
+    assert!(result.is_valid());
+    assert!(result.allowed_bases.is_empty());
+}
+
+#[test]
+fn should_skip_base_branch_check_when_base_is_unknown() {
+    let pr = base_branch_pr("feat: add login", "login", "");
+
+    let result = check_base_branch(&pr, &base_branch_config());
+
+    assert!(result.is_valid());
+    assert!(result.validation.skip_reason().is_some());
+}
+
+// ============================================================
+// check_exclusive_labels
+// ============================================================
+
+fn exclusive_labels_config() -> ExclusiveLabelsPolicyConfig {
+    ExclusiveLabelsPolicyConfig {
+        enabled: true,
+        groups: vec![
+            ExclusiveLabelGroup {
+                name: "priority".to_string(),
+                labels: vec!["priority: *".to_string()],
+            },
+            ExclusiveLabelGroup {
+                name: "release".to_string(),
+                labels: vec![
+                    "major".to_string(),
+                    "minor".to_string(),
+                    "patch".to_string(),
+                ],
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+fn label_names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn test_check_exclusive_labels_passes_with_one_label_per_group() {
+    let labels = label_names(&["priority: high", "minor", "bug"]);
+
+    let result = check_exclusive_labels(&labels, &exclusive_labels_config());
+
+    assert!(result.is_valid());
+    assert!(result.conflicts.is_empty());
+}
+
+#[test]
+fn test_check_exclusive_labels_lists_every_conflicting_group() {
+    let labels = label_names(&["Priority: High", "major", "priority: low", "Patch"]);
+
+    let result = check_exclusive_labels(&labels, &exclusive_labels_config());
+
+    assert!(!result.is_valid());
+    assert_eq!(result.conflicts.len(), 2);
+    assert_eq!(result.conflicts[0].group, "priority");
+    assert_eq!(
+        result.conflicts[0].labels,
+        vec!["Priority: High", "priority: low"]
+    );
+    assert_eq!(result.conflicts[1].group, "release");
+    assert_eq!(result.conflicts[1].labels, vec!["major", "Patch"]);
+}
+
+#[test]
+fn test_check_exclusive_labels_passes_without_groups() {
+    let config = ExclusiveLabelsPolicyConfig {
+        enabled: true,
+        ..Default::default()
+    };
+
+    let result = check_exclusive_labels(&label_names(&["major", "minor"]), &config);
+
+    assert!(result.is_valid());
+}
+
+// ============================================================
+// check_label_policy
+// ============================================================
+
+fn label_policy_config() -> LabelPolicyConfig {
+    LabelPolicyConfig {
+        enabled: true,
+        required_labels: vec!["team: *".to_string(), "reviewed".to_string()],
+        forbidden_labels: vec!["do-not-merge".to_string(), "blocked: *".to_string()],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_check_label_policy_passes_with_required_and_without_forbidden_labels() {
+    let labels = label_names(&["Team: Core", "Reviewed", "bug"]);
+
+    let result = check_label_policy(&labels, &label_policy_config());
+
+    assert!(result.is_valid());
+    assert!(result.missing_labels.is_empty());
+    assert!(result.forbidden_labels.is_empty());
+}
+
+#[test]
+fn test_check_label_policy_lists_missing_required_labels() {
+    let labels = label_names(&["reviewed"]);
+
+    let result = check_label_policy(&labels, &label_policy_config());
+
+    assert!(!result.is_valid());
+    assert_eq!(result.missing_labels, vec!["team: *"]);
+    assert!(result.forbidden_labels.is_empty());
+}
+
+#[test]
+fn test_check_label_policy_lists_applied_forbidden_labels() {
+    let labels = label_names(&["team: core", "reviewed", "Do-Not-Merge", "blocked: legal"]);
+
+    let result = check_label_policy(&labels, &label_policy_config());
+
+    assert!(!result.is_valid());
+    assert!(result.missing_labels.is_empty());
+    assert_eq!(
+        result.forbidden_labels,
+        vec!["Do-Not-Merge", "blocked: legal"]
+    );
+}
+
+#[test]
+fn test_check_label_policy_passes_without_rules() {
+    let config = LabelPolicyConfig {
+        enabled: true,
+        ..Default::default()
+    };
+
+    let result = check_label_policy(&label_names(&["do-not-merge"]), &config);
+
+    assert!(result.is_valid());
+}
+
+// ============================================================
+// check_signed_commits
+// ============================================================
+
+fn commit(sha: &str, verified: bool) -> CommitVerification {
+    CommitVerification {
+        sha: sha.to_string(),
+        verified,
+        reason: if verified { "valid" } else { "unsigned" }.to_string(),
+    }
+}
+
+#[test]
+fn test_check_signed_commits_lists_unverified_commits() {
+    let commits = vec![
+        commit("aaa111", true),
+        commit("bbb222", false),
+        commit("ccc333", false),
+    ];
+
+    let result = check_signed_commits(&commits, None, &BypassRule::default());
+
+    assert!(!result.is_valid());
+    let shas: Vec<&str> = result
+        .unverified_commits
+        .iter()
+        .map(|c| c.sha.as_str())
+        .collect();
+    assert_eq!(shas, vec!["bbb222", "ccc333"]);
+}
+
+#[test]
+fn test_check_signed_commits_passes_when_all_commits_verified() {
+    let commits = vec![commit("aaa111", true), commit("bbb222", true)];
+
+    let result = check_signed_commits(&commits, None, &BypassRule::default());
+
+    assert!(result.is_valid());
+    assert!(result.unverified_commits.is_empty());
+}
+
+#[test]
+fn test_check_signed_commits_bypassed_for_allowed_author() {
+    let commits = vec![commit("bbb222", false)];
+    let author = create_user(1, "release-bot");
+    let bypass_rule = BypassRule::new(true, vec!["release-bot".to_string()]);
+
+    let result = check_signed_commits(&commits, Some(&author), &bypass_rule);
+
+    assert!(result.is_valid());
+    assert_eq!(
+        result.validation.bypass_info().map(|b| b.rule_type.clone()),
+        Some(BypassRuleType::SignedCommits)
+    );
+}
+
+// ============================================================
+// check_revert_reference
+// ============================================================
+
+#[test]
+fn should_detect_revert_titles() {
+    assert!(is_revert_title("revert: feat(auth): add SSO"));
+    assert!(is_revert_title("Revert(auth): add SSO"));
+    assert!(is_revert_title("Revert \"feat(auth): add SSO\""));
+    assert!(!is_revert_title("feat: add a revert button"));
+    assert!(!is_revert_title("Reverting the SSO change"));
+}
+
+#[test]
+fn should_skip_revert_reference_check_for_other_prs() {
+    let pr = create_pull_request(1, "feat: add SSO", None, None);
+
+    let result = check_revert_reference(&pr, None, &create_bypass_rule_disabled());
+
+    assert!(result.is_valid());
+    assert_eq!(
+        result.validation.skip_reason(),
+        Some("The PR is not a revert")
+    );
+}
+
+#[test]
+fn should_fail_revert_without_reference() {
+    let pr = create_pull_request(
+        2,
+        "revert: feat: add SSO",
+        Some("SSO broke sign-in on 20250601, see the deadbeef dashboard."),
+        None,
+    );
+
+    let result = check_revert_reference(&pr, None, &create_bypass_rule_disabled());
+
+    assert!(!result.is_valid());
+    assert!(result.references.is_empty());
+}
+
+#[test]
+fn should_fail_revert_without_body() {
+    let pr = create_pull_request(2, "Revert \"feat: add SSO\"", None, None);
+
+    let result = check_revert_reference(&pr, None, &create_bypass_rule_disabled());
+
+    assert!(!result.is_valid());
+}
+
+#[test]
+fn should_collect_revert_references_in_every_format() {
+    let body = "Reverts owner/repo#12 and #13.\n\
+        See https://github.com/owner/repo/pull/14 and commit A1B2C3D4E5.\n\
+        Also https://github.com/owner/repo/commit/0f1e2d3c, again #13.";
+    let pr = create_pull_request(2, "revert: feat: add SSO", Some(body), None);
+
+    let result = check_revert_reference(&pr, None, &create_bypass_rule_disabled());
+
+    assert!(result.is_valid());
+    assert_eq!(
+        result.references,
+        vec![
+            "owner/repo#12",
+            "#13",
+            "https://github.com/owner/repo/pull/14",
+            "a1b2c3d4e5",
+            "https://github.com/owner/repo/commit/0f1e2d3c",
+        ]
+    );
+}
+
+#[test]
+fn should_return_bypassed_when_user_can_bypass_revert_reference() {
+    let author = create_user(1, "release-manager");
+    let pr = create_pull_request(2, "revert: feat: add SSO", None, Some(author.clone()));
+
+    let result = check_revert_reference(
+        &pr,
+        Some(&author),
+        &create_bypass_rule_enabled_for_users(vec!["release-manager"]),
+    );
+
+    assert!(result.is_valid());
+    assert_eq!(
+        result.validation.bypass_info().map(|b| b.rule_type.clone()),
+        Some(BypassRuleType::RevertReference)
+    );
+}
+
+#[test]
+fn should_pass_pr_that_is_not_behind_its_base() {
+    let config = UpToDatePolicyConfig {
+        enabled: true,
+        ..Default::default()
+    };
+
+    let result = check_up_to_date("main", 0, &config);
+
+    assert!(result.is_valid());
+    assert_eq!(result.base_branch, "main");
+    assert_eq!(result.commits_behind, 0);
+}
+
+#[test]
+fn should_fail_pr_that_is_behind_by_the_threshold() {
+    let config = UpToDatePolicyConfig {
+        enabled: true,
+        behind_by_threshold: 3,
+        ..Default::default()
+    };
+
+    assert!(check_up_to_date("main", 2, &config).is_valid());
+    assert!(!check_up_to_date("main", 3, &config).is_valid());
+    assert!(!check_up_to_date("main", 30, &config).is_valid());
+}
+
+#[test]
+fn should_treat_zero_threshold_as_one_commit() {
+    let config = UpToDatePolicyConfig {
+        enabled: true,
+        behind_by_threshold: 0,
+        ..Default::default()
+    };
+
+    assert!(check_up_to_date("main", 0, &config).is_valid());
+    assert!(!check_up_to_date("main", 1, &config).is_valid());
+}
+
+// ============================================================
+// check_required_footers
+// ============================================================
+
+fn footer_config(required: &[(&str, Option<&str>)]) -> FooterPolicyConfig {
+    FooterPolicyConfig {
+        enabled: true,
+        required: required
+            .iter()
+            .map(|(token, pattern)| RequiredFooter {
+                token: token.to_string(),
+                pattern: pattern.map(str::to_string),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn should_parse_footers_from_the_last_paragraph() {
+    let body = "Fixes the parser.\n\nSigned-off-by: Jane <jane@example.com>\nBREAKING CHANGE: the\n  config format changed\nRefs #42\n\n";
+
+    let footers = parse_footers(body);
+
+    let parsed: Vec<(&str, &str)> = footers
+        .iter()
+        .map(|footer| (footer.token.as_str(), footer.value.as_str()))
+        .collect();
+    assert_eq!(
+        parsed,
+        vec![
+            ("Signed-off-by", "Jane <jane@example.com>"),
+            ("BREAKING CHANGE", "the\nconfig format changed"),
+            ("Refs", "42"),
+        ]
+    );
+}
+
+#[test]
+fn should_not_parse_footers_from_a_prose_paragraph() {
+    assert!(parse_footers("Signed-off-by: Jane\nThis line is prose.").is_empty());
+    assert!(parse_footers("Signed-off-by: Jane\n\nThe last paragraph is prose.").is_empty());
+    assert!(parse_footers("").is_empty());
+}
+
+#[test]
+fn should_pass_when_required_footers_are_present() {
+    let pr = create_pull_request(
+        1,
+        "feat: test",
+        Some("Body.\r\n\r\nsigned-off-by: Jane <jane@example.com>\r\nReviewed-by: Alex"),
+        None,
+    );
+
+    let result = check_required_footers(
+        &pr,
+        &footer_config(&[("Signed-off-by", Some("<.+@.+>")), ("Reviewed-by", None)]),
+    );
+
+    assert!(result.is_valid());
+    assert!(result.missing.is_empty());
+}
+
+#[test]
+fn should_report_missing_footers_and_values_not_matching_their_pattern() {
+    let pr = create_pull_request(1, "feat: test", Some("Body.\n\nSigned-off-by: Jane"), None);
+
+    let result = check_required_footers(
+        &pr,
+        &footer_config(&[("Signed-off-by", Some("<.+@.+>")), ("Reviewed-by", None)]),
+    );
+
+    assert!(!result.is_valid());
+    assert_eq!(
+        result.missing,
+        vec!["Signed-off-by".to_string(), "Reviewed-by".to_string()]
+    );
+}
+
+#[test]
+fn should_report_footer_with_invalid_pattern_as_missing() {
+    let pr = create_pull_request(1, "feat: test", Some("Signed-off-by: Jane"), None);
+
+    let result = check_required_footers(&pr, &footer_config(&[("Signed-off-by", Some("(jane"))]));
+
+    assert!(!result.is_valid());
+    assert_eq!(result.missing, vec!["Signed-off-by".to_string()]);
+}
+
+// ============================================================
+// check_descriptive_title
+// ============================================================
+
+#[test]
+fn should_fail_descriptive_title_check_when_title_is_the_branch_name() {
+    for title in [
+        "feature/add-login",
+        "Feature/add login",
+        "Add login",
+        "feat: add_login",
+        "fix(auth): Add.Login",
+    ] {
+        let pr = base_branch_pr(title, "feature/add-login", "main");
+
+        let result = check_descriptive_title(&pr);
+
+        assert!(!result.is_valid(), "title `{}` should fail", title);
+        assert_eq!(result.head_branch, "feature/add-login");
+    }
+}
+
+#[test]
+fn should_pass_descriptive_title_check_when_title_describes_the_change() {
+    for title in [
+        "feat: add login with single sign-on",
+        "Add login page",
+        "feature",
+    ] {
+        let pr = base_branch_pr(title, "feature/add-login", "main");
+
+        assert!(
+            check_descriptive_title(&pr).is_valid(),
+            "title `{}` should pass",
+            title
+        );
+    }
+}
+
+#[test]
+fn should_skip_descriptive_title_check_when_head_branch_is_unknown() {
+    let pr = base_branch_pr("add-login", "", "main");
+
+    let result = check_descriptive_title(&pr);
+
+    assert!(result.is_valid());
+    assert!(result.validation.skip_reason().is_some());
+}
+
+// ============================================================
+// check_change_types
+// ============================================================
+
+fn commits(messages: &[&str]) -> Vec<PullRequestCommit> {
+    messages
+        .iter()
+        .map(|message| PullRequestCommit {
+            sha: String::new(),
+            message: message.to_string(),
+        })
+        .collect()
+}
+
+fn change_types_config(max_change_types: usize, ignored_types: &[&str]) -> ChangeTypesPolicyConfig {
+    ChangeTypesPolicyConfig {
+        enabled: true,
+        max_change_types,
+        ignored_types: ignored_types.iter().map(|t| t.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn should_fail_change_types_check_when_pr_mixes_too_many_types() {
+    let commits = commits(&[
+        "feat(auth): add login",
+        "fix: handle empty passwords",
+        "refactor!: extract the session store",
+        "docs: describe login",
+    ]);
+
+    let result = check_change_types("feat: add login", &commits, &change_types_config(3, &[]));
+
+    assert!(!result.is_valid());
+    assert_eq!(result.change_types, vec!["feat", "fix", "refactor", "docs"]);
+    assert_eq!(result.max_change_types, 3);
+}
+
+#[test]
+fn should_count_each_change_type_once_ignoring_case() {
+    let commits = commits(&["Fix: first", "fix(ui): second", "FIX: third"]);
+
+    let result = check_change_types("fix: several fixes", &commits, &change_types_config(1, &[]));
+
+    assert!(result.is_valid());
+    assert_eq!(result.change_types, vec!["fix"]);
+}
+
+#[test]
+fn should_ignore_commits_without_a_conventional_commit_type() {
+    let commits = commits(&[
+        "Merge branch 'main' into login",
+        "WIP: try things",
+        "Update README.md",
+        "feat: add login\n\nfix: this line is not the subject",
+    ]);
+
+    let result = check_change_types("Add login", &commits, &change_types_config(1, &[]));
+
+    assert!(result.is_valid());
+    assert_eq!(result.change_types, vec!["feat"]);
+}
+
+#[test]
+fn should_not_count_ignored_change_types() {
+    let commits = commits(&[
+        "fix: handle empty passwords",
+        "test: cover login",
+        "docs: login",
+    ]);
+
+    let result = check_change_types(
+        "feat: add login",
+        &commits,
+        &change_types_config(2, &["Test", "docs"]),
+    );
+
+    assert!(result.is_valid());
+    assert_eq!(result.change_types, vec!["feat", "fix"]);
+}
+
+#[test]
+fn should_count_the_title_type_without_commits() {
+    let result = check_change_types("feat: add login", &[], &change_types_config(0, &[]));
+
+    assert!(!result.is_valid());
+    assert_eq!(result.change_types, vec!["feat"]);
+
+    let result = check_change_types("Add login", &[], &change_types_config(0, &[]));
+
+    assert!(result.is_valid());
+    assert!(result.change_types.is_empty());
+}
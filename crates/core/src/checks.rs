@@ -10,15 +10,26 @@
 //! and can be merged.
 
 use crate::{
-    config::{BypassRule, CurrentPullRequestValidationConfiguration, VALID_PR_TYPES},
+    config::{
+        pattern_matches, BaseBranchPolicyConfig, BypassRule, ChangeTypesPolicyConfig,
+        ChecklistPolicyConfig, CurrentPullRequestValidationConfiguration, DeletedFilePolicyConfig,
+        ExclusiveLabelsPolicyConfig, FooterPolicyConfig, LabelPolicyConfig,
+        ReviewThreadPolicyConfig, SubjectCase, SuspiciousFilePolicyConfig,
+        TitleCharacterPolicyConfig, TitleLintPolicyConfig, UpToDatePolicyConfig, WipCheckConfig,
+        WorkItemPatterns, CONVENTIONAL_COMMIT_REGEX, VALID_PR_TYPES,
+    },
     size::PrSizeInfo,
     validation_result::{BypassInfo, BypassRuleType, ValidationResult},
+    version::{BumpLevel, Version},
+};
+use merge_warden_developer_platforms::models::{
+    CommitVerification, PullRequest, PullRequestCommit, PullRequestFile, ReviewThread, User,
 };
-use merge_warden_developer_platforms::models::{PullRequest, PullRequestFile, User};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::sync::OnceLock;
+use tracing::{debug, warn};
 
 /// Compiled once at first use. Handles all four supported closing-keyword formats:
 /// `#NNN`, `GH-NNN`, full GitHub URL, and `owner/repo#NNN` (including dots in names).
@@ -49,6 +60,44 @@ fn any_issue_regex() -> &'static Regex {
     })
 }
 
+/// Compiled once at first use. Matches a line that starts with an issue reference
+/// keyword, such as the `Fixes #` placeholder of a pull request template.
+static TEMPLATE_REFERENCE_LINE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Returns the compiled template reference line regex, initialising it on first call.
+fn template_reference_line_regex() -> &'static Regex {
+    TEMPLATE_REFERENCE_LINE_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)^(fixes|closes|resolves|references|relates\s+to)\b")
+            .expect("TEMPLATE_REFERENCE_LINE_REGEX is a valid regex")
+    })
+}
+
+/// Compiled once at first use. Matches a GitHub issue URL, capturing the owner, the
+/// repository and the issue number.
+static GITHUB_ISSUE_URL_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Returns the compiled GitHub issue URL regex, initialising it on first call.
+fn github_issue_url_regex() -> &'static Regex {
+    GITHUB_ISSUE_URL_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)^https://github\.com/([^/\s]+)/([^/\s]+)/issues/(\d+)$")
+            .expect("GITHUB_ISSUE_URL_REGEX is a valid regex")
+    })
+}
+
+/// Compiled once at first use. Matches a reference to a reverted change: a GitHub PR or
+/// commit URL, `owner/repo#NNN`, `#NNN` or a commit SHA of 7 to 40 hex characters.
+static REVERT_REFERENCE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Returns the compiled revert reference regex, initialising it on first call.
+fn revert_reference_regex() -> &'static Regex {
+    REVERT_REFERENCE_REGEX.get_or_init(|| {
+        Regex::new(
+            r"(?i)(https://github\.com/[^/\s]+/[^/\s]+/(?:pull|commit)/[0-9a-f]+)|([a-z0-9_.-]+/[a-z0-9_.-]+#\d+)|(?:^|[^\w/&])#(\d+)\b|\b([0-9a-f]{7,40})\b",
+        )
+        .expect("REVERT_REFERENCE_REGEX is a valid regex")
+    })
+}
+
 #[cfg(test)]
 #[path = "check_tests.rs"]
 mod tests;
@@ -257,6 +306,147 @@ impl TitleValidationResult {
     }
 }
 
+/// The outcome of a work item reference check.
+///
+/// This type is returned by [`check_work_item_reference`] and
+/// `MergeWarden::check_work_item_reference`. Alongside the plain
+/// [`crate::validation_result::ValidationResult`] it records which of the configured
+/// work item patterns matched, so that callers can report which tracker satisfied
+/// the check.
+///
+/// `matched_pattern` is:
+/// - `Some` when a reference was found — contains the first pattern that matched. The
+///   check still fails when fewer references were found than required.
+/// - `None` when no reference was found, or when validation was bypassed
+///
+/// `references` lists every referenced work item found by any pattern, normalized
+/// with [`normalize_work_item_reference`] and without duplicates, in the order they
+/// were found.
+///
+/// `dangling_references` lists the same-repository references that do not resolve to
+/// an issue. It is only filled in when issue reference verification is enabled.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::WorkItemValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = WorkItemValidationResult {
+///     validation: ValidationResult::valid(),
+///     matched_pattern: Some(r"PROJ-\d+".to_string()),
+///     references: vec!["PROJ-12".to_string()],
+///     dangling_references: Vec::new(),
+/// };
+/// assert!(result.is_valid());
+/// assert!(!result.was_bypassed());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkItemValidationResult {
+    /// The underlying validation outcome (valid, invalid, or bypassed).
+    pub validation: ValidationResult,
+
+    /// The pattern that matched the PR description, present only when a reference was found.
+    pub matched_pattern: Option<String>,
+
+    /// The normalized, de-duplicated work item references found in the PR description.
+    #[serde(default)]
+    pub references: Vec<String>,
+
+    /// The same-repository references that do not resolve to an issue.
+    #[serde(default)]
+    pub dangling_references: Vec<DanglingIssueReference>,
+}
+
+impl WorkItemValidationResult {
+    /// Creates a result for a PR description that matched `pattern`.
+    fn matched(pattern: &str, references: Vec<String>) -> Self {
+        Self {
+            validation: ValidationResult::valid(),
+            matched_pattern: Some(pattern.to_string()),
+            references,
+            dangling_references: Vec::new(),
+        }
+    }
+
+    /// Creates a failing result for a PR description with fewer references than required.
+    fn too_few(pattern: Option<&str>, references: Vec<String>) -> Self {
+        Self {
+            validation: ValidationResult::invalid(),
+            matched_pattern: pattern.map(str::to_string),
+            references,
+            dangling_references: Vec::new(),
+        }
+    }
+
+    /// Creates a result without a matched pattern from a plain validation outcome.
+    pub(crate) fn from_validation(validation: ValidationResult) -> Self {
+        Self {
+            validation,
+            matched_pattern: None,
+            references: Vec::new(),
+            dangling_references: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if validation passed (either a reference was found or bypassed).
+    ///
+    /// Delegates to [`ValidationResult::is_valid`][crate::validation_result::ValidationResult::is_valid].
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+
+    /// Returns `true` if validation passed due to a bypass rule.
+    ///
+    /// Delegates to [`ValidationResult::was_bypassed`][crate::validation_result::ValidationResult::was_bypassed].
+    #[must_use]
+    pub fn was_bypassed(&self) -> bool {
+        self.validation.was_bypassed()
+    }
+
+    /// Returns the bypass information if a bypass was used, or `None` otherwise.
+    ///
+    /// Delegates to [`ValidationResult::bypass_info`][crate::validation_result::ValidationResult::bypass_info].
+    #[must_use]
+    pub fn bypass_info(&self) -> Option<&BypassInfo> {
+        self.validation.bypass_info()
+    }
+}
+
+/// A same-repository issue reference that does not resolve to an issue.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::DanglingIssueReference;
+///
+/// let missing = DanglingIssueReference { number: 12, is_pull_request: false };
+/// assert_eq!(missing.to_string(), "#12 does not exist");
+///
+/// let pull_request = DanglingIssueReference { number: 13, is_pull_request: true };
+/// assert_eq!(pull_request.to_string(), "#13 is a pull request, not an issue");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DanglingIssueReference {
+    /// The referenced number
+    pub number: u64,
+
+    /// Whether the number belongs to a pull request; otherwise nothing with that
+    /// number exists
+    pub is_pull_request: bool,
+}
+
+impl fmt::Display for DanglingIssueReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_pull_request {
+            write!(f, "#{} is a pull request, not an issue", self.number)
+        } else {
+            write!(f, "#{} does not exist", self.number)
+        }
+    }
+}
+
 /// A specific issue found in a PR title that explains why the title does not conform
 /// to the Conventional Commits format.
 ///
@@ -277,6 +467,18 @@ impl TitleValidationResult {
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TitleIssue {
+    /// The description contains characters that the title character policy disallows.
+    ///
+    /// The `suggested_fix` removes the characters.
+    ///
+    /// # Examples
+    ///
+    /// - `"feat: add <Login> page"` with `disallowed_characters = "<>"` → `found: "<>"`
+    DisallowedCharacters {
+        /// The distinct disallowed characters found, in order of first appearance.
+        found: String,
+    },
+
     /// The description portion of the title is absent or whitespace-only.
     ///
     /// Triggered when the title contains a valid type and colon (e.g. `"feat: "`)
@@ -299,6 +501,20 @@ pub enum TitleIssue {
     /// - `"feat(): add login"` — parentheses with no scope name
     EmptyScope,
 
+    /// The title is longer than the title lint policy's `header_max_length`.
+    ///
+    /// No `suggested_fix` is produced because the user must decide how to shorten it.
+    ///
+    /// # Examples
+    ///
+    /// - a 90 character title with `header_max_length = 72` → `length: 90`, `max: 72`
+    HeaderTooLong {
+        /// The number of characters in the title.
+        length: usize,
+        /// The maximum number of characters allowed.
+        max: usize,
+    },
+
     /// The scope contains characters outside `[a-z0-9_-]`.
     ///
     /// The `suggested_fix` lowercases the scope and replaces spaces with `-`.
@@ -352,6 +568,44 @@ pub enum TitleIssue {
     /// - `"   "` — whitespace-only title
     NoTypePrefix,
 
+    /// The description contains more emoji than the title character policy allows.
+    ///
+    /// An emoji sequence such as a flag, a keycap, a skin-tone variant or a ZWJ family
+    /// counts once. The `suggested_fix` keeps the first `max` emoji and removes the rest.
+    ///
+    /// # Examples
+    ///
+    /// - `"feat: 🚀 launch 🎉"` with `max_emoji = 1` → `found: 2`, `max: 1`
+    TooManyEmoji {
+        /// The number of emoji in the description.
+        found: usize,
+        /// The maximum number of emoji allowed.
+        max: usize,
+    },
+
+    /// The description ends with a `.` and the title lint policy forbids it.
+    ///
+    /// The `suggested_fix` removes the trailing period.
+    ///
+    /// # Examples
+    ///
+    /// - `"feat: add login."` with `forbid_trailing_period = true`
+    TrailingPeriod,
+
+    /// The type is not in the title lint policy's `allowed_types`.
+    ///
+    /// No `suggested_fix` is produced because the user must pick the right type.
+    ///
+    /// # Examples
+    ///
+    /// - `"docs: add guide"` with `allowed_types = ["feat", "fix"]` → `found: "docs"`
+    TypeNotAllowed {
+        /// The type token as extracted from the title.
+        found: String,
+        /// The types the policy allows.
+        allowed: Vec<String>,
+    },
+
     /// The type token does not appear in the approved list and did not match a known synonym.
     ///
     /// `nearest_valid` is `Some` when the token is a known typo or synonym (e.g.
@@ -395,6 +649,19 @@ pub enum TitleIssue {
         /// The prefix (type + optional scope) including the trailing whitespace, as extracted.
         found: String,
     },
+
+    /// The first letter of the description does not have the case the title lint
+    /// policy requires.
+    ///
+    /// The `suggested_fix` changes the case of the first letter.
+    ///
+    /// # Examples
+    ///
+    /// - `"feat: Add login"` with `subject_case = "lower"` → `expected: SubjectCase::Lower`
+    WrongSubjectCase {
+        /// The case the policy requires.
+        expected: SubjectCase,
+    },
 }
 
 impl fmt::Display for TitleIssue {
@@ -451,6 +718,52 @@ impl fmt::Display for TitleIssue {
                 f,
                 "No conventional commit type prefix was found at the start of the title."
             ),
+            Self::DisallowedCharacters { found } => write!(
+                f,
+                "The description contains disallowed characters ({}) \u{2014} please remove them, as they break release notes and other tooling.",
+                found
+                    .chars()
+                    .map(|c| format!("`{c}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::TooManyEmoji { found, max: 0 } => write!(
+                f,
+                "Emoji are not allowed in the description (found {found}) \u{2014} please remove them, as they break release notes and other tooling."
+            ),
+            Self::TooManyEmoji { found, max } => write!(
+                f,
+                "The description contains {found} emoji but at most {max} are allowed \u{2014} please remove the extra emoji, as they break release notes and other tooling."
+            ),
+            Self::HeaderTooLong { length, max } => write!(
+                f,
+                "The title is {length} characters long but at most {max} are allowed \u{2014} please shorten it."
+            ),
+            Self::TrailingPeriod => write!(
+                f,
+                "The description must not end with a period \u{2014} please remove the trailing `.`."
+            ),
+            Self::TypeNotAllowed { found, allowed } => write!(
+                f,
+                "The type `{found}` is not allowed in this repository \u{2014} use one of {}.",
+                allowed
+                    .iter()
+                    .map(|t| format!("`{t}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::WrongSubjectCase {
+                expected: SubjectCase::Lower,
+            } => write!(
+                f,
+                "The description must start with a lowercase letter (e.g. `feat: add login`)."
+            ),
+            Self::WrongSubjectCase {
+                expected: SubjectCase::Sentence,
+            } => write!(
+                f,
+                "The description must start with an uppercase letter (e.g. `feat: Add login`)."
+            ),
         }
     }
 }
@@ -611,100 +924,468 @@ fn check_scope(working: &str, issues: &mut Vec<TitleIssue>) {
     }
 }
 
-/// Analyses a PR title that is known to be invalid and returns a structured diagnosis
-/// describing every detected problem and, where possible, a suggested corrected title.
+/// Returns `true` for characters that start an emoji.
 ///
-/// This is a pure function with no I/O or mutable state.  It is called by
-/// [`check_pr_title`] on the failure path.
+/// Covers the pictographic Unicode blocks; regional indicators and skin-tone modifiers
+/// are handled separately because they only form emoji in combination.
+fn is_pictographic(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2300..=0x23FF | 0x2B00..=0x2BFF
+            | 0x3030 | 0x303D | 0x3297 | 0x3299
+    ) && !is_regional_indicator(c)
+        && !is_emoji_modifier(c)
+}
+
+/// Returns `true` for the regional indicator letters that pair up into flags.
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+/// Returns `true` for the skin-tone modifiers.
+fn is_emoji_modifier(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+/// Returns the byte ranges of the emoji in `text`.
 ///
-/// Multiple [`TitleIssue`] entries may be returned simultaneously when the title
-/// exhibits several problems at once (e.g. leading whitespace **and** an uppercase type).
-/// Detection continues after each non-fatal check so that the caller receives a
-/// complete picture.
+/// Each emoji sequence is one range: a flag (two regional indicators), a keycap
+/// (`1️⃣`), an emoji with variation selectors, skin tones or tag characters, and emoji
+/// joined with zero-width joiners (`👨‍👩‍👧`).
+fn emoji_spans(text: &str) -> Vec<std::ops::Range<usize>> {
+    let mut spans = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let starts_emoji = if is_regional_indicator(c) {
+            if chars
+                .peek()
+                .is_some_and(|&(_, next)| is_regional_indicator(next))
+            {
+                chars.next();
+            }
+            true
+        } else if matches!(c, '0'..='9' | '#' | '*') {
+            // A keycap is the base character, an optional VS16 and U+20E3
+            let mut lookahead = chars.clone();
+            let next = match lookahead.next() {
+                Some((_, '\u{FE0F}')) => lookahead.next(),
+                other => other,
+            };
+            matches!(next, Some((_, '\u{20E3}')))
+        } else {
+            is_pictographic(c)
+        };
+        if !starts_emoji {
+            continue;
+        }
+
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, next)) = chars.peek() {
+            if matches!(next, '\u{FE0F}' | '\u{20E3}' | '\u{E0020}'..='\u{E007F}')
+                || is_emoji_modifier(next)
+            {
+                chars.next();
+                end = i + next.len_utf8();
+            } else if next == '\u{200D}' {
+                chars.next();
+                end = i + next.len_utf8();
+                if let Some(&(j, joined)) = chars.peek() {
+                    if is_pictographic(joined) {
+                        chars.next();
+                        end = j + joined.len_utf8();
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+        spans.push(start..end);
+    }
+    spans
+}
+
+/// Counts the emoji in `text`, counting each emoji sequence once.
 ///
-/// # Arguments
+/// # Examples
 ///
-/// * `title` - The raw PR title string, exactly as received from the provider.
+/// ```
+/// use merge_warden_core::checks::count_emoji;
+///
+/// assert_eq!(count_emoji("add login"), 0);
+/// assert_eq!(count_emoji("🚀 launch 🎉"), 2);
+/// // A ZWJ family, a flag and a skin-tone variant are one emoji each
+/// assert_eq!(count_emoji("👨‍👩‍👧 🇳🇿 👍🏽"), 3);
+/// ```
+#[must_use]
+pub fn count_emoji(text: &str) -> usize {
+    emoji_spans(text).len()
+}
+
+/// Returns the byte offset at which the title's description starts: just after the
+/// first `:`, or `0` when the title has no conventional commit prefix.
+fn title_description_start(title: &str) -> usize {
+    title.find(':').map_or(0, |colon| colon + 1)
+}
+
+/// Removes the disallowed characters and the emoji beyond the limit from the
+/// description of `title`, collapsing the whitespace left behind.
+fn strip_title_characters(title: &str, config: &TitleCharacterPolicyConfig) -> String {
+    let start = title_description_start(title);
+    let (prefix, description) = title.split_at(start);
+    let max_emoji = config.max_emoji.unwrap_or(usize::MAX);
+
+    let mut kept = String::with_capacity(description.len());
+    let mut last = 0;
+    for span in emoji_spans(description).into_iter().skip(max_emoji) {
+        kept.push_str(&description[last..span.start]);
+        last = span.end;
+    }
+    kept.push_str(&description[last..]);
+
+    let cleaned = kept
+        .chars()
+        .filter(|c| !config.disallowed_characters.contains(*c))
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if prefix.is_empty() {
+        cleaned
+    } else {
+        format!("{prefix} {cleaned}")
+    }
+}
+
+/// Checks the description of a PR title against the title character policy.
+///
+/// Only the part after the conventional commit prefix is checked, so a `!` or scope
+/// in the prefix is never flagged. The policy's `enabled` flag is not consulted;
+/// callers decide whether the policy applies.
 ///
 /// # Returns
 ///
-/// A [`TitleDiagnosis`] containing the list of issues found and, when possible,
-/// a best-effort corrected title string.
+/// `None` when the description is within the limits. Otherwise a [`TitleDiagnosis`]
+/// listing [`TitleIssue::DisallowedCharacters`] and/or [`TitleIssue::TooManyEmoji`],
+/// with a suggested title that drops the offending characters when a description
+/// remains.
 ///
 /// # Examples
 ///
 /// ```
-/// use merge_warden_core::checks::{diagnose_pr_title, TitleIssue};
+/// use merge_warden_core::checks::{diagnose_title_characters, TitleIssue};
+/// use merge_warden_core::config::TitleCharacterPolicyConfig;
 ///
-/// let diagnosis = diagnose_pr_title(" FEAT: add login");
-/// assert!(diagnosis.issues.contains(&TitleIssue::LeadingWhitespace));
-/// assert!(diagnosis.issues.contains(&TitleIssue::UppercaseType { found: "FEAT".to_string() }));
-/// assert_eq!(diagnosis.suggested_fix.as_deref(), Some("feat: add login"));
+/// let config = TitleCharacterPolicyConfig {
+///     enabled: true,
+///     max_emoji: Some(1),
+///     disallowed_characters: "<>".to_string(),
+/// };
+///
+/// assert!(diagnose_title_characters("feat: add login 🚀", &config).is_none());
+///
+/// let diagnosis = diagnose_title_characters("feat: add <login> 🚀🎉", &config).unwrap();
+/// assert_eq!(
+///     diagnosis.issues,
+///     vec![
+///         TitleIssue::DisallowedCharacters { found: "<>".to_string() },
+///         TitleIssue::TooManyEmoji { found: 2, max: 1 },
+///     ]
+/// );
+/// assert_eq!(diagnosis.suggested_fix.as_deref(), Some("feat: add login 🚀"));
 /// ```
 #[must_use]
-pub fn diagnose_pr_title(title: &str) -> TitleDiagnosis {
-    let mut issues: Vec<TitleIssue> = Vec::new();
+pub fn diagnose_title_characters(
+    title: &str,
+    config: &TitleCharacterPolicyConfig,
+) -> Option<TitleDiagnosis> {
+    let description = &title[title_description_start(title)..];
+    let mut issues = Vec::new();
 
-    // ── Step 1: Leading whitespace ────────────────────────────────────────────
-    let leading_ws = title.starts_with(|c: char| c.is_whitespace());
-    if leading_ws {
-        issues.push(TitleIssue::LeadingWhitespace);
+    let mut disallowed = String::new();
+    for c in description.chars() {
+        if config.disallowed_characters.contains(c) && !disallowed.contains(c) {
+            disallowed.push(c);
+        }
     }
-    // All subsequent work is performed on the trimmed title.
-    let working = title.trim();
-
-    // If the working string is empty after trimming, there is no prefix at all.
-    if working.is_empty() {
-        issues.push(TitleIssue::NoTypePrefix);
-        return TitleDiagnosis {
-            issues,
-            suggested_fix: None,
-        };
+    if !disallowed.is_empty() {
+        issues.push(TitleIssue::DisallowedCharacters { found: disallowed });
     }
 
-    // ── Extract the candidate type token (chars before `(`, `!`, `:`, or space) ──
-    let token_end = working.find(['(', '!', ':', ' ']).unwrap_or(working.len());
-    let raw_token = &working[..token_end];
-
-    // ── Step 2: Whitespace before colon ──────────────────────────────────────
-    // Look for whitespace immediately before the first `:` in the prefix region
-    // (the part before the description, i.e. up to and including the colon).
-    let colon_pos = working.find(':');
-    if let Some(pos) = colon_pos {
-        if pos > 0 {
-            let char_before = working[..pos]
-                .chars()
-                .next_back()
-                .is_some_and(char::is_whitespace);
-            if char_before {
-                let prefix_with_space = &working[..pos];
-                issues.push(TitleIssue::WhitespaceBeforeColon {
-                    found: prefix_with_space.to_string(),
-                });
-            }
+    if let Some(max) = config.max_emoji {
+        let found = count_emoji(description);
+        if found > max {
+            issues.push(TitleIssue::TooManyEmoji { found, max });
         }
     }
 
-    // ── Step 3 & 4: UnrecognizedType / UppercaseType ─────────────────────────
-    let token_lower = raw_token.to_lowercase();
-    let is_valid_exact = VALID_PR_TYPES.contains(&raw_token);
-    let is_valid_lower = VALID_PR_TYPES.contains(&token_lower.as_str());
+    if issues.is_empty() {
+        return None;
+    }
 
-    if !raw_token.is_empty() && !is_valid_exact {
-        if is_valid_lower {
-            // ── Step 4: Uppercase type ────────────────────────────────────────
-            issues.push(TitleIssue::UppercaseType {
-                found: raw_token.to_string(),
-            });
-        } else {
-            // ── Step 3: Unrecognised type ─────────────────────────────────────
+    let fixed = strip_title_characters(title, config);
+    let suggested_fix =
+        (!fixed[title_description_start(&fixed)..].trim().is_empty()).then_some(fixed);
+    Some(TitleDiagnosis {
+        issues,
+        suggested_fix,
+    })
+}
+
+/// Splits a PR title into its type token and its trimmed description.
+///
+/// Returns `None` when the title has no `:` separator. The type is the text before the
+/// scope or `!`, and is empty when the title starts with `:`.
+fn split_title_type(title: &str) -> Option<(&str, &str)> {
+    let colon = title.find(':')?;
+    let prefix = title[..colon].trim();
+    let type_end = prefix.find(['(', '!']).unwrap_or(prefix.len());
+    Some((prefix[..type_end].trim(), title[colon + 1..].trim()))
+}
+
+/// Removes the trailing period from the description of `title` and corrects the case
+/// of its first letter, as far as `config` requires.
+fn fix_title_lint(title: &str, config: &TitleLintPolicyConfig) -> String {
+    let Some(colon) = title.find(':') else {
+        return title.to_string();
+    };
+    let mut subject = title[colon + 1..].trim();
+    if config.forbid_trailing_period {
+        subject = subject.trim_end_matches('.').trim_end();
+    }
+
+    let mut chars = subject.chars();
+    let subject = match (config.subject_case, chars.next()) {
+        (Some(SubjectCase::Lower), Some(first)) => first.to_lowercase().chain(chars).collect(),
+        (Some(SubjectCase::Sentence), Some(first)) => first.to_uppercase().chain(chars).collect(),
+        _ => subject.to_string(),
+    };
+    format!("{} {subject}", &title[..=colon])
+}
+
+/// Checks a PR title against the commit-lint style rules of the title lint policy.
+///
+/// Every configured rule is checked and each broken rule is reported separately:
+/// [`TitleIssue::HeaderTooLong`], [`TitleIssue::TypeNotAllowed`],
+/// [`TitleIssue::TrailingPeriod`] and [`TitleIssue::WrongSubjectCase`]. The type and
+/// description rules need a `:` separator and are skipped for titles without one,
+/// which fail the title pattern anyway. Types are compared ignoring case, because an
+/// uppercase type is already reported by [`diagnose_pr_title`]. The policy's `enabled`
+/// flag is not consulted; callers decide whether the policy applies.
+///
+/// # Returns
+///
+/// `None` when the title follows every rule. Otherwise a [`TitleDiagnosis`] listing
+/// the broken rules, with a suggested title when removing the trailing period and
+/// correcting the case resolves all of them.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::{diagnose_title_lint, TitleIssue};
+/// use merge_warden_core::config::{SubjectCase, TitleLintPolicyConfig};
+///
+/// let config = TitleLintPolicyConfig {
+///     enabled: true,
+///     subject_case: Some(SubjectCase::Lower),
+///     forbid_trailing_period: true,
+///     header_max_length: Some(72),
+///     allowed_types: vec!["feat".to_string(), "fix".to_string()],
+/// };
+///
+/// assert!(diagnose_title_lint("feat: add login", &config).is_none());
+///
+/// let diagnosis = diagnose_title_lint("feat: Add login.", &config).unwrap();
+/// assert_eq!(
+///     diagnosis.issues,
+///     vec![
+///         TitleIssue::TrailingPeriod,
+///         TitleIssue::WrongSubjectCase { expected: SubjectCase::Lower },
+///     ]
+/// );
+/// assert_eq!(diagnosis.suggested_fix.as_deref(), Some("feat: add login"));
+/// ```
+#[must_use]
+pub fn diagnose_title_lint(title: &str, config: &TitleLintPolicyConfig) -> Option<TitleDiagnosis> {
+    let issues = title_lint_issues(title, config);
+    if issues.is_empty() {
+        return None;
+    }
+
+    let fixed = fix_title_lint(title, config);
+    let resolved = split_title_type(&fixed).is_some_and(|(_, subject)| !subject.is_empty())
+        && title_lint_issues(&fixed, config).is_empty();
+    Some(TitleDiagnosis {
+        issues,
+        suggested_fix: resolved.then_some(fixed),
+    })
+}
+
+/// Returns the title lint rules that `title` breaks, in the order they are reported.
+fn title_lint_issues(title: &str, config: &TitleLintPolicyConfig) -> Vec<TitleIssue> {
+    let mut issues = Vec::new();
+
+    if let Some(max) = config.header_max_length {
+        let length = title.chars().count();
+        if length > max {
+            issues.push(TitleIssue::HeaderTooLong { length, max });
+        }
+    }
+
+    if let Some((title_type, subject)) = split_title_type(title) {
+        if !title_type.is_empty()
+            && !config.allowed_types.is_empty()
+            && !config
+                .allowed_types
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(title_type))
+        {
+            issues.push(TitleIssue::TypeNotAllowed {
+                found: title_type.to_string(),
+                allowed: config.allowed_types.clone(),
+            });
+        }
+
+        if config.forbid_trailing_period && subject.ends_with('.') {
+            issues.push(TitleIssue::TrailingPeriod);
+        }
+
+        if let Some(expected) = config.subject_case {
+            if !expected.matches(subject) {
+                issues.push(TitleIssue::WrongSubjectCase { expected });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Analyses a PR title that is known to be invalid and returns a structured diagnosis
+/// describing every detected problem and, where possible, a suggested corrected title.
+///
+/// This is a pure function with no I/O or mutable state.  It is called by
+/// [`check_pr_title`] on the failure path.
+///
+/// Multiple [`TitleIssue`] entries may be returned simultaneously when the title
+/// exhibits several problems at once (e.g. leading whitespace **and** an uppercase type).
+/// Detection continues after each non-fatal check so that the caller receives a
+/// complete picture.
+///
+/// # Arguments
+///
+/// * `title` - The raw PR title string, exactly as received from the provider.
+///
+/// # Returns
+///
+/// A [`TitleDiagnosis`] containing the list of issues found and, when possible,
+/// a best-effort corrected title string.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::{diagnose_pr_title, TitleIssue};
+///
+/// let diagnosis = diagnose_pr_title(" FEAT: add login");
+/// assert!(diagnosis.issues.contains(&TitleIssue::LeadingWhitespace));
+/// assert!(diagnosis.issues.contains(&TitleIssue::UppercaseType { found: "FEAT".to_string() }));
+/// assert_eq!(diagnosis.suggested_fix.as_deref(), Some("feat: add login"));
+/// ```
+#[must_use]
+pub fn diagnose_pr_title(title: &str) -> TitleDiagnosis {
+    diagnose_title_with_types(title, &VALID_PR_TYPES)
+}
+
+/// Diagnoses an invalid PR title whose type must be one of `allowed_types`.
+///
+/// Works like [`diagnose_pr_title`], except that a type outside the allowed set
+/// that is not a typo of an allowed type is reported as
+/// [`TitleIssue::TypeNotAllowed`], listing the allowed set.
+fn diagnose_title_for_allowed_types(title: &str, allowed_types: &[String]) -> TitleDiagnosis {
+    let mut diagnosis = diagnose_title_with_types(title, allowed_types);
+    for issue in &mut diagnosis.issues {
+        if let TitleIssue::UnrecognizedType {
+            found,
+            nearest_valid: None,
+        } = issue
+        {
+            *issue = TitleIssue::TypeNotAllowed {
+                found: std::mem::take(found),
+                allowed: allowed_types.to_vec(),
+            };
+        }
+    }
+    diagnosis
+}
+
+/// Diagnoses an invalid PR title against the given set of valid types.
+fn diagnose_title_with_types<T: AsRef<str>>(title: &str, types: &[T]) -> TitleDiagnosis {
+    let is_type = |token: &str| types.iter().any(|t| t.as_ref() == token);
+    // A typo is only corrected to a type in the set.
+    let correct_typo = |token: &str| {
+        TYPE_TYPO_MAP
+            .iter()
+            .find(|(typo, correct)| *typo == token && is_type(correct))
+            .map(|(_, correct)| (*correct).to_string())
+    };
+
+    let mut issues: Vec<TitleIssue> = Vec::new();
+
+    // ── Step 1: Leading whitespace ────────────────────────────────────────────
+    let leading_ws = title.starts_with(|c: char| c.is_whitespace());
+    if leading_ws {
+        issues.push(TitleIssue::LeadingWhitespace);
+    }
+    // All subsequent work is performed on the trimmed title.
+    let working = title.trim();
+
+    // If the working string is empty after trimming, there is no prefix at all.
+    if working.is_empty() {
+        issues.push(TitleIssue::NoTypePrefix);
+        return TitleDiagnosis {
+            issues,
+            suggested_fix: None,
+        };
+    }
+
+    // ── Extract the candidate type token (chars before `(`, `!`, `:`, or space) ──
+    let token_end = working.find(['(', '!', ':', ' ']).unwrap_or(working.len());
+    let raw_token = &working[..token_end];
+
+    // ── Step 2: Whitespace before colon ──────────────────────────────────────
+    // Look for whitespace immediately before the first `:` in the prefix region
+    // (the part before the description, i.e. up to and including the colon).
+    let colon_pos = working.find(':');
+    if let Some(pos) = colon_pos {
+        if pos > 0 {
+            let char_before = working[..pos]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_whitespace);
+            if char_before {
+                let prefix_with_space = &working[..pos];
+                issues.push(TitleIssue::WhitespaceBeforeColon {
+                    found: prefix_with_space.to_string(),
+                });
+            }
+        }
+    }
+
+    // ── Step 3 & 4: UnrecognizedType / UppercaseType ─────────────────────────
+    let token_lower = raw_token.to_lowercase();
+    let is_valid_exact = is_type(raw_token);
+    let is_valid_lower = is_type(&token_lower);
+
+    if !raw_token.is_empty() && !is_valid_exact {
+        if is_valid_lower {
+            // ── Step 4: Uppercase type ────────────────────────────────────────
+            issues.push(TitleIssue::UppercaseType {
+                found: raw_token.to_string(),
+            });
+        } else {
+            // ── Step 3: Unrecognised type ─────────────────────────────────────
             // Only diagnose as UnrecognizedType when there is a colon (indicating a
             // conventional-commit attempt) or the token is a known synonym/typo.
             // Otherwise the title is plain prose and will fall through to NoTypePrefix.
-            let nearest_valid = TYPE_TYPO_MAP
-                .iter()
-                .find(|(typo, _)| *typo == token_lower.as_str())
-                .map(|(_, correct)| (*correct).to_string());
+            let nearest_valid = correct_typo(&token_lower);
             if colon_pos.is_some() || nearest_valid.is_some() {
                 issues.push(TitleIssue::UnrecognizedType {
                     found: raw_token.to_string(),
@@ -720,10 +1401,7 @@ pub fn diagnose_pr_title(title: &str) -> TitleDiagnosis {
         Some(token_lower.as_str().to_string())
     } else {
         // Unrecognised type — look it up in the typo map to get a correctable form.
-        TYPE_TYPO_MAP
-            .iter()
-            .find(|(typo, _)| *typo == token_lower.as_str())
-            .map(|(_, correct)| (*correct).to_string())
+        correct_typo(&token_lower)
     };
 
     if let Some(ref _eff_token) = effective_token {
@@ -764,15 +1442,136 @@ pub fn diagnose_pr_title(title: &str) -> TitleDiagnosis {
     }
 }
 
+/// Compiled once at first use. Accepts the same titles as [`CONVENTIONAL_COMMIT_REGEX`],
+/// capturing the type, the scope without its parentheses, the `!` and the description.
+static CONVENTIONAL_COMMIT_PARTS_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Returns the compiled conventional commit parts regex, initialising it on first call.
+fn conventional_commit_parts_regex() -> &'static Regex {
+    CONVENTIONAL_COMMIT_PARTS_REGEX.get_or_init(|| {
+        Regex::new(&conventional_commit_parts_pattern(&VALID_PR_TYPES))
+            .expect("CONVENTIONAL_COMMIT_PARTS_REGEX is a valid regex")
+    })
+}
+
+/// Builds the pattern of a conventional commit title with one of `types`, capturing
+/// the type, the scope without its parentheses, the `!` and the description.
+///
+/// The types are matched literally and case-sensitively.
+fn conventional_commit_parts_pattern<T: AsRef<str>>(types: &[T]) -> String {
+    let types: Vec<String> = types.iter().map(|t| regex::escape(t.as_ref())).collect();
+    format!(r"^({})(?:\(([a-z0-9_-]+)\))?(!)?: (.+)", types.join("|"))
+}
+
+/// The parts of a conventional commit title, such as `feat(auth)!: drop sessions`.
+///
+/// Returned by [`parse_conventional_commit`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConventionalCommit {
+    /// The commit type, such as `feat`.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// The scope without its parentheses, such as `auth`.
+    pub scope: Option<String>,
+
+    /// Whether the title marks a breaking change with `!`.
+    pub breaking: bool,
+
+    /// The description after the `: ` separator.
+    pub description: String,
+}
+
+/// Parses a PR title in the default Conventional Commits format.
+///
+/// A title is parsed when it matches [`CONVENTIONAL_COMMIT_REGEX`]; the configured
+/// `title_pattern` is not used. Only the first line of the title is read.
+///
+/// # Arguments
+///
+/// * `title` - The pull request title
+///
+/// # Returns
+///
+/// The type, scope, breaking change marker and description of the title, or `None`
+/// when the title is not a conventional commit.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::parse_conventional_commit;
+///
+/// let commit = parse_conventional_commit("feat(auth)!: drop sessions").unwrap();
+/// assert_eq!(commit.type_, "feat");
+/// assert_eq!(commit.scope.as_deref(), Some("auth"));
+/// assert!(commit.breaking);
+/// assert_eq!(commit.description, "drop sessions");
+///
+/// assert!(parse_conventional_commit("Add login").is_none());
+/// ```
+#[must_use]
+pub fn parse_conventional_commit(title: &str) -> Option<ConventionalCommit> {
+    conventional_commit_parts(conventional_commit_parts_regex(), title)
+}
+
+/// Parses a PR title in the Conventional Commits format, with one of `types`.
+///
+/// Works like [`parse_conventional_commit`], except that the title may only use one
+/// of `types` instead of the standard types. Use it with
+/// [`CurrentPullRequestValidationConfiguration::allowed_title_types`] to parse titles
+/// with the types a repository allows.
+///
+/// # Arguments
+///
+/// * `title` - The pull request title
+/// * `types` - The types the title may use, matched case-sensitively
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::parse_conventional_commit_with_types;
+///
+/// let types = ["feat", "deps"];
+/// let commit = parse_conventional_commit_with_types("deps(cargo): bump serde", &types).unwrap();
+/// assert_eq!(commit.type_, "deps");
+///
+/// assert!(parse_conventional_commit_with_types("fix: handle empty body", &types).is_none());
+/// ```
+#[must_use]
+pub fn parse_conventional_commit_with_types<T: AsRef<str>>(
+    title: &str,
+    types: &[T],
+) -> Option<ConventionalCommit> {
+    let regex = Regex::new(&conventional_commit_parts_pattern(types)).ok()?;
+    conventional_commit_parts(&regex, title)
+}
+
+/// Reads the parts of `title` captured by a conventional commit parts `regex`.
+fn conventional_commit_parts(regex: &Regex, title: &str) -> Option<ConventionalCommit> {
+    let captures = regex.captures(title)?;
+    Some(ConventionalCommit {
+        type_: captures[1].to_string(),
+        scope: captures.get(2).map(|m| m.as_str().to_string()),
+        breaking: captures.get(3).is_some(),
+        description: captures[4].to_string(),
+    })
+}
+
 /// Validates that the PR title follows the Conventional Commits format with bypass support.
 ///
 /// This function checks if the PR title follows the Conventional Commits format.
-/// If bypass rules are provided and the PR author is allowed to bypass title validation,
-/// the function will return a successful result with bypass information.
+/// If bypass rules are provided and the bypass author is allowed to bypass title
+/// validation, the function will return a successful result with bypass information.
+///
+/// When enabled, the title character policy ([`diagnose_title_characters`]) and the
+/// title lint policy ([`diagnose_title_lint`]) are checked as well, and their issues
+/// are reported together with those of the title pattern.
 ///
 /// # Arguments
 ///
 /// * `pr` - The pull request to validate
+/// * `bypass_author` - The user the bypass rule is evaluated for, normally the PR author.
+///   `None` never matches the bypass user list
 /// * `bypass_rule` - The bypass rule for title validation
 /// * `current_configuration` - The current validation configuration
 ///
@@ -799,11 +1598,13 @@ pub fn diagnose_pr_title(title: &str) -> TitleDiagnosis {
 ///     author: None,
 ///     milestone_number: None,
 ///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
 /// };
 ///
 /// let bypass_rule = BypassRule::default();
 /// let config = CurrentPullRequestValidationConfiguration::default();
-/// let result = check_pr_title(&pr, &bypass_rule, &config);
+/// let result = check_pr_title(&pr, pr.author.as_ref(), &bypass_rule, &config);
 /// assert!(result.is_valid());
 /// assert!(!result.was_bypassed());
 /// assert!(result.diagnosis.is_none());
@@ -820,10 +1621,13 @@ pub fn diagnose_pr_title(title: &str) -> TitleDiagnosis {
 ///     }),
 ///     milestone_number: None,
 ///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
 /// };
 ///
 /// let bypass_rule = BypassRule::new(true, vec!["emergency-bot".to_string()]);
-/// let result = check_pr_title(&pr_with_bad_title, &bypass_rule, &config);
+/// let author = pr_with_bad_title.author.as_ref();
+/// let result = check_pr_title(&pr_with_bad_title, author, &bypass_rule, &config);
 /// assert!(result.is_valid());
 /// assert!(result.was_bypassed());
 /// assert!(result.diagnosis.is_none());
@@ -831,16 +1635,16 @@ pub fn diagnose_pr_title(title: &str) -> TitleDiagnosis {
 #[must_use]
 pub fn check_pr_title(
     pr: &PullRequest,
+    bypass_author: Option<&User>,
     bypass_rule: &BypassRule,
     current_configuration: &CurrentPullRequestValidationConfiguration,
 ) -> TitleValidationResult {
-    let user = pr.author.as_ref();
-
-    // Check if user can bypass title validation
-    if bypass_rule.can_bypass_validation(user) {
+    // Check if user can bypass title validation. Without a bypass author nobody can
+    // bypass through the user list, so there is no user to record.
+    if let Some(user) = bypass_author.filter(|u| bypass_rule.can_bypass_validation(Some(u))) {
         let bypass_info = BypassInfo {
             rule_type: BypassRuleType::TitleConvention,
-            user: user.unwrap().login.clone(), // Safe unwrap since can_bypass_validation checks user existence
+            user: user.login.clone(),
         };
 
         return TitleValidationResult {
@@ -850,30 +1654,91 @@ pub fn check_pr_title(
     }
 
     // Otherwise, perform normal validation.
-    // NOTE: The title_pattern regex is recompiled on every call. Since the pattern is
+    // NOTE: A custom title_pattern regex is recompiled on every call. Since the pattern is
     // configuration-derived (not static), OnceLock is not suitable here. A per-instance
     // cache keyed by pattern string would improve throughput under high load.
     // This is a known performance gap — tracked for future optimisation.
-    let regex = match Regex::new(&current_configuration.title_pattern) {
-        Ok(r) => r,
-        Err(_) => {
-            return TitleValidationResult {
-                validation: ValidationResult::invalid(),
-                diagnosis: Some(diagnose_pr_title(&pr.title)),
-            }
+    let character_policy = &current_configuration.title_characters;
+    let character_diagnosis = if character_policy.enabled {
+        diagnose_title_characters(&pr.title, character_policy)
+    } else {
+        None
+    };
+    let lint_policy = &current_configuration.title_lint;
+    let lint_diagnosis = if lint_policy.enabled {
+        diagnose_title_lint(&pr.title, lint_policy)
+    } else {
+        None
+    };
+    let fix_characters = character_diagnosis.is_some();
+    let fix_lint = lint_diagnosis.is_some();
+
+    let policy_diagnosis = match (character_diagnosis, lint_diagnosis) {
+        (None, None) => None,
+        (Some(diagnosis), None) | (None, Some(diagnosis)) => Some(diagnosis),
+        (Some(mut diagnosis), Some(lint_diagnosis)) => {
+            // Only suggest a title that both policies accept
+            diagnosis.issues.extend(lint_diagnosis.issues);
+            let fixed = fix_title_lint(
+                &strip_title_characters(&pr.title, character_policy),
+                lint_policy,
+            );
+            let resolved = split_title_type(&fixed).is_some_and(|(_, subject)| !subject.is_empty())
+                && title_lint_issues(&fixed, lint_policy).is_empty();
+            diagnosis.suggested_fix = resolved.then_some(fixed);
+            Some(diagnosis)
+        }
+    };
+
+    // The allowed types of the title lint policy replace the standard ones of the
+    // default pattern.
+    let allowed_types = current_configuration
+        .allowed_title_types()
+        .filter(|_| current_configuration.title_pattern == CONVENTIONAL_COMMIT_REGEX);
+    let matches_pattern = match allowed_types {
+        Some(types) => parse_conventional_commit_with_types(&pr.title, types).is_some(),
+        None if current_configuration.title_pattern == CONVENTIONAL_COMMIT_REGEX => {
+            parse_conventional_commit(&pr.title).is_some()
         }
+        None => Regex::new(&current_configuration.title_pattern)
+            .is_ok_and(|regex| regex.is_match(&pr.title)),
     };
 
-    if regex.is_match(&pr.title) {
-        TitleValidationResult {
+    match (matches_pattern, policy_diagnosis) {
+        (true, None) => TitleValidationResult {
             validation: ValidationResult::valid(),
             diagnosis: None,
-        }
-    } else {
-        let diagnosis = diagnose_pr_title(&pr.title);
-        TitleValidationResult {
+        },
+        (true, Some(diagnosis)) => TitleValidationResult {
             validation: ValidationResult::invalid(),
             diagnosis: Some(diagnosis),
+        },
+        (false, policy_diagnosis) => {
+            let mut diagnosis = match allowed_types {
+                Some(types) => diagnose_title_for_allowed_types(&pr.title, types),
+                None => diagnose_pr_title(&pr.title),
+            };
+            if let Some(policy_diagnosis) = policy_diagnosis {
+                // A type outside the allowed types is reported by both diagnoses.
+                for issue in policy_diagnosis.issues {
+                    if !diagnosis.issues.contains(&issue) {
+                        diagnosis.issues.push(issue);
+                    }
+                }
+                diagnosis.suggested_fix = diagnosis.suggested_fix.map(|mut fix| {
+                    if fix_characters {
+                        fix = strip_title_characters(&fix, character_policy);
+                    }
+                    if fix_lint {
+                        fix = fix_title_lint(&fix, lint_policy);
+                    }
+                    fix
+                });
+            }
+            TitleValidationResult {
+                validation: ValidationResult::invalid(),
+                diagnosis: Some(diagnosis),
+            }
         }
     }
 }
@@ -886,15 +1751,30 @@ pub fn check_pr_title(
 /// returns a successful result with bypass information. Otherwise, it performs
 /// the standard work item reference validation.
 ///
+/// When several work item patterns are configured they are tried in order and the
+/// check passes if any of them matches; the first matching pattern is reported in the
+/// result. Patterns that are not valid regular expressions are logged and skipped.
+///
+/// The referenced work items found by all patterns are returned normalized, so that
+/// `GH-123`, `gh-123` and `#123` are reported once, as `#123`. See
+/// [`normalize_work_item_reference`].
+///
+/// When [`CurrentPullRequestValidationConfiguration::min_work_item_references`] is
+/// more than one, the check also fails while fewer distinct work items are referenced;
+/// the references that were found are still returned. When it is zero, the check
+/// always passes.
+///
 /// # Arguments
 ///
 /// * `pr` - The pull request to check
+/// * `bypass_author` - The user the bypass rule is evaluated for, normally the PR author.
+///   `None` never matches the bypass user list
 /// * `bypass_rules` - The bypass rules configuration
 ///
 /// # Returns
 ///
-/// A `ValidationResult` indicating whether a work item reference was found
-/// or if the validation was bypassed
+/// A `WorkItemValidationResult` indicating whether a work item reference was found,
+/// which pattern matched, or if the validation was bypassed
 ///
 /// # Examples
 ///
@@ -917,72 +1797,318 @@ pub fn check_pr_title(
 ///     author: Some(bypass_user),
 ///     milestone_number: None,
 ///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
 /// };
 ///
 /// let bypass_rule = BypassRule::new(true, vec!["bypass-user".to_string()]);
 /// let config = CurrentPullRequestValidationConfiguration::default();
 ///
-/// let result = check_work_item_reference(&pr_with_bypass, &bypass_rule, &config);
+/// let author = pr_with_bypass.author.as_ref();
+/// let result = check_work_item_reference(&pr_with_bypass, author, &bypass_rule, &config);
 /// assert!(result.is_valid()); // Bypassed, so returns true
 /// assert!(result.was_bypassed()); // Indicates bypass was used
 /// ```
 pub fn check_work_item_reference(
     pr: &PullRequest,
+    bypass_author: Option<&User>,
     bypass_rules: &BypassRule,
     current_configuration: &CurrentPullRequestValidationConfiguration,
-) -> ValidationResult {
+) -> WorkItemValidationResult {
     // Check if the user can bypass work item validation
-    let user = pr.author.as_ref();
-    if bypass_rules.can_bypass_validation(user) {
+    if let Some(user) = bypass_author.filter(|u| bypass_rules.can_bypass_validation(Some(u))) {
         let bypass_info = BypassInfo {
             rule_type: BypassRuleType::WorkItemReference,
-            user: user.unwrap().login.clone(), // Safe unwrap since can_bypass_validation checks user existence
+            user: user.login.clone(),
         };
 
-        return ValidationResult::bypassed(bypass_info);
+        return WorkItemValidationResult::from_validation(ValidationResult::bypassed(bypass_info));
     }
 
     // If no bypass, perform normal validation
-    match &pr.body {
-        Some(body) => {
-            let regex = match Regex::new(current_configuration.work_item_reference_pattern.as_str())
-            {
-                Ok(r) => r,
-                Err(_) => return ValidationResult::invalid(),
-            };
+    let body = pr.body.as_deref().unwrap_or_default();
 
-            if regex.is_match(body) {
-                ValidationResult::valid()
-            } else {
-                ValidationResult::invalid()
+    let mut matched_pattern = None;
+    let mut references: Vec<String> = Vec::new();
+    for pattern in current_configuration.work_item_reference_pattern.patterns() {
+        let regex = match Regex::new(pattern) {
+            Ok(r) => r,
+            Err(e) => {
+                warn!(pattern = %pattern, error = %e, "Skipping invalid work item pattern");
+                continue;
             }
+        };
+
+        for found in regex.find_iter(body) {
+            matched_pattern.get_or_insert(pattern);
+
+            let reference = normalize_work_item_reference(found.as_str());
+            if !reference.is_empty() && !references.contains(&reference) {
+                references.push(reference);
+            }
+        }
+    }
+
+    let required = current_configuration.min_work_item_references;
+    match matched_pattern {
+        Some(pattern) if required <= 1 || references.len() >= required => {
+            WorkItemValidationResult::matched(pattern, references)
+        }
+        // No reference is required, so a description without one passes as well
+        None if required == 0 => {
+            WorkItemValidationResult::from_validation(ValidationResult::valid())
         }
-        None => ValidationResult::invalid(),
+        pattern => WorkItemValidationResult::too_few(pattern.map(String::as_str), references),
     }
 }
 
-/// Validates PR size based on file changes and configuration.
-///
-/// This function analyzes the size of a pull request by examining the files changed
-/// and calculating the total lines modified. It supports file exclusion patterns,
-/// can optionally fail the check for oversized PRs, and supports bypass rules for
-/// automated tools that may legitimately create large PRs.
-///
-/// # Arguments
+/// Normalizes a work item reference so that equivalent references compare equal.
 ///
-/// * `pr_files` - List of files changed in the pull request
-/// * `user` - The user who created the pull request (for bypass checking)
-/// * `bypass_rule` - Bypass rule for size validation (allows specific users to bypass size checks)
-/// * `config` - Current validation configuration containing size check settings
+/// - A leading closing keyword, such as `Fixes`, is dropped.
+/// - `#123` and `GH-123`, in any case, become `#123`.
+/// - `owner/repo#123` and `https://github.com/owner/repo/issues/123` become
+///   `owner/repo#123`, with the owner and repository in lower case.
+/// - Any other `PREFIX-123` reference, such as a Jira key, gets an upper-case prefix.
 ///
-/// # Returns
-///
-/// A `ValidationResult` indicating the size validation status
+/// Other references are returned trimmed but otherwise unchanged.
 ///
 /// # Examples
 ///
 /// ```
-/// use merge_warden_developer_platforms::models::{PullRequestFile, User};
+/// use merge_warden_core::checks::normalize_work_item_reference;
+///
+/// assert_eq!(normalize_work_item_reference("gh-123"), "#123");
+/// assert_eq!(normalize_work_item_reference("Fixes #123"), "#123");
+/// assert_eq!(normalize_work_item_reference("proj-42"), "PROJ-42");
+/// assert_eq!(
+///     normalize_work_item_reference("https://github.com/Owner/Repo/issues/7"),
+///     "owner/repo#7"
+/// );
+/// ```
+pub fn normalize_work_item_reference(reference: &str) -> String {
+    let reference = reference.split_whitespace().last().unwrap_or_default();
+
+    if let Some(captures) = github_issue_url_regex().captures(reference) {
+        return format!(
+            "{}/{}#{}",
+            captures[1].to_lowercase(),
+            captures[2].to_lowercase(),
+            &captures[3]
+        );
+    }
+
+    if let Some((repository, number)) = reference.split_once('#') {
+        return if repository.is_empty() {
+            format!("#{number}")
+        } else {
+            format!("{}#{number}", repository.to_lowercase())
+        };
+    }
+
+    if let Some((prefix, number)) = reference.rsplit_once('-') {
+        if !prefix.is_empty() && !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+            return if prefix.eq_ignore_ascii_case("gh") {
+                format!("#{number}")
+            } else {
+                format!("{}-{number}", prefix.to_uppercase())
+            };
+        }
+    }
+
+    reference.to_string()
+}
+
+/// Returns the issue numbers of the references that point at the repository itself.
+///
+/// `references` are normalized work item references, as found by
+/// [`check_work_item_reference`]. `#123` references are always same-repository;
+/// `owner/repo#123` references are when the owner and repository match, ignoring
+/// case. Other references, such as Jira keys, are skipped. Each number is returned
+/// once.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::same_repository_issue_numbers;
+///
+/// let references = [
+///     "#12".to_string(),
+///     "owner/repo#13".to_string(),
+///     "other/repo#14".to_string(),
+///     "PROJ-15".to_string(),
+///     "#12".to_string(),
+/// ];
+/// assert_eq!(
+///     same_repository_issue_numbers(&references, "Owner", "Repo"),
+///     vec![12, 13]
+/// );
+/// ```
+pub fn same_repository_issue_numbers(
+    references: &[String],
+    repo_owner: &str,
+    repo_name: &str,
+) -> Vec<u64> {
+    let repository = format!("{repo_owner}/{repo_name}").to_lowercase();
+
+    let mut numbers = Vec::new();
+    for reference in references {
+        let Some((prefix, number)) = reference.split_once('#') else {
+            continue;
+        };
+        if !prefix.is_empty() && prefix.to_lowercase() != repository {
+            continue;
+        }
+        if let Ok(number) = number.parse::<u64>() {
+            if !numbers.contains(&number) {
+                numbers.push(number);
+            }
+        }
+    }
+    numbers
+}
+
+/// The most lines of a pull request template quoted by
+/// [`find_pr_template_work_item_lines`].
+const MAX_PR_TEMPLATE_WORK_ITEM_LINES: usize = 3;
+
+/// Finds the lines of a pull request template that show how to reference a work item.
+///
+/// A line counts when it starts with an issue reference keyword (`fixes`, `closes`,
+/// `resolves`, `references` or `relates to`, in any case) or matches one of the
+/// work item `patterns`. HTML comment markers, list bullets and quote markers are
+/// removed before the line is tested, so `<!-- Fixes #(issue) -->` is returned as
+/// `Fixes #(issue)`. At most three lines are returned, in template order.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::find_pr_template_work_item_lines;
+/// use merge_warden_core::config::WorkItemPatterns;
+///
+/// let template = "## Description\n\n<!-- Fixes #(issue number) -->\n\n- [ ] Tests added";
+/// assert_eq!(
+///     find_pr_template_work_item_lines(template, &WorkItemPatterns::default()),
+///     vec!["Fixes #(issue number)".to_string()]
+/// );
+/// ```
+pub fn find_pr_template_work_item_lines(
+    template: &str,
+    patterns: &WorkItemPatterns,
+) -> Vec<String> {
+    let regexes: Vec<Regex> = patterns
+        .patterns()
+        .iter()
+        .filter(|p| !p.is_empty())
+        .filter_map(|p| Regex::new(p).ok())
+        .collect();
+
+    template
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = line.strip_prefix("<!--").unwrap_or(line);
+            let line = line.strip_suffix("-->").unwrap_or(line).trim();
+            line.trim_start_matches(['-', '*', '>', ' ']).trim()
+        })
+        .filter(|line| !line.is_empty())
+        .filter(|line| {
+            template_reference_line_regex().is_match(line)
+                || regexes.iter().any(|regex| regex.is_match(line))
+        })
+        .take(MAX_PR_TEMPLATE_WORK_ITEM_LINES)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Checks whether a pull request is marked as WIP (Work In Progress).
+///
+/// Matches the PR title and body against the configured WIP patterns using
+/// case-sensitive substring matching. WIP status ignores bypass rules.
+///
+/// # Arguments
+///
+/// * `pr` - The pull request to inspect
+/// * `config` - The WIP check configuration holding the title and description patterns
+///
+/// # Returns
+///
+/// `true` if any pattern matches, `false` otherwise
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::PullRequest;
+/// use merge_warden_core::checks::check_wip_status;
+/// use merge_warden_core::config::WipCheckConfig;
+///
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "WIP: feat: add login".to_string(),
+///     draft: false,
+///     body: None,
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
+/// };
+///
+/// assert!(check_wip_status(&pr, &WipCheckConfig::default()));
+/// ```
+pub fn check_wip_status(pr: &PullRequest, config: &WipCheckConfig) -> bool {
+    if let Some(pattern) = config
+        .wip_title_patterns
+        .iter()
+        .find(|pattern| pr.title.contains(pattern.as_str()))
+    {
+        debug!(
+            pull_request = pr.number,
+            pattern = pattern.as_str(),
+            "WIP pattern matched in PR title"
+        );
+        return true;
+    }
+
+    if let Some(body) = &pr.body {
+        if let Some(pattern) = config
+            .wip_description_patterns
+            .iter()
+            .find(|pattern| body.contains(pattern.as_str()))
+        {
+            debug!(
+                pull_request = pr.number,
+                pattern = pattern.as_str(),
+                "WIP pattern matched in PR description"
+            );
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Validates PR size based on file changes and configuration.
+///
+/// This function analyzes the size of a pull request by examining the files changed
+/// and calculating the total lines modified. It supports file exclusion patterns,
+/// can optionally fail the check for oversized PRs, and supports bypass rules for
+/// automated tools that may legitimately create large PRs.
+///
+/// # Arguments
+///
+/// * `pr_files` - List of files changed in the pull request
+/// * `user` - The user who created the pull request (for bypass checking)
+/// * `bypass_rule` - Bypass rule for size validation (allows specific users to bypass size checks)
+/// * `config` - Current validation configuration containing size check settings
+///
+/// # Returns
+///
+/// A `ValidationResult` indicating the size validation status
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::{PullRequestFile, User};
 /// use merge_warden_core::checks::check_pr_size;
 /// use merge_warden_core::config::{BypassRule, CurrentPullRequestValidationConfiguration, PrSizeCheckConfig};
 ///
@@ -1022,23 +2148,21 @@ pub fn check_pr_size(
     bypass_rule: &BypassRule,
     config: &CurrentPullRequestValidationConfiguration,
 ) -> ValidationResult {
-    // If size checking is disabled, always return valid
+    // If size checking is disabled, the check is skipped and counts as valid
     if !config.pr_size_check.enabled {
-        return ValidationResult::valid();
+        return ValidationResult::skipped("Size checking is not enabled");
     }
 
     // Check if the user can bypass size validation
-    if bypass_rule.can_bypass_validation(user) {
-        return ValidationResult::valid();
+    if let Some(user) = user.filter(|u| bypass_rule.can_bypass_validation(Some(u))) {
+        return ValidationResult::bypassed(BypassInfo {
+            rule_type: BypassRuleType::PrSize,
+            user: user.login.clone(),
+        });
     }
 
     // Calculate size info with file exclusions
-    let size_info = PrSizeInfo::from_files_with_exclusions(
-        pr_files,
-        &config.pr_size_check.get_effective_thresholds(),
-        &config.pr_size_check.excluded_file_patterns,
-        config.pr_size_check.ignore_deletions,
-    );
+    let size_info = PrSizeInfo::categorize(pr_files, &config.pr_size_check);
 
     // Check if we should fail for oversized PRs
     if config.pr_size_check.fail_on_oversized && size_info.is_oversized() {
@@ -1221,3 +2345,1805 @@ pub fn extract_any_issue_reference(body: &str) -> Option<IssueReference> {
 
     None
 }
+
+/// A single Markdown task-list item (`- [ ] text` or `- [x] text`) found in a PR body.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::parse_task_list;
+///
+/// let items = parse_task_list("- [x] Tests added\n  - [ ] Docs updated");
+/// assert_eq!(items.len(), 2);
+/// assert!(items[0].checked);
+/// assert_eq!(items[1].text, "Docs updated");
+/// assert_eq!(items[1].depth, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskListItem {
+    /// The text following the checkbox, with surrounding whitespace removed.
+    pub text: String,
+
+    /// Whether the checkbox is ticked (`[x]` or `[X]`).
+    pub checked: bool,
+
+    /// Nesting level of the item; `0` for top-level items.
+    pub depth: usize,
+}
+
+/// Result of checking the task list in a PR description against the checklist policy.
+///
+/// Returned by [`check_required_checklist`]. When the check fails,
+/// `unchecked_items` lists the required items that are present but not ticked and
+/// `missing_items` lists configured items that could not be found in the description.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::ChecklistValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = ChecklistValidationResult {
+///     validation: ValidationResult::invalid(),
+///     unchecked_items: vec!["Tests added".to_string()],
+///     missing_items: vec![],
+/// };
+/// assert!(!result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChecklistValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// Required checklist items that were found in the description but are not ticked.
+    pub unchecked_items: Vec<String>,
+
+    /// Configured required items that do not appear in the description at all.
+    pub missing_items: Vec<String>,
+}
+
+impl ChecklistValidationResult {
+    /// Returns `true` if every required checklist item is ticked.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Parses the Markdown task-list items from a pull request body.
+///
+/// Items may use any bullet (`-`, `*`, `+`) or an ordered marker (`1.` / `1)`)
+/// and may be indented to any depth. The `depth` of each item is derived from its
+/// indentation relative to the enclosing items, so both two- and four-space
+/// nesting styles are handled. Lines inside fenced code blocks are ignored, as
+/// are checkboxes without any text after them.
+///
+/// # Arguments
+///
+/// * `body` - The pull request description
+///
+/// # Returns
+///
+/// The task-list items in document order.
+pub fn parse_task_list(body: &str) -> Vec<TaskListItem> {
+    let mut items = Vec::new();
+    let mut open_fence: Option<&str> = None;
+    // Indentation of the enclosing list items, innermost last.
+    let mut indent_stack: Vec<usize> = Vec::new();
+
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+
+        if let Some(fence) = open_fence {
+            if trimmed.starts_with(fence) {
+                open_fence = None;
+            }
+            continue;
+        }
+        if let Some(fence) = ["```", "~~~"].into_iter().find(|f| trimmed.starts_with(f)) {
+            open_fence = Some(fence);
+            continue;
+        }
+
+        let Some((checked, text)) = parse_task_list_line(trimmed) else {
+            continue;
+        };
+
+        let indent: usize = line
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+        while indent_stack.last().is_some_and(|&parent| parent >= indent) {
+            indent_stack.pop();
+        }
+        let depth = indent_stack.len();
+        indent_stack.push(indent);
+
+        items.push(TaskListItem {
+            text: text.to_string(),
+            checked,
+            depth,
+        });
+    }
+
+    items
+}
+
+/// Parses a single line (without leading whitespace) as a task-list item.
+///
+/// Returns the checked state and the item text, or `None` when the line is not a
+/// task-list item.
+fn parse_task_list_line(line: &str) -> Option<(bool, &str)> {
+    let after_marker = if let Some(rest) = line.strip_prefix(['-', '*', '+']) {
+        rest
+    } else {
+        let digits = line.chars().take_while(char::is_ascii_digit).count();
+        if digits == 0 || digits > 9 {
+            return None;
+        }
+        line[digits..].strip_prefix(['.', ')'])?
+    };
+
+    // The list marker must be followed by whitespace.
+    if !after_marker.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    let after_marker = after_marker.trim_start();
+    let (checked, rest) = if let Some(rest) = after_marker.strip_prefix("[ ]") {
+        (false, rest)
+    } else if let Some(rest) = after_marker
+        .strip_prefix("[x]")
+        .or_else(|| after_marker.strip_prefix("[X]"))
+    {
+        (true, rest)
+    } else {
+        return None;
+    };
+
+    if !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    let text = rest.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    Some((checked, text))
+}
+
+/// Validates that the required checklist items in the PR description are ticked.
+///
+/// When `config.required_items` is empty, every task-list item in the description
+/// is required. Otherwise each configured entry is matched, case-insensitively, against
+/// the text of the task-list items: an entry matches an item when the item text
+/// contains it. A configured entry that matches no item is reported as missing;
+/// one that only matches unticked items is reported as unchecked.
+///
+/// # Arguments
+///
+/// * `pr` - The pull request to validate
+/// * `config` - The checklist policy
+///
+/// # Returns
+///
+/// A [`ChecklistValidationResult`] listing any unchecked or missing items.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_required_checklist;
+/// use merge_warden_core::config::ChecklistPolicyConfig;
+/// use merge_warden_developer_platforms::models::PullRequest;
+///
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "feat: add checklist".to_string(),
+///     draft: false,
+///     body: Some("- [x] Tests added\n- [ ] Docs updated".to_string()),
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
+/// };
+///
+/// let config = ChecklistPolicyConfig {
+///     enabled: true,
+///     required_items: vec!["tests added".to_string()],
+/// };
+/// assert!(check_required_checklist(&pr, &config).is_valid());
+///
+/// let config = ChecklistPolicyConfig {
+///     enabled: true,
+///     required_items: vec![],
+/// };
+/// let result = check_required_checklist(&pr, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.unchecked_items, vec!["Docs updated".to_string()]);
+/// ```
+pub fn check_required_checklist(
+    pr: &PullRequest,
+    config: &ChecklistPolicyConfig,
+) -> ChecklistValidationResult {
+    let items = parse_task_list(pr.body.as_deref().unwrap_or_default());
+
+    let mut unchecked_items = Vec::new();
+    let mut missing_items = Vec::new();
+
+    if config.required_items.is_empty() {
+        unchecked_items.extend(
+            items
+                .iter()
+                .filter(|item| !item.checked)
+                .map(|item| item.text.clone()),
+        );
+    } else {
+        for required in &config.required_items {
+            let needle = required.to_lowercase();
+            let matching: Vec<&TaskListItem> = items
+                .iter()
+                .filter(|item| item.text.to_lowercase().contains(&needle))
+                .collect();
+
+            if matching.is_empty() {
+                missing_items.push(required.clone());
+            } else {
+                for item in matching.into_iter().filter(|item| !item.checked) {
+                    if !unchecked_items.contains(&item.text) {
+                        unchecked_items.push(item.text.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let validation = if unchecked_items.is_empty() && missing_items.is_empty() {
+        ValidationResult::valid()
+    } else {
+        ValidationResult::invalid()
+    };
+
+    ChecklistValidationResult {
+        validation,
+        unchecked_items,
+        missing_items,
+    }
+}
+
+/// Result of checking the review threads of a PR against the review-thread policy.
+///
+/// Returned by [`check_unresolved_review_threads`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::ReviewThreadValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = ReviewThreadValidationResult {
+///     validation: ValidationResult::invalid(),
+///     unresolved_count: 2,
+///     total_count: 5,
+/// };
+/// assert!(!result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewThreadValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The number of review threads that are not resolved.
+    pub unresolved_count: usize,
+
+    /// The total number of review threads on the PR.
+    pub total_count: usize,
+}
+
+impl ReviewThreadValidationResult {
+    /// Returns `true` if the number of unresolved threads is within the configured limit.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Validates that a PR does not have more unresolved review threads than allowed.
+///
+/// Outdated threads count like any other thread: they stay unresolved until a
+/// participant resolves them.
+///
+/// # Arguments
+///
+/// * `threads` - The review threads on the pull request
+/// * `config` - The review-thread policy
+///
+/// # Returns
+///
+/// A [`ReviewThreadValidationResult`] with the unresolved and total thread counts.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_unresolved_review_threads;
+/// use merge_warden_core::config::ReviewThreadPolicyConfig;
+/// use merge_warden_developer_platforms::models::ReviewThread;
+///
+/// let threads = vec![
+///     ReviewThread { id: "T_1".to_string(), is_resolved: true, is_outdated: false },
+///     ReviewThread { id: "T_2".to_string(), is_resolved: false, is_outdated: false },
+/// ];
+/// let config = ReviewThreadPolicyConfig { enabled: true, max_unresolved: 0 };
+///
+/// let result = check_unresolved_review_threads(&threads, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.unresolved_count, 1);
+/// ```
+pub fn check_unresolved_review_threads(
+    threads: &[ReviewThread],
+    config: &ReviewThreadPolicyConfig,
+) -> ReviewThreadValidationResult {
+    let unresolved_count = threads.iter().filter(|t| !t.is_resolved).count();
+
+    let validation = if unresolved_count <= config.max_unresolved as usize {
+        ValidationResult::valid()
+    } else {
+        ValidationResult::invalid()
+    };
+
+    ReviewThreadValidationResult {
+        validation,
+        unresolved_count,
+        total_count: threads.len(),
+    }
+}
+
+/// A deleted-file pairing rule that was triggered without the paired changes.
+///
+/// Part of a [`DeletedFilesValidationResult`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeletedFileViolation {
+    /// The `deleted` pattern of the triggered rule.
+    pub deleted_pattern: String,
+
+    /// The deleted files that matched the rule.
+    pub deleted_files: Vec<String>,
+
+    /// The patterns of which none matched another changed file.
+    pub required_patterns: Vec<String>,
+}
+
+/// Result of checking the deleted files of a PR against the deleted-file policy.
+///
+/// Returned by [`check_deleted_file_pairings`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::DeletedFilesValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = DeletedFilesValidationResult {
+///     validation: ValidationResult::valid(),
+///     violations: vec![],
+/// };
+/// assert!(result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeletedFilesValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The triggered rules whose paired changes are missing.
+    pub violations: Vec<DeletedFileViolation>,
+}
+
+impl DeletedFilesValidationResult {
+    /// Returns `true` if every triggered rule has its paired changes.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Returns `true` if the file was deleted by the PR.
+///
+/// GitHub reports deleted files with the status `removed`; `deleted` is accepted
+/// for platforms that use that name instead.
+fn is_deleted_file(file: &PullRequestFile) -> bool {
+    file.status.eq_ignore_ascii_case("removed") || file.status.eq_ignore_ascii_case("deleted")
+}
+
+/// Validates that deleted files are accompanied by their paired changes.
+///
+/// A rule is triggered when a deleted file matches its `deleted` pattern. It is
+/// satisfied when any other file changed in the PR — whatever its status —
+/// matches one of the rule's `requires` patterns. The deleted files that
+/// triggered the rule do not satisfy it themselves. Rules without `requires`
+/// patterns are ignored.
+///
+/// # Arguments
+///
+/// * `files` - The files changed in the pull request
+/// * `config` - The deleted-file policy
+///
+/// # Returns
+///
+/// A [`DeletedFilesValidationResult`] listing the rules whose paired changes are missing.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_deleted_file_pairings;
+/// use merge_warden_core::config::{DeletedFilePairingRule, DeletedFilePolicyConfig};
+/// use merge_warden_developer_platforms::models::PullRequestFile;
+///
+/// let files = vec![PullRequestFile {
+///     filename: "src/parser.rs".to_string(),
+///     additions: 0,
+///     deletions: 120,
+///     changes: 120,
+///     status: "removed".to_string(),
+/// }];
+/// let config = DeletedFilePolicyConfig {
+///     enabled: true,
+///     rules: vec![DeletedFilePairingRule {
+///         deleted: "src/*".to_string(),
+///         requires: vec!["docs/*".to_string()],
+///     }],
+///     ..Default::default()
+/// };
+///
+/// let result = check_deleted_file_pairings(&files, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.violations[0].deleted_files, vec!["src/parser.rs"]);
+/// ```
+pub fn check_deleted_file_pairings(
+    files: &[PullRequestFile],
+    config: &DeletedFilePolicyConfig,
+) -> DeletedFilesValidationResult {
+    let mut violations = Vec::new();
+
+    for rule in config.rules.iter().filter(|r| !r.requires.is_empty()) {
+        let deleted_patterns = rule.deleted_files();
+        let deleted_files: Vec<&str> = files
+            .iter()
+            .filter(|f| is_deleted_file(f) && deleted_patterns.is_excluded(&f.filename))
+            .map(|f| f.filename.as_str())
+            .collect();
+        if deleted_files.is_empty() {
+            continue;
+        }
+
+        let required_patterns = rule.required_files();
+        let has_paired_change = files.iter().any(|f| {
+            !deleted_files.contains(&f.filename.as_str())
+                && required_patterns.is_excluded(&f.filename)
+        });
+        if !has_paired_change {
+            violations.push(DeletedFileViolation {
+                deleted_pattern: rule.deleted.clone(),
+                deleted_files: deleted_files.into_iter().map(str::to_string).collect(),
+                required_patterns: rule.requires.clone(),
+            });
+        }
+    }
+
+    let validation = if violations.is_empty() {
+        ValidationResult::valid()
+    } else {
+        ValidationResult::invalid()
+    };
+
+    DeletedFilesValidationResult {
+        validation,
+        violations,
+    }
+}
+
+/// Why the suspicious file check flagged a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuspiciousFileReason {
+    /// The path matches the contained secret pattern.
+    Secret(String),
+
+    /// The path matches the contained binary pattern.
+    Binary(String),
+
+    /// The file adds the contained number of lines, more than `max_file_lines`.
+    TooLarge(u32),
+}
+
+impl fmt::Display for SuspiciousFileReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SuspiciousFileReason::Secret(pattern) => {
+                write!(f, "looks like a secret (matches `{}`)", pattern)
+            }
+            SuspiciousFileReason::Binary(pattern) => {
+                write!(f, "looks like a binary file (matches `{}`)", pattern)
+            }
+            SuspiciousFileReason::TooLarge(lines) => write!(f, "adds {} lines", lines),
+        }
+    }
+}
+
+/// A file flagged by the suspicious file check.
+///
+/// Part of a [`SuspiciousFilesValidationResult`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuspiciousFile {
+    /// The path of the flagged file.
+    pub filename: String,
+
+    /// Why the file was flagged.
+    pub reason: SuspiciousFileReason,
+}
+
+/// Result of checking the files of a PR against the suspicious file policy.
+///
+/// Returned by [`check_suspicious_files`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::SuspiciousFilesValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = SuspiciousFilesValidationResult {
+///     validation: ValidationResult::valid(),
+///     files: vec![],
+/// };
+/// assert!(result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuspiciousFilesValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The flagged files, in the order the platform listed them.
+    pub files: Vec<SuspiciousFile>,
+}
+
+impl SuspiciousFilesValidationResult {
+    /// Returns `true` if no file was flagged.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Flags files that look like secrets, binaries, or are too large to review.
+///
+/// Files deleted by the PR are ignored. Each flagged file is reported once, for
+/// the first matching reason: a secret pattern, then a binary pattern, then the
+/// `max_file_lines` limit on added lines.
+///
+/// # Arguments
+///
+/// * `files` - The files changed in the pull request
+/// * `config` - The suspicious file policy
+///
+/// # Returns
+///
+/// A [`SuspiciousFilesValidationResult`] listing the flagged files.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::{check_suspicious_files, SuspiciousFileReason};
+/// use merge_warden_core::config::SuspiciousFilePolicyConfig;
+/// use merge_warden_developer_platforms::models::PullRequestFile;
+///
+/// let files = vec![PullRequestFile {
+///     filename: "certs/server.pem".to_string(),
+///     additions: 28,
+///     deletions: 0,
+///     changes: 28,
+///     status: "added".to_string(),
+/// }];
+/// let config = SuspiciousFilePolicyConfig {
+///     enabled: true,
+///     ..Default::default()
+/// };
+///
+/// let result = check_suspicious_files(&files, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(
+///     result.files[0].reason,
+///     SuspiciousFileReason::Secret("*.pem".to_string())
+/// );
+/// ```
+pub fn check_suspicious_files(
+    files: &[PullRequestFile],
+    config: &SuspiciousFilePolicyConfig,
+) -> SuspiciousFilesValidationResult {
+    let secret_files = config.secret_files();
+    let binary_files = config.binary_files();
+    let flagged: Vec<SuspiciousFile> = files
+        .iter()
+        .filter(|f| !is_deleted_file(f))
+        .filter_map(|f| {
+            let reason = if let Some(pattern) = secret_files.excluding_pattern(&f.filename) {
+                SuspiciousFileReason::Secret(pattern.to_string())
+            } else if let Some(pattern) = binary_files.excluding_pattern(&f.filename) {
+                SuspiciousFileReason::Binary(pattern.to_string())
+            } else if config.max_file_lines > 0 && f.additions > config.max_file_lines {
+                SuspiciousFileReason::TooLarge(f.additions)
+            } else {
+                return None;
+            };
+            Some(SuspiciousFile {
+                filename: f.filename.clone(),
+                reason,
+            })
+        })
+        .collect();
+
+    let validation = if flagged.is_empty() {
+        ValidationResult::valid()
+    } else {
+        ValidationResult::invalid()
+    };
+
+    SuspiciousFilesValidationResult {
+        validation,
+        files: flagged,
+    }
+}
+
+/// Result of checking the base branch of a PR against the base branch policy.
+///
+/// Returned by [`check_base_branch`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::BaseBranchValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = BaseBranchValidationResult {
+///     validation: ValidationResult::invalid(),
+///     base_branch: "main".to_string(),
+///     allowed_bases: vec!["release/*".to_string()],
+/// };
+/// assert!(!result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaseBranchValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The base branch the PR targets.
+    pub base_branch: String,
+
+    /// The allowed base branch patterns of the rule that applies to the PR, or empty
+    /// when no rule applies.
+    pub allowed_bases: Vec<String>,
+}
+
+impl BaseBranchValidationResult {
+    /// Returns `true` if the PR targets an allowed base branch or no rule applies to it.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Returns the conventional commit type of `title`, without its scope or `!`, or
+/// `None` when the title has no conventional commit prefix.
+fn title_type(title: &str) -> Option<&str> {
+    let prefix = &title[..title.find(':')?];
+    let end = prefix.find(['(', '!']).unwrap_or(prefix.len());
+    let title_type = prefix[..end].trim();
+    (!title_type.is_empty() && !title_type.contains(char::is_whitespace)).then_some(title_type)
+}
+
+/// Validates that a PR targets a base branch allowed for its type.
+///
+/// The first rule that applies to the PR's title type or head branch is used; a PR
+/// that no rule applies to passes. The check is skipped when the platform did not
+/// report the base branch.
+///
+/// # Arguments
+///
+/// * `pr` - The pull request to check
+/// * `config` - The base branch policy
+///
+/// # Returns
+///
+/// A [`BaseBranchValidationResult`] with the actual base branch and the allowed ones.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_base_branch;
+/// use merge_warden_core::config::{BaseBranchPolicyConfig, BaseBranchRule};
+/// use merge_warden_developer_platforms::models::PullRequest;
+///
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "hotfix: stop the login crash".to_string(),
+///     draft: false,
+///     body: None,
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: "main".to_string(),
+///     head_ref: "login-crash".to_string(),
+/// };
+/// let config = BaseBranchPolicyConfig {
+///     enabled: true,
+///     rules: vec![BaseBranchRule {
+///         title_types: vec!["hotfix".to_string()],
+///         allowed_bases: vec!["release/*".to_string()],
+///         ..Default::default()
+///     }],
+///     ..Default::default()
+/// };
+///
+/// let result = check_base_branch(&pr, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.allowed_bases, vec!["release/*"]);
+/// ```
+pub fn check_base_branch(
+    pr: &PullRequest,
+    config: &BaseBranchPolicyConfig,
+) -> BaseBranchValidationResult {
+    if pr.base_ref.is_empty() {
+        return BaseBranchValidationResult {
+            validation: ValidationResult::skipped("The base branch of the PR is not known"),
+            base_branch: String::new(),
+            allowed_bases: Vec::new(),
+        };
+    }
+
+    let rule = config.rule_for(title_type(&pr.title), &pr.head_ref);
+    let validation = match rule {
+        Some(rule) if !rule.allows_base(&pr.base_ref) => ValidationResult::invalid(),
+        _ => ValidationResult::valid(),
+    };
+
+    BaseBranchValidationResult {
+        validation,
+        base_branch: pr.base_ref.clone(),
+        allowed_bases: rule.map(|r| r.allowed_bases.clone()).unwrap_or_default(),
+    }
+}
+
+/// Labels of one exclusive label group that are applied to the same PR.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelConflict {
+    /// The name of the group.
+    pub group: String,
+
+    /// The applied labels that belong to the group, in the order they are applied.
+    pub labels: Vec<String>,
+}
+
+/// Result of checking the labels of a PR against the exclusive label groups.
+///
+/// Returned by [`check_exclusive_labels`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExclusiveLabelsValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The groups with more than one applied label.
+    pub conflicts: Vec<LabelConflict>,
+}
+
+impl ExclusiveLabelsValidationResult {
+    /// Returns `true` if no group has more than one applied label.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Validates that a PR carries at most one label from each exclusive label group.
+///
+/// # Arguments
+///
+/// * `labels` - The names of the labels applied to the PR
+/// * `config` - The exclusive labels policy
+///
+/// # Returns
+///
+/// An [`ExclusiveLabelsValidationResult`] listing every group with conflicting labels.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_exclusive_labels;
+/// use merge_warden_core::config::{ExclusiveLabelGroup, ExclusiveLabelsPolicyConfig};
+///
+/// let config = ExclusiveLabelsPolicyConfig {
+///     enabled: true,
+///     groups: vec![ExclusiveLabelGroup {
+///         name: "priority".to_string(),
+///         labels: vec!["priority: high".to_string(), "priority: low".to_string()],
+///     }],
+///     ..Default::default()
+/// };
+///
+/// let labels = vec!["priority: high".to_string(), "priority: low".to_string()];
+/// let result = check_exclusive_labels(&labels, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.conflicts[0].labels, labels);
+/// ```
+pub fn check_exclusive_labels(
+    labels: &[String],
+    config: &ExclusiveLabelsPolicyConfig,
+) -> ExclusiveLabelsValidationResult {
+    let conflicts: Vec<LabelConflict> = config
+        .groups
+        .iter()
+        .filter_map(|group| {
+            let applied: Vec<String> = labels
+                .iter()
+                .filter(|label| group.contains(label))
+                .cloned()
+                .collect();
+            (applied.len() > 1).then(|| LabelConflict {
+                group: group.name.clone(),
+                labels: applied,
+            })
+        })
+        .collect();
+
+    ExclusiveLabelsValidationResult {
+        validation: if conflicts.is_empty() {
+            ValidationResult::valid()
+        } else {
+            ValidationResult::invalid()
+        },
+        conflicts,
+    }
+}
+
+/// Result of checking the labels of a PR against the label policy.
+///
+/// Returned by [`check_label_policy`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelPolicyValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The required label names or patterns that no applied label matches.
+    pub missing_labels: Vec<String>,
+
+    /// The applied labels that match a forbidden label name or pattern.
+    pub forbidden_labels: Vec<String>,
+}
+
+impl LabelPolicyValidationResult {
+    /// Returns `true` if every required label is applied and no forbidden label is.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Validates that a PR carries every required label and no forbidden label.
+///
+/// Required and forbidden labels are names or patterns with `*` wildcards, matched
+/// ignoring case. A required pattern is satisfied by any applied label it matches.
+///
+/// # Arguments
+///
+/// * `labels` - The names of the labels applied to the PR
+/// * `config` - The label policy
+///
+/// # Returns
+///
+/// A [`LabelPolicyValidationResult`] listing the missing required labels and the
+/// applied forbidden labels.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_label_policy;
+/// use merge_warden_core::config::LabelPolicyConfig;
+///
+/// let config = LabelPolicyConfig {
+///     enabled: true,
+///     required_labels: vec!["team: *".to_string()],
+///     forbidden_labels: vec!["do-not-merge".to_string()],
+///     ..Default::default()
+/// };
+///
+/// let labels = vec!["team: core".to_string()];
+/// assert!(check_label_policy(&labels, &config).is_valid());
+///
+/// let labels = vec!["do-not-merge".to_string()];
+/// let result = check_label_policy(&labels, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.missing_labels, vec!["team: *".to_string()]);
+/// assert_eq!(result.forbidden_labels, labels);
+/// ```
+pub fn check_label_policy(
+    labels: &[String],
+    config: &LabelPolicyConfig,
+) -> LabelPolicyValidationResult {
+    let applied: Vec<String> = labels.iter().map(|label| label.to_lowercase()).collect();
+    let missing_labels: Vec<String> = config
+        .required_labels
+        .iter()
+        .filter(|pattern| {
+            let pattern = pattern.to_lowercase();
+            !applied.iter().any(|label| pattern_matches(&pattern, label))
+        })
+        .cloned()
+        .collect();
+    let forbidden_labels: Vec<String> = labels
+        .iter()
+        .filter(|label| config.is_forbidden(label))
+        .cloned()
+        .collect();
+
+    LabelPolicyValidationResult {
+        validation: if missing_labels.is_empty() && forbidden_labels.is_empty() {
+            ValidationResult::valid()
+        } else {
+            ValidationResult::invalid()
+        },
+        missing_labels,
+        forbidden_labels,
+    }
+}
+
+/// Result of checking the commits of a PR for verified signatures.
+///
+/// Returned by [`check_signed_commits`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignedCommitsValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The commits whose signature is not verified, in PR order.
+    pub unverified_commits: Vec<CommitVerification>,
+}
+
+impl SignedCommitsValidationResult {
+    /// Returns `true` if every commit is verified or the check was bypassed.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Validates that every commit in a PR has a signature the platform verified.
+///
+/// The PR author is exempt when the `signed_commits` bypass rule allows them.
+///
+/// # Arguments
+///
+/// * `commits` - The verification state of each commit in the PR
+/// * `author` - The author of the PR, for bypass checking
+/// * `bypass_rule` - The `signed_commits` bypass rule
+///
+/// # Returns
+///
+/// A [`SignedCommitsValidationResult`] listing the unverified commits.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_signed_commits;
+/// use merge_warden_core::config::BypassRule;
+/// use merge_warden_developer_platforms::models::CommitVerification;
+///
+/// let commits = vec![
+///     CommitVerification { sha: "aaa111".to_string(), verified: true, reason: "valid".to_string() },
+///     CommitVerification { sha: "bbb222".to_string(), verified: false, reason: "unsigned".to_string() },
+/// ];
+///
+/// let result = check_signed_commits(&commits, None, &BypassRule::default());
+/// assert!(!result.is_valid());
+/// assert_eq!(result.unverified_commits[0].sha, "bbb222");
+/// ```
+pub fn check_signed_commits(
+    commits: &[CommitVerification],
+    author: Option<&User>,
+    bypass_rule: &BypassRule,
+) -> SignedCommitsValidationResult {
+    if let Some(user) = author.filter(|u| bypass_rule.can_bypass_validation(Some(u))) {
+        return SignedCommitsValidationResult {
+            validation: ValidationResult::bypassed(BypassInfo {
+                rule_type: BypassRuleType::SignedCommits,
+                user: user.login.clone(),
+            }),
+            unverified_commits: Vec::new(),
+        };
+    }
+
+    let unverified_commits: Vec<CommitVerification> =
+        commits.iter().filter(|c| !c.verified).cloned().collect();
+
+    SignedCommitsValidationResult {
+        validation: if unverified_commits.is_empty() {
+            ValidationResult::valid()
+        } else {
+            ValidationResult::invalid()
+        },
+        unverified_commits,
+    }
+}
+
+/// Returns `true` if `title` is the title of a revert PR.
+///
+/// A PR is a revert when its title has the `revert` conventional commit type, or
+/// starts with `Revert "` as the PRs GitHub opens from its revert button do.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::is_revert_title;
+///
+/// assert!(is_revert_title("revert: feat: add the login page"));
+/// assert!(is_revert_title("Revert \"feat: add the login page\""));
+/// assert!(!is_revert_title("fix: revert the timeout to 30 seconds"));
+/// ```
+pub fn is_revert_title(title: &str) -> bool {
+    title_type(title).is_some_and(|t| t.eq_ignore_ascii_case("revert"))
+        || title.starts_with("Revert \"")
+}
+
+/// Returns the references to reverted changes in `body`, in the order they appear.
+///
+/// PR and commit URLs and `owner/repo#NNN` references are returned as written,
+/// `#NNN` references without the text before them, and commit SHAs in lower case.
+/// Hex strings without both a letter and a digit are not taken for SHAs, so that
+/// words such as `defaced` and numbers such as dates are not mistaken for commits.
+fn extract_revert_references(body: &str) -> Vec<String> {
+    let mut references = Vec::new();
+    for captures in revert_reference_regex().captures_iter(body) {
+        let reference = if let Some(m) = captures.get(1).or_else(|| captures.get(2)) {
+            m.as_str().to_string()
+        } else if let Some(number) = captures.get(3) {
+            format!("#{}", number.as_str())
+        } else if let Some(sha) = captures.get(4).map(|m| m.as_str()) {
+            let is_sha = sha.chars().any(|c| c.is_ascii_digit())
+                && sha.chars().any(|c| c.is_ascii_alphabetic());
+            if !is_sha {
+                continue;
+            }
+            sha.to_lowercase()
+        } else {
+            continue;
+        };
+        if !references.contains(&reference) {
+            references.push(reference);
+        }
+    }
+    references
+}
+
+/// Result of checking that a revert PR references what it reverts.
+///
+/// Returned by [`check_revert_reference`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RevertReferenceValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The references to reverted PRs and commits found in the PR body.
+    pub references: Vec<String>,
+}
+
+impl RevertReferenceValidationResult {
+    /// Returns `true` if the PR is not a revert, references what it reverts or was
+    /// bypassed.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Validates that a revert PR references the PR or commit it reverts in its body.
+///
+/// PRs that are not reverts, as decided by [`is_revert_title`], are skipped. The
+/// given user is exempt when the `revert_reference` bypass rule allows them.
+///
+/// # Arguments
+///
+/// * `pr` - The pull request to check
+/// * `bypass_user` - The PR author, or the user who applied an authorised bypass label
+/// * `bypass_rule` - The `revert_reference` bypass rule
+///
+/// # Returns
+///
+/// A [`RevertReferenceValidationResult`] with the references found in the body.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_revert_reference;
+/// use merge_warden_core::config::BypassRule;
+/// use merge_warden_developer_platforms::models::PullRequest;
+///
+/// let mut pr = PullRequest {
+///     number: 2,
+///     title: "revert: feat: add the login page".to_string(),
+///     draft: false,
+///     body: Some("The login page broke sign-in.".to_string()),
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
+/// };
+/// assert!(!check_revert_reference(&pr, None, &BypassRule::default()).is_valid());
+///
+/// pr.body = Some("Reverts owner/repo#1".to_string());
+/// let result = check_revert_reference(&pr, None, &BypassRule::default());
+/// assert!(result.is_valid());
+/// assert_eq!(result.references, vec!["owner/repo#1"]);
+/// ```
+pub fn check_revert_reference(
+    pr: &PullRequest,
+    bypass_user: Option<&User>,
+    bypass_rule: &BypassRule,
+) -> RevertReferenceValidationResult {
+    if !is_revert_title(&pr.title) {
+        return RevertReferenceValidationResult {
+            validation: ValidationResult::skipped("The PR is not a revert"),
+            references: Vec::new(),
+        };
+    }
+
+    if let Some(user) = bypass_user.filter(|u| bypass_rule.can_bypass_validation(Some(u))) {
+        return RevertReferenceValidationResult {
+            validation: ValidationResult::bypassed(BypassInfo {
+                rule_type: BypassRuleType::RevertReference,
+                user: user.login.clone(),
+            }),
+            references: Vec::new(),
+        };
+    }
+
+    let references = extract_revert_references(pr.body.as_deref().unwrap_or_default());
+    RevertReferenceValidationResult {
+        validation: if references.is_empty() {
+            ValidationResult::invalid()
+        } else {
+            ValidationResult::valid()
+        },
+        references,
+    }
+}
+
+/// Result of checking how far a PR is behind its base branch.
+///
+/// Returned by [`check_up_to_date`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::UpToDateValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = UpToDateValidationResult {
+///     validation: ValidationResult::invalid(),
+///     base_branch: "main".to_string(),
+///     commits_behind: 3,
+/// };
+/// assert!(!result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpToDateValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The base branch the PR targets.
+    pub base_branch: String,
+
+    /// The number of commits on the base branch that the PR's head does not contain.
+    pub commits_behind: u64,
+}
+
+impl UpToDateValidationResult {
+    /// Returns `true` if the PR is fewer commits behind its base than the threshold.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Validates that a PR is not too far behind its base branch.
+///
+/// The PR fails the check when it is at least `behind_by_threshold` commits behind;
+/// a threshold of `0` is treated as `1`.
+///
+/// # Arguments
+///
+/// * `base_branch` - The base branch the PR targets
+/// * `commits_behind` - The number of commits on the base branch that the PR's head
+///   does not contain
+/// * `config` - The up-to-date policy
+///
+/// # Returns
+///
+/// An [`UpToDateValidationResult`] with the number of commits the PR is behind.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_up_to_date;
+/// use merge_warden_core::config::UpToDatePolicyConfig;
+///
+/// let config = UpToDatePolicyConfig {
+///     enabled: true,
+///     behind_by_threshold: 5,
+///     ..Default::default()
+/// };
+///
+/// assert!(check_up_to_date("main", 4, &config).is_valid());
+/// assert!(!check_up_to_date("main", 5, &config).is_valid());
+/// ```
+pub fn check_up_to_date(
+    base_branch: &str,
+    commits_behind: u64,
+    config: &UpToDatePolicyConfig,
+) -> UpToDateValidationResult {
+    let threshold = u64::from(config.behind_by_threshold.max(1));
+    UpToDateValidationResult {
+        validation: if commits_behind < threshold {
+            ValidationResult::valid()
+        } else {
+            ValidationResult::invalid()
+        },
+        base_branch: base_branch.to_string(),
+        commits_behind,
+    }
+}
+
+/// Compiled once at first use. Matches a conventional-commit footer line: a token
+/// followed by `:` or ` #` and a value. `BREAKING CHANGE` is the only token that may
+/// contain a space.
+static FOOTER_LINE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Returns the compiled footer line regex, initialising it on first call.
+fn footer_line_regex() -> &'static Regex {
+    FOOTER_LINE_REGEX.get_or_init(|| {
+        Regex::new(r"^(BREAKING[ -]CHANGE|[A-Za-z][A-Za-z0-9-]*)(?::\s*| #)(.*)$")
+            .expect("footer line regex is valid")
+    })
+}
+
+/// A conventional-commit footer, such as `Signed-off-by: Jane <jane@example.com>`.
+///
+/// Returned by [`parse_footers`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Footer {
+    /// The footer token, such as `Signed-off-by`.
+    pub token: String,
+
+    /// The footer value, with continuation lines joined by newlines.
+    pub value: String,
+}
+
+/// Parses the conventional-commit footers from a pull request body.
+///
+/// Footers are read from the last paragraph of the body. The paragraph only counts as
+/// the footer area when every line in it is a footer (`Token: value` or
+/// `Token #value`) or an indented continuation of the previous footer's value;
+/// otherwise the body has no footers.
+///
+/// # Arguments
+///
+/// * `body` - The pull request description
+///
+/// # Returns
+///
+/// The footers in the order in which they appear.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::parse_footers;
+///
+/// let body = "Adds the thing.\n\nReviewed-by: Alex\nRefs #42";
+/// let footers = parse_footers(body);
+/// assert_eq!(footers.len(), 2);
+/// assert_eq!(footers[0].token, "Reviewed-by");
+/// assert_eq!(footers[0].value, "Alex");
+/// assert_eq!(footers[1].token, "Refs");
+/// assert_eq!(footers[1].value, "42");
+///
+/// assert!(parse_footers("Reviewed-by: Alex\nbut not really").is_empty());
+/// ```
+pub fn parse_footers(body: &str) -> Vec<Footer> {
+    let lines: Vec<&str> = body.lines().collect();
+    let end = match lines.iter().rposition(|line| !line.trim().is_empty()) {
+        Some(index) => index + 1,
+        None => return Vec::new(),
+    };
+    let start = lines[..end]
+        .iter()
+        .rposition(|line| line.trim().is_empty())
+        .map_or(0, |index| index + 1);
+
+    let mut footers: Vec<Footer> = Vec::new();
+    for line in &lines[start..end] {
+        if line.starts_with(char::is_whitespace) {
+            match footers.last_mut() {
+                Some(footer) => {
+                    footer.value.push('\n');
+                    footer.value.push_str(line.trim());
+                }
+                None => return Vec::new(),
+            }
+            continue;
+        }
+
+        match footer_line_regex().captures(line.trim_end()) {
+            Some(captures) => footers.push(Footer {
+                token: captures[1].to_string(),
+                value: captures[2].trim().to_string(),
+            }),
+            None => return Vec::new(),
+        }
+    }
+
+    footers
+}
+
+/// Result of checking the footers of a PR description against the footer policy.
+///
+/// Returned by [`check_required_footers`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::FooterValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = FooterValidationResult {
+///     validation: ValidationResult::invalid(),
+///     missing: vec!["Signed-off-by".to_string()],
+/// };
+/// assert!(!result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FooterValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// Tokens of the required footers that are missing, or that have no value matching
+    /// their pattern.
+    pub missing: Vec<String>,
+}
+
+impl FooterValidationResult {
+    /// Returns `true` if every required footer is present.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Validates that the required footers appear at the end of a PR description.
+///
+/// Footers are read with [`parse_footers`] and their tokens are compared
+/// case-insensitively. A required footer with a `pattern` is only present when at
+/// least one footer with its token has a value matching the pattern. A pattern that is
+/// not a valid regular expression is logged and the footer is reported as missing.
+///
+/// # Arguments
+///
+/// * `pr` - The pull request whose description is checked
+/// * `config` - The footer policy
+///
+/// # Returns
+///
+/// A [`FooterValidationResult`] listing the missing footers.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_required_footers;
+/// use merge_warden_core::config::{FooterPolicyConfig, RequiredFooter};
+/// use merge_warden_developer_platforms::models::PullRequest;
+///
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "feat: add footers".to_string(),
+///     draft: false,
+///     body: Some("Adds footers.\n\nSigned-off-by: Jane <jane@example.com>".to_string()),
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
+/// };
+///
+/// let config = FooterPolicyConfig {
+///     enabled: true,
+///     required: vec![
+///         RequiredFooter {
+///             token: "signed-off-by".to_string(),
+///             pattern: Some("<.+@.+>".to_string()),
+///         },
+///         RequiredFooter {
+///             token: "Reviewed-by".to_string(),
+///             pattern: None,
+///         },
+///     ],
+/// };
+/// let result = check_required_footers(&pr, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.missing, vec!["Reviewed-by".to_string()]);
+/// ```
+pub fn check_required_footers(
+    pr: &PullRequest,
+    config: &FooterPolicyConfig,
+) -> FooterValidationResult {
+    let footers = parse_footers(pr.body.as_deref().unwrap_or_default());
+
+    let mut missing = Vec::new();
+    for required in &config.required {
+        let pattern = match required.pattern.as_deref().map(Regex::new).transpose() {
+            Ok(pattern) => pattern,
+            Err(e) => {
+                warn!(
+                    token = %required.token,
+                    error = %e,
+                    "Invalid footer pattern; treating the footer as missing"
+                );
+                missing.push(required.token.clone());
+                continue;
+            }
+        };
+
+        let present = footers.iter().any(|footer| {
+            footer.token.eq_ignore_ascii_case(&required.token)
+                && pattern
+                    .as_ref()
+                    .is_none_or(|pattern| pattern.is_match(&footer.value))
+        });
+        if !present {
+            missing.push(required.token.clone());
+        }
+    }
+
+    FooterValidationResult {
+        validation: if missing.is_empty() {
+            ValidationResult::valid()
+        } else {
+            ValidationResult::invalid()
+        },
+        missing,
+    }
+}
+
+/// Result of checking that a PR title is not just its branch name.
+///
+/// Returned by [`check_descriptive_title`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::DescriptiveTitleValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = DescriptiveTitleValidationResult {
+///     validation: ValidationResult::invalid(),
+///     head_branch: "feature/add-login".to_string(),
+/// };
+/// assert!(!result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DescriptiveTitleValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The head branch the title was compared with.
+    pub head_branch: String,
+}
+
+impl DescriptiveTitleValidationResult {
+    /// Returns `true` if the title is more than the branch name.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Lower-cases `text` and reduces the separators `-`, `_`, `/` and `.` and any run of
+/// whitespace to single spaces.
+fn normalize_title_words(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| c.is_whitespace() || matches!(c, '-' | '_' | '/' | '.'))
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Validates that a PR title describes the change rather than repeating the branch name.
+///
+/// The title fails when, after ignoring case and treating `-`, `_`, `/` and `.` as
+/// spaces, it equals the head branch name or the part of the name after its last `/`,
+/// for example `feature/add-login`, `Feature/add login` or `feat: add login` for the
+/// branch `feature/add-login`. The conventional commit prefix of the title is ignored.
+/// The check is skipped when the platform did not report the head branch.
+///
+/// # Arguments
+///
+/// * `pr` - The pull request to check
+///
+/// # Returns
+///
+/// A [`DescriptiveTitleValidationResult`] with the head branch.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_descriptive_title;
+/// use merge_warden_developer_platforms::models::PullRequest;
+///
+/// let mut pr = PullRequest {
+///     number: 1,
+///     title: "Feature/add login".to_string(),
+///     draft: false,
+///     body: None,
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: "main".to_string(),
+///     head_ref: "feature/add-login".to_string(),
+/// };
+/// assert!(!check_descriptive_title(&pr).is_valid());
+///
+/// pr.title = "feat: add login with single sign-on".to_string();
+/// assert!(check_descriptive_title(&pr).is_valid());
+/// ```
+pub fn check_descriptive_title(pr: &PullRequest) -> DescriptiveTitleValidationResult {
+    if pr.head_ref.is_empty() {
+        return DescriptiveTitleValidationResult {
+            validation: ValidationResult::skipped("The head branch of the PR is not known"),
+            head_branch: String::new(),
+        };
+    }
+
+    let title = normalize_title_words(&pr.title);
+    let description = match title_type(&pr.title) {
+        Some(_) => pr.title.split_once(':').map_or("", |(_, rest)| rest),
+        None => pr.title.as_str(),
+    };
+    let description = normalize_title_words(description);
+
+    let branch = normalize_title_words(&pr.head_ref);
+    let branch_name = normalize_title_words(
+        pr.head_ref
+            .rsplit_once('/')
+            .map_or(pr.head_ref.as_str(), |(_, name)| name),
+    );
+
+    let repeats_branch = [title, description].iter().any(|candidate| {
+        !candidate.is_empty() && (*candidate == branch || *candidate == branch_name)
+    });
+
+    DescriptiveTitleValidationResult {
+        validation: if repeats_branch {
+            ValidationResult::invalid()
+        } else {
+            ValidationResult::valid()
+        },
+        head_branch: pr.head_ref.clone(),
+    }
+}
+
+/// Result of counting the distinct change types of a PR.
+///
+/// Returned by [`check_change_types`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::ChangeTypesValidationResult;
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = ChangeTypesValidationResult {
+///     validation: ValidationResult::invalid(),
+///     change_types: vec!["feat".to_string(), "fix".to_string(), "refactor".to_string()],
+///     max_change_types: 2,
+/// };
+/// assert!(!result.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeTypesValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The distinct change types, in lower case, in the order they were found: the
+    /// title's type first, then those of the commits.
+    pub change_types: Vec<String>,
+
+    /// The most distinct change types the PR may have.
+    pub max_change_types: usize,
+}
+
+impl ChangeTypesValidationResult {
+    /// Returns `true` if the PR has no more change types than allowed.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+}
+
+/// Validates that a PR does not mix more change types than allowed.
+///
+/// The change types are the conventional commit types of the PR title and of the
+/// subject lines of its commits, ignoring case. Only the types of [`VALID_PR_TYPES`]
+/// are counted, so commits without a conventional commit prefix, such as merge
+/// commits, do not add a type. Types in the policy's `ignored_types` are not counted.
+///
+/// # Arguments
+///
+/// * `title` - The title of the pull request
+/// * `commits` - The commits of the pull request
+/// * `config` - The change types policy
+///
+/// # Returns
+///
+/// A [`ChangeTypesValidationResult`] with the distinct change types found.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_change_types;
+/// use merge_warden_core::config::ChangeTypesPolicyConfig;
+/// use merge_warden_developer_platforms::models::PullRequestCommit;
+///
+/// let commits: Vec<PullRequestCommit> = ["fix: handle empty input", "docs: describe the parser"]
+///     .into_iter()
+///     .map(|message| PullRequestCommit {
+///         sha: String::new(),
+///         message: message.to_string(),
+///     })
+///     .collect();
+/// let config = ChangeTypesPolicyConfig {
+///     enabled: true,
+///     ..Default::default()
+/// };
+///
+/// let result = check_change_types("feat: add a parser", &commits, &config);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.change_types, vec!["feat", "fix", "docs"]);
+/// ```
+pub fn check_change_types(
+    title: &str,
+    commits: &[PullRequestCommit],
+    config: &ChangeTypesPolicyConfig,
+) -> ChangeTypesValidationResult {
+    let mut change_types: Vec<String> = Vec::new();
+
+    let subjects = std::iter::once(title).chain(commits.iter().map(PullRequestCommit::subject));
+    for subject in subjects {
+        let Some(change_type) = title_type(subject).map(str::to_lowercase) else {
+            continue;
+        };
+        if VALID_PR_TYPES.contains(&change_type.as_str())
+            && !config.is_ignored(&change_type)
+            && !change_types.contains(&change_type)
+        {
+            change_types.push(change_type);
+        }
+    }
+
+    ChangeTypesValidationResult {
+        validation: if change_types.len() > config.max_change_types {
+            ValidationResult::invalid()
+        } else {
+            ValidationResult::valid()
+        },
+        change_types,
+        max_change_types: config.max_change_types,
+    }
+}
+
+/// Result of checking a version change against the change level of a PR.
+///
+/// Returned by [`check_version_bump`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VersionBumpValidationResult {
+    /// The underlying validation outcome.
+    pub validation: ValidationResult,
+
+    /// The version before the PR.
+    pub previous_version: Version,
+
+    /// The version at the head of the PR.
+    pub new_version: Version,
+
+    /// The smallest bump the changes in the PR require.
+    pub required_level: BumpLevel,
+
+    /// The version the PR should have bumped to, or `None` when the version is unchanged.
+    pub expected_version: Option<Version>,
+}
+
+impl VersionBumpValidationResult {
+    /// Returns `true` if the version is unchanged or bumped consistently with the changes.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validation.is_valid()
+    }
+
+    /// Returns `true` if the PR changes the version.
+    pub fn is_bumped(&self) -> bool {
+        self.expected_version.is_some()
+    }
+}
+
+/// Validates that a version change follows semantic versioning for the required bump level.
+///
+/// A version that is left unchanged passes: only PRs that change the version are
+/// treated as release PRs. A changed version must be the next version at a level
+/// of at least `required_level`, with the lower parts reset to `0`. Bumping more
+/// than required is accepted; skipping versions or lowering the version is not.
+/// Pre-release and build metadata are ignored.
+///
+/// # Arguments
+///
+/// * `previous` - The version before the PR
+/// * `new` - The version at the head of the PR
+/// * `required_level` - The smallest bump the changes in the PR require
+///
+/// # Returns
+///
+/// A [`VersionBumpValidationResult`] that includes the expected version when the
+/// version was changed.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::checks::check_version_bump;
+/// use merge_warden_core::version::{BumpLevel, Version};
+///
+/// let result = check_version_bump(&Version::new(1, 2, 3), &Version::new(1, 2, 4), BumpLevel::Minor);
+/// assert!(!result.is_valid());
+/// assert_eq!(result.expected_version, Some(Version::new(1, 3, 0)));
+/// ```
+pub fn check_version_bump(
+    previous: &Version,
+    new: &Version,
+    required_level: BumpLevel,
+) -> VersionBumpValidationResult {
+    let unchanged =
+        (previous.major, previous.minor, previous.patch) == (new.major, new.minor, new.patch);
+
+    let (validation, expected_version) = if unchanged {
+        (ValidationResult::valid(), None)
+    } else {
+        match previous.bump_level_to(new) {
+            Some(level) if level >= required_level => {
+                let expected = previous.bumped(level);
+                let is_next = (expected.major, expected.minor, expected.patch)
+                    == (new.major, new.minor, new.patch);
+                let validation = if is_next {
+                    ValidationResult::valid()
+                } else {
+                    ValidationResult::invalid()
+                };
+                (validation, Some(expected))
+            }
+            _ => (
+                ValidationResult::invalid(),
+                Some(previous.bumped(required_level)),
+            ),
+        }
+    };
+
+    VersionBumpValidationResult {
+        validation,
+        previous_version: previous.clone(),
+        new_version: new.clone(),
+        required_level,
+        expected_version,
+    }
+}
@@ -0,0 +1,10 @@
+//! # Clock
+//!
+//! This module re-exports the [`Clock`] abstraction through which Merge Warden reads
+//! the current time.
+//!
+//! The clock is defined in `merge_warden_developer_platforms` so that the platform
+//! code, such as the installation token cache, and the validation logic share one
+//! time source.
+
+pub use merge_warden_developer_platforms::clock::{Clock, ManualClock, SystemClock};
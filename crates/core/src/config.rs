@@ -7,11 +7,15 @@ use merge_warden_developer_platforms::{
     ConfigFetcher, RepositoryMetadataProvider,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use tracing::{debug, error, info, warn};
 
 use crate::errors::ConfigLoadError;
-use crate::size::SizeThresholds;
+use crate::size::{
+    FileExclusionPreset, FileExclusions, PrSizeCategory, ReviewEffortWeights, SizeCategoryNames,
+    SizeCountingMode, SizeThresholds,
+};
+use crate::version::ManifestFormat;
 
 #[cfg(test)]
 #[path = "config_tests.rs"]
@@ -53,12 +57,39 @@ pub const RENOVATE_STABILITY_CHECK_CONTEXT: &str = "renovate/stability-days";
 /// Default label name applied while the Renovate stability period has not elapsed.
 pub const RENOVATE_STABILITY_LABEL: &str = "pr-validation: pending-stability";
 
+/// Default label name applied to pull requests that update dependencies.
+pub const DEPENDENCIES_LABEL: &str = "dependencies";
+
 /// HTML comment marker used to identify configuration validity status comments.
 ///
 /// Merge Warden uses this marker to find and update (or delete) configuration
 /// validation comments so that only one such comment exists on a PR at any time.
 pub const CONFIG_COMMENT_MARKER: &str = "<!-- MERGE_WARDEN_CONFIG_CHECK -->";
 
+/// HTML comment marker identifying the comment that starts the guidance thread.
+///
+/// Used when [`CommentPolicyConfig::threaded`] is enabled; guidance comments are
+/// posted as replies to the threaded comment carrying this marker.
+pub const THREAD_ROOT_COMMENT_MARKER: &str = "<!-- MERGE_WARDEN_THREAD -->";
+
+/// HTML comment marker identifying the comment posted when the check conclusion changes.
+///
+/// Used when [`CommentPolicyConfig::transition_summary`] is enabled; the previous
+/// transition comment is replaced so that only one exists on a PR at any time.
+pub const TRANSITION_COMMENT_MARKER: &str = "<!-- MERGE_WARDEN_TRANSITION -->";
+
+/// HTML comment marker identifying the comment posted when a failing pull request is
+/// converted to a draft.
+///
+/// Used when [`DraftConversionPolicyConfig::enabled`] is set.
+pub const DRAFT_CONVERSION_COMMENT_MARKER: &str = "<!-- MERGE_WARDEN_DRAFT_CONVERSION -->";
+
+/// HTML comment marker identifying the comment posted when Merge Warden edits the title
+/// or description of a pull request to fix a failing check.
+///
+/// Used when [`AutofixPolicyConfig`] enables a fix.
+pub const AUTOFIX_COMMENT_MARKER: &str = "<!-- MERGE_WARDEN_AUTOFIX -->";
+
 /// Path to the repository-provided merge-warden configuration file.
 ///
 /// When a PR touches this file, Merge Warden fetches and validates its content and
@@ -66,6 +97,19 @@ pub const CONFIG_COMMENT_MARKER: &str = "<!-- MERGE_WARDEN_CONFIG_CHECK -->";
 /// and never affects the check conclusion.
 pub const CONFIG_FILE_PATH: &str = ".github/merge-warden.toml";
 
+/// Paths of the pull request template, in the order GitHub looks for them.
+///
+/// When [`WorkItemPolicyConfig::pr_template_guidance`] is enabled, the first template
+/// found on the default branch is used to tailor the missing work item comment.
+pub const PULL_REQUEST_TEMPLATE_PATHS: [&str; 6] = [
+    ".github/pull_request_template.md",
+    ".github/PULL_REQUEST_TEMPLATE.md",
+    "pull_request_template.md",
+    "PULL_REQUEST_TEMPLATE.md",
+    "docs/pull_request_template.md",
+    "docs/PULL_REQUEST_TEMPLATE.md",
+];
+
 /// The outcome of validating the content of a repository-provided configuration file.
 ///
 /// This type is returned by [`validate_config_content`] and carries both a boolean
@@ -97,7 +141,8 @@ pub struct ConfigValidationOutcome {
 ///
 /// Semantic validation (e.g., verifying that label names, regex patterns, or other
 /// field values are meaningful) is **out of scope** for this function.  Callers
-/// should not rely on this function to catch application-level misconfiguration.
+/// should not rely on this function to catch application-level misconfiguration;
+/// [`lint_config_content`] also checks the regex and glob patterns.
 ///
 /// # Error message format
 ///
@@ -144,6 +189,142 @@ pub fn validate_config_content(content: &str) -> ConfigValidationOutcome {
     }
 }
 
+/// Lints the content of a merge-warden configuration file.
+///
+/// Runs the checks of [`validate_config_content`] and then converts the parsed
+/// configuration into a [`CurrentPullRequestValidationConfiguration`] to check the
+/// values the policies use at runtime:
+///
+/// 1. The title pattern, every work item pattern and every required footer pattern
+///    must compile as regular expressions.
+/// 2. The gitignore-style path patterns (`prSize.excluded_file_patterns`,
+///    `pathSkip.skip_if_only_paths`, `dependencyUpdates.lockfile_patterns`,
+///    `dependencyUpdates.manifest_patterns`, `suspiciousFiles.secret_patterns`,
+///    `suspiciousFiles.binary_patterns` and the `deleted` and `requires` patterns of
+///    the `deletedFiles.rules`) must be valid globs.
+///
+/// Every problem found is reported, not just the first. A file that is not valid
+/// TOML cannot be checked further and only reports the parse error.
+///
+/// # Examples
+///
+/// ```rust
+/// use merge_warden_core::config::lint_config_content;
+///
+/// let content = r#"
+/// schemaVersion = 1
+///
+/// [policies.pullRequests.prTitle]
+/// pattern = "^(feat|fix"
+///
+/// [policies.pullRequests.pathSkip]
+/// skip_if_only_paths = ["docs/{a,b"]
+/// "#;
+/// let outcome = lint_config_content(content);
+/// assert!(!outcome.valid);
+/// assert_eq!(outcome.errors.len(), 2);
+/// ```
+pub fn lint_config_content(content: &str) -> ConfigValidationOutcome {
+    let config = match toml::from_str::<RepositoryProvidedConfig>(content) {
+        Ok(config) => config,
+        Err(e) => {
+            return ConfigValidationOutcome {
+                valid: false,
+                errors: vec![e.to_string()],
+            }
+        }
+    };
+
+    let mut errors = Vec::new();
+    if config.schema_version != 1 {
+        errors.push(format!(
+            "schemaVersion must be 1, found {}",
+            config.schema_version
+        ));
+    }
+
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    let mut check_regex = |key: String, pattern: &str| {
+        if let Err(e) = regex::Regex::new(pattern) {
+            errors.push(format!(
+                "{}: invalid regular expression `{}`: {}",
+                key, pattern, e
+            ));
+        }
+    };
+    check_regex(
+        "policies.pullRequests.prTitle.pattern".to_string(),
+        &validation.title_pattern,
+    );
+    let work_item_patterns = validation.work_item_reference_pattern.patterns();
+    for (index, pattern) in work_item_patterns.iter().enumerate() {
+        let key = if work_item_patterns.len() > 1 {
+            format!("policies.pullRequests.workItem.pattern[{}]", index)
+        } else {
+            "policies.pullRequests.workItem.pattern".to_string()
+        };
+        check_regex(key, pattern);
+    }
+    for (index, footer) in validation.footers.required.iter().enumerate() {
+        if let Some(pattern) = &footer.pattern {
+            check_regex(
+                format!("policies.pullRequests.footers.required[{}].pattern", index),
+                pattern,
+            );
+        }
+    }
+
+    let glob_lists = [
+        (
+            "policies.pullRequests.prSize.excluded_file_patterns",
+            &validation.pr_size_check.excluded_file_patterns,
+        ),
+        (
+            "policies.pullRequests.pathSkip.skip_if_only_paths",
+            &validation.path_skip.skip_if_only_paths,
+        ),
+        (
+            "policies.pullRequests.dependencyUpdates.lockfile_patterns",
+            &validation.dependency_updates.lockfile_patterns,
+        ),
+        (
+            "policies.pullRequests.dependencyUpdates.manifest_patterns",
+            &validation.dependency_updates.manifest_patterns,
+        ),
+        (
+            "policies.pullRequests.suspiciousFiles.secret_patterns",
+            &validation.suspicious_files.secret_patterns,
+        ),
+        (
+            "policies.pullRequests.suspiciousFiles.binary_patterns",
+            &validation.suspicious_files.binary_patterns,
+        ),
+    ];
+    let mut check_glob = |key: String, pattern: &str| {
+        if let Err(e) = FileExclusions::validate_pattern(pattern) {
+            errors.push(format!("{}: invalid pattern `{}`: {}", key, pattern, e));
+        }
+    };
+    for (key, patterns) in glob_lists {
+        for (index, pattern) in patterns.iter().enumerate() {
+            check_glob(format!("{}[{}]", key, index), pattern);
+        }
+    }
+    for (index, rule) in validation.deleted_files.rules.iter().enumerate() {
+        let key = format!("policies.pullRequests.deletedFiles.rules[{}]", index);
+        check_glob(format!("{}.deleted", key), &rule.deleted);
+        for (required_index, pattern) in rule.requires.iter().enumerate() {
+            check_glob(format!("{}.requires[{}]", key, required_index), pattern);
+        }
+    }
+
+    ConfigValidationOutcome {
+        valid: errors.is_empty(),
+        errors,
+    }
+}
+
 /// Pre-compiled regex for conventional commit format validation
 ///
 /// This regex enforces the Conventional Commits specification (https://conventionalcommits.org/)
@@ -364,9 +545,9 @@ pub struct ApplicationDefaults {
     #[serde(default = "ApplicationDefaults::default_work_item_required")]
     pub enable_work_item_validation: bool,
 
-    /// Default regex pattern for validating work item references
+    /// Default regex pattern(s) for validating work item references
     #[serde(default = "ApplicationDefaults::default_work_item_pattern")]
-    pub default_work_item_pattern: String,
+    pub default_work_item_pattern: WorkItemPatterns,
 
     /// Default label to apply when work item reference is missing
     #[serde(default = "ApplicationDefaults::default_work_item_missing_label")]
@@ -396,6 +577,114 @@ pub struct ApplicationDefaults {
     #[serde(default)]
     pub renovate_stability: RenovateStabilityConfig,
 
+    /// Application-level defaults for the check-run summary table
+    #[serde(default)]
+    pub check_summary: CheckSummaryConfig,
+
+    /// Application-level defaults for the required checklist check
+    #[serde(default)]
+    pub checklist: ChecklistPolicyConfig,
+
+    /// Application-level defaults for the unresolved review-thread check
+    #[serde(default)]
+    pub review_threads: ReviewThreadPolicyConfig,
+
+    /// Application-level defaults for the deleted-file pairing check
+    #[serde(default)]
+    pub deleted_files: DeletedFilePolicyConfig,
+
+    /// Application-level defaults for the version bump check
+    #[serde(default)]
+    pub version_bump: VersionBumpPolicyConfig,
+
+    /// Application-level defaults for the check order and short-circuiting
+    #[serde(default)]
+    pub check_execution: CheckExecutionConfig,
+
+    /// Application-level defaults for dependency-update detection and labelling
+    #[serde(default)]
+    pub dependency_updates: DependencyUpdateConfig,
+
+    /// Application-level defaults for the PR title emoji and character limits
+    #[serde(default)]
+    pub title_characters: TitleCharacterPolicyConfig,
+
+    /// Application-level defaults for the commit-lint style PR title rules
+    #[serde(default)]
+    pub title_lint: TitleLintPolicyConfig,
+
+    /// Application-level defaults for the suspicious file check
+    #[serde(default)]
+    pub suspicious_files: SuspiciousFilePolicyConfig,
+
+    /// Application-level defaults for handling reopened pull requests
+    #[serde(default)]
+    pub reopened: ReopenedPolicyConfig,
+
+    /// Application-level defaults for managing the invalid-title and missing-work-item labels
+    #[serde(default)]
+    pub status_labels: StatusLabelPolicyConfig,
+
+    /// Application-level defaults for how guidance comments are posted
+    #[serde(default)]
+    pub comments: CommentPolicyConfig,
+
+    /// Application-level defaults for the base branch check
+    #[serde(default)]
+    pub base_branch: BaseBranchPolicyConfig,
+
+    /// Application-level defaults for the exclusive labels check
+    #[serde(default)]
+    pub exclusive_labels: ExclusiveLabelsPolicyConfig,
+
+    /// Default settings for skipping pull requests that only change ignored paths.
+    #[serde(default)]
+    pub path_skip: PathSkipPolicyConfig,
+
+    /// Default signed commits check applied to all repositories
+    #[serde(default)]
+    pub signed_commits: SignedCommitsPolicyConfig,
+
+    /// Default revert reference check applied to all repositories
+    #[serde(default)]
+    pub revert_reference: RevertReferencePolicyConfig,
+
+    /// Default label creation policy applied to all repositories
+    #[serde(default)]
+    pub label_creation: LabelCreationPolicyConfig,
+
+    /// Default issue reference verification applied to all repositories
+    #[serde(default)]
+    pub issue_references: IssueReferencePolicyConfig,
+
+    /// Default up-to-date check applied to all repositories
+    #[serde(default)]
+    pub up_to_date: UpToDatePolicyConfig,
+
+    /// Default required footer check applied to all repositories
+    #[serde(default)]
+    pub footers: FooterPolicyConfig,
+
+    /// Default conversion of failing pull requests to drafts
+    #[serde(default)]
+    pub draft_conversion: DraftConversionPolicyConfig,
+
+    /// Default automatic fixes of failing checks
+    #[serde(default)]
+    pub autofix: AutofixPolicyConfig,
+
+    /// Default descriptive title check applied to all repositories
+    #[serde(default)]
+    pub descriptive_title: DescriptiveTitlePolicyConfig,
+
+    /// Default change types check applied to all repositories
+    #[serde(default)]
+    pub change_types: ChangeTypesPolicyConfig,
+
+    /// Default label policy check applied to all repositories
+    #[serde(default)]
+    pub label_policy: LabelPolicyConfig,
+
     /// Bot mention prefix used for comment-based label suppression.
     ///
     /// PR participants post a comment line of the form `<bot_mention> suppress: <label-name>`
@@ -405,6 +694,23 @@ pub struct ApplicationDefaults {
     #[serde(default = "ApplicationDefaults::default_bot_mention")]
     pub bot_mention: String,
 
+    /// Whether to append a note naming the configuration sources to the check output.
+    ///
+    /// Intended for troubleshooting: the note states whether the repository TOML file,
+    /// the org policy file, or only the application defaults were used for a PR.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub show_config_source: bool,
+
+    /// URL to which the server posts the result of every processed pull request.
+    ///
+    /// Intended for internal dashboards: the server sends the serialized
+    /// [`crate::CheckResult`] with the repository and pull request number as a JSON
+    /// `POST`. Failed deliveries are retried and then logged; they never fail the
+    /// processing of the pull request. `None` (the default) posts nothing.
+    #[serde(default)]
+    pub result_callback_url: Option<String>,
+
     /// Optional pointer to an org-level policy file.
     ///
     /// When `None`, the system behaves identically to the three-tier configuration
@@ -427,6 +733,21 @@ pub struct ApplicationDefaults {
     /// an event is processed at all, not how it is validated.
     #[serde(default)]
     pub repository_scope: Option<RepositoryScope>,
+
+    /// How the server handles GitHub merge queue events.
+    ///
+    /// Like `repository_scope`, this is not part of the [`PolicySet`] merge chain:
+    /// it controls which events are processed, not how a pull request is validated.
+    #[serde(default)]
+    pub merge_queue: MergeQueueConfig,
+
+    /// Feature flags that turn checks on or off per organization.
+    ///
+    /// Not part of the [`PolicySet`] merge chain: the flags for the repository's
+    /// owner are applied after the chain is resolved, so they take precedence over
+    /// every configuration tier. See [`FeatureFlagsConfig`].
+    #[serde(default)]
+    pub feature_flags: FeatureFlagsConfig,
 }
 
 impl ApplicationDefaults {
@@ -451,8 +772,8 @@ impl ApplicationDefaults {
     }
 
     /// Default regex pattern for work item validation
-    fn default_work_item_pattern() -> String {
-        WORK_ITEM_REGEX.to_string()
+    fn default_work_item_pattern() -> WorkItemPatterns {
+        WorkItemPatterns::default()
     }
 
     /// Default value for work item validation requirement (false)
@@ -481,9 +802,40 @@ impl Default for ApplicationDefaults {
             wip_check: WipCheckConfig::default(),
             pr_state_labels: PrStateLabelsConfig::default(),
             renovate_stability: RenovateStabilityConfig::default(),
+            check_summary: CheckSummaryConfig::default(),
+            checklist: ChecklistPolicyConfig::default(),
+            review_threads: ReviewThreadPolicyConfig::default(),
+            deleted_files: DeletedFilePolicyConfig::default(),
+            version_bump: VersionBumpPolicyConfig::default(),
+            check_execution: CheckExecutionConfig::default(),
+            dependency_updates: DependencyUpdateConfig::default(),
+            title_characters: TitleCharacterPolicyConfig::default(),
+            title_lint: TitleLintPolicyConfig::default(),
+            suspicious_files: SuspiciousFilePolicyConfig::default(),
+            reopened: ReopenedPolicyConfig::default(),
+            status_labels: StatusLabelPolicyConfig::default(),
+            comments: CommentPolicyConfig::default(),
+            base_branch: BaseBranchPolicyConfig::default(),
+            exclusive_labels: ExclusiveLabelsPolicyConfig::default(),
+            path_skip: PathSkipPolicyConfig::default(),
+            signed_commits: SignedCommitsPolicyConfig::default(),
+            revert_reference: RevertReferencePolicyConfig::default(),
+            label_creation: LabelCreationPolicyConfig::default(),
+            issue_references: IssueReferencePolicyConfig::default(),
+            up_to_date: UpToDatePolicyConfig::default(),
+            footers: FooterPolicyConfig::default(),
+            draft_conversion: DraftConversionPolicyConfig::default(),
+            autofix: AutofixPolicyConfig::default(),
+            descriptive_title: DescriptiveTitlePolicyConfig::default(),
+            change_types: ChangeTypesPolicyConfig::default(),
+            label_policy: LabelPolicyConfig::default(),
             bot_mention: ApplicationDefaults::default_bot_mention(),
+            show_config_source: false,
+            result_callback_url: None,
             org_policy_source: None,
             repository_scope: None,
+            merge_queue: MergeQueueConfig::default(),
+            feature_flags: FeatureFlagsConfig::default(),
         }
     }
 }
@@ -534,6 +886,67 @@ pub struct OrgPolicySource {
     pub fail_if_unreachable: bool,
 }
 
+/// Records which configuration layers contributed to the configuration of a PR.
+///
+/// Filled in by [`resolve_pull_request_config`] and rendered into the check output
+/// when [`ApplicationDefaults::show_config_source`] is enabled. Application defaults
+/// always form the bottom layer and are therefore not recorded explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::ConfigSource;
+///
+/// let source = ConfigSource {
+///     repository_file: Some(".github/merge-warden.toml".to_string()),
+///     org_policy_file: None,
+///     matched_conditional_policies: 0,
+/// };
+/// assert_eq!(
+///     source.to_string(),
+///     "Config: repo .github/merge-warden.toml + application defaults"
+/// );
+///
+/// assert_eq!(
+///     ConfigSource::default().to_string(),
+///     "Config: application defaults"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigSource {
+    /// Path of the repository configuration file, when one was found and used.
+    pub repository_file: Option<String>,
+
+    /// Location of the org policy file (`owner/repo:path`), when one was loaded.
+    pub org_policy_file: Option<String>,
+
+    /// Number of conditional org policies whose condition matched the repository.
+    pub matched_conditional_policies: usize,
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut layers = Vec::new();
+        if let Some(path) = &self.repository_file {
+            layers.push(format!("repo {}", path));
+        }
+        if let Some(location) = &self.org_policy_file {
+            match self.matched_conditional_policies {
+                0 => layers.push(format!("org {}", location)),
+                n => layers.push(format!(
+                    "org {} ({} conditional polic{} matched)",
+                    location,
+                    n,
+                    if n == 1 { "y" } else { "ies" }
+                )),
+            }
+        }
+        layers.push("application defaults".to_string());
+
+        write!(f, "Config: {}", layers.join(" + "))
+    }
+}
+
 /// Repository allow/deny scope filter (FR-009: Repository Scope Filtering).
 ///
 /// Added to [`ApplicationDefaults`] as an optional field. When absent
@@ -583,6 +996,100 @@ pub struct RepositoryScope {
     pub exclude_patterns: Vec<String>,
 }
 
+/// Feature flags (the `[policies.feature_flags]` table of the application
+/// configuration).
+///
+/// Lets operators roll out checks to some organizations before others, without
+/// changing code or repository configuration. Each flag is named after the key of a
+/// check, such as `footers` or `descriptive_title`, and turns that check on (`true`)
+/// or off (`false`). A flag overrides the repository and org configuration; a check
+/// without a flag keeps its configured state, so every flag is unset by default.
+///
+/// The flags in `organizations` apply to the repositories of one owner and take
+/// precedence over those in `defaults`. Owners are matched ignoring case.
+///
+/// # TOML example
+///
+/// ```toml
+/// [policies.feature_flags.defaults]
+/// descriptive_title = false
+///
+/// [policies.feature_flags.organizations.acme]
+/// descriptive_title = true
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::FeatureFlagsConfig;
+///
+/// let flags: FeatureFlagsConfig = toml::from_str(r#"
+/// [defaults]
+/// descriptive_title = false
+/// footers = true
+///
+/// [organizations.acme]
+/// descriptive_title = true
+/// "#).unwrap();
+///
+/// let acme = flags.flags_for("Acme");
+/// assert_eq!(acme.get("descriptive_title"), Some(&true));
+/// assert_eq!(acme.get("footers"), Some(&true));
+/// assert_eq!(flags.flags_for("other").get("descriptive_title"), Some(&false));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlagsConfig {
+    /// Flags for every organization, keyed by flag name.
+    #[serde(default)]
+    pub defaults: BTreeMap<String, bool>,
+
+    /// Flags for single organizations, keyed by organization and then by flag name.
+    #[serde(default)]
+    pub organizations: BTreeMap<String, BTreeMap<String, bool>>,
+}
+
+impl FeatureFlagsConfig {
+    /// Returns the flags that apply to the repositories of `organization`.
+    ///
+    /// These are the `defaults`, overridden by the flags of the organization.
+    pub fn flags_for(&self, organization: &str) -> BTreeMap<String, bool> {
+        let mut flags = self.defaults.clone();
+        for (name, org_flags) in &self.organizations {
+            if name.eq_ignore_ascii_case(organization) {
+                flags.extend(org_flags.iter().map(|(flag, &on)| (flag.clone(), on)));
+            }
+        }
+        flags
+    }
+}
+
+/// Merge queue handling (the `[policies.merge_queue]` table of the application
+/// configuration).
+///
+/// GitHub validates a pull request in a merge queue on a temporary merge group
+/// commit, and sends a `merge_group` event asking for the required checks to be
+/// reported on it. When Merge Warden's check is required and this is disabled, the
+/// check never appears on the merge group and the queue waits until it times out.
+///
+/// When enabled, the server answers a `merge_group` event with `checks_requested` by
+/// validating the pull request the merge group was created for, as for a
+/// `synchronize` event, and reporting the conclusion on the merge group commit as
+/// well. When disabled (the default), `merge_group` events are ignored, so nothing is
+/// reported twice for repositories that do not require the check in the queue.
+///
+/// # TOML example
+///
+/// ```toml
+/// [policies.merge_queue]
+/// enabled = true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeQueueConfig {
+    /// Whether `merge_group` events are processed. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
 /// Internal deserialisation type for a single condition inside a conditional policy block.
 ///
 /// Used only during TOML parsing; converted to [`PolicyCondition`] by `load_org_policy`.
@@ -857,6 +1364,13 @@ pub struct BypassRule {
 
     /// List of GitHub usernames allowed to bypass this rule
     users: Vec<String>,
+
+    /// Name of a PR label that bypasses this rule.
+    ///
+    /// The label is only honoured when the user who applied it is in `users`;
+    /// labels applied by anyone else are ignored and logged for audit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
 }
 
 impl BypassRule {
@@ -922,7 +1436,31 @@ impl BypassRule {
     /// assert_eq!(rule.users(), vec!["admin", "bot"]);
     /// ```
     pub fn new(enabled: bool, users: Vec<String>) -> Self {
-        Self { enabled, users }
+        Self {
+            enabled,
+            users,
+            label: None,
+        }
+    }
+
+    /// Sets the name of the PR label that bypasses this rule
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::BypassRule;
+    ///
+    /// let rule = BypassRule::new(true, vec!["maintainer".to_string()]).with_label("skip-checks");
+    /// assert_eq!(rule.label(), Some("skip-checks"));
+    /// ```
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Returns the name of the PR label that bypasses this rule, if one is configured
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
     }
 
     /// Returns the list of usernames allowed to bypass this rule
@@ -944,6 +1482,8 @@ impl BypassRule {
 ///
 /// - `title_convention` - Bypass for pull request title format validation
 /// - `work_items` - Bypass for work item reference validation
+/// - `signed_commits` - Bypass for the signed commits check
+/// - `revert_reference` - Bypass for the revert reference check
 /// - `branch_protection` - Reserved for future branch protection bypasses
 ///
 /// # Examples
@@ -969,6 +1509,29 @@ pub struct BypassRules {
     /// Bypass rule for PR size validation
     #[serde(default)]
     size: BypassRule,
+
+    /// Bypass rule that turns Merge Warden off for a PR entirely.
+    ///
+    /// Only the rule's label activates it: when the label was applied by an allowed
+    /// user, the check is reported as neutral and no labels or comments are managed.
+    #[serde(default)]
+    all: BypassRule,
+
+    /// Bypass rule for the signed commits check
+    #[serde(default)]
+    signed_commits: BypassRule,
+
+    /// Bypass rule for the revert reference check
+    #[serde(default)]
+    revert_reference: BypassRule,
+
+    /// Login used for bypass decisions when a PR has no author.
+    ///
+    /// GitHub reports no author for PRs opened by deleted accounts. When this is
+    /// `None` such PRs can never bypass a rule through its user list; when set, the
+    /// PR is evaluated as if this login had opened it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    missing_author_login: Option<String>,
 }
 
 impl BypassRules {
@@ -993,6 +1556,10 @@ impl BypassRules {
             title_convention,
             work_items,
             size: BypassRule::default(),
+            all: BypassRule::default(),
+            signed_commits: BypassRule::default(),
+            revert_reference: BypassRule::default(),
+            missing_author_login: None,
         }
     }
 
@@ -1012,9 +1579,80 @@ impl BypassRules {
             title_convention,
             work_items,
             size,
+            all: BypassRule::default(),
+            signed_commits: BypassRule::default(),
+            revert_reference: BypassRule::default(),
+            missing_author_login: None,
         }
     }
 
+    /// Returns these rules with the given rule for disabling Merge Warden on a PR.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::{BypassRule, BypassRules};
+    ///
+    /// let rules = BypassRules::default().with_all(
+    ///     BypassRule::new(true, vec!["release-manager".to_string()])
+    ///         .with_label("merge-warden:disabled"),
+    /// );
+    /// assert_eq!(rules.all().label(), Some("merge-warden:disabled"));
+    /// ```
+    pub fn with_all(mut self, all: BypassRule) -> Self {
+        self.all = all;
+        self
+    }
+
+    /// Returns these rules with the given rule for bypassing the signed commits check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::{BypassRule, BypassRules};
+    ///
+    /// let rules = BypassRules::default().with_signed_commits(
+    ///     BypassRule::new(true, vec!["release-bot".to_string()]),
+    /// );
+    /// assert_eq!(rules.signed_commits().users(), vec!["release-bot"]);
+    /// ```
+    pub fn with_signed_commits(mut self, signed_commits: BypassRule) -> Self {
+        self.signed_commits = signed_commits;
+        self
+    }
+
+    /// Returns these rules with the given rule for bypassing the revert reference check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::{BypassRule, BypassRules};
+    ///
+    /// let rules = BypassRules::default().with_revert_reference(
+    ///     BypassRule::new(true, vec!["release-manager".to_string()]),
+    /// );
+    /// assert_eq!(rules.revert_reference().users(), vec!["release-manager"]);
+    /// ```
+    pub fn with_revert_reference(mut self, revert_reference: BypassRule) -> Self {
+        self.revert_reference = revert_reference;
+        self
+    }
+
+    /// Returns these rules with a login to evaluate bypasses for PRs without an author.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::BypassRules;
+    ///
+    /// let rules = BypassRules::default().with_missing_author_login("ghost");
+    /// assert_eq!(rules.missing_author_login(), Some("ghost"));
+    /// ```
+    pub fn with_missing_author_login(mut self, login: impl Into<String>) -> Self {
+        self.missing_author_login = Some(login.into());
+        self
+    }
+
     /// Returns the bypass rule for title convention validation
     pub fn title_convention(&self) -> &BypassRule {
         &self.title_convention
@@ -1030,20 +1668,42 @@ impl BypassRules {
         &self.size
     }
 
+    /// Returns the bypass rule that disables Merge Warden on a PR
+    pub fn all(&self) -> &BypassRule {
+        &self.all
+    }
+
+    /// Returns the bypass rule for the signed commits check
+    pub fn signed_commits(&self) -> &BypassRule {
+        &self.signed_commits
+    }
+
+    /// Returns the bypass rule for the revert reference check
+    pub fn revert_reference(&self) -> &BypassRule {
+        &self.revert_reference
+    }
+
+    /// Returns the login used for bypass decisions when a PR has no author, if any
+    pub fn missing_author_login(&self) -> Option<&str> {
+        self.missing_author_login.as_deref()
+    }
+
     /// Merges `over` on top of `base` (lower-priority).
     ///
-    /// For each sub-rule (`title_convention`, `work_items`, `size`):
+    /// For each sub-rule (`title_convention`, `work_items`, `size`, `all`,
+    /// `signed_commits`, `revert_reference`):
     /// use the `over` sub-rule if it has been explicitly configured (its user list
-    /// is non-empty, or its `enabled` flag differs from the default `false`;
-    /// otherwise keep `base`'s sub-rule.
+    /// is non-empty, its `enabled` flag differs from the default `false`, or it
+    /// names a bypass label); otherwise keep `base`'s sub-rule.
+    /// `missing_author_login` is taken from `over` when set, otherwise from `base`.
     ///
     /// See `docs/spec/interfaces/policy-engine.md` §2.8 for the full contract.
     pub(crate) fn merge(base: &Self, over: &Self) -> Self {
-        // A sub-rule is "explicitly configured" when its enabled flag is set or
-        // it names at least one user.  An unconfigured `over` sub-rule defers to
-        // the corresponding `base` sub-rule.
+        // A sub-rule is "explicitly configured" when its enabled flag is set,
+        // it names at least one user, or it names a bypass label.  An unconfigured
+        // `over` sub-rule defers to the corresponding `base` sub-rule.
         fn is_configured(rule: &BypassRule) -> bool {
-            rule.enabled || !rule.users.is_empty()
+            rule.enabled || !rule.users.is_empty() || rule.label.is_some()
         }
 
         Self {
@@ -1062,6 +1722,25 @@ impl BypassRules {
             } else {
                 base.size.clone()
             },
+            all: if is_configured(&over.all) {
+                over.all.clone()
+            } else {
+                base.all.clone()
+            },
+            signed_commits: if is_configured(&over.signed_commits) {
+                over.signed_commits.clone()
+            } else {
+                base.signed_commits.clone()
+            },
+            revert_reference: if is_configured(&over.revert_reference) {
+                over.revert_reference.clone()
+            } else {
+                base.revert_reference.clone()
+            },
+            missing_author_login: over
+                .missing_author_login
+                .clone()
+                .or_else(|| base.missing_author_login.clone()),
         }
     }
 }
@@ -1102,6 +1781,26 @@ pub struct BypassRulesConfig {
     /// `None` means the server-level default is used for this rule.
     #[serde(default)]
     size: Option<BypassRule>,
+
+    /// Per-repo override for the rule that disables Merge Warden on a PR.
+    /// `None` means the server-level default is used for this rule.
+    #[serde(default)]
+    all: Option<BypassRule>,
+
+    /// Per-repo override for the signed commits bypass.
+    /// `None` means the server-level default is used for this rule.
+    #[serde(default)]
+    signed_commits: Option<BypassRule>,
+
+    /// Per-repo override for the revert reference bypass.
+    /// `None` means the server-level default is used for this rule.
+    #[serde(default)]
+    revert_reference: Option<BypassRule>,
+
+    /// Per-repo login used for bypass decisions on PRs without an author.
+    /// `None` means the server-level default is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    missing_author_login: Option<String>,
 }
 
 impl BypassRulesConfig {
@@ -1115,6 +1814,10 @@ impl BypassRulesConfig {
             title_convention: Some(rules.title_convention().clone()),
             work_items: Some(rules.work_item_convention().clone()),
             size: Some(rules.size().clone()),
+            all: Some(rules.all().clone()),
+            signed_commits: Some(rules.signed_commits().clone()),
+            revert_reference: Some(rules.revert_reference().clone()),
+            missing_author_login: rules.missing_author_login.clone(),
         }
     }
 
@@ -1133,6 +1836,26 @@ impl BypassRulesConfig {
         self.size.as_ref()
     }
 
+    /// Returns the per-repo rule for disabling Merge Warden on a PR, if configured.
+    pub fn all(&self) -> Option<&BypassRule> {
+        self.all.as_ref()
+    }
+
+    /// Returns the per-repo signed commits bypass rule, if configured.
+    pub fn signed_commits(&self) -> Option<&BypassRule> {
+        self.signed_commits.as_ref()
+    }
+
+    /// Returns the per-repo revert reference bypass rule, if configured.
+    pub fn revert_reference(&self) -> Option<&BypassRule> {
+        self.revert_reference.as_ref()
+    }
+
+    /// Returns the per-repo login used for PRs without an author, if configured.
+    pub fn missing_author_login(&self) -> Option<&str> {
+        self.missing_author_login.as_deref()
+    }
+
     /// Converts this config into a [`BypassRules`] value.
     ///
     /// Each sub-rule that is present is used directly; absent sub-rules become
@@ -1149,11 +1872,16 @@ impl BypassRulesConfig {
     ///
     /// [`unwrap_or_default`]: Option::unwrap_or_default
     pub(crate) fn to_bypass_rules(&self) -> BypassRules {
-        BypassRules::new_with_size(
+        let mut rules = BypassRules::new_with_size(
             self.title_convention().cloned().unwrap_or_default(),
             self.work_item_convention().cloned().unwrap_or_default(),
             self.size().cloned().unwrap_or_default(),
         )
+        .with_all(self.all().cloned().unwrap_or_default())
+        .with_signed_commits(self.signed_commits().cloned().unwrap_or_default())
+        .with_revert_reference(self.revert_reference().cloned().unwrap_or_default());
+        rules.missing_author_login = self.missing_author_login.clone();
+        rules
     }
 }
 
@@ -1222,11 +1950,20 @@ pub struct CurrentPullRequestValidationConfiguration {
     /// Whether to require work item references in PR descriptions
     pub enforce_work_item_references: bool,
 
-    /// The regular expression used to determine if a work item reference exists
-    pub work_item_reference_pattern: String,
+    /// The regular expressions used to determine if a work item reference exists.
+    /// A reference is found when any one of them matches.
+    pub work_item_reference_pattern: WorkItemPatterns,
     /// The label to apply when no work item reference is found. No label will be applied if set to `None`.
     pub missing_work_item_label: Option<String>,
 
+    /// Whether the missing work item comment points to the way the repository's pull
+    /// request template references work items.
+    pub work_item_pr_template_guidance: bool,
+
+    /// The number of distinct work item references the PR description must contain.
+    /// Defaults to `1`; `0` requires no reference.
+    pub min_work_item_references: usize,
+
     /// Configuration for PR size checking
     pub pr_size_check: PrSizeCheckConfig,
 
@@ -1242,6 +1979,87 @@ pub struct CurrentPullRequestValidationConfiguration {
     /// Configuration for Renovate stability-days label management.
     pub renovate_stability: RenovateStabilityConfig,
 
+    /// Presentation settings for the check-run summary table.
+    pub check_summary: CheckSummaryConfig,
+
+    /// Configuration for the required checklist check.
+    pub checklist: ChecklistPolicyConfig,
+
+    /// Configuration for the unresolved review-thread check.
+    pub review_threads: ReviewThreadPolicyConfig,
+
+    /// Configuration for the deleted-file pairing check.
+    pub deleted_files: DeletedFilePolicyConfig,
+
+    /// Configuration for the version bump check.
+    pub version_bump: VersionBumpPolicyConfig,
+
+    /// Order in which the checks run and whether to stop after the first blocking failure.
+    pub check_execution: CheckExecutionConfig,
+
+    /// Configuration for detecting and labelling dependency-update PRs.
+    pub dependency_updates: DependencyUpdateConfig,
+
+    /// Configuration for the PR title emoji and character limits.
+    pub title_characters: TitleCharacterPolicyConfig,
+
+    /// Configuration for the commit-lint style PR title rules.
+    pub title_lint: TitleLintPolicyConfig,
+
+    /// Configuration for the suspicious file check.
+    pub suspicious_files: SuspiciousFilePolicyConfig,
+
+    /// Configuration for handling reopened pull requests.
+    pub reopened: ReopenedPolicyConfig,
+
+    /// Configuration for managing the invalid-title and missing-work-item labels.
+    pub status_labels: StatusLabelPolicyConfig,
+
+    /// Configuration for how guidance comments are posted.
+    pub comments: CommentPolicyConfig,
+
+    /// Configuration for the base branch check.
+    pub base_branch: BaseBranchPolicyConfig,
+
+    /// Configuration for the exclusive labels check.
+    pub exclusive_labels: ExclusiveLabelsPolicyConfig,
+
+    /// Configuration for skipping pull requests that only change ignored paths.
+    pub path_skip: PathSkipPolicyConfig,
+
+    /// Signed commits check configuration
+    pub signed_commits: SignedCommitsPolicyConfig,
+
+    /// Revert reference check configuration
+    pub revert_reference: RevertReferencePolicyConfig,
+
+    /// Whether labels that do not exist in the repository may be created
+    pub label_creation: LabelCreationPolicyConfig,
+
+    /// Whether same-repository issue references must point at existing issues
+    pub issue_references: IssueReferencePolicyConfig,
+
+    /// Configuration for the up-to-date check and its label
+    pub up_to_date: UpToDatePolicyConfig,
+
+    /// Footers that must appear at the end of the PR description
+    pub footers: FooterPolicyConfig,
+
+    /// Configuration for converting failing pull requests to drafts
+    pub draft_conversion: DraftConversionPolicyConfig,
+
+    /// Configuration for automatically fixing failing checks
+    pub autofix: AutofixPolicyConfig,
+
+    /// Configuration for the check that the PR title is not just the branch name
+    pub descriptive_title: DescriptiveTitlePolicyConfig,
+
+    /// Configuration for the check that limits the distinct change types in a PR
+    pub change_types: ChangeTypesPolicyConfig,
+
+    /// Configuration for the check of required and forbidden labels
+    pub label_policy: LabelPolicyConfig,
+
     /// Rules for bypassing validation checks
     pub bypass_rules: BypassRules,
 
@@ -1250,9 +2068,165 @@ pub struct CurrentPullRequestValidationConfiguration {
 
     /// Bot mention prefix used to parse label suppression commands from PR comments.
     pub bot_mention: String,
+
+    /// Whether to append the configuration source note to the check output.
+    pub show_config_source: bool,
+
+    /// The configuration layers this configuration was resolved from.
+    pub config_source: ConfigSource,
+
+    /// The feature flags applied to this configuration, keyed by flag name.
+    ///
+    /// Set by [`CurrentPullRequestValidationConfiguration::apply_feature_flags`] and
+    /// logged for every processed pull request.
+    pub feature_flags: BTreeMap<String, bool>,
 }
 
 impl CurrentPullRequestValidationConfiguration {
+    /// Returns the conventional commit types a title may use, when the title lint
+    /// policy restricts them.
+    ///
+    /// The types are the `allowed_types` of [`Self::title_lint`], and are only
+    /// returned while that policy is enabled. They replace the standard types of the
+    /// default title pattern, [`CONVENTIONAL_COMMIT_REGEX`], and are the types that
+    /// change-type labels are applied for. `None` means the standard types of
+    /// [`VALID_PR_TYPES`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::{
+    ///     CurrentPullRequestValidationConfiguration, TitleLintPolicyConfig,
+    /// };
+    ///
+    /// let mut config = CurrentPullRequestValidationConfiguration::default();
+    /// assert_eq!(config.allowed_title_types(), None);
+    ///
+    /// config.title_lint = TitleLintPolicyConfig {
+    ///     enabled: true,
+    ///     allowed_types: vec!["feat".to_string(), "deps".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     config.allowed_title_types(),
+    ///     Some(&["feat".to_string(), "deps".to_string()][..])
+    /// );
+    /// ```
+    pub fn allowed_title_types(&self) -> Option<&[String]> {
+        let lint = &self.title_lint;
+        (lint.enabled && !lint.allowed_types.is_empty()).then_some(lint.allowed_types.as_slice())
+    }
+
+    /// Returns `true` when adding or removing a label can change the outcome of a run.
+    ///
+    /// This is the case when the label policy or exclusive label groups are enabled,
+    /// when an enabled bypass rule, including the one that disables Merge Warden, names a
+    /// label, or when a size exclusion names a label. Label changes on pull requests
+    /// need only be processed when this returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::CurrentPullRequestValidationConfiguration;
+    ///
+    /// let mut config = CurrentPullRequestValidationConfiguration::default();
+    /// assert!(!config.depends_on_labels());
+    ///
+    /// config.label_policy.enabled = true;
+    /// config.label_policy.forbidden_labels = vec!["do-not-merge".to_string()];
+    /// assert!(config.depends_on_labels());
+    /// ```
+    pub fn depends_on_labels(&self) -> bool {
+        let rules = &self.bypass_rules;
+        let bypass_labels = [
+            rules.title_convention(),
+            rules.work_item_convention(),
+            rules.size(),
+            rules.all(),
+            rules.signed_commits(),
+            rules.revert_reference(),
+        ]
+        .iter()
+        .any(|rule| rule.enabled() && rule.label().is_some());
+
+        self.label_policy.enabled
+            || self.exclusive_labels.enabled
+            || bypass_labels
+            || self
+                .pr_size_check
+                .exclusions
+                .iter()
+                .any(|exclusion| exclusion.label.is_some())
+    }
+
+    /// Enables or disables the check of the given kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::{CheckKind, CurrentPullRequestValidationConfiguration};
+    ///
+    /// let mut config = CurrentPullRequestValidationConfiguration::default();
+    /// config.set_check_enabled(CheckKind::Footers, true);
+    /// config.set_check_enabled(CheckKind::Title, false);
+    /// assert!(config.footers.enabled);
+    /// assert!(!config.enforce_title_convention);
+    /// ```
+    pub fn set_check_enabled(&mut self, kind: CheckKind, enabled: bool) {
+        match kind {
+            CheckKind::Title => self.enforce_title_convention = enabled,
+            CheckKind::WorkItem => self.enforce_work_item_references = enabled,
+            CheckKind::Checklist => self.checklist.enabled = enabled,
+            CheckKind::ReviewThreads => self.review_threads.enabled = enabled,
+            CheckKind::Size => self.pr_size_check.enabled = enabled,
+            CheckKind::VersionBump => self.version_bump.enabled = enabled,
+            CheckKind::DeletedFiles => self.deleted_files.enabled = enabled,
+            CheckKind::SuspiciousFiles => self.suspicious_files.enabled = enabled,
+            CheckKind::BaseBranch => self.base_branch.enabled = enabled,
+            CheckKind::ExclusiveLabels => self.exclusive_labels.enabled = enabled,
+            CheckKind::SignedCommits => self.signed_commits.enabled = enabled,
+            CheckKind::RevertReference => self.revert_reference.enabled = enabled,
+            CheckKind::UpToDate => self.up_to_date.enabled = enabled,
+            CheckKind::Footers => self.footers.enabled = enabled,
+            CheckKind::DescriptiveTitle => self.descriptive_title.enabled = enabled,
+            CheckKind::ChangeTypes => self.change_types.enabled = enabled,
+            CheckKind::LabelPolicy => self.label_policy.enabled = enabled,
+        }
+    }
+
+    /// Applies feature flags on top of the resolved configuration.
+    ///
+    /// Each flag is named after the key of a check, as used in
+    /// `checkExecution.order`, and overrides whether that check is enabled, whatever
+    /// the repository and org configuration say. Checks without a flag keep their
+    /// configured state. Flags with an unknown name are logged and ignored. The flags
+    /// are kept in [`Self::feature_flags`].
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - The flags to apply, as returned by [`FeatureFlagsConfig::flags_for`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::CurrentPullRequestValidationConfiguration;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut config = CurrentPullRequestValidationConfiguration::default();
+    /// config.apply_feature_flags(BTreeMap::from([("signed_commits".to_string(), true)]));
+    /// assert!(config.signed_commits.enabled);
+    /// assert_eq!(config.feature_flags.len(), 1);
+    /// ```
+    pub fn apply_feature_flags(&mut self, flags: BTreeMap<String, bool>) {
+        for (name, &enabled) in &flags {
+            match CheckKind::from_key(name) {
+                Some(kind) => self.set_check_enabled(kind, enabled),
+                None => warn!(flag = name.as_str(), "Unknown feature flag; ignoring it"),
+            }
+        }
+        self.feature_flags = flags;
+    }
+
     /// Constructs a baseline [`CurrentPullRequestValidationConfiguration`] from
     /// application defaults alone, without any repo or org overrides.
     ///
@@ -1271,14 +2245,46 @@ impl CurrentPullRequestValidationConfiguration {
             enforce_work_item_references: app.enable_work_item_validation,
             work_item_reference_pattern: app.default_work_item_pattern.clone(),
             missing_work_item_label: app.default_missing_work_item_label.clone(),
+            work_item_pr_template_guidance: false,
+            min_work_item_references: 1,
             pr_size_check: app.pr_size_check.clone(),
             change_type_labels: Some(app.change_type_labels.clone()),
             wip_check: app.wip_check.clone(),
             pr_state_labels: app.pr_state_labels.clone(),
             renovate_stability: app.renovate_stability.clone(),
+            check_summary: app.check_summary.clone(),
+            checklist: app.checklist.clone(),
+            review_threads: app.review_threads.clone(),
+            deleted_files: app.deleted_files.clone(),
+            version_bump: app.version_bump.clone(),
+            check_execution: app.check_execution.clone(),
+            dependency_updates: app.dependency_updates.clone(),
+            title_characters: app.title_characters.clone(),
+            title_lint: app.title_lint.clone(),
+            suspicious_files: app.suspicious_files.clone(),
+            reopened: app.reopened.clone(),
+            status_labels: app.status_labels.clone(),
+            comments: app.comments.clone(),
+            base_branch: app.base_branch.clone(),
+            exclusive_labels: app.exclusive_labels.clone(),
+            path_skip: app.path_skip.clone(),
+            signed_commits: app.signed_commits.clone(),
+            revert_reference: app.revert_reference.clone(),
+            label_creation: app.label_creation.clone(),
+            issue_references: app.issue_references.clone(),
+            up_to_date: app.up_to_date.clone(),
+            footers: app.footers.clone(),
+            draft_conversion: app.draft_conversion.clone(),
+            autofix: app.autofix.clone(),
+            descriptive_title: app.descriptive_title.clone(),
+            change_types: app.change_types.clone(),
+            label_policy: app.label_policy.clone(),
             bypass_rules: app.bypass_rules.clone(),
             issue_propagation: IssuePropagationConfig::default(),
             bot_mention: app.bot_mention.clone(),
+            show_config_source: app.show_config_source,
+            config_source: ConfigSource::default(),
+            feature_flags: BTreeMap::new(),
         }
     }
 
@@ -1304,19 +2310,51 @@ impl CurrentPullRequestValidationConfiguration {
             invalid_title_label,
             enforce_work_item_references,
             work_item_reference_pattern: if let Some(pattern) = work_item_reference_pattern {
-                pattern
+                pattern.into()
             } else {
-                WORK_ITEM_REGEX.to_string()
+                WorkItemPatterns::default()
             },
             missing_work_item_label,
+            work_item_pr_template_guidance: false,
+            min_work_item_references: 1,
             pr_size_check: pr_size_check.unwrap_or_default(),
             change_type_labels: None, // Use default behavior for tests
             wip_check: WipCheckConfig::default(),
             pr_state_labels: PrStateLabelsConfig::default(),
             renovate_stability: RenovateStabilityConfig::default(),
+            check_summary: CheckSummaryConfig::default(),
+            checklist: ChecklistPolicyConfig::default(),
+            review_threads: ReviewThreadPolicyConfig::default(),
+            deleted_files: DeletedFilePolicyConfig::default(),
+            version_bump: VersionBumpPolicyConfig::default(),
+            check_execution: CheckExecutionConfig::default(),
+            dependency_updates: DependencyUpdateConfig::default(),
+            title_characters: TitleCharacterPolicyConfig::default(),
+            title_lint: TitleLintPolicyConfig::default(),
+            suspicious_files: SuspiciousFilePolicyConfig::default(),
+            reopened: ReopenedPolicyConfig::default(),
+            status_labels: StatusLabelPolicyConfig::default(),
+            comments: CommentPolicyConfig::default(),
+            base_branch: BaseBranchPolicyConfig::default(),
+            exclusive_labels: ExclusiveLabelsPolicyConfig::default(),
+            path_skip: PathSkipPolicyConfig::default(),
+            signed_commits: SignedCommitsPolicyConfig::default(),
+            revert_reference: RevertReferencePolicyConfig::default(),
+            label_creation: LabelCreationPolicyConfig::default(),
+            issue_references: IssueReferencePolicyConfig::default(),
+            up_to_date: UpToDatePolicyConfig::default(),
+            footers: FooterPolicyConfig::default(),
+            draft_conversion: DraftConversionPolicyConfig::default(),
+            autofix: AutofixPolicyConfig::default(),
+            descriptive_title: DescriptiveTitlePolicyConfig::default(),
+            change_types: ChangeTypesPolicyConfig::default(),
+            label_policy: LabelPolicyConfig::default(),
             bypass_rules: bypass_rules.unwrap_or_default(),
             issue_propagation: IssuePropagationConfig::default(),
             bot_mention: "@merge-warden".to_string(),
+            show_config_source: false,
+            config_source: ConfigSource::default(),
+            feature_flags: BTreeMap::new(),
         }
     }
 }
@@ -1328,16 +2366,48 @@ impl Default for CurrentPullRequestValidationConfiguration {
             title_pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
             invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
             enforce_work_item_references: true,
-            work_item_reference_pattern: WORK_ITEM_REGEX.to_string(),
+            work_item_reference_pattern: WorkItemPatterns::default(),
             missing_work_item_label: Some(MISSING_WORK_ITEM_LABEL.to_string()),
+            work_item_pr_template_guidance: false,
+            min_work_item_references: 1,
             pr_size_check: PrSizeCheckConfig::default(),
             change_type_labels: None, // Default to None, will be populated from app defaults
             wip_check: WipCheckConfig::default(),
             pr_state_labels: PrStateLabelsConfig::default(),
             renovate_stability: RenovateStabilityConfig::default(),
+            check_summary: CheckSummaryConfig::default(),
+            checklist: ChecklistPolicyConfig::default(),
+            review_threads: ReviewThreadPolicyConfig::default(),
+            deleted_files: DeletedFilePolicyConfig::default(),
+            version_bump: VersionBumpPolicyConfig::default(),
+            check_execution: CheckExecutionConfig::default(),
+            dependency_updates: DependencyUpdateConfig::default(),
+            title_characters: TitleCharacterPolicyConfig::default(),
+            title_lint: TitleLintPolicyConfig::default(),
+            suspicious_files: SuspiciousFilePolicyConfig::default(),
+            reopened: ReopenedPolicyConfig::default(),
+            status_labels: StatusLabelPolicyConfig::default(),
+            comments: CommentPolicyConfig::default(),
+            base_branch: BaseBranchPolicyConfig::default(),
+            exclusive_labels: ExclusiveLabelsPolicyConfig::default(),
+            path_skip: PathSkipPolicyConfig::default(),
+            signed_commits: SignedCommitsPolicyConfig::default(),
+            revert_reference: RevertReferencePolicyConfig::default(),
+            label_creation: LabelCreationPolicyConfig::default(),
+            issue_references: IssueReferencePolicyConfig::default(),
+            up_to_date: UpToDatePolicyConfig::default(),
+            footers: FooterPolicyConfig::default(),
+            draft_conversion: DraftConversionPolicyConfig::default(),
+            autofix: AutofixPolicyConfig::default(),
+            descriptive_title: DescriptiveTitlePolicyConfig::default(),
+            change_types: ChangeTypesPolicyConfig::default(),
+            label_policy: LabelPolicyConfig::default(),
             bypass_rules: BypassRules::default(),
             issue_propagation: IssuePropagationConfig::default(),
             bot_mention: "@merge-warden".to_string(),
+            show_config_source: false,
+            config_source: ConfigSource::default(),
+            feature_flags: BTreeMap::new(),
         }
     }
 }
@@ -1389,6 +2459,114 @@ pub struct PullRequestsPoliciesConfig {
     /// Configuration for Renovate stability-days label management.
     #[serde(default, rename = "renovateStability")]
     pub renovate_stability: RenovateStabilityConfig,
+
+    /// Presentation settings for the check-run summary table.
+    #[serde(default, rename = "checkSummary")]
+    pub check_summary: CheckSummaryConfig,
+
+    /// Configuration for the required checklist check.
+    #[serde(default, rename = "checklist")]
+    pub checklist: ChecklistPolicyConfig,
+
+    /// Configuration for the unresolved review-thread check.
+    #[serde(default, rename = "reviewThreads")]
+    pub review_threads: ReviewThreadPolicyConfig,
+
+    /// Configuration for the deleted-file pairing check.
+    #[serde(default, rename = "deletedFiles")]
+    pub deleted_files: DeletedFilePolicyConfig,
+
+    /// Configuration for the version bump check.
+    #[serde(default, rename = "versionBump")]
+    pub version_bump: VersionBumpPolicyConfig,
+
+    /// Order in which the checks run and whether to stop after the first blocking failure.
+    #[serde(default, rename = "checkExecution")]
+    pub check_execution: CheckExecutionConfig,
+
+    /// Configuration for detecting and labelling dependency-update PRs.
+    #[serde(default, rename = "dependencyUpdates")]
+    pub dependency_updates: DependencyUpdateConfig,
+
+    /// Configuration for the PR title emoji and character limits.
+    #[serde(default, rename = "titleCharacters")]
+    pub title_characters: TitleCharacterPolicyConfig,
+
+    /// Configuration for the commit-lint style PR title rules.
+    #[serde(default, rename = "titleLint")]
+    pub title_lint: TitleLintPolicyConfig,
+
+    /// Configuration for the suspicious file check.
+    #[serde(default, rename = "suspiciousFiles")]
+    pub suspicious_files: SuspiciousFilePolicyConfig,
+
+    /// Configuration for handling reopened pull requests.
+    #[serde(default, rename = "reopened")]
+    pub reopened: ReopenedPolicyConfig,
+
+    /// Configuration for managing the invalid-title and missing-work-item labels.
+    #[serde(default, rename = "statusLabels")]
+    pub status_labels: StatusLabelPolicyConfig,
+
+    /// Configuration for how guidance comments are posted.
+    #[serde(default, rename = "comments")]
+    pub comments: CommentPolicyConfig,
+
+    /// Configuration for the base branch check.
+    #[serde(default, rename = "baseBranch")]
+    pub base_branch: BaseBranchPolicyConfig,
+
+    /// Configuration for the exclusive labels check.
+    #[serde(default, rename = "exclusiveLabels")]
+    pub exclusive_labels: ExclusiveLabelsPolicyConfig,
+
+    /// Configuration for skipping pull requests that only change ignored paths.
+    #[serde(default, rename = "pathSkip")]
+    pub path_skip: PathSkipPolicyConfig,
+
+    /// Signed commits check configuration
+    #[serde(default, rename = "signedCommits")]
+    pub signed_commits: SignedCommitsPolicyConfig,
+
+    /// Revert reference check configuration
+    #[serde(default, rename = "revertReference")]
+    pub revert_reference: RevertReferencePolicyConfig,
+
+    /// Label creation policy
+    #[serde(default, rename = "labelCreation")]
+    pub label_creation: LabelCreationPolicyConfig,
+
+    /// Issue reference verification
+    #[serde(default, rename = "issueReferences")]
+    pub issue_references: IssueReferencePolicyConfig,
+
+    /// Up-to-date check and its label
+    #[serde(default, rename = "upToDate")]
+    pub up_to_date: UpToDatePolicyConfig,
+
+    /// Required footers in the PR description
+    #[serde(default, rename = "footers")]
+    pub footers: FooterPolicyConfig,
+
+    /// Conversion of failing pull requests to drafts
+    #[serde(default, rename = "draftConversion")]
+    pub draft_conversion: DraftConversionPolicyConfig,
+
+    /// Automatic fixes of failing checks
+    #[serde(default, rename = "autofix")]
+    pub autofix: AutofixPolicyConfig,
+
+    /// Check that the PR title is not just the branch name
+    #[serde(default, rename = "descriptiveTitle")]
+    pub descriptive_title: DescriptiveTitlePolicyConfig,
+
+    /// Limit on the distinct change types in one PR
+    #[serde(default, rename = "changeTypes")]
+    pub change_types: ChangeTypesPolicyConfig,
+
+    /// Labels a PR must or must not carry
+    #[serde(default, rename = "labelPolicy")]
+    pub label_policy: LabelPolicyConfig,
 }
 
 /// Configuration for PR title policy
@@ -1459,6 +2637,184 @@ impl Default for PullRequestsTitlePolicyConfig {
     }
 }
 
+/// Configuration for limiting emoji and special characters in PR titles.
+///
+/// Applies to the description portion of the title, after the conventional commit
+/// prefix, and only when title validation is enabled. A title that breaks a limit fails
+/// the title check, with guidance in the title comment and a suggested title that drops
+/// the offending characters.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::TitleCharacterPolicyConfig;
+///
+/// let config: TitleCharacterPolicyConfig = toml::from_str(
+///     r#"
+///     enabled = true
+///     max_emoji = 1
+///     disallowed_characters = "<>`"
+///     "#,
+/// )
+/// .unwrap();
+/// assert_eq!(config.max_emoji, Some(1));
+/// assert!(config.disallowed_characters.contains('`'));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TitleCharacterPolicyConfig {
+    /// Whether the title character limits are enforced. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Maximum number of emoji allowed in the description. `None` (the default)
+    /// allows any number; `0` forbids emoji.
+    #[serde(default)]
+    pub max_emoji: Option<usize>,
+
+    /// Characters that may not appear in the description. Defaults to empty.
+    #[serde(default)]
+    pub disallowed_characters: String,
+}
+
+impl TitleCharacterPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `max_emoji`: `over` if `Some`; otherwise `base`
+    /// - `disallowed_characters`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.16 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            max_emoji: over.max_emoji.or(base.max_emoji),
+            disallowed_characters: if !over.disallowed_characters.is_empty() {
+                over.disallowed_characters.clone()
+            } else {
+                base.disallowed_characters.clone()
+            },
+        }
+    }
+}
+
+/// The case required of the first letter of a PR title's subject.
+///
+/// Only the first character of the subject is checked, so acronyms and identifiers
+/// later in the subject are allowed. A subject that starts with a digit or symbol
+/// satisfies either case.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::SubjectCase;
+///
+/// assert!(SubjectCase::Lower.matches("add login"));
+/// assert!(!SubjectCase::Lower.matches("Add login"));
+/// assert!(SubjectCase::Sentence.matches("Add login"));
+/// assert!(SubjectCase::Sentence.matches("`--verbose` flag"));
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum SubjectCase {
+    /// The subject starts with a lowercase letter, e.g. `feat: add login`.
+    Lower,
+
+    /// The subject starts with an uppercase letter, e.g. `feat: Add login`.
+    Sentence,
+}
+
+impl SubjectCase {
+    /// Whether `subject` starts with a letter of this case, or with no letter.
+    pub fn matches(&self, subject: &str) -> bool {
+        match subject.chars().next() {
+            Some(first) if first.is_alphabetic() => match self {
+                SubjectCase::Lower => !first.is_uppercase(),
+                SubjectCase::Sentence => !first.is_lowercase(),
+            },
+            _ => true,
+        }
+    }
+}
+
+/// Configuration for commit-lint style rules on the PR title.
+///
+/// The rules mirror the commitlint rules of the same purpose (`subject-case`,
+/// `subject-full-stop`, `header-max-length` and `type-enum`) and are checked in
+/// addition to the title pattern, only when title validation is enabled. Each broken
+/// rule is reported separately in the title comment, and fails the title check.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{SubjectCase, TitleLintPolicyConfig};
+///
+/// let config: TitleLintPolicyConfig = toml::from_str(
+///     r#"
+///     enabled = true
+///     subject_case = "lower"
+///     forbid_trailing_period = true
+///     header_max_length = 72
+///     allowed_types = ["feat", "fix", "chore"]
+///     "#,
+/// )
+/// .unwrap();
+/// assert_eq!(config.subject_case, Some(SubjectCase::Lower));
+/// assert_eq!(config.header_max_length, Some(72));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TitleLintPolicyConfig {
+    /// Whether the title rules are enforced. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The case required of the first letter of the subject. `None` (the default)
+    /// allows either case.
+    #[serde(default)]
+    pub subject_case: Option<SubjectCase>,
+
+    /// Whether a subject ending in `.` is rejected. Defaults to `false`.
+    #[serde(default)]
+    pub forbid_trailing_period: bool,
+
+    /// Maximum number of characters in the whole title. `None` (the default) allows
+    /// any length.
+    #[serde(default)]
+    pub header_max_length: Option<usize>,
+
+    /// The types a title may use, e.g. `["feat", "fix"]`. Empty (the default) allows
+    /// every type the title pattern accepts. With the default title pattern, the types
+    /// replace its standard types, so a type such as `deps` can be allowed as well.
+    #[serde(default)]
+    pub allowed_types: Vec<String>,
+}
+
+impl TitleLintPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `subject_case`: `over` if `Some`; otherwise `base`
+    /// - `forbid_trailing_period`: `base.forbid_trailing_period || over.forbid_trailing_period`
+    /// - `header_max_length`: `over` if `Some`; otherwise `base`
+    /// - `allowed_types`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.25 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            subject_case: over.subject_case.or(base.subject_case),
+            forbid_trailing_period: base.forbid_trailing_period || over.forbid_trailing_period,
+            header_max_length: over.header_max_length.or(base.header_max_length),
+            allowed_types: if !over.allowed_types.is_empty() {
+                over.allowed_types.clone()
+            } else {
+                base.allowed_types.clone()
+            },
+        }
+    }
+}
+
 /// Top-level configuration struct for merge-warden repository level configuration.
 /// This configuration data is read from the merge-warden.toml file in the .github directory of the
 /// repository
@@ -1546,6 +2902,8 @@ impl RepositoryProvidedConfig {
         let enforce_work_item_references = pr_policies.work_item_policies.required;
         let work_item_reference_pattern = pr_policies.work_item_policies.pattern.clone();
         let missing_work_item_label = pr_policies.work_item_policies.label_if_missing.clone();
+        let work_item_pr_template_guidance = pr_policies.work_item_policies.pr_template_guidance;
+        let min_work_item_references = pr_policies.work_item_policies.min_references;
 
         let pr_size_check = pr_policies.size_policies.clone();
         let wip_check = pr_policies.wip_policies.clone();
@@ -1558,11 +2916,40 @@ impl RepositoryProvidedConfig {
             enforce_work_item_references,
             work_item_reference_pattern,
             missing_work_item_label,
+            work_item_pr_template_guidance,
+            min_work_item_references,
             pr_size_check,
             change_type_labels: self.change_type_labels.clone(),
             wip_check,
             pr_state_labels,
             renovate_stability: pr_policies.renovate_stability.clone(),
+            check_summary: pr_policies.check_summary.clone(),
+            checklist: pr_policies.checklist.clone(),
+            review_threads: pr_policies.review_threads.clone(),
+            deleted_files: pr_policies.deleted_files.clone(),
+            version_bump: pr_policies.version_bump.clone(),
+            check_execution: pr_policies.check_execution.clone(),
+            dependency_updates: pr_policies.dependency_updates.clone(),
+            title_characters: pr_policies.title_characters.clone(),
+            title_lint: pr_policies.title_lint.clone(),
+            suspicious_files: pr_policies.suspicious_files.clone(),
+            reopened: pr_policies.reopened.clone(),
+            status_labels: pr_policies.status_labels.clone(),
+            comments: pr_policies.comments.clone(),
+            base_branch: pr_policies.base_branch.clone(),
+            exclusive_labels: pr_policies.exclusive_labels.clone(),
+            path_skip: pr_policies.path_skip.clone(),
+            signed_commits: pr_policies.signed_commits.clone(),
+            revert_reference: pr_policies.revert_reference.clone(),
+            label_creation: pr_policies.label_creation.clone(),
+            issue_references: pr_policies.issue_references.clone(),
+            up_to_date: pr_policies.up_to_date.clone(),
+            footers: pr_policies.footers.clone(),
+            draft_conversion: pr_policies.draft_conversion.clone(),
+            autofix: pr_policies.autofix.clone(),
+            descriptive_title: pr_policies.descriptive_title.clone(),
+            change_types: pr_policies.change_types.clone(),
+            label_policy: pr_policies.label_policy.clone(),
             // Merge per-sub-rule: if the repo specified a particular bypass rule,
             // use it; otherwise fall back to the server-level default for that rule.
             // This prevents a repo that overrides only one category from silently
@@ -1578,10 +2965,34 @@ impl RepositoryProvidedConfig {
                 let effective_size = repo
                     .and_then(|r| r.size().cloned())
                     .unwrap_or_else(|| bypass_rules.size().clone());
-                BypassRules::new_with_size(effective_title, effective_work_items, effective_size)
+                let effective_all = repo
+                    .and_then(|r| r.all().cloned())
+                    .unwrap_or_else(|| bypass_rules.all().clone());
+                let effective_signed_commits = repo
+                    .and_then(|r| r.signed_commits().cloned())
+                    .unwrap_or_else(|| bypass_rules.signed_commits().clone());
+                let effective_revert_reference = repo
+                    .and_then(|r| r.revert_reference().cloned())
+                    .unwrap_or_else(|| bypass_rules.revert_reference().clone());
+                let mut rules = BypassRules::new_with_size(
+                    effective_title,
+                    effective_work_items,
+                    effective_size,
+                )
+                .with_all(effective_all)
+                .with_signed_commits(effective_signed_commits)
+                .with_revert_reference(effective_revert_reference);
+                rules.missing_author_login = repo
+                    .and_then(|r| r.missing_author_login())
+                    .or_else(|| bypass_rules.missing_author_login())
+                    .map(str::to_string);
+                rules
             },
             issue_propagation: pr_policies.issue_propagation.clone(),
             bot_mention: self.bot_mention.clone(),
+            show_config_source: false,
+            config_source: ConfigSource::default(),
+            feature_flags: BTreeMap::new(),
         }
     }
 }
@@ -1597,6 +3008,127 @@ impl Default for RepositoryProvidedConfig {
     }
 }
 
+/// One or more regular expressions used to detect work item references.
+///
+/// In TOML a single pattern can be given as a plain string, which keeps existing
+/// configuration files working, or several patterns can be given as a list. The
+/// patterns are OR-combined: a PR description satisfies the check when any one of
+/// them matches.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::WorkItemPolicyConfig;
+///
+/// let single: WorkItemPolicyConfig = toml::from_str(r#"pattern = "GH-\\d+""#).unwrap();
+/// assert_eq!(single.pattern.len(), 1);
+///
+/// let many: WorkItemPolicyConfig =
+///     toml::from_str(r#"pattern = ["PROJ-\\d+", "GH-\\d+"]"#).unwrap();
+/// assert_eq!(many.pattern.patterns(), ["PROJ-\\d+", "GH-\\d+"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "WorkItemPatternsRepr", into = "WorkItemPatternsRepr")]
+pub struct WorkItemPatterns(Vec<String>);
+
+/// Serialized form of [`WorkItemPatterns`]: either a single string or a list.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum WorkItemPatternsRepr {
+    /// A single pattern, e.g. `pattern = "#\\d+"`.
+    Single(String),
+
+    /// Several OR-combined patterns, e.g. `pattern = ["PROJ-\\d+", "#\\d+"]`.
+    Multiple(Vec<String>),
+}
+
+impl WorkItemPatterns {
+    /// Creates a pattern set from a list of regular expressions.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The regular expressions, in the order they should be tried
+    pub fn new(patterns: Vec<String>) -> Self {
+        Self(patterns)
+    }
+
+    /// Returns the patterns in the order they are tried.
+    pub fn patterns(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Returns the number of patterns.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` when there are no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` when no pattern can detect a reference: there are no
+    /// patterns, or every pattern is an empty string.
+    pub fn has_no_patterns(&self) -> bool {
+        self.0.iter().all(|p| p.is_empty())
+    }
+
+    /// Returns `true` when this is the built-in default pattern.
+    fn is_default(&self) -> bool {
+        self.0.len() == 1 && self.0[0] == WORK_ITEM_REGEX
+    }
+}
+
+impl Default for WorkItemPatterns {
+    fn default() -> Self {
+        Self::from(WORK_ITEM_REGEX)
+    }
+}
+
+impl From<WorkItemPatternsRepr> for WorkItemPatterns {
+    fn from(repr: WorkItemPatternsRepr) -> Self {
+        match repr {
+            WorkItemPatternsRepr::Single(pattern) => Self(vec![pattern]),
+            WorkItemPatternsRepr::Multiple(patterns) => Self(patterns),
+        }
+    }
+}
+
+impl From<WorkItemPatterns> for WorkItemPatternsRepr {
+    fn from(patterns: WorkItemPatterns) -> Self {
+        let mut patterns = patterns.0;
+        if patterns.len() == 1 {
+            Self::Single(patterns.remove(0))
+        } else {
+            Self::Multiple(patterns)
+        }
+    }
+}
+
+impl From<&str> for WorkItemPatterns {
+    fn from(pattern: &str) -> Self {
+        Self(vec![pattern.to_string()])
+    }
+}
+
+impl From<String> for WorkItemPatterns {
+    fn from(pattern: String) -> Self {
+        Self(vec![pattern])
+    }
+}
+
+impl From<Vec<String>> for WorkItemPatterns {
+    fn from(patterns: Vec<String>) -> Self {
+        Self(patterns)
+    }
+}
+
+impl std::fmt::Display for WorkItemPatterns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join(", "))
+    }
+}
+
 /// Configuration for work item policy
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct WorkItemPolicyConfig {
@@ -1604,13 +3136,23 @@ pub struct WorkItemPolicyConfig {
     #[serde(default = "WorkItemPolicyConfig::default_required")]
     pub required: bool,
 
-    /// Regex pattern for work item references
+    /// Regex pattern(s) for work item references; a single string or a list
     #[serde(default = "WorkItemPolicyConfig::default_pattern")]
-    pub pattern: String,
+    pub pattern: WorkItemPatterns,
 
     /// Label to apply when work item reference is missing
     #[serde(default = "WorkItemPolicyConfig::default_label")]
     pub label_if_missing: Option<String>,
+
+    /// Whether the missing work item comment quotes the lines of the repository's
+    /// pull request template that reference a work item. Defaults to `false`.
+    #[serde(default)]
+    pub pr_template_guidance: bool,
+
+    /// The number of distinct work item references the PR description must contain.
+    /// Defaults to `1`; `0` requires no reference.
+    #[serde(default = "WorkItemPolicyConfig::default_min_references")]
+    pub min_references: usize,
 }
 
 impl WorkItemPolicyConfig {
@@ -1620,8 +3162,8 @@ impl WorkItemPolicyConfig {
     }
 
     /// Default regex pattern for work item validation
-    fn default_pattern() -> String {
-        WORK_ITEM_REGEX.to_string()
+    fn default_pattern() -> WorkItemPatterns {
+        WorkItemPatterns::default()
     }
 
     /// Default value for work item validation requirement (false)
@@ -1629,17 +3171,25 @@ impl WorkItemPolicyConfig {
         false
     }
 
+    /// Default number of work item references required (1)
+    fn default_min_references() -> usize {
+        1
+    }
+
     /// Merges `over` on top of `base` (lower-priority).
     ///
     /// Field-level rules:
     /// - `required`: `base.required || over.required`
     /// - `pattern`: `over.pattern` if non-empty and not equal to `WORK_ITEM_REGEX`;
-    ///   otherwise `base.pattern`
+    ///   otherwise `base.pattern`. A list replaces the base list as a whole.
     /// - `label_if_missing`: `over.label_if_missing` if `Some`; otherwise `base.label_if_missing`
+    /// - `pr_template_guidance`: `base.pr_template_guidance || over.pr_template_guidance`
+    /// - `min_references`: `over.min_references` if it differs from the default (`1`);
+    ///   otherwise `base.min_references`
     ///
     /// See `docs/spec/interfaces/policy-engine.md` §2.2 for the full contract.
     pub(crate) fn merge(base: &Self, over: &Self) -> Self {
-        let pattern = if !over.pattern.is_empty() && over.pattern != WORK_ITEM_REGEX {
+        let pattern = if !over.pattern.has_no_patterns() && !over.pattern.is_default() {
             over.pattern.clone()
         } else {
             base.pattern.clone()
@@ -1651,6 +3201,12 @@ impl WorkItemPolicyConfig {
                 .label_if_missing
                 .clone()
                 .or_else(|| base.label_if_missing.clone()),
+            pr_template_guidance: base.pr_template_guidance || over.pr_template_guidance,
+            min_references: if over.min_references != Self::default_min_references() {
+                over.min_references
+            } else {
+                base.min_references
+            },
         }
     }
 }
@@ -1661,6 +3217,8 @@ impl Default for WorkItemPolicyConfig {
             required: Self::default_required(),
             pattern: Self::default_pattern(),
             label_if_missing: Self::default_label(),
+            pr_template_guidance: false,
+            min_references: Self::default_min_references(),
         }
     }
 }
@@ -1684,14 +3242,51 @@ pub struct PrSizeCheckConfig {
     #[serde(default)]
     pub excluded_file_patterns: Vec<String>,
 
+    /// Whether to exclude commonly generated and vendored files from size calculations.
+    ///
+    /// When enabled, the [`default_excluded_file_patterns`], such as `*.lock`,
+    /// `*.generated.*` and `dist/`, are applied before the presets and the
+    /// `excluded_file_patterns`, so a custom `!` pattern re-includes a file they exclude.
+    ///
+    /// Enabled when absent; see [`PrSizeCheckConfig::uses_default_exclusions`].
+    ///
+    /// [`default_excluded_file_patterns`]: crate::size::default_excluded_file_patterns
+    #[serde(default)]
+    pub use_default_exclusions: Option<bool>,
+
+    /// Named sets of patterns to exclude from size calculations (e.g.,
+    /// `["common-generated"]`).
+    ///
+    /// The presets' patterns are applied before `excluded_file_patterns`, so a custom
+    /// `!` pattern re-includes a file that a preset excludes.
+    #[serde(default)]
+    pub excluded_file_presets: Vec<FileExclusionPreset>,
+
     /// Label prefix for size labels (defaults to "size/")
     #[serde(default = "PrSizeCheckConfig::default_label_prefix")]
     pub label_prefix: String,
 
-    /// Whether to add educational comments for oversized PRs
+    /// Whether to add educational comments for PRs in the `comment_on_categories` size
+    /// categories
     #[serde(default = "PrSizeCheckConfig::default_add_comment")]
     pub add_comment: bool,
 
+    /// Size categories that trigger an educational comment when `add_comment` is enabled.
+    ///
+    /// Defaults to `["XXL"]`, the oversized category.
+    #[serde(default = "PrSizeCheckConfig::default_comment_on_categories")]
+    pub comment_on_categories: Vec<PrSizeCategory>,
+
+    /// The number of rows of the largest files and largest directories tables in the
+    /// size comment.
+    ///
+    /// The tables show where the changed lines are concentrated so authors can see
+    /// how the PR could be split. `0` leaves the tables out.
+    ///
+    /// Defaults to `5`.
+    #[serde(default = "PrSizeCheckConfig::default_comment_breakdown_rows")]
+    pub comment_breakdown_rows: usize,
+
     /// Whether to ignore deleted lines when calculating PR size.
     ///
     /// When `true`, only additions are counted towards the PR size category. This
@@ -1702,6 +3297,79 @@ pub struct PrSizeCheckConfig {
     /// behaviour).
     #[serde(default = "PrSizeCheckConfig::default_ignore_deletions")]
     pub ignore_deletions: bool,
+
+    /// How the changed lines of each file are counted towards the PR size.
+    ///
+    /// `total_changes` counts added plus deleted lines, `net_changes` counts the
+    /// difference between the added and deleted lines of each file, so a refactor that
+    /// moves code within a file is not categorized as large, and `additions_only`
+    /// counts added lines only. `ignore_deletions = true` is the same as
+    /// `additions_only` and takes precedence.
+    ///
+    /// Defaults to `total_changes`.
+    #[serde(default)]
+    pub counting_mode: SizeCountingMode,
+
+    /// Whether to leave changed lines that only change whitespace out of the PR size.
+    ///
+    /// When `true`, a re-indented or re-spaced line and an added or removed blank line
+    /// do not count towards the size, so a reformatting PR is not categorized as large.
+    /// Telling them apart needs the diff of each file, which costs one more API request
+    /// per size check. Files whose diff the platform omits count in full, and the
+    /// setting has no effect when the size is measured against a `compare_base`.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub ignore_whitespace: bool,
+
+    /// Whether to include the size details in the Merge Warden check-run output.
+    ///
+    /// When `true`, the check output lists the size category, the line count of each
+    /// counted file and the files excluded from the size. This is independent of
+    /// `add_comment`: disable `add_comment` to keep the size information in the check
+    /// run only.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub details_in_check_output: bool,
+
+    /// Whether to add the per-file size breakdown to the size comment.
+    ///
+    /// When `true`, the comment posted for PRs in the `comment_on_categories` ends with
+    /// the line count of each counted file and the files excluded from the size, in a
+    /// collapsed `<details>` section. The breakdown is left out when it would make the
+    /// comment longer than GitHub allows.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub details_in_comment: bool,
+
+    /// Conditions that exempt a PR from the size check.
+    ///
+    /// A PR that matches any condition is not categorized, labeled or commented on,
+    /// and the exemption is recorded as a size bypass.
+    #[serde(default)]
+    pub exclusions: Vec<SizeExclusion>,
+
+    /// Branch to measure the PR size against instead of the PR's own base branch.
+    ///
+    /// By default the size is the PR's own diff, which for a stacked PR is only the
+    /// change unique to that PR. Setting this to e.g. `"main"` measures the PR head
+    /// against that branch, giving the cumulative size of the stack. When the
+    /// comparison cannot be fetched the PR's own diff is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compare_base: Option<String>,
+
+    /// Display names for the size categories, used in size labels and comments.
+    ///
+    /// Categories without a name keep their standard name (`XS` to `XXL`). Label
+    /// discovery recognizes both the configured and the standard names.
+    #[serde(default)]
+    pub category_names: SizeCategoryNames,
+
+    /// Settings of the review effort score, computed from the same files as the size.
+    #[serde(default)]
+    pub review_effort: ReviewEffortConfig,
 }
 
 impl PrSizeCheckConfig {
@@ -1725,29 +3393,179 @@ impl PrSizeCheckConfig {
         true
     }
 
+    /// Default size categories that trigger a comment (only the oversized category)
+    fn default_comment_on_categories() -> Vec<PrSizeCategory> {
+        vec![PrSizeCategory::XXL]
+    }
+
+    /// Default number of rows of each size comment breakdown table (5)
+    fn default_comment_breakdown_rows() -> usize {
+        crate::size::DEFAULT_COMMENT_BREAKDOWN_ROWS
+    }
+
     /// Default value for ignore_deletions (false — count both additions and deletions)
     fn default_ignore_deletions() -> bool {
         false
     }
 
+    /// Returns the counting mode used for the PR size.
+    ///
+    /// This is `AdditionsOnly` when `ignore_deletions` is set and `counting_mode`
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::PrSizeCheckConfig;
+    /// use merge_warden_core::size::SizeCountingMode;
+    ///
+    /// let config = PrSizeCheckConfig {
+    ///     counting_mode: SizeCountingMode::NetChanges,
+    ///     ..PrSizeCheckConfig::default()
+    /// };
+    /// assert_eq!(config.effective_counting_mode(), SizeCountingMode::NetChanges);
+    ///
+    /// let config = PrSizeCheckConfig {
+    ///     ignore_deletions: true,
+    ///     ..config
+    /// };
+    /// assert_eq!(config.effective_counting_mode(), SizeCountingMode::AdditionsOnly);
+    /// ```
+    pub fn effective_counting_mode(&self) -> SizeCountingMode {
+        if self.ignore_deletions {
+            SizeCountingMode::AdditionsOnly
+        } else {
+            self.counting_mode
+        }
+    }
+
+    /// Returns `true` if a PR in the given size category should receive a size comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::PrSizeCheckConfig;
+    /// use merge_warden_core::size::PrSizeCategory;
+    ///
+    /// let config = PrSizeCheckConfig::default();
+    /// assert!(config.should_comment_on(PrSizeCategory::XXL));
+    /// assert!(!config.should_comment_on(PrSizeCategory::XL));
+    /// ```
+    pub fn should_comment_on(&self, category: PrSizeCategory) -> bool {
+        self.add_comment && self.comment_on_categories.contains(&category)
+    }
+
+    /// Returns the first exclusion that matches the PR author and applied labels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::{PrSizeCheckConfig, SizeExclusion};
+    /// use merge_warden_developer_platforms::models::User;
+    ///
+    /// let config = PrSizeCheckConfig {
+    ///     exclusions: vec![SizeExclusion {
+    ///         author: Some("vendor-sync-*".to_string()),
+    ///         label: None,
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    /// let bot = User {
+    ///     id: 1,
+    ///     login: "vendor-sync-bot".to_string(),
+    /// };
+    ///
+    /// assert!(config.matching_exclusion(Some(&bot), &[]).is_some());
+    /// assert!(config.matching_exclusion(None, &[]).is_none());
+    /// ```
+    pub fn matching_exclusion(
+        &self,
+        author: Option<&User>,
+        labels: &[String],
+    ) -> Option<&SizeExclusion> {
+        self.exclusions
+            .iter()
+            .find(|exclusion| exclusion.matches(author, labels))
+    }
+
     /// Get the effective size thresholds, using defaults if not configured
     pub fn get_effective_thresholds(&self) -> SizeThresholds {
         self.thresholds.clone().unwrap_or_default()
     }
 
+    /// Returns whether the [`default_excluded_file_patterns`] are applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::PrSizeCheckConfig;
+    ///
+    /// assert!(PrSizeCheckConfig::default().uses_default_exclusions());
+    ///
+    /// let config: PrSizeCheckConfig = toml::from_str("use_default_exclusions = false").unwrap();
+    /// assert!(!config.uses_default_exclusions());
+    /// ```
+    ///
+    /// [`default_excluded_file_patterns`]: crate::size::default_excluded_file_patterns
+    pub fn uses_default_exclusions(&self) -> bool {
+        self.use_default_exclusions.unwrap_or(true)
+    }
+
+    /// Returns the [`default_excluded_file_patterns`] when they are applied, followed by
+    /// the patterns of the enabled presets, in order, and the custom
+    /// `excluded_file_patterns`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::PrSizeCheckConfig;
+    /// use merge_warden_core::size::{default_excluded_file_patterns, FileExclusionPreset};
+    ///
+    /// let config = PrSizeCheckConfig {
+    ///     use_default_exclusions: Some(false),
+    ///     excluded_file_presets: vec![FileExclusionPreset::Snapshots],
+    ///     excluded_file_patterns: vec!["!ui/__snapshots__/".to_string()],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     config.effective_excluded_file_patterns(),
+    ///     vec!["*.snap", "__snapshots__/", "!ui/__snapshots__/"]
+    /// );
+    ///
+    /// let defaults = PrSizeCheckConfig::default().effective_excluded_file_patterns();
+    /// assert_eq!(defaults, default_excluded_file_patterns());
+    /// ```
+    ///
+    /// [`default_excluded_file_patterns`]: crate::size::default_excluded_file_patterns
+    pub fn effective_excluded_file_patterns(&self) -> Vec<String> {
+        let defaults = if self.uses_default_exclusions() {
+            crate::size::default_excluded_file_patterns()
+        } else {
+            Vec::new()
+        };
+        defaults
+            .into_iter()
+            .chain(
+                self.excluded_file_presets
+                    .iter()
+                    .flat_map(|preset| preset.patterns()),
+            )
+            .map(str::to_string)
+            .chain(self.excluded_file_patterns.iter().cloned())
+            .collect()
+    }
+
     /// Check if a file should be excluded from size calculations
+    ///
+    /// Patterns use gitignore semantics; see [`crate::size::FileExclusions`].
     pub fn should_exclude_file(&self, file_path: &str) -> bool {
-        if self.excluded_file_patterns.is_empty() {
+        let patterns = self.effective_excluded_file_patterns();
+        if patterns.is_empty() {
             return false;
         }
 
-        // Simple glob-like pattern matching
-        for pattern in &self.excluded_file_patterns {
-            if pattern_matches(pattern, file_path) {
-                return true;
-            }
-        }
-        false
+        crate::size::FileExclusions::new(&patterns).is_excluded(file_path)
     }
 
     /// Merges `over` on top of `base` (lower-priority).
@@ -1757,9 +3575,21 @@ impl PrSizeCheckConfig {
     /// - `fail_on_oversized`: `over` wins unconditionally
     /// - `thresholds`: `over.thresholds` if `Some`; otherwise `base.thresholds`
     /// - `excluded_file_patterns`: `over` if non-empty; otherwise `base`
+    /// - `use_default_exclusions`: `over` if `Some`; otherwise `base`
+    /// - `excluded_file_presets`: `over` if non-empty; otherwise `base`
     /// - `label_prefix`: `over.label_prefix` if not equal to `"size/"`; otherwise `base.label_prefix`
     /// - `add_comment`: `over` wins unconditionally
+    /// - `comment_on_categories`: `over` if not equal to `["XXL"]`; otherwise `base`
+    /// - `comment_breakdown_rows`: `over` if not equal to `5`; otherwise `base`
     /// - `ignore_deletions`: `over` wins unconditionally
+    /// - `counting_mode`: `over` if not equal to `total_changes`; otherwise `base`
+    /// - `ignore_whitespace`: `over` wins unconditionally
+    /// - `details_in_check_output`: `base.details_in_check_output || over.details_in_check_output`
+    /// - `details_in_comment`: `base.details_in_comment || over.details_in_comment`
+    /// - `exclusions`: `over` if non-empty; otherwise `base`
+    /// - `compare_base`: `over.compare_base` if `Some`; otherwise `base.compare_base`
+    /// - `category_names`: per category, `over`'s name if set; otherwise `base`'s name
+    /// - `review_effort`: merged with [`ReviewEffortConfig::merge`]
     ///
     /// See `docs/spec/interfaces/policy-engine.md` §2.3 for the full contract.
     pub(crate) fn merge(base: &Self, over: &Self) -> Self {
@@ -1773,14 +3603,56 @@ impl PrSizeCheckConfig {
         } else {
             base.excluded_file_patterns.clone()
         };
+        let excluded_file_presets = if !over.excluded_file_presets.is_empty() {
+            over.excluded_file_presets.clone()
+        } else {
+            base.excluded_file_presets.clone()
+        };
+        let comment_on_categories =
+            if over.comment_on_categories != Self::default_comment_on_categories() {
+                over.comment_on_categories.clone()
+            } else {
+                base.comment_on_categories.clone()
+            };
+        let exclusions = if !over.exclusions.is_empty() {
+            over.exclusions.clone()
+        } else {
+            base.exclusions.clone()
+        };
+        let comment_breakdown_rows =
+            if over.comment_breakdown_rows != Self::default_comment_breakdown_rows() {
+                over.comment_breakdown_rows
+            } else {
+                base.comment_breakdown_rows
+            };
+        let counting_mode = if over.counting_mode != SizeCountingMode::default() {
+            over.counting_mode
+        } else {
+            base.counting_mode
+        };
         Self {
             enabled: base.enabled || over.enabled,
             fail_on_oversized: over.fail_on_oversized,
             thresholds: over.thresholds.clone().or_else(|| base.thresholds.clone()),
             excluded_file_patterns,
+            use_default_exclusions: over.use_default_exclusions.or(base.use_default_exclusions),
+            excluded_file_presets,
             label_prefix,
             add_comment: over.add_comment,
+            comment_on_categories,
+            comment_breakdown_rows,
             ignore_deletions: over.ignore_deletions,
+            counting_mode,
+            ignore_whitespace: over.ignore_whitespace,
+            details_in_check_output: base.details_in_check_output || over.details_in_check_output,
+            details_in_comment: base.details_in_comment || over.details_in_comment,
+            exclusions,
+            compare_base: over
+                .compare_base
+                .clone()
+                .or_else(|| base.compare_base.clone()),
+            category_names: SizeCategoryNames::merge(&base.category_names, &over.category_names),
+            review_effort: ReviewEffortConfig::merge(&base.review_effort, &over.review_effort),
         }
     }
 }
@@ -1792,9 +3664,170 @@ impl Default for PrSizeCheckConfig {
             thresholds: None,
             fail_on_oversized: Self::default_fail_on_oversized(),
             excluded_file_patterns: Vec::new(),
+            use_default_exclusions: None,
+            excluded_file_presets: Vec::new(),
             label_prefix: Self::default_label_prefix(),
             add_comment: Self::default_add_comment(),
+            comment_on_categories: Self::default_comment_on_categories(),
+            comment_breakdown_rows: Self::default_comment_breakdown_rows(),
             ignore_deletions: Self::default_ignore_deletions(),
+            counting_mode: SizeCountingMode::default(),
+            ignore_whitespace: false,
+            details_in_check_output: false,
+            details_in_comment: false,
+            exclusions: Vec::new(),
+            compare_base: None,
+            category_names: SizeCategoryNames::default(),
+            review_effort: ReviewEffortConfig::default(),
+        }
+    }
+}
+
+/// Configuration of the review effort score of a pull request.
+///
+/// The score runs from 1 to 5 and is computed from the files counted towards the PR
+/// size, see [`crate::size::PrSizeInfo::review_effort`]. It is only computed when the
+/// size check is enabled, and is never a reason for the check to fail.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReviewEffortConfig {
+    /// Whether to compute the review effort score and show it, with its reasons, in
+    /// the check output
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Whether to apply an effort label, e.g. `effort:3`, replacing any other effort
+    /// label on the PR
+    #[serde(default)]
+    pub add_label: bool,
+
+    /// Prefix of the effort labels (defaults to `"effort:"`)
+    #[serde(default = "ReviewEffortConfig::default_label_prefix")]
+    pub label_prefix: String,
+
+    /// The weight of each factor of the score
+    #[serde(default)]
+    pub weights: ReviewEffortWeights,
+}
+
+impl ReviewEffortConfig {
+    /// Default prefix for effort labels ("effort:")
+    fn default_label_prefix() -> String {
+        "effort:".to_string()
+    }
+
+    /// Returns the effort label for a score, e.g. `effort:3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::ReviewEffortConfig;
+    ///
+    /// assert_eq!(ReviewEffortConfig::default().label_for(3), "effort:3");
+    /// ```
+    pub fn label_for(&self, score: u8) -> String {
+        format!("{}{}", self.label_prefix, score)
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `add_label`: `base.add_label || over.add_label`
+    /// - `label_prefix`: `over.label_prefix` if not equal to `"effort:"`; otherwise `base.label_prefix`
+    /// - `weights`: `over.weights` if not the defaults; otherwise `base.weights`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.33 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            add_label: base.add_label || over.add_label,
+            label_prefix: if over.label_prefix != Self::default_label_prefix() {
+                over.label_prefix.clone()
+            } else {
+                base.label_prefix.clone()
+            },
+            weights: if over.weights != ReviewEffortWeights::default() {
+                over.weights.clone()
+            } else {
+                base.weights.clone()
+            },
+        }
+    }
+}
+
+impl Default for ReviewEffortConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            add_label: false,
+            label_prefix: Self::default_label_prefix(),
+            weights: ReviewEffortWeights::default(),
+        }
+    }
+}
+
+/// A condition that exempts a PR from the size check, such as a vendored-code sync
+/// opened by a bot.
+///
+/// A PR matches when every field that is set matches. A condition with no fields set
+/// matches nothing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SizeExclusion {
+    /// Glob matched against the PR author's login, e.g. `"vendor-sync[bot]"` or
+    /// `"*-bot"`. Only `*` is a wildcard and matching ignores case.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Name of a label that must be applied to the PR. Matching ignores case.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl SizeExclusion {
+    /// Returns `true` if the PR author and applied labels satisfy this condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::SizeExclusion;
+    ///
+    /// let exclusion = SizeExclusion {
+    ///     author: None,
+    ///     label: Some("vendored".to_string()),
+    /// };
+    ///
+    /// assert!(exclusion.matches(None, &["Vendored".to_string()]));
+    /// assert!(!exclusion.matches(None, &["bug".to_string()]));
+    /// ```
+    pub fn matches(&self, author: Option<&User>, labels: &[String]) -> bool {
+        if self.author.is_none() && self.label.is_none() {
+            return false;
+        }
+
+        let author_matches = self.author.as_ref().is_none_or(|pattern| {
+            author.is_some_and(|user| {
+                pattern_matches(
+                    &pattern.to_ascii_lowercase(),
+                    &user.login.to_ascii_lowercase(),
+                )
+            })
+        });
+        let label_matches = self
+            .label
+            .as_ref()
+            .is_none_or(|label| labels.iter().any(|l| l.eq_ignore_ascii_case(label)));
+
+        author_matches && label_matches
+    }
+}
+
+impl std::fmt::Display for SizeExclusion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.author, &self.label) {
+            (Some(author), Some(label)) => write!(f, "author `{author}` with label `{label}`"),
+            (Some(author), None) => write!(f, "author `{author}`"),
+            (None, Some(label)) => write!(f, "label `{label}`"),
+            (None, None) => write!(f, "no condition"),
         }
     }
 }
@@ -1942,139 +3975,2756 @@ pub struct PrStateLabelsConfig {
     #[serde(default = "PrStateLabelsConfig::default_enabled")]
     pub enabled: bool,
 
-    /// Label applied when the PR is in draft state.
-    /// Set to `None` (or omit the key) to disable labeling for this state.
-    #[serde(default)]
-    pub draft_label: Option<String>,
+    /// Label applied when the PR is in draft state.
+    /// Set to `None` (or omit the key) to disable labeling for this state.
+    #[serde(default)]
+    pub draft_label: Option<String>,
+
+    /// Label applied when the PR is open and awaiting review (not draft, not approved).
+    /// Set to `None` (or omit the key) to disable labeling for this state.
+    #[serde(default)]
+    pub review_label: Option<String>,
+
+    /// Label applied when the PR has at least one approved review and is not a draft.
+    /// Set to `None` (or omit the key) to disable labeling for this state.
+    #[serde(default)]
+    pub approved_label: Option<String>,
+}
+
+impl PrStateLabelsConfig {
+    /// Default value for `enabled` — opt-in, disabled by default.
+    fn default_enabled() -> bool {
+        false
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `draft_label`: `over.draft_label` if `Some`; otherwise `base.draft_label`
+    /// - `review_label`: `over.review_label` if `Some`; otherwise `base.review_label`
+    /// - `approved_label`: `over.approved_label` if `Some`; otherwise `base.approved_label`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.5 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            draft_label: over
+                .draft_label
+                .clone()
+                .or_else(|| base.draft_label.clone()),
+            review_label: over
+                .review_label
+                .clone()
+                .or_else(|| base.review_label.clone()),
+            approved_label: over
+                .approved_label
+                .clone()
+                .or_else(|| base.approved_label.clone()),
+        }
+    }
+}
+
+impl Default for PrStateLabelsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            draft_label: None,
+            review_label: None,
+            approved_label: None,
+        }
+    }
+}
+
+/// Configuration for Renovate stability-days label management.
+///
+/// When enabled, [`RENOVATE_STABILITY_LABEL`] (or the configured label name) is applied
+/// to the PR while the `renovate/stability-days` commit status is `pending`, `error`, or
+/// `failure`, and removed when the status is `success`.  The label is purely informational —
+/// it never influences the merge-blocking check conclusion.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{RenovateStabilityConfig, RENOVATE_STABILITY_LABEL};
+///
+/// let config = RenovateStabilityConfig::default();
+/// assert!(config.enabled);
+/// assert_eq!(config.pending_stability_label, RENOVATE_STABILITY_LABEL);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RenovateStabilityConfig {
+    /// Whether Renovate stability label management is enabled.
+    ///
+    /// Defaults to `true`.  The merge rule is `base || over`: once either tier enables
+    /// the feature the merged value is always `true`.  A repository can only opt in,
+    /// not opt out.
+    #[serde(default = "RenovateStabilityConfig::default_enabled")]
+    pub enabled: bool,
+
+    /// Label applied while the Renovate stability period has not yet elapsed.
+    ///
+    /// Defaults to [`RENOVATE_STABILITY_LABEL`].
+    #[serde(default = "RenovateStabilityConfig::default_label")]
+    pub pending_stability_label: String,
+}
+
+impl RenovateStabilityConfig {
+    /// Default value for `enabled` — opt-in enabled by default.
+    fn default_enabled() -> bool {
+        true
+    }
+
+    /// Default value for `pending_stability_label`.
+    fn default_label() -> String {
+        RENOVATE_STABILITY_LABEL.to_string()
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled` — once enabled in either tier, stays enabled.
+    ///   This means a repo-level `enabled = false` cannot override an app-level `enabled = true`.
+    ///   To disable for all repos, set `enabled = false` at the application defaults level.
+    /// - `pending_stability_label`: `over` wins if non-empty and differs from the default label;
+    ///   otherwise `base` is used
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        let label = if !over.pending_stability_label.is_empty()
+            && over.pending_stability_label != RENOVATE_STABILITY_LABEL
+        {
+            over.pending_stability_label.clone()
+        } else {
+            base.pending_stability_label.clone()
+        };
+        Self {
+            enabled: base.enabled || over.enabled,
+            pending_stability_label: label,
+        }
+    }
+}
+
+impl Default for RenovateStabilityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            pending_stability_label: Self::default_label(),
+        }
+    }
+}
+
+/// Severity used to present a failing check in the check-run summary table.
+///
+/// The severity only affects presentation (the status icon and word shown in the
+/// table). Whether a failing check blocks the merge is still decided by the
+/// individual check configuration.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::CheckSeverity;
+///
+/// let severity: CheckSeverity = toml::from_str::<toml::Value>("s = \"warning\"")
+///     .unwrap()["s"]
+///     .clone()
+///     .try_into()
+///     .unwrap();
+/// assert_eq!(severity, CheckSeverity::Warning);
+/// assert_eq!(CheckSeverity::default(), CheckSeverity::Error);
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckSeverity {
+    /// A failing check is shown as an error (❌ / `FAIL`).
+    #[default]
+    Error,
+
+    /// A failing check is shown as a warning (⚠️ / `WARN`).
+    Warning,
+
+    /// A failing check is shown as an informational notice (ℹ️ / `NOTE`).
+    Notice,
+}
+
+/// Configuration for the check run and its summary table.
+///
+/// Controls the name of the check run and how each check is presented in the
+/// Markdown table that forms the check-run `output_text`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckSeverity, CheckSummaryConfig};
+///
+/// let config = CheckSummaryConfig::default();
+/// assert!(config.use_emoji);
+/// assert_eq!(config.title_severity, CheckSeverity::Error);
+/// assert_eq!(config.size_severity, CheckSeverity::Warning);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CheckSummaryConfig {
+    /// Whether status icons are rendered as emoji.
+    ///
+    /// When `false`, plain-text status words (`PASS`, `FAIL`, `WARN`, ...) are used
+    /// instead. Defaults to `true`.
+    #[serde(default = "CheckSummaryConfig::default_use_emoji")]
+    pub use_emoji: bool,
+
+    /// Severity shown when the title check fails. Defaults to `error`.
+    #[serde(default)]
+    pub title_severity: CheckSeverity,
+
+    /// Severity shown when the work item check fails. Defaults to `error`.
+    #[serde(default)]
+    pub work_item_severity: CheckSeverity,
+
+    /// Severity shown when the size check reports an oversized PR. Defaults to `warning`.
+    #[serde(default = "CheckSummaryConfig::default_size_severity")]
+    pub size_severity: CheckSeverity,
+
+    /// Name of the check run. Defaults to
+    /// [`merge_warden_developer_platforms::DEFAULT_CHECK_RUN_NAME`] when unset.
+    ///
+    /// Branch protection rules require check runs by name, so renaming the check run
+    /// breaks rules that require the old name. See `keep_legacy_check_run`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_run_name: Option<String>,
+
+    /// Whether to also post the check run under the default name when
+    /// `check_run_name` is set, so that branch protection can move to the new name
+    /// without a gap. Defaults to `false`.
+    #[serde(default)]
+    pub keep_legacy_check_run: bool,
+
+    /// Whether the check-run summary of a passing pull request lists each check with
+    /// its status, such as `✅ PR title · ✅ Work item · ➖ Checklist (skipped)`, after
+    /// the "All PR requirements satisfied." sentence. Defaults to `false`.
+    #[serde(default)]
+    pub list_checks_on_success: bool,
+
+    /// Whether the check run shows a "Re-run Merge Warden" button that validates the
+    /// pull request again without a new push. The server handles the button's
+    /// `check_run` webhook event. Defaults to `false`.
+    #[serde(default)]
+    pub rerun_action: bool,
+
+    /// Templates that replace the check-run summary sentence, chosen by the checks
+    /// that failed. The built-in sentence is used when no template applies.
+    #[serde(default)]
+    pub templates: CheckSummaryTemplates,
+}
+
+impl CheckSummaryConfig {
+    /// Default value for `use_emoji`.
+    fn default_use_emoji() -> bool {
+        true
+    }
+
+    /// Default value for `size_severity`.
+    fn default_size_severity() -> CheckSeverity {
+        CheckSeverity::Warning
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `use_emoji`: `over` if it differs from the default (`true`); otherwise `base`
+    /// - `title_severity`, `work_item_severity`, `size_severity`: `over` if it differs
+    ///   from the field default; otherwise `base`
+    /// - `check_run_name`: `over` if set; otherwise `base`
+    /// - `keep_legacy_check_run`: `base.keep_legacy_check_run || over.keep_legacy_check_run`
+    /// - `list_checks_on_success`: `base.list_checks_on_success || over.list_checks_on_success`
+    /// - `rerun_action`: `base.rerun_action || over.rerun_action`
+    /// - `templates`: merged with [`CheckSummaryTemplates::merge`]
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.9 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        let defaults = Self::default();
+        let pick = |b: CheckSeverity, o: CheckSeverity, d: CheckSeverity| {
+            if o != d {
+                o
+            } else {
+                b
+            }
+        };
+        Self {
+            use_emoji: if over.use_emoji != defaults.use_emoji {
+                over.use_emoji
+            } else {
+                base.use_emoji
+            },
+            title_severity: pick(
+                base.title_severity,
+                over.title_severity,
+                defaults.title_severity,
+            ),
+            work_item_severity: pick(
+                base.work_item_severity,
+                over.work_item_severity,
+                defaults.work_item_severity,
+            ),
+            size_severity: pick(
+                base.size_severity,
+                over.size_severity,
+                defaults.size_severity,
+            ),
+            check_run_name: over
+                .check_run_name
+                .clone()
+                .or_else(|| base.check_run_name.clone()),
+            keep_legacy_check_run: base.keep_legacy_check_run || over.keep_legacy_check_run,
+            list_checks_on_success: base.list_checks_on_success || over.list_checks_on_success,
+            rerun_action: base.rerun_action || over.rerun_action,
+            templates: CheckSummaryTemplates::merge(&base.templates, &over.templates),
+        }
+    }
+}
+
+impl Default for CheckSummaryConfig {
+    fn default() -> Self {
+        Self {
+            use_emoji: Self::default_use_emoji(),
+            title_severity: CheckSeverity::default(),
+            work_item_severity: CheckSeverity::default(),
+            size_severity: Self::default_size_severity(),
+            check_run_name: None,
+            keep_legacy_check_run: false,
+            list_checks_on_success: false,
+            rerun_action: false,
+            templates: CheckSummaryTemplates::default(),
+        }
+    }
+}
+
+/// Templates of the check-run summary sentence, keyed by the checks that failed.
+///
+/// Each template is a MiniJinja source rendered against a
+/// [`crate::templates::CheckSummaryContext`], which holds the failing rules and the
+/// built-in summary. A template that is not set, fails to render, renders empty or
+/// renders longer than GitHub allows is replaced by the built-in summary.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckKind, CheckSummaryTemplates};
+///
+/// let templates: CheckSummaryTemplates = toml::from_str(
+///     r#"
+///     failed = "{{ default_summary }} See https://wiki.example.com/prs."
+///
+///     [[on_failure]]
+///     checks = ["work_item", "title"]
+///     template = "Fix the title and link a work item."
+///     "#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     templates.template_for(false, &[CheckKind::Title, CheckKind::WorkItem]),
+///     Some("Fix the title and link a work item.")
+/// );
+/// assert_eq!(
+///     templates.template_for(false, &[CheckKind::Title]),
+///     Some("{{ default_summary }} See https://wiki.example.com/prs.")
+/// );
+/// assert_eq!(templates.template_for(true, &[]), None);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CheckSummaryTemplates {
+    /// Template used when every check passed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passed: Option<String>,
+
+    /// Template used when a check failed and no `on_failure` entry matches the failing
+    /// checks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed: Option<String>,
+
+    /// Templates for specific combinations of failing checks.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub on_failure: Vec<CheckSummaryFailureTemplate>,
+}
+
+impl CheckSummaryTemplates {
+    /// Returns the template for an outcome, or `None` to use the built-in summary.
+    ///
+    /// When checks failed, the first `on_failure` entry whose `checks` are exactly the
+    /// failing checks, in any order, is used, and `failed` otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `passed` - Whether every check passed
+    /// * `failing_rules` - The checks that failed
+    pub fn template_for(&self, passed: bool, failing_rules: &[CheckKind]) -> Option<&str> {
+        if passed {
+            return self.passed.as_deref();
+        }
+
+        self.on_failure
+            .iter()
+            .find(|entry| {
+                entry.checks.iter().all(|kind| failing_rules.contains(kind))
+                    && failing_rules.iter().all(|kind| entry.checks.contains(kind))
+            })
+            .map(|entry| entry.template.as_str())
+            .or(self.failed.as_deref())
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `passed`, `failed`: `over` if set; otherwise `base`
+    /// - `on_failure`: `over` if non-empty; otherwise `base`
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            passed: over.passed.clone().or_else(|| base.passed.clone()),
+            failed: over.failed.clone().or_else(|| base.failed.clone()),
+            on_failure: if over.on_failure.is_empty() {
+                base.on_failure.clone()
+            } else {
+                over.on_failure.clone()
+            },
+        }
+    }
+}
+
+/// A check summary template for one combination of failing checks.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CheckSummaryFailureTemplate {
+    /// The checks that must fail, and be the only ones failing, for the template to
+    /// apply, e.g. `["title", "work_item"]`.
+    pub checks: Vec<CheckKind>,
+
+    /// The MiniJinja template source.
+    pub template: String,
+}
+
+/// Configuration for the required checklist check.
+///
+/// PR templates often contain Markdown task lists such as `- [ ] Tests added`.
+/// When enabled, the check fails until the required checkboxes in the PR
+/// description are ticked.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::ChecklistPolicyConfig;
+///
+/// let config = ChecklistPolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert!(config.required_items.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChecklistPolicyConfig {
+    /// Whether the checklist check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The checklist items that must be ticked.
+    ///
+    /// Each entry is matched case-insensitively against the text of the task-list
+    /// items in the PR description; an item matches when its text contains the
+    /// entry. When empty, every checkbox in the description must be ticked.
+    #[serde(default)]
+    pub required_items: Vec<String>,
+}
+
+/// Configuration for the unresolved review-thread check.
+///
+/// When enabled, the check fails while the PR has more unresolved review
+/// comment threads than `max_unresolved`. The check needs a platform that can
+/// report review-thread resolution state; on other platforms it is skipped.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::ReviewThreadPolicyConfig;
+///
+/// let config = ReviewThreadPolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.max_unresolved, 0);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReviewThreadPolicyConfig {
+    /// Whether the review-thread check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The number of unresolved review threads that is still accepted. Defaults to `0`.
+    #[serde(default)]
+    pub max_unresolved: u32,
+}
+
+impl ReviewThreadPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `max_unresolved`: `over` if non-zero; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.11 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            max_unresolved: if over.max_unresolved != 0 {
+                over.max_unresolved
+            } else {
+                base.max_unresolved
+            },
+        }
+    }
+}
+
+/// Configuration for the signed commits check.
+///
+/// When enabled, the check fails while any commit in the PR does not have a
+/// signature the platform could verify, and lists the offending commits. The
+/// check needs a platform that reports commit verification; on other platforms
+/// it is skipped. Users and labels in the `signed_commits` bypass rule exempt a
+/// PR from the check.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::SignedCommitsPolicyConfig;
+///
+/// let config: SignedCommitsPolicyConfig = toml::from_str("enabled = true").unwrap();
+/// assert!(config.enabled);
+/// assert!(!SignedCommitsPolicyConfig::default().enabled);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SignedCommitsPolicyConfig {
+    /// Whether the signed commits check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl SignedCommitsPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.24 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+        }
+    }
+}
+
+/// Configuration for the revert reference check.
+///
+/// When enabled, a revert PR must reference what it reverts in its body: the
+/// original PR, as `#123`, `owner/repo#123` or a PR URL, or the SHA of a reverted
+/// commit. A PR is a revert when its title has the `revert` conventional commit
+/// type, which also gives it the `revert` label, or starts with `Revert "`, as in
+/// the PRs GitHub opens from its revert button. Users and labels in the
+/// `revert_reference` bypass rule exempt a PR from the check.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::RevertReferencePolicyConfig;
+///
+/// let config: RevertReferencePolicyConfig = toml::from_str("enabled = true").unwrap();
+/// assert!(config.enabled);
+/// assert!(!RevertReferencePolicyConfig::default().enabled);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RevertReferencePolicyConfig {
+    /// Whether the revert reference check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl RevertReferencePolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.26 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+        }
+    }
+}
+
+/// Configuration for creating labels that do not exist in the repository.
+///
+/// Merge Warden applies labels such as the size, change-type, dependencies and
+/// Renovate stability labels, and creates them when the repository does not define
+/// them yet. Organizations that manage their labels centrally can set
+/// `create_missing_labels` to `false`; Merge Warden then only applies labels the
+/// repository already has, found with `list_available_labels`, and skips the others.
+/// Skipped labels are logged and noted in the check-run output.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::LabelCreationPolicyConfig;
+///
+/// let config: LabelCreationPolicyConfig =
+///     toml::from_str("create_missing_labels = false").unwrap();
+/// assert!(!config.create_missing_labels);
+/// assert!(LabelCreationPolicyConfig::default().create_missing_labels);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LabelCreationPolicyConfig {
+    /// Whether labels that do not exist in the repository are created when they are
+    /// applied. Defaults to `true`.
+    #[serde(default = "LabelCreationPolicyConfig::default_create_missing_labels")]
+    pub create_missing_labels: bool,
+}
+
+impl LabelCreationPolicyConfig {
+    /// Default value for `create_missing_labels`.
+    fn default_create_missing_labels() -> bool {
+        true
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `create_missing_labels`: `base.create_missing_labels && over.create_missing_labels`
+    ///   — once either tier disables label creation, no labels are created
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.27 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            create_missing_labels: base.create_missing_labels && over.create_missing_labels,
+        }
+    }
+}
+
+impl Default for LabelCreationPolicyConfig {
+    fn default() -> Self {
+        Self {
+            create_missing_labels: Self::default_create_missing_labels(),
+        }
+    }
+}
+
+/// How references to issues in the same repository are verified.
+///
+/// The levels are ordered from least to most strict.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueReferenceEnforcement {
+    /// References are not looked up.
+    #[default]
+    Off,
+
+    /// References that do not resolve to an issue are noted in the check-run output,
+    /// but do not fail the work item check.
+    Warn,
+
+    /// References that do not resolve to an issue fail the work item check.
+    Fail,
+}
+
+/// Configuration for verifying that issue references point at existing issues.
+///
+/// When enabled, each `#123` reference in the PR description, and each
+/// `owner/repo#123` reference to the repository itself, is looked up with the
+/// provider's `get_issue`. A reference is dangling when no issue with that number
+/// exists or when the number belongs to a pull request. References that cannot be
+/// looked up, for example because the provider is unavailable, are logged and treated
+/// as valid.
+///
+/// Verification only runs when the work item check is enabled and finds a reference.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{IssueReferenceEnforcement, IssueReferencePolicyConfig};
+///
+/// let config: IssueReferencePolicyConfig = toml::from_str(r#"enforcement = "fail""#).unwrap();
+/// assert_eq!(config.enforcement, IssueReferenceEnforcement::Fail);
+/// assert_eq!(
+///     IssueReferencePolicyConfig::default().enforcement,
+///     IssueReferenceEnforcement::Off
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IssueReferencePolicyConfig {
+    /// What to do with references that do not resolve to an issue. Defaults to `off`.
+    #[serde(default)]
+    pub enforcement: IssueReferenceEnforcement,
+}
+
+impl IssueReferencePolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enforcement`: the stricter of `base.enforcement` and `over.enforcement`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.28 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enforcement: base.enforcement.max(over.enforcement),
+        }
+    }
+}
+
+/// Configuration for the up-to-date check, which manages a label on PRs that are behind
+/// their base branch.
+///
+/// When enabled, the number of commits on the base branch that the PR's head does not
+/// contain is read from the provider's compare endpoint. A PR that is at least
+/// `behind_by_threshold` commits behind gets `label`, and the label is removed again
+/// once the PR is brought up to date. Only PRs behind the base are affected; the check
+/// conclusion is unchanged unless `severity` is `error`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckSeverity, UpToDatePolicyConfig};
+///
+/// let config = UpToDatePolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.label, "needs-rebase");
+/// assert_eq!(config.behind_by_threshold, 1);
+/// assert_eq!(config.severity, CheckSeverity::Warning);
+/// assert!(!config.fails_check());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UpToDatePolicyConfig {
+    /// Whether the up-to-date check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The label applied to PRs that are behind their base branch. Defaults to
+    /// `needs-rebase`.
+    #[serde(default = "UpToDatePolicyConfig::default_label")]
+    pub label: String,
+
+    /// How many commits a PR may be behind its base before it is labelled. Defaults
+    /// to `1`, which labels any PR that is behind; `0` is treated as `1`.
+    #[serde(default = "UpToDatePolicyConfig::default_behind_by_threshold")]
+    pub behind_by_threshold: u32,
+
+    /// Severity of a PR that is behind its base. Defaults to `warning`.
+    ///
+    /// Only `error` causes the check run to fail.
+    #[serde(default = "UpToDatePolicyConfig::default_severity")]
+    pub severity: CheckSeverity,
+}
+
+impl UpToDatePolicyConfig {
+    /// Default value for `label`.
+    fn default_label() -> String {
+        "needs-rebase".to_string()
+    }
+
+    /// Default value for `behind_by_threshold`.
+    fn default_behind_by_threshold() -> u32 {
+        1
+    }
+
+    /// Default value for `severity`.
+    fn default_severity() -> CheckSeverity {
+        CheckSeverity::Warning
+    }
+
+    /// Returns `true` if a PR behind its base should fail the check run.
+    pub fn fails_check(&self) -> bool {
+        self.severity == CheckSeverity::Error
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `label`, `behind_by_threshold`, `severity`: `over` if it differs from the
+    ///   default; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.29 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            label: if over.label != Self::default_label() {
+                over.label.clone()
+            } else {
+                base.label.clone()
+            },
+            behind_by_threshold: if over.behind_by_threshold != Self::default_behind_by_threshold()
+            {
+                over.behind_by_threshold
+            } else {
+                base.behind_by_threshold
+            },
+            severity: if over.severity != Self::default_severity() {
+                over.severity
+            } else {
+                base.severity
+            },
+        }
+    }
+}
+
+impl Default for UpToDatePolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            label: Self::default_label(),
+            behind_by_threshold: Self::default_behind_by_threshold(),
+            severity: Self::default_severity(),
+        }
+    }
+}
+
+/// A footer that must appear at the end of the PR description.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::RequiredFooter;
+///
+/// let footer: RequiredFooter = toml::from_str(r#"
+/// token = "Signed-off-by"
+/// pattern = "<.+@.+>"
+/// "#).unwrap();
+/// assert_eq!(footer.token, "Signed-off-by");
+/// assert_eq!(footer.pattern.as_deref(), Some("<.+@.+>"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RequiredFooter {
+    /// The footer token, such as `Signed-off-by` or `Reviewed-by`. Matched
+    /// case-insensitively.
+    pub token: String,
+
+    /// A regular expression that the footer's value must match. When unset, any value
+    /// is accepted.
+    #[serde(default)]
+    pub pattern: Option<String>,
+}
+
+/// Configuration for the required footer check.
+///
+/// Some teams use the PR description as the merge commit message and require
+/// conventional-commit footers such as `Signed-off-by: Name <email>` in it. When
+/// enabled, the footers are read from the last paragraph of the description and the
+/// check fails while any of the `required` footers is missing or has no value matching
+/// its pattern.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::FooterPolicyConfig;
+///
+/// let config = FooterPolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert!(config.required.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FooterPolicyConfig {
+    /// Whether the footer check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// The footers that must be present.
+    #[serde(default)]
+    pub required: Vec<RequiredFooter>,
+}
+
+impl FooterPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `required`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.30 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            required: if over.required.is_empty() {
+                base.required.clone()
+            } else {
+                over.required.clone()
+            },
+        }
+    }
+}
+
+/// Configuration for converting failing pull requests to drafts.
+///
+/// Some teams want a pull request that fails a blocking check to be marked as a draft,
+/// to signal that it is not ready for review. When enabled, a pull request that is
+/// ready for review and fails a blocking check is converted to a draft and a comment
+/// explains why. This happens at most once per pull request: when the author marks it
+/// as ready for review again, it is validated as usual but left ready for review, so
+/// that Merge Warden does not fight the author.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::DraftConversionPolicyConfig;
+///
+/// let config = DraftConversionPolicyConfig::default();
+/// assert!(!config.enabled);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DraftConversionPolicyConfig {
+    /// Whether failing pull requests are converted to drafts. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl DraftConversionPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.31 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+        }
+    }
+}
+
+/// Configuration for automatically fixing failing checks.
+///
+/// Some failures have an obvious fix, such as an uppercase type in the title or a
+/// missing work item reference when the branch is named after the issue. For each
+/// check that opts in, Merge Warden edits the pull request instead of only reporting
+/// the failure, then validates the edited pull request and posts a comment listing
+/// what it changed. Fixes are conservative: a fix is only applied when it makes the
+/// check pass, and nothing is changed for checks that are disabled or bypassed.
+///
+/// - `title` applies the suggested fix of the title check, such as lowercasing the
+///   type or adding the missing space after the colon. Fixes that would pick a
+///   different type are never applied.
+/// - `work_item` appends `Relates to #<n>` to the description when the head branch
+///   name starts with an issue number, such as `feature/123-add-login`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::AutofixPolicyConfig;
+///
+/// let config = AutofixPolicyConfig::default();
+/// assert!(!config.title);
+/// assert!(!config.work_item);
+/// assert!(!config.is_enabled());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AutofixPolicyConfig {
+    /// Whether invalid titles are fixed. Defaults to `false`.
+    #[serde(default)]
+    pub title: bool,
+
+    /// Whether a missing work item reference is added from the branch name. Defaults
+    /// to `false`.
+    #[serde(default)]
+    pub work_item: bool,
+}
+
+impl AutofixPolicyConfig {
+    /// Returns `true` when any fix is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.title || self.work_item
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `title`: `base.title || over.title`
+    /// - `work_item`: `base.work_item || over.work_item`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.36 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            title: base.title || over.title,
+            work_item: base.work_item || over.work_item,
+        }
+    }
+}
+
+/// Configuration for the descriptive title check.
+///
+/// Contributors sometimes keep the title that was suggested from the branch name,
+/// such as `feature/add-login` or `Add login`, which does not describe the change.
+/// When enabled, the check fails if the title, without its conventional commit
+/// prefix, is the head branch name, without its prefix such as `feature/`, after
+/// ignoring case and treating `-`, `_`, `/` and `.` as spaces. The `severity`
+/// controls the enforcement level: `error` fails the check, while `warning` and
+/// `notice` only report the title.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckSeverity, DescriptiveTitlePolicyConfig};
+///
+/// let config = DescriptiveTitlePolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.severity, CheckSeverity::Error);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DescriptiveTitlePolicyConfig {
+    /// Whether the descriptive title check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Severity of a failing descriptive title check. Defaults to `error`.
+    ///
+    /// Only `error` causes the check run to fail.
+    #[serde(default = "DescriptiveTitlePolicyConfig::default_severity")]
+    pub severity: CheckSeverity,
+}
+
+impl DescriptiveTitlePolicyConfig {
+    /// Default value for `severity`.
+    fn default_severity() -> CheckSeverity {
+        CheckSeverity::Error
+    }
+
+    /// Returns `true` if a failing descriptive title check should fail the check run.
+    pub fn fails_check(&self) -> bool {
+        self.severity == CheckSeverity::Error
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `severity`: `over` if it differs from the default (`error`); otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.32 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            severity: if over.severity != Self::default_severity() {
+                over.severity
+            } else {
+                base.severity
+            },
+        }
+    }
+}
+
+impl Default for DescriptiveTitlePolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            severity: Self::default_severity(),
+        }
+    }
+}
+
+/// Configuration for the change types check.
+///
+/// A PR that is at the same time a `feat`, a `fix`, a `refactor` and a `docs` change
+/// is doing too much to review well. When enabled, the check collects the distinct
+/// conventional commit types of the PR title and of the subject lines of its commits,
+/// and fails when there are more than `max_change_types`. Only the types of
+/// [`VALID_PR_TYPES`] are counted; commits without a conventional commit prefix, such
+/// as merge commits, are ignored, and so are the types in `ignored_types`. The
+/// `severity` controls the enforcement level: `error` fails the check, while `warning`
+/// and `notice` only report the types.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{ChangeTypesPolicyConfig, CheckSeverity};
+///
+/// let config = ChangeTypesPolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.severity, CheckSeverity::Warning);
+/// assert_eq!(config.max_change_types, 2);
+/// assert!(config.ignored_types.is_empty());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChangeTypesPolicyConfig {
+    /// Whether the change types check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Severity of a failing change types check. Defaults to `warning`.
+    ///
+    /// Only `error` causes the check run to fail.
+    #[serde(default = "ChangeTypesPolicyConfig::default_severity")]
+    pub severity: CheckSeverity,
+
+    /// The most distinct change types a PR may have. Defaults to `2`.
+    #[serde(default = "ChangeTypesPolicyConfig::default_max_change_types")]
+    pub max_change_types: usize,
+
+    /// Change types that are not counted, such as `test` or `docs` when tests and
+    /// documentation are expected to accompany every change. Matched ignoring case.
+    /// Defaults to none.
+    #[serde(default)]
+    pub ignored_types: Vec<String>,
+}
+
+impl ChangeTypesPolicyConfig {
+    /// Default value for `severity`.
+    fn default_severity() -> CheckSeverity {
+        CheckSeverity::Warning
+    }
+
+    /// Default value for `max_change_types`.
+    fn default_max_change_types() -> usize {
+        2
+    }
+
+    /// Returns `true` if a failing change types check should fail the check run.
+    pub fn fails_check(&self) -> bool {
+        self.severity == CheckSeverity::Error
+    }
+
+    /// Returns `true` if `change_type` is one of the `ignored_types`, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::ChangeTypesPolicyConfig;
+    ///
+    /// let config = ChangeTypesPolicyConfig {
+    ///     ignored_types: vec!["test".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// assert!(config.is_ignored("Test"));
+    /// assert!(!config.is_ignored("feat"));
+    /// ```
+    pub fn is_ignored(&self, change_type: &str) -> bool {
+        self.ignored_types
+            .iter()
+            .any(|ignored| ignored.eq_ignore_ascii_case(change_type))
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `severity`: `over` if it differs from the default (`warning`); otherwise `base`
+    /// - `max_change_types`: `over` if it differs from the default (`2`); otherwise `base`
+    /// - `ignored_types`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.35 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            severity: if over.severity != Self::default_severity() {
+                over.severity
+            } else {
+                base.severity
+            },
+            max_change_types: if over.max_change_types != Self::default_max_change_types() {
+                over.max_change_types
+            } else {
+                base.max_change_types
+            },
+            ignored_types: if over.ignored_types.is_empty() {
+                base.ignored_types.clone()
+            } else {
+                over.ignored_types.clone()
+            },
+        }
+    }
+}
+
+impl Default for ChangeTypesPolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            severity: Self::default_severity(),
+            max_change_types: Self::default_max_change_types(),
+            ignored_types: Vec::new(),
+        }
+    }
+}
+
+/// Configuration for the label policy check.
+///
+/// When enabled, a PR fails the check if it lacks a label matching one of the
+/// `required_labels`, or if it carries a label matching one of the `forbidden_labels`,
+/// such as `do-not-merge`. Each entry is a label name or a pattern with `*` wildcards,
+/// e.g. `"team: *"`. Matching ignores case. The `severity` controls the enforcement
+/// level: `error` fails the check, while `warning` and `notice` only report the labels.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckSeverity, LabelPolicyConfig};
+///
+/// let config = LabelPolicyConfig {
+///     enabled: true,
+///     forbidden_labels: vec!["do-not-merge".to_string()],
+///     ..Default::default()
+/// };
+/// assert_eq!(config.severity, CheckSeverity::Error);
+/// assert!(config.required_labels.is_empty());
+/// assert!(config.is_forbidden("Do-Not-Merge"));
+/// assert!(!config.is_forbidden("bug"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LabelPolicyConfig {
+    /// Whether the label policy check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Severity of a failing label policy check. Defaults to `error`.
+    ///
+    /// Only `error` causes the check run to fail.
+    #[serde(default = "LabelPolicyConfig::default_severity")]
+    pub severity: CheckSeverity,
+
+    /// Names or `*` patterns of the labels a PR must carry. Each entry must match at
+    /// least one applied label. Defaults to none.
+    #[serde(default)]
+    pub required_labels: Vec<String>,
+
+    /// Names or `*` patterns of the labels a PR must not carry. Defaults to none.
+    #[serde(default)]
+    pub forbidden_labels: Vec<String>,
+}
+
+impl LabelPolicyConfig {
+    /// Default value for `severity`.
+    fn default_severity() -> CheckSeverity {
+        CheckSeverity::Error
+    }
+
+    /// Returns `true` if a failing label policy check should fail the check run.
+    pub fn fails_check(&self) -> bool {
+        self.severity == CheckSeverity::Error
+    }
+
+    /// Returns `true` if `label` matches one of the `forbidden_labels`, ignoring case.
+    pub fn is_forbidden(&self, label: &str) -> bool {
+        let label = label.to_lowercase();
+        self.forbidden_labels
+            .iter()
+            .any(|pattern| pattern_matches(&pattern.to_lowercase(), &label))
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `severity`: `over` if it differs from the default (`error`); otherwise `base`
+    /// - `required_labels`: `over` if non-empty; otherwise `base`
+    /// - `forbidden_labels`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.37 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            severity: if over.severity != Self::default_severity() {
+                over.severity
+            } else {
+                base.severity
+            },
+            required_labels: if over.required_labels.is_empty() {
+                base.required_labels.clone()
+            } else {
+                over.required_labels.clone()
+            },
+            forbidden_labels: if over.forbidden_labels.is_empty() {
+                base.forbidden_labels.clone()
+            } else {
+                over.forbidden_labels.clone()
+            },
+        }
+    }
+}
+
+impl Default for LabelPolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            severity: Self::default_severity(),
+            required_labels: Vec::new(),
+            forbidden_labels: Vec::new(),
+        }
+    }
+}
+
+/// A pairing rule for the deleted-file check.
+///
+/// When the PR deletes a file matching `deleted`, at least one other file
+/// changed in the same PR must match one of the `requires` patterns. Patterns
+/// use gitignore semantics, like the size check's `excluded_file_patterns`; see
+/// [`FileExclusions`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::DeletedFilePairingRule;
+///
+/// let rule: DeletedFilePairingRule = toml::from_str(
+///     r#"
+///     deleted = "src/**/*.rs"
+///     requires = ["docs/", "tests/"]
+///     "#,
+/// )
+/// .unwrap();
+/// assert!(rule.deleted_files().is_excluded("src/parser/lexer.rs"));
+/// assert!(rule.required_files().is_excluded("docs/parser.md"));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeletedFilePairingRule {
+    /// Pattern matching the deleted files that trigger this rule.
+    pub deleted: String,
+
+    /// Patterns of which at least one must match another file changed in the PR.
+    #[serde(default)]
+    pub requires: Vec<String>,
+}
+
+impl DeletedFilePairingRule {
+    /// Compiles the `deleted` pattern. A deleted file triggers the rule when
+    /// [`FileExclusions::is_excluded`] returns `true` for it.
+    pub fn deleted_files(&self) -> FileExclusions {
+        FileExclusions::new(std::slice::from_ref(&self.deleted))
+    }
+
+    /// Compiles the `requires` patterns. A changed file satisfies the rule when
+    /// [`FileExclusions::is_excluded`] returns `true` for it.
+    pub fn required_files(&self) -> FileExclusions {
+        FileExclusions::new(&self.requires)
+    }
+}
+
+/// Configuration for the deleted-file pairing check.
+///
+/// When enabled, every deletion that matches one of the `rules` must be
+/// accompanied by a change to a file matching the rule's paired patterns, for
+/// example deleting a source file must also update its documentation. The
+/// `severity` controls the enforcement level: `error` fails the check, while
+/// `warning` and `notice` only report the missing changes.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckSeverity, DeletedFilePolicyConfig};
+///
+/// let config = DeletedFilePolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.severity, CheckSeverity::Warning);
+/// assert!(config.rules.is_empty());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeletedFilePolicyConfig {
+    /// Whether the deleted-file check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Severity of a failing deleted-file check. Defaults to `warning`.
+    ///
+    /// Only `error` causes the check run to fail.
+    #[serde(default = "DeletedFilePolicyConfig::default_severity")]
+    pub severity: CheckSeverity,
+
+    /// The pairing rules that deleted files are checked against.
+    #[serde(default)]
+    pub rules: Vec<DeletedFilePairingRule>,
+}
+
+impl DeletedFilePolicyConfig {
+    /// Default value for `severity`.
+    fn default_severity() -> CheckSeverity {
+        CheckSeverity::Warning
+    }
+
+    /// Returns `true` if a failing deleted-file check should fail the check run.
+    pub fn fails_check(&self) -> bool {
+        self.severity == CheckSeverity::Error
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `severity`: `over` if it differs from the default (`warning`); otherwise `base`
+    /// - `rules`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.12 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            severity: if over.severity != Self::default_severity() {
+                over.severity
+            } else {
+                base.severity
+            },
+            rules: if !over.rules.is_empty() {
+                over.rules.clone()
+            } else {
+                base.rules.clone()
+            },
+        }
+    }
+}
+
+impl Default for DeletedFilePolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            severity: Self::default_severity(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// Configuration for the suspicious file check.
+///
+/// When enabled, files added or modified by the PR are flagged when their path
+/// matches one of the `secret_patterns` (private keys, `.env` files) or
+/// `binary_patterns` (archives, executables), or when more than `max_file_lines`
+/// lines are added to a single file. Patterns use gitignore semantics, like the size
+/// check's `excluded_file_patterns` (see [`FileExclusions`]): a pattern without a `/`
+/// matches the file name in any directory, and a later `!` pattern re-includes files.
+/// Deleted files are never flagged.
+///
+/// The platform reports changed line counts rather than file sizes, and reports
+/// no lines for binary files, so large binaries are only found by their names.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckSeverity, SuspiciousFilePolicyConfig};
+///
+/// let config = SuspiciousFilePolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.severity, CheckSeverity::Warning);
+/// assert_eq!(config.secret_files().excluding_pattern("deploy/id_rsa"), Some("id_rsa"));
+/// assert_eq!(config.binary_files().excluding_pattern("dist/app.zip"), Some("*.zip"));
+/// assert_eq!(config.secret_files().excluding_pattern("src/main.rs"), None);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SuspiciousFilePolicyConfig {
+    /// Whether the suspicious file check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Severity of a failing suspicious file check. Defaults to `warning`.
+    ///
+    /// Only `error` causes the check run to fail.
+    #[serde(default = "SuspiciousFilePolicyConfig::default_severity")]
+    pub severity: CheckSeverity,
+
+    /// Maximum number of lines a single file may add. Defaults to `5000`; `0`
+    /// disables the limit.
+    #[serde(default = "SuspiciousFilePolicyConfig::default_max_file_lines")]
+    pub max_file_lines: u32,
+
+    /// Patterns for files that look like secrets, such as private keys.
+    #[serde(default = "SuspiciousFilePolicyConfig::default_secret_patterns")]
+    pub secret_patterns: Vec<String>,
+
+    /// Patterns for binary files, such as archives and executables.
+    #[serde(default = "SuspiciousFilePolicyConfig::default_binary_patterns")]
+    pub binary_patterns: Vec<String>,
+}
+
+impl SuspiciousFilePolicyConfig {
+    /// Default value for `severity`.
+    fn default_severity() -> CheckSeverity {
+        CheckSeverity::Warning
+    }
+
+    /// Default value for `max_file_lines`.
+    fn default_max_file_lines() -> u32 {
+        5000
+    }
+
+    /// Default value for `secret_patterns`.
+    fn default_secret_patterns() -> Vec<String> {
+        [
+            "*.pem",
+            "*.key",
+            "*.p12",
+            "*.pfx",
+            "id_rsa",
+            "id_dsa",
+            "id_ecdsa",
+            "id_ed25519",
+            ".env",
+        ]
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
+    }
+
+    /// Default value for `binary_patterns`.
+    fn default_binary_patterns() -> Vec<String> {
+        [
+            "*.zip", "*.tar", "*.tar.gz", "*.tgz", "*.7z", "*.rar", "*.jar", "*.exe", "*.dll",
+            "*.so", "*.dylib", "*.bin", "*.iso", "*.dmg",
+        ]
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
+    }
+
+    /// Compiles the `secret_patterns`. A file is a secret when
+    /// [`FileExclusions::excluding_pattern`] returns a pattern for it.
+    pub fn secret_files(&self) -> FileExclusions {
+        FileExclusions::new(&self.secret_patterns)
+    }
+
+    /// Compiles the `binary_patterns`. A file is a binary when
+    /// [`FileExclusions::excluding_pattern`] returns a pattern for it.
+    pub fn binary_files(&self) -> FileExclusions {
+        FileExclusions::new(&self.binary_patterns)
+    }
+
+    /// Returns `true` if a failing suspicious file check should fail the check run.
+    pub fn fails_check(&self) -> bool {
+        self.severity == CheckSeverity::Error
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `severity`, `max_file_lines`, `secret_patterns`, `binary_patterns`: `over` if
+    ///   it differs from the default; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.17 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            severity: if over.severity != Self::default_severity() {
+                over.severity
+            } else {
+                base.severity
+            },
+            max_file_lines: if over.max_file_lines != Self::default_max_file_lines() {
+                over.max_file_lines
+            } else {
+                base.max_file_lines
+            },
+            secret_patterns: if over.secret_patterns != Self::default_secret_patterns() {
+                over.secret_patterns.clone()
+            } else {
+                base.secret_patterns.clone()
+            },
+            binary_patterns: if over.binary_patterns != Self::default_binary_patterns() {
+                over.binary_patterns.clone()
+            } else {
+                base.binary_patterns.clone()
+            },
+        }
+    }
+}
+
+impl Default for SuspiciousFilePolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            severity: Self::default_severity(),
+            max_file_lines: Self::default_max_file_lines(),
+            secret_patterns: Self::default_secret_patterns(),
+            binary_patterns: Self::default_binary_patterns(),
+        }
+    }
+}
+
+/// Configuration for the handling of reopened pull requests.
+///
+/// A reopened pull request is always validated again in full. When `reset_artifacts`
+/// is enabled, the labels and comments Merge Warden left on the pull request before
+/// it was closed are removed first, so that only the artifacts of the new
+/// validation remain. This cleans up artifacts of checks that were disabled, or
+/// whose labels were renamed, while the pull request was closed.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::ReopenedPolicyConfig;
+///
+/// let config = ReopenedPolicyConfig::default();
+/// assert!(!config.reset_artifacts);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReopenedPolicyConfig {
+    /// Whether to remove the labels and comments left before the pull request was
+    /// closed. Defaults to `false`.
+    #[serde(default)]
+    pub reset_artifacts: bool,
+}
+
+impl ReopenedPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `reset_artifacts`: `base.reset_artifacts || over.reset_artifacts`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.18 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            reset_artifacts: base.reset_artifacts || over.reset_artifacts,
+        }
+    }
+}
+
+/// Configuration for managing the invalid-title and missing-work-item labels.
+///
+/// By default Merge Warden removes the invalid-title label once the title is valid,
+/// and the missing-work-item label once a work item is referenced. Teams that also
+/// apply these labels by hand can set `remove_on_pass` to `false`; Merge Warden then
+/// still adds the labels when a check fails, but never removes them, leaving that
+/// to people. The check status is reported either way.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::StatusLabelPolicyConfig;
+///
+/// let config = StatusLabelPolicyConfig::default();
+/// assert!(config.remove_on_pass);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StatusLabelPolicyConfig {
+    /// Whether to remove the invalid-title and missing-work-item labels when the
+    /// corresponding check passes. Defaults to `true`.
+    #[serde(default = "StatusLabelPolicyConfig::default_remove_on_pass")]
+    pub remove_on_pass: bool,
+}
+
+impl StatusLabelPolicyConfig {
+    /// Default value for `remove_on_pass`.
+    fn default_remove_on_pass() -> bool {
+        true
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `remove_on_pass`: `base.remove_on_pass && over.remove_on_pass` — once either
+    ///   tier leaves the labels to people, Merge Warden does not remove them
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.19 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            remove_on_pass: base.remove_on_pass && over.remove_on_pass,
+        }
+    }
+}
+
+impl Default for StatusLabelPolicyConfig {
+    fn default() -> Self {
+        Self {
+            remove_on_pass: Self::default_remove_on_pass(),
+        }
+    }
+}
+
+/// Templates of the title and work item guidance for one kind of contributor.
+///
+/// Each template is a MiniJinja source rendered against the same context as the
+/// built-in template it replaces; see [`crate::templates`]. `None` keeps the renderer's
+/// template.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GuidanceTemplates {
+    /// Template of the comment posted when the title is invalid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invalid_title: Option<String>,
+
+    /// Template of the comment posted when the body has no work item reference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_work_item: Option<String>,
+}
+
+impl GuidanceTemplates {
+    /// Merges `over` on top of `base` (lower-priority); each template is taken from
+    /// `over` if set, otherwise from `base`.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            invalid_title: over
+                .invalid_title
+                .clone()
+                .or_else(|| base.invalid_title.clone()),
+            missing_work_item: over
+                .missing_work_item
+                .clone()
+                .or_else(|| base.missing_work_item.clone()),
+        }
+    }
+
+    /// Whether no template is set.
+    pub fn is_empty(&self) -> bool {
+        self.invalid_title.is_none() && self.missing_work_item.is_none()
+    }
+}
+
+/// Different title and work item guidance for the repository's team and for outside
+/// contributors.
+///
+/// Internal contributors are the repository's owners, the members of the owning
+/// organization and its collaborators; everyone else is external. When a template is
+/// set for either kind, the pull request author's association with the repository is
+/// looked up, which costs one API request per guidance comment. When the association
+/// cannot be determined, the default templates are used.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::ContributorGuidanceConfig;
+///
+/// let config: ContributorGuidanceConfig = toml::from_str(
+///     r#"
+///     [internal]
+///     invalid_title = "Title: {% for issue in issues %}{{ issue }} {% endfor %}"
+///     "#,
+/// )
+/// .unwrap();
+/// assert!(config.internal.invalid_title.is_some());
+/// assert!(config.external.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ContributorGuidanceConfig {
+    /// Templates for owners, organization members and collaborators.
+    #[serde(default)]
+    pub internal: GuidanceTemplates,
+
+    /// Templates for everyone else, including first-time contributors.
+    #[serde(default)]
+    pub external: GuidanceTemplates,
+}
+
+impl ContributorGuidanceConfig {
+    /// Whether any contributor-specific template is set.
+    pub fn is_enabled(&self) -> bool {
+        !self.internal.is_empty() || !self.external.is_empty()
+    }
+}
+
+/// Configuration for how Merge Warden posts its guidance comments.
+///
+/// By default each guidance comment (invalid title, missing work item, size, WIP and
+/// configuration problems) is a top-level comment on the pull request. When
+/// `threaded` is enabled, the comments are posted as replies in a single review
+/// thread instead, which sends fewer notifications. The thread is started by a
+/// comment marked with [`THREAD_ROOT_COMMENT_MARKER`]. Platforms without threaded
+/// comments fall back to top-level comments.
+///
+/// All configured comment text is a template with the same `{{ variable }}` syntax
+/// as the built-in comments; see [`crate::explain::configured_comment_renderer`].
+///
+/// `title_bypass_notice` and `work_item_bypass_notice` replace the text of the
+/// comments posted when a check was bypassed. They can use `{{ user }}`,
+/// `{{ rule_type }}` and `{{ title }}`: the user whose bypass was applied, the
+/// bypassed rule and the PR title.
+///
+/// `title_invalid_comment_template` and `missing_work_item_comment_template` replace
+/// the templates of the invalid title and missing work item comments. They are
+/// rendered against the same context as the built-in templates, which includes the
+/// configured `pattern` and a list of example titles or references as `examples`.
+///
+/// When `transition_summary` is enabled, a summary comment is posted only when the
+/// check conclusion differs from the previous run, which is remembered in the
+/// [`crate::state::StateStore`].
+///
+/// `contributor_guidance` sets different title and work item guidance for internal
+/// and external contributors; see [`ContributorGuidanceConfig`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::CommentPolicyConfig;
+///
+/// let config = CommentPolicyConfig::default();
+/// assert!(!config.threaded);
+/// assert!(config.title_bypass_notice.is_none());
+/// assert!(!config.transition_summary);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommentPolicyConfig {
+    /// Whether to post guidance comments as replies in one review thread. Defaults
+    /// to `false`.
+    #[serde(default)]
+    pub threaded: bool,
+
+    /// Template of the comment posted when the title check was bypassed. `None`
+    /// uses the built-in template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_bypass_notice: Option<String>,
+
+    /// Template of the comment posted when the work item check was bypassed.
+    /// `None` uses the built-in template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_item_bypass_notice: Option<String>,
+
+    /// Template of the comment posted when the title check fails. `None` uses the
+    /// built-in template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_invalid_comment_template: Option<String>,
+
+    /// Template of the comment posted when the work item check fails. `None` uses
+    /// the built-in template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_work_item_comment_template: Option<String>,
+
+    /// Whether to post a summary comment when the check conclusion changes, for
+    /// example from `failure` to `success`. Defaults to `false`.
+    #[serde(default)]
+    pub transition_summary: bool,
+
+    /// Guidance templates chosen by whether the author is an internal or external
+    /// contributor.
+    #[serde(default)]
+    pub contributor_guidance: ContributorGuidanceConfig,
+}
+
+impl CommentPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `threaded`: `base.threaded || over.threaded`
+    /// - `title_bypass_notice`: `over` if set; otherwise `base`
+    /// - `work_item_bypass_notice`: `over` if set; otherwise `base`
+    /// - `title_invalid_comment_template`: `over` if set; otherwise `base`
+    /// - `missing_work_item_comment_template`: `over` if set; otherwise `base`
+    /// - `transition_summary`: `base.transition_summary || over.transition_summary`
+    /// - `contributor_guidance`: per contributor kind and template, `over` if set;
+    ///   otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.20 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            threaded: base.threaded || over.threaded,
+            title_bypass_notice: over
+                .title_bypass_notice
+                .clone()
+                .or_else(|| base.title_bypass_notice.clone()),
+            work_item_bypass_notice: over
+                .work_item_bypass_notice
+                .clone()
+                .or_else(|| base.work_item_bypass_notice.clone()),
+            title_invalid_comment_template: over
+                .title_invalid_comment_template
+                .clone()
+                .or_else(|| base.title_invalid_comment_template.clone()),
+            missing_work_item_comment_template: over
+                .missing_work_item_comment_template
+                .clone()
+                .or_else(|| base.missing_work_item_comment_template.clone()),
+            transition_summary: base.transition_summary || over.transition_summary,
+            contributor_guidance: ContributorGuidanceConfig {
+                internal: GuidanceTemplates::merge(
+                    &base.contributor_guidance.internal,
+                    &over.contributor_guidance.internal,
+                ),
+                external: GuidanceTemplates::merge(
+                    &base.contributor_guidance.external,
+                    &over.contributor_guidance.external,
+                ),
+            },
+        }
+    }
+}
+
+/// A rule of the base branch check.
+///
+/// A rule applies to a PR whose title has one of the `title_types` (`hotfix: ...`),
+/// or whose head branch starts with one of the `branch_prefixes` (`hotfix/`). Such a
+/// PR must target a base branch matching one of the `allowed_bases` patterns. A
+/// pattern must match the whole branch name; `*` matches any run of characters,
+/// including `/`, and every other character matches itself, so `release/*` allows
+/// `release/1.2` and `release/1.x/hotfix` but not `release`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::BaseBranchRule;
+///
+/// let rule: BaseBranchRule = toml::from_str(
+///     r#"
+///     title_types = ["hotfix"]
+///     branch_prefixes = ["hotfix/"]
+///     allowed_bases = ["release/*"]
+///     "#,
+/// )
+/// .unwrap();
+/// assert!(rule.applies_to(Some("hotfix"), "main"));
+/// assert!(rule.applies_to(None, "hotfix/login-crash"));
+/// assert!(rule.allows_base("release/1.2"));
+/// assert!(!rule.allows_base("main"));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BaseBranchRule {
+    /// Conventional commit title types the rule applies to, compared ignoring case.
+    #[serde(default)]
+    pub title_types: Vec<String>,
+
+    /// Head branch name prefixes the rule applies to.
+    #[serde(default)]
+    pub branch_prefixes: Vec<String>,
+
+    /// Patterns of the base branches the PR may target.
+    #[serde(default)]
+    pub allowed_bases: Vec<String>,
+}
+
+impl BaseBranchRule {
+    /// Returns `true` if the rule applies to a PR with the given title type and head branch.
+    pub fn applies_to(&self, title_type: Option<&str>, head_branch: &str) -> bool {
+        let matches_type = title_type.is_some_and(|title_type| {
+            self.title_types
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(title_type))
+        });
+        let matches_prefix = !head_branch.is_empty()
+            && self
+                .branch_prefixes
+                .iter()
+                .any(|prefix| head_branch.starts_with(prefix.as_str()));
+        matches_type || matches_prefix
+    }
+
+    /// Returns `true` if `base_branch` matches one of the `allowed_bases` patterns.
+    pub fn allows_base(&self, base_branch: &str) -> bool {
+        self.allowed_bases
+            .iter()
+            .any(|pattern| pattern_matches(pattern, base_branch))
+    }
+}
+
+/// Configuration for the base branch check.
+///
+/// When enabled, a PR matched by one of the `rules` must target one of the rule's
+/// allowed base branches, for example `hotfix` PRs must target `release/*` and
+/// features must target `develop`. The first rule that applies to the PR is used;
+/// PRs that no rule applies to pass. The `severity` controls the enforcement level:
+/// `error` fails the check, while `warning` and `notice` only report the mismatch.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{BaseBranchPolicyConfig, CheckSeverity};
+///
+/// let config = BaseBranchPolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.severity, CheckSeverity::Error);
+/// assert!(config.rules.is_empty());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BaseBranchPolicyConfig {
+    /// Whether the base branch check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Severity of a failing base branch check. Defaults to `error`.
+    ///
+    /// Only `error` causes the check run to fail.
+    #[serde(default = "BaseBranchPolicyConfig::default_severity")]
+    pub severity: CheckSeverity,
+
+    /// The rules that map PR types to their allowed base branches.
+    #[serde(default)]
+    pub rules: Vec<BaseBranchRule>,
+}
+
+impl BaseBranchPolicyConfig {
+    /// Default value for `severity`.
+    fn default_severity() -> CheckSeverity {
+        CheckSeverity::Error
+    }
+
+    /// Returns the first rule that applies to a PR with the given title type and head branch.
+    pub fn rule_for(&self, title_type: Option<&str>, head_branch: &str) -> Option<&BaseBranchRule> {
+        self.rules
+            .iter()
+            .find(|rule| rule.applies_to(title_type, head_branch))
+    }
+
+    /// Returns `true` if a failing base branch check should fail the check run.
+    pub fn fails_check(&self) -> bool {
+        self.severity == CheckSeverity::Error
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `severity`: `over` if it differs from the default (`error`); otherwise `base`
+    /// - `rules`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.21 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            severity: if over.severity != Self::default_severity() {
+                over.severity
+            } else {
+                base.severity
+            },
+            rules: if !over.rules.is_empty() {
+                over.rules.clone()
+            } else {
+                base.rules.clone()
+            },
+        }
+    }
+}
+
+impl Default for BaseBranchPolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            severity: Self::default_severity(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// A group of labels of which a PR may carry at most one, such as the priorities
+/// `priority: high` and `priority: low`.
+///
+/// Each entry of `labels` is a label name or a pattern with `*` wildcards, e.g.
+/// `"priority: *"`. Matching ignores case.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::ExclusiveLabelGroup;
+///
+/// let group = ExclusiveLabelGroup {
+///     name: "priority".to_string(),
+///     labels: vec!["priority: *".to_string()],
+/// };
+/// assert!(group.contains("Priority: High"));
+/// assert!(!group.contains("bug"));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExclusiveLabelGroup {
+    /// Name of the group, used in the check output.
+    #[serde(default)]
+    pub name: String,
+
+    /// Names or `*` patterns of the labels in the group.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl ExclusiveLabelGroup {
+    /// Returns `true` if `label` belongs to the group.
+    pub fn contains(&self, label: &str) -> bool {
+        let label = label.to_lowercase();
+        self.labels
+            .iter()
+            .any(|pattern| pattern_matches(&pattern.to_lowercase(), &label))
+    }
+}
+
+/// Configuration for the exclusive labels check.
+///
+/// When enabled, a PR fails the check if it carries more than one label from any of
+/// the `groups`. The `severity` controls the enforcement level: `error` fails the
+/// check, while `warning` and `notice` only report the conflict.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckSeverity, ExclusiveLabelsPolicyConfig};
+///
+/// let config = ExclusiveLabelsPolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.severity, CheckSeverity::Error);
+/// assert!(config.groups.is_empty());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExclusiveLabelsPolicyConfig {
+    /// Whether the exclusive labels check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Severity of a failing exclusive labels check. Defaults to `error`.
+    ///
+    /// Only `error` causes the check run to fail.
+    #[serde(default = "ExclusiveLabelsPolicyConfig::default_severity")]
+    pub severity: CheckSeverity,
+
+    /// The groups of mutually exclusive labels.
+    #[serde(default)]
+    pub groups: Vec<ExclusiveLabelGroup>,
+}
+
+impl ExclusiveLabelsPolicyConfig {
+    /// Default value for `severity`.
+    fn default_severity() -> CheckSeverity {
+        CheckSeverity::Error
+    }
+
+    /// Returns `true` if a failing exclusive labels check should fail the check run.
+    pub fn fails_check(&self) -> bool {
+        self.severity == CheckSeverity::Error
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `severity`: `over` if it differs from the default (`error`); otherwise `base`
+    /// - `groups`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.22 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            severity: if over.severity != Self::default_severity() {
+                over.severity
+            } else {
+                base.severity
+            },
+            groups: if !over.groups.is_empty() {
+                over.groups.clone()
+            } else {
+                base.groups.clone()
+            },
+        }
+    }
+}
+
+/// Configuration for skipping pull requests that only change ignored paths.
+///
+/// When every file changed by a PR matches one of the `skip_if_only_paths` patterns,
+/// Merge Warden runs none of its checks and reports a `neutral` check run instead.
+/// Patterns use gitignore semantics, like the size check's `excluded_file_patterns`.
+/// An empty list, the default, never skips a PR.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::PathSkipPolicyConfig;
+///
+/// let config = PathSkipPolicyConfig {
+///     skip_if_only_paths: vec![".github/".to_string(), "docs/".to_string()],
+/// };
+/// assert!(config.is_enabled());
+/// assert!(!PathSkipPolicyConfig::default().is_enabled());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PathSkipPolicyConfig {
+    /// Patterns for the paths a PR may change without being checked.
+    #[serde(default)]
+    pub skip_if_only_paths: Vec<String>,
+}
+
+impl PathSkipPolicyConfig {
+    /// Returns `true` if any skip pattern is configured.
+    pub fn is_enabled(&self) -> bool {
+        !self.skip_if_only_paths.is_empty()
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `skip_if_only_paths`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.23 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            skip_if_only_paths: if !over.skip_if_only_paths.is_empty() {
+                over.skip_if_only_paths.clone()
+            } else {
+                base.skip_if_only_paths.clone()
+            },
+        }
+    }
+}
+
+impl Default for ExclusiveLabelsPolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            severity: Self::default_severity(),
+            groups: Vec::new(),
+        }
+    }
+}
+
+/// Configuration for the version bump check.
+///
+/// When enabled and the PR changes the manifest at `manifest_path`, the check
+/// compares the manifest's version on the default branch with the version at
+/// the PR head. A changed version must be the next version at the level implied
+/// by the PR's changes: major for breaking changes, minor for features and patch
+/// otherwise. PRs that leave the version unchanged are not checked.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::VersionBumpPolicyConfig;
+/// use merge_warden_core::version::ManifestFormat;
+///
+/// let config = VersionBumpPolicyConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.manifest_path, "Cargo.toml");
+/// assert_eq!(config.manifest_format, ManifestFormat::Auto);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VersionBumpPolicyConfig {
+    /// Whether the version bump check is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Repository-relative path of the manifest holding the version. Defaults to `Cargo.toml`.
+    #[serde(default = "VersionBumpPolicyConfig::default_manifest_path")]
+    pub manifest_path: String,
+
+    /// Format of the manifest. Defaults to `auto`, which picks the format from the file name.
+    #[serde(default)]
+    pub manifest_format: ManifestFormat,
+}
+
+impl VersionBumpPolicyConfig {
+    /// Default value for `manifest_path`.
+    fn default_manifest_path() -> String {
+        "Cargo.toml".to_string()
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `manifest_path`: `over` if it differs from the default (`Cargo.toml`); otherwise `base`
+    /// - `manifest_format`: `over` if it differs from the default (`auto`); otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.13 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            manifest_path: if over.manifest_path != Self::default_manifest_path() {
+                over.manifest_path.clone()
+            } else {
+                base.manifest_path.clone()
+            },
+            manifest_format: if over.manifest_format != ManifestFormat::default() {
+                over.manifest_format
+            } else {
+                base.manifest_format
+            },
+        }
+    }
+}
+
+impl Default for VersionBumpPolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            manifest_path: Self::default_manifest_path(),
+            manifest_format: ManifestFormat::default(),
+        }
+    }
+}
+
+/// Configuration for detecting dependency-update pull requests and labelling them.
+///
+/// A PR is a dependency update when its title has one of the `title_types` (`deps: ...`)
+/// or `title_scopes` (`chore(deps): ...`), or when its changed files look like a
+/// dependency bump: at least one file matches `lockfile_patterns` and, with
+/// `require_only_dependency_files`, every changed file matches either `lockfile_patterns`
+/// or `manifest_patterns`. A manifest change that edits dependencies also updates the
+/// lockfile, so requiring a lockfile keeps manifest-only edits (a version or metadata
+/// change) from being labelled. Patterns use `.gitignore` syntax.
+///
+/// Human-authored PRs are detected the same way as bot-authored ones. The label is added
+/// when a PR is detected and never removed, so a manually applied label is kept.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{DependencyUpdateConfig, DEPENDENCIES_LABEL};
+///
+/// let config = DependencyUpdateConfig::default();
+/// assert!(!config.enabled);
+/// assert_eq!(config.label, DEPENDENCIES_LABEL);
+/// assert!(config.title_scopes.contains(&"deps".to_string()));
+/// assert!(config.lockfile_patterns.contains(&"Cargo.lock".to_string()));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DependencyUpdateConfig {
+    /// Whether dependency-update detection is enabled. Defaults to `false`.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Label applied to dependency-update PRs. Defaults to [`DEPENDENCIES_LABEL`].
+    #[serde(default = "DependencyUpdateConfig::default_label")]
+    pub label: String,
+
+    /// Conventional commit types that mark a dependency update, matched case-insensitively.
+    /// Defaults to `["deps"]`.
+    #[serde(default = "DependencyUpdateConfig::default_title_types")]
+    pub title_types: Vec<String>,
+
+    /// Conventional commit scopes that mark a dependency update, with any type,
+    /// matched case-insensitively. Defaults to `["deps", "deps-dev"]`.
+    #[serde(default = "DependencyUpdateConfig::default_title_scopes")]
+    pub title_scopes: Vec<String>,
+
+    /// Lockfile patterns. A change to a matching file is required for file-based
+    /// detection; an empty list turns file-based detection off.
+    #[serde(default = "DependencyUpdateConfig::default_lockfile_patterns")]
+    pub lockfile_patterns: Vec<String>,
+
+    /// Manifest patterns. Matching files may change alongside a lockfile.
+    #[serde(default = "DependencyUpdateConfig::default_manifest_patterns")]
+    pub manifest_patterns: Vec<String>,
+
+    /// Whether file-based detection requires every changed file to be a lockfile or
+    /// manifest. When `false`, any lockfile change marks the PR. Defaults to `true`.
+    #[serde(default = "DependencyUpdateConfig::default_require_only_dependency_files")]
+    pub require_only_dependency_files: bool,
+}
+
+impl DependencyUpdateConfig {
+    /// Default value for `label`.
+    fn default_label() -> String {
+        DEPENDENCIES_LABEL.to_string()
+    }
+
+    /// Default value for `title_types`.
+    fn default_title_types() -> Vec<String> {
+        vec!["deps".to_string()]
+    }
+
+    /// Default value for `title_scopes`.
+    fn default_title_scopes() -> Vec<String> {
+        vec!["deps".to_string(), "deps-dev".to_string()]
+    }
+
+    /// Default value for `lockfile_patterns`.
+    fn default_lockfile_patterns() -> Vec<String> {
+        [
+            "Cargo.lock",
+            "package-lock.json",
+            "npm-shrinkwrap.json",
+            "yarn.lock",
+            "pnpm-lock.yaml",
+            "go.sum",
+            "poetry.lock",
+            "Pipfile.lock",
+            "uv.lock",
+            "Gemfile.lock",
+            "composer.lock",
+            "packages.lock.json",
+            "gradle.lockfile",
+        ]
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
+    }
+
+    /// Default value for `manifest_patterns`.
+    fn default_manifest_patterns() -> Vec<String> {
+        [
+            "Cargo.toml",
+            "package.json",
+            "go.mod",
+            "pyproject.toml",
+            "Pipfile",
+            "requirements*.txt",
+            "Gemfile",
+            "composer.json",
+            "*.csproj",
+            "Directory.Packages.props",
+            "build.gradle",
+            "build.gradle.kts",
+            "pom.xml",
+        ]
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
+    }
+
+    /// Default value for `require_only_dependency_files`.
+    fn default_require_only_dependency_files() -> bool {
+        true
+    }
+
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `label`, `title_types`, `title_scopes`, `lockfile_patterns`, `manifest_patterns`,
+    ///   `require_only_dependency_files`: `over` if it differs from the default; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.15 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            enabled: base.enabled || over.enabled,
+            label: if over.label != Self::default_label() {
+                over.label.clone()
+            } else {
+                base.label.clone()
+            },
+            title_types: if over.title_types != Self::default_title_types() {
+                over.title_types.clone()
+            } else {
+                base.title_types.clone()
+            },
+            title_scopes: if over.title_scopes != Self::default_title_scopes() {
+                over.title_scopes.clone()
+            } else {
+                base.title_scopes.clone()
+            },
+            lockfile_patterns: if over.lockfile_patterns != Self::default_lockfile_patterns() {
+                over.lockfile_patterns.clone()
+            } else {
+                base.lockfile_patterns.clone()
+            },
+            manifest_patterns: if over.manifest_patterns != Self::default_manifest_patterns() {
+                over.manifest_patterns.clone()
+            } else {
+                base.manifest_patterns.clone()
+            },
+            require_only_dependency_files: if over.require_only_dependency_files
+                != Self::default_require_only_dependency_files()
+            {
+                over.require_only_dependency_files
+            } else {
+                base.require_only_dependency_files
+            },
+        }
+    }
+}
+
+impl Default for DependencyUpdateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            label: Self::default_label(),
+            title_types: Self::default_title_types(),
+            title_scopes: Self::default_title_scopes(),
+            lockfile_patterns: Self::default_lockfile_patterns(),
+            manifest_patterns: Self::default_manifest_patterns(),
+            require_only_dependency_files: Self::default_require_only_dependency_files(),
+        }
+    }
+}
+
+/// A validation check whose position in the check order can be configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckKind {
+    /// The conventional commit title check.
+    Title,
+
+    /// The work item reference check.
+    WorkItem,
+
+    /// The required checklist check.
+    Checklist,
+
+    /// The unresolved review-thread check. Fetches the PR's review threads.
+    ReviewThreads,
+
+    /// The PR size check.
+    Size,
+
+    /// The version bump check. Fetches the manifest on the default branch and at the PR head.
+    VersionBump,
+
+    /// The deleted-file pairing check.
+    DeletedFiles,
+
+    /// The suspicious file check, which flags likely secrets and large or binary files.
+    SuspiciousFiles,
 
-    /// Label applied when the PR is open and awaiting review (not draft, not approved).
-    /// Set to `None` (or omit the key) to disable labeling for this state.
-    #[serde(default)]
-    pub review_label: Option<String>,
+    /// The base branch check, which matches the PR's target branch against its type.
+    BaseBranch,
 
-    /// Label applied when the PR has at least one approved review and is not a draft.
-    /// Set to `None` (or omit the key) to disable labeling for this state.
-    #[serde(default)]
-    pub approved_label: Option<String>,
+    /// The exclusive labels check, which allows at most one label per label group.
+    ExclusiveLabels,
+
+    /// The signed commits check. Fetches the verification state of the PR's commits.
+    SignedCommits,
+
+    /// The revert reference check, which requires revert PRs to reference what they revert.
+    RevertReference,
+
+    /// The up-to-date check, which labels PRs that are behind their base branch. Fetches
+    /// the comparison of the PR's head with its base.
+    UpToDate,
+
+    /// The footer check, which requires footers such as `Signed-off-by:` at the end of
+    /// the PR description.
+    Footers,
+
+    /// The descriptive title check, which flags PR titles that only repeat the branch
+    /// name.
+    DescriptiveTitle,
+
+    /// The change types check, which flags PRs whose title and commits mix more
+    /// conventional commit types than allowed.
+    ChangeTypes,
+
+    /// The label policy check, which flags PRs that lack a required label or carry a
+    /// forbidden one.
+    LabelPolicy,
 }
 
-impl PrStateLabelsConfig {
-    /// Default value for `enabled` — opt-in, disabled by default.
-    fn default_enabled() -> bool {
-        false
+impl CheckKind {
+    /// The order in which the checks run when no order is configured.
+    pub const DEFAULT_ORDER: [CheckKind; 17] = [
+        CheckKind::Title,
+        CheckKind::WorkItem,
+        CheckKind::Checklist,
+        CheckKind::ReviewThreads,
+        CheckKind::Size,
+        CheckKind::VersionBump,
+        CheckKind::DeletedFiles,
+        CheckKind::SuspiciousFiles,
+        CheckKind::BaseBranch,
+        CheckKind::ExclusiveLabels,
+        CheckKind::SignedCommits,
+        CheckKind::RevertReference,
+        CheckKind::UpToDate,
+        CheckKind::Footers,
+        CheckKind::DescriptiveTitle,
+        CheckKind::ChangeTypes,
+        CheckKind::LabelPolicy,
+    ];
+
+    /// Returns the name of the check's row in the check-run summary table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::CheckKind;
+    ///
+    /// assert_eq!(CheckKind::Title.summary_name(), "PR title");
+    /// assert_eq!(CheckKind::ReviewThreads.summary_name(), "Review threads");
+    /// ```
+    pub fn summary_name(&self) -> &'static str {
+        match self {
+            CheckKind::Title => "PR title",
+            CheckKind::WorkItem => "Work item",
+            CheckKind::Checklist => "Checklist",
+            CheckKind::ReviewThreads => "Review threads",
+            CheckKind::Size => "PR size",
+            CheckKind::VersionBump => "Version bump",
+            CheckKind::DeletedFiles => "Deleted files",
+            CheckKind::SuspiciousFiles => "Suspicious files",
+            CheckKind::BaseBranch => "Base branch",
+            CheckKind::ExclusiveLabels => "Exclusive labels",
+            CheckKind::SignedCommits => "Signed commits",
+            CheckKind::RevertReference => "Revert reference",
+            CheckKind::UpToDate => "Up to date",
+            CheckKind::Footers => "Footers",
+            CheckKind::DescriptiveTitle => "Descriptive title",
+            CheckKind::ChangeTypes => "Change types",
+            CheckKind::LabelPolicy => "Label policy",
+        }
     }
 
-    /// Merges `over` on top of `base` (lower-priority).
+    /// Returns the key of the check in the configuration, as used in
+    /// `checkExecution.order` and as the name of its feature flag.
     ///
-    /// Field-level rules:
-    /// - `enabled`: `base.enabled || over.enabled`
-    /// - `draft_label`: `over.draft_label` if `Some`; otherwise `base.draft_label`
-    /// - `review_label`: `over.review_label` if `Some`; otherwise `base.review_label`
-    /// - `approved_label`: `over.approved_label` if `Some`; otherwise `base.approved_label`
+    /// # Examples
     ///
-    /// See `docs/spec/interfaces/policy-engine.md` §2.5 for the full contract.
-    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
-        Self {
-            enabled: base.enabled || over.enabled,
-            draft_label: over
-                .draft_label
-                .clone()
-                .or_else(|| base.draft_label.clone()),
-            review_label: over
-                .review_label
-                .clone()
-                .or_else(|| base.review_label.clone()),
-            approved_label: over
-                .approved_label
-                .clone()
-                .or_else(|| base.approved_label.clone()),
+    /// ```
+    /// use merge_warden_core::config::CheckKind;
+    ///
+    /// assert_eq!(CheckKind::ReviewThreads.key(), "review_threads");
+    /// assert_eq!(CheckKind::from_key("review_threads"), Some(CheckKind::ReviewThreads));
+    /// assert_eq!(CheckKind::from_key("unknown"), None);
+    /// ```
+    pub fn key(&self) -> &'static str {
+        match self {
+            CheckKind::Title => "title",
+            CheckKind::WorkItem => "work_item",
+            CheckKind::Checklist => "checklist",
+            CheckKind::ReviewThreads => "review_threads",
+            CheckKind::Size => "size",
+            CheckKind::VersionBump => "version_bump",
+            CheckKind::DeletedFiles => "deleted_files",
+            CheckKind::SuspiciousFiles => "suspicious_files",
+            CheckKind::BaseBranch => "base_branch",
+            CheckKind::ExclusiveLabels => "exclusive_labels",
+            CheckKind::SignedCommits => "signed_commits",
+            CheckKind::RevertReference => "revert_reference",
+            CheckKind::UpToDate => "up_to_date",
+            CheckKind::Footers => "footers",
+            CheckKind::DescriptiveTitle => "descriptive_title",
+            CheckKind::ChangeTypes => "change_types",
+            CheckKind::LabelPolicy => "label_policy",
         }
     }
-}
 
-impl Default for PrStateLabelsConfig {
-    fn default() -> Self {
-        Self {
-            enabled: Self::default_enabled(),
-            draft_label: None,
-            review_label: None,
-            approved_label: None,
-        }
+    /// Returns the check with the given configuration key, or `None` if there is none.
+    pub fn from_key(key: &str) -> Option<CheckKind> {
+        Self::DEFAULT_ORDER
+            .into_iter()
+            .find(|kind| kind.key() == key)
     }
 }
 
-/// Configuration for Renovate stability-days label management.
-///
-/// When enabled, [`RENOVATE_STABILITY_LABEL`] (or the configured label name) is applied
-/// to the PR while the `renovate/stability-days` commit status is `pending`, `error`, or
-/// `failure`, and removed when the status is `success`.  The label is purely informational —
-/// it never influences the merge-blocking check conclusion.
+/// The diff from which the changed files of a PR are read.
 ///
 /// # Examples
 ///
 /// ```
-/// use merge_warden_core::config::{RenovateStabilityConfig, RENOVATE_STABILITY_LABEL};
+/// use merge_warden_core::config::{CheckExecutionConfig, DiffSource};
 ///
-/// let config = RenovateStabilityConfig::default();
-/// assert!(config.enabled);
-/// assert_eq!(config.pending_stability_label, RENOVATE_STABILITY_LABEL);
+/// let config: CheckExecutionConfig = toml::from_str(r#"diff_source = "merge_base""#).unwrap();
+/// assert_eq!(config.diff_source, DiffSource::MergeBase);
+/// assert_eq!(DiffSource::default(), DiffSource::PullRequest);
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct RenovateStabilityConfig {
-    /// Whether Renovate stability label management is enabled.
-    ///
-    /// Defaults to `true`.  The merge rule is `base || over`: once either tier enables
-    /// the feature the merged value is always `true`.  A repository can only opt in,
-    /// not opt out.
-    #[serde(default = "RenovateStabilityConfig::default_enabled")]
-    pub enabled: bool,
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffSource {
+    /// The files the platform lists for the PR, from GitHub's
+    /// `GET /repos/{owner}/{repo}/pulls/{number}/files`.
+    #[default]
+    PullRequest,
+
+    /// The three-dot diff between the merge base of the PR's base branch and head, and
+    /// the head, from GitHub's `GET /repos/{owner}/{repo}/compare/{base}...{head}`.
+    /// Only the changes the PR introduces are counted, however far the base branch has
+    /// moved on.
+    MergeBase,
+}
 
-    /// Label applied while the Renovate stability period has not yet elapsed.
-    ///
-    /// Defaults to [`RENOVATE_STABILITY_LABEL`].
-    #[serde(default = "RenovateStabilityConfig::default_label")]
-    pub pending_stability_label: String,
+/// Configuration for the order in which the checks run, for stopping early and for
+/// trialling checks without enforcing them.
+///
+/// Checks listed in `order` run first, in that order; the remaining checks follow in
+/// [`CheckKind::DEFAULT_ORDER`]. With `short_circuit_on_failure` set, the checks after
+/// the first blocking failure are not run, which saves the API calls made by the review
+/// thread and version bump checks. Checks that do not run are reported as skipped, and
+/// the labels and comments they manage are left unchanged.
+///
+/// Checks listed in `report_only` run as usual, but a failure is only recorded: it is
+/// logged, shown as a notice in the check output and turns the conclusion `neutral`
+/// rather than failing it. This lets a new rule gather data before it is enforced.
+///
+/// With `concurrent` set, the provider calls of the review thread, size, version bump,
+/// exclusive labels and signed commits checks are made at the same time instead of one
+/// after the other. The checks are still evaluated, and their labels and comments updated, in
+/// the configured order. Because short-circuiting exists to avoid those calls,
+/// `concurrent` has no effect when `short_circuit_on_failure` is set.
+///
+/// `diff_source` selects the diff the file-based checks read: the size, path skip,
+/// deleted file, suspicious file, version bump and dependency update checks.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{CheckExecutionConfig, CheckKind};
+///
+/// let config: CheckExecutionConfig = toml::from_str(
+///     r#"
+///     order = ["size", "title"]
+///     short_circuit_on_failure = true
+///     "#,
+/// )
+/// .unwrap();
+/// let order = config.effective_order();
+/// assert_eq!(order[..3], [CheckKind::Size, CheckKind::Title, CheckKind::WorkItem]);
+/// assert_eq!(order.len(), CheckKind::DEFAULT_ORDER.len());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CheckExecutionConfig {
+    /// Checks to run first, in this order. Defaults to empty, which keeps the default order.
+    #[serde(default)]
+    pub order: Vec<CheckKind>,
+
+    /// Whether to stop running checks after the first blocking failure. Defaults to `false`.
+    #[serde(default)]
+    pub short_circuit_on_failure: bool,
+
+    /// Checks whose failures are recorded but never block the PR. Defaults to empty.
+    #[serde(default)]
+    pub report_only: Vec<CheckKind>,
+
+    /// Whether to make the checks' provider calls concurrently. Defaults to `false`.
+    #[serde(default)]
+    pub concurrent: bool,
+
+    /// The diff from which the PR's changed files are read. Defaults to `pull_request`.
+    #[serde(default)]
+    pub diff_source: DiffSource,
 }
 
-impl RenovateStabilityConfig {
-    /// Default value for `enabled` — opt-in enabled by default.
-    fn default_enabled() -> bool {
-        true
+impl CheckExecutionConfig {
+    /// Returns every check in the order it runs: the configured checks first, without
+    /// duplicates, followed by the rest in [`CheckKind::DEFAULT_ORDER`].
+    pub fn effective_order(&self) -> Vec<CheckKind> {
+        let mut order: Vec<CheckKind> = Vec::with_capacity(CheckKind::DEFAULT_ORDER.len());
+        for kind in self.order.iter().chain(CheckKind::DEFAULT_ORDER.iter()) {
+            if !order.contains(kind) {
+                order.push(*kind);
+            }
+        }
+        order
     }
 
-    /// Default value for `pending_stability_label`.
-    fn default_label() -> String {
-        RENOVATE_STABILITY_LABEL.to_string()
+    /// Returns `true` if failures of `kind` are recorded without blocking the PR.
+    pub fn is_report_only(&self, kind: CheckKind) -> bool {
+        self.report_only.contains(&kind)
+    }
+
+    /// Returns `true` if the checks' provider calls are made concurrently.
+    ///
+    /// Concurrency is only used without `short_circuit_on_failure`, which needs each
+    /// check to finish before deciding whether the next one runs.
+    pub fn runs_concurrently(&self) -> bool {
+        self.concurrent && !self.short_circuit_on_failure
     }
 
     /// Merges `over` on top of `base` (lower-priority).
     ///
     /// Field-level rules:
-    /// - `enabled`: `base.enabled || over.enabled` — once enabled in either tier, stays enabled.
-    ///   This means a repo-level `enabled = false` cannot override an app-level `enabled = true`.
-    ///   To disable for all repos, set `enabled = false` at the application defaults level.
-    /// - `pending_stability_label`: `over` wins if non-empty and differs from the default label;
-    ///   otherwise `base` is used
+    /// - `order`: `over` if non-empty; otherwise `base`
+    /// - `short_circuit_on_failure`: `base || over`
+    /// - `report_only`: `over` if non-empty; otherwise `base`
+    /// - `concurrent`: `base || over`
+    /// - `diff_source`: `over` if it differs from the default (`pull_request`); otherwise
+    ///   `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.14 for the full contract.
     pub(crate) fn merge(base: &Self, over: &Self) -> Self {
-        let label = if !over.pending_stability_label.is_empty()
-            && over.pending_stability_label != RENOVATE_STABILITY_LABEL
-        {
-            over.pending_stability_label.clone()
-        } else {
-            base.pending_stability_label.clone()
-        };
         Self {
-            enabled: base.enabled || over.enabled,
-            pending_stability_label: label,
+            order: if !over.order.is_empty() {
+                over.order.clone()
+            } else {
+                base.order.clone()
+            },
+            short_circuit_on_failure: base.short_circuit_on_failure
+                || over.short_circuit_on_failure,
+            report_only: if !over.report_only.is_empty() {
+                over.report_only.clone()
+            } else {
+                base.report_only.clone()
+            },
+            concurrent: base.concurrent || over.concurrent,
+            diff_source: if over.diff_source != DiffSource::default() {
+                over.diff_source
+            } else {
+                base.diff_source
+            },
         }
     }
 }
 
-impl Default for RenovateStabilityConfig {
-    fn default() -> Self {
+impl ChecklistPolicyConfig {
+    /// Merges `over` on top of `base` (lower-priority).
+    ///
+    /// Field-level rules:
+    /// - `enabled`: `base.enabled || over.enabled`
+    /// - `required_items`: `over` if non-empty; otherwise `base`
+    ///
+    /// See `docs/spec/interfaces/policy-engine.md` §2.10 for the full contract.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
         Self {
-            enabled: Self::default_enabled(),
-            pending_stability_label: Self::default_label(),
+            enabled: base.enabled || over.enabled,
+            required_items: if !over.required_items.is_empty() {
+                over.required_items.clone()
+            } else {
+                base.required_items.clone()
+            },
         }
     }
 }
@@ -2112,6 +6762,60 @@ pub struct PolicySet {
     pub pr_state: PrStateLabelsConfig,
     /// Renovate stability-days label management policy.
     pub renovate_stability: RenovateStabilityConfig,
+    /// Check-run summary table presentation policy.
+    pub check_summary: CheckSummaryConfig,
+    /// Required checklist policy.
+    pub checklist: ChecklistPolicyConfig,
+    /// Unresolved review-thread policy.
+    pub review_threads: ReviewThreadPolicyConfig,
+    /// Deleted-file pairing policy.
+    pub deleted_files: DeletedFilePolicyConfig,
+    /// Version bump policy.
+    pub version_bump: VersionBumpPolicyConfig,
+    /// Check order and short-circuit policy.
+    pub check_execution: CheckExecutionConfig,
+    /// Dependency-update detection and labelling policy.
+    pub dependency_updates: DependencyUpdateConfig,
+    /// PR title emoji and character policy.
+    pub title_characters: TitleCharacterPolicyConfig,
+    /// Commit-lint style PR title policy.
+    pub title_lint: TitleLintPolicyConfig,
+    /// Suspicious file policy.
+    pub suspicious_files: SuspiciousFilePolicyConfig,
+    /// Reopened pull request policy.
+    pub reopened: ReopenedPolicyConfig,
+    /// Status label policy.
+    pub status_labels: StatusLabelPolicyConfig,
+    /// Guidance comment policy.
+    pub comments: CommentPolicyConfig,
+    /// Configuration for the base branch check.
+    pub base_branch: BaseBranchPolicyConfig,
+    /// Configuration for the exclusive labels check.
+    pub exclusive_labels: ExclusiveLabelsPolicyConfig,
+    /// Path skip settings.
+    pub path_skip: PathSkipPolicyConfig,
+    /// Effective signed commits check configuration
+    pub signed_commits: SignedCommitsPolicyConfig,
+    /// Effective revert reference check configuration
+    pub revert_reference: RevertReferencePolicyConfig,
+    /// Effective label creation policy
+    pub label_creation: LabelCreationPolicyConfig,
+    /// Effective issue reference verification
+    pub issue_references: IssueReferencePolicyConfig,
+    /// Effective up-to-date check configuration
+    pub up_to_date: UpToDatePolicyConfig,
+    /// Effective required footer configuration
+    pub footers: FooterPolicyConfig,
+    /// Effective draft conversion configuration
+    pub draft_conversion: DraftConversionPolicyConfig,
+    /// Effective automatic fix configuration
+    pub autofix: AutofixPolicyConfig,
+    /// Effective descriptive title configuration
+    pub descriptive_title: DescriptiveTitlePolicyConfig,
+    /// Effective change types configuration
+    pub change_types: ChangeTypesPolicyConfig,
+    /// Effective label policy configuration
+    pub label_policy: LabelPolicyConfig,
     /// Issue-to-PR field propagation policy.
     pub issue_propagation: IssuePropagationConfig,
     /// Conventional-commit type → label mapping policy.
@@ -2145,6 +6849,69 @@ impl PolicySet {
                 &self.renovate_stability,
                 &over.renovate_stability,
             ),
+            check_summary: CheckSummaryConfig::merge(&self.check_summary, &over.check_summary),
+            checklist: ChecklistPolicyConfig::merge(&self.checklist, &over.checklist),
+            review_threads: ReviewThreadPolicyConfig::merge(
+                &self.review_threads,
+                &over.review_threads,
+            ),
+            deleted_files: DeletedFilePolicyConfig::merge(&self.deleted_files, &over.deleted_files),
+            version_bump: VersionBumpPolicyConfig::merge(&self.version_bump, &over.version_bump),
+            check_execution: CheckExecutionConfig::merge(
+                &self.check_execution,
+                &over.check_execution,
+            ),
+            dependency_updates: DependencyUpdateConfig::merge(
+                &self.dependency_updates,
+                &over.dependency_updates,
+            ),
+            title_characters: TitleCharacterPolicyConfig::merge(
+                &self.title_characters,
+                &over.title_characters,
+            ),
+            title_lint: TitleLintPolicyConfig::merge(&self.title_lint, &over.title_lint),
+            suspicious_files: SuspiciousFilePolicyConfig::merge(
+                &self.suspicious_files,
+                &over.suspicious_files,
+            ),
+            reopened: ReopenedPolicyConfig::merge(&self.reopened, &over.reopened),
+            status_labels: StatusLabelPolicyConfig::merge(&self.status_labels, &over.status_labels),
+            comments: CommentPolicyConfig::merge(&self.comments, &over.comments),
+            base_branch: BaseBranchPolicyConfig::merge(&self.base_branch, &over.base_branch),
+            exclusive_labels: ExclusiveLabelsPolicyConfig::merge(
+                &self.exclusive_labels,
+                &over.exclusive_labels,
+            ),
+            path_skip: PathSkipPolicyConfig::merge(&self.path_skip, &over.path_skip),
+            signed_commits: SignedCommitsPolicyConfig::merge(
+                &self.signed_commits,
+                &over.signed_commits,
+            ),
+            revert_reference: RevertReferencePolicyConfig::merge(
+                &self.revert_reference,
+                &over.revert_reference,
+            ),
+            label_creation: LabelCreationPolicyConfig::merge(
+                &self.label_creation,
+                &over.label_creation,
+            ),
+            issue_references: IssueReferencePolicyConfig::merge(
+                &self.issue_references,
+                &over.issue_references,
+            ),
+            up_to_date: UpToDatePolicyConfig::merge(&self.up_to_date, &over.up_to_date),
+            footers: FooterPolicyConfig::merge(&self.footers, &over.footers),
+            draft_conversion: DraftConversionPolicyConfig::merge(
+                &self.draft_conversion,
+                &over.draft_conversion,
+            ),
+            autofix: AutofixPolicyConfig::merge(&self.autofix, &over.autofix),
+            descriptive_title: DescriptiveTitlePolicyConfig::merge(
+                &self.descriptive_title,
+                &over.descriptive_title,
+            ),
+            change_types: ChangeTypesPolicyConfig::merge(&self.change_types, &over.change_types),
+            label_policy: LabelPolicyConfig::merge(&self.label_policy, &over.label_policy),
             issue_propagation: IssuePropagationConfig::merge(
                 &self.issue_propagation,
                 &over.issue_propagation,
@@ -2192,6 +6959,33 @@ impl PolicySet {
             wip: pr.wip_policies.clone(),
             pr_state: pr.pr_state_policies.clone(),
             renovate_stability: pr.renovate_stability.clone(),
+            check_summary: pr.check_summary.clone(),
+            checklist: pr.checklist.clone(),
+            review_threads: pr.review_threads.clone(),
+            deleted_files: pr.deleted_files.clone(),
+            version_bump: pr.version_bump.clone(),
+            check_execution: pr.check_execution.clone(),
+            dependency_updates: pr.dependency_updates.clone(),
+            title_characters: pr.title_characters.clone(),
+            title_lint: pr.title_lint.clone(),
+            suspicious_files: pr.suspicious_files.clone(),
+            reopened: pr.reopened.clone(),
+            status_labels: pr.status_labels.clone(),
+            comments: pr.comments.clone(),
+            base_branch: pr.base_branch.clone(),
+            exclusive_labels: pr.exclusive_labels.clone(),
+            path_skip: pr.path_skip.clone(),
+            signed_commits: pr.signed_commits.clone(),
+            revert_reference: pr.revert_reference.clone(),
+            label_creation: pr.label_creation.clone(),
+            issue_references: pr.issue_references.clone(),
+            up_to_date: pr.up_to_date.clone(),
+            footers: pr.footers.clone(),
+            draft_conversion: pr.draft_conversion.clone(),
+            autofix: pr.autofix.clone(),
+            descriptive_title: pr.descriptive_title.clone(),
+            change_types: pr.change_types.clone(),
+            label_policy: pr.label_policy.clone(),
             issue_propagation: pr.issue_propagation.clone(),
             change_type_labels: section.change_type_labels.clone().unwrap_or_default(),
             bypass_rules: section
@@ -2255,14 +7049,46 @@ impl PolicySet {
             enforce_work_item_references: self.work_item.required,
             work_item_reference_pattern: self.work_item.pattern.clone(),
             missing_work_item_label: self.work_item.label_if_missing.clone(),
+            work_item_pr_template_guidance: self.work_item.pr_template_guidance,
+            min_work_item_references: self.work_item.min_references,
             pr_size_check: self.size.clone(),
             change_type_labels: Some(self.change_type_labels.clone()),
             wip_check: self.wip.clone(),
             pr_state_labels: self.pr_state.clone(),
             renovate_stability: self.renovate_stability.clone(),
+            check_summary: self.check_summary.clone(),
+            checklist: self.checklist.clone(),
+            review_threads: self.review_threads.clone(),
+            deleted_files: self.deleted_files.clone(),
+            version_bump: self.version_bump.clone(),
+            check_execution: self.check_execution.clone(),
+            dependency_updates: self.dependency_updates.clone(),
+            title_characters: self.title_characters.clone(),
+            title_lint: self.title_lint.clone(),
+            suspicious_files: self.suspicious_files.clone(),
+            reopened: self.reopened.clone(),
+            status_labels: self.status_labels.clone(),
+            comments: self.comments.clone(),
+            base_branch: self.base_branch.clone(),
+            exclusive_labels: self.exclusive_labels.clone(),
+            path_skip: self.path_skip.clone(),
+            signed_commits: self.signed_commits.clone(),
+            revert_reference: self.revert_reference.clone(),
+            label_creation: self.label_creation.clone(),
+            issue_references: self.issue_references.clone(),
+            up_to_date: self.up_to_date.clone(),
+            footers: self.footers.clone(),
+            draft_conversion: self.draft_conversion.clone(),
+            autofix: self.autofix.clone(),
+            descriptive_title: self.descriptive_title.clone(),
+            change_types: self.change_types.clone(),
+            label_policy: self.label_policy.clone(),
             bypass_rules: self.bypass_rules.clone(),
             issue_propagation: self.issue_propagation.clone(),
             bot_mention: app_defaults.bot_mention.clone(),
+            show_config_source: app_defaults.show_config_source,
+            config_source: ConfigSource::default(),
+            feature_flags: BTreeMap::new(),
         }
     }
 
@@ -2292,11 +7118,40 @@ impl PolicySet {
                 required: false,
                 pattern: app.default_work_item_pattern.clone(),
                 label_if_missing: app.default_missing_work_item_label.clone(),
+                pr_template_guidance: false,
+                min_references: WorkItemPolicyConfig::default_min_references(),
             },
             size: app.pr_size_check.clone(),
             wip: app.wip_check.clone(),
             pr_state: app.pr_state_labels.clone(),
             renovate_stability: app.renovate_stability.clone(),
+            check_summary: app.check_summary.clone(),
+            checklist: app.checklist.clone(),
+            review_threads: app.review_threads.clone(),
+            deleted_files: app.deleted_files.clone(),
+            version_bump: app.version_bump.clone(),
+            check_execution: app.check_execution.clone(),
+            dependency_updates: app.dependency_updates.clone(),
+            title_characters: app.title_characters.clone(),
+            title_lint: app.title_lint.clone(),
+            suspicious_files: app.suspicious_files.clone(),
+            reopened: app.reopened.clone(),
+            status_labels: app.status_labels.clone(),
+            comments: app.comments.clone(),
+            base_branch: app.base_branch.clone(),
+            exclusive_labels: app.exclusive_labels.clone(),
+            path_skip: app.path_skip.clone(),
+            signed_commits: app.signed_commits.clone(),
+            revert_reference: app.revert_reference.clone(),
+            label_creation: app.label_creation.clone(),
+            issue_references: app.issue_references.clone(),
+            up_to_date: app.up_to_date.clone(),
+            footers: app.footers.clone(),
+            draft_conversion: app.draft_conversion.clone(),
+            autofix: app.autofix.clone(),
+            descriptive_title: app.descriptive_title.clone(),
+            change_types: app.change_types.clone(),
+            label_policy: app.label_policy.clone(),
             // `ApplicationDefaults` carries no issue-propagation settings — issue propagation
             // is a repository-level opt-in feature, so the app tier always contributes
             // `IssuePropagationConfig::default()` (both flags `false`).
@@ -2334,6 +7189,33 @@ impl PolicySet {
             wip: pr.wip_policies.clone(),
             pr_state: pr.pr_state_policies.clone(),
             renovate_stability: pr.renovate_stability.clone(),
+            check_summary: pr.check_summary.clone(),
+            checklist: pr.checklist.clone(),
+            review_threads: pr.review_threads.clone(),
+            deleted_files: pr.deleted_files.clone(),
+            version_bump: pr.version_bump.clone(),
+            check_execution: pr.check_execution.clone(),
+            dependency_updates: pr.dependency_updates.clone(),
+            title_characters: pr.title_characters.clone(),
+            title_lint: pr.title_lint.clone(),
+            suspicious_files: pr.suspicious_files.clone(),
+            reopened: pr.reopened.clone(),
+            status_labels: pr.status_labels.clone(),
+            comments: pr.comments.clone(),
+            base_branch: pr.base_branch.clone(),
+            exclusive_labels: pr.exclusive_labels.clone(),
+            path_skip: pr.path_skip.clone(),
+            signed_commits: pr.signed_commits.clone(),
+            revert_reference: pr.revert_reference.clone(),
+            label_creation: pr.label_creation.clone(),
+            issue_references: pr.issue_references.clone(),
+            up_to_date: pr.up_to_date.clone(),
+            footers: pr.footers.clone(),
+            draft_conversion: pr.draft_conversion.clone(),
+            autofix: pr.autofix.clone(),
+            descriptive_title: pr.descriptive_title.clone(),
+            change_types: pr.change_types.clone(),
+            label_policy: pr.label_policy.clone(),
             issue_propagation: pr.issue_propagation.clone(),
             change_type_labels: repo.change_type_labels.clone().unwrap_or_default(),
             bypass_rules,
@@ -2341,22 +7223,38 @@ impl PolicySet {
     }
 }
 
-/// Simple pattern matching for file exclusions
-/// Supports basic glob patterns with * wildcards
-pub(crate) fn pattern_matches(pattern: &str, file_path: &str) -> bool {
-    // Convert glob pattern to regex-like matching
-    if pattern.contains('*') {
-        // Escape all regex metacharacters in the literal parts of the pattern
-        // first, then replace the escaped `\*` with `.*` so that only `*`
-        // acts as a wildcard and characters like `.` are treated literally.
-        let regex_pattern = regex::escape(pattern).replace(r"\*", ".*");
-        if let Ok(regex) = regex::Regex::new(&format!("^{}$", regex_pattern)) {
-            return regex.is_match(file_path);
+/// Matches a name, such as a label, a branch or a login, against a name pattern.
+///
+/// Name patterns are used by the label policy, exclusive label groups, allowed base
+/// branches and size exclusions by author. The pattern must match the whole name: `*`
+/// matches any run of characters, including `/`, and every other character matches
+/// itself. Matching is case-sensitive; callers that ignore case lowercase both sides.
+///
+/// File paths use gitignore semantics instead; see [`FileExclusions`].
+pub(crate) fn pattern_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Greedy match that backtracks to the last `*` seen on a mismatch.
+    let (mut p, mut n) = (0, 0);
+    let mut last_star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            last_star = Some((p, n));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == name[n] {
+            p += 1;
+            n += 1;
+        } else if let Some((star_p, star_n)) = last_star {
+            p = star_p + 1;
+            n = star_n + 1;
+            last_star = Some((star_p, n));
+        } else {
+            return false;
         }
     }
 
-    // Exact match fallback
-    pattern == file_path
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 // ---------------------------------------------------------------------------
@@ -2592,6 +7490,33 @@ pub async fn load_merge_warden_config(
         config.policies.pull_requests.wip_policies = merged_ps.wip;
         config.policies.pull_requests.pr_state_policies = merged_ps.pr_state;
         config.policies.pull_requests.issue_propagation = merged_ps.issue_propagation;
+        config.policies.pull_requests.check_summary = merged_ps.check_summary;
+        config.policies.pull_requests.checklist = merged_ps.checklist;
+        config.policies.pull_requests.review_threads = merged_ps.review_threads;
+        config.policies.pull_requests.deleted_files = merged_ps.deleted_files;
+        config.policies.pull_requests.version_bump = merged_ps.version_bump;
+        config.policies.pull_requests.check_execution = merged_ps.check_execution;
+        config.policies.pull_requests.dependency_updates = merged_ps.dependency_updates;
+        config.policies.pull_requests.title_characters = merged_ps.title_characters;
+        config.policies.pull_requests.title_lint = merged_ps.title_lint;
+        config.policies.pull_requests.suspicious_files = merged_ps.suspicious_files;
+        config.policies.pull_requests.reopened = merged_ps.reopened;
+        config.policies.pull_requests.status_labels = merged_ps.status_labels;
+        config.policies.pull_requests.comments = merged_ps.comments;
+        config.policies.pull_requests.base_branch = merged_ps.base_branch;
+        config.policies.pull_requests.exclusive_labels = merged_ps.exclusive_labels;
+        config.policies.pull_requests.path_skip = merged_ps.path_skip;
+        config.policies.pull_requests.signed_commits = merged_ps.signed_commits;
+        config.policies.pull_requests.revert_reference = merged_ps.revert_reference;
+        config.policies.pull_requests.label_creation = merged_ps.label_creation;
+        config.policies.pull_requests.issue_references = merged_ps.issue_references;
+        config.policies.pull_requests.up_to_date = merged_ps.up_to_date;
+        config.policies.pull_requests.footers = merged_ps.footers;
+        config.policies.pull_requests.draft_conversion = merged_ps.draft_conversion;
+        config.policies.pull_requests.autofix = merged_ps.autofix;
+        config.policies.pull_requests.descriptive_title = merged_ps.descriptive_title;
+        config.policies.pull_requests.change_types = merged_ps.change_types;
+        config.policies.pull_requests.label_policy = merged_ps.label_policy;
         config.change_type_labels = Some(merged_ps.change_type_labels);
         // Write bypass_rules back so to_validation_config uses the merged result
         // rather than re-merging from the raw BypassRulesConfig sub-rules.
@@ -2612,12 +7537,7 @@ pub async fn load_merge_warden_config(
             .clone()
             .unwrap_or_default(),
         enable_work_item_validation = config.policies.pull_requests.work_item_policies.required,
-        work_item_validation_pattern = config
-            .policies
-            .pull_requests
-            .work_item_policies
-            .pattern
-            .clone(),
+        work_item_validation_pattern = %config.policies.pull_requests.work_item_policies.pattern,
         label_if_work_item_validation_fails = config
             .policies
             .pull_requests
@@ -2776,9 +7696,8 @@ pub(crate) async fn load_org_policy(
 ///
 /// # Returns
 ///
-/// - `Ok(RepositoryProvidedConfig)` — raw parsed config, or
-///   [`RepositoryProvidedConfig::default`] if the file is absent or has an
-///   unsupported schema version.
+/// - `Ok(Some(RepositoryProvidedConfig))` — raw parsed config.
+/// - `Ok(None)` — the file is absent or has an unsupported schema version.
 /// - `Err(ConfigLoadError::NotFound)` — the config fetcher returned an error.
 /// - `Err(ConfigLoadError::...)` — TOML parse error.
 async fn parse_repo_config(
@@ -2786,7 +7705,7 @@ async fn parse_repo_config(
     repo_name: &str,
     path: &str,
     fetcher: &dyn ConfigFetcher,
-) -> Result<RepositoryProvidedConfig, ConfigLoadError> {
+) -> Result<Option<RepositoryProvidedConfig>, ConfigLoadError> {
     let content = match fetcher.fetch_config(repo_owner, repo_name, path).await {
         Ok(Some(c)) => c,
         Ok(None) => {
@@ -2796,7 +7715,7 @@ async fn parse_repo_config(
                 path = path,
                 "No repo config file found; using empty defaults for repo tier"
             );
-            return Ok(RepositoryProvidedConfig::default());
+            return Ok(None);
         }
         Err(e) => {
             warn!(
@@ -2818,10 +7737,10 @@ async fn parse_repo_config(
             config_version = config.schema_version,
             "Configuration in repository has an unexpected version. Will not be able to load configuration."
         );
-        return Ok(RepositoryProvidedConfig::default());
+        return Ok(None);
     }
 
-    Ok(config)
+    Ok(Some(config))
 }
 
 /// Orchestrates the four-tier PR configuration resolution chain.
@@ -2837,6 +7756,9 @@ async fn parse_repo_config(
 /// 4. Org enforced (from [`OrgPolicy::enforced`], if `org_policy_source` is set)
 /// 5. App-level enforcement flags ([`PolicySet::from_app_enforcement_flags`])
 ///
+/// The feature flags for `repo_owner` ([`FeatureFlagsConfig::flags_for`]) are applied
+/// to the result and override every tier.
+///
 /// # Arguments
 ///
 /// * `repo_owner` — GitHub repository owner.
@@ -2857,6 +7779,7 @@ async fn parse_repo_config(
 /// * `Ok(CurrentPullRequestValidationConfiguration)` — always returned unless
 ///   `app_defaults.org_policy_source.fail_if_unreachable = true` and the org
 ///   policy cannot be loaded.
+///   Its `config_source` records which repo and org files contributed.
 /// * `Err(ConfigLoadError::OrgPolicyUnavailable)` — only when strict mode is
 ///   enabled and the org policy is unreachable or unparseable.
 ///
@@ -2885,8 +7808,9 @@ pub async fn resolve_pull_request_config(
         }
     );
 
-    let repo_config = match repo_config_res {
-        Ok(c) => c,
+    let (repo_config, repository_file) = match repo_config_res {
+        Ok(Some(c)) => (c, Some(config_path.to_string())),
+        Ok(None) => (RepositoryProvidedConfig::default(), None),
         Err(e) => {
             warn!(
                 repository_owner = repo_owner,
@@ -2895,7 +7819,7 @@ pub async fn resolve_pull_request_config(
                 "Failed to load repo config in resolve_pull_request_config; using empty defaults for repo tier"
             );
             // Fall back to an empty repo config; the four-tier chain supplies app and org values.
-            RepositoryProvidedConfig::default()
+            (RepositoryProvidedConfig::default(), None)
         }
     };
 
@@ -3023,6 +7947,16 @@ pub async fn resolve_pull_request_config(
             org_defaults_ps.bypass_rules.size(),
             effective_ps.bypass_rules.size(),
         );
+        check_opt_out(
+            "signed_commits",
+            org_defaults_ps.bypass_rules.signed_commits(),
+            effective_ps.bypass_rules.signed_commits(),
+        );
+        check_opt_out(
+            "revert_reference",
+            org_defaults_ps.bypass_rules.revert_reference(),
+            effective_ps.bypass_rules.revert_reference(),
+        );
     }
 
     for ce in &conditional_enforced_policies {
@@ -3030,7 +7964,18 @@ pub async fn resolve_pull_request_config(
     }
     effective_ps = effective_ps.merge(&org_enforced_ps).merge(&app_enforced_ps);
 
-    Ok(effective_ps.to_validation_config(app_defaults))
+    let mut validation_config = effective_ps.to_validation_config(app_defaults);
+    validation_config.config_source = ConfigSource {
+        repository_file,
+        org_policy_file: org_policy
+            .as_ref()
+            .and(app_defaults.org_policy_source.as_ref())
+            .map(|source| format!("{}/{}:{}", source.owner, source.repo, source.path)),
+        matched_conditional_policies: conditional_defaults_policies.len(),
+    };
+    validation_config.apply_feature_flags(app_defaults.feature_flags.flags_for(repo_owner));
+
+    Ok(validation_config)
 }
 
 /// Configuration for change type label detection and management
@@ -3052,6 +7997,18 @@ pub struct ChangeTypeLabelConfig {
     /// When absent all keyword labels use their built-in defaults.
     #[serde(default)]
     pub keyword_labels: KeywordLabelsConfig,
+    /// Per-category toggles for the automatic labels. When absent every category is enabled.
+    #[serde(default)]
+    pub categories: AutoLabelCategories,
+    /// Maximum number of automatic labels applied to a PR. When more are triggered, the
+    /// lowest-priority ones are dropped: keyword labels first, then the breaking-change
+    /// label, and the change-type label last. When absent the number is not limited.
+    #[serde(default)]
+    pub max_auto_labels: Option<usize>,
+    /// Whether the check run says so when no label matched the PR. Failures to
+    /// detect labels are always reported. When absent the notice is shown.
+    #[serde(default)]
+    pub report_no_labels: Option<bool>,
 }
 
 impl ChangeTypeLabelConfig {
@@ -3060,6 +8017,22 @@ impl ChangeTypeLabelConfig {
         true
     }
 
+    /// Returns whether the check run notes that no label matched the PR.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::ChangeTypeLabelConfig;
+    ///
+    /// assert!(ChangeTypeLabelConfig::default().reports_no_labels());
+    ///
+    /// let quiet: ChangeTypeLabelConfig = toml::from_str("report_no_labels = false").unwrap();
+    /// assert!(!quiet.reports_no_labels());
+    /// ```
+    pub fn reports_no_labels(&self) -> bool {
+        self.report_no_labels.unwrap_or(true)
+    }
+
     /// Merges `over` on top of `base` (lower-priority).
     ///
     /// Field-level rules:
@@ -3074,6 +8047,9 @@ impl ChangeTypeLabelConfig {
     /// - `fallback_label_settings.color_scheme`: per-key, `over` key wins if present
     /// - `fallback_label_settings.create_if_missing`: `over` wins unconditionally
     /// - `keyword_labels.*`: `over.field` if `Some`; otherwise `base.field`
+    /// - `categories.*`: `over.field` if `Some`; otherwise `base.field`
+    /// - `max_auto_labels`: `over` if `Some`; otherwise `base`
+    /// - `report_no_labels`: `over` if `Some`; otherwise `base`
     ///
     /// See `docs/spec/interfaces/policy-engine.md` §2.7 for the full contract.
     pub(crate) fn merge(base: &Self, over: &Self) -> Self {
@@ -3178,6 +8154,24 @@ impl ChangeTypeLabelConfig {
             security: ok.security.clone().or_else(|| bk.security.clone()),
             hotfix: ok.hotfix.clone().or_else(|| bk.hotfix.clone()),
             tech_debt: ok.tech_debt.clone().or_else(|| bk.tech_debt.clone()),
+            rules: if ok.rules.is_empty() {
+                bk.rules.clone()
+            } else {
+                ok.rules.clone()
+            },
+            disabled: if ok.disabled.is_empty() {
+                bk.disabled.clone()
+            } else {
+                ok.disabled.clone()
+            },
+        };
+
+        let bc = &base.categories;
+        let oc = &over.categories;
+        let categories = AutoLabelCategories {
+            change_type: oc.change_type.or(bc.change_type),
+            breaking_change: oc.breaking_change.or(bc.breaking_change),
+            keywords: oc.keywords.or(bc.keywords),
         };
 
         Self {
@@ -3186,6 +8180,9 @@ impl ChangeTypeLabelConfig {
             detection_strategy,
             fallback_label_settings,
             keyword_labels,
+            categories,
+            max_auto_labels: over.max_auto_labels.or(base.max_auto_labels),
+            report_no_labels: over.report_no_labels.or(base.report_no_labels),
         }
     }
 }
@@ -3354,6 +8351,30 @@ impl FallbackLabelSettings {
 /// | `security` | `"security"` |
 /// | `hotfix` | `"hotfix"` |
 /// | `tech_debt` | `"tech-debt"` |
+///
+/// Additional keyword→label mappings can be declared in `rules`. A rule whose label matches the
+/// effective security, hotfix or tech-debt label replaces that built-in's keywords, and any
+/// built-in listed in `disabled` is never applied.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::{BuiltinKeywordLabel, KeywordLabelsConfig};
+///
+/// let cfg: KeywordLabelsConfig = toml::from_str(
+///     r#"
+///     disabled = ["tech_debt"]
+///
+///     [[rules]]
+///     label = "database-migration"
+///     keywords = ["migration", "schema change"]
+///     "#,
+/// )
+/// .unwrap();
+/// assert_eq!(cfg.rules[0].label, "database-migration");
+/// assert!(!cfg.is_enabled(BuiltinKeywordLabel::TechDebt));
+/// assert!(cfg.is_enabled(BuiltinKeywordLabel::Security));
+/// ```
 #[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct KeywordLabelsConfig {
     /// Label applied when the PR title contains `!:` or the phrase "breaking change".
@@ -3375,9 +8396,27 @@ pub struct KeywordLabelsConfig {
     /// Defaults to `"tech-debt"` when absent or empty.
     #[serde(default)]
     pub tech_debt: Option<String>,
+
+    /// Custom keyword→label mappings, evaluated against the PR title and body.
+    ///
+    /// When a configuration layer sets any rules they replace the rules of the layers below it.
+    #[serde(default)]
+    pub rules: Vec<KeywordLabelRule>,
+
+    /// Built-in keyword labels that are never applied.
+    ///
+    /// When a configuration layer lists any entries they replace the list of the layers below it.
+    #[serde(default)]
+    pub disabled: Vec<BuiltinKeywordLabel>,
 }
 
 impl KeywordLabelsConfig {
+    /// Returns `true` unless `builtin` is listed in [`Self::disabled`].
+    #[must_use]
+    pub fn is_enabled(&self, builtin: BuiltinKeywordLabel) -> bool {
+        !self.disabled.contains(&builtin)
+    }
+
     /// Returns the effective label for breaking-change detection, falling back to the default.
     #[must_use]
     pub fn breaking_change_label(&self) -> &str {
@@ -3408,6 +8447,95 @@ impl KeywordLabelsConfig {
     }
 }
 
+/// A user-defined keyword→label mapping.
+///
+/// The label is applied when any of the keywords appears in the PR title or body. Matching is
+/// case-insensitive, respects word boundaries, treats spaces and hyphens inside a keyword as
+/// interchangeable and ignores negated mentions such as "no migration".
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct KeywordLabelRule {
+    /// The repository label to apply.
+    pub label: String,
+
+    /// The keywords that trigger the label. Empty entries are ignored.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+/// The keyword labels that Merge Warden detects out of the box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuiltinKeywordLabel {
+    /// The breaking-change label, triggered by `!:` or "breaking change" in the title or body.
+    BreakingChange,
+
+    /// The security label, triggered by "security" or "vulnerability" in the body.
+    Security,
+
+    /// The hotfix label, triggered by "hotfix" in the body.
+    Hotfix,
+
+    /// The tech-debt label, triggered by "technical debt" or "tech debt" in the body.
+    TechDebt,
+}
+
+/// Per-category toggles for the labels applied automatically from the PR title and body.
+///
+/// Each field is optional so that a configuration layer can leave a category to the layer
+/// below it; a category that no layer sets is enabled, which preserves the behaviour of
+/// configurations written before the toggles existed.
+///
+/// | Field | Labels controlled |
+/// |---|---|
+/// | `change_type` | The label for the conventional commit type in the PR title (smart or built-in) |
+/// | `breaking_change` | The breaking-change keyword label |
+/// | `keywords` | The security, hotfix, tech-debt and custom keyword labels |
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::AutoLabelCategories;
+///
+/// let categories: AutoLabelCategories = toml::from_str("keywords = false").unwrap();
+/// assert!(categories.change_type_enabled());
+/// assert!(categories.breaking_change_enabled());
+/// assert!(!categories.keywords_enabled());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct AutoLabelCategories {
+    /// Whether to apply the change-type label. Enabled when absent.
+    #[serde(default)]
+    pub change_type: Option<bool>,
+
+    /// Whether to apply the breaking-change label. Enabled when absent.
+    #[serde(default)]
+    pub breaking_change: Option<bool>,
+
+    /// Whether to apply the security, hotfix and tech-debt labels. Enabled when absent.
+    #[serde(default)]
+    pub keywords: Option<bool>,
+}
+
+impl AutoLabelCategories {
+    /// Returns `true` if the change-type label should be applied.
+    #[must_use]
+    pub fn change_type_enabled(&self) -> bool {
+        self.change_type.unwrap_or(true)
+    }
+
+    /// Returns `true` if the breaking-change label should be applied.
+    #[must_use]
+    pub fn breaking_change_enabled(&self) -> bool {
+        self.breaking_change.unwrap_or(true)
+    }
+
+    /// Returns `true` if the security, hotfix and tech-debt labels should be applied.
+    #[must_use]
+    pub fn keywords_enabled(&self) -> bool {
+        self.keywords.unwrap_or(true)
+    }
+}
+
 /// Configuration for the label detection strategy
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct LabelDetectionStrategy {
@@ -3448,6 +8576,9 @@ impl Default for ChangeTypeLabelConfig {
             detection_strategy: LabelDetectionStrategy::default(),
             fallback_label_settings: FallbackLabelSettings::default(),
             keyword_labels: KeywordLabelsConfig::default(),
+            categories: AutoLabelCategories::default(),
+            max_auto_labels: None,
+            report_no_labels: None,
         }
     }
 }
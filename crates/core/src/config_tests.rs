@@ -1,10 +1,13 @@
-use crate::config::{validate_config_content, ConfigValidationOutcome};
+use crate::config::{lint_config_content, validate_config_content, ConfigValidationOutcome};
 use crate::config::{
-    BypassRule, BypassRules, BypassRulesConfig, ChangeTypeLabelConfig,
-    CurrentPullRequestValidationConfiguration, IssuePropagationConfig, KeywordLabelsConfig,
-    OrgPolicySource, PrSizeCheckConfig, WipCheckConfig, CONVENTIONAL_COMMIT_REGEX, WORK_ITEM_REGEX,
+    BypassRule, BypassRules, BypassRulesConfig, ChangeTypeLabelConfig, CheckExecutionConfig,
+    CheckKind, CurrentPullRequestValidationConfiguration, IssuePropagationConfig,
+    KeywordLabelsConfig, OrgPolicySource, PrSizeCheckConfig, WipCheckConfig,
+    CONVENTIONAL_COMMIT_REGEX, WORK_ITEM_REGEX,
+};
+use crate::size::{
+    FileExclusionPreset, PrSizeCategory, SizeCategoryNames, SizeCountingMode, SizeThresholds,
 };
-use crate::size::SizeThresholds;
 use async_trait::async_trait;
 use merge_warden_developer_platforms::errors::Error;
 use proptest::prelude::*;
@@ -55,8 +58,8 @@ fn test_application_defaults_struct_and_methods() {
     assert_eq!(defaults.default_invalid_title_label, None);
     assert!(!defaults.enable_work_item_validation);
     assert_eq!(
-        defaults.default_work_item_pattern,
-        WORK_ITEM_REGEX.to_string()
+        defaults.default_work_item_pattern.patterns(),
+        [WORK_ITEM_REGEX]
     );
     assert_eq!(defaults.default_missing_work_item_label, None);
 }
@@ -166,7 +169,10 @@ fn test_current_pr_validation_config_new() {
         Some("custom-invalid-label".to_string())
     );
     assert!(!config.enforce_work_item_references);
-    assert_eq!(config.work_item_reference_pattern, "custom-work-item");
+    assert_eq!(
+        config.work_item_reference_pattern.patterns(),
+        ["custom-work-item"]
+    );
     assert_eq!(
         config.missing_work_item_label,
         Some("custom-missing-label".to_string())
@@ -186,8 +192,10 @@ fn test_custom_regex_patterns_are_used() {
                 },
                 work_item_policies: WorkItemPolicyConfig {
                     required: true,
-                    pattern: String::from(r"CUSTOM-\d+"),
+                    pattern: r"CUSTOM-\d+".into(),
                     label_if_missing: Some("custom-missing".to_string()),
+                    pr_template_guidance: false,
+                    min_references: 1,
                 },
                 size_policies: PrSizeCheckConfig::default(),
                 ..Default::default()
@@ -203,7 +211,7 @@ fn test_custom_regex_patterns_are_used() {
     assert!(custom_title_regex.is_match(custom_title));
     let custom_work_item = "CUSTOM-123";
     let custom_work_item_regex =
-        regex::Regex::new(&validation.work_item_reference_pattern).unwrap();
+        regex::Regex::new(&validation.work_item_reference_pattern.patterns()[0]).unwrap();
     assert!(custom_work_item_regex.is_match(custom_work_item));
 }
 
@@ -418,8 +426,13 @@ label_if_missing = "missing-work-item"
     );
     assert!(config.policies.pull_requests.work_item_policies.required);
     assert_eq!(
-        config.policies.pull_requests.work_item_policies.pattern,
-        "#\\d+".to_string()
+        config
+            .policies
+            .pull_requests
+            .work_item_policies
+            .pattern
+            .patterns(),
+        ["#\\d+"]
     );
     assert_eq!(
         config
@@ -444,8 +457,10 @@ fn test_merge_warden_config_to_validation_config_conventional_commits_and_work_i
                 },
                 work_item_policies: WorkItemPolicyConfig {
                     required: true,
-                    pattern: WORK_ITEM_REGEX.to_string(),
+                    pattern: WORK_ITEM_REGEX.into(),
                     label_if_missing: Some(MISSING_WORK_ITEM_LABEL.to_string()),
+                    pr_template_guidance: false,
+                    min_references: 1,
                 },
                 size_policies: PrSizeCheckConfig::default(),
                 ..Default::default()
@@ -481,8 +496,10 @@ fn test_merge_warden_config_to_validation_config_non_conventional_commits() {
                 },
                 work_item_policies: WorkItemPolicyConfig {
                     required: false,
-                    pattern: "".to_string(),
+                    pattern: "".into(),
                     label_if_missing: None,
+                    pr_template_guidance: false,
+                    min_references: 1,
                 },
                 size_policies: PrSizeCheckConfig::default(),
                 ..Default::default()
@@ -623,6 +640,7 @@ fn test_bypass_rule_serialization() {
     let rule = BypassRule {
         enabled: true,
         users: vec!["user1".to_string(), "user2".to_string()],
+        label: None,
     };
 
     let serialized = serde_json::to_string(&rule).expect("Failed to serialize BypassRule");
@@ -645,6 +663,87 @@ fn test_bypass_rule_deserialization() {
     assert_eq!(rule.users[1], "bot");
 }
 
+#[test]
+fn test_bypass_rule_label_parses_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.bypassRules.title_convention]
+        enabled = true
+        users = ["maintainer"]
+        label = "skip-checks"
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert_eq!(
+        validation.bypass_rules.title_convention().label(),
+        Some("skip-checks")
+    );
+    assert_eq!(validation.bypass_rules.work_item_convention().label(), None);
+}
+
+#[test]
+fn test_bypass_rules_merge_treats_label_only_rule_as_configured() {
+    let base = BypassRules::new(
+        BypassRule::new(true, vec!["admin".to_string()]),
+        BypassRule::default(),
+    );
+    let over = BypassRules::new(
+        BypassRule::new(false, vec![]).with_label("skip-checks"),
+        BypassRule::default(),
+    );
+
+    let merged = BypassRules::merge(&base, &over);
+
+    assert_eq!(merged.title_convention().label(), Some("skip-checks"));
+    assert!(!merged.title_convention().enabled());
+}
+
+#[test]
+fn test_bypass_rules_merge_missing_author_login() {
+    let base = BypassRules::default().with_missing_author_login("ghost");
+
+    let merged = BypassRules::merge(&base, &BypassRules::default());
+    assert_eq!(merged.missing_author_login(), Some("ghost"));
+
+    let over = BypassRules::default().with_missing_author_login("deleted-user");
+    let merged = BypassRules::merge(&base, &over);
+    assert_eq!(merged.missing_author_login(), Some("deleted-user"));
+}
+
+#[test]
+fn test_missing_author_login_parses_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.bypassRules]
+        missing_author_login = "ghost"
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert_eq!(
+        validation.bypass_rules.missing_author_login(),
+        Some("ghost")
+    );
+}
+
+#[test]
+fn test_missing_author_login_falls_back_to_server_default() {
+    let config = RepositoryProvidedConfig::default();
+    let server_bypass = BypassRules::default().with_missing_author_login("ghost");
+
+    let validation = config.to_validation_config(&server_bypass);
+
+    assert_eq!(
+        validation.bypass_rules.missing_author_login(),
+        Some("ghost")
+    );
+}
+
 #[test]
 fn test_bypass_rules_default() {
     let rules = BypassRules::default();
@@ -660,15 +759,22 @@ fn test_bypass_rules_serialization() {
         title_convention: BypassRule {
             enabled: true,
             users: vec!["release-bot".to_string()],
+            label: None,
         },
         work_items: BypassRule {
             enabled: false,
             users: vec![],
+            label: None,
         },
         size: BypassRule {
             enabled: false,
             users: vec![],
+            label: None,
         },
+        all: BypassRule::default(),
+        signed_commits: BypassRule::default(),
+        revert_reference: BypassRule::default(),
+        missing_author_login: None,
     };
 
     let serialized = serde_json::to_string(&rules).expect("Failed to serialize BypassRules");
@@ -725,24 +831,60 @@ fn test_application_defaults_bypass_rules_serialization() {
         default_title_pattern: "test".to_string(),
         default_invalid_title_label: Some("invalid".to_string()),
         enable_work_item_validation: true,
-        default_work_item_pattern: "pattern".to_string(),
+        default_work_item_pattern: "pattern".into(),
         default_missing_work_item_label: Some("missing".to_string()),
         pr_size_check: PrSizeCheckConfig::default(),
         bypass_rules: BypassRules {
             title_convention: BypassRule {
                 enabled: true,
                 users: vec!["admin".to_string()],
+                label: None,
             },
             work_items: BypassRule::default(),
             size: BypassRule::default(),
+            all: BypassRule::default(),
+            signed_commits: BypassRule::default(),
+            revert_reference: BypassRule::default(),
+            missing_author_login: None,
         },
         change_type_labels: ChangeTypeLabelConfig::default(),
         wip_check: WipCheckConfig::default(),
         pr_state_labels: crate::config::PrStateLabelsConfig::default(),
         renovate_stability: crate::config::RenovateStabilityConfig::default(),
+        check_summary: CheckSummaryConfig::default(),
+        checklist: crate::config::ChecklistPolicyConfig::default(),
+        review_threads: crate::config::ReviewThreadPolicyConfig::default(),
+        deleted_files: crate::config::DeletedFilePolicyConfig::default(),
+        version_bump: crate::config::VersionBumpPolicyConfig::default(),
+        check_execution: crate::config::CheckExecutionConfig::default(),
+        dependency_updates: crate::config::DependencyUpdateConfig::default(),
+        title_characters: crate::config::TitleCharacterPolicyConfig::default(),
+        title_lint: crate::config::TitleLintPolicyConfig::default(),
+        suspicious_files: crate::config::SuspiciousFilePolicyConfig::default(),
+        reopened: crate::config::ReopenedPolicyConfig::default(),
+        status_labels: crate::config::StatusLabelPolicyConfig::default(),
+        comments: crate::config::CommentPolicyConfig::default(),
+        base_branch: crate::config::BaseBranchPolicyConfig::default(),
+        exclusive_labels: crate::config::ExclusiveLabelsPolicyConfig::default(),
+        path_skip: crate::config::PathSkipPolicyConfig::default(),
+        signed_commits: crate::config::SignedCommitsPolicyConfig::default(),
+        revert_reference: crate::config::RevertReferencePolicyConfig::default(),
+        label_creation: crate::config::LabelCreationPolicyConfig::default(),
+        issue_references: crate::config::IssueReferencePolicyConfig::default(),
+        up_to_date: crate::config::UpToDatePolicyConfig::default(),
+        footers: crate::config::FooterPolicyConfig::default(),
+        draft_conversion: crate::config::DraftConversionPolicyConfig::default(),
+        autofix: crate::config::AutofixPolicyConfig::default(),
+        descriptive_title: crate::config::DescriptiveTitlePolicyConfig::default(),
+        change_types: crate::config::ChangeTypesPolicyConfig::default(),
+        label_policy: crate::config::LabelPolicyConfig::default(),
         bot_mention: "@merge-warden".to_string(),
+        show_config_source: false,
+        result_callback_url: None,
         org_policy_source: None,
         repository_scope: None,
+        merge_queue: crate::config::MergeQueueConfig::default(),
+        feature_flags: crate::config::FeatureFlagsConfig::default(),
     };
 
     let serialized =
@@ -781,9 +923,21 @@ fn test_pr_size_check_config_effective_thresholds() {
         thresholds: Some(custom_thresholds.clone()),
         fail_on_oversized: false,
         excluded_file_patterns: vec![],
+        use_default_exclusions: None,
+        excluded_file_presets: vec![],
         label_prefix: "size/".to_string(),
         add_comment: true,
+        comment_on_categories: vec![PrSizeCategory::XXL],
+        comment_breakdown_rows: 5,
         ignore_deletions: false,
+        counting_mode: SizeCountingMode::TotalChanges,
+        ignore_whitespace: false,
+        details_in_check_output: false,
+        details_in_comment: false,
+        exclusions: Vec::new(),
+        compare_base: None,
+        category_names: SizeCategoryNames::default(),
+        review_effort: ReviewEffortConfig::default(),
     };
     assert_eq!(
         config_with_custom.get_effective_thresholds(),
@@ -802,9 +956,21 @@ fn test_pr_size_check_file_exclusion_patterns() {
             "*.txt".to_string(),
             "docs/*".to_string(),
         ],
+        use_default_exclusions: None,
+        excluded_file_presets: vec![],
         label_prefix: "size/".to_string(),
         add_comment: true,
+        comment_on_categories: vec![PrSizeCategory::XXL],
+        comment_breakdown_rows: 5,
         ignore_deletions: false,
+        counting_mode: SizeCountingMode::TotalChanges,
+        ignore_whitespace: false,
+        details_in_check_output: false,
+        details_in_comment: false,
+        exclusions: Vec::new(),
+        compare_base: None,
+        category_names: SizeCategoryNames::default(),
+        review_effort: ReviewEffortConfig::default(),
     };
 
     // Test exclusion patterns
@@ -854,6 +1020,24 @@ fn test_pattern_matches_function() {
     assert!(!pattern_matches("test_*.rs", "main.rs"));
 }
 
+#[test]
+fn test_pattern_matches_name_patterns() {
+    use crate::config::pattern_matches;
+
+    // `*` matches any run of characters, including `/` and nothing at all
+    assert!(pattern_matches("release/*", "release/1.x/hotfix"));
+    assert!(pattern_matches("team: *", "team: "));
+    assert!(pattern_matches("*-bot*", "release-bot[bot]"));
+    assert!(pattern_matches("a*b*c", "aXbYbZc"));
+    assert!(!pattern_matches("a*b*c", "aXbYbZ"));
+    assert!(pattern_matches("**", ""));
+
+    // Other characters, including regex metacharacters, match themselves
+    assert!(pattern_matches("dependabot[bot]", "dependabot[bot]"));
+    assert!(!pattern_matches("v1.?", "v1.2"));
+    assert!(!pattern_matches("Bug", "bug"));
+}
+
 #[test]
 fn test_pr_size_config_serialization() {
     let config = PrSizeCheckConfig {
@@ -861,9 +1045,21 @@ fn test_pr_size_config_serialization() {
         thresholds: Some(SizeThresholds::new(5, 25, 75, 150, 300)),
         fail_on_oversized: true,
         excluded_file_patterns: vec!["*.md".to_string(), "docs/*".to_string()],
+        use_default_exclusions: None,
+        excluded_file_presets: vec![],
         label_prefix: "pr-size/".to_string(),
         add_comment: false,
+        comment_on_categories: vec![PrSizeCategory::XXL],
+        comment_breakdown_rows: 5,
         ignore_deletions: false,
+        counting_mode: SizeCountingMode::TotalChanges,
+        ignore_whitespace: false,
+        details_in_check_output: false,
+        details_in_comment: false,
+        exclusions: Vec::new(),
+        compare_base: None,
+        category_names: SizeCategoryNames::default(),
+        review_effort: ReviewEffortConfig::default(),
     };
 
     // Test that serialization works (this is important for TOML config)
@@ -886,6 +1082,40 @@ fn test_pr_size_config_serialization() {
     );
 }
 
+#[test]
+fn test_pr_size_check_config_use_default_exclusions_defaults_to_true() {
+    let config: PrSizeCheckConfig = toml::from_str("enabled = true\n").expect("Should deserialize");
+
+    assert_eq!(config.use_default_exclusions, None);
+    assert!(config.uses_default_exclusions());
+    assert!(config.should_exclude_file("web/yarn.lock"));
+
+    let config: PrSizeCheckConfig =
+        toml::from_str("enabled = true\nuse_default_exclusions = false\n")
+            .expect("Should deserialize");
+
+    assert!(!config.uses_default_exclusions());
+    assert!(config.effective_excluded_file_patterns().is_empty());
+    assert!(!config.should_exclude_file("web/yarn.lock"));
+}
+
+#[test]
+fn test_pr_size_check_config_counting_mode_parses_snake_case() {
+    let config: PrSizeCheckConfig =
+        toml::from_str("enabled = true\ncounting_mode = \"net_changes\"\n")
+            .expect("Should deserialize");
+
+    assert_eq!(config.counting_mode, SizeCountingMode::NetChanges);
+    assert_eq!(
+        config.effective_counting_mode(),
+        SizeCountingMode::NetChanges
+    );
+
+    let minimal: PrSizeCheckConfig =
+        toml::from_str("enabled = true\n").expect("Should deserialize");
+    assert_eq!(minimal.counting_mode, SizeCountingMode::TotalChanges);
+}
+
 #[test]
 fn test_pr_size_check_config_ignore_deletions_round_trip() {
     // Verify that ignore_deletions = true survives a TOML serialize → deserialize cycle.
@@ -894,9 +1124,21 @@ fn test_pr_size_check_config_ignore_deletions_round_trip() {
         thresholds: None,
         fail_on_oversized: false,
         excluded_file_patterns: vec![],
+        use_default_exclusions: None,
+        excluded_file_presets: vec![],
         label_prefix: "size/".to_string(),
         add_comment: true,
+        comment_on_categories: vec![PrSizeCategory::XXL],
+        comment_breakdown_rows: 5,
         ignore_deletions: true,
+        counting_mode: SizeCountingMode::TotalChanges,
+        ignore_whitespace: false,
+        details_in_check_output: false,
+        details_in_comment: false,
+        exclusions: Vec::new(),
+        compare_base: None,
+        category_names: SizeCategoryNames::default(),
+        review_effort: ReviewEffortConfig::default(),
     };
 
     let serialized = toml::to_string(&config).expect("Should serialize");
@@ -960,6 +1202,105 @@ fn test_repository_config_with_pr_size() {
     assert_eq!(thresholds.xl, 300);
 }
 
+#[test]
+fn test_repository_config_with_size_category_names() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.prSize.category_names]
+        xs = "tiny"
+        xxl = "huge"
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).expect("Should parse TOML");
+
+    let names = &config.policies.pull_requests.size_policies.category_names;
+    assert_eq!(names.name_for(PrSizeCategory::XS), "tiny");
+    assert_eq!(names.name_for(PrSizeCategory::M), "M");
+    assert_eq!(names.name_for(PrSizeCategory::XXL), "huge");
+}
+
+#[test]
+fn test_repository_config_with_pr_size_exclusions() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.prSize]
+        enabled = true
+
+        [[policies.pullRequests.prSize.exclusions]]
+        author = "vendor-sync[bot]"
+
+        [[policies.pullRequests.prSize.exclusions]]
+        author = "*-bot"
+        label = "vendored"
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).expect("Should parse TOML");
+
+    assert_eq!(
+        config.policies.pull_requests.size_policies.exclusions,
+        vec![
+            SizeExclusion {
+                author: Some("vendor-sync[bot]".to_string()),
+                label: None,
+            },
+            SizeExclusion {
+                author: Some("*-bot".to_string()),
+                label: Some("vendored".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_repository_config_with_pr_size_compare_base() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.prSize]
+        enabled = true
+        compare_base = "main"
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).expect("Should parse TOML");
+
+    assert_eq!(
+        config.policies.pull_requests.size_policies.compare_base,
+        Some("main".to_string())
+    );
+}
+
+#[test]
+fn test_size_exclusion_matches_author_glob_and_label() {
+    let user = |login: &str| User {
+        id: 1,
+        login: login.to_string(),
+    };
+    let by_author = SizeExclusion {
+        author: Some("vendor-sync[bot]".to_string()),
+        label: None,
+    };
+    let by_both = SizeExclusion {
+        author: Some("*-bot".to_string()),
+        label: Some("vendored".to_string()),
+    };
+    let vendored = vec!["Vendored".to_string()];
+
+    // Brackets are literal and login matching ignores case
+    assert!(by_author.matches(Some(&user("Vendor-Sync[bot]")), &[]));
+    assert!(!by_author.matches(Some(&user("vendor-syncXbot]")), &[]));
+    assert!(!by_author.matches(None, &vendored));
+
+    // Every field that is set must match
+    assert!(by_both.matches(Some(&user("docs-bot")), &vendored));
+    assert!(!by_both.matches(Some(&user("docs-bot")), &[]));
+    assert!(!by_both.matches(Some(&user("developer")), &vendored));
+
+    // A condition with no fields never matches
+    assert!(!SizeExclusion::default().matches(Some(&user("docs-bot")), &vendored));
+}
+
 #[test]
 fn test_validation_config_includes_pr_size() {
     let repo_config = RepositoryProvidedConfig {
@@ -973,9 +1314,21 @@ fn test_validation_config_includes_pr_size() {
                     thresholds: None,
                     fail_on_oversized: true,
                     excluded_file_patterns: vec!["*.md".to_string()],
+                    use_default_exclusions: None,
+                    excluded_file_presets: vec![],
                     label_prefix: "custom/".to_string(),
                     add_comment: false,
+                    comment_on_categories: vec![PrSizeCategory::XXL],
+                    comment_breakdown_rows: 5,
                     ignore_deletions: false,
+                    counting_mode: SizeCountingMode::TotalChanges,
+                    ignore_whitespace: false,
+                    details_in_check_output: false,
+                    details_in_comment: false,
+                    exclusions: Vec::new(),
+                    compare_base: None,
+                    category_names: SizeCategoryNames::default(),
+                    review_effort: ReviewEffortConfig::default(),
                 },
                 ..Default::default()
             },
@@ -1382,7 +1735,10 @@ default_missing_work_item_label = "missing-work-item"
         Some("bad-title".to_string())
     );
     assert!(defaults.enable_work_item_validation);
-    assert_eq!(defaults.default_work_item_pattern, "my-work-item-pattern");
+    assert_eq!(
+        defaults.default_work_item_pattern.patterns(),
+        ["my-work-item-pattern"]
+    );
     assert_eq!(
         defaults.default_missing_work_item_label,
         Some("missing-work-item".to_string())
@@ -1478,6 +1834,7 @@ fn test_keyword_labels_config_custom_values() {
         security: Some("security-alert".to_string()),
         hotfix: Some("urgent".to_string()),
         tech_debt: Some("cleanup".to_string()),
+        ..Default::default()
     };
     assert_eq!(cfg.breaking_change_label(), "semver-major");
     assert_eq!(cfg.security_label(), "security-alert");
@@ -1492,6 +1849,7 @@ fn test_keyword_labels_config_empty_string_falls_back_to_default() {
         security: Some(String::new()),
         hotfix: Some(String::new()),
         tech_debt: Some(String::new()),
+        ..Default::default()
     };
     assert_eq!(cfg.breaking_change_label(), "breaking-change");
     assert_eq!(cfg.security_label(), "security");
@@ -1665,6 +2023,10 @@ fn test_to_validation_config_uses_repo_bypass_rules_over_server_defaults() {
                 title_convention: Some(BypassRule::new(true, vec!["repo-title-bot".to_string()])),
                 work_items: Some(BypassRule::new(true, vec!["repo-workitem-bot".to_string()])),
                 size: Some(BypassRule::new(true, vec!["repo-size-bot".to_string()])),
+                all: None,
+                missing_author_login: None,
+                signed_commits: None,
+                revert_reference: None,
             }),
             ..Default::default()
         },
@@ -1857,6 +2219,52 @@ users = []
     );
 }
 
+#[tokio::test]
+async fn test_load_merge_warden_config_parses_all_bypass_rule_from_toml() {
+    let toml = r#"schemaVersion = 1
+
+[policies.bypassRules.all]
+enabled = true
+users = ["release-manager"]
+label = "merge-warden:disabled"
+"#;
+
+    let fetcher = MockFetcher::new(Some(toml.to_string()));
+    let app_defaults = ApplicationDefaults {
+        bypass_rules: BypassRules::default()
+            .with_all(BypassRule::new(true, vec!["server-admin".to_string()])),
+        ..Default::default()
+    };
+
+    let config = load_merge_warden_config(
+        "owner",
+        "repo",
+        "merge-warden.toml",
+        &fetcher,
+        &app_defaults,
+    )
+    .await
+    .expect("config should load");
+
+    let validation = config.to_validation_config(&app_defaults.bypass_rules);
+    let all = validation.bypass_rules.all();
+    assert!(all.enabled());
+    assert_eq!(all.users(), vec!["release-manager"]);
+    assert_eq!(all.label(), Some("merge-warden:disabled"));
+}
+
+#[test]
+fn test_bypass_rules_merge_all_over_unconfigured_keeps_base() {
+    let base = BypassRules::default()
+        .with_all(BypassRule::new(true, vec!["admin".to_string()]).with_label("disabled"));
+    let over = BypassRules::default();
+
+    let merged = BypassRules::merge(&base, &over);
+
+    assert_eq!(merged.all().label(), Some("disabled"));
+    assert_eq!(merged.all().users(), vec!["admin"]);
+}
+
 #[tokio::test]
 async fn test_to_validation_config_repo_bypass_rules_override_server_level() {
     // End-to-end: load a TOML that has bypass rules, convert to validation config
@@ -1953,6 +2361,10 @@ fn test_to_validation_config_partial_repo_bypass_inherits_server_defaults_for_mi
                 )),
                 work_items: None,
                 size: None,
+                all: None,
+                missing_author_login: None,
+                signed_commits: None,
+                revert_reference: None,
             }),
             ..Default::default()
         },
@@ -2074,6 +2486,88 @@ fn test_validate_config_content_outcome_equality() {
     assert_ne!(a, c);
 }
 
+// ── lint_config_content tests ────────────────────────────────────────────────
+
+#[test]
+fn test_lint_config_content_accepts_valid_patterns() {
+    let content = r##"
+schemaVersion = 1
+
+[policies.pullRequests.prTitle]
+pattern = "^(feat|fix): .+"
+
+[policies.pullRequests.workItem]
+pattern = ["#\\d+", "JIRA-\\d+"]
+
+[policies.pullRequests.prSize]
+excluded_file_patterns = ["*.md", "!docs/api.md", "vendor/"]
+"##;
+    let outcome = lint_config_content(content);
+    assert!(outcome.valid, "errors: {:?}", outcome.errors);
+    assert!(outcome.errors.is_empty());
+}
+
+#[test]
+fn test_lint_config_content_reports_every_problem() {
+    let content = r##"
+schemaVersion = 2
+
+[policies.pullRequests.prTitle]
+pattern = "^(feat|fix"
+
+[policies.pullRequests.workItem]
+pattern = ["#\\d+", "JIRA-[0-9"]
+
+[policies.pullRequests.prSize]
+excluded_file_patterns = ["*.md", "src/{a,b.rs"]
+
+[policies.pullRequests.pathSkip]
+skip_if_only_paths = ["docs/{a,b"]
+
+[policies.pullRequests.suspiciousFiles]
+binary_patterns = ["*.zip", "*.{tar,gz"]
+"##;
+    let outcome = lint_config_content(content);
+    assert!(!outcome.valid);
+    assert_eq!(outcome.errors.len(), 6, "errors: {:?}", outcome.errors);
+    assert!(outcome.errors[0].contains("schemaVersion"));
+    assert!(outcome.errors[1].starts_with("policies.pullRequests.prTitle.pattern:"));
+    assert!(outcome.errors[2].starts_with("policies.pullRequests.workItem.pattern[1]:"));
+    assert!(outcome.errors[3].starts_with(
+        "policies.pullRequests.prSize.excluded_file_patterns[1]: invalid pattern `src/{a,b.rs`"
+    ));
+    assert!(outcome.errors[4].starts_with("policies.pullRequests.pathSkip.skip_if_only_paths[0]:"));
+    assert!(
+        outcome.errors[5].starts_with("policies.pullRequests.suspiciousFiles.binary_patterns[1]:")
+    );
+}
+
+#[test]
+fn test_lint_config_content_reports_invalid_deleted_file_patterns() {
+    let content = r#"
+schemaVersion = 1
+
+[[policies.pullRequests.deletedFiles.rules]]
+deleted = "src/{a,b"
+requires = ["docs/", "tests/{unit"]
+"#;
+    let outcome = lint_config_content(content);
+    assert_eq!(outcome.errors.len(), 2, "errors: {:?}", outcome.errors);
+    assert!(outcome.errors[0].starts_with(
+        "policies.pullRequests.deletedFiles.rules[0].deleted: invalid pattern `src/{a,b`"
+    ));
+    assert!(
+        outcome.errors[1].starts_with("policies.pullRequests.deletedFiles.rules[0].requires[1]:")
+    );
+}
+
+#[test]
+fn test_lint_config_content_stops_at_toml_errors() {
+    let outcome = lint_config_content("not = valid = toml [[[");
+    assert!(!outcome.valid);
+    assert_eq!(outcome.errors.len(), 1);
+}
+
 // ── PolicySet structural merge tests ─────────────────────────────────────────
 //
 // Spec §5.1 — verify the "identity element" behaviour of PolicySet::merge.
@@ -2343,18 +2837,22 @@ fn work_item_merge_required_or_base_false_over_true_yields_true() {
 fn work_item_merge_pattern_over_non_default_wins() {
     let base = WorkItemPolicyConfig {
         required: false,
-        pattern: "old-wi-pattern".to_string(),
+        pattern: "old-wi-pattern".into(),
         label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
     let over = WorkItemPolicyConfig {
         required: false,
-        pattern: "GH-\\d+".to_string(),
+        pattern: "GH-\\d+".into(),
         label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
 
     let result = WorkItemPolicyConfig::merge(&base, &over);
 
-    assert_eq!(result.pattern, "GH-\\d+");
+    assert_eq!(result.pattern.patterns(), ["GH-\\d+"]);
 }
 
 /// Empty `over.pattern` falls back to `base.pattern`.
@@ -2362,18 +2860,22 @@ fn work_item_merge_pattern_over_non_default_wins() {
 fn work_item_merge_pattern_over_empty_keeps_base() {
     let base = WorkItemPolicyConfig {
         required: false,
-        pattern: "base-wi-pattern".to_string(),
+        pattern: "base-wi-pattern".into(),
         label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
     let over = WorkItemPolicyConfig {
         required: false,
-        pattern: String::new(),
+        pattern: String::new().into(),
         label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
 
     let result = WorkItemPolicyConfig::merge(&base, &over);
 
-    assert_eq!(result.pattern, "base-wi-pattern");
+    assert_eq!(result.pattern.patterns(), ["base-wi-pattern"]);
 }
 
 /// `over.pattern == WORK_ITEM_REGEX` (default) → `base.pattern` is kept.
@@ -2381,18 +2883,22 @@ fn work_item_merge_pattern_over_empty_keeps_base() {
 fn work_item_merge_pattern_over_default_keeps_base() {
     let base = WorkItemPolicyConfig {
         required: false,
-        pattern: "custom-wi-base".to_string(),
+        pattern: "custom-wi-base".into(),
         label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
     let over = WorkItemPolicyConfig {
         required: false,
-        pattern: WORK_ITEM_REGEX.to_string(),
+        pattern: WORK_ITEM_REGEX.into(),
         label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
 
     let result = WorkItemPolicyConfig::merge(&base, &over);
 
-    assert_eq!(result.pattern, "custom-wi-base");
+    assert_eq!(result.pattern.patterns(), ["custom-wi-base"]);
 }
 
 /// `over.label_if_missing = Some(_)` wins.
@@ -2400,13 +2906,17 @@ fn work_item_merge_pattern_over_default_keeps_base() {
 fn work_item_merge_label_over_some_wins() {
     let base = WorkItemPolicyConfig {
         required: false,
-        pattern: WORK_ITEM_REGEX.to_string(),
+        pattern: WORK_ITEM_REGEX.into(),
         label_if_missing: Some("base-wi-label".to_string()),
+        pr_template_guidance: false,
+        min_references: 1,
     };
     let over = WorkItemPolicyConfig {
         required: false,
-        pattern: WORK_ITEM_REGEX.to_string(),
+        pattern: WORK_ITEM_REGEX.into(),
         label_if_missing: Some("over-wi-label".to_string()),
+        pr_template_guidance: false,
+        min_references: 1,
     };
 
     let result = WorkItemPolicyConfig::merge(&base, &over);
@@ -2419,13 +2929,17 @@ fn work_item_merge_label_over_some_wins() {
 fn work_item_merge_label_over_none_keeps_base() {
     let base = WorkItemPolicyConfig {
         required: false,
-        pattern: WORK_ITEM_REGEX.to_string(),
+        pattern: WORK_ITEM_REGEX.into(),
         label_if_missing: Some("base-wi-label".to_string()),
+        pr_template_guidance: false,
+        min_references: 1,
     };
     let over = WorkItemPolicyConfig {
         required: false,
-        pattern: WORK_ITEM_REGEX.to_string(),
+        pattern: WORK_ITEM_REGEX.into(),
         label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
 
     let result = WorkItemPolicyConfig::merge(&base, &over);
@@ -2433,60 +2947,253 @@ fn work_item_merge_label_over_none_keeps_base() {
     assert_eq!(result.label_if_missing, Some("base-wi-label".to_string()));
 }
 
-// ── PrSizeCheckConfig::merge ──────────────────────────────────────────────────
-//
-// Spec §2.3 and §5.4
-
-/// `enabled` OR: base=true, over=false → true.
+/// A list `over.pattern` replaces the base pattern as a whole.
 #[test]
-fn size_merge_enabled_or_base_true_over_false_yields_true() {
-    let base = PrSizeCheckConfig {
-        enabled: true,
-        ..Default::default()
+fn work_item_merge_pattern_over_list_wins() {
+    let base = WorkItemPolicyConfig {
+        required: false,
+        pattern: "base-wi-pattern".into(),
+        label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
-    let over = PrSizeCheckConfig {
-        enabled: false,
-        ..Default::default()
+    let over = WorkItemPolicyConfig {
+        required: false,
+        pattern: vec!["PROJ-\\d+".to_string(), WORK_ITEM_REGEX.to_string()].into(),
+        label_if_missing: None,
+        pr_template_guidance: false,
+        min_references: 1,
     };
 
-    assert!(PrSizeCheckConfig::merge(&base, &over).enabled);
+    let result = WorkItemPolicyConfig::merge(&base, &over);
+
+    assert_eq!(result.pattern.patterns(), ["PROJ-\\d+", WORK_ITEM_REGEX]);
 }
 
-/// `enabled` OR: base=false, over=true → true.
+// ── WorkItemPatterns ──────────────────────────────────────────────────────────
+
 #[test]
-fn size_merge_enabled_or_base_false_over_true_yields_true() {
-    let base = PrSizeCheckConfig {
-        enabled: false,
-        ..Default::default()
-    };
-    let over = PrSizeCheckConfig {
-        enabled: true,
-        ..Default::default()
-    };
+fn work_item_pattern_accepts_single_string() {
+    let toml_str = r#"
+schemaVersion = 1
 
-    assert!(PrSizeCheckConfig::merge(&base, &over).enabled);
+[policies.pullRequests.workItem]
+required = true
+pattern = "PROJ-\\d+"
+"#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_str).unwrap();
+
+    let pattern = &config.policies.pull_requests.work_item_policies.pattern;
+    assert_eq!(pattern.patterns(), ["PROJ-\\d+"]);
 }
 
-/// `fail_on_oversized` is unconditional: over=false wins over base=true.
 #[test]
-fn size_merge_fail_on_oversized_over_false_wins_over_base_true() {
-    let base = PrSizeCheckConfig {
-        fail_on_oversized: true,
-        ..Default::default()
-    };
-    let over = PrSizeCheckConfig {
-        fail_on_oversized: false,
-        ..Default::default()
-    };
+fn work_item_pattern_accepts_list_of_strings() {
+    let toml_str = r##"
+schemaVersion = 1
 
-    assert!(!PrSizeCheckConfig::merge(&base, &over).fail_on_oversized);
+[policies.pullRequests.workItem]
+required = true
+pattern = ["PROJ-\\d+", "#\\d+"]
+"##;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_str).unwrap();
+
+    let pattern = &config.policies.pull_requests.work_item_policies.pattern;
+    assert_eq!(pattern.patterns(), ["PROJ-\\d+", "#\\d+"]);
+    assert_eq!(pattern.to_string(), "PROJ-\\d+, #\\d+");
 }
 
-/// `fail_on_oversized` is unconditional: over=true wins over base=false.
 #[test]
-fn size_merge_fail_on_oversized_over_true_wins_over_base_false() {
-    let base = PrSizeCheckConfig {
-        fail_on_oversized: false,
+fn work_item_pattern_rejects_non_string_values() {
+    let toml_str = r#"
+schemaVersion = 1
+
+[policies.pullRequests.workItem]
+pattern = 42
+"#;
+    assert!(toml::from_str::<RepositoryProvidedConfig>(toml_str).is_err());
+}
+
+#[test]
+fn work_item_pattern_serializes_single_pattern_as_string() {
+    let config = WorkItemPolicyConfig {
+        pattern: "PROJ-\\d+".into(),
+        ..Default::default()
+    };
+
+    let serialized = toml::to_string(&config).unwrap();
+
+    assert!(!serialized.contains('['));
+    let round_trip: WorkItemPolicyConfig = toml::from_str(&serialized).unwrap();
+    assert_eq!(round_trip, config);
+}
+
+#[test]
+fn work_item_pattern_list_round_trips() {
+    let config = WorkItemPolicyConfig {
+        pattern: vec!["PROJ-\\d+".to_string(), "#\\d+".to_string()].into(),
+        ..Default::default()
+    };
+
+    let serialized = toml::to_string(&config).unwrap();
+    let round_trip: WorkItemPolicyConfig = toml::from_str(&serialized).unwrap();
+
+    assert_eq!(round_trip, config);
+}
+
+/// `pr_template_guidance` OR: enabled on either side → enabled.
+#[test]
+fn work_item_merge_pr_template_guidance_or() {
+    let enabled = WorkItemPolicyConfig {
+        pr_template_guidance: true,
+        ..Default::default()
+    };
+    let disabled = WorkItemPolicyConfig::default();
+
+    assert!(WorkItemPolicyConfig::merge(&enabled, &disabled).pr_template_guidance);
+    assert!(WorkItemPolicyConfig::merge(&disabled, &enabled).pr_template_guidance);
+    assert!(!WorkItemPolicyConfig::merge(&disabled, &disabled).pr_template_guidance);
+}
+
+#[test]
+fn work_item_pr_template_guidance_parses_into_validation_config() {
+    let toml_str = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.workItem]
+        required = true
+        pr_template_guidance = true
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_str).unwrap();
+
+    assert!(
+        config
+            .policies
+            .pull_requests
+            .work_item_policies
+            .pr_template_guidance
+    );
+    assert!(
+        config
+            .to_validation_config(&BypassRules::default())
+            .work_item_pr_template_guidance
+    );
+}
+
+/// `min_references`: a repository value other than the default wins.
+#[test]
+fn work_item_merge_min_references_over_wins_when_not_default() {
+    let base = WorkItemPolicyConfig {
+        min_references: 3,
+        ..Default::default()
+    };
+    let over = WorkItemPolicyConfig {
+        min_references: 2,
+        ..Default::default()
+    };
+
+    assert_eq!(WorkItemPolicyConfig::merge(&base, &over).min_references, 2);
+    assert_eq!(
+        WorkItemPolicyConfig::merge(&base, &WorkItemPolicyConfig::default()).min_references,
+        3
+    );
+}
+
+#[test]
+fn work_item_min_references_defaults_to_one() {
+    let config: WorkItemPolicyConfig = toml::from_str("required = true").unwrap();
+
+    assert_eq!(config.min_references, 1);
+    assert_eq!(
+        CurrentPullRequestValidationConfiguration::default().min_work_item_references,
+        1
+    );
+}
+
+#[test]
+fn work_item_min_references_parses_into_validation_config() {
+    let toml_str = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.workItem]
+        required = true
+        min_references = 2
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_str).unwrap();
+
+    assert_eq!(
+        config
+            .to_validation_config(&BypassRules::default())
+            .min_work_item_references,
+        2
+    );
+}
+
+#[test]
+fn application_defaults_accept_work_item_pattern_list() {
+    let toml_str = r##"
+default_work_item_pattern = ["PROJ-\\d+", "#\\d+"]
+"##;
+    let app: ApplicationDefaults = toml::from_str(toml_str).unwrap();
+
+    let validation = CurrentPullRequestValidationConfiguration::from_app_defaults(&app);
+    assert_eq!(validation.work_item_reference_pattern.len(), 2);
+}
+
+// ── PrSizeCheckConfig::merge ──────────────────────────────────────────────────
+//
+// Spec §2.3 and §5.4
+
+/// `enabled` OR: base=true, over=false → true.
+#[test]
+fn size_merge_enabled_or_base_true_over_false_yields_true() {
+    let base = PrSizeCheckConfig {
+        enabled: true,
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        enabled: false,
+        ..Default::default()
+    };
+
+    assert!(PrSizeCheckConfig::merge(&base, &over).enabled);
+}
+
+/// `enabled` OR: base=false, over=true → true.
+#[test]
+fn size_merge_enabled_or_base_false_over_true_yields_true() {
+    let base = PrSizeCheckConfig {
+        enabled: false,
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        enabled: true,
+        ..Default::default()
+    };
+
+    assert!(PrSizeCheckConfig::merge(&base, &over).enabled);
+}
+
+/// `fail_on_oversized` is unconditional: over=false wins over base=true.
+#[test]
+fn size_merge_fail_on_oversized_over_false_wins_over_base_true() {
+    let base = PrSizeCheckConfig {
+        fail_on_oversized: true,
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        fail_on_oversized: false,
+        ..Default::default()
+    };
+
+    assert!(!PrSizeCheckConfig::merge(&base, &over).fail_on_oversized);
+}
+
+/// `fail_on_oversized` is unconditional: over=true wins over base=false.
+#[test]
+fn size_merge_fail_on_oversized_over_true_wins_over_base_false() {
+    let base = PrSizeCheckConfig {
+        fail_on_oversized: false,
         ..Default::default()
     };
     let over = PrSizeCheckConfig {
@@ -2571,6 +3278,124 @@ fn size_merge_excluded_patterns_over_empty_keeps_base() {
     );
 }
 
+/// Presets parse from kebab-case names and a non-empty `over` list replaces `base`.
+#[test]
+fn size_excluded_file_presets_parse_and_merge() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.prSize]
+        excluded_file_presets = ["common-generated"]
+        excluded_file_patterns = ["!Cargo.lock"]
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).expect("Should parse TOML");
+    let over = &config.policies.pull_requests.size_policies;
+
+    assert_eq!(
+        over.excluded_file_presets,
+        vec![FileExclusionPreset::CommonGenerated]
+    );
+    assert!(over.should_exclude_file("web/node_modules/left-pad/index.js"));
+    assert!(!over.should_exclude_file("Cargo.lock"));
+
+    let vendored_counted = PrSizeCheckConfig {
+        excluded_file_presets: vec![FileExclusionPreset::CommonGenerated],
+        excluded_file_patterns: vec!["!vendor/".to_string()],
+        ..Default::default()
+    };
+    assert!(!vendored_counted.should_exclude_file("vendor/lib/a.go"));
+    assert!(vendored_counted.should_exclude_file("yarn.lock"));
+
+    let base = PrSizeCheckConfig {
+        excluded_file_presets: vec![FileExclusionPreset::Lockfiles],
+        ..Default::default()
+    };
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, over).excluded_file_presets,
+        vec![FileExclusionPreset::CommonGenerated]
+    );
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &PrSizeCheckConfig::default()).excluded_file_presets,
+        vec![FileExclusionPreset::Lockfiles]
+    );
+}
+
+/// Non-empty `over.exclusions` replaces `base.exclusions`; empty falls back to `base`.
+#[test]
+fn size_merge_exclusions_over_non_empty_wins() {
+    let base = PrSizeCheckConfig {
+        exclusions: vec![SizeExclusion {
+            author: Some("renovate*".to_string()),
+            label: None,
+        }],
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        exclusions: vec![SizeExclusion {
+            author: None,
+            label: Some("vendored".to_string()),
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &over).exclusions,
+        over.exclusions
+    );
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &PrSizeCheckConfig::default()).exclusions,
+        base.exclusions
+    );
+}
+
+/// `over.compare_base` wins when set; `None` falls back to `base`.
+#[test]
+fn size_merge_compare_base_over_some_wins() {
+    let base = PrSizeCheckConfig {
+        compare_base: Some("main".to_string()),
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        compare_base: Some("develop".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &over).compare_base,
+        Some("develop".to_string())
+    );
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &PrSizeCheckConfig::default()).compare_base,
+        Some("main".to_string())
+    );
+}
+
+/// Each category name is taken from `over` when set; otherwise from `base`.
+#[test]
+fn size_merge_category_names_per_category() {
+    let base = PrSizeCheckConfig {
+        category_names: SizeCategoryNames {
+            xs: Some("tiny".to_string()),
+            xxl: Some("huge".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        category_names: SizeCategoryNames {
+            xxl: Some("split-me".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let merged = PrSizeCheckConfig::merge(&base, &over).category_names;
+
+    assert_eq!(merged.name_for(PrSizeCategory::XS), "tiny");
+    assert_eq!(merged.name_for(PrSizeCategory::XXL), "split-me");
+    assert_eq!(merged.name_for(PrSizeCategory::L), "L");
+}
+
 /// Non-default `over.label_prefix` wins.
 #[test]
 fn size_merge_label_prefix_over_non_default_wins() {
@@ -2619,6 +3444,45 @@ fn size_merge_add_comment_over_false_wins_over_base_true() {
     assert!(!PrSizeCheckConfig::merge(&base, &over).add_comment);
 }
 
+/// `comment_on_categories`: a non-default over list wins; the default falls back to base.
+#[test]
+fn size_merge_comment_on_categories_non_default_over_wins() {
+    let base = PrSizeCheckConfig {
+        comment_on_categories: vec![PrSizeCategory::L, PrSizeCategory::XXL],
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        comment_on_categories: vec![PrSizeCategory::XL, PrSizeCategory::XXL],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &over).comment_on_categories,
+        vec![PrSizeCategory::XL, PrSizeCategory::XXL]
+    );
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &PrSizeCheckConfig::default()).comment_on_categories,
+        vec![PrSizeCategory::L, PrSizeCategory::XXL]
+    );
+}
+
+#[test]
+fn test_pr_size_check_comment_on_categories_parses_from_toml() {
+    let config: PrSizeCheckConfig = toml::from_str(
+        r#"
+        enabled = true
+        comment_on_categories = ["XL", "XXL"]
+        "#,
+    )
+    .unwrap();
+
+    assert!(config.should_comment_on(PrSizeCategory::XL));
+    assert!(!config.should_comment_on(PrSizeCategory::L));
+
+    let defaulted: PrSizeCheckConfig = toml::from_str("enabled = true").unwrap();
+    assert_eq!(defaulted.comment_on_categories, vec![PrSizeCategory::XXL]);
+}
+
 /// `ignore_deletions` is unconditional: over=true wins over base=false.
 #[test]
 fn size_merge_ignore_deletions_over_true_wins_over_base_false() {
@@ -2634,57 +3498,229 @@ fn size_merge_ignore_deletions_over_true_wins_over_base_false() {
     assert!(PrSizeCheckConfig::merge(&base, &over).ignore_deletions);
 }
 
-// ── WipCheckConfig::merge ─────────────────────────────────────────────────────
-//
-// Spec §2.4 and §5.5
-
-/// `enforce_wip_blocking` OR: base=true, over=false → true.
+/// `use_default_exclusions`: `over=Some(true)` wins over `base=Some(false)`.
 #[test]
-fn wip_merge_enforce_or_base_true_over_false_yields_true() {
-    let base = WipCheckConfig {
-        enforce_wip_blocking: true,
+fn size_merge_use_default_exclusions_over_some_wins() {
+    let base = PrSizeCheckConfig {
+        use_default_exclusions: Some(false),
         ..Default::default()
     };
-    let over = WipCheckConfig {
-        enforce_wip_blocking: false,
+    let over = PrSizeCheckConfig {
+        use_default_exclusions: Some(true),
         ..Default::default()
     };
 
-    assert!(WipCheckConfig::merge(&base, &over).enforce_wip_blocking);
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &over).use_default_exclusions,
+        Some(true)
+    );
 }
 
-/// `enforce_wip_blocking` OR: base=false, over=true → true.
+/// `use_default_exclusions`: an unset `over` keeps the org's explicit `false`.
 #[test]
-fn wip_merge_enforce_or_base_false_over_true_yields_true() {
-    let base = WipCheckConfig {
-        enforce_wip_blocking: false,
-        ..Default::default()
-    };
-    let over = WipCheckConfig {
-        enforce_wip_blocking: true,
+fn size_merge_use_default_exclusions_over_none_keeps_base_false() {
+    let base = PrSizeCheckConfig {
+        use_default_exclusions: Some(false),
         ..Default::default()
     };
+    let over: PrSizeCheckConfig = toml::from_str("enabled = true\n").unwrap();
 
-    assert!(WipCheckConfig::merge(&base, &over).enforce_wip_blocking);
+    let merged = PrSizeCheckConfig::merge(&base, &over);
+    assert_eq!(merged.use_default_exclusions, Some(false));
+    assert!(!merged.uses_default_exclusions());
 }
 
-/// Non-default `over.wip_label` wins over `base.wip_label`.
+/// `comment_breakdown_rows` is taken from `over` when it differs from the default.
 #[test]
-fn wip_merge_label_over_non_default_wins() {
-    let base = WipCheckConfig {
-        wip_label: Some("base-wip".to_string()),
+fn size_merge_comment_breakdown_rows_over_non_default_wins() {
+    let base = PrSizeCheckConfig {
+        comment_breakdown_rows: 10,
         ..Default::default()
     };
-    let over = WipCheckConfig {
-        wip_label: Some("work-in-progress".to_string()),
+    let over = PrSizeCheckConfig {
+        comment_breakdown_rows: 0,
         ..Default::default()
     };
 
     assert_eq!(
-        WipCheckConfig::merge(&base, &over).wip_label,
-        Some("work-in-progress".to_string())
+        PrSizeCheckConfig::merge(&base, &over).comment_breakdown_rows,
+        0
     );
-}
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &PrSizeCheckConfig::default()).comment_breakdown_rows,
+        10
+    );
+}
+
+/// `counting_mode` is taken from `over` when it differs from `total_changes`.
+#[test]
+fn size_merge_counting_mode_over_non_default_wins() {
+    let base = PrSizeCheckConfig {
+        counting_mode: SizeCountingMode::AdditionsOnly,
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        counting_mode: SizeCountingMode::NetChanges,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &over).counting_mode,
+        SizeCountingMode::NetChanges
+    );
+}
+
+/// `counting_mode` falls back to `base` when `over` keeps the default.
+#[test]
+fn size_merge_counting_mode_over_default_keeps_base() {
+    let base = PrSizeCheckConfig {
+        counting_mode: SizeCountingMode::NetChanges,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        PrSizeCheckConfig::merge(&base, &PrSizeCheckConfig::default()).counting_mode,
+        SizeCountingMode::NetChanges
+    );
+}
+
+/// `ignore_whitespace` is unconditional: over=false wins over base=true.
+#[test]
+fn size_merge_ignore_whitespace_over_false_wins_over_base_true() {
+    let base = PrSizeCheckConfig {
+        ignore_whitespace: true,
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig {
+        ignore_whitespace: false,
+        ..Default::default()
+    };
+
+    assert!(!PrSizeCheckConfig::merge(&base, &over).ignore_whitespace);
+    let parsed: PrSizeCheckConfig = toml::from_str("ignore_whitespace = true").unwrap();
+    assert!(parsed.ignore_whitespace);
+}
+
+/// `details_in_check_output` OR: base=true, over=false → true.
+#[test]
+fn size_merge_details_in_check_output_or_base_true_over_false_yields_true() {
+    let base = PrSizeCheckConfig {
+        details_in_check_output: true,
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig::default();
+
+    assert!(PrSizeCheckConfig::merge(&base, &over).details_in_check_output);
+    assert!(PrSizeCheckConfig::merge(&over, &base).details_in_check_output);
+    assert!(!PrSizeCheckConfig::merge(&over, &over).details_in_check_output);
+}
+
+/// `details_in_comment` OR: once either tier enables it, it stays enabled.
+#[test]
+fn size_merge_details_in_comment_or() {
+    let base = PrSizeCheckConfig {
+        details_in_comment: true,
+        ..Default::default()
+    };
+    let over = PrSizeCheckConfig::default();
+
+    assert!(PrSizeCheckConfig::merge(&base, &over).details_in_comment);
+    assert!(PrSizeCheckConfig::merge(&over, &base).details_in_comment);
+    assert!(!PrSizeCheckConfig::merge(&over, &over).details_in_comment);
+    let parsed: PrSizeCheckConfig = toml::from_str("details_in_comment = true").unwrap();
+    assert!(parsed.details_in_comment);
+}
+
+/// `details_in_check_output` defaults to false and parses from TOML.
+#[test]
+fn size_details_in_check_output_parses_from_toml() {
+    let parsed: PrSizeCheckConfig =
+        toml::from_str("enabled = true\ndetails_in_check_output = true").unwrap();
+    assert!(parsed.details_in_check_output);
+
+    let defaulted: PrSizeCheckConfig = toml::from_str("enabled = true").unwrap();
+    assert!(!defaulted.details_in_check_output);
+}
+
+/// `review_effort` parses from TOML and merges field by field.
+#[test]
+fn size_merge_review_effort() {
+    let parsed: PrSizeCheckConfig = toml::from_str(
+        "enabled = true\n[review_effort]\nenabled = true\nlabel_prefix = \"review-effort/\"\n[review_effort.weights]\nlines = 5",
+    )
+    .unwrap();
+    assert!(parsed.review_effort.enabled);
+    assert!(!parsed.review_effort.add_label);
+    assert_eq!(parsed.review_effort.label_for(2), "review-effort/2");
+    assert_eq!(parsed.review_effort.weights.lines, 5);
+    assert_eq!(parsed.review_effort.weights.files, 2);
+
+    let over = PrSizeCheckConfig {
+        review_effort: ReviewEffortConfig {
+            add_label: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let merged = PrSizeCheckConfig::merge(&parsed, &over).review_effort;
+
+    assert!(merged.enabled);
+    assert!(merged.add_label);
+    assert_eq!(merged.label_prefix, "review-effort/");
+    assert_eq!(merged.weights.lines, 5);
+}
+
+// ── WipCheckConfig::merge ─────────────────────────────────────────────────────
+//
+// Spec §2.4 and §5.5
+
+/// `enforce_wip_blocking` OR: base=true, over=false → true.
+#[test]
+fn wip_merge_enforce_or_base_true_over_false_yields_true() {
+    let base = WipCheckConfig {
+        enforce_wip_blocking: true,
+        ..Default::default()
+    };
+    let over = WipCheckConfig {
+        enforce_wip_blocking: false,
+        ..Default::default()
+    };
+
+    assert!(WipCheckConfig::merge(&base, &over).enforce_wip_blocking);
+}
+
+/// `enforce_wip_blocking` OR: base=false, over=true → true.
+#[test]
+fn wip_merge_enforce_or_base_false_over_true_yields_true() {
+    let base = WipCheckConfig {
+        enforce_wip_blocking: false,
+        ..Default::default()
+    };
+    let over = WipCheckConfig {
+        enforce_wip_blocking: true,
+        ..Default::default()
+    };
+
+    assert!(WipCheckConfig::merge(&base, &over).enforce_wip_blocking);
+}
+
+/// Non-default `over.wip_label` wins over `base.wip_label`.
+#[test]
+fn wip_merge_label_over_non_default_wins() {
+    let base = WipCheckConfig {
+        wip_label: Some("base-wip".to_string()),
+        ..Default::default()
+    };
+    let over = WipCheckConfig {
+        wip_label: Some("work-in-progress".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        WipCheckConfig::merge(&base, &over).wip_label,
+        Some("work-in-progress".to_string())
+    );
+}
 
 /// `over.wip_label` equal to `WipCheckConfig::default().wip_label` → `base.wip_label` kept.
 #[test]
@@ -3114,6 +4150,55 @@ fn change_type_merge_enabled_or_base_false_over_true_yields_true() {
 //
 // Spec §2.7 and §5.7
 
+/// `categories.*` fields fall through to `base` when `over` leaves them unset.
+#[test]
+fn change_type_merge_categories_over_some_wins_none_falls_through() {
+    let base = ChangeTypeLabelConfig {
+        categories: AutoLabelCategories {
+            change_type: Some(false),
+            breaking_change: Some(false),
+            keywords: None,
+        },
+        max_auto_labels: None,
+        report_no_labels: None,
+        ..Default::default()
+    };
+    let over = ChangeTypeLabelConfig {
+        categories: AutoLabelCategories {
+            change_type: Some(true),
+            breaking_change: None,
+            keywords: Some(false),
+        },
+        max_auto_labels: None,
+        report_no_labels: None,
+        ..Default::default()
+    };
+
+    let result = ChangeTypeLabelConfig::merge(&base, &over).categories;
+
+    assert!(result.change_type_enabled());
+    assert!(!result.breaking_change_enabled());
+    assert!(!result.keywords_enabled());
+}
+
+/// `[change_type_labels.categories]` parses from the repository TOML.
+#[test]
+fn change_type_categories_parse_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [change_type_labels.categories]
+        keywords = false
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let categories = config.change_type_labels.unwrap().categories;
+
+    assert!(categories.change_type_enabled());
+    assert!(categories.breaking_change_enabled());
+    assert!(!categories.keywords_enabled());
+}
+
 /// `over.keyword_labels.breaking_change = Some(_)` wins.
 #[test]
 fn change_type_merge_keyword_breaking_change_over_some_wins() {
@@ -3253,6 +4338,7 @@ fn change_type_merge_all_keyword_labels_over_none_keeps_all_base() {
             security: Some("sec".to_string()),
             hotfix: Some("hot".to_string()),
             tech_debt: Some("debt".to_string()),
+            ..Default::default()
         },
         ..Default::default()
     };
@@ -3272,6 +4358,66 @@ fn change_type_merge_all_keyword_labels_over_none_keeps_all_base() {
     assert_eq!(result.keyword_labels.tech_debt, Some("debt".to_string()));
 }
 
+/// Custom keyword rules and disabled built-ins are parsed from the per-repository config.
+#[test]
+fn keyword_labels_rules_and_disabled_are_parsed() {
+    let toml = r#"
+schemaVersion = 1
+
+[change_type_labels.keyword_labels]
+disabled = ["hotfix", "breaking_change"]
+
+[[change_type_labels.keyword_labels.rules]]
+label = "database-migration"
+keywords = ["migration", "schema change"]
+"#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml).unwrap();
+    let keyword_labels = config.change_type_labels.unwrap().keyword_labels;
+
+    assert_eq!(
+        keyword_labels.rules,
+        vec![KeywordLabelRule {
+            label: "database-migration".to_string(),
+            keywords: vec!["migration".to_string(), "schema change".to_string()],
+        }]
+    );
+    assert!(!keyword_labels.is_enabled(BuiltinKeywordLabel::Hotfix));
+    assert!(!keyword_labels.is_enabled(BuiltinKeywordLabel::BreakingChange));
+    assert!(keyword_labels.is_enabled(BuiltinKeywordLabel::Security));
+}
+
+/// Non-empty keyword rules and disabled lists on `over` replace those on `base`; empty ones keep `base`.
+#[test]
+fn change_type_merge_keyword_rules_and_disabled() {
+    let rule = |label: &str| KeywordLabelRule {
+        label: label.to_string(),
+        keywords: vec!["keyword".to_string()],
+    };
+    let base = ChangeTypeLabelConfig {
+        keyword_labels: KeywordLabelsConfig {
+            rules: vec![rule("base-label")],
+            disabled: vec![BuiltinKeywordLabel::Security],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let over = ChangeTypeLabelConfig {
+        keyword_labels: KeywordLabelsConfig {
+            rules: vec![rule("over-label")],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let result = ChangeTypeLabelConfig::merge(&base, &over);
+
+    assert_eq!(result.keyword_labels.rules, vec![rule("over-label")]);
+    assert_eq!(
+        result.keyword_labels.disabled,
+        vec![BuiltinKeywordLabel::Security]
+    );
+}
+
 // ── BypassRules::merge ────────────────────────────────────────────────────────
 //
 // Spec §2.8
@@ -3486,11 +4632,11 @@ fn policy_set_from_app_defaults_enable_title_validation_not_applied() {
 #[test]
 fn policy_set_from_app_defaults_work_item_pattern_mapped() {
     let mut app = ApplicationDefaults::default();
-    app.default_work_item_pattern = "GH-\\d+".to_string();
+    app.default_work_item_pattern = "GH-\\d+".into();
 
     let ps = PolicySet::from_application_defaults(&app);
 
-    assert_eq!(ps.work_item.pattern, "GH-\\d+");
+    assert_eq!(ps.work_item.pattern.patterns(), ["GH-\\d+"]);
 }
 
 /// `enable_work_item_validation` is NOT applied by `from_application_defaults`.
@@ -3612,8 +4758,10 @@ fn policy_set_from_repo_config_work_item_mapped() {
             pull_requests: PullRequestsPoliciesConfig {
                 work_item_policies: WorkItemPolicyConfig {
                     required: true,
-                    pattern: "GH-\\d+".to_string(),
+                    pattern: "GH-\\d+".into(),
                     label_if_missing: Some("missing-wi".to_string()),
+                    pr_template_guidance: false,
+                    min_references: 1,
                 },
                 ..Default::default()
             },
@@ -3625,7 +4773,7 @@ fn policy_set_from_repo_config_work_item_mapped() {
     let ps = PolicySet::from_repository_config(&repo);
 
     assert!(ps.work_item.required);
-    assert_eq!(ps.work_item.pattern, "GH-\\d+");
+    assert_eq!(ps.work_item.pattern.patterns(), ["GH-\\d+"]);
 }
 
 /// `from_repository_config` maps `policies.pull_requests.size_policies` → `size`.
@@ -3851,7 +4999,8 @@ fn test_pull_requests_title_policy_default_pattern_is_conventional_commit_regex(
 fn test_work_item_policy_default_pattern_is_work_item_regex() {
     let cfg = WorkItemPolicyConfig::default();
     assert_eq!(
-        cfg.pattern, WORK_ITEM_REGEX,
+        cfg.pattern.patterns(),
+        [WORK_ITEM_REGEX],
         "default pattern must equal WORK_ITEM_REGEX"
     );
 }
@@ -4789,6 +5938,60 @@ async fn test_resolve_pull_request_config_no_org_source_no_repo_file() {
     );
 }
 
+#[tokio::test]
+async fn test_resolve_pull_request_config_records_config_source() {
+    let app = ApplicationDefaults {
+        show_config_source: true,
+        ..ApplicationDefaults::default()
+    };
+
+    let fetcher = MockFetcher::new(None);
+    let result = resolve_pull_request_config("owner", "repo", "path", &fetcher, &app, None)
+        .await
+        .unwrap();
+    assert!(result.show_config_source);
+    assert_eq!(result.config_source, ConfigSource::default());
+
+    let fetcher = MockFetcher::new(Some("schemaVersion = 1".to_string()));
+    let result = resolve_pull_request_config(
+        "owner",
+        "repo",
+        ".github/merge-warden.toml",
+        &fetcher,
+        &app,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        result.config_source.repository_file.as_deref(),
+        Some(".github/merge-warden.toml")
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_pull_request_config_unsupported_repo_schema_is_not_a_config_source() {
+    let fetcher = MockFetcher::new(Some("schemaVersion = 2".to_string()));
+    let app = ApplicationDefaults::default();
+    let result = resolve_pull_request_config("owner", "repo", "path", &fetcher, &app, None)
+        .await
+        .unwrap();
+    assert_eq!(result.config_source.repository_file, None);
+}
+
+#[test]
+fn test_config_source_display_lists_conditional_org_policies() {
+    let source = ConfigSource {
+        repository_file: None,
+        org_policy_file: Some("acme/policies:org.toml".to_string()),
+        matched_conditional_policies: 2,
+    };
+    assert_eq!(
+        source.to_string(),
+        "Config: org acme/policies:org.toml (2 conditional policies matched) + application defaults"
+    );
+}
+
 #[tokio::test]
 async fn test_resolve_pull_request_config_app_enforced_title_overrides_repo() {
     // Repo disables title validation; app enforcement forces it on.
@@ -4810,6 +6013,98 @@ enabled = false
     );
 }
 
+#[tokio::test]
+async fn test_resolve_pull_request_config_applies_org_feature_flags() {
+    // The repo enables the footer check; the flags turn it off everywhere but in `acme`.
+    let repo_toml = r#"
+schemaVersion = 1
+
+[policies.pullRequests.footers]
+enabled = true
+"#;
+    let fetcher = MockFetcher::new(Some(repo_toml.to_string()));
+    let app: ApplicationDefaults = toml::from_str(
+        r#"
+[feature_flags.defaults]
+footers = false
+
+[feature_flags.organizations.acme]
+footers = true
+descriptive_title = true
+"#,
+    )
+    .unwrap();
+
+    let result = resolve_pull_request_config("owner", "repo", "path", &fetcher, &app, None)
+        .await
+        .unwrap();
+    assert!(!result.footers.enabled);
+    assert!(!result.descriptive_title.enabled);
+    assert_eq!(
+        result.feature_flags,
+        std::collections::BTreeMap::from([("footers".to_string(), false)])
+    );
+
+    let result = resolve_pull_request_config("Acme", "repo", "path", &fetcher, &app, None)
+        .await
+        .unwrap();
+    assert!(result.footers.enabled);
+    assert!(result.descriptive_title.enabled);
+    assert_eq!(result.feature_flags.len(), 2);
+}
+
+#[test]
+fn test_apply_feature_flags_ignores_unknown_flags() {
+    let mut config = CurrentPullRequestValidationConfiguration::default();
+    config.apply_feature_flags(std::collections::BTreeMap::from([
+        ("no_such_check".to_string(), true),
+        ("title".to_string(), false),
+    ]));
+
+    assert!(!config.enforce_title_convention);
+    assert_eq!(config.feature_flags.len(), 2);
+}
+
+#[test]
+fn test_depends_on_labels_when_a_rule_reads_the_labels() {
+    let config = CurrentPullRequestValidationConfiguration::default();
+    assert!(!config.depends_on_labels());
+
+    let mut exclusive = config.clone();
+    exclusive.exclusive_labels.enabled = true;
+    assert!(exclusive.depends_on_labels());
+
+    let mut disable_label = config.clone();
+    disable_label.bypass_rules = BypassRules::default().with_all(
+        BypassRule::new(true, vec!["release-manager".to_string()])
+            .with_label("merge-warden:disabled"),
+    );
+    assert!(disable_label.depends_on_labels());
+
+    let mut disabled_rule = config.clone();
+    disabled_rule.bypass_rules = BypassRules::default()
+        .with_all(BypassRule::new(false, Vec::new()).with_label("merge-warden:disabled"));
+    assert!(!disabled_rule.depends_on_labels());
+
+    let mut size_exclusion = config;
+    size_exclusion.pr_size_check.exclusions = vec![crate::config::SizeExclusion {
+        author: None,
+        label: Some("vendored".to_string()),
+    }];
+    assert!(size_exclusion.depends_on_labels());
+}
+
+#[test]
+fn test_check_kind_keys_round_trip() {
+    for kind in CheckKind::DEFAULT_ORDER {
+        let key: CheckKind = toml::Value::String(kind.key().to_string())
+            .try_into()
+            .unwrap();
+        assert_eq!(key, kind);
+        assert_eq!(CheckKind::from_key(kind.key()), Some(kind));
+    }
+}
+
 #[tokio::test]
 async fn test_resolve_pull_request_config_repo_enables_title() {
     let repo_toml = r#"
@@ -4903,6 +6198,10 @@ pattern = "^ORG:"
         "Org enforced setting must override repo disabled setting"
     );
     assert_eq!(result.title_pattern, "^ORG:");
+    assert_eq!(
+        result.config_source.to_string(),
+        "Config: repo repo-policy.toml + org my-org/policies:org-policy.toml + application defaults"
+    );
 }
 
 #[tokio::test]
@@ -5172,10 +6471,10 @@ fn test_from_org_section_title_fields_mapped() {
 fn test_from_org_section_work_item_fields_mapped() {
     let mut section = OrgPolicySectionRaw::default();
     section.policies.pull_requests.work_item_policies.required = true;
-    section.policies.pull_requests.work_item_policies.pattern = "JIRA-[0-9]+".to_string();
+    section.policies.pull_requests.work_item_policies.pattern = "JIRA-[0-9]+".into();
     let ps = PolicySet::from_org_section(&section);
     assert!(ps.work_item.required);
-    assert_eq!(ps.work_item.pattern, "JIRA-[0-9]+");
+    assert_eq!(ps.work_item.pattern.patterns(), ["JIRA-[0-9]+"]);
 }
 
 #[test]
@@ -5220,6 +6519,10 @@ fn test_from_org_section_bypass_rules_populated_from_section() {
         )),
         work_items: None,
         size: None,
+        all: None,
+        missing_author_login: None,
+        signed_commits: None,
+        revert_reference: None,
     });
     let ps = PolicySet::from_org_section(&section);
     assert!(
@@ -5253,6 +6556,10 @@ fn test_from_org_section_work_items_bypass_populated_from_section() {
             vec!["release-regent[bot]".to_string()],
         )),
         size: None,
+        all: None,
+        missing_author_login: None,
+        signed_commits: None,
+        revert_reference: None,
     });
     let ps = PolicySet::from_org_section(&section);
     assert!(
@@ -5276,6 +6583,10 @@ fn test_from_org_section_size_bypass_populated_from_section() {
         title_convention: None,
         work_items: None,
         size: Some(BypassRule::new(true, vec!["size-skip-bot".to_string()])),
+        all: None,
+        missing_author_login: None,
+        signed_commits: None,
+        revert_reference: None,
     });
     let ps = PolicySet::from_org_section(&section);
     assert!(
@@ -5310,6 +6621,10 @@ fn test_from_org_section_all_three_bypass_rules_populated() {
         title_convention: Some(BypassRule::new(true, vec!["title-bot".to_string()])),
         work_items: Some(BypassRule::new(true, vec!["wi-bot".to_string()])),
         size: Some(BypassRule::new(true, vec!["size-bot".to_string()])),
+        all: None,
+        missing_author_login: None,
+        signed_commits: None,
+        revert_reference: None,
     });
     let ps = PolicySet::from_org_section(&section);
     assert!(
@@ -5340,6 +6655,10 @@ fn test_from_org_section_partial_bypass_rules_absent_sub_rules_are_default() {
         title_convention: Some(BypassRule::new(true, vec!["title-bot".to_string()])),
         work_items: None,
         size: None,
+        all: None,
+        missing_author_login: None,
+        signed_commits: None,
+        revert_reference: None,
     });
     let ps = PolicySet::from_org_section(&section);
     // The absent sub-rules must be BypassRule::default() (disabled, no users).
@@ -5363,6 +6682,10 @@ fn test_from_org_section_bypass_rules_not_default_when_configured() {
         title_convention: Some(BypassRule::new(true, vec!["some-bot".to_string()])),
         work_items: None,
         size: None,
+        all: None,
+        missing_author_login: None,
+        signed_commits: None,
+        revert_reference: None,
     });
     let ps = PolicySet::from_org_section(&section);
     assert_ne!(
@@ -5385,6 +6708,10 @@ fn test_from_org_section_bypass_rules_multiple_users_preserved_exactly() {
         title_convention: Some(BypassRule::new(true, users.clone())),
         work_items: None,
         size: None,
+        all: None,
+        missing_author_login: None,
+        signed_commits: None,
+        revert_reference: None,
     });
     let ps = PolicySet::from_org_section(&section);
     let actual: Vec<&str> = ps.bypass_rules.title_convention().users();
@@ -5701,7 +7028,10 @@ pattern = "JIRA-[0-9]+"
         policy.defaults.work_item.required,
         "defaults work_item.required should be set"
     );
-    assert_eq!(policy.defaults.work_item.pattern, "JIRA-[0-9]+");
+    assert_eq!(
+        policy.defaults.work_item.pattern.patterns(),
+        ["JIRA-[0-9]+"]
+    );
     // Cross-check: defaults has no title override, enforced has no work_item override.
     assert!(
         !policy.defaults.title.required,
@@ -5903,7 +7233,8 @@ pattern = "GH-[0-9]+"
 
     assert!(result.enforce_work_item_references);
     assert_eq!(
-        result.work_item_reference_pattern, "GH-[0-9]+",
+        result.work_item_reference_pattern.patterns(),
+        ["GH-[0-9]+"],
         "Repo work_item pattern must override org default pattern"
     );
 }
@@ -5948,7 +7279,8 @@ required = false
         "Org default work_item.required must be used when repo omits it"
     );
     assert_eq!(
-        result.work_item_reference_pattern, "JIRA-[0-9]+",
+        result.work_item_reference_pattern.patterns(),
+        ["JIRA-[0-9]+"],
         "Org default work_item pattern must be used when repo omits it"
     );
 }
@@ -6031,7 +7363,7 @@ fn test_policy_set_to_validation_config_missing_work_item_label_forwarded() {
 fn test_policy_set_to_validation_config_enforce_work_item_forwarded() {
     let mut ps = PolicySet::default();
     ps.work_item.required = true;
-    ps.work_item.pattern = "WI-[0-9]+".to_string();
+    ps.work_item.pattern = "WI-[0-9]+".into();
     let app = ApplicationDefaults::default();
     let cfg = ps.to_validation_config(&app);
     assert!(
@@ -6039,7 +7371,8 @@ fn test_policy_set_to_validation_config_enforce_work_item_forwarded() {
         "enforce_work_item_references must reflect work_item.required"
     );
     assert_eq!(
-        cfg.work_item_reference_pattern, "WI-[0-9]+",
+        cfg.work_item_reference_pattern.patterns(),
+        ["WI-[0-9]+"],
         "work_item_reference_pattern must be forwarded from PolicySet.work_item.pattern"
     );
 }
@@ -7184,13 +8517,40 @@ fn test_application_defaults_default_has_no_repository_scope() {
 }
 
 #[test]
-fn test_application_defaults_toml_absent_repository_scope_section_is_none() {
-    // No [repository_scope] section at all in the TOML document.
-    let toml_str = "enable_title_validation = true\n";
-    let parsed: ApplicationDefaults = toml::from_str(toml_str).expect("should parse");
-    assert!(
-        parsed.repository_scope.is_none(),
-        "absent [repository_scope] section must deserialize to None"
+fn test_application_defaults_toml_result_callback_url() {
+    let parsed: ApplicationDefaults =
+        toml::from_str("result_callback_url = \"https://dashboard.example.com/merge-warden\"\n")
+            .expect("should parse");
+    assert_eq!(
+        parsed.result_callback_url.as_deref(),
+        Some("https://dashboard.example.com/merge-warden")
+    );
+
+    let parsed: ApplicationDefaults = toml::from_str("").expect("should parse");
+    assert!(parsed.result_callback_url.is_none());
+}
+
+#[test]
+fn test_application_defaults_toml_merge_queue() {
+    let toml_str = r#"
+        [merge_queue]
+        enabled = true
+    "#;
+    let parsed: ApplicationDefaults = toml::from_str(toml_str).expect("should parse");
+    assert!(parsed.merge_queue.enabled);
+
+    let parsed: ApplicationDefaults = toml::from_str("").expect("should parse");
+    assert!(!parsed.merge_queue.enabled);
+}
+
+#[test]
+fn test_application_defaults_toml_absent_repository_scope_section_is_none() {
+    // No [repository_scope] section at all in the TOML document.
+    let toml_str = "enable_title_validation = true\n";
+    let parsed: ApplicationDefaults = toml::from_str(toml_str).expect("should parse");
+    assert!(
+        parsed.repository_scope.is_none(),
+        "absent [repository_scope] section must deserialize to None"
     );
 }
 
@@ -7924,3 +9284,1692 @@ proptest! {
         let _ = validate_repository_scope_patterns(&scope); // must not panic
     }
 }
+
+// ============================================================
+// CheckSummaryConfig
+// ============================================================
+
+#[test]
+fn test_check_summary_config_defaults() {
+    let config = CheckSummaryConfig::default();
+    assert!(config.use_emoji);
+    assert_eq!(config.title_severity, CheckSeverity::Error);
+    assert_eq!(config.work_item_severity, CheckSeverity::Error);
+    assert_eq!(config.size_severity, CheckSeverity::Warning);
+}
+
+#[test]
+fn test_check_summary_config_parses_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.checkSummary]
+        use_emoji = false
+        work_item_severity = "notice"
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let summary = &config.policies.pull_requests.check_summary;
+
+    assert!(!summary.use_emoji);
+    assert_eq!(summary.title_severity, CheckSeverity::Error);
+    assert_eq!(summary.work_item_severity, CheckSeverity::Notice);
+    assert_eq!(summary.size_severity, CheckSeverity::Warning);
+}
+
+#[test]
+fn test_check_summary_config_rejects_unknown_severity() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.checkSummary]
+        title_severity = "fatal"
+    "#;
+
+    assert!(toml::from_str::<RepositoryProvidedConfig>(toml_content).is_err());
+}
+
+#[test]
+fn test_check_summary_config_merge_over_non_default_wins() {
+    let base = CheckSummaryConfig::default();
+    let over = CheckSummaryConfig {
+        use_emoji: false,
+        title_severity: CheckSeverity::Warning,
+        ..Default::default()
+    };
+
+    let merged = CheckSummaryConfig::merge(&base, &over);
+
+    assert!(!merged.use_emoji);
+    assert_eq!(merged.title_severity, CheckSeverity::Warning);
+    assert_eq!(merged.size_severity, CheckSeverity::Warning);
+}
+
+#[test]
+fn test_check_summary_config_merge_default_over_defers_to_base() {
+    let base = CheckSummaryConfig {
+        use_emoji: false,
+        title_severity: CheckSeverity::Notice,
+        work_item_severity: CheckSeverity::Warning,
+        size_severity: CheckSeverity::Error,
+        check_run_name: Some("Merge Warden PR checks".to_string()),
+        keep_legacy_check_run: true,
+        list_checks_on_success: true,
+        rerun_action: true,
+        templates: CheckSummaryTemplates {
+            passed: Some("Ready to merge.".to_string()),
+            failed: None,
+            on_failure: vec![CheckSummaryFailureTemplate {
+                checks: vec![CheckKind::Title],
+                template: "Fix the title.".to_string(),
+            }],
+        },
+    };
+    let over = CheckSummaryConfig::default();
+
+    let merged = CheckSummaryConfig::merge(&base, &over);
+
+    assert_eq!(merged, base);
+}
+
+#[test]
+fn test_check_summary_config_merge_rerun_action() {
+    let enabled = CheckSummaryConfig {
+        rerun_action: true,
+        ..Default::default()
+    };
+    let disabled = CheckSummaryConfig::default();
+
+    assert!(CheckSummaryConfig::merge(&enabled, &disabled).rerun_action);
+    assert!(CheckSummaryConfig::merge(&disabled, &enabled).rerun_action);
+    assert!(!CheckSummaryConfig::merge(&disabled, &disabled).rerun_action);
+}
+
+#[test]
+fn test_check_summary_config_merge_check_run_name() {
+    let base = CheckSummaryConfig {
+        check_run_name: Some("Org checks".to_string()),
+        keep_legacy_check_run: true,
+        ..Default::default()
+    };
+    let over = CheckSummaryConfig {
+        check_run_name: Some("Repo checks".to_string()),
+        ..Default::default()
+    };
+
+    let merged = CheckSummaryConfig::merge(&base, &over);
+
+    assert_eq!(merged.check_run_name.as_deref(), Some("Repo checks"));
+    assert!(merged.keep_legacy_check_run);
+
+    let merged = CheckSummaryConfig::merge(&base, &CheckSummaryConfig::default());
+    assert_eq!(merged.check_run_name.as_deref(), Some("Org checks"));
+}
+
+#[test]
+fn test_check_summary_templates_merge_and_selection() {
+    let base: CheckSummaryTemplates = toml::from_str(
+        r#"
+        passed = "Org: ready."
+        failed = "Org: {{ default_summary }}"
+
+        [[on_failure]]
+        checks = ["title"]
+        template = "Org: fix the title."
+        "#,
+    )
+    .unwrap();
+    let over = CheckSummaryTemplates {
+        failed: Some("Repo: {{ issues | length }} issue(s).".to_string()),
+        ..Default::default()
+    };
+
+    let merged = CheckSummaryTemplates::merge(&base, &over);
+
+    assert_eq!(merged.template_for(true, &[]), Some("Org: ready."));
+    assert_eq!(
+        merged.template_for(false, &[CheckKind::Title]),
+        Some("Org: fix the title.")
+    );
+    // Only an exact combination of failing checks selects an `on_failure` entry
+    assert_eq!(
+        merged.template_for(false, &[CheckKind::Title, CheckKind::Size]),
+        Some("Repo: {{ issues | length }} issue(s).")
+    );
+    assert_eq!(
+        CheckSummaryTemplates::default().template_for(false, &[CheckKind::Title]),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_check_summary() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.checkSummary]
+        use_emoji = false
+        size_severity = "error"
+        check_run_name = "Merge Warden PR checks"
+        keep_legacy_check_run = true
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+
+    let config = load_merge_warden_config(
+        "owner",
+        "repo",
+        CONFIG_FILE_PATH,
+        &fetcher,
+        &ApplicationDefaults::default(),
+    )
+    .await
+    .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert!(!validation.check_summary.use_emoji);
+    assert_eq!(validation.check_summary.size_severity, CheckSeverity::Error);
+    assert_eq!(
+        validation.check_summary.check_run_name.as_deref(),
+        Some("Merge Warden PR checks")
+    );
+    assert!(validation.check_summary.keep_legacy_check_run);
+}
+
+// ============================================================
+// ChecklistPolicyConfig
+// ============================================================
+
+#[test]
+fn test_checklist_policy_config_parses_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.checklist]
+        enabled = true
+        required_items = ["Tests added", "Docs updated"]
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let checklist = &config.policies.pull_requests.checklist;
+
+    assert!(checklist.enabled);
+    assert_eq!(
+        checklist.required_items,
+        vec!["Tests added", "Docs updated"]
+    );
+}
+
+#[test]
+fn test_checklist_policy_config_merge() {
+    let base = ChecklistPolicyConfig {
+        enabled: true,
+        required_items: vec!["Tests added".to_string()],
+    };
+
+    let merged = ChecklistPolicyConfig::merge(&base, &ChecklistPolicyConfig::default());
+    assert_eq!(merged, base);
+
+    let over = ChecklistPolicyConfig {
+        enabled: false,
+        required_items: vec!["Docs updated".to_string()],
+    };
+    let merged = ChecklistPolicyConfig::merge(&base, &over);
+    assert!(merged.enabled);
+    assert_eq!(merged.required_items, vec!["Docs updated".to_string()]);
+}
+
+// ============================================================
+// ReviewThreadPolicyConfig
+// ============================================================
+
+#[test]
+fn test_review_thread_policy_config_parses_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.reviewThreads]
+        enabled = true
+        max_unresolved = 2
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let review_threads = &config.policies.pull_requests.review_threads;
+
+    assert!(review_threads.enabled);
+    assert_eq!(review_threads.max_unresolved, 2);
+}
+
+#[test]
+fn test_review_thread_policy_config_merge() {
+    let base = ReviewThreadPolicyConfig {
+        enabled: true,
+        max_unresolved: 3,
+    };
+
+    let merged = ReviewThreadPolicyConfig::merge(&base, &ReviewThreadPolicyConfig::default());
+    assert_eq!(merged, base);
+
+    let over = ReviewThreadPolicyConfig {
+        enabled: false,
+        max_unresolved: 1,
+    };
+    let merged = ReviewThreadPolicyConfig::merge(&base, &over);
+    assert!(merged.enabled);
+    assert_eq!(merged.max_unresolved, 1);
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_checklist() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.checklist]
+        enabled = true
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+    let app_defaults = ApplicationDefaults {
+        checklist: ChecklistPolicyConfig {
+            enabled: false,
+            required_items: vec!["Tests added".to_string()],
+        },
+        ..Default::default()
+    };
+
+    let config =
+        load_merge_warden_config("owner", "repo", CONFIG_FILE_PATH, &fetcher, &app_defaults)
+            .await
+            .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert!(validation.checklist.enabled);
+    assert_eq!(
+        validation.checklist.required_items,
+        vec!["Tests added".to_string()]
+    );
+}
+
+// ============================================================
+// DeletedFilePolicyConfig
+// ============================================================
+
+#[test]
+fn test_deleted_file_policy_config_parses_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.deletedFiles]
+        enabled = true
+        severity = "error"
+
+        [[policies.pullRequests.deletedFiles.rules]]
+        deleted = "src/*.rs"
+        requires = ["docs/*", "tests/*"]
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let deleted_files = &config.policies.pull_requests.deleted_files;
+
+    assert!(deleted_files.enabled);
+    assert_eq!(deleted_files.severity, CheckSeverity::Error);
+    assert!(deleted_files.fails_check());
+    assert_eq!(
+        deleted_files.rules,
+        vec![DeletedFilePairingRule {
+            deleted: "src/*.rs".to_string(),
+            requires: vec!["docs/*".to_string(), "tests/*".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn test_deleted_file_policy_config_defaults_to_warning() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.deletedFiles]
+        enabled = true
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let deleted_files = &config.policies.pull_requests.deleted_files;
+
+    assert_eq!(deleted_files.severity, CheckSeverity::Warning);
+    assert!(!deleted_files.fails_check());
+    assert!(deleted_files.rules.is_empty());
+}
+
+#[test]
+fn test_deleted_file_policy_config_merge() {
+    let base = DeletedFilePolicyConfig {
+        enabled: true,
+        severity: CheckSeverity::Error,
+        rules: vec![DeletedFilePairingRule {
+            deleted: "src/*".to_string(),
+            requires: vec!["docs/*".to_string()],
+        }],
+    };
+
+    let merged = DeletedFilePolicyConfig::merge(&base, &DeletedFilePolicyConfig::default());
+    assert_eq!(merged, base);
+
+    let over = DeletedFilePolicyConfig {
+        enabled: false,
+        severity: CheckSeverity::Notice,
+        rules: vec![DeletedFilePairingRule {
+            deleted: "api/*".to_string(),
+            requires: vec!["CHANGELOG.md".to_string()],
+        }],
+    };
+    let merged = DeletedFilePolicyConfig::merge(&base, &over);
+    assert!(merged.enabled);
+    assert_eq!(merged.severity, CheckSeverity::Notice);
+    assert_eq!(merged.rules, over.rules);
+}
+
+#[test]
+fn test_deleted_file_pairing_rule_matches_patterns() {
+    let rule = DeletedFilePairingRule {
+        deleted: "src/**/*.rs".to_string(),
+        requires: vec!["/docs/".to_string(), "CHANGELOG.md".to_string()],
+    };
+    let deleted_files = rule.deleted_files();
+    let required_files = rule.required_files();
+
+    assert!(deleted_files.is_excluded("src/parser.rs"));
+    assert!(deleted_files.is_excluded("src/nested/parser.rs"));
+    assert!(!deleted_files.is_excluded("src/parser.py"));
+    assert!(!deleted_files.is_excluded("vendor/src/parser.rs"));
+    assert!(required_files.is_excluded("docs/guide/parser.md"));
+    assert!(!required_files.is_excluded("crates/docs/parser.md"));
+    // A pattern without a `/` matches at any depth
+    assert!(required_files.is_excluded("crates/core/CHANGELOG.md"));
+    assert!(!required_files.is_excluded("README.md"));
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_deleted_files() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.deletedFiles]
+        enabled = true
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+    let app_defaults = ApplicationDefaults {
+        deleted_files: DeletedFilePolicyConfig {
+            enabled: false,
+            severity: CheckSeverity::Error,
+            rules: vec![DeletedFilePairingRule {
+                deleted: "src/*".to_string(),
+                requires: vec!["docs/*".to_string()],
+            }],
+        },
+        ..Default::default()
+    };
+
+    let config =
+        load_merge_warden_config("owner", "repo", CONFIG_FILE_PATH, &fetcher, &app_defaults)
+            .await
+            .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert!(validation.deleted_files.enabled);
+    assert_eq!(validation.deleted_files.severity, CheckSeverity::Error);
+    assert_eq!(validation.deleted_files.rules.len(), 1);
+}
+
+// ============================================================
+// VersionBumpPolicyConfig
+// ============================================================
+
+#[test]
+fn test_version_bump_policy_config_parses_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.versionBump]
+        enabled = true
+        manifest_path = "web/package.json"
+        manifest_format = "package_json"
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let version_bump = &config.policies.pull_requests.version_bump;
+
+    assert!(version_bump.enabled);
+    assert_eq!(version_bump.manifest_path, "web/package.json");
+    assert_eq!(
+        version_bump.manifest_format,
+        crate::version::ManifestFormat::PackageJson
+    );
+}
+
+#[test]
+fn test_version_bump_policy_config_defaults_to_cargo_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.versionBump]
+        enabled = true
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let version_bump = &config.policies.pull_requests.version_bump;
+
+    assert_eq!(version_bump.manifest_path, "Cargo.toml");
+    assert_eq!(
+        version_bump.manifest_format,
+        crate::version::ManifestFormat::Auto
+    );
+}
+
+#[test]
+fn test_version_bump_policy_config_merge() {
+    let base = VersionBumpPolicyConfig {
+        enabled: true,
+        manifest_path: "VERSION".to_string(),
+        manifest_format: crate::version::ManifestFormat::Plain,
+    };
+
+    let merged = VersionBumpPolicyConfig::merge(&base, &VersionBumpPolicyConfig::default());
+    assert_eq!(merged, base);
+
+    let over = VersionBumpPolicyConfig {
+        enabled: false,
+        manifest_path: "package.json".to_string(),
+        manifest_format: crate::version::ManifestFormat::PackageJson,
+    };
+    let merged = VersionBumpPolicyConfig::merge(&base, &over);
+    assert!(merged.enabled);
+    assert_eq!(merged.manifest_path, "package.json");
+    assert_eq!(
+        merged.manifest_format,
+        crate::version::ManifestFormat::PackageJson
+    );
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_version_bump() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.versionBump]
+        enabled = true
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+    let app_defaults = ApplicationDefaults {
+        version_bump: VersionBumpPolicyConfig {
+            enabled: false,
+            manifest_path: "VERSION".to_string(),
+            manifest_format: crate::version::ManifestFormat::Plain,
+        },
+        ..Default::default()
+    };
+
+    let config =
+        load_merge_warden_config("owner", "repo", CONFIG_FILE_PATH, &fetcher, &app_defaults)
+            .await
+            .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert!(validation.version_bump.enabled);
+    assert_eq!(validation.version_bump.manifest_path, "VERSION");
+    assert_eq!(
+        validation.version_bump.manifest_format,
+        crate::version::ManifestFormat::Plain
+    );
+}
+
+#[test]
+fn test_check_execution_config_effective_order() {
+    let config = CheckExecutionConfig::default();
+    assert_eq!(config.effective_order(), CheckKind::DEFAULT_ORDER.to_vec());
+
+    let config = CheckExecutionConfig {
+        order: vec![CheckKind::Size, CheckKind::Title, CheckKind::Size],
+        short_circuit_on_failure: false,
+        report_only: Vec::new(),
+        concurrent: false,
+        diff_source: DiffSource::PullRequest,
+    };
+    assert_eq!(
+        config.effective_order(),
+        vec![
+            CheckKind::Size,
+            CheckKind::Title,
+            CheckKind::WorkItem,
+            CheckKind::Checklist,
+            CheckKind::ReviewThreads,
+            CheckKind::VersionBump,
+            CheckKind::DeletedFiles,
+            CheckKind::SuspiciousFiles,
+            CheckKind::BaseBranch,
+            CheckKind::ExclusiveLabels,
+            CheckKind::SignedCommits,
+            CheckKind::RevertReference,
+            CheckKind::UpToDate,
+            CheckKind::Footers,
+            CheckKind::DescriptiveTitle,
+            CheckKind::ChangeTypes,
+            CheckKind::LabelPolicy,
+        ]
+    );
+}
+
+#[test]
+fn test_check_execution_config_merge() {
+    let base = CheckExecutionConfig {
+        order: vec![CheckKind::Title],
+        short_circuit_on_failure: true,
+        report_only: Vec::new(),
+        concurrent: false,
+        diff_source: DiffSource::PullRequest,
+    };
+
+    let merged = CheckExecutionConfig::merge(&base, &CheckExecutionConfig::default());
+    assert_eq!(merged, base);
+
+    let over = CheckExecutionConfig {
+        order: vec![CheckKind::Checklist],
+        short_circuit_on_failure: false,
+        report_only: Vec::new(),
+        concurrent: false,
+        diff_source: DiffSource::MergeBase,
+    };
+    let merged = CheckExecutionConfig::merge(&base, &over);
+    assert_eq!(merged.order, vec![CheckKind::Checklist]);
+    assert!(merged.short_circuit_on_failure);
+    assert_eq!(merged.diff_source, DiffSource::MergeBase);
+
+    // A tier that keeps the default diff source does not reset a merge-base setting
+    let merged = CheckExecutionConfig::merge(&over, &base);
+    assert_eq!(merged.diff_source, DiffSource::MergeBase);
+}
+
+#[test]
+fn test_change_type_label_config_max_auto_labels_parses_and_merges() {
+    let config: ChangeTypeLabelConfig = toml::from_str("max_auto_labels = 2").unwrap();
+    assert_eq!(config.max_auto_labels, Some(2));
+
+    let base = ChangeTypeLabelConfig::default();
+    assert_eq!(base.max_auto_labels, None);
+    assert_eq!(
+        ChangeTypeLabelConfig::merge(&base, &config).max_auto_labels,
+        Some(2)
+    );
+    assert_eq!(
+        ChangeTypeLabelConfig::merge(&config, &base).max_auto_labels,
+        Some(2)
+    );
+}
+
+#[test]
+fn test_change_type_label_config_report_no_labels_parses_and_merges() {
+    let quiet: ChangeTypeLabelConfig = toml::from_str("report_no_labels = false").unwrap();
+    assert!(!quiet.reports_no_labels());
+
+    let base = ChangeTypeLabelConfig::default();
+    assert!(base.reports_no_labels());
+    assert!(!ChangeTypeLabelConfig::merge(&base, &quiet).reports_no_labels());
+    assert!(!ChangeTypeLabelConfig::merge(&quiet, &base).reports_no_labels());
+
+    let loud = ChangeTypeLabelConfig {
+        report_no_labels: Some(true),
+        ..Default::default()
+    };
+    assert!(ChangeTypeLabelConfig::merge(&quiet, &loud).reports_no_labels());
+}
+
+#[test]
+fn test_check_execution_config_concurrent_requires_no_short_circuit() {
+    let config: CheckExecutionConfig = toml::from_str("concurrent = true").unwrap();
+    assert!(config.concurrent);
+    assert!(config.runs_concurrently());
+
+    let short_circuit = CheckExecutionConfig {
+        short_circuit_on_failure: true,
+        ..config.clone()
+    };
+    assert!(!short_circuit.runs_concurrently());
+
+    let merged = CheckExecutionConfig::merge(&config, &CheckExecutionConfig::default());
+    assert!(merged.concurrent);
+    assert!(!CheckExecutionConfig::default().runs_concurrently());
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_check_execution() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.checkExecution]
+        order = ["checklist", "review_threads"]
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+    let app_defaults = ApplicationDefaults {
+        check_execution: CheckExecutionConfig {
+            order: vec![CheckKind::Title],
+            short_circuit_on_failure: true,
+            report_only: Vec::new(),
+            concurrent: false,
+            diff_source: DiffSource::PullRequest,
+        },
+        ..Default::default()
+    };
+
+    let config =
+        load_merge_warden_config("owner", "repo", CONFIG_FILE_PATH, &fetcher, &app_defaults)
+            .await
+            .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert_eq!(
+        validation.check_execution.order,
+        vec![CheckKind::Checklist, CheckKind::ReviewThreads]
+    );
+    assert!(validation.check_execution.short_circuit_on_failure);
+}
+
+#[test]
+fn test_dependency_update_config_merge() {
+    let base = DependencyUpdateConfig {
+        enabled: true,
+        label: "deps".to_string(),
+        ..Default::default()
+    };
+
+    let merged = DependencyUpdateConfig::merge(&base, &DependencyUpdateConfig::default());
+    assert_eq!(merged, base);
+
+    let over = DependencyUpdateConfig {
+        title_scopes: vec!["dependencies".to_string()],
+        require_only_dependency_files: false,
+        ..Default::default()
+    };
+    let merged = DependencyUpdateConfig::merge(&base, &over);
+    assert!(merged.enabled);
+    assert_eq!(merged.label, "deps");
+    assert_eq!(merged.title_scopes, vec!["dependencies"]);
+    assert!(!merged.require_only_dependency_files);
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_dependency_updates() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.dependencyUpdates]
+        enabled = true
+        lockfile_patterns = ["vendor.lock"]
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+    let app_defaults = ApplicationDefaults {
+        dependency_updates: DependencyUpdateConfig {
+            label: "area: dependencies".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let config =
+        load_merge_warden_config("owner", "repo", CONFIG_FILE_PATH, &fetcher, &app_defaults)
+            .await
+            .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert!(validation.dependency_updates.enabled);
+    assert_eq!(validation.dependency_updates.label, "area: dependencies");
+    assert_eq!(
+        validation.dependency_updates.lockfile_patterns,
+        vec!["vendor.lock"]
+    );
+}
+
+#[test]
+fn test_check_execution_config_report_only() {
+    let config: CheckExecutionConfig =
+        toml::from_str(r#"report_only = ["size", "deleted_files"]"#).unwrap();
+    assert!(config.is_report_only(CheckKind::Size));
+    assert!(config.is_report_only(CheckKind::DeletedFiles));
+    assert!(!config.is_report_only(CheckKind::Title));
+
+    let merged = CheckExecutionConfig::merge(&config, &CheckExecutionConfig::default());
+    assert_eq!(merged.report_only, config.report_only);
+
+    let over = CheckExecutionConfig {
+        report_only: vec![CheckKind::Checklist],
+        ..Default::default()
+    };
+    let merged = CheckExecutionConfig::merge(&config, &over);
+    assert_eq!(merged.report_only, vec![CheckKind::Checklist]);
+}
+
+#[test]
+fn test_title_character_policy_config_merge() {
+    let base = TitleCharacterPolicyConfig {
+        enabled: true,
+        max_emoji: Some(2),
+        disallowed_characters: "<>".to_string(),
+    };
+
+    let merged = TitleCharacterPolicyConfig::merge(&base, &TitleCharacterPolicyConfig::default());
+    assert_eq!(merged, base);
+
+    let over = TitleCharacterPolicyConfig {
+        enabled: false,
+        max_emoji: Some(0),
+        disallowed_characters: String::new(),
+    };
+    let merged = TitleCharacterPolicyConfig::merge(&base, &over);
+    assert!(merged.enabled);
+    assert_eq!(merged.max_emoji, Some(0));
+    assert_eq!(merged.disallowed_characters, "<>");
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_title_characters() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.titleCharacters]
+        enabled = true
+        max_emoji = 0
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+    let app_defaults = ApplicationDefaults::default();
+
+    let config =
+        load_merge_warden_config("owner", "repo", CONFIG_FILE_PATH, &fetcher, &app_defaults)
+            .await
+            .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert!(validation.title_characters.enabled);
+    assert_eq!(validation.title_characters.max_emoji, Some(0));
+}
+
+#[test]
+fn test_title_lint_policy_config_merge() {
+    let base = TitleLintPolicyConfig {
+        enabled: true,
+        subject_case: Some(SubjectCase::Lower),
+        forbid_trailing_period: true,
+        header_max_length: Some(72),
+        allowed_types: vec!["feat".to_string(), "fix".to_string()],
+    };
+
+    let merged = TitleLintPolicyConfig::merge(&base, &TitleLintPolicyConfig::default());
+    assert_eq!(merged, base);
+
+    let over = TitleLintPolicyConfig {
+        enabled: false,
+        subject_case: Some(SubjectCase::Sentence),
+        forbid_trailing_period: false,
+        header_max_length: Some(100),
+        allowed_types: vec!["docs".to_string()],
+    };
+    let merged = TitleLintPolicyConfig::merge(&base, &over);
+    assert!(merged.enabled);
+    assert!(merged.forbid_trailing_period);
+    assert_eq!(merged.subject_case, Some(SubjectCase::Sentence));
+    assert_eq!(merged.header_max_length, Some(100));
+    assert_eq!(merged.allowed_types, vec!["docs"]);
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_title_lint() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.titleLint]
+        enabled = true
+        subject_case = "sentence"
+        allowed_types = ["feat", "fix"]
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+    let app_defaults = ApplicationDefaults {
+        title_lint: TitleLintPolicyConfig {
+            forbid_trailing_period: true,
+            header_max_length: Some(72),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let config =
+        load_merge_warden_config("owner", "repo", CONFIG_FILE_PATH, &fetcher, &app_defaults)
+            .await
+            .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert!(validation.title_lint.enabled);
+    assert_eq!(
+        validation.title_lint.subject_case,
+        Some(SubjectCase::Sentence)
+    );
+    assert_eq!(validation.title_lint.allowed_types, vec!["feat", "fix"]);
+    // Rules the repository does not set keep the application defaults
+    assert!(validation.title_lint.forbid_trailing_period);
+    assert_eq!(validation.title_lint.header_max_length, Some(72));
+}
+
+// ============================================================
+// SuspiciousFilePolicyConfig
+// ============================================================
+
+#[test]
+fn test_suspicious_file_policy_config_parses_from_repository_toml() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.suspiciousFiles]
+        enabled = true
+        severity = "error"
+        max_file_lines = 2000
+        secret_patterns = ["*.pem", "secrets/*"]
+    "#;
+
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let suspicious_files = &config.policies.pull_requests.suspicious_files;
+
+    assert!(suspicious_files.enabled);
+    assert!(suspicious_files.fails_check());
+    assert_eq!(suspicious_files.max_file_lines, 2000);
+    assert_eq!(suspicious_files.secret_patterns, vec!["*.pem", "secrets/*"]);
+    assert_eq!(
+        suspicious_files.binary_patterns,
+        SuspiciousFilePolicyConfig::default().binary_patterns
+    );
+}
+
+#[test]
+fn test_suspicious_file_policy_config_matches_file_names_in_any_directory() {
+    let config = SuspiciousFilePolicyConfig {
+        secret_patterns: vec![".env".to_string(), "secrets/*".to_string()],
+        ..Default::default()
+    };
+
+    let secret_files = config.secret_files();
+
+    assert_eq!(secret_files.excluding_pattern(".env"), Some(".env"));
+    assert_eq!(
+        secret_files.excluding_pattern("services/api/.env"),
+        Some(".env")
+    );
+    assert_eq!(
+        secret_files.excluding_pattern("secrets/token.txt"),
+        Some("secrets/*")
+    );
+    // Patterns with a `/` are anchored to the repository root
+    assert_eq!(
+        secret_files.excluding_pattern("app/secrets/token.txt"),
+        None
+    );
+    assert_eq!(secret_files.excluding_pattern(".env.example"), None);
+    assert_eq!(
+        config
+            .binary_files()
+            .excluding_pattern("vendor/lib/libfoo.so"),
+        Some("*.so")
+    );
+}
+
+#[test]
+fn test_suspicious_file_policy_config_uses_gitignore_semantics() {
+    let config = SuspiciousFilePolicyConfig {
+        secret_patterns: vec![
+            "**/keys/**".to_string(),
+            "*.env*".to_string(),
+            "!*.env.example".to_string(),
+        ],
+        ..Default::default()
+    };
+    let secret_files = config.secret_files();
+
+    assert_eq!(
+        secret_files.excluding_pattern("deploy/keys/prod/signing.txt"),
+        Some("**/keys/**")
+    );
+    assert_eq!(
+        secret_files.excluding_pattern("app/.env.local"),
+        Some("*.env*")
+    );
+    assert_eq!(secret_files.excluding_pattern("app/.env.example"), None);
+}
+
+#[test]
+fn test_suspicious_file_policy_config_merge() {
+    let base = SuspiciousFilePolicyConfig {
+        enabled: true,
+        severity: CheckSeverity::Error,
+        max_file_lines: 1000,
+        secret_patterns: vec!["*.pem".to_string()],
+        ..Default::default()
+    };
+
+    let merged = SuspiciousFilePolicyConfig::merge(&base, &SuspiciousFilePolicyConfig::default());
+    assert_eq!(merged, base);
+
+    let over = SuspiciousFilePolicyConfig {
+        enabled: false,
+        severity: CheckSeverity::Notice,
+        max_file_lines: 0,
+        binary_patterns: vec!["*.wasm".to_string()],
+        ..Default::default()
+    };
+    let merged = SuspiciousFilePolicyConfig::merge(&base, &over);
+    assert!(merged.enabled);
+    assert_eq!(merged.severity, CheckSeverity::Notice);
+    assert_eq!(merged.max_file_lines, 0);
+    assert_eq!(merged.secret_patterns, vec!["*.pem"]);
+    assert_eq!(merged.binary_patterns, vec!["*.wasm"]);
+}
+
+#[tokio::test]
+async fn test_load_config_propagates_suspicious_files() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.suspiciousFiles]
+        enabled = true
+    "#;
+    let fetcher = MockFetcher::new(Some(toml_content.to_string()));
+    let app_defaults = ApplicationDefaults {
+        suspicious_files: SuspiciousFilePolicyConfig {
+            enabled: false,
+            max_file_lines: 1000,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let config =
+        load_merge_warden_config("owner", "repo", CONFIG_FILE_PATH, &fetcher, &app_defaults)
+            .await
+            .unwrap();
+    let validation = config.to_validation_config(&BypassRules::default());
+
+    assert!(validation.suspicious_files.enabled);
+    assert_eq!(validation.suspicious_files.max_file_lines, 1000);
+}
+
+// ============================================================
+// ReopenedPolicyConfig
+// ============================================================
+
+#[test]
+fn test_reopened_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.reopened]
+        reset_artifacts = true
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    assert!(config.policies.pull_requests.reopened.reset_artifacts);
+
+    let enabled = ReopenedPolicyConfig {
+        reset_artifacts: true,
+    };
+    let merged = ReopenedPolicyConfig::merge(&enabled, &ReopenedPolicyConfig::default());
+    assert!(merged.reset_artifacts);
+}
+
+// ============================================================
+// StatusLabelPolicyConfig
+// ============================================================
+
+#[test]
+fn test_status_label_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.statusLabels]
+        remove_on_pass = false
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    assert!(!config.policies.pull_requests.status_labels.remove_on_pass);
+
+    let keep = StatusLabelPolicyConfig {
+        remove_on_pass: false,
+    };
+    let default = StatusLabelPolicyConfig::default();
+    assert!(default.remove_on_pass);
+    assert!(!StatusLabelPolicyConfig::merge(&keep, &default).remove_on_pass);
+    assert!(!StatusLabelPolicyConfig::merge(&default, &keep).remove_on_pass);
+    assert!(StatusLabelPolicyConfig::merge(&default, &default).remove_on_pass);
+}
+
+// ============================================================
+// CommentPolicyConfig
+// ============================================================
+
+#[test]
+fn test_comment_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.comments]
+        threaded = true
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    assert!(config.policies.pull_requests.comments.threaded);
+
+    let threaded = CommentPolicyConfig {
+        threaded: true,
+        ..Default::default()
+    };
+    let merged = CommentPolicyConfig::merge(&threaded, &CommentPolicyConfig::default());
+    assert!(merged.threaded);
+}
+
+#[test]
+fn test_comment_policy_config_transition_summary_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.comments]
+        transition_summary = true
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    assert!(config.policies.pull_requests.comments.transition_summary);
+
+    let enabled = CommentPolicyConfig {
+        transition_summary: true,
+        ..Default::default()
+    };
+    // A repository cannot turn off a summary the server enabled.
+    let merged = CommentPolicyConfig::merge(&enabled, &CommentPolicyConfig::default());
+    assert!(merged.transition_summary);
+    let merged = CommentPolicyConfig::merge(&CommentPolicyConfig::default(), &enabled);
+    assert!(merged.transition_summary);
+}
+
+#[test]
+fn test_comment_policy_config_bypass_notices_parse_and_merge() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.comments]
+        title_bypass_notice = "Title check skipped for {{ user }}."
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let comments = &config.policies.pull_requests.comments;
+    assert_eq!(
+        comments.title_bypass_notice.as_deref(),
+        Some("Title check skipped for {{ user }}.")
+    );
+    assert!(comments.work_item_bypass_notice.is_none());
+
+    let base = CommentPolicyConfig {
+        title_bypass_notice: Some("base title".to_string()),
+        work_item_bypass_notice: Some("base work item".to_string()),
+        ..Default::default()
+    };
+    let merged = CommentPolicyConfig::merge(&base, comments);
+    assert_eq!(
+        merged.title_bypass_notice.as_deref(),
+        Some("Title check skipped for {{ user }}.")
+    );
+    assert_eq!(
+        merged.work_item_bypass_notice.as_deref(),
+        Some("base work item")
+    );
+}
+
+#[test]
+fn test_comment_policy_config_comment_templates_parse_and_merge() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.comments]
+        missing_work_item_comment_template = "Link an issue matching {{ pattern }}."
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let comments = &config.policies.pull_requests.comments;
+    assert_eq!(
+        comments.missing_work_item_comment_template.as_deref(),
+        Some("Link an issue matching {{ pattern }}.")
+    );
+    assert!(comments.title_invalid_comment_template.is_none());
+
+    let base = CommentPolicyConfig {
+        title_invalid_comment_template: Some("base title".to_string()),
+        missing_work_item_comment_template: Some("base work item".to_string()),
+        ..Default::default()
+    };
+    let merged = CommentPolicyConfig::merge(&base, comments);
+    assert_eq!(
+        merged.title_invalid_comment_template.as_deref(),
+        Some("base title")
+    );
+    assert_eq!(
+        merged.missing_work_item_comment_template.as_deref(),
+        Some("Link an issue matching {{ pattern }}.")
+    );
+}
+
+#[test]
+fn test_comment_policy_config_contributor_guidance_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.comments.contributor_guidance.internal]
+        invalid_title = "Fix the title."
+
+        [policies.pullRequests.comments.contributor_guidance.external]
+        missing_work_item = "Please link an issue."
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let guidance = &config.policies.pull_requests.comments.contributor_guidance;
+    assert!(guidance.is_enabled());
+    assert_eq!(
+        guidance.internal.invalid_title.as_deref(),
+        Some("Fix the title.")
+    );
+    assert!(guidance.internal.missing_work_item.is_none());
+    assert_eq!(
+        guidance.external.missing_work_item.as_deref(),
+        Some("Please link an issue.")
+    );
+    assert!(!CommentPolicyConfig::default()
+        .contributor_guidance
+        .is_enabled());
+
+    let base = CommentPolicyConfig {
+        contributor_guidance: ContributorGuidanceConfig {
+            internal: GuidanceTemplates {
+                invalid_title: Some("base title".to_string()),
+                missing_work_item: Some("base work item".to_string()),
+            },
+            external: GuidanceTemplates::default(),
+        },
+        ..Default::default()
+    };
+    let merged = CommentPolicyConfig::merge(&base, &config.policies.pull_requests.comments);
+    let guidance = &merged.contributor_guidance;
+    assert_eq!(
+        guidance.internal.invalid_title.as_deref(),
+        Some("Fix the title.")
+    );
+    assert_eq!(
+        guidance.internal.missing_work_item.as_deref(),
+        Some("base work item")
+    );
+    assert_eq!(
+        guidance.external.missing_work_item.as_deref(),
+        Some("Please link an issue.")
+    );
+    assert!(guidance.external.invalid_title.is_none());
+}
+
+// ============================================================
+// BaseBranchPolicyConfig
+// ============================================================
+
+#[test]
+fn test_base_branch_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.baseBranch]
+        enabled = true
+
+        [[policies.pullRequests.baseBranch.rules]]
+        title_types = ["hotfix"]
+        branch_prefixes = ["hotfix/"]
+        allowed_bases = ["release/*"]
+
+        [[policies.pullRequests.baseBranch.rules]]
+        title_types = ["feat"]
+        allowed_bases = ["develop"]
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let base_branch = &config.policies.pull_requests.base_branch;
+
+    assert!(base_branch.enabled);
+    assert!(base_branch.fails_check());
+    assert_eq!(base_branch.rules.len(), 2);
+    assert_eq!(
+        base_branch
+            .rule_for(Some("HOTFIX"), "")
+            .unwrap()
+            .allowed_bases,
+        vec!["release/*"]
+    );
+    assert_eq!(
+        base_branch
+            .rule_for(None, "hotfix/crash")
+            .unwrap()
+            .allowed_bases,
+        vec!["release/*"]
+    );
+    assert_eq!(
+        base_branch
+            .rule_for(Some("feat"), "login")
+            .unwrap()
+            .allowed_bases,
+        vec!["develop"]
+    );
+    assert!(base_branch.rule_for(Some("fix"), "login").is_none());
+
+    let merged = BaseBranchPolicyConfig::merge(base_branch, &BaseBranchPolicyConfig::default());
+    assert!(merged.enabled);
+    assert_eq!(merged.rules, base_branch.rules);
+}
+
+#[test]
+fn test_base_branch_rule_allowed_bases_match_the_whole_branch_name() {
+    let rule = BaseBranchRule {
+        allowed_bases: vec!["release/*".to_string(), "main".to_string()],
+        ..Default::default()
+    };
+
+    assert!(rule.allows_base("release/1.2"));
+    // `*` also matches `/`
+    assert!(rule.allows_base("release/1.x/hotfix"));
+    assert!(!rule.allows_base("release"));
+    assert!(!rule.allows_base("old/release/1.2"));
+    assert!(rule.allows_base("main"));
+    assert!(!rule.allows_base("main-next"));
+}
+
+// ============================================================
+// ExclusiveLabelsPolicyConfig
+// ============================================================
+
+#[test]
+fn test_exclusive_labels_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.exclusiveLabels]
+        enabled = true
+        severity = "warning"
+
+        [[policies.pullRequests.exclusiveLabels.groups]]
+        name = "priority"
+        labels = ["priority: *"]
+
+        [[policies.pullRequests.exclusiveLabels.groups]]
+        name = "release"
+        labels = ["major", "minor", "patch"]
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let exclusive = &config.policies.pull_requests.exclusive_labels;
+
+    assert!(exclusive.enabled);
+    assert!(!exclusive.fails_check());
+    assert_eq!(exclusive.groups.len(), 2);
+    assert!(exclusive.groups[0].contains("Priority: High"));
+    assert!(exclusive.groups[1].contains("MINOR"));
+    assert!(!exclusive.groups[1].contains("minor-fix"));
+
+    let merged =
+        ExclusiveLabelsPolicyConfig::merge(&ExclusiveLabelsPolicyConfig::default(), exclusive);
+    assert!(merged.enabled);
+    assert_eq!(merged.severity, CheckSeverity::Warning);
+    assert_eq!(merged.groups, exclusive.groups);
+}
+
+#[test]
+fn test_revert_reference_policy_config_parses_with_its_bypass_rule() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.revertReference]
+        enabled = true
+
+        [policies.bypassRules.revert_reference]
+        enabled = true
+        users = ["release-manager"]
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    assert!(config.policies.pull_requests.revert_reference.enabled);
+
+    let server_rules = BypassRules::default()
+        .with_signed_commits(BypassRule::new(true, vec!["release-bot".to_string()]));
+    let validation = config.to_validation_config(&server_rules);
+    assert!(validation.revert_reference.enabled);
+    assert_eq!(
+        validation.bypass_rules.revert_reference().users(),
+        vec!["release-manager"]
+    );
+    // Rules the repository does not set keep the server defaults.
+    assert_eq!(
+        validation.bypass_rules.signed_commits().users(),
+        vec!["release-bot"]
+    );
+
+    // A repository cannot turn off a check the server enabled.
+    let merged = RevertReferencePolicyConfig::merge(
+        &RevertReferencePolicyConfig { enabled: true },
+        &RevertReferencePolicyConfig::default(),
+    );
+    assert!(merged.enabled);
+}
+
+#[test]
+fn test_label_creation_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.labelCreation]
+        create_missing_labels = false
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    assert!(
+        !config
+            .policies
+            .pull_requests
+            .label_creation
+            .create_missing_labels
+    );
+    let validation = config.to_validation_config(&BypassRules::default());
+    assert!(!validation.label_creation.create_missing_labels);
+
+    // Labels are created unless a repository opts out.
+    let default_config: RepositoryProvidedConfig = toml::from_str("schemaVersion = 1").unwrap();
+    assert!(
+        default_config
+            .policies
+            .pull_requests
+            .label_creation
+            .create_missing_labels
+    );
+
+    // Once any tier disables label creation, it stays disabled.
+    let disabled = LabelCreationPolicyConfig {
+        create_missing_labels: false,
+    };
+    let merged = LabelCreationPolicyConfig::merge(&disabled, &LabelCreationPolicyConfig::default());
+    assert!(!merged.create_missing_labels);
+    let merged = LabelCreationPolicyConfig::merge(&LabelCreationPolicyConfig::default(), &disabled);
+    assert!(!merged.create_missing_labels);
+}
+
+#[test]
+fn test_issue_reference_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.issueReferences]
+        enforcement = "warn"
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    assert_eq!(
+        config.policies.pull_requests.issue_references.enforcement,
+        IssueReferenceEnforcement::Warn
+    );
+    let validation = config.to_validation_config(&BypassRules::default());
+    assert_eq!(
+        validation.issue_references.enforcement,
+        IssueReferenceEnforcement::Warn
+    );
+
+    // References are not looked up unless a repository opts in.
+    let default_config: RepositoryProvidedConfig = toml::from_str("schemaVersion = 1").unwrap();
+    assert_eq!(
+        default_config
+            .policies
+            .pull_requests
+            .issue_references
+            .enforcement,
+        IssueReferenceEnforcement::Off
+    );
+
+    // The stricter tier wins.
+    let fail = IssueReferencePolicyConfig {
+        enforcement: IssueReferenceEnforcement::Fail,
+    };
+    let warn = IssueReferencePolicyConfig {
+        enforcement: IssueReferenceEnforcement::Warn,
+    };
+    let merged = IssueReferencePolicyConfig::merge(&fail, &warn);
+    assert_eq!(merged.enforcement, IssueReferenceEnforcement::Fail);
+    let merged = IssueReferencePolicyConfig::merge(&IssueReferencePolicyConfig::default(), &warn);
+    assert_eq!(merged.enforcement, IssueReferenceEnforcement::Warn);
+}
+
+#[test]
+fn test_up_to_date_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.upToDate]
+        enabled = true
+        label = "stale-base"
+        behind_by_threshold = 5
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let up_to_date = &config.policies.pull_requests.up_to_date;
+    assert!(up_to_date.enabled);
+    assert_eq!(up_to_date.label, "stale-base");
+    assert_eq!(up_to_date.behind_by_threshold, 5);
+    assert_eq!(up_to_date.severity, CheckSeverity::Warning);
+    let validation = config.to_validation_config(&BypassRules::default());
+    assert_eq!(validation.up_to_date, *up_to_date);
+
+    // Repository settings override the server's, and unset fields keep them.
+    let server = UpToDatePolicyConfig {
+        enabled: true,
+        label: "rebase-me".to_string(),
+        behind_by_threshold: 10,
+        severity: CheckSeverity::Error,
+    };
+    let repo = UpToDatePolicyConfig {
+        behind_by_threshold: 3,
+        ..UpToDatePolicyConfig::default()
+    };
+    let merged = UpToDatePolicyConfig::merge(&server, &repo);
+    assert!(merged.enabled);
+    assert_eq!(merged.label, "rebase-me");
+    assert_eq!(merged.behind_by_threshold, 3);
+    assert!(merged.fails_check());
+}
+
+#[test]
+fn test_footer_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.footers]
+        enabled = true
+
+        [[policies.pullRequests.footers.required]]
+        token = "Signed-off-by"
+        pattern = "<.+@.+>"
+
+        [[policies.pullRequests.footers.required]]
+        token = "Reviewed-by"
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let footers = &config.policies.pull_requests.footers;
+    assert!(footers.enabled);
+    assert_eq!(
+        footers.required,
+        vec![
+            RequiredFooter {
+                token: "Signed-off-by".to_string(),
+                pattern: Some("<.+@.+>".to_string()),
+            },
+            RequiredFooter {
+                token: "Reviewed-by".to_string(),
+                pattern: None,
+            },
+        ]
+    );
+    let validation = config.to_validation_config(&BypassRules::default());
+    assert_eq!(validation.footers, *footers);
+
+    // The repository's footers replace the server's; an empty list keeps them.
+    let server = FooterPolicyConfig {
+        enabled: true,
+        required: vec![RequiredFooter {
+            token: "Signed-off-by".to_string(),
+            pattern: None,
+        }],
+    };
+    let merged = FooterPolicyConfig::merge(&server, &FooterPolicyConfig::default());
+    assert_eq!(merged, server);
+    let merged = FooterPolicyConfig::merge(&server, footers);
+    assert!(merged.enabled);
+    assert_eq!(merged.required, footers.required);
+}
+
+#[test]
+fn test_draft_conversion_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.draftConversion]
+        enabled = true
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    assert!(config.policies.pull_requests.draft_conversion.enabled);
+    let validation = config.to_validation_config(&BypassRules::default());
+    assert!(validation.draft_conversion.enabled);
+
+    let app: ApplicationDefaults = toml::from_str("[draft_conversion]\nenabled = true").unwrap();
+    assert!(app.draft_conversion.enabled);
+
+    let enabled = DraftConversionPolicyConfig { enabled: true };
+    let disabled = DraftConversionPolicyConfig::default();
+    assert!(DraftConversionPolicyConfig::merge(&enabled, &disabled).enabled);
+    assert!(DraftConversionPolicyConfig::merge(&disabled, &enabled).enabled);
+    assert!(!DraftConversionPolicyConfig::merge(&disabled, &disabled).enabled);
+}
+
+#[test]
+fn test_descriptive_title_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.descriptiveTitle]
+        enabled = true
+        severity = "warning"
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let descriptive_title = &config.policies.pull_requests.descriptive_title;
+    assert!(descriptive_title.enabled);
+    assert_eq!(descriptive_title.severity, CheckSeverity::Warning);
+    assert!(!descriptive_title.fails_check());
+    let validation = config.to_validation_config(&BypassRules::default());
+    assert_eq!(&validation.descriptive_title, descriptive_title);
+
+    let app: ApplicationDefaults = toml::from_str("[descriptive_title]\nenabled = true").unwrap();
+    assert!(app.descriptive_title.enabled);
+    assert_eq!(app.descriptive_title.severity, CheckSeverity::Error);
+
+    let merged = DescriptiveTitlePolicyConfig::merge(
+        descriptive_title,
+        &DescriptiveTitlePolicyConfig::default(),
+    );
+    assert!(merged.enabled);
+    assert_eq!(merged.severity, CheckSeverity::Warning);
+
+    let merged = DescriptiveTitlePolicyConfig::merge(
+        &DescriptiveTitlePolicyConfig::default(),
+        descriptive_title,
+    );
+    assert!(merged.enabled);
+    assert_eq!(merged.severity, CheckSeverity::Warning);
+}
+
+#[test]
+fn test_change_types_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.changeTypes]
+        enabled = true
+        severity = "error"
+        max_change_types = 3
+        ignored_types = ["test", "docs"]
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let change_types = &config.policies.pull_requests.change_types;
+    assert!(change_types.enabled);
+    assert!(change_types.fails_check());
+    assert_eq!(change_types.max_change_types, 3);
+    assert!(change_types.is_ignored("Docs"));
+    let validation = config.to_validation_config(&BypassRules::default());
+    assert_eq!(&validation.change_types, change_types);
+
+    let app: ApplicationDefaults = toml::from_str("[change_types]\nenabled = true").unwrap();
+    assert!(app.change_types.enabled);
+    assert_eq!(app.change_types.severity, CheckSeverity::Warning);
+    assert_eq!(app.change_types.max_change_types, 2);
+
+    let merged = ChangeTypesPolicyConfig::merge(&app.change_types, change_types);
+    assert_eq!(merged.severity, CheckSeverity::Error);
+    assert_eq!(merged.max_change_types, 3);
+    assert_eq!(merged.ignored_types, vec!["test", "docs"]);
+
+    let merged = ChangeTypesPolicyConfig::merge(change_types, &app.change_types);
+    assert!(merged.enabled);
+    assert_eq!(merged.severity, CheckSeverity::Error);
+    assert_eq!(merged.max_change_types, 3);
+    assert_eq!(merged.ignored_types, vec!["test", "docs"]);
+}
+
+#[test]
+fn test_label_policy_config_parses_and_merges() {
+    let toml_content = r#"
+        schemaVersion = 1
+
+        [policies.pullRequests.labelPolicy]
+        enabled = true
+        severity = "warning"
+        required_labels = ["team: *"]
+        forbidden_labels = ["do-not-merge"]
+    "#;
+    let config: RepositoryProvidedConfig = toml::from_str(toml_content).unwrap();
+    let label_policy = &config.policies.pull_requests.label_policy;
+    assert!(label_policy.enabled);
+    assert!(!label_policy.fails_check());
+    assert!(label_policy.is_forbidden("Do-Not-Merge"));
+    let validation = config.to_validation_config(&BypassRules::default());
+    assert_eq!(&validation.label_policy, label_policy);
+
+    let app: ApplicationDefaults =
+        toml::from_str("[label_policy]\nforbidden_labels = [\"wip\"]").unwrap();
+    assert!(!app.label_policy.enabled);
+    assert_eq!(app.label_policy.severity, CheckSeverity::Error);
+    assert!(app.label_policy.required_labels.is_empty());
+
+    let merged = LabelPolicyConfig::merge(&app.label_policy, label_policy);
+    assert!(merged.enabled);
+    assert_eq!(merged.severity, CheckSeverity::Warning);
+    assert_eq!(merged.required_labels, vec!["team: *"]);
+    assert_eq!(merged.forbidden_labels, vec!["do-not-merge"]);
+
+    let repo = LabelPolicyConfig {
+        required_labels: vec!["reviewed".to_string()],
+        ..Default::default()
+    };
+    let merged = LabelPolicyConfig::merge(&app.label_policy, &repo);
+    assert_eq!(merged.required_labels, vec!["reviewed"]);
+    assert_eq!(merged.forbidden_labels, vec!["wip"]);
+}
+
+#[test]
+fn test_lint_config_content_reports_invalid_footer_patterns() {
+    let content = r#"
+schemaVersion = 1
+
+[[policies.pullRequests.footers.required]]
+token = "Signed-off-by"
+pattern = "<.+@.+>"
+
+[[policies.pullRequests.footers.required]]
+token = "Reviewed-by"
+pattern = "(unclosed"
+"#;
+    let outcome = lint_config_content(content);
+    assert!(!outcome.valid);
+    assert_eq!(outcome.errors.len(), 1, "errors: {:?}", outcome.errors);
+    assert!(outcome.errors[0].starts_with(
+        "policies.pullRequests.footers.required[1].pattern: invalid regular expression `(unclosed`"
+    ));
+}
@@ -0,0 +1,329 @@
+//! # Decision Log
+//!
+//! This module provides [`DecisionLog`], a structured record of what Merge Warden
+//! decided during one run on a pull request: the configuration it used, the pull
+//! request it saw, the outcome of each check, the labels and comments it changed and
+//! the check run it reported.
+//!
+//! [`MergeWarden::process_pull_request_with_decision_log`] returns the log alongside
+//! the [`CheckResult`]. Unlike the tracing logs, the decision log has no timestamps
+//! and lists its entries in a fixed order, so two runs that make the same decisions
+//! produce the same log. [`DecisionLog::to_json`] serializes it for attaching to a
+//! support ticket.
+//!
+//! ## Serialization format
+//!
+//! ```json
+//! {
+//!   "format_version": 1,
+//!   "repository": "owner/repo",
+//!   "pull_request": 42,
+//!   "config_source": "Config: repo .github/merge-warden.toml + application defaults",
+//!   "input": {
+//!     "title": "add login",
+//!     "body": "Fixes #12",
+//!     "author": "octocat",
+//!     "draft": false,
+//!     "base_ref": "main",
+//!     "head_ref": "feature/login",
+//!     "head_sha": "abc123"
+//!   },
+//!   "checks": [
+//!     { "check": "PR title", "status": "FAIL", "message": "Title does not follow the Conventional Commits format" }
+//!   ],
+//!   "labels_added": ["invalid-title-format"],
+//!   "labels_removed": [],
+//!   "comments_posted": ["<!-- PR_TITLE_CHECK -->..."],
+//!   "comments_deleted": [],
+//!   "check_run": { "conclusion": "failure", "title": "Merge Warden", "summary": "..." }
+//! }
+//! ```
+//!
+//! - `checks` - one entry per row of the check summary table, in table order, with
+//!   the plain-text status word of [`CheckStatus::icon`](crate::summary::CheckStatus::icon)
+//! - `labels_added`, `labels_removed` - the labels that differ between the start and
+//!   the end of the run, sorted by name
+//! - `comments_posted`, `comments_deleted` - the bodies of the top-level comments that
+//!   differ between the start and the end of the run, in the order the platform lists
+//!   them
+//! - `check_run` - the last check run reported, or `null` when none was
+//!
+//! [`MergeWarden::process_pull_request_with_decision_log`]: crate::MergeWarden::process_pull_request_with_decision_log
+//! [`CheckResult`]: crate::CheckResult
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use merge_warden_developer_platforms::models::{Comment, Label, PullRequest};
+use serde::Serialize;
+
+use crate::config::ConfigSource;
+use crate::summary::CheckSummaryRow;
+use crate::PullRequestTarget;
+
+#[cfg(test)]
+#[path = "decision_log_tests.rs"]
+mod tests;
+
+/// The version of the serialization format written by [`DecisionLog::to_json`].
+pub const DECISION_LOG_FORMAT_VERSION: u32 = 1;
+
+/// A structured record of the decisions made during one run on a pull request.
+///
+/// See the [module docs](self) for the serialized form.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::ConfigSource;
+/// use merge_warden_core::decision_log::DecisionLog;
+///
+/// let log = DecisionLog::new(("owner", "repo", 42), &ConfigSource::default());
+/// assert_eq!(log.repository, "owner/repo");
+/// assert_eq!(log.config_source, "Config: application defaults");
+/// assert!(log.checks.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecisionLog {
+    /// [`DECISION_LOG_FORMAT_VERSION`] at the time the log was written.
+    pub format_version: u32,
+
+    /// The repository, as `owner/repo`.
+    pub repository: String,
+
+    /// The pull request number.
+    pub pull_request: u64,
+
+    /// The configuration layers the run used, as displayed by [`ConfigSource`].
+    pub config_source: String,
+
+    /// The pull request as the platform reported it, or `None` when it could not be
+    /// fetched.
+    pub input: Option<DecisionInput>,
+
+    /// The outcome of each check, in the order of the check summary table. Empty when
+    /// the run stopped before the checks, for example because the PR is WIP.
+    pub checks: Vec<CheckDecision>,
+
+    /// The labels the run added, sorted by name.
+    pub labels_added: Vec<String>,
+
+    /// The labels the run removed, sorted by name.
+    pub labels_removed: Vec<String>,
+
+    /// The bodies of the top-level comments the run posted.
+    pub comments_posted: Vec<String>,
+
+    /// The bodies of the top-level comments the run deleted.
+    pub comments_deleted: Vec<String>,
+
+    /// The last check run the run reported, or `None` when it reported none.
+    pub check_run: Option<CheckRunDecision>,
+}
+
+impl DecisionLog {
+    /// Creates an empty log for a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The pull request the log is for
+    /// * `config_source` - The configuration layers the run uses
+    pub fn new(target: impl Into<PullRequestTarget>, config_source: &ConfigSource) -> Self {
+        let target = target.into();
+        Self {
+            format_version: DECISION_LOG_FORMAT_VERSION,
+            repository: format!("{}/{}", target.owner, target.repo),
+            pull_request: target.pr_number,
+            config_source: config_source.to_string(),
+            input: None,
+            checks: Vec::new(),
+            labels_added: Vec::new(),
+            labels_removed: Vec::new(),
+            comments_posted: Vec::new(),
+            comments_deleted: Vec::new(),
+            check_run: None,
+        }
+    }
+
+    /// The conclusion of the reported check run, or `None` when none was reported.
+    pub fn conclusion(&self) -> Option<&str> {
+        self.check_run.as_ref().map(|c| c.conclusion.as_str())
+    }
+
+    /// Records the labels that differ between `before` and `after` the run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::ConfigSource;
+    /// use merge_warden_core::decision_log::DecisionLog;
+    /// use merge_warden_developer_platforms::models::Label;
+    ///
+    /// let label = |name: &str| Label { name: name.to_string(), description: None, color: None };
+    /// let mut log = DecisionLog::new(("owner", "repo", 42), &ConfigSource::default());
+    /// log.record_label_changes(&[label("size: S")], &[label("size: M"), label("bug")]);
+    ///
+    /// assert_eq!(log.labels_added, vec!["bug", "size: M"]);
+    /// assert_eq!(log.labels_removed, vec!["size: S"]);
+    /// ```
+    pub fn record_label_changes(&mut self, before: &[Label], after: &[Label]) {
+        let difference = |from: &[Label], to: &[Label]| {
+            let mut names: Vec<String> = from
+                .iter()
+                .filter(|label| !to.iter().any(|other| other.name == label.name))
+                .map(|label| label.name.clone())
+                .collect();
+            names.sort();
+            names.dedup();
+            names
+        };
+        self.labels_added = difference(after, before);
+        self.labels_removed = difference(before, after);
+    }
+
+    /// Records the comments that differ between `before` and `after` the run, matching
+    /// comments by their ID.
+    pub fn record_comment_changes(&mut self, before: &[Comment], after: &[Comment]) {
+        let difference = |from: &[Comment], to: &[Comment]| {
+            from.iter()
+                .filter(|comment| !to.iter().any(|other| other.id == comment.id))
+                .map(|comment| comment.body.clone())
+                .collect()
+        };
+        self.comments_posted = difference(after, before);
+        self.comments_deleted = difference(before, after);
+    }
+
+    /// Records the rows of the check summary table as the outcome of each check.
+    pub fn record_checks(&mut self, rows: &[CheckSummaryRow]) {
+        self.checks = rows.iter().map(CheckDecision::from).collect();
+    }
+
+    /// Serializes the log in the format described in the [module docs](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns the `serde_json` error if the log cannot be serialized.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// The pull request a run evaluated, as the platform reported it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecisionInput {
+    /// The title of the PR.
+    pub title: String,
+
+    /// The description of the PR, or `None` when it is empty.
+    pub body: Option<String>,
+
+    /// The login of the PR author, or `None` when the platform reported no author.
+    pub author: Option<String>,
+
+    /// Whether the PR is a draft.
+    pub draft: bool,
+
+    /// The branch the PR targets.
+    pub base_ref: String,
+
+    /// The branch the PR merges from.
+    pub head_ref: String,
+
+    /// The SHA of the PR's head commit.
+    pub head_sha: String,
+}
+
+impl From<&PullRequest> for DecisionInput {
+    fn from(pr: &PullRequest) -> Self {
+        Self {
+            title: pr.title.clone(),
+            body: pr.body.clone(),
+            author: pr.author.as_ref().map(|author| author.login.clone()),
+            draft: pr.draft,
+            base_ref: pr.base_ref.clone(),
+            head_ref: pr.head_ref.clone(),
+            head_sha: pr.head_sha.clone(),
+        }
+    }
+}
+
+/// The outcome of one check, as shown in the check summary table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckDecision {
+    /// The name of the check, e.g. `"PR title"`.
+    pub check: String,
+
+    /// The plain-text status word: `PASS`, `FAIL`, `WARN`, `NOTE`, `BYPASSED` or
+    /// `SKIPPED`.
+    pub status: String,
+
+    /// The explanation of the outcome, including the reason a check was skipped.
+    pub message: String,
+}
+
+impl From<&CheckSummaryRow> for CheckDecision {
+    fn from(row: &CheckSummaryRow) -> Self {
+        Self {
+            check: row.name.clone(),
+            status: row.status.icon(false).to_string(),
+            message: row.message.clone(),
+        }
+    }
+}
+
+/// A check run reported for the pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckRunDecision {
+    /// The conclusion, e.g. `success`, `failure` or `neutral`.
+    pub conclusion: String,
+
+    /// The title of the check run.
+    pub title: String,
+
+    /// The summary of the check run.
+    pub summary: String,
+}
+
+/// Collects the decision logs of the runs that asked for one.
+///
+/// A log is kept per pull request between [`DecisionRecorder::start`] and
+/// [`DecisionRecorder::finish`], so that runs on other pull requests, which share the
+/// same `MergeWarden`, are not recorded. Recording for a pull request without a log
+/// does nothing.
+#[derive(Debug, Default)]
+pub(crate) struct DecisionRecorder {
+    /// The logs being recorded, keyed by pull request.
+    logs: Mutex<HashMap<PullRequestTarget, DecisionLog>>,
+}
+
+impl DecisionRecorder {
+    /// Starts recording a log for a pull request, replacing any log being recorded.
+    pub(crate) fn start(&self, target: PullRequestTarget, config_source: &ConfigSource) {
+        let log = DecisionLog::new(target.clone(), config_source);
+        if let Ok(mut logs) = self.logs.lock() {
+            logs.insert(target, log);
+        }
+    }
+
+    /// Applies `update` to the log of a pull request, when one is being recorded.
+    pub(crate) fn record(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        update: impl FnOnce(&mut DecisionLog),
+    ) {
+        let target = PullRequestTarget::from((repo_owner, repo_name, pr_number));
+        if let Ok(mut logs) = self.logs.lock() {
+            if let Some(log) = logs.get_mut(&target) {
+                update(log);
+            }
+        }
+    }
+
+    /// Stops recording the log of a pull request and returns it.
+    pub(crate) fn finish(&self, target: &PullRequestTarget) -> Option<DecisionLog> {
+        self.logs.lock().ok()?.remove(target)
+    }
+}
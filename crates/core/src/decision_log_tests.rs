@@ -0,0 +1,143 @@
+use super::*;
+use crate::config::CheckSeverity;
+use crate::summary::CheckStatus;
+use merge_warden_developer_platforms::models::User;
+
+fn label(name: &str) -> Label {
+    Label {
+        name: name.to_string(),
+        description: None,
+        color: None,
+    }
+}
+
+fn comment(id: u64, body: &str) -> Comment {
+    Comment {
+        id,
+        body: body.to_string(),
+        user: User {
+            id: 1,
+            login: "merge-warden[bot]".to_string(),
+        },
+    }
+}
+
+#[test]
+fn test_new_log_is_empty() {
+    let log = DecisionLog::new(("owner", "repo", 42), &ConfigSource::default());
+
+    assert_eq!(log.format_version, DECISION_LOG_FORMAT_VERSION);
+    assert_eq!(log.pull_request, 42);
+    assert!(log.input.is_none());
+    assert!(log.labels_added.is_empty());
+    assert!(log.comments_posted.is_empty());
+    assert_eq!(log.conclusion(), None);
+}
+
+#[test]
+fn test_record_label_changes_ignores_unchanged_labels() {
+    let mut log = DecisionLog::new(("owner", "repo", 42), &ConfigSource::default());
+
+    log.record_label_changes(
+        &[label("feature"), label("size: S")],
+        &[label("size: M"), label("feature"), label("size: M")],
+    );
+
+    assert_eq!(log.labels_added, vec!["size: M"]);
+    assert_eq!(log.labels_removed, vec!["size: S"]);
+}
+
+#[test]
+fn test_record_comment_changes_matches_comments_by_id() {
+    let mut log = DecisionLog::new(("owner", "repo", 42), &ConfigSource::default());
+
+    log.record_comment_changes(
+        &[comment(1, "kept"), comment(2, "old title comment")],
+        &[
+            comment(1, "kept"),
+            comment(3, "old title comment"),
+            comment(4, "size comment"),
+        ],
+    );
+
+    assert_eq!(
+        log.comments_posted,
+        vec!["old title comment", "size comment"]
+    );
+    assert_eq!(log.comments_deleted, vec!["old title comment"]);
+}
+
+#[test]
+fn test_record_checks_uses_plain_status_words() {
+    let mut log = DecisionLog::new(("owner", "repo", 42), &ConfigSource::default());
+
+    log.record_checks(&[
+        CheckSummaryRow::new("PR title", CheckStatus::Passed, "ok"),
+        CheckSummaryRow::new(
+            "Work item",
+            CheckStatus::Failed(CheckSeverity::Error),
+            "missing",
+        ),
+        CheckSummaryRow::new(
+            "PR size",
+            CheckStatus::Failed(CheckSeverity::Warning),
+            "large",
+        ),
+        CheckSummaryRow::new("Checklist", CheckStatus::Bypassed, "bypassed"),
+        CheckSummaryRow::new("Footers", CheckStatus::Skipped, "not enabled"),
+    ]);
+
+    let statuses: Vec<&str> = log.checks.iter().map(|c| c.status.as_str()).collect();
+    assert_eq!(
+        statuses,
+        vec!["PASS", "FAIL", "WARN", "BYPASSED", "SKIPPED"]
+    );
+    assert_eq!(log.checks[1].check, "Work item");
+    assert_eq!(log.checks[1].message, "missing");
+}
+
+#[test]
+fn test_to_json_writes_every_field() {
+    let mut log = DecisionLog::new(("owner", "repo", 42), &ConfigSource::default());
+    log.labels_added.push("bug".to_string());
+    log.check_run = Some(CheckRunDecision {
+        conclusion: "success".to_string(),
+        title: "Merge Warden".to_string(),
+        summary: "All checks passed".to_string(),
+    });
+
+    let json: serde_json::Value = serde_json::from_str(&log.to_json().unwrap()).unwrap();
+
+    assert_eq!(json["format_version"], DECISION_LOG_FORMAT_VERSION);
+    assert_eq!(json["repository"], "owner/repo");
+    assert_eq!(json["pull_request"], 42);
+    assert_eq!(json["config_source"], "Config: application defaults");
+    assert!(json["input"].is_null());
+    assert_eq!(json["checks"], serde_json::json!([]));
+    assert_eq!(json["labels_added"], serde_json::json!(["bug"]));
+    assert_eq!(json["labels_removed"], serde_json::json!([]));
+    assert_eq!(json["comments_posted"], serde_json::json!([]));
+    assert_eq!(json["comments_deleted"], serde_json::json!([]));
+    assert_eq!(json["check_run"]["conclusion"], "success");
+}
+
+#[test]
+fn test_recorder_only_records_started_pull_requests() {
+    let recorder = DecisionRecorder::default();
+    let target = PullRequestTarget::from(("owner", "repo", 1));
+    recorder.start(target.clone(), &ConfigSource::default());
+
+    recorder.record("owner", "repo", 1, |log| {
+        log.labels_added.push("bug".to_string())
+    });
+    recorder.record("owner", "repo", 2, |log| {
+        log.labels_added.push("feature".to_string())
+    });
+
+    let log = recorder.finish(&target).unwrap();
+    assert_eq!(log.labels_added, vec!["bug"]);
+    assert!(recorder
+        .finish(&PullRequestTarget::from(("owner", "repo", 2)))
+        .is_none());
+    assert!(recorder.finish(&target).is_none());
+}
@@ -0,0 +1,899 @@
+//! # Dry Run
+//!
+//! This module provides [`PlannedOperation`], a change Merge Warden would have made to
+//! a pull request during a dry run, and the provider wrapper that plans those changes
+//! instead of applying them.
+//!
+//! [`MergeWarden::process_pull_request_dry_run`] validates a pull request as usual,
+//! but every label, comment, check run and pull request update is recorded rather than
+//! sent to the platform. The planned operations are returned in
+//! [`CheckResult::planned_operations`], in the order they would have been applied.
+//!
+//! Reads still go to the platform, so later steps of a dry run see the pull request as
+//! it is, not as the planned operations would leave it. A label that is planned to be
+//! added is, for example, not listed as applied when the run next lists the labels.
+//!
+//! The state store is read but not written during a dry run, so a dry run does not
+//! keep a later run from commenting on a conclusion change or converting the pull
+//! request to a draft.
+//!
+//! [`MergeWarden::process_pull_request_dry_run`]: crate::MergeWarden::process_pull_request_dry_run
+//! [`CheckResult::planned_operations`]: crate::CheckResult::planned_operations
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use merge_warden_developer_platforms::errors::Error;
+use merge_warden_developer_platforms::models::{
+    AuthorAssociation, CheckRunAction, Comment, CommitStatus, CommitVerification, Issue, Label,
+    PullRequest, PullRequestCommit, PullRequestFile, PullRequestFilePatch, PullRequestSummary,
+    RateLimitStatus, Review, ReviewThread, Team, User,
+};
+use merge_warden_developer_platforms::{ConfigFetcher, PullRequestProvider};
+use serde::Serialize;
+
+use crate::PullRequestTarget;
+
+#[cfg(test)]
+#[path = "dry_run_tests.rs"]
+mod tests;
+
+/// A change to a pull request that a dry run planned instead of applying.
+///
+/// The [`Display`](fmt::Display) form is the line the `check-pr --dry-run` command
+/// prints for the operation.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::dry_run::PlannedOperation;
+///
+/// let operation = PlannedOperation::AddLabels {
+///     labels: vec!["feature".to_string(), "size: S".to_string()],
+/// };
+/// assert_eq!(operation.to_string(), "add labels: feature, size: S");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+pub enum PlannedOperation {
+    /// Add labels to the pull request.
+    AddLabels {
+        /// The labels to add.
+        labels: Vec<String>,
+    },
+
+    /// Remove a label from the pull request.
+    RemoveLabel {
+        /// The label to remove.
+        label: String,
+    },
+
+    /// Create a label in the repository, before adding it to the pull request.
+    CreateLabel {
+        /// The name of the label.
+        name: String,
+    },
+
+    /// Post a comment on the pull request.
+    AddComment {
+        /// The comment posted on, for a reply in a comment thread.
+        in_reply_to: Option<u64>,
+
+        /// The body of the comment.
+        body: String,
+    },
+
+    /// Delete a comment from the pull request.
+    DeleteComment {
+        /// The ID of the comment.
+        comment_id: u64,
+    },
+
+    /// Create or update a check run on the pull request's head commit.
+    UpdateCheckRun {
+        /// The name of the check run.
+        name: String,
+
+        /// The conclusion, e.g. `success`, `failure` or `neutral`.
+        conclusion: String,
+
+        /// The title of the check run.
+        title: String,
+
+        /// The summary of the check run.
+        summary: String,
+    },
+
+    /// Change the title or description of the pull request.
+    UpdatePullRequest {
+        /// The new title, or `None` to keep the title.
+        title: Option<String>,
+
+        /// The new description, or `None` to keep the description.
+        body: Option<String>,
+    },
+
+    /// Convert the pull request to a draft.
+    ConvertToDraft,
+
+    /// Set or clear the milestone of the pull request.
+    SetMilestone {
+        /// The milestone number, or `None` to clear the milestone.
+        milestone: Option<u64>,
+    },
+
+    /// Add the pull request to a project.
+    AddToProject {
+        /// The number of the project.
+        project_number: u64,
+
+        /// The login of the organization or user that owns the project.
+        project_owner: String,
+    },
+}
+
+impl fmt::Display for PlannedOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddLabels { labels } => write!(f, "add labels: {}", labels.join(", ")),
+            Self::RemoveLabel { label } => write!(f, "remove label: {label}"),
+            Self::CreateLabel { name } => write!(f, "create label: {name}"),
+            Self::AddComment {
+                in_reply_to: None,
+                body,
+            } => write!(f, "add comment:\n{}", indent(body)),
+            Self::AddComment {
+                in_reply_to: Some(id),
+                body,
+            } => write!(f, "reply to comment {id}:\n{}", indent(body)),
+            Self::DeleteComment { comment_id } => write!(f, "delete comment {comment_id}"),
+            Self::UpdateCheckRun {
+                name,
+                conclusion,
+                title,
+                summary,
+            } => write!(
+                f,
+                "update check run '{name}': {conclusion} ({title})\n{}",
+                indent(summary)
+            ),
+            Self::UpdatePullRequest { title, body } => {
+                write!(f, "update pull request:")?;
+                if let Some(title) = title {
+                    write!(f, "\n    title: {title}")?;
+                }
+                if let Some(body) = body {
+                    write!(f, "\n    description:\n{}", indent(&indent(body)))?;
+                }
+                Ok(())
+            }
+            Self::ConvertToDraft => write!(f, "convert to draft"),
+            Self::SetMilestone {
+                milestone: Some(number),
+            } => write!(f, "set milestone: {number}"),
+            Self::SetMilestone { milestone: None } => write!(f, "clear milestone"),
+            Self::AddToProject {
+                project_number,
+                project_owner,
+            } => write!(f, "add to project: {project_owner}/{project_number}"),
+        }
+    }
+}
+
+/// Indents every line of `text` by four spaces.
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("    {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collects the operations planned by the dry runs in progress.
+///
+/// Operations are kept per pull request between [`DryRunRecorder::start`] and
+/// [`DryRunRecorder::finish`], so that runs on other pull requests, which share the
+/// same `MergeWarden`, still apply their changes.
+#[derive(Debug, Default)]
+pub(crate) struct DryRunRecorder {
+    /// The operations planned so far, keyed by pull request.
+    state: Mutex<DryRunState>,
+}
+
+/// The mutable state of a [`DryRunRecorder`].
+#[derive(Debug, Default)]
+struct DryRunState {
+    /// The operations planned so far, keyed by pull request.
+    plans: HashMap<PullRequestTarget, Vec<PlannedOperation>>,
+
+    /// The pull request of each comment listed during a dry run, keyed by repository
+    /// and comment ID, so that a planned comment deletion can be attributed to it.
+    comments: HashMap<(String, String, u64), PullRequestTarget>,
+}
+
+impl DryRunRecorder {
+    /// Starts a dry run for a pull request, discarding any operations planned for it.
+    pub(crate) fn start(&self, target: PullRequestTarget) {
+        if let Ok(mut state) = self.state.lock() {
+            state.plans.insert(target, Vec::new());
+        }
+    }
+
+    /// Returns `true` when a dry run is in progress for a pull request.
+    pub(crate) fn is_planning(&self, repo_owner: &str, repo_name: &str, pr_number: u64) -> bool {
+        let target = PullRequestTarget::from((repo_owner, repo_name, pr_number));
+        self.state
+            .lock()
+            .map(|state| state.plans.contains_key(&target))
+            .unwrap_or(false)
+    }
+
+    /// Records `operation` for a pull request when a dry run is in progress for it.
+    ///
+    /// Returns `true` when the operation was planned, in which case it must not be
+    /// applied.
+    pub(crate) fn plan(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        operation: PlannedOperation,
+    ) -> bool {
+        let target = PullRequestTarget::from((repo_owner, repo_name, pr_number));
+        match self.state.lock() {
+            Ok(mut state) => match state.plans.get_mut(&target) {
+                Some(plan) => {
+                    plan.push(operation);
+                    true
+                }
+                None => false,
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Records `operation` for every dry run in progress in a repository, for changes
+    /// to the repository rather than to one pull request.
+    ///
+    /// Returns `true` when the operation was planned.
+    fn plan_for_repository(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        operation: PlannedOperation,
+    ) -> bool {
+        let Ok(mut state) = self.state.lock() else {
+            return false;
+        };
+        let mut planned = false;
+        for (target, plan) in state.plans.iter_mut() {
+            if target.owner == repo_owner && target.repo == repo_name {
+                plan.push(operation.clone());
+                planned = true;
+            }
+        }
+        planned
+    }
+
+    /// Records the deletion of a comment listed during a dry run on its pull request.
+    ///
+    /// Returns `true` when the deletion was planned.
+    fn plan_comment_deletion(&self, repo_owner: &str, repo_name: &str, comment_id: u64) -> bool {
+        let Ok(mut state) = self.state.lock() else {
+            return false;
+        };
+        let key = (repo_owner.to_string(), repo_name.to_string(), comment_id);
+        let Some(target) = state.comments.get(&key).cloned() else {
+            return false;
+        };
+        match state.plans.get_mut(&target) {
+            Some(plan) => {
+                plan.push(PlannedOperation::DeleteComment { comment_id });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remembers the pull request of comments listed during a dry run on it.
+    fn note_comments(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        comments: &[Comment],
+    ) {
+        let target = PullRequestTarget::from((repo_owner, repo_name, pr_number));
+        if let Ok(mut state) = self.state.lock() {
+            if !state.plans.contains_key(&target) {
+                return;
+            }
+            for comment in comments {
+                state.comments.insert(
+                    (repo_owner.to_string(), repo_name.to_string(), comment.id),
+                    target.clone(),
+                );
+            }
+        }
+    }
+
+    /// Ends the dry run for a pull request and returns the operations it planned.
+    pub(crate) fn finish(&self, target: &PullRequestTarget) -> Vec<PlannedOperation> {
+        let Ok(mut state) = self.state.lock() else {
+            return Vec::new();
+        };
+        state.comments.retain(|_, owner| owner != target);
+        state.plans.remove(target).unwrap_or_default()
+    }
+}
+
+/// A provider that plans the changes of the dry runs in progress instead of applying
+/// them, and passes every other call on to the wrapped provider.
+#[derive(Debug)]
+pub(crate) struct DryRunProvider<'a, P> {
+    /// The provider calls are passed on to.
+    inner: &'a P,
+
+    /// The dry runs in progress.
+    recorder: &'a DryRunRecorder,
+}
+
+impl<'a, P> DryRunProvider<'a, P> {
+    /// Wraps `inner`, planning the changes of the dry runs in `recorder`.
+    pub(crate) fn new(inner: &'a P, recorder: &'a DryRunRecorder) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+#[async_trait]
+impl<P: ConfigFetcher> ConfigFetcher for DryRunProvider<'_, P> {
+    async fn fetch_config(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        path: &str,
+    ) -> Result<Option<String>, Error> {
+        self.inner.fetch_config(repo_owner, repo_name, path).await
+    }
+
+    async fn fetch_config_at_ref(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        path: &str,
+        git_ref: &str,
+    ) -> Result<Option<String>, Error> {
+        self.inner
+            .fetch_config_at_ref(repo_owner, repo_name, path, git_ref)
+            .await
+    }
+}
+
+#[async_trait]
+impl<P: PullRequestProvider + Sync> PullRequestProvider for DryRunProvider<'_, P> {
+    async fn add_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        comment: &str,
+    ) -> Result<(), Error> {
+        let operation = PlannedOperation::AddComment {
+            in_reply_to: None,
+            body: comment.to_string(),
+        };
+        if self
+            .recorder
+            .plan(repo_owner, repo_name, pr_number, operation)
+        {
+            return Ok(());
+        }
+        self.inner
+            .add_comment(repo_owner, repo_name, pr_number, comment)
+            .await
+    }
+
+    async fn add_labels(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        labels: &[String],
+    ) -> Result<(), Error> {
+        let operation = PlannedOperation::AddLabels {
+            labels: labels.to_vec(),
+        };
+        if self
+            .recorder
+            .plan(repo_owner, repo_name, pr_number, operation)
+        {
+            return Ok(());
+        }
+        self.inner
+            .add_labels(repo_owner, repo_name, pr_number, labels)
+            .await
+    }
+
+    async fn delete_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        comment_id: u64,
+    ) -> Result<(), Error> {
+        if self
+            .recorder
+            .plan_comment_deletion(repo_owner, repo_name, comment_id)
+        {
+            return Ok(());
+        }
+        self.inner
+            .delete_comment(repo_owner, repo_name, comment_id)
+            .await
+    }
+
+    async fn get_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<PullRequest, Error> {
+        self.inner
+            .get_pull_request(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn get_pull_request_files(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<PullRequestFile>, Error> {
+        self.inner
+            .get_pull_request_files(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn list_applied_labels(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Label>, Error> {
+        self.inner
+            .list_applied_labels(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn list_available_labels(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+    ) -> Result<Vec<Label>, Error> {
+        self.inner
+            .list_available_labels(repo_owner, repo_name)
+            .await
+    }
+
+    async fn list_comments(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Comment>, Error> {
+        let comments = self
+            .inner
+            .list_comments(repo_owner, repo_name, pr_number)
+            .await?;
+        self.recorder
+            .note_comments(repo_owner, repo_name, pr_number, &comments);
+        Ok(comments)
+    }
+
+    async fn remove_label(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        label: &str,
+    ) -> Result<(), Error> {
+        let operation = PlannedOperation::RemoveLabel {
+            label: label.to_string(),
+        };
+        if self
+            .recorder
+            .plan(repo_owner, repo_name, pr_number, operation)
+        {
+            return Ok(());
+        }
+        self.inner
+            .remove_label(repo_owner, repo_name, pr_number, label)
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn update_pr_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+        actions: &[CheckRunAction],
+    ) -> Result<(), Error> {
+        let operation = PlannedOperation::UpdateCheckRun {
+            name: merge_warden_developer_platforms::DEFAULT_CHECK_RUN_NAME.to_string(),
+            conclusion: conclusion.to_string(),
+            title: output_title.to_string(),
+            summary: output_summary.to_string(),
+        };
+        if self
+            .recorder
+            .plan(repo_owner, repo_name, pr_number, operation)
+        {
+            return Ok(());
+        }
+        self.inner
+            .update_pr_check_status(
+                repo_owner,
+                repo_name,
+                pr_number,
+                conclusion,
+                output_title,
+                output_summary,
+                output_text,
+                actions,
+            )
+            .await
+    }
+
+    async fn list_pr_reviews(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Review>, Error> {
+        self.inner
+            .list_pr_reviews(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn get_commit_statuses(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<CommitStatus>, Error> {
+        self.inner
+            .get_commit_statuses(repo_owner, repo_name, commit_sha)
+            .await
+    }
+
+    async fn find_pull_requests_for_commit(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<u64>, Error> {
+        self.inner
+            .find_pull_requests_for_commit(repo_owner, repo_name, commit_sha)
+            .await
+    }
+
+    async fn create_label(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        name: &str,
+        color: &str,
+        description: Option<&str>,
+    ) -> Result<(), Error> {
+        let operation = PlannedOperation::CreateLabel {
+            name: name.to_string(),
+        };
+        if self
+            .recorder
+            .plan_for_repository(repo_owner, repo_name, operation)
+        {
+            return Ok(());
+        }
+        self.inner
+            .create_label(repo_owner, repo_name, name, color, description)
+            .await
+    }
+
+    async fn list_review_threads(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<ReviewThread>, Error> {
+        self.inner
+            .list_review_threads(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn find_label_applier(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        label: &str,
+    ) -> Result<Option<User>, Error> {
+        self.inner
+            .find_label_applier(repo_owner, repo_name, pr_number, label)
+            .await
+    }
+
+    async fn get_comparison_files(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<PullRequestFile>, Error> {
+        self.inner
+            .get_comparison_files(repo_owner, repo_name, base, head)
+            .await
+    }
+
+    async fn get_commits_behind(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<u64, Error> {
+        self.inner
+            .get_commits_behind(repo_owner, repo_name, base, head)
+            .await
+    }
+
+    async fn list_pull_requests(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<PullRequestSummary>, Error> {
+        self.inner
+            .list_pull_requests(repo_owner, repo_name, since)
+            .await
+    }
+
+    async fn convert_to_draft(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<(), Error> {
+        let operation = PlannedOperation::ConvertToDraft;
+        if self
+            .recorder
+            .plan(repo_owner, repo_name, pr_number, operation)
+        {
+            return Ok(());
+        }
+        self.inner
+            .convert_to_draft(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn update_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), Error> {
+        let operation = PlannedOperation::UpdatePullRequest {
+            title: title.map(str::to_string),
+            body: body.map(str::to_string),
+        };
+        if self
+            .recorder
+            .plan(repo_owner, repo_name, pr_number, operation)
+        {
+            return Ok(());
+        }
+        self.inner
+            .update_pull_request(repo_owner, repo_name, pr_number, title, body)
+            .await
+    }
+
+    async fn list_teams(&self, org: &str) -> Result<Vec<Team>, Error> {
+        self.inner.list_teams(org).await
+    }
+
+    async fn get_team_members(&self, org: &str, team: &str) -> Result<Vec<User>, Error> {
+        self.inner.get_team_members(org, team).await
+    }
+
+    async fn get_author_association(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<AuthorAssociation, Error> {
+        self.inner
+            .get_author_association(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn get_pull_request_file_patches(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<PullRequestFilePatch>, Error> {
+        self.inner
+            .get_pull_request_file_patches(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn update_named_pr_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        check_name: &str,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+        actions: &[CheckRunAction],
+    ) -> Result<(), Error> {
+        let operation = PlannedOperation::UpdateCheckRun {
+            name: check_name.to_string(),
+            conclusion: conclusion.to_string(),
+            title: output_title.to_string(),
+            summary: output_summary.to_string(),
+        };
+        if self
+            .recorder
+            .plan(repo_owner, repo_name, pr_number, operation)
+        {
+            return Ok(());
+        }
+        self.inner
+            .update_named_pr_check_status(
+                repo_owner,
+                repo_name,
+                pr_number,
+                check_name,
+                conclusion,
+                output_title,
+                output_summary,
+                output_text,
+                actions,
+            )
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn update_commit_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        head_sha: &str,
+        check_name: &str,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+    ) -> Result<(), Error> {
+        self.inner
+            .update_commit_check_status(
+                repo_owner,
+                repo_name,
+                head_sha,
+                check_name,
+                conclusion,
+                output_title,
+                output_summary,
+                output_text,
+            )
+            .await
+    }
+
+    async fn get_rate_limit_status(&self) -> Result<RateLimitStatus, Error> {
+        self.inner.get_rate_limit_status().await
+    }
+
+    async fn list_threaded_comments(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Comment>, Error> {
+        let comments = self
+            .inner
+            .list_threaded_comments(repo_owner, repo_name, pr_number)
+            .await?;
+        self.recorder
+            .note_comments(repo_owner, repo_name, pr_number, &comments);
+        Ok(comments)
+    }
+
+    async fn add_threaded_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        in_reply_to: Option<u64>,
+        comment: &str,
+    ) -> Result<Comment, Error> {
+        let operation = PlannedOperation::AddComment {
+            in_reply_to,
+            body: comment.to_string(),
+        };
+        if self
+            .recorder
+            .plan(repo_owner, repo_name, pr_number, operation)
+        {
+            // The comment was not posted, so it has no ID; replies to it are planned
+            // as replies to comment 0.
+            return Ok(Comment {
+                id: 0,
+                body: comment.to_string(),
+                user: User::default(),
+            });
+        }
+        self.inner
+            .add_threaded_comment(repo_owner, repo_name, pr_number, in_reply_to, comment)
+            .await
+    }
+
+    async fn delete_threaded_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        comment_id: u64,
+    ) -> Result<(), Error> {
+        if self
+            .recorder
+            .plan_comment_deletion(repo_owner, repo_name, comment_id)
+        {
+            return Ok(());
+        }
+        self.inner
+            .delete_threaded_comment(repo_owner, repo_name, comment_id)
+            .await
+    }
+
+    async fn list_commit_verifications(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<CommitVerification>, Error> {
+        self.inner
+            .list_commit_verifications(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn list_pull_request_commits(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<PullRequestCommit>, Error> {
+        self.inner
+            .list_pull_request_commits(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    async fn get_issue(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        issue_number: u64,
+    ) -> Result<Option<Issue>, Error> {
+        self.inner
+            .get_issue(repo_owner, repo_name, issue_number)
+            .await
+    }
+}
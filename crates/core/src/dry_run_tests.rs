@@ -0,0 +1,179 @@
+use super::*;
+
+fn comment(id: u64) -> Comment {
+    Comment {
+        id,
+        body: format!("comment {id}"),
+        user: User::default(),
+    }
+}
+
+fn target(pr_number: u64) -> PullRequestTarget {
+    PullRequestTarget::from(("owner", "repo", pr_number))
+}
+
+#[test]
+fn test_plan_without_dry_run_is_not_planned() {
+    let recorder = DryRunRecorder::default();
+
+    let planned = recorder.plan("owner", "repo", 1, PlannedOperation::ConvertToDraft);
+
+    assert!(!planned);
+    assert!(recorder.finish(&target(1)).is_empty());
+}
+
+#[test]
+fn test_plan_records_operations_in_order() {
+    let recorder = DryRunRecorder::default();
+    recorder.start(target(1));
+
+    assert!(recorder.plan(
+        "owner",
+        "repo",
+        1,
+        PlannedOperation::AddLabels {
+            labels: vec!["feature".to_string()],
+        },
+    ));
+    assert!(recorder.plan("owner", "repo", 1, PlannedOperation::ConvertToDraft));
+
+    assert_eq!(
+        recorder.finish(&target(1)),
+        vec![
+            PlannedOperation::AddLabels {
+                labels: vec!["feature".to_string()],
+            },
+            PlannedOperation::ConvertToDraft,
+        ]
+    );
+}
+
+#[test]
+fn test_plan_ignores_other_pull_requests() {
+    let recorder = DryRunRecorder::default();
+    recorder.start(target(1));
+
+    let planned = recorder.plan("owner", "repo", 2, PlannedOperation::ConvertToDraft);
+
+    assert!(!planned);
+    assert!(recorder.finish(&target(1)).is_empty());
+}
+
+#[test]
+fn test_finish_ends_the_dry_run() {
+    let recorder = DryRunRecorder::default();
+    recorder.start(target(1));
+    recorder.finish(&target(1));
+
+    let planned = recorder.plan("owner", "repo", 1, PlannedOperation::ConvertToDraft);
+
+    assert!(!planned);
+}
+
+#[test]
+fn test_is_planning_only_during_the_dry_run_of_the_pull_request() {
+    let recorder = DryRunRecorder::default();
+    assert!(!recorder.is_planning("owner", "repo", 1));
+
+    recorder.start(target(1));
+    assert!(recorder.is_planning("owner", "repo", 1));
+    assert!(!recorder.is_planning("owner", "repo", 2));
+
+    recorder.finish(&target(1));
+    assert!(!recorder.is_planning("owner", "repo", 1));
+}
+
+#[test]
+fn test_comment_deletion_is_planned_for_the_pull_request_that_listed_it() {
+    let recorder = DryRunRecorder::default();
+    recorder.start(target(1));
+    recorder.start(target(2));
+    recorder.note_comments("owner", "repo", 2, &[comment(20)]);
+
+    assert!(recorder.plan_comment_deletion("owner", "repo", 20));
+    assert!(!recorder.plan_comment_deletion("owner", "repo", 30));
+
+    assert!(recorder.finish(&target(1)).is_empty());
+    assert_eq!(
+        recorder.finish(&target(2)),
+        vec![PlannedOperation::DeleteComment { comment_id: 20 }]
+    );
+}
+
+#[test]
+fn test_comment_deletion_without_dry_run_is_not_planned() {
+    let recorder = DryRunRecorder::default();
+    recorder.note_comments("owner", "repo", 1, &[comment(10)]);
+
+    assert!(!recorder.plan_comment_deletion("owner", "repo", 10));
+}
+
+#[test]
+fn test_label_creation_is_planned_for_every_dry_run_in_the_repository() {
+    let recorder = DryRunRecorder::default();
+    recorder.start(target(1));
+    recorder.start(PullRequestTarget::from(("owner", "other", 1)));
+    let operation = PlannedOperation::CreateLabel {
+        name: "dependencies".to_string(),
+    };
+
+    assert!(recorder.plan_for_repository("owner", "repo", operation.clone()));
+
+    assert_eq!(recorder.finish(&target(1)), vec![operation]);
+    assert!(recorder
+        .finish(&PullRequestTarget::from(("owner", "other", 1)))
+        .is_empty());
+}
+
+#[test]
+fn test_display_indents_comment_bodies() {
+    let operation = PlannedOperation::AddComment {
+        in_reply_to: None,
+        body: "<!-- PR_TITLE_CHECK -->\nThe title is invalid.".to_string(),
+    };
+
+    assert_eq!(
+        operation.to_string(),
+        "add comment:\n    <!-- PR_TITLE_CHECK -->\n    The title is invalid."
+    );
+}
+
+#[test]
+fn test_display_check_run_update() {
+    let operation = PlannedOperation::UpdateCheckRun {
+        name: "MergeWarden".to_string(),
+        conclusion: "failure".to_string(),
+        title: "Merge Warden".to_string(),
+        summary: "1 check failed".to_string(),
+    };
+
+    assert_eq!(
+        operation.to_string(),
+        "update check run 'MergeWarden': failure (Merge Warden)\n    1 check failed"
+    );
+}
+
+#[test]
+fn test_display_pull_request_update_lists_changed_fields() {
+    let operation = PlannedOperation::UpdatePullRequest {
+        title: Some("feat: add login".to_string()),
+        body: None,
+    };
+
+    assert_eq!(
+        operation.to_string(),
+        "update pull request:\n    title: feat: add login"
+    );
+}
+
+#[test]
+fn test_serializes_with_operation_tag() {
+    let operation = PlannedOperation::RemoveLabel {
+        label: "size: S".to_string(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&operation).unwrap(),
+        serde_json::json!({ "operation": "remove_label", "label": "size: S" })
+    );
+}
@@ -40,6 +40,23 @@ pub enum ConfigLoadError {
     InvalidRepositoryScopePattern(String),
 }
 
+/// Errors that can occur when reading or writing pull request state through a
+/// [`crate::state::StateStore`]
+#[derive(Debug, Error)]
+pub enum StateStoreError {
+    /// The state could not be serialized or deserialized
+    #[error("Failed to serialize state: {0}")]
+    Serialization(String),
+
+    /// The stored state was written with a newer serialization format
+    #[error("Unsupported state format version: {0}")]
+    UnsupportedFormatVersion(u32),
+
+    /// The backend could not be reached or rejected the request
+    #[error("State store backend error: {0}")]
+    Backend(String),
+}
+
 /// Main error type for Merge Warden operations.
 ///
 /// This enum represents all possible errors that can occur during
@@ -89,3 +106,74 @@ pub enum MergeWardenError {
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
+
+/// Errors that can occur when registering or rendering a comment template.
+///
+/// Returned by [`crate::templates::CommentRenderer`] implementations. Merge Warden
+/// never propagates these errors out of `process_pull_request`; a failed render
+/// falls back to the built-in default template and the failure is logged.
+///
+/// # Examples
+///
+/// ```rust
+/// use merge_warden_core::errors::TemplateError;
+///
+/// let error = TemplateError::Syntax {
+///     template: "oversized_pr_comment.md".to_string(),
+///     message: "unexpected end of input".to_string(),
+/// };
+/// assert!(error.to_string().contains("oversized_pr_comment.md"));
+/// ```
+#[derive(Error, Debug)]
+pub enum TemplateError {
+    /// The template source could not be parsed
+    #[error("Invalid template '{template}': {message}")]
+    Syntax {
+        /// The name of the template that failed to parse
+        template: String,
+        /// A description of the syntax problem
+        message: String,
+    },
+
+    /// The template was parsed but rendering it against the context failed
+    #[error("Failed to render template '{template}': {message}")]
+    Render {
+        /// The name of the template that failed to render
+        template: String,
+        /// A description of the rendering problem
+        message: String,
+    },
+}
+
+/// Errors that can occur when reading a version from a manifest file.
+///
+/// Returned by [`crate::version::VersionManifest`] implementations. The version
+/// bump check reports these as a skipped check rather than failing the PR.
+///
+/// # Examples
+///
+/// ```rust
+/// use merge_warden_core::errors::VersionError;
+///
+/// let error = VersionError::InvalidVersion("1.2".to_string());
+/// assert_eq!(error.to_string(), "Invalid semantic version '1.2'");
+/// ```
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum VersionError {
+    /// The text is not a `MAJOR.MINOR.PATCH` version
+    #[error("Invalid semantic version '{0}'")]
+    InvalidVersion(String),
+
+    /// The manifest does not declare a version
+    #[error("No version found in {0}")]
+    MissingVersion(String),
+
+    /// The manifest content could not be parsed
+    #[error("Failed to parse {manifest}: {message}")]
+    Parse {
+        /// The name of the manifest format
+        manifest: String,
+        /// A description of the parse failure
+        message: String,
+    },
+}
@@ -0,0 +1,376 @@
+//! # Explain
+//!
+//! Evaluates a PR title and description against the configuration without a
+//! developer platform, so that contributors can check them locally before pushing.
+//!
+//! [`explain`] runs the title, work item and WIP checks and renders the same
+//! guidance comments Merge Warden posts on a pull request. The work item check only
+//! matches the configured patterns; it does not look up the referenced issue.
+//! Bypass rules never apply because there is no PR author.
+
+use merge_warden_developer_platforms::models::PullRequest;
+
+use crate::checks::{
+    self, DanglingIssueReference, TitleValidationResult, WorkItemValidationResult,
+};
+use crate::config::{CurrentPullRequestValidationConfiguration, WipCheckConfig};
+use crate::templates::{
+    render_comment, single_brace_placeholders, CommentRenderer, CommentTemplate,
+    InvalidTitleCommentContext, MissingWorkItemCommentContext, TemplateEngineRenderer,
+    TITLE_COMMENT_EXAMPLES, WORK_ITEM_COMMENT_EXAMPLES,
+};
+use tracing::warn;
+
+#[cfg(test)]
+#[path = "explain_tests.rs"]
+mod tests;
+
+/// The outcome of evaluating a title and description with [`explain`].
+///
+/// A check that is disabled in the configuration is `None`. Guidance is only
+/// present for a failing check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    /// The title check outcome, or `None` when title validation is disabled.
+    pub title: Option<TitleValidationResult>,
+
+    /// The invalid-title comment, present when the title check fails.
+    pub title_guidance: Option<String>,
+
+    /// The work item check outcome, or `None` when work item validation is disabled.
+    pub work_item: Option<WorkItemValidationResult>,
+
+    /// The missing-work-item comment, present when the work item check fails.
+    pub work_item_guidance: Option<String>,
+
+    /// Whether the PR would be treated as WIP, or `None` when WIP blocking is disabled.
+    pub wip_detected: Option<bool>,
+
+    /// The WIP comment, present when the PR would be treated as WIP.
+    pub wip_guidance: Option<String>,
+}
+
+impl Explanation {
+    /// Returns `true` when every enabled check passes.
+    pub fn is_passing(&self) -> bool {
+        self.title
+            .as_ref()
+            .is_none_or(TitleValidationResult::is_valid)
+            && self
+                .work_item
+                .as_ref()
+                .is_none_or(WorkItemValidationResult::is_valid)
+            && self.wip_detected != Some(true)
+    }
+}
+
+/// Runs the title, work item and WIP checks against a title and description.
+///
+/// No network calls are made. Checks that are disabled in `config` are not run.
+///
+/// # Arguments
+///
+/// * `title` - The PR title to check
+/// * `body` - The PR description, if any
+/// * `config` - The validation configuration to check against
+/// * `renderer` - The renderer for the guidance comments. Comment text configured in
+///   `config` takes precedence, see [`configured_comment_renderer`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::CurrentPullRequestValidationConfiguration;
+/// use merge_warden_core::explain::explain;
+/// use merge_warden_core::templates::TemplateEngineRenderer;
+///
+/// let config = CurrentPullRequestValidationConfiguration::default();
+/// let renderer = TemplateEngineRenderer::new();
+///
+/// let explanation = explain("feat: add login", Some("Fixes #12"), &config, &renderer);
+/// assert!(explanation.is_passing());
+///
+/// let explanation = explain("add login", Some("Fixes #12"), &config, &renderer);
+/// assert!(!explanation.is_passing());
+/// assert!(explanation.title_guidance.is_some());
+/// ```
+pub fn explain(
+    title: &str,
+    body: Option<&str>,
+    config: &CurrentPullRequestValidationConfiguration,
+    renderer: &dyn CommentRenderer,
+) -> Explanation {
+    let pr = PullRequest {
+        number: 0,
+        title: title.to_string(),
+        draft: false,
+        body: body.map(str::to_string),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    let title = config.enforce_title_convention.then(|| {
+        checks::check_pr_title(
+            &pr,
+            pr.author.as_ref(),
+            config.bypass_rules.title_convention(),
+            config,
+        )
+    });
+    let title_guidance = title
+        .as_ref()
+        .filter(|result| !result.is_valid())
+        .map(|result| {
+            let configured = configured_comment_renderer(config, CommentTemplate::InvalidTitle);
+            let renderer = configured
+                .as_ref()
+                .map_or(renderer, |r| r as &dyn CommentRenderer);
+            invalid_title_guidance(renderer, config, &pr.title, result)
+        });
+
+    let work_item = config.enforce_work_item_references.then(|| {
+        checks::check_work_item_reference(
+            &pr,
+            pr.author.as_ref(),
+            config.bypass_rules.work_item_convention(),
+            config,
+        )
+    });
+    let work_item_guidance = work_item
+        .as_ref()
+        .filter(|result| !result.is_valid())
+        .map(|result| {
+            let configured = configured_comment_renderer(config, CommentTemplate::MissingWorkItem);
+            let renderer = configured
+                .as_ref()
+                .map_or(renderer, |r| r as &dyn CommentRenderer);
+            let guidance = missing_work_item_guidance(renderer, config);
+            if config.min_work_item_references > 1 {
+                format!(
+                    "{guidance}{count}",
+                    count = work_item_count_guidance(
+                        &result.references,
+                        config.min_work_item_references
+                    ),
+                )
+            } else {
+                guidance
+            }
+        });
+
+    let wip_detected = config
+        .wip_check
+        .enforce_wip_blocking
+        .then(|| checks::check_wip_status(&pr, &config.wip_check));
+    let wip_guidance = (wip_detected == Some(true)).then(|| wip_guidance(&config.wip_check));
+
+    Explanation {
+        title,
+        title_guidance,
+        work_item,
+        work_item_guidance,
+        wip_detected,
+        wip_guidance,
+    }
+}
+
+/// Renders the comment explaining why a title is invalid.
+///
+/// # Arguments
+///
+/// * `renderer` - The renderer for the comment
+/// * `config` - The validation configuration holding the title pattern
+/// * `title` - The PR title that was checked
+/// * `result` - The failing title check outcome, whose diagnosis lists the issues
+pub fn invalid_title_guidance(
+    renderer: &dyn CommentRenderer,
+    config: &CurrentPullRequestValidationConfiguration,
+    title: &str,
+    result: &TitleValidationResult,
+) -> String {
+    let context = InvalidTitleCommentContext {
+        title: title.to_string(),
+        issues: result
+            .diagnosis
+            .as_ref()
+            .map(|d| d.issues.iter().map(ToString::to_string).collect())
+            .unwrap_or_default(),
+        suggested_fix: result
+            .diagnosis
+            .as_ref()
+            .and_then(|d| d.suggested_fix.clone()),
+        pattern: config.title_pattern.clone(),
+        examples: TITLE_COMMENT_EXAMPLES.to_string(),
+    };
+    render_comment(renderer, CommentTemplate::InvalidTitle, &context)
+}
+
+/// Returns a renderer with the comment text configured for `template` registered as
+/// its template, or `None` when no text is configured for it.
+///
+/// [`CommentTemplate::InvalidTitle`], [`CommentTemplate::MissingWorkItem`],
+/// [`CommentTemplate::TitleBypassNotice`] and [`CommentTemplate::WorkItemBypassNotice`]
+/// can be configured. The text is rendered against the same context as the built-in
+/// template, so it can use `{{ pattern }}` and `{{ examples }}`, or `{{ user }}`,
+/// `{{ rule_type }}` and `{{ title }}` for the bypass notices. Single-brace
+/// placeholders such as `{pattern}` are logged, as they are copied into the comment
+/// unchanged. Text that is not a valid template is logged and ignored, so that the
+/// built-in comment is used.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::CurrentPullRequestValidationConfiguration;
+/// use merge_warden_core::explain::configured_comment_renderer;
+/// use merge_warden_core::templates::{CommentRenderer, CommentTemplate};
+/// use serde_json::json;
+///
+/// let mut config = CurrentPullRequestValidationConfiguration::default();
+/// assert!(configured_comment_renderer(&config, CommentTemplate::InvalidTitle).is_none());
+///
+/// config.comments.title_invalid_comment_template =
+///     Some("Titles must match `{{ pattern }}`.".to_string());
+/// let renderer = configured_comment_renderer(&config, CommentTemplate::InvalidTitle).unwrap();
+/// let text = renderer
+///     .render(CommentTemplate::InvalidTitle, &json!({ "pattern": "^(feat|fix): .+$" }))
+///     .unwrap();
+/// assert_eq!(text, "Titles must match `^(feat|fix): .+$`.");
+/// ```
+pub fn configured_comment_renderer(
+    config: &CurrentPullRequestValidationConfiguration,
+    template: CommentTemplate,
+) -> Option<TemplateEngineRenderer> {
+    let comments = &config.comments;
+    let source = match template {
+        CommentTemplate::InvalidTitle => comments.title_invalid_comment_template.as_ref(),
+        CommentTemplate::MissingWorkItem => comments.missing_work_item_comment_template.as_ref(),
+        CommentTemplate::TitleBypassNotice => comments.title_bypass_notice.as_ref(),
+        CommentTemplate::WorkItemBypassNotice => comments.work_item_bypass_notice.as_ref(),
+        _ => None,
+    }?;
+
+    let placeholders = single_brace_placeholders(source);
+    if !placeholders.is_empty() {
+        warn!(
+            template = template.name(),
+            placeholders = placeholders.join(", "),
+            "The configured comment text uses single-brace placeholders, which are not replaced. Use `{{ name }}` instead."
+        );
+    }
+
+    match TemplateEngineRenderer::new().with_template(template, source.clone()) {
+        Ok(renderer) => Some(renderer),
+        Err(e) => {
+            warn!(
+                template = template.name(),
+                error = e.to_string(),
+                "The configured comment text is not a valid template. Using the default comment."
+            );
+            None
+        }
+    }
+}
+
+/// Renders the comment listing the work item patterns a description must match.
+///
+/// # Arguments
+///
+/// * `renderer` - The renderer for the comment
+/// * `config` - The validation configuration holding the work item patterns
+pub fn missing_work_item_guidance(
+    renderer: &dyn CommentRenderer,
+    config: &CurrentPullRequestValidationConfiguration,
+) -> String {
+    let context = MissingWorkItemCommentContext {
+        pattern: config.work_item_reference_pattern.to_string(),
+        patterns: config.work_item_reference_pattern.patterns().to_vec(),
+        examples: WORK_ITEM_COMMENT_EXAMPLES.to_string(),
+    };
+    render_comment(renderer, CommentTemplate::MissingWorkItem, &context)
+}
+
+/// Renders the addition to the missing work item comment that compares the number of
+/// work items referenced with the number required.
+///
+/// # Arguments
+///
+/// * `references` - The work items the description references
+/// * `required` - The number of work item references required
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::explain::work_item_count_guidance;
+///
+/// assert_eq!(
+///     work_item_count_guidance(&["#12".to_string()], 2),
+///     "\n\nFound 1 of the 2 required work item references: #12"
+/// );
+/// ```
+pub fn work_item_count_guidance(references: &[String], required: usize) -> String {
+    if references.is_empty() {
+        format!("\n\nFound 0 of the {required} required work item references.")
+    } else {
+        format!(
+            "\n\nFound {found} of the {required} required work item references: {list}",
+            found = references.len(),
+            list = references.join(", "),
+        )
+    }
+}
+
+/// Renders the comment listing the issue references that do not resolve to an issue.
+///
+/// # Arguments
+///
+/// * `references` - The dangling references found by issue reference verification
+pub fn dangling_issue_reference_guidance(references: &[DanglingIssueReference]) -> String {
+    let mut reference_lines = String::new();
+    for reference in references {
+        reference_lines.push_str(&format!("\n- {reference}"));
+    }
+
+    format!(
+        "\nThis pull request references work items that do not resolve to an issue in this repository:{reference_lines}\n\nUpdate the PR description to reference an existing issue. Pull request numbers do not count as work items.\n\nOnce the references are fixed, the checks will automatically re-evaluate."
+    )
+}
+
+/// Renders the addition to the missing work item comment that quotes the pull request
+/// template.
+///
+/// # Arguments
+///
+/// * `template_path` - The path of the repository's pull request template
+/// * `lines` - The lines of the template that reference a work item, as found by
+///   [`checks::find_pr_template_work_item_lines`]
+pub fn pr_template_work_item_guidance(template_path: &str, lines: &[String]) -> String {
+    let mut quoted_lines = String::new();
+    for line in lines {
+        quoted_lines.push_str(&format!("\n> {line}"));
+    }
+
+    format!(
+        "\n\nThis repository's pull request template (`{template_path}`) shows how to reference a work item:\n{quoted_lines}"
+    )
+}
+
+/// Renders the comment explaining how to remove WIP status.
+///
+/// # Arguments
+///
+/// * `config` - The WIP check configuration whose patterns are listed
+pub fn wip_guidance(config: &WipCheckConfig) -> String {
+    let mut pattern_lines = String::new();
+    for pattern in &config.wip_title_patterns {
+        pattern_lines.push_str(&format!("\n- `{pattern}` in the title"));
+    }
+    for pattern in &config.wip_description_patterns {
+        pattern_lines.push_str(&format!("\n- `{pattern}` in the description"));
+    }
+
+    format!(
+        "\nThis pull request is marked as **Work In Progress (WIP)** and cannot be merged until the WIP status is removed.\n\nTo remove WIP status, update the PR title and/or description to remove the WIP markers.\n\nThe following patterns are configured as WIP markers:{pattern_lines}\n\nOnce all WIP markers are removed, the checks will automatically re-evaluate."
+    )
+}
@@ -0,0 +1,239 @@
+use super::*;
+use crate::config::WipCheckConfig;
+use crate::templates::TemplateEngineRenderer;
+
+fn wip_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_explain_valid_title_and_body_passes() {
+    let config = CurrentPullRequestValidationConfiguration::default();
+
+    let explanation = explain(
+        "feat(auth): add login",
+        Some("Fixes #42"),
+        &config,
+        &TemplateEngineRenderer::new(),
+    );
+
+    assert!(explanation.is_passing());
+    assert!(explanation.title.as_ref().unwrap().is_valid());
+    assert!(explanation.work_item.as_ref().unwrap().is_valid());
+    assert_eq!(explanation.title_guidance, None);
+    assert_eq!(explanation.work_item_guidance, None);
+}
+
+#[test]
+fn test_explain_invalid_title_includes_guidance() {
+    let config = CurrentPullRequestValidationConfiguration::default();
+
+    let explanation = explain(
+        "Add login",
+        Some("Fixes #42"),
+        &config,
+        &TemplateEngineRenderer::new(),
+    );
+
+    assert!(!explanation.is_passing());
+    let title = explanation.title.as_ref().unwrap();
+    assert!(!title.is_valid());
+    let guidance = explanation.title_guidance.clone().unwrap();
+    assert_eq!(
+        guidance,
+        invalid_title_guidance(&TemplateEngineRenderer::new(), &config, "Add login", title)
+    );
+    assert!(guidance.contains("The pull request title needs correction"));
+    assert!(explanation.work_item_guidance.is_none());
+}
+
+#[test]
+fn test_explain_missing_work_item_includes_guidance() {
+    let config = CurrentPullRequestValidationConfiguration::default();
+
+    let explanation = explain(
+        "feat: add login",
+        None,
+        &config,
+        &TemplateEngineRenderer::new(),
+    );
+
+    assert!(!explanation.is_passing());
+    assert!(!explanation.work_item.as_ref().unwrap().is_valid());
+    let guidance = explanation.work_item_guidance.unwrap();
+    assert_eq!(
+        guidance,
+        missing_work_item_guidance(&TemplateEngineRenderer::new(), &config)
+    );
+    assert!(guidance.contains("missing a valid work item reference"));
+}
+
+#[test]
+fn test_explain_uses_configured_comment_text() {
+    let mut config = CurrentPullRequestValidationConfiguration::default();
+    config.comments.title_invalid_comment_template =
+        Some("Title must match {{ pattern }}".to_string());
+    config.comments.missing_work_item_comment_template =
+        Some("Examples:\n{{ examples }}".to_string());
+
+    let explanation = explain("Add login", None, &config, &TemplateEngineRenderer::new());
+
+    assert_eq!(
+        explanation.title_guidance,
+        Some(format!("Title must match {}", config.title_pattern))
+    );
+    assert_eq!(
+        explanation.work_item_guidance,
+        Some(format!(
+            "Examples:\n{}",
+            crate::templates::WORK_ITEM_COMMENT_EXAMPLES
+        ))
+    );
+}
+
+#[test]
+fn test_configured_comment_renderer_lists_patterns() {
+    let mut config = CurrentPullRequestValidationConfiguration::default();
+    assert!(configured_comment_renderer(&config, CommentTemplate::MissingWorkItem).is_none());
+
+    config.comments.missing_work_item_comment_template =
+        Some("Reference a work item matching {{ pattern }}.".to_string());
+    let renderer = configured_comment_renderer(&config, CommentTemplate::MissingWorkItem).unwrap();
+
+    assert_eq!(
+        missing_work_item_guidance(&renderer, &config),
+        format!(
+            "Reference a work item matching {}.",
+            config.work_item_reference_pattern
+        )
+    );
+    assert!(configured_comment_renderer(&config, CommentTemplate::InvalidTitle).is_none());
+}
+
+#[test]
+fn test_explain_ignores_configured_text_that_is_not_a_valid_template() {
+    let mut config = CurrentPullRequestValidationConfiguration::default();
+    config.comments.title_invalid_comment_template = Some("Title must match {{".to_string());
+
+    assert!(configured_comment_renderer(&config, CommentTemplate::InvalidTitle).is_none());
+    let explanation = explain("Add login", None, &config, &TemplateEngineRenderer::new());
+    assert!(explanation
+        .title_guidance
+        .unwrap()
+        .contains("The pull request title needs correction"));
+}
+
+#[test]
+fn test_explain_missing_work_item_reports_reference_count() {
+    let config = CurrentPullRequestValidationConfiguration {
+        min_work_item_references: 2,
+        ..Default::default()
+    };
+
+    let explanation = explain(
+        "feat: add login",
+        Some("Fixes #12"),
+        &config,
+        &TemplateEngineRenderer::new(),
+    );
+
+    let guidance = explanation.work_item_guidance.unwrap();
+    assert!(guidance.starts_with(&missing_work_item_guidance(
+        &TemplateEngineRenderer::new(),
+        &config
+    )));
+    assert!(guidance.ends_with("Found 1 of the 2 required work item references: #12"));
+}
+
+#[test]
+fn test_work_item_count_guidance_without_references() {
+    assert_eq!(
+        work_item_count_guidance(&[], 3),
+        "\n\nFound 0 of the 3 required work item references."
+    );
+}
+
+#[test]
+fn test_work_item_count_guidance_lists_references() {
+    assert_eq!(
+        work_item_count_guidance(&["#1".to_string(), "owner/repo#2".to_string()], 3),
+        "\n\nFound 2 of the 3 required work item references: #1, owner/repo#2"
+    );
+}
+
+#[test]
+fn test_explain_skips_disabled_checks() {
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: false,
+        enforce_work_item_references: false,
+        ..Default::default()
+    };
+
+    let explanation = explain("WIP", None, &config, &TemplateEngineRenderer::new());
+
+    assert!(explanation.is_passing());
+    assert_eq!(explanation.title, None);
+    assert_eq!(explanation.work_item, None);
+    // WIP blocking is disabled by default
+    assert_eq!(explanation.wip_detected, None);
+}
+
+#[test]
+fn test_explain_detects_wip_when_enforced() {
+    let config = wip_config();
+
+    let explanation = explain(
+        "WIP: feat: add login",
+        Some("Fixes #42"),
+        &config,
+        &TemplateEngineRenderer::new(),
+    );
+
+    assert_eq!(explanation.wip_detected, Some(true));
+    assert!(!explanation.is_passing());
+    assert!(explanation
+        .wip_guidance
+        .unwrap()
+        .contains("- `WIP` in the title"));
+}
+
+#[test]
+fn test_explain_clean_title_is_not_wip() {
+    let config = wip_config();
+
+    let explanation = explain(
+        "feat: add login",
+        Some("Fixes #42"),
+        &config,
+        &TemplateEngineRenderer::new(),
+    );
+
+    assert_eq!(explanation.wip_detected, Some(false));
+    assert_eq!(explanation.wip_guidance, None);
+    assert!(explanation.is_passing());
+}
+
+#[test]
+fn test_configured_comment_renderer_renders_bypass_notices() {
+    let mut config = CurrentPullRequestValidationConfiguration::default();
+    config.comments.work_item_bypass_notice =
+        Some("Skipped for {{ user }}, not for {user}.".to_string());
+
+    let renderer =
+        configured_comment_renderer(&config, CommentTemplate::WorkItemBypassNotice).unwrap();
+    let text = renderer
+        .render(
+            CommentTemplate::WorkItemBypassNotice,
+            &serde_json::json!({ "user": "release-bot", "rule_type": "", "title": "" }),
+        )
+        .unwrap();
+
+    assert_eq!(text, "Skipped for release-bot, not for {user}.");
+    assert!(configured_comment_renderer(&config, CommentTemplate::TitleBypassNotice).is_none());
+}
@@ -8,16 +8,30 @@
 //! - Scope-based labels
 //! - Breaking change indicators
 //! - Special labels based on PR description keywords
+//!
+//! [`compute_label_changes`] makes the same decisions without calling the provider, which
+//! is useful for dry runs and for testing label rules.
 
+use crate::checks::{
+    parse_conventional_commit, parse_conventional_commit_with_types, ConventionalCommit,
+};
+use crate::clock::{Clock, SystemClock};
 use crate::config::{
-    ChangeTypeLabelConfig, CurrentPullRequestValidationConfiguration, KeywordLabelsConfig,
-    PrStateLabelsConfig, RenovateStabilityConfig, CONVENTIONAL_COMMIT_REGEX,
-    KEYWORD_LABEL_COMMENT_MARKER, RENOVATE_STABILITY_CHECK_CONTEXT,
+    AutoLabelCategories, BuiltinKeywordLabel, ChangeTypeLabelConfig,
+    CurrentPullRequestValidationConfiguration, DependencyUpdateConfig, KeywordLabelsConfig,
+    PrSizeCheckConfig, PrStateLabelsConfig, RenovateStabilityConfig, KEYWORD_LABEL_COMMENT_MARKER,
+    RENOVATE_STABILITY_CHECK_CONTEXT, VALID_PR_TYPES,
 };
 use crate::errors::MergeWardenError;
-use crate::size::{PrSizeCategory, PrSizeInfo};
+use crate::size::{
+    FileExclusions, PrSizeCategory, PrSizeInfo, SizeCategoryNames, DEFAULT_COMMENT_BREAKDOWN_ROWS,
+};
+use crate::templates::{
+    render_comment, CommentRenderer, CommentTemplate, OversizedPrCommentContext,
+    TemplateEngineRenderer,
+};
 use lazy_static::lazy_static;
-use merge_warden_developer_platforms::models::{Comment, Label, PullRequest};
+use merge_warden_developer_platforms::models::{Comment, Label, PullRequest, PullRequestFile};
 use merge_warden_developer_platforms::PullRequestProvider;
 use regex::Regex;
 use std::collections::HashMap;
@@ -39,6 +53,175 @@ lazy_static! {
     /// Word-boundary regex for detecting "tech debt" / "technical debt" keywords.
     static ref TECH_DEBT_KEYWORD_RE: Regex =
         Regex::new(r"(?i)\btech(?:nical)?[\s\-]+debt\b").unwrap();
+
+    /// Conventional commit prefix, capturing the type and the optional scope.
+    static ref DEPENDENCY_TITLE_PREFIX_RE: Regex =
+        Regex::new(r"^\s*([A-Za-z]+)(?:\(([^)]*)\))?!?:").unwrap();
+}
+
+/// Returns `true` if the PR title or body marks the PR as a breaking change.
+///
+/// A `!:` in the title is unconditional; "breaking change" keywords in the title
+/// or body count unless they are negated (e.g. "no breaking changes").
+pub(crate) fn indicates_breaking_change(pr: &PullRequest) -> bool {
+    if pr.title.contains("!:") {
+        return true;
+    }
+
+    let title_lower = pr.title.to_lowercase();
+    let body_lower = pr
+        .body
+        .as_deref()
+        .map(|b| b.to_lowercase())
+        .unwrap_or_default();
+    has_unnegated_match(&BREAKING_CHANGE_KEYWORD_RE, &title_lower)
+        || has_unnegated_match(&BREAKING_CHANGE_KEYWORD_RE, &body_lower)
+}
+
+/// Returns `true` if `re` matches `text_lower` at least once without being negated.
+///
+/// `text_lower` must already be lowercased, as expected by [`is_keyword_negated`].
+fn has_unnegated_match(re: &Regex, text_lower: &str) -> bool {
+    re.find_iter(text_lower)
+        .any(|m| !is_keyword_negated(text_lower, m.range()))
+}
+
+/// A keyword label together with the patterns that trigger it.
+struct KeywordLabelMatcher {
+    /// The label to apply when one of the patterns matches.
+    label: String,
+
+    /// The built-in this label replaces, or `None` for a custom rule.
+    builtin: Option<BuiltinKeywordLabel>,
+
+    /// Case-insensitive patterns; any non-negated match triggers the label.
+    patterns: Vec<Regex>,
+
+    /// Built-in keywords are only looked for in the body; configured keywords are also
+    /// looked for in the title.
+    search_title: bool,
+}
+
+impl KeywordLabelMatcher {
+    /// Returns `true` if any pattern matches the lowercased title or body without negation.
+    fn is_triggered(&self, title_lower: &str, body_lower: &str) -> bool {
+        self.patterns.iter().any(|re| {
+            (self.search_title && has_unnegated_match(re, title_lower))
+                || has_unnegated_match(re, body_lower)
+        })
+    }
+}
+
+/// Builds a case-insensitive, word-bounded pattern for a configured keyword.
+///
+/// Whitespace and hyphens inside the keyword match any run of whitespace or hyphens, so
+/// `"schema change"` also matches `"schema-change"`. Returns `None` for a blank keyword.
+fn keyword_regex(keyword: &str) -> Option<Regex> {
+    let keyword = keyword.trim();
+    let parts: Vec<String> = keyword
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|p| !p.is_empty())
+        .map(regex::escape)
+        .collect();
+    if parts.is_empty() {
+        return None;
+    }
+
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let leading = if keyword.starts_with(is_word_char) {
+        r"\b"
+    } else {
+        ""
+    };
+    let trailing = if keyword.ends_with(is_word_char) {
+        r"\b"
+    } else {
+        ""
+    };
+    let pattern = format!("(?i){}{}{}", leading, parts.join(r"[\s\-]+"), trailing);
+
+    match Regex::new(&pattern) {
+        Ok(re) => Some(re),
+        Err(e) => {
+            warn!(
+                keyword = keyword,
+                error = %e,
+                "Ignoring keyword that cannot be turned into a pattern"
+            );
+            None
+        }
+    }
+}
+
+/// Resolves the security, hotfix, tech-debt and custom keyword labels from configuration.
+///
+/// Enabled built-ins come first, using their default keywords unless a rule targets the same
+/// label, in which case the rule's keywords replace them. The remaining rules follow in
+/// declaration order, with rules that share a label combined into one matcher. The
+/// breaking-change label is detected separately by [`indicates_breaking_change`].
+fn resolve_keyword_label_matchers(config: &KeywordLabelsConfig) -> Vec<KeywordLabelMatcher> {
+    let builtins: [(BuiltinKeywordLabel, &str, &Regex); 3] = [
+        (
+            BuiltinKeywordLabel::Security,
+            config.security_label(),
+            &SECURITY_KEYWORD_RE,
+        ),
+        (
+            BuiltinKeywordLabel::Hotfix,
+            config.hotfix_label(),
+            &HOTFIX_KEYWORD_RE,
+        ),
+        (
+            BuiltinKeywordLabel::TechDebt,
+            config.tech_debt_label(),
+            &TECH_DEBT_KEYWORD_RE,
+        ),
+    ];
+
+    let mut matchers: Vec<KeywordLabelMatcher> = builtins
+        .iter()
+        .filter(|(builtin, _, _)| config.is_enabled(*builtin))
+        .map(|(builtin, label, default_re)| KeywordLabelMatcher {
+            label: (*label).to_string(),
+            builtin: Some(*builtin),
+            patterns: vec![(*default_re).clone()],
+            search_title: false,
+        })
+        .collect();
+    let builtin_count = matchers.len();
+
+    for rule in &config.rules {
+        let label = rule.label.trim();
+        if label.is_empty() {
+            continue;
+        }
+
+        let patterns: Vec<Regex> = rule
+            .keywords
+            .iter()
+            .filter_map(|k| keyword_regex(k))
+            .collect();
+
+        match matchers.iter().position(|m| m.label == label) {
+            Some(index) => {
+                let matcher = &mut matchers[index];
+                if index < builtin_count && !matcher.search_title {
+                    // First rule for a built-in label: replace the default keywords.
+                    matcher.patterns.clear();
+                    matcher.search_title = true;
+                }
+                matcher.patterns.extend(patterns);
+            }
+            None => matchers.push(KeywordLabelMatcher {
+                label: label.to_string(),
+                builtin: None,
+                patterns,
+                search_title: true,
+            }),
+        }
+    }
+
+    matchers
 }
 
 /// Single-word negation tokens that, when found in the 5-word window immediately
@@ -114,6 +297,8 @@ mod tests;
 ///         author: None,
 ///         milestone_number: None,
 ///         head_sha: String::new(),
+///         base_ref: String::new(),
+///         head_ref: String::new(),
 ///     };
 ///
 ///     let labels = set_pull_request_labels(provider, "owner", "repo", &pr).await?;
@@ -146,7 +331,9 @@ pub async fn set_pull_request_labels<P: PullRequestProvider>(
 ///
 /// # Returns
 ///
-/// A `Result` containing a vector of labels that were applied to the PR
+/// A `Result` containing a vector of labels that were applied to the PR. Durations are
+/// measured with the [`SystemClock`]; use [`apply_pull_request_labels`] to pass another
+/// clock.
 pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
     provider: &P,
     owner: &str,
@@ -154,29 +341,70 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
     pr: &PullRequest,
     config: Option<&CurrentPullRequestValidationConfiguration>,
 ) -> Result<Vec<String>, MergeWardenError> {
+    apply_pull_request_labels(provider, owner, repo, pr, config, &SystemClock)
+        .await
+        .map(|result| result.applied_labels)
+}
+
+/// Applies the change-type and keyword labels of a pull request.
+///
+/// Works like [`set_pull_request_labels_with_config`], but reports the outcome as a
+/// [`LabelManagementResult`]. When `label_creation.create_missing_labels` is `false`,
+/// labels that do not exist in the repository are not applied and are listed in
+/// [`LabelManagementResult::skipped_labels`] instead. Use [`compute_label_changes`] to
+/// find out which labels would change without applying them.
+///
+/// # Arguments
+///
+/// * `provider` - The Git provider implementation
+/// * `owner` - The owner of the repository
+/// * `repo` - The name of the repository
+/// * `pr` - The pull request to analyze
+/// * `config` - Optional configuration with smart label detection settings
+/// * `clock` - The clock the label detection time is measured with
+pub async fn apply_pull_request_labels<P: PullRequestProvider>(
+    provider: &P,
+    owner: &str,
+    repo: &str,
+    pr: &PullRequest,
+    config: Option<&CurrentPullRequestValidationConfiguration>,
+    clock: &dyn Clock,
+) -> Result<LabelManagementResult, MergeWardenError> {
+    let create_missing_labels = config.is_none_or(|c| c.label_creation.create_missing_labels);
     // This is the implementation we created earlier - delegate to the main function
     // but include the logic in a new internal function to avoid circular calls
     let mut labels = Vec::new();
     let mut smart_detection_applied = false;
+    // Labels that could not be applied, and why; reported but never propagated
+    let mut failed_labels = Vec::new();
+    let mut error_messages = Vec::new();
 
-    // Extract type from PR title using pre-compiled regex
-    let regex = match Regex::new(CONVENTIONAL_COMMIT_REGEX) {
-        Ok(r) => r,
-        Err(_e) => {
-            return Err(MergeWardenError::ConfigError(
-                "Failed to create a title extraction regex.".to_string(),
-            ))
-        }
+    // Resolve the per-category toggles; every category is enabled without configuration.
+    let default_categories = AutoLabelCategories::default();
+    let categories = config
+        .and_then(|c| c.change_type_labels.as_ref())
+        .map_or(&default_categories, |c| &c.categories);
+
+    let title_commit = if categories.change_type_enabled() {
+        parse_title_commit(&pr.title, config)
+    } else {
+        debug!(
+            repository_owner = owner,
+            repository = repo,
+            pr_number = pr.number,
+            "Change-type labelling disabled by configuration"
+        );
+        None
     };
 
-    if let Some(captures) = regex.captures(&pr.title) {
-        let pr_type = captures.get(1).unwrap().as_str();
+    if let Some(commit) = title_commit {
+        let pr_type = commit.type_.as_str();
 
         // Use smart label detection if configured, otherwise fall back to hardcoded labels
         if let Some(config) = config {
             if let Some(ref change_type_config) = config.change_type_labels {
                 if change_type_config.enabled {
-                    let detection_start = std::time::Instant::now();
+                    let detection_start = clock.now();
 
                     info!(
                         repository_owner = owner,
@@ -187,16 +415,23 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
                         "Starting smart label detection for change type"
                     );
 
-                    // Use smart label detection with LabelManager
-                    let label_manager = LabelManager::new(Some(change_type_config.clone()));
+                    // Use smart label detection with LabelManager. Fallback labels are
+                    // created on demand, so they are not used when creation is disabled.
+                    let mut change_type_config = change_type_config.clone();
+                    if !create_missing_labels {
+                        change_type_config.fallback_label_settings.create_if_missing = false;
+                    }
+                    let label_manager = LabelManager::new(Some(change_type_config));
 
                     match label_manager
                         .apply_change_type_label(provider, owner, repo, pr.number, pr_type)
                         .await
                     {
                         Ok(result) => {
-                            let detection_duration = detection_start.elapsed();
+                            let detection_duration = clock.now() - detection_start;
                             labels.extend(result.all_applied_labels());
+                            failed_labels.extend(result.failed_labels.iter().cloned());
+                            error_messages.extend(result.error_messages.iter().cloned());
                             smart_detection_applied = true;
 
                             info!(
@@ -206,7 +441,7 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
                                 commit_type = pr_type,
                                 applied_labels = ?result.all_applied_labels(),
                                 labels_count = result.all_applied_labels().len(),
-                                detection_duration_ms = detection_duration.as_millis(),
+                                detection_duration_ms = detection_duration.num_milliseconds(),
                                 detection_method = "smart_detection",
                                 used_fallback = result.used_fallback_creation(),
                                 "Successfully applied smart change type labels"
@@ -231,7 +466,7 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
                             }
                         }
                         Err(e) => {
-                            let detection_duration = detection_start.elapsed();
+                            let detection_duration = clock.now() - detection_start;
 
                             warn!(
                                 repository_owner = owner,
@@ -239,7 +474,7 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
                                 pr_number = pr.number,
                                 commit_type = pr_type,
                                 error = %e,
-                                detection_duration_ms = detection_duration.as_millis(),
+                                detection_duration_ms = detection_duration.num_milliseconds(),
                                 "Smart label detection failed, falling back to hardcoded labels"
                             );
 
@@ -302,18 +537,6 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
         }
     }
 
-    // Resolve keyword label names via KeywordLabelsConfig accessors, which apply the
-    // empty-string fallback logic centrally.
-    let default_kw = KeywordLabelsConfig::default();
-    let keyword_labels = config
-        .and_then(|c| c.change_type_labels.as_ref())
-        .map_or(&default_kw, |c| &c.keyword_labels);
-
-    let breaking_change_label = keyword_labels.breaking_change_label().to_string();
-    let security_label = keyword_labels.security_label().to_string();
-    let hotfix_label = keyword_labels.hotfix_label().to_string();
-    let tech_debt_label = keyword_labels.tech_debt_label().to_string();
-
     let bot_mention = config
         .map(|c| c.bot_mention.as_str())
         .unwrap_or("@merge-warden");
@@ -352,47 +575,11 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
         }
     };
 
-    // Determine which keyword labels are triggered using negation-aware detection.
-    let title_lower = pr.title.to_lowercase();
-    let body_lower = pr
-        .body
-        .as_deref()
-        .map(|b| b.to_lowercase())
-        .unwrap_or_default();
-
-    // Breaking change: `!:` in title is unconditional; keyword matches are negation-checked.
-    let breaking_from_exclamation = pr.title.contains("!:");
-    let breaking_from_keyword_title = !breaking_from_exclamation
-        && BREAKING_CHANGE_KEYWORD_RE
-            .find_iter(&title_lower)
-            .any(|m| !is_keyword_negated(&title_lower, m.range()));
-    let breaking_from_keyword_body = BREAKING_CHANGE_KEYWORD_RE
-        .find_iter(&body_lower)
-        .any(|m| !is_keyword_negated(&body_lower, m.range()));
-    let breaking_triggered =
-        breaking_from_exclamation || breaking_from_keyword_title || breaking_from_keyword_body;
-
-    let security_triggered = SECURITY_KEYWORD_RE
-        .find_iter(&body_lower)
-        .any(|m| !is_keyword_negated(&body_lower, m.range()));
-
-    let hotfix_triggered = HOTFIX_KEYWORD_RE
-        .find_iter(&body_lower)
-        .any(|m| !is_keyword_negated(&body_lower, m.range()));
-
-    let tech_debt_triggered = TECH_DEBT_KEYWORD_RE
-        .find_iter(&body_lower)
-        .any(|m| !is_keyword_negated(&body_lower, m.range()));
-
     // (label_name, triggered) pairs drive both label application and comment lifecycle.
-    let keyword_label_states: Vec<(String, bool)> = vec![
-        (breaking_change_label, breaking_triggered),
-        (security_label, security_triggered),
-        (hotfix_label, hotfix_triggered),
-        (tech_debt_label, tech_debt_triggered),
-    ];
+    let keyword_label_states = keyword_label_states(pr, config);
 
     // Collect additional labels that need to be applied, skipping suppressed ones.
+    let change_type_label_count = labels.len();
     let mut additional_labels = Vec::new();
 
     for (label_name, triggered) in &keyword_label_states {
@@ -433,6 +620,79 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
         }
     }
 
+    // Keep at most `max_auto_labels` labels. `labels` is in priority order: the change-type
+    // labels, then the breaking-change label, then the other keyword labels.
+    let max_auto_labels = config
+        .and_then(|c| c.change_type_labels.as_ref())
+        .and_then(|c| c.max_auto_labels);
+    let mut dropped_labels = Vec::new();
+    if let Some(max) = max_auto_labels.filter(|max| labels.len() > *max) {
+        dropped_labels = labels.split_off(max);
+        additional_labels.retain(|label| !dropped_labels.contains(label));
+        info!(
+            repository_owner = owner,
+            repository = repo,
+            pr_number = pr.number,
+            max_auto_labels = max,
+            kept_labels = ?labels,
+            dropped_labels = ?dropped_labels,
+            "Dropped automatic labels above the configured maximum"
+        );
+
+        // Smart detection has already applied the change-type labels it found.
+        if smart_detection_applied {
+            for label in dropped_labels
+                .iter()
+                .take(change_type_label_count.saturating_sub(max))
+            {
+                if let Err(e) = provider.remove_label(owner, repo, pr.number, label).await {
+                    warn!(
+                        repository_owner = owner,
+                        repository = repo,
+                        pr_number = pr.number,
+                        label = %label,
+                        error = %e,
+                        "Failed to remove change-type label above the configured maximum"
+                    );
+                }
+            }
+        }
+    }
+
+    // Leave out the labels the repository does not define when they may not be created.
+    let mut skipped_labels = Vec::new();
+    if !create_missing_labels {
+        let to_check = if smart_detection_applied {
+            &additional_labels
+        } else {
+            &labels
+        };
+        skipped_labels = match find_missing_labels(provider, owner, repo, to_check).await {
+            Ok(missing) => missing,
+            Err(e) => {
+                warn!(
+                    repository_owner = owner,
+                    repository = repo,
+                    pr_number = pr.number,
+                    error = %e,
+                    "Failed to list repository labels; not applying labels that may not exist"
+                );
+                to_check.clone()
+            }
+        };
+        if !skipped_labels.is_empty() {
+            info!(
+                repository_owner = owner,
+                repository = repo,
+                pr_number = pr.number,
+                skipped_labels = ?skipped_labels,
+                "Skipped labels that do not exist in the repository; label creation is disabled"
+            );
+            labels.retain(|label| !skipped_labels.contains(label));
+            additional_labels.retain(|label| !skipped_labels.contains(label));
+        }
+    }
+
     // Apply labels to the PR
     if smart_detection_applied {
         // Smart detection already applied its labels, only apply additional labels
@@ -450,6 +710,8 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
                     "Failed to add additional labels to pull request, continuing with validation"
                 );
                 // Don't propagate the error, just log it and continue
+                failed_labels.extend(additional_labels.iter().cloned());
+                error_messages.push(e.to_string());
             }
         }
     } else {
@@ -466,7 +728,14 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
                 );
                 // Don't propagate the error, just log it and continue
                 // Return empty labels vector since none were successfully applied
-                return Ok(vec![]);
+                failed_labels.extend(labels);
+                error_messages.push(e.to_string());
+                return Ok(LabelManagementResult {
+                    failed_labels,
+                    skipped_labels,
+                    error_messages,
+                    ..LabelManagementResult::new()
+                });
             }
         }
     }
@@ -474,9 +743,11 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
     // Manage explanation comments for each keyword label.
     // Active (triggered and not suppressed): ensure the explanation comment is present
     //   and up to date, replacing stale copies if needed.
-    // Inactive (not triggered) or suppressed: delete any stale explanation comment.
+    // Inactive (not triggered), suppressed or dropped by `max_auto_labels`: delete any
+    //   stale explanation comment.
     for (label_name, triggered) in &keyword_label_states {
         let is_suppressed = suppressed.contains_key(label_name.as_str());
+        let is_dropped = dropped_labels.contains(label_name) || skipped_labels.contains(label_name);
         let label_marker = format!("{}{} -->", KEYWORD_LABEL_COMMENT_MARKER, label_name);
 
         // Find all existing explanation comments for this label.
@@ -486,7 +757,7 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
             .map(|c| (c.id, c.body.clone()))
             .collect();
 
-        if *triggered && !is_suppressed {
+        if *triggered && !is_suppressed && !is_dropped {
             let expected_body = build_keyword_label_comment(label_name, bot_mention);
             let already_up_to_date = existing.len() == 1 && existing[0].1 == expected_body;
 
@@ -552,116 +823,542 @@ pub async fn set_pull_request_labels_with_config<P: PullRequestProvider>(
         }
     }
 
-    Ok(labels)
-}
-
-/// Add hardcoded type-based label mapping (legacy behavior)
-fn add_hardcoded_type_label(labels: &mut Vec<String>, pr_type: &str) {
-    match pr_type {
-        "feat" => labels.push("feature".to_string()),
-        "fix" => labels.push("bug".to_string()),
-        "docs" => labels.push("documentation".to_string()),
-        "style" => labels.push("style".to_string()),
-        "refactor" => labels.push("refactor".to_string()),
-        "perf" => labels.push("performance".to_string()),
-        "test" => labels.push("testing".to_string()),
-        "build" => labels.push("build".to_string()),
-        "ci" => labels.push("ci".to_string()),
-        "chore" => labels.push("chore".to_string()),
-        "revert" => labels.push("revert".to_string()),
-        _ => {}
-    }
+    Ok(LabelManagementResult {
+        applied_labels: labels,
+        failed_labels,
+        skipped_labels,
+        error_messages,
+        ..LabelManagementResult::new()
+    })
 }
 
-/// Determines whether a keyword match within `text` is negated by a preceding negation word.
+/// Returns the labels in `labels` that do not exist in the repository.
 ///
-/// Uses clause-boundary detection (`.`, `!`, `?`, `;`, `\n`) to scope the look-back,
-/// then inspects the 5-word window immediately before `keyword_span` for any word
-/// from [`NEGATION_SINGLE_WORDS`].
+/// Names are compared case-insensitively, as on GitHub, where adding a label the
+/// repository does not define creates it. Callers use this to leave such labels out
+/// when `label_creation.create_missing_labels` is `false`.
 ///
 /// # Arguments
 ///
-/// * `text` - The full text (lowercased) being searched.
-/// * `keyword_span` - The byte-range of the keyword match within `text`.
+/// * `provider` - The Git provider implementation
+/// * `owner` - The owner of the repository
+/// * `repo` - The name of the repository
+/// * `labels` - The names of the labels to look up
 ///
-/// # Returns
+/// # Errors
 ///
-/// `true` when a negation word appears within the 5-word window before the keyword
-/// and within the same clause; `false` otherwise.
-fn is_keyword_negated(text: &str, keyword_span: std::ops::Range<usize>) -> bool {
-    let before = &text[..keyword_span.start];
-
-    // Restrict look-back to current clause (stop at sentence / clause boundaries).
-    // Uses `find_clause_boundary` rather than a plain `rfind` so that `!` followed
-    // by `[` (a Markdown image link) is not treated as a sentence terminator.
-    let clause_start = find_clause_boundary(before).map(|i| i + 1).unwrap_or(0);
-    let clause_before = &before[clause_start..];
+/// Returns [`MergeWardenError::FailedToUpdatePullRequest`] when the repository's
+/// labels cannot be listed.
+pub async fn find_missing_labels<P: PullRequestProvider>(
+    provider: &P,
+    owner: &str,
+    repo: &str,
+    labels: &[String],
+) -> Result<Vec<String>, MergeWardenError> {
+    if labels.is_empty() {
+        return Ok(Vec::new());
+    }
 
-    // Collect up to the last 5 whitespace-delimited tokens in this clause.
-    let tokens: Vec<&str> = clause_before.split_whitespace().collect();
-    let window_start = tokens.len().saturating_sub(5);
-    let window = &tokens[window_start..];
+    let available = provider
+        .list_available_labels(owner, repo)
+        .await
+        .map_err(|e| {
+            MergeWardenError::FailedToUpdatePullRequest(format!(
+                "Failed to list repository labels: {e}"
+            ))
+        })?;
 
-    // `text` is always lowercased by callers, so no `.to_lowercase()` allocation is needed.
-    // `strip_punct` removes leading/trailing punctuation so `"no,"` and `"(no)"` both match.
-    window
+    Ok(labels
         .iter()
-        .any(|tok| NEGATION_SINGLE_WORDS.contains(&strip_punct(tok)))
+        .filter(|label| {
+            !available
+                .iter()
+                .any(|existing| existing.name.eq_ignore_ascii_case(label))
+        })
+        .cloned()
+        .collect())
 }
 
-/// Finds the byte index of the rightmost clause-boundary character in `s`.
+/// The labels to add to and remove from a pull request.
 ///
-/// The characters `.`, `?`, `;`, and `\n` are always treated as clause boundaries.
-/// `!` is treated as a clause boundary **unless** it is immediately followed by `[`,
-/// which is the start of a Markdown image link (`![](url)`) and must not split the clause.
-fn find_clause_boundary(s: &str) -> Option<usize> {
-    let bytes = s.as_bytes();
-    let len = bytes.len();
-    for i in (0..len).rev() {
-        match bytes[i] {
-            b'.' | b'?' | b';' | b'\n' => return Some(i),
-            b'!' => {
-                // `![` is Markdown image syntax — not a sentence boundary.
-                if i + 1 < len && bytes[i + 1] == b'[' {
-                    continue;
-                }
-                return Some(i);
-            }
-            _ => {}
-        }
+/// Computed by [`compute_label_changes`] without changing anything on the pull request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelChanges {
+    /// Labels the pull request should have but does not have yet
+    pub to_add: Vec<String>,
+
+    /// Labels on the pull request that should be removed
+    pub to_remove: Vec<String>,
+}
+
+impl LabelChanges {
+    /// Returns `true` when the pull request already has the desired labels.
+    pub fn is_empty(&self) -> bool {
+        self.to_add.is_empty() && self.to_remove.is_empty()
     }
-    None
 }
 
-/// Strips leading and trailing punctuation from a token, preserving apostrophes
-/// and hyphens that appear in negation contractions such as `"don't"` and `"won't"`.
+/// What [`compute_label_changes`] decides the labels from.
 ///
-/// Examples: `"no,"` → `"no"`, `"(no)"` → `"no"`, `"no:"` → `"no"`.
-fn strip_punct(s: &str) -> &str {
-    s.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'' && c != '-')
+/// All of it is data the caller has already fetched, so computing the changes makes no
+/// provider calls.
+#[derive(Debug, Clone, Copy)]
+pub struct LabelChangeInput<'a> {
+    /// The pull request; its title and body drive the change-type and keyword labels
+    pub pr: &'a PullRequest,
+
+    /// The labels currently applied to the pull request
+    pub current_labels: &'a [Label],
+
+    /// The labels defined in the repository, used for smart change-type and size label
+    /// detection and to leave out missing labels when label creation is disabled
+    pub repository_labels: &'a [Label],
+
+    /// The comments on the pull request, used to find suppressed keyword labels
+    pub comments: &'a [Comment],
+
+    /// The size of the pull request, or `None` to leave the size labels alone
+    pub size_info: Option<&'a PrSizeInfo>,
 }
 
-/// Parses label suppression commands from a list of PR comments.
+/// Computes which labels to add to and remove from a pull request, without applying them.
 ///
-/// A suppression command is a line of the form:
-/// ```text
-/// <bot_mention> suppress: <label-name>
-/// ```
-/// The bot mention prefix comparison is case-insensitive.  Unknown commands are
-/// silently ignored.
+/// Covers the change-type, breaking-change, keyword and size labels, and makes the same
+/// decisions as [`apply_pull_request_labels`] and [`manage_size_labels`]:
 ///
-/// # Arguments
+/// - the change-type label comes from smart detection against `repository_labels` when
+///   configured, otherwise from the built-in mapping, and other change-type labels on the
+///   PR are removed
+/// - triggered keyword labels are added unless suppressed by a comment; suppressed labels
+///   on the PR are removed
+/// - at most `max_auto_labels` change-type and keyword labels are kept
+/// - the size label matching `size_info` replaces any other size label
+/// - when `label_creation.create_missing_labels` is `false`, labels the repository does
+///   not define are not added
 ///
-/// * `comments` - All comments fetched from the pull request.
-/// * `bot_mention` - The bot mention prefix configured for this installation.
+/// Labels the PR already has are not added again. Names are compared case-insensitively.
 ///
-/// # Returns
+/// # Examples
 ///
-/// A map from label name (trimmed, lowercase) to the login of the first commenter
-/// who issued the suppression command for that label.
-fn parse_suppressed_labels(comments: &[Comment], bot_mention: &str) -> HashMap<String, String> {
-    let mut suppressed: HashMap<String, String> = HashMap::new();
-    let prefix = format!("{} suppress:", bot_mention.to_lowercase());
+/// ```
+/// use merge_warden_core::labels::{compute_label_changes, LabelChangeInput};
+/// use merge_warden_developer_platforms::models::{Label, PullRequest};
+///
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "fix: handle empty bodies".to_string(),
+///     draft: false,
+///     body: Some("This is a hotfix.".to_string()),
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
+/// };
+/// let current = vec![Label {
+///     name: "bug".to_string(),
+///     description: None,
+///     color: None,
+/// }];
+///
+/// let changes = compute_label_changes(
+///     &LabelChangeInput {
+///         pr: &pr,
+///         current_labels: &current,
+///         repository_labels: &[],
+///         comments: &[],
+///         size_info: None,
+///     },
+///     None,
+/// );
+///
+/// assert_eq!(changes.to_add, vec!["hotfix".to_string()]);
+/// assert!(changes.to_remove.is_empty());
+/// ```
+pub fn compute_label_changes(
+    input: &LabelChangeInput<'_>,
+    config: Option<&CurrentPullRequestValidationConfiguration>,
+) -> LabelChanges {
+    let create_missing_labels = config.is_none_or(|c| c.label_creation.create_missing_labels);
+    let mut desired = Vec::new();
+    let mut to_remove = Vec::new();
+
+    // Change-type labels
+    let (change_type_labels, stale_change_type_labels, smart_detection) =
+        plan_change_type_labels(input, config, create_missing_labels);
+    desired.extend(change_type_labels);
+    to_remove.extend(stale_change_type_labels);
+    let change_type_label_count = desired.len();
+
+    // Breaking-change and keyword labels
+    let bot_mention = config
+        .map(|c| c.bot_mention.as_str())
+        .unwrap_or("@merge-warden");
+    let suppressed = parse_suppressed_labels(input.comments, bot_mention);
+    for (label_name, triggered) in keyword_label_states(input.pr, config) {
+        if suppressed.contains_key(label_name.as_str()) {
+            to_remove.push(label_name);
+        } else if triggered {
+            desired.push(label_name);
+        }
+    }
+
+    // Keep at most `max_auto_labels`, in priority order
+    let max_auto_labels = config
+        .and_then(|c| c.change_type_labels.as_ref())
+        .and_then(|c| c.max_auto_labels);
+    if let Some(max) = max_auto_labels.filter(|max| desired.len() > *max) {
+        let dropped = desired.split_off(max);
+        // Smart detection applies the change-type labels before they are counted.
+        if smart_detection {
+            to_remove.extend(
+                dropped
+                    .into_iter()
+                    .take(change_type_label_count.saturating_sub(max)),
+            );
+        }
+    }
+
+    if !create_missing_labels {
+        desired.retain(|label| has_label(input.repository_labels, label));
+    }
+
+    // Size labels
+    if let Some(size_info) = input.size_info {
+        let default_size_config = PrSizeCheckConfig::default();
+        let size_config = config.map_or(&default_size_config, |c| &c.pr_size_check);
+        let discovered = LabelDetector::new_for_size_labels()
+            .find_size_labels(input.repository_labels, &size_config.category_names);
+        let (size_label, size_changes) = plan_size_labels(
+            &discovered,
+            input.current_labels,
+            size_info,
+            &size_config.label_prefix,
+            &size_config.category_names,
+        );
+        to_remove.extend(size_changes.to_remove);
+        if create_missing_labels || has_label(input.repository_labels, &size_label) {
+            desired.extend(size_changes.to_add);
+        }
+    }
+
+    let mut changes = LabelChanges::default();
+    for label in &desired {
+        if !has_label(input.current_labels, label) && !contains_label(&changes.to_add, label) {
+            changes.to_add.push(label.clone());
+        }
+    }
+    for label in to_remove {
+        if has_label(input.current_labels, &label)
+            && !contains_label(&desired, &label)
+            && !contains_label(&changes.to_remove, &label)
+        {
+            changes.to_remove.push(label);
+        }
+    }
+    changes
+}
+
+/// Decides the change-type labels of a pull request for [`compute_label_changes`].
+///
+/// Returns the labels to apply, the change-type labels of an earlier title type to
+/// remove, and whether smart detection was used.
+fn plan_change_type_labels(
+    input: &LabelChangeInput<'_>,
+    config: Option<&CurrentPullRequestValidationConfiguration>,
+    create_missing_labels: bool,
+) -> (Vec<String>, Vec<String>, bool) {
+    let default_categories = AutoLabelCategories::default();
+    let categories = config
+        .and_then(|c| c.change_type_labels.as_ref())
+        .map_or(&default_categories, |c| &c.categories);
+    if !categories.change_type_enabled() {
+        return (Vec::new(), Vec::new(), false);
+    }
+
+    let Some(pr_type) = parse_title_commit(&input.pr.title, config).map(|commit| commit.type_)
+    else {
+        return (Vec::new(), Vec::new(), false);
+    };
+
+    let mut labels = Vec::new();
+    match config
+        .and_then(|c| c.change_type_labels.as_ref())
+        .filter(|c| c.enabled)
+    {
+        Some(change_type_config) => {
+            let mut change_type_config = change_type_config.clone();
+            if !create_missing_labels {
+                change_type_config.fallback_label_settings.create_if_missing = false;
+            }
+            let detector = LabelDetector::new_for_change_type_labels(change_type_config);
+            if let Ok(detected) = detector.find_change_type_label(input.repository_labels, &pr_type)
+            {
+                if let Some(label) = detected.label_name {
+                    labels.push(label);
+                } else if detected.should_create_fallback {
+                    if let Some(config) = detector.change_type_config.as_ref() {
+                        labels.push(fallback_change_type_label(&pr_type, config));
+                    }
+                }
+            }
+
+            let stale = if labels.is_empty() {
+                Vec::new()
+            } else {
+                input
+                    .current_labels
+                    .iter()
+                    .filter(|label| {
+                        !contains_label(&labels, &label.name)
+                            && detector.is_change_type_label(&label.name)
+                    })
+                    .map(|label| label.name.clone())
+                    .collect()
+            };
+            (labels, stale, true)
+        }
+        None => {
+            add_hardcoded_type_label(&mut labels, &pr_type);
+            (labels, Vec::new(), false)
+        }
+    }
+}
+
+/// Decides the size label of a pull request.
+///
+/// Returns the label for the PR's size category, either the one found in the repository
+/// or `label_prefix` followed by the category name, and the changes that make it the
+/// only size label on the PR.
+fn plan_size_labels(
+    discovered: &DiscoveredSizeLabels,
+    current_labels: &[Label],
+    size_info: &PrSizeInfo,
+    label_prefix: &str,
+    category_names: &SizeCategoryNames,
+) -> (String, LabelChanges) {
+    let new_label_name =
+        if let Some(name) = discovered.get_label_for_category(&size_info.size_category) {
+            name.clone()
+        } else {
+            // Fallback: use the configured label_prefix followed by the category's display name.
+            format!(
+                "{}{}",
+                label_prefix,
+                category_names.name_for(size_info.size_category)
+            )
+        };
+
+    // The discovered size labels that are currently applied to the PR.
+    let existing_size_labels: Vec<String> = current_labels
+        .iter()
+        .filter(|l| discovered.all_discovered_labels().contains(&&l.name))
+        .map(|l| l.name.clone())
+        .collect();
+
+    let mut changes = LabelChanges::default();
+    if !existing_size_labels.contains(&new_label_name) {
+        changes.to_add.push(new_label_name.clone());
+    }
+    changes.to_remove = existing_size_labels
+        .into_iter()
+        .filter(|label| label != &new_label_name)
+        .collect();
+    (new_label_name, changes)
+}
+
+/// Resolves the breaking-change and keyword labels and whether the pull request triggers
+/// each of them.
+///
+/// The breaking-change label comes first, followed by the keyword labels in the order of
+/// [`resolve_keyword_label_matchers`]. A label that appears more than once (e.g. a custom
+/// rule reusing the breaking-change label) is triggered when any of its sources is. A
+/// disabled category is never triggered.
+fn keyword_label_states(
+    pr: &PullRequest,
+    config: Option<&CurrentPullRequestValidationConfiguration>,
+) -> Vec<(String, bool)> {
+    let default_categories = AutoLabelCategories::default();
+    let categories = config
+        .and_then(|c| c.change_type_labels.as_ref())
+        .map_or(&default_categories, |c| &c.categories);
+
+    // Resolve keyword label names via KeywordLabelsConfig accessors, which apply the
+    // empty-string fallback logic centrally.
+    let default_kw = KeywordLabelsConfig::default();
+    let keyword_labels = config
+        .and_then(|c| c.change_type_labels.as_ref())
+        .map_or(&default_kw, |c| &c.keyword_labels);
+
+    // Determine which keyword labels are triggered using negation-aware detection.
+    let title_lower = pr.title.to_lowercase();
+    let body_lower = pr
+        .body
+        .as_deref()
+        .map(|b| b.to_lowercase())
+        .unwrap_or_default();
+
+    let breaking_triggered = categories.breaking_change_enabled()
+        && keyword_labels.is_enabled(BuiltinKeywordLabel::BreakingChange)
+        && indicates_breaking_change(pr);
+
+    let keywords_enabled = categories.keywords_enabled();
+
+    let mut states: Vec<(String, bool)> = vec![(
+        keyword_labels.breaking_change_label().to_string(),
+        breaking_triggered,
+    )];
+    for matcher in resolve_keyword_label_matchers(keyword_labels) {
+        let triggered = keywords_enabled && matcher.is_triggered(&title_lower, &body_lower);
+        match states.iter_mut().find(|(label, _)| *label == matcher.label) {
+            Some((_, existing)) => *existing |= triggered,
+            None => states.push((matcher.label, triggered)),
+        }
+    }
+    states
+}
+
+/// Returns `true` if `labels` holds a label named `name`, ignoring case.
+fn has_label(labels: &[Label], name: &str) -> bool {
+    labels
+        .iter()
+        .any(|label| label.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns `true` if `names` holds `name`, ignoring case.
+fn contains_label(names: &[String], name: &str) -> bool {
+    names
+        .iter()
+        .any(|existing| existing.eq_ignore_ascii_case(name))
+}
+
+/// Builds the fallback label name for a commit type from the configured name format.
+fn fallback_change_type_label(commit_type: &str, config: &ChangeTypeLabelConfig) -> String {
+    config
+        .fallback_label_settings
+        .name_format
+        .replace("{change_type}", commit_type)
+}
+
+/// Parses a pull request title as a conventional commit with the types the
+/// configuration allows, or with the standard types without a configuration.
+fn parse_title_commit(
+    title: &str,
+    config: Option<&CurrentPullRequestValidationConfiguration>,
+) -> Option<ConventionalCommit> {
+    match config.and_then(|c| c.allowed_title_types()) {
+        Some(types) => parse_conventional_commit_with_types(title, types),
+        None => parse_conventional_commit(title),
+    }
+}
+
+/// Add hardcoded type-based label mapping (legacy behavior)
+fn add_hardcoded_type_label(labels: &mut Vec<String>, pr_type: &str) {
+    match pr_type {
+        "feat" => labels.push("feature".to_string()),
+        "fix" => labels.push("bug".to_string()),
+        "docs" => labels.push("documentation".to_string()),
+        "style" => labels.push("style".to_string()),
+        "refactor" => labels.push("refactor".to_string()),
+        "perf" => labels.push("performance".to_string()),
+        "test" => labels.push("testing".to_string()),
+        "build" => labels.push("build".to_string()),
+        "ci" => labels.push("ci".to_string()),
+        "chore" => labels.push("chore".to_string()),
+        "revert" => labels.push("revert".to_string()),
+        _ => {}
+    }
+}
+
+/// Determines whether a keyword match within `text` is negated by a preceding negation word.
+///
+/// Uses clause-boundary detection (`.`, `!`, `?`, `;`, `\n`) to scope the look-back,
+/// then inspects the 5-word window immediately before `keyword_span` for any word
+/// from [`NEGATION_SINGLE_WORDS`].
+///
+/// # Arguments
+///
+/// * `text` - The full text (lowercased) being searched.
+/// * `keyword_span` - The byte-range of the keyword match within `text`.
+///
+/// # Returns
+///
+/// `true` when a negation word appears within the 5-word window before the keyword
+/// and within the same clause; `false` otherwise.
+fn is_keyword_negated(text: &str, keyword_span: std::ops::Range<usize>) -> bool {
+    let before = &text[..keyword_span.start];
+
+    // Restrict look-back to current clause (stop at sentence / clause boundaries).
+    // Uses `find_clause_boundary` rather than a plain `rfind` so that `!` followed
+    // by `[` (a Markdown image link) is not treated as a sentence terminator.
+    let clause_start = find_clause_boundary(before).map(|i| i + 1).unwrap_or(0);
+    let clause_before = &before[clause_start..];
+
+    // Collect up to the last 5 whitespace-delimited tokens in this clause.
+    let tokens: Vec<&str> = clause_before.split_whitespace().collect();
+    let window_start = tokens.len().saturating_sub(5);
+    let window = &tokens[window_start..];
+
+    // `text` is always lowercased by callers, so no `.to_lowercase()` allocation is needed.
+    // `strip_punct` removes leading/trailing punctuation so `"no,"` and `"(no)"` both match.
+    window
+        .iter()
+        .any(|tok| NEGATION_SINGLE_WORDS.contains(&strip_punct(tok)))
+}
+
+/// Finds the byte index of the rightmost clause-boundary character in `s`.
+///
+/// The characters `.`, `?`, `;`, and `\n` are always treated as clause boundaries.
+/// `!` is treated as a clause boundary **unless** it is immediately followed by `[`,
+/// which is the start of a Markdown image link (`![](url)`) and must not split the clause.
+fn find_clause_boundary(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let len = bytes.len();
+    for i in (0..len).rev() {
+        match bytes[i] {
+            b'.' | b'?' | b';' | b'\n' => return Some(i),
+            b'!' => {
+                // `![` is Markdown image syntax — not a sentence boundary.
+                if i + 1 < len && bytes[i + 1] == b'[' {
+                    continue;
+                }
+                return Some(i);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Strips leading and trailing punctuation from a token, preserving apostrophes
+/// and hyphens that appear in negation contractions such as `"don't"` and `"won't"`.
+///
+/// Examples: `"no,"` → `"no"`, `"(no)"` → `"no"`, `"no:"` → `"no"`.
+fn strip_punct(s: &str) -> &str {
+    s.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'' && c != '-')
+}
+
+/// Parses label suppression commands from a list of PR comments.
+///
+/// A suppression command is a line of the form:
+/// ```text
+/// <bot_mention> suppress: <label-name>
+/// ```
+/// The bot mention prefix comparison is case-insensitive.  Unknown commands are
+/// silently ignored.
+///
+/// # Arguments
+///
+/// * `comments` - All comments fetched from the pull request.
+/// * `bot_mention` - The bot mention prefix configured for this installation.
+///
+/// # Returns
+///
+/// A map from label name (trimmed, lowercase) to the login of the first commenter
+/// who issued the suppression command for that label.
+fn parse_suppressed_labels(comments: &[Comment], bot_mention: &str) -> HashMap<String, String> {
+    let mut suppressed: HashMap<String, String> = HashMap::new();
+    let prefix = format!("{} suppress:", bot_mention.to_lowercase());
 
     for comment in comments {
         // Skip the bot's own explanation comments — they embed the suppress command
@@ -945,13 +1642,190 @@ pub async fn manage_wip_labels<P: PullRequestProvider>(
     Ok(())
 }
 
+/// Adds or removes the needs-rebase label on a pull request.
+///
+/// When `is_behind` is `true`, `label` is added if not already present. When
+/// `is_behind` is `false`, `label` is removed if present, so that it only stays
+/// on the PR while the PR is behind its base. Label names are compared ignoring case.
+///
+/// # Arguments
+///
+/// * `provider` - The Git provider implementation
+/// * `owner` - The owner of the repository
+/// * `repo` - The name of the repository
+/// * `pr_number` - The pull request number
+/// * `is_behind` - Whether the PR is behind its base branch by at least the threshold
+/// * `label` - The configured needs-rebase label name
+///
+/// # Returns
+///
+/// `true` when the PR carries the label afterwards; errors from the provider are
+/// propagated
+pub async fn manage_needs_rebase_label<P: PullRequestProvider>(
+    provider: &P,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    is_behind: bool,
+    label: &str,
+) -> Result<bool, MergeWardenError> {
+    if label.is_empty() {
+        debug!(
+            repository_owner = owner,
+            repository = repo,
+            pr_number = pr_number,
+            "Needs-rebase label is empty — skipping label management"
+        );
+        return Ok(false);
+    }
+
+    let current_pr_labels = provider
+        .list_applied_labels(owner, repo, pr_number)
+        .await
+        .map_err(|_| {
+            MergeWardenError::FailedToUpdatePullRequest(
+                "Failed to list current PR labels".to_string(),
+            )
+        })?;
+    let applied = current_pr_labels
+        .iter()
+        .find(|l| l.name.eq_ignore_ascii_case(label));
+
+    match (is_behind, applied) {
+        (true, None) => {
+            provider
+                .add_labels(owner, repo, pr_number, &[label.to_string()])
+                .await
+                .map_err(|e| {
+                    MergeWardenError::FailedToUpdatePullRequest(format!(
+                        "Failed to add needs-rebase label: {e}"
+                    ))
+                })?;
+            info!(
+                repository_owner = owner,
+                repository = repo,
+                pr_number = pr_number,
+                label = %label,
+                "Added needs-rebase label to pull request"
+            );
+        }
+        (false, Some(applied)) => {
+            provider
+                .remove_label(owner, repo, pr_number, &applied.name)
+                .await
+                .map_err(|e| {
+                    MergeWardenError::FailedToUpdatePullRequest(format!(
+                        "Failed to remove needs-rebase label: {e}"
+                    ))
+                })?;
+            info!(
+                repository_owner = owner,
+                repository = repo,
+                pr_number = pr_number,
+                label = %applied.name,
+                "Removed needs-rebase label from pull request"
+            );
+        }
+        _ => {}
+    }
+
+    Ok(is_behind)
+}
+
+/// Applies the review effort label to a pull request, replacing any other effort label.
+///
+/// Labels starting with `label_prefix` other than `label` are removed and `label` is
+/// added if not already present, so that the PR carries a single effort label. Label
+/// names are compared ignoring case.
+///
+/// # Arguments
+///
+/// * `provider` - The Git provider implementation
+/// * `owner` - The owner of the repository
+/// * `repo` - The name of the repository
+/// * `pr_number` - The pull request number
+/// * `label` - The effort label for the PR's score, e.g. `effort:3`
+/// * `label_prefix` - The prefix shared by all effort labels, e.g. `effort:`
+///
+/// # Returns
+///
+/// `true` when the label was added or another effort label removed; errors from the
+/// provider are propagated
+pub async fn manage_review_effort_label<P: PullRequestProvider>(
+    provider: &P,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    label: &str,
+    label_prefix: &str,
+) -> Result<bool, MergeWardenError> {
+    let current_pr_labels = provider
+        .list_applied_labels(owner, repo, pr_number)
+        .await
+        .map_err(|_| {
+            MergeWardenError::FailedToUpdatePullRequest(
+                "Failed to list current PR labels".to_string(),
+            )
+        })?;
+
+    let prefix = label_prefix.to_lowercase();
+    let stale: Vec<&Label> = current_pr_labels
+        .iter()
+        .filter(|l| {
+            !prefix.is_empty()
+                && l.name.to_lowercase().starts_with(&prefix)
+                && !l.name.eq_ignore_ascii_case(label)
+        })
+        .collect();
+    for stale_label in &stale {
+        provider
+            .remove_label(owner, repo, pr_number, &stale_label.name)
+            .await
+            .map_err(|e| {
+                MergeWardenError::FailedToUpdatePullRequest(format!(
+                    "Failed to remove review effort label: {e}"
+                ))
+            })?;
+        info!(
+            repository_owner = owner,
+            repository = repo,
+            pr_number = pr_number,
+            label = %stale_label.name,
+            "Removed stale review effort label from pull request"
+        );
+    }
+
+    if has_label(&current_pr_labels, label) {
+        return Ok(!stale.is_empty());
+    }
+
+    provider
+        .add_labels(owner, repo, pr_number, &[label.to_string()])
+        .await
+        .map_err(|e| {
+            MergeWardenError::FailedToUpdatePullRequest(format!(
+                "Failed to add review effort label: {e}"
+            ))
+        })?;
+    info!(
+        repository_owner = owner,
+        repository = repo,
+        pr_number = pr_number,
+        label = %label,
+        "Added review effort label to pull request"
+    );
+
+    Ok(true)
+}
+
 /// Manages size labels for a pull request based on file changes using smart label discovery.
 ///
 /// This function implements the smart label discovery strategy from the spec:
 /// 1. Discovers existing size labels in the repository using multiple detection patterns
 /// 2. Removes any existing size labels (exclusive labeling)
 /// 3. Applies the appropriate size label based on the PR's categorization
-/// 4. Falls back to creating new labels if none are found
+/// 4. Falls back to creating new labels if none are found, unless
+///    `create_missing_labels` is `false`
 ///
 /// # Arguments
 ///
@@ -962,17 +1836,23 @@ pub async fn manage_wip_labels<P: PullRequestProvider>(
 /// * `size_info` - Information about the PR's size and categorization
 /// * `label_prefix` - The prefix to use when constructing a fallback size label (e.g. `"size/"`)
 ///   when no matching label already exists in the repository.
+/// * `category_names` - The display names of the size categories, used for label discovery
+///   and for the fallback label name
+/// * `create_missing_labels` - Whether the fallback label may be created when it does not
+///   exist in the repository
 ///
 /// # Returns
 ///
-/// A `Result` containing the size label that was applied, or None if size labeling failed
+/// A `Result` containing the size label that was applied, or None if size labeling failed,
+/// the fallback label was skipped or the labels applied to the PR could not be listed. A
+/// listing failure is logged and leaves the PR's labels unchanged for this run.
 ///
 /// # Examples
 ///
 /// ```rust,no_run
 /// use merge_warden_developer_platforms::PullRequestProvider;
 /// use merge_warden_core::labels::manage_size_labels;
-/// use merge_warden_core::size::{PrSizeInfo, SizeThresholds};
+/// use merge_warden_core::size::{PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds};
 /// use merge_warden_developer_platforms::models::PullRequestFile;
 /// use anyhow::Result;
 ///
@@ -987,7 +1867,7 @@ pub async fn manage_wip_labels<P: PullRequestProvider>(
 ///         },
 ///     ];
 ///     let thresholds = SizeThresholds::default();
-///     let size_info = PrSizeInfo::from_files_with_exclusions(&files, &thresholds, &[], false);
+///     let size_info = PrSizeInfo::from_files_with_exclusions(&files, &thresholds, &[], SizeCountingMode::TotalChanges);
 ///
 ///     let label = manage_size_labels(
 ///         provider,
@@ -996,12 +1876,15 @@ pub async fn manage_wip_labels<P: PullRequestProvider>(
 ///         123,
 ///         &size_info,
 ///         "size/",
+///         &SizeCategoryNames::default(),
+///         true,
 ///     ).await?;
 ///
 ///     println!("Applied size label: {:?}", label);
 ///     Ok(())
 /// }
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub async fn manage_size_labels<P: PullRequestProvider>(
     provider: &P,
     owner: &str,
@@ -1009,6 +1892,8 @@ pub async fn manage_size_labels<P: PullRequestProvider>(
     pr_number: u64,
     size_info: &PrSizeInfo,
     label_prefix: &str,
+    category_names: &SizeCategoryNames,
+    create_missing_labels: bool,
 ) -> Result<Option<String>, MergeWardenError> {
     info!(
         "Starting size label management for PR {}/{}/{}. Size category: {}, Total changes: {}",
@@ -1025,25 +1910,33 @@ pub async fn manage_size_labels<P: PullRequestProvider>(
         owner, repo
     );
     let detector = LabelDetector::new_for_size_labels();
-    let discovered_labels = detector.discover_size_labels(provider, owner, repo).await?;
+    let discovered_labels = detector
+        .discover_size_labels_with_names(provider, owner, repo, category_names)
+        .await?;
     info!(
         "Label discovery completed. Found {} discovered labels",
         discovered_labels.count_discovered()
     );
 
-    // Step 2: Get current labels on the PR
+    // Step 2: Get current labels on the PR. Without them stale size labels cannot be
+    // found, so a listing failure skips size label reconciliation for this run.
     debug!(
         "Step 2: Getting current labels on PR {}/{}/{}",
         owner, repo, pr_number
     );
-    let current_pr_labels = provider
-        .list_applied_labels(owner, repo, pr_number)
-        .await
-        .map_err(|_| {
-            MergeWardenError::FailedToUpdatePullRequest(
-                "Failed to list current PR labels".to_string(),
-            )
-        })?;
+    let current_pr_labels = match provider.list_applied_labels(owner, repo, pr_number).await {
+        Ok(labels) => labels,
+        Err(e) => {
+            warn!(
+                repository_owner = owner,
+                repository = repo,
+                pr_number = pr_number,
+                error = %e,
+                "Failed to list the labels applied to the pull request; skipping size label reconciliation for this run"
+            );
+            return Ok(None);
+        }
+    };
 
     debug!(
         "Found {} current labels on PR: {:?}",
@@ -1060,24 +1953,17 @@ pub async fn manage_size_labels<P: PullRequestProvider>(
         "Step 3: Determining new size label for category: {}",
         size_info.size_category.as_str()
     );
-    let new_label_name =
-        if let Some(name) = discovered_labels.get_label_for_category(&size_info.size_category) {
-            name.clone()
-        } else {
-            // Fallback: use the configured label_prefix followed by the category name.
-            format!("{}{}", label_prefix, size_info.size_category.as_str())
-        };
-
-    // Collect the discovered size labels that are currently applied to the PR.
-    let existing_size_labels: Vec<String> = current_pr_labels
-        .iter()
-        .filter(|l| discovered_labels.all_discovered_labels().contains(&&l.name))
-        .map(|l| l.name.clone())
-        .collect();
+    let (new_label_name, changes) = plan_size_labels(
+        &discovered_labels,
+        &current_pr_labels,
+        size_info,
+        label_prefix,
+        category_names,
+    );
 
     // Do nothing when the PR already has exactly the right label and no others to clean up.
     // This avoids spurious remove + re-add events that create noise in the PR activity log.
-    if existing_size_labels.len() == 1 && existing_size_labels[0] == new_label_name {
+    if changes.is_empty() {
         debug!(
             repository_owner = owner,
             repository = repo,
@@ -1090,35 +1976,29 @@ pub async fn manage_size_labels<P: PullRequestProvider>(
 
     // Step 4: Remove existing size labels that differ from the new label (exclusive labeling)
     debug!("Step 4: Removing stale size labels from PR");
-    let mut removed_labels = Vec::new();
-    for existing_label in &existing_size_labels {
-        // Only remove labels that are NOT the one we are about to apply.
-        if existing_label != &new_label_name {
-            debug!("Removing stale size label: {}", existing_label);
-            provider
-                .remove_label(owner, repo, pr_number, existing_label)
-                .await
-                .map_err(|_| {
-                    MergeWardenError::FailedToUpdatePullRequest(
-                        "Failed to remove existing size label".to_string(),
-                    )
-                })?;
-            removed_labels.push(existing_label.clone());
-        }
+    for existing_label in &changes.to_remove {
+        debug!("Removing stale size label: {}", existing_label);
+        provider
+            .remove_label(owner, repo, pr_number, existing_label)
+            .await
+            .map_err(|_| {
+                MergeWardenError::FailedToUpdatePullRequest(
+                    "Failed to remove existing size label".to_string(),
+                )
+            })?;
     }
-    if !removed_labels.is_empty() {
+    if !changes.to_remove.is_empty() {
         info!(
             "Removed {} stale size labels: {:?}",
-            removed_labels.len(),
-            removed_labels
+            changes.to_remove.len(),
+            changes.to_remove
         );
     } else {
         debug!("No stale size labels found to remove");
     }
 
     // Step 5: Apply the new size label (only if not already present after cleanup)
-    let already_applied = existing_size_labels.contains(&new_label_name);
-    if already_applied {
+    if changes.to_add.is_empty() {
         debug!(
             repository_owner = owner,
             repository = repo,
@@ -1156,6 +2036,21 @@ pub async fn manage_size_labels<P: PullRequestProvider>(
         Ok(Some(label_name.clone()))
     } else {
         // Fallback: create new label with standard format (new_label_name already computed above)
+        if !create_missing_labels
+            && !find_missing_labels(provider, owner, repo, std::slice::from_ref(&new_label_name))
+                .await?
+                .is_empty()
+        {
+            info!(
+                repository_owner = owner,
+                repository = repo,
+                pr_number = pr_number,
+                label = %new_label_name,
+                "Skipped size label that does not exist in the repository; label creation is disabled"
+            );
+            return Ok(None);
+        }
+
         warn!(
             "No existing size label found for category '{}' in repository {}/{}. Using fallback label: '{}'",
             size_info.size_category.as_str(),
@@ -1198,7 +2093,9 @@ pub async fn manage_size_labels<P: PullRequestProvider>(
 ///
 /// This function creates a helpful comment that explains why the PR is considered
 /// oversized and provides suggestions for breaking it into smaller, more reviewable
-/// pieces.
+/// pieces, with the [`DEFAULT_COMMENT_BREAKDOWN_ROWS`] largest files and top-level
+/// directories. The text is rendered from the built-in
+/// [`CommentTemplate::OversizedPullRequest`] template.
 ///
 /// # Arguments
 ///
@@ -1212,7 +2109,7 @@ pub async fn manage_size_labels<P: PullRequestProvider>(
 ///
 /// ```
 /// use merge_warden_core::labels::generate_oversized_pr_comment;
-/// use merge_warden_core::size::{PrSizeInfo, SizeThresholds};
+/// use merge_warden_core::size::{PrSizeInfo, SizeCountingMode, SizeThresholds};
 /// use merge_warden_developer_platforms::models::PullRequestFile;
 ///
 /// let files = vec![
@@ -1225,48 +2122,88 @@ pub async fn manage_size_labels<P: PullRequestProvider>(
 ///     },
 /// ];
 /// let thresholds = SizeThresholds::default();
-/// let size_info = PrSizeInfo::from_files_with_exclusions(&files, &thresholds, &[], false);
+/// let size_info = PrSizeInfo::from_files_with_exclusions(&files, &thresholds, &[], SizeCountingMode::TotalChanges);
 ///
 /// let comment = generate_oversized_pr_comment(&size_info);
 /// assert!(comment.contains("XXL"));
 /// assert!(comment.contains("550 lines"));
 /// ```
 pub fn generate_oversized_pr_comment(size_info: &PrSizeInfo) -> String {
-    format!(
-        r#"## 📏 Pull Request Size Notice
-
-This PR has been labeled as `{category}` as it contains **{total_lines} lines** of changes across {file_count} files.
-
-### Why does PR size matter?
-
-Research shows that smaller PRs are:
-- ✅ **Reviewed more thoroughly** - reviewers can focus better on smaller changes
-- ✅ **Catch more bugs** - defect detection rates decrease significantly for large PRs
-- ✅ **Merged faster** - less time in review cycles
-- ✅ **Easier to understand** - simpler to reason about the changes
-
-### 💡 Consider breaking this PR into smaller pieces
-
-Large PRs can be challenging to review effectively. Consider:
-
-1. **Separate concerns** - Split unrelated changes into different PRs
-2. **Incremental changes** - Break features into smaller, logical steps
-3. **Preparatory PRs** - Create setup/refactoring PRs before the main feature
-4. **Documentation separately** - Move documentation updates to separate PRs
-
-### Size Breakdown
-- **Total lines changed**: {total_lines}
-- **Files modified**: {file_count}
-- **Category**: {category} ({category_description})
-
-*This is an automated message to help improve code review quality. If you believe this PR cannot be reasonably split, please add a comment explaining why.*"#,
-        category = size_info.size_category.as_str(),
-        total_lines = size_info.total_lines_changed,
-        file_count = size_info.included_files.len(),
-        category_description = get_category_description(&size_info.size_category)
+    generate_oversized_pr_comment_with_renderer(
+        size_info,
+        &SizeCategoryNames::default(),
+        DEFAULT_COMMENT_BREAKDOWN_ROWS,
+        &TemplateEngineRenderer::new(),
     )
 }
 
+/// Generate the oversized PR comment using a custom [`CommentRenderer`].
+///
+/// Renders [`CommentTemplate::OversizedPullRequest`] against an
+/// [`OversizedPrCommentContext`] built from `size_info`. Falls back to the
+/// built-in template if the renderer fails.
+///
+/// # Arguments
+///
+/// * `size_info` - Information about the PR's size and categorization
+/// * `category_names` - The display names of the size categories
+/// * `breakdown_rows` - The number of rows of the largest files and largest directories
+///   tables; `0` leaves them out
+/// * `renderer` - The renderer used to produce the comment text
+///
+/// # Returns
+///
+/// The rendered comment text
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::labels::generate_oversized_pr_comment_with_renderer;
+/// use merge_warden_core::size::{PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds};
+/// use merge_warden_core::templates::{CommentTemplate, TemplateEngineRenderer};
+/// use merge_warden_developer_platforms::models::PullRequestFile;
+///
+/// let files = vec![PullRequestFile {
+///     filename: "src/large_file.rs".to_string(),
+///     additions: 600,
+///     deletions: 0,
+///     changes: 600,
+///     status: "modified".to_string(),
+/// }];
+/// let size_info =
+///     PrSizeInfo::from_files_with_exclusions(&files, &SizeThresholds::default(), &[], SizeCountingMode::TotalChanges);
+///
+/// let renderer = TemplateEngineRenderer::new()
+///     .with_template(
+///         CommentTemplate::OversizedPullRequest,
+///         "{{ category }}: {{ total_lines }} lines",
+///     )
+///     .unwrap();
+/// let names = SizeCategoryNames {
+///     xxl: Some("huge".to_string()),
+///     ..Default::default()
+/// };
+/// let comment = generate_oversized_pr_comment_with_renderer(&size_info, &names, 5, &renderer);
+/// assert_eq!(comment, "huge: 600 lines");
+/// ```
+pub fn generate_oversized_pr_comment_with_renderer(
+    size_info: &PrSizeInfo,
+    category_names: &SizeCategoryNames,
+    breakdown_rows: usize,
+    renderer: &dyn CommentRenderer,
+) -> String {
+    let context = OversizedPrCommentContext {
+        category: category_names.name_for(size_info.size_category).to_string(),
+        category_description: get_category_description(&size_info.size_category).to_string(),
+        total_lines: size_info.total_lines_changed,
+        file_count: size_info.included_files.len(),
+        counting_mode: size_info.counting_mode.description().to_string(),
+        largest_files: size_info.largest_files(breakdown_rows),
+        largest_directories: size_info.largest_directories(breakdown_rows),
+    };
+    render_comment(renderer, CommentTemplate::OversizedPullRequest, &context)
+}
+
 /// Get a human-readable description for a size category.
 fn get_category_description(category: &PrSizeCategory) -> &'static str {
     match category {
@@ -1383,6 +2320,22 @@ impl LabelDetector {
         provider: &P,
         owner: &str,
         repo: &str,
+    ) -> Result<DiscoveredSizeLabels, MergeWardenError> {
+        self.discover_size_labels_with_names(provider, owner, repo, &SizeCategoryNames::default())
+            .await
+    }
+
+    /// Discover existing size labels, recognizing custom category names.
+    ///
+    /// Works like [`discover_size_labels`](Self::discover_size_labels), but for each
+    /// category a label matching its configured display name is preferred over one
+    /// matching the standard name (`XS` to `XXL`).
+    pub async fn discover_size_labels_with_names<P: PullRequestProvider>(
+        &self,
+        provider: &P,
+        owner: &str,
+        repo: &str,
+        category_names: &SizeCategoryNames,
     ) -> Result<DiscoveredSizeLabels, MergeWardenError> {
         info!(
             repository_owner = owner,
@@ -1406,11 +2359,37 @@ impl LabelDetector {
             "Retrieved repository labels for analysis"
         );
 
+        let discovered = self.find_size_labels(&all_labels, category_names);
+
+        let total_discovered = discovered.all_discovered_labels().len();
+        info!(
+            repository_owner = owner,
+            repository = repo,
+            total_discovered_labels = total_discovered,
+            discovered_xs = ?discovered.xs,
+            discovered_s = ?discovered.s,
+            discovered_m = ?discovered.m,
+            discovered_l = ?discovered.l,
+            discovered_xl = ?discovered.xl,
+            discovered_xxl = ?discovered.xxl,
+            "Completed smart label discovery"
+        );
+
+        Ok(discovered)
+    }
+
+    /// Finds the size labels among the given repository labels.
+    ///
+    /// The detection behind [`discover_size_labels_with_names`](Self::discover_size_labels_with_names),
+    /// without fetching the labels from the provider.
+    pub fn find_size_labels(
+        &self,
+        labels: &[Label],
+        category_names: &SizeCategoryNames,
+    ) -> DiscoveredSizeLabels {
         // Log all labels for debugging
-        for label in &all_labels {
+        for label in labels {
             debug!(
-                repository_owner = owner,
-                repository = repo,
                 label_name = %label.name,
                 label_description = ?label.description,
                 "Processing repository label"
@@ -1419,85 +2398,56 @@ impl LabelDetector {
 
         let mut discovered = DiscoveredSizeLabels::new();
 
-        // Define the size categories we're looking for
-        let categories = ["XS", "S", "M", "L", "XL", "XXL"];
-
-        for category in &categories {
+        for category in PrSizeCategory::ALL {
             debug!(
-                repository_owner = owner,
-                repository = repo,
-                category = category,
+                category = category.as_str(),
                 "Searching for size labels matching category"
             );
 
-            let best_label =
-                self.find_best_label_for_size_category(&all_labels, category, owner, repo);
+            // A configured display name takes priority over the standard category name.
+            let best_label = category_names
+                .custom_name(category)
+                .into_iter()
+                .chain(std::iter::once(category.as_str()))
+                .find_map(|name| self.find_best_label_for_size_category(labels, name));
 
             if let Some(ref label_name) = best_label {
                 info!(
-                    repository_owner = owner,
-                    repository = repo,
-                    category = category,
+                    category = category.as_str(),
                     discovered_label = %label_name,
                     "Found size label for category"
                 );
             } else {
                 warn!(
-                    repository_owner = owner,
-                    repository = repo,
-                    category = category,
+                    category = category.as_str(),
                     "No size label found for category"
                 );
             }
 
-            match *category {
-                "XS" => discovered.xs = best_label,
-                "S" => discovered.s = best_label,
-                "M" => discovered.m = best_label,
-                "L" => discovered.l = best_label,
-                "XL" => discovered.xl = best_label,
-                "XXL" => discovered.xxl = best_label,
-                _ => {}
+            match category {
+                PrSizeCategory::XS => discovered.xs = best_label,
+                PrSizeCategory::S => discovered.s = best_label,
+                PrSizeCategory::M => discovered.m = best_label,
+                PrSizeCategory::L => discovered.l = best_label,
+                PrSizeCategory::XL => discovered.xl = best_label,
+                PrSizeCategory::XXL => discovered.xxl = best_label,
             }
         }
 
-        let total_discovered = discovered.all_discovered_labels().len();
-        info!(
-            repository_owner = owner,
-            repository = repo,
-            total_discovered_labels = total_discovered,
-            discovered_xs = ?discovered.xs,
-            discovered_s = ?discovered.s,
-            discovered_m = ?discovered.m,
-            discovered_l = ?discovered.l,
-            discovered_xl = ?discovered.xl,
-            discovered_xxl = ?discovered.xxl,
-            "Completed smart label discovery"
-        );
-
-        Ok(discovered)
+        discovered
     }
 
     /// Find the best matching label for a size category using priority-based selection
     fn find_best_label_for_size_category(
-        &self,
-        labels: &[Label],
-        category: &str,
-        owner: &str,
-        repo: &str,
-    ) -> Option<String> {
-        debug!(
-            repository_owner = owner,
-            repository = repo,
-            category = category,
-            "Starting priority-based label search"
-        );
+        &self,
+        labels: &[Label],
+        category: &str,
+    ) -> Option<String> {
+        debug!(category = category, "Starting priority-based label search");
 
         // Priority 1: Exact size match - size/XS, size/S, etc.
         if let Some(label) = self.find_exact_size_match(labels, category) {
             info!(
-                repository_owner = owner,
-                repository = repo,
                 category = category,
                 found_label = %label.name,
                 detection_method = "exact_size_match",
@@ -1510,8 +2460,6 @@ impl LabelDetector {
         // Priority 2: Size with separator - size-M, size_L, size: M, etc.
         if let Some(label) = self.find_size_with_separator(labels, category) {
             info!(
-                repository_owner = owner,
-                repository = repo,
                 category = category,
                 found_label = %label.name,
                 detection_method = "size_with_separator",
@@ -1524,12 +2472,10 @@ impl LabelDetector {
         // Priority 3: Standalone size - XS, M, XXL, etc.
         if let Some(label) = self.find_standalone_size(labels, category) {
             info!(
-                repository_owner = owner,
-                repository = repo,
                 category = category,
                 found_label = %label.name,
                 detection_method = "standalone_size",
-                pattern = format!("^{}$", category),
+                pattern = format!("(?i)^{}$", category),
                 "Found size label using standalone match"
             );
             return Some(label.name.clone());
@@ -1538,8 +2484,6 @@ impl LabelDetector {
         // Priority 4: Description-based - any label with (size: M) in description
         if let Some(label) = self.find_description_based(labels, category) {
             info!(
-                repository_owner = owner,
-                repository = repo,
                 category = category,
                 found_label = %label.name,
                 detection_method = "description_based",
@@ -1551,8 +2495,6 @@ impl LabelDetector {
         }
 
         debug!(
-            repository_owner = owner,
-            repository = repo,
             category = category,
             total_labels_checked = labels.len(),
             "No matching size label found for category after checking all patterns"
@@ -1624,7 +2566,7 @@ impl LabelDetector {
 
     /// Find standalone size: XS, M, XXL, etc.
     fn find_standalone_size<'a>(&self, labels: &'a [Label], category: &str) -> Option<&'a Label> {
-        let pattern = format!(r"^{}$", regex::escape(category));
+        let pattern = format!(r"(?i)^{}$", regex::escape(category));
         let regex = Regex::new(&pattern).ok()?;
 
         debug!(
@@ -1634,7 +2576,7 @@ impl LabelDetector {
         );
 
         for label in labels {
-            let matches = regex.is_match(&label.name.to_uppercase());
+            let matches = regex.is_match(&label.name);
             debug!(
                 "Standalone size check: label '{}' against pattern '{}' = {}",
                 label.name, pattern, matches
@@ -1710,11 +2652,9 @@ impl LabelDetector {
         repo: &str,
         commit_type: &str,
     ) -> Result<DiscoveredChangeTypeLabels, MergeWardenError> {
-        let config = self.change_type_config.as_ref().ok_or_else(|| {
-            MergeWardenError::ConfigError(
-                "Change type configuration not provided to LabelDetector".to_string(),
-            )
-        })?;
+        if self.change_type_config.is_none() {
+            return Err(Self::missing_change_type_config());
+        }
 
         info!(
             repository_owner = owner,
@@ -1741,12 +2681,32 @@ impl LabelDetector {
             "Retrieved repository labels for change type detection"
         );
 
+        self.find_change_type_label(&all_labels, commit_type)
+    }
+
+    /// Finds the label for a conventional commit type among the given repository labels.
+    ///
+    /// The detection behind [`detect_change_type_label`](Self::detect_change_type_label),
+    /// without fetching the labels from the provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MergeWardenError::ConfigError`] when the detector has no change type
+    /// configuration.
+    pub fn find_change_type_label(
+        &self,
+        labels: &[Label],
+        commit_type: &str,
+    ) -> Result<DiscoveredChangeTypeLabels, MergeWardenError> {
+        let config = self
+            .change_type_config
+            .as_ref()
+            .ok_or_else(Self::missing_change_type_config)?;
+
         // Get mapped label names for this commit type
         let mapped_labels = self.get_mapped_label_names(commit_type, config);
 
         debug!(
-            repository_owner = owner,
-            repository = repo,
             commit_type = commit_type,
             mapped_labels = ?mapped_labels,
             "Retrieved mapped label names for commit type"
@@ -1754,9 +2714,7 @@ impl LabelDetector {
 
         // Tier 1: Exact match detection
         if config.detection_strategy.exact_match {
-            if let Some(label) =
-                self.find_exact_match(&all_labels, &mapped_labels, owner, repo, commit_type)
-            {
+            if let Some(label) = self.find_exact_match(labels, &mapped_labels, commit_type) {
                 return Ok(DiscoveredChangeTypeLabels {
                     label_name: Some(label),
                     commit_type: commit_type.to_string(),
@@ -1767,7 +2725,7 @@ impl LabelDetector {
 
         // Tier 2: Prefix match detection
         if config.detection_strategy.prefix_match {
-            if let Some(label) = self.find_prefix_match(&all_labels, commit_type, owner, repo) {
+            if let Some(label) = self.find_prefix_match(labels, commit_type) {
                 return Ok(DiscoveredChangeTypeLabels {
                     label_name: Some(label),
                     commit_type: commit_type.to_string(),
@@ -1778,8 +2736,7 @@ impl LabelDetector {
 
         // Tier 3: Description match detection
         if config.detection_strategy.description_match {
-            if let Some(label) = self.find_description_match(&all_labels, commit_type, owner, repo)
-            {
+            if let Some(label) = self.find_description_match(labels, commit_type) {
                 return Ok(DiscoveredChangeTypeLabels {
                     label_name: Some(label),
                     commit_type: commit_type.to_string(),
@@ -1790,8 +2747,6 @@ impl LabelDetector {
 
         // No match found - should create fallback if enabled
         warn!(
-            repository_owner = owner,
-            repository = repo,
             commit_type = commit_type,
             "No existing label found for commit type"
         );
@@ -1803,6 +2758,13 @@ impl LabelDetector {
         })
     }
 
+    /// The error returned when change type detection is asked for without configuration.
+    fn missing_change_type_config() -> MergeWardenError {
+        MergeWardenError::ConfigError(
+            "Change type configuration not provided to LabelDetector".to_string(),
+        )
+    }
+
     /// Get mapped label names for a conventional commit type
     fn get_mapped_label_names(
         &self,
@@ -1861,23 +2823,14 @@ impl LabelDetector {
         &self,
         labels: &[Label],
         mapped_labels: &[String],
-        owner: &str,
-        repo: &str,
         commit_type: &str,
     ) -> Option<String> {
-        debug!(
-            repository_owner = owner,
-            repository = repo,
-            commit_type = commit_type,
-            "Starting exact match detection"
-        );
+        debug!(commit_type = commit_type, "Starting exact match detection");
 
         for mapped_label in mapped_labels {
             for label in labels {
                 if label.name.eq_ignore_ascii_case(mapped_label) {
                     info!(
-                        repository_owner = owner,
-                        repository = repo,
                         commit_type = commit_type,
                         found_label = %label.name,
                         mapped_label = %mapped_label,
@@ -1889,44 +2842,15 @@ impl LabelDetector {
             }
         }
 
-        debug!(
-            repository_owner = owner,
-            repository = repo,
-            commit_type = commit_type,
-            "No exact match found"
-        );
+        debug!(commit_type = commit_type, "No exact match found");
         None
     }
 
     /// Find prefix match for commit type
-    fn find_prefix_match(
-        &self,
-        labels: &[Label],
-        commit_type: &str,
-        owner: &str,
-        repo: &str,
-    ) -> Option<String> {
-        debug!(
-            repository_owner = owner,
-            repository = repo,
-            commit_type = commit_type,
-            "Starting prefix match detection"
-        );
+    fn find_prefix_match(&self, labels: &[Label], commit_type: &str) -> Option<String> {
+        debug!(commit_type = commit_type, "Starting prefix match detection");
 
-        // Define possible prefix patterns
-        let prefix_patterns = vec![
-            format!("{}:", commit_type),
-            format!("{}-", commit_type),
-            format!("{}_", commit_type), // matches "feat_<anything>" labels
-            format!("type: {}", commit_type),
-            format!("type-{}", commit_type),
-            format!("type_{}", commit_type),
-            format!("kind: {}", commit_type),
-            format!("kind-{}", commit_type),
-            format!("kind_{}", commit_type),
-        ];
-
-        for pattern in &prefix_patterns {
+        for pattern in &change_type_prefix_patterns(commit_type) {
             for label in labels {
                 if label
                     .name
@@ -1934,8 +2858,6 @@ impl LabelDetector {
                     .starts_with(&pattern.to_lowercase())
                 {
                     info!(
-                        repository_owner = owner,
-                        repository = repo,
                         commit_type = commit_type,
                         found_label = %label.name,
                         prefix_pattern = %pattern,
@@ -1947,26 +2869,13 @@ impl LabelDetector {
             }
         }
 
-        debug!(
-            repository_owner = owner,
-            repository = repo,
-            commit_type = commit_type,
-            "No prefix match found"
-        );
+        debug!(commit_type = commit_type, "No prefix match found");
         None
     }
 
     /// Find description match for commit type
-    fn find_description_match(
-        &self,
-        labels: &[Label],
-        commit_type: &str,
-        owner: &str,
-        repo: &str,
-    ) -> Option<String> {
+    fn find_description_match(&self, labels: &[Label], commit_type: &str) -> Option<String> {
         debug!(
-            repository_owner = owner,
-            repository = repo,
             commit_type = commit_type,
             "Starting description match detection"
         );
@@ -1980,8 +2889,6 @@ impl LabelDetector {
                 // Look for the commit type in the description
                 if description_lower.contains(&commit_type_lower) {
                     info!(
-                        repository_owner = owner,
-                        repository = repo,
                         commit_type = commit_type,
                         found_label = %label.name,
                         label_description = %description,
@@ -1993,14 +2900,65 @@ impl LabelDetector {
             }
         }
 
-        debug!(
-            repository_owner = owner,
-            repository = repo,
-            commit_type = commit_type,
-            "No description match found"
-        );
+        debug!(commit_type = commit_type, "No description match found");
         None
     }
+
+    /// Returns `true` if `label_name` belongs to the configured change-type label set.
+    ///
+    /// For every conventional commit type the set holds the mapped label names, the
+    /// labels the prefix match detects (e.g. `type: feat`) when it is enabled, and the
+    /// fallback label name. Labels found by the description match are not part of
+    /// the set, since any label may mention a commit type in its description.
+    /// Matching ignores case. Always `false` without a change type configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::ChangeTypeLabelConfig;
+    /// use merge_warden_core::labels::LabelDetector;
+    ///
+    /// let detector = LabelDetector::new_for_change_type_labels(ChangeTypeLabelConfig::default());
+    /// assert!(detector.is_change_type_label("Bug"));
+    /// assert!(detector.is_change_type_label("type: feat"));
+    /// assert!(!detector.is_change_type_label("good first issue"));
+    /// ```
+    pub fn is_change_type_label(&self, label_name: &str) -> bool {
+        let Some(config) = self.change_type_config.as_ref() else {
+            return false;
+        };
+
+        let lowercase_name = label_name.to_lowercase();
+        VALID_PR_TYPES.iter().any(|commit_type| {
+            self.get_mapped_label_names(commit_type, config)
+                .iter()
+                .any(|mapped| mapped.eq_ignore_ascii_case(label_name))
+                || config
+                    .fallback_label_settings
+                    .name_format
+                    .replace("{change_type}", commit_type)
+                    .eq_ignore_ascii_case(label_name)
+                || (config.detection_strategy.prefix_match
+                    && change_type_prefix_patterns(commit_type)
+                        .iter()
+                        .any(|pattern| lowercase_name.starts_with(&pattern.to_lowercase())))
+        })
+    }
+}
+
+/// The label name prefixes the prefix match looks for, e.g. `type: feat`.
+fn change_type_prefix_patterns(commit_type: &str) -> Vec<String> {
+    vec![
+        format!("{}:", commit_type),
+        format!("{}-", commit_type),
+        format!("{}_", commit_type), // matches "feat_<anything>" labels
+        format!("type: {}", commit_type),
+        format!("type-{}", commit_type),
+        format!("type_{}", commit_type),
+        format!("kind: {}", commit_type),
+        format!("kind-{}", commit_type),
+        format!("kind_{}", commit_type),
+    ]
 }
 
 /// Result of change type label detection
@@ -2025,6 +2983,9 @@ pub struct LabelManagementResult {
     pub created_fallback_labels: Vec<String>,
     /// Any labels that failed to be applied
     pub failed_labels: Vec<String>,
+    /// Labels that were not applied because they do not exist in the repository and
+    /// label creation is disabled
+    pub skipped_labels: Vec<String>,
     /// Error messages for any failures (non-blocking)
     pub error_messages: Vec<String>,
 }
@@ -2043,6 +3004,7 @@ impl LabelManagementResult {
             removed_labels: Vec::new(),
             created_fallback_labels: Vec::new(),
             failed_labels: Vec::new(),
+            skipped_labels: Vec::new(),
             error_messages: Vec::new(),
         }
     }
@@ -2096,6 +3058,8 @@ impl LabelManager {
     /// 2. Applies intelligent label selection based on commit type
     /// 3. Creates fallback labels if no existing labels are found
     /// 4. Handles label application with proper error handling
+    /// 5. Removes other change-type labels, such as the `bug` label of an earlier
+    ///    `fix:` title, so a PR carries only the label of its current type
     ///
     /// # Arguments
     ///
@@ -2279,6 +3243,21 @@ impl LabelManager {
                     }
                 }
             }
+
+            // Step 4: Remove the labels left by an earlier title type
+            let current_labels = result.all_applied_labels();
+            if !current_labels.is_empty() {
+                self.remove_stale_change_type_labels(
+                    provider,
+                    owner,
+                    repo,
+                    pr_number,
+                    &detector,
+                    &current_labels,
+                    &mut result,
+                )
+                .await;
+            }
         } else {
             // No configuration provided, use hardcoded defaults
             debug!(
@@ -2325,6 +3304,78 @@ impl LabelManager {
         Ok(result)
     }
 
+    /// Removes the change-type labels other than `current_labels` from a pull request,
+    /// so that only the label of the current title type remains after the type changed.
+    ///
+    /// A label is a change-type label when [`LabelDetector::is_change_type_label`] says so.
+    /// Removed labels are recorded in `result.removed_labels`; failures are recorded in
+    /// `result.error_messages`.
+    #[allow(clippy::too_many_arguments)]
+    async fn remove_stale_change_type_labels<P: PullRequestProvider>(
+        &self,
+        provider: &P,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        detector: &LabelDetector,
+        current_labels: &[String],
+        result: &mut LabelManagementResult,
+    ) {
+        let applied_labels = match provider.list_applied_labels(owner, repo, pr_number).await {
+            Ok(labels) => labels,
+            Err(e) => {
+                warn!(
+                    repository_owner = owner,
+                    repository = repo,
+                    pr_number = pr_number,
+                    error = %e,
+                    "Failed to list the PR's labels, stale change type labels were not removed"
+                );
+                return;
+            }
+        };
+
+        for label in applied_labels {
+            let is_current = current_labels
+                .iter()
+                .any(|current| current.eq_ignore_ascii_case(&label.name));
+            if is_current || !detector.is_change_type_label(&label.name) {
+                continue;
+            }
+
+            match provider
+                .remove_label(owner, repo, pr_number, &label.name)
+                .await
+            {
+                Ok(()) => {
+                    info!(
+                        repository_owner = owner,
+                        repository = repo,
+                        pr_number = pr_number,
+                        removed_label = %label.name,
+                        "Removed stale change type label"
+                    );
+                    result.removed_labels.push(label.name);
+                }
+                Err(e) => {
+                    let error_msg = format!(
+                        "Failed to remove stale change type label '{}': {}",
+                        label.name, e
+                    );
+                    warn!(
+                        repository_owner = owner,
+                        repository = repo,
+                        pr_number = pr_number,
+                        failed_label = %label.name,
+                        error = %e,
+                        "Failed to remove stale change type label"
+                    );
+                    result.error_messages.push(error_msg);
+                }
+            }
+        }
+    }
+
     /// Apply a single label to a pull request
     async fn apply_label<P: PullRequestProvider>(
         &self,
@@ -2380,15 +3431,12 @@ impl LabelManager {
         commit_type: &str,
         config: &ChangeTypeLabelConfig,
     ) -> String {
-        // Use the configured fallback format, defaulting to a standard pattern
-        let format = &config.fallback_label_settings.name_format;
-
-        // Replace {change_type} placeholder with the actual commit type
-        let fallback_label = format.replace("{change_type}", commit_type);
+        // Replace the {change_type} placeholder of the configured format
+        let fallback_label = fallback_change_type_label(commit_type, config);
 
         debug!(
             commit_type = commit_type,
-            format_template = %format,
+            format_template = %config.fallback_label_settings.name_format,
             generated_label = %fallback_label,
             "Generated fallback label using configured format"
         );
@@ -2587,73 +3635,73 @@ impl LabelManager {
 
         let body_lower = body.to_lowercase();
 
-        // Define keyword mappings
-        let keyword_mappings = vec![
-            (vec!["security", "vulnerability"], "security"),
-            (vec!["hotfix"], "hotfix"),
-            (vec!["technical debt", "tech debt"], "tech-debt"),
-        ];
+        let default_kw = KeywordLabelsConfig::default();
+        let keyword_labels = self
+            .config
+            .as_ref()
+            .map_or(&default_kw, |c| &c.keyword_labels);
 
-        for (keywords, default_label) in keyword_mappings {
-            let has_keyword = keywords.iter().any(|keyword| body_lower.contains(keyword));
+        for matcher in resolve_keyword_label_matchers(keyword_labels) {
+            if !matcher.is_triggered("", &body_lower) {
+                continue;
+            }
 
-            if has_keyword {
-                info!(
-                    repository_owner = owner,
-                    repository = repo,
-                    pr_number = pr_number,
-                    keywords = ?keywords,
-                    default_label = default_label,
-                    "Found keyword match, applying smart labeling"
-                );
+            info!(
+                repository_owner = owner,
+                repository = repo,
+                pr_number = pr_number,
+                keyword_label = %matcher.label,
+                "Found keyword match, applying smart labeling"
+            );
 
-                let label_to_apply = if let Some(ref config) = self.config {
-                    // Try to detect existing label using detection
+            // Built-in labels may map onto an existing repository label; custom rules name
+            // the exact label to apply.
+            let label_to_apply = match (&self.config, matcher.builtin) {
+                (Some(config), Some(_)) => {
                     let detector = LabelDetector::new_for_change_type_labels(config.clone());
 
                     match detector
-                        .detect_change_type_label(provider, owner, repo, default_label)
+                        .detect_change_type_label(provider, owner, repo, &matcher.label)
                         .await
                     {
                         Ok(detection_result) => detection_result.label_name.unwrap_or_else(|| {
-                            self.generate_fallback_label_name(default_label, config)
+                            self.generate_fallback_label_name(&matcher.label, config)
                         }),
-                        Err(_) => default_label.to_string(),
+                        Err(_) => matcher.label.clone(),
                     }
-                } else {
-                    default_label.to_string()
-                };
+                }
+                _ => matcher.label.clone(),
+            };
 
-                // Apply the keyword-based label
-                match self
-                    .apply_label(provider, owner, repo, pr_number, &label_to_apply)
-                    .await
-                {
-                    Ok(()) => {
-                        result.applied_labels.push(label_to_apply.clone());
-                        info!(
-                            repository_owner = owner,
-                            repository = repo,
-                            pr_number = pr_number,
-                            applied_label = %label_to_apply,
-                            matched_keywords = ?keywords,
-                            "Successfully applied keyword-based label"
-                        );
-                    }
-                    Err(e) => {
-                        let error_msg =
-                            format!("Failed to apply keyword label '{}': {}", label_to_apply, e);
-                        warn!(
-                            repository_owner = owner,
-                            repository = repo,
-                            pr_number = pr_number,
-                            failed_label = %label_to_apply,
-                            error = %e,
-                            "Failed to apply keyword-based label"
-                        );
-                        result.failed_labels.push(label_to_apply);
-                        result.error_messages.push(error_msg);
-                    }
+            // Apply the keyword-based label
+            match self
+                .apply_label(provider, owner, repo, pr_number, &label_to_apply)
+                .await
+            {
+                Ok(()) => {
+                    result.applied_labels.push(label_to_apply.clone());
+                    info!(
+                        repository_owner = owner,
+                        repository = repo,
+                        pr_number = pr_number,
+                        applied_label = %label_to_apply,
+                        keyword_label = %matcher.label,
+                        "Successfully applied keyword-based label"
+                    );
+                }
+                Err(e) => {
+                    let error_msg =
+                        format!("Failed to apply keyword label '{}': {}", label_to_apply, e);
+                    warn!(
+                        repository_owner = owner,
+                        repository = repo,
+                        pr_number = pr_number,
+                        failed_label = %label_to_apply,
+                        error = %e,
+                        "Failed to apply keyword-based label"
+                    );
+                    result.failed_labels.push(label_to_apply);
+                    result.error_messages.push(error_msg);
                 }
             }
         }
@@ -2979,3 +4027,161 @@ pub async fn manage_renovate_stability_label<P: PullRequestProvider + Sync>(
 
     Ok(())
 }
+
+/// Returns `true` if the pull request updates dependencies according to `config`.
+///
+/// The PR is a dependency update when its conventional commit title has one of the
+/// configured types or scopes, or when its changed files look like a dependency bump:
+/// a lockfile changed and, with `require_only_dependency_files`, every other changed
+/// file is a lockfile or manifest. Only file paths are inspected; the diff itself is
+/// not available, so a lockfile stands in for a change to a manifest's dependency
+/// section.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::DependencyUpdateConfig;
+/// use merge_warden_core::labels::is_dependency_update;
+/// use merge_warden_developer_platforms::models::{PullRequest, PullRequestFile};
+///
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "build: bump serde to 1.0.200".to_string(),
+///     draft: false,
+///     body: None,
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
+/// };
+/// let file = |name: &str| PullRequestFile {
+///     filename: name.to_string(),
+///     additions: 1,
+///     deletions: 1,
+///     changes: 2,
+///     status: "modified".to_string(),
+/// };
+/// let config = DependencyUpdateConfig::default();
+///
+/// assert!(is_dependency_update(&pr, &[file("Cargo.toml"), file("Cargo.lock")], &config));
+/// assert!(!is_dependency_update(&pr, &[file("Cargo.lock"), file("src/lib.rs")], &config));
+/// ```
+pub fn is_dependency_update(
+    pr: &PullRequest,
+    files: &[PullRequestFile],
+    config: &DependencyUpdateConfig,
+) -> bool {
+    if let Some(captures) = DEPENDENCY_TITLE_PREFIX_RE.captures(&pr.title) {
+        let matches_any = |value: &str, candidates: &[String]| {
+            candidates.iter().any(|c| c.eq_ignore_ascii_case(value))
+        };
+        if matches_any(&captures[1], &config.title_types) {
+            return true;
+        }
+        if let Some(scope) = captures.get(2) {
+            if matches_any(scope.as_str(), &config.title_scopes) {
+                return true;
+            }
+        }
+    }
+
+    if config.lockfile_patterns.is_empty() {
+        return false;
+    }
+
+    let lockfiles = FileExclusions::new(&config.lockfile_patterns);
+    let manifests = FileExclusions::new(&config.manifest_patterns);
+    let has_lockfile_change = files.iter().any(|f| lockfiles.is_excluded(&f.filename));
+    if !has_lockfile_change {
+        return false;
+    }
+
+    !config.require_only_dependency_files
+        || files
+            .iter()
+            .all(|f| lockfiles.is_excluded(&f.filename) || manifests.is_excluded(&f.filename))
+}
+
+/// Applies the dependencies label when the pull request updates dependencies.
+///
+/// Uses [`is_dependency_update`] to decide. The label is created in the repository when
+/// it does not exist yet. It is never removed, so a manually applied label is kept.
+///
+/// This function is a no-op when `config.enabled` is `false` or the PR is not a
+/// dependency update.
+///
+/// # Arguments
+///
+/// * `provider`    - The Git provider implementation
+/// * `repo_owner`  - Repository owner
+/// * `repo_name`   - Repository name
+/// * `pr`          - The pull request
+/// * `files`       - The files changed in the pull request
+/// * `config`      - Dependency-update detection configuration
+///
+/// # Returns
+///
+/// `true` when the label was applied
+pub async fn manage_dependency_label<P: PullRequestProvider + Sync>(
+    provider: &P,
+    repo_owner: &str,
+    repo_name: &str,
+    pr: &PullRequest,
+    files: &[PullRequestFile],
+    config: &DependencyUpdateConfig,
+) -> Result<bool, MergeWardenError> {
+    if !config.enabled || !is_dependency_update(pr, files, config) {
+        return Ok(false);
+    }
+
+    let available = provider
+        .list_available_labels(repo_owner, repo_name)
+        .await
+        .map_err(|e| {
+            MergeWardenError::FailedToUpdatePullRequest(format!(
+                "Failed to list repository labels: {e}"
+            ))
+        })?;
+
+    if !available.iter().any(|l| l.name == config.label) {
+        provider
+            .create_label(
+                repo_owner,
+                repo_name,
+                &config.label,
+                "0366d6",
+                Some("Pull requests that update dependencies"),
+            )
+            .await
+            .map_err(|e| {
+                MergeWardenError::FailedToUpdatePullRequest(format!(
+                    "Failed to create dependencies label: {e}"
+                ))
+            })?;
+    }
+
+    provider
+        .add_labels(
+            repo_owner,
+            repo_name,
+            pr.number,
+            std::slice::from_ref(&config.label),
+        )
+        .await
+        .map_err(|e| {
+            MergeWardenError::FailedToUpdatePullRequest(format!(
+                "Failed to add dependencies label: {e}"
+            ))
+        })?;
+
+    info!(
+        repository_owner = repo_owner,
+        repository = repo_name,
+        pr_number = pr.number,
+        label = %config.label,
+        "Applied dependencies label"
+    );
+
+    Ok(true)
+}
@@ -5,7 +5,7 @@ use std::sync::{Arc, Mutex};
 use tokio::test;
 
 use merge_warden_developer_platforms::models::{
-    Comment, CommitStatus, Label, PullRequest, PullRequestFile, Review, User,
+    CheckRunAction, Comment, CommitStatus, Label, PullRequest, PullRequestFile, Review, User,
 };
 use merge_warden_developer_platforms::PullRequestProvider;
 
@@ -90,6 +90,7 @@ impl PullRequestProvider for WipMockProvider {
             applied.push(Label {
                 name: l.clone(),
                 description: None,
+                color: None,
             });
         }
         Ok(())
@@ -125,6 +126,7 @@ impl PullRequestProvider for WipMockProvider {
         _title: &str,
         _summary: &str,
         _text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -253,6 +255,7 @@ impl PullRequestProvider for MockGitProvider {
             current_labels.push(Label {
                 name: label.clone(),
                 description: None,
+                color: None,
             });
         }
         Ok(())
@@ -287,6 +290,7 @@ impl PullRequestProvider for MockGitProvider {
         _output_title: &str,
         _output_summary: &str,
         _output_text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -405,6 +409,7 @@ impl PullRequestProvider for ErrorMockGitProvider {
         _output_title: &str,
         _output_summary: &str,
         _output_text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         unimplemented!("Not needed for this test")
     }
@@ -468,6 +473,8 @@ async fn test_determine_labels_breaking_change() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -498,6 +505,8 @@ async fn test_determine_labels_breaking_change_in_body() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -528,6 +537,8 @@ async fn test_determine_labels_bug_fix() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -559,6 +570,8 @@ async fn test_determine_labels_conflicting_information() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -589,6 +602,8 @@ async fn test_determine_labels_empty_pr_body() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -612,6 +627,8 @@ async fn test_determine_labels_error_handling() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let result = set_pull_request_labels(&provider, "owner", "repo", &pr).await;
@@ -642,6 +659,8 @@ async fn test_determine_labels_feature() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -670,6 +689,8 @@ async fn test_determine_labels_hotfix() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -700,6 +721,8 @@ async fn test_determine_labels_invalid_type_in_pr_title() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -726,6 +749,8 @@ async fn test_determine_labels_keyword_priority() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -761,6 +786,8 @@ async fn test_determine_labels_missing_type_in_pr_title() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -786,6 +813,8 @@ async fn test_determine_labels_multiple_keywords() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -820,6 +849,8 @@ async fn test_determine_labels_no_keywords_in_pr_body() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -846,6 +877,8 @@ async fn test_determine_labels_security() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -876,6 +909,8 @@ async fn test_determine_labels_tech_debt() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -906,6 +941,8 @@ async fn test_determine_labels_with_scope() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels(&provider, "owner", "repo", &pr)
@@ -923,8 +960,9 @@ async fn test_determine_labels_with_scope() {
 // Additional imports for smart label detection tests
 use super::{build_keyword_label_comment, is_keyword_negated, parse_suppressed_labels};
 use crate::config::{
-    ChangeTypeLabelConfig, ConventionalCommitMappings, CurrentPullRequestValidationConfiguration,
-    FallbackLabelSettings, KeywordLabelsConfig, LabelDetectionStrategy,
+    AutoLabelCategories, BuiltinKeywordLabel, ChangeTypeLabelConfig, ConventionalCommitMappings,
+    CurrentPullRequestValidationConfiguration, FallbackLabelSettings, KeywordLabelRule,
+    KeywordLabelsConfig, LabelDetectionStrategy, TitleLintPolicyConfig,
     KEYWORD_LABEL_COMMENT_MARKER,
 };
 use crate::labels::{
@@ -1023,6 +1061,7 @@ impl PullRequestProvider for SmartMockGitProvider {
             current_labels.push(Label {
                 name: label.clone(),
                 description: None,
+                color: None,
             });
         }
         Ok(())
@@ -1033,8 +1072,9 @@ impl PullRequestProvider for SmartMockGitProvider {
         _repo_owner: &str,
         _repo_name: &str,
         _pr_number: u64,
-        _label: &str,
+        label: &str,
     ) -> Result<(), Error> {
+        self.labels.lock().unwrap().retain(|l| l.name != label);
         Ok(())
     }
 
@@ -1057,6 +1097,7 @@ impl PullRequestProvider for SmartMockGitProvider {
         _output_title: &str,
         _output_summary: &str,
         _output_text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -1108,26 +1149,32 @@ async fn test_label_detector_size_labels_exact_match() {
     provider.add_repository_label(Label {
         name: "size/XS".to_string(),
         description: Some("Extra small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/S".to_string(),
         description: Some("Small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/M".to_string(),
         description: Some("Medium PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/L".to_string(),
         description: Some("Large PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/XL".to_string(),
         description: Some("Extra large PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/XXL".to_string(),
         description: Some("Extra extra large PR".to_string()),
+        color: None,
     });
 
     let detector = LabelDetector::new_for_size_labels();
@@ -1152,14 +1199,17 @@ async fn test_label_detector_size_labels_separator_match() {
     provider.add_repository_label(Label {
         name: "size-XS".to_string(),
         description: Some("Extra small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size_S".to_string(),
         description: Some("Small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size: M".to_string(),
         description: Some("Medium PR".to_string()),
+        color: None,
     });
 
     let detector = LabelDetector::new_for_size_labels();
@@ -1173,6 +1223,39 @@ async fn test_label_detector_size_labels_separator_match() {
     assert_eq!(discovered.m, Some("size: M".to_string()));
 }
 
+#[test]
+async fn test_label_detector_size_labels_prefers_custom_category_names() {
+    use crate::size::SizeCategoryNames;
+
+    let provider = SmartMockGitProvider::new();
+    for name in ["size/XS", "size/M", "size/tiny", "Huge"] {
+        provider.add_repository_label(Label {
+            name: name.to_string(),
+            description: None,
+            color: None,
+        });
+    }
+    let names = SizeCategoryNames {
+        xs: Some("tiny".to_string()),
+        m: Some("medium".to_string()),
+        xxl: Some("huge".to_string()),
+        ..Default::default()
+    };
+
+    let detector = LabelDetector::new_for_size_labels();
+    let discovered = detector
+        .discover_size_labels_with_names(&provider, "owner", "repo", &names)
+        .await
+        .unwrap();
+
+    // The custom name wins over the standard one when both labels exist.
+    assert_eq!(discovered.xs, Some("size/tiny".to_string()));
+    // Without a label for the custom name, the standard name is still recognized.
+    assert_eq!(discovered.m, Some("size/M".to_string()));
+    assert_eq!(discovered.xxl, Some("Huge".to_string()));
+    assert_eq!(discovered.s, None);
+}
+
 #[test]
 async fn test_label_detector_size_labels_separator_match_lowercase() {
     // Regression test: lowercase labels like "size:l" must be discovered correctly.
@@ -1184,26 +1267,32 @@ async fn test_label_detector_size_labels_separator_match_lowercase() {
     provider.add_repository_label(Label {
         name: "size:xs".to_string(),
         description: Some("Extra small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size:s".to_string(),
         description: Some("Small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size:m".to_string(),
         description: Some("Medium PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size:l".to_string(),
         description: Some("Large PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size:xl".to_string(),
         description: Some("Extra large PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size:xxl".to_string(),
         description: Some("Extra extra large PR".to_string()),
+        color: None,
     });
 
     let detector = LabelDetector::new_for_size_labels();
@@ -1230,26 +1319,32 @@ async fn test_label_detector_size_labels_exact_match_lowercase() {
     provider.add_repository_label(Label {
         name: "size/xs".to_string(),
         description: Some("Extra small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/s".to_string(),
         description: Some("Small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/m".to_string(),
         description: Some("Medium PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/l".to_string(),
         description: Some("Large PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/xl".to_string(),
         description: Some("Extra large PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/xxl".to_string(),
         description: Some("Extra extra large PR".to_string()),
+        color: None,
     });
 
     let detector = LabelDetector::new_for_size_labels();
@@ -1274,14 +1369,17 @@ async fn test_label_detector_size_labels_standalone_match() {
     provider.add_repository_label(Label {
         name: "XS".to_string(),
         description: Some("Extra small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "S".to_string(),
         description: Some("Small PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "M".to_string(),
         description: Some("Medium PR".to_string()),
+        color: None,
     });
 
     let detector = LabelDetector::new_for_size_labels();
@@ -1303,14 +1401,17 @@ async fn test_label_detector_size_labels_description_match() {
     provider.add_repository_label(Label {
         name: "tiny".to_string(),
         description: Some("(size: XS) Very small changes".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "small".to_string(),
         description: Some("(size: S) Small changes".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "medium".to_string(),
         description: Some("(size: M) Medium changes".to_string()),
+        color: None,
     });
 
     let detector = LabelDetector::new_for_size_labels();
@@ -1332,18 +1433,22 @@ async fn test_label_detector_size_labels_priority_selection() {
     provider.add_repository_label(Label {
         name: "size/XS".to_string(),
         description: Some("Exact match".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size-XS".to_string(),
         description: Some("Separator match".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "XS".to_string(),
         description: Some("Standalone match".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "tiny".to_string(),
         description: Some("(size: XS) Description match".to_string()),
+        color: None,
     });
 
     let detector = LabelDetector::new_for_size_labels();
@@ -1364,14 +1469,17 @@ async fn test_label_detector_change_type_exact_match() {
     provider.add_repository_label(Label {
         name: "feature".to_string(),
         description: Some("New feature".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "bug".to_string(),
         description: Some("Bug fix".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "enhancement".to_string(),
         description: Some("Enhancement".to_string()),
+        color: None,
     });
 
     let config = ChangeTypeLabelConfig {
@@ -1385,6 +1493,9 @@ async fn test_label_detector_change_type_exact_match() {
         detection_strategy: LabelDetectionStrategy::default(),
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let detector = LabelDetector::new_for_change_type_labels(config);
@@ -1406,10 +1517,12 @@ async fn test_label_detector_change_type_prefix_match() {
     provider.add_repository_label(Label {
         name: "type:feat".to_string(),
         description: Some("Feature type".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "kind:fix".to_string(),
         description: Some("Fix kind".to_string()),
+        color: None,
     });
 
     let config = ChangeTypeLabelConfig {
@@ -1423,6 +1536,9 @@ async fn test_label_detector_change_type_prefix_match() {
         },
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let detector = LabelDetector::new_for_change_type_labels(config);
@@ -1444,10 +1560,12 @@ async fn test_label_detector_change_type_description_match() {
     provider.add_repository_label(Label {
         name: "new-feature".to_string(),
         description: Some("For feat commits - new features".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "bug-fix".to_string(),
         description: Some("For fix commits - bug fixes".to_string()),
+        color: None,
     });
 
     let config = ChangeTypeLabelConfig {
@@ -1456,6 +1574,9 @@ async fn test_label_detector_change_type_description_match() {
         detection_strategy: LabelDetectionStrategy::default(),
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let detector = LabelDetector::new_for_change_type_labels(config);
@@ -1477,6 +1598,7 @@ async fn test_label_detector_change_type_no_match_fallback() {
     provider.add_repository_label(Label {
         name: "random-label".to_string(),
         description: Some("Random label".to_string()),
+        color: None,
     });
 
     let config = ChangeTypeLabelConfig {
@@ -1488,6 +1610,9 @@ async fn test_label_detector_change_type_no_match_fallback() {
         },
         detection_strategy: LabelDetectionStrategy::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let detector = LabelDetector::new_for_change_type_labels(config);
@@ -1523,6 +1648,7 @@ async fn test_label_manager_apply_change_type_label_success() {
     provider.add_repository_label(Label {
         name: "feature".to_string(),
         description: Some("New feature".to_string()),
+        color: None,
     });
 
     let config = ChangeTypeLabelConfig {
@@ -1534,6 +1660,9 @@ async fn test_label_manager_apply_change_type_label_success() {
         detection_strategy: LabelDetectionStrategy::default(),
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let manager = LabelManager::new(Some(config));
@@ -1564,6 +1693,9 @@ async fn test_label_manager_apply_change_type_label_with_fallback() {
             color_scheme: HashMap::from([("feat".to_string(), "00ff00".to_string())]),
         },
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let manager = LabelManager::new(Some(config));
@@ -1578,6 +1710,72 @@ async fn test_label_manager_apply_change_type_label_with_fallback() {
     assert!(result.used_fallback_creation());
 }
 
+#[test]
+async fn test_label_manager_replaces_change_type_label_when_title_type_changes() {
+    let provider = SmartMockGitProvider::new();
+    for name in ["feature", "bug"] {
+        provider.add_repository_label(Label {
+            name: name.to_string(),
+            description: None,
+            color: None,
+        });
+    }
+    provider
+        .add_labels("owner", "repo", 123, &["good first issue".to_string()])
+        .await
+        .unwrap();
+    let manager = LabelManager::new(Some(ChangeTypeLabelConfig::default()));
+
+    // First run: the PR is titled "fix: ..."
+    let first = manager
+        .apply_change_type_label(&provider, "owner", "repo", 123, "fix")
+        .await
+        .unwrap();
+    assert_eq!(first.applied_labels, vec!["bug".to_string()]);
+    assert!(first.removed_labels.is_empty());
+
+    // Second run: the title was changed to "feat: ..."
+    let second = manager
+        .apply_change_type_label(&provider, "owner", "repo", 123, "feat")
+        .await
+        .unwrap();
+
+    assert!(second.is_success());
+    assert_eq!(second.applied_labels, vec!["feature".to_string()]);
+    assert_eq!(second.removed_labels, vec!["bug".to_string()]);
+    let mut applied: Vec<String> = provider.get_labels().into_iter().map(|l| l.name).collect();
+    applied.sort();
+    assert_eq!(applied, vec!["feature", "good first issue"]);
+}
+
+#[test]
+async fn test_label_detector_is_change_type_label() {
+    let config = ChangeTypeLabelConfig {
+        fallback_label_settings: FallbackLabelSettings {
+            name_format: "change/{change_type}".to_string(),
+            ..FallbackLabelSettings::default()
+        },
+        ..ChangeTypeLabelConfig::default()
+    };
+    let detector = LabelDetector::new_for_change_type_labels(config.clone());
+
+    assert!(detector.is_change_type_label("Enhancement"));
+    assert!(detector.is_change_type_label("change/docs"));
+    assert!(detector.is_change_type_label("kind: refactor"));
+    assert!(!detector.is_change_type_label("good first issue"));
+    assert!(!detector.is_change_type_label("size: XL"));
+
+    let without_prefix_match = LabelDetector::new_for_change_type_labels(ChangeTypeLabelConfig {
+        detection_strategy: LabelDetectionStrategy {
+            prefix_match: false,
+            ..LabelDetectionStrategy::default()
+        },
+        ..config
+    });
+    assert!(!without_prefix_match.is_change_type_label("kind: refactor"));
+    assert!(!LabelDetector::new_for_size_labels().is_change_type_label("bug"));
+}
+
 #[test]
 async fn test_label_manager_apply_breaking_change_label() {
     let provider = SmartMockGitProvider::new();
@@ -1586,6 +1784,7 @@ async fn test_label_manager_apply_breaking_change_label() {
     provider.add_repository_label(Label {
         name: "breaking-change".to_string(),
         description: Some("Breaking change".to_string()),
+        color: None,
     });
 
     let manager = LabelManager::new(None);
@@ -1614,10 +1813,12 @@ async fn test_label_manager_apply_keyword_labels() {
     provider.add_repository_label(Label {
         name: "security".to_string(),
         description: Some("Security issue".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "hotfix".to_string(),
         description: Some("Hotfix".to_string()),
+        color: None,
     });
 
     let manager = LabelManager::new(None);
@@ -1695,14 +1896,17 @@ async fn test_smart_labeling_pipeline_end_to_end() {
     provider.add_repository_label(Label {
         name: "feature".to_string(),
         description: Some("New feature".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "size/M".to_string(),
         description: Some("Medium PR".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "breaking-change".to_string(),
         description: Some("Breaking change".to_string()),
+        color: None,
     });
 
     // Create configuration
@@ -1715,6 +1919,9 @@ async fn test_smart_labeling_pipeline_end_to_end() {
         detection_strategy: LabelDetectionStrategy::default(),
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let config_wrapper = CurrentPullRequestValidationConfiguration {
@@ -1734,6 +1941,8 @@ async fn test_smart_labeling_pipeline_end_to_end() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     // Test the complete pipeline
@@ -1762,6 +1971,7 @@ async fn test_smart_labeling_pipeline_with_fallback() {
     provider.add_repository_label(Label {
         name: "unrelated-label".to_string(),
         description: Some("Unrelated".to_string()),
+        color: None,
     });
 
     let config = ChangeTypeLabelConfig {
@@ -1774,6 +1984,9 @@ async fn test_smart_labeling_pipeline_with_fallback() {
             color_scheme: HashMap::from([("feat".to_string(), "0366d6".to_string())]),
         },
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let config_wrapper = CurrentPullRequestValidationConfiguration {
@@ -1792,6 +2005,8 @@ async fn test_smart_labeling_pipeline_with_fallback() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels =
@@ -1804,6 +2019,52 @@ async fn test_smart_labeling_pipeline_with_fallback() {
     assert_eq!(labels[0], "type: feat");
 }
 
+#[test]
+async fn test_smart_labeling_pipeline_labels_custom_allowed_type() {
+    let provider = SmartMockGitProvider::new();
+
+    let config = CurrentPullRequestValidationConfiguration {
+        change_type_labels: Some(ChangeTypeLabelConfig {
+            enabled: true,
+            fallback_label_settings: FallbackLabelSettings {
+                create_if_missing: true,
+                name_format: "type: {change_type}".to_string(),
+                color_scheme: HashMap::new(),
+            },
+            ..Default::default()
+        }),
+        title_lint: TitleLintPolicyConfig {
+            enabled: true,
+            allowed_types: vec!["feat".to_string(), "deps".to_string()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let pr = PullRequest {
+        number: 1,
+        title: "deps(cargo): bump serde".to_string(),
+        draft: false,
+        body: None,
+        author: Some(User {
+            id: 123,
+            login: "developer".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+
+    // The custom type from the title lint policy gets a change-type label
+    assert_eq!(labels, vec!["type: deps".to_string()]);
+}
+
 #[test]
 async fn test_smart_labeling_pipeline_legacy_fallback() {
     let provider = SmartMockGitProvider::new();
@@ -1820,6 +2081,8 @@ async fn test_smart_labeling_pipeline_legacy_fallback() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, None)
@@ -1842,6 +2105,9 @@ async fn test_smart_labeling_pipeline_disabled() {
         detection_strategy: LabelDetectionStrategy::default(),
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let config_wrapper = CurrentPullRequestValidationConfiguration {
@@ -1860,6 +2126,8 @@ async fn test_smart_labeling_pipeline_disabled() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels =
@@ -1880,14 +2148,17 @@ async fn test_smart_labeling_pipeline_multiple_keywords() {
     provider.add_repository_label(Label {
         name: "feature".to_string(),
         description: Some("New feature".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "security".to_string(),
         description: Some("Security issue".to_string()),
+        color: None,
     });
     provider.add_repository_label(Label {
         name: "breaking-change".to_string(),
         description: Some("Breaking change".to_string()),
+        color: None,
     });
 
     let config = ChangeTypeLabelConfig {
@@ -1899,6 +2170,9 @@ async fn test_smart_labeling_pipeline_multiple_keywords() {
         detection_strategy: LabelDetectionStrategy::default(),
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let config_wrapper = CurrentPullRequestValidationConfiguration {
@@ -1917,6 +2191,8 @@ async fn test_smart_labeling_pipeline_multiple_keywords() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels =
@@ -1939,6 +2215,7 @@ async fn test_smart_labeling_pipeline_error_recovery() {
     provider.add_repository_label(Label {
         name: "feature".to_string(),
         description: Some("New feature".to_string()),
+        color: None,
     });
 
     let config = ChangeTypeLabelConfig {
@@ -1950,6 +2227,9 @@ async fn test_smart_labeling_pipeline_error_recovery() {
         detection_strategy: LabelDetectionStrategy::default(),
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let config_wrapper = CurrentPullRequestValidationConfiguration {
@@ -1968,6 +2248,8 @@ async fn test_smart_labeling_pipeline_error_recovery() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     // Even if some parts fail, should continue processing
@@ -2062,6 +2344,9 @@ async fn test_change_type_label_config_validation() {
             color_scheme: HashMap::from([("feat".to_string(), "00ff00".to_string())]),
         },
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     // Should be valid (at least one detection method enabled)
@@ -2079,6 +2364,9 @@ async fn test_change_type_label_config_validation() {
         },
         fallback_label_settings: FallbackLabelSettings::default(),
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     // Should be invalid (no detection methods enabled)
@@ -2171,6 +2459,9 @@ async fn test_configuration_merge_behavior() {
             color_scheme: HashMap::from([("feat".to_string(), "00ff00".to_string())]),
         },
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     let repo_config = ChangeTypeLabelConfig {
@@ -2191,6 +2482,9 @@ async fn test_configuration_merge_behavior() {
             color_scheme: HashMap::from([("feat".to_string(), "ff0000".to_string())]), // Override: different color
         },
         keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
     };
 
     // In a real merge scenario, repository config would override application config
@@ -2215,10 +2509,12 @@ async fn test_discover_wip_labels_returns_none_when_no_wip_labels() {
         Label {
             name: "bug".to_string(),
             description: None,
+            color: None,
         },
         Label {
             name: "feature".to_string(),
             description: None,
+            color: None,
         },
     ]);
 
@@ -2237,10 +2533,12 @@ async fn test_discover_wip_labels_exact_hint_match() {
         Label {
             name: " WIP".to_string(),
             description: None,
+            color: None,
         },
         Label {
             name: "bug".to_string(),
             description: None,
+            color: None,
         },
     ]);
 
@@ -2259,10 +2557,12 @@ async fn test_discover_wip_labels_common_name_match_wip_lowercase() {
         Label {
             name: "wip".to_string(),
             description: None,
+            color: None,
         },
         Label {
             name: "bug".to_string(),
             description: None,
+            color: None,
         },
     ]);
 
@@ -2280,6 +2580,7 @@ async fn test_discover_wip_labels_common_name_match_work_in_progress() {
     let provider = WipMockProvider::new(vec![Label {
         name: "work-in-progress".to_string(),
         description: None,
+        color: None,
     }]);
 
     let result = discover_wip_labels(&provider, "owner", "repo", &None)
@@ -2298,10 +2599,12 @@ async fn test_discover_wip_labels_hint_takes_priority_over_common() {
         Label {
             name: "wip".to_string(),
             description: None,
+            color: None,
         },
         Label {
             name: "my-custom-wip".to_string(),
             description: None,
+            color: None,
         },
     ]);
 
@@ -2326,6 +2629,7 @@ async fn test_manage_wip_labels_adds_label_when_wip_and_no_existing_label() {
     let provider = WipMockProvider::new(vec![Label {
         name: "WIP".to_string(),
         description: None,
+        color: None,
     }]);
 
     manage_wip_labels(
@@ -2353,10 +2657,12 @@ async fn test_manage_wip_labels_does_not_double_add_label() {
         vec![Label {
             name: "WIP".to_string(),
             description: None,
+            color: None,
         }],
         vec![Label {
             name: "WIP".to_string(),
             description: None,
+            color: None,
         }],
     );
 
@@ -2384,10 +2690,12 @@ async fn test_manage_wip_labels_removes_label_when_not_wip() {
         vec![Label {
             name: "WIP".to_string(),
             description: None,
+            color: None,
         }],
         vec![Label {
             name: "WIP".to_string(),
             description: None,
+            color: None,
         }],
     );
 
@@ -2418,6 +2726,7 @@ async fn test_manage_wip_labels_remove_is_noop_when_no_wip_label_present() {
         vec![Label {
             name: "bug".to_string(),
             description: None,
+            color: None,
         }],
     );
 
@@ -2430,6 +2739,60 @@ async fn test_manage_wip_labels_remove_is_noop_when_no_wip_label_present() {
     assert_eq!(applied[0].name, "bug");
 }
 
+//  manage_needs_rebase_label tests
+
+#[tokio::test]
+async fn test_manage_needs_rebase_label_adds_label_when_behind() {
+    use crate::labels::manage_needs_rebase_label;
+
+    let provider = WipMockProvider::new(vec![]);
+
+    let labelled = manage_needs_rebase_label(&provider, "owner", "repo", 1, true, "needs-rebase")
+        .await
+        .unwrap();
+
+    assert!(labelled);
+    let applied = provider.get_applied();
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].name, "needs-rebase");
+
+    // A second run does not add the label again
+    manage_needs_rebase_label(&provider, "owner", "repo", 1, true, "needs-rebase")
+        .await
+        .unwrap();
+    assert_eq!(provider.get_applied().len(), 1);
+}
+
+#[tokio::test]
+async fn test_manage_needs_rebase_label_removes_only_its_label_when_current() {
+    use crate::labels::manage_needs_rebase_label;
+
+    let provider = WipMockProvider::with_applied(
+        vec![],
+        vec![
+            Label {
+                name: "Needs-Rebase".to_string(),
+                description: None,
+                color: None,
+            },
+            Label {
+                name: "bug".to_string(),
+                description: None,
+                color: None,
+            },
+        ],
+    );
+
+    let labelled = manage_needs_rebase_label(&provider, "owner", "repo", 1, false, "needs-rebase")
+        .await
+        .unwrap();
+
+    assert!(!labelled);
+    let applied = provider.get_applied();
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].name, "bug");
+}
+
 #[tokio::test]
 async fn test_manage_wip_labels_uses_hint_when_no_repo_label_discovered() {
     use crate::labels::manage_wip_labels;
@@ -2580,6 +2943,7 @@ impl PullRequestProvider for PrStateMockProvider {
             applied.push(Label {
                 name: l.clone(),
                 description: None,
+                color: None,
             });
         }
         Ok(())
@@ -2615,6 +2979,7 @@ impl PullRequestProvider for PrStateMockProvider {
         _title: &str,
         _summary: &str,
         _text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -2676,6 +3041,7 @@ fn make_label(name: &str) -> Label {
     Label {
         name: name.to_string(),
         description: None,
+        color: None,
     }
 }
 
@@ -2984,6 +3350,7 @@ impl PullRequestProvider for SizeLabelMockProvider {
             applied.push(Label {
                 name: l.clone(),
                 description: None,
+                color: None,
             });
         }
         Ok(())
@@ -3020,6 +3387,7 @@ impl PullRequestProvider for SizeLabelMockProvider {
         _title: &str,
         _summary: &str,
         _text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
         unimplemented!("Not needed for this test")
     }
@@ -3085,7 +3453,9 @@ async fn test_manage_size_labels_skips_api_calls_when_correct_label_already_appl
     // manage_size_labels must return immediately without calling add_labels or
     // remove_label to avoid noise on the PR timeline.
     use crate::labels::manage_size_labels;
-    use crate::size::{PrSizeCategory, PrSizeInfo, SizeThresholds};
+    use crate::size::{
+        PrSizeCategory, PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds,
+    };
 
     // PR already has "size/S" applied; S category matches 25 changed lines.
     let provider =
@@ -3101,13 +3471,22 @@ async fn test_manage_size_labels_skips_api_calls_when_correct_label_already_appl
         }],
         vec![],
         &SizeThresholds::default(),
-        false,
+        SizeCountingMode::TotalChanges,
     );
     assert_eq!(size_info.size_category, PrSizeCategory::S);
 
-    let result = manage_size_labels(&provider, "owner", "repo", 1, &size_info, "size/")
-        .await
-        .unwrap();
+    let result = manage_size_labels(
+        &provider,
+        "owner",
+        "repo",
+        1,
+        &size_info,
+        "size/",
+        &SizeCategoryNames::default(),
+        true,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         result.as_deref(),
@@ -3129,7 +3508,9 @@ async fn test_manage_size_labels_removes_stale_and_adds_new_when_category_change
     // When the PR has a stale size label (wrong category), the old label must be
     // removed and the new one added.
     use crate::labels::manage_size_labels;
-    use crate::size::{PrSizeCategory, PrSizeInfo, SizeThresholds};
+    use crate::size::{
+        PrSizeCategory, PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds,
+    };
 
     // PR currently has "size/S" but the new size is M (75 lines).
     let provider =
@@ -3145,13 +3526,22 @@ async fn test_manage_size_labels_removes_stale_and_adds_new_when_category_change
         }],
         vec![],
         &SizeThresholds::default(),
-        false,
+        SizeCountingMode::TotalChanges,
     );
     assert_eq!(size_info.size_category, PrSizeCategory::M);
 
-    let result = manage_size_labels(&provider, "owner", "repo", 1, &size_info, "size/")
-        .await
-        .unwrap();
+    let result = manage_size_labels(
+        &provider,
+        "owner",
+        "repo",
+        1,
+        &size_info,
+        "size/",
+        &SizeCategoryNames::default(),
+        true,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         result.as_deref(),
@@ -3178,7 +3568,9 @@ async fn test_manage_size_labels_removes_all_stale_and_adds_new_when_multiple_si
     // If the PR somehow accumulated multiple size labels, all stale ones must be
     // removed before the correct one is applied.
     use crate::labels::manage_size_labels;
-    use crate::size::{PrSizeCategory, PrSizeInfo, SizeThresholds};
+    use crate::size::{
+        PrSizeCategory, PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds,
+    };
 
     // PR has both "size/XS" and "size/S" applied; new category is M.
     let provider = SizeLabelMockProvider::new(
@@ -3196,13 +3588,22 @@ async fn test_manage_size_labels_removes_all_stale_and_adds_new_when_multiple_si
         }],
         vec![],
         &SizeThresholds::default(),
-        false,
+        SizeCountingMode::TotalChanges,
     );
     assert_eq!(size_info.size_category, PrSizeCategory::M);
 
-    manage_size_labels(&provider, "owner", "repo", 1, &size_info, "size/")
-        .await
-        .unwrap();
+    manage_size_labels(
+        &provider,
+        "owner",
+        "repo",
+        1,
+        &size_info,
+        "size/",
+        &SizeCategoryNames::default(),
+        true,
+    )
+    .await
+    .unwrap();
 
     let removals = provider.get_remove_calls();
     assert!(
@@ -3229,7 +3630,9 @@ async fn test_manage_size_labels_fallback_uses_default_prefix_when_no_repo_label
     // When no size labels exist in the repository, the fallback label must use
     // the supplied label_prefix (e.g. "size/") and NOT the old hardcoded "size: " format.
     use crate::labels::manage_size_labels;
-    use crate::size::{PrSizeCategory, PrSizeInfo, SizeThresholds};
+    use crate::size::{
+        PrSizeCategory, PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds,
+    };
 
     // No labels in the repository — forces the fallback path.
     let provider = SizeLabelMockProvider::new(vec![], vec![]);
@@ -3244,13 +3647,22 @@ async fn test_manage_size_labels_fallback_uses_default_prefix_when_no_repo_label
         }],
         vec![],
         &SizeThresholds::default(),
-        false,
+        SizeCountingMode::TotalChanges,
     );
     assert_eq!(size_info.size_category, PrSizeCategory::M);
 
-    let result = manage_size_labels(&provider, "owner", "repo", 1, &size_info, "size/")
-        .await
-        .unwrap();
+    let result = manage_size_labels(
+        &provider,
+        "owner",
+        "repo",
+        1,
+        &size_info,
+        "size/",
+        &SizeCategoryNames::default(),
+        true,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         result.as_deref(),
@@ -3264,7 +3676,9 @@ async fn test_manage_size_labels_fallback_uses_custom_prefix_when_no_repo_labels
     // Operators who configure label_prefix = "pr-size/" must get "pr-size/M" as
     // the fallback label, not "size/M" or "size: M".
     use crate::labels::manage_size_labels;
-    use crate::size::{PrSizeCategory, PrSizeInfo, SizeThresholds};
+    use crate::size::{
+        PrSizeCategory, PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds,
+    };
 
     // No labels in the repository — forces the fallback path.
     let provider = SizeLabelMockProvider::new(vec![], vec![]);
@@ -3279,13 +3693,22 @@ async fn test_manage_size_labels_fallback_uses_custom_prefix_when_no_repo_labels
         }],
         vec![],
         &SizeThresholds::default(),
-        false,
+        SizeCountingMode::TotalChanges,
     );
     assert_eq!(size_info.size_category, PrSizeCategory::M);
 
-    let result = manage_size_labels(&provider, "owner", "repo", 1, &size_info, "pr-size/")
-        .await
-        .unwrap();
+    let result = manage_size_labels(
+        &provider,
+        "owner",
+        "repo",
+        1,
+        &size_info,
+        "pr-size/",
+        &SizeCategoryNames::default(),
+        true,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         result.as_deref(),
@@ -3294,6 +3717,51 @@ async fn test_manage_size_labels_fallback_uses_custom_prefix_when_no_repo_labels
     );
 }
 
+#[tokio::test]
+async fn test_manage_size_labels_uses_custom_category_names() {
+    // With custom category names, the fallback label uses the display name and a
+    // previously applied label with a custom name is recognized as a stale size label.
+    use crate::labels::manage_size_labels;
+    use crate::size::{
+        PrSizeCategory, PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds,
+    };
+
+    let provider =
+        SizeLabelMockProvider::new(vec![make_label("size/tiny")], vec![make_label("size/tiny")]);
+    let names = SizeCategoryNames {
+        xs: Some("tiny".to_string()),
+        m: Some("medium".to_string()),
+        ..Default::default()
+    };
+
+    let size_info = PrSizeInfo::new(
+        vec![merge_warden_developer_platforms::models::PullRequestFile {
+            filename: "src/lib.rs".to_string(),
+            additions: 50,
+            deletions: 25,
+            changes: 75,
+            status: "modified".to_string(),
+        }],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
+    assert_eq!(size_info.size_category, PrSizeCategory::M);
+
+    let result = manage_size_labels(
+        &provider, "owner", "repo", 1, &size_info, "size/", &names, true,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.as_deref(), Some("size/medium"));
+    assert_eq!(provider.get_remove_calls(), vec!["size/tiny".to_string()]);
+    assert_eq!(
+        provider.get_add_calls(),
+        vec![vec!["size/medium".to_string()]]
+    );
+}
+
 // ── Keyword label customisation tests ────────────────────────────────────────
 
 /// Builds a minimal `CurrentPullRequestValidationConfiguration` with a
@@ -3323,6 +3791,8 @@ async fn test_keyword_labels_default_breaking_change_title() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     // No config → hard-coded default "breaking-change"
@@ -3337,6 +3807,46 @@ async fn test_keyword_labels_default_breaking_change_title() {
     );
 }
 
+#[test]
+async fn test_max_auto_labels_drops_lowest_priority_labels() {
+    let pr = PullRequest {
+        number: 2,
+        title: "fix!: patch auth".to_string(),
+        draft: false,
+        body: Some("This hotfix closes a security vulnerability.".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let unlimited = make_config_with_keyword_labels(KeywordLabelsConfig::default());
+    let mut capped = unlimited.clone();
+    capped.change_type_labels.as_mut().unwrap().max_auto_labels = Some(2);
+
+    let all_labels = set_pull_request_labels_with_config(
+        &MockGitProvider::new(),
+        "owner",
+        "repo",
+        &pr,
+        Some(&unlimited),
+    )
+    .await
+    .unwrap();
+    let provider = MockGitProvider::new();
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&capped))
+            .await
+            .unwrap();
+
+    assert_eq!(all_labels.len(), 4, "got: {:?}", all_labels);
+    assert_eq!(labels, all_labels[..2].to_vec());
+    assert_eq!(labels[1], "breaking-change");
+    let applied: Vec<String> = provider.get_labels().into_iter().map(|l| l.name).collect();
+    assert!(!applied.contains(&"security".to_string()));
+    assert!(!applied.contains(&"hotfix".to_string()));
+}
+
 #[test]
 async fn test_keyword_labels_custom_breaking_change() {
     let provider = MockGitProvider::new();
@@ -3351,6 +3861,8 @@ async fn test_keyword_labels_custom_breaking_change() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let config = make_config_with_keyword_labels(KeywordLabelsConfig {
         breaking_change: Some("semver-major".to_string()),
@@ -3388,6 +3900,8 @@ async fn test_keyword_labels_custom_security() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let config = make_config_with_keyword_labels(KeywordLabelsConfig {
         security: Some("security-alert".to_string()),
@@ -3425,6 +3939,8 @@ async fn test_keyword_labels_custom_hotfix() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let config = make_config_with_keyword_labels(KeywordLabelsConfig {
         hotfix: Some("urgent".to_string()),
@@ -3462,6 +3978,8 @@ async fn test_keyword_labels_custom_tech_debt() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let config = make_config_with_keyword_labels(KeywordLabelsConfig {
         tech_debt: Some("cleanup".to_string()),
@@ -3499,6 +4017,8 @@ async fn test_keyword_labels_empty_string_falls_back_to_default() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     // Empty string must fall back to built-in default label name.
     let config = make_config_with_keyword_labels(KeywordLabelsConfig {
@@ -3533,6 +4053,8 @@ async fn test_keyword_labels_no_config_uses_defaults() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     let labels = set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, None)
@@ -3699,6 +4221,7 @@ impl PullRequestProvider for KeywordLabelMockProvider {
         _title: &str,
         _summary: &str,
         _text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -4479,6 +5002,8 @@ async fn test_negation_breaking_change_in_body_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4525,6 +5050,8 @@ async fn test_negation_breaking_change_without_variant_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4549,6 +5076,8 @@ async fn test_negation_breaking_change_doesnt_variant_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4576,6 +5105,8 @@ async fn test_negation_breaking_change_in_title_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4616,6 +5147,8 @@ async fn test_affirmative_breaking_change_in_body_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4659,6 +5192,8 @@ async fn test_exclamation_colon_always_triggers_breaking_change_even_with_negate
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4692,6 +5227,8 @@ async fn test_body_with_both_negated_and_affirmative_breaking_change_triggers_la
         author: Some(User { id: 1, login: "dev".to_string() }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4720,6 +5257,8 @@ async fn test_negation_security_in_body_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4751,6 +5290,8 @@ async fn test_negation_no_security_issues_in_body_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4773,6 +5314,8 @@ async fn test_affirmative_security_in_body_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4808,6 +5351,8 @@ async fn test_negation_hotfix_in_body_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4839,6 +5384,8 @@ async fn test_affirmative_hotfix_in_body_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4873,6 +5420,8 @@ async fn test_negation_tech_debt_in_body_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4899,6 +5448,8 @@ async fn test_negation_not_technical_debt_in_body_not_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4921,6 +5472,8 @@ async fn test_affirmative_tech_debt_reduces_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4947,6 +5500,8 @@ async fn test_affirmative_technical_debt_in_body_applied() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, None)
         .await
@@ -4979,6 +5534,8 @@ async fn test_suppression_skips_label_application() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5014,6 +5571,7 @@ async fn test_suppression_removes_existing_label_from_pr() {
     let existing_security_label = Label {
         name: "security".to_string(),
         description: None,
+        color: None,
     };
     let provider =
         KeywordLabelMockProvider::new(vec![suppress_comment], vec![existing_security_label]);
@@ -5032,6 +5590,8 @@ async fn test_suppression_removes_existing_label_from_pr() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5070,6 +5630,8 @@ async fn test_suppression_does_not_call_remove_when_label_not_currently_applied(
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5102,6 +5664,8 @@ async fn test_suppression_isolated_to_named_label_only() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5143,6 +5707,8 @@ async fn test_suppression_multi_label_different_suppressors() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     let labels = set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5181,6 +5747,8 @@ async fn test_suppression_list_comments_failure_falls_back_to_no_suppression() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     // Must succeed (no propagated error)
     let result = set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config)).await;
@@ -5220,6 +5788,8 @@ async fn test_explanation_comment_posted_when_label_triggered_and_no_prior_comme
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5266,6 +5836,8 @@ async fn test_explanation_comment_idempotent_when_identical_body_exists() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5309,6 +5881,8 @@ async fn test_explanation_comment_stale_body_replaced_with_fresh_one() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5354,6 +5928,8 @@ async fn test_explanation_comment_two_stale_copies_both_replaced_with_fresh_one(
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5400,6 +5976,8 @@ async fn test_explanation_comment_deleted_when_detection_clears() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5441,6 +6019,8 @@ async fn test_explanation_comment_deleted_when_label_suppressed() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5481,6 +6061,8 @@ async fn test_explanation_comments_independent_per_label() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5536,6 +6118,8 @@ async fn test_clearing_one_label_does_not_affect_other_labels_comment() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5587,6 +6171,8 @@ async fn test_no_unnecessary_api_calls_when_no_keyword_matched() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     set_pull_request_labels_with_config(&provider, "o", "r", &pr, Some(&config))
         .await
@@ -5664,6 +6250,7 @@ impl PullRequestProvider for StabilityMockProvider {
             applied.push(Label {
                 name: l.clone(),
                 description: None,
+                color: None,
             });
         }
         Ok(())
@@ -5686,6 +6273,7 @@ impl PullRequestProvider for StabilityMockProvider {
         _: &str,
         _: &str,
         _: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -5983,3 +6571,800 @@ async fn manage_renovate_stability_label_creates_label_if_missing() {
         "label should be applied after creation"
     );
 }
+
+// ── Auto-label category toggles ──────────────────────────────────────────────
+
+fn make_config_with_categories(
+    categories: AutoLabelCategories,
+) -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        change_type_labels: Some(ChangeTypeLabelConfig {
+            categories,
+            ..ChangeTypeLabelConfig::default()
+        }),
+        ..CurrentPullRequestValidationConfiguration::default()
+    }
+}
+
+fn make_keyword_heavy_pr(title: &str) -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: title.to_string(),
+        draft: false,
+        body: Some(
+            "A security hotfix for the login flow. It also pays down some technical debt."
+                .to_string(),
+        ),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+#[test]
+async fn test_disabled_keyword_category_skips_keyword_labels() {
+    let provider = MockGitProvider::new();
+    let pr = make_keyword_heavy_pr("fix!: change login flow");
+    let config = make_config_with_categories(AutoLabelCategories {
+        keywords: Some(false),
+        ..AutoLabelCategories::default()
+    });
+
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+
+    assert!(labels.contains(&"breaking-change".to_string()));
+    for label in ["security", "hotfix", "tech-debt"] {
+        assert!(
+            !labels.contains(&label.to_string()),
+            "'{label}' must not be applied when keyword labels are disabled; got: {labels:?}"
+        );
+    }
+}
+
+#[test]
+async fn test_disabled_breaking_change_category_skips_breaking_change_label() {
+    let provider = MockGitProvider::new();
+    let pr = make_keyword_heavy_pr("fix!: change login flow");
+    let config = make_config_with_categories(AutoLabelCategories {
+        breaking_change: Some(false),
+        ..AutoLabelCategories::default()
+    });
+
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+
+    assert!(!labels.contains(&"breaking-change".to_string()));
+    assert!(labels.contains(&"security".to_string()));
+    assert!(labels.contains(&"hotfix".to_string()));
+}
+
+#[test]
+async fn test_disabled_change_type_category_skips_change_type_label() {
+    let provider = MockGitProvider::new();
+    let pr = PullRequest {
+        body: Some("Adds the export button.".to_string()),
+        ..make_keyword_heavy_pr("feat: add export")
+    };
+    let config = make_config_with_categories(AutoLabelCategories {
+        change_type: Some(false),
+        ..AutoLabelCategories::default()
+    });
+
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+
+    assert!(labels.is_empty(), "no labels expected; got: {labels:?}");
+    assert!(provider.get_labels().is_empty());
+}
+
+#[test]
+async fn test_default_categories_apply_all_labels() {
+    let provider = MockGitProvider::new();
+    let pr = make_keyword_heavy_pr("fix!: change login flow");
+    let config = make_config_with_categories(AutoLabelCategories::default());
+
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+
+    for label in ["breaking-change", "security", "hotfix", "tech-debt"] {
+        assert!(
+            labels.contains(&label.to_string()),
+            "'{label}' expected; got: {labels:?}"
+        );
+    }
+}
+
+fn keyword_rule(label: &str, keywords: &[&str]) -> KeywordLabelRule {
+    KeywordLabelRule {
+        label: label.to_string(),
+        keywords: keywords.iter().map(|k| k.to_string()).collect(),
+    }
+}
+
+#[test]
+async fn test_custom_keyword_rule_applies_label() {
+    let provider = MockGitProvider::new();
+    let pr = PullRequest {
+        body: Some("Adds a MIGRATION for the orders table.".to_string()),
+        ..make_keyword_heavy_pr("feat: add orders table")
+    };
+    let config = make_config_with_keyword_labels(KeywordLabelsConfig {
+        rules: vec![keyword_rule("database-migration", &["migration"])],
+        ..KeywordLabelsConfig::default()
+    });
+
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+
+    assert!(
+        labels.contains(&"database-migration".to_string()),
+        "custom keyword label expected; got: {labels:?}"
+    );
+}
+
+#[test]
+async fn test_custom_keyword_rule_matches_any_keyword_in_title_or_body() {
+    let provider = MockGitProvider::new();
+    let config = make_config_with_keyword_labels(KeywordLabelsConfig {
+        rules: vec![keyword_rule(
+            "database-migration",
+            &["migration", "schema change"],
+        )],
+        ..KeywordLabelsConfig::default()
+    });
+
+    for (title, body) in [
+        ("feat: Schema-Change for orders", "Adds a column."),
+        ("feat: add orders table", "Requires a schema change."),
+    ] {
+        let pr = PullRequest {
+            body: Some(body.to_string()),
+            ..make_keyword_heavy_pr(title)
+        };
+        let labels =
+            set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+                .await
+                .unwrap();
+        assert!(
+            labels.contains(&"database-migration".to_string()),
+            "expected label for title {title:?} / body {body:?}; got: {labels:?}"
+        );
+    }
+}
+
+#[test]
+async fn test_custom_keyword_rule_respects_word_boundaries_and_negation() {
+    let provider = MockGitProvider::new();
+    let config = make_config_with_keyword_labels(KeywordLabelsConfig {
+        rules: vec![keyword_rule("database-migration", &["migration"])],
+        ..KeywordLabelsConfig::default()
+    });
+
+    for body in ["Fixes the migrations list page.", "No migration is needed."] {
+        let pr = PullRequest {
+            body: Some(body.to_string()),
+            ..make_keyword_heavy_pr("fix: orders page")
+        };
+        let labels =
+            set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+                .await
+                .unwrap();
+        assert!(
+            !labels.contains(&"database-migration".to_string()),
+            "no label expected for body {body:?}; got: {labels:?}"
+        );
+    }
+}
+
+#[test]
+async fn test_disabled_builtin_keyword_labels_are_not_applied() {
+    let provider = MockGitProvider::new();
+    let pr = make_keyword_heavy_pr("fix!: change login flow");
+    let config = make_config_with_keyword_labels(KeywordLabelsConfig {
+        disabled: vec![
+            BuiltinKeywordLabel::BreakingChange,
+            BuiltinKeywordLabel::TechDebt,
+        ],
+        ..KeywordLabelsConfig::default()
+    });
+
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+
+    assert!(!labels.contains(&"breaking-change".to_string()));
+    assert!(!labels.contains(&"tech-debt".to_string()));
+    assert!(labels.contains(&"security".to_string()));
+    assert!(labels.contains(&"hotfix".to_string()));
+}
+
+#[test]
+async fn test_keyword_rule_overrides_builtin_keywords() {
+    let provider = MockGitProvider::new();
+    let config = make_config_with_keyword_labels(KeywordLabelsConfig {
+        rules: vec![keyword_rule("hotfix", &["urgent fix"])],
+        ..KeywordLabelsConfig::default()
+    });
+
+    let pr = PullRequest {
+        body: Some("A hotfix for the login flow.".to_string()),
+        ..make_keyword_heavy_pr("fix: login flow")
+    };
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+    assert!(
+        !labels.contains(&"hotfix".to_string()),
+        "built-in keyword must be replaced; got: {labels:?}"
+    );
+
+    let pr = PullRequest {
+        body: Some("An urgent fix for the login flow.".to_string()),
+        ..make_keyword_heavy_pr("fix: login flow")
+    };
+    let labels =
+        set_pull_request_labels_with_config(&provider, "owner", "repo", &pr, Some(&config))
+            .await
+            .unwrap();
+    assert_eq!(
+        labels.iter().filter(|l| l.as_str() == "hotfix").count(),
+        1,
+        "configured keyword must apply the label once; got: {labels:?}"
+    );
+}
+
+#[test]
+async fn test_label_manager_apply_keyword_labels_uses_custom_rules() {
+    let provider = SmartMockGitProvider::new();
+    let config = ChangeTypeLabelConfig {
+        keyword_labels: KeywordLabelsConfig {
+            rules: vec![keyword_rule("database-migration", &["migration"])],
+            disabled: vec![BuiltinKeywordLabel::Security],
+            ..KeywordLabelsConfig::default()
+        },
+        ..ChangeTypeLabelConfig::default()
+    };
+
+    let manager = LabelManager::new(Some(config));
+    let result = manager
+        .apply_keyword_labels(
+            &provider,
+            "owner",
+            "repo",
+            123,
+            Some("Security review of the new migration"),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.is_success());
+    assert_eq!(
+        result.applied_labels,
+        vec!["database-migration".to_string()]
+    );
+}
+
+// ── dependency-update detection tests ───────────────────────────────────────
+
+fn dependency_pr(title: &str) -> PullRequest {
+    PullRequest {
+        number: 7,
+        title: title.to_string(),
+        draft: false,
+        body: None,
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+fn changed_file(name: &str) -> PullRequestFile {
+    PullRequestFile {
+        filename: name.to_string(),
+        additions: 3,
+        deletions: 1,
+        changes: 4,
+        status: "modified".to_string(),
+    }
+}
+
+#[test]
+async fn is_dependency_update_matches_title_type_and_scope() {
+    let config = crate::config::DependencyUpdateConfig::default();
+
+    for title in [
+        "deps: bump serde",
+        "chore(deps): bump serde",
+        "build(Deps-Dev)!: bump eslint",
+    ] {
+        assert!(
+            crate::labels::is_dependency_update(&dependency_pr(title), &[], &config),
+            "title must be detected: {title}"
+        );
+    }
+    assert!(!crate::labels::is_dependency_update(
+        &dependency_pr("chore(ci): update workflow"),
+        &[],
+        &config
+    ));
+}
+
+#[test]
+async fn is_dependency_update_requires_lockfile_and_only_dependency_files() {
+    let config = crate::config::DependencyUpdateConfig::default();
+    let pr = dependency_pr("chore: update packages");
+
+    assert!(crate::labels::is_dependency_update(
+        &pr,
+        &[
+            changed_file("web/package.json"),
+            changed_file("web/yarn.lock")
+        ],
+        &config
+    ));
+    // A manifest without a lockfile is a metadata or version change
+    assert!(!crate::labels::is_dependency_update(
+        &pr,
+        &[changed_file("Cargo.toml")],
+        &config
+    ));
+    // Source changes mean the PR does more than update dependencies
+    assert!(!crate::labels::is_dependency_update(
+        &pr,
+        &[changed_file("Cargo.lock"), changed_file("src/main.rs")],
+        &config
+    ));
+
+    let config = crate::config::DependencyUpdateConfig {
+        require_only_dependency_files: false,
+        ..Default::default()
+    };
+    assert!(crate::labels::is_dependency_update(
+        &pr,
+        &[changed_file("Cargo.lock"), changed_file("src/main.rs")],
+        &config
+    ));
+}
+
+#[test]
+async fn is_dependency_update_file_detection_can_be_turned_off() {
+    let config = crate::config::DependencyUpdateConfig {
+        lockfile_patterns: vec![],
+        ..Default::default()
+    };
+
+    assert!(!crate::labels::is_dependency_update(
+        &dependency_pr("chore: update packages"),
+        &[changed_file("Cargo.lock")],
+        &config
+    ));
+}
+
+#[test]
+async fn manage_dependency_label_applies_and_creates_label() {
+    let provider = StabilityMockProvider::new(vec![]);
+    let config = crate::config::DependencyUpdateConfig {
+        enabled: true,
+        ..Default::default()
+    };
+
+    let applied = crate::labels::manage_dependency_label(
+        &provider,
+        "owner",
+        "repo",
+        &dependency_pr("chore(deps): bump tokio"),
+        &[],
+        &config,
+    )
+    .await
+    .unwrap();
+
+    assert!(applied);
+    assert_eq!(provider.create_label_calls(), vec!["dependencies"]);
+    assert!(provider
+        .applied_labels()
+        .iter()
+        .any(|l| l.name == "dependencies"));
+}
+
+#[test]
+async fn manage_dependency_label_disabled_is_noop() {
+    let provider = StabilityMockProvider::new(vec![]);
+    let config = crate::config::DependencyUpdateConfig::default();
+
+    let applied = crate::labels::manage_dependency_label(
+        &provider,
+        "owner",
+        "repo",
+        &dependency_pr("chore(deps): bump tokio"),
+        &[],
+        &config,
+    )
+    .await
+    .unwrap();
+
+    assert!(!applied);
+    assert!(provider.applied_labels().is_empty());
+}
+
+// ── Label creation tests ─────────────────────────────────────────────────────
+
+#[test]
+async fn test_find_missing_labels_compares_names_case_insensitively() {
+    let provider =
+        SizeLabelMockProvider::new(vec![make_label("Bug"), make_label("size/S")], vec![]);
+
+    let missing = crate::labels::find_missing_labels(
+        &provider,
+        "owner",
+        "repo",
+        &[
+            "bug".to_string(),
+            "feature".to_string(),
+            "size/S".to_string(),
+        ],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(missing, vec!["feature".to_string()]);
+}
+
+#[test]
+async fn test_apply_pull_request_labels_skips_missing_labels_when_creation_disabled() {
+    // The repository defines no labels, so neither the type label nor the
+    // breaking-change label may be applied.
+    let provider = KeywordLabelMockProvider::new(vec![], vec![]);
+    let pr = PullRequest {
+        number: 10,
+        title: "feat!: remove the v1 API".to_string(),
+        draft: false,
+        body: None,
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let config = CurrentPullRequestValidationConfiguration {
+        label_creation: crate::config::LabelCreationPolicyConfig {
+            create_missing_labels: false,
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let result = crate::labels::apply_pull_request_labels(
+        &provider,
+        "o",
+        "r",
+        &pr,
+        Some(&config),
+        &crate::clock::SystemClock,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.applied_labels.is_empty());
+    assert_eq!(
+        result.skipped_labels,
+        vec!["feature".to_string(), "breaking-change".to_string()]
+    );
+    assert!(provider.added_labels().is_empty());
+    // The explanation comment of a skipped keyword label is not posted
+    assert!(provider.posted_comments().is_empty());
+}
+
+#[test]
+async fn test_manage_size_labels_skips_missing_fallback_when_creation_disabled() {
+    use crate::labels::manage_size_labels;
+    use crate::size::{PrSizeInfo, SizeCategoryNames, SizeCountingMode, SizeThresholds};
+
+    let provider = SizeLabelMockProvider::new(vec![], vec![]);
+    let size_info = PrSizeInfo::new(
+        vec![merge_warden_developer_platforms::models::PullRequestFile {
+            filename: "src/lib.rs".to_string(),
+            additions: 50,
+            deletions: 25,
+            changes: 75,
+            status: "modified".to_string(),
+        }],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
+
+    let result = manage_size_labels(
+        &provider,
+        "owner",
+        "repo",
+        1,
+        &size_info,
+        "size/",
+        &SizeCategoryNames::default(),
+        false,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, None);
+    assert!(provider.get_add_calls().is_empty());
+}
+
+// ── compute_label_changes ────────────────────────────────────────────────────
+
+fn label_change_pr(title: &str, body: &str) -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: title.to_string(),
+        draft: false,
+        body: Some(body.to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+fn smart_label_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        change_type_labels: Some(ChangeTypeLabelConfig::default()),
+        ..CurrentPullRequestValidationConfiguration::default()
+    }
+}
+
+#[test]
+async fn test_compute_label_changes_replaces_stale_change_type_label() {
+    use crate::labels::{compute_label_changes, LabelChangeInput};
+
+    let pr = label_change_pr("fix: handle empty bodies", "");
+    let repository_labels = vec![make_label("bug"), make_label("enhancement")];
+    let current = vec![make_label("enhancement"), make_label("good first issue")];
+
+    let changes = compute_label_changes(
+        &LabelChangeInput {
+            pr: &pr,
+            current_labels: &current,
+            repository_labels: &repository_labels,
+            comments: &[],
+            size_info: None,
+        },
+        Some(&smart_label_config()),
+    );
+
+    assert_eq!(changes.to_add, vec!["bug".to_string()]);
+    assert_eq!(changes.to_remove, vec!["enhancement".to_string()]);
+}
+
+#[test]
+async fn test_compute_label_changes_is_empty_when_labels_are_current() {
+    use crate::labels::{compute_label_changes, LabelChangeInput};
+
+    let pr = label_change_pr("fix: handle empty bodies", "");
+    let current = vec![make_label("Bug")];
+
+    let changes = compute_label_changes(
+        &LabelChangeInput {
+            pr: &pr,
+            current_labels: &current,
+            repository_labels: &[make_label("bug")],
+            comments: &[],
+            size_info: None,
+        },
+        Some(&smart_label_config()),
+    );
+
+    assert!(changes.is_empty());
+}
+
+#[test]
+async fn test_compute_label_changes_removes_suppressed_keyword_labels() {
+    use crate::labels::{compute_label_changes, LabelChangeInput};
+
+    let pr = label_change_pr(
+        "feat: rotate keys",
+        "Fixes a security issue. This is a hotfix.",
+    );
+    let current = vec![make_label("security")];
+    let comments = vec![make_comment(1, "alice", "@merge-warden suppress: security")];
+
+    let changes = compute_label_changes(
+        &LabelChangeInput {
+            pr: &pr,
+            current_labels: &current,
+            repository_labels: &[],
+            comments: &comments,
+            size_info: None,
+        },
+        None,
+    );
+
+    assert_eq!(
+        changes.to_add,
+        vec!["feature".to_string(), "hotfix".to_string()]
+    );
+    assert_eq!(changes.to_remove, vec!["security".to_string()]);
+}
+
+#[test]
+async fn test_compute_label_changes_replaces_size_label() {
+    use crate::labels::{compute_label_changes, LabelChangeInput};
+    use crate::size::{PrSizeCategory, PrSizeInfo, SizeCountingMode, SizeThresholds};
+
+    let pr = label_change_pr("chore: tidy up", "");
+    let size_info = PrSizeInfo::new(
+        vec![merge_warden_developer_platforms::models::PullRequestFile {
+            filename: "src/lib.rs".to_string(),
+            additions: 400,
+            deletions: 200,
+            changes: 600,
+            status: "modified".to_string(),
+        }],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
+    assert_eq!(size_info.size_category, PrSizeCategory::XXL);
+    let current = vec![make_label("chore"), make_label("size/S")];
+
+    let changes = compute_label_changes(
+        &LabelChangeInput {
+            pr: &pr,
+            current_labels: &current,
+            repository_labels: &standard_size_repo_labels(),
+            comments: &[],
+            size_info: Some(&size_info),
+        },
+        None,
+    );
+
+    assert_eq!(changes.to_add, vec!["size/XXL".to_string()]);
+    assert_eq!(changes.to_remove, vec!["size/S".to_string()]);
+}
+
+#[test]
+async fn test_compute_label_changes_leaves_out_missing_labels_when_creation_disabled() {
+    use crate::labels::{compute_label_changes, LabelChangeInput};
+
+    let pr = label_change_pr("feat!: drop the v1 API", "");
+    let config = CurrentPullRequestValidationConfiguration {
+        label_creation: crate::config::LabelCreationPolicyConfig {
+            create_missing_labels: false,
+        },
+        ..smart_label_config()
+    };
+
+    let changes = compute_label_changes(
+        &LabelChangeInput {
+            pr: &pr,
+            current_labels: &[],
+            repository_labels: &[make_label("breaking-change")],
+            comments: &[],
+            size_info: None,
+        },
+        Some(&config),
+    );
+
+    // No feat label exists and the fallback label may not be created
+    assert_eq!(changes.to_add, vec!["breaking-change".to_string()]);
+    assert!(changes.to_remove.is_empty());
+}
+
+#[test]
+async fn test_oversized_pr_comment_states_counting_mode() {
+    use crate::labels::generate_oversized_pr_comment;
+    use crate::size::{PrSizeInfo, SizeCountingMode, SizeThresholds};
+
+    let files = vec![PullRequestFile {
+        filename: "src/large_file.rs".to_string(),
+        additions: 1200,
+        deletions: 100,
+        changes: 1300,
+        status: "modified".to_string(),
+    }];
+
+    let net = PrSizeInfo::from_files_with_exclusions(
+        &files,
+        &SizeThresholds::default(),
+        &[],
+        SizeCountingMode::NetChanges,
+    );
+    let total = PrSizeInfo::from_files_with_exclusions(
+        &files,
+        &SizeThresholds::default(),
+        &[],
+        SizeCountingMode::TotalChanges,
+    );
+
+    let net_comment = generate_oversized_pr_comment(&net);
+    assert!(net_comment.contains("**1100 lines**"));
+    assert!(net_comment.contains("- **Lines counted**: net lines (added minus deleted, per file)"));
+
+    let total_comment = generate_oversized_pr_comment(&total);
+    assert!(total_comment.contains("**1300 lines**"));
+    assert!(total_comment.contains("- **Lines counted**: added and deleted lines"));
+}
+
+fn oversized_breakdown_size_info() -> crate::size::PrSizeInfo {
+    use crate::size::{PrSizeInfo, SizeCountingMode, SizeThresholds};
+
+    let file = |filename: &str, changes: u32| PullRequestFile {
+        filename: filename.to_string(),
+        additions: changes,
+        deletions: 0,
+        changes,
+        status: "modified".to_string(),
+    };
+    PrSizeInfo::from_files_with_exclusions(
+        &[
+            file("src/api/handlers.rs", 400),
+            file("src/lib.rs", 150),
+            file("tests/api.rs", 120),
+            file("build.rs", 30),
+        ],
+        &SizeThresholds::default(),
+        &[],
+        SizeCountingMode::TotalChanges,
+    )
+}
+
+#[test]
+async fn test_oversized_pr_comment_lists_largest_files_and_directories() {
+    use crate::labels::generate_oversized_pr_comment_with_renderer;
+    use crate::size::SizeCategoryNames;
+    use crate::templates::TemplateEngineRenderer;
+
+    let comment = generate_oversized_pr_comment_with_renderer(
+        &oversized_breakdown_size_info(),
+        &SizeCategoryNames::default(),
+        2,
+        &TemplateEngineRenderer::new(),
+    );
+
+    assert!(comment.contains(
+        "### Largest files\n\n| File | Lines |\n| :--- | ---: |\n| `src/api/handlers.rs` | 400 |\n| `src/lib.rs` | 150 |\n\n### Largest directories"
+    ));
+    assert!(comment.contains(
+        "| Directory | Files | Lines |\n| :--- | ---: | ---: |\n| `src/` | 2 | 550 |\n| `tests/` | 1 | 120 |\n\n*This is an automated message"
+    ));
+    assert!(!comment.contains("build.rs"));
+}
+
+#[test]
+async fn test_oversized_pr_comment_without_breakdown_rows_leaves_tables_out() {
+    use crate::labels::generate_oversized_pr_comment_with_renderer;
+    use crate::size::SizeCategoryNames;
+    use crate::templates::TemplateEngineRenderer;
+
+    let comment = generate_oversized_pr_comment_with_renderer(
+        &oversized_breakdown_size_info(),
+        &SizeCategoryNames::default(),
+        0,
+        &TemplateEngineRenderer::new(),
+    );
+
+    assert!(!comment.contains("### Largest"));
+    assert!(comment.contains(
+        "(Extra Extra Large - Should be split for better reviewability)\n\n*This is an automated message"
+    ));
+}
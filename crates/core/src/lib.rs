@@ -48,7 +48,7 @@
 //!         title_pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
 //!         invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
 //!         enforce_work_item_references: true,
-//!         work_item_reference_pattern: WORK_ITEM_REGEX.to_string(),
+//!         work_item_reference_pattern: WORK_ITEM_REGEX.into(),
 //!         missing_work_item_label: Some(MISSING_WORK_ITEM_LABEL.to_string()),
 //!         bypass_rules: BypassRules::default(),
 //!         pr_size_check: Default::default(),
@@ -67,32 +67,308 @@
 //! ```
 
 use checks::extract_any_issue_reference;
-use indoc::formatdoc;
-use merge_warden_developer_platforms::models::{Installation, PullRequest, Repository, Review};
+use merge_warden_developer_platforms::models::{
+    CheckRunAction, Comment, Installation, Label, PullRequest, Repository, Review, User,
+};
+use merge_warden_developer_platforms::webhook::PullRequestAction;
 use merge_warden_developer_platforms::{ConfigFetcher, IssueMetadataProvider, PullRequestProvider};
 
+pub mod autofix;
 pub mod checks;
+pub mod clock;
+use clock::{Clock, SystemClock};
 pub mod config;
 use config::CurrentPullRequestValidationConfiguration;
+use config::AUTOFIX_COMMENT_MARKER;
 use config::CONFIG_COMMENT_MARKER;
+use config::DRAFT_CONVERSION_COMMENT_MARKER;
 use config::SIZE_COMMENT_MARKER;
+use config::THREAD_ROOT_COMMENT_MARKER;
 use config::TITLE_COMMENT_MARKER;
+use config::TRANSITION_COMMENT_MARKER;
 use config::WIP_COMMENT_MARKER;
 use config::WORK_ITEM_COMMENT_MARKER;
 use config::{validate_config_content, ConfigValidationOutcome, CONFIG_FILE_PATH};
+pub mod decision_log;
+use decision_log::{CheckRunDecision, DecisionInput, DecisionLog, DecisionRecorder};
+pub mod dry_run;
+use dry_run::{DryRunProvider, DryRunRecorder, PlannedOperation};
 
 /// Error types and utilities for Merge Warden operations.
 ///
 /// This module contains error types that can occur during pull request
 /// validation, configuration parsing, and Git provider interactions.
 pub mod errors;
-use errors::MergeWardenError;
-use serde::Deserialize;
+use errors::{MergeWardenError, StateStoreError};
+use futures::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{debug, error, info, instrument, warn};
 
+pub mod explain;
 pub mod labels;
 pub mod size;
+pub mod state;
+use state::{InMemoryStateStore, StateEntry, StateKey, StateStore};
+pub mod summary;
+use summary::CheckStatus;
+pub mod templates;
 pub mod validation_result;
+pub mod version;
+use templates::{
+    render_comment, AutofixCommentContext, BypassNoticeContext, CommentRenderer, CommentTemplate,
+    ConclusionTransitionContext, DraftConversionContext, InvalidConfigCommentContext,
+    TemplateEngineRenderer,
+};
+
+/// Maximum number of files listed in each section of the size details in the check output.
+const SIZE_DETAILS_MAX_FILES: usize = 50;
+
+/// Maximum length of a comment body accepted by GitHub, in characters.
+const COMMENT_MAX_CHARS: usize = 65_536;
+
+/// Maximum length of a check-run summary accepted by GitHub, in characters.
+const CHECK_SUMMARY_MAX_CHARS: usize = 65_535;
+
+/// Name under which the conclusion of the previous run is kept in the state store.
+const CONCLUSION_STATE_NAME: &str = "conclusion";
+
+/// Number of days the conclusion of the previous run is kept in the state store.
+const CONCLUSION_STATE_TTL_DAYS: i64 = 90;
+
+/// Name under which the state store records that a PR was converted to a draft.
+const DRAFT_CONVERSION_STATE_NAME: &str = "draft_conversion";
+
+/// Number of days the draft conversion of a PR is remembered in the state store.
+const DRAFT_CONVERSION_STATE_TTL_DAYS: i64 = 90;
+
+/// Reason recorded for checks that were not run because an earlier check failed.
+const SHORT_CIRCUITED_REASON: &str = "Not run because an earlier check failed";
+
+/// Reason recorded for every check when the PR only changes ignored paths.
+const PATH_SKIPPED_REASON: &str = "Not run because the pull request only changes ignored paths";
+
+/// Users whose bypass labels activate each bypass rule for the current PR.
+#[derive(Debug, Default)]
+struct LabelBypasses {
+    /// Authorised applier of the title-convention bypass label.
+    title_convention: Option<User>,
+
+    /// Authorised applier of the work-item bypass label.
+    work_items: Option<User>,
+
+    /// Authorised applier of the size bypass label.
+    size: Option<User>,
+
+    /// Authorised applier of the signed commits bypass label.
+    signed_commits: Option<User>,
+
+    /// Authorised applier of the revert reference bypass label.
+    revert_reference: Option<User>,
+}
+
+/// Outcome of the review-thread check when it is enabled.
+#[derive(Debug, Clone)]
+enum ReviewThreadOutcome {
+    /// The review threads were fetched and checked against the policy.
+    Checked(checks::ReviewThreadValidationResult),
+
+    /// The review threads could not be fetched; the check is skipped with the given reason.
+    Unavailable(&'static str),
+}
+
+/// Outcome of the signed commits check when it is enabled.
+#[derive(Debug, Clone)]
+enum SignedCommitsOutcome {
+    /// The commits were fetched and checked for verified signatures, or the PR was bypassed.
+    Checked(checks::SignedCommitsValidationResult),
+
+    /// The commits could not be fetched; the check is skipped with the given reason.
+    Unavailable(&'static str),
+}
+
+/// Outcome of the up-to-date check when it is enabled.
+#[derive(Debug, Clone)]
+enum UpToDateOutcome {
+    /// The PR was compared with its base branch and checked against the policy.
+    Checked(checks::UpToDateValidationResult),
+
+    /// The PR could not be compared with its base; the check is skipped with the given
+    /// reason.
+    Unavailable(&'static str),
+}
+
+/// Outcome of the change types check when it is enabled.
+#[derive(Debug, Clone)]
+enum ChangeTypesOutcome {
+    /// The commits were fetched and the change types of the PR were counted.
+    Checked(checks::ChangeTypesValidationResult),
+
+    /// The commits could not be fetched; the check is skipped with the given reason.
+    Unavailable(&'static str),
+}
+
+/// Outcome of the version bump check when it is enabled.
+#[derive(Debug, Clone)]
+enum VersionBumpOutcome {
+    /// The versions were read and the change was checked against the PR's change level.
+    Checked(checks::VersionBumpValidationResult),
+
+    /// The versions could not be compared; the check is skipped with the given reason.
+    Unavailable(&'static str),
+}
+
+/// Results of the checks that call the provider, fetched together when the checks run
+/// concurrently. Each check takes its result when its turn in the check order comes.
+#[derive(Debug, Default)]
+struct ConcurrentCheckResults {
+    /// Outcome of the review-thread check.
+    review_threads: Option<ReviewThreadOutcome>,
+
+    /// The size exclusion matching the PR.
+    size_exclusion: Option<config::SizeExclusion>,
+
+    /// Outcome of the version bump check.
+    version_bump: Option<VersionBumpOutcome>,
+
+    /// Result of the exclusive labels check.
+    exclusive_labels: Option<checks::ExclusiveLabelsValidationResult>,
+
+    /// Outcome of the signed commits check.
+    signed_commits: Option<SignedCommitsOutcome>,
+
+    /// Outcome of the up-to-date check.
+    up_to_date: Option<UpToDateOutcome>,
+
+    /// Outcome of the change types check.
+    change_types: Option<ChangeTypesOutcome>,
+
+    /// Result of the label policy check.
+    label_policy: Option<checks::LabelPolicyValidationResult>,
+}
+
+/// How a check contributed to the check run of a PR.
+///
+/// Every check yields one report, whether it ran, was disabled or was not run, so that
+/// the conclusion, the summary, the status table and the check output are all built
+/// from the reports of the checks.
+#[derive(Debug)]
+struct CheckReport {
+    /// Whether the check passed, was bypassed or did not run.
+    passed: bool,
+
+    /// Whether a failure of the check blocks the PR.
+    blocking: bool,
+
+    /// Whether the check would have blocked the PR, but only reports its failures.
+    report_only_failure: bool,
+
+    /// The failure as listed in the check summary, e.g. "title is invalid".
+    issue: Option<String>,
+
+    /// Why the check did not run.
+    skip_reason: Option<String>,
+
+    /// The bypass that let the check pass.
+    bypass: Option<validation_result::BypassInfo>,
+
+    /// The row of the check in the status table.
+    row: summary::CheckSummaryRow,
+
+    /// Further details of the outcome for the check output.
+    details: Option<String>,
+}
+
+impl CheckReport {
+    /// Creates the report of a check that passed, shown with `status` and `message` in
+    /// the status table.
+    fn new(
+        kind: config::CheckKind,
+        status: summary::CheckStatus,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            passed: true,
+            blocking: false,
+            report_only_failure: false,
+            issue: None,
+            skip_reason: None,
+            bypass: None,
+            row: summary::CheckSummaryRow::new(kind.summary_name(), status, message),
+            details: None,
+        }
+    }
+
+    /// Creates the report of a check that did not run for the given reason.
+    fn skipped(kind: config::CheckKind, reason: impl Into<String>) -> Self {
+        let reason = reason.into();
+        Self {
+            skip_reason: Some(reason.clone()),
+            ..Self::new(kind, summary::CheckStatus::Skipped, reason)
+        }
+    }
+
+    /// Creates the report of a check that was bypassed.
+    fn bypassed(kind: config::CheckKind, bypass: &validation_result::BypassInfo) -> Self {
+        Self {
+            bypass: Some(bypass.clone()),
+            ..Self::new(
+                kind,
+                summary::CheckStatus::Bypassed,
+                format!("Bypassed for `{}`", bypass.user),
+            )
+        }
+    }
+
+    /// Marks the check as failed, with `issue` listed in the check summary. The failure
+    /// blocks the PR unless [`CheckReport::blocking`] says otherwise.
+    fn failed(self, issue: impl Into<String>) -> Self {
+        Self {
+            passed: false,
+            blocking: true,
+            issue: Some(issue.into()),
+            ..self
+        }
+    }
+
+    /// Sets whether a failure of the check blocks the PR, for checks with a configurable
+    /// severity.
+    fn blocking(self, blocking: bool) -> Self {
+        Self { blocking, ..self }
+    }
+
+    /// Records the bypass that let the check pass, if any.
+    fn with_bypass(self, bypass: Option<&validation_result::BypassInfo>) -> Self {
+        Self {
+            bypass: bypass.cloned(),
+            ..self
+        }
+    }
+
+    /// Adds details of the outcome for the check output, if any.
+    fn with_details(self, details: Option<String>) -> Self {
+        Self { details, ..self }
+    }
+
+    /// Returns `true` if the check failed in a way that blocks the PR.
+    fn fails(&self) -> bool {
+        !self.passed && self.blocking
+    }
+
+    /// Returns `true` if the check counts as passing. A report-only failure passes.
+    fn passes(&self) -> bool {
+        self.passed || self.report_only_failure
+    }
+
+    /// Records that the check failed in report-only mode: the failure is shown as a
+    /// notice and neither blocks the PR nor is listed in the summary.
+    fn mark_report_only(&mut self) {
+        self.report_only_failure = true;
+        self.row.status = summary::CheckStatus::Failed(config::CheckSeverity::Notice);
+        self.row.message = format!("Report only: {}", self.row.message);
+    }
+}
 
 #[cfg(test)]
 #[path = "lib_tests.rs"]
@@ -102,7 +378,7 @@ mod tests;
 ///
 /// Contains information about the validation status, any labels that were added,
 /// and details about any bypass rules that were used during validation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CheckResult {
     /// Whether the PR title follows the Conventional Commits format or was bypassed
     pub title_valid: bool,
@@ -116,11 +392,231 @@ pub struct CheckResult {
     /// Whether the PR was detected as a WIP (Work In Progress)
     pub wip_detected: bool,
 
+    /// Whether every required checklist item in the PR description is ticked.
+    ///
+    /// Always `true` when the checklist check is disabled.
+    pub checklist_complete: bool,
+
+    /// Whether the number of unresolved review threads is within the configured limit.
+    ///
+    /// Always `true` when the review-thread check is disabled, or when the platform
+    /// could not report review threads.
+    pub review_threads_resolved: bool,
+
+    /// Whether every deleted file matched by a pairing rule has its paired changes.
+    ///
+    /// Always `true` when the deleted-file check is disabled. A `false` value only
+    /// fails the check run when the check's severity is `error`.
+    pub deleted_files_paired: bool,
+
+    /// Whether a version change in the PR follows semantic versioning for its changes.
+    ///
+    /// Always `true` when the version bump check is disabled, when the PR does not
+    /// change the version, or when the versions could not be read.
+    pub version_bump_valid: bool,
+
+    /// Whether no added or modified file looks like a secret, a binary, or is too large.
+    ///
+    /// Always `true` when the suspicious file check is disabled. A `false` value only
+    /// fails the check run when the check's severity is `error`.
+    pub suspicious_files_absent: bool,
+
+    /// Whether the PR targets a base branch allowed for its type.
+    ///
+    /// Always `true` when the base branch check is disabled or no rule applies to the
+    /// PR. A `false` value only fails the check run when the check's severity is `error`.
+    pub base_branch_valid: bool,
+
+    /// Whether the PR carries at most one label from each exclusive label group.
+    ///
+    /// Always `true` when the exclusive labels check is disabled. A `false` value only
+    /// fails the check run when the check's severity is `error`.
+    pub labels_exclusive: bool,
+
+    /// Whether every commit in the PR has a verified signature.
+    ///
+    /// Always `true` when the signed commits check is disabled, when the PR was
+    /// bypassed, or when the platform could not report commit verification.
+    pub commits_signed: bool,
+
+    /// Whether a revert PR references the PR or commit it reverts.
+    ///
+    /// Always `true` when the revert reference check is disabled, when the PR is not a
+    /// revert, or when it was bypassed.
+    pub revert_referenced: bool,
+
+    /// Whether the PR is fewer commits behind its base branch than the up-to-date
+    /// check's threshold.
+    ///
+    /// Always `true` when the up-to-date check is disabled, or when the platform could
+    /// not compare the PR with its base. A `false` value only fails the check run when
+    /// the check's severity is `error`.
+    pub up_to_date: bool,
+
+    /// Whether every required footer is present at the end of the PR description.
+    ///
+    /// Always `true` when the footer check is disabled.
+    pub footers_present: bool,
+
+    /// Whether the PR title is more than its branch name.
+    ///
+    /// Always `true` when the descriptive title check is disabled, or when the platform
+    /// did not report the head branch. A `false` value only fails the check run when
+    /// the check's severity is `error`.
+    pub title_descriptive: bool,
+
+    /// Whether the PR has no more distinct change types than the change types check
+    /// allows.
+    ///
+    /// Always `true` when the change types check is disabled, or when the platform could
+    /// not list the PR's commits. A `false` value only fails the check run when the
+    /// check's severity is `error`.
+    pub change_types_within_limit: bool,
+
+    /// Whether the PR carries every required label and no forbidden label.
+    ///
+    /// Always `true` when the label policy check is disabled, or when the labels of the
+    /// PR could not be listed. A `false` value only fails the check run when the check's
+    /// severity is `error`.
+    pub label_policy_valid: bool,
+
+    /// Checks that were not run because an earlier check failed and
+    /// `short_circuit_on_failure` is enabled. Their validity flags are `true`.
+    pub skipped_checks: Vec<config::CheckKind>,
+
+    /// Every check that was not run, in check order, with the reason it was skipped:
+    /// the check is disabled, the data it needs was unavailable, or an earlier check
+    /// failed. Distinguishes a skipped check from a passing one, since both have a
+    /// validity flag of `true`.
+    pub skip_reasons: Vec<(config::CheckKind, String)>,
+
+    /// Report-only checks that failed. The failures did not affect the conclusion,
+    /// which is `neutral` instead of `success`, and their validity flags are `true`.
+    pub report_only_failures: Vec<config::CheckKind>,
+
+    /// Checks whose failure made the conclusion `failure`, in check order.
+    ///
+    /// Only checks that block the PR are listed: a check with a `warning` or `notice`
+    /// severity, a report-only check, or a size check without `fail_on_oversized` is
+    /// not, even when its validity flag is `false`. Draft PRs list the checks that would
+    /// fail once the PR is ready for review, although their conclusion is `neutral`.
+    /// Empty when the PR is blocked as WIP, which `wip_detected` reports instead.
+    pub failing_rules: Vec<config::CheckKind>,
+
     /// Labels that were added to the PR based on its content
     pub labels: Vec<String>,
 
     /// Information about any bypasses that were used during validation
     pub bypasses_used: Vec<validation_result::BypassInfo>,
+
+    /// The check-run summary (`output_summary`) that was reported for the PR
+    pub check_summary: String,
+
+    /// The check-run body (`output_text`) that was reported for the PR
+    pub check_text: String,
+
+    /// The changes a dry run planned instead of applying, in the order they would
+    /// have been applied.
+    ///
+    /// Empty unless the result is from [`MergeWarden::process_pull_request_dry_run`].
+    /// The labels, comments and check run reported elsewhere in the result were not
+    /// applied either.
+    pub planned_operations: Vec<PlannedOperation>,
+}
+
+impl CheckResult {
+    /// Whether the result blocks the PR from being merged: it is marked as WIP or a
+    /// check in [`CheckResult::failing_rules`] failed.
+    ///
+    /// Draft PRs are reported with a `neutral` conclusion, but this still returns
+    /// `true` for them when a check would block the PR once it is ready for review.
+    pub fn blocks_merge(&self) -> bool {
+        self.wip_detected || !self.failing_rules.is_empty()
+    }
+}
+
+impl Default for CheckResult {
+    /// A passing result: every check is valid, the PR is not WIP and nothing was
+    /// skipped, failed, labeled, bypassed or planned.
+    fn default() -> Self {
+        Self {
+            title_valid: true,
+            work_item_referenced: true,
+            size_valid: true,
+            wip_detected: false,
+            checklist_complete: true,
+            review_threads_resolved: true,
+            deleted_files_paired: true,
+            version_bump_valid: true,
+            suspicious_files_absent: true,
+            base_branch_valid: true,
+            labels_exclusive: true,
+            commits_signed: true,
+            revert_referenced: true,
+            up_to_date: true,
+            footers_present: true,
+            title_descriptive: true,
+            change_types_within_limit: true,
+            label_policy_valid: true,
+            skipped_checks: Vec::new(),
+            skip_reasons: Vec::new(),
+            report_only_failures: Vec::new(),
+            failing_rules: Vec::new(),
+            labels: Vec::new(),
+            bypasses_used: Vec::new(),
+            check_summary: String::new(),
+            check_text: String::new(),
+            planned_operations: Vec::new(),
+        }
+    }
+}
+
+/// A pull request to process with [`MergeWarden::process_pull_requests`].
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::PullRequestTarget;
+///
+/// let target = PullRequestTarget::from(("owner", "repo", 42));
+/// assert_eq!(target.to_string(), "owner/repo#42");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PullRequestTarget {
+    /// The owner of the repository
+    pub owner: String,
+
+    /// The name of the repository
+    pub repo: String,
+
+    /// The pull request number
+    pub pr_number: u64,
+}
+
+impl<O: Into<String>, R: Into<String>> From<(O, R, u64)> for PullRequestTarget {
+    fn from((owner, repo, pr_number): (O, R, u64)) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+            pr_number,
+        }
+    }
+}
+
+impl std::fmt::Display for PullRequestTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}#{}", self.owner, self.repo, self.pr_number)
+    }
+}
+
+/// The outcome of processing one pull request with [`MergeWarden::process_pull_requests`].
+#[derive(Debug)]
+pub struct BatchProcessResult {
+    /// The pull request that was processed
+    pub target: PullRequestTarget,
+
+    /// The check result, or the error that stopped this pull request from being processed
+    pub result: Result<CheckResult, MergeWardenError>,
 }
 
 /// Webhook payload structure for GitHub webhook events.
@@ -131,7 +627,7 @@ pub struct CheckResult {
 ///
 /// # Fields
 ///
-/// * `action` - The type of action that triggered the webhook (e.g., "opened", "synchronize")
+/// * `action` - The type of action that triggered the webhook (e.g., `Opened`, `Synchronize`)
 /// * `pull_request` - The pull request data, if available
 /// * `repository` - The repository information
 /// * `installation` - The GitHub App installation information, if applicable
@@ -140,6 +636,7 @@ pub struct CheckResult {
 ///
 /// ```rust
 /// use merge_warden_core::WebhookPayload;
+/// use merge_warden_developer_platforms::webhook::PullRequestAction;
 /// use serde_json::from_str;
 ///
 /// let json = r#"{
@@ -150,12 +647,12 @@ pub struct CheckResult {
 /// }"#;
 ///
 /// let payload: WebhookPayload = from_str(json).expect("Failed to parse webhook payload");
-/// assert_eq!(payload.action, "opened");
+/// assert_eq!(payload.action, PullRequestAction::Opened);
 /// ```
 #[derive(Deserialize)]
 pub struct WebhookPayload {
     /// The action that triggered the webhook event
-    pub action: String,
+    pub action: PullRequestAction,
 
     /// The pull request data, if available in the webhook payload
     pub pull_request: Option<PullRequest>,
@@ -202,9 +699,36 @@ pub struct MergeWarden<P: PullRequestProvider + std::fmt::Debug> {
     /// updates back to pull requests. When `None`, `propagate_issue_metadata` is
     /// skipped regardless of the `issue_propagation` config flags.
     issue_provider: Option<Box<dyn IssueMetadataProvider>>,
+
+    /// Renders the comments posted on pull requests. Defaults to
+    /// [`TemplateEngineRenderer`] with the built-in templates.
+    comment_renderer: Box<dyn CommentRenderer>,
+
+    /// The source of the current time. Defaults to [`SystemClock`].
+    clock: Arc<dyn Clock>,
+
+    /// Where per-pull-request state is kept between events. Defaults to an
+    /// [`InMemoryStateStore`].
+    state_store: Arc<dyn StateStore>,
+
+    /// Records the decisions of the runs started by
+    /// [`MergeWarden::process_pull_request_with_decision_log`].
+    decision_recorder: DecisionRecorder,
+
+    /// Records the operations planned by the runs started by
+    /// [`MergeWarden::process_pull_request_dry_run`].
+    dry_run_recorder: DryRunRecorder,
 }
 
 impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
+    /// Returns the provider to make platform calls through.
+    ///
+    /// Changes to a pull request with a dry run in progress are planned instead of
+    /// applied; every other call goes to the provider this instance was created with.
+    fn provider(&self) -> DryRunProvider<'_, P> {
+        DryRunProvider::new(&self.provider, &self.dry_run_recorder)
+    }
+
     /// Checks if the PR title follows the Conventional Commits format.
     ///
     /// This is a wrapper around the `checks::check_pr_title` function that returns
@@ -213,16 +737,22 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
     /// # Arguments
     ///
     /// * `pr` - The pull request to check
+    /// * `bypass_author` - The user the title bypass rule is evaluated for
     ///
     /// # Returns
     ///
     /// A `TitleValidationResult` containing validation status, bypass information,
     /// and structured diagnosis when the title is invalid
     #[instrument]
-    fn check_title(&self, pr: &PullRequest) -> checks::TitleValidationResult {
+    fn check_title(
+        &self,
+        pr: &PullRequest,
+        bypass_author: Option<&User>,
+    ) -> checks::TitleValidationResult {
         debug!(pull_request = pr.number, "Checking PR title");
         checks::check_pr_title(
             pr,
+            bypass_author,
             self.config.bypass_rules.title_convention(),
             &self.config,
         )
@@ -236,23 +766,113 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
     /// # Arguments
     ///
     /// * `pr` - The pull request to check
+    /// * `bypass_author` - The user the work item bypass rule is evaluated for
     ///
     /// # Returns
     ///
-    /// A `ValidationResult` containing validation status and bypass information
+    /// A `WorkItemValidationResult` containing validation status, the matched pattern
+    /// and bypass information
     #[instrument]
-    fn check_work_item_reference(&self, pr: &PullRequest) -> validation_result::ValidationResult {
+    fn check_work_item_reference(
+        &self,
+        pr: &PullRequest,
+        bypass_author: Option<&User>,
+    ) -> checks::WorkItemValidationResult {
         debug!(
             pull_request = pr.number,
             "Checking work item reference in PR description"
         );
         checks::check_work_item_reference(
             pr,
+            bypass_author,
             self.config.bypass_rules.work_item_convention(),
             &self.config,
         )
     }
 
+    /// Looks up the same-repository issue references of a work item check and records
+    /// those that do not resolve to an issue.
+    ///
+    /// Does nothing when issue reference verification is off, or when the check did
+    /// not find a reference or was bypassed. With the `fail` enforcement level a
+    /// dangling reference fails the check. A reference that cannot be looked up is
+    /// logged and treated as valid, so that an unavailable provider never blocks a PR.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr_number` - The pull request number, for logging
+    /// * `result` - The outcome of the work item check, updated in place
+    async fn verify_issue_references(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        result: &mut checks::WorkItemValidationResult,
+    ) {
+        let enforcement = self.config.issue_references.enforcement;
+        if enforcement == config::IssueReferenceEnforcement::Off
+            || !result.is_valid()
+            || result.was_bypassed()
+        {
+            return;
+        }
+
+        for number in
+            checks::same_repository_issue_numbers(&result.references, repo_owner, repo_name)
+        {
+            match self
+                .provider()
+                .get_issue(repo_owner, repo_name, number)
+                .await
+            {
+                Ok(Some(issue)) if !issue.is_pull_request => {}
+                Ok(issue) => {
+                    result
+                        .dangling_references
+                        .push(checks::DanglingIssueReference {
+                            number,
+                            is_pull_request: issue.is_some(),
+                        });
+                }
+                Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                    info!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        "Issue reference verification is enabled but the platform does not support it; skipping"
+                    );
+                    return;
+                }
+                Err(e) => {
+                    warn!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        issue = number,
+                        error = e.to_string(),
+                        "Failed to look up referenced issue; treating the reference as valid"
+                    );
+                }
+            }
+        }
+
+        if !result.dangling_references.is_empty() {
+            info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                dangling = ?result.dangling_references,
+                enforcement = ?enforcement,
+                "PR description references issues that do not exist"
+            );
+            if enforcement == config::IssueReferenceEnforcement::Fail {
+                result.validation = validation_result::ValidationResult::invalid();
+            }
+        }
+    }
+
     /// Checks the size of the PR based on file changes.
     ///
     /// This is a wrapper around the `checks::check_pr_size` function that returns
@@ -283,9 +903,8 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
 
     /// Checks whether a pull request is marked as WIP (Work In Progress).
     ///
-    /// Inspects the PR title and body against the configured WIP patterns.
-    /// Returns `true` if any pattern matches. This check ignores bypass rules —
-    /// WIP status can never be bypassed.
+    /// This is a wrapper around the `checks::check_wip_status` function using the
+    /// configured WIP patterns. WIP status can never be bypassed.
     ///
     /// # Arguments
     ///
@@ -295,55 +914,135 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
     ///
     /// `true` if the PR is detected as WIP, `false` otherwise
     fn check_wip_status(&self, pr: &PullRequest) -> bool {
-        for pattern in &self.config.wip_check.wip_title_patterns {
-            if pr.title.contains(pattern.as_str()) {
+        checks::check_wip_status(pr, &self.config.wip_check)
+    }
+
+    /// Returns whether `label` is applied to the pull request, ignoring case.
+    ///
+    /// Returns `None` when the applied labels cannot be listed. The failure is logged,
+    /// and callers then leave the label alone for this run rather than act on an
+    /// incomplete view of the PR's labels; the check status is still reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr_number` - The pull request to look at
+    /// * `label` - The label to look for
+    async fn is_label_applied(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        label: &str,
+    ) -> Option<bool> {
+        match self
+            .provider()
+            .list_applied_labels(repo_owner, repo_name, pr_number)
+            .await
+        {
+            Ok(labels) => {
                 debug!(
-                    pull_request = pr.number,
-                    pattern = pattern.as_str(),
-                    "WIP pattern matched in PR title"
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    count = labels.len(),
+                    "Searched for existing labels",
                 );
-                return true;
+                Some(labels.iter().any(|l| l.name.eq_ignore_ascii_case(label)))
             }
-        }
-
-        if let Some(body) = &pr.body {
-            for pattern in &self.config.wip_check.wip_description_patterns {
-                if body.contains(pattern.as_str()) {
-                    debug!(
-                        pull_request = pr.number,
-                        pattern = pattern.as_str(),
-                        "WIP pattern matched in PR description"
-                    );
-                    return true;
-                }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    label = label,
+                    error = %e,
+                    "Failed to list the labels applied to the pull request; skipping label reconciliation for this run"
+                );
+                None
             }
         }
-
-        false
     }
 
-    /// Manages state-lifecycle labels for a pull request.
-    ///
-    /// Determines the current PR state (`draft`, `in-review`, or `approved`) and
-    /// calls [`labels::manage_pr_state_labels`] to apply the matching label while
-    /// removing the other two.
+    /// Returns the labels in `labels` that may not be applied because they do not
+    /// exist in the repository and `label_creation.create_missing_labels` is `false`.
     ///
-    /// Does nothing when `config.pr_state_labels.enabled` is `false`.
+    /// Returns nothing when labels may be created. When the repository's labels
+    /// cannot be listed, all of `labels` are returned, so that none is created by
+    /// accident. Each skipped label is logged.
     ///
     /// # Arguments
     ///
     /// * `repo_owner` - The owner of the repository
     /// * `repo_name` - The name of the repository
-    /// * `pr` - The pull request being processed
-    #[instrument]
-    async fn communicate_pr_state_labels(
+    /// * `pr_number` - The pull request the labels would be applied to
+    /// * `labels` - The labels to apply
+    async fn find_missing_labels(
         &self,
         repo_owner: &str,
         repo_name: &str,
-        pr: &PullRequest,
-    ) {
-        if !self.config.pr_state_labels.enabled {
-            return;
+        pr_number: u64,
+        labels: &[String],
+    ) -> Vec<String> {
+        if self.config.label_creation.create_missing_labels {
+            return Vec::new();
+        }
+
+        let missing = match labels::find_missing_labels(
+            &self.provider(),
+            repo_owner,
+            repo_name,
+            labels,
+        )
+        .await
+        {
+            Ok(missing) => missing,
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = %e,
+                    "Failed to list repository labels; not applying labels that may not exist"
+                );
+                labels.to_vec()
+            }
+        };
+        for label in &missing {
+            info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                label = %label,
+                "Skipped label that does not exist in the repository; label creation is disabled"
+            );
+        }
+        missing
+    }
+
+    /// Manages state-lifecycle labels for a pull request.
+    ///
+    /// Determines the current PR state (`draft`, `in-review`, or `approved`) and
+    /// calls [`labels::manage_pr_state_labels`] to apply the matching label while
+    /// removing the other two.
+    ///
+    /// Does nothing when `config.pr_state_labels.enabled` is `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr` - The pull request being processed
+    #[instrument]
+    async fn communicate_pr_state_labels(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+    ) {
+        if !self.config.pr_state_labels.enabled {
+            return;
         }
 
         // Determine approval status from reviews.
@@ -353,7 +1052,7 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
             false
         } else {
             let reviews = self
-                .provider
+                .provider()
                 .list_pr_reviews(repo_owner, repo_name, pr.number)
                 .await
                 .unwrap_or_default();
@@ -369,14 +1068,39 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
             latest_by_user.values().any(|r| r.state == "approved")
         };
 
+        // Leave the target label unset when it does not exist and may not be created;
+        // the other state labels are still removed.
+        let mut state_labels = self.config.pr_state_labels.clone();
+        let target_label = if pr.draft {
+            &mut state_labels.draft_label
+        } else if is_approved {
+            &mut state_labels.approved_label
+        } else {
+            &mut state_labels.review_label
+        };
+        if let Some(target) = target_label.as_ref().filter(|t| !t.is_empty()) {
+            if !self
+                .find_missing_labels(
+                    repo_owner,
+                    repo_name,
+                    pr.number,
+                    std::slice::from_ref(target),
+                )
+                .await
+                .is_empty()
+            {
+                *target_label = None;
+            }
+        }
+
         if let Err(e) = labels::manage_pr_state_labels(
-            &self.provider,
+            &self.provider(),
             repo_owner,
             repo_name,
             pr.number,
             pr.draft,
             is_approved,
-            &self.config.pr_state_labels,
+            &state_labels,
         )
         .await
         {
@@ -408,8 +1132,24 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
         repo_name: &str,
         pr: &PullRequest,
     ) {
+        // The label cannot be on the PR when the repository does not define it, so
+        // there is nothing to remove either.
+        if self.config.renovate_stability.enabled
+            && !self
+                .find_missing_labels(
+                    repo_owner,
+                    repo_name,
+                    pr.number,
+                    std::slice::from_ref(&self.config.renovate_stability.pending_stability_label),
+                )
+                .await
+                .is_empty()
+        {
+            return;
+        }
+
         if let Err(e) = labels::manage_renovate_stability_label(
-            &self.provider,
+            &self.provider(),
             repo_owner,
             repo_name,
             pr.number,
@@ -428,6 +1168,189 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
         }
     }
 
+    /// Applies the dependencies label when the pull request updates dependencies.
+    ///
+    /// Failures are logged at `warn` level and do not propagate — labelling is a
+    /// best-effort side-effect that must not block the overall PR check.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr` - The pull request being processed
+    /// * `pr_files` - The files changed in the pull request
+    ///
+    /// # Returns
+    ///
+    /// The applied label, or `None` when the PR is not a dependency update or
+    /// labelling failed
+    #[instrument]
+    async fn communicate_dependency_update_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+        pr_files: &[merge_warden_developer_platforms::models::PullRequestFile],
+    ) -> Option<String> {
+        let config = &self.config.dependency_updates;
+        if config.enabled
+            && labels::is_dependency_update(pr, pr_files, config)
+            && !self
+                .find_missing_labels(
+                    repo_owner,
+                    repo_name,
+                    pr.number,
+                    std::slice::from_ref(&config.label),
+                )
+                .await
+                .is_empty()
+        {
+            return None;
+        }
+
+        match labels::manage_dependency_label(
+            &self.provider(),
+            repo_owner,
+            repo_name,
+            pr,
+            pr_files,
+            &self.config.dependency_updates,
+        )
+        .await
+        {
+            Ok(true) => Some(self.config.dependency_updates.label.clone()),
+            Ok(false) => None,
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    error = %e,
+                    "Failed to manage dependencies label"
+                );
+                None
+            }
+        }
+    }
+
+    /// Applies the needs-rebase label while the PR is behind its base branch, and
+    /// removes it once the PR is up to date.
+    ///
+    /// The label is left unchanged when the PR could not be compared with its base.
+    /// Failures are logged at `warn` level and do not propagate — labelling is a
+    /// best-effort side-effect that must not block the overall PR check.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr_number` - The pull request being processed
+    /// * `result` - The result of the up-to-date check
+    ///
+    /// # Returns
+    ///
+    /// The applied label, or `None` when the PR is up to date or labelling failed
+    #[instrument]
+    async fn communicate_up_to_date_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        result: &checks::UpToDateValidationResult,
+    ) -> Option<String> {
+        let label = &self.config.up_to_date.label;
+        let is_behind = !result.is_valid();
+        if is_behind
+            && !self
+                .find_missing_labels(
+                    repo_owner,
+                    repo_name,
+                    pr_number,
+                    std::slice::from_ref(label),
+                )
+                .await
+                .is_empty()
+        {
+            return None;
+        }
+
+        match labels::manage_needs_rebase_label(
+            &self.provider(),
+            repo_owner,
+            repo_name,
+            pr_number,
+            is_behind,
+            label,
+        )
+        .await
+        {
+            Ok(true) => Some(label.clone()),
+            Ok(false) => None,
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = %e,
+                    "Failed to manage needs-rebase label"
+                );
+                None
+            }
+        }
+    }
+
+    /// Applies the review effort label for the PR's score, unless it does not exist in
+    /// the repository and labels may not be created.
+    ///
+    /// Failures are logged and never affect the check result.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr_number` - The pull request being processed
+    /// * `label` - The effort label for the PR's score
+    async fn communicate_review_effort_label(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        label: &str,
+    ) {
+        let label = label.to_string();
+        if !self
+            .find_missing_labels(
+                repo_owner,
+                repo_name,
+                pr_number,
+                std::slice::from_ref(&label),
+            )
+            .await
+            .is_empty()
+        {
+            return;
+        }
+
+        if let Err(e) = labels::manage_review_effort_label(
+            &self.provider(),
+            repo_owner,
+            repo_name,
+            pr_number,
+            &label,
+            &self.config.pr_size_check.review_effort.label_prefix,
+        )
+        .await
+        {
+            warn!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                error = %e,
+                "Failed to manage review effort label"
+            );
+        }
+    }
+
     /// Handles side effects for WIP status changes on a pull request.
     ///
     /// When `is_wip` is `true`:
@@ -464,16 +1387,35 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
             "Updating pull request WIP status",
         );
 
-        // Manage WIP label
-        let label_result = labels::manage_wip_labels(
-            &self.provider,
-            repo_owner,
-            repo_name,
-            pr.number,
-            is_wip,
-            &self.config.wip_check.wip_label,
-        )
-        .await;
+        // Manage WIP label. When the repository has no WIP label, the configured one
+        // would be created, so it is skipped if label creation is disabled.
+        let wip_label = &self.config.wip_check.wip_label;
+        let label_result = if is_wip
+            && !self.config.label_creation.create_missing_labels
+            && wip_label.is_some()
+            && matches!(
+                labels::discover_wip_labels(&self.provider(), repo_owner, repo_name, wip_label)
+                    .await,
+                Ok(None)
+            ) {
+            info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr.number,
+                "Skipped WIP label that does not exist in the repository; label creation is disabled"
+            );
+            Ok(())
+        } else {
+            labels::manage_wip_labels(
+                &self.provider(),
+                repo_owner,
+                repo_name,
+                pr.number,
+                is_wip,
+                wip_label,
+            )
+            .await
+        };
 
         if let Err(e) = label_result {
             warn!(
@@ -488,31 +1430,19 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
         if is_wip {
             // Check if WIP comment already exists
             let comments = self
-                .provider
-                .list_comments(repo_owner, repo_name, pr.number)
+                .list_guidance_comments(repo_owner, repo_name, pr.number)
                 .await
                 .unwrap_or_default();
 
             let has_comment = comments.iter().any(|c| c.body.contains(WIP_COMMENT_MARKER));
 
             if !has_comment {
-                let mut pattern_lines = String::new();
-                for pattern in &self.config.wip_check.wip_title_patterns {
-                    pattern_lines.push_str(&format!("\n- `{pattern}` in the title"));
-                }
-                for pattern in &self.config.wip_check.wip_description_patterns {
-                    pattern_lines.push_str(&format!("\n- `{pattern}` in the description"));
-                }
-
-                let comment_text = format!(
-                    "\nThis pull request is marked as **Work In Progress (WIP)** and cannot be merged until the WIP status is removed.\n\nTo remove WIP status, update the PR title and/or description to remove the WIP markers.\n\nThe following patterns are configured as WIP markers:{pattern_lines}\n\nOnce all WIP markers are removed, the checks will automatically re-evaluate."
-                );
+                let comment_text = explain::wip_guidance(&self.config.wip_check);
 
                 let comment = format!("{}{}", WIP_COMMENT_MARKER, comment_text);
 
                 let result = self
-                    .provider
-                    .add_comment(repo_owner, repo_name, pr.number, &comment)
+                    .add_guidance_comment(repo_owner, repo_name, pr.number, &comment)
                     .await;
 
                 match result {
@@ -536,16 +1466,14 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
         } else {
             // Remove ALL WIP comments (no break — clean up any duplicates too).
             let comments = self
-                .provider
-                .list_comments(repo_owner, repo_name, pr.number)
+                .list_guidance_comments(repo_owner, repo_name, pr.number)
                 .await
                 .unwrap_or_default();
 
             for comment in comments {
                 if comment.body.contains(WIP_COMMENT_MARKER) {
                     let result = self
-                        .provider
-                        .delete_comment(repo_owner, repo_name, comment.id)
+                        .delete_guidance_comment(repo_owner, repo_name, comment.id)
                         .await;
 
                     match result {
@@ -633,27 +1561,26 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
         let was_bypassed = validation_result.was_bypassed();
 
         if !is_valid {
-            // Check if PR already has the invalid title label
-            let labels = (self
-                .provider
-                .list_applied_labels(repo_owner, repo_name, pr.number)
-                .await)
-                .unwrap_or_default();
-            debug!(
-                repository_owner = repo_owner,
-                repository = repo_name,
-                pull_request = pr.number,
-                count = labels.len(),
-                "Searched for existing labels",
-            );
-
+            // Add the invalid title label unless the PR already has it
             if let Some(title_label) = &self.config.invalid_title_label {
-                let has_invalid_title_label = labels.iter().any(|label| &label.name == title_label);
+                let has_invalid_title_label = self
+                    .is_label_applied(repo_owner, repo_name, pr.number, title_label)
+                    .await;
 
-                if !has_invalid_title_label {
+                if has_invalid_title_label == Some(false)
+                    && self
+                        .find_missing_labels(
+                            repo_owner,
+                            repo_name,
+                            pr.number,
+                            std::slice::from_ref(title_label),
+                        )
+                        .await
+                        .is_empty()
+                {
                     // Add invalid title label
                     let result = self
-                        .provider
+                        .provider()
                         .add_labels(
                             repo_owner,
                             repo_name,
@@ -681,8 +1608,7 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
             }
 
             let comments = (self
-                .provider
-                .list_comments(repo_owner, repo_name, pr.number)
+                .list_guidance_comments(repo_owner, repo_name, pr.number)
                 .await)
                 .unwrap_or_default();
 
@@ -696,33 +1622,33 @@ impl<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug> MergeWarden<P> {
                 .map(|c| (c.id, c.body.clone()))
                 .collect();
 
-            // Add comment with diagnosis and format reminder
-            let format_reminder = "\
-Your PR title does not follow the [Conventional Commits](https://www.conventionalcommits.org/) message format.\n\
-- Supported types: feat, fix, docs, style, refactor, perf, test, build, ci, chore, revert\n\
-- Expected format: `<type>(<optional scope>): <description>`\n\
-- Examples:\n\
-    * feat(auth): add login functionality\n\
-    * fix: resolve null pointer exception\n\
-- For full details, see: https://www.conventionalcommits.org/\n\
-\n\
-Please update the PR title to match the conventional commit message guidelines.";
-
-            let comment_text = {
-                // Build the diagnosis section: one bullet per TitleIssue, optional suggested fix.
-                let mut diagnosis_section =
-                    String::from("\nThe pull request title needs correction:\n");
-                if let Some(diagnosis) = validation_result.diagnosis.as_ref() {
-                    for issue in &diagnosis.issues {
-                        diagnosis_section.push_str(&format!("- {issue}\n"));
-                    }
-                    if let Some(fix) = &diagnosis.suggested_fix {
-                        diagnosis_section.push_str(&format!("\nSuggested fix: `{fix}`"));
-                    }
-                }
-                // Compose final comment: diagnosis section + separator + general format reminder.
-                format!("{diagnosis_section}\n\n{format_reminder}")
-            };
+            // Render the diagnosis and format reminder from the invalid-title template.
+            // The author's contributor-specific template takes precedence, followed by
+            // the configured comment text.
+            let guidance_renderer = self
+                .contributor_guidance_renderer(
+                    repo_owner,
+                    repo_name,
+                    pr.number,
+                    CommentTemplate::InvalidTitle,
+                )
+                .await
+                .or_else(|| {
+                    explain::configured_comment_renderer(
+                        &self.config,
+                        CommentTemplate::InvalidTitle,
+                    )
+                });
+            let comment_text = explain::invalid_title_guidance(
+                guidance_renderer
+                    .as_ref()
+                    .map_or(self.comment_renderer.as_ref(), |r| {
+                        r as &dyn CommentRenderer
+                    }),
+                &self.config,
+                &pr.title,
+                validation_result,
+            );
 
             let comment = format!(
                 "{prefix}{text}",
@@ -745,8 +1671,7 @@ Please update the PR title to match the conventional commit message guidelines."
                 let mut all_deleted = true;
                 for (existing_id, _) in &existing_title_comments {
                     if self
-                        .provider
-                        .delete_comment(repo_owner, repo_name, *existing_id)
+                        .delete_guidance_comment(repo_owner, repo_name, *existing_id)
                         .await
                         .is_err()
                     {
@@ -763,8 +1688,7 @@ Please update the PR title to match the conventional commit message guidelines."
 
                 if all_deleted {
                     let result = self
-                        .provider
-                        .add_comment(repo_owner, repo_name, pr.number, &comment)
+                        .add_guidance_comment(repo_owner, repo_name, pr.number, &comment)
                         .await;
 
                     match result {
@@ -790,27 +1714,20 @@ Please update the PR title to match the conventional commit message guidelines."
             // Title validation passed (either valid or bypassed)
 
             // Check if PR has the invalid title label to remove it
-            let labels = (self
-                .provider
-                .list_applied_labels(repo_owner, repo_name, pr.number)
-                .await)
-                .unwrap_or_default();
-
-            debug!(
-                repository_owner = repo_owner,
-                repository = repo_name,
-                pull_request = pr.number,
-                count = labels.len(),
-                "Searched for existing labels",
-            );
-
-            if let Some(title_label) = &self.config.invalid_title_label {
-                let has_invalid_title_label = labels.iter().any(|label| &label.name == title_label);
+            if let Some(title_label) = self
+                .config
+                .invalid_title_label
+                .as_ref()
+                .filter(|_| self.config.status_labels.remove_on_pass)
+            {
+                let has_invalid_title_label = self
+                    .is_label_applied(repo_owner, repo_name, pr.number, title_label)
+                    .await;
 
-                if has_invalid_title_label {
+                if has_invalid_title_label == Some(true) {
                     // Remove the invalid title label
                     let result = self
-                        .provider
+                        .provider()
                         .remove_label(repo_owner, repo_name, pr.number, title_label)
                         .await;
 
@@ -836,16 +1753,14 @@ Please update the PR title to match the conventional commit message guidelines."
             // Iterating the full list (no break) ensures duplicates accumulated from
             // prior bug runs are fully cleaned up, not just the first copy.
             let comments = (self
-                .provider
-                .list_comments(repo_owner, repo_name, pr.number)
+                .list_guidance_comments(repo_owner, repo_name, pr.number)
                 .await)
                 .unwrap_or_default();
 
             for comment in comments {
                 if comment.body.contains(TITLE_COMMENT_MARKER) {
                     let result = self
-                        .provider
-                        .delete_comment(repo_owner, repo_name, comment.id)
+                        .delete_guidance_comment(repo_owner, repo_name, comment.id)
                         .await;
 
                     match result {
@@ -869,23 +1784,23 @@ Please update the PR title to match the conventional commit message guidelines."
             // If validation was bypassed, add a bypass notification comment
             if was_bypassed {
                 if let Some(bypass_info) = validation_result.bypass_info() {
-                    let bypass_comment_text = formatdoc!(
-                        r#"
-                        ⚠️ **Title Validation Bypassed**
-
-                        The PR title validation was bypassed for user `{user}`.
-                        - Original title: `{title}`
-                        - Bypass rule: {rule_type}
-                        - Bypassed by: {user}
-
-                        **Note**: This PR may not follow conventional commit format but was allowed due to bypass permissions.
-
-                        ---
-                        For more information about conventional commits, see: https://www.conventionalcommits.org/
-                        "#,
-                        user = bypass_info.user,
-                        title = pr.title,
-                        rule_type = bypass_info.rule_type
+                    let context = BypassNoticeContext {
+                        user: bypass_info.user.clone(),
+                        rule_type: bypass_info.rule_type.to_string(),
+                        title: pr.title.clone(),
+                    };
+                    let configured = explain::configured_comment_renderer(
+                        &self.config,
+                        CommentTemplate::TitleBypassNotice,
+                    );
+                    let bypass_comment_text = render_comment(
+                        configured
+                            .as_ref()
+                            .map_or(self.comment_renderer.as_ref(), |r| {
+                                r as &dyn CommentRenderer
+                            }),
+                        CommentTemplate::TitleBypassNotice,
+                        &context,
                     );
 
                     let comment = format!(
@@ -895,8 +1810,7 @@ Please update the PR title to match the conventional commit message guidelines."
                     );
 
                     let result = self
-                        .provider
-                        .add_comment(repo_owner, repo_name, pr.number, &comment)
+                        .add_guidance_comment(repo_owner, repo_name, pr.number, &comment)
                         .await;
 
                     match result {
@@ -947,7 +1861,7 @@ Please update the PR title to match the conventional commit message guidelines."
         repo_owner: &str,
         repo_name: &str,
         pr: &PullRequest,
-        validation_result: &validation_result::ValidationResult,
+        validation_result: &checks::WorkItemValidationResult,
     ) -> String {
         info!(
             repository_owner = repo_owner,
@@ -955,6 +1869,8 @@ Please update the PR title to match the conventional commit message guidelines."
             pull_request = pr.number,
             is_valid = validation_result.is_valid(),
             bypass_used = validation_result.was_bypassed(),
+            matched_pattern = validation_result.matched_pattern.as_deref(),
+            references = ?validation_result.references,
             "Updating the pull request to indicate work item validation status",
         );
 
@@ -981,28 +1897,26 @@ Please update the PR title to match the conventional commit message guidelines."
         let was_bypassed = validation_result.was_bypassed();
 
         if !is_valid {
-            // Check if PR already has the missing work item label
-            let labels = (self
-                .provider
-                .list_applied_labels(repo_owner, repo_name, pr.number)
-                .await)
-                .unwrap_or_default();
-            debug!(
-                repository_owner = repo_owner,
-                repository = repo_name,
-                pull_request = pr.number,
-                count = labels.len(),
-                "Searched for existing labels",
-            );
-
+            // Add the missing work item label unless the PR already has it
             if let Some(work_item_label) = &self.config.missing_work_item_label {
-                let has_missing_work_item_label =
-                    labels.iter().any(|label| &label.name == work_item_label);
+                let has_missing_work_item_label = self
+                    .is_label_applied(repo_owner, repo_name, pr.number, work_item_label)
+                    .await;
 
-                if !has_missing_work_item_label {
+                if has_missing_work_item_label == Some(false)
+                    && self
+                        .find_missing_labels(
+                            repo_owner,
+                            repo_name,
+                            pr.number,
+                            std::slice::from_ref(work_item_label),
+                        )
+                        .await
+                        .is_empty()
+                {
                     // Add missing work item label
                     let result = self
-                        .provider
+                        .provider()
                         .add_labels(
                             repo_owner,
                             repo_name,
@@ -1029,8 +1943,7 @@ Please update the PR title to match the conventional commit message guidelines."
             }
 
             let comments = (self
-                .provider
-                .list_comments(repo_owner, repo_name, pr.number)
+                .list_guidance_comments(repo_owner, repo_name, pr.number)
                 .await)
                 .unwrap_or_default();
 
@@ -1042,21 +1955,53 @@ Please update the PR title to match the conventional commit message guidelines."
                 .map(|c| (c.id, c.body.clone()))
                 .collect();
 
-            let comment_text = r#"
-The pull request body needs improvement:
-
-The PR body is missing a valid work item reference.
-- Supported formats:
-    * Prefixes: fixes, closes, resolves, references, relates to
-    * Work Item Identifiers: #XXX or GH-XXX
-- Examples:
-    * fixes #1234
-    * closes GH-5678
-    * resolves #9012
-    * references GH-3456
-    * relates to #7890
-
-Please update the PR body to include a valid work item reference."#;
+            let guidance_renderer = self
+                .contributor_guidance_renderer(
+                    repo_owner,
+                    repo_name,
+                    pr.number,
+                    CommentTemplate::MissingWorkItem,
+                )
+                .await
+                .or_else(|| {
+                    explain::configured_comment_renderer(
+                        &self.config,
+                        CommentTemplate::MissingWorkItem,
+                    )
+                });
+            let comment_text = if validation_result.dangling_references.is_empty() {
+                let guidance = explain::missing_work_item_guidance(
+                    guidance_renderer
+                        .as_ref()
+                        .map_or(self.comment_renderer.as_ref(), |r| {
+                            r as &dyn CommentRenderer
+                        }),
+                    &self.config,
+                );
+                let guidance = if self.config.min_work_item_references > 1 {
+                    format!(
+                        "{guidance}{count}",
+                        count = explain::work_item_count_guidance(
+                            &validation_result.references,
+                            self.config.min_work_item_references,
+                        ),
+                    )
+                } else {
+                    guidance
+                };
+                match self
+                    .pr_template_work_item_lines(repo_owner, repo_name, pr.number)
+                    .await
+                {
+                    Some((template_path, lines)) => format!(
+                        "{guidance}{template}",
+                        template = explain::pr_template_work_item_guidance(template_path, &lines),
+                    ),
+                    None => guidance,
+                }
+            } else {
+                explain::dangling_issue_reference_guidance(&validation_result.dangling_references)
+            };
             let comment = format!(
                 "{prefix}{text}",
                 prefix = WORK_ITEM_COMMENT_MARKER,
@@ -1071,8 +2016,7 @@ Please update the PR body to include a valid work item reference."#;
                 let mut all_deleted = true;
                 for (existing_id, _) in &existing_work_item_comments {
                     if self
-                        .provider
-                        .delete_comment(repo_owner, repo_name, *existing_id)
+                        .delete_guidance_comment(repo_owner, repo_name, *existing_id)
                         .await
                         .is_err()
                     {
@@ -1089,8 +2033,7 @@ Please update the PR body to include a valid work item reference."#;
 
                 if all_deleted {
                     let result = self
-                        .provider
-                        .add_comment(repo_owner, repo_name, pr.number, &comment)
+                        .add_guidance_comment(repo_owner, repo_name, pr.number, &comment)
                         .await;
 
                     match result {
@@ -1111,33 +2054,25 @@ Please update the PR body to include a valid work item reference."#;
                 }
             }
 
-            comment_text.to_string()
+            comment_text
         } else {
             // Work item validation passed (either valid or bypassed)
 
             // Check if PR has the missing work item label to remove it
-            if let Some(work_item_label) = &self.config.missing_work_item_label {
-                let labels = (self
-                    .provider
-                    .list_applied_labels(repo_owner, repo_name, pr.number)
-                    .await)
-                    .unwrap_or_default();
-
-                debug!(
-                    repository_owner = repo_owner,
-                    repository = repo_name,
-                    pull_request = pr.number,
-                    count = labels.len(),
-                    "Searched for existing labels",
-                );
-
-                let has_missing_work_item_label =
-                    labels.iter().any(|label| &label.name == work_item_label);
+            if let Some(work_item_label) = self
+                .config
+                .missing_work_item_label
+                .as_ref()
+                .filter(|_| self.config.status_labels.remove_on_pass)
+            {
+                let has_missing_work_item_label = self
+                    .is_label_applied(repo_owner, repo_name, pr.number, work_item_label)
+                    .await;
 
-                if has_missing_work_item_label {
+                if has_missing_work_item_label == Some(true) {
                     // Remove the missing work item label
                     let result = self
-                        .provider
+                        .provider()
                         .remove_label(repo_owner, repo_name, pr.number, work_item_label)
                         .await;
 
@@ -1161,16 +2096,14 @@ Please update the PR body to include a valid work item reference."#;
 
             // Find and remove ALL existing work item comments (no break).
             let comments = (self
-                .provider
-                .list_comments(repo_owner, repo_name, pr.number)
+                .list_guidance_comments(repo_owner, repo_name, pr.number)
                 .await)
                 .unwrap_or_default();
 
             for comment in comments {
                 if comment.body.contains(WORK_ITEM_COMMENT_MARKER) {
                     let result = self
-                        .provider
-                        .delete_comment(repo_owner, repo_name, comment.id)
+                        .delete_guidance_comment(repo_owner, repo_name, comment.id)
                         .await;
 
                     match result {
@@ -1194,19 +2127,23 @@ Please update the PR body to include a valid work item reference."#;
             // If validation was bypassed, add a bypass notification comment
             if was_bypassed {
                 if let Some(bypass_info) = validation_result.bypass_info() {
-                    let bypass_comment_text = formatdoc!(
-                        r#"
-                        ⚠️ **Work Item Validation Bypassed**
-
-                        The work item reference validation was bypassed for user `{user}`.
-                        - PR description may not contain required work item references
-                        - Bypass rule: {rule_type}
-                        - Bypassed by: {user}
-
-                        **Note**: This PR was allowed to proceed without work item references due to bypass permissions.
-                        "#,
-                        user = bypass_info.user,
-                        rule_type = bypass_info.rule_type
+                    let context = BypassNoticeContext {
+                        user: bypass_info.user.clone(),
+                        rule_type: bypass_info.rule_type.to_string(),
+                        title: pr.title.clone(),
+                    };
+                    let configured = explain::configured_comment_renderer(
+                        &self.config,
+                        CommentTemplate::WorkItemBypassNotice,
+                    );
+                    let bypass_comment_text = render_comment(
+                        configured
+                            .as_ref()
+                            .map_or(self.comment_renderer.as_ref(), |r| {
+                                r as &dyn CommentRenderer
+                            }),
+                        CommentTemplate::WorkItemBypassNotice,
+                        &context,
                     );
 
                     let comment = format!(
@@ -1216,8 +2153,7 @@ Please update the PR body to include a valid work item reference."#;
                     );
 
                     let result = self
-                        .provider
-                        .add_comment(repo_owner, repo_name, pr.number, &comment)
+                        .add_guidance_comment(repo_owner, repo_name, pr.number, &comment)
                         .await;
 
                     match result {
@@ -1250,7 +2186,7 @@ Please update the PR body to include a valid work item reference."#;
     /// This method:
     /// - Calculates PR size based on file changes
     /// - Applies appropriate size labels
-    /// - Adds educational comments for oversized PRs if configured
+    /// - Adds educational comments for PRs in the configured size categories
     /// - Returns a status message for inclusion in the check status
     ///
     /// # Arguments
@@ -1272,21 +2208,18 @@ Please update the PR body to include a valid work item reference."#;
         pr_files: &[merge_warden_developer_platforms::models::PullRequestFile],
     ) -> String {
         // Calculate size info
-        let size_info = crate::size::PrSizeInfo::from_files_with_exclusions(
-            pr_files,
-            &self.config.pr_size_check.get_effective_thresholds(),
-            &self.config.pr_size_check.excluded_file_patterns,
-            self.config.pr_size_check.ignore_deletions,
-        );
+        let size_info = crate::size::PrSizeInfo::categorize(pr_files, &self.config.pr_size_check);
 
         // Apply size label
         let label_result = labels::manage_size_labels(
-            &self.provider,
+            &self.provider(),
             repo_owner,
             repo_name,
             pr_number,
             &size_info,
             &self.config.pr_size_check.label_prefix,
+            &self.config.pr_size_check.category_names,
+            self.config.label_creation.create_missing_labels,
         )
         .await;
 
@@ -1312,13 +2245,24 @@ Please update the PR body to include a valid work item reference."#;
             _ => {}
         }
 
-        // Add comment for oversized PRs if configured, with deduplication to avoid
-        // repeating the same comment on each PR update.
+        let review_effort = &self.config.pr_size_check.review_effort;
+        if review_effort.enabled && review_effort.add_label {
+            let effort = size_info.review_effort(&review_effort.weights);
+            self.communicate_review_effort_label(
+                repo_owner,
+                repo_name,
+                pr_number,
+                &review_effort.label_for(effort.score),
+            )
+            .await;
+        }
+
+        // Add comment for PRs in the configured size categories, with deduplication to
+        // avoid repeating the same comment on each PR update.
         if self.config.pr_size_check.add_comment {
             let existing_size_comments: Vec<(u64, String)> = {
                 let comments = self
-                    .provider
-                    .list_comments(repo_owner, repo_name, pr_number)
+                    .list_guidance_comments(repo_owner, repo_name, pr_number)
                     .await
                     .unwrap_or_default();
                 comments
@@ -1328,8 +2272,38 @@ Please update the PR body to include a valid work item reference."#;
                     .collect()
             };
 
-            if size_info.is_oversized() {
-                let comment_body = labels::generate_oversized_pr_comment(&size_info);
+            if self
+                .config
+                .pr_size_check
+                .should_comment_on(size_info.size_category)
+            {
+                let mut comment_body = labels::generate_oversized_pr_comment_with_renderer(
+                    &size_info,
+                    &self.config.pr_size_check.category_names,
+                    self.config.pr_size_check.comment_breakdown_rows,
+                    self.comment_renderer.as_ref(),
+                );
+                if let Some(breakdown) = Self::format_size_breakdown(&size_info)
+                    .filter(|_| self.config.pr_size_check.details_in_comment)
+                {
+                    // Leave the breakdown out rather than have GitHub reject the comment.
+                    let length = SIZE_COMMENT_MARKER.chars().count()
+                        + comment_body.chars().count()
+                        + breakdown.chars().count()
+                        + 2;
+                    if length <= COMMENT_MAX_CHARS {
+                        comment_body.push_str("\n\n");
+                        comment_body.push_str(&breakdown);
+                    } else {
+                        warn!(
+                            repository_owner = repo_owner,
+                            repository = repo_name,
+                            pull_request = pr_number,
+                            length,
+                            "Size breakdown would make the size comment too long; leaving it out"
+                        );
+                    }
+                }
                 let comment = format!("{}{}", SIZE_COMMENT_MARKER, comment_body);
 
                 let already_up_to_date =
@@ -1340,8 +2314,7 @@ Please update the PR body to include a valid work item reference."#;
                     let mut all_deleted = true;
                     for (existing_id, _) in &existing_size_comments {
                         if self
-                            .provider
-                            .delete_comment(repo_owner, repo_name, *existing_id)
+                            .delete_guidance_comment(repo_owner, repo_name, *existing_id)
                             .await
                             .is_err()
                         {
@@ -1358,8 +2331,7 @@ Please update the PR body to include a valid work item reference."#;
 
                     if all_deleted {
                         match self
-                            .provider
-                            .add_comment(repo_owner, repo_name, pr_number, &comment)
+                            .add_guidance_comment(repo_owner, repo_name, pr_number, &comment)
                             .await
                         {
                             Ok(_) => {
@@ -1367,7 +2339,8 @@ Please update the PR body to include a valid work item reference."#;
                                     repository_owner = repo_owner,
                                     repository = repo_name,
                                     pull_request = pr_number,
-                                    "Added oversized PR comment"
+                                    size_category = size_info.size_category.as_str(),
+                                    "Added PR size comment"
                                 );
                             }
                             Err(e) => {
@@ -1376,18 +2349,18 @@ Please update the PR body to include a valid work item reference."#;
                                     repository = repo_name,
                                     pull_request = pr_number,
                                     error = e.to_string(),
-                                    "Failed to add oversized PR comment"
+                                    "Failed to add PR size comment"
                                 );
                             }
                         }
                     }
                 }
             } else {
-                // PR is no longer oversized — remove any existing size comments.
+                // PR is no longer in a commented size category — remove any existing
+                // size comments.
                 for (existing_id, _) in &existing_size_comments {
                     if let Err(e) = self
-                        .provider
-                        .delete_comment(repo_owner, repo_name, *existing_id)
+                        .delete_guidance_comment(repo_owner, repo_name, *existing_id)
                         .await
                     {
                         warn!(
@@ -1396,14 +2369,14 @@ Please update the PR body to include a valid work item reference."#;
                             pull_request = pr_number,
                             comment_id = existing_id,
                             error = e.to_string(),
-                            "Failed to remove stale oversized PR comment"
+                            "Failed to remove stale PR size comment"
                         );
                     } else {
                         info!(
                             repository_owner = repo_owner,
                             repository = repo_name,
                             pull_request = pr_number,
-                            "Removed stale oversized PR comment"
+                            "Removed stale PR size comment"
                         );
                     }
                 }
@@ -1411,9 +2384,22 @@ Please update the PR body to include a valid work item reference."#;
         }
 
         // Return status message
+        if !size_info.has_reviewable_changes() {
+            return match size_info.excluded_file_count() {
+                0 => "PR size: no reviewable changes (the PR has no changed files)".to_string(),
+                n => format!(
+                    "PR size: no reviewable changes (all {} changed file(s) are excluded from size checks)",
+                    n
+                ),
+            };
+        }
+
         format!(
             "PR size: {} ({} lines across {} files)",
-            size_info.size_category.as_str().to_uppercase(),
+            self.config
+                .pr_size_check
+                .category_names
+                .name_for(size_info.size_category),
             size_info.total_lines_changed,
             size_info.included_files.len()
         )
@@ -1439,7 +2425,8 @@ Please update the PR body to include a valid work item reference."#;
     ///
     /// # Returns
     ///
-    /// A `Result` containing a vector of labels that were added to the PR
+    /// A `Result` containing the labels that were added to the PR, and those that
+    /// were skipped because they do not exist and label creation is disabled
     #[instrument(
         fields(
             repository_owner = repo_owner,
@@ -1454,8 +2441,8 @@ Please update the PR body to include a valid work item reference."#;
         repo_owner: &str,
         repo_name: &str,
         pr: &PullRequest,
-    ) -> Result<Vec<String>, MergeWardenError> {
-        let start_time = std::time::Instant::now();
+    ) -> Result<labels::LabelManagementResult, MergeWardenError> {
+        let start_time = self.clock.now();
 
         info!(
             repository_owner = repo_owner,
@@ -1466,26 +2453,28 @@ Please update the PR body to include a valid work item reference."#;
         );
 
         // Attempt smart label detection with graceful error handling
-        let result = labels::set_pull_request_labels_with_config(
-            &self.provider,
+        let result = labels::apply_pull_request_labels(
+            &self.provider(),
             repo_owner,
             repo_name,
             pr,
             Some(&self.config),
+            self.clock.as_ref(),
         )
         .await;
 
-        let elapsed = start_time.elapsed();
+        let elapsed = self.clock.now() - start_time;
 
         match result {
-            Ok(applied_labels) => {
+            Ok(result) => {
+                let applied_labels = &result.applied_labels;
                 info!(
                     repository_owner = repo_owner,
                     repository = repo_name,
                     pr_number = pr.number,
                     applied_labels = ?applied_labels,
                     labels_count = applied_labels.len(),
-                    processing_duration_ms = elapsed.as_millis(),
+                    processing_duration_ms = elapsed.num_milliseconds(),
                     "Successfully determined and applied labels"
                 );
 
@@ -1505,7 +2494,7 @@ Please update the PR body to include a valid work item reference."#;
                     );
                 }
 
-                return Ok(applied_labels);
+                return Ok(result);
             }
             Err(e) => {
                 // Label failures should be logged but not propagate
@@ -1515,12 +2504,15 @@ Please update the PR body to include a valid work item reference."#;
                     repository = repo_name,
                     pr_number = pr.number,
                     error = %e,
-                    processing_duration_ms = elapsed.as_millis(),
+                    processing_duration_ms = elapsed.num_milliseconds(),
                     "Label determination failed, but PR processing will continue"
                 );
 
-                // Return empty labels vector instead of failing
-                return Ok(Vec::new());
+                // Return empty labels instead of failing, recording why
+                return Ok(labels::LabelManagementResult {
+                    error_messages: vec![e.to_string()],
+                    ..labels::LabelManagementResult::new()
+                });
             }
         }
     }
@@ -1693,6 +2685,16 @@ Please update the PR body to include a valid work item reference."#;
             );
         }
 
+        let planned = PlannedOperation::SetMilestone {
+            milestone: Some(issue_milestone.number),
+        };
+        if self
+            .dry_run_recorder
+            .plan(repo_owner, repo_name, pr.number, planned)
+        {
+            return;
+        }
+
         if let Err(e) = issue_provider
             .set_pull_request_milestone(
                 repo_owner,
@@ -1741,6 +2743,17 @@ Please update the PR body to include a valid work item reference."#;
         }
 
         for project in &metadata.projects {
+            let planned = PlannedOperation::AddToProject {
+                project_number: project.number,
+                project_owner: project.owner_login.clone(),
+            };
+            if self
+                .dry_run_recorder
+                .plan(repo_owner, repo_name, pr.number, planned)
+            {
+                continue;
+            }
+
             if let Err(e) = issue_provider
                 .add_pull_request_to_project(
                     repo_owner,
@@ -1809,7 +2822,7 @@ Please update the PR body to include a valid work item reference."#;
     ///     # async fn add_labels(&self, _: &str, _: &str, _: u64, _: &[String]) -> Result<(), Error> { unimplemented!() }
     ///     # async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     ///     # async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<Label>, Error> { unimplemented!() }
-    ///     # async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    ///     # async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     ///     # async fn get_pull_request_files(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::PullRequestFile>, Error> { unimplemented!() }
     ///     # async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     ///     # async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { Ok(vec![]) }
@@ -1834,6 +2847,11 @@ Please update the PR body to include a valid work item reference."#;
             provider,
             config: CurrentPullRequestValidationConfiguration::default(),
             issue_provider: None,
+            comment_renderer: Box::new(TemplateEngineRenderer::new()),
+            clock: Arc::new(SystemClock),
+            state_store: Arc::new(InMemoryStateStore::new()),
+            decision_recorder: DecisionRecorder::default(),
+            dry_run_recorder: DryRunRecorder::default(),
         }
     }
 
@@ -1871,8 +2889,7 @@ Please update the PR body to include a valid work item reference."#;
         // Retrieve all existing config-check comments.
         let existing_comments: Vec<(u64, String)> = {
             let comments = self
-                .provider
-                .list_comments(repo_owner, repo_name, pr.number)
+                .list_guidance_comments(repo_owner, repo_name, pr.number)
                 .await
                 .unwrap_or_default();
             comments
@@ -1886,8 +2903,7 @@ Please update the PR body to include a valid work item reference."#;
             // Configuration is valid — remove all stale warning comments.
             for (comment_id, _) in &existing_comments {
                 if let Err(e) = self
-                    .provider
-                    .delete_comment(repo_owner, repo_name, *comment_id)
+                    .delete_guidance_comment(repo_owner, repo_name, *comment_id)
                     .await
                 {
                     warn!(
@@ -1909,28 +2925,18 @@ Please update the PR body to include a valid work item reference."#;
             }
         } else {
             // Configuration is invalid — build the comment body.
-            let error_lines: String = outcome
-                .errors
-                .iter()
-                .map(|e| format!("- {e}"))
-                .collect::<Vec<_>>()
-                .join("\n");
-
-            let comment_body = formatdoc! {"
-                {marker}
-                ⚠️ **Invalid merge-warden configuration**
-
-                The `.github/merge-warden.toml` file introduced in this pull request contains \
-                errors and will not be used if this PR is merged.
-
-                **Errors found:**
-
-                {error_lines}
-
-                Please fix the configuration before merging.",
-                marker = CONFIG_COMMENT_MARKER,
-                error_lines = error_lines,
+            let context = InvalidConfigCommentContext {
+                errors: outcome.errors.clone(),
             };
+            let comment_body = format!(
+                "{marker}\n{text}",
+                marker = CONFIG_COMMENT_MARKER,
+                text = render_comment(
+                    self.comment_renderer.as_ref(),
+                    CommentTemplate::InvalidConfig,
+                    &context,
+                )
+            );
 
             let already_up_to_date =
                 existing_comments.len() == 1 && existing_comments[0].1 == comment_body;
@@ -1949,8 +2955,7 @@ Please update the PR body to include a valid work item reference."#;
             let mut all_deleted = true;
             for (comment_id, _) in &existing_comments {
                 if self
-                    .provider
-                    .delete_comment(repo_owner, repo_name, *comment_id)
+                    .delete_guidance_comment(repo_owner, repo_name, *comment_id)
                     .await
                     .is_err()
                 {
@@ -1967,8 +2972,7 @@ Please update the PR body to include a valid work item reference."#;
 
             if all_deleted {
                 match self
-                    .provider
-                    .add_comment(repo_owner, repo_name, pr.number, &comment_body)
+                    .add_guidance_comment(repo_owner, repo_name, pr.number, &comment_body)
                     .await
                 {
                     Ok(_) => {
@@ -1993,589 +2997,4665 @@ Please update the PR body to include a valid work item reference."#;
         }
     }
 
-    /// Processes a pull request, validating it against the configured rules.
-    ///
-    /// This method:
-    /// 1. Validates the PR title against the Conventional Commits format (if enabled)
-    /// 2. Checks if the PR description references a work item or issue (if enabled)
-    /// 3. Adds or removes labels and comments based on validation results
-    /// 4. Updates the PR's check run status (GitHub status check)
-    /// 5. Adds automatic labels based on PR content (if enabled)
+    /// Reports the outcome of the title check.
+    fn title_report(&self, result: &checks::TitleValidationResult) -> CheckReport {
+        let kind = config::CheckKind::Title;
+        if let Some(reason) = result.validation.skip_reason() {
+            CheckReport::skipped(kind, reason)
+        } else if let Some(bypass) = result.bypass_info() {
+            CheckReport::bypassed(kind, bypass)
+        } else if result.is_valid() {
+            CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                "Title follows the Conventional Commits format",
+            )
+        } else {
+            let message = match result
+                .diagnosis
+                .as_ref()
+                .and_then(|d| d.suggested_fix.as_ref())
+            {
+                Some(fix) => format!(
+                    "Title does not follow the Conventional Commits format (suggested: `{fix}`)"
+                ),
+                None => "Title does not follow the Conventional Commits format".to_string(),
+            };
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(self.config.check_summary.title_severity),
+                message,
+            )
+            .failed("title is invalid")
+        }
+    }
+
+    /// Reports the outcome of the work item check.
+    fn work_item_report(&self, result: &checks::WorkItemValidationResult) -> CheckReport {
+        let kind = config::CheckKind::WorkItem;
+        let severity = self.config.check_summary.work_item_severity;
+        let dangling_references = result
+            .dangling_references
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        if let Some(reason) = result.validation.skip_reason() {
+            CheckReport::skipped(kind, reason)
+        } else if let Some(bypass) = result.bypass_info() {
+            CheckReport::bypassed(kind, bypass)
+        } else if result.is_valid() {
+            let message = match (
+                &result.matched_pattern,
+                self.config.work_item_reference_pattern.len() > 1,
+            ) {
+                (Some(pattern), true) => {
+                    format!("PR description references a work item (matched `{pattern}`)")
+                }
+                (None, _) if self.config.min_work_item_references == 0 => {
+                    "No work item reference is required".to_string()
+                }
+                _ => "PR description references a work item".to_string(),
+            };
+            if result.dangling_references.is_empty() {
+                CheckReport::new(kind, CheckStatus::Passed, message)
+            } else {
+                CheckReport::new(
+                    kind,
+                    CheckStatus::Failed(config::CheckSeverity::Warning),
+                    format!("{message}, but {dangling_references}"),
+                )
+            }
+        } else if !result.dangling_references.is_empty() {
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(severity),
+                format!(
+                    "PR description references issues that do not resolve: {dangling_references}"
+                ),
+            )
+            .failed("work item reference is missing")
+        } else if self.config.min_work_item_references > 1 && !result.references.is_empty() {
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(severity),
+                format!(
+                    "PR description references {} of the {} required work items",
+                    result.references.len(),
+                    self.config.min_work_item_references
+                ),
+            )
+            .failed("work item reference is missing")
+        } else {
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(severity),
+                "PR description is missing a work item reference",
+            )
+            .failed("work item reference is missing")
+        }
+    }
+
+    /// Reports the outcome of the size check.
     ///
     /// # Arguments
     ///
-    /// * `repo_owner` - The owner of the repository (e.g., "octocat")
-    /// * `repo_name` - The name of the repository (e.g., "hello-world")
-    /// * `pr_number` - The pull request number
-    ///
-    /// # Returns
-    ///
-    /// A `Result` containing a `CheckResult` with the validation results
-    ///
-    /// # Examples
-    ///
-    /// ```rust,no_run
-    /// use async_trait::async_trait;
-    /// use merge_warden_core::MergeWarden;
-    /// use anyhow::Result;
-    /// use merge_warden_developer_platforms::{ConfigFetcher, PullRequestProvider};
-    /// use merge_warden_developer_platforms::errors::Error;
-    /// use merge_warden_developer_platforms::models::{Comment, Label, PullRequest};
-    ///
-    /// #[derive(Debug)]
-    /// struct MyProvider;
-    ///
-    /// #[async_trait]
-    /// impl PullRequestProvider for MyProvider {
-    ///     async fn get_pull_request(
-    ///         &self,
-    ///         repo_owner: &str,
-    ///         repo_name: &str,
-    ///         pr_number: u64,
-    ///     ) -> Result<PullRequest, Error> {
-    ///         // Implementation to fetch PR from GitHub API
-    ///         // ...
-    ///         # unimplemented!()
-    ///     }
-    ///
-    ///     // Implement other required methods...
-    ///     # async fn add_comment(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
-    ///     # async fn delete_comment(&self, _: &str, _: &str, _: u64) -> Result<(), Error> { unimplemented!() }
-    ///     # async fn list_comments(&self, _: &str, _: &str, _: u64) -> Result<Vec<Comment>, Error> { unimplemented!() }
-    ///     # async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<Label>, Error> { unimplemented!() }
-    ///     # async fn add_labels(&self, _: &str, _: &str, _: u64, _: &[String]) -> Result<(), Error> { unimplemented!() }
-    ///     # async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
-    ///     # async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<Label>, Error> { unimplemented!() }
-    ///     # async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
-    ///     # async fn get_pull_request_files(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::PullRequestFile>, Error> { unimplemented!() }
-    ///     # async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
-    ///     # async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { Ok(vec![]) }
-    ///     # async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { Ok(vec![]) }
-    /// }
-    ///
-    /// #[async_trait]
-    /// impl ConfigFetcher for MyProvider {
-    ///     # async fn fetch_config(&self, _: &str, _: &str, _: &str) -> Result<Option<String>, Error> { Ok(None) }
-    ///     # async fn fetch_config_at_ref(&self, _: &str, _: &str, _: &str, _: &str) -> Result<Option<String>, Error> { Ok(None) }
-    /// }
-    ///
-    /// async fn example() -> Result<()> {
-    ///     let provider = MyProvider;
-    ///     let warden = MergeWarden::new(provider);
-    ///
-    ///     let result = warden.process_pull_request("owner", "repo", 123).await?;
-    ///
-    ///     if result.title_valid && result.work_item_referenced {
-    ///         println!("PR is valid and can be merged!");
-    ///     } else {
-    ///         println!("PR has validation issues that need to be fixed");
-    ///     }
-    ///
-    ///     Ok(())
-    /// }
-    ///
-    /// fn main() {}
-    /// ```
-    #[instrument]
-    pub async fn process_pull_request(
+    /// * `result` - The outcome of the size check
+    /// * `info` - The size of the PR, or `None` when it was not sized
+    /// * `exclusion` - The size exclusion the PR matched, if any
+    fn size_report(
         &self,
-        repo_owner: &str,
-        repo_name: &str,
-        pr_number: u64,
-    ) -> Result<CheckResult, MergeWardenError> {
-        info!(
-            repository_owner = repo_owner,
-            repository = repo_name,
-            pull_request = pr_number,
-            "Processing pull request",
-        );
-
-        // Get the PR
-        let pr = self
-            .provider
-            .get_pull_request(repo_owner, repo_name, pr_number)
-            .await
-            .map_err(|e| {
-                error!(
-                    repository_owner = repo_owner,
-                    repository = repo_name,
-                    pull_request = pr_number,
-                    error = e.to_string(),
-                    "Failed to Find the PR"
-                );
-
-                MergeWardenError::GitProviderError(format!(
-                    "Failed to find the PR with number [{}] in {}/{}",
-                    pr_number, repo_owner, repo_name
-                ))
-            })?;
+        result: &validation_result::ValidationResult,
+        info: Option<&size::PrSizeInfo>,
+        exclusion: Option<&config::SizeExclusion>,
+    ) -> CheckReport {
+        let kind = config::CheckKind::Size;
+        let size_names = &self.config.pr_size_check.category_names;
+        let report = if let Some(exclusion) = exclusion {
+            CheckReport::new(
+                kind,
+                CheckStatus::Bypassed,
+                format!("Excluded by {exclusion}"),
+            )
+        } else if let Some(bypass) = result.bypass_info() {
+            CheckReport::bypassed(kind, bypass)
+        } else if let Some(info) = info {
+            if !info.has_reviewable_changes() {
+                CheckReport::new(kind, CheckStatus::Passed, "No reviewable changes")
+            } else if info.is_oversized() {
+                CheckReport::new(
+                    kind,
+                    CheckStatus::Failed(self.config.check_summary.size_severity),
+                    format!(
+                        "Oversized: {} ({} lines changed)",
+                        size_names.name_for(info.size_category),
+                        info.total_lines_changed
+                    ),
+                )
+            } else {
+                CheckReport::new(
+                    kind,
+                    CheckStatus::Passed,
+                    format!(
+                        "{} ({} lines changed)",
+                        size_names.name_for(info.size_category),
+                        info.total_lines_changed
+                    ),
+                )
+            }
+        } else {
+            CheckReport::skipped(
+                kind,
+                result
+                    .skip_reason()
+                    .unwrap_or("Size checking is not enabled"),
+            )
+        };
 
-        info!(
-            repository_owner = repo_owner,
-            repository = repo_name,
-            pull_request = pr_number,
-            "Got pull request",
-        );
+        // An excluded PR is recorded as bypassed by its author
+        let report = report.with_bypass(result.bypass_info());
+        if result.is_valid() {
+            report
+        } else {
+            report.failed("PR size exceeds threshold")
+        }
+    }
 
-        // Manage state-lifecycle labels on every event (idempotent).
-        // Runs before the draft early-return so the draft label is applied even
-        // when we skip the full validation.
-        self.communicate_pr_state_labels(repo_owner, repo_name, &pr)
-            .await;
+    /// Reports the outcome of the checklist check, which is `None` when it is disabled.
+    fn checklist_report(&self, result: Option<&checks::ChecklistValidationResult>) -> CheckReport {
+        let kind = config::CheckKind::Checklist;
+        match result {
+            None => CheckReport::skipped(kind, "Checklist validation is not enabled"),
+            Some(result) if result.is_valid() => CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                "All required checklist items are ticked",
+            ),
+            Some(result) => CheckReport::new(
+                kind,
+                CheckStatus::Failed(config::CheckSeverity::Error),
+                format!(
+                    "{} required checklist item(s) not ticked",
+                    result.unchecked_items.len() + result.missing_items.len()
+                ),
+            )
+            .failed("checklist is incomplete")
+            .with_details(Self::format_checklist_message(
+                result,
+                self.config.check_summary.use_emoji,
+            )),
+        }
+    }
 
-        // Manage the Renovate stability label based on current commit status.
-        self.communicate_renovate_stability_status(repo_owner, repo_name, &pr)
-            .await;
+    /// Reports the outcome of the review-thread check, which is `None` when it is
+    /// disabled.
+    fn review_threads_report(&self, outcome: Option<&ReviewThreadOutcome>) -> CheckReport {
+        let kind = config::CheckKind::ReviewThreads;
+        match outcome {
+            None => CheckReport::skipped(kind, "Review thread check is not enabled"),
+            Some(ReviewThreadOutcome::Unavailable(reason)) => CheckReport::skipped(kind, *reason),
+            Some(ReviewThreadOutcome::Checked(result)) => {
+                let message = format!(
+                    "{} of {} review thread(s) unresolved (limit {})",
+                    result.unresolved_count,
+                    result.total_count,
+                    self.config.review_threads.max_unresolved
+                );
+                if result.is_valid() {
+                    CheckReport::new(kind, CheckStatus::Passed, message)
+                } else {
+                    CheckReport::new(
+                        kind,
+                        CheckStatus::Failed(config::CheckSeverity::Error),
+                        message,
+                    )
+                    .failed(format!(
+                        "has {} unresolved review thread(s)",
+                        result.unresolved_count
+                    ))
+                }
+            }
+        }
+    }
 
-        // If the pull request is a draft then we still run validation so developers can
-        // correct title and description issues before they come out of draft mode.
-        // The check status is set to "neutral" (non-blocking) when validation fails in
-        // draft mode, so the PR is never hard-blocked while actively being worked on.
-        let check_title = "Merge Warden";
-        if pr.draft {
-            info!(
-                pull_request = pr.number,
-                "Pull request is in draft mode; running validation in non-blocking mode"
-            );
+    /// Reports the outcome of the version bump check, which is `None` when it is
+    /// disabled.
+    fn version_bump_report(&self, outcome: Option<&VersionBumpOutcome>) -> CheckReport {
+        let kind = config::CheckKind::VersionBump;
+        let report = match outcome {
+            None => CheckReport::skipped(kind, "Version bump check is not enabled"),
+            Some(VersionBumpOutcome::Unavailable(reason)) => CheckReport::skipped(kind, *reason),
+            Some(VersionBumpOutcome::Checked(result)) if !result.is_bumped() => CheckReport::new(
+                kind,
+                CheckStatus::Skipped,
+                format!("Version unchanged ({})", result.new_version),
+            ),
+            Some(VersionBumpOutcome::Checked(result)) if result.is_valid() => CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                format!(
+                    "{} → {} (requires at least a {} bump)",
+                    result.previous_version, result.new_version, result.required_level
+                ),
+            ),
+            Some(VersionBumpOutcome::Checked(result)) => CheckReport::new(
+                kind,
+                CheckStatus::Failed(config::CheckSeverity::Error),
+                format!(
+                    "{} → {}, expected {}",
+                    result.previous_version,
+                    result.new_version,
+                    result
+                        .expected_version
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default()
+                ),
+            )
+            .failed("version bump does not match the changes"),
+        };
+        report.with_details(outcome.and_then(|outcome| {
+            Self::format_version_bump_message(
+                outcome,
+                &self.config.version_bump.manifest_path,
+                self.config.check_summary.use_emoji,
+            )
+        }))
+    }
+
+    /// Reports the outcome of the deleted-file check, which is `None` when it is
+    /// disabled.
+    fn deleted_files_report(
+        &self,
+        result: Option<&checks::DeletedFilesValidationResult>,
+    ) -> CheckReport {
+        let kind = config::CheckKind::DeletedFiles;
+        let policy = &self.config.deleted_files;
+        match result {
+            None => CheckReport::skipped(kind, "Deleted file check is not enabled"),
+            Some(result) if result.is_valid() => CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                "Deleted files have their paired changes",
+            ),
+            Some(result) => CheckReport::new(
+                kind,
+                CheckStatus::Failed(policy.severity),
+                format!(
+                    "{} deletion rule(s) without paired changes",
+                    result.violations.len()
+                ),
+            )
+            .failed("deleted files lack paired changes")
+            .blocking(policy.fails_check())
+            .with_details(Self::format_deleted_files_message(
+                result,
+                policy.severity,
+                self.config.check_summary.use_emoji,
+            )),
         }
+    }
 
-        // Check if PR is marked as WIP — runs before all other validations and cannot be bypassed
-        if self.config.wip_check.enforce_wip_blocking {
-            let is_wip = self.check_wip_status(&pr);
+    /// Reports the outcome of the suspicious file check, which is `None` when it is
+    /// disabled.
+    fn suspicious_files_report(
+        &self,
+        result: Option<&checks::SuspiciousFilesValidationResult>,
+    ) -> CheckReport {
+        let kind = config::CheckKind::SuspiciousFiles;
+        let policy = &self.config.suspicious_files;
+        match result {
+            None => CheckReport::skipped(kind, "Suspicious file check is not enabled"),
+            Some(result) if result.is_valid() => CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                "No secret, binary or oversized files",
+            ),
+            Some(result) => CheckReport::new(
+                kind,
+                CheckStatus::Failed(policy.severity),
+                format!("{} suspicious file(s)", result.files.len()),
+            )
+            .failed("adds suspicious files")
+            .blocking(policy.fails_check())
+            .with_details(Self::format_suspicious_files_message(
+                result,
+                policy.severity,
+                self.config.check_summary.use_emoji,
+            )),
+        }
+    }
 
-            if is_wip {
-                let wip_message = self
-                    .communicate_wip_status(repo_owner, repo_name, &pr, true)
-                    .await;
+    /// Reports the outcome of the base branch check, which is `None` when it is
+    /// disabled.
+    fn base_branch_report(
+        &self,
+        result: Option<&checks::BaseBranchValidationResult>,
+    ) -> CheckReport {
+        let kind = config::CheckKind::BaseBranch;
+        let policy = &self.config.base_branch;
+        let Some(result) = result else {
+            return CheckReport::skipped(kind, "Base branch check is not enabled");
+        };
 
-                info!(
-                    repository_owner = repo_owner,
-                    repository = repo_name,
-                    pull_request = pr_number,
-                    "Pull request is marked as WIP. Blocking merge."
-                );
+        let report = if let Some(reason) = result.validation.skip_reason() {
+            CheckReport::skipped(kind, reason)
+        } else if result.allowed_bases.is_empty() {
+            CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                format!("No rule applies to `{}`", result.base_branch),
+            )
+        } else if result.is_valid() {
+            CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                format!("Targets `{}`", result.base_branch),
+            )
+        } else {
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(policy.severity),
+                format!(
+                    "expected {}, got `{}`",
+                    Self::format_branch_patterns(&result.allowed_bases),
+                    result.base_branch
+                ),
+            )
+            .failed(format!(
+                "targets `{}` instead of {}",
+                result.base_branch,
+                Self::format_branch_patterns(&result.allowed_bases)
+            ))
+            .blocking(policy.fails_check())
+        };
+        report.with_details(Self::format_base_branch_message(
+            result,
+            policy.severity,
+            self.config.check_summary.use_emoji,
+        ))
+    }
 
-                self.provider
-                    .update_pr_check_status(
-                        repo_owner,
-                        repo_name,
-                        pr_number,
-                        // WIP is always hard-blocking regardless of draft status. Unlike the
-                        // invalid-title or missing-work-item checks (which use "neutral" for
-                        // drafts), WIP blocking is an explicit developer signal that merge must
-                        // be prevented. Respecting pr.draft here would let draft PRs silently
-                        // bypass WIP enforcement, defeating its purpose.
-                        "failure",
-                        check_title,
-                        "Pull request is marked as WIP (Work In Progress). Remove WIP markers to allow merging.",
-                        &wip_message,
-                    )
-                    .await
-                    .map_err(|e| {
-                        error!(
-                            repository_owner = repo_owner,
-                            repository = repo_name,
-                            pull_request = pr_number,
-                            error = e.to_string(),
-                            "Failed to add or update GitHub check run"
-                        );
-                        MergeWardenError::FailedToUpdatePullRequest(
-                            "Failed to add or update GitHub check run".to_string(),
-                        )
-                    })?;
+    /// Reports the outcome of the exclusive labels check, which is `None` when it is
+    /// disabled.
+    fn exclusive_labels_report(
+        &self,
+        result: Option<&checks::ExclusiveLabelsValidationResult>,
+    ) -> CheckReport {
+        let kind = config::CheckKind::ExclusiveLabels;
+        let policy = &self.config.exclusive_labels;
+        let Some(result) = result else {
+            return CheckReport::skipped(kind, "Exclusive labels check is not enabled");
+        };
 
-                return Ok(CheckResult {
-                    title_valid: true,
-                    work_item_referenced: true,
-                    size_valid: true,
-                    wip_detected: true,
-                    labels: Vec::new(),
-                    bypasses_used: Vec::new(),
-                    // NOTE: issue metadata propagation (milestone / project sync) is
-                    // intentionally skipped for WIP PRs. The PR is not ready for merge,
-                    // so propagating metadata at this point could apply a milestone that
-                    // the author has not yet confirmed. Propagation will run on the next
-                    // event after the WIP marker is removed.
-                });
-            } else {
-                // PR is not WIP. Only run cleanup if there is stale WIP state to remove
-                // (a WIP label or WIP comment). This avoids unnecessary API calls on
-                // every clean PR event when WIP blocking is enabled.
-                //
-                // The label scan checks COMMON_WIP_LABEL_NAMES (common repo label names
-                // that manage_wip_labels may have applied via auto-discovery) AND the
-                // configured wip_label hint (applied when the exact hint matched the repo
-                // label or was used as direct fallback). Both are needed because either
-                // path may have been used on a previous event.
-                //
-                // If wip_label is None (labeling disabled), no label was ever applied by
-                // this code, so the label scan is skipped entirely.
-                let labeling_enabled = self
-                    .config
-                    .wip_check
-                    .wip_label
-                    .as_deref()
-                    .is_some_and(|s| !s.is_empty());
+        let report = if let Some(reason) = result.validation.skip_reason() {
+            CheckReport::skipped(kind, reason)
+        } else if result.is_valid() {
+            CheckReport::new(kind, CheckStatus::Passed, "At most one label per group")
+        } else {
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(policy.severity),
+                result
+                    .conflicts
+                    .iter()
+                    .map(Self::format_label_conflict)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+            .failed("has conflicting labels")
+            .blocking(policy.fails_check())
+        };
+        report.with_details(Self::format_exclusive_labels_message(
+            result,
+            policy.severity,
+            self.config.check_summary.use_emoji,
+        ))
+    }
 
-                let has_stale_wip = if labeling_enabled {
-                    self.provider
-                        .list_applied_labels(repo_owner, repo_name, pr_number)
-                        .await
-                        .unwrap_or_default()
-                        .iter()
-                        .any(|l| {
-                            labels::COMMON_WIP_LABEL_NAMES
-                                .iter()
-                                .any(|n| l.name.eq_ignore_ascii_case(n))
-                                || self
-                                    .config
-                                    .wip_check
-                                    .wip_label
-                                    .as_deref()
-                                    .filter(|s| !s.is_empty())
-                                    .is_some_and(|hint| l.name == hint)
-                        })
-                } else {
-                    false
-                };
+    /// Reports the outcome of the signed commits check, which is `None` when it is
+    /// disabled.
+    fn signed_commits_report(&self, outcome: Option<&SignedCommitsOutcome>) -> CheckReport {
+        let kind = config::CheckKind::SignedCommits;
+        let report = match outcome {
+            None => CheckReport::skipped(kind, "Signed commits check is not enabled"),
+            Some(SignedCommitsOutcome::Unavailable(reason)) => CheckReport::skipped(kind, *reason),
+            Some(SignedCommitsOutcome::Checked(result)) => match result.validation.bypass_info() {
+                Some(bypass) => CheckReport::bypassed(kind, bypass),
+                None if result.is_valid() => CheckReport::new(
+                    kind,
+                    CheckStatus::Passed,
+                    "All commits have verified signatures",
+                ),
+                None => CheckReport::new(
+                    kind,
+                    CheckStatus::Failed(config::CheckSeverity::Error),
+                    format!(
+                        "{} commit(s) without a verified signature",
+                        result.unverified_commits.len()
+                    ),
+                )
+                .failed(format!(
+                    "has {} unverified commit(s)",
+                    result.unverified_commits.len()
+                )),
+            },
+        };
+        report.with_details(outcome.and_then(|outcome| {
+            Self::format_signed_commits_message(outcome, self.config.check_summary.use_emoji)
+        }))
+    }
 
-                let has_stale_comment = if has_stale_wip {
-                    true // skip the second API call when we already know cleanup is needed
-                } else {
-                    self.provider
-                        .list_comments(repo_owner, repo_name, pr_number)
-                        .await
-                        .unwrap_or_default()
-                        .iter()
-                        .any(|c| c.body.contains(WIP_COMMENT_MARKER))
-                };
+    /// Reports the outcome of the revert reference check, which is `None` when it is
+    /// disabled.
+    fn revert_reference_report(
+        &self,
+        result: Option<&checks::RevertReferenceValidationResult>,
+    ) -> CheckReport {
+        let kind = config::CheckKind::RevertReference;
+        let Some(result) = result else {
+            return CheckReport::skipped(kind, "Revert reference check is not enabled");
+        };
 
-                if has_stale_wip || has_stale_comment {
-                    self.communicate_wip_status(repo_owner, repo_name, &pr, false)
-                        .await;
-                }
-            }
+        let report = if let Some(reason) = result.validation.skip_reason() {
+            CheckReport::skipped(kind, reason)
+        } else if let Some(bypass) = result.validation.bypass_info() {
+            CheckReport::bypassed(kind, bypass)
+        } else if result.is_valid() {
+            CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                format!("References {}", result.references.join(", ")),
+            )
+        } else {
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(config::CheckSeverity::Error),
+                "No reference to the reverted PR or commit",
+            )
+            .failed("does not reference the reverted change")
+        };
+        report.with_details(Self::format_revert_reference_message(
+            result,
+            self.config.check_summary.use_emoji,
+        ))
+    }
+
+    /// Reports the outcome of the up-to-date check, which is `None` when it is disabled.
+    fn up_to_date_report(&self, outcome: Option<&UpToDateOutcome>) -> CheckReport {
+        let kind = config::CheckKind::UpToDate;
+        let policy = &self.config.up_to_date;
+        let report = match outcome {
+            None => CheckReport::skipped(kind, "Up-to-date check is not enabled"),
+            Some(UpToDateOutcome::Unavailable(reason)) => CheckReport::skipped(kind, *reason),
+            Some(UpToDateOutcome::Checked(result)) if result.is_valid() => CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                match result.commits_behind {
+                    0 => format!("Up to date with `{}`", result.base_branch),
+                    n => format!("{} commit(s) behind `{}`", n, result.base_branch),
+                },
+            ),
+            Some(UpToDateOutcome::Checked(result)) => CheckReport::new(
+                kind,
+                CheckStatus::Failed(policy.severity),
+                format!(
+                    "{} commit(s) behind `{}`",
+                    result.commits_behind, result.base_branch
+                ),
+            )
+            .failed(format!(
+                "is {} commit(s) behind `{}`",
+                result.commits_behind, result.base_branch
+            ))
+            .blocking(policy.fails_check()),
+        };
+        report.with_details(outcome.and_then(|outcome| {
+            Self::format_up_to_date_message(
+                outcome,
+                policy.severity,
+                self.config.check_summary.use_emoji,
+            )
+        }))
+    }
+
+    /// Reports the outcome of the footer check, which is `None` when it is disabled.
+    fn footers_report(&self, result: Option<&checks::FooterValidationResult>) -> CheckReport {
+        let kind = config::CheckKind::Footers;
+        match result {
+            None => CheckReport::skipped(kind, "Footer check is not enabled"),
+            Some(result) if result.is_valid() => CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                "All required footers are present",
+            ),
+            Some(result) => CheckReport::new(
+                kind,
+                CheckStatus::Failed(config::CheckSeverity::Error),
+                format!("{} required footer(s) missing", result.missing.len()),
+            )
+            .failed("required footers are missing")
+            .with_details(Self::format_footer_message(
+                result,
+                self.config.check_summary.use_emoji,
+            )),
         }
+    }
+
+    /// Reports the outcome of the descriptive title check, which is `None` when it is
+    /// disabled.
+    fn descriptive_title_report(
+        &self,
+        result: Option<&checks::DescriptiveTitleValidationResult>,
+    ) -> CheckReport {
+        let kind = config::CheckKind::DescriptiveTitle;
+        let policy = &self.config.descriptive_title;
+        let Some(result) = result else {
+            return CheckReport::skipped(kind, "Descriptive title check is not enabled");
+        };
 
-        // Check PR title follows the conventional commit structure if enabled
-        let title_result = if self.config.enforce_title_convention {
-            self.check_title(&pr)
+        let report = if let Some(reason) = result.validation.skip_reason() {
+            CheckReport::skipped(kind, reason)
+        } else if result.is_valid() {
+            CheckReport::new(kind, CheckStatus::Passed, "Title describes the change")
         } else {
-            checks::TitleValidationResult {
-                validation: validation_result::ValidationResult::valid(),
-                diagnosis: None,
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(policy.severity),
+                format!("Title repeats the branch name `{}`", result.head_branch),
+            )
+            .failed("title only repeats the branch name")
+            .blocking(policy.fails_check())
+        };
+        report.with_details(Self::format_descriptive_title_message(
+            result,
+            policy.severity,
+            self.config.check_summary.use_emoji,
+        ))
+    }
+
+    /// Reports the outcome of the change types check, which is `None` when it is
+    /// disabled.
+    fn change_types_report(&self, outcome: Option<&ChangeTypesOutcome>) -> CheckReport {
+        let kind = config::CheckKind::ChangeTypes;
+        let policy = &self.config.change_types;
+        let report = match outcome {
+            None => CheckReport::skipped(kind, "Change types check is not enabled"),
+            Some(ChangeTypesOutcome::Unavailable(reason)) => CheckReport::skipped(kind, *reason),
+            Some(ChangeTypesOutcome::Checked(result)) if result.change_types.is_empty() => {
+                CheckReport::new(
+                    kind,
+                    CheckStatus::Passed,
+                    "No conventional commit types found",
+                )
             }
+            Some(ChangeTypesOutcome::Checked(result)) if result.is_valid() => CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                format!(
+                    "{} change type(s): {}",
+                    result.change_types.len(),
+                    Self::format_change_types(&result.change_types)
+                ),
+            ),
+            Some(ChangeTypesOutcome::Checked(result)) => CheckReport::new(
+                kind,
+                CheckStatus::Failed(policy.severity),
+                format!(
+                    "{} change types, at most {} allowed: {}",
+                    result.change_types.len(),
+                    result.max_change_types,
+                    Self::format_change_types(&result.change_types)
+                ),
+            )
+            .failed(format!(
+                "mixes {} change types ({})",
+                result.change_types.len(),
+                Self::format_change_types(&result.change_types)
+            ))
+            .blocking(policy.fails_check()),
+        };
+        report.with_details(outcome.and_then(|outcome| {
+            Self::format_change_types_message(
+                outcome,
+                policy.severity,
+                self.config.check_summary.use_emoji,
+            )
+        }))
+    }
+
+    /// Reports the outcome of the label policy check, which is `None` when it is
+    /// disabled.
+    fn label_policy_report(
+        &self,
+        result: Option<&checks::LabelPolicyValidationResult>,
+    ) -> CheckReport {
+        let kind = config::CheckKind::LabelPolicy;
+        let policy = &self.config.label_policy;
+        let Some(result) = result else {
+            return CheckReport::skipped(kind, "Label policy check is not enabled");
         };
 
-        // Check that the PR body has a reference to a work item if enabled
-        let work_item_result = if self.config.enforce_work_item_references {
-            self.check_work_item_reference(&pr)
+        let report = if let Some(reason) = result.validation.skip_reason() {
+            CheckReport::skipped(kind, reason)
+        } else if result.is_valid() {
+            CheckReport::new(
+                kind,
+                CheckStatus::Passed,
+                "Required labels present, no forbidden labels",
+            )
         } else {
-            validation_result::ValidationResult::valid()
+            CheckReport::new(
+                kind,
+                CheckStatus::Failed(policy.severity),
+                Self::format_label_policy_violations(result),
+            )
+            .failed("does not meet the label policy")
+            .blocking(policy.fails_check())
         };
+        report.with_details(Self::format_label_policy_message(
+            result,
+            policy.severity,
+            self.config.check_summary.use_emoji,
+        ))
+    }
 
-        // Fetch PR files unconditionally — needed for both size analysis and config
-        // validation.  This is an intentional trade-off: one extra API call is made per
-        // PR event regardless of whether `pr_size_check` is enabled, in order to support
-        // the config-change validation path without complicating the control flow.
-        let pr_files = self
-            .provider
-            .get_pull_request_files(repo_owner, repo_name, pr_number)
+    /// Formats the missing and forbidden labels of a failed label policy check, e.g.
+    /// ``missing `team: *`; forbidden `do-not-merge` ``.
+    fn format_label_policy_violations(result: &checks::LabelPolicyValidationResult) -> String {
+        let format_labels = |labels: &[String]| {
+            labels
+                .iter()
+                .map(|label| format!("`{}`", label))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut parts = Vec::new();
+        if !result.missing_labels.is_empty() {
+            parts.push(format!("missing {}", format_labels(&result.missing_labels)));
+        }
+        if !result.forbidden_labels.is_empty() {
+            parts.push(format!(
+                "forbidden {}",
+                format_labels(&result.forbidden_labels)
+            ));
+        }
+        parts.join("; ")
+    }
+
+    /// Formats change types as a comma-separated list of code spans, e.g. `` `feat`, `fix` ``.
+    fn format_change_types(change_types: &[String]) -> String {
+        change_types
+            .iter()
+            .map(|change_type| format!("`{}`", change_type))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Determines which bypass rules are activated by a label on the PR.
+    ///
+    /// A bypass label is only honoured when the user who applied it is in the rule's
+    /// bypass allowlist. Labels applied by anyone else are ignored and logged as an
+    /// audit event. The applied labels are only fetched when a rule names a label.
+    async fn resolve_label_bypasses(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> LabelBypasses {
+        let rules = &self.config.bypass_rules;
+        let named_rules = [
+            ("title_convention", rules.title_convention()),
+            ("work_items", rules.work_item_convention()),
+            ("size", rules.size()),
+            ("signed_commits", rules.signed_commits()),
+            ("revert_reference", rules.revert_reference()),
+        ];
+        if !named_rules
+            .iter()
+            .any(|(_, rule)| rule.enabled() && rule.label().is_some())
+        {
+            return LabelBypasses::default();
+        }
+
+        let applied_labels = match self
+            .provider()
+            .list_applied_labels(repo_owner, repo_name, pr_number)
             .await
-            .map_err(|e| {
-                error!(
+        {
+            Ok(labels) => labels,
+            Err(e) => {
+                warn!(
                     repository_owner = repo_owner,
                     repository = repo_name,
                     pull_request = pr_number,
                     error = e.to_string(),
-                    "Failed to fetch PR files"
+                    "Failed to list PR labels; ignoring bypass labels"
                 );
-                MergeWardenError::GitProviderError("Failed to fetch PR files".to_string())
-            })?;
+                return LabelBypasses::default();
+            }
+        };
 
-        // Perform size check when enabled.
-        let size_result = if self.config.pr_size_check.enabled {
-            self.check_pr_size(&pr_files, pr.author.as_ref())
-        } else {
-            validation_result::ValidationResult::valid()
+        let applier = |rule_name, rule| {
+            self.label_bypass_applier(
+                repo_owner,
+                repo_name,
+                pr_number,
+                &applied_labels,
+                rule_name,
+                rule,
+            )
         };
+        LabelBypasses {
+            title_convention: applier("title_convention", rules.title_convention()).await,
+            work_items: applier("work_items", rules.work_item_convention()).await,
+            size: applier("size", rules.size()).await,
+            signed_commits: applier("signed_commits", rules.signed_commits()).await,
+            revert_reference: applier("revert_reference", rules.revert_reference()).await,
+        }
+    }
 
-        // Validate .github/merge-warden.toml when it is part of the PR.
-        if pr_files.iter().any(|f| f.filename == CONFIG_FILE_PATH) {
-            if pr.head_sha.is_empty() {
+    /// Returns the authorised user who applied the bypass label of `rule`, or `None` when
+    /// the rule is disabled, names no label or its label is not in `applied_labels`.
+    async fn label_bypass_applier(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        applied_labels: &[Label],
+        rule_name: &str,
+        rule: &config::BypassRule,
+    ) -> Option<User> {
+        let label = rule.label().filter(|_| rule.enabled())?;
+        if !applied_labels
+            .iter()
+            .any(|l| l.name.eq_ignore_ascii_case(label))
+        {
+            return None;
+        }
+
+        self.authorised_label_applier(repo_owner, repo_name, pr_number, rule_name, rule, label)
+            .await
+    }
+
+    /// Returns the disable label and the user who applied it when Merge Warden is disabled
+    /// on the PR.
+    ///
+    /// The label is configured on the `all` bypass rule and is only honoured when it was
+    /// applied by a user in that rule's allowlist. The applied labels are only fetched when
+    /// the rule is enabled and names a label.
+    async fn resolve_disable_label(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Option<(String, User)> {
+        let rule = self.config.bypass_rules.all();
+        let label = rule.label().filter(|_| rule.enabled())?;
+
+        let applied_labels = match self
+            .provider()
+            .list_applied_labels(repo_owner, repo_name, pr_number)
+            .await
+        {
+            Ok(labels) => labels,
+            Err(e) => {
                 warn!(
                     repository_owner = repo_owner,
                     repository = repo_name,
                     pull_request = pr_number,
-                    "head_sha is empty; skipping config file validation"
+                    error = e.to_string(),
+                    "Failed to list PR labels; ignoring the disable label"
+                );
+                return None;
+            }
+        };
+        if !applied_labels
+            .iter()
+            .any(|l| l.name.eq_ignore_ascii_case(label))
+        {
+            return None;
+        }
+
+        let user = self
+            .authorised_label_applier(repo_owner, repo_name, pr_number, "all", rule, label)
+            .await?;
+        Some((label.to_string(), user))
+    }
+
+    /// Fetches the files changed by the PR from the configured diff source.
+    ///
+    /// With [`config::DiffSource::MergeBase`] the files are read from the comparison of
+    /// the PR's base branch and head commit, which only holds the changes the PR
+    /// introduces. When the base branch or head commit is unknown, or the comparison
+    /// cannot be fetched, the PR's own file list is used instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns the provider's error when the PR's own file list cannot be fetched.
+    async fn fetch_pr_files(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+    ) -> Result<
+        Vec<merge_warden_developer_platforms::models::PullRequestFile>,
+        merge_warden_developer_platforms::errors::Error,
+    > {
+        if self.config.check_execution.diff_source == config::DiffSource::MergeBase {
+            if pr.base_ref.is_empty() || pr.head_sha.is_empty() {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    "PR base branch or head commit unknown; reading the PR's own file list"
                 );
             } else {
                 match self
-                    .provider
-                    .fetch_config_at_ref(repo_owner, repo_name, CONFIG_FILE_PATH, &pr.head_sha)
+                    .provider()
+                    .get_comparison_files(repo_owner, repo_name, &pr.base_ref, &pr.head_sha)
                     .await
                 {
-                    Ok(Some(content)) => {
-                        let outcome = validate_config_content(&content);
-                        self.communicate_config_validity_status(
-                            repo_owner, repo_name, &pr, &outcome,
-                        )
-                        .await;
-                    }
-                    Ok(None) => {
-                        // File absent at head SHA — the config file was likely deleted in
-                        // this PR.  Treat as valid (no config to check) and clean up any
-                        // stale error comment left from a previous push.
-                        debug!(
-                            repository_owner = repo_owner,
-                            repository = repo_name,
-                            pull_request = pr_number,
-                            "Config file not found at head SHA; cleaning up stale comment"
-                        );
-                        let valid_outcome = ConfigValidationOutcome {
-                            valid: true,
-                            errors: vec![],
-                        };
-                        self.communicate_config_validity_status(
-                            repo_owner,
-                            repo_name,
-                            &pr,
-                            &valid_outcome,
-                        )
-                        .await;
-                    }
+                    Ok(files) => return Ok(files),
                     Err(e) => {
                         warn!(
                             repository_owner = repo_owner,
                             repository = repo_name,
-                            pull_request = pr_number,
-                            error = %e,
-                            "Failed to fetch config file for validation; skipping comment"
+                            pull_request = pr.number,
+                            error = e.to_string(),
+                            "Failed to fetch the merge-base diff; reading the PR's own file list"
                         );
                     }
                 }
             }
         }
 
-        // Collect bypass information for audit trail
-        let mut bypasses_used = Vec::new();
-        if let Some(bypass_info) = title_result.bypass_info() {
-            bypasses_used.push(bypass_info.clone());
+        self.provider()
+            .get_pull_request_files(repo_owner, repo_name, pr.number)
+            .await
+    }
+
+    /// Returns the skip patterns matching the PR's files when every changed file matches
+    /// one of the `skip_if_only_paths` patterns.
+    ///
+    /// The files are only fetched when patterns are configured. A PR without changed
+    /// files, or whose files cannot be fetched, is not skipped.
+    async fn resolve_path_skip(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+    ) -> Option<Vec<String>> {
+        let path_skip = &self.config.path_skip;
+        if !path_skip.is_enabled() {
+            return None;
+        }
+
+        let files = match self.fetch_pr_files(repo_owner, repo_name, pr).await {
+            Ok(files) => files,
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    error = e.to_string(),
+                    "Failed to fetch PR files; not skipping the pull request"
+                );
+                return None;
+            }
+        };
+        if files.is_empty() {
+            return None;
+        }
+
+        let exclusions = size::FileExclusions::new(&path_skip.skip_if_only_paths);
+        let mut matched_patterns: Vec<String> = Vec::new();
+        for file in &files {
+            let pattern = exclusions.excluding_pattern(&file.filename)?;
+            if !matched_patterns.iter().any(|p| p == pattern) {
+                matched_patterns.push(pattern.to_string());
+            }
+        }
+        Some(matched_patterns)
+    }
+
+    /// Reports a PR that only changes ignored paths with a `neutral` check run.
+    ///
+    /// None of the checks run and no label or comment is changed. Every check is
+    /// recorded as skipped.
+    async fn report_path_skip(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+        matched_patterns: &[String],
+    ) -> Result<CheckResult, MergeWardenError> {
+        info!(
+            repository_owner = repo_owner,
+            repository = repo_name,
+            pull_request = pr.number,
+            patterns = ?matched_patterns,
+            "Pull request only changes ignored paths; skipping all checks"
+        );
+
+        let summary =
+            "Merge Warden skipped this pull request because it only changes ignored paths.";
+        let text = format!(
+            "{}Every file changed by this pull request matches the `skip_if_only_paths` \
+             setting ({}), so no checks were run.",
+            if self.config.check_summary.use_emoji {
+                "⏭️ "
+            } else {
+                ""
+            },
+            matched_patterns
+                .iter()
+                .map(|p| format!("`{}`", p))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        self.post_check_run(
+            repo_owner,
+            repo_name,
+            pr.number,
+            "neutral",
+            "Merge Warden",
+            summary,
+            &text,
+        )
+        .await?;
+
+        Ok(CheckResult {
+            skip_reasons: self
+                .config
+                .check_execution
+                .effective_order()
+                .into_iter()
+                .map(|kind| (kind, PATH_SKIPPED_REASON.to_string()))
+                .collect(),
+            check_summary: summary.to_string(),
+            check_text: text,
+            ..Default::default()
+        })
+    }
+
+    /// Returns the size exclusion that exempts the PR from the size check, if any.
+    ///
+    /// The applied labels are only fetched when an exclusion names a label. When they
+    /// cannot be fetched, only the exclusions without a label are considered.
+    async fn resolve_size_exclusion(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+    ) -> Option<config::SizeExclusion> {
+        let size_config = &self.config.pr_size_check;
+        if size_config.exclusions.is_empty() {
+            return None;
+        }
+
+        let applied_labels = if size_config.exclusions.iter().any(|e| e.label.is_some()) {
+            match self
+                .provider()
+                .list_applied_labels(repo_owner, repo_name, pr.number)
+                .await
+            {
+                Ok(labels) => labels.into_iter().map(|l| l.name).collect(),
+                Err(e) => {
+                    warn!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr.number,
+                        error = e.to_string(),
+                        "Failed to list PR labels; ignoring label-based size exclusions"
+                    );
+                    Vec::new()
+                }
+            }
+        } else {
+            Vec::new()
+        };
+
+        size_config
+            .matching_exclusion(pr.author.as_ref(), &applied_labels)
+            .cloned()
+    }
+
+    /// Fetches the files between the configured size `compare_base` and the PR head.
+    ///
+    /// Returns `None` when size checking is disabled, no compare base is configured,
+    /// or the comparison cannot be fetched; the size is then taken from the PR's own
+    /// diff.
+    async fn fetch_size_comparison_files(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+    ) -> Option<Vec<merge_warden_developer_platforms::models::PullRequestFile>> {
+        let size_config = &self.config.pr_size_check;
+        let base = size_config.compare_base.as_deref()?;
+        if !size_config.enabled {
+            return None;
+        }
+
+        if pr.head_sha.is_empty() {
+            warn!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr.number,
+                compare_base = base,
+                "PR head commit unknown; sizing the PR's own diff"
+            );
+            return None;
+        }
+
+        match self
+            .provider()
+            .get_comparison_files(repo_owner, repo_name, base, &pr.head_sha)
+            .await
+        {
+            Ok(files) => Some(files),
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    compare_base = base,
+                    error = e.to_string(),
+                    "Failed to compare PR head with the size compare base; sizing the PR's own diff"
+                );
+                None
+            }
         }
-        if let Some(bypass_info) = work_item_result.bypass_info() {
-            bypasses_used.push(bypass_info.clone());
+    }
+
+    /// Leaves the whitespace-only changed lines out of the line counts of `pr_files`.
+    ///
+    /// Returns `None` when size checking is disabled, `ignore_whitespace` is not set, or
+    /// the file patches cannot be fetched; the full line counts are then used.
+    async fn discount_size_whitespace_changes(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        pr_files: &[merge_warden_developer_platforms::models::PullRequestFile],
+    ) -> Option<Vec<merge_warden_developer_platforms::models::PullRequestFile>> {
+        let size_config = &self.config.pr_size_check;
+        if !size_config.enabled || !size_config.ignore_whitespace {
+            return None;
+        }
+
+        match self
+            .provider()
+            .get_pull_request_file_patches(repo_owner, repo_name, pr_number)
+            .await
+        {
+            Ok(patches) => Some(size::discount_whitespace_only_changes(pr_files, &patches)),
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to fetch PR file patches; counting whitespace-only changes"
+                );
+                None
+            }
+        }
+    }
+
+    /// Posts the check run for the pull request.
+    ///
+    /// The check run is posted under `check_summary.check_run_name` when it is set, and
+    /// otherwise under the provider's default name. With `keep_legacy_check_run`, a renamed
+    /// check run is also posted under the default name so that branch protection rules
+    /// requiring it keep working during the rename. When the provider cannot name check
+    /// runs, only the default name is used. With `check_summary.rerun_action`, each check
+    /// run shows a "Re-run Merge Warden" button.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MergeWardenError::FailedToUpdatePullRequest`] when a check run cannot be
+    /// posted.
+    #[allow(clippy::too_many_arguments)]
+    async fn post_check_run(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+    ) -> Result<(), MergeWardenError> {
+        self.decision_recorder
+            .record(repo_owner, repo_name, pr_number, |log| {
+                log.check_run = Some(CheckRunDecision {
+                    conclusion: conclusion.to_string(),
+                    title: output_title.to_string(),
+                    summary: output_summary.to_string(),
+                });
+            });
+
+        let check_summary = &self.config.check_summary;
+        let renamed = check_summary
+            .check_run_name
+            .as_deref()
+            .filter(|name| *name != merge_warden_developer_platforms::DEFAULT_CHECK_RUN_NAME);
+
+        let actions = if check_summary.rerun_action {
+            vec![CheckRunAction::rerun()]
+        } else {
+            Vec::new()
+        };
+
+        let mut post_default = true;
+        let mut result = Ok(());
+        if let Some(check_name) = renamed {
+            match self
+                .provider()
+                .update_named_pr_check_status(
+                    repo_owner,
+                    repo_name,
+                    pr_number,
+                    check_name,
+                    conclusion,
+                    output_title,
+                    output_summary,
+                    output_text,
+                    &actions,
+                )
+                .await
+            {
+                Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                    warn!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        check_name,
+                        "The platform cannot name check runs; using the default check run name"
+                    );
+                }
+                other => {
+                    result = other;
+                    post_default = check_summary.keep_legacy_check_run;
+                }
+            }
+        }
+
+        if result.is_ok() && post_default {
+            result = self
+                .provider()
+                .update_pr_check_status(
+                    repo_owner,
+                    repo_name,
+                    pr_number,
+                    conclusion,
+                    output_title,
+                    output_summary,
+                    output_text,
+                    &actions,
+                )
+                .await;
+        }
+
+        result.map_err(|e| {
+            error!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                error = e.to_string(),
+                "Failed to add or update GitHub check run"
+            );
+            MergeWardenError::FailedToUpdatePullRequest(
+                "Failed to add or update GitHub check run".to_string(),
+            )
+        })
+    }
+
+    /// Writes `entry` to the state store, unless a dry run is in progress for the pull
+    /// request of `key`.
+    ///
+    /// A dry run must not record state, since a later run would otherwise treat the
+    /// planned changes as applied.
+    async fn set_state(&self, key: &StateKey, entry: StateEntry) -> Result<(), StateStoreError> {
+        if self.dry_run_recorder.is_planning(
+            &key.target.owner,
+            &key.target.repo,
+            key.target.pr_number,
+        ) {
+            return Ok(());
+        }
+
+        self.state_store.set(key, entry).await
+    }
+
+    /// Records the check conclusion and posts a summary comment when it changed.
+    ///
+    /// The conclusion of the previous run is read from the state store under
+    /// [`CONCLUSION_STATE_NAME`]. When it differs from `conclusion`, the previous
+    /// transition comment is replaced with one naming both conclusions and the check
+    /// summary. The first conclusion recorded for a PR is commented on unless it is
+    /// `success`, so that passing PRs stay quiet. State store failures are logged and
+    /// treated as if no conclusion had been recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr_number` - The number of the pull request
+    /// * `conclusion` - The conclusion reported for this run
+    /// * `summary` - The check summary reported for this run
+    async fn communicate_conclusion_transition(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        conclusion: &str,
+        summary: &str,
+    ) {
+        let key = StateKey::new((repo_owner, repo_name, pr_number), CONCLUSION_STATE_NAME);
+        let previous = match self.state_store.get(&key).await {
+            Ok(entry) => entry.and_then(|entry| entry.value::<String>().ok()),
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to read the previous check conclusion"
+                );
+                None
+            }
+        };
+
+        let stored = match StateEntry::new(
+            &conclusion,
+            self.clock.now(),
+            Some(chrono::Duration::days(CONCLUSION_STATE_TTL_DAYS)),
+        ) {
+            Ok(entry) => self.set_state(&key, entry).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = stored {
+            warn!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                error = e.to_string(),
+                "Failed to record the check conclusion"
+            );
+        }
+
+        match previous.as_deref() {
+            Some(previous) if previous == conclusion => return,
+            None if conclusion == "success" => return,
+            _ => {}
+        }
+        let context = ConclusionTransitionContext {
+            previous_conclusion: previous.clone(),
+            conclusion: conclusion.to_string(),
+            summary: summary.to_string(),
+        };
+        let comment = format!(
+            "{prefix}{text}",
+            prefix = TRANSITION_COMMENT_MARKER,
+            text = render_comment(
+                self.comment_renderer.as_ref(),
+                CommentTemplate::ConclusionTransition,
+                &context,
+            )
+        );
+
+        let existing = self
+            .list_guidance_comments(repo_owner, repo_name, pr_number)
+            .await
+            .unwrap_or_default();
+        for stale in existing
+            .iter()
+            .filter(|c| c.body.contains(TRANSITION_COMMENT_MARKER))
+        {
+            if let Err(e) = self
+                .delete_guidance_comment(repo_owner, repo_name, stale.id)
+                .await
+            {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    comment_id = stale.id,
+                    error = e.to_string(),
+                    "Failed to delete the previous transition comment"
+                );
+            }
+        }
+
+        match self
+            .add_guidance_comment(repo_owner, repo_name, pr_number, &comment)
+            .await
+        {
+            Ok(()) => info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                previous_conclusion = previous.as_deref(),
+                conclusion = conclusion,
+                "Posted check conclusion transition comment"
+            ),
+            Err(e) => warn!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                error = e.to_string(),
+                "Failed to post check conclusion transition comment"
+            ),
         }
-        if let Some(bypass_info) = size_result.bypass_info() {
-            bypasses_used.push(bypass_info.clone());
+    }
+
+    /// Converts a pull request that fails a blocking check to a draft, once.
+    ///
+    /// The conversion is recorded in the state store under
+    /// [`DRAFT_CONVERSION_STATE_NAME`]. A pull request that was converted before is
+    /// left alone, so that an author who marks it as ready for review again is not
+    /// overruled. After converting, a comment names the failing checks and explains
+    /// how to continue. Failures are logged and never fail the validation. When the
+    /// conversion is not recorded because the state store failed, the pull request
+    /// may be converted again by a later run.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr_number` - The number of the pull request
+    /// * `failing_rules` - The blocking checks that failed
+    async fn convert_failing_pull_request_to_draft(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        failing_rules: &[config::CheckKind],
+    ) {
+        let key = StateKey::new(
+            (repo_owner, repo_name, pr_number),
+            DRAFT_CONVERSION_STATE_NAME,
+        );
+        match self.state_store.get(&key).await {
+            Ok(Some(_)) => {
+                debug!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    "Pull request was converted to a draft before; leaving it ready for review"
+                );
+                return;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to read the draft conversion state; not converting the pull request"
+                );
+                return;
+            }
+        }
+
+        match self
+            .provider()
+            .convert_to_draft(repo_owner, repo_name, pr_number)
+            .await
+        {
+            Ok(()) => {}
+            Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    "Draft conversion is enabled but the platform does not support it; skipping"
+                );
+                return;
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to convert pull request to draft"
+                );
+                return;
+            }
+        }
+
+        let stored = match StateEntry::new(
+            &self.clock.now(),
+            self.clock.now(),
+            Some(chrono::Duration::days(DRAFT_CONVERSION_STATE_TTL_DAYS)),
+        ) {
+            Ok(entry) => self.set_state(&key, entry).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = stored {
+            warn!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                error = e.to_string(),
+                "Failed to record the draft conversion"
+            );
+        }
+
+        let context = DraftConversionContext {
+            failing_checks: failing_rules
+                .iter()
+                .map(|kind| kind.summary_name().to_string())
+                .collect(),
+        };
+        let comment = format!(
+            "{prefix}{text}",
+            prefix = DRAFT_CONVERSION_COMMENT_MARKER,
+            text = render_comment(
+                self.comment_renderer.as_ref(),
+                CommentTemplate::DraftConversion,
+                &context,
+            )
+        );
+        match self
+            .add_guidance_comment(repo_owner, repo_name, pr_number, &comment)
+            .await
+        {
+            Ok(()) => info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                "Converted failing pull request to draft"
+            ),
+            Err(e) => warn!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                error = e.to_string(),
+                "Failed to post draft conversion comment"
+            ),
+        }
+    }
+
+    /// Returns the path of the repository's pull request template and the lines of the
+    /// template that show how to reference a work item.
+    ///
+    /// Returns `None`, so that the default work item guidance is posted, when template
+    /// guidance is disabled, when the repository has no template, when the template
+    /// has no work item reference lines, or when a template cannot be fetched.
+    async fn pr_template_work_item_lines(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Option<(&'static str, Vec<String>)> {
+        if !self.config.work_item_pr_template_guidance {
+            return None;
+        }
+
+        for template_path in config::PULL_REQUEST_TEMPLATE_PATHS {
+            match self
+                .provider()
+                .fetch_config(repo_owner, repo_name, template_path)
+                .await
+            {
+                Ok(Some(template)) => {
+                    let lines = checks::find_pr_template_work_item_lines(
+                        &template,
+                        &self.config.work_item_reference_pattern,
+                    );
+                    debug!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        template_path = template_path,
+                        reference_lines = lines.len(),
+                        "Found the pull request template"
+                    );
+                    return (!lines.is_empty()).then_some((template_path, lines));
+                }
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        template_path = template_path,
+                        error = e.to_string(),
+                        "Failed to fetch the pull request template. Using the default work item guidance."
+                    );
+                    return None;
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns `true` when the issue a branch is named after exists in the repository
+    /// and is not a pull request.
+    ///
+    /// The description is only edited for issues that exist, so a lookup that fails
+    /// or that the platform does not support returns `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr` - The pull request whose head branch names the issue
+    /// * `issue_number` - The issue number taken from the branch name
+    async fn branch_issue_exists(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+        issue_number: u64,
+    ) -> bool {
+        match self
+            .provider()
+            .get_issue(repo_owner, repo_name, issue_number)
+            .await
+        {
+            Ok(Some(issue)) if !issue.is_pull_request => true,
+            Ok(_) => {
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    branch = pr.head_ref,
+                    issue = issue_number,
+                    "The branch names an issue that does not exist; not adding a work item reference"
+                );
+                false
+            }
+            Err(e) => {
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    branch = pr.head_ref,
+                    issue = issue_number,
+                    error = e.to_string(),
+                    "Could not look up the issue the branch names; not adding a work item reference"
+                );
+                false
+            }
+        }
+    }
+
+    /// Edits the title and description of a pull request to fix the checks that opted
+    /// in to [`config::AutofixPolicyConfig`].
+    ///
+    /// The fixes are computed by [`autofix::fix_title`] and
+    /// [`autofix::fix_work_item_reference`]; checks bypassed by a label are not fixed.
+    /// The description is only fixed when the issue the branch names exists.
+    /// Each edit is logged with the old and new value. When the platform accepts the
+    /// edit, `pr` is updated to match and a comment lists what was changed. Failures
+    /// are logged and leave `pr` unchanged, so that the checks report the original
+    /// failure.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr` - The pull request, updated in place when the edit succeeds
+    /// * `bypass_author` - The user the bypass rules are evaluated for
+    /// * `label_bypasses` - The bypass labels applied to the pull request
+    async fn autofix_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &mut PullRequest,
+        bypass_author: Option<&User>,
+        label_bypasses: &LabelBypasses,
+    ) {
+        // A bypassed check is left alone, whether a label or its author bypasses it
+        let bypass_rules = &self.config.bypass_rules;
+        let title = if label_bypasses.title_convention.is_none()
+            && !bypass_rules
+                .title_convention()
+                .can_bypass_validation(bypass_author)
+        {
+            autofix::fix_title(pr, &self.config)
+        } else {
+            None
+        };
+        let work_item_bypassed = label_bypasses.work_items.is_some()
+            || bypass_rules
+                .work_item_convention()
+                .can_bypass_validation(bypass_author);
+        let body = match autofix::branch_issue_number(&pr.head_ref) {
+            Some(issue_number) if !work_item_bypassed => {
+                match autofix::fix_work_item_reference(pr, &self.config) {
+                    Some(body)
+                        if self
+                            .branch_issue_exists(repo_owner, repo_name, pr, issue_number)
+                            .await =>
+                    {
+                        Some(body)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        if title.is_none() && body.is_none() {
+            return;
+        }
+
+        let mut context = AutofixCommentContext {
+            old_title: None,
+            new_title: None,
+            work_item_reference: None,
+            branch: pr.head_ref.clone(),
+        };
+        if let Some(title) = &title {
+            info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr.number,
+                old_title = pr.title,
+                new_title = title,
+                "Fixing pull request title"
+            );
+            context.old_title = Some(pr.title.clone());
+            context.new_title = Some(title.clone());
+        }
+        // A description is only fixed when the branch is named after an issue
+        if let (Some(_), Some(issue_number)) = (&body, autofix::branch_issue_number(&pr.head_ref)) {
+            let reference = autofix::work_item_reference_line(issue_number);
+            info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr.number,
+                branch = pr.head_ref,
+                reference = reference,
+                "Adding work item reference to pull request description"
+            );
+            context.work_item_reference = Some(reference);
+        }
+
+        match self
+            .provider()
+            .update_pull_request(
+                repo_owner,
+                repo_name,
+                pr.number,
+                title.as_deref(),
+                body.as_deref(),
+            )
+            .await
+        {
+            Ok(()) => {}
+            Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    "Autofix is enabled but the platform cannot edit pull requests; skipping"
+                );
+                return;
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    error = e.to_string(),
+                    "Failed to fix pull request"
+                );
+                return;
+            }
+        }
+
+        if let Some(title) = title {
+            pr.title = title;
+        }
+        if let Some(body) = body {
+            pr.body = Some(body);
+        }
+
+        let comment = format!(
+            "{prefix}{text}",
+            prefix = AUTOFIX_COMMENT_MARKER,
+            text = render_comment(
+                self.comment_renderer.as_ref(),
+                CommentTemplate::Autofix,
+                &context,
+            )
+        );
+        if let Err(e) = self
+            .add_guidance_comment(repo_owner, repo_name, pr.number, &comment)
+            .await
+        {
+            warn!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr.number,
+                error = e.to_string(),
+                "Failed to post autofix comment"
+            );
+        }
+    }
+
+    /// Returns a renderer for `template` using the guidance configured for the pull
+    /// request author's kind of contributor.
+    ///
+    /// Returns `None`, so that the default renderer is used, when no template is
+    /// configured for the author's kind, when the author's association with the
+    /// repository cannot be determined, or when the configured template is invalid.
+    async fn contributor_guidance_renderer(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        template: CommentTemplate,
+    ) -> Option<TemplateEngineRenderer> {
+        let guidance = &self.config.comments.contributor_guidance;
+        let source_for = |templates: &config::GuidanceTemplates| match template {
+            CommentTemplate::InvalidTitle => templates.invalid_title.clone(),
+            CommentTemplate::MissingWorkItem => templates.missing_work_item.clone(),
+            _ => None,
+        };
+        if source_for(&guidance.internal).is_none() && source_for(&guidance.external).is_none() {
+            return None;
+        }
+
+        let association = match self
+            .provider()
+            .get_author_association(repo_owner, repo_name, pr_number)
+            .await
+        {
+            Ok(association) => association,
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to determine the author's association with the repository. Using the default guidance."
+                );
+                return None;
+            }
+        };
+
+        let source = if association.is_internal() {
+            source_for(&guidance.internal)
+        } else {
+            source_for(&guidance.external)
+        }?;
+        debug!(
+            repository_owner = repo_owner,
+            repository = repo_name,
+            pull_request = pr_number,
+            author_association = ?association,
+            "Using the contributor-specific guidance template"
+        );
+
+        match TemplateEngineRenderer::new().with_template(template, source) {
+            Ok(renderer) => Some(renderer),
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "The contributor-specific guidance template is invalid. Using the default guidance."
+                );
+                None
+            }
+        }
+    }
+
+    /// Lists the comments that may hold Merge Warden guidance.
+    ///
+    /// In threaded mode these are the threaded comments, unless the provider does not
+    /// support them; otherwise they are the top-level comments.
+    async fn list_guidance_comments(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Comment>, merge_warden_developer_platforms::errors::Error> {
+        if self.config.comments.threaded {
+            match self
+                .provider()
+                .list_threaded_comments(repo_owner, repo_name, pr_number)
+                .await
+            {
+                Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {}
+                result => return result,
+            }
+        }
+
+        self.provider()
+            .list_comments(repo_owner, repo_name, pr_number)
+            .await
+    }
+
+    /// Posts a Merge Warden guidance comment.
+    ///
+    /// In threaded mode the comment is a reply in the guidance thread, which is started
+    /// first if the PR does not have one yet. Providers without threaded comments get a
+    /// top-level comment instead.
+    async fn add_guidance_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        comment: &str,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        if self.config.comments.threaded {
+            match self
+                .add_threaded_guidance_comment(repo_owner, repo_name, pr_number, comment)
+                .await
+            {
+                Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                    debug!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        "Threaded comments are not supported. Adding a top-level comment instead."
+                    );
+                }
+                result => return result,
+            }
+        }
+
+        self.provider()
+            .add_comment(repo_owner, repo_name, pr_number, comment)
+            .await
+    }
+
+    /// Replies in the guidance thread, starting the thread if needed.
+    async fn add_threaded_guidance_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        comment: &str,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        let comments = self
+            .provider()
+            .list_threaded_comments(repo_owner, repo_name, pr_number)
+            .await?;
+        let root_id = match comments
+            .iter()
+            .find(|c| c.body.contains(THREAD_ROOT_COMMENT_MARKER))
+        {
+            Some(root) => root.id,
+            None => {
+                let root = format!(
+                    "{}\n**Merge Warden** posts its guidance for this pull request in this thread.",
+                    THREAD_ROOT_COMMENT_MARKER
+                );
+                self.provider()
+                    .add_threaded_comment(repo_owner, repo_name, pr_number, None, &root)
+                    .await?
+                    .id
+            }
+        };
+
+        self.provider()
+            .add_threaded_comment(repo_owner, repo_name, pr_number, Some(root_id), comment)
+            .await
+            .map(|_| ())
+    }
+
+    /// Deletes a comment returned by [`MergeWarden::list_guidance_comments`].
+    async fn delete_guidance_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        comment_id: u64,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        if self.config.comments.threaded {
+            match self
+                .provider()
+                .delete_threaded_comment(repo_owner, repo_name, comment_id)
+                .await
+            {
+                Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {}
+                result => return result,
+            }
+        }
+
+        self.provider()
+            .delete_comment(repo_owner, repo_name, comment_id)
+            .await
+    }
+
+    /// Reports a PR on which Merge Warden has been disabled by the disable label.
+    ///
+    /// The check is set to neutral and every rule is recorded as bypassed by the user who
+    /// applied the label. No labels or comments are added, updated or removed.
+    async fn report_disabled(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+        label: &str,
+        user: &User,
+    ) -> Result<CheckResult, MergeWardenError> {
+        let bypass_info = validation_result::BypassInfo {
+            rule_type: validation_result::BypassRuleType::AllRules,
+            user: user.login.clone(),
+        };
+
+        // Log bypass usage for audit trail
+        info!(
+            repository_owner = repo_owner,
+            repository = repo_name,
+            pull_request = pr.number,
+            user = bypass_info.user,
+            rule_type = ?bypass_info.rule_type,
+            label,
+            pr_title = pr.title,
+            pr_author = pr.author.as_ref().map(|u| &u.login),
+            "Validation bypass used"
+        );
+
+        let summary = "Merge Warden is disabled on this pull request.";
+        let text = format!(
+            "⏸️ The `{}` label was applied by @{}, so every Merge Warden rule is bypassed \
+             for this pull request. Remove the label to run the checks again.",
+            label, user.login
+        );
+        self.post_check_run(
+            repo_owner,
+            repo_name,
+            pr.number,
+            "neutral",
+            "Merge Warden",
+            summary,
+            &text,
+        )
+        .await?;
+
+        Ok(CheckResult {
+            bypasses_used: vec![bypass_info],
+            check_summary: summary.to_string(),
+            check_text: text,
+            ..Default::default()
+        })
+    }
+
+    /// Returns the user who applied a bypass label when they may bypass `rule`.
+    async fn authorised_label_applier(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        rule_name: &str,
+        rule: &config::BypassRule,
+        label: &str,
+    ) -> Option<User> {
+        let applier = match self
+            .provider()
+            .find_label_applier(repo_owner, repo_name, pr_number, label)
+            .await
+        {
+            Ok(applier) => applier,
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    rule = rule_name,
+                    label,
+                    error = e.to_string(),
+                    "Could not determine who applied the bypass label; ignoring it"
+                );
+                return None;
+            }
+        };
+
+        if rule.can_bypass_validation(applier.as_ref()) {
+            return applier;
+        }
+
+        warn!(
+            repository_owner = repo_owner,
+            repository = repo_name,
+            pull_request = pr_number,
+            rule = rule_name,
+            label,
+            applied_by = applier.as_ref().map(|u| u.login.as_str()),
+            "Ignoring bypass label applied by a user who is not allowed to bypass this rule"
+        );
+        None
+    }
+
+    /// Makes the provider calls of the review-thread, size, version bump, exclusive
+    /// labels, signed commits, up-to-date, change types and label policy checks
+    /// concurrently and returns their results.
+    ///
+    /// The calls only read from the provider, so they can be interleaved safely. Labels
+    /// and comments are still updated after all checks have run, in the usual order.
+    async fn run_provider_checks_concurrently(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+        pr_files: &[merge_warden_developer_platforms::models::PullRequestFile],
+        bypass_author: Option<&User>,
+        label_bypasses: &LabelBypasses,
+    ) -> ConcurrentCheckResults {
+        let size_exclusion = async {
+            if self.config.pr_size_check.enabled {
+                self.resolve_size_exclusion(repo_owner, repo_name, pr).await
+            } else {
+                None
+            }
+        };
+        let (
+            review_threads,
+            size_exclusion,
+            version_bump,
+            exclusive_labels,
+            signed_commits,
+            up_to_date,
+            change_types,
+            label_policy,
+        ) = tokio::join!(
+            self.check_review_threads(repo_owner, repo_name, pr.number),
+            size_exclusion,
+            self.check_version_bump(repo_owner, repo_name, pr, pr_files),
+            self.check_exclusive_labels(repo_owner, repo_name, pr.number),
+            self.check_signed_commits(repo_owner, repo_name, pr, bypass_author, label_bypasses),
+            self.check_up_to_date(repo_owner, repo_name, pr),
+            self.check_change_types(repo_owner, repo_name, pr),
+            self.check_label_policy(repo_owner, repo_name, pr.number),
+        );
+
+        ConcurrentCheckResults {
+            review_threads,
+            size_exclusion,
+            version_bump,
+            exclusive_labels,
+            signed_commits,
+            up_to_date,
+            change_types,
+            label_policy,
+        }
+    }
+
+    /// Lists the PR's commits and counts the distinct change types of the PR.
+    ///
+    /// Returns `None` when the check is disabled. Platforms that cannot list the commits
+    /// of a PR, and transient fetch failures, yield [`ChangeTypesOutcome::Unavailable`]
+    /// so that the check fails open.
+    async fn check_change_types(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+    ) -> Option<ChangeTypesOutcome> {
+        if !self.config.change_types.enabled {
+            return None;
+        }
+
+        match self
+            .provider()
+            .list_pull_request_commits(repo_owner, repo_name, pr.number)
+            .await
+        {
+            Ok(commits) => Some(ChangeTypesOutcome::Checked(checks::check_change_types(
+                &pr.title,
+                &commits,
+                &self.config.change_types,
+            ))),
+            Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    "Change types check is enabled but the platform does not support it; skipping"
+                );
+                Some(ChangeTypesOutcome::Unavailable(
+                    "Not supported by this platform",
+                ))
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    error = e.to_string(),
+                    "Failed to fetch pull request commits; skipping change types check"
+                );
+                Some(ChangeTypesOutcome::Unavailable(
+                    "Could not fetch the commits of the PR",
+                ))
+            }
+        }
+    }
+
+    /// Fetches the verification state of the PR's commits and checks that every commit
+    /// is signed.
+    ///
+    /// Returns `None` when the check is disabled. A PR bypassed by its author or by an
+    /// authorised bypass label is not looked up. Platforms that cannot report commit
+    /// verification, and transient fetch failures, yield
+    /// [`SignedCommitsOutcome::Unavailable`] so that the check fails open.
+    async fn check_signed_commits(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+        bypass_author: Option<&User>,
+        label_bypasses: &LabelBypasses,
+    ) -> Option<SignedCommitsOutcome> {
+        if !self.config.signed_commits.enabled {
+            return None;
+        }
+
+        // An authorised bypass label counts as a bypass by the user who applied it
+        let bypass_rule = self.config.bypass_rules.signed_commits();
+        let bypass_user = label_bypasses.signed_commits.as_ref().or(bypass_author);
+        if bypass_rule.can_bypass_validation(bypass_user) {
+            return Some(SignedCommitsOutcome::Checked(checks::check_signed_commits(
+                &[],
+                bypass_user,
+                bypass_rule,
+            )));
+        }
+
+        match self
+            .provider()
+            .list_commit_verifications(repo_owner, repo_name, pr.number)
+            .await
+        {
+            Ok(commits) => Some(SignedCommitsOutcome::Checked(checks::check_signed_commits(
+                &commits,
+                bypass_user,
+                bypass_rule,
+            ))),
+            Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    "Signed commits check is enabled but the platform does not support it; skipping"
+                );
+                Some(SignedCommitsOutcome::Unavailable(
+                    "Not supported by this platform",
+                ))
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    error = e.to_string(),
+                    "Failed to fetch commit verifications; skipping signed commits check"
+                );
+                Some(SignedCommitsOutcome::Unavailable(
+                    "Could not fetch commit verifications",
+                ))
+            }
+        }
+    }
+
+    /// Counts the commits the PR is behind its base branch and checks them against the
+    /// up-to-date policy.
+    ///
+    /// Returns `None` when the check is disabled. PRs whose base branch or head commit is
+    /// unknown, platforms that cannot compare branches, and transient fetch failures
+    /// yield [`UpToDateOutcome::Unavailable`] so that the check fails open and the label
+    /// is left unchanged.
+    async fn check_up_to_date(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+    ) -> Option<UpToDateOutcome> {
+        if !self.config.up_to_date.enabled {
+            return None;
+        }
+
+        if pr.base_ref.is_empty() || pr.head_sha.is_empty() {
+            return Some(UpToDateOutcome::Unavailable(
+                "The base branch or head commit of the PR is not known",
+            ));
+        }
+
+        match self
+            .provider()
+            .get_commits_behind(repo_owner, repo_name, &pr.base_ref, &pr.head_sha)
+            .await
+        {
+            Ok(commits_behind) => Some(UpToDateOutcome::Checked(checks::check_up_to_date(
+                &pr.base_ref,
+                commits_behind,
+                &self.config.up_to_date,
+            ))),
+            Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    "Up-to-date check is enabled but the platform does not support it; skipping"
+                );
+                Some(UpToDateOutcome::Unavailable(
+                    "Not supported by this platform",
+                ))
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    error = e.to_string(),
+                    "Failed to compare the PR with its base; skipping up-to-date check"
+                );
+                Some(UpToDateOutcome::Unavailable(
+                    "Could not compare the PR with its base branch",
+                ))
+            }
+        }
+    }
+
+    /// Checks the PR's labels against the exclusive label groups.
+    ///
+    /// Returns `None` when the check is disabled. When the labels cannot be listed the
+    /// check is skipped rather than failed.
+    async fn check_exclusive_labels(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Option<checks::ExclusiveLabelsValidationResult> {
+        if !self.config.exclusive_labels.enabled {
+            return None;
+        }
+
+        let result = match self
+            .provider()
+            .list_applied_labels(repo_owner, repo_name, pr_number)
+            .await
+        {
+            Ok(applied) => {
+                let names: Vec<String> = applied.into_iter().map(|l| l.name).collect();
+                checks::check_exclusive_labels(&names, &self.config.exclusive_labels)
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to list labels for the exclusive labels check"
+                );
+                checks::ExclusiveLabelsValidationResult {
+                    validation: validation_result::ValidationResult::skipped(
+                        "The labels of the PR could not be listed",
+                    ),
+                    conflicts: Vec::new(),
+                }
+            }
+        };
+        Some(result)
+    }
+
+    /// Checks the PR's labels against the required and forbidden labels.
+    ///
+    /// Returns `None` when the check is disabled. When the labels cannot be listed the
+    /// check is skipped rather than failed.
+    async fn check_label_policy(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Option<checks::LabelPolicyValidationResult> {
+        if !self.config.label_policy.enabled {
+            return None;
+        }
+
+        let result = match self
+            .provider()
+            .list_applied_labels(repo_owner, repo_name, pr_number)
+            .await
+        {
+            Ok(applied) => {
+                let names: Vec<String> = applied.into_iter().map(|l| l.name).collect();
+                checks::check_label_policy(&names, &self.config.label_policy)
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to list labels for the label policy check"
+                );
+                checks::LabelPolicyValidationResult {
+                    validation: validation_result::ValidationResult::skipped(
+                        "The labels of the PR could not be listed",
+                    ),
+                    missing_labels: Vec::new(),
+                    forbidden_labels: Vec::new(),
+                }
+            }
+        };
+        Some(result)
+    }
+
+    /// Fetches the review threads of a PR and checks them against the review-thread policy.
+    ///
+    /// Returns `None` when the check is disabled. Platforms that cannot report review
+    /// threads, and transient fetch failures, yield [`ReviewThreadOutcome::Unavailable`]
+    /// so that the check fails open rather than blocking the PR.
+    async fn check_review_threads(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Option<ReviewThreadOutcome> {
+        if !self.config.review_threads.enabled {
+            return None;
+        }
+
+        match self
+            .provider()
+            .list_review_threads(repo_owner, repo_name, pr_number)
+            .await
+        {
+            Ok(threads) => Some(ReviewThreadOutcome::Checked(
+                checks::check_unresolved_review_threads(&threads, &self.config.review_threads),
+            )),
+            Err(merge_warden_developer_platforms::errors::Error::Unsupported(_)) => {
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    "Review thread check is enabled but the platform does not support it; skipping"
+                );
+                Some(ReviewThreadOutcome::Unavailable(
+                    "Not supported by this platform",
+                ))
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to fetch review threads; skipping review thread check"
+                );
+                Some(ReviewThreadOutcome::Unavailable(
+                    "Could not fetch review threads",
+                ))
+            }
+        }
+    }
+
+    /// Returns the marker that opens the message of a failed check in the check-run
+    /// output: the emoji for `severity`, or its status word when `use_emoji` is `false`.
+    fn severity_marker(severity: config::CheckSeverity, use_emoji: bool) -> &'static str {
+        summary::CheckStatus::Failed(severity).icon(use_emoji)
+    }
+
+    /// Formats the bypasses used on the PR for the check-run output, one line per bypass
+    /// naming the rule and the user.
+    ///
+    /// Returns `None` when no bypass was used.
+    fn format_bypass_message(
+        bypasses: &[validation_result::BypassInfo],
+        use_emoji: bool,
+    ) -> Option<String> {
+        if bypasses.is_empty() {
+            return None;
+        }
+
+        let mut message = format!("{}**Bypasses**:\n", if use_emoji { "🔓 " } else { "" });
+        for bypass in bypasses {
+            let description = bypass
+                .description()
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("{} bypassed", bypass.rule_type));
+            message.push_str(&format!("\n- {} by @{}", description, bypass.user));
+        }
+        Some(message)
+    }
+
+    /// Formats the list of unticked and missing checklist items for the check-run output.
+    ///
+    /// Returns `None` when the checklist is complete.
+    fn format_checklist_message(
+        result: &checks::ChecklistValidationResult,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        let mut message = format!(
+            "{} **Checklist**: The following required checklist items must be ticked before merging:\n",
+            Self::severity_marker(config::CheckSeverity::Error, use_emoji)
+        );
+        for item in &result.unchecked_items {
+            message.push_str(&format!("\n- [ ] {}", item));
+        }
+        for item in &result.missing_items {
+            message.push_str(&format!(
+                "\n- [ ] {} _(not found in the PR description)_",
+                item
+            ));
+        }
+        Some(message)
+    }
+
+    /// Formats the list of missing footers for the check-run output.
+    ///
+    /// Returns `None` when every required footer is present.
+    fn format_footer_message(
+        result: &checks::FooterValidationResult,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        let mut message = format!(
+            "{} **Footers**: The following footers must be added to the end of the PR description:\n",
+            Self::severity_marker(config::CheckSeverity::Error, use_emoji)
+        );
+        for token in &result.missing {
+            message.push_str(&format!("\n- `{}:`", token));
+        }
+        Some(message)
+    }
+
+    /// Formats the prompt for a descriptive title for the check-run output.
+    ///
+    /// Returns `None` when the title is more than the branch name.
+    fn format_descriptive_title_message(
+        result: &checks::DescriptiveTitleValidationResult,
+        severity: config::CheckSeverity,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        let icon = Self::severity_marker(severity, use_emoji);
+        Some(format!(
+            "{} **Descriptive title**: The PR title only repeats the branch name `{}`.\n\nChange the title to describe what the PR changes.",
+            icon, result.head_branch
+        ))
+    }
+
+    /// Reads the version from the manifest on the default branch and at the PR head,
+    /// and checks the change against the change level of the PR.
+    ///
+    /// Returns `None` when the check is disabled. When the PR does not change the
+    /// manifest, or the versions cannot be fetched or read, the check yields
+    /// [`VersionBumpOutcome::Unavailable`] so that it fails open rather than
+    /// blocking the PR.
+    async fn check_version_bump(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr: &PullRequest,
+        pr_files: &[merge_warden_developer_platforms::models::PullRequestFile],
+    ) -> Option<VersionBumpOutcome> {
+        let policy = &self.config.version_bump;
+        if !policy.enabled {
+            return None;
+        }
+
+        let path = policy.manifest_path.as_str();
+        if !pr_files.iter().any(|f| f.filename == path) {
+            return Some(VersionBumpOutcome::Unavailable(
+                "Version file not changed in this PR",
+            ));
+        }
+        if pr.head_sha.is_empty() {
+            warn!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr.number,
+                "head_sha is empty; skipping version bump check"
+            );
+            return Some(VersionBumpOutcome::Unavailable("PR head commit is unknown"));
+        }
+
+        let previous_content = self
+            .provider()
+            .fetch_config(repo_owner, repo_name, path)
+            .await;
+        let new_content = self
+            .provider()
+            .fetch_config_at_ref(repo_owner, repo_name, path, &pr.head_sha)
+            .await;
+        let (previous_content, new_content) = match (previous_content, new_content) {
+            (Ok(Some(previous)), Ok(Some(new))) => (previous, new),
+            (Ok(None), Ok(_)) => {
+                return Some(VersionBumpOutcome::Unavailable(
+                    "Version file does not exist on the default branch",
+                ))
+            }
+            (Ok(_), Ok(None)) => {
+                return Some(VersionBumpOutcome::Unavailable(
+                    "Version file is removed in this PR",
+                ))
+            }
+            (Err(e), _) | (_, Err(e)) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    manifest_path = path,
+                    error = e.to_string(),
+                    "Failed to fetch version file; skipping version bump check"
+                );
+                return Some(VersionBumpOutcome::Unavailable(
+                    "Could not fetch the version file",
+                ));
+            }
+        };
+
+        let manifest = policy.manifest_format.manifest_for(path);
+        let versions = manifest
+            .read_version(&previous_content)
+            .and_then(|previous| Ok((previous, manifest.read_version(&new_content)?)));
+        match versions {
+            Ok((previous, new)) => {
+                let required_level = version::required_bump_level(pr, &previous);
+                Some(VersionBumpOutcome::Checked(checks::check_version_bump(
+                    &previous,
+                    &new,
+                    required_level,
+                )))
+            }
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr.number,
+                    manifest_path = path,
+                    error = e.to_string(),
+                    "Failed to read version from version file; skipping version bump check"
+                );
+                Some(VersionBumpOutcome::Unavailable(
+                    "Could not read the version from the version file",
+                ))
+            }
+        }
+    }
+
+    /// Formats guidance for a version change that does not match the PR's changes.
+    ///
+    /// Returns `None` unless the version bump check failed.
+    fn format_version_bump_message(
+        outcome: &VersionBumpOutcome,
+        manifest_path: &str,
+        use_emoji: bool,
+    ) -> Option<String> {
+        let VersionBumpOutcome::Checked(result) = outcome else {
+            return None;
+        };
+        if result.is_valid() {
+            return None;
+        }
+
+        let expected = result.expected_version.as_ref()?;
+        Some(format!(
+            "{} **Version bump**: `{}` changes the version from `{}` to `{}`. \
+             The changes in this PR require at least a {} bump, so the version should be `{}`.",
+            Self::severity_marker(config::CheckSeverity::Error, use_emoji),
+            manifest_path,
+            result.previous_version,
+            result.new_version,
+            result.required_level,
+            expected
+        ))
+    }
+
+    /// Formats the size breakdown for the check-run output.
+    ///
+    /// Shows the size category on one line, followed by the per-file breakdown from
+    /// [`Self::format_size_breakdown`] in a collapsible section.
+    fn format_size_details(
+        size_info: &size::PrSizeInfo,
+        size_names: &size::SizeCategoryNames,
+        use_emoji: bool,
+    ) -> String {
+        let mut message = format!(
+            "{}**PR size details**: {} — {} line(s) across {} file(s){}",
+            if use_emoji { "📏 " } else { "" },
+            size_names.name_for(size_info.size_category),
+            size_info.total_lines_changed,
+            size_info.included_files.len(),
+            match size_info.counting_mode {
+                size::SizeCountingMode::TotalChanges => String::new(),
+                mode => format!(" (counting {})", mode.description()),
+            }
+        );
+
+        if let Some(breakdown) = Self::format_size_breakdown(size_info) {
+            message.push_str("\n\n");
+            message.push_str(&breakdown);
+        }
+
+        message
+    }
+
+    /// Renders a configured check summary template.
+    ///
+    /// Returns the built-in summary from `context` when the template fails to render,
+    /// renders empty or renders longer than [`CHECK_SUMMARY_MAX_CHARS`].
+    fn render_check_summary_template(
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        template: &str,
+        context: templates::CheckSummaryContext,
+    ) -> String {
+        match templates::render_check_summary(template, &context) {
+            Ok(summary) if summary.is_empty() => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    "The check summary template rendered empty. Using the built-in summary."
+                );
+                context.default_summary
+            }
+            Ok(summary) if summary.chars().count() > CHECK_SUMMARY_MAX_CHARS => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    length = summary.chars().count(),
+                    "The check summary template rendered longer than GitHub allows. Using the built-in summary."
+                );
+                context.default_summary
+            }
+            Ok(summary) => summary,
+            Err(e) => {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to render the check summary template. Using the built-in summary."
+                );
+                context.default_summary
+            }
+        }
+    }
+
+    /// Formats the review effort score and its reasons for the check-run output.
+    fn format_review_effort_message(effort: &size::ReviewEffort) -> String {
+        let mut message = format!(
+            "🧭 **Review effort**: {}/{}",
+            effort.score,
+            size::ReviewEffort::MAX_SCORE
+        );
+        let explanation = effort.explanation();
+        if !explanation.is_empty() {
+            message.push_str(" — ");
+            message.push_str(&explanation);
+        }
+        message
+    }
+
+    /// Formats the per-file size breakdown as a collapsible `<details>` section.
+    ///
+    /// Lists each counted file with the lines it contributes (largest first) and the
+    /// files excluded from the size. Each list is capped at [`SIZE_DETAILS_MAX_FILES`]
+    /// entries to keep the output within the check-run and comment limits.
+    ///
+    /// Returns `None` when the PR has no files.
+    fn format_size_breakdown(size_info: &size::PrSizeInfo) -> Option<String> {
+        if size_info.included_files.is_empty() && size_info.excluded_files.is_empty() {
+            return None;
+        }
+
+        let counted_lines = |file: &merge_warden_developer_platforms::models::PullRequestFile| {
+            size_info.counted_lines(file)
+        };
+
+        let mut message = String::from("<details>\n<summary>Per-file breakdown</summary>");
+
+        if !size_info.included_files.is_empty() {
+            let mut files: Vec<_> = size_info.included_files.iter().collect();
+            files.sort_by(|a, b| {
+                counted_lines(b)
+                    .cmp(&counted_lines(a))
+                    .then_with(|| a.filename.cmp(&b.filename))
+            });
+
+            message.push_str("\n\n| File | Lines |\n| :--- | ---: |");
+            for file in files.iter().take(SIZE_DETAILS_MAX_FILES) {
+                message.push_str(&format!(
+                    "\n| `{}` | {} |",
+                    file.filename,
+                    counted_lines(file)
+                ));
+            }
+            if files.len() > SIZE_DETAILS_MAX_FILES {
+                message.push_str(&format!(
+                    "\n\n…and {} more file(s).",
+                    files.len() - SIZE_DETAILS_MAX_FILES
+                ));
+            }
+        }
+
+        if !size_info.excluded_files.is_empty() {
+            message.push_str(&format!(
+                "\n\n**Excluded from the size** ({} file(s)):\n",
+                size_info.excluded_files.len()
+            ));
+            for (index, file) in size_info
+                .excluded_files
+                .iter()
+                .enumerate()
+                .take(SIZE_DETAILS_MAX_FILES)
+            {
+                message.push_str(&format!("\n- `{}`", file.filename));
+                if let Some(size::SizeExclusionReason::MatchedPattern { pattern }) =
+                    size_info.exclusion_reasons.get(index)
+                {
+                    message.push_str(&format!(" (matched `{}`)", pattern));
+                }
+            }
+            if size_info.excluded_files.len() > SIZE_DETAILS_MAX_FILES {
+                message.push_str(&format!(
+                    "\n- …and {} more file(s)",
+                    size_info.excluded_files.len() - SIZE_DETAILS_MAX_FILES
+                ));
+            }
+        }
+
+        message.push_str("\n\n</details>");
+        Some(message)
+    }
+
+    /// Formats the deleted files whose paired changes are missing for the check-run output.
+    ///
+    /// Returns `None` when every triggered rule has its paired changes.
+    fn format_deleted_files_message(
+        result: &checks::DeletedFilesValidationResult,
+        severity: config::CheckSeverity,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        let icon = Self::severity_marker(severity, use_emoji);
+        let mut message = format!(
+            "{} **Deleted files**: The following deletions need related changes in the same PR:\n",
+            icon
+        );
+        for violation in &result.violations {
+            let required = violation
+                .required_patterns
+                .iter()
+                .map(|p| format!("`{}`", p))
+                .collect::<Vec<_>>()
+                .join(", ");
+            for file in &violation.deleted_files {
+                message.push_str(&format!("\n- `{}` requires a change to {}", file, required));
+            }
+        }
+        Some(message)
+    }
+
+    /// Formats the files flagged by the suspicious file check for the check-run output.
+    ///
+    /// Returns `None` when no file was flagged.
+    fn format_suspicious_files_message(
+        result: &checks::SuspiciousFilesValidationResult,
+        severity: config::CheckSeverity,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        let icon = Self::severity_marker(severity, use_emoji);
+        let mut message = format!(
+            "{} **Suspicious files**: The following files may not belong in the repository:\n",
+            icon
+        );
+        for file in &result.files {
+            message.push_str(&format!("\n- `{}` {}", file.filename, file.reason));
+        }
+        message.push_str(
+            "\n\nRemove them from the PR if they were committed by accident. A committed secret stays in the branch history, so rotate it as well.",
+        );
+        Some(message)
+    }
+
+    /// Formats branch patterns as a list of code spans, e.g. "`develop` or `release/*`".
+    fn format_branch_patterns(patterns: &[String]) -> String {
+        patterns
+            .iter()
+            .map(|p| format!("`{}`", p))
+            .collect::<Vec<_>>()
+            .join(" or ")
+    }
+
+    /// Formats a base branch mismatch for the check-run output.
+    ///
+    /// Returns `None` when the PR targets an allowed base branch.
+    fn format_base_branch_message(
+        result: &checks::BaseBranchValidationResult,
+        severity: config::CheckSeverity,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        let icon = Self::severity_marker(severity, use_emoji);
+        Some(format!(
+            "{} **Base branch**: This PR targets `{}`, but PRs of its type must target {}.\n\nChange the base branch of the PR to one of the allowed branches.",
+            icon,
+            result.base_branch,
+            Self::format_branch_patterns(&result.allowed_bases)
+        ))
+    }
+
+    /// Formats the change types of a PR that mixes too many for the check-run output.
+    ///
+    /// Returns `None` when the PR has no more change types than allowed or its commits
+    /// could not be listed.
+    fn format_change_types_message(
+        outcome: &ChangeTypesOutcome,
+        severity: config::CheckSeverity,
+        use_emoji: bool,
+    ) -> Option<String> {
+        let ChangeTypesOutcome::Checked(result) = outcome else {
+            return None;
+        };
+        if result.is_valid() {
+            return None;
+        }
+
+        let icon = Self::severity_marker(severity, use_emoji);
+        Some(format!(
+            "{} **Change types**: This PR mixes {} change types ({}), but at most {} are allowed.\n\nSplit the PR so that each one makes a single kind of change, such as a feature or a fix.",
+            icon,
+            result.change_types.len(),
+            Self::format_change_types(&result.change_types),
+            result.max_change_types
+        ))
+    }
+
+    /// Formats how far a PR is behind its base branch for the check-run output.
+    ///
+    /// Returns `None` when the PR is up to date or could not be compared with its base.
+    fn format_up_to_date_message(
+        outcome: &UpToDateOutcome,
+        severity: config::CheckSeverity,
+        use_emoji: bool,
+    ) -> Option<String> {
+        let UpToDateOutcome::Checked(result) = outcome else {
+            return None;
+        };
+        if result.is_valid() {
+            return None;
+        }
+
+        let icon = Self::severity_marker(severity, use_emoji);
+        Some(format!(
+            "{} **Up to date**: This PR is {} commit(s) behind `{}`.\n\nRebase the PR on `{}`, or merge `{}` into it, to bring it up to date.",
+            icon,
+            result.commits_behind,
+            result.base_branch,
+            result.base_branch,
+            result.base_branch
+        ))
+    }
+
+    /// Formats the missing reference of a revert PR for the check-run output.
+    ///
+    /// Returns `None` when the check passed, was bypassed or the PR is not a revert.
+    fn format_revert_reference_message(
+        result: &checks::RevertReferenceValidationResult,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        Some(format!(
+            "{} **Revert reference**: This PR reverts a change but does not say which one.\n\nAdd the reverted PR, for example `Reverts #123` or `Reverts owner/repo#123`, or the SHA of the reverted commit to the PR description.",
+            Self::severity_marker(config::CheckSeverity::Error, use_emoji)
+        ))
+    }
+
+    /// Formats a label conflict as the group name and its labels, e.g.
+    /// "priority: `high`, `low`".
+    fn format_label_conflict(conflict: &checks::LabelConflict) -> String {
+        format!(
+            "{}: {}",
+            conflict.group,
+            conflict
+                .labels
+                .iter()
+                .map(|l| format!("`{}`", l))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    /// Formats the exclusive label conflicts for the check-run output.
+    ///
+    /// Returns `None` when no group has more than one applied label.
+    fn format_exclusive_labels_message(
+        result: &checks::ExclusiveLabelsValidationResult,
+        severity: config::CheckSeverity,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        let icon = Self::severity_marker(severity, use_emoji);
+        let mut message = format!(
+            "{} **Exclusive labels**: This PR has more than one label from a group that allows only one:\n",
+            icon
+        );
+        for conflict in &result.conflicts {
+            message.push_str(&format!("\n- {}", Self::format_label_conflict(conflict)));
+        }
+        message.push_str("\n\nRemove all but one label of each group.");
+        Some(message)
+    }
+
+    /// Formats the missing and forbidden labels for the check-run output.
+    ///
+    /// Returns `None` when the PR carries every required label and no forbidden label.
+    fn format_label_policy_message(
+        result: &checks::LabelPolicyValidationResult,
+        severity: config::CheckSeverity,
+        use_emoji: bool,
+    ) -> Option<String> {
+        if result.is_valid() {
+            return None;
+        }
+
+        let icon = Self::severity_marker(severity, use_emoji);
+        let mut message = format!("{} **Label policy**:", icon);
+        if !result.missing_labels.is_empty() {
+            message.push_str("\n\nThis PR is missing a required label:\n");
+            for label in &result.missing_labels {
+                message.push_str(&format!("\n- `{}`", label));
+            }
+        }
+        if !result.forbidden_labels.is_empty() {
+            message.push_str("\n\nThis PR carries a label that blocks merging:\n");
+            for label in &result.forbidden_labels {
+                message.push_str(&format!("\n- `{}`", label));
+            }
+        }
+        Some(message)
+    }
+
+    /// Formats the commits without a verified signature for the check-run output.
+    ///
+    /// Returns `None` when the check passed, was bypassed or could not run.
+    fn format_signed_commits_message(
+        outcome: &SignedCommitsOutcome,
+        use_emoji: bool,
+    ) -> Option<String> {
+        let SignedCommitsOutcome::Checked(result) = outcome else {
+            return None;
+        };
+        if result.is_valid() {
+            return None;
+        }
+
+        let mut message = format!(
+            "{} **Signed commits**: These commits do not have a verified signature:\n",
+            Self::severity_marker(config::CheckSeverity::Error, use_emoji)
+        );
+        for commit in &result.unverified_commits {
+            message.push_str(&format!("\n- `{}` ({})", commit.sha, commit.reason));
+        }
+        message.push_str(
+            "\n\nSign the commits, for example with `git rebase --exec 'git commit --amend --no-edit -S'`, and force-push.",
+        );
+        Some(message)
+    }
+
+    /// Processes a pull request, validating it against the configured rules.
+    ///
+    /// This method:
+    /// 1. Validates the PR title against the Conventional Commits format (if enabled)
+    /// 2. Checks if the PR description references a work item or issue (if enabled)
+    /// 3. Adds or removes labels and comments based on validation results
+    /// 4. Updates the PR's check run status (GitHub status check)
+    /// 5. Adds automatic labels based on PR content (if enabled)
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository (e.g., "octocat")
+    /// * `repo_name` - The name of the repository (e.g., "hello-world")
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `CheckResult` with the validation results
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use async_trait::async_trait;
+    /// use merge_warden_core::MergeWarden;
+    /// use anyhow::Result;
+    /// use merge_warden_developer_platforms::{ConfigFetcher, PullRequestProvider};
+    /// use merge_warden_developer_platforms::errors::Error;
+    /// use merge_warden_developer_platforms::models::{Comment, Label, PullRequest};
+    ///
+    /// #[derive(Debug)]
+    /// struct MyProvider;
+    ///
+    /// #[async_trait]
+    /// impl PullRequestProvider for MyProvider {
+    ///     async fn get_pull_request(
+    ///         &self,
+    ///         repo_owner: &str,
+    ///         repo_name: &str,
+    ///         pr_number: u64,
+    ///     ) -> Result<PullRequest, Error> {
+    ///         // Implementation to fetch PR from GitHub API
+    ///         // ...
+    ///         # unimplemented!()
+    ///     }
+    ///
+    ///     // Implement other required methods...
+    ///     # async fn add_comment(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
+    ///     # async fn delete_comment(&self, _: &str, _: &str, _: u64) -> Result<(), Error> { unimplemented!() }
+    ///     # async fn list_comments(&self, _: &str, _: &str, _: u64) -> Result<Vec<Comment>, Error> { unimplemented!() }
+    ///     # async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<Label>, Error> { unimplemented!() }
+    ///     # async fn add_labels(&self, _: &str, _: &str, _: u64, _: &[String]) -> Result<(), Error> { unimplemented!() }
+    ///     # async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
+    ///     # async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<Label>, Error> { unimplemented!() }
+    ///     # async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
+    ///     # async fn get_pull_request_files(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::PullRequestFile>, Error> { unimplemented!() }
+    ///     # async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
+    ///     # async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { Ok(vec![]) }
+    ///     # async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { Ok(vec![]) }
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl ConfigFetcher for MyProvider {
+    ///     # async fn fetch_config(&self, _: &str, _: &str, _: &str) -> Result<Option<String>, Error> { Ok(None) }
+    ///     # async fn fetch_config_at_ref(&self, _: &str, _: &str, _: &str, _: &str) -> Result<Option<String>, Error> { Ok(None) }
+    /// }
+    ///
+    /// async fn example() -> Result<()> {
+    ///     let provider = MyProvider;
+    ///     let warden = MergeWarden::new(provider);
+    ///
+    ///     let result = warden.process_pull_request("owner", "repo", 123).await?;
+    ///
+    ///     if result.title_valid && result.work_item_referenced {
+    ///         println!("PR is valid and can be merged!");
+    ///     } else {
+    ///         println!("PR has validation issues that need to be fixed");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// fn main() {}
+    /// ```
+    #[instrument]
+    pub async fn process_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<CheckResult, MergeWardenError> {
+        info!(
+            repository_owner = repo_owner,
+            repository = repo_name,
+            pull_request = pr_number,
+            "Processing pull request",
+        );
+        if !self.config.feature_flags.is_empty() {
+            info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                feature_flags = ?self.config.feature_flags,
+                "Feature flags applied to this run",
+            );
+        }
+
+        // Get the PR
+        let mut pr = self
+            .provider()
+            .get_pull_request(repo_owner, repo_name, pr_number)
+            .await
+            .map_err(|e| {
+                error!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to Find the PR"
+                );
+
+                MergeWardenError::GitProviderError(format!(
+                    "Failed to find the PR with number [{}] in {}/{}",
+                    pr_number, repo_owner, repo_name
+                ))
+            })?;
+
+        info!(
+            repository_owner = repo_owner,
+            repository = repo_name,
+            pull_request = pr_number,
+            "Got pull request",
+        );
+        self.decision_recorder
+            .record(repo_owner, repo_name, pr_number, |log| {
+                log.input = Some(DecisionInput::from(&pr));
+            });
+
+        // GitHub reports no author for PRs opened by deleted accounts. Such PRs only
+        // match user bypass lists when a stand-in login has been configured. The
+        // stand-in is used for bypass evaluation only; the PR keeps no author, so that
+        // author-based size exclusions and logs are not affected by it.
+        let mut missing_author_stand_in = None;
+        if pr.author.is_none() {
+            match self.config.bypass_rules.missing_author_login() {
+                Some(login) => {
+                    info!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        login = login,
+                        "Pull request has no author; evaluating bypasses as the configured login"
+                    );
+                    missing_author_stand_in = Some(User {
+                        id: 0,
+                        login: login.to_string(),
+                    });
+                }
+                None => {
+                    info!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        "Pull request has no author; user bypasses will not apply"
+                    );
+                }
+            }
+        }
+        let bypass_author = pr.author.clone().or(missing_author_stand_in);
+
+        // A disable label applied by an allowed user turns Merge Warden off for the PR
+        // before any label or comment is touched.
+        if let Some((label, user)) = self
+            .resolve_disable_label(repo_owner, repo_name, pr_number)
+            .await
+        {
+            return self
+                .report_disabled(repo_owner, repo_name, &pr, &label, &user)
+                .await;
+        }
+
+        // A PR that only changes ignored paths is not checked at all.
+        if let Some(matched_patterns) = self.resolve_path_skip(repo_owner, repo_name, &pr).await {
+            return self
+                .report_path_skip(repo_owner, repo_name, &pr, &matched_patterns)
+                .await;
+        }
+
+        // Manage state-lifecycle labels on every event (idempotent).
+        // Runs before the draft early-return so the draft label is applied even
+        // when we skip the full validation.
+        self.communicate_pr_state_labels(repo_owner, repo_name, &pr)
+            .await;
+
+        // Manage the Renovate stability label based on current commit status.
+        self.communicate_renovate_stability_status(repo_owner, repo_name, &pr)
+            .await;
+
+        // If the pull request is a draft then we still run validation so developers can
+        // correct title and description issues before they come out of draft mode.
+        // The check status is set to "neutral" (non-blocking) when validation fails in
+        // draft mode, so the PR is never hard-blocked while actively being worked on.
+        let check_title = "Merge Warden";
+        if pr.draft {
+            info!(
+                pull_request = pr.number,
+                "Pull request is in draft mode; running validation in non-blocking mode"
+            );
+        }
+
+        // Check if PR is marked as WIP — runs before all other validations and cannot be bypassed
+        if self.config.wip_check.enforce_wip_blocking {
+            let is_wip = self.check_wip_status(&pr);
+
+            if is_wip {
+                let wip_message = self
+                    .communicate_wip_status(repo_owner, repo_name, &pr, true)
+                    .await;
+
+                info!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    "Pull request is marked as WIP. Blocking merge."
+                );
+
+                let wip_summary = "Pull request is marked as WIP (Work In Progress). Remove WIP markers to allow merging.";
+                self.post_check_run(
+                    repo_owner,
+                    repo_name,
+                    pr_number,
+                    // WIP is always hard-blocking regardless of draft status. Unlike the
+                    // invalid-title or missing-work-item checks (which use "neutral" for
+                    // drafts), WIP blocking is an explicit developer signal that merge must
+                    // be prevented. Respecting pr.draft here would let draft PRs silently
+                    // bypass WIP enforcement, defeating its purpose.
+                    "failure",
+                    check_title,
+                    wip_summary,
+                    &wip_message,
+                )
+                .await?;
+                if self.config.comments.transition_summary {
+                    self.communicate_conclusion_transition(
+                        repo_owner,
+                        repo_name,
+                        pr_number,
+                        "failure",
+                        wip_summary,
+                    )
+                    .await;
+                }
+
+                return Ok(CheckResult {
+                    wip_detected: true,
+                    check_summary: wip_summary.to_string(),
+                    check_text: wip_message,
+                    // NOTE: issue metadata propagation (milestone / project sync) is
+                    // intentionally skipped for WIP PRs. The PR is not ready for merge,
+                    // so propagating metadata at this point could apply a milestone that
+                    // the author has not yet confirmed. Propagation will run on the next
+                    // event after the WIP marker is removed.
+                    ..Default::default()
+                });
+            } else {
+                // PR is not WIP. Only run cleanup if there is stale WIP state to remove
+                // (a WIP label or WIP comment). This avoids unnecessary API calls on
+                // every clean PR event when WIP blocking is enabled.
+                //
+                // The label scan checks COMMON_WIP_LABEL_NAMES (common repo label names
+                // that manage_wip_labels may have applied via auto-discovery) AND the
+                // configured wip_label hint (applied when the exact hint matched the repo
+                // label or was used as direct fallback). Both are needed because either
+                // path may have been used on a previous event.
+                //
+                // If wip_label is None (labeling disabled), no label was ever applied by
+                // this code, so the label scan is skipped entirely.
+                let labeling_enabled = self
+                    .config
+                    .wip_check
+                    .wip_label
+                    .as_deref()
+                    .is_some_and(|s| !s.is_empty());
+
+                let has_stale_wip = if labeling_enabled {
+                    self.provider()
+                        .list_applied_labels(repo_owner, repo_name, pr_number)
+                        .await
+                        .unwrap_or_default()
+                        .iter()
+                        .any(|l| {
+                            labels::COMMON_WIP_LABEL_NAMES
+                                .iter()
+                                .any(|n| l.name.eq_ignore_ascii_case(n))
+                                || self
+                                    .config
+                                    .wip_check
+                                    .wip_label
+                                    .as_deref()
+                                    .filter(|s| !s.is_empty())
+                                    .is_some_and(|hint| l.name == hint)
+                        })
+                } else {
+                    false
+                };
+
+                let has_stale_comment = if has_stale_wip {
+                    true // skip the second API call when we already know cleanup is needed
+                } else {
+                    self.list_guidance_comments(repo_owner, repo_name, pr_number)
+                        .await
+                        .unwrap_or_default()
+                        .iter()
+                        .any(|c| c.body.contains(WIP_COMMENT_MARKER))
+                };
+
+                if has_stale_wip || has_stale_comment {
+                    self.communicate_wip_status(repo_owner, repo_name, &pr, false)
+                        .await;
+                }
+            }
+        }
+
+        // Resolve bypass labels before running the checks they can bypass
+        let label_bypasses = self
+            .resolve_label_bypasses(repo_owner, repo_name, pr_number)
+            .await;
+
+        // Fix the checks that opted in before running them, so they see the fixed PR
+        if self.config.autofix.is_enabled() {
+            self.autofix_pull_request(
+                repo_owner,
+                repo_name,
+                &mut pr,
+                bypass_author.as_ref(),
+                &label_bypasses,
+            )
+            .await;
+        }
+
+        // Fetch PR files unconditionally — needed for both size analysis and config
+        // validation.  This is an intentional trade-off: one extra API call is made per
+        // PR event regardless of whether `pr_size_check` is enabled, in order to support
+        // the config-change validation path without complicating the control flow.
+        let pr_files = self
+            .fetch_pr_files(repo_owner, repo_name, &pr)
+            .await
+            .map_err(|e| {
+                error!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    error = e.to_string(),
+                    "Failed to fetch PR files"
+                );
+                MergeWardenError::GitProviderError("Failed to fetch PR files".to_string())
+            })?;
+
+        // The size is measured from the configured compare base when there is one, so
+        // a stacked PR can be sized as the whole stack rather than its own diff.
+        let size_comparison_files = self
+            .fetch_size_comparison_files(repo_owner, repo_name, &pr)
+            .await;
+        let whitespace_discounted_files = if size_comparison_files.is_none() {
+            self.discount_size_whitespace_changes(repo_owner, repo_name, pr_number, &pr_files)
+                .await
+        } else {
+            None
+        };
+        let size_files = size_comparison_files
+            .as_deref()
+            .or(whitespace_discounted_files.as_deref())
+            .unwrap_or(&pr_files);
+
+        // Run the checks in the configured order. With `short_circuit_on_failure` the
+        // checks after the first blocking failure are not run; they are reported as
+        // skipped and the labels and comments they manage are left unchanged. A failing
+        // report-only check is recorded but neither blocks nor short-circuits.
+        // When the checks run concurrently, the provider calls are made together up
+        // front and each check picks up its result in order, so the outcome is the same.
+        // Each check yields a report, and the conclusion, summary and status table are
+        // built from the reports alone.
+        let check_execution = &self.config.check_execution;
+        let mut concurrent_results = if check_execution.runs_concurrently() {
+            Some(
+                self.run_provider_checks_concurrently(
+                    repo_owner,
+                    repo_name,
+                    &pr,
+                    &pr_files,
+                    bypass_author.as_ref(),
+                    &label_bypasses,
+                )
+                .await,
+            )
+        } else {
+            None
+        };
+        let mut reports = HashMap::with_capacity(config::CheckKind::DEFAULT_ORDER.len());
+        let mut title_outcome = None;
+        let mut work_item_outcome = None;
+        let mut size_exclusion = None;
+        let mut size_info = None;
+        let mut up_to_date_outcome = None;
+        let mut short_circuited_checks = Vec::new();
+        let mut report_only_failures = Vec::new();
+        let mut has_blocking_failure = false;
+        for kind in check_execution.effective_order() {
+            if has_blocking_failure && check_execution.short_circuit_on_failure {
+                short_circuited_checks.push(kind);
+                reports.insert(kind, CheckReport::skipped(kind, SHORT_CIRCUITED_REASON));
+                continue;
+            }
+
+            let mut report = match kind {
+                // Check PR title follows the conventional commit structure if enabled
+                config::CheckKind::Title => {
+                    let result = if self.config.enforce_title_convention {
+                        let result = self.check_title(&pr, bypass_author.as_ref());
+                        match &label_bypasses.title_convention {
+                            Some(user) if !result.validation.was_bypassed() => {
+                                checks::TitleValidationResult {
+                                    validation: validation_result::ValidationResult::bypassed(
+                                        validation_result::BypassInfo {
+                                            rule_type:
+                                                validation_result::BypassRuleType::TitleConvention,
+                                            user: user.login.clone(),
+                                        },
+                                    ),
+                                    diagnosis: None,
+                                }
+                            }
+                            _ => result,
+                        }
+                    } else {
+                        checks::TitleValidationResult {
+                            validation: validation_result::ValidationResult::skipped(
+                                "Title validation is not enabled",
+                            ),
+                            diagnosis: None,
+                        }
+                    };
+                    let report = self.title_report(&result);
+                    title_outcome = Some(result);
+                    report
+                }
+
+                // Check that the PR body has a reference to a work item if enabled
+                config::CheckKind::WorkItem => {
+                    let result = if self.config.enforce_work_item_references {
+                        let mut result =
+                            self.check_work_item_reference(&pr, bypass_author.as_ref());
+                        self.verify_issue_references(repo_owner, repo_name, pr.number, &mut result)
+                            .await;
+                        match &label_bypasses.work_items {
+                            Some(user) if !result.validation.was_bypassed() => {
+                                checks::WorkItemValidationResult::from_validation(
+                                    validation_result::ValidationResult::bypassed(
+                                        validation_result::BypassInfo {
+                                            rule_type:
+                                                validation_result::BypassRuleType::WorkItemReference,
+                                            user: user.login.clone(),
+                                        },
+                                    ),
+                                )
+                            }
+                            _ => result,
+                        }
+                    } else {
+                        checks::WorkItemValidationResult::from_validation(
+                            validation_result::ValidationResult::skipped(
+                                "Work item validation is not enabled",
+                            ),
+                        )
+                    };
+                    let report = self.work_item_report(&result);
+                    work_item_outcome = Some(result);
+                    report
+                }
+
+                // Check that the required checklist items in the PR body are ticked if enabled
+                config::CheckKind::Checklist => {
+                    let result = self
+                        .config
+                        .checklist
+                        .enabled
+                        .then(|| checks::check_required_checklist(&pr, &self.config.checklist));
+                    self.checklist_report(result.as_ref())
+                }
+
+                // Check the number of unresolved review threads if enabled
+                config::CheckKind::ReviewThreads => {
+                    let outcome = match concurrent_results.as_mut() {
+                        Some(results) => results.review_threads.take(),
+                        None => {
+                            self.check_review_threads(repo_owner, repo_name, pr_number)
+                                .await
+                        }
+                    };
+                    self.review_threads_report(outcome.as_ref())
+                }
+
+                // Perform size check when enabled.
+                config::CheckKind::Size => {
+                    let result = if !self.config.pr_size_check.enabled {
+                        validation_result::ValidationResult::skipped("Size checking is not enabled")
+                    } else if let Some(exclusion) = match concurrent_results.as_mut() {
+                        Some(results) => results.size_exclusion.take(),
+                        None => {
+                            self.resolve_size_exclusion(repo_owner, repo_name, &pr)
+                                .await
+                        }
+                    } {
+                        // Excluded PRs are not sized; the exemption is recorded against the author
+                        let bypass_info = validation_result::BypassInfo {
+                            rule_type: validation_result::BypassRuleType::PrSize,
+                            // GitHub shows deleted accounts as `ghost`
+                            user: pr
+                                .author
+                                .as_ref()
+                                .map(|u| u.login.clone())
+                                .unwrap_or_else(|| "ghost".to_string()),
+                        };
+                        info!(
+                            repository_owner = repo_owner,
+                            repository = repo_name,
+                            pull_request = pr_number,
+                            user = bypass_info.user,
+                            exclusion = %exclusion,
+                            "PR matches a size exclusion; skipping size check"
+                        );
+                        size_exclusion = Some(exclusion);
+                        validation_result::ValidationResult::bypassed(bypass_info)
+                    } else {
+                        // An authorised bypass label counts as a bypass by the user who applied it
+                        let bypass_user = label_bypasses.size.as_ref().or(bypass_author.as_ref());
+                        self.check_pr_size(size_files, bypass_user)
+                    };
+                    if self.config.pr_size_check.enabled && size_exclusion.is_none() {
+                        size_info = Some(size::PrSizeInfo::categorize(
+                            size_files,
+                            &self.config.pr_size_check,
+                        ));
+                    }
+                    self.size_report(&result, size_info.as_ref(), size_exclusion.as_ref())
+                }
+
+                // Check that a version change follows semver for the PR's changes if enabled
+                config::CheckKind::VersionBump => {
+                    let outcome = match concurrent_results.as_mut() {
+                        Some(results) => results.version_bump.take(),
+                        None => {
+                            self.check_version_bump(repo_owner, repo_name, &pr, &pr_files)
+                                .await
+                        }
+                    };
+                    self.version_bump_report(outcome.as_ref())
+                }
+
+                // Check that deleted files are accompanied by their paired changes if enabled
+                config::CheckKind::DeletedFiles => {
+                    let result = self.config.deleted_files.enabled.then(|| {
+                        checks::check_deleted_file_pairings(&pr_files, &self.config.deleted_files)
+                    });
+                    self.deleted_files_report(result.as_ref())
+                }
+
+                // Flag files that look like secrets, binaries or are too large if enabled
+                config::CheckKind::SuspiciousFiles => {
+                    let result = self.config.suspicious_files.enabled.then(|| {
+                        checks::check_suspicious_files(&pr_files, &self.config.suspicious_files)
+                    });
+                    self.suspicious_files_report(result.as_ref())
+                }
+
+                // Check that the PR targets a base branch allowed for its type if enabled
+                config::CheckKind::BaseBranch => {
+                    let result = self
+                        .config
+                        .base_branch
+                        .enabled
+                        .then(|| checks::check_base_branch(&pr, &self.config.base_branch));
+                    self.base_branch_report(result.as_ref())
+                }
+
+                // Check that the PR has at most one label of each exclusive group if enabled
+                config::CheckKind::ExclusiveLabels => {
+                    let result = match concurrent_results.as_mut() {
+                        Some(results) => results.exclusive_labels.take(),
+                        None => {
+                            self.check_exclusive_labels(repo_owner, repo_name, pr_number)
+                                .await
+                        }
+                    };
+                    self.exclusive_labels_report(result.as_ref())
+                }
+
+                // Check that every commit has a verified signature if enabled
+                config::CheckKind::SignedCommits => {
+                    let outcome = match concurrent_results.as_mut() {
+                        Some(results) => results.signed_commits.take(),
+                        None => {
+                            self.check_signed_commits(
+                                repo_owner,
+                                repo_name,
+                                &pr,
+                                bypass_author.as_ref(),
+                                &label_bypasses,
+                            )
+                            .await
+                        }
+                    };
+                    self.signed_commits_report(outcome.as_ref())
+                }
+
+                // Check that a revert PR references what it reverts if enabled
+                config::CheckKind::RevertReference => {
+                    let result = self.config.revert_reference.enabled.then(|| {
+                        // An authorised bypass label counts as a bypass by the user who applied it
+                        let bypass_user = label_bypasses
+                            .revert_reference
+                            .as_ref()
+                            .or(bypass_author.as_ref());
+                        checks::check_revert_reference(
+                            &pr,
+                            bypass_user,
+                            self.config.bypass_rules.revert_reference(),
+                        )
+                    });
+                    self.revert_reference_report(result.as_ref())
+                }
+
+                // Check that the PR is not too far behind its base branch if enabled
+                config::CheckKind::UpToDate => {
+                    let outcome = match concurrent_results.as_mut() {
+                        Some(results) => results.up_to_date.take(),
+                        None => self.check_up_to_date(repo_owner, repo_name, &pr).await,
+                    };
+                    let report = self.up_to_date_report(outcome.as_ref());
+                    up_to_date_outcome = outcome;
+                    report
+                }
+
+                // Check that the required footers are in the PR body if enabled
+                config::CheckKind::Footers => {
+                    let result = self
+                        .config
+                        .footers
+                        .enabled
+                        .then(|| checks::check_required_footers(&pr, &self.config.footers));
+                    self.footers_report(result.as_ref())
+                }
+
+                // Check that the PR title is more than the branch name if enabled
+                config::CheckKind::DescriptiveTitle => {
+                    let result = self
+                        .config
+                        .descriptive_title
+                        .enabled
+                        .then(|| checks::check_descriptive_title(&pr));
+                    self.descriptive_title_report(result.as_ref())
+                }
+
+                // Check that the PR does not mix too many change types if enabled
+                config::CheckKind::ChangeTypes => {
+                    let outcome = match concurrent_results.as_mut() {
+                        Some(results) => results.change_types.take(),
+                        None => self.check_change_types(repo_owner, repo_name, &pr).await,
+                    };
+                    self.change_types_report(outcome.as_ref())
+                }
+
+                // Check that the PR has the required labels and no forbidden ones if enabled
+                config::CheckKind::LabelPolicy => {
+                    let result = match concurrent_results.as_mut() {
+                        Some(results) => results.label_policy.take(),
+                        None => {
+                            self.check_label_policy(repo_owner, repo_name, pr_number)
+                                .await
+                        }
+                    };
+                    self.label_policy_report(result.as_ref())
+                }
+            };
+
+            if report.fails() {
+                if check_execution.is_report_only(kind) {
+                    // Recorded for trial metrics: the rule would have failed this PR
+                    info!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        check = ?kind,
+                        report_only = true,
+                        "Report-only check would have failed"
+                    );
+                    report.mark_report_only();
+                    report_only_failures.push(kind);
+                } else {
+                    has_blocking_failure = true;
+                }
+            }
+            reports.insert(kind, report);
+        }
+
+        if !short_circuited_checks.is_empty() {
+            info!(
+                repository_owner = repo_owner,
+                repository = repo_name,
+                pull_request = pr_number,
+                skipped = ?short_circuited_checks,
+                "Skipped checks after a blocking failure"
+            );
+        }
+
+        // Checks that did not run count as passing, but are marked as skipped
+        let title_result = title_outcome.unwrap_or_else(|| checks::TitleValidationResult {
+            validation: validation_result::ValidationResult::skipped(SHORT_CIRCUITED_REASON),
+            diagnosis: None,
+        });
+        let work_item_result = work_item_outcome.unwrap_or_else(|| {
+            checks::WorkItemValidationResult::from_validation(
+                validation_result::ValidationResult::skipped(SHORT_CIRCUITED_REASON),
+            )
+        });
+
+        // Validate .github/merge-warden.toml when it is part of the PR.
+        if pr_files.iter().any(|f| f.filename == CONFIG_FILE_PATH) {
+            if pr.head_sha.is_empty() {
+                warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    "head_sha is empty; skipping config file validation"
+                );
+            } else {
+                match self
+                    .provider()
+                    .fetch_config_at_ref(repo_owner, repo_name, CONFIG_FILE_PATH, &pr.head_sha)
+                    .await
+                {
+                    Ok(Some(content)) => {
+                        let outcome = validate_config_content(&content);
+                        self.communicate_config_validity_status(
+                            repo_owner, repo_name, &pr, &outcome,
+                        )
+                        .await;
+                    }
+                    Ok(None) => {
+                        // File absent at head SHA — the config file was likely deleted in
+                        // this PR.  Treat as valid (no config to check) and clean up any
+                        // stale error comment left from a previous push.
+                        debug!(
+                            repository_owner = repo_owner,
+                            repository = repo_name,
+                            pull_request = pr_number,
+                            "Config file not found at head SHA; cleaning up stale comment"
+                        );
+                        let valid_outcome = ConfigValidationOutcome {
+                            valid: true,
+                            errors: vec![],
+                        };
+                        self.communicate_config_validity_status(
+                            repo_owner,
+                            repo_name,
+                            &pr,
+                            &valid_outcome,
+                        )
+                        .await;
+                    }
+                    Err(e) => {
+                        warn!(
+                            repository_owner = repo_owner,
+                            repository = repo_name,
+                            pull_request = pr_number,
+                            error = %e,
+                            "Failed to fetch config file for validation; skipping comment"
+                        );
+                    }
+                }
+            }
+        }
+
+        // Collect bypass information for audit trail
+        let bypasses_used = config::CheckKind::DEFAULT_ORDER
+            .iter()
+            .filter_map(|kind| reports[kind].bypass.clone())
+            .collect::<Vec<_>>();
+
+        // Report-only failures count as passing so that they never block the PR
+        let passes = |kind| reports[&kind].passes();
+        let is_report_only_failure = |kind| report_only_failures.contains(&kind);
+
+        // Record why each check that did not run was skipped
+        let skip_reasons = check_execution
+            .effective_order()
+            .into_iter()
+            .filter_map(|kind| {
+                reports[&kind]
+                    .skip_reason
+                    .clone()
+                    .map(|reason| (kind, reason))
+            })
+            .collect::<Vec<_>>();
+
+        // Apply labels and comments based on the title validation results
+        let title_message = if short_circuited_checks.contains(&config::CheckKind::Title)
+            || is_report_only_failure(config::CheckKind::Title)
+        {
+            String::new()
+        } else if title_result.bypass_info().is_some() {
+            "Title validation bypassed".to_string()
+        } else {
+            self.communicate_pr_title_validity_status(repo_owner, repo_name, &pr, &title_result)
+                .await
+        };
+
+        // Apply labels and comment based on the work item validation results
+        let work_item_message = if short_circuited_checks.contains(&config::CheckKind::WorkItem)
+            || is_report_only_failure(config::CheckKind::WorkItem)
+        {
+            String::new()
+        } else if work_item_result.bypass_info().is_some() {
+            "Work item validation bypassed".to_string()
+        } else {
+            self.communicate_pr_work_item_validity_status(
+                repo_owner,
+                repo_name,
+                &pr,
+                &work_item_result,
+            )
+            .await
+        };
+
+        // Handle size labeling and comments if size checking is enabled
+        let size_message = if size_info.is_some() {
+            self.communicate_pr_size_status(repo_owner, repo_name, pr_number, size_files)
+                .await
+        } else if let Some(exclusion) = &size_exclusion {
+            format!("PR size: not checked (excluded by {exclusion})")
+        } else {
+            String::new()
+        };
+
+        // Determine labels with enhanced error handling and monitoring
+        let (label_result, label_detection_failed) =
+            match self.determine_labels(repo_owner, repo_name, &pr).await {
+                Ok(result) => {
+                    let failed = !result.is_success();
+                    (result, failed)
+                }
+                Err(e) => {
+                    warn!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pr_number = pr_number,
+                        error = %e,
+                        "Label determination failed, continuing with empty labels"
+                    );
+                    (labels::LabelManagementResult::new(), true)
+                }
+            };
+        let mut labels = label_result.applied_labels;
+
+        // Label dependency updates, including human-authored ones
+        if let Some(label) = self
+            .communicate_dependency_update_status(repo_owner, repo_name, &pr, &pr_files)
+            .await
+        {
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+
+        // Label PRs that are behind their base; a report-only trial leaves the label alone
+        if let Some(UpToDateOutcome::Checked(result)) = up_to_date_outcome
+            .as_ref()
+            .filter(|_| !check_execution.is_report_only(config::CheckKind::UpToDate))
+        {
+            if let Some(label) = self
+                .communicate_up_to_date_status(repo_owner, repo_name, pr_number, result)
+                .await
+            {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+        }
+
+        // Generate smart label status message for check reporting
+        let use_emoji = self.config.check_summary.use_emoji;
+        let smart_label_message = if let Some(change_type_labels) = &self.config.change_type_labels
+        {
+            if !labels.is_empty() {
+                format!(
+                    "{} **Smart Label Detection**: Applied {} label(s): {}",
+                    summary::CheckStatus::Passed.icon(use_emoji),
+                    labels.len(),
+                    labels.join(", ")
+                )
+            } else if label_detection_failed {
+                format!(
+                    "{} **Smart Label Detection**: Label detection failed, so no labels were applied",
+                    Self::severity_marker(config::CheckSeverity::Warning, use_emoji)
+                )
+            } else if change_type_labels.reports_no_labels() {
+                format!(
+                    "{} **Smart Label Detection**: No labels apply to this pull request",
+                    Self::severity_marker(config::CheckSeverity::Notice, use_emoji)
+                )
+            } else {
+                String::new()
+            }
+        } else if !labels.is_empty() {
+            format!(
+                "{}**Legacy Labeling**: Applied {} label(s): {}",
+                if use_emoji { "📋 " } else { "" },
+                labels.len(),
+                labels.join(", ")
+            )
+        } else {
+            String::new()
+        };
+
+        // Determine check conclusion:
+        // - "success" when all validations pass
+        // - "neutral" when a draft PR has validation failures (non-blocking — developers
+        //   can correct issues before converting to ready-for-review)
+        // - "failure" for non-draft PRs with validation failures
+        let failing_rules = check_execution
+            .effective_order()
+            .into_iter()
+            .filter(|kind| !reports[kind].passes() && reports[kind].blocking)
+            .collect::<Vec<_>>();
+        let all_valid = failing_rules.is_empty();
+        let check_conclusion = if all_valid && report_only_failures.is_empty() {
+            "success"
+        } else if all_valid {
+            // Report-only failures are surfaced without blocking the PR
+            "neutral"
+        } else if pr.draft {
+            // Draft PRs are never hard-blocked: use "neutral" so required checks don't
+            // prevent the PR from being ready-for-review once the author fixes the issues.
+            "neutral"
+        } else {
+            "failure"
+        };
+
+        // Enhanced check summary that includes all validation results and bypass information
+        let all_checks_passed = reports.values().all(CheckReport::passes);
+        let issues = config::CheckKind::DEFAULT_ORDER
+            .iter()
+            .map(|kind| &reports[kind])
+            .filter(|report| !report.passes())
+            .filter_map(|report| report.issue.clone())
+            .collect::<Vec<_>>();
+
+        let check_summary = if all_checks_passed {
+            let base = if bypasses_used.is_empty() {
+                "All PR requirements satisfied.".to_string()
+            } else {
+                match bypasses_used.len() {
+                    1 => "All PR requirements satisfied (1 validation bypassed).".to_string(),
+                    n => format!(
+                        "All PR requirements satisfied ({} validations bypassed).",
+                        n
+                    ),
+                }
+            };
+            let base = match report_only_failures.as_slice() {
+                [] => base,
+                kinds => format!(
+                    "{base} Report-only checks that would fail: {}.",
+                    kinds
+                        .iter()
+                        .map(config::CheckKind::summary_name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+            if pr.draft {
+                format!("{base} (Draft mode \u{2014} validation is non-blocking.)")
+            } else {
+                base
+            }
+        } else {
+            let issue_text = match issues.as_slice() {
+                [only] => format!("PR {}.", only),
+                [first, second] => format!("PR {} and {}.", first, second),
+                [rest @ .., last] => format!("PR {}, and {}.", rest.join(", "), last),
+                [] => "PR requirements are not satisfied.".to_string(),
+            };
+            if pr.draft {
+                format!("{issue_text} (Draft mode \u{2014} issues shown for early feedback; validation is non-blocking.)")
+            } else {
+                issue_text
+            }
+        };
+        let check_summary = match self
+            .config
+            .check_summary
+            .templates
+            .template_for(all_checks_passed, &failing_rules)
+        {
+            Some(template) => {
+                let context = templates::CheckSummaryContext {
+                    passed: all_checks_passed,
+                    failing_rules: failing_rules
+                        .iter()
+                        .map(|kind| kind.key().to_string())
+                        .collect(),
+                    failing_checks: failing_rules
+                        .iter()
+                        .map(|kind| kind.summary_name().to_string())
+                        .collect(),
+                    issues,
+                    report_only_failures: report_only_failures
+                        .iter()
+                        .map(|kind| kind.summary_name().to_string())
+                        .collect(),
+                    bypass_count: bypasses_used.len(),
+                    draft: pr.draft,
+                    default_summary: check_summary,
+                };
+                Self::render_check_summary_template(
+                    repo_owner, repo_name, pr_number, template, context,
+                )
+            }
+            None => check_summary,
+        };
+
+        // Propagate issue metadata (milestone / projects) to the PR when an
+        // IssueMetadataProvider has been attached via with_issue_provider.
+        // Runs after all validation and labelling, immediately before the final
+        // check-status update. Failures are non-fatal and logged at warn level.
+        if let Some(issue_prov) = &self.issue_provider {
+            self.propagate_issue_metadata(repo_owner, repo_name, &pr, issue_prov.as_ref())
+                .await;
+        } else if self.config.issue_propagation.sync_milestone_from_issue
+            || self.config.issue_propagation.sync_project_from_issue
+        {
+            warn!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr.number,
+                "Issue propagation configured but no IssueMetadataProvider is attached; skipping"
+            );
+        }
+
+        // Per-check status table, shown at the top of the check output
+        let summary_rows = config::CheckKind::DEFAULT_ORDER
+            .iter()
+            .map(|kind| reports[kind].row.clone())
+            .collect::<Vec<_>>();
+        self.decision_recorder
+            .record(repo_owner, repo_name, pr_number, |log| {
+                log.record_checks(&summary_rows);
+            });
+        let summary_table =
+            summary::render_check_summary_table(&summary_rows, &self.config.check_summary);
+        let check_summary = if all_checks_passed && self.config.check_summary.list_checks_on_success
+        {
+            format!(
+                "{check_summary}\n\n{}",
+                summary::render_check_summary_line(&summary_rows, &self.config.check_summary)
+            )
+        } else {
+            check_summary
+        };
+
+        // Smart text formatting that includes all messages with separators when content exists
+        let text = {
+            let mut messages = Vec::new();
+            if !summary_table.is_empty() {
+                messages.push(summary_table);
+            }
+            if let Some(bypass_message) = Self::format_bypass_message(&bypasses_used, use_emoji) {
+                messages.push(bypass_message);
+            }
+            if !title_message.is_empty() {
+                messages.push(title_message);
+            }
+            if !work_item_message.is_empty() {
+                messages.push(work_item_message);
+            }
+            if !size_message.is_empty() {
+                messages.push(size_message);
+            }
+            if let Some(size_info) = size_info
+                .as_ref()
+                .filter(|_| self.config.pr_size_check.details_in_check_output)
+            {
+                messages.push(Self::format_size_details(
+                    size_info,
+                    &self.config.pr_size_check.category_names,
+                    use_emoji,
+                ));
+            }
+            if let Some(size_info) = size_info
+                .as_ref()
+                .filter(|_| self.config.pr_size_check.review_effort.enabled)
+            {
+                messages.push(Self::format_review_effort_message(
+                    &size_info.review_effort(&self.config.pr_size_check.review_effort.weights),
+                ));
+            }
+            for kind in config::CheckKind::DEFAULT_ORDER {
+                if let Some(details) = &reports[&kind].details {
+                    messages.push(details.clone());
+                }
+            }
+            if !smart_label_message.is_empty() {
+                messages.push(smart_label_message);
+            }
+            if !label_result.skipped_labels.is_empty() {
+                messages.push(format!(
+                    "{} **Label Creation**: Skipped {} label(s) that do not exist in the repository: {}",
+                    Self::severity_marker(config::CheckSeverity::Warning, use_emoji),
+                    label_result.skipped_labels.len(),
+                    label_result.skipped_labels.join(", ")
+                ));
+            }
+            if self.config.show_config_source {
+                messages.push(format!("_{}_", self.config.config_source));
+            }
+            messages.join("\n\n---\n\n")
+        };
+        self.post_check_run(
+            repo_owner,
+            repo_name,
+            pr_number,
+            check_conclusion,
+            check_title,
+            &check_summary,
+            &text,
+        )
+        .await?;
+        if self.config.comments.transition_summary {
+            self.communicate_conclusion_transition(
+                repo_owner,
+                repo_name,
+                pr_number,
+                check_conclusion,
+                &check_summary,
+            )
+            .await;
+        }
+        // Only ready-for-review PRs conclude "failure"; drafts are reported as neutral
+        if self.config.draft_conversion.enabled && check_conclusion == "failure" {
+            self.convert_failing_pull_request_to_draft(
+                repo_owner,
+                repo_name,
+                pr_number,
+                &failing_rules,
+            )
+            .await;
+        }
+        Ok(CheckResult {
+            title_valid: passes(config::CheckKind::Title),
+            work_item_referenced: passes(config::CheckKind::WorkItem),
+            size_valid: passes(config::CheckKind::Size),
+            checklist_complete: passes(config::CheckKind::Checklist),
+            review_threads_resolved: passes(config::CheckKind::ReviewThreads),
+            deleted_files_paired: passes(config::CheckKind::DeletedFiles),
+            version_bump_valid: passes(config::CheckKind::VersionBump),
+            suspicious_files_absent: passes(config::CheckKind::SuspiciousFiles),
+            base_branch_valid: passes(config::CheckKind::BaseBranch),
+            labels_exclusive: passes(config::CheckKind::ExclusiveLabels),
+            commits_signed: passes(config::CheckKind::SignedCommits),
+            revert_referenced: passes(config::CheckKind::RevertReference),
+            up_to_date: passes(config::CheckKind::UpToDate),
+            footers_present: passes(config::CheckKind::Footers),
+            title_descriptive: passes(config::CheckKind::DescriptiveTitle),
+            change_types_within_limit: passes(config::CheckKind::ChangeTypes),
+            label_policy_valid: passes(config::CheckKind::LabelPolicy),
+            skipped_checks: short_circuited_checks,
+            skip_reasons,
+            report_only_failures,
+            failing_rules,
+            labels,
+            bypasses_used,
+            check_summary,
+            check_text: text,
+            ..Default::default()
+        })
+    }
+
+    /// Processes a pull request and returns a [`DecisionLog`] of the run alongside the
+    /// [`CheckResult`].
+    ///
+    /// The pull request is processed as by [`MergeWarden::process_pull_request`]. The
+    /// log records the configuration source, the pull request as fetched, the outcome
+    /// of each check, the check run reported and the labels and top-level comments that
+    /// changed. The label and comment changes are found by listing them before and
+    /// after the run, which costs four extra API calls; when a list fails, the changes
+    /// it would show are left empty.
+    ///
+    /// Runs on other pull requests are not recorded, so the same `MergeWarden` can
+    /// process other pull requests at the same time. Two runs on the same pull request
+    /// must not overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository (e.g., "octocat")
+    /// * `repo_name` - The name of the repository (e.g., "hello-world")
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`MergeWarden::process_pull_request`].
+    #[instrument]
+    pub async fn process_pull_request_with_decision_log(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<(CheckResult, DecisionLog), MergeWardenError> {
+        let target = PullRequestTarget::from((repo_owner, repo_name, pr_number));
+        let labels_before = self
+            .provider()
+            .list_applied_labels(repo_owner, repo_name, pr_number)
+            .await
+            .ok();
+        let comments_before = self
+            .provider()
+            .list_comments(repo_owner, repo_name, pr_number)
+            .await
+            .ok();
+
+        self.decision_recorder
+            .start(target.clone(), &self.config.config_source);
+        let result = self
+            .process_pull_request(repo_owner, repo_name, pr_number)
+            .await;
+        let mut log = self
+            .decision_recorder
+            .finish(&target)
+            .unwrap_or_else(|| DecisionLog::new(target, &self.config.config_source));
+        let result = result?;
+
+        if let (Some(before), Ok(after)) = (
+            labels_before,
+            self.provider()
+                .list_applied_labels(repo_owner, repo_name, pr_number)
+                .await,
+        ) {
+            log.record_label_changes(&before, &after);
+        }
+        if let (Some(before), Ok(after)) = (
+            comments_before,
+            self.provider()
+                .list_comments(repo_owner, repo_name, pr_number)
+                .await,
+        ) {
+            log.record_comment_changes(&before, &after);
+        }
+
+        Ok((result, log))
+    }
+
+    /// Processes a pull request without changing it, and returns the changes that would
+    /// have been made in [`CheckResult::planned_operations`].
+    ///
+    /// The pull request is validated as by [`MergeWarden::process_pull_request`], but
+    /// labels, comments, check runs, pull request updates, milestones and projects are
+    /// not changed on the platform. See [`dry_run`] for how the planned operations are
+    /// recorded.
+    ///
+    /// Runs on other pull requests still apply their changes, so the same
+    /// `MergeWarden` can process other pull requests at the same time; only a label the
+    /// repository is missing is not created while a dry run on one of its pull requests
+    /// is in progress. Two runs on the same pull request must not overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository (e.g., "octocat")
+    /// * `repo_name` - The name of the repository (e.g., "hello-world")
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`MergeWarden::process_pull_request`].
+    #[instrument]
+    pub async fn process_pull_request_dry_run(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<CheckResult, MergeWardenError> {
+        self.plan_run(
+            repo_owner,
+            repo_name,
+            pr_number,
+            self.process_pull_request(repo_owner, repo_name, pr_number),
+        )
+        .await
+    }
+
+    /// Processes a reopened pull request without changing it, and returns the changes
+    /// that would have been made in [`CheckResult::planned_operations`].
+    ///
+    /// This is the dry run of [`MergeWarden::process_reopened_pull_request`], so the
+    /// removal of the artifacts left before the pull request was closed is planned as
+    /// well. See [`MergeWarden::process_pull_request_dry_run`] for what is planned
+    /// instead of applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository (e.g., "octocat")
+    /// * `repo_name` - The name of the repository (e.g., "hello-world")
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`MergeWarden::process_reopened_pull_request`].
+    #[instrument]
+    pub async fn process_reopened_pull_request_dry_run(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<CheckResult, MergeWardenError> {
+        self.plan_run(
+            repo_owner,
+            repo_name,
+            pr_number,
+            self.process_reopened_pull_request(repo_owner, repo_name, pr_number),
+        )
+        .await
+    }
+
+    /// Runs `run` as a dry run for a pull request and adds the operations it planned to
+    /// its result.
+    async fn plan_run(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        run: impl std::future::Future<Output = Result<CheckResult, MergeWardenError>>,
+    ) -> Result<CheckResult, MergeWardenError> {
+        let target = PullRequestTarget::from((repo_owner, repo_name, pr_number));
+        self.dry_run_recorder.start(target.clone());
+        let result = run.await;
+        let planned_operations = self.dry_run_recorder.finish(&target);
+
+        let mut result = result?;
+        result.planned_operations = planned_operations;
+        Ok(result)
+    }
+
+    /// Processes a pull request that was reopened.
+    ///
+    /// A reopened pull request is validated again in full by
+    /// [`MergeWarden::process_pull_request`]. When `reopened.reset_artifacts` is
+    /// enabled, the validation comments and status labels left on the pull request
+    /// before it was closed are removed first, so that only the labels and comments
+    /// of the new validation remain.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository (e.g., "octocat")
+    /// * `repo_name` - The name of the repository (e.g., "hello-world")
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `CheckResult` with the validation results
+    #[instrument]
+    pub async fn process_reopened_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<CheckResult, MergeWardenError> {
+        if self.config.reopened.reset_artifacts {
+            self.remove_validation_artifacts(repo_owner, repo_name, pr_number)
+                .await;
         }
 
-        // Extract validity flags for downstream logic
-        let is_title_valid = title_result.is_valid();
-        let is_work_item_referenced = work_item_result.is_valid();
-        let is_size_valid = size_result.is_valid();
+        self.process_pull_request(repo_owner, repo_name, pr_number)
+            .await
+    }
 
-        // Apply labels and comments based on the title validation results
-        let title_message = if title_result.bypass_info().is_some() {
-            "Title validation bypassed".to_string()
-        } else {
-            self.communicate_pr_title_validity_status(repo_owner, repo_name, &pr, &title_result)
-                .await
-        };
+    /// Processes a pull request in a merge queue and reports the result on its merge group.
+    ///
+    /// The pull request is evaluated as by [`MergeWarden::process_pull_request_dry_run`],
+    /// so its labels, comments, check run and state are left as they are, and only the
+    /// check run on `head_sha`, the merge group commit GitHub validates the queue on, is
+    /// posted. Its conclusion is `failure` when [`CheckResult::blocks_merge`] and
+    /// `success` otherwise, since the queue only waits for a passing check. The check run
+    /// uses the same name or names as the one posted for the pull request.
+    ///
+    /// The changes the evaluation would have made to the pull request are discarded, so
+    /// [`CheckResult::planned_operations`] is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository (e.g., "octocat")
+    /// * `repo_name` - The name of the repository (e.g., "hello-world")
+    /// * `pr_number` - The pull request the merge group was created for
+    /// * `head_sha` - The SHA of the merge group commit
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`MergeWarden::process_pull_request_dry_run`], or
+    /// [`MergeWardenError::FailedToUpdatePullRequest`] when the check run cannot be
+    /// posted on the merge group, including on platforms without merge queues.
+    #[instrument]
+    pub async fn process_merge_group(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        head_sha: &str,
+    ) -> Result<CheckResult, MergeWardenError> {
+        let mut result = self
+            .process_pull_request_dry_run(repo_owner, repo_name, pr_number)
+            .await?;
+        result.planned_operations.clear();
 
-        // Apply labels and comment based on the work item validation results
-        let work_item_message = if work_item_result.bypass_info().is_some() {
-            "Work item validation bypassed".to_string()
+        let conclusion = if result.blocks_merge() {
+            "failure"
         } else {
-            self.communicate_pr_work_item_validity_status(
-                repo_owner,
-                repo_name,
-                &pr,
-                &work_item_result,
-            )
-            .await
+            "success"
         };
+        let default_name = merge_warden_developer_platforms::DEFAULT_CHECK_RUN_NAME;
+        let check_summary = &self.config.check_summary;
+        let mut check_names = vec![check_summary
+            .check_run_name
+            .as_deref()
+            .unwrap_or(default_name)];
+        if check_names[0] != default_name && check_summary.keep_legacy_check_run {
+            check_names.push(default_name);
+        }
 
-        // Handle size labeling and comments if size checking is enabled
-        let size_message = if self.config.pr_size_check.enabled {
-            self.communicate_pr_size_status(repo_owner, repo_name, pr_number, &pr_files)
+        for check_name in check_names {
+            self.provider()
+                .update_commit_check_status(
+                    repo_owner,
+                    repo_name,
+                    head_sha,
+                    check_name,
+                    conclusion,
+                    "Merge Warden",
+                    &result.check_summary,
+                    &result.check_text,
+                )
                 .await
-        } else {
-            String::new()
-        };
+                .map_err(|e| {
+                    error!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        head_sha,
+                        check_name,
+                        error = e.to_string(),
+                        "Failed to report the check run on the merge group"
+                    );
+                    MergeWardenError::FailedToUpdatePullRequest(
+                        "Failed to report the check run on the merge group".to_string(),
+                    )
+                })?;
+        }
 
-        // Determine labels with enhanced error handling and monitoring
-        let labels = self
-            .determine_labels(repo_owner, repo_name, &pr)
+        info!(
+            repository_owner = repo_owner,
+            repository = repo_name,
+            pull_request = pr_number,
+            head_sha,
+            conclusion,
+            "Reported check run on the merge group"
+        );
+
+        Ok(result)
+    }
+
+    /// Removes the validation comments and status labels Merge Warden added to a PR.
+    ///
+    /// Failures are logged and otherwise ignored; the following validation adds back
+    /// whatever still applies.
+    async fn remove_validation_artifacts(&self, repo_owner: &str, repo_name: &str, pr_number: u64) {
+        let markers = [
+            TITLE_COMMENT_MARKER,
+            WORK_ITEM_COMMENT_MARKER,
+            SIZE_COMMENT_MARKER,
+            WIP_COMMENT_MARKER,
+            CONFIG_COMMENT_MARKER,
+        ];
+        let comments = self
+            .list_guidance_comments(repo_owner, repo_name, pr_number)
             .await
-            .unwrap_or_else(|e| {
+            .unwrap_or_default();
+        for comment in comments
+            .iter()
+            .filter(|c| markers.iter().any(|m| c.body.contains(m)))
+        {
+            if let Err(e) = self
+                .delete_guidance_comment(repo_owner, repo_name, comment.id)
+                .await
+            {
                 warn!(
                     repository_owner = repo_owner,
                     repository = repo_name,
-                    pr_number = pr_number,
-                    error = %e,
-                    "Label determination failed, continuing with empty labels"
+                    pull_request = pr_number,
+                    comment_id = comment.id,
+                    error = e.to_string(),
+                    "Failed to remove validation comment from reopened pull request"
                 );
-                Vec::new()
-            });
-
-        // Generate smart label status message for check reporting
-        let smart_label_message = if self.config.change_type_labels.is_some() {
-            if labels.is_empty() {
-                "⚠️ **Smart Label Detection**: No labels applied (detection may have failed or no matching labels found)".to_string()
-            } else {
-                format!(
-                    "✅ **Smart Label Detection**: Applied {} label(s): {}",
-                    labels.len(),
-                    labels.join(", ")
-                )
             }
-        } else if !labels.is_empty() {
-            format!(
-                "📋 **Legacy Labeling**: Applied {} label(s): {}",
-                labels.len(),
-                labels.join(", ")
-            )
-        } else {
-            String::new()
-        };
-
-        // Determine check conclusion:
-        // - "success" when all validations pass
-        // - "neutral" when a draft PR has validation failures (non-blocking — developers
-        //   can correct issues before converting to ready-for-review)
-        // - "failure" for non-draft PRs with validation failures
-        let should_fail_on_size = if self.config.pr_size_check.enabled {
-            let size_info = crate::size::PrSizeInfo::from_files_with_exclusions(
-                &pr_files,
-                &self.config.pr_size_check.get_effective_thresholds(),
-                &self.config.pr_size_check.excluded_file_patterns,
-                self.config.pr_size_check.ignore_deletions,
-            );
-            self.config.pr_size_check.fail_on_oversized && size_info.is_oversized()
-        } else {
-            false
-        };
-
-        let all_valid =
-            is_title_valid && is_work_item_referenced && (is_size_valid || !should_fail_on_size);
-        let check_conclusion = if all_valid {
-            "success"
-        } else if pr.draft {
-            // Draft PRs are never hard-blocked: use "neutral" so required checks don't
-            // prevent the PR from being ready-for-review once the author fixes the issues.
-            "neutral"
-        } else {
-            "failure"
-        };
+        }
 
-        // Enhanced check summary that includes all validation results and bypass information
-        let check_summary = if is_title_valid && is_work_item_referenced && is_size_valid {
-            let base = if bypasses_used.is_empty() {
-                "All PR requirements satisfied.".to_string()
-            } else {
-                match bypasses_used.len() {
-                    1 => "All PR requirements satisfied (1 validation bypassed).".to_string(),
-                    n => format!(
-                        "All PR requirements satisfied ({} validations bypassed).",
-                        n
-                    ),
-                }
-            };
-            if pr.draft {
-                format!("{base} (Draft mode \u{2014} validation is non-blocking.)")
+        // Labels that people manage by hand are left alone.
+        let (invalid_title_label, missing_work_item_label) =
+            if self.config.status_labels.remove_on_pass {
+                (
+                    self.config.invalid_title_label.as_deref(),
+                    self.config.missing_work_item_label.as_deref(),
+                )
             } else {
-                base
-            }
-        } else {
-            let mut issues = Vec::new();
-            if !is_title_valid {
-                issues.push("title is invalid");
-            }
-            if !is_work_item_referenced {
-                issues.push("work item reference is missing");
-            }
-            if !is_size_valid {
-                issues.push("PR size exceeds threshold");
-            }
-
-            let issue_text = match issues.len() {
-                1 => format!("PR {}.", issues[0]),
-                2 => format!("PR {} and {}.", issues[0], issues[1]),
-                _ => format!("PR {}, {}, and {}.", issues[0], issues[1], issues[2]),
+                (None, None)
             };
-            if pr.draft {
-                format!("{issue_text} (Draft mode \u{2014} issues shown for early feedback; validation is non-blocking.)")
-            } else {
-                issue_text
-            }
-        };
-
-        // Propagate issue metadata (milestone / projects) to the PR when an
-        // IssueMetadataProvider has been attached via with_issue_provider.
-        // Runs after all validation and labelling, immediately before the final
-        // check-status update. Failures are non-fatal and logged at warn level.
-        if let Some(issue_prov) = &self.issue_provider {
-            self.propagate_issue_metadata(repo_owner, repo_name, &pr, issue_prov.as_ref())
-                .await;
-        } else if self.config.issue_propagation.sync_milestone_from_issue
-            || self.config.issue_propagation.sync_project_from_issue
-        {
-            warn!(
-                owner = repo_owner,
-                repo = repo_name,
-                pr = pr.number,
-                "Issue propagation configured but no IssueMetadataProvider is attached; skipping"
-            );
-        }
-
-        // Smart text formatting that includes all messages with separators when content exists
-        let text = {
-            let mut messages = Vec::new();
-            if !title_message.is_empty() {
-                messages.push(title_message);
-            }
-            if !work_item_message.is_empty() {
-                messages.push(work_item_message);
-            }
-            if !size_message.is_empty() {
-                messages.push(size_message);
-            }
-            if !smart_label_message.is_empty() {
-                messages.push(smart_label_message);
-            }
-            messages.join("\n\n---\n\n")
-        };
-        self.provider
-            .update_pr_check_status(
-                repo_owner,
-                repo_name,
-                pr_number,
-                check_conclusion,
-                check_title,
-                &check_summary,
-                &text,
-            )
+        let status_labels = [
+            invalid_title_label,
+            missing_work_item_label,
+            self.config.wip_check.wip_label.as_deref(),
+        ];
+        let applied = self
+            .provider()
+            .list_applied_labels(repo_owner, repo_name, pr_number)
             .await
-            .map_err(|e| {
-                error!(
+            .unwrap_or_default();
+        for label in applied
+            .iter()
+            .filter(|l| status_labels.contains(&Some(l.name.as_str())))
+        {
+            if let Err(e) = self
+                .provider()
+                .remove_label(repo_owner, repo_name, pr_number, &label.name)
+                .await
+            {
+                warn!(
                     repository_owner = repo_owner,
                     repository = repo_name,
                     pull_request = pr_number,
+                    label = label.name,
                     error = e.to_string(),
-                    "Failed to add or update GitHub check run"
+                    "Failed to remove status label from reopened pull request"
                 );
-                MergeWardenError::FailedToUpdatePullRequest(
-                    "Failed to add or update GitHub check run".to_string(),
-                )
-            })?;
-        Ok(CheckResult {
-            title_valid: is_title_valid,
-            work_item_referenced: is_work_item_referenced,
-            size_valid: is_size_valid,
-            wip_detected: false,
-            labels,
-            bypasses_used,
-        })
+            }
+        }
+
+        info!(
+            repository_owner = repo_owner,
+            repository = repo_name,
+            pull_request = pr_number,
+            "Removed validation artifacts from reopened pull request"
+        );
+    }
+
+    /// Processes many pull requests, yielding each result as soon as it completes.
+    ///
+    /// At most `max_concurrency` pull requests are processed at a time; a value of `0`
+    /// is treated as `1`. Targets are only pulled from `targets` as capacity frees up
+    /// and results are consumed, so a slow consumer applies backpressure and the
+    /// targets never need to be collected in memory. Results are yielded in completion
+    /// order, not input order.
+    ///
+    /// A pull request that fails to process does not stop the batch: its error is
+    /// yielded in [`BatchProcessResult::result`] and the remaining pull requests are
+    /// still processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets` - The pull requests to process, as [`PullRequestTarget`]s or
+    ///   `(owner, repo, pr_number)` tuples. Use [`futures::stream::iter`] to process
+    ///   an iterator.
+    /// * `max_concurrency` - The maximum number of pull requests processed at once
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::stream::{self, StreamExt};
+    /// use merge_warden_core::MergeWarden;
+    /// use merge_warden_developer_platforms::{ConfigFetcher, PullRequestProvider};
+    ///
+    /// async fn audit<P: PullRequestProvider + ConfigFetcher + std::fmt::Debug>(
+    ///     warden: &MergeWarden<P>,
+    /// ) {
+    ///     let targets = stream::iter((1..=1000).map(|n| ("owner", "repo", n)));
+    ///     let mut results = std::pin::pin!(warden.process_pull_requests(targets, 8));
+    ///
+    ///     let mut processed = 0;
+    ///     while let Some(item) = results.next().await {
+    ///         processed += 1;
+    ///         match item.result {
+    ///             Ok(result) => println!("[{processed}] {}: title valid = {}", item.target, result.title_valid),
+    ///             Err(e) => eprintln!("[{processed}] {}: failed: {e}", item.target),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn process_pull_requests<'a, S>(
+        &'a self,
+        targets: S,
+        max_concurrency: usize,
+    ) -> impl Stream<Item = BatchProcessResult> + 'a
+    where
+        S: Stream + 'a,
+        S::Item: Into<PullRequestTarget>,
+    {
+        targets
+            .map(move |target| async move {
+                let target: PullRequestTarget = target.into();
+                let result = self
+                    .process_pull_request(&target.owner, &target.repo, target.pr_number)
+                    .await;
+                if let Err(e) = &result {
+                    warn!(
+                        repository_owner = target.owner,
+                        repository = target.repo,
+                        pull_request = target.pr_number,
+                        error = %e,
+                        "Failed to process pull request in batch"
+                    );
+                }
+                BatchProcessResult { target, result }
+            })
+            .buffer_unordered(max_concurrency.max(1))
     }
 
     /// Creates a new `MergeWarden` instance with custom configuration.
@@ -2629,7 +7709,7 @@ Please update the PR body to include a valid work item reference."#;
     ///     # async fn add_labels(&self, _: &str, _: &str, _: u64, _: &[String]) -> Result<(), Error> { unimplemented!() }
     ///     # async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     ///     # async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<Label>, Error> { unimplemented!() }
-    ///     # async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    ///     # async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     ///     # async fn get_pull_request_files(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::PullRequestFile>, Error> { unimplemented!() }
     ///     # async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     ///     # async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { Ok(vec![]) }
@@ -2649,7 +7729,7 @@ Please update the PR body to include a valid work item reference."#;
     ///         title_pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
     ///         invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
     ///         enforce_work_item_references: true,
-    ///         work_item_reference_pattern: WORK_ITEM_REGEX.to_string(),
+    ///         work_item_reference_pattern: WORK_ITEM_REGEX.into(),
     ///         missing_work_item_label: Some(MISSING_WORK_ITEM_LABEL.to_string()),
     ///         bypass_rules: BypassRules::default(),
     ///         pr_size_check: Default::default(),
@@ -2667,6 +7747,11 @@ Please update the PR body to include a valid work item reference."#;
             provider,
             config,
             issue_provider: None,
+            comment_renderer: Box::new(TemplateEngineRenderer::new()),
+            clock: Arc::new(SystemClock),
+            state_store: Arc::new(InMemoryStateStore::new()),
+            decision_recorder: DecisionRecorder::default(),
+            dry_run_recorder: DryRunRecorder::default(),
         }
     }
 
@@ -2693,4 +7778,63 @@ Please update the PR body to include a valid work item reference."#;
         self.issue_provider = Some(provider);
         self
     }
+
+    /// Replaces the [`CommentRenderer`] used to produce pull request comments.
+    ///
+    /// Every comment Merge Warden posts (title and work item guidance, bypass
+    /// notices and the oversized PR notice) is rendered through this renderer.
+    /// If the renderer fails, the built-in template is used instead and the
+    /// failure is logged at `warn` level.
+    ///
+    /// # Arguments
+    ///
+    /// * `renderer` - A boxed [`CommentRenderer`] implementation.
+    ///
+    /// # Returns
+    ///
+    /// The updated `MergeWarden` instance (builder pattern).
+    pub fn with_comment_renderer(mut self, renderer: Box<dyn CommentRenderer>) -> Self {
+        self.comment_renderer = renderer;
+        self
+    }
+
+    /// Replaces the [`Clock`] used to read the current time.
+    ///
+    /// Every time-based decision and duration measurement reads the time through
+    /// this clock. Tests pass a [`clock::ManualClock`] to control time.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The clock to use instead of the [`SystemClock`].
+    ///
+    /// # Returns
+    ///
+    /// The updated `MergeWarden` instance (builder pattern).
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Replaces the [`StateStore`] in which per-pull-request state is kept.
+    ///
+    /// Every feature that remembers something between events stores it here, so a
+    /// durable backend only has to be configured once. The store is shared, so the
+    /// same instance can be passed to the `MergeWarden` created for each event.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_store` - The store to use instead of an [`InMemoryStateStore`].
+    ///
+    /// # Returns
+    ///
+    /// The updated `MergeWarden` instance (builder pattern).
+    pub fn with_state_store(mut self, state_store: Arc<dyn StateStore>) -> Self {
+        self.state_store = state_store;
+        self
+    }
+
+    /// Returns the [`StateStore`] in which per-pull-request state is kept.
+    pub fn state_store(&self) -> &Arc<dyn StateStore> {
+        &self.state_store
+    }
 }
@@ -1,29 +1,43 @@
 use crate::{
-    checks::TitleValidationResult,
+    checks::{TitleValidationResult, WorkItemValidationResult},
     config::{
-        BypassRule, BypassRules, ChangeTypeLabelConfig, ConventionalCommitMappings,
-        CurrentPullRequestValidationConfiguration, FallbackLabelSettings, IssuePropagationConfig,
-        KeywordLabelsConfig, LabelDetectionStrategy, WipCheckConfig, CONFIG_COMMENT_MARKER,
-        CONVENTIONAL_COMMIT_REGEX, MISSING_WORK_ITEM_LABEL, SIZE_COMMENT_MARKER,
-        TITLE_COMMENT_MARKER, TITLE_INVALID_LABEL, WIP_COMMENT_MARKER, WORK_ITEM_COMMENT_MARKER,
-        WORK_ITEM_REGEX,
+        BypassRule, BypassRules, ChangeTypeLabelConfig, ChangeTypesPolicyConfig,
+        CheckExecutionConfig, CheckKind, CheckSeverity, CheckSummaryConfig, ChecklistPolicyConfig,
+        ConfigSource, ConventionalCommitMappings, CurrentPullRequestValidationConfiguration,
+        DependencyUpdateConfig, DescriptiveTitlePolicyConfig, DraftConversionPolicyConfig,
+        FallbackLabelSettings, FooterPolicyConfig, IssuePropagationConfig, KeywordLabelsConfig,
+        LabelDetectionStrategy, ReviewThreadPolicyConfig, WipCheckConfig, AUTOFIX_COMMENT_MARKER,
+        CONFIG_COMMENT_MARKER, CONVENTIONAL_COMMIT_REGEX, DRAFT_CONVERSION_COMMENT_MARKER,
+        MISSING_WORK_ITEM_LABEL, SIZE_COMMENT_MARKER, THREAD_ROOT_COMMENT_MARKER,
+        TITLE_COMMENT_MARKER, TITLE_INVALID_LABEL, TRANSITION_COMMENT_MARKER, WIP_COMMENT_MARKER,
+        WORK_ITEM_COMMENT_MARKER, WORK_ITEM_REGEX,
     },
-    validation_result::{BypassRuleType, ValidationResult},
-    MergeWarden,
+    dry_run::PlannedOperation,
+    state::{InMemoryStateStore, StateStore},
+    templates::{
+        CommentTemplate, TemplateEngineRenderer, DEFAULT_MISSING_WORK_ITEM_COMMENT_TEMPLATE,
+    },
+    validation_result::{BypassInfo, BypassRuleType, ValidationResult},
+    BatchProcessResult, CheckResult, MergeWarden, PullRequestTarget,
 };
 use async_trait::async_trait;
+use futures::StreamExt;
 use std::{
     collections::HashMap,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
 };
 use tokio::test;
 use tracing::info;
 
 use merge_warden_developer_platforms::models::{
-    Comment, Label, PullRequest, PullRequestFile, Review,
+    AuthorAssociation, CheckRunAction, Comment, CommitVerification, Label, PullRequest,
+    PullRequestCommit, PullRequestFile, PullRequestFilePatch, Review, ReviewThread,
 };
-use merge_warden_developer_platforms::PullRequestProvider;
 use merge_warden_developer_platforms::{errors::Error, models::User};
+use merge_warden_developer_platforms::{PullRequestProvider, DEFAULT_CHECK_RUN_NAME};
 
 // Mock implementation of PullRequestProvider for testing
 #[derive(Debug)]
@@ -33,6 +47,9 @@ struct ErrorMockGitProvider {
     error_on_add_comment: bool,
     invalid_pr_title: bool,
     invalid_pr_body: bool,
+    error_on_pr_numbers: Vec<u64>,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
 }
 
 impl ErrorMockGitProvider {
@@ -43,6 +60,9 @@ impl ErrorMockGitProvider {
             error_on_add_comment: false,
             invalid_pr_body: false,
             invalid_pr_title: false,
+            error_on_pr_numbers: Vec::new(),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            max_in_flight: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -58,6 +78,10 @@ impl ErrorMockGitProvider {
         self.error_on_get_pr = true;
     }
 
+    fn with_get_pr_error_for(&mut self, pr_number: u64) {
+        self.error_on_pr_numbers.push(pr_number);
+    }
+
     #[allow(dead_code)]
     fn with_invalid_pr_body(&mut self) {
         self.invalid_pr_body = true;
@@ -74,9 +98,15 @@ impl PullRequestProvider for ErrorMockGitProvider {
         &self,
         _repo_owner: &str,
         _repo_name: &str,
-        _pr_number: u64,
+        pr_number: u64,
     ) -> Result<PullRequest, Error> {
-        if self.error_on_get_pr {
+        // Track how many PRs are fetched at once, yielding so that concurrent callers overlap
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        tokio::task::yield_now().await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        if self.error_on_get_pr || self.error_on_pr_numbers.contains(&pr_number) {
             Err(Error::ApiError())
         } else {
             let title = if self.invalid_pr_title {
@@ -92,7 +122,7 @@ impl PullRequestProvider for ErrorMockGitProvider {
             };
 
             Ok(PullRequest {
-                number: 1,
+                number: pr_number,
                 title: title.to_string(),
                 draft: false,
                 body: Some(body.to_string()),
@@ -102,6 +132,8 @@ impl PullRequestProvider for ErrorMockGitProvider {
                 }),
                 milestone_number: None,
                 head_sha: String::new(),
+                base_ref: String::new(),
+                head_ref: String::new(),
             })
         }
     }
@@ -192,6 +224,7 @@ impl PullRequestProvider for ErrorMockGitProvider {
         _output_title: &str,
         _output_summary: &str,
         _output_text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -261,10 +294,13 @@ struct CheckStatusUpdate {
     repo_owner: String,
     repo_name: String,
     pr_number: u64,
+    head_sha: Option<String>,
+    check_name: String,
     conclusion: String,
     title: String,
     summary: String,
     text: String,
+    actions: Vec<CheckRunAction>,
 }
 
 // Mock implementation of PullRequestProvider that returns different PRs based on PR number
@@ -391,6 +427,7 @@ impl PullRequestProvider for DynamicMockGitProvider {
             current_labels.push(Label {
                 name: label.clone(),
                 description: None,
+                color: None,
             });
         }
         Ok(())
@@ -435,16 +472,20 @@ impl PullRequestProvider for DynamicMockGitProvider {
         output_title: &str,
         output_summary: &str,
         output_text: &str,
+        actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         let mut updates = self.check_status_updates.lock().unwrap();
         updates.push(CheckStatusUpdate {
             repo_owner: repo_owner.to_string(),
             repo_name: repo_name.to_string(),
             pr_number,
+            head_sha: None,
+            check_name: DEFAULT_CHECK_RUN_NAME.to_string(),
             conclusion: conclusion.to_string(),
             title: output_title.to_string(),
             summary: output_summary.to_string(),
             text: output_text.to_string(),
+            actions: actions.to_vec(),
         });
         Ok(())
     }
@@ -508,13 +549,35 @@ impl merge_warden_developer_platforms::ConfigFetcher for DynamicMockGitProvider
     }
 }
 
+/// A threaded comment and the ID of the comment it replies to.
+type ThreadedComment = (Comment, Option<u64>);
+
 // Mock implementation of PullRequestProvider for testing
-#[derive(Debug)]
+//
+// Clones share their state, so a clone can back a second `MergeWarden` for the same
+// pull request.
+#[derive(Debug, Clone)]
 struct MockGitProvider {
     pull_request: Arc<Mutex<Option<PullRequest>>>,
     labels: Arc<Mutex<Vec<Label>>>,
     comments: Arc<Mutex<Vec<Comment>>>,
     check_status_updates: Arc<Mutex<Vec<CheckStatusUpdate>>>,
+    review_threads: Arc<Mutex<Option<Vec<ReviewThread>>>>,
+    label_appliers: Arc<Mutex<HashMap<String, User>>>,
+    /// Threaded comments with the comment they reply to; `None` when unsupported.
+    threaded_comments: Arc<Mutex<Option<Vec<ThreadedComment>>>>,
+    /// Delay added to the label and review-thread listings; `None` answers at once.
+    latency: Arc<Mutex<Option<std::time::Duration>>>,
+    /// Number of delayed calls currently in progress.
+    calls_in_flight: Arc<AtomicUsize>,
+    /// Highest number of delayed calls that were in progress at the same time.
+    max_calls_in_flight: Arc<AtomicUsize>,
+    /// The author's association with the repository; `None` when unsupported.
+    author_association: Arc<Mutex<Option<AuthorAssociation>>>,
+    /// Numbers of the pull requests converted to drafts, in order.
+    drafts_converted: Arc<Mutex<Vec<u64>>>,
+    /// Issues returned by `get_issue`; `None` when unsupported.
+    issues: Arc<Mutex<Option<Vec<merge_warden_developer_platforms::models::Issue>>>>,
 }
 
 impl MockGitProvider {
@@ -524,9 +587,77 @@ impl MockGitProvider {
             labels: Arc::new(Mutex::new(Vec::new())),
             comments: Arc::new(Mutex::new(Vec::new())),
             check_status_updates: Arc::new(Mutex::new(Vec::new())),
+            review_threads: Arc::new(Mutex::new(None)),
+            label_appliers: Arc::new(Mutex::new(HashMap::new())),
+            threaded_comments: Arc::new(Mutex::new(None)),
+            latency: Arc::new(Mutex::new(None)),
+            calls_in_flight: Arc::new(AtomicUsize::new(0)),
+            max_calls_in_flight: Arc::new(AtomicUsize::new(0)),
+            author_association: Arc::new(Mutex::new(None)),
+            drafts_converted: Arc::new(Mutex::new(Vec::new())),
+            issues: Arc::new(Mutex::new(None)),
         }
     }
 
+    fn set_author_association(&self, association: AuthorAssociation) {
+        *self.author_association.lock().unwrap() = Some(association);
+    }
+
+    fn set_issues(&self, issues: Vec<merge_warden_developer_platforms::models::Issue>) {
+        *self.issues.lock().unwrap() = Some(issues);
+    }
+
+    fn set_latency(&self, latency: std::time::Duration) {
+        *self.latency.lock().unwrap() = Some(latency);
+    }
+
+    fn max_calls_in_flight(&self) -> usize {
+        self.max_calls_in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Waits for the configured latency, recording how many calls overlap.
+    async fn simulate_latency(&self) {
+        let Some(latency) = *self.latency.lock().unwrap() else {
+            return;
+        };
+        let in_flight = self.calls_in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_calls_in_flight
+            .fetch_max(in_flight, Ordering::SeqCst);
+        tokio::time::sleep(latency).await;
+        self.calls_in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    fn enable_threaded_comments(&self) {
+        *self.threaded_comments.lock().unwrap() = Some(Vec::new());
+    }
+
+    fn get_threaded_comments(&self) -> Vec<ThreadedComment> {
+        self.threaded_comments
+            .lock()
+            .unwrap()
+            .clone()
+            .unwrap_or_default()
+    }
+
+    fn apply_label_as(&self, label: &str, login: &str) {
+        self.labels.lock().unwrap().push(Label {
+            name: label.to_string(),
+            description: None,
+            color: None,
+        });
+        self.label_appliers.lock().unwrap().insert(
+            label.to_string(),
+            User {
+                id: 99,
+                login: login.to_string(),
+            },
+        );
+    }
+
+    fn set_review_threads(&self, threads: Vec<ReviewThread>) {
+        *self.review_threads.lock().unwrap() = Some(threads);
+    }
+
     fn set_pull_request(&self, pr: PullRequest) {
         let mut pull_request = self.pull_request.lock().unwrap();
         *pull_request = Some(pr);
@@ -546,10 +677,69 @@ impl MockGitProvider {
         let updates = self.check_status_updates.lock().unwrap().clone();
         updates
     }
+
+    fn get_drafts_converted(&self) -> Vec<u64> {
+        self.drafts_converted.lock().unwrap().clone()
+    }
 }
 
 #[async_trait]
 impl PullRequestProvider for MockGitProvider {
+    async fn convert_to_draft(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        pr_number: u64,
+    ) -> Result<(), Error> {
+        self.drafts_converted.lock().unwrap().push(pr_number);
+        if let Some(pr) = self.pull_request.lock().unwrap().as_mut() {
+            pr.draft = true;
+        }
+        Ok(())
+    }
+
+    async fn update_pull_request(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), Error> {
+        if let Some(pr) = self.pull_request.lock().unwrap().as_mut() {
+            if let Some(title) = title {
+                pr.title = title.to_string();
+            }
+            if let Some(body) = body {
+                pr.body = Some(body.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_author_association(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<AuthorAssociation, Error> {
+        (*self.author_association.lock().unwrap())
+            .ok_or_else(|| Error::Unsupported("get_author_association".to_string()))
+    }
+
+    async fn get_issue(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        issue_number: u64,
+    ) -> Result<Option<merge_warden_developer_platforms::models::Issue>, Error> {
+        let issues = self.issues.lock().unwrap();
+        let issues = issues
+            .as_ref()
+            .ok_or_else(|| Error::Unsupported("get_issue".to_string()))?;
+        Ok(issues.iter().find(|i| i.number == issue_number).cloned())
+    }
+
     async fn get_pull_request(
         &self,
         _repo_owner: &str,
@@ -616,6 +806,7 @@ impl PullRequestProvider for MockGitProvider {
             current_labels.push(Label {
                 name: label.clone(),
                 description: None,
+                color: None,
             });
         }
         Ok(())
@@ -639,8 +830,9 @@ impl PullRequestProvider for MockGitProvider {
         _repo_name: &str,
         _pr_number: u64,
     ) -> Result<Vec<Label>, Error> {
-        let labels = self.labels.lock().unwrap();
-        Ok(labels.clone())
+        self.simulate_latency().await;
+        let labels = self.labels.lock().unwrap().clone();
+        Ok(labels)
     }
 
     async fn list_available_labels(
@@ -660,16 +852,75 @@ impl PullRequestProvider for MockGitProvider {
         output_title: &str,
         output_summary: &str,
         output_text: &str,
+        actions: &[CheckRunAction],
+    ) -> Result<(), Error> {
+        let mut updates = self.check_status_updates.lock().unwrap();
+        updates.push(CheckStatusUpdate {
+            repo_owner: repo_owner.to_string(),
+            repo_name: repo_name.to_string(),
+            pr_number,
+            head_sha: None,
+            check_name: DEFAULT_CHECK_RUN_NAME.to_string(),
+            conclusion: conclusion.to_string(),
+            title: output_title.to_string(),
+            summary: output_summary.to_string(),
+            text: output_text.to_string(),
+            actions: actions.to_vec(),
+        });
+        Ok(())
+    }
+
+    async fn update_named_pr_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        check_name: &str,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+        actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         let mut updates = self.check_status_updates.lock().unwrap();
         updates.push(CheckStatusUpdate {
             repo_owner: repo_owner.to_string(),
             repo_name: repo_name.to_string(),
             pr_number,
+            head_sha: None,
+            check_name: check_name.to_string(),
+            conclusion: conclusion.to_string(),
+            title: output_title.to_string(),
+            summary: output_summary.to_string(),
+            text: output_text.to_string(),
+            actions: actions.to_vec(),
+        });
+        Ok(())
+    }
+
+    async fn update_commit_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        head_sha: &str,
+        check_name: &str,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+    ) -> Result<(), Error> {
+        let mut updates = self.check_status_updates.lock().unwrap();
+        updates.push(CheckStatusUpdate {
+            repo_owner: repo_owner.to_string(),
+            repo_name: repo_name.to_string(),
+            pr_number: 0,
+            head_sha: Some(head_sha.to_string()),
+            check_name: check_name.to_string(),
             conclusion: conclusion.to_string(),
             title: output_title.to_string(),
             summary: output_summary.to_string(),
             text: output_text.to_string(),
+            actions: Vec::new(),
         });
         Ok(())
     }
@@ -709,6 +960,81 @@ impl PullRequestProvider for MockGitProvider {
     ) -> Result<Vec<u64>, Error> {
         Ok(vec![])
     }
+
+    async fn list_review_threads(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<ReviewThread>, Error> {
+        self.simulate_latency().await;
+        match self.review_threads.lock().unwrap().clone() {
+            Some(threads) => Ok(threads),
+            None => Err(Error::Unsupported("list_review_threads".to_string())),
+        }
+    }
+
+    async fn list_threaded_comments(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<Comment>, Error> {
+        match &*self.threaded_comments.lock().unwrap() {
+            Some(comments) => Ok(comments.iter().map(|(c, _)| c.clone()).collect()),
+            None => Err(Error::Unsupported("list_threaded_comments".to_string())),
+        }
+    }
+
+    async fn add_threaded_comment(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+        in_reply_to: Option<u64>,
+        comment: &str,
+    ) -> Result<Comment, Error> {
+        match &mut *self.threaded_comments.lock().unwrap() {
+            Some(comments) => {
+                let added = Comment {
+                    id: 1000 + comments.iter().map(|(c, _)| c.id).max().unwrap_or(0) + 1,
+                    body: comment.to_string(),
+                    user: User {
+                        id: 10,
+                        login: "a".to_string(),
+                    },
+                };
+                comments.push((added.clone(), in_reply_to));
+                Ok(added)
+            }
+            None => Err(Error::Unsupported("add_threaded_comment".to_string())),
+        }
+    }
+
+    async fn delete_threaded_comment(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        comment_id: u64,
+    ) -> Result<(), Error> {
+        match &mut *self.threaded_comments.lock().unwrap() {
+            Some(comments) => {
+                comments.retain(|(c, _)| c.id != comment_id);
+                Ok(())
+            }
+            None => Err(Error::Unsupported("delete_threaded_comment".to_string())),
+        }
+    }
+
+    async fn find_label_applier(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+        label: &str,
+    ) -> Result<Option<User>, Error> {
+        Ok(self.label_appliers.lock().unwrap().get(label).cloned())
+    }
 }
 
 #[async_trait]
@@ -761,7 +1087,7 @@ async fn test_constructor_with_config() {
         title_pattern: "ab".to_string(),
         invalid_title_label: None,
         enforce_work_item_references: true,
-        work_item_reference_pattern: "cd".to_string(),
+        work_item_reference_pattern: "cd".into(),
         missing_work_item_label: None,
         pr_size_check: crate::config::PrSizeCheckConfig::default(),
         change_type_labels: None, // Use default behavior for tests
@@ -800,6 +1126,8 @@ async fn test_process_pull_request_valid() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     provider.set_pull_request(pr);
 
@@ -863,6 +1191,8 @@ async fn test_process_pull_request_invalid_title() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     provider.set_pull_request(pr);
 
@@ -916,6 +1246,8 @@ async fn test_process_pull_request_missing_work_item() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     provider.set_pull_request(pr);
 
@@ -953,2865 +1285,4682 @@ async fn test_process_pull_request_missing_work_item() {
 }
 
 #[tokio::test]
-async fn test_process_pull_request_both_invalid() {
-    // Create a mock provider
+async fn test_process_pull_request_uses_custom_comment_renderer() {
     let provider = MockGitProvider::new();
-
-    // Set up a PR with invalid title and missing work item reference
     let pr = PullRequest {
         number: 1,
-        title: "invalid title".to_string(), // Missing conventional commit format
+        title: "feat: add new feature".to_string(),
         draft: false,
         body: Some("No work item reference".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
+        author: None,
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     provider.set_pull_request(pr);
 
-    // Create a MergeWarden instance
-    let warden = MergeWarden::new(provider);
+    let renderer = TemplateEngineRenderer::new()
+        .with_template(
+            CommentTemplate::MissingWorkItem,
+            "Link an issue matching `{{ pattern }}`.",
+        )
+        .unwrap();
+    let warden = MergeWarden::new(provider).with_comment_renderer(Box::new(renderer));
 
-    // Process the PR
-    let result = warden
+    warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result
-    assert!(!result.title_valid, "Title should be invalid");
-    assert!(
-        !result.work_item_referenced,
-        "Work item should not be referenced"
-    );
-
-    // Verify both labels were added
-    let labels = warden.provider.get_labels();
-    assert!(
-        labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
-        "Invalid title label should be added"
-    );
-    assert!(
-        labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
-        "Missing work item label should be added"
-    );
-
-    // Verify both comments were added
     let comments = warden.provider.get_comments();
-    assert!(
-        comments
-            .iter()
-            .any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
-        "Title comment should be added"
-    );
-    assert!(
-        comments
-            .iter()
-            .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)),
-        "Work item comment should be added"
+    let work_item_comment = comments
+        .iter()
+        .find(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER))
+        .expect("Work item comment should be added");
+    assert_eq!(
+        work_item_comment.body,
+        format!("{WORK_ITEM_COMMENT_MARKER}Link an issue matching `{WORK_ITEM_REGEX}`.")
     );
 }
 
 #[tokio::test]
-async fn test_handle_title_validation_invalid_to_valid() {
-    // Create a mock provider
+async fn test_process_pull_request_check_text_starts_with_summary_table() {
     let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("No work item reference".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+    let warden = MergeWarden::new(provider);
 
-    // Add the invalid title label
-    provider
-        .add_labels("owner", "repo", 1, &[TITLE_INVALID_LABEL.to_string()])
-        .await
-        .unwrap();
-
-    // Add a title comment
-    let comment = format!(
-            "{}\n## Invalid PR Title Format\n\nYour PR title doesn't follow the Conventional Commits format.",
-            TITLE_COMMENT_MARKER
-        );
-    provider
-        .add_comment("owner", "repo", 1, &comment)
+    warden
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Create a MergeWarden instance
-    let warden = MergeWarden::new(provider);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1);
+    let update = &updates[0];
+    assert_eq!(update.summary, "PR work item reference is missing.");
+    assert!(update
+        .text
+        .starts_with("| Status | Check | Details |\n| :---: | :--- | :--- |\n"));
+    assert!(update
+        .text
+        .contains("| ✅ | PR title | Title follows the Conventional Commits format |"));
+    assert!(update
+        .text
+        .contains("| ❌ | Work item | PR description is missing a work item reference |"));
+    assert!(update
+        .text
+        .contains("| ➖ | PR size | Size checking is not enabled |"));
+}
 
-    // Set up a PR
-    let pr = PullRequest {
+#[tokio::test]
+async fn test_process_pull_request_incomplete_checklist_fails_with_unticked_items() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
         number: 1,
         title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("Test body".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
+        body: Some("Fixes #1\n\n- [x] Tests added\n  - [ ] Docs updated".to_string()),
+        author: None,
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+    let config = CurrentPullRequestValidationConfiguration {
+        checklist: ChecklistPolicyConfig {
+            enabled: true,
+            required_items: vec![],
+        },
+        ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Handle title validation with valid title
-    let validation_result = TitleValidationResult {
-        validation: ValidationResult::valid(),
-        diagnosis: None,
-    };
-    warden
-        .communicate_pr_title_validity_status("owner", "repo", &pr, &validation_result)
-        .await;
-
-    // Verify the label was removed
-    let labels = warden.provider.get_labels();
-    assert!(
-        !labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
-        "Invalid title label should be removed"
-    );
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Verify the comment was removed
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
-        "Title comment should be removed"
-    );
+    assert!(!result.checklist_complete);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert_eq!(update.summary, "PR checklist is incomplete.");
+    assert!(update
+        .text
+        .contains("| ❌ | Checklist | 1 required checklist item(s) not ticked |"));
+    assert!(update.text.contains("\n- [ ] Docs updated"));
 }
 
 #[tokio::test]
-async fn test_process_pull_request_custom_config_disabled_checks() {
-    // Create a mock provider
+async fn test_process_pull_request_complete_checklist_passes() {
     let provider = MockGitProvider::new();
-
-    // Set up a PR with invalid title and missing work item reference
-    let pr = PullRequest {
+    provider.set_pull_request(PullRequest {
         number: 1,
-        title: "invalid title".to_string(), // Missing conventional commit format
+        title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("No work item reference".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
+        body: Some("Fixes #1\n\n- [x] Tests added\n- [ ] Optional extra".to_string()),
+        author: None,
         milestone_number: None,
         head_sha: String::new(),
-    };
-    provider.set_pull_request(pr); // Create a custom configuration with disabled checks
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
     let config = CurrentPullRequestValidationConfiguration {
-        enforce_title_convention: false,
-        title_pattern: "ab".to_string(),
-        invalid_title_label: None,
-        enforce_work_item_references: false,
-        work_item_reference_pattern: "cd".to_string(),
-        missing_work_item_label: None,
-        pr_size_check: crate::config::PrSizeCheckConfig::default(),
-        change_type_labels: None, // Use default behavior for tests
-        bypass_rules: BypassRules::default(),
+        checklist: ChecklistPolicyConfig {
+            enabled: true,
+            required_items: vec!["Tests added".to_string()],
+        },
         ..Default::default()
     };
-
-    // Create a MergeWarden instance with custom config
     let warden = MergeWarden::with_config(provider, config);
 
-    // Process the PR
     let result = warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result - both should be valid since checks are disabled
-    assert!(
-        result.title_valid,
-        "Title should be valid when check is disabled"
-    );
-    assert!(
-        result.work_item_referenced,
-        "Work item should be referenced when check is disabled"
-    );
-
-    // Verify no labels were added
-    let labels = warden.provider.get_labels();
-    assert!(
-        !labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
-        "Invalid title label should not be added when check is disabled"
-    );
-    assert!(
-        !labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
-        "Missing work item label should not be added when check is disabled"
-    );
-
-    // Verify no comments were added
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
-        "Title comment should not be added when check is disabled"
-    );
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)),
-        "Work item comment should not be added when check is disabled"
-    );
+    assert!(result.checklist_complete);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Checklist | All required checklist items are ticked |"));
 }
 
-#[tokio::test]
-async fn test_process_pull_request_existing_labels_comments() {
-    // Create a mock provider
+fn footer_test_warden(body: &str) -> MergeWarden<MockGitProvider> {
     let provider = MockGitProvider::new();
-
-    // Add existing labels and comments
-    provider
-        .add_labels(
-            "owner",
-            "repo",
-            1,
-            &[
-                TITLE_INVALID_LABEL.to_string(),
-                MISSING_WORK_ITEM_LABEL.to_string(),
-                "feature".to_string(),
-            ],
-        )
-        .await
-        .unwrap();
-
-    let title_comment = format!(
-        "{}\n## Invalid PR Title Format\n\nYour PR title doesn't follow the Conventional Commits format.",
-        TITLE_COMMENT_MARKER
-    );
-    provider
-        .add_comment("owner", "repo", 1, &title_comment)
-        .await
-        .unwrap();
-
-    let work_item_comment = format!(
-        "{}\n## Missing Work Item Reference\n\nYour PR description doesn't reference a work item or GitHub issue.",
-        WORK_ITEM_COMMENT_MARKER
-    );
-    provider
-        .add_comment("owner", "repo", 1, &work_item_comment)
-        .await
-        .unwrap();
-
-    // Set up a valid PR
-    let pr = PullRequest {
+    provider.set_pull_request(PullRequest {
         number: 1,
         title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("Fixes #123".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
+        body: Some(body.to_string()),
+        author: None,
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+    let config = CurrentPullRequestValidationConfiguration {
+        footers: FooterPolicyConfig {
+            enabled: true,
+            required: vec![
+                crate::config::RequiredFooter {
+                    token: "Signed-off-by".to_string(),
+                    pattern: Some("<.+@.+>".to_string()),
+                },
+                crate::config::RequiredFooter {
+                    token: "Reviewed-by".to_string(),
+                    pattern: None,
+                },
+            ],
+        },
+        ..Default::default()
     };
-    provider.set_pull_request(pr);
+    MergeWarden::with_config(provider, config)
+}
 
-    // Create a MergeWarden instance
-    let warden = MergeWarden::new(provider);
+#[tokio::test]
+async fn test_process_pull_request_missing_footers_fails_with_missing_tokens() {
+    let warden = footer_test_warden("Fixes #1\n\nSigned-off-by: Jane <jane@example.com>");
 
-    // Process the PR
     let result = warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result
-    assert!(result.title_valid, "Title should be valid");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be referenced"
-    );
+    assert!(!result.footers_present);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert_eq!(update.summary, "PR required footers are missing.");
+    assert!(update
+        .text
+        .contains("| ❌ | Footers | 1 required footer(s) missing |"));
+    assert!(update.text.contains("\n- `Reviewed-by:`"));
+}
 
-    // Verify the invalid labels were removed
-    let labels = warden.provider.get_labels();
-    assert!(
-        !labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
-        "Invalid title label should be removed"
-    );
-    assert!(
-        !labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
-        "Missing work item label should be removed"
-    );
+#[tokio::test]
+async fn test_process_pull_request_present_footers_pass() {
+    let warden =
+        footer_test_warden("Fixes #1\n\nSigned-off-by: Jane <jane@example.com>\nReviewed-by: Alex");
 
-    // Verify the feature label remains
-    assert!(
-        labels.iter().any(|l| l.name == "feature"),
-        "Feature label should remain"
-    );
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Verify the comments were removed
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
-        "Title comment should be removed"
-    );
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)),
-        "Work item comment should be removed"
-    );
+    assert!(result.footers_present);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Footers | All required footers are present |"));
 }
 
-#[tokio::test]
-async fn test_process_pull_request_dynamic_provider() {
-    // Create a dynamic mock provider
-    let mut provider = DynamicMockGitProvider::new();
+fn review_thread(id: &str, is_resolved: bool) -> ReviewThread {
+    ReviewThread {
+        id: id.to_string(),
+        is_resolved,
+        is_outdated: false,
+    }
+}
 
-    // Add two different PRs
-    let valid_pr = PullRequest {
+fn review_thread_test_pr() -> PullRequest {
+    PullRequest {
         number: 1,
         title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("Fixes #123".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
+        body: Some("Fixes #1".to_string()),
+        author: None,
         milestone_number: None,
         head_sha: String::new(),
-    };
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
 
-    let invalid_pr = PullRequest {
-        number: 2,
-        title: "invalid title".to_string(),
-        draft: false,
-        body: Some("No work item reference".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
+#[tokio::test]
+async fn test_process_pull_request_unresolved_review_threads_over_limit_fails() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
+    provider.set_review_threads(vec![
+        review_thread("T_1", false),
+        review_thread("T_2", false),
+        review_thread("T_3", true),
+    ]);
+    let config = CurrentPullRequestValidationConfiguration {
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 1,
+        },
+        ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    provider.add_pull_request(valid_pr);
-    provider.add_pull_request(invalid_pr);
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Create a MergeWarden instance
-    let warden = MergeWarden::new(provider);
+    assert!(!result.review_threads_resolved);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert_eq!(update.summary, "PR has 2 unresolved review thread(s).");
+    assert!(update
+        .text
+        .contains("| ❌ | Review threads | 2 of 3 review thread(s) unresolved (limit 1) |"));
+}
 
-    // Process the valid PR
-    let result1 = warden
+#[tokio::test]
+async fn test_process_pull_request_unresolved_review_threads_within_limit_passes() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
+    provider.set_review_threads(vec![
+        review_thread("T_1", false),
+        review_thread("T_2", true),
+    ]);
+    let config = CurrentPullRequestValidationConfiguration {
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 1,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result for valid PR
-    assert!(result1.title_valid, "Title should be valid for PR #1");
-    assert!(
-        result1.work_item_referenced,
-        "Work item should be referenced for PR #1"
-    );
+    assert!(result.review_threads_resolved);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Review threads | 1 of 2 review thread(s) unresolved (limit 1) |"));
+}
 
-    // Process the invalid PR
-    let result2 = warden
-        .process_pull_request("owner", "repo", 2)
+#[tokio::test]
+async fn test_process_pull_request_review_threads_unsupported_platform_is_skipped() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
+    let config = CurrentPullRequestValidationConfiguration {
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 0,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result for invalid PR
-    assert!(!result2.title_valid, "Title should be invalid for PR #2");
-    assert!(
-        !result2.work_item_referenced,
-        "Work item should not be referenced for PR #2"
-    );
+    assert!(result.review_threads_resolved);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ➖ | Review threads | Not supported by this platform |"));
+}
 
-    // Verify the labels were added
-    let labels = warden.provider.get_labels();
-    assert!(
-        labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
-        "Invalid title label should be added for PR #2"
-    );
-    assert!(
-        labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
-        "Missing work item label should be added for PR #2"
-    );
+#[tokio::test]
+async fn test_process_pull_request_short_circuit_skips_checks_after_failure() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: "not a conventional title".to_string(),
+        ..review_thread_test_pr()
+    });
+    provider.set_review_threads(vec![review_thread("T_1", false)]);
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 0,
+        },
+        check_execution: CheckExecutionConfig {
+            order: Vec::new(),
+            short_circuit_on_failure: true,
+            report_only: Vec::new(),
+            concurrent: false,
+            diff_source: crate::config::DiffSource::PullRequest,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.title_valid);
+    assert!(result.review_threads_resolved);
+    assert!(result.skipped_checks.contains(&CheckKind::ReviewThreads));
+    assert!(!result.skipped_checks.contains(&CheckKind::Title));
+    assert!(result.skip_reasons.contains(&(
+        CheckKind::ReviewThreads,
+        "Not run because an earlier check failed".to_string()
+    )));
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+    assert_eq!(updates[0].summary, "PR title is invalid.");
+    assert!(updates[0]
+        .text
+        .contains("| ➖ | Review threads | Not run because an earlier check failed |"));
 }
 
 #[tokio::test]
-async fn test_process_pull_request_error_add_comment() {
-    // Create a mock provider that returns an error when adding a comment
-    let mut provider = ErrorMockGitProvider::new();
-    provider.with_invalid_pr_title();
-    provider.with_add_comment_error();
+async fn test_process_pull_request_concurrent_checks_overlap_provider_calls() {
+    async fn run(concurrent: bool) -> (crate::CheckResult, usize) {
+        let provider = MockGitProvider::new();
+        provider.set_pull_request(review_thread_test_pr());
+        provider.set_review_threads(vec![review_thread("T_1", false)]);
+        provider.apply_label_as("priority: high", "maintainer");
+        provider.apply_label_as("priority: low", "maintainer");
+        provider.set_latency(std::time::Duration::from_millis(20));
+        let config = CurrentPullRequestValidationConfiguration {
+            review_threads: ReviewThreadPolicyConfig {
+                enabled: true,
+                max_unresolved: 0,
+            },
+            pr_size_check: crate::config::PrSizeCheckConfig {
+                enabled: true,
+                exclusions: vec![crate::config::SizeExclusion {
+                    author: None,
+                    label: Some("skip-size".to_string()),
+                }],
+                ..Default::default()
+            },
+            check_execution: CheckExecutionConfig {
+                concurrent,
+                diff_source: crate::config::DiffSource::PullRequest,
+                ..Default::default()
+            },
+            enforce_work_item_references: false,
+            exclusive_labels: exclusive_priority_labels(CheckSeverity::Error),
+            ..Default::default()
+        };
+        let warden = MergeWarden::with_config(provider, config);
+
+        let result = warden
+            .process_pull_request("owner", "repo", 1)
+            .await
+            .unwrap();
+        (result, warden.provider.max_calls_in_flight())
+    }
 
-    // Create a MergeWarden instance
-    let warden = MergeWarden::new(provider);
+    let (sequential, sequential_overlap) = run(false).await;
+    let (concurrent, concurrent_overlap) = run(true).await;
 
-    // Process the PR - should return Ok even if adding a comment fails
-    let result = warden.process_pull_request("owner", "repo", 1).await;
+    // Review threads, the size exclusion and the exclusive labels are fetched together
+    assert_eq!(sequential_overlap, 1);
+    assert_eq!(concurrent_overlap, 3);
 
-    // Verify the result is Ok (no error should be returned)
-    assert!(
-        result.is_ok(),
-        "Should return Ok even when adding a comment fails"
-    );
-    // Optionally, check that the output string contains a warning or expected message
-    let output = result.unwrap();
-    assert!(!output.title_valid, "The title should not be valid");
+    assert!(!concurrent.review_threads_resolved);
+    assert!(!concurrent.labels_exclusive);
+    assert_eq!(concurrent.check_summary, sequential.check_summary);
+    assert_eq!(concurrent.check_text, sequential.check_text);
+    assert_eq!(concurrent.labels, sequential.labels);
 }
 
 #[tokio::test]
-async fn test_process_pull_request_error_add_labels() {
-    // Create a mock provider that returns an error when adding labels
-    let mut provider = ErrorMockGitProvider::new();
-    provider.with_add_labels_error();
-
-    // Create a MergeWarden instance
-    let warden = MergeWarden::new(provider);
+async fn test_process_pull_request_short_circuit_follows_configured_order() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: "not a conventional title".to_string(),
+        ..review_thread_test_pr()
+    });
+    provider.set_review_threads(vec![review_thread("T_1", false)]);
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 0,
+        },
+        check_execution: CheckExecutionConfig {
+            order: vec![CheckKind::ReviewThreads],
+            short_circuit_on_failure: true,
+            report_only: Vec::new(),
+            concurrent: false,
+            diff_source: crate::config::DiffSource::PullRequest,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Process the PR - should succeed even if labels fail
-    let result = warden.process_pull_request("owner", "repo", 1).await;
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Should succeed - labeling is non-critical, core validation should still work
-    assert!(
-        result.is_ok(),
-        "Should succeed even when adding labels fails"
-    );
+    // The review-thread check runs first and fails, so the title check is skipped
+    assert!(!result.review_threads_resolved);
+    assert!(result.title_valid);
+    assert!(result.skipped_checks.contains(&CheckKind::Title));
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+    assert!(updates[0]
+        .text
+        .contains("| ➖ | PR title | Not run because an earlier check failed |"));
+    assert!(warden.provider.get_comments().is_empty());
+}
 
-    let check_result = result.unwrap();
+#[tokio::test]
+async fn test_process_pull_request_without_short_circuit_runs_all_checks() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: "not a conventional title".to_string(),
+        ..review_thread_test_pr()
+    });
+    provider.set_review_threads(vec![review_thread("T_1", false)]);
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 0,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Verify that core validation functionality still works
-    assert!(check_result.title_valid, "Title validation should work");
-    assert!(
-        check_result.work_item_referenced,
-        "Work item validation should work"
-    );
-    assert!(check_result.size_valid, "Size validation should work");
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Labels should be empty since label application failed
-    assert!(
-        check_result.labels.is_empty(),
-        "Labels should be empty when label application fails"
-    );
+    assert!(!result.title_valid);
+    assert!(!result.review_threads_resolved);
+    assert!(result.skipped_checks.is_empty());
 }
 
 #[tokio::test]
-async fn test_process_pull_request_error_get_pr() {
-    // Create a mock provider that returns an error when getting a PR
-    let mut provider = ErrorMockGitProvider::new();
-    provider.with_get_pr_error();
+async fn test_process_pull_request_posts_check_run_under_configured_name() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
+    let config = CurrentPullRequestValidationConfiguration {
+        check_summary: CheckSummaryConfig {
+            check_run_name: Some("Merge Warden PR checks".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Create a MergeWarden instance
-    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Process the PR - should return an error
-    let result = warden.process_pull_request("owner", "repo", 1).await;
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].check_name, "Merge Warden PR checks");
+}
 
-    // Verify the error
-    assert!(
-        result.is_err(),
-        "Should return an error when getting a PR fails"
+fn check_summary_template_test_warden(
+    title: &str,
+    body: Option<&str>,
+    templates: crate::config::CheckSummaryTemplates,
+) -> MergeWarden<MockGitProvider> {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: title.to_string(),
+        body: body.map(str::to_string),
+        ..review_thread_test_pr()
+    });
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        enforce_work_item_references: true,
+        check_summary: CheckSummaryConfig {
+            templates,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    MergeWarden::with_config(provider, config)
+}
+
+#[tokio::test]
+async fn test_process_pull_request_renders_check_summary_template_for_failing_checks() {
+    let templates: crate::config::CheckSummaryTemplates = toml::from_str(
+        r#"
+        failed = "{{ default_summary }} See https://wiki.example.com/prs."
+
+        [[on_failure]]
+        checks = ["title", "work_item"]
+        template = "Failing: {{ failing_checks | join(' and ') }} ({{ failing_rules | join(',') }})."
+        "#,
+    )
+    .unwrap();
+    let warden = check_summary_template_test_warden("add login", None, templates.clone());
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.check_summary,
+        "Failing: PR title and Work item (title,work_item)."
     );
+
+    let warden = check_summary_template_test_warden("add login", Some("Fixes #1"), templates);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
     assert_eq!(
-        result.unwrap_err().to_string(),
-        "Git provider error: Failed to find the PR with number [1] in owner/repo",
-        "Should return the specific error message"
+        result.check_summary,
+        "PR title is invalid. See https://wiki.example.com/prs."
     );
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].summary, result.check_summary);
 }
 
 #[tokio::test]
-async fn test_handle_work_item_validation_missing_to_present() {
-    // Create a mock provider
-    let provider = MockGitProvider::new();
+async fn test_process_pull_request_keeps_built_in_summary_when_template_fails() {
+    let templates = crate::config::CheckSummaryTemplates {
+        passed: Some("{% if %}".to_string()),
+        failed: Some("{{ 'x' * 70000 }}".to_string()),
+        on_failure: Vec::new(),
+    };
+    let warden =
+        check_summary_template_test_warden("feat: add login", Some("Fixes #1"), templates.clone());
 
-    // Add the missing work item label
-    provider
-        .add_labels("owner", "repo", 1, &[MISSING_WORK_ITEM_LABEL.to_string()])
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Add a work item comment
-    let comment = format!(
-            "{}\n## Missing Work Item Reference\n\nYour PR description doesn't reference a work item or GitHub issue.",
-            WORK_ITEM_COMMENT_MARKER
-        );
-    provider
-        .add_comment("owner", "repo", 1, &comment)
+    assert_eq!(result.check_summary, "All PR requirements satisfied.");
+
+    // A summary longer than GitHub allows is replaced as well
+    let warden = check_summary_template_test_warden("add login", Some("Fixes #1"), templates);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Create a MergeWarden instance
-    let warden = MergeWarden::new(provider);
+    assert_eq!(result.check_summary, "PR title is invalid.");
+}
 
-    // Set up a PR
-    let pr = PullRequest {
-        number: 1,
-        title: "feat: add new feature".to_string(),
-        draft: false,
-        body: Some("Fixes #123".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
+#[tokio::test]
+async fn test_process_pull_request_keeps_legacy_check_run_during_rename() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
+    let config = CurrentPullRequestValidationConfiguration {
+        check_summary: CheckSummaryConfig {
+            check_run_name: Some("Merge Warden PR checks".to_string()),
+            keep_legacy_check_run: true,
+            ..Default::default()
+        },
+        ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Handle work item validation with valid work item reference
-    let validation_result = ValidationResult::valid();
     warden
-        .communicate_pr_work_item_validity_status("owner", "repo", &pr, &validation_result)
-        .await;
-
-    // Verify the label was removed
-    let labels = warden.provider.get_labels();
-    assert!(
-        !labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
-        "Missing work item label should be removed"
-    );
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Verify the comment was removed
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)),
-        "Work item comment should be removed"
+    let updates = warden.provider.get_check_status_updates();
+    let names: Vec<&str> = updates.iter().map(|u| u.check_name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["Merge Warden PR checks", DEFAULT_CHECK_RUN_NAME]
     );
+    // Both check runs report the same outcome
+    assert_eq!(updates[0].conclusion, updates[1].conclusion);
+    assert_eq!(updates[0].text, updates[1].text);
 }
 
 #[tokio::test]
-async fn test_bypass_functionality_with_title_bypass() {
-    // Test the complete bypass flow: validation, logging, and comment generation
-    let provider = DynamicMockGitProvider::new();
-
-    // Create a PR with invalid title but user who can bypass
-    let pr = PullRequest {
-        number: 123,
-        title: "invalid title format".to_string(), // Invalid conventional commit format
-        draft: false,
-        body: Some("Fixes #456".to_string()), // Valid work item reference
-        author: Some(User {
-            id: 789,
-            login: "bypass-user".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
+async fn test_process_pull_request_lists_checks_in_success_summary_when_configured() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("feat: add login"));
+    let config = CurrentPullRequestValidationConfiguration {
+        check_summary: CheckSummaryConfig {
+            list_checks_on_success: true,
+            ..Default::default()
+        },
+        ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Add the PR to the mock provider
-    let mut provider_mut = provider;
-    provider_mut.add_pull_request(pr.clone());
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Create MergeWarden with bypass rules allowing "bypass-user" to bypass title validation
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .summary
+        .starts_with("All PR requirements satisfied.\n\n✅ PR title · ✅ Work item · "));
+    assert!(updates[0].summary.contains("➖ Checklist (skipped)"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_adds_rerun_action_when_configured() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
     let config = CurrentPullRequestValidationConfiguration {
-        bypass_rules: BypassRules::new(
-            BypassRule::new(true, vec!["bypass-user".to_string()]), // Title bypass enabled
-            BypassRule::new(false, vec![]),                         // Work item bypass disabled
-        ),
+        check_summary: CheckSummaryConfig {
+            rerun_action: true,
+            ..Default::default()
+        },
         ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::with_config(provider_mut, config);
-
-    // Process the pull request
-    let result = warden
-        .process_pull_request("owner", "repo", 123)
+    warden
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result indicates success due to bypass
-    assert!(result.title_valid, "Title should be valid due to bypass");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be naturally valid"
-    );
-
-    // Verify bypass information is recorded
-    assert_eq!(
-        result.bypasses_used.len(),
-        1,
-        "Should have one bypass recorded"
-    );
-    let bypass_info = &result.bypasses_used[0];
-    assert_eq!(bypass_info.user, "bypass-user");
-    assert_eq!(bypass_info.rule_type, BypassRuleType::TitleConvention);
-}
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].actions, vec![CheckRunAction::rerun()]);
 
-#[tokio::test]
-async fn test_bypass_functionality_with_work_item_bypass() {
-    // Test bypass for work item validation
-    let provider = DynamicMockGitProvider::new();
+    // Without the setting the check run has no buttons
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::new(provider);
 
-    // Create a PR with valid title but no work item reference, user who can bypass work item validation
-    let pr = PullRequest {
-        number: 124,
-        title: "feat: add new feature".to_string(), // Valid conventional commit format
-        draft: false,
-        body: Some("This is an emergency fix".to_string()), // No work item reference
-        author: Some(User {
-            id: 890,
-            login: "emergency-user".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    };
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Add the PR to the mock provider
-    let mut provider_mut = provider;
-    provider_mut.add_pull_request(pr.clone());
+    assert!(warden.provider.get_check_status_updates()[0]
+        .actions
+        .is_empty());
+}
 
-    // Create MergeWarden with bypass rules allowing "emergency-user" to bypass work item validation
+#[tokio::test]
+async fn test_process_pull_request_does_not_list_checks_in_failure_summary() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
     let config = CurrentPullRequestValidationConfiguration {
-        bypass_rules: BypassRules::new(
-            BypassRule::new(false, vec![]), // Title bypass disabled
-            BypassRule::new(true, vec!["emergency-user".to_string()]), // Work item bypass enabled
-        ),
+        check_summary: CheckSummaryConfig {
+            list_checks_on_success: true,
+            ..Default::default()
+        },
         ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::with_config(provider_mut, config);
-
-    // Process the pull request
-    let result = warden
-        .process_pull_request("owner", "repo", 124)
+    warden
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result indicates success due to bypass
-    assert!(result.title_valid, "Title should be naturally valid");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be valid due to bypass"
-    );
-
-    // Verify bypass information is recorded
-    assert_eq!(
-        result.bypasses_used.len(),
-        1,
-        "Should have one bypass recorded"
-    );
-    let bypass_info = &result.bypasses_used[0];
-    assert_eq!(bypass_info.user, "emergency-user");
-    assert_eq!(bypass_info.rule_type, BypassRuleType::WorkItemReference);
+    let updates = warden.provider.get_check_status_updates();
+    assert!(!updates[0].summary.contains(" · "));
 }
 
 #[tokio::test]
-async fn test_bypass_functionality_with_multiple_bypasses() {
-    // Test multiple bypasses in the same PR
-    let provider = DynamicMockGitProvider::new();
-
-    // Create a PR with both invalid title and no work item reference, user who can bypass both
-    let pr = PullRequest {
-        number: 125,
-        title: "urgent fix".to_string(), // Invalid conventional commit format
-        draft: false,
-        body: Some("Emergency production fix".to_string()), // No work item reference
-        author: Some(User {
-            id: 999,
-            login: "admin-user".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
+async fn test_process_pull_request_check_run_name_falls_back_when_unsupported() {
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(review_thread_test_pr());
+    let config = CurrentPullRequestValidationConfiguration {
+        check_summary: CheckSummaryConfig {
+            check_run_name: Some("Merge Warden PR checks".to_string()),
+            keep_legacy_check_run: true,
+            ..Default::default()
+        },
+        ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Add the PR to the mock provider
-    let mut provider_mut = provider;
-    provider_mut.add_pull_request(pr.clone());
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Create MergeWarden with bypass rules allowing "admin-user" to bypass both validations
+    // The platform cannot name check runs, so only the default check run is posted
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].check_name, DEFAULT_CHECK_RUN_NAME);
+}
+
+#[tokio::test]
+async fn test_process_pull_request_reports_skip_reasons_for_disabled_checks() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
     let config = CurrentPullRequestValidationConfiguration {
-        bypass_rules: BypassRules::new(
-            BypassRule::new(true, vec!["admin-user".to_string()]), // Title bypass enabled
-            BypassRule::new(true, vec!["admin-user".to_string()]), // Work item bypass enabled
-        ),
+        enforce_title_convention: true,
+        enforce_work_item_references: false,
         ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::with_config(provider_mut, config);
-
-    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 125)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result indicates success due to bypasses
-    assert!(result.title_valid, "Title should be valid due to bypass");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be valid due to bypass"
-    );
-
-    // Verify both bypasses are recorded
-    assert_eq!(
-        result.bypasses_used.len(),
-        2,
-        "Should have two bypasses recorded"
-    );
-
-    // Check that we have both bypass types
-    let bypass_types: std::collections::HashSet<_> = result
-        .bypasses_used
+    // A disabled check counts as passing but is reported as skipped, unlike a passing check
+    assert!(result.title_valid);
+    assert!(result.work_item_referenced);
+    assert!(result.skipped_checks.is_empty());
+    assert!(result.skip_reasons.contains(&(
+        CheckKind::WorkItem,
+        "Work item validation is not enabled".to_string()
+    )));
+    assert!(result.skip_reasons.contains(&(
+        CheckKind::Checklist,
+        "Checklist validation is not enabled".to_string()
+    )));
+    assert!(!result
+        .skip_reasons
         .iter()
-        .map(|info| &info.rule_type)
-        .collect();
-    assert!(bypass_types.contains(&BypassRuleType::TitleConvention));
-    assert!(bypass_types.contains(&BypassRuleType::WorkItemReference));
-
-    // Verify all bypasses are attributed to the same user
-    for bypass_info in &result.bypasses_used {
-        assert_eq!(bypass_info.user, "admin-user");
-    }
+        .any(|(kind, _)| *kind == CheckKind::Title));
 }
 
 #[tokio::test]
-async fn test_no_bypass_when_user_not_authorized() {
-    // Test that bypasses are not applied when user is not in the allowed list
-    let provider = DynamicMockGitProvider::new();
-
-    // Create a PR with invalid title, user who CANNOT bypass
-    let pr = PullRequest {
-        number: 126,
-        title: "bad title".to_string(), // Invalid conventional commit format
-        draft: false,
-        body: Some("Fixes #789".to_string()), // Valid work item reference
-        author: Some(User {
-            id: 111,
-            login: "regular-user".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    };
-
-    // Add the PR to the mock provider
-    let mut provider_mut = provider;
-    provider_mut.add_pull_request(pr.clone());
-
-    // Create MergeWarden with bypass rules NOT allowing "regular-user"
+async fn test_process_pull_request_report_only_failure_is_neutral() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: "not a conventional title".to_string(),
+        ..review_thread_test_pr()
+    });
+    provider.set_review_threads(vec![review_thread("T_1", false)]);
     let config = CurrentPullRequestValidationConfiguration {
-        bypass_rules: BypassRules::new(
-            BypassRule::new(true, vec!["admin-user".to_string()]), // Only admin-user can bypass
-            BypassRule::new(false, vec![]),                        // Work item bypass disabled
-        ),
+        enforce_title_convention: true,
+        invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 1,
+        },
+        check_execution: CheckExecutionConfig {
+            short_circuit_on_failure: true,
+            report_only: vec![CheckKind::Title],
+            ..Default::default()
+        },
         ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::with_config(provider_mut, config);
-
-    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 126)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result shows validation failure (no bypass applied)
-    assert!(!result.title_valid, "Title should be invalid (no bypass)");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be naturally valid"
-    );
-
-    // Verify no bypasses are recorded
+    assert!(result.title_valid);
+    assert_eq!(result.report_only_failures, vec![CheckKind::Title]);
+    // A report-only failure does not short-circuit the later checks
+    assert!(result.skipped_checks.is_empty());
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "neutral");
     assert_eq!(
-        result.bypasses_used.len(),
-        0,
-        "Should have no bypasses recorded"
-    );
-}
+        updates[0].summary,
+        "All PR requirements satisfied. Report-only checks that would fail: PR title."
+    );
+    assert!(updates[0].text.contains(
+        "| ℹ️ | PR title | Report only: Title does not follow the Conventional Commits format"
+    ));
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Review threads | 1 of 1 review thread(s) unresolved (limit 1) |"));
+    assert!(!warden
+        .provider
+        .get_labels()
+        .iter()
+        .any(|l| l.name == TITLE_INVALID_LABEL));
+}
 
 #[tokio::test]
-async fn test_check_status_with_bypass_information() {
-    // Test that check status includes bypass information in the summary
-    let provider = DynamicMockGitProvider::new();
-
-    // Create a PR with invalid title and no work item reference, user who can bypass both
-    let pr = PullRequest {
-        number: 200,
-        title: "urgent fix".to_string(), // Invalid conventional commit format
-        draft: false,
-        body: Some("Emergency production fix".to_string()), // No work item reference
-        author: Some(User {
-            id: 200,
-            login: "emergency-admin".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
+async fn test_process_pull_request_report_only_does_not_hide_enforced_failures() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: "not a conventional title".to_string(),
+        ..review_thread_test_pr()
+    });
+    provider.set_review_threads(vec![review_thread("T_1", false)]);
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 0,
+        },
+        check_execution: CheckExecutionConfig {
+            report_only: vec![CheckKind::Title],
+            ..Default::default()
+        },
+        ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Add the PR to the mock provider
-    let mut provider_mut = provider;
-    provider_mut.add_pull_request(pr.clone());
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Create MergeWarden with bypass rules allowing "emergency-admin" to bypass both validations
+    assert!(!result.review_threads_resolved);
+    assert_eq!(result.report_only_failures, vec![CheckKind::Title]);
+    assert_eq!(result.failing_rules, vec![CheckKind::ReviewThreads]);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+    assert_eq!(updates[0].summary, "PR has 1 unresolved review thread(s).");
+}
+
+#[tokio::test]
+async fn test_process_pull_request_lists_failing_rules_in_check_order() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: "not a conventional title".to_string(),
+        ..review_thread_test_pr()
+    });
+    provider.set_review_threads(vec![review_thread("T_1", false)]);
     let config = CurrentPullRequestValidationConfiguration {
-        bypass_rules: BypassRules::new(
-            BypassRule::new(true, vec!["emergency-admin".to_string()]), // Title bypass enabled
-            BypassRule::new(true, vec!["emergency-admin".to_string()]), // Work item bypass enabled
-        ),
+        enforce_title_convention: true,
+        review_threads: ReviewThreadPolicyConfig {
+            enabled: true,
+            max_unresolved: 0,
+        },
+        check_execution: CheckExecutionConfig {
+            order: vec![CheckKind::ReviewThreads],
+            ..Default::default()
+        },
         ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::with_config(provider_mut, config);
-
-    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 200)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result indicates success due to bypass
-    assert!(result.title_valid, "Title should be valid due to bypass");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be valid due to bypass"
-    );
-
-    // Verify bypass information is recorded
     assert_eq!(
-        result.bypasses_used.len(),
-        2,
-        "Should have two bypasses recorded"
+        result.failing_rules,
+        vec![CheckKind::ReviewThreads, CheckKind::Title]
     );
-
-    // Note: We can't directly test the check status text here since it's passed to the mock provider,
-    // but we can verify that the bypass information is properly collected and would be included
-    // in the summary based on our implementation.
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
 }
 
 #[tokio::test]
-async fn test_check_status_text_formatting() {
-    // Test the smart text formatting for check status
-    let provider = DynamicMockGitProvider::new();
+async fn test_process_pull_request_has_no_failing_rules_when_valid() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
+    let warden = MergeWarden::with_config(
+        provider,
+        CurrentPullRequestValidationConfiguration {
+            enforce_title_convention: true,
+            ..Default::default()
+        },
+    );
 
-    // Create a PR that's valid (no comments needed)
-    let pr = PullRequest {
-        number: 201,
-        title: "feat: add new feature".to_string(), // Valid conventional commit format
-        draft: false,
-        body: Some("Fixes #123".to_string()), // Valid work item reference
-        author: Some(User {
-            id: 201,
-            login: "regular-dev".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    };
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Add the PR to the mock provider
-    let mut provider_mut = provider;
-    provider_mut.add_pull_request(pr.clone());
+    assert!(result.failing_rules.is_empty());
+}
 
-    // Create MergeWarden with default config (no bypasses)
-    let config = CurrentPullRequestValidationConfiguration::default();
-    let warden = MergeWarden::with_config(provider_mut, config);
+#[tokio::test]
+async fn test_process_pull_request_labels_dependency_update() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: "chore(deps): bump tokio to 1.40".to_string(),
+        ..review_thread_test_pr()
+    });
+    let config = CurrentPullRequestValidationConfiguration {
+        dependency_updates: DependencyUpdateConfig {
+            enabled: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 201)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result indicates success without bypasses
-    assert!(result.title_valid, "Title should be naturally valid");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be naturally valid"
-    );
-    assert_eq!(
-        result.bypasses_used.len(),
-        0,
-        "Should have no bypasses recorded"
-    );
-
-    // The check status should show "All PR requirements satisfied." without bypass mention
-    // since no bypasses were used
+    assert!(result.labels.contains(&"dependencies".to_string()));
+    assert!(warden
+        .provider
+        .get_labels()
+        .iter()
+        .any(|l| l.name == "dependencies"));
 }
 
-#[tokio::test]
-async fn test_check_status_bypass_information_formatting() {
-    // Test the specific messages included in check status when bypasses are used
-    let provider = DynamicMockGitProvider::new();
+fn title_label_bypass_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["maintainer".to_string()]).with_label("skip-checks"),
+            BypassRule::default(),
+        ),
+        ..Default::default()
+    }
+}
 
-    // Create a PR with invalid title but author who can bypass
-    let pr = PullRequest {
-        number: 301,
-        title: "invalid title format".to_string(), // Invalid conventional commit format
+fn invalid_title_pr() -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: "not a conventional title".to_string(),
         draft: false,
-        body: Some("Fixes #123".to_string()), // Valid work item reference
+        body: Some("Fixes #1".to_string()),
         author: Some(User {
-            id: 301,
-            login: "bypass-user".to_string(),
+            id: 1,
+            login: "contributor".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
-    };
-
-    // Add the PR to the mock provider
-    let mut provider_mut = provider;
-    provider_mut.add_pull_request(pr.clone());
-
-    // Create MergeWarden with bypass enabled for title validation for this user
-    let config = CurrentPullRequestValidationConfiguration {
-        bypass_rules: BypassRules::new(
-            BypassRule::new(true, vec!["bypass-user".to_string()]),
-            BypassRule::new(false, vec![]),
-        ),
-        ..Default::default()
-    };
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
 
-    let warden = MergeWarden::with_config(provider_mut, config);
+#[tokio::test]
+async fn test_bypass_label_applied_by_allowed_user_bypasses_title_check() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(invalid_title_pr());
+    provider.apply_label_as("skip-checks", "maintainer");
+    let warden = MergeWarden::with_config(provider, title_label_bypass_config());
 
-    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 301)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result indicates one bypass was used
-    assert!(result.title_valid, "Title should be valid due to bypass");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be naturally valid"
-    );
-    assert_eq!(
-        result.bypasses_used.len(),
-        1,
-        "Should have one bypass recorded"
-    );
+    assert!(result.title_valid);
+    assert_eq!(result.bypasses_used.len(), 1);
+    assert_eq!(result.bypasses_used[0].user, "maintainer");
     assert_eq!(
         result.bypasses_used[0].rule_type,
         BypassRuleType::TitleConvention
     );
-    assert_eq!(result.bypasses_used[0].user_login(), Some("bypass-user"));
-
-    // Verify check was marked as successful with bypass indication
-    let updates = warden.provider.get_check_status_updates();
-    assert_eq!(updates.len(), 1, "Should have one check status update");
-
-    let update = &updates[0];
-    assert_eq!(update.conclusion, "success");
-    assert_eq!(update.title, "Merge Warden");
-    assert_eq!(
-        update.summary,
-        "All PR requirements satisfied (1 validation bypassed)."
-    );
 }
 
 #[tokio::test]
-async fn test_check_status_multiple_bypasses_formatting() {
-    // Test the message when multiple bypasses are used
-    let provider = DynamicMockGitProvider::new();
+async fn test_bypass_label_applied_by_other_user_is_ignored() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(invalid_title_pr());
+    provider.apply_label_as("skip-checks", "contributor");
+    let warden = MergeWarden::with_config(provider, title_label_bypass_config());
 
-    // Create a PR with invalid title and missing work item but author who can bypass both
-    let pr = PullRequest {
-        number: 302,
-        title: "invalid title format".to_string(), // Invalid conventional commit format
-        draft: false,
-        body: Some("No work item reference here".to_string()), // No work item reference
-        author: Some(User {
-            id: 302,
-            login: "super-bypass-user".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    };
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    // Add the PR to the mock provider
-    let mut provider_mut = provider;
-    provider_mut.add_pull_request(pr.clone());
+    assert!(!result.title_valid);
+    assert!(result.bypasses_used.is_empty());
+}
 
-    // Create MergeWarden with bypasses enabled for both title and work item validation
+#[tokio::test]
+async fn test_bypass_label_applied_by_allowed_user_bypasses_size_check() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        title: "feat: add login".to_string(),
+        ..invalid_title_pr()
+    });
+    provider.apply_label_as("skip-size", "maintainer");
     let config = CurrentPullRequestValidationConfiguration {
-        bypass_rules: BypassRules::new(
-            BypassRule::new(true, vec!["super-bypass-user".to_string()]),
-            BypassRule::new(true, vec!["super-bypass-user".to_string()]),
+        bypass_rules: BypassRules::new_with_size(
+            BypassRule::default(),
+            BypassRule::default(),
+            BypassRule::new(true, vec!["maintainer".to_string()]).with_label("skip-size"),
         ),
+        pr_size_check: crate::config::PrSizeCheckConfig {
+            enabled: true,
+            ..Default::default()
+        },
         ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::with_config(provider_mut, config);
-
-    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 302)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result indicates two bypasses were used
-    assert!(result.title_valid, "Title should be valid due to bypass");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be valid due to bypass"
-    );
-    assert_eq!(
-        result.bypasses_used.len(),
-        2,
-        "Should have two bypasses recorded"
-    );
+    assert_eq!(result.bypasses_used.len(), 1);
+    assert_eq!(result.bypasses_used[0].user, "maintainer");
+    assert_eq!(result.bypasses_used[0].rule_type, BypassRuleType::PrSize);
+}
+
+fn disable_label_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::default().with_all(
+            BypassRule::new(true, vec!["maintainer".to_string()])
+                .with_label("merge-warden:disabled"),
+        ),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_disable_label_applied_by_allowed_user_skips_all_processing() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(invalid_title_pr());
+    provider.apply_label_as("merge-warden:disabled", "maintainer");
+    let warden = MergeWarden::with_config(provider, disable_label_config());
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.title_valid);
+    assert!(result.labels.is_empty());
+    assert_eq!(result.bypasses_used.len(), 1);
+    assert_eq!(result.bypasses_used[0].user, "maintainer");
+    assert_eq!(result.bypasses_used[0].rule_type, BypassRuleType::AllRules);
 
-    // Verify check was marked as successful with multiple bypass indication
     let updates = warden.provider.get_check_status_updates();
-    assert_eq!(updates.len(), 1, "Should have one check status update");
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].conclusion, "neutral");
+    assert!(updates[0].text.contains("`merge-warden:disabled`"));
+    assert!(updates[0].text.contains("@maintainer"));
 
-    let update = &updates[0];
-    assert_eq!(update.conclusion, "success");
-    assert_eq!(update.title, "Merge Warden");
-    assert_eq!(
-        update.summary,
-        "All PR requirements satisfied (2 validations bypassed)."
-    );
+    // Only the disable label itself remains; no labels or comments were added.
+    let labels = warden.provider.get_labels();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].name, "merge-warden:disabled");
+    assert!(warden.provider.get_comments().is_empty());
 }
 
 #[tokio::test]
-async fn test_process_pull_request_smart_label_detection() {
-    // Setup provider with existing repository labels
+async fn test_disable_label_applied_by_other_user_is_ignored() {
     let provider = MockGitProvider::new();
+    provider.set_pull_request(invalid_title_pr());
+    provider.apply_label_as("merge-warden:disabled", "contributor");
+    let warden = MergeWarden::with_config(provider, disable_label_config());
 
-    // Create a PR with conventional commit type "feat"
-    let pr = PullRequest {
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.title_valid);
+    assert!(result.bypasses_used.is_empty());
+    let updates = warden.provider.get_check_status_updates();
+    assert_ne!(updates[0].conclusion, "neutral");
+}
+
+#[tokio::test]
+async fn test_process_pull_request_shows_config_source_when_enabled() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
+    let config = CurrentPullRequestValidationConfiguration {
+        show_config_source: true,
+        config_source: ConfigSource {
+            repository_file: Some(".github/merge-warden.toml".to_string()),
+            org_policy_file: None,
+            matched_conditional_policies: 0,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let updates = warden.provider.get_check_status_updates();
+    assert!(updates[0]
+        .text
+        .ends_with("\n\n---\n\n_Config: repo .github/merge-warden.toml + application defaults_"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_omits_config_source_by_default() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(review_thread_test_pr());
+    let warden = MergeWarden::new(provider);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let updates = warden.provider.get_check_status_updates();
+    assert!(!updates[0].text.contains("Config:"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_check_summary_without_emoji_uses_severity_words() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
         number: 1,
-        title: "feat(auth): add GitHub login functionality".to_string(),
+        title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("This PR adds GitHub login functionality. Fixes #123".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
+        body: Some("No work item reference".to_string()),
+        author: None,
         milestone_number: None,
         head_sha: String::new(),
-    };
-    provider.set_pull_request(pr);
-
-    // Configure smart label detection
-    let change_type_config = ChangeTypeLabelConfig {
-        enabled: true,
-        conventional_commit_mappings: ConventionalCommitMappings {
-            feat: vec!["enhancement".to_string(), "feature".to_string()],
-            fix: vec!["bug".to_string(), "bugfix".to_string()],
-            docs: vec!["documentation".to_string()],
-            style: vec!["style".to_string()],
-            refactor: vec!["refactor".to_string()],
-            perf: vec!["performance".to_string()],
-            test: vec!["test".to_string()],
-            chore: vec!["chore".to_string()],
-            ci: vec!["ci".to_string()],
-            build: vec!["build".to_string()],
-            revert: vec!["revert".to_string()],
-        },
-        detection_strategy: LabelDetectionStrategy {
-            exact_match: true,
-            prefix_match: true,
-            description_match: true,
-            common_prefixes: vec!["type:".to_string(), "kind:".to_string()],
-        },
-        fallback_label_settings: FallbackLabelSettings {
-            name_format: "type: {change_type}".to_string(),
-            color_scheme: HashMap::from([
-                ("feat".to_string(), "00ff00".to_string()),
-                ("fix".to_string(), "ff0000".to_string()),
-            ]),
-            create_if_missing: true,
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+    let config = CurrentPullRequestValidationConfiguration {
+        check_summary: CheckSummaryConfig {
+            use_emoji: false,
+            work_item_severity: CheckSeverity::Warning,
+            ..Default::default()
         },
-        keyword_labels: KeywordLabelsConfig::default(),
+        ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert!(update
+        .text
+        .contains("| PASS | PR title | Title follows the Conventional Commits format |"));
+    assert!(update
+        .text
+        .contains("| WARN | Work item | PR description is missing a work item reference |"));
+    assert!(!update.text.contains('✅'));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_reports_matched_work_item_pattern() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Implements PROJ-42".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
     let config = CurrentPullRequestValidationConfiguration {
-        enforce_title_convention: true,
-        title_pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
-        invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
-        enforce_work_item_references: true,
-        work_item_reference_pattern: WORK_ITEM_REGEX.to_string(),
-        missing_work_item_label: Some(MISSING_WORK_ITEM_LABEL.to_string()),
-        pr_size_check: Default::default(),
-        change_type_labels: Some(change_type_config),
-        bypass_rules: BypassRules::default(),
+        work_item_reference_pattern: vec![WORK_ITEM_REGEX.to_string(), r"PROJ-\d+".to_string()]
+            .into(),
         ..Default::default()
     };
-
-    // Create a MergeWarden instance with smart label configuration
     let warden = MergeWarden::with_config(provider, config);
 
-    // Process the PR
-    let result = warden
+    warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // Verify the result
-    assert!(result.title_valid, "Title should be valid");
-    assert!(
-        result.work_item_referenced,
-        "Work item should be referenced"
-    );
-    assert!(result.size_valid, "Size should be valid");
-
-    // For now, just verify the basic functionality is working
-    // TODO: Add repository label support to MockGitProvider to test smart label detection
-    // The smart label detection logic should be working in the actual code
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "success");
+    assert!(update.text.contains(
+        r"| ✅ | Work item | PR description references a work item (matched `PROJ-\d+`) |"
+    ));
 }
 
 #[tokio::test]
-async fn test_process_pull_request_smart_label_detection_with_audit_logging() {
-    // This test validates task 5.0 integration: enhanced error handling, performance monitoring, and audit logging
+async fn test_process_pull_request_both_invalid() {
+    // Create a mock provider
     let provider = MockGitProvider::new();
 
-    // Create a PR with conventional commit type "feat"
+    // Set up a PR with invalid title and missing work item reference
     let pr = PullRequest {
         number: 1,
-        title: "feat(auth): add GitHub OAuth integration".to_string(),
+        title: "invalid title".to_string(), // Missing conventional commit format
         draft: false,
-        body: Some(
-            "This PR adds OAuth functionality for better authentication. Fixes #789".to_string(),
-        ),
+        body: Some("No work item reference".to_string()),
         author: Some(User {
-            id: 123,
-            login: "smart-dev".to_string(),
+            id: 456,
+            login: "developer123".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     provider.set_pull_request(pr);
 
-    // Configure comprehensive smart label detection
-    let change_type_config = ChangeTypeLabelConfig {
-        enabled: true,
-        conventional_commit_mappings: ConventionalCommitMappings {
-            feat: vec![
-                "enhancement".to_string(),
-                "feature".to_string(),
-                "new-feature".to_string(),
-            ],
-            fix: vec![
-                "bug".to_string(),
-                "bugfix".to_string(),
-                "hotfix".to_string(),
-            ],
-            docs: vec!["documentation".to_string(), "docs".to_string()],
-            style: vec!["style".to_string(), "formatting".to_string()],
-            refactor: vec!["refactor".to_string(), "refactoring".to_string()],
-            perf: vec!["performance".to_string(), "optimization".to_string()],
-            test: vec!["test".to_string(), "testing".to_string()],
-            chore: vec!["chore".to_string(), "maintenance".to_string()],
-            ci: vec!["ci".to_string(), "continuous-integration".to_string()],
-            build: vec!["build".to_string(), "build-system".to_string()],
-            revert: vec!["revert".to_string(), "rollback".to_string()],
-        },
-        detection_strategy: LabelDetectionStrategy {
-            exact_match: true,
-            prefix_match: true,
-            description_match: true,
-            common_prefixes: vec![
-                "type:".to_string(),
-                "kind:".to_string(),
-                "category:".to_string(),
-                "tag:".to_string(),
-            ],
-        },
-        fallback_label_settings: FallbackLabelSettings {
-            name_format: "type: {change_type}".to_string(),
-            color_scheme: HashMap::from([
-                ("feat".to_string(), "0366d6".to_string()),
-                ("fix".to_string(), "d73a4a".to_string()),
-                ("docs".to_string(), "0075ca".to_string()),
-                ("style".to_string(), "f9d0c4".to_string()),
-                ("refactor".to_string(), "a2eeef".to_string()),
-                ("perf".to_string(), "7057ff".to_string()),
-                ("test".to_string(), "0e8a16".to_string()),
-                ("chore".to_string(), "fef2c0".to_string()),
-                ("ci".to_string(), "0052cc".to_string()),
-                ("build".to_string(), "1d76db".to_string()),
-                ("revert".to_string(), "b60205".to_string()),
-            ]),
-            create_if_missing: true,
-        },
-        keyword_labels: KeywordLabelsConfig::default(),
-    };
-
-    let config = CurrentPullRequestValidationConfiguration {
-        enforce_title_convention: true,
-        title_pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
-        invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
-        enforce_work_item_references: true,
-        work_item_reference_pattern: WORK_ITEM_REGEX.to_string(),
-        missing_work_item_label: Some(MISSING_WORK_ITEM_LABEL.to_string()),
-        pr_size_check: Default::default(),
-        change_type_labels: Some(change_type_config),
-        bypass_rules: BypassRules::default(),
-        ..Default::default()
-    };
-
-    // Create a MergeWarden instance with enhanced smart label configuration
-    let warden = MergeWarden::with_config(provider, config);
+    // Create a MergeWarden instance
+    let warden = MergeWarden::new(provider);
 
     // Process the PR
     let result = warden
-        .process_pull_request("test-org", "smart-repo", 1)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
     // Verify the result
-    assert!(result.title_valid, "Title should be valid");
+    assert!(!result.title_valid, "Title should be invalid");
     assert!(
-        result.work_item_referenced,
-        "Work item should be referenced"
+        !result.work_item_referenced,
+        "Work item should not be referenced"
     );
-    assert!(result.size_valid, "Size should be valid");
 
-    // Verify check status update includes smart label information
-    let updates = warden.provider.get_check_status_updates();
-    assert_eq!(updates.len(), 1, "Should have one check status update");
-
-    let update = &updates[0];
-    assert_eq!(update.conclusion, "success");
-    assert_eq!(update.title, "Merge Warden");
-    assert_eq!(update.summary, "All PR requirements satisfied.");
-
-    // The text should include smart label detection information
+    // Verify both labels were added
+    let labels = warden.provider.get_labels();
     assert!(
-        update.text.contains("Smart Label Detection") || update.text.contains("Legacy Labeling"),
-        "Check status should include labeling information"
+        labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
+        "Invalid title label should be added"
+    );
+    assert!(
+        labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
+        "Missing work item label should be added"
     );
 
-    // Verify that labels were processed (even if MockGitProvider doesn't simulate repository labels,
-    // the processing should complete without errors)
-    // In a real scenario with actual repository labels, we would see smart detection working
-
-    info!("Smart label detection integration test completed successfully");
+    // Verify both comments were added
+    let comments = warden.provider.get_comments();
+    assert!(
+        comments
+            .iter()
+            .any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
+        "Title comment should be added"
+    );
+    assert!(
+        comments
+            .iter()
+            .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)),
+        "Work item comment should be added"
+    );
 }
 
-//  check_wip_status tests
-
 #[tokio::test]
-async fn test_check_wip_status_detects_wip_prefix_in_title() {
-    use crate::config::WipCheckConfig;
-
-    let provider = ErrorMockGitProvider::new();
-    let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
-    let warden = MergeWarden::with_config(provider, config);
-
-    let pr = PullRequest {
-        number: 1,
-        title: "WIP: add login feature".to_string(),
-        draft: false,
-        body: Some("Fixes #123".to_string()),
-        author: None,
-        milestone_number: None,
-        head_sha: String::new(),
-    };
+async fn test_handle_title_validation_invalid_to_valid() {
+    // Create a mock provider
+    let provider = MockGitProvider::new();
 
-    assert!(warden.check_wip_status(&pr), "Should detect 'WIP:' prefix");
-}
+    // Add the invalid title label
+    provider
+        .add_labels("owner", "repo", 1, &[TITLE_INVALID_LABEL.to_string()])
+        .await
+        .unwrap();
 
-#[tokio::test]
-async fn test_check_wip_status_detects_bracketed_wip_in_title() {
-    use crate::config::WipCheckConfig;
+    // Add a title comment
+    let comment = format!(
+            "{}\n## Invalid PR Title Format\n\nYour PR title doesn't follow the Conventional Commits format.",
+            TITLE_COMMENT_MARKER
+        );
+    provider
+        .add_comment("owner", "repo", 1, &comment)
+        .await
+        .unwrap();
 
-    let provider = ErrorMockGitProvider::new();
-    let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
-    let warden = MergeWarden::with_config(provider, config);
+    // Create a MergeWarden instance
+    let warden = MergeWarden::new(provider);
 
+    // Set up a PR
     let pr = PullRequest {
-        number: 2,
-        title: "[WIP] implement user auth".to_string(),
+        number: 1,
+        title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("Fixes #456".to_string()),
-        author: None,
+        body: Some("Test body".to_string()),
+        author: Some(User {
+            id: 456,
+            login: "developer123".to_string(),
+        }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
-    assert!(
-        warden.check_wip_status(&pr),
-        "Should detect '[WIP]' in title"
-    );
-}
-
-#[tokio::test]
-async fn test_check_wip_status_returns_false_for_clean_title() {
-    use crate::config::WipCheckConfig;
-
-    let provider = ErrorMockGitProvider::new();
-    let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
-    let warden = MergeWarden::with_config(provider, config);
-
-    let pr = PullRequest {
-        number: 3,
-        title: "feat(auth): add login functionality".to_string(),
-        draft: false,
-        body: Some("Fixes #789".to_string()),
-        author: None,
-        milestone_number: None,
-        head_sha: String::new(),
+    // Handle title validation with valid title
+    let validation_result = TitleValidationResult {
+        validation: ValidationResult::valid(),
+        diagnosis: None,
     };
+    warden
+        .communicate_pr_title_validity_status("owner", "repo", &pr, &validation_result)
+        .await;
 
+    // Verify the label was removed
+    let labels = warden.provider.get_labels();
     assert!(
-        !warden.check_wip_status(&pr),
-        "Should not flag a clean conventional-commit title"
+        !labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
+        "Invalid title label should be removed"
     );
-}
-
-#[tokio::test]
-async fn test_check_wip_status_detects_custom_description_pattern() {
-    use crate::config::WipCheckConfig;
-
-    let provider = ErrorMockGitProvider::new();
-    let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            wip_description_patterns: vec!["DO NOT MERGE".to_string()],
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
-    let warden = MergeWarden::with_config(provider, config);
-
-    let pr = PullRequest {
-        number: 4,
-        title: "feat: some feature".to_string(),
-        draft: false,
-        body: Some("DO NOT MERGE: waiting for design approval\nFixes #100".to_string()),
-        author: None,
-        milestone_number: None,
-        head_sha: String::new(),
-    };
 
+    // Verify the comment was removed
+    let comments = warden.provider.get_comments();
     assert!(
-        warden.check_wip_status(&pr),
-        "Should detect custom description pattern"
+        !comments
+            .iter()
+            .any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
+        "Title comment should be removed"
     );
 }
 
 #[tokio::test]
-async fn test_check_wip_status_no_body_with_description_pattern_returns_false() {
-    use crate::config::WipCheckConfig;
-
-    let provider = ErrorMockGitProvider::new();
-    let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            wip_description_patterns: vec!["DO NOT MERGE".to_string()],
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
-    let warden = MergeWarden::with_config(provider, config);
+async fn test_process_pull_request_custom_config_disabled_checks() {
+    // Create a mock provider
+    let provider = MockGitProvider::new();
 
+    // Set up a PR with invalid title and missing work item reference
     let pr = PullRequest {
-        number: 5,
-        title: "feat: some feature".to_string(),
-        draft: false,
-        body: None, // No body
-        author: None,
-        milestone_number: None,
-        head_sha: String::new(),
-    };
-
-    assert!(
-        !warden.check_wip_status(&pr),
-        "No body means description patterns cannot match"
-    );
-}
-
-//  process_pull_request WIP integration tests
-
-#[tokio::test]
-async fn test_process_pull_request_wip_title_returns_wip_detected_true() {
-    use crate::config::WipCheckConfig;
-
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 500,
-        title: "WIP: add new feature".to_string(),
+        number: 1,
+        title: "invalid title".to_string(), // Missing conventional commit format
         draft: false,
-        body: Some("Fixes #123".to_string()),
+        body: Some("No work item reference".to_string()),
         author: Some(User {
-            id: 1,
-            login: "dev".to_string(),
+            id: 456,
+            login: "developer123".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
-    });
-
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    provider.set_pull_request(pr); // Create a custom configuration with disabled checks
     let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
+        enforce_title_convention: false,
+        title_pattern: "ab".to_string(),
+        invalid_title_label: None,
+        enforce_work_item_references: false,
+        work_item_reference_pattern: "cd".into(),
+        missing_work_item_label: None,
+        pr_size_check: crate::config::PrSizeCheckConfig::default(),
+        change_type_labels: None, // Use default behavior for tests
+        bypass_rules: BypassRules::default(),
+        ..Default::default()
     };
 
+    // Create a MergeWarden instance with custom config
     let warden = MergeWarden::with_config(provider, config);
+
+    // Process the PR
     let result = warden
-        .process_pull_request("owner", "repo", 500)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
+    // Verify the result - both should be valid since checks are disabled
     assert!(
-        result.wip_detected,
-        "WIP PR should have wip_detected = true"
+        result.title_valid,
+        "Title should be valid when check is disabled"
+    );
+    assert!(
+        result.work_item_referenced,
+        "Work item should be referenced when check is disabled"
     );
-}
 
-#[tokio::test]
-async fn test_process_pull_request_wip_sets_failure_check_status() {
-    use crate::config::WipCheckConfig;
+    // Verify no labels were added
+    let labels = warden.provider.get_labels();
+    assert!(
+        !labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
+        "Invalid title label should not be added when check is disabled"
+    );
+    assert!(
+        !labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
+        "Missing work item label should not be added when check is disabled"
+    );
 
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 501,
-        title: "[WIP] implement payment flow".to_string(),
-        draft: false,
-        body: Some("Fixes #200".to_string()),
-        author: Some(User {
-            id: 2,
-            login: "dev2".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    });
+    // Verify no comments were added
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
+        "Title comment should not be added when check is disabled"
+    );
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)),
+        "Work item comment should not be added when check is disabled"
+    );
+}
 
-    let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
+#[tokio::test]
+async fn test_process_pull_request_existing_labels_comments() {
+    // Create a mock provider
+    let provider = MockGitProvider::new();
 
-    let warden = MergeWarden::with_config(provider, config);
-    let result = warden
-        .process_pull_request("owner", "repo", 501)
+    // Add existing labels and comments
+    provider
+        .add_labels(
+            "owner",
+            "repo",
+            1,
+            &[
+                TITLE_INVALID_LABEL.to_string(),
+                MISSING_WORK_ITEM_LABEL.to_string(),
+                "feature".to_string(),
+            ],
+        )
         .await
         .unwrap();
 
-    assert!(result.wip_detected);
-    // Verify the check was set to failure
-    let updates = warden.provider.get_check_status_updates();
-    assert_eq!(updates.len(), 1);
-    assert_eq!(
-        updates[0].conclusion, "failure",
-        "WIP PR should result in a failure check status"
+    let title_comment = format!(
+        "{}\n## Invalid PR Title Format\n\nYour PR title doesn't follow the Conventional Commits format.",
+        TITLE_COMMENT_MARKER
     );
-}
+    provider
+        .add_comment("owner", "repo", 1, &title_comment)
+        .await
+        .unwrap();
 
-#[tokio::test]
-async fn test_process_pull_request_clean_pr_has_wip_detected_false() {
-    use crate::config::WipCheckConfig;
+    let work_item_comment = format!(
+        "{}\n## Missing Work Item Reference\n\nYour PR description doesn't reference a work item or GitHub issue.",
+        WORK_ITEM_COMMENT_MARKER
+    );
+    provider
+        .add_comment("owner", "repo", 1, &work_item_comment)
+        .await
+        .unwrap();
 
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 502,
-        title: "feat(auth): implement OAuth login".to_string(),
+    // Set up a valid PR
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("Fixes #300".to_string()),
+        body: Some("Fixes #123".to_string()),
         author: Some(User {
-            id: 3,
-            login: "dev3".to_string(),
+            id: 456,
+            login: "developer123".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
-    });
-
-    let config = CurrentPullRequestValidationConfiguration {
-        enforce_title_convention: true,
-        enforce_work_item_references: true,
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
+    provider.set_pull_request(pr);
 
-    let warden = MergeWarden::with_config(provider, config);
+    // Create a MergeWarden instance
+    let warden = MergeWarden::new(provider);
+
+    // Process the PR
     let result = warden
-        .process_pull_request("owner", "repo", 502)
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
+    // Verify the result
+    assert!(result.title_valid, "Title should be valid");
     assert!(
-        !result.wip_detected,
-        "Clean PR should have wip_detected = false"
+        result.work_item_referenced,
+        "Work item should be referenced"
+    );
+
+    // Verify the invalid labels were removed
+    let labels = warden.provider.get_labels();
+    assert!(
+        !labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
+        "Invalid title label should be removed"
+    );
+    assert!(
+        !labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
+        "Missing work item label should be removed"
+    );
+
+    // Verify the feature label remains
+    assert!(
+        labels.iter().any(|l| l.name == "feature"),
+        "Feature label should remain"
+    );
+
+    // Verify the comments were removed
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
+        "Title comment should be removed"
+    );
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)),
+        "Work item comment should be removed"
     );
 }
 
 #[tokio::test]
-async fn test_process_pull_request_wip_blocking_disabled_does_not_flag_wip() {
-    // WIP blocking is disabled  even a WIP-titled PR should not be blocked
+async fn test_process_pull_request_dynamic_provider() {
+    // Create a dynamic mock provider
     let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 503,
-        title: "WIP: work in progress feature".to_string(),
+
+    // Add two different PRs
+    let valid_pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("Fixes #400".to_string()),
+        body: Some("Fixes #123".to_string()),
         author: Some(User {
-            id: 4,
-            login: "dev4".to_string(),
+            id: 456,
+            login: "developer123".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
-    });
-
-    let config = CurrentPullRequestValidationConfiguration {
-        enforce_title_convention: true,
-        enforce_work_item_references: true,
-        // wip_check is disabled by default
-        ..CurrentPullRequestValidationConfiguration::default()
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
-    let warden = MergeWarden::with_config(provider, config);
-    let result = warden
-        .process_pull_request("owner", "repo", 503)
-        .await
-        .unwrap();
-
-    assert!(
-        !result.wip_detected,
-        "WIP checking disabled means wip_detected stays false"
-    );
-    // The title "WIP: work in progress feature" does not match Conventional Commits,
-    // but that is a separate validation result  wip_detected itself must be false
-}
-
-#[tokio::test]
-async fn test_process_pull_request_wip_bypasses_are_not_applied() {
-    use crate::config::WipCheckConfig;
-
-    // Even a user with full bypass permissions cannot bypass WIP blocking
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 504,
-        title: "WIP: add something".to_string(),
+    let invalid_pr = PullRequest {
+        number: 2,
+        title: "invalid title".to_string(),
         draft: false,
-        body: Some("Fixes #500".to_string()),
+        body: Some("No work item reference".to_string()),
         author: Some(User {
-            id: 5,
-            login: "release-bot".to_string(),
+            id: 456,
+            login: "developer123".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
-    });
-
-    let bypass_rule = BypassRule::new(true, vec!["release-bot".to_string()]);
-    let config = CurrentPullRequestValidationConfiguration {
-        bypass_rules: BypassRules::new(bypass_rule.clone(), bypass_rule.clone()),
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
-    let warden = MergeWarden::with_config(provider, config);
-    let result = warden
-        .process_pull_request("owner", "repo", 504)
+    provider.add_pull_request(valid_pr);
+    provider.add_pull_request(invalid_pr);
+
+    // Create a MergeWarden instance
+    let warden = MergeWarden::new(provider);
+
+    // Process the valid PR
+    let result1 = warden
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
+    // Verify the result for valid PR
+    assert!(result1.title_valid, "Title should be valid for PR #1");
     assert!(
-        result.wip_detected,
-        "Bypass rules must not override WIP blocking"
+        result1.work_item_referenced,
+        "Work item should be referenced for PR #1"
+    );
+
+    // Process the invalid PR
+    let result2 = warden
+        .process_pull_request("owner", "repo", 2)
+        .await
+        .unwrap();
+
+    // Verify the result for invalid PR
+    assert!(!result2.title_valid, "Title should be invalid for PR #2");
+    assert!(
+        !result2.work_item_referenced,
+        "Work item should not be referenced for PR #2"
+    );
+
+    // Verify the labels were added
+    let labels = warden.provider.get_labels();
+    assert!(
+        labels.iter().any(|l| l.name == TITLE_INVALID_LABEL),
+        "Invalid title label should be added for PR #2"
+    );
+    assert!(
+        labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
+        "Missing work item label should be added for PR #2"
     );
 }
 
 #[tokio::test]
-async fn test_communicate_wip_status_adds_comment_when_wip() {
-    use crate::config::WipCheckConfig;
+async fn test_process_pull_request_error_add_comment() {
+    // Create a mock provider that returns an error when adding a comment
+    let mut provider = ErrorMockGitProvider::new();
+    provider.with_invalid_pr_title();
+    provider.with_add_comment_error();
 
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 600,
-        title: "WIP: test".to_string(),
-        draft: false,
-        body: Some("Fixes #1".to_string()),
-        author: Some(User {
-            id: 10,
-            login: "tester".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    });
+    // Create a MergeWarden instance
+    let warden = MergeWarden::new(provider);
 
-    let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
+    // Process the PR - should return Ok even if adding a comment fails
+    let result = warden.process_pull_request("owner", "repo", 1).await;
 
-    let warden = MergeWarden::with_config(provider, config);
-    let pr = PullRequest {
-        number: 600,
-        title: "WIP: test".to_string(),
-        draft: false,
-        body: Some("Fixes #1".to_string()),
-        author: None,
-        milestone_number: None,
-        head_sha: String::new(),
-    };
+    // Verify the result is Ok (no error should be returned)
+    assert!(
+        result.is_ok(),
+        "Should return Ok even when adding a comment fails"
+    );
+    // Optionally, check that the output string contains a warning or expected message
+    let output = result.unwrap();
+    assert!(!output.title_valid, "The title should not be valid");
+}
 
-    warden
-        .communicate_wip_status("owner", "repo", &pr, true)
-        .await;
+#[tokio::test]
+async fn test_process_pull_request_error_add_labels() {
+    // Create a mock provider that returns an error when adding labels
+    let mut provider = ErrorMockGitProvider::new();
+    provider.with_add_labels_error();
 
-    let comments = warden.provider.get_comments();
+    // Create a MergeWarden instance
+    let warden = MergeWarden::new(provider);
+
+    // Process the PR - should succeed even if labels fail
+    let result = warden.process_pull_request("owner", "repo", 1).await;
+
+    // Should succeed - labeling is non-critical, core validation should still work
     assert!(
-        !comments.is_empty(),
-        "A WIP blocking comment should be added"
+        result.is_ok(),
+        "Should succeed even when adding labels fails"
     );
+
+    let check_result = result.unwrap();
+
+    // Verify that core validation functionality still works
+    assert!(check_result.title_valid, "Title validation should work");
     assert!(
-        comments.iter().any(|c| c.body.contains(WIP_COMMENT_MARKER)),
-        "WIP comment must contain the WIP_COMMENT_MARKER"
+        check_result.work_item_referenced,
+        "Work item validation should work"
+    );
+    assert!(check_result.size_valid, "Size validation should work");
+
+    // Labels should be empty since label application failed
+    assert!(
+        check_result.labels.is_empty(),
+        "Labels should be empty when label application fails"
     );
 }
 
 #[tokio::test]
-async fn test_communicate_wip_status_removes_comment_when_not_wip() {
-    use crate::config::WipCheckConfig;
+async fn test_process_pull_request_error_get_pr() {
+    // Create a mock provider that returns an error when getting a PR
+    let mut provider = ErrorMockGitProvider::new();
+    provider.with_get_pr_error();
 
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 601,
-        title: "feat: done now".to_string(),
-        draft: false,
-        body: Some("Fixes #2".to_string()),
-        author: Some(User {
-            id: 11,
-            login: "tester2".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    });
+    // Create a MergeWarden instance
+    let warden = MergeWarden::new(provider);
 
-    // Pre-populate with a WIP comment
-    {
-        let wip_comment_body = format!("{}some wip text", WIP_COMMENT_MARKER);
-        let mut comments = provider.comments.lock().unwrap();
-        comments.push(Comment {
-            id: 99,
-            body: wip_comment_body,
-            user: User {
-                id: 11,
-                login: "bot".to_string(),
-            },
-        });
-    }
+    // Process the PR - should return an error
+    let result = warden.process_pull_request("owner", "repo", 1).await;
 
-    let config = CurrentPullRequestValidationConfiguration {
-        wip_check: WipCheckConfig {
-            enforce_wip_blocking: true,
-            ..WipCheckConfig::default()
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
+    // Verify the error
+    assert!(
+        result.is_err(),
+        "Should return an error when getting a PR fails"
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Git provider error: Failed to find the PR with number [1] in owner/repo",
+        "Should return the specific error message"
+    );
+}
 
-    let warden = MergeWarden::with_config(provider, config);
-    let pr = PullRequest {
-        number: 601,
-        title: "feat: done now".to_string(),
-        draft: false,
-        body: Some("Fixes #2".to_string()),
-        author: None,
-        milestone_number: None,
-        head_sha: String::new(),
-    };
+#[tokio::test]
+async fn test_process_pull_requests_yields_every_result_despite_failures() {
+    let mut provider = ErrorMockGitProvider::new();
+    provider.with_get_pr_error_for(2);
+    let warden = MergeWarden::new(provider);
 
-    warden
-        .communicate_wip_status("owner", "repo", &pr, false)
-        .await;
+    let targets = futures::stream::iter([
+        ("owner", "repo", 1),
+        ("owner", "repo", 2),
+        ("owner", "repo", 3),
+    ]);
+    let mut results: Vec<BatchProcessResult> =
+        warden.process_pull_requests(targets, 2).collect().await;
+    results.sort_by_key(|item| item.target.pr_number);
 
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments.iter().any(|c| c.body.contains(WIP_COMMENT_MARKER)),
-        "WIP comment should be removed when PR is no longer WIP"
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results[0].target,
+        PullRequestTarget::from(("owner", "repo", 1))
     );
+    assert!(results[0].result.as_ref().unwrap().title_valid);
+    assert!(results[1].result.is_err());
+    assert!(results[2].result.is_ok());
 }
 
-/// Regression test: a reviewer who first approves and then supersedes that review
-/// with `changes_requested` must result in the review label being applied, not the
-/// approved label.  The fix groups reviews by reviewer and takes the latest.
 #[tokio::test]
-async fn test_communicate_pr_state_labels_superseded_approval_shows_review_state() {
-    use crate::config::PrStateLabelsConfig;
+async fn test_process_pull_requests_bounds_concurrency() {
+    let provider = ErrorMockGitProvider::new();
+    let max_in_flight = provider.max_in_flight.clone();
+    let warden = MergeWarden::new(provider);
 
-    let reviewer = User {
-        id: 42,
-        login: "reviewer1".to_string(),
-    };
+    let targets = futures::stream::iter((1..=6).map(|n| ("owner", "repo", n)));
+    let results: Vec<BatchProcessResult> = warden.process_pull_requests(targets, 2).collect().await;
 
-    // API returns reviews oldest-first: an approval followed by a changes_requested
-    // from the same reviewer.  The changes_requested supersedes the approval.
-    let reviews = vec![
-        Review {
-            id: 1,
-            state: "approved".to_string(),
-            user: reviewer.clone(),
-        },
-        Review {
-            id: 2,
-            state: "changes_requested".to_string(),
-            user: reviewer.clone(),
-        },
-    ];
+    assert_eq!(results.len(), 6);
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_process_pull_requests_treats_zero_concurrency_as_one() {
+    let provider = ErrorMockGitProvider::new();
+    let max_in_flight = provider.max_in_flight.clone();
+    let warden = MergeWarden::new(provider);
+
+    let targets = futures::stream::iter((1..=3).map(|n| ("owner", "repo", n)));
+    let results: Vec<BatchProcessResult> = warden.process_pull_requests(targets, 0).collect().await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_handle_work_item_validation_missing_to_present() {
+    // Create a mock provider
+    let provider = MockGitProvider::new();
+
+    // Add the missing work item label
+    provider
+        .add_labels("owner", "repo", 1, &[MISSING_WORK_ITEM_LABEL.to_string()])
+        .await
+        .unwrap();
+
+    // Add a work item comment
+    let comment = format!(
+            "{}\n## Missing Work Item Reference\n\nYour PR description doesn't reference a work item or GitHub issue.",
+            WORK_ITEM_COMMENT_MARKER
+        );
+    provider
+        .add_comment("owner", "repo", 1, &comment)
+        .await
+        .unwrap();
+
+    // Create a MergeWarden instance
+    let warden = MergeWarden::new(provider);
 
+    // Set up a PR
     let pr = PullRequest {
-        number: 700,
-        title: "feat: some feature".to_string(),
+        number: 1,
+        title: "feat: add new feature".to_string(),
         draft: false,
-        body: Some("Fixes #1".to_string()),
+        body: Some("Fixes #123".to_string()),
         author: Some(User {
-            id: 10,
-            login: "author".to_string(),
+            id: 456,
+            login: "developer123".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
-    let mut provider = DynamicMockGitProvider::new().with_reviews(reviews);
-    provider.add_pull_request(pr.clone());
-
-    let config = CurrentPullRequestValidationConfiguration {
-        pr_state_labels: PrStateLabelsConfig {
-            enabled: true,
-            draft_label: Some("state: draft".to_string()),
-            review_label: Some("state: in-review".to_string()),
-            approved_label: Some("state: approved".to_string()),
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
+    // Handle work item validation with valid work item reference
+    let validation_result = WorkItemValidationResult {
+        validation: ValidationResult::valid(),
+        matched_pattern: Some(WORK_ITEM_REGEX.to_string()),
+        references: vec!["#123".to_string()],
+        dangling_references: Vec::new(),
     };
-
-    let warden = MergeWarden::with_config(provider, config);
     warden
-        .communicate_pr_state_labels("owner", "repo", &pr)
+        .communicate_pr_work_item_validity_status("owner", "repo", &pr, &validation_result)
         .await;
 
+    // Verify the label was removed
     let labels = warden.provider.get_labels();
     assert!(
-        labels.iter().any(|l| l.name == "state: in-review"),
-        "The review label must be applied when the latest review is changes_requested, got: {:?}",
-        labels
+        !labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL),
+        "Missing work item label should be removed"
     );
+
+    // Verify the comment was removed
+    let comments = warden.provider.get_comments();
     assert!(
-        !labels.iter().any(|l| l.name == "state: approved"),
-        "The approved label must NOT be applied when the latest review supersedes the approval, got: {:?}",
-        labels
+        !comments
+            .iter()
+            .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)),
+        "Work item comment should be removed"
     );
 }
 
-// ---------------------------------------------------------------------------
-// propagate_issue_metadata tests
-// ---------------------------------------------------------------------------
-
-use merge_warden_developer_platforms::{
-    models::{IssueMetadata, IssueMilestone, IssueProject},
-    IssueMetadataProvider,
-};
-
-/// Minimal mock for `IssueMetadataProvider`.
-#[derive(Debug, Clone)]
-struct MockIssueProvider {
-    /// Metadata returned by `get_issue_metadata`. `None` means 404.
-    metadata: Option<IssueMetadata>,
-    /// Set to `true` to make `get_issue_metadata` return an error.
-    error_on_get: bool,
-    /// Set to `true` to make `set_pull_request_milestone` return an error.
-    error_on_set_milestone: bool,
-    /// Set to `true` to make `add_pull_request_to_project` return an error.
-    error_on_add_project: bool,
-    /// Records every `set_pull_request_milestone` call as `(pr_number, milestone_number)`.
-    milestone_calls: Arc<Mutex<Vec<(u64, Option<u64>)>>>,
-    /// Records every `add_pull_request_to_project` call as `(pr_number, project_number)`.
-    project_calls: Arc<Mutex<Vec<(u64, u64)>>>,
-}
-
-impl MockIssueProvider {
-    fn new() -> Self {
-        Self {
-            metadata: None,
-            error_on_get: false,
-            error_on_set_milestone: false,
-            error_on_add_project: false,
-            milestone_calls: Arc::new(Mutex::new(Vec::new())),
-            project_calls: Arc::new(Mutex::new(Vec::new())),
-        }
-    }
-
-    fn with_metadata(mut self, m: IssueMetadata) -> Self {
-        self.metadata = Some(m);
-        self
-    }
-
-    fn with_get_error(mut self) -> Self {
-        self.error_on_get = true;
-        self
-    }
+#[tokio::test]
+async fn test_bypass_functionality_with_title_bypass() {
+    // Test the complete bypass flow: validation, logging, and comment generation
+    let provider = DynamicMockGitProvider::new();
 
-    fn with_set_milestone_error(mut self) -> Self {
-        self.error_on_set_milestone = true;
-        self
-    }
+    // Create a PR with invalid title but user who can bypass
+    let pr = PullRequest {
+        number: 123,
+        title: "invalid title format".to_string(), // Invalid conventional commit format
+        draft: false,
+        body: Some("Fixes #456".to_string()), // Valid work item reference
+        author: Some(User {
+            id: 789,
+            login: "bypass-user".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
 
-    fn with_add_project_error(mut self) -> Self {
-        self.error_on_add_project = true;
-        self
-    }
+    // Add the PR to the mock provider
+    let mut provider_mut = provider;
+    provider_mut.add_pull_request(pr.clone());
 
-    fn milestone_calls(&self) -> Vec<(u64, Option<u64>)> {
-        self.milestone_calls.lock().unwrap().clone()
-    }
+    // Create MergeWarden with bypass rules allowing "bypass-user" to bypass title validation
+    let config = CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["bypass-user".to_string()]), // Title bypass enabled
+            BypassRule::new(false, vec![]),                         // Work item bypass disabled
+        ),
+        ..Default::default()
+    };
 
-    fn project_calls(&self) -> Vec<(u64, u64)> {
-        self.project_calls.lock().unwrap().clone()
-    }
-}
+    let warden = MergeWarden::with_config(provider_mut, config);
 
-#[async_trait]
-impl IssueMetadataProvider for MockIssueProvider {
-    async fn get_issue_metadata(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        _issue_number: u64,
-    ) -> Result<Option<IssueMetadata>, Error> {
-        if self.error_on_get {
-            return Err(Error::ApiError());
-        }
-        Ok(self.metadata.clone())
-    }
+    // Process the pull request
+    let result = warden
+        .process_pull_request("owner", "repo", 123)
+        .await
+        .unwrap();
 
-    async fn set_pull_request_milestone(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        pr_number: u64,
-        milestone_number: Option<u64>,
-    ) -> Result<(), Error> {
-        if self.error_on_set_milestone {
-            return Err(Error::FailedToUpdatePullRequest(
-                "mock set_milestone error".to_string(),
-            ));
-        }
-        self.milestone_calls
-            .lock()
-            .unwrap()
-            .push((pr_number, milestone_number));
-        Ok(())
-    }
+    // Verify the result indicates success due to bypass
+    assert!(result.title_valid, "Title should be valid due to bypass");
+    assert!(
+        result.work_item_referenced,
+        "Work item should be naturally valid"
+    );
 
-    async fn add_pull_request_to_project(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        pr_number: u64,
-        project_number: u64,
-        _project_owner_login: &str,
-    ) -> Result<(), Error> {
-        if self.error_on_add_project {
-            return Err(Error::ApiError());
-        }
-        self.project_calls
-            .lock()
-            .unwrap()
-            .push((pr_number, project_number));
-        Ok(())
-    }
+    // Verify bypass information is recorded
+    assert_eq!(
+        result.bypasses_used.len(),
+        1,
+        "Should have one bypass recorded"
+    );
+    let bypass_info = &result.bypasses_used[0];
+    assert_eq!(bypass_info.user, "bypass-user");
+    assert_eq!(bypass_info.rule_type, BypassRuleType::TitleConvention);
 }
 
-/// Helper: build a `MergeWarden` with `sync_milestone_from_issue` and/or
-/// `sync_project_from_issue` set as requested.
-fn warden_with_issue_propagation(milestone: bool, project: bool) -> MergeWarden<MockGitProvider> {
-    let provider = MockGitProvider::new();
-    let config = CurrentPullRequestValidationConfiguration {
-        issue_propagation: IssuePropagationConfig {
-            sync_milestone_from_issue: milestone,
-            sync_project_from_issue: project,
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
-    };
-    MergeWarden::with_config(provider, config)
-}
+#[tokio::test]
+async fn test_bypass_functionality_with_work_item_bypass() {
+    // Test bypass for work item validation
+    let provider = DynamicMockGitProvider::new();
 
-/// Helper: minimal `PullRequest` with the given body and milestone_number.
-fn make_pr(body: Option<&str>, milestone_number: Option<u64>) -> PullRequest {
-    PullRequest {
-        number: 42,
-        title: "feat: something".to_string(),
+    // Create a PR with valid title but no work item reference, user who can bypass work item validation
+    let pr = PullRequest {
+        number: 124,
+        title: "feat: add new feature".to_string(), // Valid conventional commit format
         draft: false,
-        body: body.map(|s| s.to_string()),
+        body: Some("This is an emergency fix".to_string()), // No work item reference
         author: Some(User {
-            id: 1,
-            login: "dev".to_string(),
+            id: 890,
+            login: "emergency-user".to_string(),
         }),
-        milestone_number,
+        milestone_number: None,
         head_sha: String::new(),
-    }
-}
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
 
-#[tokio::test]
-async fn test_propagate_issue_metadata_flags_off_skips_all_calls() {
-    // Both flags off → no API calls regardless of PR body / issue data.
-    let warden = warden_with_issue_propagation(false, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 7,
-            title: "v1.0".to_string(),
-        }),
-        projects: vec![],
-    });
-    let pr = make_pr(Some("Closes #10"), None);
+    // Add the PR to the mock provider
+    let mut provider_mut = provider;
+    provider_mut.add_pull_request(pr.clone());
 
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
-        .await;
+    // Create MergeWarden with bypass rules allowing "emergency-user" to bypass work item validation
+    let config = CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(
+            BypassRule::new(false, vec![]), // Title bypass disabled
+            BypassRule::new(true, vec!["emergency-user".to_string()]), // Work item bypass enabled
+        ),
+        ..Default::default()
+    };
 
-    assert!(
-        issue_provider.milestone_calls().is_empty(),
-        "set_pull_request_milestone must not be called when flag is off"
-    );
-    assert!(
-        issue_provider.project_calls().is_empty(),
-        "add_pull_request_to_project must not be called when flag is off"
-    );
-}
+    let warden = MergeWarden::with_config(provider_mut, config);
 
-#[tokio::test]
-async fn test_propagate_issue_metadata_empty_body_skips_propagation() {
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 7,
-            title: "v1.0".to_string(),
-        }),
-        projects: vec![],
-    });
+    // Process the pull request
+    let result = warden
+        .process_pull_request("owner", "repo", 124)
+        .await
+        .unwrap();
 
-    // No body at all.
-    let pr = make_pr(None, None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
-        .await;
-    assert!(issue_provider.milestone_calls().is_empty());
+    // Verify the result indicates success due to bypass
+    assert!(result.title_valid, "Title should be naturally valid");
+    assert!(
+        result.work_item_referenced,
+        "Work item should be valid due to bypass"
+    );
 
-    // Empty-string body.
-    let pr_empty = make_pr(Some(""), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr_empty, &issue_provider)
-        .await;
-    assert!(issue_provider.milestone_calls().is_empty());
+    // Verify bypass information is recorded
+    assert_eq!(
+        result.bypasses_used.len(),
+        1,
+        "Should have one bypass recorded"
+    );
+    let bypass_info = &result.bypasses_used[0];
+    assert_eq!(bypass_info.user, "emergency-user");
+    assert_eq!(bypass_info.rule_type, BypassRuleType::WorkItemReference);
 }
 
 #[tokio::test]
-async fn test_propagate_issue_metadata_references_keyword_triggers_propagation() {
-    // "References #10" is an informational keyword but should still trigger
-    // milestone/project propagation — an issue can require multiple PRs.
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 7,
-            title: "v1.0".to_string(),
+async fn test_title_bypass_notice_uses_configured_text() {
+    let config = CurrentPullRequestValidationConfiguration {
+        comments: crate::config::CommentPolicyConfig {
+            title_bypass_notice: Some(
+                "Title check skipped for {{ user }} ({{ rule_type }}): `{{ title }}`. See https://example.com/policy".to_string(),
+            ),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(MockGitProvider::new(), config);
+    let pr = PullRequest {
+        number: 1,
+        title: "release 1.2.3".to_string(),
+        draft: false,
+        body: None,
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let validation_result = TitleValidationResult {
+        validation: ValidationResult::bypassed(BypassInfo {
+            rule_type: BypassRuleType::TitleConvention,
+            user: "release-bot".to_string(),
         }),
-        projects: vec![],
-    });
+        diagnosis: None,
+    };
 
-    let pr = make_pr(Some("This PR references #10 but does not close it."), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+    let text = warden
+        .communicate_pr_title_validity_status("owner", "repo", &pr, &validation_result)
         .await;
 
-    // Milestone should be set because 'references' now triggers propagation.
-    let calls = issue_provider.milestone_calls();
+    let expected = "Title check skipped for release-bot (Title Convention): `release 1.2.3`. See https://example.com/policy";
+    assert_eq!(text, expected);
+    let comments = warden.provider.get_comments();
+    assert_eq!(comments.len(), 1);
     assert_eq!(
-        calls.len(),
-        1,
-        "Expected milestone to be set via 'references' keyword"
+        comments[0].body,
+        format!("{TITLE_COMMENT_MARKER}{expected}")
     );
-    assert_eq!(calls[0], (42, Some(7)));
+}
+
+fn contributor_guidance_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        comments: crate::config::CommentPolicyConfig {
+            contributor_guidance: crate::config::ContributorGuidanceConfig {
+                internal: crate::config::GuidanceTemplates {
+                    invalid_title: Some("Title: {{ issues | join(', ') }}".to_string()),
+                    missing_work_item: Some(
+                        "Add a reference matching `{{ pattern }}`.".to_string(),
+                    ),
+                },
+                external: crate::config::GuidanceTemplates {
+                    invalid_title: Some("Thanks for your contribution! {{ title }}".to_string()),
+                    missing_work_item: None,
+                },
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn invalid_title_result() -> TitleValidationResult {
+    TitleValidationResult {
+        validation: ValidationResult::invalid(),
+        diagnosis: None,
+    }
 }
 
 #[tokio::test]
-async fn test_propagate_issue_metadata_relates_to_keyword_triggers_propagation() {
-    // "Relates to #10" should also trigger propagation.
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 7,
-            title: "v1.0".to_string(),
-        }),
-        projects: vec![],
-    });
+async fn test_contributor_guidance_uses_the_internal_template_for_members() {
+    let provider = MockGitProvider::new();
+    provider.set_author_association(AuthorAssociation::Member);
+    let warden = MergeWarden::with_config(provider, contributor_guidance_config());
 
-    let pr = make_pr(Some("This PR relates to #10"), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+    let text = warden
+        .communicate_pr_title_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &invalid_title_result(),
+        )
         .await;
 
-    let calls = issue_provider.milestone_calls();
-    assert_eq!(
-        calls.len(),
-        1,
-        "Expected milestone to be set via 'relates to' keyword"
-    );
-    assert_eq!(calls[0], (42, Some(7)));
+    assert!(text.starts_with("Title: "), "unexpected guidance: {text}");
 }
 
 #[tokio::test]
-async fn test_propagate_issue_metadata_sets_milestone_when_pr_has_none() {
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 5,
-            title: "v2.0.0".to_string(),
-        }),
-        projects: vec![],
-    });
+async fn test_contributor_guidance_uses_the_external_template_for_first_time_contributors() {
+    let provider = MockGitProvider::new();
+    provider.set_author_association(AuthorAssociation::FirstTimeContributor);
+    let warden = MergeWarden::with_config(provider, contributor_guidance_config());
 
-    let pr = make_pr(Some("Closes #10"), None); // PR has no milestone yet
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+    let text = warden
+        .communicate_pr_title_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &invalid_title_result(),
+        )
         .await;
 
-    let calls = issue_provider.milestone_calls();
-    assert_eq!(calls.len(), 1, "Expected exactly one milestone call");
-    assert_eq!(calls[0], (42, Some(5)));
+    assert_eq!(
+        text,
+        "Thanks for your contribution! not a conventional title"
+    );
 }
 
 #[tokio::test]
-async fn test_propagate_issue_metadata_overwrites_different_pr_milestone() {
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 9,
-            title: "v3.0.0".to_string(),
-        }),
-        projects: vec![],
-    });
+async fn test_contributor_guidance_falls_back_to_the_default_template() {
+    let default_text = MergeWarden::new(MockGitProvider::new())
+        .communicate_pr_title_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &invalid_title_result(),
+        )
+        .await;
 
-    // PR already has milestone 3, issue has milestone 9 → should overwrite.
-    let pr = make_pr(Some("Fixes #20"), Some(3));
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+    // The provider cannot report the author's association.
+    let warden = MergeWarden::with_config(MockGitProvider::new(), contributor_guidance_config());
+    let text = warden
+        .communicate_pr_title_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &invalid_title_result(),
+        )
         .await;
+    assert_eq!(text, default_text);
 
-    let calls = issue_provider.milestone_calls();
-    assert_eq!(calls.len(), 1, "Expected exactly one milestone call");
-    assert_eq!(calls[0], (42, Some(9)));
+    // No work item template is configured for external contributors.
+    let provider = MockGitProvider::new();
+    provider.set_author_association(AuthorAssociation::Contributor);
+    let warden = MergeWarden::with_config(provider, contributor_guidance_config());
+    let validation_result = WorkItemValidationResult {
+        validation: ValidationResult::invalid(),
+        matched_pattern: None,
+        references: Vec::new(),
+        dangling_references: Vec::new(),
+    };
+    let text = warden
+        .communicate_pr_work_item_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &validation_result,
+        )
+        .await;
+    assert!(
+        !text.starts_with("Add a reference"),
+        "unexpected guidance: {text}"
+    );
 }
 
 #[tokio::test]
-async fn test_propagate_issue_metadata_no_op_when_pr_already_has_same_milestone() {
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 5,
-            title: "v2.0.0".to_string(),
-        }),
-        projects: vec![],
-    });
+async fn test_contributor_guidance_uses_the_internal_work_item_template() {
+    let provider = MockGitProvider::new();
+    provider.set_author_association(AuthorAssociation::Owner);
+    let warden = MergeWarden::with_config(provider, contributor_guidance_config());
+    let validation_result = WorkItemValidationResult {
+        validation: ValidationResult::invalid(),
+        matched_pattern: None,
+        references: Vec::new(),
+        dangling_references: Vec::new(),
+    };
 
-    // PR already has the same milestone → no-op (assertion 7 from spec).
-    let pr = make_pr(Some("Closes #10"), Some(5));
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+    let text = warden
+        .communicate_pr_work_item_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &validation_result,
+        )
         .await;
 
     assert!(
-        issue_provider.milestone_calls().is_empty(),
-        "set_pull_request_milestone must not be called when PR already has the same milestone"
+        text.starts_with("Add a reference matching `"),
+        "unexpected guidance: {text}"
     );
 }
 
+fn configured_comment_text_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        comments: crate::config::CommentPolicyConfig {
+            title_invalid_comment_template: Some(
+                "Titles must match `{{ pattern }}`, for example:\n{{ examples }}".to_string(),
+            ),
+            missing_work_item_comment_template: Some(
+                "Link a work item matching `{{ pattern }}`, for example:\n{{ examples }}"
+                    .to_string(),
+            ),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
 #[tokio::test]
-async fn test_propagate_issue_metadata_no_op_when_issue_has_no_milestone() {
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: None, // issue has no milestone
-        projects: vec![],
-    });
+async fn test_title_comment_uses_configured_text() {
+    let config = configured_comment_text_config();
+    let expected = format!(
+        "Titles must match `{}`, for example:\n{}",
+        config.title_pattern,
+        crate::templates::TITLE_COMMENT_EXAMPLES
+    );
+    let warden = MergeWarden::with_config(MockGitProvider::new(), config);
 
-    let pr = make_pr(Some("Closes #10"), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+    let text = warden
+        .communicate_pr_title_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &invalid_title_result(),
+        )
         .await;
 
-    assert!(
-        issue_provider.milestone_calls().is_empty(),
-        "set_pull_request_milestone must not be called when issue has no milestone"
+    assert_eq!(text, expected);
+    let comments = warden.provider.get_comments();
+    assert_eq!(comments.len(), 1);
+    assert_eq!(
+        comments[0].body,
+        format!("{TITLE_COMMENT_MARKER}{expected}")
     );
 }
 
 #[tokio::test]
-async fn test_propagate_issue_metadata_issue_not_found_is_no_op() {
-    let warden = warden_with_issue_propagation(true, false);
-    // `metadata: None` simulates a 404 response.
-    let issue_provider = MockIssueProvider::new();
+async fn test_work_item_comment_uses_configured_text() {
+    let config = configured_comment_text_config();
+    let expected = format!(
+        "Link a work item matching `{}`, for example:\n{}",
+        config.work_item_reference_pattern,
+        crate::templates::WORK_ITEM_COMMENT_EXAMPLES
+    );
+    let warden = MergeWarden::with_config(MockGitProvider::new(), config);
+    let validation_result = WorkItemValidationResult {
+        validation: ValidationResult::invalid(),
+        matched_pattern: None,
+        references: Vec::new(),
+        dangling_references: Vec::new(),
+    };
 
-    let pr = make_pr(Some("Closes #99"), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+    let text = warden
+        .communicate_pr_work_item_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &validation_result,
+        )
         .await;
 
-    assert!(
-        issue_provider.milestone_calls().is_empty(),
-        "No milestone call expected when issue is not found"
+    assert_eq!(text, expected);
+    let comments = warden.provider.get_comments();
+    assert_eq!(comments.len(), 1);
+    assert_eq!(
+        comments[0].body,
+        format!("{WORK_ITEM_COMMENT_MARKER}{expected}")
     );
 }
 
 #[tokio::test]
-async fn test_propagate_issue_metadata_get_error_is_non_fatal() {
-    // `get_issue_metadata` fails → propagation logs a warning but does not panic.
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_get_error();
+async fn test_contributor_guidance_takes_precedence_over_configured_text() {
+    let provider = MockGitProvider::new();
+    provider.set_author_association(AuthorAssociation::Member);
+    let mut config = contributor_guidance_config();
+    config.comments.title_invalid_comment_template = Some("Fix the title.".to_string());
+    let warden = MergeWarden::with_config(provider, config);
 
-    let pr = make_pr(Some("Closes #10"), None);
-    // Must complete without panicking and make no downstream calls.
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+    let text = warden
+        .communicate_pr_title_validity_status(
+            "owner",
+            "repo",
+            &invalid_title_pr(),
+            &invalid_title_result(),
+        )
         .await;
 
-    assert!(issue_provider.milestone_calls().is_empty());
+    assert_eq!(text, "Title: ");
 }
 
 #[tokio::test]
-async fn test_propagate_issue_metadata_set_milestone_error_is_non_fatal() {
-    // `set_pull_request_milestone` fails → logs a warning, does not propagate error.
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new()
-        .with_metadata(IssueMetadata {
-            milestone: Some(IssueMilestone {
-                number: 5,
-                title: "v2.0.0".to_string(),
-            }),
-            projects: vec![],
-        })
-        .with_set_milestone_error();
-
-    let pr = make_pr(Some("Closes #10"), None);
-    // Must complete without panicking.
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
-        .await;
-    // No assertion on call count — the provider returns an error, which is expected.
-}
+async fn test_bypass_functionality_with_multiple_bypasses() {
+    // Test multiple bypasses in the same PR
+    let provider = DynamicMockGitProvider::new();
 
-#[tokio::test]
-async fn test_propagate_issue_metadata_cross_repo_reference() {
-    // "Closes owner2/other-repo#55" — the issue lookup must use the cross-repo
-    // owner/repo, not the PR's own repo.
-    let warden = warden_with_issue_propagation(true, false);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 11,
-            title: "cross".to_string(),
+    // Create a PR with both invalid title and no work item reference, user who can bypass both
+    let pr = PullRequest {
+        number: 125,
+        title: "urgent fix".to_string(), // Invalid conventional commit format
+        draft: false,
+        body: Some("Emergency production fix".to_string()), // No work item reference
+        author: Some(User {
+            id: 999,
+            login: "admin-user".to_string(),
         }),
-        projects: vec![],
-    });
-
-    let pr = make_pr(Some("Closes owner2/other-repo#55"), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
-        .await;
-
-    let calls = issue_provider.milestone_calls();
-    assert_eq!(
-        calls.len(),
-        1,
-        "Expected exactly one milestone call for cross-repo reference"
-    );
-    assert_eq!(calls[0], (42, Some(11)));
-}
-
-#[tokio::test]
-async fn test_propagate_issue_metadata_adds_pr_to_projects() {
-    let warden = warden_with_issue_propagation(false, true);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: None,
-        projects: vec![
-            IssueProject {
-                number: 1,
-                owner_login: "myorg".to_string(),
-                title: "Team Alpha".to_string(),
-            },
-            IssueProject {
-                number: 2,
-                owner_login: "myorg".to_string(),
-                title: "Team Beta".to_string(),
-            },
-        ],
-    });
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
 
-    let pr = make_pr(Some("Closes #10"), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
-        .await;
+    // Add the PR to the mock provider
+    let mut provider_mut = provider;
+    provider_mut.add_pull_request(pr.clone());
 
-    let calls = issue_provider.project_calls();
-    assert_eq!(calls.len(), 2, "Expected one project call per project");
-    assert!(calls.iter().any(|(_, num)| *num == 1));
-    assert!(calls.iter().any(|(_, num)| *num == 2));
-    // Milestone should not have been called since the flag is off.
-    assert!(issue_provider.milestone_calls().is_empty());
-}
+    // Create MergeWarden with bypass rules allowing "admin-user" to bypass both validations
+    let config = CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["admin-user".to_string()]), // Title bypass enabled
+            BypassRule::new(true, vec!["admin-user".to_string()]), // Work item bypass enabled
+        ),
+        ..Default::default()
+    };
 
-#[tokio::test]
-async fn test_propagate_issue_metadata_no_projects_on_issue_skips_project_calls() {
-    let warden = warden_with_issue_propagation(false, true);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: None,
-        projects: vec![], // no projects
-    });
+    let warden = MergeWarden::with_config(provider_mut, config);
 
-    let pr = make_pr(Some("Closes #10"), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
-        .await;
+    // Process the pull request
+    let result = warden
+        .process_pull_request("owner", "repo", 125)
+        .await
+        .unwrap();
 
+    // Verify the result indicates success due to bypasses
+    assert!(result.title_valid, "Title should be valid due to bypass");
     assert!(
-        issue_provider.project_calls().is_empty(),
-        "No project calls expected when issue has no linked projects"
+        result.work_item_referenced,
+        "Work item should be valid due to bypass"
     );
-}
-
-#[tokio::test]
-async fn test_propagate_issue_metadata_add_project_error_is_non_fatal() {
-    let warden = warden_with_issue_propagation(false, true);
-    let issue_provider = MockIssueProvider::new()
-        .with_metadata(IssueMetadata {
-            milestone: None,
-            projects: vec![IssueProject {
-                number: 1,
-                owner_login: "myorg".to_string(),
-                title: "Alpha".to_string(),
-            }],
-        })
-        .with_add_project_error();
-
-    let pr = make_pr(Some("Closes #10"), None);
-    // Must not panic.
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
-        .await;
-}
 
-#[tokio::test]
-async fn test_propagate_issue_metadata_both_flags_enabled() {
-    // Both flags on → both milestone and project calls should be made.
-    let warden = warden_with_issue_propagation(true, true);
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 3,
-            title: "Q1".to_string(),
-        }),
-        projects: vec![IssueProject {
-            number: 7,
-            owner_login: "myorg".to_string(),
-            title: "Roadmap".to_string(),
-        }],
-    });
+    // Verify both bypasses are recorded
+    assert_eq!(
+        result.bypasses_used.len(),
+        2,
+        "Should have two bypasses recorded"
+    );
 
-    let pr = make_pr(Some("Closes #10"), None);
-    warden
-        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
-        .await;
+    // Check that we have both bypass types
+    let bypass_types: std::collections::HashSet<_> = result
+        .bypasses_used
+        .iter()
+        .map(|info| &info.rule_type)
+        .collect();
+    assert!(bypass_types.contains(&BypassRuleType::TitleConvention));
+    assert!(bypass_types.contains(&BypassRuleType::WorkItemReference));
 
-    assert_eq!(issue_provider.milestone_calls().len(), 1);
-    assert_eq!(issue_provider.project_calls().len(), 1);
+    // Verify all bypasses are attributed to the same user
+    for bypass_info in &result.bypasses_used {
+        assert_eq!(bypass_info.user, "admin-user");
+    }
 }
 
-// ---------------------------------------------------------------------------
-// process_pull_request + with_issue_provider integration tests (task 6.8)
-// ---------------------------------------------------------------------------
-
-/// Verify that `process_pull_request` calls `propagate_issue_metadata` when an
-/// `IssueMetadataProvider` is wired in via `with_issue_provider` and the
-/// `sync_milestone_from_issue` flag is enabled.
 #[tokio::test]
-async fn test_process_pull_request_calls_milestone_propagation_via_issue_provider() {
-    // Set up a PR with a closing issue reference in its body.
+async fn test_no_bypass_when_user_not_authorized() {
+    // Test that bypasses are not applied when user is not in the allowed list
+    let provider = DynamicMockGitProvider::new();
+
+    // Create a PR with invalid title, user who CANNOT bypass
     let pr = PullRequest {
-        number: 77,
-        title: "feat: new widget".to_string(),
+        number: 126,
+        title: "bad title".to_string(), // Invalid conventional commit format
         draft: false,
-        body: Some("Closes #10".to_string()),
+        body: Some("Fixes #789".to_string()), // Valid work item reference
         author: Some(User {
-            id: 1,
-            login: "dev".to_string(),
+            id: 111,
+            login: "regular-user".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
-    let pr_provider = MockGitProvider::new();
-    pr_provider.set_pull_request(pr);
-
-    // Issue provider: issue 10 has milestone 5.
-    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
-        milestone: Some(IssueMilestone {
-            number: 5,
-            title: "v1.0".to_string(),
-        }),
-        projects: vec![],
-    });
-    let issue_provider_check = issue_provider.clone();
+    // Add the PR to the mock provider
+    let mut provider_mut = provider;
+    provider_mut.add_pull_request(pr.clone());
 
+    // Create MergeWarden with bypass rules NOT allowing "regular-user"
     let config = CurrentPullRequestValidationConfiguration {
-        issue_propagation: IssuePropagationConfig {
-            sync_milestone_from_issue: true,
-            sync_project_from_issue: false,
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["admin-user".to_string()]), // Only admin-user can bypass
+            BypassRule::new(false, vec![]),                        // Work item bypass disabled
+        ),
+        ..Default::default()
     };
 
-    let warden =
-        MergeWarden::with_config(pr_provider, config).with_issue_provider(Box::new(issue_provider));
+    let warden = MergeWarden::with_config(provider_mut, config);
 
-    warden
-        .process_pull_request("owner", "repo", 77)
+    // Process the pull request
+    let result = warden
+        .process_pull_request("owner", "repo", 126)
         .await
         .unwrap();
 
-    let calls = issue_provider_check.milestone_calls();
+    // Verify the result shows validation failure (no bypass applied)
+    assert!(!result.title_valid, "Title should be invalid (no bypass)");
+    assert!(
+        result.work_item_referenced,
+        "Work item should be naturally valid"
+    );
+
+    // Verify no bypasses are recorded
     assert_eq!(
-        calls.len(),
-        1,
-        "set_pull_request_milestone should be called once when issue provider is wired in"
+        result.bypasses_used.len(),
+        0,
+        "Should have no bypasses recorded"
     );
-    assert_eq!(calls[0], (77, Some(5)));
 }
 
-/// Verify that `process_pull_request` does NOT call any issue-provider methods
-/// when no `IssueMetadataProvider` is attached (backward-compatible behaviour).
 #[tokio::test]
-async fn test_process_pull_request_skips_propagation_without_issue_provider() {
-    let pr = PullRequest {
-        number: 1,
-        title: "feat: something".to_string(),
+async fn test_no_bypass_when_pr_has_no_author() {
+    // PRs opened by deleted accounts have no author and must not match bypass lists
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 127,
+        title: "bad title".to_string(),
         draft: false,
-        body: Some("Closes #10".to_string()),
-        author: Some(User {
-            id: 1,
-            login: "dev".to_string(),
-        }),
+        body: Some("Fixes #789".to_string()),
+        author: None,
         milestone_number: None,
         head_sha: String::new(),
-    };
-
-    let pr_provider = MockGitProvider::new();
-    pr_provider.set_pull_request(pr);
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
 
-    // Config has the flag enabled, but no issue provider is set.
     let config = CurrentPullRequestValidationConfiguration {
-        issue_propagation: IssuePropagationConfig {
-            sync_milestone_from_issue: true,
-            sync_project_from_issue: false,
-        },
-        ..CurrentPullRequestValidationConfiguration::default()
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["ghost".to_string()]),
+            BypassRule::new(true, vec!["ghost".to_string()]),
+        ),
+        ..Default::default()
     };
 
-    // No `.with_issue_provider(...)` call — propagation must be silently skipped.
-    let warden = MergeWarden::with_config(pr_provider, config);
+    let warden = MergeWarden::with_config(provider, config);
 
-    // Must complete without panicking.
-    warden
-        .process_pull_request("owner", "repo", 1)
+    let result = warden
+        .process_pull_request("owner", "repo", 127)
         .await
         .unwrap();
-    // If we reach here without a panic, the test passes. There is no issue
-    // provider to assert on since none was supplied.
-}
 
-// ──────────────────────────────────────────────────────────────────────────────
-// Task 5.0 — Tests for targeted comment content produced by
-// communicate_pr_title_validity_status() for each TitleIssue variant.
-// ──────────────────────────────────────────────────────────────────────────────
+    assert!(!result.title_valid, "Title should be invalid (no bypass)");
+    assert!(result.bypasses_used.is_empty());
+}
 
-/// Build a minimal `PullRequest` with the given number and title.
-///
-/// The body always contains a valid work item reference so that work-item
-/// validation does not interfere with the assertions about title comments.
-fn make_pr_for_title_test(number: u64, title: &str) -> PullRequest {
-    PullRequest {
-        number,
-        title: title.to_string(),
+#[tokio::test]
+async fn test_missing_author_login_is_used_for_bypass_of_pr_without_author() {
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 128,
+        title: "bad title".to_string(),
         draft: false,
-        body: Some("Fixes #123".to_string()),
-        author: Some(User {
-            id: 1,
-            login: "dev".to_string(),
-        }),
+        body: Some("Fixes #789".to_string()),
+        author: None,
         milestone_number: None,
         head_sha: String::new(),
-    }
-}
-
-/// Build a `DynamicMockGitProvider` that already contains `pr`.
-fn provider_with_pr(pr: PullRequest) -> DynamicMockGitProvider {
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(pr);
-    provider
-}
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
 
-/// Return the single title-related comment body from the provider, or panic
-/// if none (or more than one) was found.
-fn get_title_comment(comments: Vec<Comment>) -> String {
-    let title_comments: Vec<_> = comments
-        .iter()
-        .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
-        .collect();
-    assert_eq!(
-        title_comments.len(),
-        1,
-        "expected exactly one title comment, got {}: {:?}",
-        title_comments.len(),
-        title_comments.iter().map(|c| &c.body).collect::<Vec<_>>()
-    );
-    title_comments[0].body.clone()
-}
+    let config = CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["ghost".to_string()]),
+            BypassRule::new(false, vec![]),
+        )
+        .with_missing_author_login("ghost"),
+        ..Default::default()
+    };
 
-#[tokio::test]
-async fn test_comment_contains_leading_whitespace_message_and_suggested_fix() {
-    // 5.1 — Title " feat: add login" triggers LeadingWhitespace.
-    let pr = make_pr_for_title_test(1, " feat: add login");
-    let provider = provider_with_pr(pr);
-    let warden = MergeWarden::new(provider);
+    let warden = MergeWarden::with_config(provider, config);
 
     let result = warden
-        .process_pull_request("owner", "repo", 1)
+        .process_pull_request("owner", "repo", 128)
         .await
         .unwrap();
-    assert!(!result.title_valid, "title should be invalid");
 
-    let body = get_title_comment(warden.provider.get_comments());
-    assert!(
-        body.contains("starts with whitespace"),
-        "comment should describe leading-whitespace issue; got:\n{body}"
-    );
-    assert!(
-        body.contains("Suggested fix: `feat: add login`"),
-        "comment should include suggested fix; got:\n{body}"
-    );
-    assert!(
-        body.contains("Conventional Commits"),
-        "comment should include general format reminder; got:\n{body}"
+    assert!(result.title_valid, "Title should be valid due to bypass");
+    assert_eq!(result.bypasses_used.len(), 1);
+    assert_eq!(result.bypasses_used[0].user, "ghost");
+    assert_eq!(
+        result.bypasses_used[0].rule_type,
+        BypassRuleType::TitleConvention
     );
 }
 
 #[tokio::test]
-async fn test_comment_contains_whitespace_before_colon_message_and_suggested_fix() {
-    // 5.2 — Title "feat : add login" triggers WhitespaceBeforeColon.
-    let pr = make_pr_for_title_test(2, "feat : add login");
-    let provider = provider_with_pr(pr);
-    let warden = MergeWarden::new(provider);
+async fn test_check_status_with_bypass_information() {
+    // Test that check status includes bypass information in the summary
+    let provider = DynamicMockGitProvider::new();
 
-    let result = warden
-        .process_pull_request("owner", "repo", 2)
-        .await
-        .unwrap();
-    assert!(!result.title_valid, "title should be invalid");
+    // Create a PR with invalid title and no work item reference, user who can bypass both
+    let pr = PullRequest {
+        number: 200,
+        title: "urgent fix".to_string(), // Invalid conventional commit format
+        draft: false,
+        body: Some("Emergency production fix".to_string()), // No work item reference
+        author: Some(User {
+            id: 200,
+            login: "emergency-admin".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
 
-    let body = get_title_comment(warden.provider.get_comments());
-    assert!(
-        body.contains("whitespace before the `:` separator"),
-        "comment should describe whitespace-before-colon issue; got:\n{body}"
-    );
-    assert!(
-        body.contains("Suggested fix: `feat: add login`"),
-        "comment should include suggested fix; got:\n{body}"
-    );
-}
+    // Add the PR to the mock provider
+    let mut provider_mut = provider;
+    provider_mut.add_pull_request(pr.clone());
 
-#[tokio::test]
-async fn test_comment_contains_uppercase_type_message_and_suggested_fix_and_reminder() {
-    // 5.3 — Title "FEAT: add login" triggers UppercaseType.
-    let pr = make_pr_for_title_test(3, "FEAT: add login");
-    let provider = provider_with_pr(pr);
-    let warden = MergeWarden::new(provider);
+    // Create MergeWarden with bypass rules allowing "emergency-admin" to bypass both validations
+    let config = CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["emergency-admin".to_string()]), // Title bypass enabled
+            BypassRule::new(true, vec!["emergency-admin".to_string()]), // Work item bypass enabled
+        ),
+        ..Default::default()
+    };
+
+    let warden = MergeWarden::with_config(provider_mut, config);
 
+    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 3)
+        .process_pull_request("owner", "repo", 200)
         .await
         .unwrap();
-    assert!(!result.title_valid, "title should be invalid");
 
-    let body = get_title_comment(warden.provider.get_comments());
-    assert!(
-        body.contains("must be lowercase"),
-        "comment should describe uppercase-type issue; got:\n{body}"
-    );
+    // Verify the result indicates success due to bypass
+    assert!(result.title_valid, "Title should be valid due to bypass");
     assert!(
-        body.contains("Suggested fix: `feat: add login`"),
-        "comment should include suggested fix; got:\n{body}"
+        result.work_item_referenced,
+        "Work item should be valid due to bypass"
     );
-    assert!(
-        body.contains("Conventional Commits"),
-        "comment should include general format reminder; got:\n{body}"
+
+    // Verify bypass information is recorded
+    assert_eq!(
+        result.bypasses_used.len(),
+        2,
+        "Should have two bypasses recorded"
     );
+
+    // Note: We can't directly test the check status text here since it's passed to the mock provider,
+    // but we can verify that the bypass information is properly collected and would be included
+    // in the summary based on our implementation.
 }
 
 #[tokio::test]
-async fn test_comment_contains_unrecognized_type_message_with_nearest_valid_suggestion() {
-    // 5.4 — Title "feature: add login" triggers UnrecognizedType with nearest_valid "feat".
-    let pr = make_pr_for_title_test(4, "feature: add login");
-    let provider = provider_with_pr(pr);
-    let warden = MergeWarden::new(provider);
+async fn test_check_status_text_formatting() {
+    // Test the smart text formatting for check status
+    let provider = DynamicMockGitProvider::new();
+
+    // Create a PR that's valid (no comments needed)
+    let pr = PullRequest {
+        number: 201,
+        title: "feat: add new feature".to_string(), // Valid conventional commit format
+        draft: false,
+        body: Some("Fixes #123".to_string()), // Valid work item reference
+        author: Some(User {
+            id: 201,
+            login: "regular-dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    // Add the PR to the mock provider
+    let mut provider_mut = provider;
+    provider_mut.add_pull_request(pr.clone());
+
+    // Create MergeWarden with default config (no bypasses)
+    let config = CurrentPullRequestValidationConfiguration::default();
+    let warden = MergeWarden::with_config(provider_mut, config);
 
+    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 4)
+        .process_pull_request("owner", "repo", 201)
         .await
         .unwrap();
-    assert!(!result.title_valid, "title should be invalid");
 
-    let body = get_title_comment(warden.provider.get_comments());
-    assert!(
-        body.contains("not a recognized conventional commit type"),
-        "comment should describe unrecognised-type issue; got:\n{body}"
-    );
+    // Verify the result indicates success without bypasses
+    assert!(result.title_valid, "Title should be naturally valid");
     assert!(
-        body.contains("`feat`"),
-        "comment should mention the nearest valid type; got:\n{body}"
+        result.work_item_referenced,
+        "Work item should be naturally valid"
     );
-    assert!(
-        body.contains("Suggested fix: `feat: add login`"),
-        "comment should include suggested fix; got:\n{body}"
+    assert_eq!(
+        result.bypasses_used.len(),
+        0,
+        "Should have no bypasses recorded"
     );
+
+    // The check status should show "All PR requirements satisfied." without bypass mention
+    // since no bypasses were used
 }
 
 #[tokio::test]
-async fn test_comment_contains_missing_colon_message_and_suggested_fix() {
-    // 5.5 — Title "feat add login" triggers MissingColon.
-    let pr = make_pr_for_title_test(5, "feat add login");
-    let provider = provider_with_pr(pr);
-    let warden = MergeWarden::new(provider);
+async fn test_check_status_bypass_information_formatting() {
+    // Test the specific messages included in check status when bypasses are used
+    let provider = DynamicMockGitProvider::new();
 
-    let result = warden
-        .process_pull_request("owner", "repo", 5)
-        .await
-        .unwrap();
-    assert!(!result.title_valid, "title should be invalid");
+    // Create a PR with invalid title but author who can bypass
+    let pr = PullRequest {
+        number: 301,
+        title: "invalid title format".to_string(), // Invalid conventional commit format
+        draft: false,
+        body: Some("Fixes #123".to_string()), // Valid work item reference
+        author: Some(User {
+            id: 301,
+            login: "bypass-user".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
 
-    let body = get_title_comment(warden.provider.get_comments());
-    assert!(
-        body.contains("`:` separator is required"),
-        "comment should describe missing-colon issue; got:\n{body}"
-    );
-    assert!(
-        body.contains("Suggested fix: `feat: add login`"),
-        "comment should include suggested fix; got:\n{body}"
-    );
-}
+    // Add the PR to the mock provider
+    let mut provider_mut = provider;
+    provider_mut.add_pull_request(pr.clone());
 
-#[tokio::test]
-async fn test_comment_contains_missing_space_after_colon_message_and_suggested_fix() {
-    // 5.6 — Title "feat:add login" triggers MissingSpaceAfterColon.
-    let pr = make_pr_for_title_test(6, "feat:add login");
-    let provider = provider_with_pr(pr);
-    let warden = MergeWarden::new(provider);
+    // Create MergeWarden with bypass enabled for title validation for this user
+    let config = CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["bypass-user".to_string()]),
+            BypassRule::new(false, vec![]),
+        ),
+        ..Default::default()
+    };
 
+    let warden = MergeWarden::with_config(provider_mut, config);
+
+    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 6)
+        .process_pull_request("owner", "repo", 301)
         .await
         .unwrap();
-    assert!(!result.title_valid, "title should be invalid");
 
-    let body = get_title_comment(warden.provider.get_comments());
+    // Verify the result indicates one bypass was used
+    assert!(result.title_valid, "Title should be valid due to bypass");
     assert!(
-        body.contains("space is required after the `:` separator"),
-        "comment should describe missing-space-after-colon issue; got:\n{body}"
+        result.work_item_referenced,
+        "Work item should be naturally valid"
     );
-    assert!(
-        body.contains("Suggested fix: `feat: add login`"),
-        "comment should include suggested fix; got:\n{body}"
+    assert_eq!(
+        result.bypasses_used.len(),
+        1,
+        "Should have one bypass recorded"
+    );
+    assert_eq!(
+        result.bypasses_used[0].rule_type,
+        BypassRuleType::TitleConvention
+    );
+    assert_eq!(result.bypasses_used[0].user_login(), Some("bypass-user"));
+
+    // Verify check was marked as successful with bypass indication
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1, "Should have one check status update");
+
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "success");
+    assert_eq!(update.title, "Merge Warden");
+    assert_eq!(
+        update.summary,
+        "All PR requirements satisfied (1 validation bypassed)."
     );
 }
 
 #[tokio::test]
-async fn test_comment_contains_no_type_prefix_message_and_no_suggested_fix_line() {
-    // 5.7 — Title "Add login functionality" triggers NoTypePrefix; no suggested fix.
-    let pr = make_pr_for_title_test(7, "Add login functionality");
-    let provider = provider_with_pr(pr);
-    let warden = MergeWarden::new(provider);
+async fn test_check_status_multiple_bypasses_formatting() {
+    // Test the message when multiple bypasses are used
+    let provider = DynamicMockGitProvider::new();
+
+    // Create a PR with invalid title and missing work item but author who can bypass both
+    let pr = PullRequest {
+        number: 302,
+        title: "invalid title format".to_string(), // Invalid conventional commit format
+        draft: false,
+        body: Some("No work item reference here".to_string()), // No work item reference
+        author: Some(User {
+            id: 302,
+            login: "super-bypass-user".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    // Add the PR to the mock provider
+    let mut provider_mut = provider;
+    provider_mut.add_pull_request(pr.clone());
+
+    // Create MergeWarden with bypasses enabled for both title and work item validation
+    let config = CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["super-bypass-user".to_string()]),
+            BypassRule::new(true, vec!["super-bypass-user".to_string()]),
+        ),
+        ..Default::default()
+    };
 
+    let warden = MergeWarden::with_config(provider_mut, config);
+
+    // Process the pull request
     let result = warden
-        .process_pull_request("owner", "repo", 7)
+        .process_pull_request("owner", "repo", 302)
         .await
         .unwrap();
-    assert!(!result.title_valid, "title should be invalid");
 
-    let body = get_title_comment(warden.provider.get_comments());
+    // Verify the result indicates two bypasses were used
+    assert!(result.title_valid, "Title should be valid due to bypass");
     assert!(
-        body.contains("No conventional commit type prefix"),
-        "comment should describe no-type-prefix issue; got:\n{body}"
+        result.work_item_referenced,
+        "Work item should be valid due to bypass"
     );
-    assert!(
-        body.contains("Conventional Commits"),
-        "comment should include general format reminder; got:\n{body}"
+    assert_eq!(
+        result.bypasses_used.len(),
+        2,
+        "Should have two bypasses recorded"
     );
-    assert!(
-        !body.contains("Suggested fix:"),
-        "comment must not include a suggested fix when none exists; got:\n{body}"
+
+    // Verify check was marked as successful with multiple bypass indication
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1, "Should have one check status update");
+
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "success");
+    assert_eq!(update.title, "Merge Warden");
+    assert_eq!(
+        update.summary,
+        "All PR requirements satisfied (2 validations bypassed)."
     );
 }
 
 #[tokio::test]
-async fn test_no_title_comment_posted_when_enforce_title_convention_is_false() {
-    // 5.8 — When enforce_title_convention is false a comment must never be posted,
-    //        even for a title that would otherwise be invalid.
-    let pr = make_pr_for_title_test(8, "completely wrong title");
-    let provider = provider_with_pr(pr);
-
+async fn test_check_text_lists_each_bypass_with_rule_and_user() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        body: Some("No work item reference here".to_string()),
+        ..invalid_title_pr()
+    });
+    provider.apply_label_as("skip-checks", "alice");
     let config = CurrentPullRequestValidationConfiguration {
-        enforce_title_convention: false,
-        ..CurrentPullRequestValidationConfiguration::default()
+        bypass_rules: BypassRules::new(
+            BypassRule::new(true, vec!["alice".to_string()]).with_label("skip-checks"),
+            BypassRule::new(true, vec!["contributor".to_string()]),
+        ),
+        ..Default::default()
     };
     let warden = MergeWarden::with_config(provider, config);
 
-    warden
-        .process_pull_request("owner", "repo", 8)
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments.iter().any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
-        "no title comment should be posted when enforce_title_convention is false; got:\n{comments:?}"
+    assert_eq!(result.bypasses_used.len(), 2);
+    assert_eq!(
+        result.check_summary,
+        "All PR requirements satisfied (2 validations bypassed)."
     );
+    assert!(result.check_text.contains(
+        "🔓 **Bypasses**:\n\n- Title validation bypassed by @alice\n- Work item validation bypassed by @contributor"
+    ));
 }
 
 #[tokio::test]
-async fn test_stale_title_comment_is_replaced_when_diagnosis_changes() {
-    // When a PR title changes from one invalid form to another the pre-existing
-    // title-validation comment (with a different diagnosis body) must be replaced,
-    // not left stale, so the author sees up-to-date feedback.
-
+async fn test_check_text_has_no_bypass_section_without_bypasses() {
     let provider = MockGitProvider::new();
-
-    // Pre-seed a comment that represents a previous (now stale) diagnosis for an
-    // UppercaseType error ("FEAT").
-    let stale_comment = format!(
-        "{}\nThe pull request title needs correction:\n- The type `FEAT` must be lowercase",
-        TITLE_COMMENT_MARKER
-    );
-    provider
-        .add_comment("owner", "repo", 1, &stale_comment)
-        .await
-        .unwrap();
-
-    // The author has since pushed a new title that has a DIFFERENT issue:
-    // "feature" is an UnrecognizedType (synonym for "feat"), not an UppercaseType.
-    let pr = PullRequest {
-        number: 1,
-        title: "feature: add login".to_string(),
-        draft: false,
-        body: Some("Fixes #123".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    };
-    provider.set_pull_request(pr);
-
+    provider.set_pull_request(review_thread_test_pr());
     let warden = MergeWarden::new(provider);
-    warden
+
+    let result = warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let comments = warden.provider.get_comments();
-    let title_comments: Vec<_> = comments
-        .iter()
-        .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
-        .collect();
-
-    assert_eq!(
-        title_comments.len(),
-        1,
-        "exactly one title comment should remain after diagnosis update; got: {:?}",
-        title_comments.iter().map(|c| &c.body).collect::<Vec<_>>()
-    );
-    assert!(
-        !title_comments[0].body.contains("must be lowercase"),
-        "stale UppercaseType message should no longer appear; got:\n{}",
-        title_comments[0].body
-    );
-    assert!(
-        title_comments[0]
-            .body
-            .contains("not a recognized conventional commit type"),
-        "updated comment should describe the UnrecognizedType issue; got:\n{}",
-        title_comments[0].body
-    );
+    assert!(result.bypasses_used.is_empty());
+    assert!(!result.check_text.contains("**Bypasses**"));
 }
 
 #[tokio::test]
-async fn test_identical_title_comment_is_not_reposted() {
-    // When the title is unchanged between two push events the existing comment
-    // body is identical and should not be deleted-and-reposted (idempotent path).
-
+async fn test_process_pull_request_smart_label_detection() {
+    // Setup provider with existing repository labels
     let provider = MockGitProvider::new();
 
-    // Simulate a PR with an invalid title ("feature: ...") that has already
-    // been processed and has a comment on it.
+    // Create a PR with conventional commit type "feat"
     let pr = PullRequest {
         number: 1,
-        title: "feature: add login".to_string(),
+        title: "feat(auth): add GitHub login functionality".to_string(),
         draft: false,
-        body: Some("Fixes #123".to_string()),
+        body: Some("This PR adds GitHub login functionality. Fixes #123".to_string()),
         author: Some(User {
             id: 456,
             login: "developer123".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
     provider.set_pull_request(pr);
 
-    let warden = MergeWarden::new(provider);
-
-    // First processing run — comment is created.
-    warden
-        .process_pull_request("owner", "repo", 1)
-        .await
-        .unwrap();
-
-    let comment_count_after_first_run = warden
-        .provider
-        .get_comments()
-        .iter()
-        .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
-        .count();
-    assert_eq!(
-        comment_count_after_first_run, 1,
-        "first run should produce exactly one title comment"
-    );
-
-    // Second processing run with the same title — comment body is identical.
-    warden
-        .process_pull_request("owner", "repo", 1)
-        .await
-        .unwrap();
-
-    let comment_count_after_second_run = warden
-        .provider
-        .get_comments()
-        .iter()
-        .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
-        .count();
-    assert_eq!(
-        comment_count_after_second_run, 1,
-        "second run with identical title should not create a duplicate comment"
-    );
-}
+    // Configure smart label detection
+    let change_type_config = ChangeTypeLabelConfig {
+        enabled: true,
+        conventional_commit_mappings: ConventionalCommitMappings {
+            feat: vec!["enhancement".to_string(), "feature".to_string()],
+            fix: vec!["bug".to_string(), "bugfix".to_string()],
+            docs: vec!["documentation".to_string()],
+            style: vec!["style".to_string()],
+            refactor: vec!["refactor".to_string()],
+            perf: vec!["performance".to_string()],
+            test: vec!["test".to_string()],
+            chore: vec!["chore".to_string()],
+            ci: vec!["ci".to_string()],
+            build: vec!["build".to_string()],
+            revert: vec!["revert".to_string()],
+        },
+        detection_strategy: LabelDetectionStrategy {
+            exact_match: true,
+            prefix_match: true,
+            description_match: true,
+            common_prefixes: vec!["type:".to_string(), "kind:".to_string()],
+        },
+        fallback_label_settings: FallbackLabelSettings {
+            name_format: "type: {change_type}".to_string(),
+            color_scheme: HashMap::from([
+                ("feat".to_string(), "00ff00".to_string()),
+                ("fix".to_string(), "ff0000".to_string()),
+            ]),
+            create_if_missing: true,
+        },
+        keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
+    };
 
-// ──────────────────────────────────────────────────────────────────────────────
-// Draft PR processing tests
-//
-// Draft PRs must run full validation so authors see issues early, but the
-// check conclusion must be "neutral" (non-blocking) rather than "failure" to
-// avoid hard-blocking the PR while it is actively being worked on.
-// ──────────────────────────────────────────────────────────────────────────────
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        title_pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
+        invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
+        enforce_work_item_references: true,
+        work_item_reference_pattern: WORK_ITEM_REGEX.into(),
+        missing_work_item_label: Some(MISSING_WORK_ITEM_LABEL.to_string()),
+        pr_size_check: Default::default(),
+        change_type_labels: Some(change_type_config),
+        bypass_rules: BypassRules::default(),
+        ..Default::default()
+    };
 
-#[tokio::test]
-async fn test_process_pull_request_draft_with_invalid_title_yields_neutral_check_conclusion() {
-    // A draft PR with an invalid title must still be validated, but the
-    // resulting check status must use "neutral" (not "failure") so the PR
-    // is never hard-blocked while in draft mode.
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 1,
-        title: "invalid title format".to_string(), // does not match conventional commit
-        draft: true,
-        body: Some("Fixes #123".to_string()),
-        author: Some(User {
-            id: 1,
-            login: "dev".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    });
+    // Create a MergeWarden instance with smart label configuration
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::new(provider);
+    // Process the PR
     let result = warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
+    // Verify the result
+    assert!(result.title_valid, "Title should be valid");
     assert!(
-        !result.title_valid,
-        "Validation must still run for draft PRs; title should be invalid"
+        result.work_item_referenced,
+        "Work item should be referenced"
     );
+    assert!(result.size_valid, "Size should be valid");
 
-    let updates = warden.provider.get_check_status_updates();
-    assert_eq!(updates.len(), 1, "Expected one check status update");
-    assert_eq!(
-        updates[0].conclusion, "neutral",
-        "Draft PRs with failures must use 'neutral' conclusion, got: {}",
-        updates[0].conclusion
-    );
-    assert!(
-        updates[0].summary.contains("Draft mode"),
-        "Check summary should mention draft mode, got: {}",
-        updates[0].summary
-    );
+    // For now, just verify the basic functionality is working
+    // TODO: Add repository label support to MockGitProvider to test smart label detection
+    // The smart label detection logic should be working in the actual code
 }
 
 #[tokio::test]
-async fn test_process_pull_request_draft_with_valid_pr_yields_success_check_conclusion() {
-    // A draft PR that passes all validations must still produce "success",
-    // not "neutral" — "neutral" is only for draft + failures.
-    let mut provider = DynamicMockGitProvider::new();
-    provider.add_pull_request(PullRequest {
-        number: 2,
-        title: "feat: add login".to_string(),
-        draft: true,
-        body: Some("Fixes #456".to_string()),
+async fn test_process_pull_request_smart_label_detection_with_audit_logging() {
+    // This test validates task 5.0 integration: enhanced error handling, performance monitoring, and audit logging
+    let provider = MockGitProvider::new();
+
+    // Create a PR with conventional commit type "feat"
+    let pr = PullRequest {
+        number: 1,
+        title: "feat(auth): add GitHub OAuth integration".to_string(),
+        draft: false,
+        body: Some(
+            "This PR adds OAuth functionality for better authentication. Fixes #789".to_string(),
+        ),
         author: Some(User {
-            id: 2,
-            login: "dev2".to_string(),
+            id: 123,
+            login: "smart-dev".to_string(),
         }),
         milestone_number: None,
         head_sha: String::new(),
-    });
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    provider.set_pull_request(pr);
 
-    let warden = MergeWarden::new(provider);
+    // Configure comprehensive smart label detection
+    let change_type_config = ChangeTypeLabelConfig {
+        enabled: true,
+        conventional_commit_mappings: ConventionalCommitMappings {
+            feat: vec![
+                "enhancement".to_string(),
+                "feature".to_string(),
+                "new-feature".to_string(),
+            ],
+            fix: vec![
+                "bug".to_string(),
+                "bugfix".to_string(),
+                "hotfix".to_string(),
+            ],
+            docs: vec!["documentation".to_string(), "docs".to_string()],
+            style: vec!["style".to_string(), "formatting".to_string()],
+            refactor: vec!["refactor".to_string(), "refactoring".to_string()],
+            perf: vec!["performance".to_string(), "optimization".to_string()],
+            test: vec!["test".to_string(), "testing".to_string()],
+            chore: vec!["chore".to_string(), "maintenance".to_string()],
+            ci: vec!["ci".to_string(), "continuous-integration".to_string()],
+            build: vec!["build".to_string(), "build-system".to_string()],
+            revert: vec!["revert".to_string(), "rollback".to_string()],
+        },
+        detection_strategy: LabelDetectionStrategy {
+            exact_match: true,
+            prefix_match: true,
+            description_match: true,
+            common_prefixes: vec![
+                "type:".to_string(),
+                "kind:".to_string(),
+                "category:".to_string(),
+                "tag:".to_string(),
+            ],
+        },
+        fallback_label_settings: FallbackLabelSettings {
+            name_format: "type: {change_type}".to_string(),
+            color_scheme: HashMap::from([
+                ("feat".to_string(), "0366d6".to_string()),
+                ("fix".to_string(), "d73a4a".to_string()),
+                ("docs".to_string(), "0075ca".to_string()),
+                ("style".to_string(), "f9d0c4".to_string()),
+                ("refactor".to_string(), "a2eeef".to_string()),
+                ("perf".to_string(), "7057ff".to_string()),
+                ("test".to_string(), "0e8a16".to_string()),
+                ("chore".to_string(), "fef2c0".to_string()),
+                ("ci".to_string(), "0052cc".to_string()),
+                ("build".to_string(), "1d76db".to_string()),
+                ("revert".to_string(), "b60205".to_string()),
+            ]),
+            create_if_missing: true,
+        },
+        keyword_labels: KeywordLabelsConfig::default(),
+        categories: Default::default(),
+        max_auto_labels: None,
+        report_no_labels: None,
+    };
+
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        title_pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
+        invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
+        enforce_work_item_references: true,
+        work_item_reference_pattern: WORK_ITEM_REGEX.into(),
+        missing_work_item_label: Some(MISSING_WORK_ITEM_LABEL.to_string()),
+        pr_size_check: Default::default(),
+        change_type_labels: Some(change_type_config),
+        bypass_rules: BypassRules::default(),
+        ..Default::default()
+    };
+
+    // Create a MergeWarden instance with enhanced smart label configuration
+    let warden = MergeWarden::with_config(provider, config);
+
+    // Process the PR
     let result = warden
-        .process_pull_request("owner", "repo", 2)
+        .process_pull_request("test-org", "smart-repo", 1)
         .await
         .unwrap();
 
+    // Verify the result
     assert!(result.title_valid, "Title should be valid");
     assert!(
         result.work_item_referenced,
         "Work item should be referenced"
     );
+    assert!(result.size_valid, "Size should be valid");
 
+    // Verify check status update includes smart label information
     let updates = warden.provider.get_check_status_updates();
-    assert_eq!(updates.len(), 1, "Expected one check status update");
-    assert_eq!(
-        updates[0].conclusion, "success",
-        "Draft PR that passes all validations should yield 'success', got: {}",
-        updates[0].conclusion
+    assert_eq!(updates.len(), 1, "Should have one check status update");
+
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "success");
+    assert_eq!(update.title, "Merge Warden");
+    assert_eq!(update.summary, "All PR requirements satisfied.");
+
+    // The text should include smart label detection information
+    assert!(
+        update.text.contains("Smart Label Detection") || update.text.contains("Legacy Labeling"),
+        "Check status should include labeling information"
     );
+
+    // Verify that labels were processed (even if MockGitProvider doesn't simulate repository labels,
+    // the processing should complete without errors)
+    // In a real scenario with actual repository labels, we would see smart detection working
+
+    info!("Smart label detection integration test completed successfully");
 }
 
-// ──────────────────────────────────────────────────────────────────────────────
+//  check_wip_status tests
+
+#[tokio::test]
+async fn test_check_wip_status_detects_wip_prefix_in_title() {
+    use crate::config::WipCheckConfig;
+
+    let provider = ErrorMockGitProvider::new();
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let pr = PullRequest {
+        number: 1,
+        title: "WIP: add login feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    assert!(warden.check_wip_status(&pr), "Should detect 'WIP:' prefix");
+}
+
+#[tokio::test]
+async fn test_check_wip_status_detects_bracketed_wip_in_title() {
+    use crate::config::WipCheckConfig;
+
+    let provider = ErrorMockGitProvider::new();
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let pr = PullRequest {
+        number: 2,
+        title: "[WIP] implement user auth".to_string(),
+        draft: false,
+        body: Some("Fixes #456".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    assert!(
+        warden.check_wip_status(&pr),
+        "Should detect '[WIP]' in title"
+    );
+}
+
+#[tokio::test]
+async fn test_check_wip_status_returns_false_for_clean_title() {
+    use crate::config::WipCheckConfig;
+
+    let provider = ErrorMockGitProvider::new();
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let pr = PullRequest {
+        number: 3,
+        title: "feat(auth): add login functionality".to_string(),
+        draft: false,
+        body: Some("Fixes #789".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    assert!(
+        !warden.check_wip_status(&pr),
+        "Should not flag a clean conventional-commit title"
+    );
+}
+
+#[tokio::test]
+async fn test_check_wip_status_detects_custom_description_pattern() {
+    use crate::config::WipCheckConfig;
+
+    let provider = ErrorMockGitProvider::new();
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            wip_description_patterns: vec!["DO NOT MERGE".to_string()],
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let pr = PullRequest {
+        number: 4,
+        title: "feat: some feature".to_string(),
+        draft: false,
+        body: Some("DO NOT MERGE: waiting for design approval\nFixes #100".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    assert!(
+        warden.check_wip_status(&pr),
+        "Should detect custom description pattern"
+    );
+}
+
+#[tokio::test]
+async fn test_check_wip_status_no_body_with_description_pattern_returns_false() {
+    use crate::config::WipCheckConfig;
+
+    let provider = ErrorMockGitProvider::new();
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            wip_description_patterns: vec!["DO NOT MERGE".to_string()],
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let pr = PullRequest {
+        number: 5,
+        title: "feat: some feature".to_string(),
+        draft: false,
+        body: None, // No body
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    assert!(
+        !warden.check_wip_status(&pr),
+        "No body means description patterns cannot match"
+    );
+}
+
+//  process_pull_request WIP integration tests
+
+#[tokio::test]
+async fn test_process_pull_request_wip_title_returns_wip_detected_true() {
+    use crate::config::WipCheckConfig;
+
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 500,
+        title: "WIP: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    let result = warden
+        .process_pull_request("owner", "repo", 500)
+        .await
+        .unwrap();
+
+    assert!(
+        result.wip_detected,
+        "WIP PR should have wip_detected = true"
+    );
+}
+
+#[tokio::test]
+async fn test_process_pull_request_wip_sets_failure_check_status() {
+    use crate::config::WipCheckConfig;
+
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 501,
+        title: "[WIP] implement payment flow".to_string(),
+        draft: false,
+        body: Some("Fixes #200".to_string()),
+        author: Some(User {
+            id: 2,
+            login: "dev2".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    let result = warden
+        .process_pull_request("owner", "repo", 501)
+        .await
+        .unwrap();
+
+    assert!(result.wip_detected);
+    // Verify the check was set to failure
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(
+        updates[0].conclusion, "failure",
+        "WIP PR should result in a failure check status"
+    );
+}
+
+#[tokio::test]
+async fn test_process_pull_request_wip_returns_reported_check_output() {
+    use crate::config::WipCheckConfig;
+
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 503,
+        title: "WIP: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    let result = warden
+        .process_pull_request("owner", "repo", 503)
+        .await
+        .unwrap();
+
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(result.check_summary, updates[0].summary);
+    assert_eq!(result.check_text, updates[0].text);
+}
+
+#[tokio::test]
+async fn test_process_pull_request_clean_pr_has_wip_detected_false() {
+    use crate::config::WipCheckConfig;
+
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 502,
+        title: "feat(auth): implement OAuth login".to_string(),
+        draft: false,
+        body: Some("Fixes #300".to_string()),
+        author: Some(User {
+            id: 3,
+            login: "dev3".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        enforce_work_item_references: true,
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    let result = warden
+        .process_pull_request("owner", "repo", 502)
+        .await
+        .unwrap();
+
+    assert!(
+        !result.wip_detected,
+        "Clean PR should have wip_detected = false"
+    );
+}
+
+#[tokio::test]
+async fn test_process_pull_request_wip_blocking_disabled_does_not_flag_wip() {
+    // WIP blocking is disabled  even a WIP-titled PR should not be blocked
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 503,
+        title: "WIP: work in progress feature".to_string(),
+        draft: false,
+        body: Some("Fixes #400".to_string()),
+        author: Some(User {
+            id: 4,
+            login: "dev4".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        enforce_work_item_references: true,
+        // wip_check is disabled by default
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    let result = warden
+        .process_pull_request("owner", "repo", 503)
+        .await
+        .unwrap();
+
+    assert!(
+        !result.wip_detected,
+        "WIP checking disabled means wip_detected stays false"
+    );
+    // The title "WIP: work in progress feature" does not match Conventional Commits,
+    // but that is a separate validation result  wip_detected itself must be false
+}
+
+#[tokio::test]
+async fn test_process_pull_request_wip_bypasses_are_not_applied() {
+    use crate::config::WipCheckConfig;
+
+    // Even a user with full bypass permissions cannot bypass WIP blocking
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 504,
+        title: "WIP: add something".to_string(),
+        draft: false,
+        body: Some("Fixes #500".to_string()),
+        author: Some(User {
+            id: 5,
+            login: "release-bot".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let bypass_rule = BypassRule::new(true, vec!["release-bot".to_string()]);
+    let config = CurrentPullRequestValidationConfiguration {
+        bypass_rules: BypassRules::new(bypass_rule.clone(), bypass_rule.clone()),
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    let result = warden
+        .process_pull_request("owner", "repo", 504)
+        .await
+        .unwrap();
+
+    assert!(
+        result.wip_detected,
+        "Bypass rules must not override WIP blocking"
+    );
+}
+
+#[tokio::test]
+async fn test_communicate_wip_status_adds_comment_when_wip() {
+    use crate::config::WipCheckConfig;
+
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 600,
+        title: "WIP: test".to_string(),
+        draft: false,
+        body: Some("Fixes #1".to_string()),
+        author: Some(User {
+            id: 10,
+            login: "tester".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    let pr = PullRequest {
+        number: 600,
+        title: "WIP: test".to_string(),
+        draft: false,
+        body: Some("Fixes #1".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    warden
+        .communicate_wip_status("owner", "repo", &pr, true)
+        .await;
+
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments.is_empty(),
+        "A WIP blocking comment should be added"
+    );
+    assert!(
+        comments.iter().any(|c| c.body.contains(WIP_COMMENT_MARKER)),
+        "WIP comment must contain the WIP_COMMENT_MARKER"
+    );
+}
+
+#[tokio::test]
+async fn test_communicate_wip_status_removes_comment_when_not_wip() {
+    use crate::config::WipCheckConfig;
+
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 601,
+        title: "feat: done now".to_string(),
+        draft: false,
+        body: Some("Fixes #2".to_string()),
+        author: Some(User {
+            id: 11,
+            login: "tester2".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    // Pre-populate with a WIP comment
+    {
+        let wip_comment_body = format!("{}some wip text", WIP_COMMENT_MARKER);
+        let mut comments = provider.comments.lock().unwrap();
+        comments.push(Comment {
+            id: 99,
+            body: wip_comment_body,
+            user: User {
+                id: 11,
+                login: "bot".to_string(),
+            },
+        });
+    }
+
+    let config = CurrentPullRequestValidationConfiguration {
+        wip_check: WipCheckConfig {
+            enforce_wip_blocking: true,
+            ..WipCheckConfig::default()
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    let pr = PullRequest {
+        number: 601,
+        title: "feat: done now".to_string(),
+        draft: false,
+        body: Some("Fixes #2".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    warden
+        .communicate_wip_status("owner", "repo", &pr, false)
+        .await;
+
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments.iter().any(|c| c.body.contains(WIP_COMMENT_MARKER)),
+        "WIP comment should be removed when PR is no longer WIP"
+    );
+}
+
+/// Regression test: a reviewer who first approves and then supersedes that review
+/// with `changes_requested` must result in the review label being applied, not the
+/// approved label.  The fix groups reviews by reviewer and takes the latest.
+#[tokio::test]
+async fn test_communicate_pr_state_labels_superseded_approval_shows_review_state() {
+    use crate::config::PrStateLabelsConfig;
+
+    let reviewer = User {
+        id: 42,
+        login: "reviewer1".to_string(),
+    };
+
+    // API returns reviews oldest-first: an approval followed by a changes_requested
+    // from the same reviewer.  The changes_requested supersedes the approval.
+    let reviews = vec![
+        Review {
+            id: 1,
+            state: "approved".to_string(),
+            user: reviewer.clone(),
+        },
+        Review {
+            id: 2,
+            state: "changes_requested".to_string(),
+            user: reviewer.clone(),
+        },
+    ];
+
+    let pr = PullRequest {
+        number: 700,
+        title: "feat: some feature".to_string(),
+        draft: false,
+        body: Some("Fixes #1".to_string()),
+        author: Some(User {
+            id: 10,
+            login: "author".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    let mut provider = DynamicMockGitProvider::new().with_reviews(reviews);
+    provider.add_pull_request(pr.clone());
+
+    let config = CurrentPullRequestValidationConfiguration {
+        pr_state_labels: PrStateLabelsConfig {
+            enabled: true,
+            draft_label: Some("state: draft".to_string()),
+            review_label: Some("state: in-review".to_string()),
+            approved_label: Some("state: approved".to_string()),
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    warden
+        .communicate_pr_state_labels("owner", "repo", &pr)
+        .await;
+
+    let labels = warden.provider.get_labels();
+    assert!(
+        labels.iter().any(|l| l.name == "state: in-review"),
+        "The review label must be applied when the latest review is changes_requested, got: {:?}",
+        labels
+    );
+    assert!(
+        !labels.iter().any(|l| l.name == "state: approved"),
+        "The approved label must NOT be applied when the latest review supersedes the approval, got: {:?}",
+        labels
+    );
+}
+
+// ---------------------------------------------------------------------------
+// propagate_issue_metadata tests
+// ---------------------------------------------------------------------------
+
+use merge_warden_developer_platforms::{
+    models::{IssueMetadata, IssueMilestone, IssueProject},
+    IssueMetadataProvider,
+};
+
+/// Minimal mock for `IssueMetadataProvider`.
+#[derive(Debug, Clone)]
+struct MockIssueProvider {
+    /// Metadata returned by `get_issue_metadata`. `None` means 404.
+    metadata: Option<IssueMetadata>,
+    /// Set to `true` to make `get_issue_metadata` return an error.
+    error_on_get: bool,
+    /// Set to `true` to make `set_pull_request_milestone` return an error.
+    error_on_set_milestone: bool,
+    /// Set to `true` to make `add_pull_request_to_project` return an error.
+    error_on_add_project: bool,
+    /// Records every `set_pull_request_milestone` call as `(pr_number, milestone_number)`.
+    milestone_calls: Arc<Mutex<Vec<(u64, Option<u64>)>>>,
+    /// Records every `add_pull_request_to_project` call as `(pr_number, project_number)`.
+    project_calls: Arc<Mutex<Vec<(u64, u64)>>>,
+}
+
+impl MockIssueProvider {
+    fn new() -> Self {
+        Self {
+            metadata: None,
+            error_on_get: false,
+            error_on_set_milestone: false,
+            error_on_add_project: false,
+            milestone_calls: Arc::new(Mutex::new(Vec::new())),
+            project_calls: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn with_metadata(mut self, m: IssueMetadata) -> Self {
+        self.metadata = Some(m);
+        self
+    }
+
+    fn with_get_error(mut self) -> Self {
+        self.error_on_get = true;
+        self
+    }
+
+    fn with_set_milestone_error(mut self) -> Self {
+        self.error_on_set_milestone = true;
+        self
+    }
+
+    fn with_add_project_error(mut self) -> Self {
+        self.error_on_add_project = true;
+        self
+    }
+
+    fn milestone_calls(&self) -> Vec<(u64, Option<u64>)> {
+        self.milestone_calls.lock().unwrap().clone()
+    }
+
+    fn project_calls(&self) -> Vec<(u64, u64)> {
+        self.project_calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl IssueMetadataProvider for MockIssueProvider {
+    async fn get_issue_metadata(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _issue_number: u64,
+    ) -> Result<Option<IssueMetadata>, Error> {
+        if self.error_on_get {
+            return Err(Error::ApiError());
+        }
+        Ok(self.metadata.clone())
+    }
+
+    async fn set_pull_request_milestone(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        pr_number: u64,
+        milestone_number: Option<u64>,
+    ) -> Result<(), Error> {
+        if self.error_on_set_milestone {
+            return Err(Error::FailedToUpdatePullRequest(
+                "mock set_milestone error".to_string(),
+            ));
+        }
+        self.milestone_calls
+            .lock()
+            .unwrap()
+            .push((pr_number, milestone_number));
+        Ok(())
+    }
+
+    async fn add_pull_request_to_project(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        pr_number: u64,
+        project_number: u64,
+        _project_owner_login: &str,
+    ) -> Result<(), Error> {
+        if self.error_on_add_project {
+            return Err(Error::ApiError());
+        }
+        self.project_calls
+            .lock()
+            .unwrap()
+            .push((pr_number, project_number));
+        Ok(())
+    }
+}
+
+/// Helper: build a `MergeWarden` with `sync_milestone_from_issue` and/or
+/// `sync_project_from_issue` set as requested.
+fn warden_with_issue_propagation(milestone: bool, project: bool) -> MergeWarden<MockGitProvider> {
+    let provider = MockGitProvider::new();
+    let config = CurrentPullRequestValidationConfiguration {
+        issue_propagation: IssuePropagationConfig {
+            sync_milestone_from_issue: milestone,
+            sync_project_from_issue: project,
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+    MergeWarden::with_config(provider, config)
+}
+
+/// Helper: minimal `PullRequest` with the given body and milestone_number.
+fn make_pr(body: Option<&str>, milestone_number: Option<u64>) -> PullRequest {
+    PullRequest {
+        number: 42,
+        title: "feat: something".to_string(),
+        draft: false,
+        body: body.map(|s| s.to_string()),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_flags_off_skips_all_calls() {
+    // Both flags off → no API calls regardless of PR body / issue data.
+    let warden = warden_with_issue_propagation(false, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 7,
+            title: "v1.0".to_string(),
+        }),
+        projects: vec![],
+    });
+    let pr = make_pr(Some("Closes #10"), None);
+
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    assert!(
+        issue_provider.milestone_calls().is_empty(),
+        "set_pull_request_milestone must not be called when flag is off"
+    );
+    assert!(
+        issue_provider.project_calls().is_empty(),
+        "add_pull_request_to_project must not be called when flag is off"
+    );
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_empty_body_skips_propagation() {
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 7,
+            title: "v1.0".to_string(),
+        }),
+        projects: vec![],
+    });
+
+    // No body at all.
+    let pr = make_pr(None, None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+    assert!(issue_provider.milestone_calls().is_empty());
+
+    // Empty-string body.
+    let pr_empty = make_pr(Some(""), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr_empty, &issue_provider)
+        .await;
+    assert!(issue_provider.milestone_calls().is_empty());
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_references_keyword_triggers_propagation() {
+    // "References #10" is an informational keyword but should still trigger
+    // milestone/project propagation — an issue can require multiple PRs.
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 7,
+            title: "v1.0".to_string(),
+        }),
+        projects: vec![],
+    });
+
+    let pr = make_pr(Some("This PR references #10 but does not close it."), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    // Milestone should be set because 'references' now triggers propagation.
+    let calls = issue_provider.milestone_calls();
+    assert_eq!(
+        calls.len(),
+        1,
+        "Expected milestone to be set via 'references' keyword"
+    );
+    assert_eq!(calls[0], (42, Some(7)));
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_relates_to_keyword_triggers_propagation() {
+    // "Relates to #10" should also trigger propagation.
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 7,
+            title: "v1.0".to_string(),
+        }),
+        projects: vec![],
+    });
+
+    let pr = make_pr(Some("This PR relates to #10"), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    let calls = issue_provider.milestone_calls();
+    assert_eq!(
+        calls.len(),
+        1,
+        "Expected milestone to be set via 'relates to' keyword"
+    );
+    assert_eq!(calls[0], (42, Some(7)));
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_sets_milestone_when_pr_has_none() {
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 5,
+            title: "v2.0.0".to_string(),
+        }),
+        projects: vec![],
+    });
+
+    let pr = make_pr(Some("Closes #10"), None); // PR has no milestone yet
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    let calls = issue_provider.milestone_calls();
+    assert_eq!(calls.len(), 1, "Expected exactly one milestone call");
+    assert_eq!(calls[0], (42, Some(5)));
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_overwrites_different_pr_milestone() {
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 9,
+            title: "v3.0.0".to_string(),
+        }),
+        projects: vec![],
+    });
+
+    // PR already has milestone 3, issue has milestone 9 → should overwrite.
+    let pr = make_pr(Some("Fixes #20"), Some(3));
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    let calls = issue_provider.milestone_calls();
+    assert_eq!(calls.len(), 1, "Expected exactly one milestone call");
+    assert_eq!(calls[0], (42, Some(9)));
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_no_op_when_pr_already_has_same_milestone() {
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 5,
+            title: "v2.0.0".to_string(),
+        }),
+        projects: vec![],
+    });
+
+    // PR already has the same milestone → no-op (assertion 7 from spec).
+    let pr = make_pr(Some("Closes #10"), Some(5));
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    assert!(
+        issue_provider.milestone_calls().is_empty(),
+        "set_pull_request_milestone must not be called when PR already has the same milestone"
+    );
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_no_op_when_issue_has_no_milestone() {
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: None, // issue has no milestone
+        projects: vec![],
+    });
+
+    let pr = make_pr(Some("Closes #10"), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    assert!(
+        issue_provider.milestone_calls().is_empty(),
+        "set_pull_request_milestone must not be called when issue has no milestone"
+    );
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_issue_not_found_is_no_op() {
+    let warden = warden_with_issue_propagation(true, false);
+    // `metadata: None` simulates a 404 response.
+    let issue_provider = MockIssueProvider::new();
+
+    let pr = make_pr(Some("Closes #99"), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    assert!(
+        issue_provider.milestone_calls().is_empty(),
+        "No milestone call expected when issue is not found"
+    );
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_get_error_is_non_fatal() {
+    // `get_issue_metadata` fails → propagation logs a warning but does not panic.
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_get_error();
+
+    let pr = make_pr(Some("Closes #10"), None);
+    // Must complete without panicking and make no downstream calls.
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    assert!(issue_provider.milestone_calls().is_empty());
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_set_milestone_error_is_non_fatal() {
+    // `set_pull_request_milestone` fails → logs a warning, does not propagate error.
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new()
+        .with_metadata(IssueMetadata {
+            milestone: Some(IssueMilestone {
+                number: 5,
+                title: "v2.0.0".to_string(),
+            }),
+            projects: vec![],
+        })
+        .with_set_milestone_error();
+
+    let pr = make_pr(Some("Closes #10"), None);
+    // Must complete without panicking.
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+    // No assertion on call count — the provider returns an error, which is expected.
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_cross_repo_reference() {
+    // "Closes owner2/other-repo#55" — the issue lookup must use the cross-repo
+    // owner/repo, not the PR's own repo.
+    let warden = warden_with_issue_propagation(true, false);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 11,
+            title: "cross".to_string(),
+        }),
+        projects: vec![],
+    });
+
+    let pr = make_pr(Some("Closes owner2/other-repo#55"), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    let calls = issue_provider.milestone_calls();
+    assert_eq!(
+        calls.len(),
+        1,
+        "Expected exactly one milestone call for cross-repo reference"
+    );
+    assert_eq!(calls[0], (42, Some(11)));
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_adds_pr_to_projects() {
+    let warden = warden_with_issue_propagation(false, true);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: None,
+        projects: vec![
+            IssueProject {
+                number: 1,
+                owner_login: "myorg".to_string(),
+                title: "Team Alpha".to_string(),
+            },
+            IssueProject {
+                number: 2,
+                owner_login: "myorg".to_string(),
+                title: "Team Beta".to_string(),
+            },
+        ],
+    });
+
+    let pr = make_pr(Some("Closes #10"), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    let calls = issue_provider.project_calls();
+    assert_eq!(calls.len(), 2, "Expected one project call per project");
+    assert!(calls.iter().any(|(_, num)| *num == 1));
+    assert!(calls.iter().any(|(_, num)| *num == 2));
+    // Milestone should not have been called since the flag is off.
+    assert!(issue_provider.milestone_calls().is_empty());
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_no_projects_on_issue_skips_project_calls() {
+    let warden = warden_with_issue_propagation(false, true);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: None,
+        projects: vec![], // no projects
+    });
+
+    let pr = make_pr(Some("Closes #10"), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    assert!(
+        issue_provider.project_calls().is_empty(),
+        "No project calls expected when issue has no linked projects"
+    );
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_add_project_error_is_non_fatal() {
+    let warden = warden_with_issue_propagation(false, true);
+    let issue_provider = MockIssueProvider::new()
+        .with_metadata(IssueMetadata {
+            milestone: None,
+            projects: vec![IssueProject {
+                number: 1,
+                owner_login: "myorg".to_string(),
+                title: "Alpha".to_string(),
+            }],
+        })
+        .with_add_project_error();
+
+    let pr = make_pr(Some("Closes #10"), None);
+    // Must not panic.
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+}
+
+#[tokio::test]
+async fn test_propagate_issue_metadata_both_flags_enabled() {
+    // Both flags on → both milestone and project calls should be made.
+    let warden = warden_with_issue_propagation(true, true);
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 3,
+            title: "Q1".to_string(),
+        }),
+        projects: vec![IssueProject {
+            number: 7,
+            owner_login: "myorg".to_string(),
+            title: "Roadmap".to_string(),
+        }],
+    });
+
+    let pr = make_pr(Some("Closes #10"), None);
+    warden
+        .propagate_issue_metadata("owner", "repo", &pr, &issue_provider)
+        .await;
+
+    assert_eq!(issue_provider.milestone_calls().len(), 1);
+    assert_eq!(issue_provider.project_calls().len(), 1);
+}
+
+// ---------------------------------------------------------------------------
+// process_pull_request + with_issue_provider integration tests (task 6.8)
+// ---------------------------------------------------------------------------
+
+/// Verify that `process_pull_request` calls `propagate_issue_metadata` when an
+/// `IssueMetadataProvider` is wired in via `with_issue_provider` and the
+/// `sync_milestone_from_issue` flag is enabled.
+#[tokio::test]
+async fn test_process_pull_request_calls_milestone_propagation_via_issue_provider() {
+    // Set up a PR with a closing issue reference in its body.
+    let pr = PullRequest {
+        number: 77,
+        title: "feat: new widget".to_string(),
+        draft: false,
+        body: Some("Closes #10".to_string()),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    let pr_provider = MockGitProvider::new();
+    pr_provider.set_pull_request(pr);
+
+    // Issue provider: issue 10 has milestone 5.
+    let issue_provider = MockIssueProvider::new().with_metadata(IssueMetadata {
+        milestone: Some(IssueMilestone {
+            number: 5,
+            title: "v1.0".to_string(),
+        }),
+        projects: vec![],
+    });
+    let issue_provider_check = issue_provider.clone();
+
+    let config = CurrentPullRequestValidationConfiguration {
+        issue_propagation: IssuePropagationConfig {
+            sync_milestone_from_issue: true,
+            sync_project_from_issue: false,
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    let warden =
+        MergeWarden::with_config(pr_provider, config).with_issue_provider(Box::new(issue_provider));
+
+    warden
+        .process_pull_request("owner", "repo", 77)
+        .await
+        .unwrap();
+
+    let calls = issue_provider_check.milestone_calls();
+    assert_eq!(
+        calls.len(),
+        1,
+        "set_pull_request_milestone should be called once when issue provider is wired in"
+    );
+    assert_eq!(calls[0], (77, Some(5)));
+}
+
+/// Verify that `process_pull_request` does NOT call any issue-provider methods
+/// when no `IssueMetadataProvider` is attached (backward-compatible behaviour).
+#[tokio::test]
+async fn test_process_pull_request_skips_propagation_without_issue_provider() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: something".to_string(),
+        draft: false,
+        body: Some("Closes #10".to_string()),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    let pr_provider = MockGitProvider::new();
+    pr_provider.set_pull_request(pr);
+
+    // Config has the flag enabled, but no issue provider is set.
+    let config = CurrentPullRequestValidationConfiguration {
+        issue_propagation: IssuePropagationConfig {
+            sync_milestone_from_issue: true,
+            sync_project_from_issue: false,
+        },
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+
+    // No `.with_issue_provider(...)` call — propagation must be silently skipped.
+    let warden = MergeWarden::with_config(pr_provider, config);
+
+    // Must complete without panicking.
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+    // If we reach here without a panic, the test passes. There is no issue
+    // provider to assert on since none was supplied.
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Task 5.0 — Tests for targeted comment content produced by
+// communicate_pr_title_validity_status() for each TitleIssue variant.
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Build a minimal `PullRequest` with the given number and title.
+///
+/// The body always contains a valid work item reference so that work-item
+/// validation does not interfere with the assertions about title comments.
+fn make_pr_for_title_test(number: u64, title: &str) -> PullRequest {
+    PullRequest {
+        number,
+        title: title.to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+/// Build a `DynamicMockGitProvider` that already contains `pr`.
+fn provider_with_pr(pr: PullRequest) -> DynamicMockGitProvider {
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(pr);
+    provider
+}
+
+/// Return the single title-related comment body from the provider, or panic
+/// if none (or more than one) was found.
+fn get_title_comment(comments: Vec<Comment>) -> String {
+    let title_comments: Vec<_> = comments
+        .iter()
+        .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
+        .collect();
+    assert_eq!(
+        title_comments.len(),
+        1,
+        "expected exactly one title comment, got {}: {:?}",
+        title_comments.len(),
+        title_comments.iter().map(|c| &c.body).collect::<Vec<_>>()
+    );
+    title_comments[0].body.clone()
+}
+
+#[tokio::test]
+async fn test_comment_contains_leading_whitespace_message_and_suggested_fix() {
+    // 5.1 — Title " feat: add login" triggers LeadingWhitespace.
+    let pr = make_pr_for_title_test(1, " feat: add login");
+    let provider = provider_with_pr(pr);
+    let warden = MergeWarden::new(provider);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+    assert!(!result.title_valid, "title should be invalid");
+
+    let body = get_title_comment(warden.provider.get_comments());
+    assert!(
+        body.contains("starts with whitespace"),
+        "comment should describe leading-whitespace issue; got:\n{body}"
+    );
+    assert!(
+        body.contains("Suggested fix: `feat: add login`"),
+        "comment should include suggested fix; got:\n{body}"
+    );
+    assert!(
+        body.contains("Conventional Commits"),
+        "comment should include general format reminder; got:\n{body}"
+    );
+}
+
+#[tokio::test]
+async fn test_comment_contains_whitespace_before_colon_message_and_suggested_fix() {
+    // 5.2 — Title "feat : add login" triggers WhitespaceBeforeColon.
+    let pr = make_pr_for_title_test(2, "feat : add login");
+    let provider = provider_with_pr(pr);
+    let warden = MergeWarden::new(provider);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 2)
+        .await
+        .unwrap();
+    assert!(!result.title_valid, "title should be invalid");
+
+    let body = get_title_comment(warden.provider.get_comments());
+    assert!(
+        body.contains("whitespace before the `:` separator"),
+        "comment should describe whitespace-before-colon issue; got:\n{body}"
+    );
+    assert!(
+        body.contains("Suggested fix: `feat: add login`"),
+        "comment should include suggested fix; got:\n{body}"
+    );
+}
+
+#[tokio::test]
+async fn test_comment_contains_uppercase_type_message_and_suggested_fix_and_reminder() {
+    // 5.3 — Title "FEAT: add login" triggers UppercaseType.
+    let pr = make_pr_for_title_test(3, "FEAT: add login");
+    let provider = provider_with_pr(pr);
+    let warden = MergeWarden::new(provider);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 3)
+        .await
+        .unwrap();
+    assert!(!result.title_valid, "title should be invalid");
+
+    let body = get_title_comment(warden.provider.get_comments());
+    assert!(
+        body.contains("must be lowercase"),
+        "comment should describe uppercase-type issue; got:\n{body}"
+    );
+    assert!(
+        body.contains("Suggested fix: `feat: add login`"),
+        "comment should include suggested fix; got:\n{body}"
+    );
+    assert!(
+        body.contains("Conventional Commits"),
+        "comment should include general format reminder; got:\n{body}"
+    );
+}
+
+#[tokio::test]
+async fn test_comment_contains_unrecognized_type_message_with_nearest_valid_suggestion() {
+    // 5.4 — Title "feature: add login" triggers UnrecognizedType with nearest_valid "feat".
+    let pr = make_pr_for_title_test(4, "feature: add login");
+    let provider = provider_with_pr(pr);
+    let warden = MergeWarden::new(provider);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 4)
+        .await
+        .unwrap();
+    assert!(!result.title_valid, "title should be invalid");
+
+    let body = get_title_comment(warden.provider.get_comments());
+    assert!(
+        body.contains("not a recognized conventional commit type"),
+        "comment should describe unrecognised-type issue; got:\n{body}"
+    );
+    assert!(
+        body.contains("`feat`"),
+        "comment should mention the nearest valid type; got:\n{body}"
+    );
+    assert!(
+        body.contains("Suggested fix: `feat: add login`"),
+        "comment should include suggested fix; got:\n{body}"
+    );
+}
+
+#[tokio::test]
+async fn test_comment_contains_missing_colon_message_and_suggested_fix() {
+    // 5.5 — Title "feat add login" triggers MissingColon.
+    let pr = make_pr_for_title_test(5, "feat add login");
+    let provider = provider_with_pr(pr);
+    let warden = MergeWarden::new(provider);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 5)
+        .await
+        .unwrap();
+    assert!(!result.title_valid, "title should be invalid");
+
+    let body = get_title_comment(warden.provider.get_comments());
+    assert!(
+        body.contains("`:` separator is required"),
+        "comment should describe missing-colon issue; got:\n{body}"
+    );
+    assert!(
+        body.contains("Suggested fix: `feat: add login`"),
+        "comment should include suggested fix; got:\n{body}"
+    );
+}
+
+#[tokio::test]
+async fn test_comment_contains_missing_space_after_colon_message_and_suggested_fix() {
+    // 5.6 — Title "feat:add login" triggers MissingSpaceAfterColon.
+    let pr = make_pr_for_title_test(6, "feat:add login");
+    let provider = provider_with_pr(pr);
+    let warden = MergeWarden::new(provider);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 6)
+        .await
+        .unwrap();
+    assert!(!result.title_valid, "title should be invalid");
+
+    let body = get_title_comment(warden.provider.get_comments());
+    assert!(
+        body.contains("space is required after the `:` separator"),
+        "comment should describe missing-space-after-colon issue; got:\n{body}"
+    );
+    assert!(
+        body.contains("Suggested fix: `feat: add login`"),
+        "comment should include suggested fix; got:\n{body}"
+    );
+}
+
+#[tokio::test]
+async fn test_comment_contains_no_type_prefix_message_and_no_suggested_fix_line() {
+    // 5.7 — Title "Add login functionality" triggers NoTypePrefix; no suggested fix.
+    let pr = make_pr_for_title_test(7, "Add login functionality");
+    let provider = provider_with_pr(pr);
+    let warden = MergeWarden::new(provider);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 7)
+        .await
+        .unwrap();
+    assert!(!result.title_valid, "title should be invalid");
+
+    let body = get_title_comment(warden.provider.get_comments());
+    assert!(
+        body.contains("No conventional commit type prefix"),
+        "comment should describe no-type-prefix issue; got:\n{body}"
+    );
+    assert!(
+        body.contains("Conventional Commits"),
+        "comment should include general format reminder; got:\n{body}"
+    );
+    assert!(
+        !body.contains("Suggested fix:"),
+        "comment must not include a suggested fix when none exists; got:\n{body}"
+    );
+}
+
+#[tokio::test]
+async fn test_no_title_comment_posted_when_enforce_title_convention_is_false() {
+    // 5.8 — When enforce_title_convention is false a comment must never be posted,
+    //        even for a title that would otherwise be invalid.
+    let pr = make_pr_for_title_test(8, "completely wrong title");
+    let provider = provider_with_pr(pr);
+
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: false,
+        ..CurrentPullRequestValidationConfiguration::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 8)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments.iter().any(|c| c.body.contains(TITLE_COMMENT_MARKER)),
+        "no title comment should be posted when enforce_title_convention is false; got:\n{comments:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_stale_title_comment_is_replaced_when_diagnosis_changes() {
+    // When a PR title changes from one invalid form to another the pre-existing
+    // title-validation comment (with a different diagnosis body) must be replaced,
+    // not left stale, so the author sees up-to-date feedback.
+
+    let provider = MockGitProvider::new();
+
+    // Pre-seed a comment that represents a previous (now stale) diagnosis for an
+    // UppercaseType error ("FEAT").
+    let stale_comment = format!(
+        "{}\nThe pull request title needs correction:\n- The type `FEAT` must be lowercase",
+        TITLE_COMMENT_MARKER
+    );
+    provider
+        .add_comment("owner", "repo", 1, &stale_comment)
+        .await
+        .unwrap();
+
+    // The author has since pushed a new title that has a DIFFERENT issue:
+    // "feature" is an UnrecognizedType (synonym for "feat"), not an UppercaseType.
+    let pr = PullRequest {
+        number: 1,
+        title: "feature: add login".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 456,
+            login: "developer123".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    provider.set_pull_request(pr);
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    let title_comments: Vec<_> = comments
+        .iter()
+        .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
+        .collect();
+
+    assert_eq!(
+        title_comments.len(),
+        1,
+        "exactly one title comment should remain after diagnosis update; got: {:?}",
+        title_comments.iter().map(|c| &c.body).collect::<Vec<_>>()
+    );
+    assert!(
+        !title_comments[0].body.contains("must be lowercase"),
+        "stale UppercaseType message should no longer appear; got:\n{}",
+        title_comments[0].body
+    );
+    assert!(
+        title_comments[0]
+            .body
+            .contains("not a recognized conventional commit type"),
+        "updated comment should describe the UnrecognizedType issue; got:\n{}",
+        title_comments[0].body
+    );
+}
+
+#[tokio::test]
+async fn test_identical_title_comment_is_not_reposted() {
+    // When the title is unchanged between two push events the existing comment
+    // body is identical and should not be deleted-and-reposted (idempotent path).
+
+    let provider = MockGitProvider::new();
+
+    // Simulate a PR with an invalid title ("feature: ...") that has already
+    // been processed and has a comment on it.
+    let pr = PullRequest {
+        number: 1,
+        title: "feature: add login".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 456,
+            login: "developer123".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    provider.set_pull_request(pr);
+
+    let warden = MergeWarden::new(provider);
+
+    // First processing run — comment is created.
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comment_count_after_first_run = warden
+        .provider
+        .get_comments()
+        .iter()
+        .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
+        .count();
+    assert_eq!(
+        comment_count_after_first_run, 1,
+        "first run should produce exactly one title comment"
+    );
+
+    // Second processing run with the same title — comment body is identical.
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comment_count_after_second_run = warden
+        .provider
+        .get_comments()
+        .iter()
+        .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
+        .count();
+    assert_eq!(
+        comment_count_after_second_run, 1,
+        "second run with identical title should not create a duplicate comment"
+    );
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Draft PR processing tests
+//
+// Draft PRs must run full validation so authors see issues early, but the
+// check conclusion must be "neutral" (non-blocking) rather than "failure" to
+// avoid hard-blocking the PR while it is actively being worked on.
+// ──────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_process_pull_request_draft_with_invalid_title_yields_neutral_check_conclusion() {
+    // A draft PR with an invalid title must still be validated, but the
+    // resulting check status must use "neutral" (not "failure") so the PR
+    // is never hard-blocked while in draft mode.
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 1,
+        title: "invalid title format".to_string(), // does not match conventional commit
+        draft: true,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let warden = MergeWarden::new(provider);
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(
+        !result.title_valid,
+        "Validation must still run for draft PRs; title should be invalid"
+    );
+
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1, "Expected one check status update");
+    assert_eq!(
+        updates[0].conclusion, "neutral",
+        "Draft PRs with failures must use 'neutral' conclusion, got: {}",
+        updates[0].conclusion
+    );
+    assert!(
+        updates[0].summary.contains("Draft mode"),
+        "Check summary should mention draft mode, got: {}",
+        updates[0].summary
+    );
+}
+
+#[tokio::test]
+async fn test_process_pull_request_draft_with_valid_pr_yields_success_check_conclusion() {
+    // A draft PR that passes all validations must still produce "success",
+    // not "neutral" — "neutral" is only for draft + failures.
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(PullRequest {
+        number: 2,
+        title: "feat: add login".to_string(),
+        draft: true,
+        body: Some("Fixes #456".to_string()),
+        author: Some(User {
+            id: 2,
+            login: "dev2".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    });
+
+    let warden = MergeWarden::new(provider);
+    let result = warden
+        .process_pull_request("owner", "repo", 2)
+        .await
+        .unwrap();
+
+    assert!(result.title_valid, "Title should be valid");
+    assert!(
+        result.work_item_referenced,
+        "Work item should be referenced"
+    );
+
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1, "Expected one check status update");
+    assert_eq!(
+        updates[0].conclusion, "success",
+        "Draft PR that passes all validations should yield 'success', got: {}",
+        updates[0].conclusion
+    );
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
 // Oversized PR comment deduplication tests
 //
 // When a PR is oversized and already has a size comment with the same body,
@@ -3819,1118 +5968,5075 @@ async fn test_process_pull_request_draft_with_valid_pr_yields_success_check_conc
 // below the oversized threshold the existing size comment must be removed.
 // ──────────────────────────────────────────────────────────────────────────────
 
-/// Mock provider for size-comment tests: supports configurable PR files and
-/// tracks comments and check status updates.
-#[derive(Debug)]
-struct SizeMockGitProvider {
-    pull_request: PullRequest,
-    pr_files: Arc<Mutex<Vec<PullRequestFile>>>,
-    labels: Arc<Mutex<Vec<Label>>>,
-    comments: Arc<Mutex<Vec<Comment>>>,
-    check_status_updates: Arc<Mutex<Vec<CheckStatusUpdate>>>,
+/// Mock provider for size-comment tests: supports configurable PR files and
+/// tracks comments and check status updates.
+#[derive(Debug)]
+struct SizeMockGitProvider {
+    pull_request: PullRequest,
+    pr_files: Arc<Mutex<Vec<PullRequestFile>>>,
+    /// Files returned by `get_comparison_files`; `None` makes the comparison fail.
+    comparison_files: Option<Vec<PullRequestFile>>,
+    /// `(base, head)` of every comparison requested.
+    comparisons: Arc<Mutex<Vec<(String, String)>>>,
+    /// Patches returned by `get_pull_request_file_patches`; `None` makes it unsupported.
+    file_patches: Option<Vec<PullRequestFilePatch>>,
+    /// Number of times the file patches were requested.
+    file_patch_requests: Arc<Mutex<u32>>,
+    labels: Arc<Mutex<Vec<Label>>>,
+    comments: Arc<Mutex<Vec<Comment>>>,
+    check_status_updates: Arc<Mutex<Vec<CheckStatusUpdate>>>,
+    /// Commits returned by `list_commit_verifications`; `None` makes it unsupported.
+    commit_verifications: Option<Vec<CommitVerification>>,
+    /// Commits returned by `list_pull_request_commits`; `None` makes it unsupported.
+    pr_commits: Option<Vec<PullRequestCommit>>,
+    /// Issues returned by `get_issue`; `None` makes it unsupported.
+    issues: Option<Vec<merge_warden_developer_platforms::models::Issue>>,
+    /// Count returned by `get_commits_behind`; `None` makes it unsupported.
+    commits_behind: Option<u64>,
+    /// Whether `add_labels` fails.
+    fail_add_labels: bool,
+    /// Whether `list_applied_labels` fails.
+    fail_list_applied_labels: bool,
+}
+
+impl SizeMockGitProvider {
+    fn new(pull_request: PullRequest, files: Vec<PullRequestFile>) -> Self {
+        Self {
+            pull_request,
+            pr_files: Arc::new(Mutex::new(files)),
+            comparison_files: None,
+            comparisons: Arc::new(Mutex::new(Vec::new())),
+            file_patches: None,
+            file_patch_requests: Arc::new(Mutex::new(0)),
+            labels: Arc::new(Mutex::new(Vec::new())),
+            comments: Arc::new(Mutex::new(Vec::new())),
+            check_status_updates: Arc::new(Mutex::new(Vec::new())),
+            commit_verifications: None,
+            pr_commits: None,
+            issues: None,
+            commits_behind: None,
+            fail_add_labels: false,
+            fail_list_applied_labels: false,
+        }
+    }
+
+    fn with_add_labels_failure(mut self) -> Self {
+        self.fail_add_labels = true;
+        self
+    }
+
+    fn with_list_applied_labels_failure(mut self) -> Self {
+        self.fail_list_applied_labels = true;
+        self
+    }
+
+    fn with_commits_behind(mut self, commits_behind: u64) -> Self {
+        self.commits_behind = Some(commits_behind);
+        self
+    }
+
+    fn with_applied_labels(self, names: &[&str]) -> Self {
+        self.labels
+            .lock()
+            .unwrap()
+            .extend(names.iter().map(|name| Label {
+                name: name.to_string(),
+                description: None,
+                color: None,
+            }));
+        self
+    }
+
+    fn with_files(self, files: Vec<PullRequestFile>) -> Self {
+        *self.pr_files.lock().unwrap() = files;
+        self
+    }
+
+    fn get_applied_label_names(&self) -> Vec<String> {
+        self.labels
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|l| l.name.clone())
+            .collect()
+    }
+
+    fn with_issues(mut self, issues: Vec<merge_warden_developer_platforms::models::Issue>) -> Self {
+        self.issues = Some(issues);
+        self
+    }
+
+    fn with_file_patches(mut self, patches: Vec<PullRequestFilePatch>) -> Self {
+        self.file_patches = Some(patches);
+        self
+    }
+
+    fn with_commit_verifications(mut self, commits: Vec<CommitVerification>) -> Self {
+        self.commit_verifications = Some(commits);
+        self
+    }
+
+    fn with_commit_messages(mut self, messages: &[&str]) -> Self {
+        self.pr_commits = Some(
+            messages
+                .iter()
+                .enumerate()
+                .map(|(i, message)| PullRequestCommit {
+                    sha: format!("sha{}", i),
+                    message: message.to_string(),
+                })
+                .collect(),
+        );
+        self
+    }
+
+    fn with_comparison_files(mut self, files: Vec<PullRequestFile>) -> Self {
+        self.comparison_files = Some(files);
+        self
+    }
+
+    fn set_pr_files(&self, files: Vec<PullRequestFile>) {
+        *self.pr_files.lock().unwrap() = files;
+    }
+
+    fn get_comments(&self) -> Vec<Comment> {
+        self.comments.lock().unwrap().clone()
+    }
+
+    #[allow(dead_code)]
+    fn get_check_status_updates(&self) -> Vec<CheckStatusUpdate> {
+        self.check_status_updates.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl merge_warden_developer_platforms::PullRequestProvider for SizeMockGitProvider {
+    async fn get_pull_request(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<PullRequest, merge_warden_developer_platforms::errors::Error> {
+        Ok(self.pull_request.clone())
+    }
+
+    async fn add_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        comment: &str,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        let mut comments = self.comments.lock().unwrap();
+        let id = comments.len() as u64 + 1;
+        comments.push(Comment {
+            id,
+            body: comment.to_string(),
+            user: User {
+                id: 10,
+                login: "bot".to_string(),
+            },
+        });
+        Ok(())
+    }
+
+    async fn delete_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        comment_id: u64,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        self.comments.lock().unwrap().retain(|c| c.id != comment_id);
+        Ok(())
+    }
+
+    async fn list_comments(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<Vec<Comment>, merge_warden_developer_platforms::errors::Error> {
+        Ok(self.comments.lock().unwrap().clone())
+    }
+
+    async fn add_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        labels: &[String],
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        if self.fail_add_labels {
+            return Err(
+                merge_warden_developer_platforms::errors::Error::FailedToUpdatePullRequest(
+                    "Failed to add labels".to_string(),
+                ),
+            );
+        }
+        let mut current = self.labels.lock().unwrap();
+        for l in labels {
+            current.push(Label {
+                name: l.clone(),
+                description: None,
+                color: None,
+            });
+        }
+        Ok(())
+    }
+
+    async fn remove_label(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        label: &str,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        self.labels.lock().unwrap().retain(|l| l.name != label);
+        Ok(())
+    }
+
+    async fn list_applied_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<Vec<Label>, merge_warden_developer_platforms::errors::Error> {
+        if self.fail_list_applied_labels {
+            return Err(merge_warden_developer_platforms::errors::Error::ApiError());
+        }
+        Ok(self.labels.lock().unwrap().clone())
+    }
+
+    async fn list_available_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Vec<Label>, merge_warden_developer_platforms::errors::Error> {
+        Ok(Vec::new())
+    }
+
+    async fn update_pr_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+        actions: &[CheckRunAction],
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        let mut updates = self.check_status_updates.lock().unwrap();
+        updates.push(CheckStatusUpdate {
+            repo_owner: repo_owner.to_string(),
+            repo_name: repo_name.to_string(),
+            pr_number,
+            head_sha: None,
+            check_name: DEFAULT_CHECK_RUN_NAME.to_string(),
+            conclusion: conclusion.to_string(),
+            title: output_title.to_string(),
+            summary: output_summary.to_string(),
+            text: output_text.to_string(),
+            actions: actions.to_vec(),
+        });
+        Ok(())
+    }
+
+    async fn get_pull_request_files(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<Vec<PullRequestFile>, merge_warden_developer_platforms::errors::Error> {
+        Ok(self.pr_files.lock().unwrap().clone())
+    }
+
+    async fn get_comparison_files(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<PullRequestFile>, merge_warden_developer_platforms::errors::Error> {
+        self.comparisons
+            .lock()
+            .unwrap()
+            .push((base.to_string(), head.to_string()));
+        self.comparison_files.clone().ok_or_else(|| {
+            merge_warden_developer_platforms::errors::Error::Unsupported(
+                "get_comparison_files".to_string(),
+            )
+        })
+    }
+
+    async fn get_pull_request_file_patches(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<Vec<PullRequestFilePatch>, merge_warden_developer_platforms::errors::Error> {
+        *self.file_patch_requests.lock().unwrap() += 1;
+        self.file_patches.clone().ok_or_else(|| {
+            merge_warden_developer_platforms::errors::Error::Unsupported(
+                "get_pull_request_file_patches".to_string(),
+            )
+        })
+    }
+
+    async fn list_pr_reviews(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<
+        Vec<merge_warden_developer_platforms::models::Review>,
+        merge_warden_developer_platforms::errors::Error,
+    > {
+        Ok(vec![])
+    }
+
+    async fn get_commit_statuses(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _commit_sha: &str,
+    ) -> Result<
+        Vec<merge_warden_developer_platforms::models::CommitStatus>,
+        merge_warden_developer_platforms::errors::Error,
+    > {
+        Ok(vec![])
+    }
+
+    async fn find_pull_requests_for_commit(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _commit_sha: &str,
+    ) -> Result<Vec<u64>, merge_warden_developer_platforms::errors::Error> {
+        Ok(vec![])
+    }
+
+    async fn list_commit_verifications(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<CommitVerification>, merge_warden_developer_platforms::errors::Error> {
+        self.commit_verifications.clone().ok_or(
+            merge_warden_developer_platforms::errors::Error::Unsupported(
+                "list_commit_verifications".to_string(),
+            ),
+        )
+    }
+
+    async fn list_pull_request_commits(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<PullRequestCommit>, merge_warden_developer_platforms::errors::Error> {
+        self.pr_commits.clone().ok_or(
+            merge_warden_developer_platforms::errors::Error::Unsupported(
+                "list_pull_request_commits".to_string(),
+            ),
+        )
+    }
+
+    async fn get_issue(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        issue_number: u64,
+    ) -> Result<
+        Option<merge_warden_developer_platforms::models::Issue>,
+        merge_warden_developer_platforms::errors::Error,
+    > {
+        let issues = self.issues.as_ref().ok_or(
+            merge_warden_developer_platforms::errors::Error::Unsupported("get_issue".to_string()),
+        )?;
+        Ok(issues.iter().find(|i| i.number == issue_number).cloned())
+    }
+
+    async fn get_commits_behind(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _base: &str,
+        _head: &str,
+    ) -> Result<u64, merge_warden_developer_platforms::errors::Error> {
+        self.commits_behind.ok_or(
+            merge_warden_developer_platforms::errors::Error::Unsupported(
+                "get_commits_behind".to_string(),
+            ),
+        )
+    }
+}
+
+#[async_trait]
+impl merge_warden_developer_platforms::ConfigFetcher for SizeMockGitProvider {
+    async fn fetch_config(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _path: &str,
+    ) -> Result<Option<String>, merge_warden_developer_platforms::errors::Error> {
+        Ok(None)
+    }
+
+    async fn fetch_config_at_ref(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _path: &str,
+        _git_ref: &str,
+    ) -> Result<Option<String>, merge_warden_developer_platforms::errors::Error> {
+        Ok(None)
+    }
+}
+
+/// Build a `PullRequestFile` that contributes the given number of changed lines.
+fn make_pr_file(filename: &str, changes: u32) -> PullRequestFile {
+    PullRequestFile {
+        filename: filename.to_string(),
+        additions: changes,
+        deletions: 0,
+        changes,
+        status: "modified".to_string(),
+    }
+}
+
+/// Returns the pull request the tests of a single check start from: PR #1 titled
+/// `fix: correct the totals` that references work item #1, without an author or
+/// branches. Tests override the fields their check reads with struct update syntax.
+fn check_test_pr() -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: "fix: correct the totals".to_string(),
+        draft: false,
+        body: Some("Fixes #1".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+/// Builds a provider serving `pr`, which modifies `src/lib.rs`. Use
+/// [`SizeMockGitProvider::with_files`] for other changes.
+fn check_test_provider(pr: PullRequest) -> SizeMockGitProvider {
+    SizeMockGitProvider::new(pr, vec![make_pr_file("src/lib.rs", 10)])
+}
+
+/// Returns the default configuration with the settings of the check under test
+/// applied by `configure`.
+fn check_test_config(
+    configure: impl FnOnce(&mut CurrentPullRequestValidationConfiguration),
+) -> CurrentPullRequestValidationConfiguration {
+    let mut config = CurrentPullRequestValidationConfiguration::default();
+    configure(&mut config);
+    config
+}
+
+#[tokio::test]
+async fn test_process_pull_request_skips_pr_that_only_changes_ignored_paths() {
+    let pr = PullRequest {
+        number: 1,
+        title: "invalid title".to_string(),
+        draft: false,
+        body: None,
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(
+        pr,
+        vec![
+            make_pr_file(".github/workflows/ci.yml", 10),
+            make_pr_file("docs/guide.md", 20),
+        ],
+    );
+    let config = CurrentPullRequestValidationConfiguration {
+        path_skip: crate::config::PathSkipPolicyConfig {
+            skip_if_only_paths: vec![
+                ".github/".to_string(),
+                "docs/".to_string(),
+                "*.txt".to_string(),
+            ],
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.title_valid);
+    assert!(result.work_item_referenced);
+    assert_eq!(
+        result.skip_reasons.len(),
+        crate::config::CheckKind::DEFAULT_ORDER.len()
+    );
+    assert!(result
+        .skip_reasons
+        .iter()
+        .all(|(_, reason)| reason.contains("only changes ignored paths")));
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].conclusion, "neutral");
+    assert!(updates[0].text.contains("`.github/`, `docs/`"));
+    assert!(!updates[0].text.contains("`*.txt`"));
+    assert!(warden.provider.get_comments().is_empty());
+
+    // A single file outside the ignored paths makes the PR subject to the checks.
+    warden.provider.set_pr_files(vec![
+        make_pr_file("docs/guide.md", 20),
+        make_pr_file("src/main.rs", 5),
+    ]);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.title_valid);
+    assert!(result
+        .skip_reasons
+        .iter()
+        .all(|(_, reason)| !reason.contains("ignored paths")));
+}
+
+/// Build an oversized-PR config (size check enabled, comment enabled, not hard-failing).
+fn size_check_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        pr_size_check: crate::config::PrSizeCheckConfig {
+            enabled: true,
+            add_comment: true,
+            fail_on_oversized: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_identical_size_comment_is_not_reposted_when_pr_remains_oversized() {
+    // When a PR is oversized and processed twice without any change, the second
+    // run must not add a duplicate size comment (idempotent behaviour matching
+    // the title/work-item comment deduplication).
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    // 600 changes → XXL (oversized with default thresholds where XL threshold = 500).
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 600)]);
+
+    let warden = MergeWarden::with_config(provider, size_check_config());
+
+    // First run — size comment should be created.
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let count_after_first = warden
+        .provider
+        .get_comments()
+        .iter()
+        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
+        .count();
+    assert_eq!(
+        count_after_first, 1,
+        "first run should produce exactly one size comment"
+    );
+
+    // Second run with the same oversized PR — comment body is identical, must not duplicate.
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let count_after_second = warden
+        .provider
+        .get_comments()
+        .iter()
+        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
+        .count();
+    assert_eq!(
+        count_after_second, 1,
+        "second run with identical oversized PR must not create a duplicate size comment"
+    );
+}
+
+#[tokio::test]
+async fn test_size_comment_is_removed_when_pr_becomes_non_oversized() {
+    // When a previously oversized PR is later reduced below the XXL threshold,
+    // the existing size comment must be cleaned up automatically.
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add feature".to_string(),
+        draft: false,
+        body: Some("Fixes #999".to_string()),
+        author: Some(User {
+            id: 1,
+            login: "dev".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    // Start oversized: 600 changes.
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 600)]);
+
+    let warden = MergeWarden::with_config(provider, size_check_config());
+
+    // First run — oversized, comment added.
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let count_after_first = warden
+        .provider
+        .get_comments()
+        .iter()
+        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
+        .count();
+    assert_eq!(
+        count_after_first, 1,
+        "first run should produce a size comment"
+    );
+
+    // Author reduces the PR: 5 changes → XS (well below oversized threshold).
+    warden
+        .provider
+        .set_pr_files(vec![make_pr_file("src/main.rs", 5)]);
+
+    // Second run — PR is no longer oversized; comment must be removed.
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let count_after_second = warden
+        .provider
+        .get_comments()
+        .iter()
+        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
+        .count();
+    assert_eq!(
+        count_after_second, 0,
+        "size comment must be removed when PR is no longer oversized"
+    );
+}
+
+#[tokio::test]
+async fn test_size_comment_follows_configured_categories() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add feature".to_string(),
+        draft: false,
+        body: Some("Fixes #999".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+
+    // 300 changes → XL, which the default (XXL only) would not comment on.
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 300)]);
+    let mut config = size_check_config();
+    config.pr_size_check.comment_on_categories = vec![
+        crate::size::PrSizeCategory::XL,
+        crate::size::PrSizeCategory::XXL,
+    ];
+
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let size_comments: Vec<_> = warden
+        .provider
+        .get_comments()
+        .into_iter()
+        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
+        .collect();
+    assert_eq!(
+        size_comments.len(),
+        1,
+        "XL PR should receive a size comment"
+    );
+    assert!(size_comments[0].body.contains("labeled as `XL`"));
+
+    // 150 changes → L, which is not in the configured categories.
+    warden
+        .provider
+        .set_pr_files(vec![make_pr_file("src/main.rs", 150)]);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let count = warden
+        .provider
+        .get_comments()
+        .iter()
+        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
+        .count();
+    assert_eq!(count, 0, "L PR should not keep a size comment");
+}
+
+#[tokio::test]
+async fn test_default_size_comment_categories_skip_xl() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add feature".to_string(),
+        draft: false,
+        body: Some("Fixes #999".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 300)]);
+
+    let warden = MergeWarden::with_config(provider, size_check_config());
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!warden
+        .provider
+        .get_comments()
+        .iter()
+        .any(|c| c.body.contains(SIZE_COMMENT_MARKER)));
+}
+
+#[tokio::test]
+async fn test_pr_with_no_changed_files_reports_no_reviewable_changes() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add feature".to_string(),
+        draft: false,
+        body: Some("Fixes #999".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![]);
+    let warden = MergeWarden::with_config(provider, size_check_config());
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.size_valid);
+    assert!(warden
+        .provider
+        .get_comments()
+        .iter()
+        .all(|c| !c.body.contains(SIZE_COMMENT_MARKER)));
+    let updates = warden.provider.get_check_status_updates();
+    let update = updates.last().unwrap();
+    assert_eq!(update.conclusion, "success");
+    assert!(update
+        .text
+        .contains("PR size: no reviewable changes (the PR has no changed files)"));
+    assert!(update
+        .text
+        .contains("| ✅ | PR size | No reviewable changes |"));
+}
+
+#[tokio::test]
+async fn test_pr_with_only_excluded_files_reports_no_reviewable_changes() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add feature".to_string(),
+        draft: false,
+        body: Some("Fixes #999".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    // Excluded files are ignored for size, however large they are.
+    let provider = SizeMockGitProvider::new(
+        pr,
+        vec![
+            make_pr_file("Cargo.lock", 5000),
+            make_pr_file("web/package-lock.json", 800),
+        ],
+    );
+    let mut config = size_check_config();
+    config.pr_size_check.fail_on_oversized = true;
+    config.pr_size_check.excluded_file_patterns =
+        vec!["Cargo.lock".to_string(), "package-lock.json".to_string()];
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.size_valid);
+    assert!(warden
+        .provider
+        .get_comments()
+        .iter()
+        .all(|c| !c.body.contains(SIZE_COMMENT_MARKER)));
+    let updates = warden.provider.get_check_status_updates();
+    let update = updates.last().unwrap();
+    assert_eq!(update.conclusion, "success");
+    assert!(update.text.contains(
+        "PR size: no reviewable changes (all 2 changed file(s) are excluded from size checks)"
+    ));
+}
+
+#[tokio::test]
+async fn test_size_details_are_added_to_check_output_when_enabled() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(
+        pr,
+        vec![
+            make_pr_file("src/lib.rs", 20),
+            make_pr_file("src/main.rs", 40),
+            make_pr_file("Cargo.lock", 300),
+        ],
+    );
+    let mut config = size_check_config();
+    config.pr_size_check.add_comment = false;
+    config.pr_size_check.details_in_check_output = true;
+    config.pr_size_check.excluded_file_patterns = vec!["*.lock".to_string()];
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(warden.provider.get_comments().is_empty());
+    let text = &result.check_text;
+    assert!(text.contains("📏 **PR size details**: M — 60 line(s) across 2 file(s)"));
+    let main = text.find("| `src/main.rs` | 40 |").unwrap();
+    let lib = text.find("| `src/lib.rs` | 20 |").unwrap();
+    assert!(main < lib, "files should be listed largest first");
+    assert!(text
+        .contains("**Excluded from the size** (1 file(s)):\n\n- `Cargo.lock` (matched `*.lock`)"));
+    assert!(text.contains(
+        "📏 **PR size details**: M — 60 line(s) across 2 file(s)\n\n<details>\n<summary>Per-file breakdown</summary>"
+    ));
+    assert!(text.contains("- `Cargo.lock` (matched `*.lock`)\n\n</details>"));
+}
+
+#[tokio::test]
+async fn test_review_effort_is_added_to_check_output_and_labelled() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(
+        pr,
+        vec![
+            make_pr_file("src/lib.rs", 40),
+            make_pr_file("tests/lib_tests.rs", 20),
+        ],
+    )
+    .with_applied_labels(&["effort:5", "bug"]);
+    let mut config = size_check_config();
+    config.pr_size_check.add_comment = false;
+    config.pr_size_check.review_effort.enabled = true;
+    config.pr_size_check.review_effort.add_label = true;
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.check_text.contains(
+        "🧭 **Review effort**: 2/5 — 60 lines changed (3/5), 2 files touched (2/5), \
+         1 language (Rust) (1/5), includes test changes (1/5)"
+    ));
+    let labels = warden.provider.get_applied_label_names();
+    assert!(labels.contains(&"effort:2".to_string()));
+    assert!(labels.contains(&"bug".to_string()));
+    assert!(!labels.contains(&"effort:5".to_string()));
+}
+
+#[tokio::test]
+async fn test_review_effort_is_not_labelled_unless_enabled() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/lib.rs", 40)]);
+    let mut config = size_check_config();
+    config.pr_size_check.review_effort.enabled = true;
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.check_text.contains("🧭 **Review effort**: 2/5"));
+    assert!(!warden
+        .provider
+        .get_applied_label_names()
+        .iter()
+        .any(|label| label.starts_with("effort:")));
+}
+
+#[tokio::test]
+async fn test_size_breakdown_is_added_to_size_comment_when_enabled() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(
+        pr,
+        vec![
+            make_pr_file("src/generated.rs", 700),
+            make_pr_file("src/main.rs", 400),
+        ],
+    );
+    let mut config = size_check_config();
+    config.pr_size_check.details_in_comment = true;
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    let size_comment = comments
+        .iter()
+        .find(|c| c.body.contains(SIZE_COMMENT_MARKER))
+        .expect("oversized PR should get a size comment");
+    assert!(size_comment
+        .body
+        .contains("<details>\n<summary>Per-file breakdown</summary>"));
+    let generated = size_comment
+        .body
+        .find("| `src/generated.rs` | 700 |")
+        .unwrap();
+    let main = size_comment.body.find("| `src/main.rs` | 400 |").unwrap();
+    assert!(generated < main, "files should be listed largest first");
+    assert!(size_comment.body.ends_with("</details>"));
+    // The check output only shows the breakdown when `details_in_check_output` is set.
+    assert!(!result.check_text.contains("Per-file breakdown"));
+}
+
+#[tokio::test]
+async fn test_size_breakdown_is_not_added_to_size_comment_by_default() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 1200)]);
+    let warden = MergeWarden::with_config(provider, size_check_config());
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    let size_comment = comments
+        .iter()
+        .find(|c| c.body.contains(SIZE_COMMENT_MARKER))
+        .expect("oversized PR should get a size comment");
+    assert!(!size_comment.body.contains("<details>"));
+}
+
+#[tokio::test]
+async fn test_size_details_are_not_added_to_check_output_by_default() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 40)]);
+    let warden = MergeWarden::with_config(provider, size_check_config());
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.check_text.contains("PR size details"));
+}
+
+#[tokio::test]
+async fn test_size_exclusion_by_author_skips_size_check_and_records_bypass() {
+    let pr = PullRequest {
+        number: 1,
+        title: "chore: sync vendored code".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 7,
+            login: "Vendor-Sync[bot]".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("vendor/lib.rs", 5000)]);
+    let mut config = size_check_config();
+    config.pr_size_check.fail_on_oversized = true;
+    config.pr_size_check.exclusions = vec![crate::config::SizeExclusion {
+        author: Some("vendor-sync*".to_string()),
+        label: None,
+    }];
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.size_valid);
+    assert_eq!(
+        warden
+            .provider
+            .get_check_status_updates()
+            .last()
+            .unwrap()
+            .conclusion,
+        "success"
+    );
+    assert_eq!(
+        result.bypasses_used,
+        vec![crate::validation_result::BypassInfo {
+            rule_type: crate::validation_result::BypassRuleType::PrSize,
+            user: "Vendor-Sync[bot]".to_string(),
+        }]
+    );
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|l| !l.name.starts_with("size/")));
+    assert!(warden.provider.get_comments().is_empty());
+    let text = &result.check_text;
+    assert!(text.contains("Excluded by author `vendor-sync*`"));
+    assert!(text.contains("Size validation bypassed by @Vendor-Sync[bot]"));
+    assert!(text.contains("PR size: not checked (excluded by author `vendor-sync*`)"));
+}
+
+#[tokio::test]
+async fn test_size_exclusion_by_label_skips_size_labeling() {
+    let pr = PullRequest {
+        number: 1,
+        title: "chore: sync vendored code".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("vendor/lib.rs", 5000)]);
+    provider.labels.lock().unwrap().push(Label {
+        name: "vendored-sync".to_string(),
+        description: None,
+        color: None,
+    });
+    let mut config = size_check_config();
+    config.pr_size_check.exclusions = vec![crate::config::SizeExclusion {
+        author: None,
+        label: Some("Vendored-Sync".to_string()),
+    }];
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(result.bypasses_used.len(), 1);
+    assert_eq!(
+        result.bypasses_used[0].rule_type,
+        crate::validation_result::BypassRuleType::PrSize
+    );
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|l| !l.name.starts_with("size/")));
+    assert!(warden.provider.get_comments().is_empty());
+}
+
+#[tokio::test]
+async fn test_size_exclusion_not_matching_still_sizes_pr() {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 7,
+            login: "developer".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 40)]);
+    let mut config = size_check_config();
+    config.pr_size_check.exclusions = vec![crate::config::SizeExclusion {
+        author: Some("vendor-sync*".to_string()),
+        label: Some("vendored-sync".to_string()),
+    }];
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.bypasses_used.is_empty());
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/S"));
+}
+
+#[tokio::test]
+async fn test_size_exclusion_by_author_ignores_missing_author_login() {
+    // The stand-in login only applies to bypass rules, not to author-based exclusions
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 40)]);
+    let mut config = size_check_config();
+    config.bypass_rules = config.bypass_rules.with_missing_author_login("ghost");
+    config.pr_size_check.exclusions = vec![crate::config::SizeExclusion {
+        author: Some("ghost".to_string()),
+        label: None,
+    }];
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.bypasses_used.is_empty());
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/S"));
+}
+
+fn stacked_pr() -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 7,
+            login: "developer".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: "abc123".to_string(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_size_compare_base_sizes_pr_against_configured_branch() {
+    // The PR's own diff is small, but the stack relative to main is oversized.
+    let provider = SizeMockGitProvider::new(stacked_pr(), vec![make_pr_file("src/main.rs", 40)])
+        .with_comparison_files(vec![
+            make_pr_file("src/main.rs", 40),
+            make_pr_file("src/lib.rs", 600),
+        ]);
+    let mut config = size_check_config();
+    config.pr_size_check.compare_base = Some("main".to_string());
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *warden.provider.comparisons.lock().unwrap(),
+        vec![("main".to_string(), "abc123".to_string())]
+    );
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/XXL"));
+}
+
+#[tokio::test]
+async fn test_size_compare_base_failure_falls_back_to_pr_diff() {
+    let provider = SizeMockGitProvider::new(stacked_pr(), vec![make_pr_file("src/main.rs", 40)]);
+    let mut config = size_check_config();
+    config.pr_size_check.compare_base = Some("main".to_string());
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(warden.provider.comparisons.lock().unwrap().len(), 1);
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/S"));
+}
+
+#[tokio::test]
+async fn test_size_without_compare_base_does_not_compare() {
+    let provider = SizeMockGitProvider::new(stacked_pr(), vec![make_pr_file("src/main.rs", 40)])
+        .with_comparison_files(vec![make_pr_file("src/lib.rs", 600)]);
+    let warden = MergeWarden::with_config(provider, size_check_config());
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(warden.provider.comparisons.lock().unwrap().is_empty());
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/S"));
+}
+
+#[tokio::test]
+async fn test_merge_base_diff_source_sizes_only_the_pr_changes() {
+    // The PR's file list includes changes made on main since the PR branched
+    let pr = PullRequest {
+        base_ref: "main".to_string(),
+        ..stacked_pr()
+    };
+    let provider = SizeMockGitProvider::new(
+        pr,
+        vec![
+            make_pr_file("src/main.rs", 40),
+            make_pr_file("src/lib.rs", 600),
+        ],
+    )
+    .with_comparison_files(vec![make_pr_file("src/main.rs", 40)]);
+    let mut config = size_check_config();
+    config.check_execution.diff_source = crate::config::DiffSource::MergeBase;
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *warden.provider.comparisons.lock().unwrap(),
+        vec![("main".to_string(), "abc123".to_string())]
+    );
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/S"));
+}
+
+#[tokio::test]
+async fn test_merge_base_diff_source_without_base_branch_reads_pr_files() {
+    let provider = SizeMockGitProvider::new(stacked_pr(), vec![make_pr_file("src/lib.rs", 600)])
+        .with_comparison_files(vec![make_pr_file("src/main.rs", 40)]);
+    let mut config = size_check_config();
+    config.check_execution.diff_source = crate::config::DiffSource::MergeBase;
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(warden.provider.comparisons.lock().unwrap().is_empty());
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/XXL"));
+}
+
+/// A reformatted file: 300 lines re-indented, plus one real change.
+fn reindented_file() -> (PullRequestFile, PullRequestFilePatch) {
+    let mut patch = String::from("@@ -1,301 +1,301 @@\n");
+    for i in 0..300 {
+        patch.push_str(&format!("-let x{i} = {i};\n"));
+    }
+    patch.push_str("-let total = 0;\n");
+    for i in 0..300 {
+        patch.push_str(&format!("+    let x{i} = {i};\n"));
+    }
+    patch.push_str("+    let total = 1;\n");
+
+    let file = PullRequestFile {
+        filename: "src/lib.rs".to_string(),
+        additions: 301,
+        deletions: 301,
+        changes: 602,
+        status: "modified".to_string(),
+    };
+    let patch = PullRequestFilePatch {
+        filename: "src/lib.rs".to_string(),
+        patch: Some(patch),
+    };
+    (file, patch)
+}
+
+#[tokio::test]
+async fn test_size_ignore_whitespace_discounts_reindented_lines() {
+    let (file, patch) = reindented_file();
+    let provider =
+        SizeMockGitProvider::new(stacked_pr(), vec![file]).with_file_patches(vec![patch]);
+    let mut config = size_check_config();
+    config.pr_size_check.ignore_whitespace = true;
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(*warden.provider.file_patch_requests.lock().unwrap(), 1);
+    // Only the changed `total` line is counted, once as added and once as deleted
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/XS"));
+}
+
+#[tokio::test]
+async fn test_size_without_ignore_whitespace_does_not_fetch_patches() {
+    let (file, patch) = reindented_file();
+    let provider =
+        SizeMockGitProvider::new(stacked_pr(), vec![file]).with_file_patches(vec![patch]);
+    let warden = MergeWarden::with_config(provider, size_check_config());
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(*warden.provider.file_patch_requests.lock().unwrap(), 0);
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/XXL"));
+}
+
+#[tokio::test]
+async fn test_size_ignore_whitespace_falls_back_to_full_counts_without_patches() {
+    let (file, _) = reindented_file();
+    let provider = SizeMockGitProvider::new(stacked_pr(), vec![file]);
+    let mut config = size_check_config();
+    config.pr_size_check.ignore_whitespace = true;
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(*warden.provider.file_patch_requests.lock().unwrap(), 1);
+    assert!(warden
+        .provider
+        .labels
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|l| l.name == "size/XXL"));
+}
+
+// ── Config change validation tests ────────────────────────────────────────────
+
+/// A minimal mock provider for config-change validation tests.
+///
+/// Tracks comments written/deleted so we can assert on them after processing.
+#[derive(Debug)]
+struct ConfigCheckMockProvider {
+    pr: PullRequest,
+    pr_files: Vec<merge_warden_developer_platforms::models::PullRequestFile>,
+    /// The value returned by `fetch_config_at_ref`.
+    config_at_ref: Option<Result<Option<String>, merge_warden_developer_platforms::errors::Error>>,
+    /// File contents returned by `fetch_config`, keyed by path.
+    base_files: HashMap<String, String>,
+    /// File contents returned by `fetch_config_at_ref`, keyed by path; takes
+    /// precedence over `config_at_ref`.
+    head_files: HashMap<String, String>,
+    comments: Arc<Mutex<Vec<Comment>>>,
+    check_updates: Arc<Mutex<Vec<String>>>,
+}
+
+impl ConfigCheckMockProvider {
+    fn new(pr: PullRequest) -> Self {
+        Self {
+            pr,
+            pr_files: vec![],
+            config_at_ref: None,
+            base_files: HashMap::new(),
+            head_files: HashMap::new(),
+            comments: Arc::new(Mutex::new(vec![])),
+            check_updates: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    fn with_changed_file(mut self, path: &str, base: Option<&str>, head: Option<&str>) -> Self {
+        use merge_warden_developer_platforms::models::PullRequestFile;
+        self.pr_files.push(PullRequestFile {
+            filename: path.to_string(),
+            additions: 1,
+            deletions: 1,
+            changes: 2,
+            status: "modified".to_string(),
+        });
+        if let Some(base) = base {
+            self.base_files.insert(path.to_string(), base.to_string());
+        }
+        if let Some(head) = head {
+            self.head_files.insert(path.to_string(), head.to_string());
+        }
+        self
+    }
+
+    fn with_base_file(mut self, path: &str, content: &str) -> Self {
+        self.base_files
+            .insert(path.to_string(), content.to_string());
+        self
+    }
+
+    fn with_config_file(mut self, content: Option<String>) -> Self {
+        use merge_warden_developer_platforms::models::PullRequestFile;
+        self.pr_files.push(PullRequestFile {
+            filename: ".github/merge-warden.toml".to_string(),
+            additions: 1,
+            deletions: 0,
+            changes: 1,
+            status: "added".to_string(),
+        });
+        self.config_at_ref = Some(Ok(content));
+        self
+    }
+
+    fn with_config_fetch_error(mut self) -> Self {
+        use merge_warden_developer_platforms::models::PullRequestFile;
+        self.pr_files.push(PullRequestFile {
+            filename: ".github/merge-warden.toml".to_string(),
+            additions: 1,
+            deletions: 0,
+            changes: 1,
+            status: "added".to_string(),
+        });
+        self.config_at_ref = Some(Err(
+            merge_warden_developer_platforms::errors::Error::ApiError(),
+        ));
+        self
+    }
+
+    fn with_existing_comment(self, body: &str) -> Self {
+        self.comments.lock().unwrap().push(Comment {
+            id: 99,
+            body: body.to_string(),
+            user: User {
+                id: 1,
+                login: "merge-warden".to_string(),
+            },
+        });
+        self
+    }
+
+    fn get_comments(&self) -> Vec<Comment> {
+        self.comments.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl merge_warden_developer_platforms::PullRequestProvider for ConfigCheckMockProvider {
+    async fn get_pull_request(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<PullRequest, merge_warden_developer_platforms::errors::Error> {
+        Ok(self.pr.clone())
+    }
+
+    async fn add_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        comment: &str,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        let mut comments = self.comments.lock().unwrap();
+        let id = comments.len() as u64 + 1;
+        comments.push(Comment {
+            id,
+            body: comment.to_string(),
+            user: User {
+                id: 1,
+                login: "merge-warden".to_string(),
+            },
+        });
+        Ok(())
+    }
+
+    async fn delete_comment(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        comment_id: u64,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        self.comments.lock().unwrap().retain(|c| c.id != comment_id);
+        Ok(())
+    }
+
+    async fn list_comments(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<Vec<Comment>, merge_warden_developer_platforms::errors::Error> {
+        Ok(self.comments.lock().unwrap().clone())
+    }
+
+    async fn add_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        _labels: &[String],
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        Ok(())
+    }
+
+    async fn remove_label(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        _label: &str,
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        Ok(())
+    }
+
+    async fn list_applied_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<Vec<Label>, merge_warden_developer_platforms::errors::Error> {
+        Ok(vec![])
+    }
+
+    async fn list_available_labels(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Vec<Label>, merge_warden_developer_platforms::errors::Error> {
+        Ok(vec![])
+    }
+
+    async fn update_pr_check_status(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+        conclusion: &str,
+        _title: &str,
+        _summary: &str,
+        _text: &str,
+        _actions: &[CheckRunAction],
+    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
+        self.check_updates
+            .lock()
+            .unwrap()
+            .push(conclusion.to_string());
+        Ok(())
+    }
+
+    async fn get_pull_request_files(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<
+        Vec<merge_warden_developer_platforms::models::PullRequestFile>,
+        merge_warden_developer_platforms::errors::Error,
+    > {
+        Ok(self.pr_files.clone())
+    }
+
+    async fn list_pr_reviews(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _number: u64,
+    ) -> Result<Vec<Review>, merge_warden_developer_platforms::errors::Error> {
+        Ok(vec![])
+    }
+
+    async fn get_commit_statuses(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _commit_sha: &str,
+    ) -> Result<
+        Vec<merge_warden_developer_platforms::models::CommitStatus>,
+        merge_warden_developer_platforms::errors::Error,
+    > {
+        Ok(vec![])
+    }
+
+    async fn find_pull_requests_for_commit(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _commit_sha: &str,
+    ) -> Result<Vec<u64>, merge_warden_developer_platforms::errors::Error> {
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl merge_warden_developer_platforms::ConfigFetcher for ConfigCheckMockProvider {
+    async fn fetch_config(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        path: &str,
+    ) -> Result<Option<String>, merge_warden_developer_platforms::errors::Error> {
+        Ok(self.base_files.get(path).cloned())
+    }
+
+    async fn fetch_config_at_ref(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        path: &str,
+        _git_ref: &str,
+    ) -> Result<Option<String>, merge_warden_developer_platforms::errors::Error> {
+        if let Some(content) = self.head_files.get(path) {
+            return Ok(Some(content.clone()));
+        }
+        match &self.config_at_ref {
+            Some(Ok(content)) => Ok(content.clone()),
+            Some(Err(_)) => Err(merge_warden_developer_platforms::errors::Error::ApiError()),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Helper: build a simple valid PR that will pass title and work-item checks.
+fn make_valid_pr_with_sha(sha: &str) -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: "feat: add new feature".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 456,
+            login: "developer123".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: sha.to_string(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_config_check_no_comment_when_config_not_in_pr_files() {
+    // When .github/merge-warden.toml is NOT in the PR file list the warden
+    // must NOT post any CONFIG_COMMENT_MARKER comment.
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"));
+    // No config file added → config_at_ref is None → file list is empty.
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
+        "no config check comment expected when config file is not in PR files"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_no_comment_when_config_is_valid() {
+    // When the config file is changed but its content is valid, no comment must
+    // be posted and the check conclusion must still be "success".
+    let valid_toml = "schemaVersion = 1";
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_config_file(Some(valid_toml.to_string()));
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
+        "no config check comment expected for a valid config file"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_posts_error_comment_when_config_is_invalid() {
+    // When the config file is changed and its content is invalid, an error
+    // comment bearing CONFIG_COMMENT_MARKER must be posted on the PR.
+    let bad_toml = "not = valid = toml [[[";
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_config_file(Some(bad_toml.to_string()));
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    let config_comments: Vec<_> = comments
+        .iter()
+        .filter(|c| c.body.contains(CONFIG_COMMENT_MARKER))
+        .collect();
+    assert_eq!(
+        config_comments.len(),
+        1,
+        "exactly one config error comment expected; got: {:?}",
+        comments
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_does_not_affect_check_conclusion() {
+    // Even when the config file is invalid the check conclusion must NOT change
+    // (it must remain "success" for a PR that passes all other validations).
+    let bad_toml = "not = valid = toml [[[";
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_config_file(Some(bad_toml.to_string()));
+
+    let warden = MergeWarden::new(provider);
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(
+        result.title_valid,
+        "title_valid must remain true regardless of config validity"
+    );
+    assert!(
+        result.work_item_referenced,
+        "work_item_referenced must remain true regardless of config validity"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_deletes_stale_comment_when_config_becomes_valid() {
+    // When there is an existing CONFIG_COMMENT_MARKER comment from a previous
+    // run and the config file is now valid, the comment must be deleted.
+    let valid_toml = "schemaVersion = 1";
+    let existing_body = format!("{}\nSome old error", CONFIG_COMMENT_MARKER);
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_config_file(Some(valid_toml.to_string()))
+        .with_existing_comment(&existing_body);
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let remaining = warden.provider.get_comments();
+    assert!(
+        !remaining
+            .iter()
+            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
+        "stale config error comment must be deleted when config is now valid"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_no_comment_when_fetch_returns_none() {
+    // When fetch_config_at_ref returns Ok(None) (file absent at head SHA) and there is no
+    // pre-existing CONFIG_COMMENT_MARKER comment, the warden must not post a new comment.
+    let provider =
+        ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123")).with_config_file(None); // file in PR list but absent at ref
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
+        "no config check comment expected when file is absent at head SHA and no stale comment exists"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_deletes_stale_comment_when_config_file_deleted() {
+    // When fetch_config_at_ref returns Ok(None) (e.g., config file was deleted in this PR)
+    // any stale error comment from a previous push must be cleaned up.
+    let stale_comment = format!(
+        "{}\n⚠️ **Invalid merge-warden configuration**\nold error",
+        CONFIG_COMMENT_MARKER
+    );
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_config_file(None) // file in PR list but absent at ref
+        .with_existing_comment(&stale_comment);
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
+        "stale config check comment must be deleted when config file is absent at head SHA"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_skips_validation_when_head_sha_empty() {
+    // When the PR's head_sha is empty the warden must skip config validation entirely —
+    // no fetch call, no comment posted.  We use invalid config content so that if the
+    // guard is missing and the fetch happens, an error comment would be posted.
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha(""))
+        .with_config_file(Some("schemaVersion = 99".to_string())); // invalid if fetched
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
+        "no config check comment must be posted when head_sha is empty"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_no_comment_when_fetch_returns_error() {
+    // When fetch_config_at_ref returns an error the warden must log a warning
+    // and skip the check — no comment must be posted and the call must not
+    // propagate the error as a hard failure.
+    let provider =
+        ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123")).with_config_fetch_error();
+
+    let warden = MergeWarden::new(provider);
+    let result = warden.process_pull_request("owner", "repo", 1).await;
+
+    // The error must NOT propagate — process_pull_request must succeed.
+    assert!(
+        result.is_ok(),
+        "fetch_config_at_ref error must not propagate as a hard failure"
+    );
+    let comments = warden.provider.get_comments();
+    assert!(
+        !comments
+            .iter()
+            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
+        "no config check comment expected when fetch_config_at_ref fails"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_no_repost_when_error_comment_unchanged() {
+    // Pre-seed a CONFIG_COMMENT_MARKER comment whose body exactly matches what
+    // communicate_config_validity_status would post for schemaVersion = 99.
+    // The warden must detect the body is unchanged (already_up_to_date) and
+    // NOT delete + re-post the comment.
+    //
+    // We distinguish "skipped" from "deleted + re-posted" by checking the comment
+    // ID: the pre-seeded comment has id=99; if the shortcut were bypassed the
+    // comment would be deleted then re-added with id=1.
+    use indoc::formatdoc;
+
+    let bad_toml = "schemaVersion = 99";
+    let error_lines = "- schemaVersion must be 1, found 99";
+    let expected_body = formatdoc! {"
+        {marker}
+        ⚠️ **Invalid merge-warden configuration**
+
+        The `.github/merge-warden.toml` file introduced in this pull request contains \
+        errors and will not be used if this PR is merged.
+
+        **Errors found:**
+
+        {error_lines}
+
+        Please fix the configuration before merging.",
+        marker = CONFIG_COMMENT_MARKER,
+        error_lines = error_lines,
+    };
+
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_config_file(Some(bad_toml.to_string()))
+        .with_existing_comment(&expected_body);
+
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    let config_comments: Vec<_> = comments
+        .iter()
+        .filter(|c| c.body.contains(CONFIG_COMMENT_MARKER))
+        .collect();
+    assert_eq!(
+        config_comments.len(),
+        1,
+        "exactly one config check comment must remain after processing"
+    );
+    assert_eq!(
+        config_comments[0].id, 99,
+        "comment must not have been deleted and re-posted; id=99 must be preserved"
+    );
+}
+
+#[tokio::test]
+async fn test_config_check_renders_error_comment_from_template() {
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_config_file(Some("schemaVersion = 99".to_string()));
+    let renderer = TemplateEngineRenderer::new()
+        .with_template(
+            CommentTemplate::InvalidConfig,
+            "Config is broken: {{ errors | join('; ') }}",
+        )
+        .unwrap();
+
+    let warden = MergeWarden::new(provider).with_comment_renderer(Box::new(renderer));
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = warden.provider.get_comments();
+    let config_comments: Vec<_> = comments
+        .iter()
+        .filter(|c| c.body.contains(CONFIG_COMMENT_MARKER))
+        .collect();
+    assert_eq!(config_comments.len(), 1);
+    assert_eq!(
+        config_comments[0].body,
+        format!("{CONFIG_COMMENT_MARKER}\nConfig is broken: schemaVersion must be 1, found 99")
+    );
+}
+
+// ── Pull request template guidance tests ─────────────────────────────────────
+
+const PR_TEMPLATE: &str = "## Description\n\n## Related issue\n\n<!-- Closes #(issue number) -->\n";
+
+fn pr_template_guidance_warden(
+    provider: ConfigCheckMockProvider,
+    enabled: bool,
+) -> MergeWarden<ConfigCheckMockProvider> {
+    let config = CurrentPullRequestValidationConfiguration {
+        work_item_pr_template_guidance: enabled,
+        ..Default::default()
+    };
+    MergeWarden::with_config(provider, config)
+}
+
+fn make_pr_without_work_item() -> PullRequest {
+    PullRequest {
+        body: Some("Adds a new feature".to_string()),
+        ..make_valid_pr_with_sha("abc123")
+    }
+}
+
+fn work_item_comment_body(warden: &MergeWarden<ConfigCheckMockProvider>) -> String {
+    warden
+        .provider
+        .get_comments()
+        .into_iter()
+        .find(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER))
+        .expect("Work item comment should be added")
+        .body
+}
+
+#[tokio::test]
+async fn test_process_pull_request_work_item_comment_quotes_pr_template() {
+    let provider = ConfigCheckMockProvider::new(make_pr_without_work_item())
+        .with_base_file(".github/PULL_REQUEST_TEMPLATE.md", PR_TEMPLATE);
+    let warden = pr_template_guidance_warden(provider, true);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let body = work_item_comment_body(&warden);
+    assert!(body.contains(DEFAULT_MISSING_WORK_ITEM_COMMENT_TEMPLATE));
+    assert!(body.ends_with(
+        "This repository's pull request template (`.github/PULL_REQUEST_TEMPLATE.md`) shows how to reference a work item:\n\n> Closes #(issue number)"
+    ));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_work_item_comment_ignores_pr_template_when_disabled() {
+    let provider = ConfigCheckMockProvider::new(make_pr_without_work_item())
+        .with_base_file(".github/pull_request_template.md", PR_TEMPLATE);
+    let warden = pr_template_guidance_warden(provider, false);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        work_item_comment_body(&warden),
+        format!("{WORK_ITEM_COMMENT_MARKER}{DEFAULT_MISSING_WORK_ITEM_COMMENT_TEMPLATE}")
+    );
+}
+
+#[tokio::test]
+async fn test_process_pull_request_work_item_comment_falls_back_without_pr_template() {
+    let provider = ConfigCheckMockProvider::new(make_pr_without_work_item()).with_base_file(
+        "docs/pull_request_template.md",
+        "## Description\n\n- [ ] Tests added\n",
+    );
+    let warden = pr_template_guidance_warden(provider, true);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        work_item_comment_body(&warden),
+        format!("{WORK_ITEM_COMMENT_MARKER}{DEFAULT_MISSING_WORK_ITEM_COMMENT_TEMPLATE}")
+    );
+}
+
+#[tokio::test]
+async fn test_process_pull_request_work_item_comment_reports_reference_count() {
+    let provider = ConfigCheckMockProvider::new(PullRequest {
+        body: Some("Fixes #12".to_string()),
+        ..make_valid_pr_with_sha("abc123")
+    });
+    let config = CurrentPullRequestValidationConfiguration {
+        min_work_item_references: 2,
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        work_item_comment_body(&warden),
+        format!(
+            "{WORK_ITEM_COMMENT_MARKER}{DEFAULT_MISSING_WORK_ITEM_COMMENT_TEMPLATE}\n\nFound 1 of the 2 required work item references: #12"
+        )
+    );
+}
+
+// ── Renovate stability label tests ──────────────────────────────────────────
+
+fn make_pr_for_stability(number: u64) -> PullRequest {
+    PullRequest {
+        number,
+        title: "chore: update dependencies".to_string(),
+        draft: false,
+        body: Some("Closes #1".to_string()),
+        author: Some(User {
+            id: 1,
+            login: "renovate".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: "deadbeef".to_string(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+fn stability_config_enabled() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        renovate_stability: crate::config::RenovateStabilityConfig {
+            enabled: true,
+            pending_stability_label: crate::config::RENOVATE_STABILITY_LABEL.to_string(),
+        },
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn process_pull_request_pending_stability_applies_label() {
+    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
+        merge_warden_developer_platforms::models::CommitStatus {
+            context: "renovate/stability-days".to_string(),
+            state: "pending".to_string(),
+            description: None,
+        },
+    ]);
+    provider.add_pull_request(make_pr_for_stability(1));
+
+    let warden = MergeWarden::with_config(provider, stability_config_enabled());
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let labels = warden.provider.get_labels();
+    assert!(
+        labels
+            .iter()
+            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
+        "stability label should be applied when status is pending"
+    );
+}
+
+#[tokio::test]
+async fn process_pull_request_success_stability_removes_label() {
+    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
+        merge_warden_developer_platforms::models::CommitStatus {
+            context: "renovate/stability-days".to_string(),
+            state: "success".to_string(),
+            description: None,
+        },
+    ]);
+    provider.add_pull_request(make_pr_for_stability(1));
+
+    let warden = MergeWarden::with_config(provider, stability_config_enabled());
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    // remove_label was called (does not error); label not in applied list
+    let labels = warden.provider.get_labels();
+    assert!(
+        !labels
+            .iter()
+            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
+        "stability label should not be present when status is success"
+    );
+}
+
+#[tokio::test]
+async fn process_pull_request_no_stability_context_is_noop() {
+    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
+        merge_warden_developer_platforms::models::CommitStatus {
+            context: "ci/build".to_string(),
+            state: "success".to_string(),
+            description: None,
+        },
+    ]);
+    provider.add_pull_request(make_pr_for_stability(1));
+
+    let warden = MergeWarden::with_config(provider, stability_config_enabled());
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let labels = warden.provider.get_labels();
+    assert!(
+        !labels
+            .iter()
+            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
+        "stability label should not be applied when stability context is absent"
+    );
+}
+
+#[tokio::test]
+async fn process_pull_request_stability_disabled_is_noop() {
+    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
+        merge_warden_developer_platforms::models::CommitStatus {
+            context: "renovate/stability-days".to_string(),
+            state: "pending".to_string(),
+            description: None,
+        },
+    ]);
+    provider.add_pull_request(make_pr_for_stability(1));
+
+    let config = CurrentPullRequestValidationConfiguration {
+        renovate_stability: crate::config::RenovateStabilityConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let warden = MergeWarden::with_config(provider, config);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let labels = warden.provider.get_labels();
+    assert!(
+        !labels
+            .iter()
+            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
+        "stability label should not be applied when feature is disabled"
+    );
+}
+
+#[tokio::test]
+async fn process_pull_request_stability_error_does_not_propagate() {
+    // When the stability label call fails internally, process_pull_request
+    // should still complete (errors are logged at warn, not returned).
+    // DynamicMockGitProvider does not error on list_available_labels, so
+    // this test primarily exercises the path without panicking.
+    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
+        merge_warden_developer_platforms::models::CommitStatus {
+            context: "renovate/stability-days".to_string(),
+            state: "pending".to_string(),
+            description: None,
+        },
+    ]);
+    provider.add_pull_request(make_pr_for_stability(1));
+
+    let warden = MergeWarden::with_config(provider, stability_config_enabled());
+    let result = warden.process_pull_request("owner", "repo", 1).await;
+    assert!(
+        result.is_ok(),
+        "stability label errors must not propagate as process_pull_request failures"
+    );
+}
+
+#[tokio::test]
+async fn process_pull_request_draft_receives_stability_label() {
+    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
+        merge_warden_developer_platforms::models::CommitStatus {
+            context: "renovate/stability-days".to_string(),
+            state: "pending".to_string(),
+            description: None,
+        },
+    ]);
+    let mut pr = make_pr_for_stability(1);
+    pr.draft = true;
+    provider.add_pull_request(pr);
+
+    let warden = MergeWarden::with_config(provider, stability_config_enabled());
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let labels = warden.provider.get_labels();
+    assert!(
+        labels
+            .iter()
+            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
+        "stability label should also be applied on draft PRs"
+    );
+}
+
+#[tokio::test]
+async fn process_pull_request_check_conclusion_unaffected_by_stability() {
+    // A valid PR with pending stability status should still pass the title/body
+    // checks and produce a "success" check-status conclusion.
+    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
+        merge_warden_developer_platforms::models::CommitStatus {
+            context: "renovate/stability-days".to_string(),
+            state: "pending".to_string(),
+            description: None,
+        },
+    ]);
+    provider.add_pull_request(make_pr_for_stability(1));
+
+    let warden = MergeWarden::with_config(provider, stability_config_enabled());
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let updates = warden.provider.get_check_status_updates();
+    let conclusion = updates.last().map(|u| u.conclusion.as_str()).unwrap_or("");
+    // The stability label does not affect the Merge Warden check conclusion.
+    assert!(
+        conclusion == "success" || conclusion == "failure",
+        "check conclusion should be determined by title/body validations, not stability state; got '{conclusion}'"
+    );
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Deleted-file pairing check
+// ──────────────────────────────────────────────────────────────────────────────
+
+fn deleted_file(filename: &str) -> PullRequestFile {
+    PullRequestFile {
+        filename: filename.to_string(),
+        additions: 0,
+        deletions: 10,
+        changes: 10,
+        status: "removed".to_string(),
+    }
+}
+
+/// Returns the deleted-file policy requiring a `docs/*` change for deletions in `src/`.
+fn deleted_files_policy(
+    severity: crate::config::CheckSeverity,
+) -> crate::config::DeletedFilePolicyConfig {
+    crate::config::DeletedFilePolicyConfig {
+        enabled: true,
+        severity,
+        rules: vec![crate::config::DeletedFilePairingRule {
+            deleted: "src/*".to_string(),
+            requires: vec!["docs/*".to_string()],
+        }],
+    }
+}
+
+#[tokio::test]
+async fn test_process_pull_request_deleted_files_without_pairing_warns() {
+    let provider =
+        check_test_provider(check_test_pr()).with_files(vec![deleted_file("src/parser.rs")]);
+    let warden = MergeWarden::with_config(
+        provider,
+        check_test_config(|c| {
+            c.deleted_files = deleted_files_policy(crate::config::CheckSeverity::Warning)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.deleted_files_paired);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "success");
+    assert_eq!(update.summary, "PR deleted files lack paired changes.");
+    assert!(update
+        .text
+        .contains("| ⚠️ | Deleted files | 1 deletion rule(s) without paired changes |"));
+    assert!(update
+        .text
+        .contains("- `src/parser.rs` requires a change to `docs/*`"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_deleted_files_without_pairing_fails_with_error_severity() {
+    let provider =
+        check_test_provider(check_test_pr()).with_files(vec![deleted_file("src/parser.rs")]);
+    let warden = MergeWarden::with_config(
+        provider,
+        check_test_config(|c| {
+            c.deleted_files = deleted_files_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.deleted_files_paired);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+}
+
+#[tokio::test]
+async fn test_process_pull_request_deleted_files_with_pairing_passes() {
+    let provider = check_test_provider(check_test_pr()).with_files(vec![
+        deleted_file("src/parser.rs"),
+        make_pr_file("docs/parser.md", 3),
+    ]);
+    let warden = MergeWarden::with_config(
+        provider,
+        check_test_config(|c| {
+            c.deleted_files = deleted_files_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.deleted_files_paired);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "success");
+    assert!(update
+        .text
+        .contains("| ✅ | Deleted files | Deleted files have their paired changes |"));
+}
+
+/// Returns the suspicious-file policy with its default patterns enabled at `severity`.
+fn suspicious_files_policy(
+    severity: crate::config::CheckSeverity,
+) -> crate::config::SuspiciousFilePolicyConfig {
+    crate::config::SuspiciousFilePolicyConfig {
+        enabled: true,
+        severity,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_process_pull_request_suspicious_files_warns() {
+    let provider = check_test_provider(check_test_pr()).with_files(vec![
+        make_pr_file("src/lib.rs", 10),
+        make_pr_file("config/.env", 3),
+    ]);
+    let warden = MergeWarden::with_config(
+        provider,
+        check_test_config(|c| {
+            c.suspicious_files = suspicious_files_policy(crate::config::CheckSeverity::Warning)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.suspicious_files_absent);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "success");
+    assert_eq!(update.summary, "PR adds suspicious files.");
+    assert!(update
+        .text
+        .contains("| ⚠️ | Suspicious files | 1 suspicious file(s) |"));
+    assert!(update
+        .text
+        .contains("- `config/.env` looks like a secret (matches `.env`)"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_suspicious_files_fails_with_error_severity() {
+    let provider =
+        check_test_provider(check_test_pr()).with_files(vec![make_pr_file("certs/server.pem", 20)]);
+    let warden = MergeWarden::with_config(
+        provider,
+        check_test_config(|c| {
+            c.suspicious_files = suspicious_files_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.suspicious_files_absent);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+}
+
+#[tokio::test]
+async fn test_process_pull_request_without_suspicious_files_passes() {
+    let provider =
+        check_test_provider(check_test_pr()).with_files(vec![make_pr_file("src/lib.rs", 10)]);
+    let warden = MergeWarden::with_config(
+        provider,
+        check_test_config(|c| {
+            c.suspicious_files = suspicious_files_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.suspicious_files_absent);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Suspicious files | No secret, binary or oversized files |"));
+}
+
+/// Returns a hotfix pull request from `login-crash` into `base_ref`.
+fn base_branch_test_pr(base_ref: &str) -> PullRequest {
+    PullRequest {
+        title: "hotfix: stop the login crash".to_string(),
+        base_ref: base_ref.to_string(),
+        head_ref: "login-crash".to_string(),
+        ..check_test_pr()
+    }
+}
+
+/// Applies a base-branch policy that only allows hotfixes into `release/*`.
+fn configure_hotfix_base_branch(config: &mut CurrentPullRequestValidationConfiguration) {
+    // `hotfix` is not a conventional commit type
+    config.enforce_title_convention = false;
+    config.base_branch = crate::config::BaseBranchPolicyConfig {
+        enabled: true,
+        rules: vec![crate::config::BaseBranchRule {
+            title_types: vec!["hotfix".to_string()],
+            allowed_bases: vec!["release/*".to_string()],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_when_base_branch_is_not_allowed() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(base_branch_test_pr("main")),
+        check_test_config(configure_hotfix_base_branch),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.base_branch_valid);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert_eq!(update.summary, "PR targets `main` instead of `release/*`.");
+    assert!(update
+        .text
+        .contains("| ❌ | Base branch | expected `release/*`, got `main` |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_passes_when_base_branch_is_allowed() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(base_branch_test_pr("release/2.1")),
+        check_test_config(configure_hotfix_base_branch),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.base_branch_valid);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Base branch | Targets `release/2.1` |"));
+}
+
+fn descriptive_title_test_warden(
+    title: &str,
+    severity: CheckSeverity,
+) -> MergeWarden<MockGitProvider> {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(PullRequest {
+        number: 1,
+        title: title.to_string(),
+        draft: false,
+        body: Some("Fixes #1".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: "main".to_string(),
+        head_ref: "feat/add-login".to_string(),
+    });
+    let config = CurrentPullRequestValidationConfiguration {
+        descriptive_title: DescriptiveTitlePolicyConfig {
+            enabled: true,
+            severity,
+        },
+        ..Default::default()
+    };
+    MergeWarden::with_config(provider, config)
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_when_title_repeats_the_branch_name() {
+    let warden = descriptive_title_test_warden("feat: add login", CheckSeverity::Error);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.title_descriptive);
+    assert_eq!(result.failing_rules, vec![CheckKind::DescriptiveTitle]);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert_eq!(update.summary, "PR title only repeats the branch name.");
+    assert!(update
+        .text
+        .contains("| ❌ | Descriptive title | Title repeats the branch name `feat/add-login` |"));
+    assert!(update
+        .text
+        .contains("The PR title only repeats the branch name `feat/add-login`."));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_with_warning_severity_reports_title_without_failing() {
+    let warden = descriptive_title_test_warden("feat: add login", CheckSeverity::Warning);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.title_descriptive);
+    assert!(result.failing_rules.is_empty());
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0].text.contains("⚠️ **Descriptive title**"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_without_emoji_marks_messages_with_status_words() {
+    let mut warden = descriptive_title_test_warden("feat: add login", CheckSeverity::Warning);
+    warden.config.check_summary.use_emoji = false;
+    warden.config.footers = FooterPolicyConfig {
+        enabled: true,
+        required: vec![crate::config::RequiredFooter {
+            token: "Signed-off-by".to_string(),
+            pattern: None,
+        }],
+    };
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let updates = warden.provider.get_check_status_updates();
+    let text = &updates[0].text;
+    assert!(text.contains("WARN **Descriptive title**"));
+    assert!(text.contains("FAIL **Footers**"));
+    for emoji in ["✅", "❌", "⚠️", "ℹ️", "🔓", "📏"] {
+        assert!(!text.contains(emoji), "{emoji} in {text}");
+    }
+}
+
+#[tokio::test]
+async fn test_process_pull_request_passes_when_title_describes_the_change() {
+    let warden =
+        descriptive_title_test_warden("feat: add login with single sign-on", CheckSeverity::Error);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.title_descriptive);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Descriptive title | Title describes the change |"));
+}
+
+fn change_types_test_warden(
+    messages: Option<&[&str]>,
+    severity: CheckSeverity,
+) -> MergeWarden<SizeMockGitProvider> {
+    let pr = PullRequest {
+        number: 1,
+        title: "feat: add login".to_string(),
+        draft: false,
+        body: None,
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let mut provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/lib.rs", 10)]);
+    if let Some(messages) = messages {
+        provider = provider.with_commit_messages(messages);
+    }
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: false,
+        enforce_work_item_references: false,
+        change_types: ChangeTypesPolicyConfig {
+            enabled: true,
+            severity,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    MergeWarden::with_config(provider, config)
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_when_pr_mixes_too_many_change_types() {
+    let warden = change_types_test_warden(
+        Some(&[
+            "feat: add login form",
+            "Merge branch 'main' into login",
+            "fix: handle empty passwords",
+            "refactor: extract the session store",
+        ]),
+        CheckSeverity::Error,
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.change_types_within_limit);
+    assert_eq!(result.failing_rules, vec![CheckKind::ChangeTypes]);
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert!(update
+        .summary
+        .contains("mixes 3 change types (`feat`, `fix`, `refactor`)"));
+    assert!(update.text.contains(
+        "| ❌ | Change types | 3 change types, at most 2 allowed: `feat`, `fix`, `refactor` |"
+    ));
+    assert!(update.text.contains("❌ **Change types**"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_only_warns_about_change_types_by_default() {
+    let warden = change_types_test_warden(
+        Some(&["feat: add login form", "fix: typo", "docs: describe login"]),
+        CheckSeverity::Warning,
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.change_types_within_limit);
+    assert!(result.failing_rules.is_empty());
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0].text.contains("⚠️ **Change types**"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_reports_change_types_within_limit() {
+    let warden = change_types_test_warden(
+        Some(&["feat: add login form", "test: cover login"]),
+        CheckSeverity::Error,
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.change_types_within_limit);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Change types | 2 change type(s): `feat`, `test` |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_skips_change_types_check_when_unsupported() {
+    let warden = change_types_test_warden(None, CheckSeverity::Error);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.change_types_within_limit);
+    assert!(result.skip_reasons.contains(&(
+        CheckKind::ChangeTypes,
+        "Not supported by this platform".to_string()
+    )));
+}
+
+/// Returns the exclusive-label policy allowing at most one `priority: *` label.
+fn exclusive_priority_labels(
+    severity: crate::config::CheckSeverity,
+) -> crate::config::ExclusiveLabelsPolicyConfig {
+    crate::config::ExclusiveLabelsPolicyConfig {
+        enabled: true,
+        severity,
+        groups: vec![crate::config::ExclusiveLabelGroup {
+            name: "priority".to_string(),
+            labels: vec!["priority: *".to_string()],
+        }],
+    }
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_on_conflicting_exclusive_labels() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(check_test_pr())
+            .with_applied_labels(&["priority: high", "priority: low"]),
+        check_test_config(|c| {
+            c.exclusive_labels = exclusive_priority_labels(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.labels_exclusive);
+    assert_eq!(
+        result.failing_rules,
+        vec![crate::config::CheckKind::ExclusiveLabels]
+    );
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert_eq!(update.summary, "PR has conflicting labels.");
+    assert!(update
+        .text
+        .contains("| ❌ | Exclusive labels | priority: `priority: high`, `priority: low` |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_reports_exclusive_label_warning_without_failing() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(check_test_pr())
+            .with_applied_labels(&["priority: high", "priority: low"]),
+        check_test_config(|c| {
+            c.exclusive_labels = exclusive_priority_labels(crate::config::CheckSeverity::Warning)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.labels_exclusive);
+    assert!(result.failing_rules.is_empty());
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("⚠️ **Exclusive labels**: This PR has more than one label"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_passes_with_one_label_per_exclusive_group() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(check_test_pr()).with_applied_labels(&["priority: high", "bug"]),
+        check_test_config(|c| {
+            c.exclusive_labels = exclusive_priority_labels(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.labels_exclusive);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Exclusive labels | At most one label per group |"));
+}
+
+/// Returns the label policy requiring a `team: *` label and forbidding `do-not-merge`.
+fn team_label_policy(severity: crate::config::CheckSeverity) -> crate::config::LabelPolicyConfig {
+    crate::config::LabelPolicyConfig {
+        enabled: true,
+        severity,
+        required_labels: vec!["team: *".to_string()],
+        forbidden_labels: vec!["do-not-merge".to_string()],
+    }
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_on_label_policy_violations() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(check_test_pr()).with_applied_labels(&["do-not-merge"]),
+        check_test_config(|c| {
+            c.label_policy = team_label_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.label_policy_valid);
+    assert_eq!(
+        result.failing_rules,
+        vec![crate::config::CheckKind::LabelPolicy]
+    );
+    let updates = warden.provider.get_check_status_updates();
+    let update = &updates[0];
+    assert_eq!(update.conclusion, "failure");
+    assert_eq!(update.summary, "PR does not meet the label policy.");
+    assert!(update
+        .text
+        .contains("| ❌ | Label policy | missing `team: *`; forbidden `do-not-merge` |"));
+    assert!(update
+        .text
+        .contains("This PR carries a label that blocks merging:\n\n- `do-not-merge`"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_reports_label_policy_warning_without_failing() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(check_test_pr()).with_applied_labels(&[]),
+        check_test_config(|c| {
+            c.label_policy = team_label_policy(crate::config::CheckSeverity::Warning)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.label_policy_valid);
+    assert!(result.failing_rules.is_empty());
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("⚠️ **Label policy**:\n\nThis PR is missing a required label:\n\n- `team: *`"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_passes_label_policy_with_required_labels() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(check_test_pr()).with_applied_labels(&["Team: Core", "bug"]),
+        check_test_config(|c| {
+            c.label_policy = team_label_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.label_policy_valid);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Label policy | Required labels present, no forbidden labels |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_on_unsigned_commits() {
+    let provider = check_test_provider(check_test_pr()).with_commit_verifications(vec![
+        CommitVerification {
+            sha: "abc1234".to_string(),
+            verified: true,
+            reason: "valid".to_string(),
+        },
+        CommitVerification {
+            sha: "def5678".to_string(),
+            verified: false,
+            reason: "unsigned".to_string(),
+        },
+    ]);
+    let warden = MergeWarden::with_config(
+        provider,
+        check_test_config(|c| {
+            c.signed_commits = crate::config::SignedCommitsPolicyConfig { enabled: true }
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.commits_signed);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+    assert_eq!(updates[0].summary, "PR has 1 unverified commit(s).");
+    assert!(updates[0].text.contains("- `def5678` (unsigned)"));
+    assert!(!updates[0].text.contains("- `abc1234`"));
+    assert!(updates[0]
+        .text
+        .contains("| ❌ | Signed commits | 1 commit(s) without a verified signature |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_skips_signed_commits_when_unsupported() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(check_test_pr()),
+        check_test_config(|c| {
+            c.signed_commits = crate::config::SignedCommitsPolicyConfig { enabled: true }
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.commits_signed);
+    assert!(result.skip_reasons.contains(&(
+        crate::config::CheckKind::SignedCommits,
+        "Not supported by this platform".to_string()
+    )));
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+}
+
+#[tokio::test]
+async fn test_process_pull_request_bypasses_signed_commits_for_authorised_author() {
+    let author = User {
+        id: 7,
+        login: "release-bot".to_string(),
+    };
+    let provider = check_test_provider(PullRequest {
+        author: Some(author),
+        ..check_test_pr()
+    })
+    .with_commit_verifications(vec![CommitVerification {
+        sha: "def5678".to_string(),
+        verified: false,
+        reason: "unsigned".to_string(),
+    }]);
+    let config = check_test_config(|c| {
+        c.signed_commits = crate::config::SignedCommitsPolicyConfig { enabled: true };
+        c.bypass_rules = crate::config::BypassRules::default().with_signed_commits(
+            crate::config::BypassRule::new(true, vec!["release-bot".to_string()]),
+        );
+    });
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.commits_signed);
+    assert_eq!(result.bypasses_used.len(), 1);
+    assert_eq!(
+        result.bypasses_used[0].rule_type,
+        crate::validation_result::BypassRuleType::SignedCommits
+    );
+}
+
+/// Returns PR #2, which reverts `feat: add SSO` and has `body`.
+fn revert_test_pr(body: Option<&str>) -> PullRequest {
+    PullRequest {
+        number: 2,
+        title: "revert: feat: add SSO".to_string(),
+        body: body.map(str::to_string),
+        ..check_test_pr()
+    }
+}
+
+/// Enables the revert reference check. Revert descriptions do not reference work items.
+fn configure_revert_reference(config: &mut CurrentPullRequestValidationConfiguration) {
+    config.enforce_work_item_references = false;
+    config.revert_reference = crate::config::RevertReferencePolicyConfig { enabled: true };
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_revert_without_reference() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(revert_test_pr(Some("SSO broke sign-in."))),
+        check_test_config(configure_revert_reference),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 2)
+        .await
+        .unwrap();
+
+    assert!(!result.revert_referenced);
+    assert_eq!(
+        result.failing_rules,
+        vec![crate::config::CheckKind::RevertReference]
+    );
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+    assert_eq!(
+        updates[0].summary,
+        "PR does not reference the reverted change."
+    );
+    assert!(updates[0].text.contains("**Revert reference**"));
+    assert!(updates[0]
+        .text
+        .contains("| ❌ | Revert reference | No reference to the reverted PR or commit |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_passes_revert_with_reference() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(revert_test_pr(Some("Reverts owner/repo#1"))),
+        check_test_config(configure_revert_reference),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 2)
+        .await
+        .unwrap();
+
+    assert!(result.revert_referenced);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Revert reference | References owner/repo#1 |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_bypasses_revert_reference_for_authorised_author() {
+    let author = User {
+        id: 7,
+        login: "release-manager".to_string(),
+    };
+    let config = check_test_config(|c| {
+        configure_revert_reference(c);
+        c.bypass_rules = crate::config::BypassRules::default().with_revert_reference(
+            crate::config::BypassRule::new(true, vec!["release-manager".to_string()]),
+        );
+    });
+    let provider = check_test_provider(PullRequest {
+        author: Some(author),
+        ..revert_test_pr(None)
+    });
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 2)
+        .await
+        .unwrap();
+
+    assert!(result.revert_referenced);
+    assert_eq!(result.bypasses_used.len(), 1);
+    assert_eq!(
+        result.bypasses_used[0].rule_type,
+        crate::validation_result::BypassRuleType::RevertReference
+    );
+}
+
+/// Returns PR #20 with the description `body`.
+fn issue_reference_test_pr(body: &str) -> PullRequest {
+    PullRequest {
+        number: 20,
+        body: Some(body.to_string()),
+        ..check_test_pr()
+    }
+}
+
+fn issue_reference_test_issues() -> Vec<merge_warden_developer_platforms::models::Issue> {
+    vec![
+        merge_warden_developer_platforms::models::Issue {
+            number: 12,
+            title: "SSO login".to_string(),
+            is_pull_request: false,
+        },
+        merge_warden_developer_platforms::models::Issue {
+            number: 13,
+            title: "feat: add SAML".to_string(),
+            is_pull_request: true,
+        },
+    ]
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_dangling_issue_reference() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(issue_reference_test_pr("Fixes #12, relates to #99"))
+            .with_issues(issue_reference_test_issues()),
+        check_test_config(|c| {
+            c.issue_references = crate::config::IssueReferencePolicyConfig {
+                enforcement: crate::config::IssueReferenceEnforcement::Fail,
+            }
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 20)
+        .await
+        .unwrap();
+
+    assert!(!result.work_item_referenced);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+    assert!(updates[0].text.contains(
+        "| ❌ | Work item | PR description references issues that do not resolve: #99 does not exist |"
+    ));
+    let comments = warden.provider.get_comments();
+    assert!(comments
+        .iter()
+        .any(|c| c.body.contains("- #99 does not exist")));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_warns_about_pull_request_reference() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(issue_reference_test_pr(
+            "Fixes #12, relates to owner/repo#13",
+        ))
+        .with_issues(issue_reference_test_issues()),
+        check_test_config(|c| {
+            c.issue_references = crate::config::IssueReferencePolicyConfig {
+                enforcement: crate::config::IssueReferenceEnforcement::Warn,
+            }
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 20)
+        .await
+        .unwrap();
+
+    assert!(result.work_item_referenced);
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0].text.contains(
+        "| ⚠️ | Work item | PR description references a work item, but #13 is a pull request, not an issue |"
+    ));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_skips_issue_reference_verification_when_unsupported() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(issue_reference_test_pr("Fixes #99")),
+        check_test_config(|c| {
+            c.issue_references = crate::config::IssueReferencePolicyConfig {
+                enforcement: crate::config::IssueReferenceEnforcement::Fail,
+            }
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 20)
+        .await
+        .unwrap();
+
+    assert!(result.work_item_referenced);
+    let updates = warden.provider.get_check_status_updates();
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Work item | PR description references a work item |"));
 }
 
-impl SizeMockGitProvider {
-    fn new(pull_request: PullRequest, files: Vec<PullRequestFile>) -> Self {
-        Self {
-            pull_request,
-            pr_files: Arc::new(Mutex::new(files)),
-            labels: Arc::new(Mutex::new(Vec::new())),
-            comments: Arc::new(Mutex::new(Vec::new())),
-            check_status_updates: Arc::new(Mutex::new(Vec::new())),
-        }
-    }
+#[tokio::test]
+async fn test_process_pull_request_returns_reported_check_output() {
+    let provider =
+        check_test_provider(check_test_pr()).with_files(vec![deleted_file("src/parser.rs")]);
+    let warden = MergeWarden::with_config(
+        provider,
+        check_test_config(|c| {
+            c.deleted_files = deleted_files_policy(crate::config::CheckSeverity::Warning)
+        }),
+    );
 
-    fn set_pr_files(&self, files: Vec<PullRequestFile>) {
-        *self.pr_files.lock().unwrap() = files;
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(result.check_summary, updates[0].summary);
+    assert_eq!(result.check_text, updates[0].text);
+    assert!(result.check_text.contains("| Deleted files |"));
+}
+
+/// Returns PR #21, which merges `sso` at `abc123` into `main`.
+fn up_to_date_test_pr() -> PullRequest {
+    PullRequest {
+        number: 21,
+        head_sha: "abc123".to_string(),
+        base_ref: "main".to_string(),
+        head_ref: "sso".to_string(),
+        ..check_test_pr()
     }
+}
 
-    fn get_comments(&self) -> Vec<Comment> {
-        self.comments.lock().unwrap().clone()
+/// Returns the up-to-date policy flagging PRs 3 or more commits behind their base.
+fn up_to_date_policy(
+    severity: crate::config::CheckSeverity,
+) -> crate::config::UpToDatePolicyConfig {
+    crate::config::UpToDatePolicyConfig {
+        enabled: true,
+        behind_by_threshold: 3,
+        severity,
+        ..Default::default()
     }
+}
 
-    #[allow(dead_code)]
-    fn get_check_status_updates(&self) -> Vec<CheckStatusUpdate> {
-        self.check_status_updates.lock().unwrap().clone()
+#[tokio::test]
+async fn test_process_pull_request_labels_pr_behind_base_without_failing() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(up_to_date_test_pr()).with_commits_behind(5),
+        check_test_config(|c| {
+            c.up_to_date = up_to_date_policy(crate::config::CheckSeverity::Warning)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 21)
+        .await
+        .unwrap();
+
+    assert!(!result.up_to_date);
+    assert!(result.failing_rules.is_empty());
+    assert!(result.labels.contains(&"needs-rebase".to_string()));
+    assert!(warden
+        .provider
+        .get_applied_label_names()
+        .contains(&"needs-rebase".to_string()));
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "success");
+    assert!(updates[0]
+        .text
+        .contains("| ⚠️ | Up to date | 5 commit(s) behind `main` |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_fails_pr_behind_base_when_configured() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(up_to_date_test_pr()).with_commits_behind(3),
+        check_test_config(|c| {
+            c.up_to_date = up_to_date_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 21)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.failing_rules,
+        vec![crate::config::CheckKind::UpToDate]
+    );
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates[0].conclusion, "failure");
+    assert!(updates[0].summary.contains("is 3 commit(s) behind `main`"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_removes_needs_rebase_label_once_current() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(up_to_date_test_pr())
+            .with_commits_behind(2)
+            .with_applied_labels(&["needs-rebase", "bug"]),
+        check_test_config(|c| {
+            c.up_to_date = up_to_date_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 21)
+        .await
+        .unwrap();
+
+    assert!(result.up_to_date);
+    let applied = warden.provider.get_applied_label_names();
+    assert!(applied.contains(&"bug".to_string()));
+    assert!(!applied.contains(&"needs-rebase".to_string()));
+    let updates = warden.provider.get_check_status_updates();
+    assert!(updates[0]
+        .text
+        .contains("| ✅ | Up to date | 2 commit(s) behind `main` |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_skips_up_to_date_check_when_unsupported() {
+    let warden = MergeWarden::with_config(
+        check_test_provider(up_to_date_test_pr()).with_applied_labels(&["needs-rebase"]),
+        check_test_config(|c| {
+            c.up_to_date = up_to_date_policy(crate::config::CheckSeverity::Error)
+        }),
+    );
+
+    let result = warden
+        .process_pull_request("owner", "repo", 21)
+        .await
+        .unwrap();
+
+    assert!(result.up_to_date);
+    assert!(result.skip_reasons.contains(&(
+        crate::config::CheckKind::UpToDate,
+        "Not supported by this platform".to_string()
+    )));
+    // The label is left alone when the PR could not be compared
+    assert!(warden
+        .provider
+        .get_applied_label_names()
+        .contains(&"needs-rebase".to_string()));
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Version bump check
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Enables the version bump check with its default manifests.
+fn enable_version_bump(config: &mut CurrentPullRequestValidationConfiguration) {
+    config.version_bump = crate::config::VersionBumpPolicyConfig {
+        enabled: true,
+        ..Default::default()
+    };
+}
+
+fn cargo_manifest(version: &str) -> String {
+    format!("[package]\nname = \"example\"\nversion = \"{version}\"\n")
+}
+
+#[tokio::test]
+async fn test_process_pull_request_version_bump_matching_changes_passes() {
+    // make_valid_pr_with_sha uses a `feat:` title, which requires a minor bump.
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_changed_file(
+            "Cargo.toml",
+            Some(&cargo_manifest("1.4.2")),
+            Some(&cargo_manifest("1.5.0")),
+        );
+    let warden = MergeWarden::with_config(provider, check_test_config(enable_version_bump));
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.version_bump_valid);
+    assert!(result
+        .check_text
+        .contains("| ✅ | Version bump | 1.4.2 → 1.5.0 (requires at least a minor bump) |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_version_bump_too_small_fails() {
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_changed_file(
+            "Cargo.toml",
+            Some(&cargo_manifest("1.4.2")),
+            Some(&cargo_manifest("1.4.3")),
+        );
+    let warden = MergeWarden::with_config(provider, check_test_config(enable_version_bump));
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.version_bump_valid);
+    assert_eq!(
+        warden
+            .provider
+            .check_updates
+            .lock()
+            .unwrap()
+            .last()
+            .unwrap(),
+        "failure"
+    );
+    assert!(result
+        .check_text
+        .contains("| ❌ | Version bump | 1.4.2 → 1.4.3, expected 1.5.0 |"));
+    assert!(result.check_text.contains("the version should be `1.5.0`"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_version_bump_skipped_when_manifest_not_changed() {
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"));
+    let warden = MergeWarden::with_config(provider, check_test_config(enable_version_bump));
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.version_bump_valid);
+    assert!(result
+        .check_text
+        .contains("| ➖ | Version bump | Version file not changed in this PR |"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_version_bump_skipped_when_version_unreadable() {
+    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
+        .with_changed_file(
+            "Cargo.toml",
+            Some(&cargo_manifest("1.4.2")),
+            Some("[package"),
+        );
+    let warden = MergeWarden::with_config(provider, check_test_config(enable_version_bump));
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.version_bump_valid);
+    assert!(result
+        .check_text
+        .contains("| ➖ | Version bump | Could not read the version from the version file |"));
+}
+
+fn reopened_test_pr(title: &str) -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: title.to_string(),
+        draft: false,
+        body: Some("Fixes #42".to_string()),
+        author: Some(User {
+            id: 456,
+            login: "developer123".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     }
 }
 
-#[async_trait]
-impl merge_warden_developer_platforms::PullRequestProvider for SizeMockGitProvider {
-    async fn get_pull_request(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<PullRequest, merge_warden_developer_platforms::errors::Error> {
-        Ok(self.pull_request.clone())
-    }
+async fn add_stale_title_artifacts(provider: &MockGitProvider) {
+    provider
+        .add_labels("owner", "repo", 1, &[TITLE_INVALID_LABEL.to_string()])
+        .await
+        .unwrap();
+    provider
+        .add_comment(
+            "owner",
+            "repo",
+            1,
+            &format!("{}\n## Invalid PR Title Format", TITLE_COMMENT_MARKER),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_process_reopened_pull_request_removes_stale_artifacts() {
+    // The title check was disabled while the PR was closed, so the title label and
+    // comment from before the close are no longer managed by any check.
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    add_stale_title_artifacts(&provider).await;
+    provider
+        .add_comment("owner", "repo", 1, "Looks good to me")
+        .await
+        .unwrap();
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: false,
+        reopened: crate::config::ReopenedPolicyConfig {
+            reset_artifacts: true,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_reopened_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.title_valid);
+    assert!(!warden
+        .provider
+        .get_labels()
+        .iter()
+        .any(|l| l.name == TITLE_INVALID_LABEL));
+    let comments = warden.provider.get_comments();
+    assert!(!comments
+        .iter()
+        .any(|c| c.body.contains(TITLE_COMMENT_MARKER)));
+    assert!(
+        comments.iter().any(|c| c.body == "Looks good to me"),
+        "Comments not added by Merge Warden should be kept"
+    );
+}
+
+#[tokio::test]
+async fn test_process_reopened_pull_request_after_failing_state_reapplies_artifacts() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let config = CurrentPullRequestValidationConfiguration {
+        reopened: crate::config::ReopenedPolicyConfig {
+            reset_artifacts: true,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    // The PR failed the title check before it was closed.
+    let before = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+    assert!(!before.title_valid);
+
+    let result = warden
+        .process_reopened_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.title_valid);
+    assert_eq!(
+        warden
+            .provider
+            .get_labels()
+            .iter()
+            .filter(|l| l.name == TITLE_INVALID_LABEL)
+            .count(),
+        1
+    );
+    assert_eq!(
+        warden
+            .provider
+            .get_comments()
+            .iter()
+            .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
+            .count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_process_reopened_pull_request_keeps_artifacts_without_reset() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    add_stale_title_artifacts(&provider).await;
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: false,
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    warden
+        .process_reopened_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(warden
+        .provider
+        .get_labels()
+        .iter()
+        .any(|l| l.name == TITLE_INVALID_LABEL));
+    assert!(warden
+        .provider
+        .get_comments()
+        .iter()
+        .any(|c| c.body.contains(TITLE_COMMENT_MARKER)));
+}
+
+#[tokio::test]
+async fn test_process_merge_group_reports_on_the_merge_group_commit() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::with_config(
+        provider,
+        CurrentPullRequestValidationConfiguration::default(),
+    );
+
+    let result = warden
+        .process_merge_group("owner", "repo", 1, "queue-sha")
+        .await
+        .unwrap();
+
+    assert!(result.blocks_merge());
+    assert!(result.planned_operations.is_empty());
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].head_sha.as_deref(), Some("queue-sha"));
+    assert_eq!(updates[0].check_name, DEFAULT_CHECK_RUN_NAME);
+    assert_eq!(updates[0].conclusion, "failure");
+    assert_eq!(updates[0].summary, result.check_summary);
+}
+
+#[tokio::test]
+async fn test_process_merge_group_does_not_change_the_pr() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::with_config(
+        provider,
+        CurrentPullRequestValidationConfiguration::default(),
+    );
+
+    warden
+        .process_merge_group("owner", "repo", 1, "queue-sha")
+        .await
+        .unwrap();
+
+    assert!(warden.provider.get_labels().is_empty());
+    assert!(warden.provider.get_comments().is_empty());
+    assert!(warden
+        .provider
+        .get_check_status_updates()
+        .iter()
+        .all(|u| u.head_sha.as_deref() == Some("queue-sha")));
+}
+
+#[tokio::test]
+async fn test_process_merge_group_uses_the_check_run_names() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("feat: add login"));
+    let config = CurrentPullRequestValidationConfiguration {
+        check_summary: CheckSummaryConfig {
+            check_run_name: Some("Merge Warden PR checks".to_string()),
+            keep_legacy_check_run: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
 
-    async fn add_comment(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-        comment: &str,
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        let mut comments = self.comments.lock().unwrap();
-        let id = comments.len() as u64 + 1;
-        comments.push(Comment {
-            id,
-            body: comment.to_string(),
-            user: User {
-                id: 10,
-                login: "bot".to_string(),
-            },
-        });
-        Ok(())
-    }
+    let result = warden
+        .process_merge_group("owner", "repo", 1, "queue-sha")
+        .await
+        .unwrap();
 
-    async fn delete_comment(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        comment_id: u64,
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        self.comments.lock().unwrap().retain(|c| c.id != comment_id);
-        Ok(())
-    }
+    assert!(!result.blocks_merge());
+    let updates = warden.provider.get_check_status_updates();
+    let on_group: Vec<(&str, &str)> = updates
+        .iter()
+        .filter(|u| u.head_sha.as_deref() == Some("queue-sha"))
+        .map(|u| (u.check_name.as_str(), u.conclusion.as_str()))
+        .collect();
+    assert_eq!(
+        on_group,
+        vec![
+            ("Merge Warden PR checks", "success"),
+            (DEFAULT_CHECK_RUN_NAME, "success")
+        ]
+    );
+}
 
-    async fn list_comments(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<Vec<Comment>, merge_warden_developer_platforms::errors::Error> {
-        Ok(self.comments.lock().unwrap().clone())
-    }
+#[tokio::test]
+async fn test_process_merge_group_fails_when_the_platform_cannot_report_on_commits() {
+    let mut provider = DynamicMockGitProvider::new();
+    provider.add_pull_request(review_thread_test_pr());
+    let warden = MergeWarden::with_config(
+        provider,
+        CurrentPullRequestValidationConfiguration::default(),
+    );
 
-    async fn add_labels(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-        labels: &[String],
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        let mut current = self.labels.lock().unwrap();
-        for l in labels {
-            current.push(Label {
-                name: l.clone(),
-                description: None,
-            });
-        }
-        Ok(())
-    }
+    let result = warden
+        .process_merge_group("owner", "repo", 1, "queue-sha")
+        .await;
 
-    async fn remove_label(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-        label: &str,
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        self.labels.lock().unwrap().retain(|l| l.name != label);
-        Ok(())
-    }
+    assert!(matches!(
+        result,
+        Err(crate::errors::MergeWardenError::FailedToUpdatePullRequest(
+            _
+        ))
+    ));
+}
 
-    async fn list_applied_labels(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<Vec<Label>, merge_warden_developer_platforms::errors::Error> {
-        Ok(self.labels.lock().unwrap().clone())
-    }
+#[tokio::test]
+async fn test_process_pull_request_keeps_status_labels_when_removal_disabled() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("feat: add login"));
+    provider
+        .add_labels(
+            "owner",
+            "repo",
+            1,
+            &[
+                TITLE_INVALID_LABEL.to_string(),
+                MISSING_WORK_ITEM_LABEL.to_string(),
+            ],
+        )
+        .await
+        .unwrap();
+    let config = CurrentPullRequestValidationConfiguration {
+        status_labels: crate::config::StatusLabelPolicyConfig {
+            remove_on_pass: false,
+        },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
 
-    async fn list_available_labels(
-        &self,
-        _owner: &str,
-        _repo: &str,
-    ) -> Result<Vec<Label>, merge_warden_developer_platforms::errors::Error> {
-        Ok(Vec::new())
-    }
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    async fn update_pr_check_status(
-        &self,
-        repo_owner: &str,
-        repo_name: &str,
-        pr_number: u64,
-        conclusion: &str,
-        output_title: &str,
-        output_summary: &str,
-        output_text: &str,
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        let mut updates = self.check_status_updates.lock().unwrap();
-        updates.push(CheckStatusUpdate {
-            repo_owner: repo_owner.to_string(),
-            repo_name: repo_name.to_string(),
-            pr_number,
-            conclusion: conclusion.to_string(),
-            title: output_title.to_string(),
-            summary: output_summary.to_string(),
-            text: output_text.to_string(),
-        });
-        Ok(())
-    }
+    assert!(result.title_valid);
+    assert!(result.work_item_referenced);
+    let labels = warden.provider.get_labels();
+    assert!(labels.iter().any(|l| l.name == TITLE_INVALID_LABEL));
+    assert!(labels.iter().any(|l| l.name == MISSING_WORK_ITEM_LABEL));
+    let updates = warden.provider.get_check_status_updates();
+    assert_eq!(updates.last().unwrap().conclusion, "success");
+}
 
-    async fn get_pull_request_files(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<Vec<PullRequestFile>, merge_warden_developer_platforms::errors::Error> {
-        Ok(self.pr_files.lock().unwrap().clone())
+fn threaded_comments_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        comments: crate::config::CommentPolicyConfig {
+            threaded: true,
+            ..Default::default()
+        },
+        ..Default::default()
     }
+}
 
-    async fn list_pr_reviews(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<
-        Vec<merge_warden_developer_platforms::models::Review>,
-        merge_warden_developer_platforms::errors::Error,
-    > {
-        Ok(vec![])
+#[tokio::test]
+async fn test_process_pull_request_threads_guidance_comments() {
+    let provider = MockGitProvider::new();
+    provider.enable_threaded_comments();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::with_config(provider, threaded_comments_config());
+
+    // Running twice must not add a second thread or a second title reply.
+    for _ in 0..2 {
+        warden
+            .process_pull_request("owner", "repo", 1)
+            .await
+            .unwrap();
     }
 
-    async fn get_commit_statuses(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        _commit_sha: &str,
-    ) -> Result<
-        Vec<merge_warden_developer_platforms::models::CommitStatus>,
-        merge_warden_developer_platforms::errors::Error,
-    > {
-        Ok(vec![])
-    }
+    assert!(warden.provider.get_comments().is_empty());
+    let threaded = warden.provider.get_threaded_comments();
+    let roots: Vec<_> = threaded
+        .iter()
+        .filter(|(c, _)| c.body.contains(THREAD_ROOT_COMMENT_MARKER))
+        .collect();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].1, None);
+    let title_replies: Vec<_> = threaded
+        .iter()
+        .filter(|(c, _)| c.body.contains(TITLE_COMMENT_MARKER))
+        .collect();
+    assert_eq!(title_replies.len(), 1);
+    assert_eq!(title_replies[0].1, Some(roots[0].0.id));
 
-    async fn find_pull_requests_for_commit(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        _commit_sha: &str,
-    ) -> Result<Vec<u64>, merge_warden_developer_platforms::errors::Error> {
-        Ok(vec![])
-    }
+    // Fixing the title removes the reply but keeps the thread for later guidance.
+    warden
+        .provider
+        .set_pull_request(reopened_test_pr("feat: add login"));
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let threaded = warden.provider.get_threaded_comments();
+    assert_eq!(threaded.len(), 1);
+    assert!(threaded[0].0.body.contains(THREAD_ROOT_COMMENT_MARKER));
 }
 
-#[async_trait]
-impl merge_warden_developer_platforms::ConfigFetcher for SizeMockGitProvider {
-    async fn fetch_config(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        _path: &str,
-    ) -> Result<Option<String>, merge_warden_developer_platforms::errors::Error> {
-        Ok(None)
-    }
+#[tokio::test]
+async fn test_process_pull_request_threaded_comments_fall_back_to_top_level() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::with_config(provider, threaded_comments_config());
 
-    async fn fetch_config_at_ref(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        _path: &str,
-        _git_ref: &str,
-    ) -> Result<Option<String>, merge_warden_developer_platforms::errors::Error> {
-        Ok(None)
-    }
-}
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-/// Build a `PullRequestFile` that contributes the given number of changed lines.
-fn make_pr_file(filename: &str, changes: u32) -> PullRequestFile {
-    PullRequestFile {
-        filename: filename.to_string(),
-        additions: changes,
-        deletions: 0,
-        changes,
-        status: "modified".to_string(),
-    }
+    let comments = warden.provider.get_comments();
+    assert_eq!(
+        comments
+            .iter()
+            .filter(|c| c.body.contains(TITLE_COMMENT_MARKER))
+            .count(),
+        1
+    );
 }
 
-/// Build an oversized-PR config (size check enabled, comment enabled, not hard-failing).
-fn size_check_config() -> CurrentPullRequestValidationConfiguration {
+fn transition_summary_config() -> CurrentPullRequestValidationConfiguration {
     CurrentPullRequestValidationConfiguration {
-        pr_size_check: crate::config::PrSizeCheckConfig {
-            enabled: true,
-            add_comment: true,
-            fail_on_oversized: false,
+        comments: crate::config::CommentPolicyConfig {
+            transition_summary: true,
             ..Default::default()
         },
         ..Default::default()
     }
 }
 
-#[tokio::test]
-async fn test_identical_size_comment_is_not_reposted_when_pr_remains_oversized() {
-    // When a PR is oversized and processed twice without any change, the second
-    // run must not add a duplicate size comment (idempotent behaviour matching
-    // the title/work-item comment deduplication).
-    let pr = PullRequest {
-        number: 1,
-        title: "feat: add new feature".to_string(),
-        draft: false,
-        body: Some("Fixes #123".to_string()),
-        author: Some(User {
-            id: 1,
-            login: "dev".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    };
+fn transition_comments(provider: &MockGitProvider) -> Vec<Comment> {
+    provider
+        .get_comments()
+        .into_iter()
+        .filter(|c| c.body.contains(TRANSITION_COMMENT_MARKER))
+        .collect()
+}
 
-    // 600 changes → XXL (oversized with default thresholds where XL threshold = 500).
-    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 600)]);
+#[tokio::test]
+async fn test_process_pull_request_comments_only_on_conclusion_transitions() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::with_config(provider, transition_summary_config());
+
+    // The first failing run is reported; rerunning with the same conclusion is not.
+    for _ in 0..2 {
+        warden
+            .process_pull_request("owner", "repo", 1)
+            .await
+            .unwrap();
+    }
 
-    let warden = MergeWarden::with_config(provider, size_check_config());
+    let comments = transition_comments(&warden.provider);
+    assert_eq!(comments.len(), 1);
+    assert!(comments[0].body.contains("concluded `failure`"));
 
-    // First run — size comment should be created.
+    warden
+        .provider
+        .set_pull_request(reopened_test_pr("feat: add login"));
     warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let count_after_first = warden
-        .provider
-        .get_comments()
-        .iter()
-        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
-        .count();
-    assert_eq!(
-        count_after_first, 1,
-        "first run should produce exactly one size comment"
-    );
+    // The transition replaces the previous comment.
+    let comments = transition_comments(&warden.provider);
+    assert_eq!(comments.len(), 1);
+    assert!(comments[0]
+        .body
+        .contains("changed from `failure` to `success`"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_comments_transition_once_across_runs_sharing_a_state_store() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let state_store: Arc<dyn StateStore> = Arc::new(InMemoryStateStore::new());
+
+    // Like the CLI, every run creates its own MergeWarden.
+    for _ in 0..2 {
+        let warden = MergeWarden::with_config(provider.clone(), transition_summary_config())
+            .with_state_store(state_store.clone());
+        warden
+            .process_pull_request("owner", "repo", 1)
+            .await
+            .unwrap();
+    }
+
+    let comments = transition_comments(&provider);
+    assert_eq!(comments.len(), 1);
+    assert!(comments[0].body.contains("concluded `failure`"));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_renders_transition_comment_from_template() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let renderer = TemplateEngineRenderer::new()
+        .with_template(
+            CommentTemplate::ConclusionTransition,
+            "Checks: {{ previous_conclusion or 'none' }} -> {{ conclusion }}",
+        )
+        .unwrap();
+    let warden = MergeWarden::with_config(provider, transition_summary_config())
+        .with_comment_renderer(Box::new(renderer));
 
-    // Second run with the same oversized PR — comment body is identical, must not duplicate.
     warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let count_after_second = warden
-        .provider
-        .get_comments()
-        .iter()
-        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
-        .count();
+    let comments = transition_comments(&warden.provider);
+    assert_eq!(comments.len(), 1);
     assert_eq!(
-        count_after_second, 1,
-        "second run with identical oversized PR must not create a duplicate size comment"
+        comments[0].body,
+        format!("{TRANSITION_COMMENT_MARKER}Checks: none -> failure")
     );
 }
 
 #[tokio::test]
-async fn test_size_comment_is_removed_when_pr_becomes_non_oversized() {
-    // When a previously oversized PR is later reduced below the XXL threshold,
-    // the existing size comment must be cleaned up automatically.
-    let pr = PullRequest {
-        number: 1,
-        title: "feat: add feature".to_string(),
-        draft: false,
-        body: Some("Fixes #999".to_string()),
-        author: Some(User {
-            id: 1,
-            login: "dev".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: String::new(),
-    };
-
-    // Start oversized: 600 changes.
-    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/main.rs", 600)]);
-
-    let warden = MergeWarden::with_config(provider, size_check_config());
+async fn test_process_pull_request_does_not_comment_on_first_success() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("feat: add login"));
+    let warden = MergeWarden::with_config(provider, transition_summary_config());
 
-    // First run — oversized, comment added.
     warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let count_after_first = warden
-        .provider
-        .get_comments()
-        .iter()
-        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
-        .count();
-    assert_eq!(
-        count_after_first, 1,
-        "first run should produce a size comment"
-    );
+    assert!(transition_comments(&warden.provider).is_empty());
+}
 
-    // Author reduces the PR: 5 changes → XS (well below oversized threshold).
-    warden
-        .provider
-        .set_pr_files(vec![make_pr_file("src/main.rs", 5)]);
+#[tokio::test]
+async fn test_process_pull_request_does_not_comment_transitions_by_default() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::with_config(
+        provider,
+        CurrentPullRequestValidationConfiguration::default(),
+    );
 
-    // Second run — PR is no longer oversized; comment must be removed.
     warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let count_after_second = warden
-        .provider
-        .get_comments()
-        .iter()
-        .filter(|c| c.body.contains(SIZE_COMMENT_MARKER))
-        .count();
-    assert_eq!(
-        count_after_second, 0,
-        "size comment must be removed when PR is no longer oversized"
-    );
+    assert!(transition_comments(&warden.provider).is_empty());
 }
 
-// ── Config change validation tests ────────────────────────────────────────────
+fn draft_conversion_config() -> CurrentPullRequestValidationConfiguration {
+    CurrentPullRequestValidationConfiguration {
+        draft_conversion: DraftConversionPolicyConfig { enabled: true },
+        ..Default::default()
+    }
+}
 
-/// A minimal mock provider for config-change validation tests.
-///
-/// Tracks comments written/deleted so we can assert on them after processing.
-#[derive(Debug)]
-struct ConfigCheckMockProvider {
-    pr: PullRequest,
-    pr_files: Vec<merge_warden_developer_platforms::models::PullRequestFile>,
-    /// The value returned by `fetch_config_at_ref`.
-    config_at_ref: Option<Result<Option<String>, merge_warden_developer_platforms::errors::Error>>,
-    comments: Arc<Mutex<Vec<Comment>>>,
-    check_updates: Arc<Mutex<Vec<String>>>,
+fn draft_conversion_comments(provider: &MockGitProvider) -> Vec<Comment> {
+    provider
+        .get_comments()
+        .into_iter()
+        .filter(|c| c.body.contains(DRAFT_CONVERSION_COMMENT_MARKER))
+        .collect()
 }
 
-impl ConfigCheckMockProvider {
-    fn new(pr: PullRequest) -> Self {
-        Self {
-            pr,
-            pr_files: vec![],
-            config_at_ref: None,
-            comments: Arc::new(Mutex::new(vec![])),
-            check_updates: Arc::new(Mutex::new(vec![])),
-        }
-    }
+#[tokio::test]
+async fn test_process_pull_request_converts_failing_pull_request_to_draft_once() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::with_config(provider, draft_conversion_config());
 
-    fn with_config_file(mut self, content: Option<String>) -> Self {
-        use merge_warden_developer_platforms::models::PullRequestFile;
-        self.pr_files.push(PullRequestFile {
-            filename: ".github/merge-warden.toml".to_string(),
-            additions: 1,
-            deletions: 0,
-            changes: 1,
-            status: "added".to_string(),
-        });
-        self.config_at_ref = Some(Ok(content));
-        self
-    }
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    fn with_config_fetch_error(mut self) -> Self {
-        use merge_warden_developer_platforms::models::PullRequestFile;
-        self.pr_files.push(PullRequestFile {
-            filename: ".github/merge-warden.toml".to_string(),
-            additions: 1,
-            deletions: 0,
-            changes: 1,
-            status: "added".to_string(),
-        });
-        self.config_at_ref = Some(Err(
-            merge_warden_developer_platforms::errors::Error::ApiError(),
-        ));
-        self
-    }
+    assert_eq!(warden.provider.get_drafts_converted(), vec![1]);
+    let comments = draft_conversion_comments(&warden.provider);
+    assert_eq!(comments.len(), 1);
+    assert!(comments[0].body.contains("these checks failed: PR title."));
 
-    fn with_existing_comment(self, body: &str) -> Self {
-        self.comments.lock().unwrap().push(Comment {
-            id: 99,
-            body: body.to_string(),
-            user: User {
-                id: 1,
-                login: "merge-warden".to_string(),
-            },
-        });
-        self
-    }
+    // The author marks the still-failing PR as ready for review again.
+    warden
+        .provider
+        .set_pull_request(reopened_test_pr("invalid title"));
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    fn get_comments(&self) -> Vec<Comment> {
-        self.comments.lock().unwrap().clone()
-    }
+    assert_eq!(warden.provider.get_drafts_converted(), vec![1]);
+    assert_eq!(draft_conversion_comments(&warden.provider).len(), 1);
 }
 
-#[async_trait]
-impl merge_warden_developer_platforms::PullRequestProvider for ConfigCheckMockProvider {
-    async fn get_pull_request(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<PullRequest, merge_warden_developer_platforms::errors::Error> {
-        Ok(self.pr.clone())
+#[tokio::test]
+async fn test_process_pull_request_converts_to_draft_once_across_runs_sharing_a_state_store() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let state_store: Arc<dyn StateStore> = Arc::new(InMemoryStateStore::new());
+
+    // Like the CLI, every run creates its own MergeWarden.
+    for _ in 0..2 {
+        let warden = MergeWarden::with_config(provider.clone(), draft_conversion_config())
+            .with_state_store(state_store.clone());
+        warden
+            .process_pull_request("owner", "repo", 1)
+            .await
+            .unwrap();
+        // The author marks the still-failing PR as ready for review again.
+        provider.set_pull_request(reopened_test_pr("invalid title"));
     }
 
-    async fn add_comment(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-        comment: &str,
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        let mut comments = self.comments.lock().unwrap();
-        let id = comments.len() as u64 + 1;
-        comments.push(Comment {
-            id,
-            body: comment.to_string(),
-            user: User {
-                id: 1,
-                login: "merge-warden".to_string(),
-            },
-        });
-        Ok(())
-    }
+    assert_eq!(provider.get_drafts_converted(), vec![1]);
+    assert_eq!(draft_conversion_comments(&provider).len(), 1);
+}
 
-    async fn delete_comment(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        comment_id: u64,
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        self.comments.lock().unwrap().retain(|c| c.id != comment_id);
-        Ok(())
-    }
+#[tokio::test]
+async fn test_process_pull_request_renders_draft_conversion_comment_from_template() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let renderer = TemplateEngineRenderer::new()
+        .with_template(
+            CommentTemplate::DraftConversion,
+            "Back to draft: {{ failing_checks | join(' and ') }}",
+        )
+        .unwrap();
+    let warden = MergeWarden::with_config(provider, draft_conversion_config())
+        .with_comment_renderer(Box::new(renderer));
 
-    async fn list_comments(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<Vec<Comment>, merge_warden_developer_platforms::errors::Error> {
-        Ok(self.comments.lock().unwrap().clone())
-    }
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    let comments = draft_conversion_comments(&warden.provider);
+    assert_eq!(comments.len(), 1);
+    assert_eq!(
+        comments[0].body,
+        format!("{DRAFT_CONVERSION_COMMENT_MARKER}Back to draft: PR title")
+    );
+}
 
-    async fn add_labels(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-        _labels: &[String],
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        Ok(())
-    }
+#[tokio::test]
+async fn test_process_pull_request_does_not_convert_passing_or_draft_pull_requests() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("feat: add login"));
+    let warden = MergeWarden::with_config(provider, draft_conversion_config());
 
-    async fn remove_label(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-        _label: &str,
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        Ok(())
-    }
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    async fn list_applied_labels(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<Vec<Label>, merge_warden_developer_platforms::errors::Error> {
-        Ok(vec![])
-    }
+    let mut draft = reopened_test_pr("invalid title");
+    draft.draft = true;
+    warden.provider.set_pull_request(draft);
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    async fn list_available_labels(
-        &self,
-        _owner: &str,
-        _repo: &str,
-    ) -> Result<Vec<Label>, merge_warden_developer_platforms::errors::Error> {
-        Ok(vec![])
-    }
+    assert!(warden.provider.get_drafts_converted().is_empty());
+    assert!(draft_conversion_comments(&warden.provider).is_empty());
+}
 
-    async fn update_pr_check_status(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-        conclusion: &str,
-        _title: &str,
-        _summary: &str,
-        _text: &str,
-    ) -> Result<(), merge_warden_developer_platforms::errors::Error> {
-        self.check_updates
-            .lock()
-            .unwrap()
-            .push(conclusion.to_string());
-        Ok(())
-    }
+#[tokio::test]
+async fn test_process_pull_request_does_not_convert_to_draft_by_default() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let warden = MergeWarden::with_config(
+        provider,
+        CurrentPullRequestValidationConfiguration::default(),
+    );
 
-    async fn get_pull_request_files(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<
-        Vec<merge_warden_developer_platforms::models::PullRequestFile>,
-        merge_warden_developer_platforms::errors::Error,
-    > {
-        Ok(self.pr_files.clone())
-    }
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    async fn list_pr_reviews(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _number: u64,
-    ) -> Result<Vec<Review>, merge_warden_developer_platforms::errors::Error> {
-        Ok(vec![])
-    }
+    assert!(warden.provider.get_drafts_converted().is_empty());
+}
 
-    async fn get_commit_statuses(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        _commit_sha: &str,
-    ) -> Result<
-        Vec<merge_warden_developer_platforms::models::CommitStatus>,
-        merge_warden_developer_platforms::errors::Error,
-    > {
-        Ok(vec![])
-    }
+fn label_creation_test_warden(title: &str) -> MergeWarden<SizeMockGitProvider> {
+    let pr = PullRequest {
+        number: 3,
+        title: title.to_string(),
+        draft: false,
+        body: Some("Fixes #1".to_string()),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    // The mock repository defines no labels.
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/lib.rs", 10)]);
+    let config = CurrentPullRequestValidationConfiguration {
+        invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
+        pr_size_check: crate::config::PrSizeCheckConfig {
+            enabled: true,
+            ..Default::default()
+        },
+        label_creation: crate::config::LabelCreationPolicyConfig {
+            create_missing_labels: false,
+        },
+        ..Default::default()
+    };
+    MergeWarden::with_config(provider, config)
+}
 
-    async fn find_pull_requests_for_commit(
-        &self,
-        _repo_owner: &str,
-        _repo_name: &str,
-        _commit_sha: &str,
-    ) -> Result<Vec<u64>, merge_warden_developer_platforms::errors::Error> {
-        Ok(vec![])
-    }
+#[tokio::test]
+async fn test_process_pull_request_skips_missing_labels_when_label_creation_disabled() {
+    let warden = label_creation_test_warden("feat: add SSO");
+
+    let result = warden
+        .process_pull_request("owner", "repo", 3)
+        .await
+        .unwrap();
+
+    assert!(result.labels.is_empty());
+    // Neither the type label nor the size label was applied
+    assert!(warden.provider.labels.lock().unwrap().is_empty());
+    let updates = warden.provider.get_check_status_updates();
+    assert!(updates[0].text.contains(
+        "**Label Creation**: Skipped 1 label(s) that do not exist in the repository: feature"
+    ));
 }
 
-#[async_trait]
-impl merge_warden_developer_platforms::ConfigFetcher for ConfigCheckMockProvider {
-    async fn fetch_config(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _path: &str,
-    ) -> Result<Option<String>, merge_warden_developer_platforms::errors::Error> {
-        Ok(None)
-    }
+#[tokio::test]
+async fn test_process_pull_request_skips_missing_invalid_title_label_when_label_creation_disabled()
+{
+    let warden = label_creation_test_warden("add SSO");
 
-    async fn fetch_config_at_ref(
-        &self,
-        _owner: &str,
-        _repo: &str,
-        _path: &str,
-        _git_ref: &str,
-    ) -> Result<Option<String>, merge_warden_developer_platforms::errors::Error> {
-        match &self.config_at_ref {
-            Some(Ok(content)) => Ok(content.clone()),
-            Some(Err(_)) => Err(merge_warden_developer_platforms::errors::Error::ApiError()),
-            None => Ok(None),
-        }
-    }
+    let result = warden
+        .process_pull_request("owner", "repo", 3)
+        .await
+        .unwrap();
+
+    assert!(!result.title_valid);
+    assert!(warden.provider.labels.lock().unwrap().is_empty());
 }
 
-/// Helper: build a simple valid PR that will pass title and work-item checks.
-fn make_valid_pr_with_sha(sha: &str) -> PullRequest {
-    PullRequest {
-        number: 1,
-        title: "feat: add new feature".to_string(),
+fn label_listing_failure_test_warden(
+    title: &str,
+    body: Option<&str>,
+) -> MergeWarden<SizeMockGitProvider> {
+    let pr = PullRequest {
+        number: 4,
+        title: title.to_string(),
         draft: false,
-        body: Some("Fixes #123".to_string()),
-        author: Some(User {
-            id: 456,
-            login: "developer123".to_string(),
-        }),
+        body: body.map(str::to_string),
+        author: None,
         milestone_number: None,
-        head_sha: sha.to_string(),
-    }
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    };
+    let provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/lib.rs", 10)])
+        .with_list_applied_labels_failure();
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: true,
+        enforce_work_item_references: true,
+        invalid_title_label: Some(TITLE_INVALID_LABEL.to_string()),
+        missing_work_item_label: Some(MISSING_WORK_ITEM_LABEL.to_string()),
+        pr_size_check: crate::config::PrSizeCheckConfig {
+            enabled: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    MergeWarden::with_config(provider, config)
 }
 
 #[tokio::test]
-async fn test_config_check_no_comment_when_config_not_in_pr_files() {
-    // When .github/merge-warden.toml is NOT in the PR file list the warden
-    // must NOT post any CONFIG_COMMENT_MARKER comment.
-    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"));
-    // No config file added → config_at_ref is None → file list is empty.
+async fn test_title_label_is_left_alone_when_label_listing_fails() {
+    let warden = label_listing_failure_test_warden("add SSO", Some("Fixes #1"));
 
-    let warden = MergeWarden::new(provider);
-    warden
-        .process_pull_request("owner", "repo", 1)
+    let result = warden
+        .process_pull_request("owner", "repo", 4)
         .await
         .unwrap();
 
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
-        "no config check comment expected when config file is not in PR files"
-    );
+    assert!(!result.title_valid);
+    assert!(!warden
+        .provider
+        .get_applied_label_names()
+        .contains(&TITLE_INVALID_LABEL.to_string()));
+    assert!(warden
+        .provider
+        .get_comments()
+        .iter()
+        .any(|c| c.body.contains(TITLE_COMMENT_MARKER)));
+    assert_eq!(warden.provider.get_check_status_updates().len(), 1);
+}
+
+#[tokio::test]
+async fn test_work_item_label_is_left_alone_when_label_listing_fails() {
+    let warden = label_listing_failure_test_warden("feat: add SSO", None);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 4)
+        .await
+        .unwrap();
+
+    assert!(!result.work_item_referenced);
+    assert!(!warden
+        .provider
+        .get_applied_label_names()
+        .contains(&MISSING_WORK_ITEM_LABEL.to_string()));
+    assert!(warden
+        .provider
+        .get_comments()
+        .iter()
+        .any(|c| c.body.contains(WORK_ITEM_COMMENT_MARKER)));
+    assert_eq!(warden.provider.get_check_status_updates().len(), 1);
+}
+
+#[tokio::test]
+async fn test_size_label_is_left_alone_when_label_listing_fails() {
+    let warden = label_listing_failure_test_warden("feat: add SSO", Some("Fixes #1"));
+
+    let result = warden
+        .process_pull_request("owner", "repo", 4)
+        .await
+        .unwrap();
+
+    assert!(result.size_valid);
+    assert!(!warden
+        .provider
+        .get_applied_label_names()
+        .iter()
+        .any(|label| label.starts_with("size/")));
+    assert!(result
+        .check_text
+        .contains("PR size: XS (10 lines across 1 files)"));
+    assert_eq!(warden.provider.get_check_status_updates().len(), 1);
+}
+
+fn smart_label_test_warden(
+    title: &str,
+    report_no_labels: Option<bool>,
+    fail_add_labels: bool,
+) -> MergeWarden<SizeMockGitProvider> {
+    let pr = PullRequest {
+        number: 22,
+        title: title.to_string(),
+        draft: false,
+        body: None,
+        author: None,
+        milestone_number: None,
+        head_sha: "abc123".to_string(),
+        base_ref: "main".to_string(),
+        head_ref: "topic".to_string(),
+    };
+    let mut provider = SizeMockGitProvider::new(pr, vec![make_pr_file("src/lib.rs", 10)]);
+    if fail_add_labels {
+        provider = provider.with_add_labels_failure();
+    }
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: false,
+        enforce_work_item_references: false,
+        change_type_labels: Some(ChangeTypeLabelConfig {
+            report_no_labels,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    MergeWarden::with_config(provider, config)
 }
 
 #[tokio::test]
-async fn test_config_check_no_comment_when_config_is_valid() {
-    // When the config file is changed but its content is valid, no comment must
-    // be posted and the check conclusion must still be "success".
-    let valid_toml = "schemaVersion = 1";
-    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
-        .with_config_file(Some(valid_toml.to_string()));
+async fn test_process_pull_request_notes_calmly_when_no_labels_apply() {
+    let warden = smart_label_test_warden("Update the readme", None, false);
 
-    let warden = MergeWarden::new(provider);
-    warden
-        .process_pull_request("owner", "repo", 1)
+    let result = warden
+        .process_pull_request("owner", "repo", 22)
         .await
         .unwrap();
 
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
-        "no config check comment expected for a valid config file"
-    );
+    assert!(result.labels.is_empty());
+    assert!(result
+        .check_text
+        .contains("ℹ️ **Smart Label Detection**: No labels apply to this pull request"));
+    assert!(!result.check_text.contains("⚠️ **Smart Label Detection**"));
 }
 
 #[tokio::test]
-async fn test_config_check_posts_error_comment_when_config_is_invalid() {
-    // When the config file is changed and its content is invalid, an error
-    // comment bearing CONFIG_COMMENT_MARKER must be posted on the PR.
-    let bad_toml = "not = valid = toml [[[";
-    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
-        .with_config_file(Some(bad_toml.to_string()));
+async fn test_process_pull_request_can_suppress_the_no_labels_notice() {
+    let warden = smart_label_test_warden("Update the readme", Some(false), false);
 
-    let warden = MergeWarden::new(provider);
-    warden
-        .process_pull_request("owner", "repo", 1)
+    let result = warden
+        .process_pull_request("owner", "repo", 22)
         .await
         .unwrap();
 
-    let comments = warden.provider.get_comments();
-    let config_comments: Vec<_> = comments
-        .iter()
-        .filter(|c| c.body.contains(CONFIG_COMMENT_MARKER))
-        .collect();
-    assert_eq!(
-        config_comments.len(),
-        1,
-        "exactly one config error comment expected; got: {:?}",
-        comments
-    );
+    assert!(result.labels.is_empty());
+    assert!(!result.check_text.contains("Smart Label Detection"));
 }
 
 #[tokio::test]
-async fn test_config_check_does_not_affect_check_conclusion() {
-    // Even when the config file is invalid the check conclusion must NOT change
-    // (it must remain "success" for a PR that passes all other validations).
-    let bad_toml = "not = valid = toml [[[";
-    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
-        .with_config_file(Some(bad_toml.to_string()));
+async fn test_process_pull_request_warns_when_label_detection_fails() {
+    // A failure is reported even when the no-labels notice is suppressed
+    let warden = smart_label_test_warden("feat: add SSO", Some(false), true);
 
-    let warden = MergeWarden::new(provider);
     let result = warden
-        .process_pull_request("owner", "repo", 1)
+        .process_pull_request("owner", "repo", 22)
         .await
         .unwrap();
 
-    assert!(
-        result.title_valid,
-        "title_valid must remain true regardless of config validity"
-    );
-    assert!(
-        result.work_item_referenced,
-        "work_item_referenced must remain true regardless of config validity"
-    );
+    assert!(result.labels.is_empty());
+    assert!(result.check_text.contains(
+        "⚠️ **Smart Label Detection**: Label detection failed, so no labels were applied"
+    ));
 }
 
-#[tokio::test]
-async fn test_config_check_deletes_stale_comment_when_config_becomes_valid() {
-    // When there is an existing CONFIG_COMMENT_MARKER comment from a previous
-    // run and the config file is now valid, the comment must be deleted.
-    let valid_toml = "schemaVersion = 1";
-    let existing_body = format!("{}\nSome old error", CONFIG_COMMENT_MARKER);
-    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
-        .with_config_file(Some(valid_toml.to_string()))
-        .with_existing_comment(&existing_body);
-
-    let warden = MergeWarden::new(provider);
-    warden
-        .process_pull_request("owner", "repo", 1)
-        .await
-        .unwrap();
+// ── Decision log tests ───────────────────────────────────────────────────────
 
-    let remaining = warden.provider.get_comments();
-    assert!(
-        !remaining
-            .iter()
-            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
-        "stale config error comment must be deleted when config is now valid"
-    );
+fn make_pr_with_invalid_title() -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: "invalid title".to_string(),
+        draft: false,
+        body: Some("Fixes #123".to_string()),
+        author: Some(User {
+            id: 456,
+            login: "developer123".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: "abc123".to_string(),
+        base_ref: "main".to_string(),
+        head_ref: "feature/login".to_string(),
+    }
 }
 
 #[tokio::test]
-async fn test_config_check_no_comment_when_fetch_returns_none() {
-    // When fetch_config_at_ref returns Ok(None) (file absent at head SHA) and there is no
-    // pre-existing CONFIG_COMMENT_MARKER comment, the warden must not post a new comment.
-    let provider =
-        ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123")).with_config_file(None); // file in PR list but absent at ref
-
+async fn test_process_pull_request_with_decision_log_records_the_run() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(make_pr_with_invalid_title());
     let warden = MergeWarden::new(provider);
-    warden
-        .process_pull_request("owner", "repo", 1)
+
+    let (result, log) = warden
+        .process_pull_request_with_decision_log("owner", "repo", 1)
         .await
         .unwrap();
 
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
-        "no config check comment expected when file is absent at head SHA and no stale comment exists"
+    assert!(!result.title_valid);
+    assert_eq!(log.repository, "owner/repo");
+    assert_eq!(log.pull_request, 1);
+    assert_eq!(log.config_source, "Config: application defaults");
+
+    let input = log
+        .input
+        .as_ref()
+        .expect("the fetched PR should be recorded");
+    assert_eq!(input.title, "invalid title");
+    assert_eq!(input.author.as_deref(), Some("developer123"));
+    assert_eq!(input.head_ref, "feature/login");
+
+    let title_check = log
+        .checks
+        .iter()
+        .find(|c| c.check == "PR title")
+        .expect("the title check should be recorded");
+    assert_eq!(title_check.status, "FAIL");
+    assert!(log
+        .checks
+        .iter()
+        .any(|c| c.check == "Work item" && c.status == "PASS"));
+
+    assert_eq!(log.labels_added, vec![TITLE_INVALID_LABEL.to_string()]);
+    assert!(log.labels_removed.is_empty());
+    assert!(log
+        .comments_posted
+        .iter()
+        .any(|body| body.contains(TITLE_COMMENT_MARKER)));
+    assert!(log.comments_deleted.is_empty());
+
+    assert_eq!(log.conclusion(), Some("failure"));
+    assert_eq!(
+        log.check_run.as_ref().unwrap().summary,
+        result.check_summary
     );
 }
 
 #[tokio::test]
-async fn test_config_check_deletes_stale_comment_when_config_file_deleted() {
-    // When fetch_config_at_ref returns Ok(None) (e.g., config file was deleted in this PR)
-    // any stale error comment from a previous push must be cleaned up.
-    let stale_comment = format!(
-        "{}\n⚠️ **Invalid merge-warden configuration**\nold error",
-        CONFIG_COMMENT_MARKER
-    );
-    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
-        .with_config_file(None) // file in PR list but absent at ref
-        .with_existing_comment(&stale_comment);
-
+async fn test_process_pull_request_with_decision_log_records_removed_labels() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(make_pr_with_invalid_title());
     let warden = MergeWarden::new(provider);
     warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
-        "stale config check comment must be deleted when config file is absent at head SHA"
-    );
+    warden.provider.set_pull_request(PullRequest {
+        title: "feat: add login".to_string(),
+        ..make_pr_with_invalid_title()
+    });
+    let (_, log) = warden
+        .process_pull_request_with_decision_log("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!log.labels_added.contains(&TITLE_INVALID_LABEL.to_string()));
+    assert_eq!(log.labels_removed, vec![TITLE_INVALID_LABEL.to_string()]);
+    assert_eq!(log.conclusion(), Some("success"));
 }
 
 #[tokio::test]
-async fn test_config_check_skips_validation_when_head_sha_empty() {
-    // When the PR's head_sha is empty the warden must skip config validation entirely —
-    // no fetch call, no comment posted.  We use invalid config content so that if the
-    // guard is missing and the fetch happens, an error comment would be posted.
-    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha(""))
-        .with_config_file(Some("schemaVersion = 99".to_string())); // invalid if fetched
+async fn test_process_pull_request_with_decision_log_is_deterministic() {
+    let mut logs = Vec::new();
+    for _ in 0..2 {
+        let provider = MockGitProvider::new();
+        provider.set_pull_request(make_pr_with_invalid_title());
+        let warden = MergeWarden::new(provider);
+
+        let (_, log) = warden
+            .process_pull_request_with_decision_log("owner", "repo", 1)
+            .await
+            .unwrap();
+        logs.push(log.to_json().unwrap());
+    }
+
+    assert_eq!(logs[0], logs[1]);
+}
 
+#[tokio::test]
+async fn test_process_pull_request_does_not_record_a_decision_log() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(make_pr_with_invalid_title());
     let warden = MergeWarden::new(provider);
+
     warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
-        "no config check comment must be posted when head_sha is empty"
-    );
+    assert!(warden
+        .decision_recorder
+        .finish(&PullRequestTarget::from(("owner", "repo", 1)))
+        .is_none());
 }
 
-#[tokio::test]
-async fn test_config_check_no_comment_when_fetch_returns_error() {
-    // When fetch_config_at_ref returns an error the warden must log a warning
-    // and skip the check — no comment must be posted and the call must not
-    // propagate the error as a hard failure.
-    let provider =
-        ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123")).with_config_fetch_error();
+// ── Autofix tests ────────────────────────────────────────────────────────────
 
-    let warden = MergeWarden::new(provider);
-    let result = warden.process_pull_request("owner", "repo", 1).await;
+/// A provider holding [`make_autofixable_pr`] and issue #123, which its branch names.
+fn make_autofixable_provider() -> MockGitProvider {
+    let provider = MockGitProvider::new();
+    provider.set_issues(vec![merge_warden_developer_platforms::models::Issue {
+        number: 123,
+        title: "Add login".to_string(),
+        is_pull_request: false,
+    }]);
+    provider.set_pull_request(make_autofixable_pr());
+    provider
+}
 
-    // The error must NOT propagate — process_pull_request must succeed.
-    assert!(
-        result.is_ok(),
-        "fetch_config_at_ref error must not propagate as a hard failure"
-    );
-    let comments = warden.provider.get_comments();
-    assert!(
-        !comments
-            .iter()
-            .any(|c| c.body.contains(CONFIG_COMMENT_MARKER)),
-        "no config check comment expected when fetch_config_at_ref fails"
-    );
+fn make_autofixable_pr() -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: "FEAT: add login".to_string(),
+        draft: false,
+        body: Some("Adds a login page.".to_string()),
+        author: Some(User {
+            id: 456,
+            login: "developer123".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: "abc123".to_string(),
+        base_ref: "main".to_string(),
+        head_ref: "feature/123-add-login".to_string(),
+    }
 }
 
 #[tokio::test]
-async fn test_config_check_no_repost_when_error_comment_unchanged() {
-    // Pre-seed a CONFIG_COMMENT_MARKER comment whose body exactly matches what
-    // communicate_config_validity_status would post for schemaVersion = 99.
-    // The warden must detect the body is unchanged (already_up_to_date) and
-    // NOT delete + re-post the comment.
-    //
-    // We distinguish "skipped" from "deleted + re-posted" by checking the comment
-    // ID: the pre-seeded comment has id=99; if the shortcut were bypassed the
-    // comment would be deleted then re-added with id=1.
-    use indoc::formatdoc;
-
-    let bad_toml = "schemaVersion = 99";
-    let error_lines = "- schemaVersion must be 1, found 99";
-    let expected_body = formatdoc! {"
-        {marker}
-        ⚠️ **Invalid merge-warden configuration**
-
-        The `.github/merge-warden.toml` file introduced in this pull request contains \
-        errors and will not be used if this PR is merged.
-
-        **Errors found:**
-
-        {error_lines}
-
-        Please fix the configuration before merging.",
-        marker = CONFIG_COMMENT_MARKER,
-        error_lines = error_lines,
+async fn test_process_pull_request_autofixes_title_and_work_item() {
+    let provider = make_autofixable_provider();
+    let config = CurrentPullRequestValidationConfiguration {
+        autofix: crate::config::AutofixPolicyConfig {
+            title: true,
+            work_item: true,
+        },
+        ..Default::default()
     };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let provider = ConfigCheckMockProvider::new(make_valid_pr_with_sha("abc123"))
-        .with_config_file(Some(bad_toml.to_string()))
-        .with_existing_comment(&expected_body);
-
-    let warden = MergeWarden::new(provider);
-    warden
+    let result = warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
+    assert!(result.title_valid);
+    assert!(result.work_item_referenced);
+
+    let pr = warden
+        .provider
+        .pull_request
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap();
+    assert_eq!(pr.title, "feat: add login");
+    assert_eq!(
+        pr.body.as_deref(),
+        Some("Adds a login page.\n\nRelates to #123")
+    );
+
     let comments = warden.provider.get_comments();
-    let config_comments: Vec<_> = comments
+    let autofix_comments: Vec<&Comment> = comments
         .iter()
-        .filter(|c| c.body.contains(CONFIG_COMMENT_MARKER))
+        .filter(|c| c.body.contains(AUTOFIX_COMMENT_MARKER))
         .collect();
-    assert_eq!(
-        config_comments.len(),
-        1,
-        "exactly one config check comment must remain after processing"
-    );
-    assert_eq!(
-        config_comments[0].id, 99,
-        "comment must not have been deleted and re-posted; id=99 must be preserved"
-    );
+    assert_eq!(autofix_comments.len(), 1);
+    assert!(autofix_comments[0]
+        .body
+        .contains("Changed the title from `FEAT: add login` to `feat: add login`."));
+    assert!(autofix_comments[0]
+        .body
+        .contains("Added `Relates to #123` to the description"));
+    assert!(!comments.iter().any(
+        |c| c.body.contains(TITLE_COMMENT_MARKER) || c.body.contains(WORK_ITEM_COMMENT_MARKER)
+    ));
 }
 
-// ── Renovate stability label tests ──────────────────────────────────────────
+#[tokio::test]
+async fn test_process_pull_request_renders_autofix_comment_from_template() {
+    let provider = make_autofixable_provider();
+    let config = CurrentPullRequestValidationConfiguration {
+        autofix: crate::config::AutofixPolicyConfig {
+            title: true,
+            work_item: true,
+        },
+        ..Default::default()
+    };
+    let renderer = TemplateEngineRenderer::new()
+        .with_template(
+            CommentTemplate::Autofix,
+            "Retitled to `{{ new_title }}` and linked {{ work_item_reference }} from {{ branch }}.",
+        )
+        .unwrap();
+    let warden =
+        MergeWarden::with_config(provider, config).with_comment_renderer(Box::new(renderer));
+
+    warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
 
-fn make_pr_for_stability(number: u64) -> PullRequest {
-    PullRequest {
-        number,
-        title: "chore: update dependencies".to_string(),
-        draft: false,
-        body: Some("Closes #1".to_string()),
-        author: Some(User {
-            id: 1,
-            login: "renovate".to_string(),
-        }),
-        milestone_number: None,
-        head_sha: "deadbeef".to_string(),
-    }
+    let autofix_comments: Vec<Comment> = warden
+        .provider
+        .get_comments()
+        .into_iter()
+        .filter(|c| c.body.contains(AUTOFIX_COMMENT_MARKER))
+        .collect();
+    assert_eq!(autofix_comments.len(), 1);
+    assert_eq!(
+        autofix_comments[0].body,
+        format!(
+            "{AUTOFIX_COMMENT_MARKER}Retitled to `feat: add login` and linked Relates to #123 from feature/123-add-login."
+        )
+    );
 }
 
-fn stability_config_enabled() -> CurrentPullRequestValidationConfiguration {
-    CurrentPullRequestValidationConfiguration {
-        renovate_stability: crate::config::RenovateStabilityConfig {
-            enabled: true,
-            pending_stability_label: crate::config::RENOVATE_STABILITY_LABEL.to_string(),
+#[tokio::test]
+async fn test_process_pull_request_autofix_skips_issues_that_do_not_exist() {
+    let provider = MockGitProvider::new();
+    provider.set_issues(vec![]);
+    provider.set_pull_request(make_autofixable_pr());
+    let config = CurrentPullRequestValidationConfiguration {
+        autofix: crate::config::AutofixPolicyConfig {
+            title: false,
+            work_item: true,
         },
         ..Default::default()
-    }
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.work_item_referenced);
+    let pr = warden
+        .provider
+        .pull_request
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap();
+    assert_eq!(pr.body.as_deref(), Some("Adds a login page."));
+    assert!(!warden
+        .provider
+        .get_comments()
+        .iter()
+        .any(|c| c.body.contains(AUTOFIX_COMMENT_MARKER)));
 }
 
 #[tokio::test]
-async fn process_pull_request_pending_stability_applies_label() {
-    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
-        merge_warden_developer_platforms::models::CommitStatus {
-            context: "renovate/stability-days".to_string(),
-            state: "pending".to_string(),
-            description: None,
+async fn test_process_pull_request_autofixes_only_enabled_checks() {
+    let provider = make_autofixable_provider();
+    let config = CurrentPullRequestValidationConfiguration {
+        autofix: crate::config::AutofixPolicyConfig {
+            title: true,
+            work_item: false,
         },
-    ]);
-    provider.add_pull_request(make_pr_for_stability(1));
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::with_config(provider, stability_config_enabled());
-    warden
+    let result = warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let labels = warden.provider.get_labels();
-    assert!(
-        labels
-            .iter()
-            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
-        "stability label should be applied when status is pending"
-    );
+    assert!(result.title_valid);
+    assert!(!result.work_item_referenced);
+    let pr = warden
+        .provider
+        .pull_request
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap();
+    assert_eq!(pr.title, "feat: add login");
+    assert_eq!(pr.body.as_deref(), Some("Adds a login page."));
 }
 
 #[tokio::test]
-async fn process_pull_request_success_stability_removes_label() {
-    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
-        merge_warden_developer_platforms::models::CommitStatus {
-            context: "renovate/stability-days".to_string(),
-            state: "success".to_string(),
-            description: None,
-        },
-    ]);
-    provider.add_pull_request(make_pr_for_stability(1));
+async fn test_process_pull_request_does_not_autofix_by_default() {
+    let provider = make_autofixable_provider();
+    let warden = MergeWarden::new(provider);
 
-    let warden = MergeWarden::with_config(provider, stability_config_enabled());
-    warden
+    let result = warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    // remove_label was called (does not error); label not in applied list
-    let labels = warden.provider.get_labels();
-    assert!(
-        !labels
-            .iter()
-            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
-        "stability label should not be present when status is success"
-    );
+    assert!(!result.title_valid);
+    assert!(!result.work_item_referenced);
+    let pr = warden
+        .provider
+        .pull_request
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap();
+    assert_eq!(pr.title, "FEAT: add login");
+    assert_eq!(pr.body.as_deref(), Some("Adds a login page."));
+    assert!(!warden
+        .provider
+        .get_comments()
+        .iter()
+        .any(|c| c.body.contains(AUTOFIX_COMMENT_MARKER)));
 }
 
+// ── Dry run tests ────────────────────────────────────────────────────────────
+
 #[tokio::test]
-async fn process_pull_request_no_stability_context_is_noop() {
-    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
-        merge_warden_developer_platforms::models::CommitStatus {
-            context: "ci/build".to_string(),
-            state: "success".to_string(),
-            description: None,
-        },
-    ]);
-    provider.add_pull_request(make_pr_for_stability(1));
+async fn test_process_pull_request_dry_run_does_not_change_the_pr() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(make_pr_with_invalid_title());
+    let warden = MergeWarden::new(provider);
 
-    let warden = MergeWarden::with_config(provider, stability_config_enabled());
+    let result = warden
+        .process_pull_request_dry_run("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(!result.title_valid);
+    assert!(warden.provider.get_labels().is_empty());
+    assert!(warden.provider.get_comments().is_empty());
+    assert!(warden.provider.get_check_status_updates().is_empty());
+
+    assert!(result.planned_operations.iter().any(|op| matches!(
+        op,
+        PlannedOperation::AddLabels { labels } if labels.contains(&TITLE_INVALID_LABEL.to_string())
+    )));
+    assert!(result.planned_operations.iter().any(|op| matches!(
+        op,
+        PlannedOperation::AddComment { body, .. } if body.contains(TITLE_COMMENT_MARKER)
+    )));
+    assert!(matches!(
+        result.planned_operations.last(),
+        Some(PlannedOperation::UpdateCheckRun { conclusion, summary, .. })
+            if conclusion == "failure" && *summary == result.check_summary
+    ));
+}
+
+#[tokio::test]
+async fn test_process_pull_request_dry_run_plans_removal_of_stale_artifacts() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(make_pr_with_invalid_title());
+    let warden = MergeWarden::new(provider);
     warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
+    let title_comment = warden
+        .provider
+        .get_comments()
+        .into_iter()
+        .find(|c| c.body.contains(TITLE_COMMENT_MARKER))
+        .expect("Title comment should be added");
 
-    let labels = warden.provider.get_labels();
-    assert!(
-        !labels
-            .iter()
-            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
-        "stability label should not be applied when stability context is absent"
-    );
+    warden.provider.set_pull_request(PullRequest {
+        title: "feat: add login".to_string(),
+        ..make_pr_with_invalid_title()
+    });
+    let result = warden
+        .process_pull_request_dry_run("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.title_valid);
+    assert!(result
+        .planned_operations
+        .contains(&PlannedOperation::RemoveLabel {
+            label: TITLE_INVALID_LABEL.to_string(),
+        }));
+    assert!(result
+        .planned_operations
+        .contains(&PlannedOperation::DeleteComment {
+            comment_id: title_comment.id,
+        }));
+    assert!(warden
+        .provider
+        .get_labels()
+        .iter()
+        .any(|l| l.name == TITLE_INVALID_LABEL));
+    assert!(warden
+        .provider
+        .get_comments()
+        .iter()
+        .any(|c| c.id == title_comment.id));
 }
 
 #[tokio::test]
-async fn process_pull_request_stability_disabled_is_noop() {
-    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
-        merge_warden_developer_platforms::models::CommitStatus {
-            context: "renovate/stability-days".to_string(),
-            state: "pending".to_string(),
-            description: None,
-        },
-    ]);
-    provider.add_pull_request(make_pr_for_stability(1));
-
+async fn test_process_pull_request_dry_run_plans_autofix() {
+    let provider = make_autofixable_provider();
     let config = CurrentPullRequestValidationConfiguration {
-        renovate_stability: crate::config::RenovateStabilityConfig {
-            enabled: false,
-            ..Default::default()
+        autofix: crate::config::AutofixPolicyConfig {
+            title: true,
+            work_item: false,
         },
         ..Default::default()
     };
-
     let warden = MergeWarden::with_config(provider, config);
-    warden
-        .process_pull_request("owner", "repo", 1)
+
+    let result = warden
+        .process_pull_request_dry_run("owner", "repo", 1)
         .await
         .unwrap();
 
-    let labels = warden.provider.get_labels();
-    assert!(
-        !labels
-            .iter()
-            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
-        "stability label should not be applied when feature is disabled"
-    );
+    assert!(result
+        .planned_operations
+        .contains(&PlannedOperation::UpdatePullRequest {
+            title: Some("feat: add login".to_string()),
+            body: None,
+        }));
+    let pr = warden
+        .provider
+        .pull_request
+        .lock()
+        .unwrap()
+        .clone()
+        .unwrap();
+    assert_eq!(pr.title, "FEAT: add login");
 }
 
 #[tokio::test]
-async fn process_pull_request_stability_error_does_not_propagate() {
-    // When the stability label call fails internally, process_pull_request
-    // should still complete (errors are logged at warn, not returned).
-    // DynamicMockGitProvider does not error on list_available_labels, so
-    // this test primarily exercises the path without panicking.
-    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
-        merge_warden_developer_platforms::models::CommitStatus {
-            context: "renovate/stability-days".to_string(),
-            state: "pending".to_string(),
-            description: None,
-        },
-    ]);
-    provider.add_pull_request(make_pr_for_stability(1));
+async fn test_process_pull_request_after_dry_run_applies_changes() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(make_pr_with_invalid_title());
+    let warden = MergeWarden::new(provider);
+    warden
+        .process_pull_request_dry_run("owner", "repo", 1)
+        .await
+        .unwrap();
 
-    let warden = MergeWarden::with_config(provider, stability_config_enabled());
-    let result = warden.process_pull_request("owner", "repo", 1).await;
-    assert!(
-        result.is_ok(),
-        "stability label errors must not propagate as process_pull_request failures"
-    );
+    let result = warden
+        .process_pull_request("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result.planned_operations.is_empty());
+    assert!(warden
+        .provider
+        .get_labels()
+        .iter()
+        .any(|l| l.name == TITLE_INVALID_LABEL));
+    assert!(!warden.provider.get_check_status_updates().is_empty());
 }
 
 #[tokio::test]
-async fn process_pull_request_draft_receives_stability_label() {
-    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
-        merge_warden_developer_platforms::models::CommitStatus {
-            context: "renovate/stability-days".to_string(),
-            state: "pending".to_string(),
-            description: None,
+async fn test_process_pull_request_after_dry_run_comments_transition_and_converts_to_draft() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    let config = CurrentPullRequestValidationConfiguration {
+        comments: crate::config::CommentPolicyConfig {
+            transition_summary: true,
+            ..Default::default()
         },
-    ]);
-    let mut pr = make_pr_for_stability(1);
-    pr.draft = true;
-    provider.add_pull_request(pr);
+        draft_conversion: DraftConversionPolicyConfig { enabled: true },
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
+
+    let result = warden
+        .process_pull_request_dry_run("owner", "repo", 1)
+        .await
+        .unwrap();
+
+    assert!(result
+        .planned_operations
+        .contains(&PlannedOperation::ConvertToDraft));
+    assert!(result.planned_operations.iter().any(|op| matches!(
+        op,
+        PlannedOperation::AddComment { body, .. } if body.contains(TRANSITION_COMMENT_MARKER)
+    )));
+    assert!(warden.provider.get_drafts_converted().is_empty());
 
-    let warden = MergeWarden::with_config(provider, stability_config_enabled());
     warden
         .process_pull_request("owner", "repo", 1)
         .await
         .unwrap();
 
-    let labels = warden.provider.get_labels();
-    assert!(
-        labels
-            .iter()
-            .any(|l| l.name == crate::config::RENOVATE_STABILITY_LABEL),
-        "stability label should also be applied on draft PRs"
-    );
+    assert_eq!(warden.provider.get_drafts_converted(), vec![1]);
+    assert_eq!(draft_conversion_comments(&warden.provider).len(), 1);
+    assert_eq!(transition_comments(&warden.provider).len(), 1);
 }
 
 #[tokio::test]
-async fn process_pull_request_check_conclusion_unaffected_by_stability() {
-    // A valid PR with pending stability status should still pass the title/body
-    // checks and produce a "success" check-status conclusion.
-    let mut provider = DynamicMockGitProvider::new().with_commit_statuses(vec![
-        merge_warden_developer_platforms::models::CommitStatus {
-            context: "renovate/stability-days".to_string(),
-            state: "pending".to_string(),
-            description: None,
+async fn test_process_reopened_pull_request_dry_run_plans_removal_of_stale_artifacts() {
+    let provider = MockGitProvider::new();
+    provider.set_pull_request(reopened_test_pr("invalid title"));
+    add_stale_title_artifacts(&provider).await;
+    let config = CurrentPullRequestValidationConfiguration {
+        enforce_title_convention: false,
+        reopened: crate::config::ReopenedPolicyConfig {
+            reset_artifacts: true,
         },
-    ]);
-    provider.add_pull_request(make_pr_for_stability(1));
+        ..Default::default()
+    };
+    let warden = MergeWarden::with_config(provider, config);
 
-    let warden = MergeWarden::with_config(provider, stability_config_enabled());
-    warden
-        .process_pull_request("owner", "repo", 1)
+    let result = warden
+        .process_reopened_pull_request_dry_run("owner", "repo", 1)
         .await
         .unwrap();
 
-    let updates = warden.provider.get_check_status_updates();
-    let conclusion = updates.last().map(|u| u.conclusion.as_str()).unwrap_or("");
-    // The stability label does not affect the Merge Warden check conclusion.
-    assert!(
-        conclusion == "success" || conclusion == "failure",
-        "check conclusion should be determined by title/body validations, not stability state; got '{conclusion}'"
-    );
+    assert!(result
+        .planned_operations
+        .contains(&PlannedOperation::RemoveLabel {
+            label: TITLE_INVALID_LABEL.to_string(),
+        }));
+    assert!(result
+        .planned_operations
+        .iter()
+        .any(|op| matches!(op, PlannedOperation::DeleteComment { .. })));
+    assert!(warden
+        .provider
+        .get_labels()
+        .iter()
+        .any(|l| l.name == TITLE_INVALID_LABEL));
+    assert!(warden
+        .provider
+        .get_comments()
+        .iter()
+        .any(|c| c.body.contains(TITLE_COMMENT_MARKER)));
+}
+
+#[tokio::test]
+async fn test_check_result_default_is_a_passing_result() {
+    let result = CheckResult::default();
+
+    assert!(result.title_valid);
+    assert!(result.work_item_referenced);
+    assert!(result.label_policy_valid);
+    assert!(!result.wip_detected);
+    assert!(result.failing_rules.is_empty());
+    assert!(!result.blocks_merge());
 }
@@ -7,8 +7,13 @@
 //! more manageable pull request sizes, with research showing that review effectiveness
 //! decreases significantly for larger PRs.
 
-use merge_warden_developer_platforms::models::PullRequestFile;
+use crate::config::PrSizeCheckConfig;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use merge_warden_developer_platforms::models::{PullRequestFile, PullRequestFilePatch};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::warn;
 
 #[cfg(test)]
 #[path = "size_tests.rs"]
@@ -57,6 +62,25 @@ pub enum PrSizeCategory {
 }
 
 impl PrSizeCategory {
+    /// Every size category, from smallest to largest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::PrSizeCategory;
+    ///
+    /// let names: Vec<&str> = PrSizeCategory::ALL.iter().map(|c| c.as_str()).collect();
+    /// assert_eq!(names, ["XS", "S", "M", "L", "XL", "XXL"]);
+    /// ```
+    pub const ALL: [PrSizeCategory; 6] = [
+        PrSizeCategory::XS,
+        PrSizeCategory::S,
+        PrSizeCategory::M,
+        PrSizeCategory::L,
+        PrSizeCategory::XL,
+        PrSizeCategory::XXL,
+    ];
+
     /// Determine the size category from the total number of lines changed.
     ///
     /// Uses the standard thresholds defined in the industry research on
@@ -185,9 +209,12 @@ impl std::fmt::Display for PrSizeCategory {
 
 /// Configurable thresholds for PR size categorization.
 ///
+/// Line-count thresholds that separate the PR size categories.
+///
 /// Allows teams to customize the line count thresholds that determine
 /// which size category a PR falls into based on their workflow and
-/// review practices.
+/// review practices. Each field is the inclusive upper bound of its category;
+/// a PR with more lines than `xl` is XXL.
 ///
 /// # Examples
 ///
@@ -261,6 +288,172 @@ impl SizeThresholds {
     pub fn new(xs: u32, s: u32, m: u32, l: u32, xl: u32) -> Self {
         Self { xs, s, m, l, xl }
     }
+
+    /// Returns the inclusive upper bound of `category`, or `None` for XXL, which is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::{PrSizeCategory, SizeThresholds};
+    ///
+    /// let thresholds = SizeThresholds::default();
+    /// assert_eq!(thresholds.upper_bound(PrSizeCategory::M), Some(100));
+    /// assert_eq!(thresholds.upper_bound(PrSizeCategory::XXL), None);
+    /// ```
+    pub fn upper_bound(&self, category: PrSizeCategory) -> Option<u32> {
+        match category {
+            PrSizeCategory::XS => Some(self.xs),
+            PrSizeCategory::S => Some(self.s),
+            PrSizeCategory::M => Some(self.m),
+            PrSizeCategory::L => Some(self.l),
+            PrSizeCategory::XL => Some(self.xl),
+            PrSizeCategory::XXL => None,
+        }
+    }
+}
+
+/// Display names for the PR size categories.
+///
+/// Lets a repository use its own vocabulary for the size categories, e.g. `"tiny"` instead
+/// of `"XS"`, in labels and comments. Categorization itself always uses [`PrSizeCategory`].
+/// A category without a configured name is shown under its standard name.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::size::{PrSizeCategory, SizeCategoryNames};
+///
+/// let names = SizeCategoryNames {
+///     xs: Some("tiny".to_string()),
+///     ..Default::default()
+/// };
+/// assert_eq!(names.name_for(PrSizeCategory::XS), "tiny");
+/// assert_eq!(names.name_for(PrSizeCategory::M), "M");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeCategoryNames {
+    /// Display name for the XS category (default: `"XS"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xs: Option<String>,
+    /// Display name for the S category (default: `"S"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub s: Option<String>,
+    /// Display name for the M category (default: `"M"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m: Option<String>,
+    /// Display name for the L category (default: `"L"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l: Option<String>,
+    /// Display name for the XL category (default: `"XL"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xl: Option<String>,
+    /// Display name for the XXL category (default: `"XXL"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xxl: Option<String>,
+}
+
+impl SizeCategoryNames {
+    /// Returns the configured name of `category`, if one is set.
+    pub fn custom_name(&self, category: PrSizeCategory) -> Option<&str> {
+        match category {
+            PrSizeCategory::XS => self.xs.as_deref(),
+            PrSizeCategory::S => self.s.as_deref(),
+            PrSizeCategory::M => self.m.as_deref(),
+            PrSizeCategory::L => self.l.as_deref(),
+            PrSizeCategory::XL => self.xl.as_deref(),
+            PrSizeCategory::XXL => self.xxl.as_deref(),
+        }
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    }
+
+    /// Returns the name to show for `category`: the configured name, or the standard
+    /// name from [`PrSizeCategory::as_str`] when none is set.
+    pub fn name_for(&self, category: PrSizeCategory) -> &str {
+        self.custom_name(category)
+            .unwrap_or_else(|| category.as_str())
+    }
+
+    /// Merges `over` on top of `base`, taking each name from `over` when it is set.
+    pub(crate) fn merge(base: &Self, over: &Self) -> Self {
+        Self {
+            xs: over.xs.clone().or_else(|| base.xs.clone()),
+            s: over.s.clone().or_else(|| base.s.clone()),
+            m: over.m.clone().or_else(|| base.m.clone()),
+            l: over.l.clone().or_else(|| base.l.clone()),
+            xl: over.xl.clone().or_else(|| base.xl.clone()),
+            xxl: over.xxl.clone().or_else(|| base.xxl.clone()),
+        }
+    }
+}
+
+/// How the changed lines of a file are counted towards the PR size.
+///
+/// # Modes
+///
+/// * `TotalChanges` - added plus deleted lines (the default)
+/// * `NetChanges` - the difference between the added and deleted lines of each file,
+///   so code moved within a file counts only the lines it grew or shrank by
+/// * `AdditionsOnly` - added lines only; deleted lines and removed files count 0
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::size::SizeCountingMode;
+/// use merge_warden_developer_platforms::models::PullRequestFile;
+///
+/// let file = PullRequestFile {
+///     filename: "src/lib.rs".to_string(),
+///     additions: 120,
+///     deletions: 100,
+///     changes: 220,
+///     status: "modified".to_string(),
+/// };
+///
+/// assert_eq!(SizeCountingMode::TotalChanges.counted_lines(&file), 220);
+/// assert_eq!(SizeCountingMode::NetChanges.counted_lines(&file), 20);
+/// assert_eq!(SizeCountingMode::AdditionsOnly.counted_lines(&file), 120);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SizeCountingMode {
+    /// Count added plus deleted lines.
+    #[default]
+    TotalChanges,
+
+    /// Count the difference between the added and deleted lines of each file.
+    NetChanges,
+
+    /// Count added lines only.
+    AdditionsOnly,
+}
+
+impl SizeCountingMode {
+    /// Returns the number of lines `file` contributes to the size in this mode.
+    pub fn counted_lines(self, file: &PullRequestFile) -> u32 {
+        match self {
+            SizeCountingMode::TotalChanges => file.changes,
+            SizeCountingMode::NetChanges => file.additions.abs_diff(file.deletions),
+            SizeCountingMode::AdditionsOnly => file.additions,
+        }
+    }
+
+    /// Returns a short description of what is counted, for use in comments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::SizeCountingMode;
+    ///
+    /// assert_eq!(SizeCountingMode::AdditionsOnly.description(), "added lines only");
+    /// ```
+    pub fn description(self) -> &'static str {
+        match self {
+            SizeCountingMode::TotalChanges => "added and deleted lines",
+            SizeCountingMode::NetChanges => "net lines (added minus deleted, per file)",
+            SizeCountingMode::AdditionsOnly => "added lines only",
+        }
+    }
 }
 
 /// Comprehensive information about a pull request's size and file changes.
@@ -272,7 +465,7 @@ impl SizeThresholds {
 /// # Examples
 ///
 /// ```
-/// use merge_warden_core::size::{PrSizeInfo, PrSizeCategory};
+/// use merge_warden_core::size::{PrSizeCategory, PrSizeInfo, SizeCountingMode};
 /// use merge_warden_developer_platforms::models::PullRequestFile;
 ///
 /// let files = vec![
@@ -289,17 +482,19 @@ impl SizeThresholds {
 ///     total_lines_changed: 20,
 ///     included_files: files,
 ///     excluded_files: vec![],
+///     exclusion_reasons: vec![],
 ///     size_category: PrSizeCategory::XS,
+///     counting_mode: SizeCountingMode::TotalChanges,
 /// };
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PrSizeInfo {
     /// Total lines counted for size categorisation, excluding filtered files.
     ///
-    /// When `ignore_deletions` is `false` (the default), this is the sum of
-    /// `additions + deletions` (`f.changes`) across all included files.
-    /// When `ignore_deletions` is `true`, this holds additions only (`f.additions`);
-    /// deleted lines and wholly-removed files contribute 0.
+    /// This is the sum of [`SizeCountingMode::counted_lines`] across all included
+    /// files: `additions + deletions` for `TotalChanges` (the default), the per-file
+    /// difference between them for `NetChanges`, and additions only for
+    /// `AdditionsOnly`.
     pub total_lines_changed: u32,
 
     /// List of files included in the size calculation
@@ -308,8 +503,14 @@ pub struct PrSizeInfo {
     /// List of files excluded from the size calculation (e.g., generated files)
     pub excluded_files: Vec<PullRequestFile>,
 
+    /// Why each file in `excluded_files` was excluded, in the same order.
+    pub exclusion_reasons: Vec<SizeExclusionReason>,
+
     /// The determined size category based on total lines changed
     pub size_category: PrSizeCategory,
+
+    /// How the lines of each file were counted towards the size.
+    pub counting_mode: SizeCountingMode,
 }
 
 impl PrSizeInfo {
@@ -323,12 +524,12 @@ impl PrSizeInfo {
     /// * `included_files` - Files to include in size calculation
     /// * `excluded_files` - Files excluded from size calculation
     /// * `thresholds` - Size category thresholds to use
-    /// * `ignore_deletions` - When `true`, count only additions; when `false`, count additions + deletions
+    /// * `counting_mode` - How the lines of each file are counted
     ///
     /// # Examples
     ///
     /// ```
-    /// use merge_warden_core::size::{PrSizeInfo, SizeThresholds};
+    /// use merge_warden_core::size::{PrSizeInfo, SizeCountingMode, SizeThresholds};
     /// use merge_warden_developer_platforms::models::PullRequestFile;
     ///
     /// let files = vec![
@@ -345,7 +546,7 @@ impl PrSizeInfo {
     ///     files,
     ///     vec![],
     ///     &SizeThresholds::default(),
-    ///     false,
+    ///     SizeCountingMode::TotalChanges,
     /// );
     ///
     /// assert_eq!(size_info.total_lines_changed, 15);
@@ -354,21 +555,24 @@ impl PrSizeInfo {
         included_files: Vec<PullRequestFile>,
         excluded_files: Vec<PullRequestFile>,
         thresholds: &SizeThresholds,
-        ignore_deletions: bool,
+        counting_mode: SizeCountingMode,
     ) -> Self {
-        let total_lines_changed: u32 = if ignore_deletions {
-            included_files.iter().map(|f| f.additions).sum()
-        } else {
-            included_files.iter().map(|f| f.changes).sum()
-        };
+        let total_lines_changed: u32 = included_files
+            .iter()
+            .map(|f| counting_mode.counted_lines(f))
+            .sum();
         let size_category =
             PrSizeCategory::from_line_count_with_thresholds(total_lines_changed, thresholds);
 
+        let exclusion_reasons = vec![SizeExclusionReason::Provided; excluded_files.len()];
+
         Self {
             total_lines_changed,
             included_files,
             excluded_files,
+            exclusion_reasons,
             size_category,
+            counting_mode,
         }
     }
 
@@ -382,12 +586,12 @@ impl PrSizeInfo {
     /// * `all_files` - All files changed in the pull request
     /// * `thresholds` - Size category thresholds to use
     /// * `exclusion_patterns` - Patterns for files to exclude from size calculation
-    /// * `ignore_deletions` - When `true`, count only additions; when `false`, count additions + deletions
+    /// * `counting_mode` - How the lines of each file are counted
     ///
     /// # Examples
     ///
     /// ```
-    /// use merge_warden_core::size::{PrSizeInfo, SizeThresholds};
+    /// use merge_warden_core::size::{PrSizeInfo, SizeCountingMode, SizeThresholds};
     /// use merge_warden_developer_platforms::models::PullRequestFile;
     ///
     /// let files = vec![
@@ -412,7 +616,7 @@ impl PrSizeInfo {
     ///     &files,
     ///     &SizeThresholds::default(),
     ///     &exclusion_patterns,
-    ///     false,
+    ///     SizeCountingMode::TotalChanges,
     /// );
     ///
     /// assert_eq!(size_info.total_lines_changed, 15); // Only src/lib.rs counted
@@ -423,11 +627,72 @@ impl PrSizeInfo {
         all_files: &[merge_warden_developer_platforms::models::PullRequestFile],
         thresholds: &SizeThresholds,
         exclusion_patterns: &[String],
-        ignore_deletions: bool,
+        counting_mode: SizeCountingMode,
     ) -> Self {
-        let (included_files, excluded_files) =
-            filter_files_by_patterns(all_files, exclusion_patterns);
-        Self::new(included_files, excluded_files, thresholds, ignore_deletions)
+        let (included_files, excluded) = filter_files_by_patterns(all_files, exclusion_patterns);
+        let (excluded_files, exclusion_reasons) = excluded.into_iter().unzip();
+        Self {
+            exclusion_reasons,
+            ..Self::new(included_files, excluded_files, thresholds, counting_mode)
+        }
+    }
+
+    /// Categorise a set of changed files using a size-check configuration.
+    ///
+    /// Applies the configuration's thresholds, exclusion patterns and counting mode
+    /// exactly as Merge Warden does when it checks a PR, so the result can be used to
+    /// preview the size label and comment without a provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - All files changed in the pull request
+    /// * `config` - The size-check configuration to apply
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::config::PrSizeCheckConfig;
+    /// use merge_warden_core::labels::generate_oversized_pr_comment;
+    /// use merge_warden_core::size::{PrSizeCategory, PrSizeInfo, SizeThresholds};
+    /// use merge_warden_developer_platforms::models::PullRequestFile;
+    ///
+    /// let files = vec![
+    ///     PullRequestFile {
+    ///         filename: "src/lib.rs".to_string(),
+    ///         additions: 180,
+    ///         deletions: 20,
+    ///         changes: 200,
+    ///         status: "modified".to_string(),
+    ///     },
+    ///     PullRequestFile {
+    ///         filename: "Cargo.lock".to_string(),
+    ///         additions: 900,
+    ///         deletions: 0,
+    ///         changes: 900,
+    ///         status: "modified".to_string(),
+    ///     },
+    /// ];
+    /// let config = PrSizeCheckConfig {
+    ///     thresholds: Some(SizeThresholds::new(10, 25, 50, 100, 150)),
+    ///     excluded_file_patterns: vec!["*.lock".to_string()],
+    ///     ..PrSizeCheckConfig::default()
+    /// };
+    ///
+    /// let size_info = PrSizeInfo::categorize(&files, &config);
+    /// assert_eq!(size_info.total_lines_changed, 200);
+    /// assert_eq!(size_info.size_category, PrSizeCategory::XXL);
+    /// assert_eq!(size_info.excluded_file_count(), 1);
+    ///
+    /// let comment = generate_oversized_pr_comment(&size_info);
+    /// assert!(comment.contains("200 lines"));
+    /// ```
+    pub fn categorize(files: &[PullRequestFile], config: &PrSizeCheckConfig) -> Self {
+        Self::from_files_with_exclusions(
+            files,
+            &config.get_effective_thresholds(),
+            &config.effective_excluded_file_patterns(),
+            config.effective_counting_mode(),
+        )
     }
 
     /// Check if this PR is considered oversized based on its category.
@@ -435,13 +700,15 @@ impl PrSizeInfo {
     /// # Examples
     ///
     /// ```
-    /// use merge_warden_core::size::{PrSizeInfo, PrSizeCategory, SizeThresholds};
+    /// use merge_warden_core::size::{PrSizeCategory, PrSizeInfo, SizeCountingMode};
     ///
     /// let large_size_info = PrSizeInfo {
     ///     total_lines_changed: 600,
     ///     included_files: vec![],
     ///     excluded_files: vec![],
+    ///     exclusion_reasons: vec![],
     ///     size_category: PrSizeCategory::XXL,
+    ///     counting_mode: SizeCountingMode::TotalChanges,
     /// };
     ///
     /// assert!(large_size_info.is_oversized());
@@ -450,12 +717,37 @@ impl PrSizeInfo {
         self.size_category.is_oversized()
     }
 
+    /// Check if the PR contains any files that count towards its size.
+    ///
+    /// Returns `false` when the PR has no changed files at all, or when every
+    /// changed file matched an exclusion pattern. Such a PR counts zero lines, so it is
+    /// categorised as XS and is never oversized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::{PrSizeCategory, PrSizeInfo, SizeCountingMode, SizeThresholds};
+    ///
+    /// let empty = PrSizeInfo::from_files_with_exclusions(
+    ///     &[],
+    ///     &SizeThresholds::default(),
+    ///     &[],
+    ///     SizeCountingMode::TotalChanges,
+    /// );
+    ///
+    /// assert!(!empty.has_reviewable_changes());
+    /// assert_eq!(empty.size_category, PrSizeCategory::XS);
+    /// ```
+    pub fn has_reviewable_changes(&self) -> bool {
+        !self.included_files.is_empty()
+    }
+
     /// Get the number of files included in the size calculation.
     ///
     /// # Examples
     ///
     /// ```
-    /// use merge_warden_core::size::PrSizeInfo;
+    /// use merge_warden_core::size::{PrSizeInfo, SizeCountingMode};
     /// use merge_warden_developer_platforms::models::PullRequestFile;
     ///
     /// let files = vec![
@@ -479,7 +771,7 @@ impl PrSizeInfo {
     ///     files,
     ///     vec![],
     ///     &merge_warden_core::size::SizeThresholds::default(),
-    ///     false,
+    ///     SizeCountingMode::TotalChanges,
     /// );
     ///
     /// assert_eq!(size_info.included_file_count(), 2);
@@ -493,7 +785,7 @@ impl PrSizeInfo {
     /// # Examples
     ///
     /// ```
-    /// use merge_warden_core::size::PrSizeInfo;
+    /// use merge_warden_core::size::{PrSizeInfo, SizeCountingMode};
     /// use merge_warden_developer_platforms::models::PullRequestFile;
     ///
     /// let excluded = vec![
@@ -510,7 +802,7 @@ impl PrSizeInfo {
     ///     vec![],
     ///     excluded,
     ///     &merge_warden_core::size::SizeThresholds::default(),
-    ///     false,
+    ///     SizeCountingMode::TotalChanges,
     /// );
     ///
     /// assert_eq!(size_info.excluded_file_count(), 1);
@@ -518,12 +810,1074 @@ impl PrSizeInfo {
     pub fn excluded_file_count(&self) -> usize {
         self.excluded_files.len()
     }
+
+    /// Returns the number of lines `file` contributes to the size.
+    ///
+    /// See [`SizeCountingMode::counted_lines`].
+    pub fn counted_lines(&self, file: &PullRequestFile) -> u32 {
+        self.counting_mode.counted_lines(file)
+    }
+
+    /// Returns the per-file size breakdown of the pull request.
+    ///
+    /// The breakdown lists every file with its line counts, in the order the files were
+    /// given, and is intended for serialization by consumers that present the size
+    /// themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::{PrSizeInfo, SizeCountingMode, SizeExclusionReason, SizeThresholds};
+    /// use merge_warden_developer_platforms::models::PullRequestFile;
+    ///
+    /// let files = vec![
+    ///     PullRequestFile {
+    ///         filename: "src/lib.rs".to_string(),
+    ///         additions: 30,
+    ///         deletions: 10,
+    ///         changes: 40,
+    ///         status: "modified".to_string(),
+    ///     },
+    ///     PullRequestFile {
+    ///         filename: "Cargo.lock".to_string(),
+    ///         additions: 200,
+    ///         deletions: 0,
+    ///         changes: 200,
+    ///         status: "modified".to_string(),
+    ///     },
+    /// ];
+    /// let size_info = PrSizeInfo::from_files_with_exclusions(
+    ///     &files,
+    ///     &SizeThresholds::default(),
+    ///     &["*.lock".to_string()],
+    ///     SizeCountingMode::TotalChanges,
+    /// );
+    ///
+    /// let breakdown = size_info.breakdown();
+    /// assert_eq!(breakdown.included_files[0].counted_lines, 40);
+    /// assert_eq!(
+    ///     breakdown.excluded_files[0].reason,
+    ///     SizeExclusionReason::MatchedPattern {
+    ///         pattern: "*.lock".to_string()
+    ///     }
+    /// );
+    ///
+    /// let json = serde_json::to_value(&breakdown).unwrap();
+    /// assert_eq!(json["size_category"], "S");
+    /// assert_eq!(json["excluded_files"][0]["reason"]["kind"], "matched_pattern");
+    /// ```
+    pub fn breakdown(&self) -> PrSizeBreakdown {
+        PrSizeBreakdown {
+            size_category: self.size_category,
+            total_lines_changed: self.total_lines_changed,
+            ignore_deletions: self.counting_mode == SizeCountingMode::AdditionsOnly,
+            counting_mode: self.counting_mode,
+            included_files: self
+                .included_files
+                .iter()
+                .map(|file| IncludedFileSize {
+                    filename: file.filename.clone(),
+                    status: file.status.clone(),
+                    additions: file.additions,
+                    deletions: file.deletions,
+                    counted_lines: self.counted_lines(file),
+                })
+                .collect(),
+            excluded_files: self
+                .excluded_files
+                .iter()
+                .enumerate()
+                .map(|(index, file)| ExcludedFileSize {
+                    filename: file.filename.clone(),
+                    status: file.status.clone(),
+                    additions: file.additions,
+                    deletions: file.deletions,
+                    reason: self
+                        .exclusion_reasons
+                        .get(index)
+                        .cloned()
+                        .unwrap_or(SizeExclusionReason::Provided),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the counted files with the most lines, largest first.
+    ///
+    /// Files that contribute no lines are left out, and files with the same count are
+    /// ordered by path. At most `limit` entries are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::{PrSizeInfo, SizeCountingMode, SizeThresholds};
+    /// use merge_warden_developer_platforms::models::PullRequestFile;
+    ///
+    /// let file = |filename: &str, changes: u32| PullRequestFile {
+    ///     filename: filename.to_string(),
+    ///     additions: changes,
+    ///     deletions: 0,
+    ///     changes,
+    ///     status: "modified".to_string(),
+    /// };
+    /// let size_info = PrSizeInfo::new(
+    ///     vec![file("src/a.rs", 10), file("src/b.rs", 300), file("README.md", 40)],
+    ///     vec![],
+    ///     &SizeThresholds::default(),
+    ///     SizeCountingMode::TotalChanges,
+    /// );
+    ///
+    /// let largest = size_info.largest_files(2);
+    /// assert_eq!(largest.len(), 2);
+    /// assert_eq!(largest[0].path, "src/b.rs");
+    /// assert_eq!(largest[1].counted_lines, 40);
+    /// ```
+    pub fn largest_files(&self, limit: usize) -> Vec<SizeBreakdownEntry> {
+        let entries = self
+            .included_files
+            .iter()
+            .map(|file| SizeBreakdownEntry {
+                path: file.filename.clone(),
+                counted_lines: self.counted_lines(file),
+                file_count: 1,
+            })
+            .collect();
+        largest_entries(entries, limit)
+    }
+
+    /// Returns the top-level directories with the most counted lines, largest first.
+    ///
+    /// Each entry sums the counted lines of the files under one top-level directory,
+    /// e.g. `src/`. Files at the repository root are grouped under `/`. Directories
+    /// that contribute no lines are left out, and directories with the same count are
+    /// ordered by path. At most `limit` entries are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::{PrSizeInfo, SizeCountingMode, SizeThresholds};
+    /// use merge_warden_developer_platforms::models::PullRequestFile;
+    ///
+    /// let file = |filename: &str, changes: u32| PullRequestFile {
+    ///     filename: filename.to_string(),
+    ///     additions: changes,
+    ///     deletions: 0,
+    ///     changes,
+    ///     status: "modified".to_string(),
+    /// };
+    /// let size_info = PrSizeInfo::new(
+    ///     vec![file("src/a.rs", 10), file("src/api/b.rs", 300), file("README.md", 40)],
+    ///     vec![],
+    ///     &SizeThresholds::default(),
+    ///     SizeCountingMode::TotalChanges,
+    /// );
+    ///
+    /// let directories = size_info.largest_directories(5);
+    /// assert_eq!(directories[0].path, "src/");
+    /// assert_eq!(directories[0].counted_lines, 310);
+    /// assert_eq!(directories[0].file_count, 2);
+    /// assert_eq!(directories[1].path, "/");
+    /// ```
+    pub fn largest_directories(&self, limit: usize) -> Vec<SizeBreakdownEntry> {
+        let mut directories: HashMap<String, SizeBreakdownEntry> = HashMap::new();
+        for file in &self.included_files {
+            let path = match file.filename.split_once('/') {
+                Some((directory, _)) => format!("{}/", directory),
+                None => "/".to_string(),
+            };
+            let entry = directories
+                .entry(path.clone())
+                .or_insert_with(|| SizeBreakdownEntry {
+                    path,
+                    counted_lines: 0,
+                    file_count: 0,
+                });
+            entry.counted_lines += self.counted_lines(file);
+            entry.file_count += 1;
+        }
+        largest_entries(directories.into_values().collect(), limit)
+    }
+
+    /// Estimates the effort needed to review the pull request.
+    ///
+    /// Only the files counted towards the size are considered. Each factor is scored
+    /// from 1 to 5:
+    ///
+    /// - lines: from the size category, 1 for XS up to 5 for XL and XXL
+    /// - files: 1 for one file, 2 for up to 3, 3 for up to 8, 4 for up to 15, 5 above
+    /// - languages: the number of languages, recognized by file extension, up to 5
+    /// - tests: 1 when a test file changed and 5 when none did
+    ///
+    /// The score is the weighted average of the factor scores, rounded half up. It is
+    /// 1 when all weights are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::{PrSizeInfo, ReviewEffortWeights, SizeCountingMode, SizeThresholds};
+    /// use merge_warden_developer_platforms::models::PullRequestFile;
+    ///
+    /// let file = |name: &str, changes: u32| PullRequestFile {
+    ///     filename: name.to_string(),
+    ///     additions: changes,
+    ///     deletions: 0,
+    ///     changes,
+    ///     status: "modified".to_string(),
+    /// };
+    /// let size_info = PrSizeInfo::new(
+    ///     vec![file("src/lib.rs", 40), file("tests/lib_tests.rs", 20)],
+    ///     vec![],
+    ///     &SizeThresholds::default(),
+    ///     SizeCountingMode::TotalChanges,
+    /// );
+    ///
+    /// let effort = size_info.review_effort(&ReviewEffortWeights::default());
+    /// assert_eq!(effort.score, 2);
+    /// assert_eq!(effort.factors.len(), 4);
+    /// ```
+    pub fn review_effort(&self, weights: &ReviewEffortWeights) -> ReviewEffort {
+        let file_count = self.included_files.len();
+        let mut languages: Vec<&str> = self
+            .included_files
+            .iter()
+            .filter_map(|file| file_language(&file.filename))
+            .collect();
+        languages.sort_unstable();
+        languages.dedup();
+        let has_tests = self
+            .included_files
+            .iter()
+            .any(|file| is_test_file(&file.filename));
+
+        let candidates = [
+            (
+                ReviewEffortFactorKind::Lines,
+                weights.lines,
+                size_category_score(self.size_category),
+                format!(
+                    "{} line{} changed",
+                    self.total_lines_changed,
+                    if self.total_lines_changed == 1 {
+                        ""
+                    } else {
+                        "s"
+                    }
+                ),
+            ),
+            (
+                ReviewEffortFactorKind::Files,
+                weights.files,
+                file_count_score(file_count),
+                format!(
+                    "{} file{} touched",
+                    file_count,
+                    if file_count == 1 { "" } else { "s" }
+                ),
+            ),
+            (
+                ReviewEffortFactorKind::Languages,
+                weights.languages,
+                languages.len().clamp(1, ReviewEffort::MAX_SCORE as usize) as u8,
+                if languages.is_empty() {
+                    "no recognized languages".to_string()
+                } else {
+                    format!(
+                        "{} language{} ({})",
+                        languages.len(),
+                        if languages.len() == 1 { "" } else { "s" },
+                        languages.join(", ")
+                    )
+                },
+            ),
+            (
+                ReviewEffortFactorKind::Tests,
+                weights.tests,
+                if has_tests {
+                    ReviewEffort::MIN_SCORE
+                } else {
+                    ReviewEffort::MAX_SCORE
+                },
+                if has_tests {
+                    "includes test changes".to_string()
+                } else {
+                    "no test changes".to_string()
+                },
+            ),
+        ];
+
+        let factors: Vec<ReviewEffortFactor> = candidates
+            .into_iter()
+            .filter(|(_, weight, _, _)| *weight > 0)
+            .map(|(kind, weight, score, reason)| ReviewEffortFactor {
+                kind,
+                score,
+                weight,
+                reason,
+            })
+            .collect();
+
+        let total_weight: u64 = factors.iter().map(|f| u64::from(f.weight)).sum();
+        let score = if total_weight == 0 {
+            ReviewEffort::MIN_SCORE
+        } else {
+            let weighted: u64 = factors
+                .iter()
+                .map(|f| u64::from(f.weight) * u64::from(f.score))
+                .sum();
+            // Rounds the weighted average half up.
+            ((2 * weighted + total_weight) / (2 * total_weight)) as u8
+        };
+
+        ReviewEffort {
+            score: score.clamp(ReviewEffort::MIN_SCORE, ReviewEffort::MAX_SCORE),
+            factors,
+        }
+    }
+}
+
+/// Why a file was left out of the PR size.
+///
+/// Serialized with a `kind` tag, e.g. `{"kind": "matched_pattern", "pattern": "*.lock"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SizeExclusionReason {
+    /// The file matched one of the exclusion patterns: the built-in
+    /// [`default_excluded_file_patterns`], a preset's patterns or the
+    /// `excluded_file_patterns`.
+    MatchedPattern {
+        /// The pattern that excluded the file, as configured.
+        pattern: String,
+    },
+
+    /// The caller passed the file as excluded, see [`PrSizeInfo::new`].
+    Provided,
+}
+
+/// A file counted towards the PR size.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IncludedFileSize {
+    /// The file path relative to the repository root.
+    pub filename: String,
+
+    /// The file status reported by the platform, e.g. `"modified"`.
+    pub status: String,
+
+    /// Number of lines added to the file.
+    pub additions: u32,
+
+    /// Number of lines deleted from the file.
+    pub deletions: u32,
+
+    /// Number of lines the file contributes to the size.
+    pub counted_lines: u32,
+}
+
+/// A file left out of the PR size.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExcludedFileSize {
+    /// The file path relative to the repository root.
+    pub filename: String,
+
+    /// The file status reported by the platform, e.g. `"modified"`.
+    pub status: String,
+
+    /// Number of lines added to the file.
+    pub additions: u32,
+
+    /// Number of lines deleted from the file.
+    pub deletions: u32,
+
+    /// Why the file was excluded.
+    pub reason: SizeExclusionReason,
+}
+
+/// The per-file size breakdown of a pull request, returned by [`PrSizeInfo::breakdown`].
+///
+/// The shape is stable: fields are only added, never renamed or removed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrSizeBreakdown {
+    /// The size category of the pull request.
+    pub size_category: PrSizeCategory,
+
+    /// The total number of lines counted towards the size.
+    pub total_lines_changed: u32,
+
+    /// Whether only additions were counted towards the size.
+    ///
+    /// `true` exactly when `counting_mode` is `AdditionsOnly`.
+    pub ignore_deletions: bool,
+
+    /// How the lines of each file were counted towards the size.
+    #[serde(default)]
+    pub counting_mode: SizeCountingMode,
+
+    /// The files counted towards the size.
+    pub included_files: Vec<IncludedFileSize>,
+
+    /// The files left out of the size.
+    pub excluded_files: Vec<ExcludedFileSize>,
+}
+
+/// The lines a file or directory contributes to the PR size, returned by
+/// [`PrSizeInfo::largest_files`] and [`PrSizeInfo::largest_directories`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeBreakdownEntry {
+    /// The file path, or the directory path ending in `/`; `/` is the repository root.
+    pub path: String,
+
+    /// Number of lines counted towards the size.
+    pub counted_lines: u32,
+
+    /// Number of counted files; 1 for a file.
+    pub file_count: usize,
+}
+
+/// The number of rows of each size breakdown table in the oversized PR comment, unless
+/// `PrSizeCheckConfig::comment_breakdown_rows` is set.
+pub const DEFAULT_COMMENT_BREAKDOWN_ROWS: usize = 5;
+
+/// Sorts `entries` by counted lines, largest first and then by path, and keeps the
+/// first `limit` entries that contribute lines.
+fn largest_entries(mut entries: Vec<SizeBreakdownEntry>, limit: usize) -> Vec<SizeBreakdownEntry> {
+    entries.retain(|entry| entry.counted_lines > 0);
+    entries.sort_by(|a, b| {
+        b.counted_lines
+            .cmp(&a.counted_lines)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    entries.truncate(limit);
+    entries
+}
+
+/// The weight of each factor of the review effort score.
+///
+/// The score is the weighted average of the factor scores, so only the ratio between
+/// the weights matters. A factor with a weight of zero is left out of the score.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::size::ReviewEffortWeights;
+///
+/// let weights = ReviewEffortWeights::default();
+/// assert_eq!(weights.lines, 3);
+/// assert_eq!(weights.tests, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewEffortWeights {
+    /// Weight of the number of lines changed (default: 3)
+    #[serde(default = "ReviewEffortWeights::default_lines")]
+    pub lines: u32,
+
+    /// Weight of the number of files touched (default: 2)
+    #[serde(default = "ReviewEffortWeights::default_files")]
+    pub files: u32,
+
+    /// Weight of the number of languages changed (default: 1)
+    #[serde(default = "ReviewEffortWeights::default_languages")]
+    pub languages: u32,
+
+    /// Weight of whether the PR changes tests (default: 1)
+    #[serde(default = "ReviewEffortWeights::default_tests")]
+    pub tests: u32,
+}
+
+impl ReviewEffortWeights {
+    /// Default weight of the lines changed (3)
+    fn default_lines() -> u32 {
+        3
+    }
+
+    /// Default weight of the files touched (2)
+    fn default_files() -> u32 {
+        2
+    }
+
+    /// Default weight of the languages changed (1)
+    fn default_languages() -> u32 {
+        1
+    }
+
+    /// Default weight of the test changes (1)
+    fn default_tests() -> u32 {
+        1
+    }
+}
+
+impl Default for ReviewEffortWeights {
+    fn default() -> Self {
+        Self {
+            lines: Self::default_lines(),
+            files: Self::default_files(),
+            languages: Self::default_languages(),
+            tests: Self::default_tests(),
+        }
+    }
+}
+
+/// A factor that contributes to the review effort score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewEffortFactorKind {
+    /// The number of lines counted towards the PR size.
+    Lines,
+
+    /// The number of files counted towards the PR size.
+    Files,
+
+    /// The number of programming languages among the counted files.
+    Languages,
+
+    /// Whether any counted file is a test.
+    Tests,
+}
+
+/// The score of one factor of the review effort, with the reason for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewEffortFactor {
+    /// The factor that was scored.
+    pub kind: ReviewEffortFactorKind,
+
+    /// The score of the factor, from 1 (least effort) to 5 (most effort).
+    pub score: u8,
+
+    /// The configured weight of the factor.
+    pub weight: u32,
+
+    /// Why the factor got its score, e.g. `"120 lines changed"`.
+    pub reason: String,
+}
+
+/// An estimate of the effort needed to review a pull request, returned by
+/// [`PrSizeInfo::review_effort`].
+///
+/// The score runs from 1 (trivial to review) to 5 (needs a lot of review effort).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewEffort {
+    /// The overall score, from 1 to 5.
+    pub score: u8,
+
+    /// The factors that make up the score, leaving out factors with a weight of zero.
+    pub factors: Vec<ReviewEffortFactor>,
+}
+
+impl ReviewEffort {
+    /// Lowest review effort score.
+    pub const MIN_SCORE: u8 = 1;
+
+    /// Highest review effort score.
+    pub const MAX_SCORE: u8 = 5;
+
+    /// Returns the reasons for the score as one sentence, e.g.
+    /// `"120 lines changed (3/5), 4 files touched (3/5)"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::{PrSizeInfo, ReviewEffortWeights, SizeCountingMode, SizeThresholds};
+    /// use merge_warden_developer_platforms::models::PullRequestFile;
+    ///
+    /// let files = vec![PullRequestFile {
+    ///     filename: "src/lib.rs".to_string(),
+    ///     additions: 8,
+    ///     deletions: 0,
+    ///     changes: 8,
+    ///     status: "modified".to_string(),
+    /// }];
+    /// let size_info = PrSizeInfo::new(
+    ///     files,
+    ///     vec![],
+    ///     &SizeThresholds::default(),
+    ///     SizeCountingMode::TotalChanges,
+    /// );
+    /// let weights = ReviewEffortWeights {
+    ///     languages: 0,
+    ///     tests: 0,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     size_info.review_effort(&weights).explanation(),
+    ///     "8 lines changed (1/5), 1 file touched (1/5)"
+    /// );
+    /// ```
+    pub fn explanation(&self) -> String {
+        self.factors
+            .iter()
+            .map(|factor| format!("{} ({}/{})", factor.reason, factor.score, Self::MAX_SCORE))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Returns the language of a file from its extension, or `None` for files whose
+/// extension is not a known programming or markup language.
+fn file_language(path: &str) -> Option<&'static str> {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let (stem, extension) = file_name.rsplit_once('.')?;
+    if stem.is_empty() {
+        return None;
+    }
+
+    let language = match extension.to_ascii_lowercase().as_str() {
+        "rs" => "Rust",
+        "go" => "Go",
+        "py" => "Python",
+        "rb" => "Ruby",
+        "java" => "Java",
+        "kt" | "kts" => "Kotlin",
+        "scala" => "Scala",
+        "swift" => "Swift",
+        "cs" => "C#",
+        "fs" => "F#",
+        "c" | "h" => "C",
+        "cc" | "cpp" | "cxx" | "hpp" => "C++",
+        "js" | "jsx" | "mjs" | "cjs" => "JavaScript",
+        "ts" | "tsx" => "TypeScript",
+        "php" => "PHP",
+        "sh" | "bash" => "Shell",
+        "ps1" => "PowerShell",
+        "sql" => "SQL",
+        "html" | "htm" => "HTML",
+        "css" | "scss" | "sass" | "less" => "CSS",
+        "md" => "Markdown",
+        "toml" => "TOML",
+        "yml" | "yaml" => "YAML",
+        "json" => "JSON",
+        "xml" => "XML",
+        "tf" => "Terraform",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// Returns `true` if the path is a test file, either because it is in a test
+/// directory or because its name marks it as a test.
+fn is_test_file(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    let mut segments: Vec<&str> = lower.split('/').collect();
+    let file_name = segments.pop().unwrap_or_default();
+    if segments
+        .iter()
+        .any(|segment| matches!(*segment, "test" | "tests" | "__tests__" | "spec" | "specs"))
+    {
+        return true;
+    }
+
+    let stem = file_name.split('.').next().unwrap_or_default();
+    stem.starts_with("test_")
+        || stem.ends_with("_test")
+        || stem.ends_with("_tests")
+        || stem.ends_with("_spec")
+        || file_name.contains(".test.")
+        || file_name.contains(".spec.")
+}
+
+/// Scores the number of files touched from 1 to 5.
+fn file_count_score(count: usize) -> u8 {
+    match count {
+        0..=1 => 1,
+        2..=3 => 2,
+        4..=8 => 3,
+        9..=15 => 4,
+        _ => 5,
+    }
+}
+
+/// Scores the size category from 1 to 5, counting XL and XXL as the maximum.
+fn size_category_score(category: PrSizeCategory) -> u8 {
+    match category {
+        PrSizeCategory::XS => 1,
+        PrSizeCategory::S => 2,
+        PrSizeCategory::M => 3,
+        PrSizeCategory::L => 4,
+        PrSizeCategory::XL | PrSizeCategory::XXL => 5,
+    }
+}
+
+/// Lockfiles written by package managers.
+const LOCKFILE_PATTERNS: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "poetry.lock",
+    "Pipfile.lock",
+    "Gemfile.lock",
+    "composer.lock",
+    "go.sum",
+];
+
+/// Directories holding third-party code checked into the repository.
+const VENDORED_PATTERNS: &[&str] = &["node_modules/", "vendor/", "third_party/"];
+
+/// Minified assets and their source maps.
+const MINIFIED_PATTERNS: &[&str] = &["*.min.js", "*.min.css", "*.min.js.map", "*.min.css.map"];
+
+/// Test snapshot files.
+const SNAPSHOT_PATTERNS: &[&str] = &["*.snap", "__snapshots__/"];
+
+/// Generated files that no preset covers: other lockfiles, generated sources and build
+/// output.
+const GENERATED_PATTERNS: &[&str] = &["*.lock", "*.generated.*", "dist/"];
+
+/// Returns the commonly generated and vendored files left out of the PR size unless
+/// `PrSizeCheckConfig::use_default_exclusions` is disabled.
+///
+/// These are `*.lock`, `*.generated.*` and `dist/`, followed by the patterns of the
+/// [`FileExclusionPreset::Lockfiles`], [`FileExclusionPreset::Vendored`] and
+/// [`FileExclusionPreset::Minified`] presets. The patterns come before the presets and
+/// the custom `excluded_file_patterns`, so a custom `!` pattern re-includes a file that
+/// one of them excludes.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::size::{default_excluded_file_patterns, FileExclusionPreset};
+///
+/// let defaults = default_excluded_file_patterns();
+/// assert!(defaults.contains(&"dist/"));
+/// assert!(FileExclusionPreset::Lockfiles
+///     .patterns()
+///     .iter()
+///     .all(|pattern| defaults.contains(pattern)));
+/// ```
+pub fn default_excluded_file_patterns() -> Vec<&'static str> {
+    [
+        GENERATED_PATTERNS,
+        LOCKFILE_PATTERNS,
+        VENDORED_PATTERNS,
+        MINIFIED_PATTERNS,
+    ]
+    .concat()
+}
+
+/// A named set of file exclusion patterns for commonly generated or vendored files.
+///
+/// Presets are enabled through `PrSizeCheckConfig::excluded_file_presets` and expand to
+/// the patterns returned by [`FileExclusionPreset::patterns`]. The expanded patterns come
+/// before the custom `excluded_file_patterns`, so a custom `!` pattern re-includes a
+/// file that a preset excludes.
+///
+/// Serialized in kebab case, e.g. `"common-generated"`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::size::{FileExclusionPreset, FileExclusions};
+///
+/// let patterns: Vec<String> = FileExclusionPreset::Vendored
+///     .patterns()
+///     .into_iter()
+///     .map(str::to_string)
+///     .collect();
+/// let exclusions = FileExclusions::new(&patterns);
+///
+/// assert!(exclusions.is_excluded("web/node_modules/react/index.js"));
+/// assert!(!exclusions.is_excluded("src/main.rs"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FileExclusionPreset {
+    /// Package manager lockfiles: `Cargo.lock`, `package-lock.json`,
+    /// `npm-shrinkwrap.json`, `yarn.lock`, `pnpm-lock.yaml`, `poetry.lock`,
+    /// `Pipfile.lock`, `Gemfile.lock`, `composer.lock` and `go.sum`.
+    Lockfiles,
+
+    /// Vendored dependency directories: `node_modules/`, `vendor/` and `third_party/`.
+    Vendored,
+
+    /// Minified assets: `*.min.js`, `*.min.css`, `*.min.js.map` and `*.min.css.map`.
+    Minified,
+
+    /// Test snapshots: `*.snap` and `__snapshots__/`.
+    Snapshots,
+
+    /// All of `lockfiles`, `vendored`, `minified` and `snapshots`.
+    CommonGenerated,
+}
+
+impl FileExclusionPreset {
+    /// Returns the gitignore-style patterns the preset expands to.
+    pub fn patterns(self) -> Vec<&'static str> {
+        match self {
+            FileExclusionPreset::Lockfiles => LOCKFILE_PATTERNS.to_vec(),
+            FileExclusionPreset::Vendored => VENDORED_PATTERNS.to_vec(),
+            FileExclusionPreset::Minified => MINIFIED_PATTERNS.to_vec(),
+            FileExclusionPreset::Snapshots => SNAPSHOT_PATTERNS.to_vec(),
+            FileExclusionPreset::CommonGenerated => [
+                LOCKFILE_PATTERNS,
+                VENDORED_PATTERNS,
+                MINIFIED_PATTERNS,
+                SNAPSHOT_PATTERNS,
+            ]
+            .concat(),
+        }
+    }
+}
+
+/// File exclusion patterns compiled with gitignore semantics.
+///
+/// Patterns follow the rules of a `.gitignore` file, applied to repository-relative
+/// paths:
+///
+/// * A pattern without a `/` matches at any depth: `*.md` excludes `README.md` and
+///   `docs/guide/setup.md`.
+/// * A leading `/`, or a `/` in the middle of the pattern, anchors it to the repository
+///   root: `/CHANGELOG.md` only excludes the root changelog, and `docs/*` only matches
+///   under the root `docs` directory.
+/// * A trailing `/` matches a directory, which excludes every file below it: `vendor/`.
+/// * `**` matches any number of directories: `**/generated/**`.
+/// * A leading `!` negates the pattern, re-including files that an earlier pattern
+///   excluded.
+///
+/// When several patterns match a file, the **last** matching pattern wins, so a negation
+/// must come after the pattern it overrides. A pattern that matches the file itself takes
+/// precedence over one that matches a parent directory; unlike git, a negation can
+/// therefore re-include a file inside an excluded directory
+/// (`["vendor/", "!vendor/patched.rs"]`).
+///
+/// Invalid patterns are logged and ignored.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::size::FileExclusions;
+///
+/// let exclusions = FileExclusions::new(&[
+///     "*.lock".to_string(),
+///     "/docs/".to_string(),
+///     "!docs/api.md".to_string(),
+/// ]);
+///
+/// assert!(exclusions.is_excluded("Cargo.lock"));
+/// assert!(exclusions.is_excluded("web/yarn.lock"));
+/// assert!(exclusions.is_excluded("docs/guide/setup.md"));
+/// assert!(!exclusions.is_excluded("docs/api.md"));
+/// assert!(!exclusions.is_excluded("src/docs/notes.md"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileExclusions {
+    /// The compiled exclusion patterns
+    matcher: Gitignore,
+}
+
+impl FileExclusions {
+    /// Compiles the given patterns, in order.
+    pub fn new(patterns: &[String]) -> Self {
+        let mut builder = GitignoreBuilder::new("");
+        for pattern in patterns {
+            if let Err(e) = builder.add_line(None, pattern) {
+                warn!(
+                    pattern = pattern.as_str(),
+                    error = %e,
+                    "Ignoring invalid file exclusion pattern"
+                );
+            }
+        }
+
+        let matcher = builder.build().unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to compile file exclusion patterns; excluding no files");
+            Gitignore::empty()
+        });
+        Self { matcher }
+    }
+
+    /// Checks that `pattern` is a valid gitignore-style pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem when the pattern cannot be compiled,
+    /// for example because an alternate group is not closed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::FileExclusions;
+    ///
+    /// assert!(FileExclusions::validate_pattern("docs/**/*.md").is_ok());
+    /// assert!(FileExclusions::validate_pattern("src/{a,b.rs").is_err());
+    /// ```
+    pub fn validate_pattern(pattern: &str) -> Result<(), String> {
+        GitignoreBuilder::new("")
+            .add_line(None, pattern)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Returns `true` if the repository-relative `path` is excluded.
+    pub fn is_excluded(&self, path: &str) -> bool {
+        self.excluding_pattern(path).is_some()
+    }
+
+    /// Returns the pattern that excludes the repository-relative `path`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::size::FileExclusions;
+    ///
+    /// let exclusions = FileExclusions::new(&["*.lock".to_string(), "vendor/".to_string()]);
+    ///
+    /// assert_eq!(exclusions.excluding_pattern("vendor/lib/a.rs"), Some("vendor/"));
+    /// assert_eq!(exclusions.excluding_pattern("src/main.rs"), None);
+    /// ```
+    pub fn excluding_pattern(&self, path: &str) -> Option<&str> {
+        let path = path.trim_start_matches('/');
+        if path.is_empty() {
+            return None;
+        }
+
+        match self.matcher.matched_path_or_any_parents(path, false) {
+            Match::Ignore(glob) => Some(glob.original()),
+            Match::None | Match::Whitelist(_) => None,
+        }
+    }
+}
+
+/// The changed lines of a file's diff that only change whitespace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WhitespaceOnlyChanges {
+    /// Added lines that only change whitespace
+    pub additions: u32,
+
+    /// Deleted lines that only change whitespace
+    pub deletions: u32,
+}
+
+/// Counts the changed lines of a unified diff that only change whitespace.
+///
+/// Within each run of changed lines, an added line that equals a deleted line once all
+/// whitespace is removed is a re-indented or re-spaced line, and both count as
+/// whitespace-only. Added and deleted blank lines are whitespace-only too. This matches
+/// what `git diff -w` leaves out. A line that moved to another part of the file is a
+/// real change.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::size::count_whitespace_only_changes;
+///
+/// let patch = "@@ -1,4 +1,5 @@\n fn main() {\n-println!(\"hi\");\n-let x=1;\n+    println!(\"hi\");\n+    let x = 2;\n+\n }";
+///
+/// let changes = count_whitespace_only_changes(patch);
+/// // The re-indented `println!` and the new blank line; `x = 2` is a real change
+/// assert_eq!(changes.additions, 2);
+/// assert_eq!(changes.deletions, 1);
+/// ```
+pub fn count_whitespace_only_changes(patch: &str) -> WhitespaceOnlyChanges {
+    fn strip_whitespace(line: &str) -> String {
+        line.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    fn count_run(
+        deleted: &mut Vec<String>,
+        added: &mut Vec<String>,
+        changes: &mut WhitespaceOnlyChanges,
+    ) {
+        let mut unmatched: HashMap<&str, u32> = HashMap::new();
+        for line in deleted.iter() {
+            if line.is_empty() {
+                changes.deletions += 1;
+            } else {
+                *unmatched.entry(line.as_str()).or_default() += 1;
+            }
+        }
+        for line in added.iter() {
+            if line.is_empty() {
+                changes.additions += 1;
+            } else if let Some(count) = unmatched.get_mut(line.as_str()).filter(|c| **c > 0) {
+                *count -= 1;
+                changes.additions += 1;
+                changes.deletions += 1;
+            }
+        }
+        deleted.clear();
+        added.clear();
+    }
+
+    let mut changes = WhitespaceOnlyChanges::default();
+    let mut deleted = Vec::new();
+    let mut added = Vec::new();
+    for line in patch.lines() {
+        if let Some(line) = line.strip_prefix('-') {
+            deleted.push(strip_whitespace(line));
+        } else if let Some(line) = line.strip_prefix('+') {
+            added.push(strip_whitespace(line));
+        } else if !line.starts_with('\\') {
+            // A context line or hunk header ends the run; a "\ No newline at end of
+            // file" marker does not
+            count_run(&mut deleted, &mut added, &mut changes);
+        }
+    }
+    count_run(&mut deleted, &mut added, &mut changes);
+    changes
+}
+
+/// Returns `files` with the whitespace-only changed lines of each file left out of its
+/// line counts.
+///
+/// Each file is matched to its patch by file name. Files without a patch, such as
+/// binary files or files whose diff the platform omitted, keep their full counts.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::size::discount_whitespace_only_changes;
+/// use merge_warden_developer_platforms::models::{PullRequestFile, PullRequestFilePatch};
+///
+/// let files = vec![PullRequestFile {
+///     filename: "src/lib.rs".to_string(),
+///     additions: 2,
+///     deletions: 2,
+///     changes: 4,
+///     status: "modified".to_string(),
+/// }];
+/// let patches = vec![PullRequestFilePatch {
+///     filename: "src/lib.rs".to_string(),
+///     patch: Some("@@ -1,2 +1,2 @@\n-a\n-b\n+  a\n+c".to_string()),
+/// }];
+///
+/// let files = discount_whitespace_only_changes(&files, &patches);
+/// assert_eq!(files[0].additions, 1);
+/// assert_eq!(files[0].deletions, 1);
+/// assert_eq!(files[0].changes, 2);
+/// ```
+pub fn discount_whitespace_only_changes(
+    files: &[PullRequestFile],
+    patches: &[PullRequestFilePatch],
+) -> Vec<PullRequestFile> {
+    files
+        .iter()
+        .map(|file| {
+            let Some(patch) = patches
+                .iter()
+                .find(|p| p.filename == file.filename)
+                .and_then(|p| p.patch.as_deref())
+            else {
+                return file.clone();
+            };
+
+            let whitespace = count_whitespace_only_changes(patch);
+            let additions = file.additions.saturating_sub(whitespace.additions);
+            let deletions = file.deletions.saturating_sub(whitespace.deletions);
+            PullRequestFile {
+                additions,
+                deletions,
+                changes: file
+                    .changes
+                    .saturating_sub(file.additions - additions + file.deletions - deletions),
+                ..file.clone()
+            }
+        })
+        .collect()
 }
 
 /// Filter files based on exclusion patterns.
 ///
-/// Files matching any of the exclusion patterns will be moved to the excluded list.
-/// This function supports basic glob-like patterns using the `glob` crate.
+/// Files excluded by the patterns will be moved to the excluded list. Patterns use
+/// gitignore semantics; see [`FileExclusions`].
 ///
 /// # Arguments
 ///
@@ -532,30 +1886,31 @@ impl PrSizeInfo {
 ///
 /// # Returns
 ///
-/// A tuple of (included_files, excluded_files)
+/// A tuple of (included_files, excluded_files), where each excluded file is paired with
+/// the reason it was excluded
 fn filter_files_by_patterns(
     all_files: &[merge_warden_developer_platforms::models::PullRequestFile],
     exclusion_patterns: &[String],
 ) -> (
     Vec<merge_warden_developer_platforms::models::PullRequestFile>,
-    Vec<merge_warden_developer_platforms::models::PullRequestFile>,
+    Vec<(
+        merge_warden_developer_platforms::models::PullRequestFile,
+        SizeExclusionReason,
+    )>,
 ) {
     let mut included = Vec::new();
     let mut excluded = Vec::new();
 
+    let exclusions = FileExclusions::new(exclusion_patterns);
     for file in all_files {
-        let should_exclude = exclusion_patterns.iter().any(|pattern| {
-            // For now, use simple string matching
-            // TODO: Consider using glob patterns for more sophisticated matching
-            file.filename.contains(pattern)
-                || file.filename.ends_with(pattern)
-                || pattern == "*" && file.filename.contains(".")
-        });
-
-        if should_exclude {
-            excluded.push(file.clone());
-        } else {
-            included.push(file.clone());
+        match exclusions.excluding_pattern(&file.filename) {
+            Some(pattern) => excluded.push((
+                file.clone(),
+                SizeExclusionReason::MatchedPattern {
+                    pattern: pattern.to_string(),
+                },
+            )),
+            None => included.push(file.clone()),
         }
     }
 
@@ -175,7 +175,12 @@ fn test_size_thresholds_custom() {
 
 #[test]
 fn test_pr_size_info_new_empty() {
-    let size_info = PrSizeInfo::new(vec![], vec![], &SizeThresholds::default(), false);
+    let size_info = PrSizeInfo::new(
+        vec![],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
 
     assert_eq!(size_info.total_lines_changed, 0);
     assert_eq!(size_info.size_category, PrSizeCategory::XS);
@@ -194,7 +199,12 @@ fn test_pr_size_info_new_single_file() {
         status: "modified".to_string(),
     };
 
-    let size_info = PrSizeInfo::new(vec![file], vec![], &SizeThresholds::default(), false);
+    let size_info = PrSizeInfo::new(
+        vec![file],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
 
     assert_eq!(size_info.total_lines_changed, 20);
     assert_eq!(size_info.size_category, PrSizeCategory::S);
@@ -229,7 +239,12 @@ fn test_pr_size_info_new_multiple_files() {
         },
     ];
 
-    let size_info = PrSizeInfo::new(files, vec![], &SizeThresholds::default(), false);
+    let size_info = PrSizeInfo::new(
+        files,
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
 
     assert_eq!(size_info.total_lines_changed, 85); // 20 + 40 + 25
     assert_eq!(size_info.size_category, PrSizeCategory::M);
@@ -269,7 +284,7 @@ fn test_pr_size_info_new_with_excluded_files() {
         included_files,
         excluded_files,
         &SizeThresholds::default(),
-        false,
+        SizeCountingMode::TotalChanges,
     );
 
     // Should only count included files
@@ -290,7 +305,12 @@ fn test_pr_size_info_oversized() {
         status: "modified".to_string(),
     }];
 
-    let size_info = PrSizeInfo::new(files, vec![], &SizeThresholds::default(), false);
+    let size_info = PrSizeInfo::new(
+        files,
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
 
     assert_eq!(size_info.total_lines_changed, 600);
     assert_eq!(size_info.size_category, PrSizeCategory::XXL);
@@ -316,7 +336,12 @@ fn test_pr_size_info_with_custom_thresholds() {
         status: "modified".to_string(),
     }];
 
-    let size_info = PrSizeInfo::new(files, vec![], &custom_thresholds, false);
+    let size_info = PrSizeInfo::new(
+        files,
+        vec![],
+        &custom_thresholds,
+        SizeCountingMode::TotalChanges,
+    );
 
     assert_eq!(size_info.total_lines_changed, 30);
     assert_eq!(size_info.size_category, PrSizeCategory::M); // Exactly at the M threshold
@@ -432,7 +457,12 @@ fn test_pr_size_info_zero_changes_file() {
         },
     ];
 
-    let size_info = PrSizeInfo::new(files, vec![], &SizeThresholds::default(), false);
+    let size_info = PrSizeInfo::new(
+        files,
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
 
     assert_eq!(size_info.total_lines_changed, 7); // Only the modified file counts
     assert_eq!(size_info.size_category, PrSizeCategory::XS);
@@ -463,7 +493,7 @@ fn test_pr_size_info_from_files_with_exclusions() {
         &files,
         &SizeThresholds::default(),
         &exclusion_patterns,
-        false,
+        SizeCountingMode::TotalChanges,
     );
 
     // Only the main.rs file should be included in size calculation
@@ -493,8 +523,12 @@ fn test_pr_size_info_no_exclusions() {
         },
     ];
 
-    let size_info =
-        PrSizeInfo::from_files_with_exclusions(&files, &SizeThresholds::default(), &[], false);
+    let size_info = PrSizeInfo::from_files_with_exclusions(
+        &files,
+        &SizeThresholds::default(),
+        &[],
+        SizeCountingMode::TotalChanges,
+    );
 
     // All files should be included
     assert_eq!(size_info.total_lines_changed, 45); // 15 + 30
@@ -502,6 +536,126 @@ fn test_pr_size_info_no_exclusions() {
     assert_eq!(size_info.excluded_files.len(), 0);
 }
 
+fn patterns(patterns: &[&str]) -> Vec<String> {
+    patterns.iter().map(|p| p.to_string()).collect()
+}
+
+#[test]
+fn test_file_exclusions_pattern_without_slash_matches_at_any_depth() {
+    let exclusions = FileExclusions::new(&patterns(&["*.md", "package-lock.json"]));
+
+    assert!(exclusions.is_excluded("README.md"));
+    assert!(exclusions.is_excluded("docs/guide/setup.md"));
+    assert!(exclusions.is_excluded("web/package-lock.json"));
+    assert!(!exclusions.is_excluded("src/md.rs"));
+    assert!(!exclusions.is_excluded("my-package-lock.json.bak"));
+}
+
+#[test]
+fn test_file_exclusions_double_star_matches_any_number_of_directories() {
+    let exclusions = FileExclusions::new(&patterns(&["**/generated/**", "src/**/*.snap"]));
+
+    assert!(exclusions.is_excluded("generated/schema.rs"));
+    assert!(exclusions.is_excluded("crates/api/generated/client/mod.rs"));
+    assert!(exclusions.is_excluded("src/snapshots/parser.snap"));
+    assert!(exclusions.is_excluded("src/a/b/c/output.snap"));
+    assert!(!exclusions.is_excluded("tests/snapshots/parser.snap"));
+    assert!(!exclusions.is_excluded("src/generator.rs"));
+}
+
+#[test]
+fn test_file_exclusions_leading_slash_anchors_to_root() {
+    let exclusions = FileExclusions::new(&patterns(&["/CHANGELOG.md", "/docs/"]));
+
+    assert!(exclusions.is_excluded("CHANGELOG.md"));
+    assert!(!exclusions.is_excluded("crates/core/CHANGELOG.md"));
+    assert!(exclusions.is_excluded("docs/guide/setup.md"));
+    assert!(!exclusions.is_excluded("crates/core/docs/notes.md"));
+}
+
+#[test]
+fn test_file_exclusions_middle_slash_anchors_to_root() {
+    let exclusions = FileExclusions::new(&patterns(&["docs/*"]));
+
+    assert!(exclusions.is_excluded("docs/api.md"));
+    assert!(exclusions.is_excluded("docs/guide/setup.md"));
+    assert!(!exclusions.is_excluded("src/docs/api.md"));
+}
+
+#[test]
+fn test_file_exclusions_trailing_slash_excludes_directory_contents() {
+    let exclusions = FileExclusions::new(&patterns(&["vendor/"]));
+
+    assert!(exclusions.is_excluded("vendor/lib.rs"));
+    assert!(exclusions.is_excluded("third_party/vendor/deep/lib.rs"));
+    assert!(!exclusions.is_excluded("vendor.rs"));
+}
+
+#[test]
+fn test_file_exclusions_negation_re_includes_files() {
+    let exclusions = FileExclusions::new(&patterns(&["*.lock", "!Cargo.lock"]));
+
+    assert!(exclusions.is_excluded("yarn.lock"));
+    assert!(!exclusions.is_excluded("Cargo.lock"));
+    assert!(!exclusions.is_excluded("crates/core/Cargo.lock"));
+}
+
+#[test]
+fn test_file_exclusions_last_matching_pattern_wins() {
+    let exclusions = FileExclusions::new(&patterns(&["!Cargo.lock", "*.lock"]));
+
+    // The negation comes first, so the later `*.lock` overrides it.
+    assert!(exclusions.is_excluded("Cargo.lock"));
+}
+
+#[test]
+fn test_file_exclusions_negation_re_includes_file_in_excluded_directory() {
+    let exclusions = FileExclusions::new(&patterns(&["vendor/", "!vendor/patched.rs"]));
+
+    assert!(exclusions.is_excluded("vendor/lib.rs"));
+    assert!(!exclusions.is_excluded("vendor/patched.rs"));
+}
+
+#[test]
+fn test_file_exclusions_without_patterns_excludes_nothing() {
+    let exclusions = FileExclusions::new(&[]);
+
+    assert!(!exclusions.is_excluded("README.md"));
+    assert!(!exclusions.is_excluded(""));
+}
+
+#[test]
+fn test_pr_size_info_from_files_with_gitignore_exclusions() {
+    let file = |filename: &str, changes: u32| PullRequestFile {
+        filename: filename.to_string(),
+        additions: changes,
+        deletions: 0,
+        changes,
+        status: "modified".to_string(),
+    };
+    let files = vec![
+        file("src/main.rs", 10),
+        file("src/generated/bindings.rs", 900),
+        file("src/generated/README.md", 5),
+        file("docs/guide.md", 40),
+    ];
+
+    let size_info = PrSizeInfo::from_files_with_exclusions(
+        &files,
+        &SizeThresholds::default(),
+        &patterns(&["**/generated/**", "!**/generated/README.md", "/docs/"]),
+        SizeCountingMode::TotalChanges,
+    );
+
+    assert_eq!(size_info.total_lines_changed, 15);
+    let included: Vec<&str> = size_info
+        .included_files
+        .iter()
+        .map(|f| f.filename.as_str())
+        .collect();
+    assert_eq!(included, vec!["src/main.rs", "src/generated/README.md"]);
+}
+
 #[test]
 fn test_size_category_determination() {
     let files = vec![PullRequestFile {
@@ -512,8 +666,12 @@ fn test_size_category_determination() {
         status: "modified".to_string(),
     }];
 
-    let size_info =
-        PrSizeInfo::from_files_with_exclusions(&files, &SizeThresholds::default(), &[], false);
+    let size_info = PrSizeInfo::from_files_with_exclusions(
+        &files,
+        &SizeThresholds::default(),
+        &[],
+        SizeCountingMode::TotalChanges,
+    );
 
     // 800 lines should be XXL with default thresholds
     assert!(size_info.is_oversized());
@@ -534,7 +692,12 @@ fn test_pr_size_info_ignore_deletions_false_uses_changes() {
         status: "modified".to_string(),
     };
 
-    let size_info = PrSizeInfo::new(vec![file], vec![], &SizeThresholds::default(), false);
+    let size_info = PrSizeInfo::new(
+        vec![file],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
 
     assert_eq!(size_info.total_lines_changed, 510);
 }
@@ -551,7 +714,12 @@ fn test_pr_size_info_ignore_deletions_true_uses_additions_only() {
         status: "modified".to_string(),
     };
 
-    let size_info = PrSizeInfo::new(vec![file], vec![], &SizeThresholds::default(), true);
+    let size_info = PrSizeInfo::new(
+        vec![file],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::AdditionsOnly,
+    );
 
     assert_eq!(size_info.total_lines_changed, 10);
     assert_eq!(size_info.size_category, PrSizeCategory::XS);
@@ -580,7 +748,7 @@ fn test_pr_size_info_wholly_deleted_file_contributes_zero_when_ignore_deletions(
         vec![deleted_file, new_file],
         vec![],
         &SizeThresholds::default(),
-        true,
+        SizeCountingMode::AdditionsOnly,
     );
 
     // Only the 25 additions from new_module.rs count; the removed file contributes 0.
@@ -608,8 +776,18 @@ fn test_pr_size_info_mixed_pr_ignore_deletions_true() {
         },
     ];
 
-    let size_info_with = PrSizeInfo::new(files.clone(), vec![], &SizeThresholds::default(), true);
-    let size_info_without = PrSizeInfo::new(files, vec![], &SizeThresholds::default(), false);
+    let size_info_with = PrSizeInfo::new(
+        files.clone(),
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::AdditionsOnly,
+    );
+    let size_info_without = PrSizeInfo::new(
+        files,
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
 
     assert_eq!(size_info_with.total_lines_changed, 55); // 50 + 5 additions only
     assert_eq!(size_info_without.total_lines_changed, 375); // 70 + 305 changes
@@ -640,7 +818,7 @@ fn test_pr_size_info_from_files_with_exclusions_ignore_deletions_true() {
         &files,
         &SizeThresholds::default(),
         &exclusion_patterns,
-        true,
+        SizeCountingMode::AdditionsOnly,
     );
 
     // package-lock.json is excluded; only main.rs additions (15) count.
@@ -648,3 +826,735 @@ fn test_pr_size_info_from_files_with_exclusions_ignore_deletions_true() {
     assert_eq!(size_info.included_files.len(), 1);
     assert_eq!(size_info.excluded_files.len(), 1);
 }
+
+#[test]
+fn test_pr_size_info_net_changes_counts_difference_per_file() {
+    // A refactor that moves code within a file only counts the lines the file grew or
+    // shrank by.
+    let files = vec![
+        PullRequestFile {
+            filename: "src/moved.rs".to_string(),
+            additions: 400,
+            deletions: 390,
+            changes: 790,
+            status: "modified".to_string(),
+        },
+        PullRequestFile {
+            filename: "src/shrunk.rs".to_string(),
+            additions: 5,
+            deletions: 30,
+            changes: 35,
+            status: "modified".to_string(),
+        },
+    ];
+
+    let net = PrSizeInfo::new(
+        files.clone(),
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::NetChanges,
+    );
+    let total = PrSizeInfo::new(
+        files,
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
+
+    assert_eq!(net.total_lines_changed, 35); // 10 + 25
+    assert_eq!(net.size_category, PrSizeCategory::S);
+    assert_eq!(net.counted_lines(&net.included_files[1]), 25);
+    assert_eq!(total.total_lines_changed, 825);
+    assert_eq!(total.size_category, PrSizeCategory::XXL);
+}
+
+#[test]
+fn test_pr_size_info_net_changes_counts_removed_file_in_full() {
+    let removed = PullRequestFile {
+        filename: "src/old_module.rs".to_string(),
+        additions: 0,
+        deletions: 120,
+        changes: 120,
+        status: "removed".to_string(),
+    };
+
+    let size_info = PrSizeInfo::new(
+        vec![removed],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::NetChanges,
+    );
+
+    assert_eq!(size_info.total_lines_changed, 120);
+}
+
+#[test]
+fn test_pr_size_info_with_no_files_is_xs_without_reviewable_changes() {
+    let size_info = PrSizeInfo::from_files_with_exclusions(
+        &[],
+        &SizeThresholds::default(),
+        &[],
+        SizeCountingMode::TotalChanges,
+    );
+
+    assert_eq!(size_info.total_lines_changed, 0);
+    assert_eq!(size_info.size_category, PrSizeCategory::XS);
+    assert!(!size_info.has_reviewable_changes());
+    assert!(!size_info.is_oversized());
+    assert_eq!(size_info.included_file_count(), 0);
+    assert_eq!(size_info.excluded_file_count(), 0);
+}
+
+#[test]
+fn test_pr_size_info_with_all_files_excluded_is_xs_without_reviewable_changes() {
+    let files = vec![PullRequestFile {
+        filename: "Cargo.lock".to_string(),
+        additions: 4000,
+        deletions: 2000,
+        changes: 6000,
+        status: "modified".to_string(),
+    }];
+
+    let size_info = PrSizeInfo::from_files_with_exclusions(
+        &files,
+        &SizeThresholds::default(),
+        &["Cargo.lock".to_string()],
+        SizeCountingMode::TotalChanges,
+    );
+
+    assert_eq!(size_info.total_lines_changed, 0);
+    assert_eq!(size_info.size_category, PrSizeCategory::XS);
+    assert!(!size_info.has_reviewable_changes());
+    assert!(!size_info.is_oversized());
+    assert_eq!(size_info.excluded_file_count(), 1);
+}
+
+#[test]
+fn test_pr_size_info_with_zero_line_file_has_reviewable_changes() {
+    let files = vec![PullRequestFile {
+        filename: "assets/logo.png".to_string(),
+        additions: 0,
+        deletions: 0,
+        changes: 0,
+        status: "added".to_string(),
+    }];
+
+    let size_info = PrSizeInfo::from_files_with_exclusions(
+        &files,
+        &SizeThresholds::default(),
+        &[],
+        SizeCountingMode::TotalChanges,
+    );
+
+    assert!(size_info.has_reviewable_changes());
+    assert_eq!(size_info.size_category, PrSizeCategory::XS);
+}
+
+fn make_categorize_file(filename: &str, additions: u32) -> PullRequestFile {
+    PullRequestFile {
+        filename: filename.to_string(),
+        additions,
+        deletions: 0,
+        changes: additions,
+        status: "modified".to_string(),
+    }
+}
+
+fn categorize_test_files() -> Vec<PullRequestFile> {
+    vec![
+        PullRequestFile {
+            filename: "src/lib.rs".to_string(),
+            additions: 40,
+            deletions: 30,
+            changes: 70,
+            status: "modified".to_string(),
+        },
+        PullRequestFile {
+            filename: "Cargo.lock".to_string(),
+            additions: 900,
+            deletions: 100,
+            changes: 1000,
+            status: "modified".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn test_categorize_applies_config_thresholds_and_exclusions() {
+    let config = PrSizeCheckConfig {
+        thresholds: Some(SizeThresholds::new(10, 20, 30, 40, 60)),
+        excluded_file_patterns: vec!["*.lock".to_string()],
+        ..PrSizeCheckConfig::default()
+    };
+
+    let size_info = PrSizeInfo::categorize(&categorize_test_files(), &config);
+
+    assert_eq!(size_info.total_lines_changed, 70);
+    assert_eq!(size_info.size_category, PrSizeCategory::XXL);
+    assert_eq!(size_info.included_file_count(), 1);
+    assert_eq!(size_info.excluded_file_count(), 1);
+}
+
+#[test]
+fn test_categorize_uses_default_thresholds_and_ignore_deletions() {
+    let config = PrSizeCheckConfig {
+        excluded_file_patterns: vec!["*.lock".to_string()],
+        ignore_deletions: true,
+        ..PrSizeCheckConfig::default()
+    };
+
+    let size_info = PrSizeInfo::categorize(&categorize_test_files(), &config);
+
+    assert_eq!(size_info.total_lines_changed, 40);
+    assert_eq!(size_info.size_category, PrSizeCategory::S);
+}
+
+#[test]
+fn test_largest_files_orders_by_lines_then_path_and_skips_uncounted_files() {
+    let removed = PullRequestFile {
+        filename: "src/old.rs".to_string(),
+        additions: 0,
+        deletions: 400,
+        changes: 400,
+        status: "removed".to_string(),
+    };
+    let size_info = PrSizeInfo::new(
+        vec![
+            make_categorize_file("src/b.rs", 20),
+            make_categorize_file("src/a.rs", 20),
+            make_categorize_file("src/c.rs", 90),
+            removed,
+        ],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::AdditionsOnly,
+    );
+
+    let paths: Vec<String> = size_info
+        .largest_files(5)
+        .into_iter()
+        .map(|entry| entry.path)
+        .collect();
+
+    assert_eq!(paths, vec!["src/c.rs", "src/a.rs", "src/b.rs"]);
+    assert_eq!(size_info.largest_files(1).len(), 1);
+    assert!(size_info.largest_files(0).is_empty());
+}
+
+#[test]
+fn test_largest_directories_groups_by_top_level_directory() {
+    let size_info = PrSizeInfo::new(
+        vec![
+            make_categorize_file("crates/core/src/lib.rs", 200),
+            make_categorize_file("crates/cli/src/main.rs", 100),
+            make_categorize_file("docs/guide.md", 50),
+            make_categorize_file("README.md", 10),
+            make_categorize_file("Makefile", 5),
+        ],
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
+
+    assert_eq!(
+        size_info.largest_directories(2),
+        vec![
+            SizeBreakdownEntry {
+                path: "crates/".to_string(),
+                counted_lines: 300,
+                file_count: 2,
+            },
+            SizeBreakdownEntry {
+                path: "docs/".to_string(),
+                counted_lines: 50,
+                file_count: 1,
+            },
+        ]
+    );
+    assert_eq!(
+        size_info.largest_directories(5)[2],
+        SizeBreakdownEntry {
+            path: "/".to_string(),
+            counted_lines: 15,
+            file_count: 2,
+        }
+    );
+}
+
+#[test]
+fn test_categorize_excludes_default_patterns() {
+    let files = vec![
+        make_categorize_file("src/lib.rs", 40),
+        make_categorize_file("flake.lock", 300),
+        make_categorize_file("src/api.generated.ts", 900),
+        make_categorize_file("web/dist/bundle.js", 2000),
+    ];
+
+    let size_info = PrSizeInfo::categorize(&files, &PrSizeCheckConfig::default());
+
+    assert_eq!(size_info.total_lines_changed, 40);
+    assert_eq!(
+        size_info.exclusion_reasons,
+        vec![
+            SizeExclusionReason::MatchedPattern {
+                pattern: "*.lock".to_string()
+            },
+            SizeExclusionReason::MatchedPattern {
+                pattern: "*.generated.*".to_string()
+            },
+            SizeExclusionReason::MatchedPattern {
+                pattern: "dist/".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_categorize_without_default_exclusions_counts_generated_files() {
+    let files = vec![
+        make_categorize_file("src/lib.rs", 40),
+        make_categorize_file("flake.lock", 300),
+    ];
+    let config = PrSizeCheckConfig {
+        use_default_exclusions: Some(false),
+        ..PrSizeCheckConfig::default()
+    };
+
+    let size_info = PrSizeInfo::categorize(&files, &config);
+
+    assert_eq!(size_info.total_lines_changed, 340);
+    assert_eq!(size_info.excluded_file_count(), 0);
+}
+
+#[test]
+fn test_categorize_custom_pattern_re_includes_default_exclusion() {
+    let files = vec![
+        make_categorize_file("src/lib.rs", 40),
+        make_categorize_file("dist/index.js", 60),
+    ];
+    let config = PrSizeCheckConfig {
+        excluded_file_patterns: vec!["!dist/".to_string()],
+        ..PrSizeCheckConfig::default()
+    };
+
+    let size_info = PrSizeInfo::categorize(&files, &config);
+
+    assert_eq!(size_info.total_lines_changed, 100);
+    assert_eq!(size_info.excluded_file_count(), 0);
+}
+
+#[test]
+fn test_categorize_uses_counting_mode() {
+    let config = PrSizeCheckConfig {
+        excluded_file_patterns: vec!["*.lock".to_string()],
+        counting_mode: SizeCountingMode::NetChanges,
+        ..PrSizeCheckConfig::default()
+    };
+
+    let size_info = PrSizeInfo::categorize(&categorize_test_files(), &config);
+
+    assert_eq!(size_info.total_lines_changed, 10);
+    assert_eq!(size_info.counting_mode, SizeCountingMode::NetChanges);
+}
+
+#[test]
+fn test_categorize_ignore_deletions_overrides_counting_mode() {
+    let config = PrSizeCheckConfig {
+        excluded_file_patterns: vec!["*.lock".to_string()],
+        ignore_deletions: true,
+        counting_mode: SizeCountingMode::NetChanges,
+        ..PrSizeCheckConfig::default()
+    };
+
+    let size_info = PrSizeInfo::categorize(&categorize_test_files(), &config);
+
+    assert_eq!(size_info.total_lines_changed, 40);
+    assert_eq!(size_info.counting_mode, SizeCountingMode::AdditionsOnly);
+}
+
+#[test]
+fn test_categorize_applies_exclusion_presets_before_custom_patterns() {
+    let mut files = categorize_test_files();
+    files.push(PullRequestFile {
+        filename: "web/dist/app.min.js".to_string(),
+        additions: 500,
+        deletions: 0,
+        changes: 500,
+        status: "added".to_string(),
+    });
+    let config = PrSizeCheckConfig {
+        excluded_file_presets: vec![FileExclusionPreset::CommonGenerated],
+        excluded_file_patterns: vec!["!Cargo.lock".to_string()],
+        ..PrSizeCheckConfig::default()
+    };
+
+    let size_info = PrSizeInfo::categorize(&files, &config);
+
+    assert_eq!(size_info.total_lines_changed, 1070);
+    assert_eq!(size_info.excluded_file_count(), 1);
+    assert_eq!(
+        size_info.exclusion_reasons,
+        vec![SizeExclusionReason::MatchedPattern {
+            pattern: "*.min.js".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_file_exclusion_preset_common_generated_combines_all_presets() {
+    let combined = FileExclusionPreset::CommonGenerated.patterns();
+
+    for preset in [
+        FileExclusionPreset::Lockfiles,
+        FileExclusionPreset::Vendored,
+        FileExclusionPreset::Minified,
+        FileExclusionPreset::Snapshots,
+    ] {
+        for pattern in preset.patterns() {
+            assert!(combined.contains(&pattern), "{pattern} missing");
+        }
+    }
+
+    let exclusions =
+        FileExclusions::new(&combined.into_iter().map(str::to_string).collect::<Vec<_>>());
+    assert!(exclusions.is_excluded("web/package-lock.json"));
+    assert!(exclusions.is_excluded("vendor/github.com/pkg/errors/errors.go"));
+    assert!(exclusions.is_excluded("src/__snapshots__/app.test.ts.snap"));
+    assert!(!exclusions.is_excluded("src/vendors.rs"));
+}
+
+#[test]
+fn test_size_thresholds_upper_bound_matches_categorisation() {
+    let thresholds = SizeThresholds::new(5, 25, 75, 150, 300);
+
+    for category in PrSizeCategory::ALL {
+        match thresholds.upper_bound(category) {
+            Some(bound) => {
+                assert_eq!(
+                    PrSizeCategory::from_line_count_with_thresholds(bound, &thresholds),
+                    category
+                );
+                assert!(
+                    PrSizeCategory::from_line_count_with_thresholds(bound + 1, &thresholds)
+                        > category
+                );
+            }
+            None => assert_eq!(category, PrSizeCategory::XXL),
+        }
+    }
+}
+
+#[test]
+fn test_size_category_names_default_to_standard_names() {
+    let names = SizeCategoryNames::default();
+
+    for category in PrSizeCategory::ALL {
+        assert_eq!(names.name_for(category), category.as_str());
+        assert_eq!(names.custom_name(category), None);
+    }
+}
+
+#[test]
+fn test_size_category_names_ignore_blank_names() {
+    let names = SizeCategoryNames {
+        s: Some("  ".to_string()),
+        l: Some(" large ".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(names.name_for(PrSizeCategory::S), "S");
+    assert_eq!(names.name_for(PrSizeCategory::L), "large");
+}
+
+#[test]
+fn test_breakdown_lists_counted_lines_and_exclusion_patterns() {
+    let config = PrSizeCheckConfig {
+        excluded_file_patterns: vec!["*.md".to_string(), "*.lock".to_string()],
+        ignore_deletions: true,
+        ..PrSizeCheckConfig::default()
+    };
+
+    let breakdown = PrSizeInfo::categorize(&categorize_test_files(), &config).breakdown();
+
+    assert_eq!(
+        breakdown,
+        PrSizeBreakdown {
+            size_category: PrSizeCategory::S,
+            total_lines_changed: 40,
+            ignore_deletions: true,
+            counting_mode: SizeCountingMode::AdditionsOnly,
+            included_files: vec![IncludedFileSize {
+                filename: "src/lib.rs".to_string(),
+                status: "modified".to_string(),
+                additions: 40,
+                deletions: 30,
+                counted_lines: 40,
+            }],
+            excluded_files: vec![ExcludedFileSize {
+                filename: "Cargo.lock".to_string(),
+                status: "modified".to_string(),
+                additions: 900,
+                deletions: 100,
+                reason: SizeExclusionReason::MatchedPattern {
+                    pattern: "*.lock".to_string(),
+                },
+            }],
+        }
+    );
+}
+
+#[test]
+fn test_breakdown_marks_files_passed_as_excluded() {
+    let files = categorize_test_files();
+    let size_info = PrSizeInfo::new(
+        vec![files[0].clone()],
+        vec![files[1].clone()],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
+
+    let breakdown = size_info.breakdown();
+
+    assert_eq!(breakdown.included_files[0].counted_lines, 70);
+    assert_eq!(
+        breakdown.excluded_files[0].reason,
+        SizeExclusionReason::Provided
+    );
+}
+
+#[test]
+fn test_breakdown_serialization_shape() {
+    let config = PrSizeCheckConfig {
+        excluded_file_patterns: vec!["*.lock".to_string()],
+        ..PrSizeCheckConfig::default()
+    };
+    let breakdown = PrSizeInfo::categorize(&categorize_test_files(), &config).breakdown();
+
+    let json = serde_json::to_value(&breakdown).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "size_category": "M",
+            "total_lines_changed": 70,
+            "ignore_deletions": false,
+            "counting_mode": "total_changes",
+            "included_files": [{
+                "filename": "src/lib.rs",
+                "status": "modified",
+                "additions": 40,
+                "deletions": 30,
+                "counted_lines": 70
+            }],
+            "excluded_files": [{
+                "filename": "Cargo.lock",
+                "status": "modified",
+                "additions": 900,
+                "deletions": 100,
+                "reason": { "kind": "matched_pattern", "pattern": "*.lock" }
+            }]
+        })
+    );
+    let round_trip: PrSizeBreakdown = serde_json::from_value(json).unwrap();
+    assert_eq!(round_trip, breakdown);
+}
+
+#[test]
+fn test_count_whitespace_only_changes_matches_reindented_lines() {
+    let patch = "@@ -1,3 +1,3 @@\n fn main() {\n-let a=1;\n-let b = 2;\n+    let a = 1;\n+    let b = 3;\n }";
+
+    assert_eq!(
+        count_whitespace_only_changes(patch),
+        WhitespaceOnlyChanges {
+            additions: 1,
+            deletions: 1
+        }
+    );
+}
+
+#[test]
+fn test_count_whitespace_only_changes_counts_blank_lines() {
+    let patch = "@@ -1,3 +1,3 @@\n a\n-\n+  \n+\n b";
+
+    assert_eq!(
+        count_whitespace_only_changes(patch),
+        WhitespaceOnlyChanges {
+            additions: 2,
+            deletions: 1
+        }
+    );
+}
+
+#[test]
+fn test_count_whitespace_only_changes_does_not_match_moved_lines() {
+    // The line moved past a context line, which is a real change
+    let patch = "@@ -1,3 +1,3 @@\n-let a = 1;\n keep();\n+let a = 1;";
+
+    assert_eq!(
+        count_whitespace_only_changes(patch),
+        WhitespaceOnlyChanges::default()
+    );
+}
+
+#[test]
+fn test_count_whitespace_only_changes_ignores_no_newline_marker() {
+    let patch = "@@ -1 +1 @@\n-end\n\\ No newline at end of file\n+end";
+
+    assert_eq!(
+        count_whitespace_only_changes(patch),
+        WhitespaceOnlyChanges {
+            additions: 1,
+            deletions: 1
+        }
+    );
+}
+
+#[test]
+fn test_discount_whitespace_only_changes_keeps_files_without_patch() {
+    let files = vec![
+        PullRequestFile {
+            filename: "src/lib.rs".to_string(),
+            additions: 3,
+            deletions: 2,
+            changes: 5,
+            status: "modified".to_string(),
+        },
+        PullRequestFile {
+            filename: "logo.png".to_string(),
+            additions: 0,
+            deletions: 0,
+            changes: 0,
+            status: "added".to_string(),
+        },
+        PullRequestFile {
+            filename: "src/main.rs".to_string(),
+            additions: 10,
+            deletions: 0,
+            changes: 10,
+            status: "modified".to_string(),
+        },
+    ];
+    let patches = vec![
+        PullRequestFilePatch {
+            filename: "src/lib.rs".to_string(),
+            patch: Some("@@ -1,2 +1,3 @@\n-a\n-b\n+ a\n+\tb\n+c".to_string()),
+        },
+        PullRequestFilePatch {
+            filename: "logo.png".to_string(),
+            patch: None,
+        },
+    ];
+
+    let discounted = discount_whitespace_only_changes(&files, &patches);
+
+    assert_eq!(discounted[0].additions, 1);
+    assert_eq!(discounted[0].deletions, 0);
+    assert_eq!(discounted[0].changes, 1);
+    assert_eq!(discounted[1].changes, 0);
+    assert_eq!(discounted[2].changes, 10);
+}
+
+fn effort_file(filename: &str, changes: u32) -> PullRequestFile {
+    PullRequestFile {
+        filename: filename.to_string(),
+        additions: changes,
+        deletions: 0,
+        changes,
+        status: "modified".to_string(),
+    }
+}
+
+#[test]
+fn test_review_effort_combines_weighted_factors() {
+    let files: Vec<PullRequestFile> = (0..10)
+        .map(|i| effort_file(&format!("src/module_{i}.rs"), 30))
+        .chain([
+            effort_file("web/app.ts", 50),
+            effort_file("web/app.css", 20),
+            effort_file("README.md", 10),
+        ])
+        .collect();
+    let size_info = PrSizeInfo::new(
+        files,
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
+
+    let effort = size_info.review_effort(&ReviewEffortWeights::default());
+
+    // lines 5 (x3), files 4 (x2), languages 4 (x1), tests 5 (x1): 32 / 7 rounds to 5
+    assert_eq!(effort.score, 5);
+    let scores: Vec<(ReviewEffortFactorKind, u8)> =
+        effort.factors.iter().map(|f| (f.kind, f.score)).collect();
+    assert_eq!(
+        scores,
+        vec![
+            (ReviewEffortFactorKind::Lines, 5),
+            (ReviewEffortFactorKind::Files, 4),
+            (ReviewEffortFactorKind::Languages, 4),
+            (ReviewEffortFactorKind::Tests, 5),
+        ]
+    );
+    assert_eq!(
+        effort.explanation(),
+        "380 lines changed (5/5), 13 files touched (4/5), \
+         4 languages (CSS, Markdown, Rust, TypeScript) (4/5), no test changes (5/5)"
+    );
+}
+
+#[test]
+fn test_review_effort_uses_configured_weights() {
+    let files = vec![
+        effort_file("src/lib.rs", 400),
+        effort_file("src/lib_tests.rs", 100),
+    ];
+    let size_info = PrSizeInfo::new(
+        files,
+        vec![],
+        &SizeThresholds::default(),
+        SizeCountingMode::TotalChanges,
+    );
+
+    let tests_only = ReviewEffortWeights {
+        lines: 0,
+        files: 0,
+        languages: 0,
+        tests: 1,
+    };
+    let effort = size_info.review_effort(&tests_only);
+    assert_eq!(effort.score, 1);
+    assert_eq!(effort.explanation(), "includes test changes (1/5)");
+
+    let no_weights = ReviewEffortWeights {
+        tests: 0,
+        ..tests_only
+    };
+    let effort = size_info.review_effort(&no_weights);
+    assert_eq!(effort.score, 1);
+    assert!(effort.factors.is_empty());
+    assert_eq!(effort.explanation(), "");
+}
+
+#[test]
+fn test_review_effort_recognizes_test_files_and_languages() {
+    for path in [
+        "tests/integration.rs",
+        "crates/core/src/size_tests.rs",
+        "pkg/handler_test.go",
+        "src/__tests__/app.js",
+        "src/app.spec.ts",
+        "test_parser.py",
+    ] {
+        assert!(is_test_file(path), "{path} should be a test file");
+    }
+    for path in ["src/testing.rs", "src/contest/main.rs", "docs/spec.md"] {
+        assert!(!is_test_file(path), "{path} should not be a test file");
+    }
+
+    assert_eq!(file_language("src/main.RS"), Some("Rust"));
+    assert_eq!(file_language("web/index.tsx"), Some("TypeScript"));
+    assert_eq!(file_language(".gitignore"), None);
+    assert_eq!(file_language("LICENSE"), None);
+    assert_eq!(file_language("assets/logo.png"), None);
+}
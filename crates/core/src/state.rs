@@ -0,0 +1,329 @@
+//! # State
+//!
+//! This module provides the [`StateStore`] abstraction through which Merge Warden
+//! persists state about a pull request between events, such as the outcome of the
+//! previous run.
+//!
+//! Each feature stores its state under its own name within the pull request, so one
+//! backend serves all of them. [`InMemoryStateStore`] is the default; it keeps the
+//! state for the lifetime of the process. Durable backends, such as Azure Table
+//! Storage or Redis, implement [`StateStore`] and store [`StateEntry::to_json`].
+//!
+//! ## Serialization format
+//!
+//! A stored entry is a JSON object:
+//!
+//! ```json
+//! {
+//!   "format_version": 1,
+//!   "value": { "conclusion": "failure" },
+//!   "updated_at": "2025-06-01T12:00:00Z",
+//!   "expires_at": "2025-06-08T12:00:00Z"
+//! }
+//! ```
+//!
+//! - `format_version` - [`STATE_FORMAT_VERSION`] at the time the entry was written
+//! - `value` - the feature's state, serialized with `serde_json`
+//! - `updated_at` - when the entry was written, in RFC 3339 UTC
+//! - `expires_at` - when the entry stops being returned, or `null` to keep it until
+//!   it is removed
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::sync::{Arc, Mutex};
+
+use crate::clock::{Clock, SystemClock};
+use crate::errors::StateStoreError;
+use crate::PullRequestTarget;
+
+#[cfg(test)]
+#[path = "state_tests.rs"]
+mod tests;
+
+/// The version of the serialization format written by [`StateEntry::to_json`].
+pub const STATE_FORMAT_VERSION: u32 = 1;
+
+/// Identifies one piece of state: a feature's name within a pull request.
+///
+/// Repository owners and names are compared case-insensitively, as on GitHub.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::state::StateKey;
+///
+/// let key = StateKey::new(("Owner", "Repo", 42), "conclusion");
+/// assert_eq!(key.partition_key(), "owner/repo");
+/// assert_eq!(key.row_key(), "42/conclusion");
+/// assert_eq!(key.to_string(), "owner/repo#42/conclusion");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StateKey {
+    /// The pull request the state belongs to
+    pub target: PullRequestTarget,
+
+    /// The name under which the feature stores its state
+    pub name: String,
+}
+
+impl StateKey {
+    /// Creates the key of the state `name` of a pull request.
+    pub fn new(target: impl Into<PullRequestTarget>, name: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Returns the repository part of the key, `owner/repo` in lower case.
+    ///
+    /// Backends that partition their data, such as Azure Table Storage, use this as
+    /// the partition key so that all state of a repository is stored together.
+    pub fn partition_key(&self) -> String {
+        format!("{}/{}", self.target.owner, self.target.repo).to_lowercase()
+    }
+
+    /// Returns the pull request part of the key, `number/name`.
+    pub fn row_key(&self) -> String {
+        format!("{}/{}", self.target.pr_number, self.name)
+    }
+}
+
+impl fmt::Display for StateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}#{}/{}",
+            self.partition_key(),
+            self.target.pr_number,
+            self.name
+        )
+    }
+}
+
+/// A stored piece of state with its expiry.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use merge_warden_core::state::StateEntry;
+///
+/// let now = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+/// let entry = StateEntry::new(&"failure", now, Some(Duration::days(7))).unwrap();
+///
+/// assert_eq!(entry.value::<String>().unwrap(), "failure");
+/// assert!(!entry.is_expired(now + Duration::days(6)));
+/// assert!(entry.is_expired(now + Duration::days(7)));
+///
+/// let json = entry.to_json().unwrap();
+/// assert_eq!(StateEntry::from_json(&json).unwrap(), entry);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateEntry {
+    /// The serialization format version the entry was written with
+    pub format_version: u32,
+
+    /// The feature's state
+    pub value: serde_json::Value,
+
+    /// When the entry was written
+    pub updated_at: DateTime<Utc>,
+
+    /// When the entry expires, or `None` to keep it until it is removed
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl StateEntry {
+    /// Creates an entry holding `value`, written at `now` and expiring after `ttl`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateStoreError::Serialization`] if `value` cannot be serialized.
+    pub fn new<T: Serialize>(
+        value: &T,
+        now: DateTime<Utc>,
+        ttl: Option<Duration>,
+    ) -> Result<Self, StateStoreError> {
+        Ok(Self {
+            format_version: STATE_FORMAT_VERSION,
+            value: serde_json::to_value(value)
+                .map_err(|e| StateStoreError::Serialization(e.to_string()))?,
+            updated_at: now,
+            expires_at: ttl.map(|ttl| now + ttl),
+        })
+    }
+
+    /// Deserializes the stored value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateStoreError::Serialization`] if the value does not have the
+    /// shape of `T`, for example because it was written by an older version.
+    pub fn value<T: DeserializeOwned>(&self) -> Result<T, StateStoreError> {
+        serde_json::from_value(self.value.clone())
+            .map_err(|e| StateStoreError::Serialization(e.to_string()))
+    }
+
+    /// Whether the entry has expired at `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Serializes the entry in the stored format described in the [module docs](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateStoreError::Serialization`] if the entry cannot be serialized.
+    pub fn to_json(&self) -> Result<String, StateStoreError> {
+        serde_json::to_string(self).map_err(|e| StateStoreError::Serialization(e.to_string()))
+    }
+
+    /// Parses an entry from the stored format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StateStoreError::Serialization`] if `json` is not a stored entry,
+    /// and [`StateStoreError::UnsupportedFormatVersion`] if it was written with a
+    /// newer format version than [`STATE_FORMAT_VERSION`].
+    pub fn from_json(json: &str) -> Result<Self, StateStoreError> {
+        let entry: Self = serde_json::from_str(json)
+            .map_err(|e| StateStoreError::Serialization(e.to_string()))?;
+        if entry.format_version > STATE_FORMAT_VERSION {
+            return Err(StateStoreError::UnsupportedFormatVersion(
+                entry.format_version,
+            ));
+        }
+        Ok(entry)
+    }
+}
+
+/// Persists per-pull-request state between events.
+///
+/// Implementations must not return expired entries. They may remove them lazily,
+/// when they are read, or rely on the backend's own expiry.
+#[async_trait]
+pub trait StateStore: Debug + Send + Sync {
+    /// Returns the entry stored under `key`, or `None` if there is none or it expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateStoreError`] if the backend cannot be read.
+    async fn get(&self, key: &StateKey) -> Result<Option<StateEntry>, StateStoreError>;
+
+    /// Stores `entry` under `key`, replacing any previous entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateStoreError`] if the backend cannot be written.
+    async fn set(&self, key: &StateKey, entry: StateEntry) -> Result<(), StateStoreError>;
+
+    /// Removes the entry stored under `key`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateStoreError`] if the backend cannot be written.
+    async fn expire(&self, key: &StateKey) -> Result<(), StateStoreError>;
+}
+
+/// A [`StateStore`] that keeps the state in memory.
+///
+/// The state is lost when the process exits and is not shared between server
+/// instances. Expired entries are removed when they are read.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::state::{InMemoryStateStore, StateEntry, StateKey, StateStore};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let store = InMemoryStateStore::new();
+/// let key = StateKey::new(("owner", "repo", 42), "conclusion");
+///
+/// store
+///     .set(&key, StateEntry::new(&"success", chrono::Utc::now(), None).unwrap())
+///     .await
+///     .unwrap();
+/// let entry = store.get(&key).await.unwrap().unwrap();
+/// assert_eq!(entry.value::<String>().unwrap(), "success");
+///
+/// store.expire(&key).await.unwrap();
+/// assert!(store.get(&key).await.unwrap().is_none());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct InMemoryStateStore {
+    /// The stored entries
+    entries: Mutex<HashMap<StateKey, StateEntry>>,
+
+    /// The clock against which expiry is checked
+    clock: Arc<dyn Clock>,
+}
+
+impl InMemoryStateStore {
+    /// Creates an empty store that checks expiry against the [`SystemClock`].
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    /// Creates an empty store that checks expiry against `clock`.
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+
+    /// Normalises the repository part of `key` so that lookups ignore its case.
+    fn normalize(key: &StateKey) -> StateKey {
+        StateKey {
+            target: PullRequestTarget {
+                owner: key.target.owner.to_lowercase(),
+                repo: key.target.repo.to_lowercase(),
+                pr_number: key.target.pr_number,
+            },
+            name: key.name.clone(),
+        }
+    }
+}
+
+impl Default for InMemoryStateStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl StateStore for InMemoryStateStore {
+    async fn get(&self, key: &StateKey) -> Result<Option<StateEntry>, StateStoreError> {
+        let key = Self::normalize(key);
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(&key) {
+            Some(entry) if entry.is_expired(self.clock.now()) => {
+                entries.remove(&key);
+                Ok(None)
+            }
+            entry => Ok(entry.cloned()),
+        }
+    }
+
+    async fn set(&self, key: &StateKey, entry: StateEntry) -> Result<(), StateStoreError> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(Self::normalize(key), entry);
+        Ok(())
+    }
+
+    async fn expire(&self, key: &StateKey) -> Result<(), StateStoreError> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&Self::normalize(key));
+        Ok(())
+    }
+}
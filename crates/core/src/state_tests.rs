@@ -0,0 +1,147 @@
+use super::*;
+use crate::clock::ManualClock;
+use chrono::TimeZone;
+
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()
+}
+
+fn key(name: &str) -> StateKey {
+    StateKey::new(("owner", "repo", 42), name)
+}
+
+#[tokio::test]
+async fn test_in_memory_store_returns_nothing_for_unknown_keys() {
+    let store = InMemoryStateStore::new();
+
+    assert!(store.get(&key("conclusion")).await.unwrap().is_none());
+    // Expiring a missing entry is not an error
+    store.expire(&key("conclusion")).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_in_memory_store_keeps_features_and_pull_requests_apart() {
+    let store = InMemoryStateStore::new();
+    store
+        .set(
+            &key("conclusion"),
+            StateEntry::new(&"failure", start(), None).unwrap(),
+        )
+        .await
+        .unwrap();
+    store
+        .set(&key("size"), StateEntry::new(&120, start(), None).unwrap())
+        .await
+        .unwrap();
+
+    let conclusion = store.get(&key("conclusion")).await.unwrap().unwrap();
+    let size = store.get(&key("size")).await.unwrap().unwrap();
+    assert_eq!(conclusion.value::<String>().unwrap(), "failure");
+    assert_eq!(size.value::<u32>().unwrap(), 120);
+    assert!(store
+        .get(&StateKey::new(("owner", "repo", 43), "conclusion"))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_in_memory_store_ignores_repository_case() {
+    let store = InMemoryStateStore::new();
+    store
+        .set(
+            &StateKey::new(("Owner", "Repo", 42), "conclusion"),
+            StateEntry::new(&"success", start(), None).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert!(store.get(&key("conclusion")).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_in_memory_store_replaces_entries() {
+    let store = InMemoryStateStore::new();
+    store
+        .set(
+            &key("conclusion"),
+            StateEntry::new(&"failure", start(), None).unwrap(),
+        )
+        .await
+        .unwrap();
+    store
+        .set(
+            &key("conclusion"),
+            StateEntry::new(&"success", start(), None).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let entry = store.get(&key("conclusion")).await.unwrap().unwrap();
+    assert_eq!(entry.value::<String>().unwrap(), "success");
+}
+
+#[tokio::test]
+async fn test_in_memory_store_drops_expired_entries() {
+    let clock = Arc::new(ManualClock::new(start()));
+    let store = InMemoryStateStore::with_clock(clock.clone());
+    store
+        .set(
+            &key("cooldown"),
+            StateEntry::new(&true, start(), Some(Duration::minutes(10))).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    clock.advance(Duration::minutes(9));
+    assert!(store.get(&key("cooldown")).await.unwrap().is_some());
+
+    clock.advance(Duration::minutes(1));
+    assert!(store.get(&key("cooldown")).await.unwrap().is_none());
+}
+
+#[test]
+fn test_state_entry_serializes_in_the_documented_format() {
+    let entry = StateEntry::new(
+        &serde_json::json!({ "conclusion": "failure" }),
+        start(),
+        Some(Duration::days(7)),
+    )
+    .unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&entry.to_json().unwrap()).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "format_version": 1,
+            "value": { "conclusion": "failure" },
+            "updated_at": "2025-06-01T12:00:00Z",
+            "expires_at": "2025-06-08T12:00:00Z"
+        })
+    );
+}
+
+#[test]
+fn test_state_entry_rejects_newer_format_versions() {
+    let json = r#"{"format_version":2,"value":null,"updated_at":"2025-06-01T12:00:00Z","expires_at":null}"#;
+
+    assert!(matches!(
+        StateEntry::from_json(json),
+        Err(StateStoreError::UnsupportedFormatVersion(2))
+    ));
+    assert!(matches!(
+        StateEntry::from_json("not json"),
+        Err(StateStoreError::Serialization(_))
+    ));
+}
+
+#[test]
+fn test_state_entry_value_reports_shape_mismatch() {
+    let entry = StateEntry::new(&"failure", start(), None).unwrap();
+
+    assert!(matches!(
+        entry.value::<u32>(),
+        Err(StateStoreError::Serialization(_))
+    ));
+}
@@ -0,0 +1,187 @@
+//! # Check Summary
+//!
+//! This module renders the per-check status table that forms the `output_text`
+//! of the Merge Warden check run.
+//!
+//! Each validation check contributes one [`CheckSummaryRow`]. The table shows a
+//! status icon, the check name and a short message. Icons are emoji by default;
+//! when [`CheckSummaryConfig::use_emoji`] is `false` plain-text status words are
+//! used instead so the output stays readable in clients that do not render emoji.
+//!
+//! When [`CheckSummaryConfig::list_checks_on_success`] is set, the rows of a passing
+//! pull request are also rendered as a single line for the check-run summary.
+
+use crate::config::{CheckSeverity, CheckSummaryConfig};
+
+#[cfg(test)]
+#[path = "summary_tests.rs"]
+mod tests;
+
+/// The outcome of a single check as shown in the summary table.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::CheckSeverity;
+/// use merge_warden_core::summary::CheckStatus;
+///
+/// assert_eq!(CheckStatus::Passed.icon(true), "✅");
+/// assert_eq!(CheckStatus::Failed(CheckSeverity::Warning).icon(false), "WARN");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// The check ran and passed.
+    Passed,
+
+    /// The check ran and failed; the severity controls how the failure is shown.
+    Failed(CheckSeverity),
+
+    /// The check was skipped because a bypass rule applied to the PR author.
+    Bypassed,
+
+    /// The check is not enabled for this repository.
+    Skipped,
+}
+
+impl CheckStatus {
+    /// Returns the status icon, either as an emoji or as a plain-text word.
+    ///
+    /// # Arguments
+    ///
+    /// * `use_emoji` - When `true` an emoji is returned; otherwise a status word
+    #[must_use]
+    pub fn icon(self, use_emoji: bool) -> &'static str {
+        match (self, use_emoji) {
+            (Self::Passed, true) => "✅",
+            (Self::Passed, false) => "PASS",
+            (Self::Failed(CheckSeverity::Error), true) => "❌",
+            (Self::Failed(CheckSeverity::Error), false) => "FAIL",
+            (Self::Failed(CheckSeverity::Warning), true) => "⚠️",
+            (Self::Failed(CheckSeverity::Warning), false) => "WARN",
+            (Self::Failed(CheckSeverity::Notice), true) => "ℹ️",
+            (Self::Failed(CheckSeverity::Notice), false) => "NOTE",
+            (Self::Bypassed, true) => "⏭️",
+            (Self::Bypassed, false) => "BYPASSED",
+            (Self::Skipped, true) => "➖",
+            (Self::Skipped, false) => "SKIPPED",
+        }
+    }
+}
+
+/// A single row in the check summary table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckSummaryRow {
+    /// The human-readable name of the check, e.g. `"PR title"`.
+    pub name: String,
+
+    /// The outcome of the check.
+    pub status: CheckStatus,
+
+    /// A short, single-line explanation of the outcome.
+    pub message: String,
+}
+
+impl CheckSummaryRow {
+    /// Creates a new row.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The human-readable name of the check
+    /// * `status` - The outcome of the check
+    /// * `message` - A short explanation of the outcome
+    pub fn new(name: impl Into<String>, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Renders the check summary table as Markdown.
+///
+/// Pipe characters and line breaks inside messages are escaped so that each row
+/// stays on one table line. Returns an empty string when `rows` is empty.
+///
+/// # Arguments
+///
+/// * `rows` - One row per check, in display order
+/// * `config` - Presentation settings
+///
+/// # Returns
+///
+/// The Markdown table.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::CheckSummaryConfig;
+/// use merge_warden_core::summary::{render_check_summary_table, CheckStatus, CheckSummaryRow};
+///
+/// let rows = vec![CheckSummaryRow::new("PR title", CheckStatus::Passed, "Follows Conventional Commits")];
+/// let table = render_check_summary_table(&rows, &CheckSummaryConfig::default());
+/// assert!(table.contains("| ✅ | PR title | Follows Conventional Commits |"));
+/// ```
+#[must_use]
+pub fn render_check_summary_table(rows: &[CheckSummaryRow], config: &CheckSummaryConfig) -> String {
+    if rows.is_empty() {
+        return String::new();
+    }
+
+    let mut table = String::from("| Status | Check | Details |\n| :---: | :--- | :--- |");
+    for row in rows {
+        table.push_str(&format!(
+            "\n| {} | {} | {} |",
+            row.status.icon(config.use_emoji),
+            escape_cell(&row.name),
+            escape_cell(&row.message)
+        ));
+    }
+    table
+}
+
+/// Renders the checks as a single line, such as `✅ PR title · ➖ Checklist (skipped)`.
+///
+/// Skipped and bypassed checks are noted as such when emoji are used; the plain-text
+/// status words already say so. Returns an empty string when `rows` is empty.
+///
+/// # Arguments
+///
+/// * `rows` - One row per check, in display order
+/// * `config` - Presentation settings
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::config::CheckSummaryConfig;
+/// use merge_warden_core::summary::{render_check_summary_line, CheckStatus, CheckSummaryRow};
+///
+/// let rows = vec![
+///     CheckSummaryRow::new("PR title", CheckStatus::Passed, "Follows Conventional Commits"),
+///     CheckSummaryRow::new("Checklist", CheckStatus::Skipped, "Checklist validation is not enabled"),
+/// ];
+/// let line = render_check_summary_line(&rows, &CheckSummaryConfig::default());
+/// assert_eq!(line, "✅ PR title · ➖ Checklist (skipped)");
+/// ```
+#[must_use]
+pub fn render_check_summary_line(rows: &[CheckSummaryRow], config: &CheckSummaryConfig) -> String {
+    rows.iter()
+        .map(|row| {
+            let note = match row.status {
+                CheckStatus::Skipped if config.use_emoji => " (skipped)",
+                CheckStatus::Bypassed if config.use_emoji => " (bypassed)",
+                _ => "",
+            };
+            format!("{} {}{}", row.status.icon(config.use_emoji), row.name, note)
+        })
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+/// Escapes a value so it can be placed inside a single Markdown table cell.
+fn escape_cell(value: &str) -> String {
+    value
+        .replace('|', "\\|")
+        .replace("\r\n", " ")
+        .replace('\n', " ")
+}
@@ -0,0 +1,133 @@
+use super::*;
+
+#[test]
+fn test_icons_with_emoji() {
+    assert_eq!(CheckStatus::Passed.icon(true), "✅");
+    assert_eq!(CheckStatus::Failed(CheckSeverity::Error).icon(true), "❌");
+    assert_eq!(CheckStatus::Failed(CheckSeverity::Warning).icon(true), "⚠️");
+    assert_eq!(CheckStatus::Failed(CheckSeverity::Notice).icon(true), "ℹ️");
+    assert_eq!(CheckStatus::Bypassed.icon(true), "⏭️");
+    assert_eq!(CheckStatus::Skipped.icon(true), "➖");
+}
+
+#[test]
+fn test_icons_without_emoji_are_ascii() {
+    let statuses = [
+        CheckStatus::Passed,
+        CheckStatus::Failed(CheckSeverity::Error),
+        CheckStatus::Failed(CheckSeverity::Warning),
+        CheckStatus::Failed(CheckSeverity::Notice),
+        CheckStatus::Bypassed,
+        CheckStatus::Skipped,
+    ];
+    for status in statuses {
+        assert!(
+            status.icon(false).is_ascii(),
+            "{status:?} should be plain text"
+        );
+    }
+    assert_eq!(
+        CheckStatus::Failed(CheckSeverity::Error).icon(false),
+        "FAIL"
+    );
+}
+
+#[test]
+fn test_render_empty_rows_returns_empty_string() {
+    assert_eq!(
+        render_check_summary_table(&[], &CheckSummaryConfig::default()),
+        ""
+    );
+}
+
+#[test]
+fn test_render_table_with_emoji() {
+    let rows = vec![
+        CheckSummaryRow::new("PR title", CheckStatus::Passed, "OK"),
+        CheckSummaryRow::new(
+            "Work item",
+            CheckStatus::Failed(CheckSeverity::Error),
+            "Missing",
+        ),
+    ];
+
+    let table = render_check_summary_table(&rows, &CheckSummaryConfig::default());
+
+    assert_eq!(
+        table,
+        "| Status | Check | Details |\n| :---: | :--- | :--- |\n| ✅ | PR title | OK |\n| ❌ | Work item | Missing |"
+    );
+}
+
+#[test]
+fn test_render_table_without_emoji() {
+    let rows = vec![CheckSummaryRow::new(
+        "PR size",
+        CheckStatus::Failed(CheckSeverity::Warning),
+        "Oversized",
+    )];
+    let config = CheckSummaryConfig {
+        use_emoji: false,
+        ..Default::default()
+    };
+
+    let table = render_check_summary_table(&rows, &config);
+
+    assert!(table.ends_with("| WARN | PR size | Oversized |"));
+    assert!(table.is_ascii());
+}
+
+#[test]
+fn test_render_table_escapes_cells() {
+    let rows = vec![CheckSummaryRow::new(
+        "PR title",
+        CheckStatus::Passed,
+        "a | b\nc",
+    )];
+
+    let table = render_check_summary_table(&rows, &CheckSummaryConfig::default());
+
+    assert!(table.ends_with("| ✅ | PR title | a \\| b c |"));
+}
+
+#[test]
+fn test_render_line_notes_skipped_and_bypassed_checks() {
+    let rows = vec![
+        CheckSummaryRow::new(
+            "PR title",
+            CheckStatus::Passed,
+            "Follows Conventional Commits",
+        ),
+        CheckSummaryRow::new("Work item", CheckStatus::Bypassed, "Bypassed for `bot`"),
+        CheckSummaryRow::new("Checklist", CheckStatus::Skipped, "Not enabled"),
+    ];
+
+    let line = render_check_summary_line(&rows, &CheckSummaryConfig::default());
+
+    assert_eq!(
+        line,
+        "✅ PR title · ⏭️ Work item (bypassed) · ➖ Checklist (skipped)"
+    );
+}
+
+#[test]
+fn test_render_line_without_emoji() {
+    let rows = vec![
+        CheckSummaryRow::new(
+            "PR title",
+            CheckStatus::Passed,
+            "Follows Conventional Commits",
+        ),
+        CheckSummaryRow::new("Checklist", CheckStatus::Skipped, "Not enabled"),
+    ];
+    let config = CheckSummaryConfig {
+        use_emoji: false,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        render_check_summary_line(&rows, &config),
+        "PASS PR title · SKIPPED Checklist"
+    );
+    assert_eq!(render_check_summary_line(&[], &config), "");
+}
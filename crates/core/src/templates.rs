@@ -0,0 +1,720 @@
+//! # Comment Templates
+//!
+//! This module renders every comment Merge Warden posts on a pull request from a
+//! named template instead of ad-hoc `format!` calls.
+//!
+//! Rendering is abstracted behind the [`CommentRenderer`] trait so that callers can
+//! swap the template engine or the template text without touching the validation
+//! logic. The default implementation, [`TemplateEngineRenderer`], uses the
+//! [MiniJinja](https://docs.rs/minijinja) engine, which supports Jinja2-style
+//! conditionals (`{% if %}`), loops (`{% for %}`) and filters.
+//!
+//! ## Context model
+//!
+//! Each [`CommentTemplate`] is rendered against a fixed context struct. The struct
+//! fields are the variables available inside the template:
+//!
+//! | Template | Context | Variables |
+//! |----------|---------|-----------|
+//! | [`CommentTemplate::OversizedPullRequest`] | [`OversizedPrCommentContext`] | `category`, `category_description`, `total_lines`, `file_count`, `counting_mode`, `largest_files` and `largest_directories` (lists of `path`, `counted_lines`, `file_count`) |
+//! | [`CommentTemplate::InvalidTitle`] | [`InvalidTitleCommentContext`] | `title`, `issues` (list of strings), `suggested_fix` (optional), `pattern`, `examples` |
+//! | [`CommentTemplate::MissingWorkItem`] | [`MissingWorkItemCommentContext`] | `pattern`, `patterns` (list of strings), `examples` |
+//! | [`CommentTemplate::TitleBypassNotice`] | [`BypassNoticeContext`] | `user`, `rule_type`, `title` |
+//! | [`CommentTemplate::WorkItemBypassNotice`] | [`BypassNoticeContext`] | `user`, `rule_type`, `title` |
+//! | [`CommentTemplate::ConclusionTransition`] | [`ConclusionTransitionContext`] | `previous_conclusion` (optional), `conclusion`, `summary` |
+//! | [`CommentTemplate::DraftConversion`] | [`DraftConversionContext`] | `failing_checks` (list of strings) |
+//! | [`CommentTemplate::InvalidConfig`] | [`InvalidConfigCommentContext`] | `errors` (list of strings) |
+//! | [`CommentTemplate::Autofix`] | [`AutofixCommentContext`] | `old_title` and `new_title` (optional), `work_item_reference` (optional), `branch` |
+//!
+//! The hidden comment markers (e.g. `TITLE_COMMENT_MARKER`) are prepended by the
+//! caller after rendering, so custom templates never need to include them.
+//!
+//! ## Configured comment text
+//!
+//! The invalid title, missing work item and bypass notice comments can also be
+//! replaced through `CommentPolicyConfig`. The configured text is registered with
+//! [`TemplateEngineRenderer::with_template`] and rendered against the same context as
+//! the built-in template, so it uses the same `{{ variable }}` syntax, for example
+//! `{{ pattern }}` or `{{ user }}`. Single-brace placeholders such as `{pattern}` are
+//! not replaced; see [`single_brace_placeholders`].
+//!
+//! ## Check summaries
+//!
+//! The check-run summary sentence can also be templated, per outcome, through
+//! `CheckSummaryConfig::templates`. Those templates are rendered with
+//! [`render_check_summary`] against a [`CheckSummaryContext`].
+//!
+//! ## Example
+//!
+//! ```
+//! use merge_warden_core::templates::{
+//!     BypassNoticeContext, CommentRenderer, CommentTemplate, TemplateEngineRenderer,
+//! };
+//!
+//! let renderer = TemplateEngineRenderer::new()
+//!     .with_template(
+//!         CommentTemplate::TitleBypassNotice,
+//!         "Title check skipped for {{ user }}.",
+//!     )
+//!     .expect("template is valid");
+//!
+//! let context = BypassNoticeContext {
+//!     user: "release-bot".to_string(),
+//!     rule_type: "TitleConvention".to_string(),
+//!     title: "release 1.2.3".to_string(),
+//! };
+//! let text = renderer
+//!     .render(CommentTemplate::TitleBypassNotice, &serde_json::to_value(&context).unwrap())
+//!     .expect("template renders");
+//! assert_eq!(text, "Title check skipped for release-bot.");
+//! ```
+
+use crate::errors::TemplateError;
+use crate::size::SizeBreakdownEntry;
+use minijinja::Environment;
+use serde::Serialize;
+use std::fmt;
+use tracing::warn;
+
+#[cfg(test)]
+#[path = "templates_tests.rs"]
+mod tests;
+
+/// Default template for the comment posted on oversized pull requests.
+pub const DEFAULT_OVERSIZED_PR_COMMENT_TEMPLATE: &str = r#"## 📏 Pull Request Size Notice
+
+This PR has been labeled as `{{ category }}` as it contains **{{ total_lines }} lines** of changes across {{ file_count }} files.
+
+### Why does PR size matter?
+
+Research shows that smaller PRs are:
+- ✅ **Reviewed more thoroughly** - reviewers can focus better on smaller changes
+- ✅ **Catch more bugs** - defect detection rates decrease significantly for large PRs
+- ✅ **Merged faster** - less time in review cycles
+- ✅ **Easier to understand** - simpler to reason about the changes
+
+### 💡 Consider breaking this PR into smaller pieces
+
+Large PRs can be challenging to review effectively. Consider:
+
+1. **Separate concerns** - Split unrelated changes into different PRs
+2. **Incremental changes** - Break features into smaller, logical steps
+3. **Preparatory PRs** - Create setup/refactoring PRs before the main feature
+4. **Documentation separately** - Move documentation updates to separate PRs
+
+### Size Breakdown
+- **Total lines changed**: {{ total_lines }}
+- **Lines counted**: {{ counting_mode }}
+- **Files modified**: {{ file_count }}
+- **Category**: {{ category }} ({{ category_description }})
+{% if largest_files %}
+### Largest files
+
+| File | Lines |
+| :--- | ---: |
+{% for entry in largest_files %}| `{{ entry.path }}` | {{ entry.counted_lines }} |
+{% endfor %}{% endif %}{% if largest_directories %}
+### Largest directories
+
+| Directory | Files | Lines |
+| :--- | ---: | ---: |
+{% for entry in largest_directories %}| `{{ entry.path }}` | {{ entry.file_count }} | {{ entry.counted_lines }} |
+{% endfor %}{% endif %}
+*This is an automated message to help improve code review quality. If you believe this PR cannot be reasonably split, please add a comment explaining why.*"#;
+
+/// Default template for the comment posted when the PR title is invalid.
+pub const DEFAULT_INVALID_TITLE_COMMENT_TEMPLATE: &str = r#"
+The pull request title needs correction:
+{% for issue in issues %}- {{ issue }}
+{% endfor %}{% if suggested_fix %}
+Suggested fix: `{{ suggested_fix }}`{% endif %}
+
+Your PR title does not follow the [Conventional Commits](https://www.conventionalcommits.org/) message format.
+- Supported types: feat, fix, docs, style, refactor, perf, test, build, ci, chore, revert
+- Expected format: `<type>(<optional scope>): <description>`
+- Examples:
+* feat(auth): add login functionality
+* fix: resolve null pointer exception
+- For full details, see: https://www.conventionalcommits.org/
+
+Please update the PR title to match the conventional commit message guidelines."#;
+
+/// Default template for the comment posted when the PR body lacks a work item reference.
+pub const DEFAULT_MISSING_WORK_ITEM_COMMENT_TEMPLATE: &str = r#"
+The pull request body needs improvement:
+
+The PR body is missing a valid work item reference.
+- Supported formats:
+    * Prefixes: fixes, closes, resolves, references, relates to
+    * Work Item Identifiers: #XXX or GH-XXX
+- Examples:
+    * fixes #1234
+    * closes GH-5678
+    * resolves #9012
+    * references GH-3456
+    * relates to #7890
+
+Please update the PR body to include a valid work item reference."#;
+
+/// Default template for the notice posted when title validation was bypassed.
+pub const DEFAULT_TITLE_BYPASS_NOTICE_TEMPLATE: &str = r#"⚠️ **Title Validation Bypassed**
+
+The PR title validation was bypassed for user `{{ user }}`.
+- Original title: `{{ title }}`
+- Bypass rule: {{ rule_type }}
+- Bypassed by: {{ user }}
+
+**Note**: This PR may not follow conventional commit format but was allowed due to bypass permissions.
+
+---
+For more information about conventional commits, see: https://www.conventionalcommits.org/
+"#;
+
+/// Default template for the notice posted when work item validation was bypassed.
+pub const DEFAULT_WORK_ITEM_BYPASS_NOTICE_TEMPLATE: &str = r#"⚠️ **Work Item Validation Bypassed**
+
+The work item reference validation was bypassed for user `{{ user }}`.
+- PR description may not contain required work item references
+- Bypass rule: {{ rule_type }}
+- Bypassed by: {{ user }}
+
+**Note**: This PR was allowed to proceed without work item references due to bypass permissions.
+"#;
+
+/// Default template for the comment posted when the check conclusion changed.
+pub const DEFAULT_CONCLUSION_TRANSITION_COMMENT_TEMPLATE: &str = r#"**Merge Warden** checks {% if previous_conclusion %}changed from `{{ previous_conclusion }}` to `{{ conclusion }}`{% else %}concluded `{{ conclusion }}`{% endif %}.
+
+{{ summary }}"#;
+
+/// Default template for the comment posted when a failing pull request was converted
+/// to a draft.
+pub const DEFAULT_DRAFT_CONVERSION_COMMENT_TEMPLATE: &str = r#"**Merge Warden** converted this pull request to a draft because these checks failed: {{ failing_checks | join(", ") }}.
+
+Fix the issues reported in the check, then mark the pull request as ready for review. It will not be converted to a draft again."#;
+
+/// Default template for the comment posted when a pull request introduces an invalid
+/// `.github/merge-warden.toml`.
+pub const DEFAULT_INVALID_CONFIG_COMMENT_TEMPLATE: &str = r#"⚠️ **Invalid merge-warden configuration**
+
+The `.github/merge-warden.toml` file introduced in this pull request contains errors and will not be used if this PR is merged.
+
+**Errors found:**
+
+{% for error in errors %}- {{ error }}
+{% endfor %}
+Please fix the configuration before merging."#;
+
+/// Default template for the comment posted when Merge Warden edited a pull request to
+/// fix failing checks.
+pub const DEFAULT_AUTOFIX_COMMENT_TEMPLATE: &str = r#"**Merge Warden** edited this pull request to fix failing checks:
+
+{% if new_title %}- Changed the title from `{{ old_title }}` to `{{ new_title }}`.
+{% endif %}{% if work_item_reference %}- Added `{{ work_item_reference }}` to the description, based on the branch name `{{ branch }}`.
+{% endif %}
+If a change is not what you meant, edit the pull request again. Merge Warden only makes these changes while the check fails."#;
+
+/// Example titles, one per line, for the `examples` of [`InvalidTitleCommentContext`].
+pub const TITLE_COMMENT_EXAMPLES: &str =
+    "* feat(auth): add login functionality\n* fix: resolve null pointer exception";
+
+/// Example work item references, one per line, for the `examples` of
+/// [`MissingWorkItemCommentContext`].
+pub const WORK_ITEM_COMMENT_EXAMPLES: &str =
+    "* fixes #1234\n* closes GH-5678\n* resolves #9012\n* references GH-3456\n* relates to #7890";
+
+/// Identifies one of the comments Merge Warden can post on a pull request.
+///
+/// Each variant has a stable template name (see [`CommentTemplate::name`]) and a
+/// built-in default source (see [`CommentTemplate::default_source`]).
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::templates::CommentTemplate;
+///
+/// assert_eq!(CommentTemplate::InvalidTitle.name(), "invalid_title_comment.md");
+/// assert!(CommentTemplate::InvalidTitle.default_source().contains("{% for issue in issues %}"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommentTemplate {
+    /// Size notice posted on pull requests in an oversized category.
+    OversizedPullRequest,
+
+    /// Guidance posted when the title does not follow Conventional Commits.
+    InvalidTitle,
+
+    /// Guidance posted when the body has no work item reference.
+    MissingWorkItem,
+
+    /// Notice posted when title validation was bypassed.
+    TitleBypassNotice,
+
+    /// Notice posted when work item validation was bypassed.
+    WorkItemBypassNotice,
+
+    /// Summary posted when the check conclusion changed since the previous run.
+    ConclusionTransition,
+
+    /// Notice posted when a failing pull request was converted to a draft.
+    DraftConversion,
+
+    /// Errors found in a `.github/merge-warden.toml` introduced by the pull request.
+    InvalidConfig,
+
+    /// Summary of the edits made to fix failing checks.
+    Autofix,
+}
+
+impl CommentTemplate {
+    /// All comment templates, in a stable order.
+    pub const ALL: [CommentTemplate; 9] = [
+        CommentTemplate::OversizedPullRequest,
+        CommentTemplate::InvalidTitle,
+        CommentTemplate::MissingWorkItem,
+        CommentTemplate::TitleBypassNotice,
+        CommentTemplate::WorkItemBypassNotice,
+        CommentTemplate::ConclusionTransition,
+        CommentTemplate::DraftConversion,
+        CommentTemplate::InvalidConfig,
+        CommentTemplate::Autofix,
+    ];
+
+    /// Returns the stable name under which the template is registered.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::OversizedPullRequest => "oversized_pr_comment.md",
+            Self::InvalidTitle => "invalid_title_comment.md",
+            Self::MissingWorkItem => "missing_work_item_comment.md",
+            Self::TitleBypassNotice => "title_bypass_notice.md",
+            Self::WorkItemBypassNotice => "work_item_bypass_notice.md",
+            Self::ConclusionTransition => "conclusion_transition_comment.md",
+            Self::DraftConversion => "draft_conversion_comment.md",
+            Self::InvalidConfig => "invalid_config_comment.md",
+            Self::Autofix => "autofix_comment.md",
+        }
+    }
+
+    /// Returns the built-in template source shipped with Merge Warden.
+    #[must_use]
+    pub fn default_source(self) -> &'static str {
+        match self {
+            Self::OversizedPullRequest => DEFAULT_OVERSIZED_PR_COMMENT_TEMPLATE,
+            Self::InvalidTitle => DEFAULT_INVALID_TITLE_COMMENT_TEMPLATE,
+            Self::MissingWorkItem => DEFAULT_MISSING_WORK_ITEM_COMMENT_TEMPLATE,
+            Self::TitleBypassNotice => DEFAULT_TITLE_BYPASS_NOTICE_TEMPLATE,
+            Self::WorkItemBypassNotice => DEFAULT_WORK_ITEM_BYPASS_NOTICE_TEMPLATE,
+            Self::ConclusionTransition => DEFAULT_CONCLUSION_TRANSITION_COMMENT_TEMPLATE,
+            Self::DraftConversion => DEFAULT_DRAFT_CONVERSION_COMMENT_TEMPLATE,
+            Self::InvalidConfig => DEFAULT_INVALID_CONFIG_COMMENT_TEMPLATE,
+            Self::Autofix => DEFAULT_AUTOFIX_COMMENT_TEMPLATE,
+        }
+    }
+}
+
+impl fmt::Display for CommentTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Context for [`CommentTemplate::OversizedPullRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OversizedPrCommentContext {
+    /// The display name of the size category, e.g. `"XL"`.
+    pub category: String,
+
+    /// A human-readable description of the size category.
+    pub category_description: String,
+
+    /// Total number of changed lines counted towards the size.
+    pub total_lines: u32,
+
+    /// Number of files counted towards the size.
+    pub file_count: usize,
+
+    /// What was counted towards `total_lines`, from [`SizeCountingMode::description`],
+    /// e.g. `"added lines only"`.
+    ///
+    /// [`SizeCountingMode::description`]: crate::size::SizeCountingMode::description
+    pub counting_mode: String,
+
+    /// The counted files with the most lines, largest first; empty when the breakdown
+    /// is disabled.
+    pub largest_files: Vec<SizeBreakdownEntry>,
+
+    /// The top-level directories with the most counted lines, largest first; empty when
+    /// the breakdown is disabled.
+    pub largest_directories: Vec<SizeBreakdownEntry>,
+}
+
+/// Context for [`CommentTemplate::InvalidTitle`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvalidTitleCommentContext {
+    /// The PR title as received from the provider.
+    pub title: String,
+
+    /// One human-readable message per diagnosed title issue.
+    pub issues: Vec<String>,
+
+    /// A best-effort corrected title, when one could be inferred.
+    pub suggested_fix: Option<String>,
+
+    /// The pattern the title must match.
+    pub pattern: String,
+
+    /// Example titles, one per line; see [`TITLE_COMMENT_EXAMPLES`].
+    pub examples: String,
+}
+
+/// Context for [`CommentTemplate::MissingWorkItem`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MissingWorkItemCommentContext {
+    /// The configured work item patterns, joined with `", "`.
+    pub pattern: String,
+
+    /// The configured work item patterns; a reference must match any one of them.
+    pub patterns: Vec<String>,
+
+    /// Example work item references, one per line; see [`WORK_ITEM_COMMENT_EXAMPLES`].
+    pub examples: String,
+}
+
+/// Context for [`CommentTemplate::TitleBypassNotice`] and
+/// [`CommentTemplate::WorkItemBypassNotice`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BypassNoticeContext {
+    /// The login of the user whose bypass permission was applied.
+    pub user: String,
+
+    /// The bypassed rule, as displayed by `BypassRuleType`.
+    pub rule_type: String,
+
+    /// The PR title at the time of the bypass.
+    pub title: String,
+}
+
+/// Context for [`CommentTemplate::ConclusionTransition`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConclusionTransitionContext {
+    /// The conclusion of the previous run, or `None` when no run was recorded.
+    pub previous_conclusion: Option<String>,
+
+    /// The conclusion of this run, e.g. `"failure"`.
+    pub conclusion: String,
+
+    /// The check summary of this run.
+    pub summary: String,
+}
+
+/// Context for [`CommentTemplate::DraftConversion`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DraftConversionContext {
+    /// The display names of the blocking checks that failed, e.g. `["PR title"]`.
+    pub failing_checks: Vec<String>,
+}
+
+/// Context for [`CommentTemplate::InvalidConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvalidConfigCommentContext {
+    /// One message per problem found in the configuration file.
+    pub errors: Vec<String>,
+}
+
+/// Context for [`CommentTemplate::Autofix`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AutofixCommentContext {
+    /// The title before the edit, when the title was changed.
+    pub old_title: Option<String>,
+
+    /// The title after the edit, when the title was changed.
+    pub new_title: Option<String>,
+
+    /// The work item reference added to the description, e.g. `"Relates to #123"`.
+    pub work_item_reference: Option<String>,
+
+    /// The name of the head branch the work item reference was taken from.
+    pub branch: String,
+}
+
+/// Renders pull request comments from named templates.
+///
+/// Implementations receive the template identifier and a JSON representation of
+/// the matching context struct (see the [module documentation](self) for the
+/// context model). Implementations must be thread-safe because a single renderer
+/// is shared by every pull request processed by a `MergeWarden` instance.
+pub trait CommentRenderer: Send + Sync + fmt::Debug {
+    /// Renders the given template against the supplied context.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The comment to render
+    /// * `context` - The serialized context struct for the template
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateError`] when the template cannot be rendered.
+    fn render(
+        &self,
+        template: CommentTemplate,
+        context: &serde_json::Value,
+    ) -> Result<String, TemplateError>;
+}
+
+/// The default [`CommentRenderer`], backed by the MiniJinja template engine.
+///
+/// Created with the built-in templates registered; individual templates can be
+/// replaced with [`TemplateEngineRenderer::with_template`].
+///
+/// Trailing newlines in template sources are preserved and no HTML escaping is
+/// applied, because the output is Markdown.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::templates::{CommentRenderer, CommentTemplate, TemplateEngineRenderer};
+/// use serde_json::json;
+///
+/// let renderer = TemplateEngineRenderer::new();
+/// let text = renderer
+///     .render(
+///         CommentTemplate::InvalidTitle,
+///         &json!({ "title": "bad", "issues": ["No prefix."], "suggested_fix": null }),
+///     )
+///     .unwrap();
+/// assert!(text.contains("- No prefix."));
+/// ```
+pub struct TemplateEngineRenderer {
+    /// The template environment holding every registered template.
+    env: Environment<'static>,
+}
+
+impl TemplateEngineRenderer {
+    /// Creates a renderer with every built-in template registered.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut env = Environment::new();
+        env.set_keep_trailing_newline(true);
+        for template in CommentTemplate::ALL {
+            env.add_template(template.name(), template.default_source())
+                .expect("built-in comment templates are valid");
+        }
+        Self { env }
+    }
+
+    /// Replaces the source of a single template.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The comment whose template should be replaced
+    /// * `source` - The new template source
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateError::Syntax`] when `source` cannot be parsed.
+    pub fn with_template(
+        mut self,
+        template: CommentTemplate,
+        source: impl Into<String>,
+    ) -> Result<Self, TemplateError> {
+        self.env
+            .add_template_owned(template.name(), source.into())
+            .map_err(|e| TemplateError::Syntax {
+                template: template.name().to_string(),
+                message: e.to_string(),
+            })?;
+        Ok(self)
+    }
+}
+
+impl Default for TemplateEngineRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for TemplateEngineRenderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TemplateEngineRenderer")
+            .field(
+                "templates",
+                &self
+                    .env
+                    .templates()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl CommentRenderer for TemplateEngineRenderer {
+    fn render(
+        &self,
+        template: CommentTemplate,
+        context: &serde_json::Value,
+    ) -> Result<String, TemplateError> {
+        let render_error = |e: minijinja::Error| TemplateError::Render {
+            template: template.name().to_string(),
+            message: e.to_string(),
+        };
+        self.env
+            .get_template(template.name())
+            .map_err(render_error)?
+            .render(context)
+            .map_err(render_error)
+    }
+}
+
+/// Context for a check summary template, see `CheckSummaryConfig::templates`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckSummaryContext {
+    /// Whether every check passed.
+    pub passed: bool,
+
+    /// The configuration keys of the checks that failed, in check order, e.g.
+    /// `["title", "work_item"]`.
+    pub failing_rules: Vec<String>,
+
+    /// The display names of the checks that failed, e.g. `["PR title", "Work item"]`.
+    pub failing_checks: Vec<String>,
+
+    /// One short phrase per problem found, e.g. `"title is invalid"`.
+    pub issues: Vec<String>,
+
+    /// The display names of the report-only checks that would fail.
+    pub report_only_failures: Vec<String>,
+
+    /// The number of validations bypassed.
+    pub bypass_count: usize,
+
+    /// Whether the pull request is a draft.
+    pub draft: bool,
+
+    /// The summary Merge Warden uses when no template applies.
+    pub default_summary: String,
+}
+
+/// Renders a check summary template.
+///
+/// Surrounding whitespace is removed from the result.
+///
+/// # Arguments
+///
+/// * `source` - The MiniJinja template source
+/// * `context` - The outcome of the checks
+///
+/// # Errors
+///
+/// Returns [`TemplateError::Syntax`] when `source` cannot be parsed and
+/// [`TemplateError::Render`] when it cannot be rendered.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::templates::{render_check_summary, CheckSummaryContext};
+///
+/// let context = CheckSummaryContext {
+///     passed: false,
+///     failing_rules: vec!["title".to_string()],
+///     failing_checks: vec!["PR title".to_string()],
+///     issues: vec!["title is invalid".to_string()],
+///     report_only_failures: vec![],
+///     bypass_count: 0,
+///     draft: false,
+///     default_summary: "PR title is invalid.".to_string(),
+/// };
+///
+/// let summary = render_check_summary(
+///     "{{ default_summary }} Failing: {{ failing_rules | join(', ') }}.",
+///     &context,
+/// )
+/// .unwrap();
+/// assert_eq!(summary, "PR title is invalid. Failing: title.");
+/// ```
+pub fn render_check_summary(
+    source: &str,
+    context: &CheckSummaryContext,
+) -> Result<String, TemplateError> {
+    const NAME: &str = "check_summary";
+    let mut env = Environment::new();
+    env.add_template_owned(NAME, source.to_string())
+        .map_err(|e| TemplateError::Syntax {
+            template: NAME.to_string(),
+            message: e.to_string(),
+        })?;
+    env.get_template(NAME)
+        .and_then(|template| template.render(context))
+        .map(|text| text.trim().to_string())
+        .map_err(|e| TemplateError::Render {
+            template: NAME.to_string(),
+            message: e.to_string(),
+        })
+}
+
+/// Returns the names of the single-brace placeholders, such as `{pattern}`, in a
+/// configured template.
+///
+/// Configured comment text is a template, so variables are written `{{ pattern }}`.
+/// A single-brace placeholder is copied into the comment as-is, which is almost
+/// always a mistake; callers log a warning for it.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::templates::single_brace_placeholders;
+///
+/// assert_eq!(
+///     single_brace_placeholders("Match {pattern}, e.g. {{ examples }}."),
+///     vec!["pattern".to_string()]
+/// );
+/// assert!(single_brace_placeholders("Match `{{ pattern }}`.").is_empty());
+/// ```
+pub fn single_brace_placeholders(source: &str) -> Vec<String> {
+    let placeholder = regex::Regex::new(r"\{+\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}+")
+        .expect("placeholder pattern is valid");
+    placeholder
+        .captures_iter(source)
+        .filter(|captures| {
+            let text = &captures[0];
+            !text.starts_with("{{") && !text.ends_with("}}")
+        })
+        .map(|captures| captures[1].to_string())
+        .collect()
+}
+
+/// Renders a comment, falling back to the built-in template if rendering fails.
+///
+/// A broken custom template must never stop Merge Warden from communicating with
+/// the PR author, so failures are logged at `warn` level and the default template
+/// is used instead.
+///
+/// # Arguments
+///
+/// * `renderer` - The renderer to use
+/// * `template` - The comment to render
+/// * `context` - The context struct for the template
+///
+/// # Returns
+///
+/// The rendered comment text.
+pub fn render_comment<C: Serialize>(
+    renderer: &dyn CommentRenderer,
+    template: CommentTemplate,
+    context: &C,
+) -> String {
+    let value = serde_json::to_value(context).unwrap_or(serde_json::Value::Null);
+    match renderer.render(template, &value) {
+        Ok(text) => text,
+        Err(e) => {
+            warn!(
+                template = template.name(),
+                error = e.to_string(),
+                "Failed to render comment template. Falling back to the built-in template."
+            );
+            TemplateEngineRenderer::new()
+                .render(template, &value)
+                .unwrap_or_default()
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use super::*;
+use crate::config::CONVENTIONAL_COMMIT_REGEX;
+use serde_json::json;
+
+fn render_default<C: Serialize>(template: CommentTemplate, context: &C) -> String {
+    render_comment(&TemplateEngineRenderer::new(), template, context)
+}
+
+#[test]
+fn test_all_default_templates_are_registered() {
+    let renderer = TemplateEngineRenderer::new();
+    for template in CommentTemplate::ALL {
+        assert!(
+            renderer.env.get_template(template.name()).is_ok(),
+            "template {template} should be registered"
+        );
+    }
+}
+
+#[test]
+fn test_template_names_are_unique() {
+    let mut names: Vec<&str> = CommentTemplate::ALL.iter().map(|t| t.name()).collect();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), CommentTemplate::ALL.len());
+}
+
+#[test]
+fn test_invalid_title_template_with_issues_and_fix() {
+    let context = InvalidTitleCommentContext {
+        title: "FEAT: add login".to_string(),
+        issues: vec!["First issue.".to_string(), "Second issue.".to_string()],
+        suggested_fix: Some("feat: add login".to_string()),
+        pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
+        examples: TITLE_COMMENT_EXAMPLES.to_string(),
+    };
+
+    let text = render_default(CommentTemplate::InvalidTitle, &context);
+
+    assert!(text.starts_with(
+        "\nThe pull request title needs correction:\n- First issue.\n- Second issue.\n\nSuggested fix: `feat: add login`\n\nYour PR title does not follow"
+    ));
+    assert!(text.ends_with(
+        "Please update the PR title to match the conventional commit message guidelines."
+    ));
+}
+
+#[test]
+fn test_invalid_title_template_without_fix() {
+    let context = InvalidTitleCommentContext {
+        title: "Add login".to_string(),
+        issues: vec!["No prefix.".to_string()],
+        suggested_fix: None,
+        pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
+        examples: TITLE_COMMENT_EXAMPLES.to_string(),
+    };
+
+    let text = render_default(CommentTemplate::InvalidTitle, &context);
+
+    assert!(text.starts_with(
+        "\nThe pull request title needs correction:\n- No prefix.\n\n\nYour PR title does not follow"
+    ));
+    assert!(!text.contains("Suggested fix"));
+}
+
+#[test]
+fn test_invalid_title_template_without_issues() {
+    let context = InvalidTitleCommentContext {
+        title: "Add login".to_string(),
+        issues: vec![],
+        suggested_fix: None,
+        pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
+        examples: TITLE_COMMENT_EXAMPLES.to_string(),
+    };
+
+    let text = render_default(CommentTemplate::InvalidTitle, &context);
+
+    assert!(text.starts_with("\nThe pull request title needs correction:\n\n\nYour PR title"));
+}
+
+#[test]
+fn test_title_bypass_notice_matches_legacy_text() {
+    let context = BypassNoticeContext {
+        user: "release-bot".to_string(),
+        rule_type: "TitleConvention".to_string(),
+        title: "release 1.0".to_string(),
+    };
+
+    let text = render_default(CommentTemplate::TitleBypassNotice, &context);
+
+    let expected = indoc::formatdoc!(
+        r#"
+        ⚠️ **Title Validation Bypassed**
+
+        The PR title validation was bypassed for user `{user}`.
+        - Original title: `{title}`
+        - Bypass rule: {rule_type}
+        - Bypassed by: {user}
+
+        **Note**: This PR may not follow conventional commit format but was allowed due to bypass permissions.
+
+        ---
+        For more information about conventional commits, see: https://www.conventionalcommits.org/
+        "#,
+        user = "release-bot",
+        title = "release 1.0",
+        rule_type = "TitleConvention"
+    );
+    assert_eq!(text, expected);
+}
+
+#[test]
+fn test_work_item_bypass_notice_matches_legacy_text() {
+    let context = BypassNoticeContext {
+        user: "release-bot".to_string(),
+        rule_type: "WorkItemReference".to_string(),
+        title: "release 1.0".to_string(),
+    };
+
+    let text = render_default(CommentTemplate::WorkItemBypassNotice, &context);
+
+    let expected = indoc::formatdoc!(
+        r#"
+        ⚠️ **Work Item Validation Bypassed**
+
+        The work item reference validation was bypassed for user `{user}`.
+        - PR description may not contain required work item references
+        - Bypass rule: {rule_type}
+        - Bypassed by: {user}
+
+        **Note**: This PR was allowed to proceed without work item references due to bypass permissions.
+        "#,
+        user = "release-bot",
+        rule_type = "WorkItemReference"
+    );
+    assert_eq!(text, expected);
+}
+
+#[test]
+fn test_single_brace_placeholders_ignores_template_variables() {
+    let found = single_brace_placeholders(
+        "{user} bypassed {{ rule_type }} on `{ title }`; {{user}} and {{{ odd }}} and { not a name }",
+    );
+
+    assert_eq!(found, vec!["user".to_string(), "title".to_string()]);
+}
+
+#[test]
+fn test_replaced_guidance_template_can_use_pattern_and_examples() {
+    let renderer = TemplateEngineRenderer::new()
+        .with_template(
+            CommentTemplate::MissingWorkItem,
+            "Match `{{ pattern }}`:\n{{ examples }}",
+        )
+        .unwrap();
+    let context = MissingWorkItemCommentContext {
+        pattern: "#\\d+".to_string(),
+        patterns: vec!["#\\d+".to_string()],
+        examples: WORK_ITEM_COMMENT_EXAMPLES.to_string(),
+    };
+
+    let text = render_comment(&renderer, CommentTemplate::MissingWorkItem, &context);
+
+    assert_eq!(
+        text,
+        format!("Match `#\\d+`:\n{WORK_ITEM_COMMENT_EXAMPLES}")
+    );
+}
+
+#[test]
+fn test_conclusion_transition_template_matches_legacy_text() {
+    let mut context = ConclusionTransitionContext {
+        previous_conclusion: Some("failure".to_string()),
+        conclusion: "success".to_string(),
+        summary: "All checks passed.".to_string(),
+    };
+
+    assert_eq!(
+        render_default(CommentTemplate::ConclusionTransition, &context),
+        "**Merge Warden** checks changed from `failure` to `success`.\n\nAll checks passed."
+    );
+
+    context.previous_conclusion = None;
+    context.conclusion = "failure".to_string();
+    context.summary = "PR title is invalid.".to_string();
+    assert_eq!(
+        render_default(CommentTemplate::ConclusionTransition, &context),
+        "**Merge Warden** checks concluded `failure`.\n\nPR title is invalid."
+    );
+}
+
+#[test]
+fn test_draft_conversion_template_matches_legacy_text() {
+    let context = DraftConversionContext {
+        failing_checks: vec!["PR title".to_string(), "Work item".to_string()],
+    };
+
+    assert_eq!(
+        render_default(CommentTemplate::DraftConversion, &context),
+        "**Merge Warden** converted this pull request to a draft because these checks failed: PR title, Work item.\n\nFix the issues reported in the check, then mark the pull request as ready for review. It will not be converted to a draft again."
+    );
+}
+
+#[test]
+fn test_invalid_config_template_matches_legacy_text() {
+    let context = InvalidConfigCommentContext {
+        errors: vec![
+            "schemaVersion must be 1, found 99".to_string(),
+            "unknown field `foo`".to_string(),
+        ],
+    };
+
+    assert_eq!(
+        render_default(CommentTemplate::InvalidConfig, &context),
+        "⚠️ **Invalid merge-warden configuration**\n\nThe `.github/merge-warden.toml` file introduced in this pull request contains errors and will not be used if this PR is merged.\n\n**Errors found:**\n\n- schemaVersion must be 1, found 99\n- unknown field `foo`\n\nPlease fix the configuration before merging."
+    );
+}
+
+#[test]
+fn test_autofix_template_matches_legacy_text() {
+    let mut context = AutofixCommentContext {
+        old_title: Some("FEAT: add login".to_string()),
+        new_title: Some("feat: add login".to_string()),
+        work_item_reference: Some("Relates to #123".to_string()),
+        branch: "feature/123-add-login".to_string(),
+    };
+
+    assert_eq!(
+        render_default(CommentTemplate::Autofix, &context),
+        "**Merge Warden** edited this pull request to fix failing checks:\n\n- Changed the title from `FEAT: add login` to `feat: add login`.\n- Added `Relates to #123` to the description, based on the branch name `feature/123-add-login`.\n\nIf a change is not what you meant, edit the pull request again. Merge Warden only makes these changes while the check fails."
+    );
+
+    context.work_item_reference = None;
+    assert_eq!(
+        render_default(CommentTemplate::Autofix, &context),
+        "**Merge Warden** edited this pull request to fix failing checks:\n\n- Changed the title from `FEAT: add login` to `feat: add login`.\n\nIf a change is not what you meant, edit the pull request again. Merge Warden only makes these changes while the check fails."
+    );
+}
+
+#[test]
+fn test_values_are_not_html_escaped() {
+    let context = BypassNoticeContext {
+        user: "bot".to_string(),
+        rule_type: "TitleConvention".to_string(),
+        title: "fix: handle <T> & \"quotes\"".to_string(),
+    };
+
+    let text = render_default(CommentTemplate::TitleBypassNotice, &context);
+
+    assert!(text.contains("`fix: handle <T> & \"quotes\"`"));
+}
+
+#[test]
+fn test_with_template_overrides_single_template() {
+    let renderer = TemplateEngineRenderer::new()
+        .with_template(
+            CommentTemplate::MissingWorkItem,
+            "Please link an issue matching `{{ pattern }}`.",
+        )
+        .unwrap();
+
+    let text = renderer
+        .render(
+            CommentTemplate::MissingWorkItem,
+            &json!({ "pattern": "#\\d+" }),
+        )
+        .unwrap();
+    assert_eq!(text, "Please link an issue matching `#\\d+`.");
+
+    // Other templates keep their defaults.
+    let other = renderer
+        .render(
+            CommentTemplate::InvalidTitle,
+            &json!({ "title": "x", "issues": [], "suggested_fix": null }),
+        )
+        .unwrap();
+    assert!(other.contains("Conventional Commits"));
+}
+
+#[test]
+fn test_with_template_supports_loops_and_conditionals() {
+    let renderer = TemplateEngineRenderer::new()
+        .with_template(
+            CommentTemplate::InvalidTitle,
+            "{% for issue in issues %}* {{ issue }}\n{% endfor %}{% if suggested_fix %}Try: {{ suggested_fix }}{% endif %}",
+        )
+        .unwrap();
+
+    let text = renderer
+        .render(
+            CommentTemplate::InvalidTitle,
+            &json!({ "title": "x", "issues": ["a", "b"], "suggested_fix": "feat: x" }),
+        )
+        .unwrap();
+    assert_eq!(text, "* a\n* b\nTry: feat: x");
+}
+
+#[test]
+fn test_with_template_rejects_invalid_syntax() {
+    let result =
+        TemplateEngineRenderer::new().with_template(CommentTemplate::InvalidTitle, "{% if %}");
+
+    match result {
+        Err(TemplateError::Syntax { template, .. }) => {
+            assert_eq!(template, "invalid_title_comment.md");
+        }
+        other => panic!("expected syntax error, got {other:?}"),
+    }
+}
+
+#[derive(Debug)]
+struct FailingRenderer;
+
+impl CommentRenderer for FailingRenderer {
+    fn render(
+        &self,
+        template: CommentTemplate,
+        _context: &serde_json::Value,
+    ) -> Result<String, TemplateError> {
+        Err(TemplateError::Render {
+            template: template.name().to_string(),
+            message: "boom".to_string(),
+        })
+    }
+}
+
+#[test]
+fn test_render_comment_falls_back_to_default_on_failure() {
+    let context = MissingWorkItemCommentContext {
+        pattern: "#\\d+".to_string(),
+        patterns: vec!["#\\d+".to_string()],
+        examples: WORK_ITEM_COMMENT_EXAMPLES.to_string(),
+    };
+
+    let text = render_comment(&FailingRenderer, CommentTemplate::MissingWorkItem, &context);
+
+    assert_eq!(text, DEFAULT_MISSING_WORK_ITEM_COMMENT_TEMPLATE);
+}
+
+#[test]
+fn test_invalid_title_template_matches_legacy_text() {
+    let format_reminder = "\
+Your PR title does not follow the [Conventional Commits](https://www.conventionalcommits.org/) message format.\n\
+- Supported types: feat, fix, docs, style, refactor, perf, test, build, ci, chore, revert\n\
+- Expected format: `<type>(<optional scope>): <description>`\n\
+- Examples:\n\
+    * feat(auth): add login functionality\n\
+    * fix: resolve null pointer exception\n\
+- For full details, see: https://www.conventionalcommits.org/\n\
+\n\
+Please update the PR title to match the conventional commit message guidelines.";
+    let expected = format!(
+        "\nThe pull request title needs correction:\n- Issue.\n\nSuggested fix: `feat: x`\n\n{format_reminder}"
+    );
+
+    let text = render_default(
+        CommentTemplate::InvalidTitle,
+        &InvalidTitleCommentContext {
+            title: "FEAT: x".to_string(),
+            issues: vec!["Issue.".to_string()],
+            suggested_fix: Some("feat: x".to_string()),
+            pattern: CONVENTIONAL_COMMIT_REGEX.to_string(),
+            examples: TITLE_COMMENT_EXAMPLES.to_string(),
+        },
+    );
+
+    assert_eq!(text, expected);
+}
+
+fn check_summary_context() -> CheckSummaryContext {
+    CheckSummaryContext {
+        passed: false,
+        failing_rules: vec!["title".to_string(), "work_item".to_string()],
+        failing_checks: vec!["PR title".to_string(), "Work item".to_string()],
+        issues: vec!["PR title is invalid.".to_string()],
+        report_only_failures: Vec::new(),
+        bypass_count: 0,
+        draft: false,
+        default_summary: "PR title is invalid.".to_string(),
+    }
+}
+
+#[test]
+fn test_render_check_summary_exposes_the_context() {
+    let summary = render_check_summary(
+        "  {{ default_summary }} Fix: {{ failing_checks | join(', ') }}.\n",
+        &check_summary_context(),
+    )
+    .unwrap();
+
+    assert_eq!(summary, "PR title is invalid. Fix: PR title, Work item.");
+    assert_eq!(
+        render_check_summary("{{ 'x' * 70000 }}", &check_summary_context())
+            .unwrap()
+            .len(),
+        70_000
+    );
+}
+
+#[test]
+fn test_render_check_summary_reports_syntax_errors() {
+    let error = render_check_summary("{% if %}", &check_summary_context()).unwrap_err();
+
+    assert!(matches!(error, TemplateError::Syntax { .. }));
+}
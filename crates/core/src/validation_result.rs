@@ -50,9 +50,21 @@ use serde::{Deserialize, Serialize};
 /// assert!(result.bypass_used);
 /// assert!(result.bypass_info.is_some());
 /// ```
+///
+/// ## Not run
+/// ```
+/// use merge_warden_core::validation_result::ValidationResult;
+///
+/// let result = ValidationResult::skipped("Size checking is not enabled");
+/// assert!(result.is_valid);
+/// assert!(result.is_skipped());
+/// assert_eq!(result.skip_reason(), Some("Size checking is not enabled"));
+/// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ValidationResult {
-    /// Whether the validation passed (either valid content or bypassed)
+    /// Whether the validation passed (either valid content or bypassed).
+    ///
+    /// Also `true` for a check that was not run, so that it does not block the PR.
     pub is_valid: bool,
 
     /// Whether a bypass rule was used to make this validation pass
@@ -60,6 +72,10 @@ pub struct ValidationResult {
 
     /// Detailed information about the bypass, if used
     pub bypass_info: Option<BypassInfo>,
+
+    /// Why the check was not run, e.g. because it is disabled. `None` when it ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
 }
 
 /// Information about a bypass that was used during validation
@@ -102,6 +118,10 @@ pub struct BypassInfo {
 /// match rule {
 ///     BypassRuleType::TitleConvention => println!("Title validation was bypassed"),
 ///     BypassRuleType::WorkItemReference => println!("Work item validation was bypassed"),
+///     BypassRuleType::PrSize => println!("Size validation was bypassed"),
+///     BypassRuleType::AllRules => println!("Merge Warden was disabled"),
+///     BypassRuleType::SignedCommits => println!("Signed commits check was bypassed"),
+///     BypassRuleType::RevertReference => println!("Revert reference check was bypassed"),
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -111,6 +131,18 @@ pub enum BypassRuleType {
 
     /// Work item reference validation was bypassed
     WorkItemReference,
+
+    /// PR size validation was bypassed by a bypass rule or a size exclusion
+    PrSize,
+
+    /// Merge Warden was disabled on the PR, bypassing every rule
+    AllRules,
+
+    /// The signed commits check was bypassed
+    SignedCommits,
+
+    /// The revert reference check was bypassed
+    RevertReference,
 }
 
 impl ValidationResult {
@@ -132,6 +164,7 @@ impl ValidationResult {
             is_valid: true,
             bypass_used: false,
             bypass_info: None,
+            skip_reason: None,
         }
     }
 
@@ -153,6 +186,7 @@ impl ValidationResult {
             is_valid: false,
             bypass_used: false,
             bypass_info: None,
+            skip_reason: None,
         }
     }
 
@@ -185,6 +219,36 @@ impl ValidationResult {
             is_valid: true,
             bypass_used: true,
             bypass_info: Some(bypass_info),
+            skip_reason: None,
+        }
+    }
+
+    /// Creates a validation result for a check that was not run
+    ///
+    /// Use this when a check is disabled or was not reached, so that it can be told
+    /// apart from a check that passed. The result counts as valid and does not block
+    /// the PR.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - Why the check was not run, shown in the check summary
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::validation_result::ValidationResult;
+    ///
+    /// let result = ValidationResult::skipped("Title validation is not enabled");
+    /// assert!(result.is_valid());
+    /// assert!(result.is_skipped());
+    /// assert!(!ValidationResult::valid().is_skipped());
+    /// ```
+    pub fn skipped(reason: impl Into<String>) -> Self {
+        Self {
+            is_valid: true,
+            bypass_used: false,
+            bypass_info: None,
+            skip_reason: Some(reason.into()),
         }
     }
 
@@ -254,6 +318,16 @@ impl ValidationResult {
     pub fn bypass_info(&self) -> Option<&BypassInfo> {
         self.bypass_info.as_ref()
     }
+
+    /// Returns true if the check was not run
+    pub fn is_skipped(&self) -> bool {
+        self.skip_reason.is_some()
+    }
+
+    /// Returns why the check was not run, or None if it ran
+    pub fn skip_reason(&self) -> Option<&str> {
+        self.skip_reason.as_deref()
+    }
 }
 
 impl BypassInfo {
@@ -293,6 +367,10 @@ impl BypassInfo {
         match self.rule_type {
             BypassRuleType::TitleConvention => Some("Title validation bypassed"),
             BypassRuleType::WorkItemReference => Some("Work item validation bypassed"),
+            BypassRuleType::PrSize => Some("Size validation bypassed"),
+            BypassRuleType::AllRules => Some("All validation bypassed"),
+            BypassRuleType::SignedCommits => Some("Signed commits validation bypassed"),
+            BypassRuleType::RevertReference => Some("Revert reference validation bypassed"),
         }
     }
 }
@@ -302,6 +380,10 @@ impl std::fmt::Display for BypassRuleType {
         match self {
             BypassRuleType::TitleConvention => write!(f, "Title Convention"),
             BypassRuleType::WorkItemReference => write!(f, "Work Item Reference"),
+            BypassRuleType::PrSize => write!(f, "PR Size"),
+            BypassRuleType::AllRules => write!(f, "All Rules"),
+            BypassRuleType::SignedCommits => write!(f, "Signed Commits"),
+            BypassRuleType::RevertReference => write!(f, "Revert Reference"),
         }
     }
 }
@@ -18,6 +18,28 @@ fn test_validation_result_invalid() {
     assert!(result.bypass_info.is_none());
 }
 
+#[test]
+fn test_validation_result_skipped() {
+    let result = ValidationResult::skipped("Size checking is not enabled");
+
+    assert!(result.is_valid());
+    assert!(!result.was_bypassed());
+    assert!(result.is_skipped());
+    assert_eq!(result.skip_reason(), Some("Size checking is not enabled"));
+    assert!(!ValidationResult::valid().is_skipped());
+    assert!(!ValidationResult::invalid().is_skipped());
+}
+
+#[test]
+fn test_validation_result_skip_reason_is_optional_in_json() {
+    let json = r#"{"is_valid": true, "bypass_used": false, "bypass_info": null}"#;
+    let result: ValidationResult = serde_json::from_str(json).unwrap();
+    assert_eq!(result, ValidationResult::valid());
+
+    let serialized = serde_json::to_value(ValidationResult::valid()).unwrap();
+    assert!(serialized.get("skip_reason").is_none());
+}
+
 #[test]
 fn test_validation_result_bypassed_title_convention() {
     let bypass_info = BypassInfo {
@@ -85,6 +107,7 @@ fn test_bypass_rule_type_display() {
         BypassRuleType::WorkItemReference.to_string(),
         "Work Item Reference"
     );
+    assert_eq!(BypassRuleType::AllRules.to_string(), "All Rules");
 }
 
 #[test]
@@ -0,0 +1,401 @@
+//! # Version Bump Validation
+//!
+//! This module provides the building blocks for the version bump check: a
+//! minimal semantic version type, the change level implied by a PR, and the
+//! manifest formats that a version can be read from.
+//!
+//! Manifest formats are pluggable through the [`VersionManifest`] trait. The
+//! built-in formats are selected with [`ManifestFormat`]; callers that need a
+//! different format can implement the trait and pass the versions it reads to
+//! [`crate::checks::check_version_bump`].
+
+use crate::errors::VersionError;
+use lazy_static::lazy_static;
+use merge_warden_developer_platforms::models::PullRequest;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(test)]
+#[path = "version_tests.rs"]
+mod tests;
+
+lazy_static! {
+    /// Matches a conventional commit listed as a Markdown list item, e.g. `* feat(api): add X`.
+    static ref LISTED_COMMIT_RE: Regex =
+        Regex::new(r"(?m)^\s*[-*]\s+(?:\*\*)?([a-z]+)(?:\([a-z0-9_-]+\))?(!)?:").unwrap();
+}
+
+/// The part of a version that a change requires to be bumped.
+///
+/// Levels are ordered: `Patch < Minor < Major`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::version::BumpLevel;
+///
+/// assert!(BumpLevel::Major > BumpLevel::Minor);
+/// assert_eq!(BumpLevel::Minor.to_string(), "minor");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BumpLevel {
+    /// Bug fixes and other backwards compatible changes.
+    Patch,
+
+    /// New, backwards compatible functionality.
+    Minor,
+
+    /// Breaking changes.
+    Major,
+}
+
+impl fmt::Display for BumpLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BumpLevel::Patch => write!(f, "patch"),
+            BumpLevel::Minor => write!(f, "minor"),
+            BumpLevel::Major => write!(f, "major"),
+        }
+    }
+}
+
+/// A semantic version (`MAJOR.MINOR.PATCH`, with optional pre-release and build parts).
+///
+/// Only the numeric core takes part in comparisons; pre-release and build
+/// metadata are kept for display.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::version::Version;
+///
+/// let version: Version = "1.4.2-rc.1".parse().unwrap();
+/// assert_eq!((version.major, version.minor, version.patch), (1, 4, 2));
+/// assert_eq!(version.to_string(), "1.4.2-rc.1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Version {
+    /// The major version number.
+    pub major: u64,
+
+    /// The minor version number.
+    pub minor: u64,
+
+    /// The patch version number.
+    pub patch: u64,
+
+    /// Pre-release and build metadata, including the leading `-` or `+`.
+    pub suffix: Option<String>,
+}
+
+impl Version {
+    /// Creates a version without pre-release or build metadata.
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            suffix: None,
+        }
+    }
+
+    /// Returns the version that follows this one when bumping `level`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use merge_warden_core::version::{BumpLevel, Version};
+    ///
+    /// assert_eq!(Version::new(1, 4, 2).bumped(BumpLevel::Minor), Version::new(1, 5, 0));
+    /// ```
+    pub fn bumped(&self, level: BumpLevel) -> Self {
+        match level {
+            BumpLevel::Major => Self::new(self.major + 1, 0, 0),
+            BumpLevel::Minor => Self::new(self.major, self.minor + 1, 0),
+            BumpLevel::Patch => Self::new(self.major, self.minor, self.patch + 1),
+        }
+    }
+
+    /// Returns the level bumped between this version and `new`.
+    ///
+    /// Returns `None` when the numeric core of `new` is not greater than this one.
+    pub fn bump_level_to(&self, new: &Version) -> Option<BumpLevel> {
+        let core = |v: &Version| (v.major, v.minor, v.patch);
+        if core(new) <= core(self) {
+            None
+        } else if new.major != self.major {
+            Some(BumpLevel::Major)
+        } else if new.minor != self.minor {
+            Some(BumpLevel::Minor)
+        } else {
+            Some(BumpLevel::Patch)
+        }
+    }
+
+    /// Returns the level that a breaking change must bump from this version.
+    ///
+    /// Below `1.0.0` the public API is not considered stable, so a breaking
+    /// change only requires a minor bump.
+    pub fn breaking_change_level(&self) -> BumpLevel {
+        if self.major == 0 {
+            BumpLevel::Minor
+        } else {
+            BumpLevel::Major
+        }
+    }
+}
+
+impl FromStr for Version {
+    type Err = VersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || VersionError::InvalidVersion(s.to_string());
+
+        let core_end = s.find(['-', '+']).unwrap_or(s.len());
+        let (core, suffix) = s.split_at(core_end);
+        let parts: Vec<&str> = core.trim_start_matches('v').split('.').collect();
+        let [major, minor, patch] = parts.as_slice() else {
+            return Err(invalid());
+        };
+        let number = |part: &str| {
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            part.parse::<u64>().map_err(|_| invalid())
+        };
+
+        Ok(Self {
+            major: number(major)?,
+            minor: number(minor)?,
+            patch: number(patch)?,
+            suffix: (!suffix.is_empty()).then(|| suffix.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(suffix) = &self.suffix {
+            write!(f, "{}", suffix)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the version from the content of a manifest file.
+///
+/// Implement this trait to support a manifest format that is not built in.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::errors::VersionError;
+/// use merge_warden_core::version::{Version, VersionManifest};
+///
+/// struct GradleProperties;
+///
+/// impl VersionManifest for GradleProperties {
+///     fn name(&self) -> &str {
+///         "gradle.properties"
+///     }
+///
+///     fn read_version(&self, content: &str) -> Result<Version, VersionError> {
+///         content
+///             .lines()
+///             .find_map(|line| line.strip_prefix("version="))
+///             .ok_or_else(|| VersionError::MissingVersion(self.name().to_string()))?
+///             .parse()
+///     }
+/// }
+///
+/// let version = GradleProperties.read_version("group=org.example\nversion=2.1.0\n").unwrap();
+/// assert_eq!(version, Version::new(2, 1, 0));
+/// ```
+pub trait VersionManifest: Send + Sync {
+    /// A short name for the format, used in messages.
+    fn name(&self) -> &str;
+
+    /// Reads the version from the manifest content.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VersionError`] when the content cannot be parsed or does not
+    /// contain a valid version.
+    fn read_version(&self, content: &str) -> Result<Version, VersionError>;
+}
+
+/// Reads `package.version`, or `workspace.package.version`, from a `Cargo.toml`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CargoManifest;
+
+impl VersionManifest for CargoManifest {
+    fn name(&self) -> &str {
+        "Cargo.toml"
+    }
+
+    fn read_version(&self, content: &str) -> Result<Version, VersionError> {
+        let value: toml::Value = toml::from_str(content).map_err(|e| VersionError::Parse {
+            manifest: self.name().to_string(),
+            message: e.to_string(),
+        })?;
+
+        value
+            .get("package")
+            .or_else(|| value.get("workspace").and_then(|w| w.get("package")))
+            .and_then(|p| p.get("version"))
+            .and_then(toml::Value::as_str)
+            .ok_or_else(|| VersionError::MissingVersion(self.name().to_string()))?
+            .parse()
+    }
+}
+
+/// Reads the top-level `version` field from a `package.json`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PackageJsonManifest;
+
+impl VersionManifest for PackageJsonManifest {
+    fn name(&self) -> &str {
+        "package.json"
+    }
+
+    fn read_version(&self, content: &str) -> Result<Version, VersionError> {
+        let value: serde_json::Value =
+            serde_json::from_str(content).map_err(|e| VersionError::Parse {
+                manifest: self.name().to_string(),
+                message: e.to_string(),
+            })?;
+
+        value
+            .get("version")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| VersionError::MissingVersion(self.name().to_string()))?
+            .parse()
+    }
+}
+
+/// Reads a file whose first non-empty line is the version, such as `VERSION`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlainVersionFile;
+
+impl VersionManifest for PlainVersionFile {
+    fn name(&self) -> &str {
+        "version file"
+    }
+
+    fn read_version(&self, content: &str) -> Result<Version, VersionError> {
+        content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .ok_or_else(|| VersionError::MissingVersion(self.name().to_string()))?
+            .parse()
+    }
+}
+
+/// The built-in manifest formats the version bump check can read.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::version::ManifestFormat;
+///
+/// let manifest = ManifestFormat::Auto.manifest_for("web/package.json");
+/// assert_eq!(manifest.name(), "package.json");
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ManifestFormat {
+    /// Chooses the format from the manifest file name; unknown names are read as plain text.
+    #[default]
+    Auto,
+
+    /// A Rust `Cargo.toml`.
+    Cargo,
+
+    /// A Node.js `package.json`.
+    PackageJson,
+
+    /// A plain text file containing only the version.
+    Plain,
+}
+
+impl ManifestFormat {
+    /// Returns the manifest reader for this format and the manifest at `path`.
+    pub fn manifest_for(&self, path: &str) -> Box<dyn VersionManifest> {
+        let format = match self {
+            ManifestFormat::Auto => {
+                let file_name = path.rsplit('/').next().unwrap_or(path);
+                match file_name {
+                    "Cargo.toml" => ManifestFormat::Cargo,
+                    "package.json" => ManifestFormat::PackageJson,
+                    _ => ManifestFormat::Plain,
+                }
+            }
+            other => *other,
+        };
+
+        match format {
+            ManifestFormat::Cargo => Box::new(CargoManifest),
+            ManifestFormat::PackageJson => Box::new(PackageJsonManifest),
+            ManifestFormat::Auto | ManifestFormat::Plain => Box::new(PlainVersionFile),
+        }
+    }
+}
+
+/// Returns the change level implied by the PR, for a project currently at `current`.
+///
+/// The level is derived from the PR title and from conventional commits listed as
+/// Markdown list items in the PR body, as release tools do in their release PRs:
+///
+/// - a breaking change (`!:` or a "breaking change" note) requires a major bump,
+///   or a minor bump while the major version is `0`
+/// - a `feat` requires a minor bump
+/// - anything else requires a patch bump
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_core::version::{required_bump_level, BumpLevel, Version};
+/// use merge_warden_developer_platforms::models::PullRequest;
+///
+/// let pr = PullRequest {
+///     number: 1,
+///     title: "chore(main): release 1.3.0".to_string(),
+///     draft: false,
+///     body: Some("### Features\n\n* **api:** add search\n* feat: add export".to_string()),
+///     author: None,
+///     milestone_number: None,
+///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
+/// };
+/// assert_eq!(required_bump_level(&pr, &Version::new(1, 2, 0)), BumpLevel::Minor);
+/// ```
+pub fn required_bump_level(pr: &PullRequest, current: &Version) -> BumpLevel {
+    let body = pr.body.as_deref().unwrap_or_default();
+    let title_type = pr
+        .title
+        .split([':', '(', '!'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    let listed_commits: Vec<_> = LISTED_COMMIT_RE.captures_iter(body).collect();
+    let breaking = crate::labels::indicates_breaking_change(pr)
+        || listed_commits.iter().any(|c| c.get(2).is_some());
+    let feature = title_type == "feat" || listed_commits.iter().any(|c| &c[1] == "feat");
+
+    if breaking {
+        current.breaking_change_level()
+    } else if feature {
+        BumpLevel::Minor
+    } else {
+        BumpLevel::Patch
+    }
+}
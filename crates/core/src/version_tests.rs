@@ -0,0 +1,276 @@
+use super::*;
+
+fn pr_with(title: &str, body: Option<&str>) -> PullRequest {
+    PullRequest {
+        number: 1,
+        title: title.to_string(),
+        draft: false,
+        body: body.map(str::to_string),
+        author: None,
+        milestone_number: None,
+        head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
+    }
+}
+
+// ============================================================
+// Version
+// ============================================================
+
+#[test]
+fn test_version_parses_core_and_suffix() {
+    let version: Version = "2.10.3-beta.1+build.5".parse().unwrap();
+
+    assert_eq!(version.major, 2);
+    assert_eq!(version.minor, 10);
+    assert_eq!(version.patch, 3);
+    assert_eq!(version.suffix.as_deref(), Some("-beta.1+build.5"));
+    assert_eq!(version.to_string(), "2.10.3-beta.1+build.5");
+}
+
+#[test]
+fn test_version_accepts_leading_v_and_whitespace() {
+    let version: Version = " v1.2.3\n".parse().unwrap();
+
+    assert_eq!(version, Version::new(1, 2, 3));
+}
+
+#[test]
+fn test_version_rejects_invalid_text() {
+    for text in ["1.2", "1.2.3.4", "1.x.3", "", "1..3", "1.2.-3"] {
+        assert!(
+            text.parse::<Version>().is_err(),
+            "'{}' should not parse",
+            text
+        );
+    }
+}
+
+#[test]
+fn test_version_bumped_resets_lower_parts() {
+    let version = Version::new(1, 4, 2);
+
+    assert_eq!(version.bumped(BumpLevel::Major), Version::new(2, 0, 0));
+    assert_eq!(version.bumped(BumpLevel::Minor), Version::new(1, 5, 0));
+    assert_eq!(version.bumped(BumpLevel::Patch), Version::new(1, 4, 3));
+}
+
+#[test]
+fn test_version_bump_level_to() {
+    let version = Version::new(1, 4, 2);
+
+    assert_eq!(
+        version.bump_level_to(&Version::new(2, 0, 0)),
+        Some(BumpLevel::Major)
+    );
+    assert_eq!(
+        version.bump_level_to(&Version::new(1, 5, 0)),
+        Some(BumpLevel::Minor)
+    );
+    assert_eq!(
+        version.bump_level_to(&Version::new(1, 4, 3)),
+        Some(BumpLevel::Patch)
+    );
+    assert_eq!(version.bump_level_to(&Version::new(1, 4, 2)), None);
+    assert_eq!(version.bump_level_to(&Version::new(1, 3, 9)), None);
+}
+
+#[test]
+fn test_breaking_change_level_is_minor_before_1_0() {
+    assert_eq!(
+        Version::new(0, 9, 1).breaking_change_level(),
+        BumpLevel::Minor
+    );
+    assert_eq!(
+        Version::new(1, 0, 0).breaking_change_level(),
+        BumpLevel::Major
+    );
+}
+
+// ============================================================
+// Manifests
+// ============================================================
+
+#[test]
+fn test_cargo_manifest_reads_package_version() {
+    let content = r#"
+        [package]
+        name = "example"
+        version = "0.3.1"
+    "#;
+
+    assert_eq!(
+        CargoManifest.read_version(content).unwrap(),
+        Version::new(0, 3, 1)
+    );
+}
+
+#[test]
+fn test_cargo_manifest_reads_workspace_package_version() {
+    let content = r#"
+        [workspace]
+        members = ["crates/*"]
+
+        [workspace.package]
+        version = "1.2.0"
+    "#;
+
+    assert_eq!(
+        CargoManifest.read_version(content).unwrap(),
+        Version::new(1, 2, 0)
+    );
+}
+
+#[test]
+fn test_cargo_manifest_without_version_is_an_error() {
+    let content = r#"
+        [package]
+        name = "example"
+        version.workspace = true
+    "#;
+
+    assert_eq!(
+        CargoManifest.read_version(content),
+        Err(VersionError::MissingVersion("Cargo.toml".to_string()))
+    );
+}
+
+#[test]
+fn test_cargo_manifest_with_invalid_toml_is_a_parse_error() {
+    let result = CargoManifest.read_version("[package");
+
+    assert!(matches!(result, Err(VersionError::Parse { .. })));
+}
+
+#[test]
+fn test_package_json_manifest_reads_version() {
+    let content = r#"{ "name": "example", "version": "4.0.0-rc.2" }"#;
+
+    let version = PackageJsonManifest.read_version(content).unwrap();
+
+    assert_eq!((version.major, version.minor, version.patch), (4, 0, 0));
+}
+
+#[test]
+fn test_package_json_manifest_without_version_is_an_error() {
+    let result = PackageJsonManifest.read_version(r#"{ "name": "example" }"#);
+
+    assert_eq!(
+        result,
+        Err(VersionError::MissingVersion("package.json".to_string()))
+    );
+}
+
+#[test]
+fn test_plain_version_file_reads_first_non_empty_line() {
+    assert_eq!(
+        PlainVersionFile.read_version("\n1.0.7\n").unwrap(),
+        Version::new(1, 0, 7)
+    );
+}
+
+#[test]
+fn test_manifest_format_auto_detects_from_file_name() {
+    assert_eq!(
+        ManifestFormat::Auto.manifest_for("Cargo.toml").name(),
+        "Cargo.toml"
+    );
+    assert_eq!(
+        ManifestFormat::Auto
+            .manifest_for("packages/web/package.json")
+            .name(),
+        "package.json"
+    );
+    assert_eq!(
+        ManifestFormat::Auto.manifest_for("VERSION").name(),
+        "version file"
+    );
+}
+
+#[test]
+fn test_manifest_format_explicit_overrides_file_name() {
+    assert_eq!(
+        ManifestFormat::Cargo.manifest_for("release.toml").name(),
+        "Cargo.toml"
+    );
+}
+
+#[test]
+fn test_manifest_format_deserializes_snake_case() {
+    let value: toml::Value = toml::from_str(r#"format = "package_json""#).unwrap();
+
+    let format: ManifestFormat = value["format"].clone().try_into().unwrap();
+
+    assert_eq!(format, ManifestFormat::PackageJson);
+}
+
+// ============================================================
+// required_bump_level
+// ============================================================
+
+#[test]
+fn test_required_bump_level_for_fix_is_patch() {
+    let pr = pr_with("fix: handle empty body", None);
+
+    assert_eq!(
+        required_bump_level(&pr, &Version::new(1, 0, 0)),
+        BumpLevel::Patch
+    );
+}
+
+#[test]
+fn test_required_bump_level_for_feat_title_is_minor() {
+    let pr = pr_with("feat(api): add search", None);
+
+    assert_eq!(
+        required_bump_level(&pr, &Version::new(1, 0, 0)),
+        BumpLevel::Minor
+    );
+}
+
+#[test]
+fn test_required_bump_level_for_breaking_title_is_major() {
+    let pr = pr_with("feat!: drop v1 endpoints", None);
+
+    assert_eq!(
+        required_bump_level(&pr, &Version::new(1, 0, 0)),
+        BumpLevel::Major
+    );
+}
+
+#[test]
+fn test_required_bump_level_for_breaking_change_before_1_0_is_minor() {
+    let pr = pr_with("fix: rename option", Some("BREAKING CHANGE: renamed `foo`"));
+
+    assert_eq!(
+        required_bump_level(&pr, &Version::new(0, 4, 0)),
+        BumpLevel::Minor
+    );
+}
+
+#[test]
+fn test_required_bump_level_reads_listed_commits() {
+    let pr = pr_with(
+        "chore(main): release 2.0.0",
+        Some("## Changes\n\n- fix: correct totals\n- refactor(core)!: remove legacy API\n"),
+    );
+
+    assert_eq!(
+        required_bump_level(&pr, &Version::new(1, 3, 0)),
+        BumpLevel::Major
+    );
+}
+
+#[test]
+fn test_required_bump_level_ignores_negated_breaking_change() {
+    let pr = pr_with(
+        "fix: correct totals",
+        Some("This is not a breaking change."),
+    );
+
+    assert_eq!(
+        required_bump_level(&pr, &Version::new(1, 0, 0)),
+        BumpLevel::Patch
+    );
+}
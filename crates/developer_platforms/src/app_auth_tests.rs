@@ -3,14 +3,21 @@
 //! Each test spins up a WireMock server, creates an `AppAuthProvider` pointing
 //! at it, and verifies the correct behaviour for the happy path and error cases.
 
-use github_bot_sdk::error::AuthError;
+use chrono::{DateTime, Duration, Utc};
+use github_bot_sdk::{
+    auth::{AuthenticationProvider, InstallationId, InstallationPermissions, InstallationToken},
+    error::AuthError,
+};
 use serde_json::json;
 use wiremock::{
     matchers::{header_exists, method, path},
     Mock, MockServer, ResponseTemplate,
 };
 
-use super::AppAuthProvider;
+use super::{AppAuthProvider, InstallationTokenCache, DEFAULT_TOKEN_REFRESH_MARGIN_SECS};
+use crate::clock::ManualClock;
+use chrono::TimeZone;
+use std::sync::Arc;
 
 // ---------------------------------------------------------------------------
 // Test RSA private key (2048-bit, PKCS#1, for tests only)
@@ -32,6 +39,32 @@ fn make_auth_provider(server_uri: &str) -> AppAuthProvider {
         .expect("Failed to create AppAuthProvider with test key")
 }
 
+/// Creates an `InstallationToken` for the given installation that expires at `expires_at`.
+fn make_token(installation_id: u64, expires_at: DateTime<Utc>) -> InstallationToken {
+    InstallationToken::new(
+        format!("ghs_test_token_{}", installation_id),
+        InstallationId::new(installation_id),
+        expires_at,
+        InstallationPermissions::default(),
+        vec![],
+    )
+}
+
+/// Mounts an access token endpoint for installation 42 that issues tokens
+/// living for `lifetime` and expects to be called exactly `expected_calls` times.
+async fn mount_access_token_endpoint(server: &MockServer, lifetime: Duration, expected_calls: u64) {
+    Mock::given(method("POST"))
+        .and(path("/app/installations/42/access_tokens"))
+        .and(header_exists("Authorization"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "token": "ghs_test_token_42",
+            "expires_at": (Utc::now() + lifetime).to_rfc3339(),
+        })))
+        .expect(expected_calls)
+        .mount(server)
+        .await;
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -101,3 +134,232 @@ async fn test_resolve_installation_id_missing_id_field_returns_error() {
         result
     );
 }
+
+#[tokio::test]
+async fn test_installation_token_is_served_from_cache() {
+    let server = MockServer::start().await;
+    mount_access_token_endpoint(&server, Duration::hours(1), 1).await;
+
+    let provider = make_auth_provider(&server.uri());
+    provider
+        .installation_token(InstallationId::new(42))
+        .await
+        .expect("first call should fetch a token");
+    provider
+        .installation_token(InstallationId::new(42))
+        .await
+        .expect("second call should use the cached token");
+
+    assert_eq!(provider.token_cache().len().await, 1);
+    // The `expect(1)` on the mock is verified when the server is dropped.
+}
+
+#[tokio::test]
+async fn test_installation_token_refreshes_within_refresh_margin() {
+    let server = MockServer::start().await;
+    // Tokens expire in 2 minutes, inside the default 5 minute refresh margin,
+    // so every call must fetch a new token.
+    mount_access_token_endpoint(&server, Duration::minutes(2), 2).await;
+
+    let provider = make_auth_provider(&server.uri());
+    provider
+        .installation_token(InstallationId::new(42))
+        .await
+        .expect("first call should fetch a token");
+    provider
+        .installation_token(InstallationId::new(42))
+        .await
+        .expect("second call should fetch a new token");
+}
+
+#[tokio::test]
+async fn test_refresh_installation_token_bypasses_cache() {
+    let server = MockServer::start().await;
+    mount_access_token_endpoint(&server, Duration::hours(1), 2).await;
+
+    let provider = make_auth_provider(&server.uri());
+    provider
+        .installation_token(InstallationId::new(42))
+        .await
+        .expect("first call should fetch a token");
+    provider
+        .refresh_installation_token(InstallationId::new(42))
+        .await
+        .expect("refresh should always fetch a token");
+}
+
+#[tokio::test]
+async fn test_installation_token_fetch_failure_is_not_cached() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/app/installations/42/access_tokens"))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Bad credentials"))
+        .mount(&server)
+        .await;
+
+    let provider = make_auth_provider(&server.uri());
+    let result = provider.installation_token(InstallationId::new(42)).await;
+
+    assert!(
+        matches!(result, Err(AuthError::TokenExchangeFailed { .. })),
+        "Expected TokenExchangeFailed, got {:?}",
+        result
+    );
+    assert!(provider.token_cache().is_empty().await);
+}
+
+#[tokio::test]
+async fn test_token_cache_default_uses_default_refresh_margin() {
+    let cache = InstallationTokenCache::default();
+
+    assert_eq!(
+        cache.refresh_margin(),
+        Duration::seconds(DEFAULT_TOKEN_REFRESH_MARGIN_SECS)
+    );
+    assert!(cache.is_empty().await);
+}
+
+#[tokio::test]
+async fn test_token_cache_negative_margin_is_clamped_to_zero() {
+    let cache = InstallationTokenCache::new(Duration::minutes(-5));
+
+    assert_eq!(cache.refresh_margin(), Duration::zero());
+}
+
+#[tokio::test]
+async fn test_token_cache_get_returns_fresh_token() {
+    let cache = InstallationTokenCache::new(Duration::minutes(5));
+    let expires_at = Utc::now() + Duration::hours(1);
+    cache
+        .insert(
+            InstallationId::new(7),
+            make_token(7, expires_at),
+            expires_at,
+        )
+        .await;
+
+    assert!(cache.get(InstallationId::new(7)).await.is_some());
+    assert!(cache.get(InstallationId::new(8)).await.is_none());
+}
+
+#[tokio::test]
+async fn test_token_cache_get_ignores_token_within_refresh_margin() {
+    let cache = InstallationTokenCache::new(Duration::minutes(5));
+    let expires_at = Utc::now() + Duration::minutes(4);
+    cache
+        .insert(
+            InstallationId::new(7),
+            make_token(7, expires_at),
+            expires_at,
+        )
+        .await;
+
+    assert!(cache.get(InstallationId::new(7)).await.is_none());
+    assert_eq!(cache.len().await, 1);
+}
+
+#[tokio::test]
+async fn test_token_cache_expires_token_by_the_clock() {
+    let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+    let clock = Arc::new(ManualClock::new(start));
+    let cache = InstallationTokenCache::new(Duration::minutes(5)).with_clock(clock.clone());
+    let expires_at = start + Duration::hours(1);
+    cache
+        .insert(
+            InstallationId::new(7),
+            make_token(7, expires_at),
+            expires_at,
+        )
+        .await;
+
+    clock.advance(Duration::minutes(54));
+    assert!(cache.get(InstallationId::new(7)).await.is_some());
+
+    // Five minutes before expiry the token is inside the refresh margin
+    clock.advance(Duration::minutes(1));
+    assert!(cache.get(InstallationId::new(7)).await.is_none());
+}
+
+#[tokio::test]
+async fn test_token_cache_get_or_refresh_fetches_again_once_the_clock_passes_the_margin() {
+    let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+    let clock = Arc::new(ManualClock::new(start));
+    let cache = InstallationTokenCache::new(Duration::minutes(5)).with_clock(clock.clone());
+    let calls = std::sync::atomic::AtomicUsize::new(0);
+    let fetch = || async {
+        calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let expires_at = start + Duration::hours(1);
+        Ok((make_token(7, expires_at), expires_at))
+    };
+
+    cache
+        .get_or_refresh(InstallationId::new(7), fetch)
+        .await
+        .expect("fetch should succeed");
+    clock.advance(Duration::minutes(30));
+    cache
+        .get_or_refresh(InstallationId::new(7), fetch)
+        .await
+        .expect("cached token should be served");
+    assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+    clock.advance(Duration::minutes(25));
+    cache
+        .get_or_refresh(InstallationId::new(7), fetch)
+        .await
+        .expect("fetch should succeed");
+    assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_token_cache_invalidate_removes_entry() {
+    let cache = InstallationTokenCache::default();
+    let expires_at = Utc::now() + Duration::hours(1);
+    cache
+        .insert(
+            InstallationId::new(7),
+            make_token(7, expires_at),
+            expires_at,
+        )
+        .await;
+
+    cache.invalidate(InstallationId::new(7)).await;
+
+    assert!(cache.is_empty().await);
+}
+
+#[tokio::test]
+async fn test_token_cache_clones_share_entries() {
+    let cache = InstallationTokenCache::default();
+    let clone = cache.clone();
+    let expires_at = Utc::now() + Duration::hours(1);
+    clone
+        .insert(
+            InstallationId::new(7),
+            make_token(7, expires_at),
+            expires_at,
+        )
+        .await;
+
+    assert!(cache.get(InstallationId::new(7)).await.is_some());
+}
+
+#[tokio::test]
+async fn test_token_cache_get_or_refresh_fetches_once() {
+    let cache = InstallationTokenCache::default();
+    let calls = std::sync::atomic::AtomicUsize::new(0);
+
+    for _ in 0..3 {
+        cache
+            .get_or_refresh(InstallationId::new(7), || async {
+                calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let expires_at = Utc::now() + Duration::hours(1);
+                Ok((make_token(7, expires_at), expires_at))
+            })
+            .await
+            .expect("fetch should succeed");
+    }
+
+    assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
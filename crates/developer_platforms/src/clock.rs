@@ -0,0 +1,87 @@
+//! # Clock
+//!
+//! This module provides the [`Clock`] abstraction through which Merge Warden reads
+//! the current time.
+//!
+//! Production code uses [`SystemClock`]. Tests use [`ManualClock`], whose time only
+//! changes when the test sets or advances it, so time-based rules can be verified
+//! deterministically.
+
+use chrono::{DateTime, Duration, Utc};
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+#[cfg(test)]
+#[path = "clock_tests.rs"]
+mod tests;
+
+/// A source of the current time.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::clock::{Clock, SystemClock};
+///
+/// let before = chrono::Utc::now();
+/// assert!(SystemClock.now() >= before);
+/// ```
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock, reading the current time from the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that only moves when told to.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use merge_warden_developer_platforms::clock::{Clock, ManualClock};
+///
+/// let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// let clock = ManualClock::new(start);
+/// clock.advance(Duration::hours(2));
+///
+/// assert_eq!(clock.now(), start + Duration::hours(2));
+/// ```
+#[derive(Debug)]
+pub struct ManualClock {
+    /// The time the clock reports
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    /// Creates a clock that reads `start` until it is set or advanced.
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(start),
+        }
+    }
+
+    /// Moves the clock forward by `duration`. A negative duration moves it back.
+    pub fn advance(&self, duration: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(|e| e.into_inner());
+        *now += duration;
+    }
+
+    /// Sets the clock to `time`.
+    pub fn set(&self, time: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) = time;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
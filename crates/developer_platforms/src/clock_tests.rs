@@ -0,0 +1,40 @@
+use super::*;
+use chrono::TimeZone;
+
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()
+}
+
+#[test]
+fn test_manual_clock_returns_the_same_time_until_changed() {
+    let clock = ManualClock::new(start());
+
+    assert_eq!(clock.now(), start());
+    assert_eq!(clock.now(), start());
+}
+
+#[test]
+fn test_manual_clock_advance_and_set() {
+    let clock = ManualClock::new(start());
+
+    clock.advance(Duration::days(3));
+    assert_eq!(clock.now(), start() + Duration::days(3));
+
+    clock.advance(Duration::minutes(-30));
+    assert_eq!(
+        clock.now(),
+        start() + Duration::days(3) - Duration::minutes(30)
+    );
+
+    clock.set(start());
+    assert_eq!(clock.now(), start());
+}
+
+#[test]
+fn test_system_clock_follows_the_current_time() {
+    let before = Utc::now();
+    let now = SystemClock.now();
+    let after = Utc::now();
+
+    assert!(before <= now && now <= after);
+}
@@ -147,4 +147,15 @@ pub enum Error {
     /// Parameters: installation ID, error message from the SDK.
     #[error("Failed to refresh installation token for installation {0}: {1}")]
     TokenRefreshFailed(u64, String),
+
+    /// The operation is not supported by this developer platform.
+    ///
+    /// Returned by provider methods that rely on platform-specific APIs (for
+    /// example the GitHub GraphQL API) when the provider cannot implement them.
+    /// Callers should treat the corresponding feature as unavailable rather
+    /// than as a failure.
+    ///
+    /// The string parameter names the unsupported operation.
+    #[error("Operation not supported by this platform: {0}")]
+    Unsupported(String),
 }
@@ -1,19 +1,27 @@
 use async_trait::async_trait;
 use base64::Engine;
 use github_bot_sdk::{
-    client::{parse_link_header, CreateCommentRequest, CreateLabelRequest, InstallationClient},
+    client::{
+        parse_link_header, CreateCommentRequest, CreateLabelRequest, InstallationClient,
+        UpdatePullRequestRequest,
+    },
     error::ApiError,
 };
 use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::{
     errors::Error,
     models::{
-        Comment, CommitStatus, IssueMetadata, IssueMilestone, IssueProject, Label, PullRequest,
-        PullRequestFile, RepositoryContext, Review, User,
+        AuthorAssociation, CheckRunAction, Comment, CommitStatus, CommitVerification, Issue,
+        IssueMetadata, IssueMilestone, IssueProject, Label, PullRequest, PullRequestCommit,
+        PullRequestFile, PullRequestFilePatch, PullRequestSummary, RateLimitStatus,
+        RepositoryContext, Review, ReviewThread, Team, User,
     },
     ConfigFetcher, IssueMetadataProvider, PullRequestProvider, RepositoryMetadataProvider,
+    DEFAULT_CHECK_RUN_NAME,
 };
 
 #[cfg(test)]
@@ -44,6 +52,46 @@ fn map_api_error(e: ApiError) -> Error {
     }
 }
 
+/// Converts a `github_bot_sdk` label to the crate-local [`Label`].
+///
+/// GitHub always reports a colour; an empty string is treated as absent.
+fn label_from_sdk(label: github_bot_sdk::client::Label) -> Label {
+    Label {
+        name: label.name,
+        description: label.description,
+        color: Some(label.color).filter(|c| !c.is_empty()),
+    }
+}
+
+/// Builds a [`PullRequestFile`] from one entry of a GitHub `files` array.
+///
+/// Shared by the pull request files and compare endpoints, which use the same
+/// file representation. Missing fields default to empty or zero.
+fn pull_request_file_from_json(v: &serde_json::Value) -> PullRequestFile {
+    PullRequestFile {
+        filename: v["filename"].as_str().unwrap_or_default().to_string(),
+        additions: v["additions"].as_u64().unwrap_or_default() as u32,
+        deletions: v["deletions"].as_u64().unwrap_or_default() as u32,
+        changes: v["changes"].as_u64().unwrap_or_default() as u32,
+        status: v["status"].as_str().unwrap_or_default().to_string(),
+    }
+}
+
+/// Builds a [`Comment`] from a GitHub comment object.
+///
+/// Returns `None` when the comment has no `id`. A missing body or user defaults to
+/// empty values.
+fn comment_from_json(v: &serde_json::Value) -> Option<Comment> {
+    Some(Comment {
+        id: v["id"].as_u64()?,
+        body: v["body"].as_str().unwrap_or_default().to_string(),
+        user: User {
+            id: v["user"]["id"].as_u64().unwrap_or_default(),
+            login: v["user"]["login"].as_str().unwrap_or_default().to_string(),
+        },
+    })
+}
+
 /// GitHub implementation of developer platform traits.
 ///
 /// Wraps an installation-scoped [`InstallationClient`] to expose it through the
@@ -71,6 +119,23 @@ fn map_api_error(e: ApiError) -> Error {
 pub struct GitHubProvider {
     /// Installation-scoped GitHub API client.
     client: InstallationClient,
+
+    /// Teams and team members fetched so far, shared between clones.
+    teams: Arc<Mutex<TeamCache>>,
+}
+
+/// Teams and team members fetched by a [`GitHubProvider`].
+///
+/// Team membership rarely changes while a pull request is processed, so each list
+/// is fetched once per provider. Organization and team names are stored in lower
+/// case, as GitHub compares them ignoring case.
+#[derive(Debug, Default)]
+struct TeamCache {
+    /// Teams by organization
+    teams: HashMap<String, Vec<Team>>,
+
+    /// Team members by organization and team slug
+    members: HashMap<(String, String), Vec<User>>,
 }
 
 impl GitHubProvider {
@@ -80,7 +145,130 @@ impl GitHubProvider {
     ///
     /// * `client` - An [`InstallationClient`] authenticated for a specific GitHub App installation.
     pub fn new(client: InstallationClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            teams: Arc::new(Mutex::new(TeamCache::default())),
+        }
+    }
+
+    /// Fetches every page of a GitHub list endpoint.
+    ///
+    /// `path` must not contain a query string; `per_page` and `page` are appended.
+    ///
+    /// # Errors
+    ///
+    /// Returns the mapped API error if a request fails, and
+    /// [`Error::InvalidResponse`] if a page has a non-success status or is not a
+    /// JSON array.
+    async fn get_all_pages(&self, path: &str) -> Result<Vec<serde_json::Value>, Error> {
+        const PAGE_SIZE: usize = 100;
+
+        let mut items = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let page_path = format!("{}?per_page={}&page={}", path, PAGE_SIZE, page);
+            let response = self.client.get(&page_path).await.map_err(|e| {
+                error!(path, page, error = %e, "Failed to fetch list page");
+                map_api_error(e)
+            })?;
+
+            if !response.status().is_success() {
+                error!(
+                    path,
+                    page,
+                    status = response.status().as_u16(),
+                    "Non-success status fetching list page"
+                );
+                return Err(Error::InvalidResponse);
+            }
+
+            let page_items: Vec<serde_json::Value> =
+                response.json().await.map_err(|_| Error::InvalidResponse)?;
+            let page_len = page_items.len();
+            items.extend(page_items);
+
+            if page_len < PAGE_SIZE {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(items)
+    }
+
+    /// Posts a completed check run on a commit.
+    ///
+    /// POSTs to `POST /repos/{owner}/{repo}/check-runs`. The `actions` are only sent
+    /// when there are any, so that check runs without buttons keep their payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns the mapped API error if the request fails, and
+    /// [`Error::FailedToUpdatePullRequest`] for a non-success status.
+    #[allow(clippy::too_many_arguments)]
+    async fn post_check_run(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        head_sha: &str,
+        check_name: &str,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+        actions: &[CheckRunAction],
+    ) -> Result<(), Error> {
+        let url = format!("/repos/{}/{}/check-runs", repo_owner, repo_name);
+        let mut payload = json!({
+            "name": check_name,
+            "head_sha": head_sha,
+            "status": "completed",
+            "conclusion": conclusion,
+            "output": {
+                "title": output_title,
+                "summary": output_summary,
+                "text": output_text,
+            }
+        });
+        if !actions.is_empty() {
+            payload["actions"] = json!(actions);
+        }
+
+        let response = self.client.post(&url, &payload).await.map_err(|e| {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                sha = head_sha,
+                error = %e,
+                "Failed to post check run"
+            );
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                sha = head_sha,
+                status = response.status().as_u16(),
+                "Non-success status creating check run"
+            );
+            return Err(Error::FailedToUpdatePullRequest(
+                "Failed to create/update check run".to_string(),
+            ));
+        }
+
+        info!(
+            owner = repo_owner,
+            repo = repo_name,
+            sha = head_sha,
+            check_name,
+            conclusion,
+            "Successfully updated check run status"
+        );
+
+        Ok(())
     }
 
     /// Fetches the default branch name for a repository.
@@ -490,6 +678,8 @@ impl PullRequestProvider for GitHubProvider {
             }),
             milestone_number: pr.milestone.as_ref().map(|m| m.number),
             head_sha: pr.head.sha,
+            base_ref: pr.base.branch_ref,
+            head_ref: pr.head.branch_ref,
         })
     }
 
@@ -549,16 +739,7 @@ impl PullRequestProvider for GitHubProvider {
         let items: Vec<serde_json::Value> =
             response.json().await.map_err(|_| Error::InvalidResponse)?;
 
-        let files: Vec<PullRequestFile> = items
-            .into_iter()
-            .map(|v| PullRequestFile {
-                filename: v["filename"].as_str().unwrap_or_default().to_string(),
-                additions: v["additions"].as_u64().unwrap_or_default() as u32,
-                deletions: v["deletions"].as_u64().unwrap_or_default() as u32,
-                changes: v["changes"].as_u64().unwrap_or_default() as u32,
-                status: v["status"].as_str().unwrap_or_default().to_string(),
-            })
-            .collect();
+        let files: Vec<PullRequestFile> = items.iter().map(pull_request_file_from_json).collect();
 
         debug!(
             owner = repo_owner,
@@ -611,14 +792,7 @@ impl PullRequestProvider for GitHubProvider {
                 map_api_error(e)
             })?;
 
-        Ok(pr
-            .labels
-            .into_iter()
-            .map(|l| Label {
-                name: l.name,
-                description: l.description,
-            })
-            .collect())
+        Ok(pr.labels.into_iter().map(label_from_sdk).collect())
     }
 
     /// Lists all labels available in the repository.
@@ -647,15 +821,7 @@ impl PullRequestProvider for GitHubProvider {
             .labels()
             .list(repo_owner, repo_name)
             .await
-            .map(|labels| {
-                labels
-                    .into_iter()
-                    .map(|l| Label {
-                        name: l.name,
-                        description: l.description,
-                    })
-                    .collect()
-            })
+            .map(|labels| labels.into_iter().map(label_from_sdk).collect())
             .map_err(map_api_error)
     }
 
@@ -765,8 +931,8 @@ impl PullRequestProvider for GitHubProvider {
 
     /// Creates or updates a GitHub check run for the pull request.
     ///
-    /// Fetches the PR head commit SHA and then POSTs to
-    /// `POST /repos/{owner}/{repo}/check-runs`.
+    /// Posts the check run under [`DEFAULT_CHECK_RUN_NAME`]; see
+    /// [`GitHubProvider::update_named_pr_check_status`].
     ///
     /// # Arguments
     ///
@@ -777,6 +943,7 @@ impl PullRequestProvider for GitHubProvider {
     /// * `output_title` - The title shown in the check run details
     /// * `output_summary` - A brief summary of the check results
     /// * `output_text` - Detailed text output (supports Markdown)
+    /// * `actions` - Buttons to show on the check run
     ///
     /// # Returns
     ///
@@ -796,72 +963,20 @@ impl PullRequestProvider for GitHubProvider {
         output_title: &str,
         output_summary: &str,
         output_text: &str,
+        actions: &[CheckRunAction],
     ) -> Result<(), Error> {
-        // Fetch the PR to get the head commit SHA for the check run.
-        let pr = self
-            .client
-            .pull_requests()
-            .get(repo_owner, repo_name, pr_number)
-            .await
-            .map_err(|e| {
-                error!(
-                    owner = repo_owner,
-                    repo = repo_name,
-                    pr = pr_number,
-                    error = %e,
-                    "Failed to get PR head SHA for check run"
-                );
-                map_api_error(e)
-            })?;
-
-        let head_sha = pr.head.sha;
-
-        let url = format!("/repos/{}/{}/check-runs", repo_owner, repo_name);
-        let payload = json!({
-            "name": "MergeWarden",
-            "head_sha": head_sha,
-            "status": "completed",
-            "conclusion": conclusion,
-            "output": {
-                "title": output_title,
-                "summary": output_summary,
-                "text": output_text,
-            }
-        });
-
-        let response = self.client.post(&url, &payload).await.map_err(|e| {
-            error!(
-                owner = repo_owner,
-                repo = repo_name,
-                pr = pr_number,
-                error = %e,
-                "Failed to post check run"
-            );
-            map_api_error(e)
-        })?;
-
-        if !response.status().is_success() {
-            error!(
-                owner = repo_owner,
-                repo = repo_name,
-                pr = pr_number,
-                status = response.status().as_u16(),
-                "Non-success status creating check run"
-            );
-            return Err(Error::FailedToUpdatePullRequest(
-                "Failed to create/update check run".to_string(),
-            ));
-        }
-
-        info!(
-            owner = repo_owner,
-            repo = repo_name,
-            pr = pr_number,
+        self.update_named_pr_check_status(
+            repo_owner,
+            repo_name,
+            pr_number,
+            DEFAULT_CHECK_RUN_NAME,
             conclusion,
-            "Successfully updated PR check run status"
-        );
-
-        Ok(())
+            output_title,
+            output_summary,
+            output_text,
+            actions,
+        )
+        .await
     }
 
     /// Lists all reviews submitted on a pull request.
@@ -1152,6 +1267,1350 @@ impl PullRequestProvider for GitHubProvider {
                 map_api_error(e)
             })
     }
+
+    /// Lists the review threads on a pull request with their resolution state.
+    ///
+    /// Review-thread resolution is not exposed by the REST API, so this posts the
+    /// `reviewThreads` query to `POST /graphql`, following the `endCursor` until
+    /// every page has been read.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses, GraphQL errors or
+    /// a response without the expected fields (for example an unknown PR).
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn list_review_threads(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<ReviewThread>, Error> {
+        const QUERY: &str =
+            "query($owner: String!, $repo: String!, $number: Int!, $cursor: String) { \
+            repository(owner: $owner, name: $repo) { \
+                pullRequest(number: $number) { \
+                    reviewThreads(first: 100, after: $cursor) { \
+                        nodes { id isResolved isOutdated } \
+                        pageInfo { hasNextPage endCursor } \
+                    } \
+                } \
+            } \
+        }";
+
+        let mut threads = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let payload = json!({
+                "query": QUERY,
+                "variables": {
+                    "owner": repo_owner,
+                    "repo": repo_name,
+                    "number": pr_number,
+                    "cursor": cursor,
+                }
+            });
+
+            let response = self.client.post("/graphql", &payload).await.map_err(|e| {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    error = %e,
+                    "Failed to query review threads"
+                );
+                map_api_error(e)
+            })?;
+
+            if !response.status().is_success() {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    status = response.status().as_u16(),
+                    "Non-success status querying review threads"
+                );
+                return Err(Error::InvalidResponse);
+            }
+
+            let json: serde_json::Value =
+                response.json().await.map_err(|_| Error::InvalidResponse)?;
+
+            if let Some(errors) = json["errors"].as_array().filter(|e| !e.is_empty()) {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    errors = %serde_json::Value::Array(errors.clone()),
+                    "GraphQL errors querying review threads"
+                );
+                return Err(Error::InvalidResponse);
+            }
+
+            let connection = &json["data"]["repository"]["pullRequest"]["reviewThreads"];
+            let Some(nodes) = connection["nodes"].as_array() else {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    "GraphQL response is missing 'reviewThreads'"
+                );
+                return Err(Error::InvalidResponse);
+            };
+
+            threads.extend(nodes.iter().filter_map(|node| {
+                Some(ReviewThread {
+                    id: node["id"].as_str()?.to_string(),
+                    is_resolved: node["isResolved"].as_bool()?,
+                    is_outdated: node["isOutdated"].as_bool().unwrap_or(false),
+                })
+            }));
+
+            let page_info = &connection["pageInfo"];
+            match (
+                page_info["hasNextPage"].as_bool(),
+                page_info["endCursor"].as_str(),
+            ) {
+                (Some(true), Some(end_cursor)) => cursor = Some(end_cursor.to_string()),
+                _ => break,
+            }
+        }
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            pr = pr_number,
+            count = threads.len(),
+            unresolved = threads.iter().filter(|t| !t.is_resolved).count(),
+            "Fetched review threads"
+        );
+
+        Ok(threads)
+    }
+
+    /// Finds the user who most recently applied `label` to a pull request.
+    ///
+    /// Pages through `GET /repos/{owner}/{repo}/issues/{number}/events` (oldest
+    /// first) and keeps the actor of the last `labeled` event for the label.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses or a body that is
+    /// not a JSON array.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn find_label_applier(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        label: &str,
+    ) -> Result<Option<User>, Error> {
+        const PAGE_SIZE: usize = 100;
+
+        let mut applier: Option<User> = None;
+        let mut page = 1;
+
+        loop {
+            let path = format!(
+                "/repos/{}/{}/issues/{}/events?per_page={}&page={}",
+                repo_owner, repo_name, pr_number, PAGE_SIZE, page
+            );
+
+            let response = self.client.get(&path).await.map_err(|e| {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    error = %e,
+                    "Failed to fetch pull request events"
+                );
+                map_api_error(e)
+            })?;
+
+            if !response.status().is_success() {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    status = response.status().as_u16(),
+                    "Non-success status fetching pull request events"
+                );
+                return Err(Error::InvalidResponse);
+            }
+
+            let events: Vec<serde_json::Value> =
+                response.json().await.map_err(|_| Error::InvalidResponse)?;
+
+            for event in &events {
+                let is_label_event = event["event"].as_str() == Some("labeled")
+                    && event["label"]["name"]
+                        .as_str()
+                        .is_some_and(|name| name.eq_ignore_ascii_case(label));
+                if !is_label_event {
+                    continue;
+                }
+
+                if let (Some(id), Some(login)) = (
+                    event["actor"]["id"].as_u64(),
+                    event["actor"]["login"].as_str(),
+                ) {
+                    applier = Some(User {
+                        id,
+                        login: login.to_string(),
+                    });
+                }
+            }
+
+            if events.len() < PAGE_SIZE {
+                break;
+            }
+            page += 1;
+        }
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            pr = pr_number,
+            label,
+            applier = applier.as_ref().map(|u| u.login.as_str()),
+            "Looked up label applier"
+        );
+
+        Ok(applier)
+    }
+
+    /// Lists the files changed between `base` and `head`.
+    ///
+    /// Calls `GET /repos/{owner}/{repo}/compare/{base}...{head}` and reads its
+    /// `files` array, which GitHub limits to 300 files.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses or a body without
+    /// a `files` array.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name))]
+    async fn get_comparison_files(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<PullRequestFile>, Error> {
+        let path = format!(
+            "/repos/{}/{}/compare/{}...{}",
+            repo_owner, repo_name, base, head
+        );
+
+        let response = self.client.get(&path).await.map_err(|e| {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                base,
+                head,
+                error = %e,
+                "Failed to compare commits"
+            );
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                base,
+                head,
+                status = response.status().as_u16(),
+                "Non-success status comparing commits"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        let json: serde_json::Value = response.json().await.map_err(|_| Error::InvalidResponse)?;
+        let files: Vec<PullRequestFile> = json["files"]
+            .as_array()
+            .ok_or(Error::InvalidResponse)?
+            .iter()
+            .map(pull_request_file_from_json)
+            .collect();
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            base,
+            head,
+            count = files.len(),
+            "Fetched comparison files"
+        );
+
+        Ok(files)
+    }
+
+    /// Counts the commits on `base` that `head` does not contain.
+    ///
+    /// Calls `GET /repos/{owner}/{repo}/compare/{head}...{base}` and reads its
+    /// `ahead_by`, the number of commits `base` is ahead of their merge base.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses or a body without
+    /// an `ahead_by` count.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name))]
+    async fn get_commits_behind(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<u64, Error> {
+        let path = format!(
+            "/repos/{}/{}/compare/{}...{}",
+            repo_owner, repo_name, head, base
+        );
+
+        let response = self.client.get(&path).await.map_err(|e| {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                base,
+                head,
+                error = %e,
+                "Failed to compare commits"
+            );
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                base,
+                head,
+                status = response.status().as_u16(),
+                "Non-success status comparing commits"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        let json: serde_json::Value = response.json().await.map_err(|_| Error::InvalidResponse)?;
+        let behind_by = json["ahead_by"].as_u64().ok_or(Error::InvalidResponse)?;
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            base,
+            head,
+            behind_by,
+            "Counted commits behind base"
+        );
+
+        Ok(behind_by)
+    }
+
+    /// Lists the open pull requests of a repository, most recently updated first.
+    ///
+    /// Uses `GET /repos/{owner}/{repo}/pulls?state=open&sort=updated&direction=desc`.
+    /// The endpoint cannot filter by update time, so pages are fetched until one holds
+    /// a pull request that was updated before `since`.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name))]
+    async fn list_pull_requests(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<PullRequestSummary>, Error> {
+        const PAGE_SIZE: usize = 100;
+
+        let mut pull_requests = Vec::new();
+        let mut page = 1;
+        loop {
+            let path = format!(
+                "/repos/{}/{}/pulls?state=open&sort=updated&direction=desc&per_page={}&page={}",
+                repo_owner, repo_name, PAGE_SIZE, page
+            );
+            let response = self.client.get(&path).await.map_err(|e| {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    page,
+                    error = %e,
+                    "Failed to list pull requests"
+                );
+                map_api_error(e)
+            })?;
+
+            if !response.status().is_success() {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    page,
+                    status = response.status().as_u16(),
+                    "Non-success status listing pull requests"
+                );
+                return Err(Error::InvalidResponse);
+            }
+
+            let items: Vec<serde_json::Value> =
+                response.json().await.map_err(|_| Error::InvalidResponse)?;
+            let page_len = items.len();
+            let mut reached_since = false;
+            for item in items {
+                let (Some(number), Some(updated_at)) = (
+                    item["number"].as_u64(),
+                    item["updated_at"]
+                        .as_str()
+                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok()),
+                ) else {
+                    warn!(
+                        owner = repo_owner,
+                        repo = repo_name,
+                        "Skipping pull request without a number or update time"
+                    );
+                    continue;
+                };
+                let updated_at = updated_at.with_timezone(&chrono::Utc);
+                if since.is_some_and(|since| updated_at < since) {
+                    reached_since = true;
+                    break;
+                }
+                pull_requests.push(PullRequestSummary { number, updated_at });
+            }
+
+            if reached_since || page_len < PAGE_SIZE {
+                break;
+            }
+            page += 1;
+        }
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            count = pull_requests.len(),
+            "Listed pull requests"
+        );
+        Ok(pull_requests)
+    }
+
+    /// Converts a pull request that is ready for review back to a draft.
+    ///
+    /// The REST API cannot change the draft state, so this reads the pull request's
+    /// `node_id` from `GET /repos/{owner}/{repo}/pulls/{pr_number}` and posts the
+    /// `convertPullRequestToDraft` mutation to `POST /graphql`. Nothing is posted when
+    /// the pull request already is a draft.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses, a pull request
+    /// without a `node_id` or GraphQL errors.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn convert_to_draft(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<(), Error> {
+        const MUTATION: &str = "mutation($id: ID!) { \
+            convertPullRequestToDraft(input: { pullRequestId: $id }) { \
+                pullRequest { isDraft } \
+            } \
+        }";
+
+        let path = format!("/repos/{}/{}/pulls/{}", repo_owner, repo_name, pr_number);
+        let response = self.client.get(&path).await.map_err(|e| {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                error = %e,
+                "Failed to get pull request to convert to draft"
+            );
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                status = response.status().as_u16(),
+                "Non-success status fetching pull request to convert to draft"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        let pr: serde_json::Value = response.json().await.map_err(|_| Error::InvalidResponse)?;
+        if pr["draft"].as_bool() == Some(true) {
+            debug!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                "Pull request already is a draft"
+            );
+            return Ok(());
+        }
+        let node_id = pr["node_id"].as_str().ok_or(Error::InvalidResponse)?;
+
+        let payload = json!({
+            "query": MUTATION,
+            "variables": { "id": node_id }
+        });
+        let response = self.client.post("/graphql", &payload).await.map_err(|e| {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                error = %e,
+                "Failed to convert pull request to draft"
+            );
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                status = response.status().as_u16(),
+                "Non-success status converting pull request to draft"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        let json: serde_json::Value = response.json().await.map_err(|_| Error::InvalidResponse)?;
+        if let Some(errors) = json["errors"].as_array().filter(|e| !e.is_empty()) {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                errors = %serde_json::Value::Array(errors.clone()),
+                "GraphQL errors converting pull request to draft"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        info!(
+            owner = repo_owner,
+            repo = repo_name,
+            pr = pr_number,
+            "Converted pull request to draft"
+        );
+        Ok(())
+    }
+
+    /// Updates the title and/or description of a pull request.
+    ///
+    /// Uses `PATCH /repos/{owner}/{repo}/pulls/{pr_number}`, sending only the fields
+    /// that are `Some`. Nothing is sent when both are `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FailedToUpdatePullRequest`] if the request fails.
+    #[instrument(skip(self, title, body), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn update_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), Error> {
+        if title.is_none() && body.is_none() {
+            return Ok(());
+        }
+
+        let request = UpdatePullRequestRequest {
+            title: title.map(str::to_string),
+            body: body.map(str::to_string),
+            ..Default::default()
+        };
+        self.client
+            .pull_requests()
+            .update(repo_owner, repo_name, pr_number, request)
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                warn!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    error = %e,
+                    "Failed to update pull request"
+                );
+                Error::FailedToUpdatePullRequest(format!("Failed to update pull request: {}", e))
+            })
+    }
+
+    /// Lists the teams of an organization.
+    ///
+    /// Uses `GET /orgs/{org}/teams`, which requires the `members: read`
+    /// organization permission. The result is cached for the lifetime of the
+    /// provider.
+    #[instrument(skip(self))]
+    async fn list_teams(&self, org: &str) -> Result<Vec<Team>, Error> {
+        let key = org.to_lowercase();
+        let cached = self
+            .teams
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .teams
+            .get(&key)
+            .cloned();
+        if let Some(teams) = cached {
+            debug!(org, count = teams.len(), "Using cached teams");
+            return Ok(teams);
+        }
+
+        let teams: Vec<Team> = self
+            .get_all_pages(&format!("/orgs/{}/teams", org))
+            .await?
+            .into_iter()
+            .filter_map(|v| {
+                Some(Team {
+                    id: v["id"].as_u64()?,
+                    slug: v["slug"].as_str()?.to_string(),
+                    name: v["name"].as_str().unwrap_or_default().to_string(),
+                })
+            })
+            .collect();
+
+        debug!(org, count = teams.len(), "Fetched teams");
+        self.teams
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .teams
+            .insert(key, teams.clone());
+        Ok(teams)
+    }
+
+    /// Lists the members of an organization's team.
+    ///
+    /// Uses `GET /orgs/{org}/teams/{team}/members`, which includes the members of
+    /// child teams. The result is cached for the lifetime of the provider.
+    #[instrument(skip(self))]
+    async fn get_team_members(&self, org: &str, team: &str) -> Result<Vec<User>, Error> {
+        let key = (org.to_lowercase(), team.to_lowercase());
+        let cached = self
+            .teams
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .members
+            .get(&key)
+            .cloned();
+        if let Some(members) = cached {
+            debug!(
+                org,
+                team,
+                count = members.len(),
+                "Using cached team members"
+            );
+            return Ok(members);
+        }
+
+        let members: Vec<User> = self
+            .get_all_pages(&format!("/orgs/{}/teams/{}/members", org, team))
+            .await?
+            .into_iter()
+            .filter_map(|v| {
+                Some(User {
+                    id: v["id"].as_u64()?,
+                    login: v["login"].as_str()?.to_string(),
+                })
+            })
+            .collect();
+
+        debug!(org, team, count = members.len(), "Fetched team members");
+        self.teams
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .members
+            .insert(key, members.clone());
+        Ok(members)
+    }
+
+    /// Returns how the author of the pull request is associated with the repository.
+    ///
+    /// Calls `GET /repos/{owner}/{repo}/pulls/{pr_number}` and reads its
+    /// `author_association`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses or a body without a
+    /// known `author_association`.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn get_author_association(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<AuthorAssociation, Error> {
+        let path = format!("/repos/{}/{}/pulls/{}", repo_owner, repo_name, pr_number);
+
+        let response = self.client.get(&path).await.map_err(|e| {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                error = %e,
+                "Failed to get pull request author association"
+            );
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                status = response.status().as_u16(),
+                "Non-success status fetching pull request author association"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        let json: serde_json::Value = response.json().await.map_err(|_| Error::InvalidResponse)?;
+        let association: AuthorAssociation =
+            serde_json::from_value(json["author_association"].clone())
+                .map_err(|_| Error::InvalidResponse)?;
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            pr = pr_number,
+            association = ?association,
+            "Fetched pull request author association"
+        );
+
+        Ok(association)
+    }
+
+    /// Lists the unified diff of each file changed in the pull request.
+    ///
+    /// Calls `GET /repos/{owner}/{repo}/pulls/{pr_number}/files` and reads the
+    /// `patch` of each file. GitHub leaves out the patch of binary files and of very
+    /// large diffs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses or an unparseable body.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn get_pull_request_file_patches(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<PullRequestFilePatch>, Error> {
+        let path = format!(
+            "/repos/{}/{}/pulls/{}/files",
+            repo_owner, repo_name, pr_number
+        );
+
+        let response = self.client.get(&path).await.map_err(|e| {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                error = %e,
+                "Failed to get pull request file patches"
+            );
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                status = response.status().as_u16(),
+                "Non-success status fetching pull request file patches"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        let items: Vec<serde_json::Value> =
+            response.json().await.map_err(|_| Error::InvalidResponse)?;
+
+        let patches: Vec<PullRequestFilePatch> = items
+            .iter()
+            .map(|v| PullRequestFilePatch {
+                filename: v["filename"].as_str().unwrap_or_default().to_string(),
+                patch: v["patch"].as_str().map(str::to_string),
+            })
+            .collect();
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            pr = pr_number,
+            count = patches.len(),
+            "Fetched pull request file patches"
+        );
+
+        Ok(patches)
+    }
+
+    /// Creates or updates a named GitHub check run for the pull request.
+    ///
+    /// Fetches the PR head commit SHA and then POSTs to
+    /// `POST /repos/{owner}/{repo}/check-runs` with `check_name` as the check run name.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr_number` - The pull request number
+    /// * `check_name` - The name of the check run
+    /// * `conclusion` - The conclusion status ("success", "failure", "cancelled", etc.)
+    /// * `output_title` - The title shown in the check run details
+    /// * `output_summary` - A brief summary of the check results
+    /// * `output_text` - Detailed text output (supports Markdown)
+    /// * `actions` - Buttons to show on the check run
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pull request cannot be fetched or the check run
+    /// POST fails.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn update_named_pr_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        check_name: &str,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+        actions: &[CheckRunAction],
+    ) -> Result<(), Error> {
+        // Fetch the PR to get the head commit SHA for the check run.
+        let pr = self
+            .client
+            .pull_requests()
+            .get(repo_owner, repo_name, pr_number)
+            .await
+            .map_err(|e| {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    error = %e,
+                    "Failed to get PR head SHA for check run"
+                );
+                map_api_error(e)
+            })?;
+
+        self.post_check_run(
+            repo_owner,
+            repo_name,
+            &pr.head.sha,
+            check_name,
+            conclusion,
+            output_title,
+            output_summary,
+            output_text,
+            actions,
+        )
+        .await
+    }
+
+    /// Creates or updates a named GitHub check run for a commit.
+    ///
+    /// POSTs to `POST /repos/{owner}/{repo}/check-runs` with `head_sha` as the commit
+    /// and `check_name` as the check run name.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `head_sha` - The SHA of the commit the check run is reported on
+    /// * `check_name` - The name of the check run
+    /// * `conclusion` - The conclusion status ("success", "failure", "cancelled", etc.)
+    /// * `output_title` - The title shown in the check run details
+    /// * `output_summary` - A brief summary of the check results
+    /// * `output_text` - Detailed text output (supports Markdown)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the check run POST fails.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, sha = head_sha))]
+    async fn update_commit_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        head_sha: &str,
+        check_name: &str,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+    ) -> Result<(), Error> {
+        self.post_check_run(
+            repo_owner,
+            repo_name,
+            head_sha,
+            check_name,
+            conclusion,
+            output_title,
+            output_summary,
+            output_text,
+            &[],
+        )
+        .await
+    }
+
+    /// Reports the REST API rate-limit budget of the installation token.
+    ///
+    /// Reads the `rate` object of `GET /rate_limit`, which describes the core REST
+    /// API budget. GitHub does not count this request against the budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response does not contain the
+    /// `limit`, `remaining` and `reset` fields.
+    #[instrument(skip(self))]
+    async fn get_rate_limit_status(&self) -> Result<RateLimitStatus, Error> {
+        let response = self.client.get("/rate_limit").await.map_err(|e| {
+            error!(error = %e, "Failed to get rate limit status");
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                status = response.status().as_u16(),
+                "Non-success status getting rate limit status"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        let json: serde_json::Value = response.json().await.map_err(|_| Error::InvalidResponse)?;
+        let rate = &json["rate"];
+        let (Some(limit), Some(remaining), Some(reset)) = (
+            rate["limit"].as_u64(),
+            rate["remaining"].as_u64(),
+            rate["reset"].as_i64(),
+        ) else {
+            return Err(Error::InvalidResponse);
+        };
+        let reset_at = chrono::DateTime::from_timestamp(reset, 0).ok_or(Error::InvalidResponse)?;
+
+        let status = RateLimitStatus {
+            limit: limit as u32,
+            remaining: remaining as u32,
+            reset_at,
+        };
+
+        debug!(
+            limit = status.limit,
+            remaining = status.remaining,
+            reset_at = %status.reset_at,
+            "Fetched rate limit status"
+        );
+
+        Ok(status)
+    }
+
+    /// Lists the review comments on a pull request.
+    ///
+    /// Pages through `GET /repos/{owner}/{repo}/pulls/{pr_number}/comments`, which
+    /// returns both the comments that start review threads and their replies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error (via [`map_api_error`]) if the API call fails or the
+    /// response cannot be parsed.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn list_threaded_comments(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Comment>, Error> {
+        let mut all_comments: Vec<Comment> = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let path = format!(
+                "/repos/{}/{}/pulls/{}/comments?per_page=100&page={}",
+                repo_owner, repo_name, pr_number, page
+            );
+
+            let response = self.client.get(&path).await.map_err(|e| {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    error = %e,
+                    "Failed to list pull request review comments"
+                );
+                map_api_error(e)
+            })?;
+
+            if !response.status().is_success() {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    status = response.status().as_u16(),
+                    "Non-success status listing pull request review comments"
+                );
+                return Err(Error::InvalidResponse);
+            }
+
+            let has_next = response
+                .headers()
+                .get("Link")
+                .and_then(|h| h.to_str().ok())
+                .map(|h| parse_link_header(Some(h)).has_next())
+                .unwrap_or(false);
+
+            let items: Vec<serde_json::Value> =
+                response.json().await.map_err(|_| Error::InvalidResponse)?;
+            all_comments.extend(items.iter().filter_map(comment_from_json));
+
+            if !has_next {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(all_comments)
+    }
+
+    /// Adds a review comment to a pull request, as a reply or as a new thread.
+    ///
+    /// Replies are posted to
+    /// `POST /repos/{owner}/{repo}/pulls/{pr_number}/comments/{in_reply_to}/replies`.
+    /// GitHub attaches every review thread to a file, so a new thread is posted on
+    /// the first file the pull request changes, at its head commit, with
+    /// `subject_type` set to `file`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] if a new thread is requested for a pull
+    /// request without changed files, and [`Error::FailedToUpdatePullRequest`] if
+    /// the comment cannot be created.
+    #[instrument(skip(self, comment), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn add_threaded_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        in_reply_to: Option<u64>,
+        comment: &str,
+    ) -> Result<Comment, Error> {
+        let (path, payload) = match in_reply_to {
+            Some(root_id) => (
+                format!(
+                    "/repos/{}/{}/pulls/{}/comments/{}/replies",
+                    repo_owner, repo_name, pr_number, root_id
+                ),
+                json!({ "body": comment }),
+            ),
+            None => {
+                let pr = self
+                    .get_pull_request(repo_owner, repo_name, pr_number)
+                    .await?;
+                let files = self
+                    .get_pull_request_files(repo_owner, repo_name, pr_number)
+                    .await?;
+                let file = files.first().ok_or(Error::InvalidResponse)?;
+                (
+                    format!(
+                        "/repos/{}/{}/pulls/{}/comments",
+                        repo_owner, repo_name, pr_number
+                    ),
+                    json!({
+                        "body": comment,
+                        "commit_id": pr.head_sha,
+                        "path": file.filename,
+                        "subject_type": "file",
+                    }),
+                )
+            }
+        };
+
+        let response = self.client.post(&path, &payload).await.map_err(|e| {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                error = %e,
+                "Failed to add review comment"
+            );
+            map_api_error(e)
+        })?;
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                pr = pr_number,
+                status = response.status().as_u16(),
+                "Non-success status adding review comment"
+            );
+            return Err(Error::FailedToUpdatePullRequest(
+                "Failed to add review comment".to_string(),
+            ));
+        }
+
+        let json: serde_json::Value = response.json().await.map_err(|_| Error::InvalidResponse)?;
+        comment_from_json(&json).ok_or(Error::InvalidResponse)
+    }
+
+    /// Deletes a review comment.
+    ///
+    /// Calls `DELETE /repos/{owner}/{repo}/pulls/comments/{comment_id}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FailedToUpdatePullRequest`] if the API call fails.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, comment = comment_id))]
+    async fn delete_threaded_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        comment_id: u64,
+    ) -> Result<(), Error> {
+        let path = format!(
+            "/repos/{}/{}/pulls/comments/{}",
+            repo_owner, repo_name, comment_id
+        );
+
+        let response = self.client.delete(&path).await.map_err(|e| {
+            warn!(
+                owner = repo_owner,
+                repo = repo_name,
+                comment = comment_id,
+                error = %e,
+                "Failed to delete review comment"
+            );
+            Error::FailedToUpdatePullRequest(format!("Failed to delete review comment: {}", e))
+        })?;
+
+        if !response.status().is_success() {
+            warn!(
+                owner = repo_owner,
+                repo = repo_name,
+                comment = comment_id,
+                status = response.status().as_u16(),
+                "Non-success status deleting review comment"
+            );
+            return Err(Error::FailedToUpdatePullRequest(
+                "Failed to delete review comment".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Lists the commits of a pull request with their signature verification state.
+    ///
+    /// Pages through `GET /repos/{owner}/{repo}/pulls/{number}/commits` and reads
+    /// each commit's `commit.verification` object. GitHub returns at most 250
+    /// commits for a pull request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses or a body that is
+    /// not a JSON array.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn list_commit_verifications(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<CommitVerification>, Error> {
+        const PAGE_SIZE: usize = 100;
+
+        let mut commits = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let path = format!(
+                "/repos/{}/{}/pulls/{}/commits?per_page={}&page={}",
+                repo_owner, repo_name, pr_number, PAGE_SIZE, page
+            );
+
+            let response = self.client.get(&path).await.map_err(|e| {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    error = %e,
+                    "Failed to list pull request commits"
+                );
+                map_api_error(e)
+            })?;
+
+            if !response.status().is_success() {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    pr = pr_number,
+                    status = response.status().as_u16(),
+                    "Non-success status listing pull request commits"
+                );
+                return Err(Error::InvalidResponse);
+            }
+
+            let items: Vec<serde_json::Value> =
+                response.json().await.map_err(|_| Error::InvalidResponse)?;
+            let page_len = items.len();
+
+            // A commit without a verification object is treated as unsigned.
+            commits.extend(items.into_iter().filter_map(|v| {
+                let verification = &v["commit"]["verification"];
+                Some(CommitVerification {
+                    sha: v["sha"].as_str()?.to_string(),
+                    verified: verification["verified"].as_bool().unwrap_or(false),
+                    reason: verification["reason"]
+                        .as_str()
+                        .unwrap_or("unsigned")
+                        .to_string(),
+                })
+            }));
+
+            if page_len < PAGE_SIZE {
+                break;
+            }
+            page += 1;
+        }
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            pr = pr_number,
+            count = commits.len(),
+            unverified = commits.iter().filter(|c| !c.verified).count(),
+            "Fetched commit verifications"
+        );
+
+        Ok(commits)
+    }
+
+    /// Lists the commits of a pull request with their messages.
+    ///
+    /// Pages through `GET /repos/{owner}/{repo}/pulls/{number}/commits`. GitHub
+    /// returns at most 250 commits for a pull request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for non-200 responses or a body that is
+    /// not a JSON array.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, pr = pr_number))]
+    async fn list_pull_request_commits(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<PullRequestCommit>, Error> {
+        let commits: Vec<PullRequestCommit> = self
+            .get_all_pages(&format!(
+                "/repos/{}/{}/pulls/{}/commits",
+                repo_owner, repo_name, pr_number
+            ))
+            .await?
+            .into_iter()
+            .filter_map(|v| {
+                Some(PullRequestCommit {
+                    sha: v["sha"].as_str()?.to_string(),
+                    message: v["commit"]["message"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                })
+            })
+            .collect();
+
+        debug!(
+            owner = repo_owner,
+            repo = repo_name,
+            pr = pr_number,
+            count = commits.len(),
+            "Fetched pull request commits"
+        );
+
+        Ok(commits)
+    }
+
+    /// Looks up an issue or pull request by its number.
+    ///
+    /// Calls `GET /repos/{owner}/{repo}/issues/{number}`. GitHub serves pull
+    /// requests from the same endpoint and marks them with a `pull_request` object,
+    /// which sets [`Issue::is_pull_request`].
+    ///
+    /// Returns `Ok(None)` when nothing with that number exists (404).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidResponse`] for other non-success responses or a body
+    /// without a number.
+    /// Returns the appropriate [`Error`] variant for auth/rate-limit failures.
+    #[instrument(skip(self), fields(owner = repo_owner, repo = repo_name, issue = issue_number))]
+    async fn get_issue(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        issue_number: u64,
+    ) -> Result<Option<Issue>, Error> {
+        let path = format!(
+            "/repos/{}/{}/issues/{}",
+            repo_owner, repo_name, issue_number
+        );
+
+        let response = match self.client.get(&path).await {
+            Ok(response) => response,
+            Err(ApiError::NotFound) => return Ok(None),
+            Err(e) => {
+                error!(
+                    owner = repo_owner,
+                    repo = repo_name,
+                    issue = issue_number,
+                    error = %e,
+                    "Failed to fetch issue"
+                );
+                return Err(map_api_error(e));
+            }
+        };
+
+        if response.status().as_u16() == 404 {
+            debug!(
+                owner = repo_owner,
+                repo = repo_name,
+                issue = issue_number,
+                "Issue not found (404)"
+            );
+            return Ok(None);
+        }
+
+        if !response.status().is_success() {
+            error!(
+                owner = repo_owner,
+                repo = repo_name,
+                issue = issue_number,
+                status = response.status().as_u16(),
+                "Non-success status fetching issue"
+            );
+            return Err(Error::InvalidResponse);
+        }
+
+        let body: serde_json::Value = response.json().await.map_err(|_| Error::InvalidResponse)?;
+        let number = body["number"].as_u64().ok_or(Error::InvalidResponse)?;
+
+        Ok(Some(Issue {
+            number,
+            title: body["title"].as_str().unwrap_or_default().to_string(),
+            is_pull_request: body.get("pull_request").is_some_and(|v| !v.is_null()),
+        }))
+    }
 }
 
 #[async_trait]
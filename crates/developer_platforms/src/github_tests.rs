@@ -14,12 +14,13 @@ use github_bot_sdk::{
 };
 use serde_json::json;
 use wiremock::{
-    matchers::{body_string_contains, method, path, query_param},
+    matchers::{body_partial_json, body_string_contains, method, path, query_param},
     Mock, MockServer, ResponseTemplate,
 };
 
 use super::GitHubProvider;
 use crate::errors::Error;
+use crate::models::{AuthorAssociation, CheckRunAction};
 use crate::{
     ConfigFetcher, IssueMetadataProvider, PullRequestProvider, RepositoryMetadataProvider,
 };
@@ -297,6 +298,11 @@ async fn test_get_pull_request_returns_pr_data() {
         pr.head_sha, "abc123",
         "head_sha must be mapped from head.sha"
     );
+    assert_eq!(pr.base_ref, "main", "base_ref must be mapped from base.ref");
+    assert_eq!(
+        pr.head_ref, "feature-branch",
+        "head_ref must be mapped from head.ref"
+    );
 }
 
 #[tokio::test]
@@ -426,6 +432,219 @@ async fn test_get_pull_request_files_empty() {
     assert!(files.is_empty());
 }
 
+// ---------------------------------------------------------------------------
+// get_author_association
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_author_association_reads_pull_request() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "number": 5,
+            "title": "feat: add login",
+            "author_association": "FIRST_TIME_CONTRIBUTOR"
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let association = provider
+        .get_author_association("owner", "repo", 5)
+        .await
+        .unwrap();
+
+    assert_eq!(association, AuthorAssociation::FirstTimeContributor);
+}
+
+#[tokio::test]
+async fn test_get_author_association_unknown_value_returns_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "number": 5,
+            "author_association": "SOMETHING_NEW"
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider.get_author_association("owner", "repo", 5).await;
+
+    assert!(matches!(result, Err(Error::InvalidResponse)));
+}
+
+// ---------------------------------------------------------------------------
+// get_pull_request_file_patches
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_pull_request_file_patches_returns_patches() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/5/files"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "filename": "src/lib.rs",
+                "status": "modified",
+                "additions": 1,
+                "deletions": 1,
+                "changes": 2,
+                "patch": "@@ -1 +1 @@\n-fn a(){}\n+fn a() {}"
+            },
+            {
+                "filename": "logo.png",
+                "status": "added",
+                "additions": 0,
+                "deletions": 0,
+                "changes": 0
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let patches = provider
+        .get_pull_request_file_patches("owner", "repo", 5)
+        .await
+        .unwrap();
+
+    assert_eq!(patches.len(), 2);
+    assert_eq!(patches[0].filename, "src/lib.rs");
+    assert_eq!(
+        patches[0].patch.as_deref(),
+        Some("@@ -1 +1 @@\n-fn a(){}\n+fn a() {}")
+    );
+    assert_eq!(patches[1].filename, "logo.png");
+    assert_eq!(patches[1].patch, None);
+}
+
+#[tokio::test]
+async fn test_get_pull_request_file_patches_error_returns_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/5/files"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .get_pull_request_file_patches("owner", "repo", 5)
+        .await;
+
+    assert!(result.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// get_comparison_files
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_comparison_files_returns_files() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/compare/main...abc123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "ahead",
+            "files": [
+                {
+                    "filename": "src/lib.rs",
+                    "status": "modified",
+                    "additions": 120,
+                    "deletions": 30,
+                    "changes": 150
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let files = provider
+        .get_comparison_files("owner", "repo", "main", "abc123")
+        .await
+        .unwrap();
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].filename, "src/lib.rs");
+    assert_eq!(files[0].additions, 120);
+    assert_eq!(files[0].deletions, 30);
+}
+
+#[tokio::test]
+async fn test_get_comparison_files_not_found_returns_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/compare/missing...abc123"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "Not Found"})))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .get_comparison_files("owner", "repo", "missing", "abc123")
+        .await;
+
+    assert!(result.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// get_commits_behind
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_commits_behind_reads_how_far_the_base_is_ahead() {
+    let server = MockServer::start().await;
+
+    // The base is compared against the head, so its `ahead_by` is how far the
+    // head is behind.
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/compare/abc123...main"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "diverged",
+            "ahead_by": 4,
+            "behind_by": 2,
+            "files": []
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let behind_by = provider
+        .get_commits_behind("owner", "repo", "main", "abc123")
+        .await
+        .unwrap();
+
+    assert_eq!(behind_by, 4);
+}
+
+#[tokio::test]
+async fn test_get_commits_behind_without_count_returns_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/compare/abc123...main"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "identical" })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .get_commits_behind("owner", "repo", "main", "abc123")
+        .await;
+
+    assert!(matches!(result, Err(Error::InvalidResponse)));
+}
+
 // ---------------------------------------------------------------------------
 // list_applied_labels
 // ---------------------------------------------------------------------------
@@ -486,8 +705,10 @@ async fn test_list_applied_labels_returns_pr_labels() {
     assert_eq!(labels.len(), 2);
     assert_eq!(labels[0].name, "enhancement");
     assert_eq!(labels[0].description, Some("An enhancement".to_string()));
+    assert_eq!(labels[0].color, Some("84b6eb".to_string()));
     assert_eq!(labels[1].name, "good first issue");
     assert_eq!(labels[1].description, None);
+    assert_eq!(labels[1].color, Some("0075ca".to_string()));
 }
 
 #[tokio::test]
@@ -568,7 +789,10 @@ async fn test_list_available_labels_returns_repo_labels() {
     assert_eq!(labels.len(), 2);
     assert_eq!(labels[0].name, "bug");
     assert_eq!(labels[0].description, Some("Something wrong".to_string()));
+    assert_eq!(labels[0].color, Some("d73a4a".to_string()));
     assert_eq!(labels[1].name, "enhancement");
+    assert_eq!(labels[1].description, None);
+    assert_eq!(labels[1].color, Some("84b6eb".to_string()));
 }
 
 // ---------------------------------------------------------------------------
@@ -676,11 +900,8 @@ async fn test_remove_label_not_found_is_noop() {
 // update_pr_check_status
 // ---------------------------------------------------------------------------
 
-#[tokio::test]
-async fn test_update_pr_check_status_success() {
-    let server = MockServer::start().await;
-
-    // First: GET pull request to retrieve head SHA
+/// Mounts the pull request that check run updates fetch for its head SHA.
+async fn mount_check_run_pull_request(server: &MockServer) {
     Mock::given(method("GET"))
         .and(path("/repos/owner/repo/pulls/10"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
@@ -715,12 +936,21 @@ async fn test_update_pr_check_status_success() {
             "merged_at": null,
             "html_url": "https://github.com/owner/repo/pull/10"
         })))
-        .mount(&server)
+        .mount(server)
         .await;
+}
+
+#[tokio::test]
+async fn test_update_pr_check_status_success() {
+    let server = MockServer::start().await;
+
+    // First: GET pull request to retrieve head SHA
+    mount_check_run_pull_request(&server).await;
 
-    // Second: POST check run
+    // Second: POST check run under the default name
     Mock::given(method("POST"))
         .and(path("/repos/owner/repo/check-runs"))
+        .and(body_string_contains("MergeWarden"))
         .respond_with(ResponseTemplate::new(201).set_body_json(json!({
             "id": 5001,
             "name": "MergeWarden",
@@ -740,6 +970,115 @@ async fn test_update_pr_check_status_success() {
             "All checks passed",
             "PR meets all requirements",
             "Everything looks good",
+            &[],
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_update_pr_check_status_sends_actions() {
+    let server = MockServer::start().await;
+    mount_check_run_pull_request(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/check-runs"))
+        .and(body_partial_json(json!({
+            "actions": [{
+                "label": "Re-run Merge Warden",
+                "description": "Validate this pull request again",
+                "identifier": "merge_warden_rerun"
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "id": 5004 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .update_pr_check_status(
+            "owner",
+            "repo",
+            10,
+            "failure",
+            "Checks failed",
+            "PR title is invalid",
+            "",
+            &[CheckRunAction::rerun()],
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_update_named_pr_check_status_uses_check_name() {
+    let server = MockServer::start().await;
+    mount_check_run_pull_request(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/check-runs"))
+        .and(body_string_contains("Merge Warden PR checks"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 5002,
+            "name": "Merge Warden PR checks",
+            "status": "completed",
+            "conclusion": "success"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .update_named_pr_check_status(
+            "owner",
+            "repo",
+            10,
+            "Merge Warden PR checks",
+            "success",
+            "All checks passed",
+            "PR meets all requirements",
+            "Everything looks good",
+            &[],
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_update_commit_check_status_posts_on_the_commit() {
+    let server = MockServer::start().await;
+
+    // No pull request is fetched: the check run goes straight to the given commit.
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/check-runs"))
+        .and(body_string_contains("\"head_sha\":\"queue123\""))
+        .and(body_string_contains("Merge Warden PR checks"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 5003,
+            "name": "Merge Warden PR checks",
+            "status": "completed",
+            "conclusion": "failure"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .update_commit_check_status(
+            "owner",
+            "repo",
+            "queue123",
+            "Merge Warden PR checks",
+            "failure",
+            "Checks failed",
+            "PR title is invalid",
+            "",
         )
         .await;
 
@@ -2248,3 +2587,806 @@ async fn test_get_repository_context_property_null_value_stored_as_empty_string(
         "env property must be present with correct value"
     );
 }
+
+// ---------------------------------------------------------------------------
+// list_review_threads
+// ---------------------------------------------------------------------------
+
+/// Helper: GraphQL response JSON for one page of review threads.
+fn graphql_review_threads_response(
+    nodes: serde_json::Value,
+    end_cursor: Option<&str>,
+) -> serde_json::Value {
+    json!({
+        "data": {
+            "repository": {
+                "pullRequest": {
+                    "reviewThreads": {
+                        "nodes": nodes,
+                        "pageInfo": {
+                            "hasNextPage": end_cursor.is_some(),
+                            "endCursor": end_cursor
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_list_review_threads_maps_resolution_state() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("reviewThreads"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(graphql_review_threads_response(
+                json!([
+                    { "id": "T_1", "isResolved": true, "isOutdated": false },
+                    { "id": "T_2", "isResolved": false, "isOutdated": true }
+                ]),
+                None,
+            )),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let threads = provider
+        .list_review_threads("owner", "repo", 7)
+        .await
+        .expect("should succeed");
+
+    assert_eq!(threads.len(), 2);
+    assert!(threads[0].is_resolved);
+    assert_eq!(threads[1].id, "T_2");
+    assert!(!threads[1].is_resolved);
+    assert!(threads[1].is_outdated);
+}
+
+#[tokio::test]
+async fn test_list_review_threads_follows_pagination() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("\"cursor\":null"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(graphql_review_threads_response(
+                json!([{ "id": "T_1", "isResolved": false, "isOutdated": false }]),
+                Some("page-2"),
+            )),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("\"cursor\":\"page-2\""))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(graphql_review_threads_response(
+                json!([{ "id": "T_2", "isResolved": true, "isOutdated": false }]),
+                None,
+            )),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let threads = provider
+        .list_review_threads("owner", "repo", 7)
+        .await
+        .expect("should succeed");
+
+    let ids: Vec<&str> = threads.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["T_1", "T_2"]);
+}
+
+#[tokio::test]
+async fn test_list_review_threads_returns_invalid_response_on_graphql_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": { "pullRequest": null } },
+            "errors": [{ "message": "Could not resolve to a PullRequest with the number of 7." }]
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider.list_review_threads("owner", "repo", 7).await;
+
+    assert!(matches!(result, Err(Error::InvalidResponse)));
+}
+
+// ---------------------------------------------------------------------------
+// find_label_applier
+// ---------------------------------------------------------------------------
+
+/// Helper: one `labeled` issue event.
+fn labeled_event(label: &str, actor_id: u64, actor_login: &str) -> serde_json::Value {
+    json!({
+        "event": "labeled",
+        "label": { "name": label, "color": "ededed" },
+        "actor": { "id": actor_id, "login": actor_login }
+    })
+}
+
+#[tokio::test]
+async fn test_find_label_applier_returns_most_recent_labeler() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/7/events"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            labeled_event("skip-checks", 1, "contributor"),
+            { "event": "unlabeled", "label": { "name": "skip-checks" }, "actor": { "id": 2, "login": "maintainer" } },
+            labeled_event("Skip-Checks", 2, "maintainer"),
+            labeled_event("size/XS", 3, "merge-warden[bot]")
+        ])))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let applier = provider
+        .find_label_applier("owner", "repo", 7, "skip-checks")
+        .await
+        .expect("should succeed");
+
+    assert_eq!(applier.map(|u| u.login), Some("maintainer".to_string()));
+}
+
+#[tokio::test]
+async fn test_find_label_applier_returns_none_when_label_never_applied() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/7/events"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([labeled_event(
+                "size/XS",
+                3,
+                "merge-warden[bot]"
+            )])),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let applier = provider
+        .find_label_applier("owner", "repo", 7, "skip-checks")
+        .await
+        .expect("should succeed");
+
+    assert!(applier.is_none());
+}
+
+#[tokio::test]
+async fn test_find_label_applier_returns_invalid_response_on_error_status() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/7/events"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .find_label_applier("owner", "repo", 7, "skip-checks")
+        .await;
+
+    assert!(result.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// get_rate_limit_status
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_rate_limit_status_returns_core_budget() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "resources": {
+                "core": { "limit": 5000, "remaining": 4200, "reset": 1700000000, "used": 800 },
+                "graphql": { "limit": 5000, "remaining": 5000, "reset": 1700000100, "used": 0 }
+            },
+            "rate": { "limit": 5000, "remaining": 4200, "reset": 1700000000, "used": 800 }
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let status = provider
+        .get_rate_limit_status()
+        .await
+        .expect("should succeed");
+
+    assert_eq!(status.limit, 5000);
+    assert_eq!(status.remaining, 4200);
+    assert_eq!(status.reset_at.timestamp(), 1_700_000_000);
+}
+
+#[tokio::test]
+async fn test_get_rate_limit_status_returns_invalid_response_on_missing_fields() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "resources": {} })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider.get_rate_limit_status().await;
+
+    assert!(matches!(result, Err(Error::InvalidResponse)));
+}
+
+// ---------------------------------------------------------------------------
+// threaded comments
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_list_threaded_comments_returns_review_comments() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/1/comments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 10, "body": "<!-- MERGE_WARDEN_THREAD -->", "user": { "id": 5, "login": "merge-warden[bot]" } },
+            { "id": 11, "body": "Please fix the title", "in_reply_to_id": 10, "user": { "id": 5, "login": "merge-warden[bot]" } }
+        ])))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let comments = provider
+        .list_threaded_comments("owner", "repo", 1)
+        .await
+        .expect("should succeed");
+
+    assert_eq!(comments.len(), 2);
+    assert_eq!(comments[1].id, 11);
+    assert_eq!(comments[1].body, "Please fix the title");
+    assert_eq!(comments[1].user.login, "merge-warden[bot]");
+}
+
+#[tokio::test]
+async fn test_add_threaded_comment_replies_to_thread() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/pulls/1/comments/10/replies"))
+        .and(body_string_contains("Please fix the title"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 11,
+            "body": "Please fix the title",
+            "user": { "id": 5, "login": "merge-warden[bot]" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let comment = provider
+        .add_threaded_comment("owner", "repo", 1, Some(10), "Please fix the title")
+        .await
+        .expect("should succeed");
+
+    assert_eq!(comment.id, 11);
+}
+
+#[tokio::test]
+async fn test_delete_threaded_comment_deletes_review_comment() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/repos/owner/repo/pulls/comments/11"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    provider
+        .delete_threaded_comment("owner", "repo", 11)
+        .await
+        .expect("should succeed");
+}
+
+// ---------------------------------------------------------------------------
+// list_commit_verifications
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_list_commit_verifications_maps_verification_state() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/7/commits"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "sha": "aaa111",
+                "commit": {
+                    "message": "feat: signed",
+                    "verification": { "verified": true, "reason": "valid" }
+                }
+            },
+            {
+                "sha": "bbb222",
+                "commit": {
+                    "message": "fix: unsigned",
+                    "verification": { "verified": false, "reason": "unsigned" }
+                }
+            },
+            {
+                "sha": "ccc333",
+                "commit": { "message": "chore: no verification object" }
+            }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let commits = provider
+        .list_commit_verifications("owner", "repo", 7)
+        .await
+        .expect("should succeed");
+
+    assert_eq!(commits.len(), 3);
+    assert_eq!(commits[0].sha, "aaa111");
+    assert!(commits[0].verified);
+    assert_eq!(commits[0].reason, "valid");
+    assert!(!commits[1].verified);
+    assert_eq!(commits[1].reason, "unsigned");
+    assert!(!commits[2].verified);
+    assert_eq!(commits[2].reason, "unsigned");
+}
+
+#[tokio::test]
+async fn test_list_commit_verifications_not_found_returns_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/7/commits"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "Not Found"})))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider.list_commit_verifications("owner", "repo", 7).await;
+
+    assert!(result.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// list_pull_request_commits
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_list_pull_request_commits_maps_messages() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/7/commits"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "sha": "aaa111",
+                "commit": { "message": "feat: add login\n\nWith single sign-on." }
+            },
+            {
+                "sha": "bbb222",
+                "commit": { "message": "docs: describe login" }
+            }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let commits = provider
+        .list_pull_request_commits("owner", "repo", 7)
+        .await
+        .expect("should succeed");
+
+    assert_eq!(commits.len(), 2);
+    assert_eq!(commits[0].sha, "aaa111");
+    assert_eq!(commits[0].subject(), "feat: add login");
+    assert_eq!(commits[1].message, "docs: describe login");
+}
+
+// ---------------------------------------------------------------------------
+// list_teams / get_team_members
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_list_teams_maps_teams_and_caches_them() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/teams"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 1, "slug": "release-managers", "name": "Release Managers" },
+            { "id": 2, "slug": "security", "name": "Security" }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let teams = provider.list_teams("org").await.unwrap();
+    // The organization name is compared ignoring case, so this is served from the cache
+    let cached = provider.clone().list_teams("Org").await.unwrap();
+
+    assert_eq!(teams.len(), 2);
+    assert_eq!(teams[0].slug, "release-managers");
+    assert_eq!(teams[0].name, "Release Managers");
+    assert_eq!(teams[1].id, 2);
+    assert_eq!(cached, teams);
+}
+
+#[tokio::test]
+async fn test_get_team_members_reads_every_page_and_caches_them() {
+    let server = MockServer::start().await;
+
+    let first_page: Vec<serde_json::Value> = (1..=100)
+        .map(|id| json!({ "id": id, "login": format!("user{}", id) }))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/teams/security/members"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(first_page))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/teams/security/members"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([{ "id": 101, "login": "alice" }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let members = provider.get_team_members("org", "security").await.unwrap();
+    let cached = provider.get_team_members("org", "Security").await.unwrap();
+
+    assert_eq!(members.len(), 101);
+    assert_eq!(members[100].login, "alice");
+    assert_eq!(cached, members);
+}
+
+#[tokio::test]
+async fn test_get_team_members_does_not_cache_failures() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/teams/missing/members"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "Not Found"})))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+
+    assert!(provider.get_team_members("org", "missing").await.is_err());
+    assert!(provider.get_team_members("org", "missing").await.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// get_issue
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_get_issue_distinguishes_issues_from_pull_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/12"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "number": 12,
+            "title": "Login fails on Safari",
+            "state": "open"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/13"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "number": 13,
+            "title": "fix: handle Safari cookies",
+            "state": "open",
+            "pull_request": { "url": "https://api.github.com/repos/owner/repo/pulls/13" }
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let issue = provider
+        .get_issue("owner", "repo", 12)
+        .await
+        .expect("should succeed")
+        .expect("issue should exist");
+    let pull_request = provider
+        .get_issue("owner", "repo", 13)
+        .await
+        .expect("should succeed")
+        .expect("pull request should exist");
+
+    assert_eq!(issue.number, 12);
+    assert_eq!(issue.title, "Login fails on Safari");
+    assert!(!issue.is_pull_request);
+    assert!(pull_request.is_pull_request);
+}
+
+#[tokio::test]
+async fn test_get_issue_not_found_returns_none() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/issues/99"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "Not Found"})))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .get_issue("owner", "repo", 99)
+        .await
+        .expect("a missing issue is not an error");
+
+    assert!(result.is_none());
+}
+
+// ---------------------------------------------------------------------------
+// list_pull_requests
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_list_pull_requests_maps_open_pull_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls"))
+        .and(query_param("state", "open"))
+        .and(query_param("sort", "updated"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "number": 7, "updated_at": "2025-06-01T12:00:00Z" },
+            { "number": 3, "updated_at": "2025-05-30T08:30:00Z" }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let pull_requests = provider
+        .list_pull_requests("owner", "repo", None)
+        .await
+        .unwrap();
+
+    let numbers: Vec<u64> = pull_requests.iter().map(|pr| pr.number).collect();
+    assert_eq!(numbers, vec![7, 3]);
+    assert_eq!(
+        pull_requests[0].updated_at.to_rfc3339(),
+        "2025-06-01T12:00:00+00:00"
+    );
+}
+
+#[tokio::test]
+async fn test_list_pull_requests_stops_at_pull_requests_updated_before_since() {
+    let server = MockServer::start().await;
+
+    let first_page: Vec<serde_json::Value> = (1..=100)
+        .map(|n| json!({ "number": n, "updated_at": "2025-06-01T12:00:00Z" }))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(first_page))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "number": 101, "updated_at": "2025-05-31T10:00:00Z" },
+            { "number": 103, "updated_at": "2025-05-31T00:00:00Z" },
+            { "number": 102, "updated_at": "2025-05-30T10:00:00Z" }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls"))
+        .and(query_param("page", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let since = chrono::DateTime::parse_from_rfc3339("2025-05-31T00:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let pull_requests = provider
+        .list_pull_requests("owner", "repo", Some(since))
+        .await
+        .unwrap();
+
+    // A pull request updated at exactly `since` is included
+    assert_eq!(pull_requests.len(), 102);
+    assert_eq!(pull_requests.last().unwrap().number, 103);
+}
+
+// ---------------------------------------------------------------------------
+// convert_to_draft
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_convert_to_draft_posts_mutation_with_node_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "number": 5,
+            "node_id": "PR_kwDOA",
+            "draft": false
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("convertPullRequestToDraft"))
+        .and(body_string_contains("\"id\":\"PR_kwDOA\""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "convertPullRequestToDraft": { "pullRequest": { "isDraft": true } } }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    provider
+        .convert_to_draft("owner", "repo", 5)
+        .await
+        .expect("should succeed");
+}
+
+#[tokio::test]
+async fn test_convert_to_draft_skips_draft_pull_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "number": 5,
+            "node_id": "PR_kwDOA",
+            "draft": true
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    provider
+        .convert_to_draft("owner", "repo", 5)
+        .await
+        .expect("should succeed");
+}
+
+#[tokio::test]
+async fn test_convert_to_draft_returns_invalid_response_on_graphql_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/pulls/5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "number": 5,
+            "node_id": "PR_kwDOA",
+            "draft": false
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "convertPullRequestToDraft": null },
+            "errors": [{ "message": "Resource not accessible by integration" }]
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider.convert_to_draft("owner", "repo", 5).await;
+
+    assert!(matches!(result, Err(Error::InvalidResponse)));
+}
+
+// ---------------------------------------------------------------------------
+// update_pull_request
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_update_pull_request_patches_only_the_given_fields() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/repos/owner/repo/pulls/42"))
+        .and(body_partial_json(json!({ "title": "feat: add login" })))
+        .and(|request: &wiremock::Request| {
+            serde_json::from_slice::<serde_json::Value>(&request.body)
+                .is_ok_and(|body| body.get("body").is_none())
+        })
+        .respond_with(ResponseTemplate::new(200).set_body_json(minimal_pr_json(42)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    provider
+        .update_pull_request("owner", "repo", 42, Some("feat: add login"), None)
+        .await
+        .expect("should succeed");
+}
+
+#[tokio::test]
+async fn test_update_pull_request_without_changes_sends_nothing() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    provider
+        .update_pull_request("owner", "repo", 42, None, None)
+        .await
+        .expect("should succeed");
+}
+
+#[tokio::test]
+async fn test_update_pull_request_api_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/repos/owner/repo/pulls/99"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found"
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = make_provider(&server.uri()).await;
+    let result = provider
+        .update_pull_request("owner", "repo", 99, None, Some("Fixes #1"))
+        .await;
+
+    assert!(matches!(result, Err(Error::FailedToUpdatePullRequest(_))));
+}
@@ -14,6 +14,9 @@
 //! - [`ConfigFetcher`] - Trait for fetching configuration files
 //! - [`models`] - Data models for pull requests, comments, labels, etc.
 //! - [`github`] - GitHub implementation of the provider traits
+//! - [`webhook`] - Verification and parsing of GitHub webhook deliveries
+//! - `test_util` - An in-memory provider and the provider contract tests, behind the
+//!   `test-util` feature
 //! - [`errors`] - Error types for the crate
 //!
 //! # Examples
@@ -43,6 +46,9 @@ use async_trait::async_trait;
 /// GitHub App authentication provider for webhook-driven deployments.
 pub mod app_auth;
 
+/// Source of the current time, replaceable in tests.
+pub mod clock;
+
 /// Error types for developer platform operations.
 pub mod errors;
 
@@ -52,15 +58,28 @@ pub mod github;
 /// Data models for pull requests, comments, labels, and other platform entities.
 pub mod models;
 
+/// Webhook signature verification and payload extraction.
+pub mod webhook;
+
+/// In-memory provider and contract tests for provider implementations.
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
 #[cfg(test)]
 mod lib_tests;
 
 use errors::Error;
 use models::{
-    Comment, CommitStatus, IssueMetadata, Label, PullRequest, PullRequestFile, RepositoryContext,
-    Review,
+    AuthorAssociation, CheckRunAction, Comment, CommitStatus, CommitVerification, Issue,
+    IssueMetadata, Label, PullRequest, PullRequestCommit, PullRequestFile, PullRequestFilePatch,
+    PullRequestSummary, RateLimitStatus, RepositoryContext, Review, ReviewThread, Team, User,
 };
 
+/// Name of the check run posted by [`PullRequestProvider::update_pr_check_status`].
+///
+/// Branch protection rules refer to check runs by name, so this name must stay stable.
+pub const DEFAULT_CHECK_RUN_NAME: &str = "MergeWarden";
+
 /// Trait to fetch configuration files from remote repositories.
 #[async_trait]
 pub trait ConfigFetcher: Sync + Send {
@@ -128,7 +147,7 @@ pub trait ConfigFetcher: Sync + Send {
 ///     # async fn add_labels(&self, _: &str, _: &str, _: u64, _: &[String]) -> Result<(), Error> { unimplemented!() }
 ///     # async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
 ///     # async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<Label>, Error> { unimplemented!() }
-///     # async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+///     # async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
 ///     # async fn get_pull_request_files(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::PullRequestFile>, Error> { unimplemented!() }
 ///     # async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
 ///     # async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
@@ -167,7 +186,7 @@ pub trait PullRequestProvider {
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
     /// #     async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
@@ -222,7 +241,7 @@ pub trait PullRequestProvider {
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
     /// #     async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
@@ -276,7 +295,7 @@ pub trait PullRequestProvider {
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
     /// #     async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
@@ -350,7 +369,7 @@ pub trait PullRequestProvider {
     /// #     async fn add_labels(&self, _: &str, _: &str, _: u64, _: &[String]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
@@ -406,7 +425,7 @@ pub trait PullRequestProvider {
     /// #     async fn list_comments(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Comment>, Error> { unimplemented!() }
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
@@ -461,7 +480,7 @@ pub trait PullRequestProvider {
     /// #     async fn list_comments(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Comment>, Error> { unimplemented!() }
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
@@ -511,7 +530,7 @@ pub trait PullRequestProvider {
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
     /// #     async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
@@ -564,7 +583,7 @@ pub trait PullRequestProvider {
     /// #     async fn list_comments(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Comment>, Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
     /// #     async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
@@ -601,6 +620,8 @@ pub trait PullRequestProvider {
     /// * `output_title` - The title for the check run output
     /// * `output_summary` - The summary for the check run output
     /// * `output_text` - The text for the check run output. Supports Markdown
+    /// * `actions` - Buttons to show on the check run, such as
+    ///   [`CheckRunAction::rerun`]. Platforms without check run actions ignore them
     ///
     /// # Returns
     ///
@@ -637,6 +658,7 @@ pub trait PullRequestProvider {
     ///     output_title: &str,
     ///     output_summary: &str,
     ///     output_text: &str,
+    ///     actions: &[merge_warden_developer_platforms::models::CheckRunAction],
     /// ) -> Result<(), Error> {
     ///     // Implementation to update pull request check status
     ///     # unimplemented!()
@@ -653,6 +675,7 @@ pub trait PullRequestProvider {
         output_title: &str,
         output_summary: &str,
         output_text: &str,
+        actions: &[CheckRunAction],
     ) -> Result<(), Error>;
 
     /// Lists all reviews submitted on a pull request.
@@ -717,7 +740,7 @@ pub trait PullRequestProvider {
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
     /// #     async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn find_pull_requests_for_commit(&self, _: &str, _: &str, _: &str) -> Result<Vec<u64>, Error> { unimplemented!() }
     ///
@@ -774,7 +797,7 @@ pub trait PullRequestProvider {
     /// #     async fn remove_label(&self, _: &str, _: &str, _: u64, _: &str) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_applied_labels(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
     /// #     async fn list_available_labels(&self, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::Label>, Error> { unimplemented!() }
-    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str) -> Result<(), Error> { unimplemented!() }
+    /// #     async fn update_pr_check_status(&self, _: &str, _: &str, _: u64, _: &str, _: &str, _: &str, _: &str, _: &[merge_warden_developer_platforms::models::CheckRunAction]) -> Result<(), Error> { unimplemented!() }
     /// #     async fn list_pr_reviews(&self, _: &str, _: &str, _: u64) -> Result<Vec<merge_warden_developer_platforms::models::Review>, Error> { unimplemented!() }
     /// #     async fn get_commit_statuses(&self, _: &str, _: &str, _: &str) -> Result<Vec<merge_warden_developer_platforms::models::CommitStatus>, Error> { unimplemented!() }
     ///
@@ -827,6 +850,490 @@ pub trait PullRequestProvider {
     ) -> Result<(), Error> {
         Ok(())
     }
+
+    /// Lists the review comment threads on a pull request together with their
+    /// resolution state.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    ///
+    /// # Returns
+    /// Every review thread on the pull request, in the order the platform returns them.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Review-thread
+    /// resolution state is only available through the GitHub GraphQL API, so only
+    /// [`github::GitHubProvider`] overrides this method.
+    async fn list_review_threads(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<ReviewThread>, Error> {
+        Err(Error::Unsupported("list_review_threads".to_string()))
+    }
+
+    /// Finds the user who most recently applied a label to a pull request.
+    ///
+    /// Used to check that a bypass label was applied by someone who is allowed
+    /// to bypass the corresponding rule.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    /// * `label`      — Name of the label, compared case-insensitively.
+    ///
+    /// # Returns
+    /// The user from the most recent "labeled" event for `label`, or `None` when
+    /// the PR history contains no such event.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] reads the PR event history.
+    async fn find_label_applier(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+        _label: &str,
+    ) -> Result<Option<User>, Error> {
+        Err(Error::Unsupported("find_label_applier".to_string()))
+    }
+
+    /// Lists the files changed between two branches, tags or commits.
+    ///
+    /// Used to measure a pull request against a branch other than its own base,
+    /// for example the cumulative size of a stacked PR.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `base`       — Branch, tag or commit SHA to compare from.
+    /// * `head`       — Branch, tag or commit SHA to compare to.
+    ///
+    /// # Returns
+    /// The files changed between the merge base of `base` and `head`, and `head`.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] implements the comparison.
+    async fn get_comparison_files(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _base: &str,
+        _head: &str,
+    ) -> Result<Vec<PullRequestFile>, Error> {
+        Err(Error::Unsupported("get_comparison_files".to_string()))
+    }
+
+    /// Counts the commits on one branch that another branch does not contain.
+    ///
+    /// Used to tell whether a pull request is behind its base branch.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `base`       — Branch, tag or commit SHA whose commits are counted, such as
+    ///   the pull request's base branch.
+    /// * `head`       — Branch, tag or commit SHA to compare against, such as the
+    ///   pull request's head commit.
+    ///
+    /// # Returns
+    /// The number of commits reachable from `base` but not from `head`; `0` when
+    /// `head` is up to date with `base`.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] implements the comparison.
+    async fn get_commits_behind(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _base: &str,
+        _head: &str,
+    ) -> Result<u64, Error> {
+        Err(Error::Unsupported("get_commits_behind".to_string()))
+    }
+
+    /// Lists the open pull requests of a repository, most recently updated first.
+    ///
+    /// Used to poll repositories for pull requests to check when webhooks cannot be
+    /// delivered.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `since`      — When set, only pull requests updated at or after this time
+    ///   are returned. Pull requests updated at exactly `since` are included, as
+    ///   update times only have a precision of one second.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`].
+    async fn list_pull_requests(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<PullRequestSummary>, Error> {
+        Err(Error::Unsupported("list_pull_requests".to_string()))
+    }
+
+    /// Converts a pull request that is ready for review back to a draft.
+    ///
+    /// Used to signal that a pull request failing its checks is not ready for review.
+    /// Converting a pull request that already is a draft has no effect.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`].
+    async fn convert_to_draft(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<(), Error> {
+        Err(Error::Unsupported("convert_to_draft".to_string()))
+    }
+
+    /// Updates the title and/or description of a pull request.
+    ///
+    /// Used to fix failing checks automatically. Fields that are `None` are left
+    /// unchanged.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    /// * `title`      — The new title, or `None` to keep the current title.
+    /// * `body`       — The new description, or `None` to keep the current description.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`].
+    async fn update_pull_request(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+        _title: Option<&str>,
+        _body: Option<&str>,
+    ) -> Result<(), Error> {
+        Err(Error::Unsupported("update_pull_request".to_string()))
+    }
+
+    /// Lists the teams of an organization.
+    ///
+    /// Used to resolve team-based bypass rules and code owner approvals.
+    ///
+    /// # Arguments
+    /// * `org` — Organization login.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] has a concept of teams.
+    async fn list_teams(&self, _org: &str) -> Result<Vec<Team>, Error> {
+        Err(Error::Unsupported("list_teams".to_string()))
+    }
+
+    /// Lists the members of an organization's team.
+    ///
+    /// # Arguments
+    /// * `org`  — Organization login.
+    /// * `team` — Slug of the team, as returned in [`Team::slug`].
+    ///
+    /// # Returns
+    /// The direct and child-team members of the team.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] has a concept of teams.
+    async fn get_team_members(&self, _org: &str, _team: &str) -> Result<Vec<User>, Error> {
+        Err(Error::Unsupported("get_team_members".to_string()))
+    }
+
+    /// Returns how the author of a pull request is associated with the repository.
+    ///
+    /// Used to tell the repository's own team from outside contributors.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] reports author associations.
+    async fn get_author_association(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<AuthorAssociation, Error> {
+        Err(Error::Unsupported("get_author_association".to_string()))
+    }
+
+    /// Lists the unified diff of each file changed in a pull request.
+    ///
+    /// Used to tell changed lines that only differ in whitespace from real changes,
+    /// which the line counts of [`PullRequestProvider::get_pull_request_files`] cannot.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    ///
+    /// # Returns
+    /// One entry per changed file, in the order of
+    /// [`PullRequestProvider::get_pull_request_files`]. Files whose diff the platform
+    /// omits, such as binary files, have no patch.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] fetches file patches.
+    async fn get_pull_request_file_patches(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<PullRequestFilePatch>, Error> {
+        Err(Error::Unsupported(
+            "get_pull_request_file_patches".to_string(),
+        ))
+    }
+
+    /// Updates the check run with the given name for the pull request.
+    ///
+    /// Behaves like [`PullRequestProvider::update_pr_check_status`], which always uses
+    /// [`DEFAULT_CHECK_RUN_NAME`], but posts the check run under `check_name`.
+    ///
+    /// # Arguments
+    /// * `repo_owner`     — Repository owner.
+    /// * `repo_name`      — Repository name.
+    /// * `pr_number`      — Pull request number.
+    /// * `check_name`     — Name of the check run, as referenced by branch protection.
+    /// * `conclusion`     — Check run conclusion (e.g., "success", "failure").
+    /// * `output_title`   — Title of the check run output.
+    /// * `output_summary` — Summary of the check run output.
+    /// * `output_text`    — Text of the check run output. Supports Markdown.
+    /// * `actions`        — Buttons to show on the check run.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] names check runs.
+    #[allow(clippy::too_many_arguments)]
+    async fn update_named_pr_check_status(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+        _check_name: &str,
+        _conclusion: &str,
+        _output_title: &str,
+        _output_summary: &str,
+        _output_text: &str,
+        _actions: &[CheckRunAction],
+    ) -> Result<(), Error> {
+        Err(Error::Unsupported(
+            "update_named_pr_check_status".to_string(),
+        ))
+    }
+
+    /// Updates the check run with the given name for a commit.
+    ///
+    /// Behaves like [`PullRequestProvider::update_named_pr_check_status`], but posts the
+    /// check run on `head_sha` instead of the head of a pull request. Used to report on
+    /// commits that belong to no pull request, such as the merge group commits of a
+    /// merge queue.
+    ///
+    /// # Arguments
+    /// * `repo_owner`     — Repository owner.
+    /// * `repo_name`      — Repository name.
+    /// * `head_sha`       — SHA of the commit the check run is reported on.
+    /// * `check_name`     — Name of the check run, as referenced by branch protection.
+    /// * `conclusion`     — Check run conclusion (e.g., "success", "failure").
+    /// * `output_title`   — Title of the check run output.
+    /// * `output_summary` — Summary of the check run output.
+    /// * `output_text`    — Text of the check run output. Supports Markdown.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] reports check runs on commits.
+    #[allow(clippy::too_many_arguments)]
+    async fn update_commit_check_status(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _head_sha: &str,
+        _check_name: &str,
+        _conclusion: &str,
+        _output_title: &str,
+        _output_summary: &str,
+        _output_text: &str,
+    ) -> Result<(), Error> {
+        Err(Error::Unsupported("update_commit_check_status".to_string()))
+    }
+
+    /// Reports the current API rate-limit budget of the provider's credentials.
+    ///
+    /// Used to throttle work before the budget runs out and to show operators how
+    /// much of it is in use. Reading the budget does not count against it.
+    ///
+    /// # Returns
+    /// The request limit, the requests remaining and the time the budget resets.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] reports its rate limit.
+    async fn get_rate_limit_status(&self) -> Result<RateLimitStatus, Error> {
+        Err(Error::Unsupported("get_rate_limit_status".to_string()))
+    }
+
+    /// Lists the threaded review comments on a pull request.
+    ///
+    /// Threaded comments are separate from the top-level comments returned by
+    /// [`PullRequestProvider::list_comments`]; they include both the comments that
+    /// start a thread and the replies in it.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] supports threaded comments.
+    async fn list_threaded_comments(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<Comment>, Error> {
+        Err(Error::Unsupported("list_threaded_comments".to_string()))
+    }
+
+    /// Adds a threaded review comment to a pull request.
+    ///
+    /// # Arguments
+    /// * `repo_owner`  — Repository owner.
+    /// * `repo_name`   — Repository name.
+    /// * `pr_number`   — Pull request number.
+    /// * `in_reply_to` — The comment that starts the thread to reply in, or `None`
+    ///   to start a new thread.
+    /// * `comment`     — Text of the comment. Supports Markdown.
+    ///
+    /// # Returns
+    /// The comment that was added.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] supports threaded comments.
+    async fn add_threaded_comment(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+        _in_reply_to: Option<u64>,
+        _comment: &str,
+    ) -> Result<Comment, Error> {
+        Err(Error::Unsupported("add_threaded_comment".to_string()))
+    }
+
+    /// Deletes a threaded review comment.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `comment_id` — ID of the threaded comment.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] supports threaded comments.
+    async fn delete_threaded_comment(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _comment_id: u64,
+    ) -> Result<(), Error> {
+        Err(Error::Unsupported("delete_threaded_comment".to_string()))
+    }
+
+    /// Lists the commits of a pull request together with their signature
+    /// verification state.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    ///
+    /// # Returns
+    /// One entry per commit on the pull request, oldest first.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Signature
+    /// verification is GitHub-specific metadata, so only [`github::GitHubProvider`]
+    /// overrides this method.
+    async fn list_commit_verifications(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<CommitVerification>, Error> {
+        Err(Error::Unsupported("list_commit_verifications".to_string()))
+    }
+
+    /// Lists the commits of a pull request with their messages.
+    ///
+    /// # Arguments
+    /// * `repo_owner` — Repository owner.
+    /// * `repo_name`  — Repository name.
+    /// * `pr_number`  — Pull request number.
+    ///
+    /// # Returns
+    /// One entry per commit on the pull request, oldest first.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] overrides this method.
+    async fn list_pull_request_commits(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _pr_number: u64,
+    ) -> Result<Vec<PullRequestCommit>, Error> {
+        Err(Error::Unsupported("list_pull_request_commits".to_string()))
+    }
+
+    /// Looks up an issue in a repository by its number.
+    ///
+    /// # Arguments
+    /// * `repo_owner`   — Repository owner.
+    /// * `repo_name`    — Repository name.
+    /// * `issue_number` — Issue number within the repository.
+    ///
+    /// # Returns
+    /// - `Ok(Some(issue))` — an issue or pull request with that number exists;
+    ///   [`Issue::is_pull_request`] tells them apart.
+    /// - `Ok(None)` — nothing with that number exists (404).
+    /// - `Err(e)` — transient or permission error.
+    ///
+    /// # Default
+    /// The default implementation returns [`Error::Unsupported`]. Only
+    /// [`github::GitHubProvider`] looks up issues.
+    async fn get_issue(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _issue_number: u64,
+    ) -> Result<Option<Issue>, Error> {
+        Err(Error::Unsupported("get_issue".to_string()))
+    }
 }
 
 /// Provides read access to issue metadata for propagation to pull requests.
@@ -4,7 +4,7 @@
 //! requirements specified in issue #169.
 
 use crate::errors::Error;
-use crate::models::{Comment, Label, PullRequest, PullRequestFile};
+use crate::models::{CheckRunAction, Comment, Label, PullRequest, PullRequestFile};
 use crate::PullRequestProvider;
 use async_trait::async_trait;
 
@@ -24,32 +24,39 @@ impl MockApiProvider {
                 Label {
                     name: "bug".to_string(),
                     description: Some("Something isn't working".to_string()),
+                    color: None,
                 },
                 Label {
                     name: "enhancement".to_string(),
                     description: Some("New feature or request".to_string()),
+                    color: None,
                 },
             ],
             available_labels: vec![
                 Label {
                     name: "bug".to_string(),
                     description: Some("Something isn't working".to_string()),
+                    color: None,
                 },
                 Label {
                     name: "enhancement".to_string(),
                     description: Some("New feature or request".to_string()),
+                    color: None,
                 },
                 Label {
                     name: "documentation".to_string(),
                     description: Some("Improvements or additions to documentation".to_string()),
+                    color: None,
                 },
                 Label {
                     name: "size: XS".to_string(),
                     description: Some("Extra small PR".to_string()),
+                    color: None,
                 },
                 Label {
                     name: "size: S".to_string(),
                     description: Some("Small PR".to_string()),
+                    color: None,
                 },
             ],
             comments: vec![],
@@ -106,6 +113,8 @@ impl PullRequestProvider for MockApiProvider {
             author: None,
             milestone_number: None,
             head_sha: String::new(),
+            base_ref: String::new(),
+            head_ref: String::new(),
         })
     }
 
@@ -168,6 +177,7 @@ impl PullRequestProvider for MockApiProvider {
         _output_title: &str,
         _output_summary: &str,
         _output_text: &str,
+        _actions: &[CheckRunAction],
     ) -> Result<(), Error> {
         // Mock implementation - always succeeds
         Ok(())
@@ -332,7 +342,16 @@ mod tests {
 
         // U - update_pr_check_status
         provider
-            .update_pr_check_status("owner", "repo", 123, "success", "title", "summary", "text")
+            .update_pr_check_status(
+                "owner",
+                "repo",
+                123,
+                "success",
+                "title",
+                "summary",
+                "text",
+                &[],
+            )
             .await
             .expect("update_pr_check_status should work");
     }
@@ -372,4 +391,157 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_list_review_threads_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider.list_review_threads("owner", "repo", 123).await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_find_label_applier_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider
+            .find_label_applier("owner", "repo", 123, "skip-checks")
+            .await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+    #[tokio::test]
+    async fn test_get_author_association_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider.get_author_association("owner", "repo", 123).await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_pull_request_file_patches_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider
+            .get_pull_request_file_patches("owner", "repo", 123)
+            .await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_rate_limit_status_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider.get_rate_limit_status().await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_commit_verifications_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider
+            .list_commit_verifications("owner", "repo", 123)
+            .await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_pull_request_commits_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider
+            .list_pull_request_commits("owner", "repo", 123)
+            .await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider.get_issue("owner", "repo", 123).await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_commits_behind_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider
+            .get_commits_behind("owner", "repo", "main", "abc123")
+            .await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_pull_requests_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider.list_pull_requests("owner", "repo", None).await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_convert_to_draft_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider.convert_to_draft("owner", "repo", 5).await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_pull_request_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider
+            .update_pull_request("owner", "repo", 5, Some("feat: add login"), None)
+            .await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
+
+    #[tokio::test]
+    async fn test_team_methods_default_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        assert!(matches!(
+            provider.list_teams("org").await,
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            provider.get_team_members("org", "release-managers").await,
+            Err(Error::Unsupported(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_commit_check_status_defaults_to_unsupported() {
+        let provider = MockApiProvider::new();
+
+        let result = provider
+            .update_commit_check_status(
+                "owner",
+                "repo",
+                "abc123",
+                "Merge Warden",
+                "success",
+                "title",
+                "summary",
+                "text",
+            )
+            .await;
+
+        assert!(matches!(result, Err(Error::Unsupported(_))));
+    }
 }
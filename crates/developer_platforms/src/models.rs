@@ -8,12 +8,54 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 #[path = "models_tests.rs"]
 mod tests;
 
+/// A button shown on a check run that asks the app to perform an action.
+///
+/// When a user clicks the button, the platform sends a `check_run` webhook event with
+/// the `requested_action` action and the button's `identifier`. GitHub limits the
+/// label to 20, the description to 40 and the identifier to 20 characters.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::CheckRunAction;
+///
+/// let action = CheckRunAction::rerun();
+/// assert_eq!(action.identifier, CheckRunAction::RERUN_IDENTIFIER);
+/// assert!(action.label.len() <= 20);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckRunAction {
+    /// The text of the button
+    pub label: String,
+
+    /// What the button does, shown when hovering over it
+    pub description: String,
+
+    /// The identifier sent back in the webhook event when the button is clicked
+    pub identifier: String,
+}
+
+impl CheckRunAction {
+    /// Identifier of the action that re-runs Merge Warden on the pull request.
+    pub const RERUN_IDENTIFIER: &'static str = "merge_warden_rerun";
+
+    /// Returns the "Re-run Merge Warden" action.
+    pub fn rerun() -> Self {
+        Self {
+            label: "Re-run Merge Warden".to_string(),
+            description: "Validate this pull request again".to_string(),
+            identifier: Self::RERUN_IDENTIFIER.to_string(),
+        }
+    }
+}
+
 /// Represents a comment on a pull request.
 ///
 /// This struct contains the essential information about a comment
@@ -99,6 +141,35 @@ pub struct Installation {
     pub name: Option<String>,
 }
 
+/// An issue or pull request looked up by its number.
+///
+/// GitHub numbers issues and pull requests from the same sequence, so a number
+/// referenced as `#123` may belong to either. `is_pull_request` tells them apart.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::Issue;
+///
+/// let issue = Issue {
+///     number: 42,
+///     title: "Login fails on Safari".to_string(),
+///     is_pull_request: false,
+/// };
+/// assert!(!issue.is_pull_request);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Issue {
+    /// The issue number within the repository
+    pub number: u64,
+
+    /// The title of the issue
+    pub title: String,
+
+    /// Whether the number belongs to a pull request rather than an issue
+    pub is_pull_request: bool,
+}
+
 /// Metadata fetched from a referenced issue for propagation to a pull request.
 ///
 /// Contains the milestone and project information from the issue that
@@ -193,6 +264,8 @@ pub struct IssueProject {
 /// # Fields
 ///
 /// * `name` - The name of the label
+/// * `description` - The label description, when the platform has one
+/// * `color` - The label colour as a six-digit hex code without a leading `#`
 ///
 /// # Examples
 ///
@@ -202,6 +275,7 @@ pub struct IssueProject {
 /// let label = Label {
 ///     name: "bug".to_string(),
 ///     description: None,
+///     color: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -211,6 +285,10 @@ pub struct Label {
 
     /// The description of the label (optional)
     pub description: Option<String>,
+
+    /// The colour of the label as a six-digit hex code, e.g. `d73a4a` (optional)
+    #[serde(default)]
+    pub color: Option<String>,
 }
 
 /// Represents an organization on a Git provider platform.
@@ -268,6 +346,8 @@ pub struct Organization {
 ///     }),
 ///     milestone_number: None,
 ///     head_sha: String::new(),
+///     base_ref: String::new(),
+///     head_ref: String::new(),
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -301,6 +381,18 @@ pub struct PullRequest {
     /// than the config on the default branch.
     #[serde(default)]
     pub head_sha: String,
+
+    /// The name of the branch the pull request will be merged into, e.g. `main`.
+    ///
+    /// Empty when the platform did not report it.
+    #[serde(default)]
+    pub base_ref: String,
+
+    /// The name of the branch that holds the changes of the pull request.
+    ///
+    /// Empty when the platform did not report it.
+    #[serde(default)]
+    pub head_ref: String,
 }
 
 /// Represents a file that has been changed in a pull request.
@@ -348,6 +440,75 @@ pub struct PullRequestFile {
     pub status: String,
 }
 
+/// The unified diff of a file changed in a pull request.
+///
+/// Platforms leave out the diff of binary files and of files whose diff is too large,
+/// so `patch` is optional.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::PullRequestFilePatch;
+///
+/// let patch = PullRequestFilePatch {
+///     filename: "src/main.rs".to_string(),
+///     patch: Some("@@ -1 +1 @@\n-fn main(){}\n+fn main() {}".to_string()),
+/// };
+/// assert!(patch.patch.is_some());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequestFilePatch {
+    /// The file path relative to the repository root
+    pub filename: String,
+
+    /// The hunks of the file's unified diff, or `None` when the platform omits them
+    pub patch: Option<String>,
+}
+
+/// The API request budget of the credentials a provider uses.
+///
+/// Platforms allow a fixed number of requests per window. The budget is shared by
+/// every request made with the same credentials, so for a GitHub App it covers all
+/// work done for one installation.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use merge_warden_developer_platforms::models::RateLimitStatus;
+///
+/// let status = RateLimitStatus {
+///     limit: 5000,
+///     remaining: 0,
+///     reset_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+/// };
+/// assert!(status.is_exhausted());
+/// assert_eq!(status.used(), 5000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// The number of requests allowed in the current window
+    pub limit: u32,
+
+    /// The number of requests left in the current window
+    pub remaining: u32,
+
+    /// When the current window ends and the budget is restored to `limit`
+    pub reset_at: DateTime<Utc>,
+}
+
+impl RateLimitStatus {
+    /// Returns `true` if no requests are left in the current window.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Returns the number of requests made in the current window.
+    pub fn used(&self) -> u32 {
+        self.limit.saturating_sub(self.remaining)
+    }
+}
+
 /// Represents a repository on a Git provider platform.
 ///
 /// This struct contains essential information about a repository
@@ -431,6 +592,143 @@ pub struct Review {
     pub user: User,
 }
 
+/// Represents a review comment thread on a pull request.
+///
+/// Review threads are the conversations attached to lines of the diff. A thread
+/// is resolved once a participant marks the conversation as resolved.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::ReviewThread;
+///
+/// let thread = ReviewThread {
+///     id: "PRRT_kwDOABC123".to_string(),
+///     is_resolved: false,
+///     is_outdated: true,
+/// };
+/// assert!(!thread.is_resolved);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewThread {
+    /// The platform-specific identifier of the thread
+    pub id: String,
+
+    /// Whether the thread has been marked as resolved
+    pub is_resolved: bool,
+
+    /// Whether the lines the thread refers to have changed since it was started
+    pub is_outdated: bool,
+}
+
+/// An open pull request as listed by
+/// [`PullRequestProvider::list_pull_requests`](crate::PullRequestProvider::list_pull_requests).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use merge_warden_developer_platforms::models::PullRequestSummary;
+///
+/// let summary = PullRequestSummary {
+///     number: 42,
+///     updated_at: Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap(),
+/// };
+/// assert_eq!(summary.number, 42);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestSummary {
+    /// The pull request number
+    pub number: u64,
+
+    /// When the pull request was last updated, for example by a push, an edit of its
+    /// title or description, or a new comment
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Represents a team of an organization on a Git provider platform.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::Team;
+///
+/// let team = Team {
+///     id: 42,
+///     slug: "release-managers".to_string(),
+///     name: "Release Managers".to_string(),
+/// };
+/// assert_eq!(team.slug, "release-managers");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Team {
+    /// The unique identifier of the team
+    pub id: u64,
+
+    /// The URL-friendly name of the team, used to refer to it in the API and in
+    /// mentions such as `@org/release-managers`
+    pub slug: String,
+
+    /// The display name of the team
+    pub name: String,
+}
+
+/// Represents a commit in a pull request.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::PullRequestCommit;
+///
+/// let commit = PullRequestCommit {
+///     sha: "6dcb09b5b57875f334f61aebed695e2e4193db5e".to_string(),
+///     message: "fix(auth): refresh expired tokens\n\nThe token was reused after expiry.".to_string(),
+/// };
+/// assert_eq!(commit.subject(), "fix(auth): refresh expired tokens");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequestCommit {
+    /// The SHA of the commit
+    pub sha: String,
+
+    /// The full commit message, including the subject line
+    pub message: String,
+}
+
+impl PullRequestCommit {
+    /// Returns the first line of the commit message.
+    pub fn subject(&self) -> &str {
+        self.message.lines().next().unwrap_or_default()
+    }
+}
+
+/// Represents the signature verification state of a commit in a pull request.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::CommitVerification;
+///
+/// let commit = CommitVerification {
+///     sha: "6dcb09b5b57875f334f61aebed695e2e4193db5e".to_string(),
+///     verified: false,
+///     reason: "unsigned".to_string(),
+/// };
+/// assert!(!commit.verified);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitVerification {
+    /// The SHA of the commit
+    pub sha: String,
+
+    /// Whether the platform verified the commit's signature
+    pub verified: bool,
+
+    /// The platform's reason for the verification state (e.g., "valid", "unsigned",
+    /// "unknown_key")
+    pub reason: String,
+}
+
 /// Represents a user on a Git provider platform.
 ///
 /// This struct contains the essential user information needed
@@ -461,6 +759,60 @@ pub struct User {
     pub login: String,
 }
 
+/// How the author of a pull request is associated with the repository.
+///
+/// Mapped from the `author_association` field of GitHub pull requests, e.g.
+/// `"MEMBER"` or `"FIRST_TIME_CONTRIBUTOR"`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::models::AuthorAssociation;
+///
+/// let association: AuthorAssociation = serde_json::from_str(r#""FIRST_TIME_CONTRIBUTOR""#).unwrap();
+/// assert_eq!(association, AuthorAssociation::FirstTimeContributor);
+/// assert!(!association.is_internal());
+/// assert!(AuthorAssociation::Member.is_internal());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuthorAssociation {
+    /// The author owns the repository
+    Owner,
+
+    /// The author is a member of the organization that owns the repository
+    Member,
+
+    /// The author has been invited to collaborate on the repository
+    Collaborator,
+
+    /// The author has previously committed to the repository
+    Contributor,
+
+    /// The author has not previously committed to the repository
+    FirstTimeContributor,
+
+    /// The author has not previously committed to GitHub
+    FirstTimer,
+
+    /// The author is a placeholder for an unclaimed user
+    Mannequin,
+
+    /// The author has no association with the repository
+    None,
+}
+
+impl AuthorAssociation {
+    /// Whether the author belongs to the team behind the repository: an owner, a
+    /// member of the owning organization or a collaborator.
+    pub fn is_internal(self) -> bool {
+        matches!(
+            self,
+            AuthorAssociation::Owner | AuthorAssociation::Member | AuthorAssociation::Collaborator
+        )
+    }
+}
+
 /// A single commit status entry returned by the GitHub Commit Statuses API.
 ///
 /// GitHub returns commit statuses newest-first. When multiple entries exist for
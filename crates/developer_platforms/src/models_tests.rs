@@ -126,6 +126,7 @@ fn test_label_serialization() {
     let label = Label {
         name: "bug".to_string(),
         description: None,
+        color: None,
     };
 
     // Serialize to JSON
@@ -136,6 +137,16 @@ fn test_label_serialization() {
     assert_eq!(parsed["name"], "bug");
 }
 
+#[test]
+fn test_label_deserialization_defaults_missing_color() {
+    let label: Label = serde_json::from_str(r#"{"name": "bug", "description": "Something wrong"}"#)
+        .expect("Failed to deserialize Label");
+
+    assert_eq!(label.name, "bug");
+    assert_eq!(label.description, Some("Something wrong".to_string()));
+    assert_eq!(label.color, None);
+}
+
 #[test]
 fn test_organization_deserialization() {
     // Create JSON
@@ -347,6 +358,8 @@ fn test_pull_request_serialization() {
         author: None,
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     // Serialize to JSON
@@ -392,6 +405,8 @@ fn test_pull_request_with_author_serialization() {
         }),
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     // Serialize to JSON
@@ -417,6 +432,8 @@ fn test_pull_request_without_author() {
         author: None,
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     // Serialize to JSON
@@ -446,6 +463,8 @@ fn test_pull_request_without_body() {
         author: None,
         milestone_number: None,
         head_sha: String::new(),
+        base_ref: String::new(),
+        head_ref: String::new(),
     };
 
     // Serialize to JSON
@@ -1204,3 +1223,41 @@ fn test_commit_status_description_none_serializes_as_null_key_present() {
         parsed["description"]
     );
 }
+
+#[test]
+fn test_rate_limit_status_reports_usage() {
+    let reset_at = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let status = RateLimitStatus {
+        limit: 5000,
+        remaining: 4200,
+        reset_at,
+    };
+
+    assert!(!status.is_exhausted());
+    assert_eq!(status.used(), 800);
+
+    let exhausted = RateLimitStatus {
+        remaining: 0,
+        ..status
+    };
+    assert!(exhausted.is_exhausted());
+    assert_eq!(exhausted.used(), 5000);
+}
+
+#[test]
+fn test_author_association_uses_github_names() {
+    let associations: Vec<AuthorAssociation> = from_str(
+        r#"["OWNER", "MEMBER", "COLLABORATOR", "CONTRIBUTOR", "FIRST_TIME_CONTRIBUTOR", "FIRST_TIMER", "MANNEQUIN", "NONE"]"#,
+    )
+    .unwrap();
+
+    let internal: Vec<bool> = associations.iter().map(|a| a.is_internal()).collect();
+    assert_eq!(
+        internal,
+        vec![true, true, true, false, false, false, false, false]
+    );
+    assert_eq!(
+        to_string(&AuthorAssociation::FirstTimer).unwrap(),
+        r#""FIRST_TIMER""#
+    );
+}
@@ -0,0 +1,799 @@
+//! Test utilities for [`PullRequestProvider`] implementations.
+//!
+//! This module is compiled for the crate's own tests and, for other crates, behind the
+//! `test-util` feature. It contains:
+//!
+//! - [`InMemoryProvider`] - A provider that keeps pull requests, labels, comments and
+//!   check runs in memory
+//! - [`ProviderContractFixture`] - How the contract tests seed and observe a provider
+//! - [`run_provider_contract`] - The contract tests that every provider must pass
+//!
+//! # Provider contract
+//!
+//! Merge Warden relies on these semantics from every provider:
+//!
+//! - Adding a label that is already applied leaves it applied once
+//! - Removing a label that is not applied succeeds and changes nothing
+//! - An added comment is listed with its body and an ID that deletes it; deleting a
+//!   comment that no longer exists fails
+//! - Updating the check status of a pull request twice leaves one check run, named
+//!   [`DEFAULT_CHECK_RUN_NAME`], with the latest result
+//! - All files of a pull request are listed, in order, however many pages the
+//!   platform splits them into
+//!
+//! # Opting in
+//!
+//! A provider opts in by implementing [`ProviderContractFixture`] for a test fixture
+//! that seeds the provider's backing state, for example a mock HTTP server, and
+//! calling [`run_provider_contract`] from a test. [`InMemoryProvider`] is its own
+//! fixture, so its tests call `run_provider_contract(&InMemoryProvider::new())`.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::errors::Error;
+use crate::models::{
+    CheckRunAction, Comment, CommitStatus, Label, PullRequest, PullRequestFile, Review, User,
+};
+use crate::{PullRequestProvider, DEFAULT_CHECK_RUN_NAME};
+
+#[cfg(test)]
+#[path = "test_util_tests.rs"]
+mod tests;
+
+/// Repository owner used by the contract tests.
+const CONTRACT_OWNER: &str = "contract-owner";
+
+/// Repository name used by the contract tests.
+const CONTRACT_REPO: &str = "contract-repo";
+
+/// Number of files seeded by the files pagination contract, more than the 100 files
+/// GitHub returns per page.
+const CONTRACT_FILE_COUNT: usize = 250;
+
+/// A check run as last reported for a pull request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckRunRecord {
+    /// The name of the check run
+    pub name: String,
+
+    /// The check run conclusion (e.g., "success", "failure")
+    pub conclusion: String,
+
+    /// The title of the check run output
+    pub title: String,
+
+    /// The summary of the check run output
+    pub summary: String,
+
+    /// The text of the check run output
+    pub text: String,
+
+    /// The buttons shown on the check run
+    pub actions: Vec<CheckRunAction>,
+}
+
+/// The state of one pull request held by an [`InMemoryProvider`].
+#[derive(Debug)]
+struct PullRequestState {
+    /// The pull request itself
+    pull_request: PullRequest,
+
+    /// The files changed by the pull request
+    files: Vec<PullRequestFile>,
+
+    /// The labels applied to the pull request
+    labels: Vec<Label>,
+
+    /// The check runs of the pull request, one per name
+    check_runs: Vec<CheckRunRecord>,
+}
+
+/// The state held by an [`InMemoryProvider`].
+#[derive(Debug, Default)]
+struct InMemoryState {
+    /// Pull requests, keyed by `owner/repo` in lower case and number
+    pull_requests: HashMap<(String, u64), PullRequestState>,
+
+    /// Labels defined per repository, keyed by `owner/repo` in lower case
+    available_labels: HashMap<String, Vec<Label>>,
+
+    /// Comments per repository, keyed by `owner/repo` in lower case, with their
+    /// pull request number
+    comments: HashMap<String, Vec<(u64, Comment)>>,
+
+    /// The ID given to the next comment
+    next_comment_id: u64,
+}
+
+/// A [`PullRequestProvider`] that keeps its state in memory.
+///
+/// Pull requests must be seeded with [`InMemoryProvider::add_pull_request`] before
+/// they can be read or changed; operations on unknown pull requests fail with
+/// [`Error::InvalidResponse`], as a missing pull request does on GitHub. Of the
+/// optional operations of the trait, only named check runs and pull request updates
+/// are supported; reviews and commit statuses are not.
+#[derive(Debug, Default)]
+pub struct InMemoryProvider {
+    /// The pull requests, labels and comments
+    state: Mutex<InMemoryState>,
+}
+
+impl InMemoryProvider {
+    /// Creates a provider without pull requests or labels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pull request, replacing any pull request with the same number.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pull_request` - The pull request
+    /// * `files` - The files changed by the pull request
+    pub fn add_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pull_request: PullRequest,
+        files: Vec<PullRequestFile>,
+    ) {
+        let key = (repository_key(repo_owner, repo_name), pull_request.number);
+        self.lock().pull_requests.insert(
+            key,
+            PullRequestState {
+                pull_request,
+                files,
+                labels: Vec::new(),
+                check_runs: Vec::new(),
+            },
+        );
+    }
+
+    /// Defines a label in a repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `label` - The label
+    pub fn add_available_label(&self, repo_owner: &str, repo_name: &str, label: Label) {
+        self.lock()
+            .available_labels
+            .entry(repository_key(repo_owner, repo_name))
+            .or_default()
+            .push(label);
+    }
+
+    /// Returns the check runs of a pull request, in the order they were first posted.
+    ///
+    /// Returns an empty list for unknown pull requests.
+    pub fn check_runs(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Vec<CheckRunRecord> {
+        self.lock()
+            .pull_requests
+            .get(&(repository_key(repo_owner, repo_name), pr_number))
+            .map(|pr| pr.check_runs.clone())
+            .unwrap_or_default()
+    }
+
+    /// Locks the state, recovering it if a panicking test poisoned the lock.
+    fn lock(&self) -> std::sync::MutexGuard<'_, InMemoryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Runs `f` against a pull request, failing with [`Error::InvalidResponse`] if the
+    /// pull request is unknown.
+    fn with_pull_request<T>(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        f: impl FnOnce(&mut PullRequestState) -> T,
+    ) -> Result<T, Error> {
+        self.lock()
+            .pull_requests
+            .get_mut(&(repository_key(repo_owner, repo_name), pr_number))
+            .map(f)
+            .ok_or(Error::InvalidResponse)
+    }
+}
+
+/// Returns the key of a repository in the in-memory state.
+fn repository_key(repo_owner: &str, repo_name: &str) -> String {
+    format!("{}/{}", repo_owner, repo_name).to_lowercase()
+}
+
+#[async_trait]
+impl PullRequestProvider for InMemoryProvider {
+    async fn add_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        comment: &str,
+    ) -> Result<(), Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |_| ())?;
+
+        let mut state = self.lock();
+        state.next_comment_id += 1;
+        let comment = Comment {
+            id: state.next_comment_id,
+            body: comment.to_string(),
+            user: User {
+                id: 1,
+                login: "merge-warden[bot]".to_string(),
+            },
+        };
+        state
+            .comments
+            .entry(repository_key(repo_owner, repo_name))
+            .or_default()
+            .push((pr_number, comment));
+        Ok(())
+    }
+
+    async fn add_labels(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        labels: &[String],
+    ) -> Result<(), Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |pr| {
+            for name in labels {
+                if !pr.labels.iter().any(|l| l.name.eq_ignore_ascii_case(name)) {
+                    pr.labels.push(Label {
+                        name: name.clone(),
+                        description: None,
+                        color: None,
+                    });
+                }
+            }
+        })
+    }
+
+    async fn delete_comment(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        comment_id: u64,
+    ) -> Result<(), Error> {
+        let mut state = self.lock();
+        let comments = state
+            .comments
+            .entry(repository_key(repo_owner, repo_name))
+            .or_default();
+        let count = comments.len();
+        comments.retain(|(_, c)| c.id != comment_id);
+        if comments.len() == count {
+            return Err(Error::FailedToUpdatePullRequest(format!(
+                "Comment {} does not exist",
+                comment_id
+            )));
+        }
+        Ok(())
+    }
+
+    async fn get_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<PullRequest, Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |pr| {
+            pr.pull_request.clone()
+        })
+    }
+
+    async fn get_pull_request_files(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<PullRequestFile>, Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |pr| pr.files.clone())
+    }
+
+    async fn list_applied_labels(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Label>, Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |pr| pr.labels.clone())
+    }
+
+    async fn list_available_labels(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+    ) -> Result<Vec<Label>, Error> {
+        Ok(self
+            .lock()
+            .available_labels
+            .get(&repository_key(repo_owner, repo_name))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn list_comments(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Comment>, Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |_| ())?;
+
+        Ok(self
+            .lock()
+            .comments
+            .get(&repository_key(repo_owner, repo_name))
+            .map(|comments| {
+                comments
+                    .iter()
+                    .filter(|(number, _)| *number == pr_number)
+                    .map(|(_, c)| c.clone())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn remove_label(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        label: &str,
+    ) -> Result<(), Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |pr| {
+            pr.labels.retain(|l| !l.name.eq_ignore_ascii_case(label));
+        })
+    }
+
+    async fn update_pr_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+        actions: &[CheckRunAction],
+    ) -> Result<(), Error> {
+        self.update_named_pr_check_status(
+            repo_owner,
+            repo_name,
+            pr_number,
+            DEFAULT_CHECK_RUN_NAME,
+            conclusion,
+            output_title,
+            output_summary,
+            output_text,
+            actions,
+        )
+        .await
+    }
+
+    async fn list_pr_reviews(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Result<Vec<Review>, Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |_| Vec::new())
+    }
+
+    async fn get_commit_statuses(
+        &self,
+        _repo_owner: &str,
+        _repo_name: &str,
+        _commit_sha: &str,
+    ) -> Result<Vec<CommitStatus>, Error> {
+        Ok(Vec::new())
+    }
+
+    async fn find_pull_requests_for_commit(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<u64>, Error> {
+        let key = repository_key(repo_owner, repo_name);
+        let mut numbers: Vec<u64> = self
+            .lock()
+            .pull_requests
+            .iter()
+            .filter(|((repository, _), pr)| {
+                *repository == key && pr.pull_request.head_sha == commit_sha
+            })
+            .map(|((_, number), _)| *number)
+            .collect();
+        numbers.sort_unstable();
+        Ok(numbers)
+    }
+
+    async fn update_named_pr_check_status(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        check_name: &str,
+        conclusion: &str,
+        output_title: &str,
+        output_summary: &str,
+        output_text: &str,
+        actions: &[CheckRunAction],
+    ) -> Result<(), Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |pr| {
+            let record = CheckRunRecord {
+                name: check_name.to_string(),
+                conclusion: conclusion.to_string(),
+                title: output_title.to_string(),
+                summary: output_summary.to_string(),
+                text: output_text.to_string(),
+                actions: actions.to_vec(),
+            };
+            match pr.check_runs.iter_mut().find(|r| r.name == check_name) {
+                Some(existing) => *existing = record,
+                None => pr.check_runs.push(record),
+            }
+        })
+    }
+
+    async fn update_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<(), Error> {
+        self.with_pull_request(repo_owner, repo_name, pr_number, |pr| {
+            if let Some(title) = title {
+                pr.pull_request.title = title.to_string();
+            }
+            if let Some(body) = body {
+                pr.pull_request.body = Some(body.to_string());
+            }
+        })
+    }
+}
+
+/// Seeds and observes the state behind a provider for the contract tests.
+///
+/// Provider authors implement this for a test fixture, for example one that mounts
+/// responses on a mock HTTP server, and pass it to [`run_provider_contract`]. The
+/// contract tests use pull requests in the `contract-owner/contract-repo` repository.
+#[async_trait]
+pub trait ProviderContractFixture: Sync {
+    /// The provider under test
+    type Provider: PullRequestProvider + Sync;
+
+    /// Returns the provider under test.
+    fn provider(&self) -> &Self::Provider;
+
+    /// Makes a pull request and its changed files known to the provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pull_request` - The pull request
+    /// * `files` - The files changed by the pull request, in the order the provider
+    ///   must list them
+    async fn seed_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pull_request: PullRequest,
+        files: Vec<PullRequestFile>,
+    );
+
+    /// Returns the check runs the provider posted on a pull request.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - The owner of the repository
+    /// * `repo_name` - The name of the repository
+    /// * `pr_number` - The pull request number
+    async fn check_runs(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Vec<CheckRunRecord>;
+}
+
+#[async_trait]
+impl ProviderContractFixture for InMemoryProvider {
+    type Provider = Self;
+
+    fn provider(&self) -> &Self::Provider {
+        self
+    }
+
+    async fn seed_pull_request(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pull_request: PullRequest,
+        files: Vec<PullRequestFile>,
+    ) {
+        self.add_pull_request(repo_owner, repo_name, pull_request, files);
+    }
+
+    async fn check_runs(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+    ) -> Vec<CheckRunRecord> {
+        InMemoryProvider::check_runs(self, repo_owner, repo_name, pr_number)
+    }
+}
+
+/// Returns a pull request for the contract tests.
+fn contract_pull_request(number: u64) -> PullRequest {
+    PullRequest {
+        number,
+        title: format!("feat: contract test {}", number),
+        draft: false,
+        body: None,
+        author: Some(User {
+            id: 2,
+            login: "contract-author".to_string(),
+        }),
+        milestone_number: None,
+        head_sha: format!("{:040x}", number),
+        base_ref: "main".to_string(),
+        head_ref: format!("contract-{}", number),
+    }
+}
+
+/// Returns the names of the labels applied to a pull request, in lower case and
+/// sorted.
+async fn applied_label_names<P: PullRequestProvider + Sync>(
+    provider: &P,
+    pr_number: u64,
+) -> Vec<String> {
+    let mut names: Vec<String> = provider
+        .list_applied_labels(CONTRACT_OWNER, CONTRACT_REPO, pr_number)
+        .await
+        .expect("list_applied_labels should succeed")
+        .into_iter()
+        .map(|l| l.name.to_lowercase())
+        .collect();
+    names.sort();
+    names
+}
+
+/// Asserts that adding and removing labels is idempotent.
+///
+/// # Panics
+///
+/// Panics if the provider breaks the contract.
+pub async fn assert_label_changes_are_idempotent<F: ProviderContractFixture>(fixture: &F) {
+    const PR_NUMBER: u64 = 101;
+    fixture
+        .seed_pull_request(
+            CONTRACT_OWNER,
+            CONTRACT_REPO,
+            contract_pull_request(PR_NUMBER),
+            Vec::new(),
+        )
+        .await;
+    let provider = fixture.provider();
+    let labels = ["size: XS".to_string(), "type: feature".to_string()];
+
+    for _ in 0..2 {
+        provider
+            .add_labels(CONTRACT_OWNER, CONTRACT_REPO, PR_NUMBER, &labels)
+            .await
+            .expect("add_labels should succeed, also for labels already applied");
+    }
+    assert_eq!(
+        applied_label_names(provider, PR_NUMBER).await,
+        vec!["size: xs", "type: feature"],
+        "adding a label twice should apply it once"
+    );
+
+    for _ in 0..2 {
+        provider
+            .remove_label(CONTRACT_OWNER, CONTRACT_REPO, PR_NUMBER, "size: XS")
+            .await
+            .expect("remove_label should succeed, also for labels not applied");
+    }
+    assert_eq!(
+        applied_label_names(provider, PR_NUMBER).await,
+        vec!["type: feature"],
+        "removing a label should leave the other labels applied"
+    );
+}
+
+/// Asserts that added comments are listed and can be deleted by ID.
+///
+/// # Panics
+///
+/// Panics if the provider breaks the contract.
+pub async fn assert_comments_can_be_listed_and_deleted<F: ProviderContractFixture>(fixture: &F) {
+    const PR_NUMBER: u64 = 102;
+    fixture
+        .seed_pull_request(
+            CONTRACT_OWNER,
+            CONTRACT_REPO,
+            contract_pull_request(PR_NUMBER),
+            Vec::new(),
+        )
+        .await;
+    let provider = fixture.provider();
+
+    for body in ["First comment", "Second comment"] {
+        provider
+            .add_comment(CONTRACT_OWNER, CONTRACT_REPO, PR_NUMBER, body)
+            .await
+            .expect("add_comment should succeed");
+    }
+    let comments = provider
+        .list_comments(CONTRACT_OWNER, CONTRACT_REPO, PR_NUMBER)
+        .await
+        .expect("list_comments should succeed");
+    let bodies: Vec<&str> = comments.iter().map(|c| c.body.as_str()).collect();
+    assert_eq!(
+        bodies,
+        vec!["First comment", "Second comment"],
+        "comments should be listed oldest first"
+    );
+    assert_ne!(
+        comments[0].id, comments[1].id,
+        "comments should have distinct IDs"
+    );
+
+    provider
+        .delete_comment(CONTRACT_OWNER, CONTRACT_REPO, comments[0].id)
+        .await
+        .expect("delete_comment should succeed");
+    let remaining = provider
+        .list_comments(CONTRACT_OWNER, CONTRACT_REPO, PR_NUMBER)
+        .await
+        .expect("list_comments should succeed");
+    assert_eq!(
+        remaining.iter().map(|c| c.id).collect::<Vec<_>>(),
+        vec![comments[1].id],
+        "deleting a comment should leave the other comments"
+    );
+
+    assert!(
+        provider
+            .delete_comment(CONTRACT_OWNER, CONTRACT_REPO, comments[0].id)
+            .await
+            .is_err(),
+        "deleting a comment that no longer exists should fail"
+    );
+}
+
+/// Asserts that updating the check status replaces the result of the check run.
+///
+/// # Panics
+///
+/// Panics if the provider breaks the contract.
+pub async fn assert_check_status_updates_replace_the_result<F: ProviderContractFixture>(
+    fixture: &F,
+) {
+    const PR_NUMBER: u64 = 103;
+    fixture
+        .seed_pull_request(
+            CONTRACT_OWNER,
+            CONTRACT_REPO,
+            contract_pull_request(PR_NUMBER),
+            Vec::new(),
+        )
+        .await;
+    let provider = fixture.provider();
+
+    provider
+        .update_pr_check_status(
+            CONTRACT_OWNER,
+            CONTRACT_REPO,
+            PR_NUMBER,
+            "failure",
+            "Merge Warden",
+            "PR title is invalid.",
+            "",
+            &[],
+        )
+        .await
+        .expect("update_pr_check_status should succeed");
+    provider
+        .update_pr_check_status(
+            CONTRACT_OWNER,
+            CONTRACT_REPO,
+            PR_NUMBER,
+            "success",
+            "Merge Warden",
+            "All PR requirements satisfied.",
+            "Details",
+            &[],
+        )
+        .await
+        .expect("update_pr_check_status should succeed");
+
+    assert_eq!(
+        fixture
+            .check_runs(CONTRACT_OWNER, CONTRACT_REPO, PR_NUMBER)
+            .await,
+        vec![CheckRunRecord {
+            name: DEFAULT_CHECK_RUN_NAME.to_string(),
+            conclusion: "success".to_string(),
+            title: "Merge Warden".to_string(),
+            summary: "All PR requirements satisfied.".to_string(),
+            text: "Details".to_string(),
+            actions: Vec::new(),
+        }],
+        "the check run should hold the latest result"
+    );
+}
+
+/// Asserts that every file of a pull request is listed, in order.
+///
+/// # Panics
+///
+/// Panics if the provider breaks the contract.
+pub async fn assert_all_pull_request_files_are_listed<F: ProviderContractFixture>(fixture: &F) {
+    const PR_NUMBER: u64 = 104;
+    let files: Vec<PullRequestFile> = (0..CONTRACT_FILE_COUNT)
+        .map(|i| PullRequestFile {
+            filename: format!("src/file_{:03}.rs", i),
+            additions: 1,
+            deletions: 0,
+            changes: 1,
+            status: "added".to_string(),
+        })
+        .collect();
+    fixture
+        .seed_pull_request(
+            CONTRACT_OWNER,
+            CONTRACT_REPO,
+            contract_pull_request(PR_NUMBER),
+            files.clone(),
+        )
+        .await;
+
+    let listed = fixture
+        .provider()
+        .get_pull_request_files(CONTRACT_OWNER, CONTRACT_REPO, PR_NUMBER)
+        .await
+        .expect("get_pull_request_files should succeed");
+
+    assert_eq!(
+        listed
+            .iter()
+            .map(|f| f.filename.as_str())
+            .collect::<Vec<_>>(),
+        files
+            .iter()
+            .map(|f| f.filename.as_str())
+            .collect::<Vec<_>>(),
+        "every changed file should be listed, in order"
+    );
+}
+
+/// Runs every contract test against the provider of a fixture.
+///
+/// # Panics
+///
+/// Panics at the first contract the provider breaks.
+pub async fn run_provider_contract<F: ProviderContractFixture>(fixture: &F) {
+    assert_label_changes_are_idempotent(fixture).await;
+    assert_comments_can_be_listed_and_deleted(fixture).await;
+    assert_check_status_updates_replace_the_result(fixture).await;
+    assert_all_pull_request_files_are_listed(fixture).await;
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+fn seeded_provider() -> InMemoryProvider {
+    let provider = InMemoryProvider::new();
+    provider.add_pull_request("owner", "repo", contract_pull_request(1), Vec::new());
+    provider
+}
+
+#[tokio::test]
+async fn test_in_memory_provider_passes_the_provider_contract() {
+    run_provider_contract(&InMemoryProvider::new()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_provider_rejects_unknown_pull_requests() {
+    let provider = seeded_provider();
+
+    assert!(matches!(
+        provider.get_pull_request("owner", "repo", 2).await,
+        Err(Error::InvalidResponse)
+    ));
+    assert!(matches!(
+        provider
+            .add_labels("owner", "repo", 2, &["bug".to_string()])
+            .await,
+        Err(Error::InvalidResponse)
+    ));
+    assert!(provider.check_runs("owner", "repo", 2).is_empty());
+}
+
+#[tokio::test]
+async fn test_in_memory_provider_compares_repositories_ignoring_case() {
+    let provider = seeded_provider();
+    provider.add_available_label(
+        "owner",
+        "repo",
+        Label {
+            name: "bug".to_string(),
+            description: None,
+            color: Some("d73a4a".to_string()),
+        },
+    );
+
+    provider
+        .add_comment("Owner", "Repo", 1, "Hello")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        provider.list_comments("owner", "repo", 1).await.unwrap()[0].body,
+        "Hello"
+    );
+    assert_eq!(
+        provider
+            .list_available_labels("OWNER", "repo")
+            .await
+            .unwrap()[0]
+            .name,
+        "bug"
+    );
+}
+
+#[tokio::test]
+async fn test_in_memory_provider_keeps_check_runs_apart_by_name() {
+    let provider = seeded_provider();
+
+    provider
+        .update_pr_check_status("owner", "repo", 1, "failure", "t", "s", "", &[])
+        .await
+        .unwrap();
+    provider
+        .update_named_pr_check_status(
+            "owner",
+            "repo",
+            1,
+            "Renamed",
+            "success",
+            "t",
+            "s",
+            "",
+            &[CheckRunAction::rerun()],
+        )
+        .await
+        .unwrap();
+
+    let check_runs = provider.check_runs("owner", "repo", 1);
+    let names: Vec<&str> = check_runs.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec![DEFAULT_CHECK_RUN_NAME, "Renamed"]);
+    assert_eq!(check_runs[1].actions, vec![CheckRunAction::rerun()]);
+}
+
+#[tokio::test]
+async fn test_in_memory_provider_finds_pull_requests_for_their_head_commit() {
+    let provider = seeded_provider();
+    provider.add_pull_request("owner", "other", contract_pull_request(1), Vec::new());
+
+    let head_sha = contract_pull_request(1).head_sha;
+
+    assert_eq!(
+        provider
+            .find_pull_requests_for_commit("owner", "repo", &head_sha)
+            .await
+            .unwrap(),
+        vec![1]
+    );
+    assert!(provider
+        .find_pull_requests_for_commit("owner", "repo", "unknown")
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_in_memory_provider_updates_only_the_given_pull_request_fields() {
+    let provider = seeded_provider();
+    let original = contract_pull_request(1);
+
+    provider
+        .update_pull_request("owner", "repo", 1, Some("feat: add login"), None)
+        .await
+        .unwrap();
+
+    let pr = provider.get_pull_request("owner", "repo", 1).await.unwrap();
+    assert_eq!(pr.title, "feat: add login");
+    assert_eq!(pr.body, original.body);
+    assert!(matches!(
+        provider
+            .update_pull_request("owner", "repo", 2, None, Some("Fixes #1"))
+            .await,
+        Err(Error::InvalidResponse)
+    ));
+}
@@ -0,0 +1,380 @@
+//! # Webhooks
+//!
+//! Signature verification and payload extraction for GitHub webhook deliveries,
+//! separate from any event processing.
+//!
+//! [`validate_webhook_payload`] takes the raw body and `X-Hub-Signature-256`
+//! header of a delivery and returns the pull request it refers to. This lets a
+//! test harness check webhook routing without running the pull request pipeline.
+//! The signature is checked by the SDK's [`SignatureValidator`], the same check
+//! the `WebhookReceiver` applies to live deliveries.
+
+use github_bot_sdk::webhook::SignatureValidator;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[cfg(test)]
+#[path = "webhook_tests.rs"]
+mod tests;
+
+/// Webhook event types that refer to a single pull request.
+pub const PULL_REQUEST_EVENT_TYPES: [&str; 3] = [
+    "pull_request",
+    "pull_request_review",
+    "pull_request_review_thread",
+];
+
+/// Webhook event type GitHub sends when a merge queue needs checks for a merge group.
+pub const MERGE_GROUP_EVENT_TYPE: &str = "merge_group";
+
+/// The `merge_group` action that asks for checks to be reported on the merge group.
+pub const MERGE_GROUP_CHECKS_REQUESTED: &str = "checks_requested";
+
+/// Webhook event type GitHub sends when a check run changes or one of its buttons is pressed.
+pub const CHECK_RUN_EVENT_TYPE: &str = "check_run";
+
+/// The `check_run` action GitHub sends when a user presses a check run button.
+pub const CHECK_RUN_REQUESTED_ACTION: &str = "requested_action";
+
+/// Prefix of the temporary branches GitHub creates for merge groups.
+const MERGE_QUEUE_BRANCH_PREFIX: &str = "gh-readonly-queue/";
+
+/// The pull request and base branch a merge queue branch was created for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeQueueRef {
+    /// The branch the merge queue merges into, e.g. `main`.
+    pub base_branch: String,
+
+    /// The number of the pull request the merge group ends with.
+    pub pr_number: u64,
+}
+
+/// Parses a merge queue branch name into the pull request it was created for.
+///
+/// GitHub names the branch of a merge group `gh-readonly-queue/<base>/pr-<number>-<sha>`,
+/// where `<number>` is the pull request added last. Earlier pull requests in the group
+/// were validated in merge groups of their own. The `refs/heads/` prefix is optional.
+///
+/// Returns `None` when `git_ref` is not a merge queue branch.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::webhook::{parse_merge_queue_ref, MergeQueueRef};
+///
+/// assert_eq!(
+///     parse_merge_queue_ref("refs/heads/gh-readonly-queue/release/1.x/pr-42-0123abcd"),
+///     Some(MergeQueueRef {
+///         base_branch: "release/1.x".to_string(),
+///         pr_number: 42,
+///     })
+/// );
+/// assert_eq!(parse_merge_queue_ref("refs/heads/feature/pr-42-0123abcd"), None);
+/// ```
+pub fn parse_merge_queue_ref(git_ref: &str) -> Option<MergeQueueRef> {
+    let branch = git_ref.strip_prefix("refs/heads/").unwrap_or(git_ref);
+    let (base_branch, group) = branch
+        .strip_prefix(MERGE_QUEUE_BRANCH_PREFIX)?
+        .rsplit_once('/')?;
+    let (number, _sha) = group.strip_prefix("pr-")?.split_once('-')?;
+    if base_branch.is_empty() {
+        return None;
+    }
+
+    Some(MergeQueueRef {
+        base_branch: base_branch.to_string(),
+        pr_number: number.parse().ok()?,
+    })
+}
+
+/// Errors returned when a webhook delivery cannot be validated.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::webhook::WebhookPayloadError;
+///
+/// let error = WebhookPayloadError::MissingField("pull_request.number");
+/// assert_eq!(error.to_string(), "Webhook payload is missing 'pull_request.number'");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum WebhookPayloadError {
+    /// The delivery carried no `X-Hub-Signature-256` header.
+    #[error("Missing webhook signature")]
+    MissingSignature,
+
+    /// The signature is malformed, was not produced with the webhook secret, or could
+    /// not be checked.
+    #[error("Webhook signature is not valid for the payload")]
+    InvalidSignature,
+
+    /// The body is not a JSON document.
+    ///
+    /// Parameter: the JSON parser's error message.
+    #[error("Webhook payload is not valid JSON: {0}")]
+    MalformedPayload(String),
+
+    /// The event type does not refer to a pull request, e.g. `status` or `push`.
+    ///
+    /// Parameter: the event type from the `X-GitHub-Event` header.
+    #[error("Event type '{0}' does not refer to a pull request")]
+    UnsupportedEventType(String),
+
+    /// A field needed to identify the pull request is absent or has the wrong type.
+    ///
+    /// Parameter: the dotted path of the field.
+    #[error("Webhook payload is missing '{0}'")]
+    MissingField(&'static str),
+}
+
+/// The pull request a validated webhook delivery refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookPullRequestEvent {
+    /// The event type from the `X-GitHub-Event` header, e.g. `pull_request`.
+    pub event_type: String,
+
+    /// The `action` field of the payload, e.g. `opened`. Review and review-thread
+    /// actions such as `submitted` are kept in [`PullRequestAction::Unknown`].
+    pub action: PullRequestAction,
+
+    /// The login of the repository owner.
+    pub owner: String,
+
+    /// The repository name.
+    pub repo: String,
+
+    /// The pull request number.
+    pub pr_number: u64,
+}
+
+impl WebhookPullRequestEvent {
+    /// Returns `true` when Merge Warden re-evaluates the pull request for this event.
+    ///
+    /// Review and review-thread events always do, because they may change the approval
+    /// state or the unresolved thread count. `pull_request` events only do for actions
+    /// that change the PR's title, body, commits or draft state; see
+    /// [`PullRequestAction::requires_processing`].
+    pub fn requires_processing(&self) -> bool {
+        self.event_type != "pull_request" || self.action.requires_processing()
+    }
+}
+
+/// The `action` of a `pull_request` webhook event.
+///
+/// Actions Merge Warden does not distinguish are kept in [`PullRequestAction::Unknown`],
+/// so a payload with a new action still deserializes.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::webhook::PullRequestAction;
+///
+/// let action: PullRequestAction = serde_json::from_str(r#""synchronize""#).unwrap();
+/// assert_eq!(action, PullRequestAction::Synchronize);
+/// assert!(action.requires_processing());
+///
+/// let action = PullRequestAction::from("auto_merge_enabled");
+/// assert_eq!(action, PullRequestAction::Unknown("auto_merge_enabled".to_string()));
+/// assert_eq!(action.as_str(), "auto_merge_enabled");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum PullRequestAction {
+    /// The pull request was opened.
+    Opened,
+    /// The title or body was edited, or the base branch changed.
+    Edited,
+    /// A closed pull request was reopened.
+    Reopened,
+    /// New commits were pushed to the head branch.
+    Synchronize,
+    /// A draft pull request was marked ready for review.
+    ReadyForReview,
+    /// The pull request was converted to a draft.
+    ConvertedToDraft,
+    /// The conversation was unlocked.
+    Unlocked,
+    /// The conversation was locked.
+    Locked,
+    /// The pull request was closed or merged.
+    Closed,
+    /// A label was added.
+    Labeled,
+    /// A label was removed.
+    Unlabeled,
+    /// Any other action.
+    ///
+    /// Parameter: the action as sent by the platform.
+    Unknown(String),
+}
+
+impl PullRequestAction {
+    /// Returns the action as it appears in the webhook payload.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PullRequestAction::Opened => "opened",
+            PullRequestAction::Edited => "edited",
+            PullRequestAction::Reopened => "reopened",
+            PullRequestAction::Synchronize => "synchronize",
+            PullRequestAction::ReadyForReview => "ready_for_review",
+            PullRequestAction::ConvertedToDraft => "converted_to_draft",
+            PullRequestAction::Unlocked => "unlocked",
+            PullRequestAction::Locked => "locked",
+            PullRequestAction::Closed => "closed",
+            PullRequestAction::Labeled => "labeled",
+            PullRequestAction::Unlabeled => "unlabeled",
+            PullRequestAction::Unknown(action) => action,
+        }
+    }
+
+    /// Returns `true` when the action changes the PR's title, body, commits, labels or
+    /// draft state, so Merge Warden re-evaluates the PR.
+    ///
+    /// Label changes only affect the checks that read the labels, so callers that know
+    /// the repository configuration may skip the actions for which
+    /// [`PullRequestAction::changes_labels`] is `true` when no check reads them.
+    pub fn requires_processing(&self) -> bool {
+        matches!(
+            self,
+            PullRequestAction::Opened
+                | PullRequestAction::Edited
+                | PullRequestAction::ReadyForReview
+                | PullRequestAction::ConvertedToDraft
+                | PullRequestAction::Reopened
+                | PullRequestAction::Unlocked
+                | PullRequestAction::Synchronize
+                | PullRequestAction::Labeled
+                | PullRequestAction::Unlabeled
+        )
+    }
+
+    /// Returns `true` when the action adds or removes a label.
+    pub fn changes_labels(&self) -> bool {
+        matches!(
+            self,
+            PullRequestAction::Labeled | PullRequestAction::Unlabeled
+        )
+    }
+}
+
+impl From<&str> for PullRequestAction {
+    fn from(action: &str) -> Self {
+        match action {
+            "opened" => PullRequestAction::Opened,
+            "edited" => PullRequestAction::Edited,
+            "reopened" => PullRequestAction::Reopened,
+            "synchronize" => PullRequestAction::Synchronize,
+            "ready_for_review" => PullRequestAction::ReadyForReview,
+            "converted_to_draft" => PullRequestAction::ConvertedToDraft,
+            "unlocked" => PullRequestAction::Unlocked,
+            "locked" => PullRequestAction::Locked,
+            "closed" => PullRequestAction::Closed,
+            "labeled" => PullRequestAction::Labeled,
+            "unlabeled" => PullRequestAction::Unlabeled,
+            other => PullRequestAction::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for PullRequestAction {
+    fn from(action: String) -> Self {
+        PullRequestAction::from(action.as_str())
+    }
+}
+
+/// Reads the `action` field of a webhook payload.
+///
+/// # Errors
+///
+/// Returns [`WebhookPayloadError::MissingField`] when the payload has no string
+/// `action`.
+///
+/// # Examples
+///
+/// ```
+/// use merge_warden_developer_platforms::webhook::{parse_pull_request_action, PullRequestAction};
+/// use serde_json::json;
+///
+/// let action = parse_pull_request_action(&json!({ "action": "synchronize" })).unwrap();
+/// assert_eq!(action, PullRequestAction::Synchronize);
+/// assert!(parse_pull_request_action(&json!({})).is_err());
+/// ```
+pub fn parse_pull_request_action(
+    payload: &Value,
+) -> Result<PullRequestAction, WebhookPayloadError> {
+    PullRequestAction::deserialize(&payload["action"])
+        .map_err(|_| WebhookPayloadError::MissingField("action"))
+}
+
+/// Verifies a webhook delivery and extracts the pull request it refers to.
+///
+/// Nothing is sent to GitHub and no pull request processing takes place.
+///
+/// # Arguments
+///
+/// * `validator` - The SDK validator holding the webhook secret of the GitHub App
+/// * `event_type` - The value of the `X-GitHub-Event` header
+/// * `body` - The raw request body, exactly as received
+/// * `signature` - The value of the `X-Hub-Signature-256` header, if present
+///
+/// # Errors
+///
+/// The signature is checked first, so an unsigned or wrongly signed delivery is
+/// rejected before its body is parsed. A signature that cannot be checked, for
+/// example because the secret is unavailable, is treated as invalid. See
+/// [`WebhookPayloadError`] for the cases.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use github_bot_sdk::webhook::SignatureValidator;
+/// use merge_warden_developer_platforms::webhook::{
+///     validate_webhook_payload, WebhookPayloadError,
+/// };
+///
+/// # async fn example(validator: SignatureValidator) {
+/// let result = validate_webhook_payload(&validator, "pull_request", b"{}", None).await;
+/// assert_eq!(result, Err(WebhookPayloadError::MissingSignature));
+/// # }
+/// ```
+pub async fn validate_webhook_payload(
+    validator: &SignatureValidator,
+    event_type: &str,
+    body: &[u8],
+    signature: Option<&str>,
+) -> Result<WebhookPullRequestEvent, WebhookPayloadError> {
+    let signature = signature.ok_or(WebhookPayloadError::MissingSignature)?;
+    // The SDK reports a malformed header as an error and a mismatched digest as `false`
+    if !matches!(validator.validate(body, signature).await, Ok(true)) {
+        return Err(WebhookPayloadError::InvalidSignature);
+    }
+
+    if !PULL_REQUEST_EVENT_TYPES.contains(&event_type) {
+        return Err(WebhookPayloadError::UnsupportedEventType(
+            event_type.to_string(),
+        ));
+    }
+
+    let payload: Value = serde_json::from_slice(body)
+        .map_err(|e| WebhookPayloadError::MalformedPayload(e.to_string()))?;
+
+    let action = parse_pull_request_action(&payload)?;
+    let owner = payload["repository"]["owner"]["login"]
+        .as_str()
+        .ok_or(WebhookPayloadError::MissingField("repository.owner.login"))?;
+    let repo = payload["repository"]["name"]
+        .as_str()
+        .filter(|name| !name.is_empty())
+        .ok_or(WebhookPayloadError::MissingField("repository.name"))?;
+    let pr_number = payload["pull_request"]["number"]
+        .as_u64()
+        .ok_or(WebhookPayloadError::MissingField("pull_request.number"))?;
+
+    Ok(WebhookPullRequestEvent {
+        event_type: event_type.to_string(),
+        action,
+        owner: owner.to_string(),
+        repo: repo.to_string(),
+        pr_number,
+    })
+}
@@ -0,0 +1,300 @@
+use super::*;
+use async_trait::async_trait;
+use github_bot_sdk::{
+    auth::{GitHubAppId, PrivateKey, SecretProvider},
+    error::SecretError,
+};
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+use std::sync::Arc;
+
+const SECRET: &str = "test-webhook-secret";
+
+/// [`SecretProvider`] that only knows the webhook secret.
+struct TestSecrets;
+
+#[async_trait]
+impl SecretProvider for TestSecrets {
+    async fn get_private_key(&self) -> Result<PrivateKey, SecretError> {
+        Err(SecretError::NotFound {
+            key: "private_key".to_string(),
+        })
+    }
+
+    async fn get_app_id(&self) -> Result<GitHubAppId, SecretError> {
+        Err(SecretError::NotFound {
+            key: "app_id".to_string(),
+        })
+    }
+
+    async fn get_webhook_secret(&self) -> Result<String, SecretError> {
+        Ok(SECRET.to_string())
+    }
+
+    fn cache_duration(&self) -> chrono::Duration {
+        chrono::Duration::hours(1)
+    }
+}
+
+fn validator() -> SignatureValidator {
+    SignatureValidator::new(Arc::new(TestSecrets))
+}
+
+/// Signs `body` the way GitHub does for the `X-Hub-Signature-256` header.
+fn sign(body: &[u8], secret: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+fn pull_request_body(action: &str) -> Vec<u8> {
+    serde_json::to_vec(&json!({
+        "action": action,
+        "pull_request": { "number": 42 },
+        "repository": { "name": "repo", "owner": { "login": "owner" } },
+        "installation": { "id": 1 }
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_validate_webhook_payload_extracts_pull_request() {
+    let body = pull_request_body("opened");
+    let signature = sign(&body, SECRET);
+
+    let event = validate_webhook_payload(&validator(), "pull_request", &body, Some(&signature))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        event,
+        WebhookPullRequestEvent {
+            event_type: "pull_request".to_string(),
+            action: PullRequestAction::Opened,
+            owner: "owner".to_string(),
+            repo: "repo".to_string(),
+            pr_number: 42,
+        }
+    );
+    assert!(event.requires_processing());
+}
+
+#[tokio::test]
+async fn test_validate_webhook_payload_rejects_missing_signature() {
+    let body = pull_request_body("opened");
+
+    let result = validate_webhook_payload(&validator(), "pull_request", &body, None).await;
+
+    assert_eq!(result, Err(WebhookPayloadError::MissingSignature));
+}
+
+#[tokio::test]
+async fn test_validate_webhook_payload_rejects_signature_from_other_secret() {
+    let body = pull_request_body("opened");
+    let signature = sign(&body, "other-secret");
+
+    let result =
+        validate_webhook_payload(&validator(), "pull_request", &body, Some(&signature)).await;
+
+    assert_eq!(result, Err(WebhookPayloadError::InvalidSignature));
+}
+
+#[tokio::test]
+async fn test_validate_webhook_payload_rejects_tampered_body() {
+    let signature = sign(&pull_request_body("opened"), SECRET);
+    let body = pull_request_body("closed");
+
+    let result =
+        validate_webhook_payload(&validator(), "pull_request", &body, Some(&signature)).await;
+
+    assert_eq!(result, Err(WebhookPayloadError::InvalidSignature));
+}
+
+#[tokio::test]
+async fn test_validate_webhook_payload_rejects_malformed_signature() {
+    let body = pull_request_body("opened");
+    let digest = sign(&body, SECRET);
+
+    for signature in [
+        digest.trim_start_matches("sha256="),
+        "sha256=not-hex",
+        "sha1=abcdef",
+        "",
+    ] {
+        assert_eq!(
+            validate_webhook_payload(&validator(), "pull_request", &body, Some(signature)).await,
+            Err(WebhookPayloadError::InvalidSignature),
+            "signature {signature:?} should be rejected"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_validate_webhook_payload_rejects_malformed_json() {
+    let body = b"not json";
+    let signature = sign(body, SECRET);
+
+    let result =
+        validate_webhook_payload(&validator(), "pull_request", body, Some(&signature)).await;
+
+    assert!(matches!(
+        result,
+        Err(WebhookPayloadError::MalformedPayload(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_validate_webhook_payload_rejects_non_pull_request_event() {
+    let body = serde_json::to_vec(&json!({ "state": "success" })).unwrap();
+    let signature = sign(&body, SECRET);
+
+    let result = validate_webhook_payload(&validator(), "status", &body, Some(&signature)).await;
+
+    assert_eq!(
+        result,
+        Err(WebhookPayloadError::UnsupportedEventType(
+            "status".to_string()
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_validate_webhook_payload_reports_missing_fields() {
+    let cases = [
+        (
+            json!({ "pull_request": { "number": 1 }, "repository": { "name": "r", "owner": { "login": "o" } } }),
+            "action",
+        ),
+        (
+            json!({ "action": "opened", "pull_request": { "number": 1 }, "repository": { "name": "r" } }),
+            "repository.owner.login",
+        ),
+        (
+            json!({ "action": "opened", "pull_request": { "number": 1 }, "repository": { "name": "", "owner": { "login": "o" } } }),
+            "repository.name",
+        ),
+        (
+            json!({ "action": "opened", "pull_request": { "number": "1" }, "repository": { "name": "r", "owner": { "login": "o" } } }),
+            "pull_request.number",
+        ),
+    ];
+
+    for (payload, field) in cases {
+        let body = serde_json::to_vec(&payload).unwrap();
+        let signature = sign(&body, SECRET);
+
+        let result =
+            validate_webhook_payload(&validator(), "pull_request", &body, Some(&signature)).await;
+
+        assert_eq!(result, Err(WebhookPayloadError::MissingField(field)));
+    }
+}
+
+/// Builds the event GitHub sends for `event_type` with `action` on owner/repo#1.
+fn make_event(event_type: &str, action: &str) -> WebhookPullRequestEvent {
+    WebhookPullRequestEvent {
+        event_type: event_type.to_string(),
+        action: PullRequestAction::from(action),
+        owner: "owner".to_string(),
+        repo: "repo".to_string(),
+        pr_number: 1,
+    }
+}
+
+#[test]
+fn test_event_requires_processing() {
+    for action in [
+        "opened",
+        "edited",
+        "ready_for_review",
+        "converted_to_draft",
+        "reopened",
+        "unlocked",
+        "synchronize",
+        "labeled",
+        "unlabeled",
+    ] {
+        assert!(
+            make_event("pull_request", action).requires_processing(),
+            "{action}"
+        );
+    }
+
+    for action in ["closed", "assigned", "locked"] {
+        assert!(
+            !make_event("pull_request", action).requires_processing(),
+            "{action}"
+        );
+    }
+
+    assert!(make_event("pull_request_review", "submitted").requires_processing());
+    assert!(make_event("pull_request_review_thread", "resolved").requires_processing());
+}
+
+#[test]
+fn test_pull_request_action_round_trips_known_and_unknown_actions() {
+    for action in [
+        "opened",
+        "edited",
+        "reopened",
+        "synchronize",
+        "ready_for_review",
+        "converted_to_draft",
+        "unlocked",
+        "locked",
+        "closed",
+        "labeled",
+        "unlabeled",
+    ] {
+        let parsed = PullRequestAction::from(action);
+
+        assert!(!matches!(parsed, PullRequestAction::Unknown(_)), "{action}");
+        assert_eq!(parsed.as_str(), action);
+    }
+
+    let parsed: PullRequestAction = serde_json::from_value(json!("enqueued")).unwrap();
+    assert_eq!(parsed, PullRequestAction::Unknown("enqueued".to_string()));
+    assert_eq!(parsed.as_str(), "enqueued");
+    assert!(!parsed.requires_processing());
+}
+
+#[test]
+fn test_pull_request_action_changes_labels_only_for_label_actions() {
+    assert!(PullRequestAction::Labeled.changes_labels());
+    assert!(PullRequestAction::Unlabeled.changes_labels());
+    assert!(!PullRequestAction::Synchronize.changes_labels());
+    assert!(!PullRequestAction::Unknown("assigned".to_string()).changes_labels());
+}
+
+#[test]
+fn test_parse_merge_queue_ref_extracts_pull_request_and_base() {
+    assert_eq!(
+        parse_merge_queue_ref("refs/heads/gh-readonly-queue/main/pr-123-6f1d2c3b"),
+        Some(MergeQueueRef {
+            base_branch: "main".to_string(),
+            pr_number: 123,
+        })
+    );
+    assert_eq!(
+        parse_merge_queue_ref("gh-readonly-queue/release/2.0/pr-7-abc"),
+        Some(MergeQueueRef {
+            base_branch: "release/2.0".to_string(),
+            pr_number: 7,
+        })
+    );
+}
+
+#[test]
+fn test_parse_merge_queue_ref_rejects_other_branches() {
+    for git_ref in [
+        "refs/heads/main",
+        "refs/heads/feature/pr-123-abc",
+        "refs/heads/gh-readonly-queue/main",
+        "refs/heads/gh-readonly-queue/main/pr-abc-123",
+        "refs/heads/gh-readonly-queue/main/123-abc",
+        "refs/heads/gh-readonly-queue//pr-1-abc",
+    ] {
+        assert_eq!(parse_merge_queue_ref(git_ref), None, "{git_ref}");
+    }
+}
@@ -464,11 +464,7 @@ impl TestBotInstance {
             };
 
             // Only process relevant PR actions
-            let action = payload.action.as_str();
-            if !matches!(
-                action,
-                "opened" | "edited" | "ready_for_review" | "reopened" | "unlocked" | "synchronize"
-            ) {
+            if !payload.action.requires_processing() {
                 return axum::http::StatusCode::OK;
             }
 
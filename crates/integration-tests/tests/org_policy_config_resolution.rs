@@ -337,7 +337,8 @@ required = false
         "Org default work_item.required must apply when repo omits it"
     );
     assert_eq!(
-        cfg.work_item_reference_pattern, "JIRA-[0-9]+",
+        cfg.work_item_reference_pattern.patterns(),
+        ["JIRA-[0-9]+"],
         "Org default work_item pattern must apply when repo omits it"
     );
 }
@@ -511,7 +512,8 @@ pattern = "GH-[0-9]+"
     // Org enforced work_item beats repo.
     assert!(cfg.enforce_work_item_references);
     assert_eq!(
-        cfg.work_item_reference_pattern, "ORG-ENFORCED-[0-9]+",
+        cfg.work_item_reference_pattern.patterns(),
+        ["ORG-ENFORCED-[0-9]+"],
         "Org enforced work_item pattern must beat repo pattern"
     );
 }
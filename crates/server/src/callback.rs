@@ -0,0 +1,143 @@
+// See docs/spec/interfaces/server-config.md — ResultCallback
+
+use std::time::Duration;
+
+use merge_warden_core::CheckResult;
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use crate::errors::ServerError;
+
+#[cfg(test)]
+#[path = "callback_tests.rs"]
+mod tests;
+
+/// Number of delivery attempts before a report is given up.
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry. Each further retry waits twice as long.
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Time allowed for a single delivery attempt, including reading the response.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The JSON body posted to the result callback URL.
+///
+/// See docs/spec/interfaces/server-config.md — `CheckResultReport`
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResultReport<'a> {
+    /// Owner of the repository the pull request belongs to.
+    pub repository_owner: &'a str,
+    /// Name of the repository the pull request belongs to.
+    pub repository: &'a str,
+    /// Number of the pull request.
+    pub pull_request: u64,
+    /// The result of processing the pull request.
+    pub result: &'a CheckResult,
+}
+
+/// Posts [`CheckResultReport`]s to the URL configured in
+/// `ApplicationDefaults::result_callback_url`.
+///
+/// Requests that fail to send, or that the service answers with `429` or a `5xx`
+/// status, are retried with exponential backoff. Any other status is final. Each
+/// attempt times out after 10 seconds.
+///
+/// See docs/spec/interfaces/server-config.md — `ResultCallback`
+#[derive(Debug, Clone)]
+pub struct ResultCallback {
+    url: String,
+    client: reqwest::Client,
+    max_attempts: u32,
+    initial_backoff: Duration,
+}
+
+impl ResultCallback {
+    /// Creates a callback posting to `url`, with three attempts of at most 10
+    /// seconds each, starting at a 500 ms backoff.
+    ///
+    /// # Errors
+    /// Returns [`ServerError::ResultCallbackError`] when the HTTP client cannot be
+    /// built.
+    pub fn new(url: impl Into<String>) -> Result<Self, ServerError> {
+        let client = reqwest::Client::builder()
+            .timeout(DEFAULT_REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| {
+                ServerError::ResultCallbackError(format!(
+                    "Failed to build the result callback HTTP client: {}",
+                    e
+                ))
+            })?;
+
+        Ok(ResultCallback {
+            url: url.into(),
+            client,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+        })
+    }
+
+    /// Creates the callback for `ApplicationDefaults::result_callback_url`, or
+    /// returns `None` when the URL is not set or blank.
+    ///
+    /// # Errors
+    /// Returns [`ServerError::ResultCallbackError`] when the HTTP client cannot be
+    /// built.
+    pub fn from_url(url: Option<&str>) -> Result<Option<Self>, ServerError> {
+        url.map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(Self::new)
+            .transpose()
+    }
+
+    /// Posts `report` to the callback URL, retrying transient failures.
+    ///
+    /// # Errors
+    /// Returns [`ServerError::ResultCallbackError`] when the last attempt fails to
+    /// send or is not answered with a success status.
+    pub async fn send(&self, report: &CheckResultReport<'_>) -> Result<(), ServerError> {
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            let (message, retryable) = match self.client.post(&self.url).json(report).send().await {
+                Ok(response) if response.status().is_success() => {
+                    debug!(
+                        url = self.url.as_str(),
+                        pull_request = report.pull_request,
+                        attempt,
+                        "Posted check result to the result callback"
+                    );
+                    return Ok(());
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    (
+                        format!("the service responded with status {}", status),
+                        status.is_server_error()
+                            || status == reqwest::StatusCode::TOO_MANY_REQUESTS,
+                    )
+                }
+                Err(e) => (format!("the request failed: {}", e), true),
+            };
+
+            if !retryable || attempt >= self.max_attempts {
+                return Err(ServerError::ResultCallbackError(format!(
+                    "Failed to post the check result to {} after {} attempt(s): {}",
+                    self.url, attempt, message
+                )));
+            }
+
+            warn!(
+                url = self.url.as_str(),
+                pull_request = report.pull_request,
+                attempt,
+                error = message.as_str(),
+                "Failed to post check result to the result callback; retrying"
+            );
+            tokio::time::sleep(backoff).await;
+            backoff = backoff.saturating_mul(2);
+            attempt += 1;
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use merge_warden_core::CheckResult;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::{CheckResultReport, ResultCallback};
+use crate::errors::ServerError;
+
+fn make_result() -> CheckResult {
+    CheckResult {
+        work_item_referenced: false,
+        labels: vec!["feature".to_string()],
+        check_summary: "PR is missing a work item reference.".to_string(),
+        ..Default::default()
+    }
+}
+
+fn make_callback(server: &MockServer) -> ResultCallback {
+    ResultCallback {
+        initial_backoff: Duration::from_millis(1),
+        ..ResultCallback::new(format!("{}/results", server.uri())).unwrap()
+    }
+}
+
+#[tokio::test]
+async fn send_posts_the_report_as_json() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/results"))
+        .and(body_partial_json(serde_json::json!({
+            "repository_owner": "owner",
+            "repository": "repo",
+            "pull_request": 42,
+            "result": {
+                "title_valid": true,
+                "work_item_referenced": false,
+                "labels": ["feature"],
+                "check_summary": "PR is missing a work item reference."
+            }
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = make_result();
+    let report = CheckResultReport {
+        repository_owner: "owner",
+        repository: "repo",
+        pull_request: 42,
+        result: &result,
+    };
+
+    make_callback(&server).send(&report).await.unwrap();
+}
+
+#[tokio::test]
+async fn send_retries_server_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = make_result();
+    let report = CheckResultReport {
+        repository_owner: "owner",
+        repository: "repo",
+        pull_request: 42,
+        result: &result,
+    };
+
+    make_callback(&server).send(&report).await.unwrap();
+}
+
+#[tokio::test]
+async fn send_gives_up_after_the_last_attempt() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let result = make_result();
+    let report = CheckResultReport {
+        repository_owner: "owner",
+        repository: "repo",
+        pull_request: 42,
+        result: &result,
+    };
+
+    let error = make_callback(&server).send(&report).await.unwrap_err();
+
+    assert!(matches!(error, ServerError::ResultCallbackError(_)));
+    assert!(error.to_string().contains("after 3 attempt(s)"));
+}
+
+#[tokio::test]
+async fn send_does_not_retry_client_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(400))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = make_result();
+    let report = CheckResultReport {
+        repository_owner: "owner",
+        repository: "repo",
+        pull_request: 42,
+        result: &result,
+    };
+
+    let error = make_callback(&server).send(&report).await.unwrap_err();
+
+    assert!(error.to_string().contains("status 400"));
+}
+
+#[tokio::test]
+async fn send_times_out_a_hanging_service() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let result = make_result();
+    let report = CheckResultReport {
+        repository_owner: "owner",
+        repository: "repo",
+        pull_request: 42,
+        result: &result,
+    };
+    let callback = ResultCallback {
+        client: reqwest::Client::builder()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap(),
+        ..make_callback(&server)
+    };
+
+    let error = callback.send(&report).await.unwrap_err();
+
+    assert!(matches!(error, ServerError::ResultCallbackError(_)));
+    assert!(error.to_string().contains("after 3 attempt(s)"));
+}
+
+#[test]
+fn from_url_creates_a_callback_only_for_a_non_blank_url() {
+    let callback = ResultCallback::from_url(Some(" https://dashboard.example.com/results "))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(callback.url, "https://dashboard.example.com/results");
+    assert!(ResultCallback::from_url(Some("  ")).unwrap().is_none());
+    assert!(ResultCallback::from_url(None).unwrap().is_none());
+}
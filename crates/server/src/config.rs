@@ -1,6 +1,6 @@
 // See docs/spec/interfaces/server-config.md for the full contract.
 
-use std::{fmt, path::PathBuf};
+use std::{fmt, path::PathBuf, sync::Arc, time::Duration};
 
 #[cfg(test)]
 #[path = "config_tests.rs"]
@@ -9,9 +9,11 @@ mod tests;
 use merge_warden_core::{
     config::{validate_repository_scope_patterns, ApplicationDefaults},
     errors::ConfigLoadError,
+    state::{InMemoryStateStore, StateStore},
 };
 
 use crate::errors::ServerError;
+use crate::keyvault::{KeyVaultSecretProvider, ManagedIdentityCredential};
 
 // ---------------------------------------------------------------------------
 // SecretString
@@ -70,6 +72,115 @@ pub struct ServerSecrets {
     pub github_webhook_secret: Option<SecretString>,
 }
 
+// ---------------------------------------------------------------------------
+// SecretProvider
+// ---------------------------------------------------------------------------
+
+/// Name of the secret holding the numeric GitHub App ID.
+pub const GITHUB_APP_ID_SECRET: &str = "MERGE_WARDEN_GITHUB_APP_ID";
+
+/// Name of the secret holding the PEM-encoded GitHub App private key.
+pub const GITHUB_APP_PRIVATE_KEY_SECRET: &str = "MERGE_WARDEN_GITHUB_APP_PRIVATE_KEY";
+
+/// Name of the secret holding the webhook signing secret.
+pub const GITHUB_WEBHOOK_SECRET: &str = "GITHUB_WEBHOOK_SECRET";
+
+/// Directory read by [`FileSecretProvider`] when `MERGE_WARDEN_SECRETS_DIR` is not set.
+pub const DEFAULT_SECRETS_DIR: &str = "/run/secrets";
+
+/// A source of the named secrets read by [`load_secrets`].
+///
+/// Selected once at startup from `MERGE_WARDEN_SECRET_SOURCE` by
+/// [`secret_provider_from_env`].
+///
+/// See docs/spec/interfaces/server-config.md — `SecretProvider`
+#[async_trait::async_trait]
+pub trait SecretProvider: fmt::Debug + Send + Sync {
+    /// Returns the secret stored under `name`, or `None` when it is not set.
+    ///
+    /// # Errors
+    /// Returns a [`ServerError`] when the secret exists but cannot be read.
+    async fn get_secret(&self, name: &str) -> Result<Option<SecretString>, ServerError>;
+
+    /// Returns the error reported when the required secret `name` is not set.
+    fn missing_secret_error(&self, name: &str) -> ServerError;
+
+    /// Describes where the secrets are read from, for the health report. Never
+    /// contains secret values.
+    fn describe(&self) -> String;
+}
+
+/// Reads each secret from the environment variable of the same name.
+///
+/// This is the default provider. Container platforms use it to inject secrets
+/// from their own secret stores, e.g. Azure Container Apps Key Vault references.
+#[derive(Debug, Default)]
+pub struct EnvSecretProvider;
+
+#[async_trait::async_trait]
+impl SecretProvider for EnvSecretProvider {
+    async fn get_secret(&self, name: &str) -> Result<Option<SecretString>, ServerError> {
+        Ok(std::env::var(name).ok().map(SecretString::new))
+    }
+
+    fn missing_secret_error(&self, name: &str) -> ServerError {
+        ServerError::MissingEnvVar(name.to_string())
+    }
+
+    fn describe(&self) -> String {
+        "loaded from environment".to_string()
+    }
+}
+
+/// Reads each secret from the file of the same name in a directory, such as
+/// mounted Docker or Kubernetes secrets.
+///
+/// A single trailing line ending is removed from the file contents.
+#[derive(Debug)]
+pub struct FileSecretProvider {
+    /// Directory holding one file per secret.
+    directory: PathBuf,
+}
+
+impl FileSecretProvider {
+    /// Creates a provider that reads secrets from files in `directory`.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretProvider for FileSecretProvider {
+    async fn get_secret(&self, name: &str) -> Result<Option<SecretString>, ServerError> {
+        let path = self.directory.join(name);
+        match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                let value = content
+                    .strip_suffix('\n')
+                    .map(|v| v.strip_suffix('\r').unwrap_or(v))
+                    .unwrap_or(&content);
+                Ok(Some(SecretString::new(value.to_string())))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(ServerError::ConfigError(format!(
+                "Failed to read secret file '{}': {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    fn missing_secret_error(&self, name: &str) -> ServerError {
+        ServerError::MissingSecretFile(self.directory.join(name))
+    }
+
+    fn describe(&self) -> String {
+        format!("loaded from files in {}", self.directory.display())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ReceiverMode
 // ---------------------------------------------------------------------------
@@ -92,6 +203,34 @@ pub enum ReceiverMode {
     Queue,
 }
 
+// ---------------------------------------------------------------------------
+// StateStoreBackend
+// ---------------------------------------------------------------------------
+
+/// Where per-pull-request state is kept between events.
+///
+/// Selected once at startup from `MERGE_WARDEN_STATE_STORE`. Values are
+/// case-insensitive. Every feature that remembers state between events uses the
+/// same backend.
+///
+/// See docs/spec/interfaces/server-config.md — `StateStoreBackend`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StateStoreBackend {
+    /// State is kept in the server process. It is lost on restart and not shared
+    /// between replicas.
+    #[default]
+    Memory,
+}
+
+impl StateStoreBackend {
+    /// Creates the store for this backend.
+    pub fn create_store(self) -> Arc<dyn StateStore> {
+        match self {
+            StateStoreBackend::Memory => Arc::new(InMemoryStateStore::new()),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // QueueServerConfig
 // ---------------------------------------------------------------------------
@@ -170,6 +309,84 @@ impl QueueServerConfig {
     }
 }
 
+// ---------------------------------------------------------------------------
+// ProcessingThrottleConfig
+// ---------------------------------------------------------------------------
+
+/// Settings that smooth out bursts of pull request events, such as the
+/// `synchronize` events sent for every open PR after a base branch is rebased.
+///
+/// All settings are off by default.
+///
+/// See docs/spec/interfaces/server-config.md — `ProcessingThrottleConfig`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessingThrottleConfig {
+    /// Upper bound of the random delay before a pull request event is processed.
+    /// From `MERGE_WARDEN_PROCESSING_JITTER_MS`. Default: no delay.
+    pub max_jitter: Duration,
+    /// Maximum number of pull request events processed at the same time, across
+    /// all processor tasks. From `MERGE_WARDEN_MAX_CONCURRENT_PROCESSING`.
+    /// `None` (the default) applies no limit beyond the number of processor tasks.
+    pub max_concurrent: Option<usize>,
+    /// Maximum number of pull request events of one repository processed at the same
+    /// time, so that a busy repository cannot take every slot of `max_concurrent`.
+    /// From `MERGE_WARDEN_MAX_CONCURRENT_PER_REPOSITORY`. `None` (the default)
+    /// applies no per-repository limit.
+    pub max_concurrent_per_repository: Option<usize>,
+}
+
+impl ProcessingThrottleConfig {
+    /// Reads the throttle settings from the environment.
+    ///
+    /// # Errors
+    /// Returns [`ServerError::InvalidEnvVar`] when a variable is not a number, or when
+    /// `MERGE_WARDEN_MAX_CONCURRENT_PROCESSING` or
+    /// `MERGE_WARDEN_MAX_CONCURRENT_PER_REPOSITORY` is `0`.
+    pub fn from_env() -> Result<Self, ServerError> {
+        let max_jitter = match std::env::var("MERGE_WARDEN_PROCESSING_JITTER_MS") {
+            Ok(v) => {
+                Duration::from_millis(v.parse::<u64>().map_err(|e| ServerError::InvalidEnvVar {
+                    name: "MERGE_WARDEN_PROCESSING_JITTER_MS".to_string(),
+                    message: format!("Expected a number of milliseconds: {}", e),
+                })?)
+            }
+            Err(_) => Duration::ZERO,
+        };
+
+        Ok(Self {
+            max_jitter,
+            max_concurrent: Self::limit_from_env("MERGE_WARDEN_MAX_CONCURRENT_PROCESSING")?,
+            max_concurrent_per_repository: Self::limit_from_env(
+                "MERGE_WARDEN_MAX_CONCURRENT_PER_REPOSITORY",
+            )?,
+        })
+    }
+
+    /// Reads a concurrency limit from the environment variable `name`.
+    ///
+    /// Returns `None` when the variable is not set.
+    ///
+    /// # Errors
+    /// Returns [`ServerError::InvalidEnvVar`] when the value is not a positive integer.
+    fn limit_from_env(name: &str) -> Result<Option<usize>, ServerError> {
+        let Ok(v) = std::env::var(name) else {
+            return Ok(None);
+        };
+
+        let limit = v.parse::<usize>().map_err(|e| ServerError::InvalidEnvVar {
+            name: name.to_string(),
+            message: format!("Expected a positive integer: {}", e),
+        })?;
+        if limit == 0 {
+            return Err(ServerError::InvalidEnvVar {
+                name: name.to_string(),
+                message: "Must be at least 1; got 0".to_string(),
+            });
+        }
+        Ok(Some(limit))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ServerConfig
 // ---------------------------------------------------------------------------
@@ -188,39 +405,114 @@ pub struct ServerConfig {
     pub application_defaults: ApplicationDefaults,
     /// Queue-mode settings. `Some(...)` only when `receiver_mode == ReceiverMode::Queue`.
     pub queue: Option<QueueServerConfig>,
+    /// Burst smoothing for pull request events, in both receiver modes.
+    pub throttle: ProcessingThrottleConfig,
+    /// Backend for per-pull-request state. From `MERGE_WARDEN_STATE_STORE`.
+    /// Default: `Memory`.
+    pub state_store: StateStoreBackend,
+    /// Path of the TOML file the application defaults were loaded from
+    /// (`MERGE_WARDEN_CONFIG_FILE`). `None` when compiled-in defaults are used.
+    pub config_file: Option<PathBuf>,
 }
 
 // ---------------------------------------------------------------------------
 // Public functions
 // ---------------------------------------------------------------------------
 
-/// Reads GitHub App credentials from environment variables.
+/// Selects the [`SecretProvider`] named by `MERGE_WARDEN_SECRET_SOURCE`.
+///
+/// `env` (the default) reads environment variables; `file` reads files from
+/// `MERGE_WARDEN_SECRETS_DIR`, which defaults to [`DEFAULT_SECRETS_DIR`];
+/// `keyvault` reads the Azure Key Vault at `MERGE_WARDEN_KEY_VAULT_URL` with the
+/// managed identity of the host. Values are case-insensitive.
+///
+/// See docs/spec/interfaces/server-config.md — `secret_provider_from_env()`
+///
+/// # Errors
+/// - [`ServerError::InvalidEnvVar`] when `MERGE_WARDEN_SECRET_SOURCE` names an unknown source.
+/// - [`ServerError::MissingEnvVar`] when the source is `keyvault` and
+///   `MERGE_WARDEN_KEY_VAULT_URL` is not set.
+pub fn secret_provider_from_env() -> Result<Box<dyn SecretProvider>, ServerError> {
+    let source = std::env::var("MERGE_WARDEN_SECRET_SOURCE")
+        .unwrap_or_else(|_| "env".to_string())
+        .to_lowercase();
+
+    match source.as_str() {
+        "env" => Ok(Box::new(EnvSecretProvider)),
+        "file" => {
+            let directory = std::env::var("MERGE_WARDEN_SECRETS_DIR")
+                .unwrap_or_else(|_| DEFAULT_SECRETS_DIR.to_string());
+            Ok(Box::new(FileSecretProvider::new(directory)))
+        }
+        "keyvault" => {
+            let vault_url = std::env::var("MERGE_WARDEN_KEY_VAULT_URL").map_err(|_| {
+                ServerError::MissingEnvVar("MERGE_WARDEN_KEY_VAULT_URL".to_string())
+            })?;
+            Ok(Box::new(KeyVaultSecretProvider::new(
+                vault_url,
+                ManagedIdentityCredential::from_env(),
+            )?))
+        }
+        other => Err(ServerError::InvalidEnvVar {
+            name: "MERGE_WARDEN_SECRET_SOURCE".to_string(),
+            message: format!("Expected 'env', 'file' or 'keyvault', got '{}'", other),
+        }),
+    }
+}
+
+/// Reads GitHub App credentials from the secret source selected by
+/// `MERGE_WARDEN_SECRET_SOURCE`.
+///
+/// Returns the provider the secrets were read from along with the secrets, so the
+/// caller can report the source and raise its errors for secrets required later.
+///
+/// See docs/spec/interfaces/server-config.md — `load_secrets()`
+///
+/// # Errors
+/// - Any error returned by [`secret_provider_from_env`] or [`load_secrets_from`].
+pub async fn load_secrets() -> Result<(ServerSecrets, Box<dyn SecretProvider>), ServerError> {
+    let provider = secret_provider_from_env()?;
+    let secrets = load_secrets_from(provider.as_ref()).await?;
+    Ok((secrets, provider))
+}
+
+/// Reads GitHub App credentials from `provider`.
 /// `GITHUB_WEBHOOK_SECRET` is only required in webhook mode — in queue mode
 /// the separate receiving service owns signature validation.
 ///
 /// See docs/spec/interfaces/server-config.md — `load_secrets()`
 ///
 /// # Errors
-/// - [`ServerError::MissingEnvVar`] when any required variable is absent.
+/// - [`SecretProvider::missing_secret_error`] when a required secret is absent:
+///   [`ServerError::MissingEnvVar`], [`ServerError::MissingSecretFile`] or
+///   [`ServerError::MissingKeyVaultSecret`].
 /// - [`ServerError::InvalidEnvVar`] when `MERGE_WARDEN_GITHUB_APP_ID` is not a valid `u64`.
-pub fn load_secrets() -> Result<ServerSecrets, ServerError> {
-    let app_id_str = std::env::var("MERGE_WARDEN_GITHUB_APP_ID")
-        .map_err(|_| ServerError::MissingEnvVar("MERGE_WARDEN_GITHUB_APP_ID".to_string()))?;
-
-    let github_app_id: u64 = app_id_str.parse().map_err(|e| ServerError::InvalidEnvVar {
-        name: "MERGE_WARDEN_GITHUB_APP_ID".to_string(),
-        message: format!("Expected an unsigned integer: {}", e),
-    })?;
-
-    let github_app_private_key = std::env::var("MERGE_WARDEN_GITHUB_APP_PRIVATE_KEY")
-        .map(SecretString::new)
-        .map_err(|_| {
-            ServerError::MissingEnvVar("MERGE_WARDEN_GITHUB_APP_PRIVATE_KEY".to_string())
-        })?;
+/// - Any error returned by [`SecretProvider::get_secret`].
+pub async fn load_secrets_from(
+    provider: &dyn SecretProvider,
+) -> Result<ServerSecrets, ServerError> {
+    let required = |name: &'static str| async move {
+        provider
+            .get_secret(name)
+            .await?
+            .ok_or_else(|| provider.missing_secret_error(name))
+    };
+
+    let app_id_str = required(GITHUB_APP_ID_SECRET).await?;
 
-    let github_webhook_secret = std::env::var("GITHUB_WEBHOOK_SECRET")
-        .map(SecretString::new)
-        .ok();
+    let github_app_id: u64 =
+        app_id_str
+            .expose()
+            .trim()
+            .parse()
+            .map_err(|e| ServerError::InvalidEnvVar {
+                name: GITHUB_APP_ID_SECRET.to_string(),
+                message: format!("Expected an unsigned integer: {}", e),
+            })?;
+
+    let github_app_private_key = required(GITHUB_APP_PRIVATE_KEY_SECRET).await?;
+
+    let github_webhook_secret = provider.get_secret(GITHUB_WEBHOOK_SECRET).await?;
 
     Ok(ServerSecrets {
         github_app_id,
@@ -234,7 +526,7 @@ pub fn load_secrets() -> Result<ServerSecrets, ServerError> {
 /// See docs/spec/interfaces/server-config.md — `load_config()`
 ///
 /// # Errors
-/// - [`ServerError::InvalidEnvVar`] for malformed port or receiver mode values.
+/// - [`ServerError::InvalidEnvVar`] for malformed port, receiver mode or throttle values.
 /// - [`ServerError::MissingEnvVar`] for `MERGE_WARDEN_QUEUE_PROVIDER` in queue mode.
 /// - [`ServerError::ConfigError`] if the TOML file cannot be parsed.
 pub fn load_config() -> Result<ServerConfig, ServerError> {
@@ -265,6 +557,21 @@ pub fn load_config() -> Result<ServerConfig, ServerError> {
         }
     };
 
+    // --- State store ---
+    let state_store = match std::env::var("MERGE_WARDEN_STATE_STORE")
+        .unwrap_or_else(|_| "memory".to_string())
+        .to_lowercase()
+        .as_str()
+    {
+        "memory" => StateStoreBackend::Memory,
+        other => {
+            return Err(ServerError::InvalidEnvVar {
+                name: "MERGE_WARDEN_STATE_STORE".to_string(),
+                message: format!("Expected 'memory', got '{}'", other),
+            })
+        }
+    };
+
     // --- Config file path ---
     let config_file_path = std::env::var("MERGE_WARDEN_CONFIG_FILE")
         .ok()
@@ -341,10 +648,16 @@ pub fn load_config() -> Result<ServerConfig, ServerError> {
         None
     };
 
+    // --- Burst smoothing ---
+    let throttle = ProcessingThrottleConfig::from_env()?;
+
     Ok(ServerConfig {
         port,
         receiver_mode,
         application_defaults,
         queue,
+        throttle,
+        state_store,
+        config_file: config_file_path,
     })
 }
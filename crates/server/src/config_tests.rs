@@ -39,6 +39,16 @@ impl Drop for EnvGuard {
     }
 }
 
+/// Runs `future` to completion on a single-threaded runtime, so the async secret
+/// loading can be tested while `ENV_MUTEX` is held.
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
 // ---------------------------------------------------------------------------
 // SecretString
 // ---------------------------------------------------------------------------
@@ -83,10 +93,11 @@ fn load_secrets_succeeds_when_all_vars_present() {
         ],
     );
 
-    let result = load_secrets();
+    let result = block_on(load_secrets());
     assert!(result.is_ok(), "Expected Ok, got: {:?}", result);
 
-    let s = result.unwrap();
+    let (s, provider) = result.unwrap();
+    assert_eq!(provider.describe(), "loaded from environment");
     assert_eq!(s.github_app_id, 42);
     assert_eq!(s.github_app_private_key.expose(), "pem-content");
     assert_eq!(
@@ -116,7 +127,7 @@ fn load_secrets_errors_when_app_id_missing() {
         ],
     );
 
-    let r = load_secrets();
+    let r = block_on(load_secrets());
     assert!(
         matches!(&r, Err(ServerError::MissingEnvVar(n)) if n == "MERGE_WARDEN_GITHUB_APP_ID"),
         "Expected MissingEnvVar(MERGE_WARDEN_GITHUB_APP_ID), got: {:?}",
@@ -142,7 +153,7 @@ fn load_secrets_errors_when_app_id_is_not_numeric() {
         ],
     );
 
-    let r = load_secrets();
+    let r = block_on(load_secrets());
     assert!(
         matches!(&r, Err(ServerError::InvalidEnvVar { name, .. }) if name == "MERGE_WARDEN_GITHUB_APP_ID"),
         "Expected InvalidEnvVar(MERGE_WARDEN_GITHUB_APP_ID), got: {:?}",
@@ -167,7 +178,7 @@ fn load_secrets_errors_when_private_key_missing() {
         ],
     );
 
-    let r = load_secrets();
+    let r = block_on(load_secrets());
     assert!(
         matches!(&r, Err(ServerError::MissingEnvVar(n)) if n == "MERGE_WARDEN_GITHUB_APP_PRIVATE_KEY"),
         "Expected MissingEnvVar(MERGE_WARDEN_GITHUB_APP_PRIVATE_KEY), got: {:?}",
@@ -192,15 +203,164 @@ fn load_secrets_returns_none_webhook_secret_when_var_absent() {
         ],
     );
 
-    let r = load_secrets();
+    let r = block_on(load_secrets());
     assert!(r.is_ok(), "Expected Ok, got: {:?}", r);
-    let s = r.unwrap();
+    let (s, _) = r.unwrap();
     assert!(
         s.github_webhook_secret.is_none(),
         "Expected github_webhook_secret to be None when GITHUB_WEBHOOK_SECRET is absent"
     );
 }
 
+// ---------------------------------------------------------------------------
+// SecretProvider
+// ---------------------------------------------------------------------------
+
+/// Creates an empty per-test directory for secret files.
+fn secrets_dir(name: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("merge_warden_server_secrets_{}", name));
+    let _ = std::fs::remove_dir_all(&path);
+    std::fs::create_dir_all(&path).unwrap();
+    path
+}
+
+#[test]
+fn file_secret_provider_reads_secrets_and_strips_one_trailing_newline() {
+    let dir = secrets_dir("read");
+    std::fs::write(dir.join(GITHUB_APP_ID_SECRET), "42\n").unwrap();
+    std::fs::write(
+        dir.join(GITHUB_APP_PRIVATE_KEY_SECRET),
+        "-----BEGIN KEY-----\nabc\n-----END KEY-----\r\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join(GITHUB_WEBHOOK_SECRET), "hook-secret").unwrap();
+
+    let r = block_on(load_secrets_from(&FileSecretProvider::new(&dir)));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let s = r.unwrap();
+    assert_eq!(s.github_app_id, 42);
+    assert_eq!(
+        s.github_app_private_key.expose(),
+        "-----BEGIN KEY-----\nabc\n-----END KEY-----"
+    );
+    assert_eq!(
+        s.github_webhook_secret.as_ref().unwrap().expose(),
+        "hook-secret"
+    );
+}
+
+#[test]
+fn file_secret_provider_reports_missing_file_path() {
+    let dir = secrets_dir("missing");
+    std::fs::write(dir.join(GITHUB_APP_ID_SECRET), "42").unwrap();
+
+    let r = block_on(load_secrets_from(&FileSecretProvider::new(&dir)));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert!(
+        matches!(&r, Err(ServerError::MissingSecretFile(p)) if *p == dir.join(GITHUB_APP_PRIVATE_KEY_SECRET)),
+        "Expected MissingSecretFile for the private key, got: {:?}",
+        r
+    );
+}
+
+#[test]
+fn file_secret_provider_treats_absent_webhook_secret_as_none() {
+    let dir = secrets_dir("no_webhook");
+    std::fs::write(dir.join(GITHUB_APP_ID_SECRET), "7").unwrap();
+    std::fs::write(dir.join(GITHUB_APP_PRIVATE_KEY_SECRET), "k").unwrap();
+
+    let r = block_on(load_secrets_from(&FileSecretProvider::new(&dir)));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert!(r.unwrap().github_webhook_secret.is_none());
+}
+
+#[test]
+fn load_secrets_reads_files_when_secret_source_is_file() {
+    let _lock = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let dir = secrets_dir("source_file");
+    std::fs::write(dir.join(GITHUB_APP_ID_SECRET), "99").unwrap();
+    std::fs::write(dir.join(GITHUB_APP_PRIVATE_KEY_SECRET), "file-key").unwrap();
+    let _env = EnvGuard::prepare(
+        &[
+            ("MERGE_WARDEN_SECRET_SOURCE", "File"),
+            ("MERGE_WARDEN_SECRETS_DIR", dir.to_str().unwrap()),
+            // Environment values are ignored when reading from files
+            ("MERGE_WARDEN_GITHUB_APP_ID", "1"),
+        ],
+        &[
+            "MERGE_WARDEN_SECRET_SOURCE",
+            "MERGE_WARDEN_SECRETS_DIR",
+            "MERGE_WARDEN_GITHUB_APP_ID",
+            "MERGE_WARDEN_GITHUB_APP_PRIVATE_KEY",
+            "GITHUB_WEBHOOK_SECRET",
+        ],
+    );
+
+    let r = block_on(load_secrets());
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let (s, provider) = r.unwrap();
+    assert_eq!(
+        provider.describe(),
+        format!("loaded from files in {}", dir.display())
+    );
+    assert_eq!(s.github_app_id, 99);
+    assert_eq!(s.github_app_private_key.expose(), "file-key");
+}
+
+#[test]
+fn secret_provider_from_env_defaults_to_env_and_rejects_unknown_source() {
+    let _lock = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvGuard::prepare(&[], &["MERGE_WARDEN_SECRET_SOURCE"]);
+    assert!(matches!(
+        secret_provider_from_env()
+            .unwrap()
+            .missing_secret_error(GITHUB_WEBHOOK_SECRET),
+        ServerError::MissingEnvVar(n) if n == GITHUB_WEBHOOK_SECRET
+    ));
+
+    std::env::set_var("MERGE_WARDEN_SECRET_SOURCE", "vault");
+    let r = secret_provider_from_env();
+    assert!(
+        matches!(&r, Err(ServerError::InvalidEnvVar { name, .. }) if name == "MERGE_WARDEN_SECRET_SOURCE"),
+        "Expected InvalidEnvVar(MERGE_WARDEN_SECRET_SOURCE), got: {:?}",
+        r
+    );
+}
+
+#[test]
+fn secret_provider_from_env_selects_key_vault_and_requires_its_url() {
+    let _lock = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvGuard::prepare(
+        &[("MERGE_WARDEN_SECRET_SOURCE", "KeyVault")],
+        &["MERGE_WARDEN_KEY_VAULT_URL"],
+    );
+    let r = secret_provider_from_env();
+    assert!(
+        matches!(&r, Err(ServerError::MissingEnvVar(n)) if n == "MERGE_WARDEN_KEY_VAULT_URL"),
+        "Expected MissingEnvVar(MERGE_WARDEN_KEY_VAULT_URL), got: {:?}",
+        r
+    );
+
+    std::env::set_var(
+        "MERGE_WARDEN_KEY_VAULT_URL",
+        "https://kv-merge-warden.vault.azure.net/",
+    );
+    let provider = secret_provider_from_env().unwrap();
+    assert_eq!(
+        provider.describe(),
+        "loaded from Key Vault https://kv-merge-warden.vault.azure.net"
+    );
+    assert!(matches!(
+        provider.missing_secret_error(GITHUB_WEBHOOK_SECRET),
+        ServerError::MissingKeyVaultSecret { name, .. } if name == "github-webhook-secret"
+    ));
+}
+
 // ---------------------------------------------------------------------------
 // load_config — port
 // ---------------------------------------------------------------------------
@@ -307,6 +467,58 @@ fn load_config_errors_on_unknown_receiver_mode() {
     );
 }
 
+// ---------------------------------------------------------------------------
+// load_config — state store
+// ---------------------------------------------------------------------------
+
+#[test]
+fn load_config_defaults_state_store_to_memory() {
+    let _lock = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvGuard::prepare(
+        &[],
+        &[
+            "MERGE_WARDEN_PORT",
+            "MERGE_WARDEN_RECEIVER_MODE",
+            "MERGE_WARDEN_CONFIG_FILE",
+            "MERGE_WARDEN_STATE_STORE",
+        ],
+    );
+    assert_eq!(
+        load_config().unwrap().state_store,
+        StateStoreBackend::Memory
+    );
+
+    std::env::set_var("MERGE_WARDEN_STATE_STORE", "Memory");
+    assert_eq!(
+        load_config().unwrap().state_store,
+        StateStoreBackend::Memory
+    );
+}
+
+#[test]
+fn load_config_errors_on_unknown_state_store() {
+    let _lock = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvGuard::prepare(
+        &[("MERGE_WARDEN_STATE_STORE", "redis")],
+        &[
+            "MERGE_WARDEN_PORT",
+            "MERGE_WARDEN_RECEIVER_MODE",
+            "MERGE_WARDEN_CONFIG_FILE",
+            "MERGE_WARDEN_STATE_STORE",
+        ],
+    );
+
+    let r = load_config();
+    assert!(
+        matches!(
+            &r,
+            Err(ServerError::InvalidEnvVar { name, .. }) if name == "MERGE_WARDEN_STATE_STORE"
+        ),
+        "Expected InvalidEnvVar(MERGE_WARDEN_STATE_STORE), got: {:?}",
+        r
+    );
+}
+
 // ---------------------------------------------------------------------------
 // load_config — queue mode
 // ---------------------------------------------------------------------------
@@ -746,3 +958,84 @@ fn load_config_succeeds_when_repository_scope_include_patterns_is_empty_list() {
         .expect("repository_scope should be Some");
     assert!(scope.include_patterns.is_empty());
 }
+
+// ---------------------------------------------------------------------------
+// load_config — processing throttle
+// ---------------------------------------------------------------------------
+
+#[test]
+fn load_config_disables_throttle_by_default() {
+    let _lock = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvGuard::prepare(
+        &[],
+        &[
+            "MERGE_WARDEN_PORT",
+            "MERGE_WARDEN_RECEIVER_MODE",
+            "MERGE_WARDEN_CONFIG_FILE",
+            "MERGE_WARDEN_PROCESSING_JITTER_MS",
+            "MERGE_WARDEN_MAX_CONCURRENT_PROCESSING",
+            "MERGE_WARDEN_MAX_CONCURRENT_PER_REPOSITORY",
+        ],
+    );
+
+    let r = load_config();
+    assert!(r.is_ok(), "{:?}", r);
+    assert_eq!(r.unwrap().throttle, ProcessingThrottleConfig::default());
+}
+
+#[test]
+fn load_config_reads_throttle_settings() {
+    let _lock = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let _env = EnvGuard::prepare(
+        &[
+            ("MERGE_WARDEN_PROCESSING_JITTER_MS", "2500"),
+            ("MERGE_WARDEN_MAX_CONCURRENT_PROCESSING", "3"),
+            ("MERGE_WARDEN_MAX_CONCURRENT_PER_REPOSITORY", "1"),
+        ],
+        &[
+            "MERGE_WARDEN_PORT",
+            "MERGE_WARDEN_RECEIVER_MODE",
+            "MERGE_WARDEN_CONFIG_FILE",
+        ],
+    );
+
+    let r = load_config();
+    assert!(r.is_ok(), "{:?}", r);
+    let throttle = r.unwrap().throttle;
+    assert_eq!(throttle.max_jitter, Duration::from_millis(2500));
+    assert_eq!(throttle.max_concurrent, Some(3));
+    assert_eq!(throttle.max_concurrent_per_repository, Some(1));
+}
+
+#[test]
+fn load_config_errors_on_invalid_throttle_settings() {
+    let _lock = ENV_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+    let cases = [
+        ("MERGE_WARDEN_PROCESSING_JITTER_MS", "soon"),
+        ("MERGE_WARDEN_MAX_CONCURRENT_PROCESSING", "0"),
+        ("MERGE_WARDEN_MAX_CONCURRENT_PROCESSING", "many"),
+        ("MERGE_WARDEN_MAX_CONCURRENT_PER_REPOSITORY", "0"),
+        ("MERGE_WARDEN_MAX_CONCURRENT_PER_REPOSITORY", "one"),
+    ];
+
+    for (var, value) in cases {
+        let _env = EnvGuard::prepare(
+            &[(var, value)],
+            &[
+                "MERGE_WARDEN_PORT",
+                "MERGE_WARDEN_RECEIVER_MODE",
+                "MERGE_WARDEN_CONFIG_FILE",
+                "MERGE_WARDEN_PROCESSING_JITTER_MS",
+                "MERGE_WARDEN_MAX_CONCURRENT_PROCESSING",
+                "MERGE_WARDEN_MAX_CONCURRENT_PER_REPOSITORY",
+            ],
+        );
+
+        let r = load_config();
+        assert!(
+            matches!(&r, Err(ServerError::InvalidEnvVar { name, .. }) if name == var),
+            "Expected InvalidEnvVar({var}) for '{value}', got: {:?}",
+            r
+        );
+    }
+}
@@ -12,6 +12,16 @@ pub enum ServerError {
     #[error("Missing required environment variable '{0}'")]
     MissingEnvVar(String),
 
+    /// A required secret file was absent when secrets are read from files.
+    ///
+    /// Parameter: the path of the missing file.
+    #[error("Missing required secret file '{}'", .0.display())]
+    MissingSecretFile(std::path::PathBuf),
+
+    /// A required secret was absent from the Key Vault secrets are read from.
+    #[error("Missing required secret '{name}' in Key Vault '{vault}'")]
+    MissingKeyVaultSecret { vault: String, name: String },
+
     /// An environment variable was present but its value was not valid.
     #[error("Invalid environment variable '{name}': {message}")]
     InvalidEnvVar { name: String, message: String },
@@ -35,6 +45,11 @@ pub enum ServerError {
     #[error("Request processing error: {0}")]
     ProcessingError(String),
 
+    /// The result of a processed pull request could not be posted to the
+    /// configured result callback URL. Logged only; never fails the event.
+    #[error("Result callback error: {0}")]
+    ResultCallbackError(String),
+
     /// The `tracing` subscriber could not be installed.
     #[error("Telemetry initialization failed: {0}")]
     TelemetryInitFailed(String),
@@ -452,7 +452,11 @@ pub async fn run_event_processor(
     let handler = crate::webhook::MergeWardenWebhookHandler::new(
         state.github_client.clone(),
         state.policies.clone(),
-    );
+    )
+    .with_throttle(state.throttle.clone())
+    .with_state_store(state.state_store.clone())
+    .with_result_callback(state.result_callback.clone())
+    .with_bot_login(state.bot_login.clone());
 
     while let Some(event) = ingress.next_event().await? {
         match handler.handle_event(&event.envelope).await {
@@ -0,0 +1,244 @@
+// See docs/spec/interfaces/server-config.md — KeyVaultSecretProvider
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+use crate::config::{SecretProvider, SecretString};
+use crate::errors::ServerError;
+
+#[cfg(test)]
+#[path = "keyvault_tests.rs"]
+mod tests;
+
+/// Key Vault REST API version used to read secrets.
+const KEY_VAULT_API_VERSION: &str = "7.4";
+
+/// Resource the managed identity token is requested for.
+const KEY_VAULT_RESOURCE: &str = "https://vault.azure.net";
+
+/// Azure Instance Metadata Service token endpoint, used when the platform does not
+/// set `IDENTITY_ENDPOINT`.
+const IMDS_TOKEN_ENDPOINT: &str = "http://169.254.169.254/metadata/identity/oauth2/token";
+
+/// Time allowed for a single token or secret request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Returns the Key Vault secret name for the secret `name`.
+///
+/// Key Vault secret names may only contain letters, digits and dashes, so the
+/// environment variable names are lowercased and their underscores replaced by
+/// dashes: `MERGE_WARDEN_GITHUB_APP_ID` is read from `merge-warden-github-app-id`.
+pub fn key_vault_secret_name(name: &str) -> String {
+    name.to_lowercase().replace('_', "-")
+}
+
+/// Fetches access tokens for Key Vault from the managed identity of the host.
+///
+/// See docs/spec/interfaces/server-config.md — `ManagedIdentityCredential`
+#[derive(Debug)]
+pub struct ManagedIdentityCredential {
+    /// Token endpoint of the identity service.
+    endpoint: String,
+    /// API version of the identity service.
+    api_version: &'static str,
+    /// Header sent with each token request to prove it comes from the host.
+    header: (&'static str, SecretString),
+    /// Client ID of a user-assigned identity. `None` uses the system-assigned one.
+    client_id: Option<String>,
+}
+
+impl ManagedIdentityCredential {
+    /// Creates a credential for the Azure Instance Metadata Service, as used on
+    /// virtual machines and AKS.
+    pub fn instance_metadata(client_id: Option<String>) -> Self {
+        Self {
+            endpoint: IMDS_TOKEN_ENDPOINT.to_string(),
+            api_version: "2018-02-01",
+            header: ("Metadata", SecretString::new("true".to_string())),
+            client_id,
+        }
+    }
+
+    /// Creates a credential for the identity endpoint that App Service and
+    /// Container Apps expose through `IDENTITY_ENDPOINT` and `IDENTITY_HEADER`.
+    pub fn identity_endpoint(
+        endpoint: impl Into<String>,
+        identity_header: SecretString,
+        client_id: Option<String>,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            api_version: "2019-08-01",
+            header: ("X-IDENTITY-HEADER", identity_header),
+            client_id,
+        }
+    }
+
+    /// Selects the identity endpoint when both `IDENTITY_ENDPOINT` and
+    /// `IDENTITY_HEADER` are set, and the Instance Metadata Service otherwise.
+    /// `AZURE_CLIENT_ID` selects a user-assigned identity.
+    pub fn from_env() -> Self {
+        let client_id = std::env::var("AZURE_CLIENT_ID").ok();
+        match (
+            std::env::var("IDENTITY_ENDPOINT"),
+            std::env::var("IDENTITY_HEADER"),
+        ) {
+            (Ok(endpoint), Ok(header)) => {
+                Self::identity_endpoint(endpoint, SecretString::new(header), client_id)
+            }
+            _ => Self::instance_metadata(client_id),
+        }
+    }
+
+    /// Requests an access token for Key Vault.
+    ///
+    /// # Errors
+    /// Returns [`ServerError::AuthError`] when the request fails, is not answered
+    /// with a success status, or the response has no access token.
+    async fn get_token(&self, client: &reqwest::Client) -> Result<SecretString, ServerError> {
+        #[derive(Deserialize)]
+        struct TokenResponse {
+            access_token: String,
+        }
+
+        let mut query = vec![
+            ("api-version", self.api_version),
+            ("resource", KEY_VAULT_RESOURCE),
+        ];
+        if let Some(client_id) = &self.client_id {
+            query.push(("client_id", client_id.as_str()));
+        }
+
+        let response = client
+            .get(&self.endpoint)
+            .query(&query)
+            .header(self.header.0, self.header.1.expose())
+            .send()
+            .await
+            .map_err(|e| {
+                ServerError::AuthError(format!(
+                    "Failed to request a managed identity token from {}: {}",
+                    self.endpoint, e
+                ))
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ServerError::AuthError(format!(
+                "The managed identity endpoint {} responded with status {}",
+                self.endpoint, status
+            )));
+        }
+
+        let token: TokenResponse = response.json().await.map_err(|e| {
+            ServerError::AuthError(format!(
+                "Failed to read the managed identity token from {}: {}",
+                self.endpoint, e
+            ))
+        })?;
+        Ok(SecretString::new(token.access_token))
+    }
+}
+
+/// Reads each secret from an Azure Key Vault, authenticating with the managed
+/// identity of the host.
+///
+/// The secret `name` is read from [`key_vault_secret_name`]`(name)`. The access
+/// token is requested on the first read and reused for the rest.
+///
+/// See docs/spec/interfaces/server-config.md — `KeyVaultSecretProvider`
+#[derive(Debug)]
+pub struct KeyVaultSecretProvider {
+    /// Vault URL without a trailing slash, e.g. `https://my-vault.vault.azure.net`.
+    vault_url: String,
+    credential: ManagedIdentityCredential,
+    client: reqwest::Client,
+    token: OnceCell<SecretString>,
+}
+
+impl KeyVaultSecretProvider {
+    /// Creates a provider that reads secrets from the vault at `vault_url`.
+    ///
+    /// # Errors
+    /// Returns [`ServerError::ConfigError`] when the HTTP client cannot be built.
+    pub fn new(
+        vault_url: impl Into<String>,
+        credential: ManagedIdentityCredential,
+    ) -> Result<Self, ServerError> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| {
+                ServerError::ConfigError(format!(
+                    "Failed to build the Key Vault HTTP client: {}",
+                    e
+                ))
+            })?;
+
+        Ok(Self {
+            vault_url: vault_url.into().trim_end_matches('/').to_string(),
+            credential,
+            client,
+            token: OnceCell::new(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretProvider for KeyVaultSecretProvider {
+    async fn get_secret(&self, name: &str) -> Result<Option<SecretString>, ServerError> {
+        #[derive(Deserialize)]
+        struct SecretBundle {
+            value: String,
+        }
+
+        let token = self
+            .token
+            .get_or_try_init(|| self.credential.get_token(&self.client))
+            .await?;
+
+        let url = format!("{}/secrets/{}", self.vault_url, key_vault_secret_name(name));
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("api-version", KEY_VAULT_API_VERSION)])
+            .bearer_auth(token.expose())
+            .send()
+            .await
+            .map_err(|e| {
+                ServerError::ConfigError(format!(
+                    "Failed to read Key Vault secret '{}': {}",
+                    url, e
+                ))
+            })?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(ServerError::ConfigError(format!(
+                "Failed to read Key Vault secret '{}': the vault responded with status {}",
+                url, status
+            )));
+        }
+
+        let bundle: SecretBundle = response.json().await.map_err(|e| {
+            ServerError::ConfigError(format!("Failed to read Key Vault secret '{}': {}", url, e))
+        })?;
+        Ok(Some(SecretString::new(bundle.value)))
+    }
+
+    fn missing_secret_error(&self, name: &str) -> ServerError {
+        ServerError::MissingKeyVaultSecret {
+            vault: self.vault_url.clone(),
+            name: key_vault_secret_name(name),
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!("loaded from Key Vault {}", self.vault_url)
+    }
+}
@@ -0,0 +1,139 @@
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::{key_vault_secret_name, KeyVaultSecretProvider, ManagedIdentityCredential};
+use crate::config::{
+    load_secrets_from, SecretProvider, SecretString, GITHUB_APP_ID_SECRET,
+    GITHUB_APP_PRIVATE_KEY_SECRET,
+};
+use crate::errors::ServerError;
+
+/// Serves a managed identity token from `/token` on `server`, expecting `times` requests.
+async fn mount_token(server: &MockServer, times: u64) {
+    Mock::given(method("GET"))
+        .and(path("/token"))
+        .and(header("X-IDENTITY-HEADER", "identity-header"))
+        .and(query_param("resource", "https://vault.azure.net"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "vault-token",
+            "expires_on": "1700000000",
+        })))
+        .expect(times)
+        .mount(server)
+        .await;
+}
+
+/// Serves the Key Vault secret `name` with `value` from `server`.
+async fn mount_secret(server: &MockServer, name: &str, value: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/secrets/{}", name)))
+        .and(query_param("api-version", "7.4"))
+        .and(header("Authorization", "Bearer vault-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "value": value,
+            "id": format!("https://vault/secrets/{}/1", name),
+        })))
+        .mount(server)
+        .await;
+}
+
+fn make_provider(server: &MockServer) -> KeyVaultSecretProvider {
+    KeyVaultSecretProvider::new(
+        server.uri(),
+        ManagedIdentityCredential::identity_endpoint(
+            format!("{}/token", server.uri()),
+            SecretString::new("identity-header".to_string()),
+            None,
+        ),
+    )
+    .unwrap()
+}
+
+#[test]
+fn key_vault_secret_name_uses_dashes_and_lowercase() {
+    assert_eq!(
+        key_vault_secret_name(GITHUB_APP_ID_SECRET),
+        "merge-warden-github-app-id"
+    );
+    assert_eq!(
+        key_vault_secret_name("GITHUB_WEBHOOK_SECRET"),
+        "github-webhook-secret"
+    );
+}
+
+#[tokio::test]
+async fn load_secrets_from_key_vault_reads_each_secret_with_one_token() {
+    let server = MockServer::start().await;
+    mount_token(&server, 1).await;
+    mount_secret(&server, "merge-warden-github-app-id", "42").await;
+    mount_secret(&server, "merge-warden-github-app-private-key", "vault-key").await;
+    mount_secret(&server, "github-webhook-secret", "hook-secret").await;
+
+    let s = load_secrets_from(&make_provider(&server)).await.unwrap();
+
+    assert_eq!(s.github_app_id, 42);
+    assert_eq!(s.github_app_private_key.expose(), "vault-key");
+    assert_eq!(
+        s.github_webhook_secret.as_ref().unwrap().expose(),
+        "hook-secret"
+    );
+}
+
+#[tokio::test]
+async fn load_secrets_from_key_vault_reports_missing_secret() {
+    let server = MockServer::start().await;
+    mount_token(&server, 1).await;
+    mount_secret(&server, "merge-warden-github-app-id", "42").await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let r = load_secrets_from(&make_provider(&server)).await;
+
+    assert!(
+        matches!(&r, Err(ServerError::MissingKeyVaultSecret { name, .. })
+            if name == &key_vault_secret_name(GITHUB_APP_PRIVATE_KEY_SECRET)),
+        "Expected MissingKeyVaultSecret for the private key, got: {:?}",
+        r
+    );
+}
+
+#[tokio::test]
+async fn load_secrets_from_key_vault_fails_when_the_token_is_refused() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(400))
+        .mount(&server)
+        .await;
+
+    let r = load_secrets_from(&make_provider(&server)).await;
+
+    assert!(
+        matches!(&r, Err(ServerError::AuthError(_))),
+        "Expected AuthError, got: {:?}",
+        r
+    );
+}
+
+#[tokio::test]
+async fn get_secret_reports_vault_errors_other_than_not_found() {
+    let server = MockServer::start().await;
+    mount_token(&server, 1).await;
+    Mock::given(method("GET"))
+        .and(path("/secrets/github-webhook-secret"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+
+    let r = make_provider(&server)
+        .get_secret("GITHUB_WEBHOOK_SECRET")
+        .await;
+
+    assert!(
+        matches!(&r, Err(ServerError::ConfigError(m)) if m.contains("403")),
+        "Expected ConfigError with the status, got: {:?}",
+        r
+    );
+}
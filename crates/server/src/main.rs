@@ -5,9 +5,11 @@
 // See docs/spec/design/containerisation.md     — deployment spec
 // See docs/spec/design/queue-architecture.md   — queue-mode wiring
 
+mod callback;
 mod config;
 mod errors;
 mod ingress;
+mod keyvault;
 mod telemetry;
 mod webhook;
 
@@ -19,6 +21,7 @@ use github_bot_sdk::client::{ClientConfig, GitHubClient};
 use merge_warden_developer_platforms::app_auth::AppAuthProvider;
 use queue_runtime::{QueueClientFactory, QueueName};
 use tracing::{debug, error, info};
+use webhook::{ComponentHealth, StartupHealth};
 
 #[tokio::main]
 async fn main() -> Result<(), ServerError> {
@@ -28,9 +31,9 @@ async fn main() -> Result<(), ServerError> {
 
     info!("Starting merge-warden-server");
 
-    // 2. Load secrets (fail fast if any required env var is absent).
-    debug!("Loading secrets from environment");
-    let secrets = config::load_secrets()?;
+    // 2. Load secrets (fail fast if any required secret is absent).
+    debug!("Loading secrets");
+    let (secrets, secret_provider) = config::load_secrets().await?;
 
     // 3. Load application configuration.
     debug!("Loading application configuration");
@@ -39,6 +42,10 @@ async fn main() -> Result<(), ServerError> {
     info!(
         port = server_config.port,
         receiver_mode = ?server_config.receiver_mode,
+        max_jitter_ms = server_config.throttle.max_jitter.as_millis() as u64,
+        max_concurrent = ?server_config.throttle.max_concurrent,
+        max_concurrent_per_repository = ?server_config.throttle.max_concurrent_per_repository,
+        state_store = ?server_config.state_store,
         "Configuration loaded"
     );
 
@@ -46,9 +53,7 @@ async fn main() -> Result<(), ServerError> {
     if server_config.receiver_mode == ReceiverMode::Webhook
         && secrets.github_webhook_secret.is_none()
     {
-        return Err(ServerError::MissingEnvVar(
-            "GITHUB_WEBHOOK_SECRET".to_string(),
-        ));
+        return Err(secret_provider.missing_secret_error(config::GITHUB_WEBHOOK_SECRET));
     }
 
     // 5. Initialise GitHub App client.
@@ -70,6 +75,45 @@ async fn main() -> Result<(), ServerError> {
         .build()
         .map_err(|e| ServerError::AuthError(format!("Failed to build GitHub client: {}", e)))?;
 
+    // Probe app authentication once with a real `GET /app` call so `/health`
+    // reports a wrong app ID, a revoked key or a deleted app. A failure is not
+    // fatal: the server keeps running and reports `503` until it is redeployed
+    // with working credentials.
+    let (github_app_health, bot_login) = match github_client.get_app().await {
+        Ok(app) if app.id == secrets.github_app_id => (
+            ComponentHealth::ok(format!(
+                "authenticated as GitHub App {} ({})",
+                app.slug, app.id
+            )),
+            Some(format!("{}[bot]", app.slug)),
+        ),
+        Ok(app) => {
+            error!(
+                app_id = secrets.github_app_id,
+                authenticated_app_id = app.id,
+                "GitHub App authentication returned a different app at startup"
+            );
+            (
+                ComponentHealth::failed(format!(
+                    "GitHub App authentication returned app {} instead of the configured app {}",
+                    app.id, secrets.github_app_id
+                )),
+                None,
+            )
+        }
+        Err(e) => {
+            error!(
+                app_id = secrets.github_app_id,
+                error = %e,
+                "GitHub App authentication failed at startup"
+            );
+            (
+                ComponentHealth::failed(format!("GitHub App authentication failed: {}", e)),
+                None,
+            )
+        }
+    };
+
     debug!("GitHub App client initialised");
 
     // 6. Optionally create the queue client (queue mode only).
@@ -125,10 +169,32 @@ async fn main() -> Result<(), ServerError> {
     // 8. Build AppState.
     let (queue_client_opt, queue_name_opt) = queue_pair.unzip();
 
+    // Fail fast rather than run without posting the results the operator asked for.
+    let result_callback = callback::ResultCallback::from_url(
+        server_config
+            .application_defaults
+            .result_callback_url
+            .as_deref(),
+    )?;
+
+    let health = StartupHealth {
+        configuration: ComponentHealth::ok(match &server_config.config_file {
+            Some(path) => format!("loaded from {}", path.display()),
+            None => "using compiled-in defaults".to_string(),
+        }),
+        secrets: ComponentHealth::ok(secret_provider.describe()),
+        github_app: github_app_health,
+    };
+
     let state = Arc::new(webhook::AppState {
         receiver: receiver_opt,
         github_client: github_client.clone(),
         policies: server_config.application_defaults.clone(),
+        health,
+        throttle: webhook::ProcessingThrottle::new(&server_config.throttle),
+        state_store: server_config.state_store.create_store(),
+        bot_login,
+        result_callback,
     });
 
     // 9. Spawn processor tasks.
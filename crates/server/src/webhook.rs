@@ -2,7 +2,12 @@
 // See docs/spec/interfaces/developer-platforms-sdk.md — MergeWardenWebhookHandler
 // See docs/spec/design/containerisation.md       — HTTP routes
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{hash_map::RandomState, BTreeMap, HashMap},
+    hash::{BuildHasher, Hasher},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use axum::{
@@ -11,7 +16,7 @@ use axum::{
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
     routing::{get, post},
-    Router,
+    Json, Router,
 };
 use github_bot_sdk::{
     auth::{GitHubAppId, InstallationId, PrivateKey, SecretProvider},
@@ -26,13 +31,23 @@ use merge_warden_core::{
         CurrentPullRequestValidationConfiguration,
     },
     errors::ConfigLoadError,
-    MergeWarden,
+    state::{InMemoryStateStore, StateStore},
+    CheckResult, MergeWarden,
 };
 use merge_warden_developer_platforms::github::GitHubProvider;
+use merge_warden_developer_platforms::models::CheckRunAction;
+use merge_warden_developer_platforms::webhook::{
+    parse_merge_queue_ref, parse_pull_request_action, PullRequestAction, CHECK_RUN_EVENT_TYPE,
+    CHECK_RUN_REQUESTED_ACTION, MERGE_GROUP_CHECKS_REQUESTED, MERGE_GROUP_EVENT_TYPE,
+    PULL_REQUEST_EVENT_TYPES,
+};
 use merge_warden_developer_platforms::PullRequestProvider as _;
-use tokio::sync::mpsc;
+use serde::Serialize;
+use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
 use tracing::{debug, error, info, instrument, warn};
 
+use crate::callback::{CheckResultReport, ResultCallback};
+use crate::config::ProcessingThrottleConfig;
 use crate::errors::ServerError;
 
 #[cfg(test)]
@@ -109,6 +124,367 @@ pub struct AppState {
     pub github_client: GitHubClient,
     /// Application policy defaults loaded from configuration.
     pub policies: ApplicationDefaults,
+    /// Status of the startup dependencies, reported by [`health_check`].
+    pub health: StartupHealth,
+    /// Burst smoothing shared by all processor tasks.
+    pub throttle: ProcessingThrottle,
+    /// Per-pull-request state shared by all processor tasks.
+    pub state_store: Arc<dyn StateStore>,
+    /// Login of the app's bot account, e.g. `merge-warden[bot]`, or `None` when
+    /// the app could not be looked up at startup.
+    pub bot_login: Option<String>,
+    /// Receives the result of every processed pull request, or `None` when
+    /// `result_callback_url` is not configured.
+    pub result_callback: Option<ResultCallback>,
+}
+
+// ---------------------------------------------------------------------------
+// StartupHealth
+// ---------------------------------------------------------------------------
+
+/// Outcome of initialising a single startup dependency.
+///
+/// See docs/spec/design/containerisation.md — health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentStatus {
+    /// The dependency initialised successfully.
+    Ok,
+    /// The dependency failed to initialise; the server cannot process events.
+    Failed,
+}
+
+/// Status and optional detail for a single startup dependency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComponentHealth {
+    /// Whether the dependency initialised successfully.
+    pub status: ComponentStatus,
+    /// Human-readable detail, e.g. where the configuration came from or why
+    /// initialisation failed. Never contains secret values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ComponentHealth {
+    /// A successfully initialised dependency with the given detail.
+    pub fn ok(detail: impl Into<String>) -> Self {
+        Self {
+            status: ComponentStatus::Ok,
+            detail: Some(detail.into()),
+        }
+    }
+
+    /// A dependency that failed to initialise, with the failure reason.
+    pub fn failed(detail: impl Into<String>) -> Self {
+        Self {
+            status: ComponentStatus::Failed,
+            detail: Some(detail.into()),
+        }
+    }
+
+    /// Returns `true` when the dependency initialised successfully.
+    pub fn is_ok(&self) -> bool {
+        self.status == ComponentStatus::Ok
+    }
+}
+
+/// Startup status of the server's critical dependencies.
+///
+/// Captured once in `main()` and served by [`health_check`]. Configuration and
+/// secrets are loaded before the HTTP listener starts — a failure there aborts
+/// startup — so a running server always reports them as `ok` together with
+/// their source. GitHub App authentication is probed at startup without
+/// aborting, so a failure is visible to orchestrators as a non-200 response.
+///
+/// See docs/spec/design/containerisation.md — health check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StartupHealth {
+    /// Loading of the application configuration (TOML file or compiled defaults).
+    pub configuration: ComponentHealth,
+    /// Loading of the GitHub App credentials and webhook secret.
+    pub secrets: ComponentHealth,
+    /// Authentication of the GitHub App client, probed with a `GET /app` call
+    /// signed with the app JWT.
+    pub github_app: ComponentHealth,
+}
+
+impl StartupHealth {
+    /// Returns `true` when every critical dependency initialised successfully.
+    pub fn is_healthy(&self) -> bool {
+        self.configuration.is_ok() && self.secrets.is_ok() && self.github_app.is_ok()
+    }
+}
+
+/// JSON body returned by [`health_check`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    /// Overall status: `ok` when all components are healthy, `failed` otherwise.
+    pub status: ComponentStatus,
+    /// Per-component startup status.
+    pub components: StartupHealth,
+    /// Pull request events being processed and waiting per repository, keyed by
+    /// the lower-case repository full name. Empty, and left out of the JSON, when
+    /// no per-repository limit is configured or no events are in flight.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub repository_queues: BTreeMap<String, RepositoryQueueDepth>,
+}
+
+impl HealthReport {
+    /// Builds the report and the matching HTTP status code for `health`.
+    ///
+    /// Returns `200 OK` when all components are healthy and
+    /// `503 Service Unavailable` otherwise.
+    pub fn from_startup_health(health: &StartupHealth) -> (StatusCode, Self) {
+        let (code, status) = if health.is_healthy() {
+            (StatusCode::OK, ComponentStatus::Ok)
+        } else {
+            (StatusCode::SERVICE_UNAVAILABLE, ComponentStatus::Failed)
+        };
+        (
+            code,
+            Self {
+                status,
+                components: health.clone(),
+                repository_queues: BTreeMap::new(),
+            },
+        )
+    }
+}
+
+/// Pull request events of one repository that are in flight, as reported by
+/// [`health_check`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RepositoryQueueDepth {
+    /// Events currently being processed.
+    pub processing: usize,
+    /// Events waiting for a free slot of the repository.
+    pub waiting: usize,
+}
+
+// ---------------------------------------------------------------------------
+// ProcessingThrottle
+// ---------------------------------------------------------------------------
+
+/// Smooths out bursts of pull request events.
+///
+/// A force-push of a base branch can send a `synchronize` event for every open
+/// PR at once. Before a pull request event is processed, the handler waits for a
+/// random delay of up to `max_jitter`, which spreads the GitHub API calls of a
+/// burst over time. It then takes a permit of the event's repository, so that at
+/// most `max_concurrent_per_repository` events of one repository are processed at
+/// the same time, and finally a permit from a limiter shared by all processor
+/// tasks, so that at most `max_concurrent` events are processed at the same time.
+///
+/// Waiting for the repository first keeps a busy repository from occupying the
+/// shared slots with events that could not start anyway, so events of other
+/// repositories still get processed.
+///
+/// `Clone` is cheap: the limiters are shared through an `Arc`.
+///
+/// See docs/spec/interfaces/server-config.md — `ProcessingThrottleConfig`
+#[derive(Debug, Clone, Default)]
+pub struct ProcessingThrottle {
+    /// Upper bound of the random delay; zero disables the delay.
+    max_jitter: Duration,
+    /// Limits concurrent processing; `None` when no limit is configured.
+    limiter: Option<Arc<Semaphore>>,
+    /// Limits concurrent processing per repository; `None` when no limit is
+    /// configured.
+    repositories: Option<Arc<RepositoryLimiter>>,
+}
+
+impl ProcessingThrottle {
+    /// Creates a throttle from the server configuration.
+    pub fn new(config: &ProcessingThrottleConfig) -> Self {
+        ProcessingThrottle {
+            max_jitter: config.max_jitter,
+            limiter: config
+                .max_concurrent
+                .map(|limit| Arc::new(Semaphore::new(limit))),
+            repositories: config
+                .max_concurrent_per_repository
+                .map(|limit| Arc::new(RepositoryLimiter::new(limit))),
+        }
+    }
+
+    /// Returns a random delay between zero and `max_jitter`.
+    pub fn jitter(&self) -> Duration {
+        if self.max_jitter.is_zero() {
+            return Duration::ZERO;
+        }
+
+        // Each `RandomState` is seeded differently, which is random enough to
+        // spread a burst without adding a dependency on a random number crate.
+        let bits = RandomState::new().build_hasher().finish();
+        let fraction = (bits >> 11) as f64 / (1u64 << 53) as f64;
+        self.max_jitter.mul_f64(fraction)
+    }
+
+    /// Waits for the random delay, then for a free slot of `repository` and
+    /// finally for a free processing slot.
+    ///
+    /// `repository` is the full name (`owner/name`) of the repository the event
+    /// belongs to; it is compared case-insensitively. The returned permit holds
+    /// the slots until it is dropped.
+    pub async fn acquire(&self, repository: &str) -> ProcessingPermit {
+        let delay = self.jitter();
+        if !delay.is_zero() {
+            debug!(
+                delay_ms = delay.as_millis() as u64,
+                "Delaying event processing"
+            );
+            tokio::time::sleep(delay).await;
+        }
+
+        let repository = match &self.repositories {
+            Some(repositories) => Some(RepositoryLimiter::acquire(repositories, repository).await),
+            None => None,
+        };
+
+        let global = match &self.limiter {
+            // The semaphore is never closed, so acquiring cannot fail.
+            Some(limiter) => Arc::clone(limiter).acquire_owned().await.ok(),
+            None => None,
+        };
+
+        ProcessingPermit {
+            _repository: repository,
+            _global: global,
+        }
+    }
+
+    /// Returns the number of events processed and waiting per repository.
+    ///
+    /// Empty when no per-repository limit is configured.
+    pub fn queue_depths(&self) -> BTreeMap<String, RepositoryQueueDepth> {
+        self.repositories
+            .as_ref()
+            .map(|repositories| repositories.queue_depths())
+            .unwrap_or_default()
+    }
+}
+
+/// The slots taken by [`ProcessingThrottle::acquire`]; dropping it frees them.
+#[derive(Debug)]
+pub struct ProcessingPermit {
+    /// The slot of the event's repository; `None` without a per-repository limit.
+    _repository: Option<RepositorySlot>,
+    /// The shared processing slot; `None` without a global limit.
+    _global: Option<OwnedSemaphorePermit>,
+}
+
+/// Limits the number of events processed at the same time for each repository.
+///
+/// Each repository in flight gets its own semaphore. The entry is removed again
+/// once no event of the repository is processed or waiting, so the map only
+/// holds repositories with events in flight.
+#[derive(Debug)]
+struct RepositoryLimiter {
+    /// Maximum number of events processed at the same time per repository.
+    limit: usize,
+    /// The repositories with events in flight, keyed by lower-case full name.
+    queues: Mutex<HashMap<String, RepositoryQueue>>,
+}
+
+/// The state of one repository in a [`RepositoryLimiter`].
+#[derive(Debug)]
+struct RepositoryQueue {
+    /// Hands out the repository's processing slots.
+    semaphore: Arc<Semaphore>,
+    /// Number of events in flight.
+    depth: RepositoryQueueDepth,
+}
+
+impl RepositoryLimiter {
+    /// Creates a limiter allowing `limit` events per repository.
+    fn new(limit: usize) -> Self {
+        RepositoryLimiter {
+            limit,
+            queues: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits for a free slot of `repository`.
+    ///
+    /// The event counts as waiting until the slot is taken. If the returned future
+    /// is dropped before then, the count is corrected by [`RepositorySlot`]'s
+    /// `Drop`.
+    async fn acquire(limiter: &Arc<Self>, repository: &str) -> RepositorySlot {
+        let key = repository.to_lowercase();
+        let semaphore = {
+            let mut queues = limiter.queues.lock().unwrap_or_else(|e| e.into_inner());
+            let queue = queues
+                .entry(key.clone())
+                .or_insert_with(|| RepositoryQueue {
+                    semaphore: Arc::new(Semaphore::new(limiter.limit)),
+                    depth: RepositoryQueueDepth::default(),
+                });
+            queue.depth.waiting += 1;
+            Arc::clone(&queue.semaphore)
+        };
+
+        let mut slot = RepositorySlot {
+            limiter: Arc::clone(limiter),
+            key,
+            permit: None,
+        };
+
+        // The semaphore is never closed, so acquiring cannot fail.
+        let permit = semaphore.acquire_owned().await.ok();
+
+        let mut queues = limiter.queues.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(queue) = queues.get_mut(&slot.key) {
+            queue.depth.waiting -= 1;
+            queue.depth.processing += 1;
+        }
+        slot.permit = permit;
+        slot
+    }
+
+    /// Returns the number of events processed and waiting per repository.
+    fn queue_depths(&self) -> BTreeMap<String, RepositoryQueueDepth> {
+        self.queues
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(key, queue)| (key.clone(), queue.depth))
+            .collect()
+    }
+}
+
+/// An event's place in the queue of its repository.
+///
+/// Counted as waiting until `permit` is set, and as processing afterwards.
+#[derive(Debug)]
+struct RepositorySlot {
+    /// The limiter the slot belongs to.
+    limiter: Arc<RepositoryLimiter>,
+    /// The lower-case full name of the repository.
+    key: String,
+    /// The repository's processing slot, once taken.
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for RepositorySlot {
+    fn drop(&mut self) {
+        let mut queues = self
+            .limiter
+            .queues
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let Some(queue) = queues.get_mut(&self.key) else {
+            return;
+        };
+
+        if self.permit.is_some() {
+            queue.depth.processing -= 1;
+        } else {
+            queue.depth.waiting -= 1;
+        }
+        if queue.depth == RepositoryQueueDepth::default() {
+            queues.remove(&self.key);
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -128,36 +504,146 @@ pub struct MergeWardenWebhookHandler {
     github_client: GitHubClient,
     /// Policy defaults used when no per-repo config file is found.
     policies: ApplicationDefaults,
+    /// Burst smoothing applied before a pull request event is processed.
+    throttle: ProcessingThrottle,
+    /// Per-pull-request state passed to every [`MergeWarden`] the handler creates.
+    state_store: Arc<dyn StateStore>,
+    /// Receives the result of every processed pull request, if any.
+    result_callback: Option<ResultCallback>,
+    /// Login of the app's bot account. Label changes it makes are not processed.
+    bot_login: Option<String>,
 }
 
 impl MergeWardenWebhookHandler {
     /// Creates a new handler from the given client and policy defaults.
+    ///
+    /// The handler does not throttle processing; see [`Self::with_throttle`]. State
+    /// is kept in memory; see [`Self::with_state_store`]. Results are not posted;
+    /// see [`Self::with_result_callback`].
     pub fn new(github_client: GitHubClient, policies: ApplicationDefaults) -> Self {
         MergeWardenWebhookHandler {
             github_client,
             policies,
+            throttle: ProcessingThrottle::default(),
+            state_store: Arc::new(InMemoryStateStore::new()),
+            result_callback: None,
+            bot_login: None,
         }
     }
 
+    /// Sets the throttle applied before a pull request event is processed.
+    pub fn with_throttle(mut self, throttle: ProcessingThrottle) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Sets the store in which per-pull-request state is kept between events.
+    pub fn with_state_store(mut self, state_store: Arc<dyn StateStore>) -> Self {
+        self.state_store = state_store;
+        self
+    }
+
+    /// Sets the callback that receives the result of every processed pull request.
+    pub fn with_result_callback(mut self, result_callback: Option<ResultCallback>) -> Self {
+        self.result_callback = result_callback;
+        self
+    }
+
+    /// Sets the login of the app's bot account, e.g. `merge-warden[bot]`.
+    ///
+    /// `labeled` and `unlabeled` events sent by this account are ignored, as they
+    /// come from the labels Merge Warden applied itself.
+    pub fn with_bot_login(mut self, bot_login: Option<String>) -> Self {
+        self.bot_login = bot_login;
+        self
+    }
+
+    /// Posts the result of a processed pull request to the result callback, if one
+    /// is configured. A failed delivery is logged and otherwise ignored.
+    ///
+    /// The delivery runs on its own task so a slow callback service does not hold
+    /// the caller's processing permit.
+    fn report_result(
+        &self,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_number: u64,
+        result: &CheckResult,
+    ) {
+        let Some(callback) = self.result_callback.clone() else {
+            return;
+        };
+
+        let repo_owner = repo_owner.to_string();
+        let repo_name = repo_name.to_string();
+        let result = result.clone();
+        tokio::spawn(async move {
+            let report = CheckResultReport {
+                repository_owner: &repo_owner,
+                repository: &repo_name,
+                pull_request: pr_number,
+                result: &result,
+            };
+            if let Err(e) = callback.send(&report).await {
+                warn!(
+                    repository_owner = repo_owner.as_str(),
+                    repository = repo_name.as_str(),
+                    pull_request = pr_number,
+                    error = %e,
+                    "Failed to report the check result"
+                );
+            }
+        });
+    }
+
     /// Processes a `pull_request` webhook event.
     ///
     /// Validates the action, extracts PR metadata, builds a per-installation
     /// GitHub client, loads the repo-level config, and delegates to
-    /// [`MergeWarden::process_pull_request`].
+    /// [`MergeWarden::process_pull_request`], or to
+    /// [`MergeWarden::process_reopened_pull_request`] for reopened pull requests.
+    /// `labeled` and `unlabeled` events are only processed when the config
+    /// [depends on the labels](CurrentPullRequestValidationConfiguration::depends_on_labels),
+    /// and never when they were sent by the app's own
+    /// [bot account](Self::with_bot_login).
     pub async fn handle_pull_request(&self, envelope: &EventEnvelope) -> Result<(), ServerError> {
-        let action = envelope.payload.raw()["action"].as_str().unwrap_or("");
+        let action = match parse_pull_request_action(envelope.payload.raw()) {
+            Ok(action) => action,
+            Err(e) => {
+                error!(
+                    repository = envelope.repository.full_name.as_str(),
+                    error = %e,
+                    "Webhook payload missing action"
+                );
+                return Err(ServerError::ProcessingError(
+                    "Missing action in webhook payload".to_string(),
+                ));
+            }
+        };
         // For pull_request_review events (action = "submitted"/"dismissed") the
-        // review approval state may have changed, so we always re-evaluate.
+        // review approval state may have changed, and for pull_request_review_thread
+        // events (action = "resolved"/"unresolved") the unresolved thread count may
+        // have changed, so we always re-evaluate.
         // For pull_request events we only process the subset of actions that
         // indicate a meaningful state change.
-        if envelope.event_type == "pull_request" {
-            match action {
-                "opened" | "edited" | "ready_for_review" | "converted_to_draft" | "reopened"
-                | "unlocked" | "synchronize" => {}
-                _ => {
-                    info!(action, "Pull request action does not require processing");
-                    return Ok(());
-                }
+        if envelope.event_type == "pull_request" && !action.requires_processing() {
+            info!(
+                action = action.as_str(),
+                "Pull request action does not require processing"
+            );
+            return Ok(());
+        }
+
+        // Merge Warden's own label writes would otherwise trigger another run.
+        if action.changes_labels() {
+            let sender = envelope.payload.raw()["sender"]["login"].as_str();
+            if sender.is_some() && sender == self.bot_login.as_deref() {
+                info!(
+                    action = action.as_str(),
+                    sender = sender.unwrap_or_default(),
+                    "Ignoring a label change made by Merge Warden"
+                );
+                return Ok(());
             }
         }
 
@@ -201,11 +687,14 @@ impl MergeWardenWebhookHandler {
         let repo_owner = &envelope.repository.owner.login;
         let repo_name = &envelope.repository.name;
 
+        // Held until processing completes, freeing the slot for the next event
+        let _permit = self.throttle.acquire(&envelope.repository.full_name).await;
+
         info!(
             repository_owner = repo_owner.as_str(),
             repository = repo_name.as_str(),
             pull_request = pr_number,
-            action,
+            action = action.as_str(),
             "Processing pull request"
         );
 
@@ -265,26 +754,50 @@ impl MergeWardenWebhookHandler {
                     "Failed to resolve PR config from {}: {}. Using compiled-in defaults.",
                     merge_warden_config_path, e
                 );
-                CurrentPullRequestValidationConfiguration::from_app_defaults(&self.policies)
+                let mut fallback =
+                    CurrentPullRequestValidationConfiguration::from_app_defaults(&self.policies);
+                fallback.apply_feature_flags(self.policies.feature_flags.flags_for(repo_owner));
+                fallback
             }
         };
 
+        // A label change can only change the outcome when a check reads the labels.
+        if action.changes_labels() && !validation_config.depends_on_labels() {
+            info!(
+                repository_owner = repo_owner.as_str(),
+                repository = repo_name.as_str(),
+                pull_request = pr_number,
+                action = action.as_str(),
+                "No check reads the labels; ignoring the label change"
+            );
+            return Ok(());
+        }
+
         let warden = MergeWarden::with_config(provider, validation_config)
-            .with_issue_provider(Box::new(issue_provider));
+            .with_issue_provider(Box::new(issue_provider))
+            .with_state_store(self.state_store.clone());
 
-        warden
-            .process_pull_request(repo_owner, repo_name, pr_number.into())
-            .await
-            .map_err(|e| {
-                error!(
-                    repository_owner = repo_owner.as_str(),
-                    repository = repo_name.as_str(),
-                    pull_request = pr_number,
-                    error = %e,
-                    "Failed to process pull request"
-                );
-                ServerError::ProcessingError(format!("Failed to process pull request: {}", e))
-            })?;
+        let result = if action == PullRequestAction::Reopened {
+            warden
+                .process_reopened_pull_request(repo_owner, repo_name, pr_number.into())
+                .await
+        } else {
+            warden
+                .process_pull_request(repo_owner, repo_name, pr_number.into())
+                .await
+        };
+        let check_result = result.map_err(|e| {
+            error!(
+                repository_owner = repo_owner.as_str(),
+                repository = repo_name.as_str(),
+                pull_request = pr_number,
+                error = %e,
+                "Failed to process pull request"
+            );
+            ServerError::ProcessingError(format!("Failed to process pull request: {}", e))
+        })?;
+
+        self.report_result(repo_owner, repo_name, pr_number.into(), &check_result);
 
         info!(
             repository_owner = repo_owner.as_str(),
@@ -383,6 +896,118 @@ impl MergeWardenWebhookHandler {
             return Ok(());
         }
 
+        self.reprocess_pull_requests(&provider, repo_owner, repo_name, pr_numbers, "status")
+            .await;
+
+        Ok(())
+    }
+
+    /// Processes a `check_run` webhook event.
+    ///
+    /// GitHub sends the event with the `requested_action` action when a user presses a
+    /// button on a check run. When the button is the [`CheckRunAction::rerun`] button,
+    /// this handler:
+    ///
+    /// 1. Resolves the installation client from the payload `installation.id`.
+    /// 2. Reads the pull requests from `check_run.pull_requests`, falling back to
+    ///    [`merge_warden_developer_platforms::PullRequestProvider::find_pull_requests_for_commit`]
+    ///    for the check run's `head_sha` when the list is empty.
+    /// 3. Re-runs [`MergeWarden::process_pull_request`] for each pull request.
+    ///
+    /// All other `check_run` actions and buttons are ignored.
+    pub async fn handle_check_run_event(
+        &self,
+        envelope: &EventEnvelope,
+    ) -> Result<(), ServerError> {
+        let payload = envelope.payload.raw();
+        let action = payload["action"].as_str().unwrap_or("");
+        let identifier = payload["requested_action"]["identifier"]
+            .as_str()
+            .unwrap_or("");
+        if action != CHECK_RUN_REQUESTED_ACTION || identifier != CheckRunAction::RERUN_IDENTIFIER {
+            debug!(action, identifier, "Ignoring check run event");
+            return Ok(());
+        }
+
+        let installation_id = match payload["installation"]["id"].as_u64() {
+            Some(id) => id,
+            None => {
+                error!("Check run event payload missing 'installation.id' field");
+                return Err(ServerError::ProcessingError(
+                    "Missing installation ID in check run payload".to_string(),
+                ));
+            }
+        };
+
+        let repo_owner = &envelope.repository.owner.login;
+        let repo_name = &envelope.repository.name;
+        let head_sha = payload["check_run"]["head_sha"].as_str().unwrap_or("");
+
+        // Held until processing completes, freeing the slot for the next event
+        let _permit = self.throttle.acquire(&envelope.repository.full_name).await;
+
+        info!(
+            repository_owner = repo_owner.as_str(),
+            repository = repo_name.as_str(),
+            head_sha,
+            "Processing check run re-run request"
+        );
+
+        let installation_client = self
+            .github_client
+            .installation_by_id(InstallationId::new(installation_id))
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to create installation client for check run event");
+                ServerError::AuthError(format!("Failed to create installation client: {e}"))
+            })?;
+
+        let provider = GitHubProvider::new(installation_client);
+
+        let mut pr_numbers: Vec<u64> = payload["check_run"]["pull_requests"]
+            .as_array()
+            .map(|prs| prs.iter().filter_map(|pr| pr["number"].as_u64()).collect())
+            .unwrap_or_default();
+        if pr_numbers.is_empty() && !head_sha.is_empty() {
+            pr_numbers = provider
+                .find_pull_requests_for_commit(repo_owner, repo_name, head_sha)
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to find PRs for check run head SHA");
+                    ServerError::ProcessingError(format!("Failed to find PRs for commit: {e}"))
+                })?;
+        }
+
+        if pr_numbers.is_empty() {
+            debug!(
+                repository_owner = repo_owner.as_str(),
+                repository = repo_name.as_str(),
+                head_sha,
+                "No open PRs found for check run — nothing to do"
+            );
+            return Ok(());
+        }
+
+        self.reprocess_pull_requests(&provider, repo_owner, repo_name, pr_numbers, "check_run")
+            .await;
+
+        Ok(())
+    }
+
+    /// Re-runs validation for pull requests in one repository after an event that is not
+    /// itself a pull request event, such as a commit status change.
+    ///
+    /// The repository config is loaded once and shared by all pull requests. When it cannot
+    /// be loaded the application defaults are used. Errors for individual pull requests are
+    /// logged at `warn` and do not abort processing of the remaining ones.
+    async fn reprocess_pull_requests(
+        &self,
+        provider: &GitHubProvider,
+        repo_owner: &str,
+        repo_name: &str,
+        pr_numbers: Vec<u64>,
+        event_type: &str,
+    ) {
         let merge_warden_config_path = ".github/merge-warden.toml";
 
         // All PRs in the loop share the same repository, so the config is
@@ -392,30 +1017,38 @@ impl MergeWardenWebhookHandler {
             repo_owner,
             repo_name,
             merge_warden_config_path,
-            &provider,
+            provider,
             &self.policies,
-            Some(&provider),
+            Some(provider),
         )
         .await
         {
             Ok(config) => config,
             Err(ConfigLoadError::OrgPolicyUnavailable(ref msg)) => {
                 warn!(
-                    repository_owner = repo_owner.as_str(),
-                    repository = repo_name.as_str(),
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    event_type,
                     org_policy_error = msg.as_str(),
-                    "Org policy unreachable during status event processing; using defaults"
+                    "Org policy unreachable during event processing; using defaults"
                 );
-                CurrentPullRequestValidationConfiguration::from_app_defaults(&self.policies)
+                let mut fallback =
+                    CurrentPullRequestValidationConfiguration::from_app_defaults(&self.policies);
+                fallback.apply_feature_flags(self.policies.feature_flags.flags_for(repo_owner));
+                fallback
             }
             Err(e) => {
                 warn!(
-                    repository_owner = repo_owner.as_str(),
-                    repository = repo_name.as_str(),
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    event_type,
                     error = %e,
-                    "Failed to resolve config during status event; using defaults"
+                    "Failed to resolve config during event processing; using defaults"
                 );
-                CurrentPullRequestValidationConfiguration::from_app_defaults(&self.policies)
+                let mut fallback =
+                    CurrentPullRequestValidationConfiguration::from_app_defaults(&self.policies);
+                fallback.apply_feature_flags(self.policies.feature_flags.flags_for(repo_owner));
+                fallback
             }
         };
 
@@ -423,28 +1056,194 @@ impl MergeWardenWebhookHandler {
             let issue_provider = provider.clone();
 
             let warden = MergeWarden::with_config(provider.clone(), validation_config.clone())
-                .with_issue_provider(Box::new(issue_provider));
+                .with_issue_provider(Box::new(issue_provider))
+                .with_state_store(self.state_store.clone());
 
-            if let Err(e) = warden
+            match warden
                 .process_pull_request(repo_owner, repo_name, pr_number)
                 .await
             {
+                Err(e) => warn!(
+                    repository_owner = repo_owner,
+                    repository = repo_name,
+                    pull_request = pr_number,
+                    event_type,
+                    error = %e,
+                    "Failed to re-evaluate PR"
+                ),
+                Ok(check_result) => {
+                    info!(
+                        repository_owner = repo_owner,
+                        repository = repo_name,
+                        pull_request = pr_number,
+                        event_type,
+                        "PR re-evaluated"
+                    );
+                    self.report_result(repo_owner, repo_name, pr_number, &check_result);
+                }
+            }
+        }
+    }
+
+    /// Processes a `merge_group` webhook event.
+    ///
+    /// GitHub sends the event with the `checks_requested` action when a merge queue
+    /// creates a merge group and waits for the required checks on its head commit.
+    /// When `policies.merge_queue.enabled` is set, this handler:
+    ///
+    /// 1. Reads the pull request number from the merge group's `head_ref`, a
+    ///    `gh-readonly-queue/<base>/pr-<number>-<sha>` branch.
+    /// 2. Resolves the installation client and loads the repository config.
+    /// 3. Calls [`MergeWarden::process_merge_group`], which evaluates the pull request
+    ///    without changing it and reports the conclusion on the merge group's `head_sha`.
+    ///
+    /// Other actions, such as `destroyed`, and all events while merge queue handling
+    /// is disabled are ignored.
+    pub async fn handle_merge_group_event(
+        &self,
+        envelope: &EventEnvelope,
+    ) -> Result<(), ServerError> {
+        let payload = envelope.payload.raw();
+        let action = payload["action"].as_str().unwrap_or("");
+        if !self.policies.merge_queue.enabled || action != MERGE_GROUP_CHECKS_REQUESTED {
+            debug!(
+                action,
+                enabled = self.policies.merge_queue.enabled,
+                "Ignoring merge group event"
+            );
+            return Ok(());
+        }
+
+        let head_sha = match payload["merge_group"]["head_sha"].as_str() {
+            Some(sha) => sha,
+            None => {
+                error!("Merge group event payload missing 'merge_group.head_sha' field");
+                return Err(ServerError::ProcessingError(
+                    "Missing merge_group.head_sha in merge group payload".to_string(),
+                ));
+            }
+        };
+
+        let head_ref = payload["merge_group"]["head_ref"].as_str().unwrap_or("");
+        let queue_ref = match parse_merge_queue_ref(head_ref) {
+            Some(queue_ref) => queue_ref,
+            None => {
+                error!(
+                    head_ref,
+                    "Merge group head ref does not name a pull request"
+                );
+                return Err(ServerError::ProcessingError(format!(
+                    "Merge group head ref '{head_ref}' does not name a pull request"
+                )));
+            }
+        };
+
+        let installation_id = match payload["installation"]["id"].as_u64() {
+            Some(id) => id,
+            None => {
+                error!("Merge group event payload missing 'installation.id' field");
+                return Err(ServerError::ProcessingError(
+                    "Missing installation ID in merge group payload".to_string(),
+                ));
+            }
+        };
+
+        let repo_owner = &envelope.repository.owner.login;
+        let repo_name = &envelope.repository.name;
+        let pr_number = queue_ref.pr_number;
+
+        // Held until processing completes, freeing the slot for the next event
+        let _permit = self.throttle.acquire(&envelope.repository.full_name).await;
+
+        info!(
+            repository_owner = repo_owner.as_str(),
+            repository = repo_name.as_str(),
+            pull_request = pr_number,
+            base_branch = queue_ref.base_branch.as_str(),
+            head_sha,
+            "Processing merge group"
+        );
+
+        let installation_client = self
+            .github_client
+            .installation_by_id(InstallationId::new(installation_id))
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to create installation client for merge group event");
+                ServerError::AuthError(format!("Failed to create installation client: {e}"))
+            })?;
+
+        let provider = GitHubProvider::new(installation_client);
+        let issue_provider = provider.clone();
+
+        let merge_warden_config_path = ".github/merge-warden.toml";
+
+        // As for pull request events, an unreachable org policy aborts processing: the
+        // queue must not merge a pull request that was validated against the wrong policy.
+        let validation_config = match resolve_pull_request_config(
+            repo_owner,
+            repo_name,
+            merge_warden_config_path,
+            &provider,
+            &self.policies,
+            Some(&provider),
+        )
+        .await
+        {
+            Ok(config) => config,
+            Err(ConfigLoadError::OrgPolicyUnavailable(ref msg)) => {
+                error!(
+                    merge_warden_config_path,
+                    org_policy_error = msg.as_str(),
+                    "Org policy unreachable and fail_if_unreachable = true; aborting merge group processing"
+                );
+                return Err(ServerError::ProcessingError(format!(
+                    "Org policy unavailable: {}",
+                    msg
+                )));
+            }
+            Err(e) => {
                 warn!(
                     repository_owner = repo_owner.as_str(),
                     repository = repo_name.as_str(),
-                    pull_request = pr_number,
                     error = %e,
-                    "Failed to process PR during status event"
+                    "Failed to resolve config during merge group event; using defaults"
                 );
-            } else {
-                info!(
+                let mut fallback =
+                    CurrentPullRequestValidationConfiguration::from_app_defaults(&self.policies);
+                fallback.apply_feature_flags(self.policies.feature_flags.flags_for(repo_owner));
+                fallback
+            }
+        };
+
+        let warden = MergeWarden::with_config(provider, validation_config)
+            .with_issue_provider(Box::new(issue_provider))
+            .with_state_store(self.state_store.clone());
+
+        let check_result = warden
+            .process_merge_group(repo_owner, repo_name, pr_number, head_sha)
+            .await
+            .map_err(|e| {
+                error!(
                     repository_owner = repo_owner.as_str(),
                     repository = repo_name.as_str(),
                     pull_request = pr_number,
-                    "PR re-evaluated after renovate stability status change"
+                    head_sha,
+                    error = %e,
+                    "Failed to process merge group"
                 );
-            }
-        }
+                ServerError::ProcessingError(format!("Failed to process merge group: {}", e))
+            })?;
+
+        self.report_result(repo_owner, repo_name, pr_number, &check_result);
+
+        info!(
+            repository_owner = repo_owner.as_str(),
+            repository = repo_name.as_str(),
+            pull_request = pr_number,
+            head_sha,
+            "Merge group processing completed"
+        );
 
         Ok(())
     }
@@ -494,7 +1293,21 @@ impl WebhookHandler for MergeWardenWebhookHandler {
                 .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>);
         }
 
-        if envelope.event_type != "pull_request" && envelope.event_type != "pull_request_review" {
+        if envelope.event_type == CHECK_RUN_EVENT_TYPE {
+            return self
+                .handle_check_run_event(envelope)
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>);
+        }
+
+        if envelope.event_type == MERGE_GROUP_EVENT_TYPE {
+            return self
+                .handle_merge_group_event(envelope)
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>);
+        }
+
+        if !PULL_REQUEST_EVENT_TYPES.contains(&envelope.event_type.as_str()) {
             debug!(event_type = %envelope.event_type, "Ignoring non-pull-request event");
             return Ok(());
         }
@@ -589,13 +1402,31 @@ pub async fn handle_webhook(
     }
 }
 
-/// `GET /health` — liveness probe for container orchestrators.
+/// `GET /health` — health probe for container orchestrators.
+///
+/// Reports the startup status of each critical dependency as a JSON
+/// [`HealthReport`]. No external calls are made per request; the status is the
+/// one captured in `main()`. When a per-repository processing limit is
+/// configured, the report also lists the events in flight per repository.
+/// The queue depths do not affect the status code.
 ///
-/// Returns `200 OK` without checking external dependencies (GitHub API, queue).
+/// # Responses
+/// - `200 OK` — all startup dependencies initialised successfully.
+/// - `503 Service Unavailable` — at least one critical dependency failed.
 ///
 /// See docs/spec/design/containerisation.md — health check
-pub async fn health_check() -> impl IntoResponse {
-    StatusCode::OK
+pub async fn health_check(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let (code, mut report) = HealthReport::from_startup_health(&state.health);
+    report.repository_queues = state.throttle.queue_depths();
+    if code != StatusCode::OK {
+        warn!(
+            configuration = ?report.components.configuration.status,
+            secrets = ?report.components.secrets.status,
+            github_app = ?report.components.github_app.status,
+            "Health check reporting failed startup dependency"
+        );
+    }
+    (code, Json(report))
 }
 
 // ---------------------------------------------------------------------------
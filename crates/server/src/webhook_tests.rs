@@ -1,16 +1,23 @@
-use axum::{http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse};
 use chrono::Utc;
 use github_bot_sdk::{
     client::{ClientConfig, GitHubClient, OwnerType, Repository, RepositoryOwner},
     events::{EventEnvelope, EventPayload},
     webhook::WebhookHandler,
 };
-use merge_warden_core::config::{ApplicationDefaults, RepositoryScope};
+use merge_warden_core::config::{ApplicationDefaults, MergeQueueConfig, RepositoryScope};
 use merge_warden_developer_platforms::app_auth::AppAuthProvider;
 use serde_json::json;
 
 use super::health_check;
 use super::MergeWardenWebhookHandler;
+use super::{
+    AppState, ComponentHealth, ComponentStatus, HealthReport, ProcessingThrottle,
+    RepositoryQueueDepth, StartupHealth,
+};
+use crate::config::ProcessingThrottleConfig;
 
 // ---------------------------------------------------------------------------
 // Test helpers
@@ -31,6 +38,15 @@ fn make_test_handler() -> MergeWardenWebhookHandler {
     MergeWardenWebhookHandler::new(github_client, ApplicationDefaults::default())
 }
 
+/// Number of events the throttle lets start processing now, or `None` when
+/// concurrent processing is not limited.
+fn available_permits(throttle: &ProcessingThrottle) -> Option<usize> {
+    throttle
+        .limiter
+        .as_ref()
+        .map(|limiter| limiter.available_permits())
+}
+
 fn make_status_envelope(context: &str) -> EventEnvelope {
     let repo = Repository {
         id: 1,
@@ -214,12 +230,98 @@ fn assert_err_contains(
 // health_check
 // ---------------------------------------------------------------------------
 
+fn healthy_startup() -> StartupHealth {
+    StartupHealth {
+        configuration: ComponentHealth::ok("using compiled-in defaults"),
+        secrets: ComponentHealth::ok("loaded from environment"),
+        github_app: ComponentHealth::ok("authenticated as GitHub App 12345"),
+    }
+}
+
+fn make_app_state(health: StartupHealth) -> Arc<AppState> {
+    let auth = AppAuthProvider::new(12345, TEST_PEM, "https://api.github.com")
+        .expect("test RSA key must be valid");
+    let github_client = GitHubClient::builder(auth)
+        .config(ClientConfig::default())
+        .build()
+        .expect("GitHub client must build");
+    Arc::new(AppState {
+        receiver: None,
+        github_client,
+        policies: ApplicationDefaults::default(),
+        health,
+        throttle: ProcessingThrottle::default(),
+        state_store: Arc::new(merge_warden_core::state::InMemoryStateStore::new()),
+        bot_login: None,
+        result_callback: None,
+    })
+}
+
 #[tokio::test]
 async fn health_check_returns_200_ok() {
-    let response = health_check().await.into_response();
+    let state = make_app_state(healthy_startup());
+    let response = health_check(State(state)).await.into_response();
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn health_check_returns_503_when_github_auth_failed() {
+    let state = make_app_state(StartupHealth {
+        github_app: ComponentHealth::failed("GitHub App authentication failed: bad key"),
+        ..healthy_startup()
+    });
+    let response = health_check(State(state)).await.into_response();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[test]
+fn health_report_serialises_component_statuses() {
+    let health = StartupHealth {
+        github_app: ComponentHealth::failed("GitHub App authentication failed: bad key"),
+        ..healthy_startup()
+    };
+    let (code, report) = HealthReport::from_startup_health(&health);
+
+    assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(report.status, ComponentStatus::Failed);
+
+    let body = serde_json::to_value(&report).expect("report must serialise");
+    assert_eq!(body["status"], "failed");
+    assert_eq!(body["components"]["configuration"]["status"], "ok");
+    assert_eq!(body["components"]["secrets"]["status"], "ok");
+    assert_eq!(body["components"]["github_app"]["status"], "failed");
+    assert_eq!(
+        body["components"]["github_app"]["detail"],
+        "GitHub App authentication failed: bad key"
+    );
+    assert!(body.get("repository_queues").is_none());
+}
+
+#[test]
+fn health_report_serialises_repository_queues() {
+    let (_, mut report) = HealthReport::from_startup_health(&healthy_startup());
+    report.repository_queues.insert(
+        "owner/repo".to_string(),
+        RepositoryQueueDepth {
+            processing: 2,
+            waiting: 5,
+        },
+    );
+
+    let body = serde_json::to_value(&report).expect("report must serialise");
+    assert_eq!(
+        body["repository_queues"],
+        json!({ "owner/repo": { "processing": 2, "waiting": 5 } })
+    );
+}
+
+#[test]
+fn health_report_is_ok_when_all_components_ok() {
+    let (code, report) = HealthReport::from_startup_health(&healthy_startup());
+    assert_eq!(code, StatusCode::OK);
+    assert_eq!(report.status, ComponentStatus::Ok);
+}
+
 // ---------------------------------------------------------------------------
 // handle_status_event
 // ---------------------------------------------------------------------------
@@ -240,6 +342,252 @@ async fn handle_status_event_ignores_non_renovate_context() {
     );
 }
 
+// ---------------------------------------------------------------------------
+// handle_pull_request actions
+// ---------------------------------------------------------------------------
+
+/// Builds a `pull_request` envelope with the given `action` and no
+/// `installation.id`; see [`make_pull_request_envelope`].
+fn make_pull_request_action_envelope(action: &str) -> EventEnvelope {
+    let payload = json!({
+        "action": action,
+        "pull_request": { "number": 1 },
+        "repository": { "name": "repo" },
+    });
+    EventEnvelope::new(
+        "pull_request".to_string(),
+        make_repository("repo"),
+        EventPayload::new(payload),
+    )
+}
+
+/// Builds a `pull_request` envelope like [`make_pull_request_action_envelope`],
+/// sent by `sender`.
+fn make_pull_request_action_envelope_from(action: &str, sender: &str) -> EventEnvelope {
+    let payload = json!({
+        "action": action,
+        "pull_request": { "number": 1 },
+        "repository": { "name": "repo" },
+        "sender": { "login": sender },
+    });
+    EventEnvelope::new(
+        "pull_request".to_string(),
+        make_repository("repo"),
+        EventPayload::new(payload),
+    )
+}
+
+/// Label changes can change the label policy, exclusive label, disable label
+/// and bypass label outcomes, so they must reach the processing path rather
+/// than be discarded with the other unhandled actions.
+#[tokio::test]
+async fn handle_event_processes_pull_request_label_changes() {
+    let handler = make_test_handler();
+
+    for action in ["labeled", "unlabeled"] {
+        let envelope = make_pull_request_action_envelope(action);
+
+        let result = handler.handle_event(&envelope).await;
+
+        assert_err_contains(&result, "Missing installation ID");
+    }
+}
+
+/// Label changes made by Merge Warden itself must not trigger another run.
+#[tokio::test]
+async fn handle_event_ignores_label_changes_sent_by_the_bot() {
+    let handler = make_test_handler().with_bot_login(Some("merge-warden[bot]".to_string()));
+
+    for action in ["labeled", "unlabeled"] {
+        let envelope = make_pull_request_action_envelope_from(action, "merge-warden[bot]");
+
+        let result = handler.handle_event(&envelope).await;
+
+        assert!(
+            result.is_ok(),
+            "{action} event from the bot should be ignored: {:?}",
+            result
+        );
+    }
+}
+
+/// Label changes made by anyone else are still processed.
+#[tokio::test]
+async fn handle_event_processes_label_changes_sent_by_other_users() {
+    let handler = make_test_handler().with_bot_login(Some("merge-warden[bot]".to_string()));
+    let envelope = make_pull_request_action_envelope_from("labeled", "octocat");
+
+    let result = handler.handle_event(&envelope).await;
+
+    assert_err_contains(&result, "Missing installation ID");
+}
+
+#[tokio::test]
+async fn handle_event_ignores_pull_request_actions_that_need_no_processing() {
+    let handler = make_test_handler();
+    let envelope = make_pull_request_action_envelope("assigned");
+
+    let result = handler.handle_event(&envelope).await;
+
+    assert!(
+        result.is_ok(),
+        "assigned event should be ignored: {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn handle_event_rejects_pull_request_events_without_an_action() {
+    let handler = make_test_handler();
+    let envelope = EventEnvelope::new(
+        "pull_request".to_string(),
+        make_repository("repo"),
+        EventPayload::new(json!({
+            "pull_request": { "number": 1 },
+            "repository": { "name": "repo" },
+        })),
+    );
+
+    let result = handler.handle_event(&envelope).await;
+
+    assert_err_contains(&result, "Missing action");
+}
+
+// ---------------------------------------------------------------------------
+// handle_merge_group_event
+// ---------------------------------------------------------------------------
+
+fn make_merge_queue_handler(enabled: bool) -> MergeWardenWebhookHandler {
+    let auth = AppAuthProvider::new(12345, TEST_PEM, "https://api.github.com")
+        .expect("test RSA key must be valid");
+    let github_client = GitHubClient::builder(auth)
+        .config(ClientConfig::default())
+        .build()
+        .expect("GitHub client must build");
+    let policies = ApplicationDefaults {
+        merge_queue: MergeQueueConfig { enabled },
+        ..ApplicationDefaults::default()
+    };
+    MergeWardenWebhookHandler::new(github_client, policies)
+}
+
+/// Builds a `merge_group` envelope without `installation.id`, so that a handler
+/// that goes on to process the event fails offline before any GitHub API call.
+fn make_merge_group_envelope(action: &str, head_ref: &str) -> EventEnvelope {
+    let repo = make_repository("repo");
+    let payload = json!({
+        "action": action,
+        "merge_group": {
+            "head_sha": "6f1d2c3b",
+            "head_ref": head_ref,
+            "base_ref": "refs/heads/main",
+        },
+        "repository": { "name": "repo" },
+    });
+    EventEnvelope::new("merge_group".to_string(), repo, EventPayload::new(payload))
+}
+
+#[tokio::test]
+async fn handle_merge_group_event_is_ignored_when_disabled() {
+    let handler = make_merge_queue_handler(false);
+    let envelope = make_merge_group_envelope(
+        "checks_requested",
+        "refs/heads/gh-readonly-queue/main/pr-42-6f1d2c3b",
+    );
+
+    let result = handler.handle_event(&envelope).await;
+
+    assert!(result.is_ok(), "disabled merge queue: {:?}", result);
+}
+
+#[tokio::test]
+async fn handle_merge_group_event_ignores_destroyed_merge_groups() {
+    let handler = make_merge_queue_handler(true);
+    let envelope = make_merge_group_envelope(
+        "destroyed",
+        "refs/heads/gh-readonly-queue/main/pr-42-6f1d2c3b",
+    );
+
+    let result = handler.handle_merge_group_event(&envelope).await;
+
+    assert!(result.is_ok(), "destroyed merge group: {:?}", result);
+}
+
+#[tokio::test]
+async fn handle_event_routes_merge_group_events_when_enabled() {
+    let handler = make_merge_queue_handler(true);
+    let envelope = make_merge_group_envelope(
+        "checks_requested",
+        "refs/heads/gh-readonly-queue/main/pr-42-6f1d2c3b",
+    );
+
+    let result = handler.handle_event(&envelope).await;
+
+    assert_err_contains(&result, "Missing installation ID in merge group payload");
+}
+
+#[tokio::test]
+async fn handle_merge_group_event_rejects_unknown_head_refs() {
+    let handler = make_merge_queue_handler(true);
+    let envelope = make_merge_group_envelope("checks_requested", "refs/heads/main");
+
+    let result = handler.handle_merge_group_event(&envelope).await;
+
+    let error = result.expect_err("head ref without a pull request");
+    assert!(error.to_string().contains("does not name a pull request"));
+}
+
+// ---------------------------------------------------------------------------
+// handle_check_run_event
+// ---------------------------------------------------------------------------
+
+/// Builds a `check_run` envelope without `installation.id`, so that a handler
+/// that goes on to process the event fails offline before any GitHub API call.
+fn make_check_run_envelope(action: &str, identifier: &str) -> EventEnvelope {
+    let repo = make_repository("repo");
+    let payload = json!({
+        "action": action,
+        "check_run": {
+            "name": "MergeWarden",
+            "head_sha": "6f1d2c3b",
+            "pull_requests": [{ "number": 42 }],
+        },
+        "requested_action": { "identifier": identifier },
+        "repository": { "name": "repo" },
+    });
+    EventEnvelope::new("check_run".to_string(), repo, EventPayload::new(payload))
+}
+
+#[tokio::test]
+async fn handle_check_run_event_ignores_other_actions() {
+    let handler = make_test_handler();
+    let envelope = make_check_run_envelope("completed", "merge_warden_rerun");
+
+    let result = handler.handle_check_run_event(&envelope).await;
+
+    assert!(result.is_ok(), "completed check run: {:?}", result);
+}
+
+#[tokio::test]
+async fn handle_check_run_event_ignores_other_buttons() {
+    let handler = make_test_handler();
+    let envelope = make_check_run_envelope("requested_action", "other_app_fix");
+
+    let result = handler.handle_check_run_event(&envelope).await;
+
+    assert!(result.is_ok(), "unknown button: {:?}", result);
+}
+
+#[tokio::test]
+async fn handle_event_routes_check_run_rerun_requests() {
+    let handler = make_test_handler();
+    let envelope = make_check_run_envelope("requested_action", "merge_warden_rerun");
+
+    let result = handler.handle_event(&envelope).await;
+
+    assert_err_contains(&result, "Missing installation ID in check run payload");
+}
+
 // ---------------------------------------------------------------------------
 // handle_event routing
 // ---------------------------------------------------------------------------
@@ -772,3 +1120,105 @@ async fn handle_event_outcome_matches_is_repository_in_scope_for_status_events()
         }
     }
 }
+
+// ---------------------------------------------------------------------------
+// ProcessingThrottle
+// ---------------------------------------------------------------------------
+
+#[test]
+fn processing_throttle_jitter_stays_within_max() {
+    let throttle = ProcessingThrottle::new(&ProcessingThrottleConfig {
+        max_jitter: std::time::Duration::from_millis(200),
+        max_concurrent: None,
+        max_concurrent_per_repository: None,
+    });
+
+    for _ in 0..100 {
+        assert!(throttle.jitter() <= std::time::Duration::from_millis(200));
+    }
+}
+
+#[test]
+fn processing_throttle_without_jitter_never_delays() {
+    let throttle = ProcessingThrottle::default();
+
+    assert!(throttle.jitter().is_zero());
+    assert_eq!(available_permits(&throttle), None);
+    assert!(throttle.queue_depths().is_empty());
+}
+
+#[tokio::test]
+async fn processing_throttle_limits_concurrent_processing() {
+    let throttle = ProcessingThrottle::new(&ProcessingThrottleConfig {
+        max_jitter: std::time::Duration::ZERO,
+        max_concurrent: Some(2),
+        max_concurrent_per_repository: None,
+    });
+    let shared = throttle.clone();
+
+    let first = throttle.acquire("owner/repo").await;
+    let _second = shared.acquire("owner/other").await;
+    assert_eq!(available_permits(&throttle), Some(0));
+
+    drop(first);
+    assert_eq!(available_permits(&shared), Some(1));
+}
+
+#[tokio::test]
+async fn processing_throttle_limits_concurrent_processing_per_repository() {
+    let throttle = ProcessingThrottle::new(&ProcessingThrottleConfig {
+        max_jitter: std::time::Duration::ZERO,
+        max_concurrent: None,
+        max_concurrent_per_repository: Some(1),
+    });
+
+    let first = throttle.acquire("owner/busy").await;
+
+    // Other repositories are not held up by the busy one
+    let other = tokio::time::timeout(
+        std::time::Duration::from_millis(100),
+        throttle.acquire("owner/quiet"),
+    )
+    .await;
+    assert!(other.is_ok());
+    drop(other);
+
+    // The repository is matched case-insensitively, so this event has to wait
+    let blocked = tokio::time::timeout(
+        std::time::Duration::from_millis(50),
+        throttle.acquire("Owner/Busy"),
+    )
+    .await;
+    assert!(blocked.is_err());
+
+    let shared = throttle.clone();
+    let waiter = tokio::spawn(async move {
+        let _permit = shared.acquire("owner/busy").await;
+    });
+    while throttle.queue_depths()["owner/busy"].waiting == 0 {
+        tokio::task::yield_now().await;
+    }
+    assert_eq!(
+        throttle.queue_depths()["owner/busy"],
+        RepositoryQueueDepth {
+            processing: 1,
+            waiting: 1,
+        }
+    );
+
+    drop(first);
+    waiter.await.expect("waiting event must be processed");
+    assert!(throttle.queue_depths().is_empty());
+}
+
+#[test]
+fn handler_posts_results_only_when_given_a_callback() {
+    let callback =
+        crate::callback::ResultCallback::new("https://dashboard.example.com/results").unwrap();
+
+    assert!(make_test_handler()
+        .with_result_callback(Some(callback))
+        .result_callback
+        .is_some());
+    assert!(make_test_handler().result_callback.is_none());
+}